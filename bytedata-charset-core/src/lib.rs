@@ -0,0 +1,74 @@
+//! Parsing of single-byte mapping files, shared by `bytedata-charset` at runtime and `bytedata-unicode-macros` at compile time,
+//! so that both read a mapping the same way.
+
+#![no_std]
+
+/// Parse a single `0x`-prefixed hexadecimal number.
+fn parse_hex(value: &str) -> Option<u32> {
+    let value = value
+        .strip_prefix("0x")
+        .or_else(|| value.strip_prefix("0X"))?;
+    u32::from_str_radix(value, 16).ok()
+}
+
+/// Parse the byte column of a line.
+///
+/// The unicode.org `MAPPINGS` format uses the hexadecimal byte value, while the WHATWG `index-*.txt` files use the decimal pointer, which is the offset from `0x80`.
+fn parse_byte(value: &str) -> Result<u8, &'static str> {
+    if value.starts_with("0x") || value.starts_with("0X") {
+        let byte = parse_hex(value).ok_or("invalid byte value")?;
+        return u8::try_from(byte)
+            .ok()
+            .ok_or("only single-byte mappings are supported");
+    }
+    let pointer = value.parse::<u8>().ok().ok_or("invalid pointer")?;
+    if pointer >= 0x80 {
+        return Err("only single-byte mappings are supported");
+    }
+    Ok(0x80 | pointer)
+}
+
+/// Parse the unicode column of a line.
+///
+/// Apple mapping files may prefix the character with a directional hint such as `<RL>+` or map a byte to a sequence of characters joined by `+`,
+/// in which case the hints are dropped and only the first character is used.
+fn parse_char(mut value: &str) -> Result<char, &'static str> {
+    while value.starts_with('<') {
+        let Some((_, rest)) = value.split_once(">+") else {
+            return Err("invalid character value");
+        };
+        value = rest;
+    }
+    let value = value.split('+').next().unwrap_or_default();
+    let codepoint = parse_hex(value).ok_or("invalid character value")?;
+    char::from_u32(codepoint).ok_or("invalid character value")
+}
+
+/// Parse a line of a single-byte mapping file.
+///
+/// Returns `None` for lines that only contain whitespace or a comment,
+/// otherwise the byte and the character it maps to, which is `None` if the byte is explicitly undefined.
+///
+/// Checking for bytes that are mapped more than once is left to the caller.
+///
+/// # Errors
+///
+/// Returns a description of the problem if the line is not a valid mapping.
+#[inline]
+pub fn parse_mapping_line(line: &str) -> Result<Option<(u8, Option<char>)>, &'static str> {
+    let line = line.split('#').next().unwrap_or_default();
+    let mut fields = line.split_whitespace();
+    let Some(byte) = fields.next() else {
+        return Ok(None);
+    };
+    let byte = parse_byte(byte)?;
+    let Some(ch) = fields.next() else {
+        return Ok(Some((byte, None)));
+    };
+    let ch = parse_char(ch)?;
+    // `'\0'` marks an undefined byte in the tables
+    if ch == '\0' && byte != 0 {
+        return Err("only byte 0x00 may map to U+0000");
+    }
+    Ok(Some((byte, Some(ch))))
+}
@@ -50,6 +50,24 @@ impl Ascii7Encoding {
             return crate::DecodeResult::InvalidChar(byte, 1);
         }
 
+        #[cfg(all(any(target_arch = "x86_64", target_arch = "x86"), feature = "sse2"))]
+        if maxlen >= 16 && sse2_detected() {
+            // SAFETY: The pointer is valid and the length is correct, and sse2 has been checked.
+            let res = unsafe { ascii7_decode_sse2(bytes_ptr, maxlen, 0) };
+            if !matches!(res, crate::DecodeResult::Empty) {
+                return res;
+            }
+        }
+
+        #[cfg(feature = "swar")]
+        if maxlen >= core::mem::size_of::<usize>() {
+            // SAFETY: The pointer is valid and the length is correct.
+            let res = unsafe { ascii7_decode_swar(bytes_ptr, maxlen, 0) };
+            if !matches!(res, crate::DecodeResult::Empty) {
+                return res;
+            }
+        }
+
         // SAFETY: The pointer is valid and the length is correct.
         unsafe { ascii7_decode_const(bytes_ptr, maxlen, 0) }
     }
@@ -105,6 +123,24 @@ impl Ascii7Encoding {
             }
         }
 
+        #[cfg(all(any(target_arch = "x86_64", target_arch = "x86"), feature = "sse2"))]
+        if maxlen >= 16 && sse2_detected() {
+            // SAFETY: The pointer is valid and the length is correct, and sse2 has been checked.
+            let res = unsafe { ascii7_encode_sse2(chars_ptr, maxlen, 0) };
+            if !matches!(res, crate::EncodeResult::Empty) {
+                return res;
+            }
+        }
+
+        #[cfg(feature = "swar")]
+        if maxlen >= core::mem::size_of::<usize>() {
+            // SAFETY: The pointer is valid and the length is correct.
+            let res = unsafe { ascii7_encode_swar(chars_ptr, maxlen, 0) };
+            if !matches!(res, crate::EncodeResult::Empty) {
+                return res;
+            }
+        }
+
         // SAFETY: The pointer is valid and the length is correct.
         unsafe { ascii7_encode_const(chars_ptr, maxlen, 0) }
     }
@@ -213,6 +249,20 @@ impl crate::CharsetDecoding for Ascii7Encoding {
     fn decode(&self, bytes: &[u8]) -> crate::DecodeResult {
         Self::decode(self, bytes)
     }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn decode_all<'a>(
+        &self,
+        bytes: bytedata::ByteData<'a>,
+        invalid: crate::DecodeInvalid,
+    ) -> Result<bytedata::StringData<'a>, crate::DecodeError> {
+        if bytes.as_slice().is_ascii() {
+            // SAFETY: ascii is valid utf-8
+            return Ok(unsafe { bytedata::StringData::from_bytedata_unchecked(bytes) });
+        }
+        crate::bulk::decode_all(self, bytes, invalid)
+    }
 }
 
 impl crate::CharsetEncoding for Ascii7Encoding {
@@ -220,6 +270,39 @@ impl crate::CharsetEncoding for Ascii7Encoding {
     fn encode(&self, chars: &str) -> crate::EncodeResult {
         Self::encode(self, chars)
     }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn encode_all<'a>(
+        &self,
+        chars: &'a str,
+        invalid: crate::EncodeInvalid,
+    ) -> Result<bytedata::ByteData<'a>, &'static str> {
+        if chars.is_ascii() {
+            return Ok(chars.as_bytes().into());
+        }
+        crate::bulk::encode_all(self, chars, invalid)
+    }
+}
+
+/// Count the leading ASCII bytes of the given bytes, using the fastest ASCII fast path available.
+#[cfg(all(
+    feature = "alloc",
+    any(feature = "ascii7-compat", feature = "single-byte")
+))]
+#[expect(clippy::redundant_pub_crate)]
+pub(crate) fn ascii7_prefix(bytes: &[u8]) -> usize {
+    let mut len = 0_usize;
+    // the vectorized paths may leave a short tail unchecked, so the prefix is counted until a non-ASCII byte is reached
+    while let Some(rest) = bytes.get(len..) {
+        let crate::DecodeResult::Utf8(run) = ASCII7.decode(rest) else {
+            break;
+        };
+        #[expect(clippy::cast_possible_truncation)]
+        let run = run as usize;
+        len += run;
+    }
+    len
 }
 
 const fn detect_const_inner(bytes: &[u8]) -> crate::detect::DetectionResult {
@@ -299,7 +382,7 @@ unsafe fn ascii7_encode_avx512bw(
             data,
             core::arch::x86_64::_mm512_set1_epi8(0x80_u8 as i8),
         );
-        let zc = masked.leading_zeros() as usize;
+        let zc = masked.trailing_zeros() as usize;
         utflen += zc;
         if zc == 64 {
             maxlen -= 64;
@@ -340,7 +423,7 @@ unsafe fn ascii7_encode_avx512f(
             data,
             core::arch::x86_64::_mm512_set1_epi8(0x80_u8 as i8),
         );
-        let zc = (masked.leading_zeros() as usize) << 2;
+        let zc = (masked.trailing_zeros() as usize) << 2;
         utflen += zc;
         if zc == 64 {
             maxlen -= 64;
@@ -378,17 +461,21 @@ pub(crate) unsafe fn ascii7_encode_avx(
     mut utflen: usize,
 ) -> crate::EncodeResult {
     #[cfg(target_arch = "x86")]
-    use core::arch::x86::{__m256i, _mm256_loadu_si256, _mm256_set1_epi8, _mm256_testc_si256};
+    use core::arch::x86::{__m256i, _mm256_loadu_si256, _mm256_set1_epi8, _mm256_testz_si256};
     #[cfg(target_arch = "x86_64")]
-    use core::arch::x86_64::{__m256i, _mm256_loadu_si256, _mm256_set1_epi8, _mm256_testc_si256};
+    use core::arch::x86_64::{__m256i, _mm256_loadu_si256, _mm256_set1_epi8, _mm256_testz_si256};
 
     loop {
         #[expect(clippy::cast_ptr_alignment)]
         let data = _mm256_loadu_si256(val.cast::<__m256i>());
         #[expect(clippy::cast_possible_wrap)]
-        let masked = _mm256_testc_si256(data, _mm256_set1_epi8(0x80_u8 as i8));
-        let zc = masked.leading_zeros();
-        utflen += zc as usize;
+        let all_ascii = _mm256_testz_si256(data, _mm256_set1_epi8(0x80_u8 as i8)) != 0;
+        // the test only tells if all bytes are ASCII, so the ASCII prefix of a mixed chunk is counted one by one
+        let mut zc = if all_ascii { 32 } else { 0_usize };
+        while zc < 32 && val.add(zc).read() < 128 {
+            zc += 1;
+        }
+        utflen += zc;
         if zc == 32 {
             maxlen -= 32;
             val = val.add(32);
@@ -417,17 +504,21 @@ pub(crate) unsafe fn ascii7_decode_avx(
     mut utflen: usize,
 ) -> crate::DecodeResult {
     #[cfg(target_arch = "x86")]
-    use core::arch::x86::{__m256i, _mm256_loadu_si256, _mm256_set1_epi8, _mm256_testc_si256};
+    use core::arch::x86::{__m256i, _mm256_loadu_si256, _mm256_set1_epi8, _mm256_testz_si256};
     #[cfg(target_arch = "x86_64")]
-    use core::arch::x86_64::{__m256i, _mm256_loadu_si256, _mm256_set1_epi8, _mm256_testc_si256};
+    use core::arch::x86_64::{__m256i, _mm256_loadu_si256, _mm256_set1_epi8, _mm256_testz_si256};
 
     loop {
         #[expect(clippy::cast_ptr_alignment)]
         let data = _mm256_loadu_si256(val.cast::<__m256i>());
         #[expect(clippy::cast_possible_wrap)]
-        let masked = _mm256_testc_si256(data, _mm256_set1_epi8(0x80_u8 as i8));
-        let zc = masked.leading_zeros();
-        utflen += zc as usize;
+        let all_ascii = _mm256_testz_si256(data, _mm256_set1_epi8(0x80_u8 as i8)) != 0;
+        // the test only tells if all bytes are ASCII, so the ASCII prefix of a mixed chunk is counted one by one
+        let mut zc = if all_ascii { 32 } else { 0_usize };
+        while zc < 32 && val.add(zc).read() < 128 {
+            zc += 1;
+        }
+        utflen += zc;
         if zc == 32 && maxlen >= 64 {
             maxlen -= 32;
             val = val.add(32);
@@ -472,7 +563,7 @@ unsafe fn ascii7_decode_avx512bw(
             data,
             core::arch::x86_64::_mm512_set1_epi8(0x80_u8 as i8),
         );
-        let zc = masked.leading_zeros() as usize;
+        let zc = masked.trailing_zeros() as usize;
         utflen += zc;
         if zc == 64 {
             maxlen -= 64;
@@ -513,7 +604,7 @@ unsafe fn ascii7_decode_avx512f(
             data,
             core::arch::x86_64::_mm512_set1_epi8(0x80_u8 as i8),
         );
-        let zc = (masked.leading_zeros() as usize) << 2;
+        let zc = (masked.trailing_zeros() as usize) << 2;
         utflen += zc;
         if zc == 64 {
             maxlen -= 64;
@@ -563,3 +654,296 @@ const unsafe fn ascii7_decode_const(
     }
     crate::DecodeResult::Utf8(utflen as u64)
 }
+
+/// Count the leading ASCII bytes of the given bytes, a machine word at a time.
+#[cfg(feature = "swar")]
+const unsafe fn ascii7_prefix_swar(mut val: *const u8, mut maxlen: usize) -> usize {
+    const WORD: usize = core::mem::size_of::<usize>();
+    const HIGH: usize = usize::from_ne_bytes([0x80; WORD]);
+    let mut len = 0_usize;
+    while maxlen >= WORD {
+        let high = val.cast::<usize>().read_unaligned() & HIGH;
+        if high != 0 {
+            // the byte first in memory is the least significant on little endian targets
+            let zc = if cfg!(target_endian = "little") {
+                high.trailing_zeros()
+            } else {
+                high.leading_zeros()
+            };
+            return len + (zc >> 3_u32) as usize;
+        }
+        len += WORD;
+        maxlen -= WORD;
+        val = val.add(WORD);
+    }
+    while maxlen != 0 && val.read() < 128 {
+        len += 1;
+        maxlen -= 1;
+        val = val.add(1);
+    }
+    len
+}
+
+/// Decode the leading ASCII bytes of the given bytes, a machine word at a time.
+///
+/// Returns [`DecodeResult::Empty`] if there are no leading ASCII bytes and `utflen` is `0`.
+///
+/// [`DecodeResult::Empty`]: crate::DecodeResult::Empty
+#[cfg(feature = "swar")]
+#[inline]
+#[expect(clippy::redundant_pub_crate)]
+pub(crate) const unsafe fn ascii7_decode_swar(
+    val: *const u8,
+    maxlen: usize,
+    utflen: usize,
+) -> crate::DecodeResult {
+    let utflen = utflen + ascii7_prefix_swar(val, maxlen);
+    if utflen != 0 {
+        return crate::DecodeResult::Utf8(utflen as u64);
+    }
+    crate::DecodeResult::Empty
+}
+
+/// Encode the leading ASCII characters of the given bytes, a machine word at a time.
+///
+/// Returns [`EncodeResult::Empty`] if there are no leading ASCII characters and `utflen` is `0`.
+///
+/// [`EncodeResult::Empty`]: crate::EncodeResult::Empty
+#[cfg(feature = "swar")]
+#[inline]
+#[expect(clippy::redundant_pub_crate)]
+pub(crate) const unsafe fn ascii7_encode_swar(
+    val: *const u8,
+    maxlen: usize,
+    utflen: usize,
+) -> crate::EncodeResult {
+    let utflen = utflen + ascii7_prefix_swar(val, maxlen);
+    if utflen != 0 {
+        return crate::EncodeResult::Utf8(utflen as u64);
+    }
+    crate::EncodeResult::Empty
+}
+
+/// Returns `true` if the CPU supports SSE2, as detected at runtime.
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    feature = "sse2",
+    feature = "std"
+))]
+#[inline]
+#[expect(clippy::redundant_pub_crate)]
+pub(crate) fn sse2_detected() -> bool {
+    is_x86_feature_detected!("sse2")
+}
+
+/// Returns `true` if SSE2 is enabled for the target, as runtime detection requires `std`.
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    feature = "sse2",
+    not(feature = "std")
+))]
+#[inline]
+#[expect(clippy::redundant_pub_crate)]
+pub(crate) const fn sse2_detected() -> bool {
+    cfg!(target_feature = "sse2")
+}
+
+/// Count the leading ASCII bytes of the given bytes, 16 bytes at a time.
+#[cfg(all(any(target_arch = "x86_64", target_arch = "x86"), feature = "sse2"))]
+#[target_feature(enable = "sse2")]
+unsafe fn ascii7_prefix_sse2(mut val: *const u8, mut maxlen: usize) -> usize {
+    #[cfg(target_arch = "x86")]
+    use core::arch::x86::{__m128i, _mm_loadu_si128, _mm_movemask_epi8};
+    #[cfg(target_arch = "x86_64")]
+    use core::arch::x86_64::{__m128i, _mm_loadu_si128, _mm_movemask_epi8};
+
+    let mut len = 0_usize;
+    while maxlen >= 16 {
+        #[expect(clippy::cast_ptr_alignment)]
+        let data = _mm_loadu_si128(val.cast::<__m128i>());
+        // one bit per byte, set for the bytes that have the high bit set
+        let mask = _mm_movemask_epi8(data);
+        if mask != 0_i32 {
+            return len + mask.trailing_zeros() as usize;
+        }
+        len += 16;
+        maxlen -= 16;
+        val = val.add(16);
+    }
+    while maxlen != 0 && val.read() < 128 {
+        len += 1;
+        maxlen -= 1;
+        val = val.add(1);
+    }
+    len
+}
+
+/// Decode the leading ASCII bytes of the given bytes using SSE2.
+///
+/// Returns [`DecodeResult::Empty`] if there are no leading ASCII bytes and `utflen` is `0`.
+///
+/// [`DecodeResult::Empty`]: crate::DecodeResult::Empty
+#[cfg(all(any(target_arch = "x86_64", target_arch = "x86"), feature = "sse2"))]
+#[target_feature(enable = "sse2")]
+#[expect(clippy::redundant_pub_crate)]
+pub(crate) unsafe fn ascii7_decode_sse2(
+    val: *const u8,
+    maxlen: usize,
+    utflen: usize,
+) -> crate::DecodeResult {
+    let utflen = utflen + ascii7_prefix_sse2(val, maxlen);
+    if utflen != 0 {
+        return crate::DecodeResult::Utf8(utflen as u64);
+    }
+    crate::DecodeResult::Empty
+}
+
+/// Encode the leading ASCII characters of the given bytes using SSE2.
+///
+/// Returns [`EncodeResult::Empty`] if there are no leading ASCII characters and `utflen` is `0`.
+///
+/// [`EncodeResult::Empty`]: crate::EncodeResult::Empty
+#[cfg(all(any(target_arch = "x86_64", target_arch = "x86"), feature = "sse2"))]
+#[target_feature(enable = "sse2")]
+#[expect(clippy::redundant_pub_crate)]
+pub(crate) unsafe fn ascii7_encode_sse2(
+    val: *const u8,
+    maxlen: usize,
+    utflen: usize,
+) -> crate::EncodeResult {
+    let utflen = utflen + ascii7_prefix_sse2(val, maxlen);
+    if utflen != 0 {
+        return crate::EncodeResult::Utf8(utflen as u64);
+    }
+    crate::EncodeResult::Empty
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(any(
+        feature = "sse2",
+        feature = "swar",
+        feature = "avx",
+        feature = "avx512f"
+    ))]
+    /// Inputs with the first non-ASCII byte at every position of a few words.
+    fn inputs() -> impl Iterator<Item = [u8; 70]> {
+        (0..=70).map(|pos| {
+            let mut data = [b'a'; 70];
+            if let Some(byte) = data.get_mut(pos) {
+                *byte = 0xC3;
+            }
+            data
+        })
+    }
+
+    #[cfg(any(
+        feature = "sse2",
+        feature = "swar",
+        feature = "avx",
+        feature = "avx512f"
+    ))]
+    /// The result of the scalar decoding of the leading ASCII bytes.
+    fn expected(bytes: &[u8]) -> crate::DecodeResult {
+        match bytes.iter().take_while(|byte| byte.is_ascii()).count() {
+            0 => crate::DecodeResult::Empty,
+            len => crate::DecodeResult::Utf8(len as u64),
+        }
+    }
+
+    #[cfg(feature = "swar")]
+    #[test]
+    fn test_ascii7_swar() {
+        for data in inputs() {
+            for start in 0..8 {
+                let bytes = data.get(start..).unwrap_or_default();
+                // SAFETY: the pointer and length are of a valid slice
+                let res = unsafe { ascii7_decode_swar(bytes.as_ptr(), bytes.len(), 0) };
+                assert_eq!(res, expected(bytes));
+            }
+        }
+        // SAFETY: the pointer and length are of a valid slice
+        let res = unsafe { ascii7_encode_swar(b"abc".as_ptr(), 3, 2) };
+        assert_eq!(res, crate::EncodeResult::Utf8(5));
+    }
+
+    #[cfg(all(any(target_arch = "x86_64", target_arch = "x86"), feature = "sse2"))]
+    #[test]
+    fn test_ascii7_sse2() {
+        if !sse2_detected() {
+            return;
+        }
+        for data in inputs() {
+            for start in 0..16 {
+                let bytes = data.get(start..).unwrap_or_default();
+                // SAFETY: the pointer and length are of a valid slice, and sse2 has been checked
+                let res = unsafe { ascii7_decode_sse2(bytes.as_ptr(), bytes.len(), 0) };
+                assert_eq!(res, expected(bytes));
+            }
+        }
+        // SAFETY: the pointer and length are of a valid slice, and sse2 has been checked
+        let res = unsafe { ascii7_encode_sse2(b"\xC3\xA9".as_ptr(), 2, 0) };
+        assert_eq!(res, crate::EncodeResult::Empty);
+    }
+
+    #[cfg(all(any(target_arch = "x86_64", target_arch = "x86"), feature = "avx"))]
+    #[test]
+    fn test_ascii7_avx() {
+        if !is_x86_feature_detected!("avx") {
+            return;
+        }
+        for data in inputs() {
+            for start in 0..32 {
+                let bytes = data.get(start..).unwrap_or_default();
+                if bytes.len() < 32 {
+                    continue;
+                }
+                // SAFETY: the pointer and length are of a valid slice with at least 32 bytes, and avx has been checked
+                let res = unsafe { ascii7_decode_avx(bytes.as_ptr(), bytes.len(), 0) };
+                // whole chunks are consumed while more than a chunk follows, so only a prefix of a long ASCII run may be returned
+                let ascii = bytes.iter().take_while(|byte| byte.is_ascii()).count();
+                if ascii < 32 {
+                    assert_eq!(res, expected(bytes));
+                } else {
+                    assert!(
+                        matches!(res, crate::DecodeResult::Utf8(len) if len >= 32 && len <= ascii as u64),
+                        "{res:?} for {ascii} ASCII bytes"
+                    );
+                }
+            }
+        }
+    }
+
+    #[cfg(all(target_arch = "x86_64", feature = "avx512f"))]
+    #[test]
+    fn test_ascii7_avx512() {
+        if !is_x86_feature_detected!("avx512f") {
+            return;
+        }
+        for data in inputs() {
+            for start in 0..8 {
+                let bytes = data.get(start..).unwrap_or_default();
+                if bytes.len() < 64 {
+                    continue;
+                }
+                // SAFETY: the pointer and length are of a valid slice with at least 64 bytes, and avx512f has been checked
+                let res = unsafe { ascii7_decode_avx512f(bytes.as_ptr(), bytes.len(), 0) };
+                // the lanes are 4 bytes wide, so a shorter ASCII prefix is left to the caller
+                let ascii = bytes.iter().take_while(|byte| byte.is_ascii()).count();
+                if ascii < 4 {
+                    assert_eq!(res, crate::DecodeResult::Empty);
+                } else {
+                    assert_eq!(res, expected(bytes));
+                }
+                #[cfg(feature = "avx512bw")]
+                if is_x86_feature_detected!("avx512bw") {
+                    // SAFETY: the pointer and length are of a valid slice with at least 64 bytes, and avx512bw has been checked
+                    let bw = unsafe { ascii7_decode_avx512bw(bytes.as_ptr(), bytes.len(), 0) };
+                    assert_eq!(bw, expected(bytes));
+                }
+            }
+        }
+    }
+}
@@ -19,6 +19,7 @@
 pub struct AsciiCompatible {
     chars: &'static [char; 128],
     name: &'static str,
+    index: crate::reverse_index::ReverseIndex<128>,
 }
 
 impl AsciiCompatible {
@@ -26,7 +27,11 @@ impl AsciiCompatible {
     #[inline]
     #[must_use]
     pub const fn new(name: &'static str, chars: &'static [char; 128]) -> Self {
-        Self { chars, name }
+        Self {
+            chars,
+            name,
+            index: crate::reverse_index::ReverseIndex::new(chars, 0x80, false),
+        }
     }
 
     /// Decode characters from the given bytes.
@@ -87,6 +92,24 @@ impl AsciiCompatible {
             return decode_const_inner(self.chars, byte);
         }
 
+        #[cfg(all(any(target_arch = "x86_64", target_arch = "x86"), feature = "sse2"))]
+        if maxlen >= 16 && crate::ascii7::sse2_detected() {
+            // SAFETY: The pointer is valid and the length is correct, and sse2 has been checked.
+            let res = unsafe { super::ascii7::ascii7_decode_sse2(bytes_ptr, maxlen, 0) };
+            if !matches!(res, crate::DecodeResult::Empty) {
+                return res;
+            }
+        }
+
+        #[cfg(feature = "swar")]
+        if maxlen >= core::mem::size_of::<usize>() {
+            // SAFETY: The pointer is valid and the length is correct.
+            let res = unsafe { super::ascii7::ascii7_decode_swar(bytes_ptr, maxlen, 0) };
+            if !matches!(res, crate::DecodeResult::Empty) {
+                return res;
+            }
+        }
+
         fallback(self, bytes)
     }
 
@@ -126,6 +149,13 @@ impl AsciiCompatible {
                     // SAFETY: The pointer is valid and the length is correct.
                     bytes = unsafe { bytes.get_unchecked(len as usize..) };
                 }
+                crate::DecodeResult::CharPair(ch0, ch1, len) => {
+                    chars.push(ch0);
+                    chars.push(ch1);
+                    consumed += len;
+                    // SAFETY: The pointer is valid and the length is correct.
+                    bytes = unsafe { bytes.get_unchecked(len as usize..) };
+                }
                 #[expect(clippy::cast_possible_truncation)]
                 crate::DecodeResult::Utf8(len) => {
                     let len = len as usize;
@@ -170,7 +200,7 @@ impl AsciiCompatible {
         if i != 0 {
             return crate::EncodeResult::Utf8(i as u64);
         }
-        encode_const_inner(self.chars, chars)
+        encode_const_inner(&self.index, chars)
     }
 
     /// Encode characters from the given bytes.
@@ -203,7 +233,25 @@ impl AsciiCompatible {
             if !matches!(res, crate::EncodeResult::Empty) {
                 return res;
             }
-            return encode_const_inner(self.chars, chars);
+            return encode_const_inner(&self.index, chars);
+        }
+
+        #[cfg(all(any(target_arch = "x86_64", target_arch = "x86"), feature = "sse2"))]
+        if maxlen >= 16 && crate::ascii7::sse2_detected() {
+            // SAFETY: The pointer is valid and the length is correct, and sse2 has been checked.
+            let res = unsafe { super::ascii7::ascii7_encode_sse2(chars_ptr, maxlen, 0) };
+            if !matches!(res, crate::EncodeResult::Empty) {
+                return res;
+            }
+        }
+
+        #[cfg(feature = "swar")]
+        if maxlen >= core::mem::size_of::<usize>() {
+            // SAFETY: The pointer is valid and the length is correct.
+            let res = unsafe { super::ascii7::ascii7_encode_swar(chars_ptr, maxlen, 0) };
+            if !matches!(res, crate::EncodeResult::Empty) {
+                return res;
+            }
         }
 
         fallback(self, chars)
@@ -218,6 +266,11 @@ impl crate::Charset for AsciiCompatible {
         self.name
     }
 
+    #[inline]
+    fn charset_alias(&self) -> &[&'static str] {
+        core::slice::from_ref(&self.name)
+    }
+
     #[inline]
     fn size_hint(&self) -> (u16, u16) {
         (1, 1)
@@ -230,6 +283,18 @@ impl crate::CharsetDecoding for AsciiCompatible {
     fn decode(&self, bytes: &[u8]) -> crate::DecodeResult {
         Self::decode(self, bytes)
     }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn decode_all<'a>(
+        &self,
+        bytes: bytedata::ByteData<'a>,
+        invalid: crate::DecodeInvalid,
+    ) -> Result<bytedata::StringData<'a>, crate::DecodeError> {
+        crate::bulk::decode_all_single_byte(self.name, bytes, invalid, true, |byte| {
+            self.chars[(byte & 0x7F) as usize]
+        })
+    }
 }
 
 #[cfg_attr(docsrs, doc(cfg(feature = "ascii7-compat")))]
@@ -238,6 +303,27 @@ impl crate::CharsetEncoding for AsciiCompatible {
     fn encode(&self, chars: &str) -> crate::EncodeResult {
         Self::encode(self, chars)
     }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn encode_all<'a>(
+        &self,
+        chars: &'a str,
+        invalid: crate::EncodeInvalid,
+    ) -> Result<bytedata::ByteData<'a>, &'static str> {
+        crate::bulk::encode_all_ascii(self, true, chars, invalid)
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn encode_to_vec(
+        &self,
+        chars: &str,
+        invalid: crate::EncodeInvalid,
+        out: &mut alloc::vec::Vec<u8>,
+    ) -> Result<(), &'static str> {
+        crate::bulk::encode_to_vec_ascii(self, true, chars, invalid, out)
+    }
 }
 
 #[inline]
@@ -255,24 +341,21 @@ const fn decode_const_inner(chars: &[char; 128], byte: u8) -> crate::DecodeResul
 }
 
 #[expect(clippy::too_many_lines)]
-const fn encode_const_inner(chars: &'static [char; 128], data: &str) -> crate::EncodeResult {
+const fn encode_const_inner(
+    index: &crate::reverse_index::ReverseIndex<128>,
+    data: &str,
+) -> crate::EncodeResult {
     /// Find a character in the extended charset. Returns `0` if not found or if the value was actually `0`.
     #[inline]
-    const fn find_char(chars: &'static [char; 128], ch: u32) -> u8 {
+    const fn find_char(index: &crate::reverse_index::ReverseIndex<128>, ch: u32) -> u8 {
         if ch < 128 {
             #[expect(clippy::cast_possible_truncation)]
             return ch as u8;
         }
-        let mut i = 0;
-        while i < 128 {
-            if chars[i] as u32 != ch {
-                i += 1;
-                continue;
-            }
-            #[expect(clippy::cast_possible_truncation)]
-            return (128 + i) as u8;
+        match index.get(ch) {
+            Some(byte) => byte,
+            None => 0,
         }
-        0
     }
     let mut buf = [0_u8; 14];
     let mut consumed = 0;
@@ -327,7 +410,7 @@ const fn encode_const_inner(chars: &'static [char; 128], data: &str) -> crate::E
             }
             break;
         }
-        let found = find_char(chars, cp);
+        let found = find_char(index, cp);
         if found == 0 && cp != 0 {
             if consumed != 0 {
                 break;
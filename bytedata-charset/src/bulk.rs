@@ -0,0 +1,433 @@
+//! Helpers for decoding and encoding a whole input at once.
+
+use crate::{CharsetStatefulDecoding, CharsetStatefulEncoding, DecodeError, DecodeInvalid};
+
+/// Turn the collected UTF-8 bytes into a string.
+#[inline]
+#[expect(clippy::redundant_pub_crate)]
+pub(crate) fn into_string_data<'a>(out: bytedata::SharedBytesBuilder) -> bytedata::StringData<'a> {
+    let data = if out.len() <= bytedata::ByteChunk::LEN {
+        bytedata::ByteData::from_chunk_slice(out.as_slice())
+    } else {
+        bytedata::ByteData::from_shared(out.build())
+    };
+    // SAFETY: only complete utf-8 sequences are ever written to the output
+    unsafe { bytedata::StringData::from_bytedata_unchecked(data) }
+}
+
+/// Turn the collected encoded bytes into a `ByteData`.
+#[inline]
+#[expect(clippy::redundant_pub_crate)]
+pub(crate) fn into_byte_data<'a>(out: bytedata::SharedBytesBuilder) -> bytedata::ByteData<'a> {
+    if out.len() <= bytedata::ByteChunk::LEN {
+        bytedata::ByteData::from_chunk_slice(out.as_slice())
+    } else {
+        bytedata::ByteData::from_shared(out.build())
+    }
+}
+
+/// Handle an invalid sequence at `offset` of the input according to the `invalid` mode.
+#[inline]
+#[expect(clippy::redundant_pub_crate)]
+pub(crate) fn decode_invalid(
+    charset: &str,
+    invalid: DecodeInvalid,
+    out: &mut bytedata::SharedBytesBuilder,
+    offset: usize,
+    bytes: &[u8],
+    len: u32,
+    truncated: bool,
+) -> Result<(), DecodeError> {
+    match invalid {
+        DecodeInvalid::Replace => {
+            out.extend_from_slice("\u{FFFD}".as_bytes());
+            Ok(())
+        }
+        DecodeInvalid::Ignore => Ok(()),
+        DecodeInvalid::Error => Err(DecodeError::new(
+            charset,
+            offset as u64,
+            bytes,
+            len,
+            truncated,
+        )),
+    }
+}
+
+/// Decode a whole input using the generic step-wise decoder of the charset.
+#[expect(clippy::redundant_pub_crate)]
+pub(crate) fn decode_all<'a, C: CharsetStatefulDecoding + ?Sized>(
+    charset: &C,
+    bytes: bytedata::ByteData<'a>,
+    invalid: DecodeInvalid,
+) -> Result<bytedata::StringData<'a>, DecodeError> {
+    let mut state = crate::CharsetState::new();
+    let input = bytes.as_slice();
+    let mut out = bytedata::SharedBytesBuilder::new();
+    let mut char_buf = [0_u8; 4];
+    let mut pos = 0;
+    while let Some(rest) = input.get(pos..).filter(|rest| !rest.is_empty()) {
+        let mut next = state;
+        let mut res = charset.decode_stateful(&mut next, rest);
+        // the rest is the end of the input, so let the decoder finish what it was waiting for
+        let truncated = matches!(res, crate::DecodeResult::Incomplete);
+        if truncated {
+            next = state;
+            res = charset.decode_finish(&mut next, rest);
+        }
+        state = next;
+        match res {
+            crate::DecodeResult::Char(ch, len) => {
+                out.extend_from_slice(ch.encode_utf8(&mut char_buf).as_bytes());
+                pos += len as usize;
+            }
+            crate::DecodeResult::CharPair(ch0, ch1, len) => {
+                out.extend_from_slice(ch0.encode_utf8(&mut char_buf).as_bytes());
+                out.extend_from_slice(ch1.encode_utf8(&mut char_buf).as_bytes());
+                pos += len as usize;
+            }
+            crate::DecodeResult::Utf8(len) => {
+                #[expect(clippy::cast_possible_truncation)]
+                let len = len as usize;
+                if len == input.len() {
+                    // SAFETY: the whole input is utf-8 compatible, so it is returned without copying
+                    return Ok(unsafe { bytedata::StringData::from_bytedata_unchecked(bytes) });
+                }
+                if out.is_empty() {
+                    out.reserve(input.len());
+                }
+                out.extend_from_slice(rest.get(..len).unwrap_or_default());
+                pos += len;
+            }
+            crate::DecodeResult::InvalidChar(_, len) => {
+                decode_invalid(
+                    charset.charset_name(),
+                    invalid,
+                    &mut out,
+                    pos,
+                    rest,
+                    len,
+                    truncated,
+                )?;
+                pos += len as usize;
+            }
+            crate::DecodeResult::Incomplete => {
+                #[expect(clippy::cast_possible_truncation)]
+                let len = rest.len().min(u32::MAX as usize) as u32;
+                decode_invalid(
+                    charset.charset_name(),
+                    invalid,
+                    &mut out,
+                    pos,
+                    rest,
+                    len,
+                    true,
+                )?;
+                pos = input.len();
+            }
+            crate::DecodeResult::Empty => {
+                // the decoder discarded or finished the rest of the input
+                pos = input.len();
+            }
+        }
+    }
+    Ok(into_string_data(out))
+}
+
+/// Encode a whole input using the generic step-wise encoder of the charset, passing the encoded bytes to `output`.
+#[expect(clippy::redundant_pub_crate)]
+pub(crate) fn encode_with<C: CharsetStatefulEncoding + ?Sized>(
+    charset: &C,
+    chars: &str,
+    invalid: crate::EncodeInvalid,
+    output: &mut dyn FnMut(&[u8]),
+) -> Result<(), &'static str> {
+    let mut state = crate::CharsetState::new();
+    let mut rest = chars;
+    while !rest.is_empty() {
+        let len = match charset.encode_stateful(&mut state, rest) {
+            crate::EncodeResult::Chunk(chunk, len) => {
+                output(chunk.as_slice());
+                len as usize
+            }
+            crate::EncodeResult::Utf8(len) => {
+                #[expect(clippy::cast_possible_truncation)]
+                let len = len as usize;
+                output(rest.as_bytes().get(..len).unwrap_or_default());
+                len
+            }
+            crate::EncodeResult::InvalidChar(ch, len) => {
+                charset.encode_invalid(&mut state, ch, invalid, output)?;
+                len as usize
+            }
+            crate::EncodeResult::Incomplete | crate::EncodeResult::Empty => {
+                return Err("unable to encode characters");
+            }
+        };
+        rest = rest.get(len..).unwrap_or_default();
+    }
+    loop {
+        match charset.encode_finish(&mut state) {
+            crate::EncodeResult::Chunk(chunk, _) => output(chunk.as_slice()),
+            crate::EncodeResult::Empty => return Ok(()),
+            crate::EncodeResult::Utf8(_)
+            | crate::EncodeResult::InvalidChar(..)
+            | crate::EncodeResult::Incomplete => return Err("unable to finish encoding"),
+        }
+    }
+}
+
+/// Encode a whole input using the generic step-wise encoder of the charset.
+#[expect(clippy::redundant_pub_crate)]
+pub(crate) fn encode_all<'a, C: CharsetStatefulEncoding + ?Sized>(
+    charset: &C,
+    chars: &'a str,
+    invalid: crate::EncodeInvalid,
+) -> Result<bytedata::ByteData<'a>, &'static str> {
+    let mut state = crate::CharsetState::new();
+    if let crate::EncodeResult::Utf8(len) = charset.encode_stateful(&mut state, chars) {
+        if len == chars.len() as u64
+            && matches!(
+                charset.encode_finish(&mut state),
+                crate::EncodeResult::Empty
+            )
+        {
+            // the whole input is encoded as is, so it is returned without copying
+            return Ok(chars.as_bytes().into());
+        }
+    }
+    let mut out = bytedata::SharedBytesBuilder::with_capacity(chars.len());
+    encode_with(charset, chars, invalid, &mut |bytes| {
+        out.extend_from_slice(bytes);
+    })?;
+    Ok(into_byte_data(out))
+}
+
+/// Encode a whole input using the generic step-wise encoder of the charset, appending the bytes to `out`.
+///
+/// The vector is left unchanged if an error is returned.
+#[expect(clippy::redundant_pub_crate)]
+pub(crate) fn encode_to_vec<C: CharsetStatefulEncoding + ?Sized>(
+    charset: &C,
+    chars: &str,
+    invalid: crate::EncodeInvalid,
+    out: &mut alloc::vec::Vec<u8>,
+) -> Result<(), &'static str> {
+    let prefix = out.len();
+    out.reserve(chars.len());
+    let res = encode_with(charset, chars, invalid, &mut |bytes| {
+        out.extend_from_slice(bytes);
+    });
+    if res.is_err() {
+        out.truncate(prefix);
+    }
+    res
+}
+
+/// Decode a whole input of a single-byte charset, copying the runs of ASCII bytes with the ASCII fast paths.
+///
+/// `lookup` maps a byte to its character, where `'\0'` marks an undefined byte for every byte but `0x00`.
+/// The fast paths are only used if `ascii` is `true`, meaning that the charset maps the ASCII bytes to themselves.
+#[cfg(any(feature = "ascii7-compat", feature = "single-byte"))]
+#[expect(clippy::redundant_pub_crate)]
+pub(crate) fn decode_all_single_byte<'a, F: Fn(u8) -> char>(
+    charset: &str,
+    bytes: bytedata::ByteData<'a>,
+    invalid: DecodeInvalid,
+    ascii: bool,
+    lookup: F,
+) -> Result<bytedata::StringData<'a>, DecodeError> {
+    let input = bytes.as_slice();
+    let start = if ascii {
+        crate::ascii7::ascii7_prefix(input)
+    } else {
+        0
+    };
+    if ascii && start == input.len() {
+        // SAFETY: ascii is valid utf-8
+        return Ok(unsafe { bytedata::StringData::from_bytedata_unchecked(bytes) });
+    }
+    // every byte decodes to at most three bytes of utf-8
+    let mut out = bytedata::SharedBytesBuilder::with_capacity(input.len() * 3);
+    let (prefix, mut rest) = input.split_at(start);
+    out.extend_from_slice(prefix);
+    let mut char_buf = [0_u8; 4];
+    while let Some((&byte, tail)) = rest.split_first() {
+        if ascii && byte < 0x80 {
+            let (run, after) = rest.split_at(crate::ascii7::ascii7_prefix(rest));
+            out.extend_from_slice(run);
+            rest = after;
+            continue;
+        }
+        let ch = lookup(byte);
+        if ch == '\0' && byte != 0 {
+            decode_invalid(
+                charset,
+                invalid,
+                &mut out,
+                input.len() - rest.len(),
+                core::slice::from_ref(&byte),
+                1,
+                false,
+            )?;
+        } else {
+            out.extend_from_slice(ch.encode_utf8(&mut char_buf).as_bytes());
+        }
+        rest = tail;
+    }
+    Ok(into_string_data(out))
+}
+
+/// Encode a whole input of an ASCII compatible charset, returning the input without copying if it is all ASCII.
+#[cfg(any(feature = "ascii7-compat", feature = "single-byte"))]
+#[expect(clippy::redundant_pub_crate)]
+pub(crate) fn encode_all_ascii<'a, C: CharsetStatefulEncoding + ?Sized>(
+    charset: &C,
+    ascii: bool,
+    chars: &'a str,
+    invalid: crate::EncodeInvalid,
+) -> Result<bytedata::ByteData<'a>, &'static str> {
+    if ascii && crate::ascii7::ascii7_prefix(chars.as_bytes()) == chars.len() {
+        return Ok(chars.as_bytes().into());
+    }
+    encode_all(charset, chars, invalid)
+}
+
+/// Encode a whole input of an ASCII compatible charset, appending the bytes to `out` in one go if it is all ASCII.
+///
+/// The vector is left unchanged if an error is returned.
+#[cfg(any(feature = "ascii7-compat", feature = "single-byte"))]
+#[expect(clippy::redundant_pub_crate)]
+pub(crate) fn encode_to_vec_ascii<C: CharsetStatefulEncoding + ?Sized>(
+    charset: &C,
+    ascii: bool,
+    chars: &str,
+    invalid: crate::EncodeInvalid,
+    out: &mut alloc::vec::Vec<u8>,
+) -> Result<(), &'static str> {
+    if ascii && crate::ascii7::ascii7_prefix(chars.as_bytes()) == chars.len() {
+        out.extend_from_slice(chars.as_bytes());
+        return Ok(());
+    }
+    encode_to_vec(charset, chars, invalid, out)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{CharsetDecoding, CharsetEncoding, DecodeInvalid, EncodeInvalid};
+
+    #[test]
+    fn test_decode_all() {
+        let utf8 = crate::UTF8.decode_all(b"h\xC3\xA9llo".into(), DecodeInvalid::Error);
+        assert_eq!(utf8.as_deref(), Ok("h\u{E9}llo"));
+
+        let utf16 = crate::Utf16Encoding::UTF16_LE
+            .decode_all(b"h\0\xE9\0\x3D\xD8\x00\xDE".into(), DecodeInvalid::Error);
+        assert_eq!(utf16.as_deref(), Ok("h\u{E9}\u{1F600}"));
+
+        let replaced = crate::UTF8.decode_all(b"a\xFFb\xE2\x82".into(), DecodeInvalid::Replace);
+        assert_eq!(replaced.as_deref(), Ok("a\u{FFFD}b\u{FFFD}"));
+        let ignored = crate::UTF8.decode_all(b"a\xFFb\xE2\x82".into(), DecodeInvalid::Ignore);
+        assert_eq!(ignored.as_deref(), Ok("ab"));
+
+        let failed =
+            crate::Utf16Encoding::UTF16_LE.decode_all(b"a\0b".into(), DecodeInvalid::Error);
+        assert_eq!(
+            failed.map(|_| ()),
+            Err(crate::DecodeError::new("utf-16", 2, b"b", 1, true))
+        );
+    }
+
+    #[test]
+    fn test_encode_all() {
+        let utf16 = crate::Utf16Encoding::UTF16_BE.encode_all("a\u{1F600}", EncodeInvalid::Error);
+        assert_eq!(utf16.as_deref(), Ok(b"\0a\xD8\x3D\xDE\x00".as_slice()));
+
+        let ascii = crate::Ascii7Encoding::new().encode_all("abc", EncodeInvalid::Error);
+        assert_eq!(ascii.as_deref(), Ok(b"abc".as_slice()));
+        let replaced = crate::Ascii7Encoding::new().encode_all("a\u{E9}b", EncodeInvalid::XmlHex);
+        assert_eq!(replaced.as_deref(), Ok(b"a&#xE9;b".as_slice()));
+
+        let mut out = b"x".to_vec();
+        let failed =
+            crate::Ascii7Encoding::new().encode_to_vec("a\u{E9}b", EncodeInvalid::Error, &mut out);
+        assert_eq!(failed, Err("invalid character"));
+        assert_eq!(out, b"x");
+        let res = crate::Ascii7Encoding::new().encode_to_vec(
+            "a\u{E9}b",
+            EncodeInvalid::Replace,
+            &mut out,
+        );
+        assert_eq!(res, Ok(()));
+        assert_eq!(out, b"xa?b");
+    }
+
+    #[cfg(all(feature = "iso-8859-7", feature = "ibm866"))]
+    #[test]
+    fn test_single_byte_all() {
+        // long enough ASCII runs for the vectorized fast paths, broken up by bytes that have to be looked up
+        let mut bytes = alloc::vec::Vec::new();
+        for byte in [0x80_u8, 0xAE, 0xE9, 0xFE] {
+            bytes.extend_from_slice(&[b'a'; 70]);
+            bytes.push(byte);
+        }
+        bytes.extend_from_slice(b"tail");
+
+        let iso =
+            crate::iso_8859::ISO_8859_7.decode_all(bytes.as_slice().into(), DecodeInvalid::Replace);
+        let iso_stepwise = super::decode_all(
+            &crate::iso_8859::ISO_8859_7,
+            bytes.as_slice().into(),
+            DecodeInvalid::Replace,
+        );
+        assert_eq!(iso.as_deref(), iso_stepwise.as_deref());
+        let Ok(iso) = iso else { unreachable!() };
+        assert_eq!(iso.chars().filter(|&ch| ch == '\u{FFFD}').count(), 1);
+        let failed =
+            crate::iso_8859::ISO_8859_7.decode_all(bytes.as_slice().into(), DecodeInvalid::Error);
+        assert_eq!(
+            failed.map(|_| ()),
+            Err(crate::DecodeError::new(
+                "iso-8859-7",
+                141,
+                b"\xAE",
+                1,
+                false
+            ))
+        );
+
+        let dos =
+            crate::single_byte::IBM866.decode_all(bytes.as_slice().into(), DecodeInvalid::Error);
+        let dos_stepwise = super::decode_all(
+            &crate::single_byte::IBM866,
+            bytes.as_slice().into(),
+            DecodeInvalid::Error,
+        );
+        assert_eq!(dos.as_deref(), dos_stepwise.as_deref());
+
+        let Ok(text) = dos else { unreachable!() };
+        let encoded = crate::single_byte::IBM866.encode_all(&text, EncodeInvalid::Error);
+        assert_eq!(encoded.as_deref(), Ok(bytes.as_slice()));
+        let mut out = alloc::vec::Vec::new();
+        let res = crate::iso_8859::ISO_8859_7.encode_to_vec(
+            "plain ascii text that is longer than a vector",
+            EncodeInvalid::Error,
+            &mut out,
+        );
+        assert_eq!(res, Ok(()));
+        assert_eq!(out, b"plain ascii text that is longer than a vector");
+    }
+
+    #[cfg(feature = "iso-2022-jp")]
+    #[test]
+    fn test_stateful_all() {
+        use crate::{CharsetStatefulDecoding, CharsetStatefulEncoding};
+
+        let encoded =
+            crate::multi_byte::ISO_2022_JP.encode_all_stateful("a\u{3042}", EncodeInvalid::Error);
+        assert_eq!(encoded.as_deref(), Ok(b"a\x1B$B$\"\x1B(B".as_slice()));
+        let decoded = crate::multi_byte::ISO_2022_JP
+            .decode_all_stateful(b"a\x1B$B$\"\x1B(B".into(), DecodeInvalid::Error);
+        assert_eq!(decoded.as_deref(), Ok("a\u{3042}"));
+    }
+}
@@ -0,0 +1,201 @@
+/// An error returned by a [`DecodeStream`] when the input can not be decoded.
+///
+/// The error records where in the source the failure occurred, counted in bytes from the start of the stream, and the bytes of the offending sequence.
+/// The name of the charset is copied into the error, so that charsets with a name only known at runtime are reported by that name.
+///
+/// [`DecodeStream`]: crate::DecodeStream
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct DecodeError {
+    charset: [u8; Self::MAX_NAME_LEN],
+    charset_len: u8,
+    offset: u64,
+    len: u32,
+    bytes: [u8; Self::MAX_BYTES],
+    kept: u8,
+    truncated: bool,
+}
+
+impl DecodeError {
+    /// The maximum number of offending bytes kept by the error.
+    pub const MAX_BYTES: usize = 8;
+
+    /// The maximum length in bytes of the charset name kept by the error, which is the longest name allowed by the IANA charset registry.
+    ///
+    /// Longer names are cut at a character boundary.
+    pub const MAX_NAME_LEN: usize = 40;
+
+    /// Create a new decode error for the sequence starting with `bytes` at the given offset of the source stream.
+    ///
+    /// The `len` is the full length of the offending sequence, of which at most [`DecodeError::MAX_BYTES`] bytes are kept.
+    #[inline]
+    #[must_use]
+    pub fn new(charset: &str, offset: u64, bytes: &[u8], len: u32, truncated: bool) -> Self {
+        let mut data = [0_u8; Self::MAX_BYTES];
+        let kept = bytes.len().min(len as usize).min(Self::MAX_BYTES);
+        data[..kept].copy_from_slice(&bytes[..kept]);
+        let mut name = [0_u8; Self::MAX_NAME_LEN];
+        let mut name_len = charset.len().min(Self::MAX_NAME_LEN);
+        while !charset.is_char_boundary(name_len) {
+            name_len -= 1;
+        }
+        name[..name_len].copy_from_slice(&charset.as_bytes()[..name_len]);
+        Self {
+            charset: name,
+            #[expect(clippy::cast_possible_truncation)]
+            charset_len: name_len as u8,
+            offset,
+            len,
+            bytes: data,
+            #[expect(clippy::cast_possible_truncation)]
+            kept: kept as u8,
+            truncated,
+        }
+    }
+
+    /// The name of the charset that failed to decode the input.
+    #[inline]
+    #[must_use]
+    pub const fn charset_name(&self) -> &str {
+        match core::str::from_utf8(self.charset.split_at(self.charset_len as usize).0) {
+            Ok(name) => name,
+            // the name is only ever cut at a character boundary
+            Err(_) => "",
+        }
+    }
+
+    /// The offset of the offending sequence in bytes from the start of the source stream.
+    #[inline]
+    #[must_use]
+    pub const fn offset(&self) -> u64 {
+        self.offset
+    }
+
+    /// The number of bytes of the offending sequence.
+    #[inline]
+    #[must_use]
+    pub const fn len(&self) -> u32 {
+        self.len
+    }
+
+    /// Returns `true` if the offending sequence is empty, which never happens for errors returned by a [`DecodeStream`].
+    ///
+    /// [`DecodeStream`]: crate::DecodeStream
+    #[inline]
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The bytes of the offending sequence, limited to the first [`DecodeError::MAX_BYTES`] bytes.
+    #[inline]
+    #[must_use]
+    pub const fn bytes(&self) -> &[u8] {
+        self.bytes.split_at(self.kept as usize).0
+    }
+
+    /// Returns `true` if the stream ended in the middle of a character rather than containing an invalid sequence.
+    #[inline]
+    #[must_use]
+    pub const fn is_truncated(&self) -> bool {
+        self.truncated
+    }
+}
+
+impl core::fmt::Debug for DecodeError {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("DecodeError")
+            .field("charset", &self.charset_name())
+            .field("offset", &self.offset)
+            .field("len", &self.len)
+            .field("bytes", &self.bytes())
+            .field("truncated", &self.truncated)
+            .finish_non_exhaustive()
+    }
+}
+
+impl core::fmt::Display for DecodeError {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if self.truncated {
+            write!(
+                f,
+                "got to end of stream while decoding {} at byte offset {}:",
+                self.charset_name(),
+                self.offset
+            )?;
+        } else {
+            write!(
+                f,
+                "invalid {} sequence at byte offset {}:",
+                self.charset_name(),
+                self.offset
+            )?;
+        }
+        for byte in self.bytes() {
+            write!(f, " {byte:02X}")?;
+        }
+        if self.len > u32::from(self.kept) {
+            f.write_str(" ...")?;
+        }
+        Ok(())
+    }
+}
+
+impl core::error::Error for DecodeError {}
+
+#[cfg(feature = "std")]
+// `std::io::ErrorKind` has no stable counterpart in `core`
+#[expect(clippy::std_instead_of_core)]
+impl From<DecodeError> for std::io::Error {
+    #[inline]
+    fn from(value: DecodeError) -> Self {
+        let kind = if value.truncated {
+            std::io::ErrorKind::UnexpectedEof
+        } else {
+            std::io::ErrorKind::InvalidData
+        };
+        Self::new(kind, value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_decode_error() {
+        use alloc::string::ToString;
+
+        let err = DecodeError::new("utf-8", 3, b"\xFF\xFE\x00", 2, false);
+        assert_eq!(err.charset_name(), "utf-8");
+        assert_eq!(err.offset(), 3);
+        assert_eq!(err.len(), 2);
+        assert_eq!(err.bytes(), b"\xFF\xFE");
+        assert!(!err.is_truncated());
+        assert_eq!(
+            err.to_string(),
+            "invalid utf-8 sequence at byte offset 3: FF FE"
+        );
+
+        let long = DecodeError::new("replacement", 0, b"0123456789", 10, false);
+        assert_eq!(long.bytes(), b"01234567");
+        assert_eq!(
+            long.to_string(),
+            "invalid replacement sequence at byte offset 0: 30 31 32 33 34 35 36 37 ..."
+        );
+
+        let truncated = DecodeError::new("utf-8", 2, b"\xE2\x82", 2, true);
+        assert_eq!(
+            truncated.to_string(),
+            "got to end of stream while decoding utf-8 at byte offset 2: E2 82"
+        );
+
+        let runtime = alloc::format!("x-{}", "runtime");
+        let named = DecodeError::new(&runtime, 0, b"\x80", 1, false);
+        assert_eq!(named.charset_name(), "x-runtime");
+        let cut = DecodeError::new(&"\u{E9}".repeat(21), 0, b"\x80", 1, false);
+        assert_eq!(cut.charset_name(), "\u{E9}".repeat(20));
+    }
+}
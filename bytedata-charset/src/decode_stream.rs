@@ -1,4 +1,4 @@
-use super::CharsetDecoding;
+use super::{CharsetStatefulDecoding, DecodeError};
 
 /// The operation to perform when an invalid character is encountered.
 #[derive(Debug, Clone, Copy)]
@@ -17,6 +17,9 @@ pub struct DecodeStream<'a, C, S> {
     source: S,
     buffer: bytedata::ByteQueue<'a>,
     invalid: DecodeInvalid,
+    state: crate::CharsetState,
+    offset: u64,
+    eof: bool,
 }
 
 impl<C: core::fmt::Debug, S> core::fmt::Debug for DecodeStream<'_, C, S> {
@@ -26,20 +29,37 @@ impl<C: core::fmt::Debug, S> core::fmt::Debug for DecodeStream<'_, C, S> {
             .field("charset", &self.charset)
             .field("buffer", &self.buffer)
             .field("invalid", &self.invalid)
+            .field("state", &self.state)
+            .field("offset", &self.offset)
+            .field("eof", &self.eof)
             .finish()
     }
 }
 
-impl<C: CharsetDecoding, S> DecodeStream<'_, C, S> {
+impl<'a, C: CharsetStatefulDecoding, S> DecodeStream<'a, C, S> {
     /// Create a new decode stream.
     #[inline]
     #[must_use]
     pub const fn new(charset: C, source: S) -> Self {
+        Self::with_buffered(charset, source, bytedata::ByteQueue::new())
+    }
+
+    /// Create a new decode stream that decodes the already buffered bytes before reading from the source.
+    ///
+    /// The buffered bytes are the start of the stream, which is how a [`StreamDetector`] replays the bytes it has read to detect the charset.
+    ///
+    /// [`StreamDetector`]: crate::StreamDetector
+    #[inline]
+    #[must_use]
+    pub const fn with_buffered(charset: C, source: S, buffered: bytedata::ByteQueue<'a>) -> Self {
         Self {
             charset,
             source,
-            buffer: bytedata::ByteQueue::new(),
+            buffer: buffered,
             invalid: DecodeInvalid::Replace,
+            state: crate::CharsetState::new(),
+            offset: 0,
+            eof: false,
         }
     }
 
@@ -62,6 +82,80 @@ impl<C: CharsetDecoding, S> DecodeStream<'_, C, S> {
     pub const fn charset(&self) -> &C {
         &self.charset
     }
+
+    /// Returns the current decoding state of the stream.
+    #[inline]
+    #[must_use]
+    pub const fn state(&self) -> crate::CharsetState {
+        self.state
+    }
+
+    /// Returns the number of bytes of the source that have been decoded so far.
+    #[inline]
+    #[must_use]
+    pub const fn offset(&self) -> u64 {
+        self.offset
+    }
+
+    /// Decode the next character from the buffered bytes.
+    ///
+    /// The returned state should be stored if the result is consumed.
+    #[inline]
+    fn decode_next(&self, bytes: &[u8]) -> (crate::DecodeResult, crate::CharsetState) {
+        let mut state = self.state;
+        // the end of input is only reached when every buffered byte is passed to the decoder
+        let res = if self.eof && bytes.len() == self.buffer.len() {
+            self.charset.decode_finish(&mut state, bytes)
+        } else {
+            self.charset.decode_stateful(&mut state, bytes)
+        };
+        (res, state)
+    }
+
+    /// Drop the bytes of a consumed result from the buffer and keep the state of the result.
+    #[inline]
+    fn consume(&mut self, len: usize, state: crate::CharsetState) {
+        core::mem::drop(self.buffer.drain(..len));
+        self.offset += len as u64;
+        self.state = state;
+    }
+
+    /// Create the error for an invalid sequence at the front of the buffered bytes.
+    fn invalid_error(&self, bytes: &[u8], len: u32) -> DecodeError {
+        // a sequence reported as invalid by `decode_finish` may be the start of a character cut off by the end of the stream
+        let truncated = self.eof
+            && bytes.len() == self.buffer.len()
+            && matches!(
+                self.charset.decode_stateful(&mut self.state.clone(), bytes),
+                crate::DecodeResult::Incomplete
+            );
+        DecodeError::new(
+            self.charset.charset_name(),
+            self.offset,
+            bytes,
+            len,
+            truncated,
+        )
+    }
+
+    /// Create the error for bytes left in the buffer at the end of the stream.
+    fn truncated_error(&self) -> DecodeError {
+        let mut bytes = [0_u8; DecodeError::MAX_BYTES];
+        let mut kept = 0;
+        for (target, byte) in bytes.iter_mut().zip(self.buffer.bytes()) {
+            *target = byte;
+            kept += 1;
+        }
+        #[expect(clippy::cast_possible_truncation)]
+        let len = self.buffer.len().min(u32::MAX as usize) as u32;
+        DecodeError::new(
+            self.charset.charset_name(),
+            self.offset,
+            &bytes[..kept],
+            len,
+            true,
+        )
+    }
 }
 
 #[inline]
@@ -74,20 +168,12 @@ fn read_buffer<'a>(
     let mut input_n = *chunk_buffer_input_n;
     if input_n != 0 {
         let end = (charset_max_bytes as usize).min(chunk_buffer_input.len());
-        let req = end - input_n;
-        if req == 0 {
-            return bytedata::ByteData::empty();
-        }
-        let mut moved = false;
+        let req = end.saturating_sub(input_n);
         for byte in buffer.bytes().skip(input_n).take(req) {
             chunk_buffer_input[input_n] = byte;
             input_n += 1;
-            *chunk_buffer_input_n = input_n;
-            moved = true;
-        }
-        if !moved {
-            return bytedata::ByteData::empty();
         }
+        *chunk_buffer_input_n = input_n;
         bytedata::ByteData::from_chunk_slice(&chunk_buffer_input[..input_n])
     } else if let Some(chunk) = buffer.front() {
         chunk.clone()
@@ -96,13 +182,18 @@ fn read_buffer<'a>(
     }
 }
 
-impl<'a, C: CharsetDecoding, S: Iterator> DecodeStream<'a, C, S>
+impl<'a, C: CharsetStatefulDecoding, S: Iterator> DecodeStream<'a, C, S>
 where
     S::Item: Into<bytedata::ByteData<'a>>,
 {
     /// Pulls the next chunk of data from the source iterator and decodes it.
-    #[expect(clippy::missing_inline_in_public_items)]
-    pub fn iter_next(&mut self) -> Option<Result<bytedata::StringData<'a>, &'static str>> {
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`DecodeError`] if the stream is set to fail on invalid characters and an invalid sequence is found, or if the stream ends in the middle of a character.
+    /// The characters decoded before the invalid sequence are returned first, and the invalid sequence is skipped so the stream can continue after the error.
+    #[expect(clippy::too_many_lines, clippy::missing_inline_in_public_items)]
+    pub fn iter_next(&mut self) -> Option<Result<bytedata::StringData<'a>, DecodeError>> {
         let mut chunk_buffer_output = [0_u8; 14];
         let mut chunk_buffer_input = [0_u8; 14];
         let mut chunk_buffer_output_n = 0;
@@ -115,9 +206,11 @@ where
                     self.charset.size_hint().1,
                     &self.buffer,
                 );
-                match self.charset.decode(inner_buf.as_slice()) {
+                let (res, state) = self.decode_next(inner_buf.as_slice());
+                match res {
                     crate::DecodeResult::Char(ch, len) => {
-                        core::mem::drop(self.buffer.drain(..len as usize));
+                        self.consume(len as usize, state);
+                        chunk_buffer_input_n = 0;
                         let u8_len = ch
                             .encode_utf8(&mut chunk_buffer_output[chunk_buffer_output_n..])
                             .len();
@@ -132,10 +225,38 @@ where
                         let chunk = unsafe { bytedata::StringData::from_bytedata_unchecked(chunk) };
                         return Some(Ok(chunk));
                     }
+                    crate::DecodeResult::CharPair(ch0, ch1, len) => {
+                        if chunk_buffer_output_n + ch0.len_utf8() + ch1.len_utf8() > 14 {
+                            let chunk = bytedata::ByteData::from_chunk_slice(
+                                &chunk_buffer_output[..chunk_buffer_output_n],
+                            );
+                            // SAFETY: the buffer is filled with valid utf-8 data
+                            let chunk =
+                                unsafe { bytedata::StringData::from_bytedata_unchecked(chunk) };
+                            return Some(Ok(chunk));
+                        }
+                        self.consume(len as usize, state);
+                        chunk_buffer_input_n = 0;
+                        chunk_buffer_output_n += ch0
+                            .encode_utf8(&mut chunk_buffer_output[chunk_buffer_output_n..])
+                            .len();
+                        chunk_buffer_output_n += ch1
+                            .encode_utf8(&mut chunk_buffer_output[chunk_buffer_output_n..])
+                            .len();
+                        if chunk_buffer_output_n <= 10 {
+                            continue;
+                        }
+                        let chunk = bytedata::ByteData::from_chunk_slice(
+                            &chunk_buffer_output[..chunk_buffer_output_n],
+                        );
+                        // SAFETY: the buffer is filled with valid utf-8 data
+                        let chunk = unsafe { bytedata::StringData::from_bytedata_unchecked(chunk) };
+                        return Some(Ok(chunk));
+                    }
                     crate::DecodeResult::InvalidChar(_char_data, len) => {
                         match self.invalid {
                             DecodeInvalid::Replace => {
-                                core::mem::drop(self.buffer.drain(..len as usize));
+                                self.consume(len as usize, state);
                                 chunk_buffer_input_n = 0;
                                 let u8_len = '\u{FFFD}'
                                     .encode_utf8(&mut chunk_buffer_output[chunk_buffer_output_n..])
@@ -153,12 +274,18 @@ where
                                 return Some(Ok(chunk));
                             }
                             DecodeInvalid::Ignore => {
-                                core::mem::drop(self.buffer.drain(..len as usize));
+                                self.consume(len as usize, state);
                                 chunk_buffer_input_n = 0;
                                 continue;
                             }
                             DecodeInvalid::Error => {
-                                return Some(Err("invalid character"));
+                                if chunk_buffer_output_n != 0 {
+                                    // return the characters decoded so far, the error is reported by the next call
+                                    break;
+                                }
+                                let err = self.invalid_error(inner_buf.as_slice(), len);
+                                self.consume(len as usize, state);
+                                return Some(Err(err));
                             }
                         }
                     }
@@ -174,7 +301,7 @@ where
                         }
                         #[expect(clippy::cast_possible_truncation)]
                         let len = len as usize;
-                        core::mem::drop(self.buffer.drain(..len));
+                        self.consume(len, state);
                         let inner_buf = if inner_buf.len() == len {
                             inner_buf
                         } else {
@@ -185,7 +312,31 @@ where
                             bytedata::StringData::from_bytedata_unchecked(inner_buf)
                         }));
                     }
+                    crate::DecodeResult::Empty if !inner_buf.is_empty() => {
+                        // the decoder discarded the bytes without producing any characters
+                        self.consume(inner_buf.len(), state);
+                        chunk_buffer_input_n = 0;
+                    }
+                    crate::DecodeResult::Empty
+                        if self.eof && inner_buf.len() == self.buffer.len() =>
+                    {
+                        // the decoder has handled all remaining bytes
+                        self.offset += self.buffer.len() as u64;
+                        self.buffer = bytedata::ByteQueue::new();
+                        self.state = state;
+                        break;
+                    }
                     crate::DecodeResult::Incomplete | crate::DecodeResult::Empty => {
+                        // join the front chunk with the following ones if a character is split between them
+                        if chunk_buffer_input_n == 0
+                            && self.buffer.len() > inner_buf.len()
+                            && inner_buf.len() < chunk_buffer_input.len()
+                        {
+                            chunk_buffer_input_n = inner_buf.len();
+                            chunk_buffer_input[..chunk_buffer_input_n]
+                                .copy_from_slice(inner_buf.as_slice());
+                            continue;
+                        }
                         break;
                     }
                 }
@@ -200,16 +351,18 @@ where
                 return Some(Ok(chunk));
             }
 
-            let Some(additional_data) = self.source.next() else {
-                if !self.buffer.is_empty() {
+            if self.eof {
+                if self.buffer.is_empty() {
                     return None;
                 }
-                return Some(Err("got to end of stream while decoding characters"));
-            };
-
-            let additional_data = additional_data.into();
+                return Some(Err(self.truncated_error()));
+            }
 
-            self.buffer.push_back(additional_data);
+            if let Some(additional_data) = self.source.next() {
+                self.buffer.push_back(additional_data.into());
+            } else {
+                self.eof = true;
+            }
         }
     }
 }
@@ -221,12 +374,16 @@ pub trait DecodeStreamRead {
     /// # Errors
     ///
     /// - `std::io::ErrorKind::UnexpectedEof` → if the stream ends before the next character is fully read.
+    /// - `std::io::ErrorKind::InvalidData` → if the stream is set to fail on invalid characters and an invalid sequence is found.
     /// - `*` → if the input stream returns an error.
+    ///
+    /// Decoding errors wrap a [`DecodeError`], which can be retrieved using [`std::io::Error::get_ref`] and downcasting.
+    /// The invalid sequence is skipped, so reading can continue after the error.
     fn read<'a>(&'a mut self, buf: &'a mut [u8]) -> std::io::Result<&'a str>;
 }
 
 #[cfg(feature = "std")]
-impl<C: CharsetDecoding, S: std::io::Read> DecodeStreamRead for DecodeStream<'_, C, S> {
+impl<C: CharsetStatefulDecoding, S: std::io::Read> DecodeStreamRead for DecodeStream<'_, C, S> {
     #[expect(clippy::too_many_lines, clippy::missing_inline_in_public_items)]
     fn read<'a>(&'a mut self, buf: &'a mut [u8]) -> std::io::Result<&'a str> {
         debug_assert!(buf.len() >= 4, "buf.len() must be at least 4 bytes");
@@ -242,13 +399,26 @@ impl<C: CharsetDecoding, S: std::io::Read> DecodeStreamRead for DecodeStream<'_,
                     self.charset.size_hint().1,
                     &self.buffer,
                 );
-                match self.charset.decode(inner_buf.as_slice()) {
+                let (res, state) = self.decode_next(inner_buf.as_slice());
+                match res {
                     crate::DecodeResult::Char(ch, len) => {
                         let u8len = ch.len_utf8();
                         if u8len <= buf.len() - t_offset {
                             ch.encode_utf8(&mut buf[t_offset..]);
                             t_offset += u8len;
-                            core::mem::drop(self.buffer.drain(..len as usize));
+                            self.consume(len as usize, state);
+                            chunk_buffer_n = 0;
+                            continue;
+                        }
+                        fail = std::io::ErrorKind::WriteZero;
+                        break;
+                    }
+                    crate::DecodeResult::CharPair(ch0, ch1, len) => {
+                        let u8len = ch0.len_utf8() + ch1.len_utf8();
+                        if u8len <= buf.len() - t_offset {
+                            t_offset += ch0.encode_utf8(&mut buf[t_offset..]).len();
+                            t_offset += ch1.encode_utf8(&mut buf[t_offset..]).len();
+                            self.consume(len as usize, state);
                             chunk_buffer_n = 0;
                             continue;
                         }
@@ -263,7 +433,7 @@ impl<C: CharsetDecoding, S: std::io::Read> DecodeStreamRead for DecodeStream<'_,
                                     buf[t_offset + 1] = 0xBF;
                                     buf[t_offset + 2] = 0xBD;
                                     t_offset += 3;
-                                    core::mem::drop(self.buffer.drain(..len as usize));
+                                    self.consume(len as usize, state);
                                     chunk_buffer_n = 0;
                                     continue;
                                 }
@@ -271,7 +441,7 @@ impl<C: CharsetDecoding, S: std::io::Read> DecodeStreamRead for DecodeStream<'_,
                                 break;
                             }
                             DecodeInvalid::Ignore => {
-                                core::mem::drop(self.buffer.drain(..len as usize));
+                                self.consume(len as usize, state);
                                 chunk_buffer_n = 0;
                                 continue;
                             }
@@ -282,10 +452,9 @@ impl<C: CharsetDecoding, S: std::io::Read> DecodeStreamRead for DecodeStream<'_,
                                         core::str::from_utf8_unchecked(&buf[..t_offset])
                                     });
                                 }
-                                return Err(std::io::Error::new(
-                                    std::io::ErrorKind::InvalidData,
-                                    "invalid character",
-                                ));
+                                let err = self.invalid_error(inner_buf.as_slice(), len);
+                                self.consume(len as usize, state);
+                                return Err(err.into());
                             }
                         }
                     }
@@ -316,11 +485,22 @@ impl<C: CharsetDecoding, S: std::io::Read> DecodeStreamRead for DecodeStream<'_,
                         let end = t_offset + len;
                         buf[t_offset..end].copy_from_slice(&slic[..len]);
                         t_offset += len;
-                        core::mem::drop(self.buffer.drain(..len));
+                        self.consume(len, state);
                         chunk_buffer_n = 0;
                         continue;
                     }
+                    crate::DecodeResult::Empty if !inner_buf.is_empty() => {
+                        // the decoder discarded the bytes without producing any characters
+                        self.consume(inner_buf.len(), state);
+                        chunk_buffer_n = 0;
+                    }
                     crate::DecodeResult::Empty => {
+                        if self.eof && inner_buf.len() == self.buffer.len() {
+                            // the decoder has handled all remaining bytes
+                            self.offset += self.buffer.len() as u64;
+                            self.buffer = bytedata::ByteQueue::new();
+                            self.state = state;
+                        }
                         fail = std::io::ErrorKind::BrokenPipe;
                         break;
                     }
@@ -345,6 +525,14 @@ impl<C: CharsetDecoding, S: std::io::Read> DecodeStreamRead for DecodeStream<'_,
                 return Ok(unsafe { core::str::from_utf8_unchecked(&buf[..t_offset]) });
             }
 
+            if self.eof {
+                if self.buffer.is_empty() || fail == std::io::ErrorKind::BrokenPipe {
+                    // SAFETY: the buffer is empty, so we can return an empty string
+                    return Ok(unsafe { core::str::from_utf8_unchecked(&buf[..0]) });
+                }
+                return Err(std::io::Error::new(fail, self.truncated_error()));
+            }
+
             // if the user provided buffer is smaller than the chunk buffer, we should use it for the next read
             let (zero_cop, wbuf) = if buf.len() < chunk_buffer.len() {
                 (false, chunk_buffer.as_mut_slice())
@@ -359,34 +547,28 @@ impl<C: CharsetDecoding, S: std::io::Read> DecodeStreamRead for DecodeStream<'_,
             // read more bytes from the source
             let len = self.source.read(wbuf)?;
             if len == 0 {
-                if self.buffer.is_empty() {
-                    // SAFETY: the buffer is empty, so we can return an empty string
-                    return Ok(unsafe { core::str::from_utf8_unchecked(&buf[..0]) });
-                }
-                if fail == std::io::ErrorKind::BrokenPipe {
-                    // encoder returned "empty", but we have some bytes left
-                    // for now, we just return the empty string
-
-                    // SAFETY: the buffer is empty, so we can return an empty string
-                    return Ok(unsafe { core::str::from_utf8_unchecked(&buf[..0]) });
-                }
-                return Err(std::io::Error::new(
-                    fail,
-                    "got to end of stream while decoding characters",
-                ));
+                // let the decoder finish the remaining bytes
+                self.eof = true;
+                continue;
             }
 
             // if the charset is single-byte or var-byte, we can test if the read data starts with something utf-8 compatible
             if self.buffer.is_empty() && zero_cop && self.charset.size_hint().0 == 1 {
-                if let crate::DecodeResult::Utf8(utf_len) = self.charset.decode(&buf[..len]) {
+                let mut state = self.state;
+                if let crate::DecodeResult::Utf8(utf_len) =
+                    self.charset.decode_stateful(&mut state, &buf[..len])
+                {
+                    self.state = state;
                     #[expect(clippy::cast_possible_truncation)]
                     let utf_len = utf_len as usize;
                     if utf_len == len {
+                        self.offset += len as u64;
                         // SAFETY: the whole chunk is utf-8 compatible, so return it without copying
                         return Ok(unsafe { core::str::from_utf8_unchecked(&buf[..len]) });
                     }
                     // mark the utf-8 compatible part as read
                     t_offset += utf_len;
+                    self.offset += utf_len as u64;
                 }
             }
 
@@ -403,9 +585,180 @@ impl<C: CharsetDecoding, S: std::io::Read> DecodeStreamRead for DecodeStream<'_,
 }
 
 #[cfg(feature = "std")]
-impl<C: CharsetDecoding, S: std::io::Read> std::io::Read for DecodeStream<'_, C, S> {
+impl<C: CharsetStatefulDecoding, S: std::io::Read> std::io::Read for DecodeStream<'_, C, S> {
     #[inline]
     fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
         DecodeStreamRead::read(self, buf).map(str::len)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "alloc")]
+    fn decode_chunks<C: CharsetStatefulDecoding>(
+        charset: C,
+        chunks: &[&'static [u8]],
+    ) -> Result<alloc::string::String, DecodeError> {
+        let mut stream = DecodeStream::new(charset, chunks.iter().copied());
+        let mut out = alloc::string::String::new();
+        while let Some(chunk) = stream.iter_next() {
+            out.push_str(chunk?.as_str());
+        }
+        Ok(out)
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_iter_split_chunks() {
+        let chunks: &[&[u8]] = &[b"h\xC3", b"\xA9llo w\xF0\x9F", b"\x98", b"\x80!"];
+        let out = decode_chunks(crate::Utf8Encoding::new(), chunks);
+        assert_eq!(out.as_deref(), Ok("h\u{E9}llo w\u{1F600}!"));
+
+        let empty = decode_chunks(crate::Utf8Encoding::new(), &[b"", b"abc", b""]);
+        assert_eq!(empty.as_deref(), Ok("abc"));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_iter_truncated_end() {
+        let out = decode_chunks(crate::Utf8Encoding::new(), &[b"ab\xE2", b"\x82"]);
+        assert_eq!(out.as_deref(), Ok("ab\u{FFFD}"));
+
+        let mut stream = DecodeStream::new(crate::Utf8Encoding::new(), [b"ab\xE2"].into_iter())
+            .with_invalid(DecodeInvalid::Error);
+        assert_eq!(
+            stream.iter_next().map(|res| res.map(|chunk| chunk.len())),
+            Some(Ok(2))
+        );
+        let err = stream.iter_next().and_then(Result::err);
+        assert_eq!(err, Some(DecodeError::new("utf-8", 2, b"\xE2", 1, true)));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_iter_error_offset() {
+        let chunks: [&[u8]; 3] = [b"ab", b"c\xC3\xA9", b"\xFFd"];
+        let mut stream = DecodeStream::new(crate::Utf8Encoding::new(), chunks.into_iter())
+            .with_invalid(DecodeInvalid::Error);
+        let mut out = alloc::string::String::new();
+        let err = loop {
+            match stream.iter_next() {
+                Some(Ok(chunk)) => out.push_str(chunk.as_str()),
+                Some(Err(err)) => break Some(err),
+                None => break None,
+            }
+        };
+        assert_eq!(out, "abc\u{E9}");
+        assert_eq!(err.map(|err| err.offset()), Some(5));
+        assert_eq!(
+            err.as_ref().map(DecodeError::bytes),
+            Some(b"\xFF".as_slice())
+        );
+        assert_eq!(err.map(|err| err.is_truncated()), Some(false));
+        assert_eq!(stream.offset(), 6);
+
+        // the invalid byte is skipped and decoding continues after it
+        assert_eq!(
+            stream.iter_next().map(|res| res.map(|chunk| chunk.len())),
+            Some(Ok(1))
+        );
+        assert!(stream.iter_next().is_none());
+    }
+
+    #[test]
+    fn test_iter_error_flush() {
+        // the characters decoded into the output buffer before the error are not lost
+        let chunks: [&[u8]; 1] = [b"\xE9\x00t\x00\xE9\x00\x00\xDC!\x00"];
+        let charset = crate::Utf16Encoding::new(crate::CharsetEndian::Little);
+        let mut stream =
+            DecodeStream::new(charset, chunks.into_iter()).with_invalid(DecodeInvalid::Error);
+        let first = stream.iter_next();
+        assert_eq!(
+            first
+                .as_ref()
+                .map(|res| res.as_ref().map(bytedata::StringData::as_str)),
+            Some(Ok("\u{E9}t\u{E9}"))
+        );
+        let err = stream.iter_next().and_then(Result::err);
+        assert_eq!(
+            err,
+            Some(DecodeError::new("utf-16", 6, b"\x00\xDC", 2, false))
+        );
+        let last = stream.iter_next();
+        assert_eq!(
+            last.as_ref()
+                .map(|res| res.as_ref().map(bytedata::StringData::as_str)),
+            Some(Ok("!"))
+        );
+        assert!(stream.iter_next().is_none());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_read_split_chunks() {
+        let source = std::io::Read::chain(&b"h\xC3"[..], &b"\xA9llo \xE2\x82"[..]);
+        let mut stream = DecodeStream::new(crate::Utf8Encoding::new(), source);
+        let mut buf = [0_u8; 64];
+        let mut out = String::new();
+        while let Ok(chunk) = DecodeStreamRead::read(&mut stream, &mut buf) {
+            if chunk.is_empty() {
+                break;
+            }
+            out.push_str(chunk);
+        }
+        assert_eq!(out, "h\u{E9}llo \u{FFFD}");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_read_invalid_skipped() {
+        let mut stream = DecodeStream::new(crate::Utf8Encoding::new(), &b"ab\xFFcd"[..])
+            .with_invalid(DecodeInvalid::Error);
+        let mut buf = [0_u8; 64];
+        assert_eq!(
+            DecodeStreamRead::read(&mut stream, &mut buf).ok(),
+            Some("ab")
+        );
+        let err = DecodeStreamRead::read(&mut stream, &mut buf).err();
+        let inner = err
+            .as_ref()
+            .and_then(|err| err.get_ref())
+            .and_then(|err| err.downcast_ref::<DecodeError>());
+        assert_eq!(inner.map(DecodeError::offset), Some(2));
+        assert_eq!(
+            DecodeStreamRead::read(&mut stream, &mut buf).ok(),
+            Some("cd")
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    // `std::io::ErrorKind` has no stable counterpart in `core`
+    #[expect(clippy::std_instead_of_core)]
+    fn test_read_error() {
+        let source = std::io::Read::chain(&b"abc"[..], &b"\xE2\x82"[..]);
+        let mut stream = DecodeStream::new(crate::Utf8Encoding::new(), source)
+            .with_invalid(DecodeInvalid::Error);
+        let mut buf = [0_u8; 64];
+        let err = loop {
+            match DecodeStreamRead::read(&mut stream, &mut buf) {
+                Ok("") => break None,
+                Ok(_) => {}
+                Err(err) => break Some(err),
+            }
+        };
+        assert_eq!(
+            err.as_ref().map(std::io::Error::kind),
+            Some(std::io::ErrorKind::UnexpectedEof)
+        );
+        let inner = err
+            .as_ref()
+            .and_then(|err| err.get_ref())
+            .and_then(|err| err.downcast_ref::<DecodeError>());
+        assert_eq!(inner.map(DecodeError::offset), Some(3));
+        assert_eq!(inner.map(DecodeError::bytes), Some(b"\xE2\x82".as_slice()));
+        assert_eq!(inner.map(DecodeError::is_truncated), Some(true));
+    }
+}
@@ -157,12 +157,12 @@ impl UtfDetector {
             #[cfg(feature = "utf-32")]
             crate::Utf32Encoding::UTF32_BE.detect_const(bytes),
             #[cfg(feature = "utf-32")]
-            crate::Utf32Encoding::UTF32_BE.detect_const(bytes),
+            crate::Utf32Encoding::UTF32_LE.detect_const(bytes),
         ];
         let mut pos = 0;
         let mut idx = 1;
         while idx < x.len() {
-            if !x[idx].le(x[pos]) {
+            if !x[pos].le(x[idx]) {
                 pos = idx;
             }
             idx += 1;
@@ -202,7 +202,7 @@ impl UtfDetector {
         let mut pos = 0;
         let mut idx = 1;
         while idx < x.len() {
-            if !x[idx].1.le(x[pos].1) {
+            if !x[pos].1.le(x[idx].1) {
                 pos = idx;
             }
             idx += 1;
@@ -227,3 +227,67 @@ impl CharsetSelector for UtfDetector {
         Self::select_const(bytes)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn select_name(bytes: &[u8]) -> (&'static str, DetectionResult) {
+        let (charset, result) = UtfDetector::select_const(bytes);
+        (crate::CharsetRegistry::canonical_name(charset), result)
+    }
+
+    #[test]
+    fn test_utf_detector() {
+        assert_eq!(
+            UtfDetector::detect_const(b"\xEF\xBB\xBFabc"),
+            DetectionResult::Certain
+        );
+        assert_eq!(
+            select_name(b"\xFF\xFEa\x00"),
+            ("utf-16le", DetectionResult::Certain)
+        );
+        assert_eq!(
+            select_name(b"\xFE\xFF\x00a"),
+            ("utf-16be", DetectionResult::Certain)
+        );
+        // the best result of all UTF charsets is used, not the worst
+        assert_eq!(
+            select_name("h\u{E9}llo".as_bytes()),
+            ("utf-8", DetectionResult::Tentative)
+        );
+        assert_eq!(
+            UtfDetector::detect_const("h\u{E9}llo".as_bytes()),
+            DetectionResult::Tentative
+        );
+    }
+
+    #[cfg(feature = "utf-32")]
+    #[test]
+    fn test_utf32_detector() {
+        assert_eq!(
+            select_name(b"\x00\x00\xFE\xFF\x00\x00\x00a"),
+            ("utf-32be", DetectionResult::Certain)
+        );
+        // a UTF-32LE byte order mark starts with a UTF-16LE one, which is checked first
+        assert_eq!(
+            select_name(b"\xFF\xFE\x00\x00a\x00\x00\x00"),
+            ("utf-16le", DetectionResult::Certain)
+        );
+
+        // both check the same charsets
+        let inputs: [&[u8]; 5] = [
+            b"\x00\x00\xFE\xFFa",
+            b"\xFF\xFE\x00\x00",
+            b"a\x00\x00\x00b\x00\x00\x00",
+            b"\xD8\xD8\x01\x00",
+            b"",
+        ];
+        for bytes in inputs {
+            assert_eq!(
+                UtfDetector::detect_const(bytes),
+                UtfDetector::select_const(bytes).1
+            );
+        }
+    }
+}
@@ -0,0 +1,452 @@
+use crate::single_byte::SingleByteEncoding;
+
+/// The IBM1047 charset.
+const IBM1047_CHARSET: [char; 256] = {
+    let mut charset = ['\0'; 256];
+
+    charset[0x00] = '\u{0000}';
+    charset[0x01] = '\u{0001}';
+    charset[0x02] = '\u{0002}';
+    charset[0x03] = '\u{0003}';
+    charset[0x04] = '\u{009C}';
+    charset[0x05] = '\u{0009}';
+    charset[0x06] = '\u{0086}';
+    charset[0x07] = '\u{007F}';
+    charset[0x08] = '\u{0097}';
+    charset[0x09] = '\u{008D}';
+    charset[0x0a] = '\u{008E}';
+    charset[0x0b] = '\u{000B}';
+    charset[0x0c] = '\u{000C}';
+    charset[0x0d] = '\u{000D}';
+    charset[0x0e] = '\u{000E}';
+    charset[0x0f] = '\u{000F}';
+
+    charset[0x10] = '\u{0010}';
+    charset[0x11] = '\u{0011}';
+    charset[0x12] = '\u{0012}';
+    charset[0x13] = '\u{0013}';
+    charset[0x14] = '\u{009D}';
+    charset[0x15] = '\u{0085}';
+    charset[0x16] = '\u{0008}';
+    charset[0x17] = '\u{0087}';
+    charset[0x18] = '\u{0018}';
+    charset[0x19] = '\u{0019}';
+    charset[0x1a] = '\u{0092}';
+    charset[0x1b] = '\u{008F}';
+    charset[0x1c] = '\u{001C}';
+    charset[0x1d] = '\u{001D}';
+    charset[0x1e] = '\u{001E}';
+    charset[0x1f] = '\u{001F}';
+
+    charset[0x20] = '\u{0080}';
+    charset[0x21] = '\u{0081}';
+    charset[0x22] = '\u{0082}';
+    charset[0x23] = '\u{0083}';
+    charset[0x24] = '\u{0084}';
+    charset[0x25] = '\u{000A}';
+    charset[0x26] = '\u{0017}';
+    charset[0x27] = '\u{001B}';
+    charset[0x28] = '\u{0088}';
+    charset[0x29] = '\u{0089}';
+    charset[0x2a] = '\u{008A}';
+    charset[0x2b] = '\u{008B}';
+    charset[0x2c] = '\u{008C}';
+    charset[0x2d] = '\u{0005}';
+    charset[0x2e] = '\u{0006}';
+    charset[0x2f] = '\u{0007}';
+
+    charset[0x30] = '\u{0090}';
+    charset[0x31] = '\u{0091}';
+    charset[0x32] = '\u{0016}';
+    charset[0x33] = '\u{0093}';
+    charset[0x34] = '\u{0094}';
+    charset[0x35] = '\u{0095}';
+    charset[0x36] = '\u{0096}';
+    charset[0x37] = '\u{0004}';
+    charset[0x38] = '\u{0098}';
+    charset[0x39] = '\u{0099}';
+    charset[0x3a] = '\u{009A}';
+    charset[0x3b] = '\u{009B}';
+    charset[0x3c] = '\u{0014}';
+    charset[0x3d] = '\u{0015}';
+    charset[0x3e] = '\u{009E}';
+    charset[0x3f] = '\u{001A}';
+
+    charset[0x40] = '\u{0020}';
+    charset[0x41] = '\u{00A0}';
+    charset[0x42] = '\u{00E2}';
+    charset[0x43] = '\u{00E4}';
+    charset[0x44] = '\u{00E0}';
+    charset[0x45] = '\u{00E1}';
+    charset[0x46] = '\u{00E3}';
+    charset[0x47] = '\u{00E5}';
+    charset[0x48] = '\u{00E7}';
+    charset[0x49] = '\u{00F1}';
+    charset[0x4a] = '\u{00A2}';
+    charset[0x4b] = '\u{002E}';
+    charset[0x4c] = '\u{003C}';
+    charset[0x4d] = '\u{0028}';
+    charset[0x4e] = '\u{002B}';
+    charset[0x4f] = '\u{007C}';
+
+    charset[0x50] = '\u{0026}';
+    charset[0x51] = '\u{00E9}';
+    charset[0x52] = '\u{00EA}';
+    charset[0x53] = '\u{00EB}';
+    charset[0x54] = '\u{00E8}';
+    charset[0x55] = '\u{00ED}';
+    charset[0x56] = '\u{00EE}';
+    charset[0x57] = '\u{00EF}';
+    charset[0x58] = '\u{00EC}';
+    charset[0x59] = '\u{00DF}';
+    charset[0x5a] = '\u{0021}';
+    charset[0x5b] = '\u{0024}';
+    charset[0x5c] = '\u{002A}';
+    charset[0x5d] = '\u{0029}';
+    charset[0x5e] = '\u{003B}';
+    charset[0x5f] = '\u{005E}';
+
+    charset[0x60] = '\u{002D}';
+    charset[0x61] = '\u{002F}';
+    charset[0x62] = '\u{00C2}';
+    charset[0x63] = '\u{00C4}';
+    charset[0x64] = '\u{00C0}';
+    charset[0x65] = '\u{00C1}';
+    charset[0x66] = '\u{00C3}';
+    charset[0x67] = '\u{00C5}';
+    charset[0x68] = '\u{00C7}';
+    charset[0x69] = '\u{00D1}';
+    charset[0x6a] = '\u{00A6}';
+    charset[0x6b] = '\u{002C}';
+    charset[0x6c] = '\u{0025}';
+    charset[0x6d] = '\u{005F}';
+    charset[0x6e] = '\u{003E}';
+    charset[0x6f] = '\u{003F}';
+
+    charset[0x70] = '\u{00F8}';
+    charset[0x71] = '\u{00C9}';
+    charset[0x72] = '\u{00CA}';
+    charset[0x73] = '\u{00CB}';
+    charset[0x74] = '\u{00C8}';
+    charset[0x75] = '\u{00CD}';
+    charset[0x76] = '\u{00CE}';
+    charset[0x77] = '\u{00CF}';
+    charset[0x78] = '\u{00CC}';
+    charset[0x79] = '\u{0060}';
+    charset[0x7a] = '\u{003A}';
+    charset[0x7b] = '\u{0023}';
+    charset[0x7c] = '\u{0040}';
+    charset[0x7d] = '\u{0027}';
+    charset[0x7e] = '\u{003D}';
+    charset[0x7f] = '\u{0022}';
+
+    charset[0x80] = '\u{00D8}';
+    charset[0x81] = '\u{0061}';
+    charset[0x82] = '\u{0062}';
+    charset[0x83] = '\u{0063}';
+    charset[0x84] = '\u{0064}';
+    charset[0x85] = '\u{0065}';
+    charset[0x86] = '\u{0066}';
+    charset[0x87] = '\u{0067}';
+    charset[0x88] = '\u{0068}';
+    charset[0x89] = '\u{0069}';
+    charset[0x8a] = '\u{00AB}';
+    charset[0x8b] = '\u{00BB}';
+    charset[0x8c] = '\u{00F0}';
+    charset[0x8d] = '\u{00FD}';
+    charset[0x8e] = '\u{00FE}';
+    charset[0x8f] = '\u{00B1}';
+
+    charset[0x90] = '\u{00B0}';
+    charset[0x91] = '\u{006A}';
+    charset[0x92] = '\u{006B}';
+    charset[0x93] = '\u{006C}';
+    charset[0x94] = '\u{006D}';
+    charset[0x95] = '\u{006E}';
+    charset[0x96] = '\u{006F}';
+    charset[0x97] = '\u{0070}';
+    charset[0x98] = '\u{0071}';
+    charset[0x99] = '\u{0072}';
+    charset[0x9a] = '\u{00AA}';
+    charset[0x9b] = '\u{00BA}';
+    charset[0x9c] = '\u{00E6}';
+    charset[0x9d] = '\u{00B8}';
+    charset[0x9e] = '\u{00C6}';
+    charset[0x9f] = '\u{00A4}';
+
+    charset[0xa0] = '\u{00B5}';
+    charset[0xa1] = '\u{007E}';
+    charset[0xa2] = '\u{0073}';
+    charset[0xa3] = '\u{0074}';
+    charset[0xa4] = '\u{0075}';
+    charset[0xa5] = '\u{0076}';
+    charset[0xa6] = '\u{0077}';
+    charset[0xa7] = '\u{0078}';
+    charset[0xa8] = '\u{0079}';
+    charset[0xa9] = '\u{007A}';
+    charset[0xaa] = '\u{00A1}';
+    charset[0xab] = '\u{00BF}';
+    charset[0xac] = '\u{00D0}';
+    charset[0xad] = '\u{005B}';
+    charset[0xae] = '\u{00DE}';
+    charset[0xaf] = '\u{00AE}';
+
+    charset[0xb0] = '\u{00AC}';
+    charset[0xb1] = '\u{00A3}';
+    charset[0xb2] = '\u{00A5}';
+    charset[0xb3] = '\u{00B7}';
+    charset[0xb4] = '\u{00A9}';
+    charset[0xb5] = '\u{00A7}';
+    charset[0xb6] = '\u{00B6}';
+    charset[0xb7] = '\u{00BC}';
+    charset[0xb8] = '\u{00BD}';
+    charset[0xb9] = '\u{00BE}';
+    charset[0xba] = '\u{00DD}';
+    charset[0xbb] = '\u{00A8}';
+    charset[0xbc] = '\u{00AF}';
+    charset[0xbd] = '\u{005D}';
+    charset[0xbe] = '\u{00B4}';
+    charset[0xbf] = '\u{00D7}';
+
+    charset[0xc0] = '\u{007B}';
+    charset[0xc1] = '\u{0041}';
+    charset[0xc2] = '\u{0042}';
+    charset[0xc3] = '\u{0043}';
+    charset[0xc4] = '\u{0044}';
+    charset[0xc5] = '\u{0045}';
+    charset[0xc6] = '\u{0046}';
+    charset[0xc7] = '\u{0047}';
+    charset[0xc8] = '\u{0048}';
+    charset[0xc9] = '\u{0049}';
+    charset[0xca] = '\u{00AD}';
+    charset[0xcb] = '\u{00F4}';
+    charset[0xcc] = '\u{00F6}';
+    charset[0xcd] = '\u{00F2}';
+    charset[0xce] = '\u{00F3}';
+    charset[0xcf] = '\u{00F5}';
+
+    charset[0xd0] = '\u{007D}';
+    charset[0xd1] = '\u{004A}';
+    charset[0xd2] = '\u{004B}';
+    charset[0xd3] = '\u{004C}';
+    charset[0xd4] = '\u{004D}';
+    charset[0xd5] = '\u{004E}';
+    charset[0xd6] = '\u{004F}';
+    charset[0xd7] = '\u{0050}';
+    charset[0xd8] = '\u{0051}';
+    charset[0xd9] = '\u{0052}';
+    charset[0xda] = '\u{00B9}';
+    charset[0xdb] = '\u{00FB}';
+    charset[0xdc] = '\u{00FC}';
+    charset[0xdd] = '\u{00F9}';
+    charset[0xde] = '\u{00FA}';
+    charset[0xdf] = '\u{00FF}';
+
+    charset[0xe0] = '\u{005C}';
+    charset[0xe1] = '\u{00F7}';
+    charset[0xe2] = '\u{0053}';
+    charset[0xe3] = '\u{0054}';
+    charset[0xe4] = '\u{0055}';
+    charset[0xe5] = '\u{0056}';
+    charset[0xe6] = '\u{0057}';
+    charset[0xe7] = '\u{0058}';
+    charset[0xe8] = '\u{0059}';
+    charset[0xe9] = '\u{005A}';
+    charset[0xea] = '\u{00B2}';
+    charset[0xeb] = '\u{00D4}';
+    charset[0xec] = '\u{00D6}';
+    charset[0xed] = '\u{00D2}';
+    charset[0xee] = '\u{00D3}';
+    charset[0xef] = '\u{00D5}';
+
+    charset[0xf0] = '\u{0030}';
+    charset[0xf1] = '\u{0031}';
+    charset[0xf2] = '\u{0032}';
+    charset[0xf3] = '\u{0033}';
+    charset[0xf4] = '\u{0034}';
+    charset[0xf5] = '\u{0035}';
+    charset[0xf6] = '\u{0036}';
+    charset[0xf7] = '\u{0037}';
+    charset[0xf8] = '\u{0038}';
+    charset[0xf9] = '\u{0039}';
+    charset[0xfa] = '\u{00B3}';
+    charset[0xfb] = '\u{00DB}';
+    charset[0xfc] = '\u{00DC}';
+    charset[0xfd] = '\u{00D9}';
+    charset[0xfe] = '\u{00DA}';
+    charset[0xff] = '\u{009F}';
+
+    charset
+};
+
+/// The IBM1047 charset with the NL and LF control characters swapped.
+const IBM1047_CHARSET_SWAP_LF_NL: [char; 256] = super::swap_lf_nl(IBM1047_CHARSET);
+
+/// An encoding for IBM1047, with the standard mapping of the NL and LF control characters.
+#[cfg_attr(docsrs, doc(cfg(feature = "ibm1047")))]
+pub static IBM1047: Ibm1047 = Ibm1047::new();
+
+/// An encoding for IBM1047, with the NL and LF control characters swapped.
+#[cfg_attr(docsrs, doc(cfg(feature = "ibm1047")))]
+pub static IBM1047_SWAP_LF_NL: Ibm1047 = Ibm1047::new_swap_lf_nl();
+
+/// A mapper from bytes to the corresponding unicode character.
+const ENCODER: SingleByteEncoding =
+    SingleByteEncoding::new(<Ibm1047 as crate::Charset>::CHARSET_NAME, &IBM1047_CHARSET);
+
+/// A mapper from bytes to the corresponding unicode character, with the NL and LF control characters swapped.
+const ENCODER_SWAP_LF_NL: SingleByteEncoding =
+    SingleByteEncoding::new("ibm1047-swaplfnl", &IBM1047_CHARSET_SWAP_LF_NL);
+
+/// An encoding for IBM1047 (EBCDIC Latin-1/Open Systems).
+/// If possible use [`UTF-8`] instead.
+///
+/// See the [module documentation](super) for how the NL and LF control characters are mapped.
+///
+/// [`UTF-8`]: crate::Utf8Encoding
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(docsrs, doc(cfg(feature = "ibm1047")))]
+pub struct Ibm1047 {
+    swap_lf_nl: bool,
+}
+
+impl Ibm1047 {
+    /// Create a new IBM1047 encoding instance, decoding NL (`0x15`) as U+0085 and LF (`0x25`) as U+000A.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { swap_lf_nl: false }
+    }
+
+    /// Create a new IBM1047 encoding instance, decoding NL (`0x15`) as U+000A and LF (`0x25`) as U+0085.
+    #[inline]
+    #[must_use]
+    pub const fn new_swap_lf_nl() -> Self {
+        Self { swap_lf_nl: true }
+    }
+
+    /// Encode characters from the given bytes.
+    #[must_use]
+    #[inline]
+    pub const fn encode_const(&self, chars: &str) -> crate::EncodeResult {
+        self.single_byte().encode_const(chars)
+    }
+
+    /// Encode characters from the given bytes.
+    #[must_use]
+    #[inline]
+    pub fn encode(&self, chars: &str) -> crate::EncodeResult {
+        self.single_byte().encode(chars)
+    }
+
+    /// Decode characters from the given bytes.
+    #[must_use]
+    #[inline]
+    pub const fn decode_const(&self, bytes: &[u8]) -> crate::DecodeResult {
+        self.single_byte().decode_const(bytes)
+    }
+
+    /// Decode characters from the given bytes.
+    #[must_use]
+    #[inline]
+    pub fn decode(&self, bytes: &[u8]) -> crate::DecodeResult {
+        self.single_byte().decode(bytes)
+    }
+
+    /// Get the generic single-byte charset encoder for this charset.
+    #[must_use]
+    #[inline]
+    pub const fn single_byte(&self) -> &'static SingleByteEncoding {
+        if self.swap_lf_nl {
+            &ENCODER_SWAP_LF_NL
+        } else {
+            &ENCODER
+        }
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "ibm1047")))]
+impl core::default::Default for Ibm1047 {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "ibm1047")))]
+impl crate::Charset for Ibm1047 {
+    const CHARSET_NAME: &'static str = "ibm1047";
+
+    #[inline]
+    fn charset_name(&self) -> &'static str {
+        crate::Charset::charset_name(self.single_byte())
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (u16, u16) {
+        (1, 1)
+    }
+
+    #[inline]
+    fn charset_alias(&self) -> &[&'static str] {
+        if self.swap_lf_nl {
+            return &["ibm1047-swaplfnl"];
+        }
+        &[
+            // IANA
+            Self::CHARSET_NAME,
+            "cp1047",
+            "csibm1047",
+            // other
+            "ibm-1047",
+            "1047",
+        ]
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "ibm1047")))]
+impl crate::CharsetDecoding for Ibm1047 {
+    #[inline]
+    fn decode(&self, bytes: &[u8]) -> crate::DecodeResult {
+        Self::decode(self, bytes)
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn decode_all<'a>(
+        &self,
+        bytes: bytedata::ByteData<'a>,
+        invalid: crate::DecodeInvalid,
+    ) -> Result<bytedata::StringData<'a>, crate::DecodeError> {
+        crate::CharsetDecoding::decode_all(self.single_byte(), bytes, invalid)
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "ibm1047")))]
+impl crate::CharsetEncoding for Ibm1047 {
+    #[inline]
+    fn encode(&self, chars: &str) -> crate::EncodeResult {
+        Self::encode(self, chars)
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn encode_all<'a>(
+        &self,
+        chars: &'a str,
+        invalid: crate::EncodeInvalid,
+    ) -> Result<bytedata::ByteData<'a>, &'static str> {
+        crate::CharsetEncoding::encode_all(self.single_byte(), chars, invalid)
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn encode_to_vec(
+        &self,
+        chars: &str,
+        invalid: crate::EncodeInvalid,
+        out: &mut alloc::vec::Vec<u8>,
+    ) -> Result<(), &'static str> {
+        crate::CharsetEncoding::encode_to_vec(self.single_byte(), chars, invalid, out)
+    }
+}
@@ -0,0 +1,105 @@
+//! EBCDIC code pages.
+//!
+//! EBCDIC has two line ending control characters: NL (next line, `0x15`) and LF (line feed, `0x25`).
+//! The standard mapping, used by the IBM tables as well as by `iconv` and Java, decodes NL as U+0085 and LF as U+000A.
+//! Text from z/OS UNIX and similar systems often uses NL as the line terminator, which is expected to become U+000A in Unicode text.
+//! Each code page is therefore also available with NL and LF swapped, similar to the `swaplfnl` option of ICU, labeled as `<name>-swaplfnl`.
+
+#[cfg(feature = "ibm037")]
+mod ibm037;
+#[cfg(feature = "ibm037")]
+pub use ibm037::*;
+
+#[cfg(feature = "ibm273")]
+mod ibm273;
+#[cfg(feature = "ibm273")]
+pub use ibm273::*;
+
+#[cfg(feature = "ibm500")]
+mod ibm500;
+#[cfg(feature = "ibm500")]
+pub use ibm500::*;
+
+#[cfg(feature = "ibm1047")]
+mod ibm1047;
+#[cfg(feature = "ibm1047")]
+pub use ibm1047::*;
+
+/// Swap the NL (`0x15`) and LF (`0x25`) entries of an EBCDIC charset table.
+#[cfg_attr(
+    not(any(
+        feature = "ibm037",
+        feature = "ibm273",
+        feature = "ibm500",
+        feature = "ibm1047"
+    )),
+    expect(dead_code)
+)]
+const fn swap_lf_nl(mut charset: [char; 256]) -> [char; 256] {
+    let nl = charset[0x15];
+    charset[0x15] = charset[0x25];
+    charset[0x25] = nl;
+    charset
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "ibm1047")]
+    #[test]
+    fn test_ibm1047_newlines() {
+        use super::{IBM1047, IBM1047_SWAP_LF_NL};
+
+        assert!(matches!(
+            IBM1047.decode(b"\x15"),
+            crate::DecodeResult::Char('\u{85}', 1)
+        ));
+        assert!(matches!(
+            IBM1047.decode(b"\x25"),
+            crate::DecodeResult::Char('\n', 1)
+        ));
+        assert!(matches!(
+            IBM1047_SWAP_LF_NL.decode(b"\x15"),
+            crate::DecodeResult::Char('\n', 1)
+        ));
+        assert!(matches!(
+            IBM1047_SWAP_LF_NL.decode(b"\x25"),
+            crate::DecodeResult::Char('\u{85}', 1)
+        ));
+
+        assert!(matches!(
+            IBM1047.encode("\n[\u{85}"),
+            crate::EncodeResult::Chunk(chunk, 4) if chunk.as_slice() == b"\x25\xAD\x15"
+        ));
+        assert!(matches!(
+            IBM1047_SWAP_LF_NL.encode("\n[\u{85}"),
+            crate::EncodeResult::Chunk(chunk, 4) if chunk.as_slice() == b"\x15\xAD\x25"
+        ));
+    }
+
+    #[cfg(feature = "ibm037")]
+    #[test]
+    fn test_ibm037_round_trip() {
+        use super::IBM037;
+
+        for byte in 0..=u8::MAX {
+            let mut buf = [0_u8; 4];
+            let res = IBM037.decode(&[byte]);
+            if let crate::DecodeResult::Char(ch, 1) = res {
+                assert!(matches!(
+                    IBM037.encode(ch.encode_utf8(&mut buf)),
+                    crate::EncodeResult::Chunk(chunk, _) if chunk.as_slice() == [byte]
+                ));
+            } else {
+                // control characters in the same position as in ASCII
+                assert!(
+                    matches!(res, crate::DecodeResult::Utf8(1)) && byte < 0x20,
+                    "byte {byte:#04X} decoded to {res:?}"
+                );
+            }
+        }
+        assert!(matches!(
+            IBM037.encode("\u{20AC}"),
+            crate::EncodeResult::InvalidChar('\u{20AC}', 3)
+        ));
+    }
+}
@@ -0,0 +1,211 @@
+/// The operation to perform when a character can not be encoded in the target charset.
+///
+/// Except for [`EncodeInvalid::Error`] the character is replaced by a short text, which is in turn encoded using the target charset.
+/// See [`CharsetStatefulEncoding::encode_invalid`] for encoding the replacement.
+///
+/// The enum is non-exhaustive, as some modes are only available through cargo features.
+///
+/// [`CharsetStatefulEncoding::encode_invalid`]: crate::CharsetStatefulEncoding::encode_invalid
+#[derive(Debug, Clone, Copy)]
+#[non_exhaustive]
+pub enum EncodeInvalid {
+    /// Replace the invalid character with a question mark.
+    Replace,
+    /// Ignore the invalid character.
+    Ignore,
+    /// Return an error when an invalid character is encountered.
+    Error,
+    /// Replace the invalid character with a decimal HTML numeric character reference such as `&#8364;`.
+    ///
+    /// This is the "html" error mode of the WHATWG Encoding Standard used when submitting forms.
+    HtmlDecimal,
+    /// Replace the invalid character with a hexadecimal XML numeric character reference such as `&#x20AC;`.
+    XmlHex,
+    /// Replace the invalid character with an ASCII approximation such as `e` for `é` or `EUR` for `€`, see [`transliterate`].
+    ///
+    /// Characters without an approximation are replaced with a question mark.
+    /// The approximation is only used for characters that the target charset can not encode, so `é` is kept as is when encoding to ISO-8859-1.
+    ///
+    /// [`transliterate`]: crate::transliterate
+    #[cfg(feature = "transliterate")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "transliterate")))]
+    Transliterate,
+    /// Replace the invalid character with the text written by the callback.
+    ///
+    /// The text is limited to [`EncodeReplacement::CAPACITY`] bytes, writing more is an error.
+    Callback(fn(char, &mut dyn core::fmt::Write) -> core::fmt::Result),
+}
+
+impl EncodeInvalid {
+    /// Get the text to encode in place of a character that can not be encoded.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the mode is [`EncodeInvalid::Error`] or if the callback fails.
+    #[expect(clippy::missing_inline_in_public_items)]
+    pub fn replacement(self, ch: char) -> Result<EncodeReplacement, &'static str> {
+        use core::fmt::Write as _;
+
+        let mut replacement = EncodeReplacement::new();
+        let res = match self {
+            Self::Replace => replacement.write_str("?"),
+            Self::Ignore => Ok(()),
+            Self::Error => return Err("invalid character"),
+            Self::HtmlDecimal => write!(replacement, "&#{};", u32::from(ch)),
+            Self::XmlHex => write!(replacement, "&#x{:X};", u32::from(ch)),
+            #[cfg(feature = "transliterate")]
+            Self::Transliterate => replacement.write_str(crate::transliterate(ch).unwrap_or("?")),
+            Self::Callback(callback) => callback(ch, &mut replacement),
+        };
+        match res {
+            Ok(()) => Ok(replacement),
+            Err(_) => Err("invalid character replacement"),
+        }
+    }
+}
+
+/// The text to encode in place of a character that can not be encoded, see [`EncodeInvalid::replacement`].
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct EncodeReplacement {
+    data: [u8; Self::CAPACITY],
+    len: u8,
+}
+
+impl EncodeReplacement {
+    /// The maximum number of bytes of a replacement.
+    pub const CAPACITY: usize = bytedata::ByteChunk::LEN;
+
+    /// Create an empty replacement.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            data: [0; Self::CAPACITY],
+            len: 0,
+        }
+    }
+
+    /// The replacement text.
+    #[inline]
+    #[must_use]
+    pub const fn as_str(&self) -> &str {
+        let (data, _) = self.data.split_at(self.len as usize);
+        // SAFETY: the data is only ever written from complete strings
+        unsafe { core::str::from_utf8_unchecked(data) }
+    }
+}
+
+impl Default for EncodeReplacement {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl core::fmt::Debug for EncodeReplacement {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("EncodeReplacement")
+            .field(&self.as_str())
+            .finish()
+    }
+}
+
+impl core::ops::Deref for EncodeReplacement {
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl core::fmt::Write for EncodeReplacement {
+    #[inline]
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        let start = self.len as usize;
+        let end = start + s.len();
+        let Some(target) = self.data.get_mut(start..end) else {
+            return Err(core::fmt::Error);
+        };
+        target.copy_from_slice(s.as_bytes());
+        #[expect(clippy::cast_possible_truncation)]
+        {
+            self.len = end as u8;
+        };
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CharsetStatefulEncoding;
+
+    #[cfg(feature = "alloc")]
+    fn replacement_str(
+        invalid: EncodeInvalid,
+        ch: char,
+    ) -> Result<alloc::string::String, &'static str> {
+        invalid
+            .replacement(ch)
+            .map(|replacement| alloc::string::String::from(replacement.as_str()))
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_replacement() {
+        assert_eq!(
+            replacement_str(EncodeInvalid::Replace, '\u{20AC}').as_deref(),
+            Ok("?")
+        );
+        assert_eq!(
+            replacement_str(EncodeInvalid::Ignore, '\u{20AC}').as_deref(),
+            Ok("")
+        );
+        assert_eq!(
+            replacement_str(EncodeInvalid::Error, '\u{20AC}'),
+            Err("invalid character")
+        );
+        assert_eq!(
+            replacement_str(EncodeInvalid::HtmlDecimal, '\u{20AC}').as_deref(),
+            Ok("&#8364;")
+        );
+        assert_eq!(
+            replacement_str(EncodeInvalid::XmlHex, '\u{10FFFF}').as_deref(),
+            Ok("&#x10FFFF;")
+        );
+        let callback = EncodeInvalid::Callback(|ch, writer| write!(writer, "[{}]", ch.len_utf8()));
+        assert_eq!(replacement_str(callback, '\u{20AC}').as_deref(), Ok("[3]"));
+        let too_long = EncodeInvalid::Callback(|_, writer| writer.write_str("0123456789abcdef"));
+        assert_eq!(
+            replacement_str(too_long, '\u{20AC}'),
+            Err("invalid character replacement")
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_encode_invalid() {
+        let mut out = alloc::vec::Vec::new();
+        let mut state = crate::CharsetState::new();
+        let res = crate::Utf16Encoding::UTF16_BE.encode_invalid(
+            &mut state,
+            '\u{20AC}',
+            EncodeInvalid::XmlHex,
+            &mut |bytes| out.extend_from_slice(bytes),
+        );
+        assert_eq!(res, Ok(()));
+        assert_eq!(out, b"\0&\0#\0x\x002\x000\0A\0C\0;");
+
+        let mut ascii = alloc::vec::Vec::new();
+        let failed = crate::Ascii7Encoding::new().encode_invalid(
+            &mut state,
+            '\u{20AC}',
+            EncodeInvalid::Callback(|_, writer| writer.write_str("\u{20AC}")),
+            &mut |bytes| ascii.extend_from_slice(bytes),
+        );
+        assert_eq!(failed, Err("invalid character replacement"));
+        assert!(ascii.is_empty());
+    }
+}
@@ -0,0 +1,531 @@
+use super::{CharsetStatefulEncoding, EncodeInvalid};
+
+/// A stream encoding text into bytes of a charset.
+///
+/// The text is either pulled from an iterator of string chunks using [`EncodeStream::iter_next`], or written to the stream through [`std::io::Write`] in which case the encoded bytes are written to the inner writer.
+pub struct EncodeStream<'a, C, S> {
+    charset: C,
+    inner: S,
+    buffer: bytedata::ByteQueue<'a>,
+    output: bytedata::ByteQueue<'a>,
+    invalid: EncodeInvalid,
+    state: crate::CharsetState,
+    eof: bool,
+}
+
+impl<C: core::fmt::Debug, S> core::fmt::Debug for EncodeStream<'_, C, S> {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("EncodeStream")
+            .field("charset", &self.charset)
+            .field("buffer", &self.buffer)
+            .field("output", &self.output)
+            .field("invalid", &self.invalid)
+            .field("state", &self.state)
+            .field("eof", &self.eof)
+            .finish()
+    }
+}
+
+impl<C: CharsetStatefulEncoding, S> EncodeStream<'_, C, S> {
+    /// Create a new encode stream.
+    #[inline]
+    #[must_use]
+    pub const fn new(charset: C, inner: S) -> Self {
+        Self {
+            charset,
+            inner,
+            buffer: bytedata::ByteQueue::new(),
+            output: bytedata::ByteQueue::new(),
+            invalid: EncodeInvalid::Replace,
+            state: crate::CharsetState::new(),
+            eof: false,
+        }
+    }
+
+    /// Set the invalid character handling.
+    #[inline]
+    pub fn set_invalid(&mut self, invalid: EncodeInvalid) {
+        self.invalid = invalid;
+    }
+
+    /// Set the invalid character handling.
+    #[inline]
+    #[must_use]
+    pub const fn with_invalid(mut self, invalid: EncodeInvalid) -> Self {
+        self.invalid = invalid;
+        self
+    }
+
+    /// Returns the charset used for encoding.
+    #[inline]
+    pub const fn charset(&self) -> &C {
+        &self.charset
+    }
+
+    /// Returns the current encoding state of the stream.
+    #[inline]
+    #[must_use]
+    pub const fn state(&self) -> crate::CharsetState {
+        self.state
+    }
+
+    /// Consumes the stream and returns the inner source or writer.
+    #[inline]
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+
+    /// Encode the next characters of the stream.
+    ///
+    /// The returned state should be stored if the result is consumed.
+    #[inline]
+    fn encode_next(&self, chars: &str) -> (crate::EncodeResult, crate::CharsetState) {
+        let mut state = self.state;
+        let res = self.charset.encode_stateful(&mut state, chars);
+        (res, state)
+    }
+}
+
+/// Get the next bytes to encode, joining the front chunk with the following ones if the encoder needs more data.
+///
+/// The returned bytes always end on a character boundary.
+#[inline]
+fn read_buffer<'a>(
+    chunk_buffer_input_n: &mut usize,
+    chunk_buffer_input: &mut [u8],
+    buffer: &bytedata::ByteQueue<'a>,
+) -> bytedata::ByteData<'a> {
+    let mut input_n = *chunk_buffer_input_n;
+    if input_n != 0 {
+        let req = chunk_buffer_input.len().saturating_sub(input_n);
+        for byte in buffer.bytes().skip(input_n).take(req) {
+            chunk_buffer_input[input_n] = byte;
+            input_n += 1;
+        }
+        *chunk_buffer_input_n = input_n;
+        let joined = &chunk_buffer_input[..input_n];
+        let valid = match core::str::from_utf8(joined) {
+            Ok(_) => input_n,
+            Err(err) => err.valid_up_to(),
+        };
+        bytedata::ByteData::from_chunk_slice(&joined[..valid])
+    } else if let Some(chunk) = buffer.front() {
+        chunk.clone()
+    } else {
+        bytedata::ByteData::empty()
+    }
+}
+
+impl<'a, C: CharsetStatefulEncoding, S: Iterator> EncodeStream<'a, C, S>
+where
+    S::Item: Into<bytedata::StringData<'a>>,
+{
+    /// Pulls the next chunk of text from the source iterator and encodes it.
+    ///
+    /// Runs of characters which are encoded the same as in UTF-8 are returned without copying.
+    /// Once the source is exhausted the bytes needed to return the charset to its initial state are returned.
+    #[expect(clippy::missing_inline_in_public_items)]
+    pub fn iter_next(&mut self) -> Option<Result<bytedata::ByteData<'a>, &'static str>> {
+        if let Some(chunk) = self.output.pop_front() {
+            // encoded replacements of invalid characters
+            return Some(Ok(chunk));
+        }
+        let mut chunk_buffer_input = [0_u8; 14];
+        let mut chunk_buffer_input_n = 0;
+        loop {
+            loop {
+                let inner_buf = read_buffer(
+                    &mut chunk_buffer_input_n,
+                    &mut chunk_buffer_input,
+                    &self.buffer,
+                );
+                if inner_buf.is_empty() {
+                    break;
+                }
+                // SAFETY: the buffer only contains complete utf-8 strings and `read_buffer` ends on a character boundary
+                let chars = unsafe { core::str::from_utf8_unchecked(inner_buf.as_slice()) };
+                let (res, state) = self.encode_next(chars);
+                match res {
+                    crate::EncodeResult::Chunk(chunk, len) => {
+                        core::mem::drop(self.buffer.drain(..len as usize));
+                        self.state = state;
+                        return Some(Ok(bytedata::ByteData::from_chunk(chunk)));
+                    }
+                    crate::EncodeResult::Utf8(len) => {
+                        #[expect(clippy::cast_possible_truncation)]
+                        let len = len as usize;
+                        core::mem::drop(self.buffer.drain(..len));
+                        self.state = state;
+                        if inner_buf.len() == len {
+                            return Some(Ok(inner_buf));
+                        }
+                        return Some(Ok(inner_buf.into_sliced(..len)));
+                    }
+                    crate::EncodeResult::InvalidChar(ch, len) => {
+                        let mut state = state;
+                        let output = &mut self.output;
+                        let reported = self.charset.encode_invalid(
+                            &mut state,
+                            ch,
+                            self.invalid,
+                            &mut |bytes| {
+                                output.push_back(bytedata::ByteData::from_chunk_slice(bytes));
+                            },
+                        );
+                        // the invalid character is skipped even when reported, so that the stream can continue after it
+                        core::mem::drop(self.buffer.drain(..len as usize));
+                        chunk_buffer_input_n = 0;
+                        self.state = state;
+                        if let Err(err) = reported {
+                            return Some(Err(err));
+                        }
+                        if let Some(chunk) = self.output.pop_front() {
+                            return Some(Ok(chunk));
+                        }
+                    }
+                    crate::EncodeResult::Incomplete | crate::EncodeResult::Empty => {
+                        // join the front chunk with the following ones if the encoder needs more characters
+                        if chunk_buffer_input_n == 0
+                            && self.buffer.len() > inner_buf.len()
+                            && inner_buf.len() < chunk_buffer_input.len()
+                        {
+                            chunk_buffer_input_n = inner_buf.len();
+                            chunk_buffer_input[..chunk_buffer_input_n]
+                                .copy_from_slice(inner_buf.as_slice());
+                            continue;
+                        }
+                        break;
+                    }
+                }
+            }
+
+            if self.eof {
+                if !self.buffer.is_empty() {
+                    return Some(Err("got to end of stream while encoding characters"));
+                }
+                let mut state = self.state;
+                return match self.charset.encode_finish(&mut state) {
+                    crate::EncodeResult::Chunk(chunk, _) => {
+                        self.state = state;
+                        Some(Ok(bytedata::ByteData::from_chunk(chunk)))
+                    }
+                    crate::EncodeResult::Empty => {
+                        self.state = state;
+                        None
+                    }
+                    crate::EncodeResult::Utf8(_)
+                    | crate::EncodeResult::InvalidChar(..)
+                    | crate::EncodeResult::Incomplete => {
+                        Some(Err("unexpected result when finishing the stream"))
+                    }
+                };
+            }
+
+            if let Some(additional_data) = self.inner.next() {
+                let additional_data: bytedata::StringData<'a> = additional_data.into();
+                self.buffer
+                    .push_back(bytedata::ByteData::from(additional_data));
+            } else {
+                self.eof = true;
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+// `std::io::ErrorKind` has no stable counterpart in `core`
+#[expect(clippy::std_instead_of_core)]
+impl<C: CharsetStatefulEncoding, W: std::io::Write> EncodeStream<'_, C, W> {
+    /// Finish the stream by writing the bytes needed to return the charset to its initial state, then flush the inner writer.
+    ///
+    /// # Errors
+    ///
+    /// - `std::io::ErrorKind::UnexpectedEof` → if the stream ends in the middle of a character.
+    /// - `*` → if the inner writer returns an error.
+    #[expect(clippy::missing_inline_in_public_items)]
+    pub fn finish(&mut self) -> std::io::Result<()> {
+        if !self.buffer.is_empty() {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::UnexpectedEof,
+                "got to end of stream while encoding characters",
+            ));
+        }
+        loop {
+            let mut state = self.state;
+            match self.charset.encode_finish(&mut state) {
+                crate::EncodeResult::Chunk(chunk, _) => {
+                    self.inner.write_all(chunk.as_slice())?;
+                    self.state = state;
+                }
+                crate::EncodeResult::Empty => {
+                    self.state = state;
+                    break;
+                }
+                crate::EncodeResult::Utf8(_)
+                | crate::EncodeResult::InvalidChar(..)
+                | crate::EncodeResult::Incomplete => {
+                    return Err(std::io::Error::new(
+                        std::io::ErrorKind::InvalidData,
+                        "unexpected result when finishing the stream",
+                    ));
+                }
+            }
+        }
+        self.eof = true;
+        self.inner.flush()
+    }
+
+    /// Encode the characters to the inner writer, returning the number of bytes consumed.
+    ///
+    /// Stops early if the encoder needs more characters than are available.
+    fn write_chars(&mut self, mut chars: &str) -> std::io::Result<usize> {
+        let mut consumed = 0;
+        while !chars.is_empty() {
+            let (res, mut state) = self.encode_next(chars);
+            let len = match res {
+                crate::EncodeResult::Chunk(chunk, len) => {
+                    self.inner.write_all(chunk.as_slice())?;
+                    len as usize
+                }
+                crate::EncodeResult::Utf8(len) => {
+                    #[expect(clippy::cast_possible_truncation)]
+                    let len = len as usize;
+                    self.inner
+                        .write_all(chars.as_bytes().get(..len).unwrap_or_default())?;
+                    len
+                }
+                crate::EncodeResult::InvalidChar(ch, len) => {
+                    let inner = &mut self.inner;
+                    let mut written = Ok(());
+                    self.charset
+                        .encode_invalid(&mut state, ch, self.invalid, &mut |bytes| {
+                            if written.is_ok() {
+                                written = inner.write_all(bytes);
+                            }
+                        })
+                        .map_err(|err| std::io::Error::new(std::io::ErrorKind::InvalidData, err))?;
+                    written?;
+                    len as usize
+                }
+                crate::EncodeResult::Incomplete | crate::EncodeResult::Empty => break,
+            };
+            self.state = state;
+            consumed += len;
+            chars = chars.get(len..).unwrap_or_default();
+        }
+        Ok(consumed)
+    }
+
+    /// Encode the bytes to the inner writer, keeping a trailing incomplete character for the next write.
+    fn write_bytes(&mut self, bytes: &[u8]) -> std::io::Result<()> {
+        let valid = match core::str::from_utf8(bytes) {
+            Ok(chars) => chars.len(),
+            Err(err) if err.error_len().is_none() => err.valid_up_to(),
+            Err(err) => return Err(std::io::Error::new(std::io::ErrorKind::InvalidData, err)),
+        };
+        // SAFETY: the bytes up to `valid` are valid utf-8
+        let chars = unsafe { core::str::from_utf8_unchecked(&bytes[..valid]) };
+        let consumed = self.write_chars(chars)?;
+        let rest = &bytes[consumed..];
+        if !rest.is_empty() {
+            let rest = if rest.len() <= bytedata::ByteChunk::LEN {
+                bytedata::ByteData::from_chunk_slice(rest)
+            } else {
+                bytedata::ByteData::from_shared(rest.into())
+            };
+            self.buffer.push_back(rest);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<C: CharsetStatefulEncoding, W: std::io::Write> std::io::Write for EncodeStream<'_, C, W> {
+    /// Encode the utf-8 bytes to the inner writer.
+    ///
+    /// A character split between two writes is kept until the rest of it is written.
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        if self.buffer.is_empty() {
+            self.write_bytes(buf)?;
+        } else {
+            // prepend the bytes kept from the previous write
+            let mut joined = alloc::vec::Vec::with_capacity(self.buffer.len() + buf.len());
+            joined.extend(self.buffer.bytes());
+            joined.extend_from_slice(buf);
+            self.buffer = bytedata::ByteQueue::new();
+            self.write_bytes(&joined)?;
+        }
+        Ok(buf.len())
+    }
+
+    #[inline]
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "alloc")]
+    fn encode_chunks<C: CharsetStatefulEncoding>(
+        charset: C,
+        invalid: EncodeInvalid,
+        chunks: &[&'static str],
+    ) -> Result<alloc::vec::Vec<u8>, &'static str> {
+        let mut stream = EncodeStream::new(charset, chunks.iter().copied()).with_invalid(invalid);
+        let mut out = alloc::vec::Vec::new();
+        while let Some(chunk) = stream.iter_next() {
+            out.extend_from_slice(chunk?.as_slice());
+        }
+        Ok(out)
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_iter_utf8_passthrough() {
+        let chunks = &["abc", "", "d\u{E9}f"];
+        let mut stream = EncodeStream::new(crate::Utf8Encoding::new(), chunks.iter().copied());
+        let first = stream.iter_next().map(|res| res.map(|chunk| chunk.len()));
+        assert_eq!(first, Some(Ok(3)));
+        let out = encode_chunks(crate::Utf8Encoding::new(), EncodeInvalid::Error, chunks);
+        assert_eq!(out.as_deref(), Ok("abcd\u{E9}f".as_bytes()));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_iter_invalid() {
+        let chunks = &["a\u{E9}", "\u{1F600}b"];
+        let replaced = encode_chunks(crate::Ascii7Encoding::new(), EncodeInvalid::Replace, chunks);
+        assert_eq!(replaced.as_deref(), Ok(b"a??b".as_slice()));
+        let ignored = encode_chunks(crate::Ascii7Encoding::new(), EncodeInvalid::Ignore, chunks);
+        assert_eq!(ignored.as_deref(), Ok(b"ab".as_slice()));
+        let failed = encode_chunks(crate::Ascii7Encoding::new(), EncodeInvalid::Error, chunks);
+        assert_eq!(failed, Err("invalid character"));
+        let html = encode_chunks(
+            crate::Ascii7Encoding::new(),
+            EncodeInvalid::HtmlDecimal,
+            chunks,
+        );
+        assert_eq!(html.as_deref(), Ok(b"a&#233;&#128512;b".as_slice()));
+        let xml = encode_chunks(crate::Ascii7Encoding::new(), EncodeInvalid::XmlHex, chunks);
+        assert_eq!(xml.as_deref(), Ok(b"a&#xE9;&#x1F600;b".as_slice()));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_iter_after_error() {
+        let chunks = &["a\u{E9}", "b"];
+        let mut stream = EncodeStream::new(crate::Ascii7Encoding::new(), chunks.iter().copied())
+            .with_invalid(EncodeInvalid::Error);
+        let mut results = alloc::vec::Vec::new();
+        while let Some(chunk) = stream.iter_next() {
+            results.push(chunk.map(|bytes| bytes.as_slice().to_vec()));
+        }
+        assert_eq!(
+            results,
+            [
+                Ok(b"a".to_vec()),
+                Err("invalid character"),
+                Ok(b"b".to_vec())
+            ]
+        );
+    }
+
+    #[cfg(all(feature = "alloc", feature = "transliterate", feature = "iso-8859-1"))]
+    #[test]
+    fn test_iter_transliterate() {
+        let chunks = &[
+            "Caf\u{E9} \u{201C}M\u{FC}ller\u{201D}",
+            " \u{20AC}5 \u{3042}",
+        ];
+        let ascii = encode_chunks(
+            crate::Ascii7Encoding::new(),
+            EncodeInvalid::Transliterate,
+            chunks,
+        );
+        assert_eq!(ascii.as_deref(), Ok(b"Cafe \"Muller\" EUR5 ?".as_slice()));
+        let latin1 = encode_chunks(
+            crate::iso_8859::ISO_8859_1,
+            EncodeInvalid::Transliterate,
+            chunks,
+        );
+        assert_eq!(
+            latin1.as_deref(),
+            Ok(b"Caf\xE9 \"M\xFCller\" EUR5 ?".as_slice())
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_iter_utf16() {
+        let out = encode_chunks(
+            crate::Utf16Encoding::UTF16_BE,
+            EncodeInvalid::Error,
+            &["a", "\u{1F600}"],
+        );
+        assert_eq!(out.as_deref(), Ok(b"\0a\xD8\x3D\xDE\x00".as_slice()));
+    }
+
+    #[cfg(all(feature = "alloc", feature = "iso-2022-jp"))]
+    #[test]
+    fn test_iter_finish() {
+        let out = encode_chunks(
+            crate::multi_byte::ISO_2022_JP,
+            EncodeInvalid::Error,
+            &["a\u{3042}", "\u{3044}"],
+        );
+        assert_eq!(out.as_deref(), Ok(b"a\x1B$B$\"$$\x1B(B".as_slice()));
+        let html = encode_chunks(
+            crate::multi_byte::ISO_2022_JP,
+            EncodeInvalid::HtmlDecimal,
+            &["\u{3042}\u{E9}\u{3044}"],
+        );
+        assert_eq!(
+            html.as_deref(),
+            Ok(b"\x1B$B$\"\x1B(B&#233;\x1B$B$$\x1B(B".as_slice())
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_write_split_chars() {
+        use std::io::Write as _;
+
+        let mut stream = EncodeStream::new(crate::Utf16Encoding::UTF16_LE, Vec::new());
+        for chunk in [b"h\xC3".as_slice(), b"\xA9llo \xF0\x9F", b"\x98", b"\x80"] {
+            assert!(matches!(stream.write_all(chunk), Ok(())));
+        }
+        assert!(matches!(stream.finish(), Ok(())));
+        let expected: Vec<u8> = "h\u{E9}llo \u{1F600}"
+            .encode_utf16()
+            .flat_map(u16::to_le_bytes)
+            .collect();
+        assert_eq!(stream.into_inner(), expected);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_write_invalid() {
+        use std::io::Write as _;
+
+        let mut stream = EncodeStream::new(crate::Ascii7Encoding::new(), Vec::new())
+            .with_invalid(EncodeInvalid::Replace);
+        assert!(matches!(stream.write_all(b"a\xC3"), Ok(())));
+        assert!(stream.finish().is_err());
+        assert!(matches!(stream.write_all(b"\xA9"), Ok(())));
+        assert!(matches!(stream.finish(), Ok(())));
+        assert_eq!(stream.into_inner(), b"a?");
+
+        let mut html = EncodeStream::new(crate::Ascii7Encoding::new(), Vec::new())
+            .with_invalid(EncodeInvalid::HtmlDecimal);
+        assert!(matches!(html.write_all("\u{20AC}1".as_bytes()), Ok(())));
+        assert_eq!(html.into_inner(), b"&#8364;1");
+
+        let mut invalid_utf8 = EncodeStream::new(crate::Ascii7Encoding::new(), Vec::new());
+        assert!(invalid_utf8.write_all(b"a\xFF").is_err());
+    }
+}
@@ -0,0 +1,596 @@
+//! ## HTML charset prescan
+//!
+//! Determines the charset of an HTML document from its byte order mark or from a `<meta>` declaration near its start, before falling back to detection.
+//! See the [`HtmlDetector`] struct for more information.
+
+use crate::detect::DetectionResult;
+
+/// The number of bytes at the start of a document that are scanned for a `<meta>` declaration.
+const PRESCAN_LIMIT: usize = 1024;
+
+/// A detector that determines the charset of an HTML document the way browsers do before parsing it.
+///
+/// The charset is selected by the first of these steps that succeeds:
+///
+/// 1. A byte order mark for UTF-8, UTF-16BE or UTF-16LE, which makes the charset [`DetectionResult::Certain`].
+///    Unlike the [`UtfDetector`], a UTF-32 byte order mark is not recognized, as browsers treat it as a UTF-16LE one.
+/// 2. A `<meta charset>` or `<meta http-equiv="Content-Type">` declaration found by [`HtmlDetector::prescan`], which makes the charset [`DetectionResult::Tentative`].
+/// 3. The fallback [`WebDetector`], which sniffs the charset from the content.
+///    If the bytes end inside a tag or comment before the first 1024 bytes, the charset it selects is [`DetectionResult::Incomplete`], as the tag could still declare the charset.
+///
+/// A charset declared by the transport layer, such as in a `Content-Type` header, takes precedence over the declaration in the document
+/// and should be resolved with the [`CharsetRegistry`] before this detector is used.
+///
+/// [`CharsetRegistry`]: crate::CharsetRegistry
+/// [`UtfDetector`]: crate::UtfDetector
+/// [`WebDetector`]: crate::WebDetector
+#[derive(Debug, Clone, Copy, Default)]
+pub struct HtmlDetector {
+    fallback: crate::WebDetector,
+}
+
+impl HtmlDetector {
+    /// Create a new detector falling back to a [`WebDetector`] without a default charset.
+    ///
+    /// [`WebDetector`]: crate::WebDetector
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            fallback: crate::WebDetector::new(),
+        }
+    }
+
+    /// Create a new detector falling back to the given [`WebDetector`] when the document neither starts with a byte order mark nor declares its charset.
+    ///
+    /// [`WebDetector`]: crate::WebDetector
+    #[inline]
+    #[must_use]
+    pub const fn with_fallback(fallback: crate::WebDetector) -> Self {
+        Self { fallback }
+    }
+
+    /// The detector used when the document neither starts with a byte order mark nor declares its charset.
+    #[inline]
+    #[must_use]
+    pub const fn fallback(&self) -> &crate::WebDetector {
+        &self.fallback
+    }
+
+    /// The charset indicated by the byte order mark at the start of the bytes, as sniffed by the [HTML standard].
+    ///
+    /// [HTML standard]: https://encoding.spec.whatwg.org/#bom-sniff
+    #[must_use]
+    #[expect(clippy::missing_inline_in_public_items)]
+    pub fn bom(bytes: &[u8]) -> Option<&'static dyn crate::CharsetCodec> {
+        match Self::sniff_bom(bytes) {
+            (charset, DetectionResult::Certain) => Some(charset),
+            _ => None,
+        }
+    }
+
+    /// Run the [`UtfDetector`] on the first three bytes, which is as many as the standard sniffs.
+    ///
+    /// Within three bytes only a byte order mark is certain, and a UTF-32LE one is taken for UTF-16LE just as browsers do.
+    ///
+    /// [`UtfDetector`]: crate::UtfDetector
+    fn sniff_bom(bytes: &[u8]) -> (&'static dyn crate::CharsetCodec, DetectionResult) {
+        let (charset, result) = crate::UtfDetector::select_const(bytes.get(..3).unwrap_or(bytes));
+        let charset =
+            crate::CharsetRegistry::lookup(crate::CharsetRegistry::canonical_name(charset))
+                .unwrap_or(&crate::UTF8);
+        (charset, result)
+    }
+
+    /// Scan the first 1024 bytes of a document for a `<meta>` element declaring its charset.
+    ///
+    /// This follows the algorithm to [prescan a byte stream] of the HTML standard, which skips comments and the attributes of other tags.
+    /// A declared UTF-16 charset resolves to UTF-8 and `x-user-defined` resolves to `windows-1252`, as the declaration could not have been read otherwise.
+    /// Returns [`PrescanResult::Incomplete`] if the bytes end inside a tag or comment before the first 1024 bytes, in which case more of the document should be passed if available.
+    ///
+    /// [prescan a byte stream]: https://html.spec.whatwg.org/multipage/parsing.html#prescan-a-byte-stream-to-determine-its-encoding
+    #[must_use]
+    #[expect(clippy::missing_inline_in_public_items)]
+    pub fn prescan(bytes: &[u8]) -> PrescanResult {
+        match Self::scan(bytes.get(..PRESCAN_LIMIT).unwrap_or(bytes)) {
+            Some(Some(charset)) => PrescanResult::Declared(charset),
+            Some(None) => PrescanResult::NotDeclared,
+            // a tag that is cut off by the limit can not declare the charset
+            None if bytes.len() >= PRESCAN_LIMIT => PrescanResult::NotDeclared,
+            None => PrescanResult::Incomplete,
+        }
+    }
+
+    /// Scan the bytes for a `<meta>` element declaring the charset, returning [`None`] if they end inside a tag or comment.
+    #[expect(clippy::option_option)]
+    fn scan(bytes: &[u8]) -> Option<Option<&'static dyn crate::CharsetCodec>> {
+        let mut scanner = Scanner { bytes, pos: 0 };
+        while scanner.pos < scanner.bytes.len() {
+            if scanner.starts_with(b"<!--") {
+                // the `-->` may share its dashes with the `<!--`, as in `<!-->`
+                scanner.pos += 2;
+                scanner.skip_to(b"-->")?;
+            } else if scanner.starts_with(b"<meta")
+                && scanner.byte_at(5).is_some_and(is_space_or_slash)
+            {
+                scanner.pos += 5;
+                if let Some(charset) = scanner.meta()? {
+                    return Some(Some(charset));
+                }
+            } else if (scanner.starts_with(b"<")
+                && scanner
+                    .byte_at(1)
+                    .is_some_and(|byte| byte.is_ascii_alphabetic()))
+                || (scanner.starts_with(b"</")
+                    && scanner
+                        .byte_at(2)
+                        .is_some_and(|byte| byte.is_ascii_alphabetic()))
+            {
+                let name_end = scanner
+                    .bytes
+                    .get(scanner.pos..)?
+                    .iter()
+                    .position(|&byte| is_space(byte) || byte == b'>')?;
+                scanner.pos += name_end;
+                while let Attribute::Found(_, _) = scanner.attribute()? {}
+            } else if scanner.starts_with(b"<")
+                && matches!(scanner.byte_at(1), Some(b'!' | b'/' | b'?'))
+            {
+                scanner.pos += 1;
+                scanner.skip_to(b">")?;
+            } else if scanner.starts_with(b"<") && scanner.byte_at(1).is_none() {
+                // the next bytes decide what the `<` starts
+                return None;
+            } else {
+                // any other byte is skipped
+            }
+            scanner.pos += 1;
+        }
+        Some(None)
+    }
+
+    /// Scan the first 1024 bytes in the queue for a `<meta>` element declaring the charset of the document.
+    ///
+    /// See [`HtmlDetector::prescan`] for more information.
+    #[must_use]
+    #[expect(clippy::missing_inline_in_public_items)]
+    pub fn prescan_queue(queue: &bytedata::ByteQueue<'_>) -> PrescanResult {
+        let mut buf = [0_u8; PRESCAN_LIMIT];
+        let mut len = 0;
+        for (target, byte) in buf.iter_mut().zip(queue.bytes()) {
+            *target = byte;
+            len += 1;
+        }
+        Self::prescan(buf.get(..len).unwrap_or_default())
+    }
+
+    /// Select the charset of the document starting with the given bytes, together with the detection result of that charset.
+    ///
+    /// Fewer than three bytes that the [`UtfDetector`] reports as incomplete, such as the start of a byte order mark,
+    /// and bytes that end inside a tag or comment before the first 1024 bytes are [`DetectionResult::Incomplete`].
+    ///
+    /// [`UtfDetector`]: crate::UtfDetector
+    #[must_use]
+    #[expect(clippy::missing_inline_in_public_items)]
+    pub fn select_charset(
+        &self,
+        bytes: &[u8],
+    ) -> (&'static dyn crate::CharsetCodec, DetectionResult) {
+        match Self::sniff_bom(bytes) {
+            (charset, DetectionResult::Certain) => return (charset, DetectionResult::Certain),
+            (charset, DetectionResult::Incomplete) if bytes.len() < 3 => {
+                return (charset, DetectionResult::Incomplete);
+            }
+            _ => {}
+        }
+        match Self::prescan(bytes) {
+            PrescanResult::Declared(charset) => (charset, DetectionResult::Tentative),
+            PrescanResult::NotDeclared => self.fallback.select_charset(bytes),
+            PrescanResult::Incomplete => (
+                self.fallback.select_charset(bytes).0,
+                DetectionResult::Incomplete,
+            ),
+        }
+    }
+}
+
+/// The result of [`HtmlDetector::prescan`].
+#[derive(Clone, Copy)]
+#[expect(clippy::exhaustive_enums)]
+pub enum PrescanResult {
+    /// A `<meta>` element declares a charset that is enabled through cargo features.
+    Declared(&'static dyn crate::CharsetCodec),
+    /// The scan completed without finding a declaration, or the declared label is not enabled through cargo features.
+    NotDeclared,
+    /// The bytes end inside a tag or comment before the first 1024 bytes, so the scan can not complete yet.
+    Incomplete,
+}
+
+impl PrescanResult {
+    /// The declared charset, if any.
+    #[inline]
+    #[must_use]
+    pub fn charset(self) -> Option<&'static dyn crate::CharsetCodec> {
+        match self {
+            Self::Declared(charset) => Some(charset),
+            Self::NotDeclared | Self::Incomplete => None,
+        }
+    }
+}
+
+impl core::fmt::Debug for PrescanResult {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match *self {
+            Self::Declared(charset) => f
+                .debug_tuple("Declared")
+                .field(&crate::CharsetRegistry::canonical_name(charset))
+                .finish(),
+            Self::NotDeclared => f.write_str("NotDeclared"),
+            Self::Incomplete => f.write_str("Incomplete"),
+        }
+    }
+}
+
+impl crate::detect::CharsetDetector for HtmlDetector {
+    #[inline]
+    fn detect(&self, bytes: &[u8]) -> DetectionResult {
+        self.select_charset(bytes).1
+    }
+}
+
+impl crate::detect::CharsetSelector for HtmlDetector {
+    type Charset = &'static dyn crate::CharsetCodec;
+
+    #[inline]
+    fn select(&self, bytes: &[u8]) -> (Self::Charset, DetectionResult) {
+        self.select_charset(bytes)
+    }
+}
+
+/// An attribute of a tag, as the name and value bytes of the document.
+enum Attribute<'b> {
+    Found(&'b [u8], &'b [u8]),
+    End,
+}
+
+/// The position of the prescan within the document, where every method returns [`None`] when the document ends before a token does.
+struct Scanner<'b> {
+    bytes: &'b [u8],
+    pos: usize,
+}
+
+impl<'b> Scanner<'b> {
+    fn byte_at(&self, offset: usize) -> Option<u8> {
+        self.bytes.get(self.pos + offset).copied()
+    }
+
+    fn starts_with(&self, prefix: &[u8]) -> bool {
+        self.bytes
+            .get(self.pos..self.pos + prefix.len())
+            .is_some_and(|bytes| bytes.eq_ignore_ascii_case(prefix))
+    }
+
+    /// Move to the last byte of the next occurrence of `needle`.
+    fn skip_to(&mut self, needle: &[u8]) -> Option<()> {
+        let found = self
+            .bytes
+            .get(self.pos..)?
+            .windows(needle.len())
+            .position(|window| window == needle)?;
+        self.pos += found + needle.len() - 1;
+        Some(())
+    }
+
+    /// Read the attributes of a `<meta>` element, returning the charset it declares if any.
+    #[expect(clippy::option_option)]
+    fn meta(&mut self) -> Option<Option<&'static dyn crate::CharsetCodec>> {
+        let mut seen = [false; 3];
+        let mut got_pragma = false;
+        let mut need_pragma = None;
+        let mut charset = None;
+        let mut charset_set = false;
+        while let Attribute::Found(name, value) = self.attribute()? {
+            let Some(index) = [&b"http-equiv"[..], b"content", b"charset"]
+                .iter()
+                .position(|known| name.eq_ignore_ascii_case(known))
+            else {
+                continue;
+            };
+            let Some(entry) = seen.get_mut(index).filter(|entry| !**entry) else {
+                continue;
+            };
+            *entry = true;
+            match index {
+                0 => got_pragma |= value.eq_ignore_ascii_case(b"content-type"),
+                1 => {
+                    if let Some(found) = content_charset(value).filter(|_| !charset_set) {
+                        charset = Some(found);
+                        charset_set = true;
+                        need_pragma = Some(true);
+                    }
+                }
+                _ => {
+                    charset = encoding(value);
+                    charset_set = true;
+                    need_pragma = Some(false);
+                }
+            }
+        }
+        match need_pragma {
+            None => return Some(None),
+            Some(true) if !got_pragma => return Some(None),
+            _ => {}
+        }
+        Some(charset.and_then(resolve_declared))
+    }
+
+    /// Read the next attribute of a tag, leaving the position at the `>` when there are no more attributes.
+    fn attribute(&mut self) -> Option<Attribute<'b>> {
+        while is_space_or_slash(self.byte_at(0)?) {
+            self.pos += 1;
+        }
+        if self.byte_at(0)? == b'>' {
+            return Some(Attribute::End);
+        }
+        let name_start = self.pos;
+        let name = loop {
+            match self.byte_at(0)? {
+                b'=' if self.pos > name_start => break self.bytes.get(name_start..self.pos)?,
+                byte if is_space(byte) => {
+                    let name = self.bytes.get(name_start..self.pos)?;
+                    while is_space(self.byte_at(0)?) {
+                        self.pos += 1;
+                    }
+                    if self.byte_at(0)? != b'=' {
+                        return Some(Attribute::Found(name, b""));
+                    }
+                    break name;
+                }
+                b'/' | b'>' => {
+                    return Some(Attribute::Found(self.bytes.get(name_start..self.pos)?, b""));
+                }
+                _ => self.pos += 1,
+            }
+        };
+        // skip the `=`
+        self.pos += 1;
+        while is_space(self.byte_at(0)?) {
+            self.pos += 1;
+        }
+        let value_start = self.pos;
+        let quote = self.byte_at(0)?;
+        if quote == b'>' {
+            return Some(Attribute::Found(name, b""));
+        }
+        self.pos += 1;
+        if quote == b'"' || quote == b'\'' {
+            while self.byte_at(0)? != quote {
+                self.pos += 1;
+            }
+            let value = self.bytes.get(value_start + 1..self.pos)?;
+            self.pos += 1;
+            return Some(Attribute::Found(name, value));
+        }
+        loop {
+            let byte = self.byte_at(0)?;
+            if is_space(byte) || byte == b'>' {
+                return Some(Attribute::Found(
+                    name,
+                    self.bytes.get(value_start..self.pos)?,
+                ));
+            }
+            self.pos += 1;
+        }
+    }
+}
+
+/// Extract the charset from the value of the `content` attribute of a `<meta>` element, such as `text/html; charset=utf-8`.
+fn content_charset(value: &[u8]) -> Option<&'static dyn crate::CharsetCodec> {
+    let mut pos = 0;
+    loop {
+        pos += value
+            .get(pos..)?
+            .windows(7)
+            .position(|window| window.eq_ignore_ascii_case(b"charset"))?
+            + 7;
+        while value.get(pos).copied().is_some_and(is_space) {
+            pos += 1;
+        }
+        if value.get(pos) == Some(&b'=') {
+            break;
+        }
+    }
+    pos += 1;
+    while value.get(pos).copied().is_some_and(is_space) {
+        pos += 1;
+    }
+    let rest = value.get(pos..)?;
+    match rest.first().copied() {
+        Some(quote @ (b'"' | b'\'')) => {
+            let rest = rest.get(1..)?;
+            let end = rest.iter().position(|&byte| byte == quote)?;
+            encoding(rest.get(..end)?)
+        }
+        Some(_) => {
+            let end = rest
+                .iter()
+                .position(|&byte| is_space(byte) || byte == b';')
+                .unwrap_or(rest.len());
+            encoding(rest.get(..end)?)
+        }
+        None => None,
+    }
+}
+
+/// Resolve a label declared in the document to a charset.
+fn encoding(label: &[u8]) -> Option<&'static dyn crate::CharsetCodec> {
+    core::str::from_utf8(label)
+        .ok()
+        .and_then(crate::CharsetRegistry::lookup)
+}
+
+/// Replace charsets that a document read as ASCII could not have been declared in.
+fn resolve_declared(
+    charset: &'static dyn crate::CharsetCodec,
+) -> Option<&'static dyn crate::CharsetCodec> {
+    match crate::CharsetRegistry::canonical_name(charset) {
+        "utf-16be" | "utf-16le" => Some(&crate::UTF8),
+        "x-user-defined" => crate::CharsetRegistry::lookup("windows-1252"),
+        _ => Some(charset),
+    }
+}
+
+const fn is_space(byte: u8) -> bool {
+    matches!(byte, b'\t' | b'\n' | b'\x0C' | b'\r' | b' ')
+}
+
+const fn is_space_or_slash(byte: u8) -> bool {
+    is_space(byte) || byte == b'/'
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn prescan_name(html: &[u8]) -> Option<&'static str> {
+        HtmlDetector::prescan(html)
+            .charset()
+            .map(crate::CharsetRegistry::canonical_name)
+    }
+
+    #[test]
+    fn test_meta_charset() {
+        assert_eq!(
+            prescan_name(b"<!DOCTYPE html><html><head><meta charset=\"utf-8\">"),
+            Some("utf-8")
+        );
+        assert_eq!(
+            prescan_name(b"<META CHARSET=UTF-8><meta charset=windows-1252>"),
+            Some("utf-8")
+        );
+        assert_eq!(prescan_name(b"<meta/charset='utf-8'>"), Some("utf-8"));
+        assert_eq!(prescan_name(b"<meta charset=\"utf-16le\">"), Some("utf-8"));
+        assert_eq!(prescan_name(b"<meta charset=\"no-such-charset\">"), None);
+        assert_eq!(prescan_name(b"<meta charset=\"utf-8\""), None);
+        assert_eq!(prescan_name(b"<html><body>no declaration</body>"), None);
+    }
+
+    #[test]
+    fn test_incomplete() {
+        let incomplete =
+            |html: &[u8]| matches!(HtmlDetector::prescan(html), PrescanResult::Incomplete);
+        assert!(incomplete(b"<meta charset=\"utf-8\""));
+        assert!(incomplete(b"<html><head><meta charset=\"koi8"));
+        assert!(incomplete(b"<!-- <meta charset=utf-8>"));
+        assert!(incomplete(b"<html"));
+        assert!(incomplete(b"<html><head><"));
+        assert!(!incomplete(b"<html><body>no declaration</body>"));
+        assert!(!incomplete(b"<meta charset=utf-8>"));
+        assert!(!incomplete(b""));
+
+        // the scan ends at the limit, even inside a tag
+        let mut long = [b' '; PRESCAN_LIMIT + 20];
+        long[PRESCAN_LIMIT - 2..PRESCAN_LIMIT + 18].copy_from_slice(b"<meta charset=utf-8>");
+        assert!(matches!(
+            HtmlDetector::prescan(&long),
+            PrescanResult::NotDeclared
+        ));
+    }
+
+    #[cfg(feature = "iso-8859-2")]
+    #[test]
+    fn test_http_equiv() {
+        assert_eq!(
+            prescan_name(
+                b"<meta http-equiv=\"Content-Type\" content=\"text/html; charset=ISO-8859-2\">"
+            ),
+            Some("iso-8859-2")
+        );
+        assert_eq!(
+            prescan_name(
+                b"<meta content='text/html;charset = \"iso-8859-2\"' http-equiv=content-type>"
+            ),
+            Some("iso-8859-2")
+        );
+        // the content attribute is only used together with http-equiv
+        assert_eq!(
+            prescan_name(b"<meta content=\"text/html; charset=iso-8859-2\"><meta charset=utf-8>"),
+            Some("utf-8")
+        );
+        // duplicate attributes are ignored
+        assert_eq!(
+            prescan_name(b"<meta http-equiv=content-type content=\"charset=iso-8859-2\" content=\"charset=utf-8\">"),
+            Some("iso-8859-2")
+        );
+    }
+
+    #[test]
+    fn test_skip_markup() {
+        assert_eq!(
+            prescan_name(b"<!-- <meta charset=\"utf-16be\"> --><meta charset=utf-8>"),
+            Some("utf-8")
+        );
+        assert_eq!(prescan_name(b"<!--><meta charset=utf-8>"), Some("utf-8"));
+        assert_eq!(
+            prescan_name(b"<div title=\"<meta charset=ascii>\"><meta charset=utf-8>"),
+            Some("utf-8")
+        );
+        assert_eq!(
+            prescan_name(b"<?xml version=\"1.0\"?></p><meta charset=utf-8>"),
+            Some("utf-8")
+        );
+
+        let mut late = [b' '; PRESCAN_LIMIT + 20];
+        late[PRESCAN_LIMIT - 2..PRESCAN_LIMIT + 18].copy_from_slice(b"<meta charset=utf-8>");
+        assert_eq!(prescan_name(&late), None);
+        late[PRESCAN_LIMIT - 20..PRESCAN_LIMIT].copy_from_slice(b"<meta charset=utf-8>");
+        assert_eq!(prescan_name(&late), Some("utf-8"));
+    }
+
+    #[test]
+    fn test_prescan_queue() {
+        let mut queue = bytedata::ByteQueue::new();
+        queue.push_back(&b"<meta char"[..]);
+        queue.push_back(&b"set=utf-8>"[..]);
+        assert_eq!(
+            HtmlDetector::prescan_queue(&queue)
+                .charset()
+                .map(crate::CharsetRegistry::canonical_name),
+            Some("utf-8")
+        );
+    }
+
+    #[test]
+    fn test_select() {
+        let detector = HtmlDetector::new();
+        let select_name = |bytes: &[u8]| {
+            let (charset, result) = detector.select_charset(bytes);
+            (crate::CharsetRegistry::canonical_name(charset), result)
+        };
+        assert_eq!(
+            select_name(b"\xFF\xFE<\x00m\x00"),
+            ("utf-16le", DetectionResult::Certain)
+        );
+        assert_eq!(
+            select_name(b"\xEF\xBB\xBF<meta charset=utf-16be>"),
+            ("utf-8", DetectionResult::Certain)
+        );
+        assert_eq!(
+            select_name(b"\xEF\xBB"),
+            ("utf-8", DetectionResult::Incomplete)
+        );
+        assert_eq!(
+            select_name(b"\xFF\xFE\x00\x00<\x00"),
+            ("utf-16le", DetectionResult::Certain)
+        );
+        assert_eq!(
+            select_name(b"<meta charset=utf-8>"),
+            ("utf-8", DetectionResult::Tentative)
+        );
+        assert_eq!(
+            select_name(b"<html><head><meta charset=\"koi8").1,
+            DetectionResult::Incomplete
+        );
+    }
+}
@@ -84,6 +84,24 @@ impl Iso8859_1 {
             return crate::DecodeResult::Char(byte as char, 1);
         }
 
+        #[cfg(all(any(target_arch = "x86_64", target_arch = "x86"), feature = "sse2"))]
+        if maxlen >= 16 && crate::ascii7::sse2_detected() {
+            // SAFETY: The pointer is valid and the length is correct, and sse2 has been checked.
+            let res = unsafe { crate::ascii7::ascii7_decode_sse2(bytes_ptr, maxlen, 0) };
+            if !matches!(res, crate::DecodeResult::Empty) {
+                return res;
+            }
+        }
+
+        #[cfg(feature = "swar")]
+        if maxlen >= core::mem::size_of::<usize>() {
+            // SAFETY: The pointer is valid and the length is correct.
+            let res = unsafe { crate::ascii7::ascii7_decode_swar(bytes_ptr, maxlen, 0) };
+            if !matches!(res, crate::DecodeResult::Empty) {
+                return res;
+            }
+        }
+
         fallback(bytes)
     }
 
@@ -142,6 +160,24 @@ impl Iso8859_1 {
             return encode_const_inner(chars_ptr, maxlen);
         }
 
+        #[cfg(all(any(target_arch = "x86_64", target_arch = "x86"), feature = "sse2"))]
+        if maxlen >= 16 && crate::ascii7::sse2_detected() {
+            // SAFETY: The pointer is valid and the length is correct, and sse2 has been checked.
+            let res = unsafe { crate::ascii7::ascii7_encode_sse2(chars_ptr, maxlen, 0) };
+            if !matches!(res, crate::EncodeResult::Empty) {
+                return res;
+            }
+        }
+
+        #[cfg(feature = "swar")]
+        if maxlen >= core::mem::size_of::<usize>() {
+            // SAFETY: The pointer is valid and the length is correct.
+            let res = unsafe { crate::ascii7::ascii7_encode_swar(chars_ptr, maxlen, 0) };
+            if !matches!(res, crate::EncodeResult::Empty) {
+                return res;
+            }
+        }
+
         fallback(chars)
     }
 
@@ -196,6 +232,37 @@ impl crate::CharsetDecoding for Iso8859_1 {
     fn decode(&self, bytes: &[u8]) -> crate::DecodeResult {
         Self::decode(self, bytes)
     }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn decode_all<'a>(
+        &self,
+        bytes: bytedata::ByteData<'a>,
+        _invalid: crate::DecodeInvalid,
+    ) -> Result<bytedata::StringData<'a>, crate::DecodeError> {
+        let input = bytes.as_slice();
+        let start = crate::ascii7::ascii7_prefix(input);
+        if start == input.len() {
+            // SAFETY: ascii is valid utf-8
+            return Ok(unsafe { bytedata::StringData::from_bytedata_unchecked(bytes) });
+        }
+        // every byte is a valid character, so the output is at most twice the size of the input
+        let mut out = bytedata::SharedBytesBuilder::with_capacity(input.len() * 2);
+        let (ascii, mut rest) = input.split_at(start);
+        out.extend_from_slice(ascii);
+        while let Some((&byte, tail)) = rest.split_first() {
+            if byte < 0x80 {
+                let (run, after) = rest.split_at(crate::ascii7::ascii7_prefix(rest));
+                out.extend_from_slice(run);
+                rest = after;
+                continue;
+            }
+            out.push(0xC0 | (byte >> 6_u8));
+            out.push(0x80 | (byte & 0x3F));
+            rest = tail;
+        }
+        Ok(crate::bulk::into_string_data(out))
+    }
 }
 
 #[cfg_attr(docsrs, doc(cfg(feature = "iso-8859-1")))]
@@ -204,6 +271,27 @@ impl crate::CharsetEncoding for Iso8859_1 {
     fn encode(&self, chars: &str) -> crate::EncodeResult {
         Self::encode(self, chars)
     }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn encode_all<'a>(
+        &self,
+        chars: &'a str,
+        invalid: crate::EncodeInvalid,
+    ) -> Result<bytedata::ByteData<'a>, &'static str> {
+        crate::bulk::encode_all_ascii(self, true, chars, invalid)
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn encode_to_vec(
+        &self,
+        chars: &str,
+        invalid: crate::EncodeInvalid,
+        out: &mut alloc::vec::Vec<u8>,
+    ) -> Result<(), &'static str> {
+        crate::bulk::encode_to_vec_ascii(self, true, chars, invalid, out)
+    }
 }
 
 const fn encode_const_inner(mut data: *const u8, mut maxlen: usize) -> crate::EncodeResult {
@@ -219,7 +307,16 @@ const fn encode_const_inner(mut data: *const u8, mut maxlen: usize) -> crate::En
             let slic = unsafe { core::slice::from_raw_parts(data, maxlen) };
             bytedata::const_utf8_char_next(slic)
         };
-        if b_len == 0 || ch > 255 {
+        if b_len == 0 {
+            break;
+        }
+        if ch > 255 {
+            if buf_len == 0 {
+                if let Some(ch) = char::from_u32(ch) {
+                    #[expect(clippy::cast_possible_truncation)]
+                    return crate::EncodeResult::InvalidChar(ch, b_len as u16);
+                }
+            }
             break;
         }
         #[expect(clippy::cast_possible_truncation)]
@@ -255,6 +352,6 @@ const fn encode_const_inner(mut data: *const u8, mut maxlen: usize) -> crate::En
     if buf_len == 0 {
         return crate::EncodeResult::Incomplete;
     }
-    let buf = bytedata::const_or_bytes(bytedata::const_slice(&buf, 0..ascii_offset), b"");
+    let buf = bytedata::const_or_bytes(bytedata::const_slice(&buf, 0..buf_len), b"");
     crate::EncodeResult::Chunk(bytedata::ByteChunk::from_slice(buf), consumed)
 }
@@ -203,6 +203,16 @@ impl crate::CharsetDecoding for Iso8859_11 {
     fn decode(&self, bytes: &[u8]) -> crate::DecodeResult {
         Self::decode(self, bytes)
     }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn decode_all<'a>(
+        &self,
+        bytes: bytedata::ByteData<'a>,
+        invalid: crate::DecodeInvalid,
+    ) -> Result<bytedata::StringData<'a>, crate::DecodeError> {
+        crate::CharsetDecoding::decode_all(&ENCODER, bytes, invalid)
+    }
 }
 
 #[cfg_attr(docsrs, doc(cfg(feature = "iso-8859-11")))]
@@ -211,4 +221,25 @@ impl crate::CharsetEncoding for Iso8859_11 {
     fn encode(&self, chars: &str) -> crate::EncodeResult {
         Self::encode(self, chars)
     }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn encode_all<'a>(
+        &self,
+        chars: &'a str,
+        invalid: crate::EncodeInvalid,
+    ) -> Result<bytedata::ByteData<'a>, &'static str> {
+        crate::CharsetEncoding::encode_all(&ENCODER, chars, invalid)
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn encode_to_vec(
+        &self,
+        chars: &str,
+        invalid: crate::EncodeInvalid,
+        out: &mut alloc::vec::Vec<u8>,
+    ) -> Result<(), &'static str> {
+        crate::CharsetEncoding::encode_to_vec(&ENCODER, chars, invalid, out)
+    }
 }
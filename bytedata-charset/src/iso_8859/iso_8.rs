@@ -1,11 +1,11 @@
 use crate::ascii7_compat::AsciiCompatible;
 
 /// The ISO-8859-8 charset.
-const ISO_8859_8_CHARSET: [char; 128] = {
+pub(super) const ISO_8859_8_CHARSET: [char; 128] = {
     let mut charset = super::ISO_8859_1_CHARSET;
 
     charset[0xA0 & 0x7F] = '\u{00A0}';
-
+    charset[0xA1 & 0x7F] = '\0';
     charset[0xA2 & 0x7F] = '\u{00A2}';
     charset[0xA3 & 0x7F] = '\u{00A3}';
     charset[0xA4 & 0x7F] = '\u{00A4}';
@@ -36,6 +36,38 @@ const ISO_8859_8_CHARSET: [char; 128] = {
     charset[0xBC & 0x7F] = '\u{00BC}';
     charset[0xBD & 0x7F] = '\u{00BD}';
     charset[0xBE & 0x7F] = '\u{00BE}';
+    charset[0xBF & 0x7F] = '\0';
+    charset[0xC0 & 0x7F] = '\0';
+    charset[0xC1 & 0x7F] = '\0';
+    charset[0xC2 & 0x7F] = '\0';
+    charset[0xC3 & 0x7F] = '\0';
+    charset[0xC4 & 0x7F] = '\0';
+    charset[0xC5 & 0x7F] = '\0';
+    charset[0xC6 & 0x7F] = '\0';
+    charset[0xC7 & 0x7F] = '\0';
+    charset[0xC8 & 0x7F] = '\0';
+    charset[0xC9 & 0x7F] = '\0';
+    charset[0xCA & 0x7F] = '\0';
+    charset[0xCB & 0x7F] = '\0';
+    charset[0xCC & 0x7F] = '\0';
+    charset[0xCD & 0x7F] = '\0';
+    charset[0xCE & 0x7F] = '\0';
+    charset[0xCF & 0x7F] = '\0';
+    charset[0xD0 & 0x7F] = '\0';
+    charset[0xD1 & 0x7F] = '\0';
+    charset[0xD2 & 0x7F] = '\0';
+    charset[0xD3 & 0x7F] = '\0';
+    charset[0xD4 & 0x7F] = '\0';
+    charset[0xD5 & 0x7F] = '\0';
+    charset[0xD6 & 0x7F] = '\0';
+    charset[0xD7 & 0x7F] = '\0';
+    charset[0xD8 & 0x7F] = '\0';
+    charset[0xD9 & 0x7F] = '\0';
+    charset[0xDA & 0x7F] = '\0';
+    charset[0xDB & 0x7F] = '\0';
+    charset[0xDC & 0x7F] = '\0';
+    charset[0xDD & 0x7F] = '\0';
+    charset[0xDE & 0x7F] = '\0';
 
     charset[0xDF & 0x7F] = '\u{2017}';
 
@@ -67,9 +99,11 @@ const ISO_8859_8_CHARSET: [char; 128] = {
     charset[0xF8 & 0x7F] = '\u{05E8}';
     charset[0xF9 & 0x7F] = '\u{05E9}';
     charset[0xFA & 0x7F] = '\u{05EA}';
-
+    charset[0xFB & 0x7F] = '\0';
+    charset[0xFC & 0x7F] = '\0';
     charset[0xFD & 0x7F] = '\u{200E}';
     charset[0xFE & 0x7F] = '\u{200F}';
+    charset[0xFF & 0x7F] = '\0';
 
     charset
 };
@@ -171,8 +205,8 @@ impl crate::Charset for Iso8859_8 {
             "iso-8859-8-e",
             "iso8859-8",
             "iso88598",
-            "sun_eu_greek",
-            //"visual",
+            "csiso88598e",
+            "visual",
         ]
     }
 }
@@ -183,6 +217,16 @@ impl crate::CharsetDecoding for Iso8859_8 {
     fn decode(&self, bytes: &[u8]) -> crate::DecodeResult {
         Self::decode(self, bytes)
     }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn decode_all<'a>(
+        &self,
+        bytes: bytedata::ByteData<'a>,
+        invalid: crate::DecodeInvalid,
+    ) -> Result<bytedata::StringData<'a>, crate::DecodeError> {
+        crate::CharsetDecoding::decode_all(&ENCODER, bytes, invalid)
+    }
 }
 
 #[cfg_attr(docsrs, doc(cfg(feature = "iso-8859-8")))]
@@ -191,4 +235,56 @@ impl crate::CharsetEncoding for Iso8859_8 {
     fn encode(&self, chars: &str) -> crate::EncodeResult {
         Self::encode(self, chars)
     }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn encode_all<'a>(
+        &self,
+        chars: &'a str,
+        invalid: crate::EncodeInvalid,
+    ) -> Result<bytedata::ByteData<'a>, &'static str> {
+        crate::CharsetEncoding::encode_all(&ENCODER, chars, invalid)
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn encode_to_vec(
+        &self,
+        chars: &str,
+        invalid: crate::EncodeInvalid,
+        out: &mut alloc::vec::Vec<u8>,
+    ) -> Result<(), &'static str> {
+        crate::CharsetEncoding::encode_to_vec(&ENCODER, chars, invalid, out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unassigned() {
+        assert_eq!(
+            ISO_8859_8.decode_const(b"\xE0"),
+            crate::DecodeResult::Char('\u{05D0}', 1)
+        );
+        assert_eq!(
+            ISO_8859_8.decode_const(b"\xDF"),
+            crate::DecodeResult::Char('\u{2017}', 1)
+        );
+        // the bytes without a Hebrew assignment must not decode as ISO-8859-1
+        for byte in [0xA1_u8, 0xBF, 0xC0, 0xD0, 0xDE, 0xFB, 0xFC, 0xFF] {
+            assert!(
+                matches!(
+                    ISO_8859_8.decode_const(&[byte]),
+                    crate::DecodeResult::InvalidChar(_, 1)
+                ),
+                "byte {byte:#04X} must be invalid"
+            );
+        }
+        assert!(matches!(
+            ISO_8859_8.encode_const("\u{00C0}"),
+            crate::EncodeResult::InvalidChar('\u{00C0}', _)
+        ));
+    }
 }
@@ -0,0 +1,143 @@
+use crate::ascii7_compat::AsciiCompatible;
+
+/// An encoding for ISO-8859-8-I.
+#[cfg_attr(docsrs, doc(cfg(feature = "iso-8859-8-i")))]
+pub static ISO_8859_8_I: Iso8859_8I = Iso8859_8I::new();
+
+/// A mapper from bytes over 128 to the corresponding unicode character.
+const ENCODER: AsciiCompatible = AsciiCompatible::new(
+    <Iso8859_8I as crate::Charset>::CHARSET_NAME,
+    &super::iso_8::ISO_8859_8_CHARSET,
+);
+
+/// An encoding for ISO-8859-8-I, the logical order variant of [`ISO-8859-8`].
+///
+/// The bytes map to the same characters as ISO-8859-8, but the text is stored in logical order instead of visual order.
+/// If possible use [`UTF-8`] instead.
+///
+/// [`ISO-8859-8`]: super::Iso8859_8
+/// [`UTF-8`]: crate::Utf8Encoding
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[expect(clippy::exhaustive_structs)]
+#[cfg_attr(docsrs, doc(cfg(feature = "iso-8859-8-i")))]
+pub struct Iso8859_8I;
+
+impl Iso8859_8I {
+    /// Create a new ISO-8859-8-I encoding instance.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self
+    }
+
+    /// Encode characters from the given bytes.
+    #[must_use]
+    #[inline]
+    pub const fn encode_const(&self, chars: &str) -> crate::EncodeResult {
+        ENCODER.encode_const(chars)
+    }
+
+    /// Encode characters from the given bytes.
+    #[must_use]
+    #[inline]
+    pub fn encode(&self, chars: &str) -> crate::EncodeResult {
+        ENCODER.encode(chars)
+    }
+
+    /// Decode characters from the given bytes.
+    #[must_use]
+    #[inline]
+    pub const fn decode_const(&self, bytes: &[u8]) -> crate::DecodeResult {
+        ENCODER.decode_const(bytes)
+    }
+
+    /// Decode characters from the given bytes.
+    #[must_use]
+    #[inline]
+    pub fn decode(&self, bytes: &[u8]) -> crate::DecodeResult {
+        ENCODER.decode(bytes)
+    }
+
+    /// Get the generic ASCII-compatible charset encoder for this charset.
+    #[must_use]
+    #[inline]
+    pub const fn ascii_compat(&self) -> &'static AsciiCompatible {
+        &ENCODER
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "iso-8859-8-i")))]
+impl core::default::Default for Iso8859_8I {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "iso-8859-8-i")))]
+impl crate::Charset for Iso8859_8I {
+    const CHARSET_NAME: &'static str = "iso-8859-8-i";
+
+    #[inline]
+    fn size_hint(&self) -> (u16, u16) {
+        (1, 1)
+    }
+
+    #[inline]
+    fn charset_alias(&self) -> &[&'static str] {
+        &[
+            // IANA
+            Self::CHARSET_NAME,
+            "csiso88598i",
+            // other
+            "logical",
+        ]
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "iso-8859-8-i")))]
+impl crate::CharsetDecoding for Iso8859_8I {
+    #[inline]
+    fn decode(&self, bytes: &[u8]) -> crate::DecodeResult {
+        Self::decode(self, bytes)
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn decode_all<'a>(
+        &self,
+        bytes: bytedata::ByteData<'a>,
+        invalid: crate::DecodeInvalid,
+    ) -> Result<bytedata::StringData<'a>, crate::DecodeError> {
+        crate::CharsetDecoding::decode_all(&ENCODER, bytes, invalid)
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "iso-8859-8-i")))]
+impl crate::CharsetEncoding for Iso8859_8I {
+    #[inline]
+    fn encode(&self, chars: &str) -> crate::EncodeResult {
+        Self::encode(self, chars)
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn encode_all<'a>(
+        &self,
+        chars: &'a str,
+        invalid: crate::EncodeInvalid,
+    ) -> Result<bytedata::ByteData<'a>, &'static str> {
+        crate::CharsetEncoding::encode_all(&ENCODER, chars, invalid)
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn encode_to_vec(
+        &self,
+        chars: &str,
+        invalid: crate::EncodeInvalid,
+        out: &mut alloc::vec::Vec<u8>,
+    ) -> Result<(), &'static str> {
+        crate::CharsetEncoding::encode_to_vec(&ENCODER, chars, invalid, out)
+    }
+}
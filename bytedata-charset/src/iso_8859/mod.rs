@@ -39,6 +39,10 @@ pub use iso_7::*;
 mod iso_8;
 #[cfg(feature = "iso-8859-8")]
 pub use iso_8::*;
+#[cfg(feature = "iso-8859-8-i")]
+mod iso_8_i;
+#[cfg(feature = "iso-8859-8-i")]
+pub use iso_8_i::*;
 #[cfg(feature = "iso-8859-9")]
 mod iso_9;
 #[cfg(feature = "iso-8859-9")]
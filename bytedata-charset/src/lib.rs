@@ -122,6 +122,9 @@ extern crate alloc;
 
 mod traits;
 
+#[cfg(feature = "alloc")]
+mod bulk;
+
 mod detect;
 mod endian;
 mod result;
@@ -141,9 +144,20 @@ mod utf32;
 #[cfg(feature = "utf-32")]
 pub use utf32::*;
 
+#[cfg(feature = "replacement")]
+mod replacement;
+#[cfg(feature = "replacement")]
+pub use replacement::*;
+
+#[cfg(any(feature = "ascii7-compat", feature = "single-byte"))]
+mod reverse_index;
+
 #[cfg(feature = "ascii7-compat")]
 #[cfg_attr(docsrs, doc(cfg(feature = "ascii7-compat")))]
 pub mod ascii7_compat;
+#[cfg(feature = "ebcdic")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ebcdic")))]
+pub mod ebcdic;
 #[cfg(feature = "iso-8859")]
 #[cfg_attr(docsrs, doc(cfg(feature = "iso-8859")))]
 pub mod iso_8859;
@@ -159,8 +173,45 @@ pub mod multi_byte;
 #[cfg_attr(docsrs, doc(cfg(feature = "single-byte")))]
 pub mod single_byte;
 
+mod decode_error;
+pub use decode_error::*;
+
 mod decode_stream;
 pub use decode_stream::*;
 
+mod encode_invalid;
+pub use encode_invalid::*;
+
+mod encode_stream;
+pub use encode_stream::*;
+
+#[cfg(feature = "transliterate")]
+mod transliterate;
+#[cfg(feature = "transliterate")]
+pub use transliterate::transliterate;
+
+#[cfg(feature = "macros")]
+#[cfg_attr(docsrs, doc(cfg(feature = "macros")))]
+pub use bytedata_unicode_macros::{ascii_compatible_charset, single_byte_charset};
+
+mod registry;
+pub use registry::*;
+
+mod web_detect;
+pub use web_detect::*;
+
+mod html_prescan;
+pub use html_prescan::*;
+
+#[cfg(feature = "alloc")]
+mod stream_detect;
+#[cfg(feature = "alloc")]
+pub use stream_detect::*;
+
+#[cfg(feature = "statistical-detect")]
+mod statistical;
+#[cfg(feature = "statistical-detect")]
+pub use statistical::*;
+
 pub use endian::CharsetEndian;
 pub use result::{DecodeResult, EncodeResult, ExhaustiveDecodeResult, ExhaustiveEncodeResult};
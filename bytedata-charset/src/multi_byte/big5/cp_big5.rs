@@ -0,0 +1,14659 @@
+/// Based on <https://encoding.spec.whatwg.org/index-big5.txt>.
+/// - Identifier: 8dfc771062e7be0810919082c2c06baa2236147909e0ecc235b1cb9ad782ac82
+/// - Date: 2024-09-18
+#[expect(clippy::decimal_literal_representation)]
+pub(super) static BIG5_CP_TO_PTR: [(u32, u16); 14653] = [
+    (0x00A7, 5103),
+    (0x00A8, 10951),
+    (0x00AF, 5120),
+    (0x00B0, 5205),
+    (0x00B1, 5137),
+    (0x00B7, 5040),
+    (0x00D7, 5135),
+    (0x00F7, 5136),
+    (0x00F8, 11300),
+    (0x014B, 11301),
+    (0x0153, 11299),
+    (0x0250, 11295),
+    (0x0254, 11297),
+    (0x025B, 11296),
+    (0x026A, 11303),
+    (0x0275, 11298),
+    (0x0283, 11294),
+    (0x028A, 11302),
+    (0x02C6, 10952),
+    (0x02C7, 5430),
+    (0x02C9, 5428),
+    (0x02CA, 5429),
+    (0x02CB, 5431),
+    (0x02CD, 5123),
+    (0x02D9, 5427),
+    (0x0391, 5342),
+    (0x0392, 5343),
+    (0x0393, 5344),
+    (0x0394, 5345),
+    (0x0395, 5346),
+    (0x0396, 5347),
+    (0x0397, 5348),
+    (0x0398, 5349),
+    (0x0399, 5350),
+    (0x039A, 5351),
+    (0x039B, 5352),
+    (0x039C, 5353),
+    (0x039D, 5354),
+    (0x039E, 5355),
+    (0x039F, 5356),
+    (0x03A0, 5357),
+    (0x03A1, 5358),
+    (0x03A3, 5359),
+    (0x03A4, 5360),
+    (0x03A5, 5361),
+    (0x03A6, 5362),
+    (0x03A7, 5363),
+    (0x03A8, 5364),
+    (0x03A9, 5365),
+    (0x03B1, 5366),
+    (0x03B2, 5367),
+    (0x03B3, 5368),
+    (0x03B4, 5369),
+    (0x03B5, 5370),
+    (0x03B6, 5371),
+    (0x03B7, 5372),
+    (0x03B8, 5373),
+    (0x03B9, 5374),
+    (0x03BA, 5375),
+    (0x03BB, 5376),
+    (0x03BC, 5377),
+    (0x03BD, 5378),
+    (0x03BE, 5379),
+    (0x03BF, 5380),
+    (0x03C0, 5381),
+    (0x03C1, 5382),
+    (0x03C3, 5383),
+    (0x03C4, 5384),
+    (0x03C5, 5385),
+    (0x03C6, 5386),
+    (0x03C7, 5387),
+    (0x03C8, 5388),
+    (0x03C9, 5389),
+    (0x0401, 11141),
+    (0x0410, 11135),
+    (0x0411, 11136),
+    (0x0412, 11137),
+    (0x0413, 11138),
+    (0x0414, 11139),
+    (0x0415, 11140),
+    (0x0416, 11142),
+    (0x0417, 11143),
+    (0x0418, 11144),
+    (0x0419, 11145),
+    (0x041A, 11146),
+    (0x041B, 11147),
+    (0x041C, 11148),
+    (0x041D, 11149),
+    (0x041E, 11150),
+    (0x041F, 11151),
+    (0x0420, 11152),
+    (0x0421, 11153),
+    (0x0422, 11154),
+    (0x0423, 11155),
+    (0x0424, 11156),
+    (0x0425, 11157),
+    (0x0426, 11158),
+    (0x0427, 11159),
+    (0x0428, 11160),
+    (0x0429, 11161),
+    (0x042A, 11162),
+    (0x042B, 11163),
+    (0x042C, 11164),
+    (0x042D, 11165),
+    (0x042E, 11166),
+    (0x042F, 11167),
+    (0x0430, 11168),
+    (0x0431, 11169),
+    (0x0432, 11170),
+    (0x0433, 11171),
+    (0x0434, 11172),
+    (0x0435, 11173),
+    (0x0436, 11175),
+    (0x0437, 11176),
+    (0x0438, 11177),
+    (0x0439, 11178),
+    (0x043A, 11179),
+    (0x043B, 11180),
+    (0x043C, 11181),
+    (0x043D, 11182),
+    (0x043E, 11183),
+    (0x043F, 11184),
+    (0x0440, 11185),
+    (0x0441, 11186),
+    (0x0442, 11187),
+    (0x0443, 11188),
+    (0x0444, 11189),
+    (0x0445, 11190),
+    (0x0446, 11191),
+    (0x0447, 11192),
+    (0x0448, 11193),
+    (0x0449, 11194),
+    (0x044A, 11195),
+    (0x044B, 11196),
+    (0x044C, 11197),
+    (0x044D, 11198),
+    (0x044E, 11199),
+    (0x044F, 11200),
+    (0x0451, 11174),
+    (0x2013, 5046),
+    (0x2014, 5048),
+    (0x2018, 5091),
+    (0x2019, 5092),
+    (0x201C, 5093),
+    (0x201D, 5094),
+    (0x2025, 5036),
+    (0x2026, 5035),
+    (0x2027, 5029),
+    (0x2032, 5098),
+    (0x2035, 5097),
+    (0x203B, 5102),
+    (0x20AC, 5465),
+    (0x2103, 5191),
+    (0x2105, 5119),
+    (0x2109, 5192),
+    (0x2116, 11259),
+    (0x2121, 11260),
+    (0x2160, 5268),
+    (0x2161, 5269),
+    (0x2162, 5270),
+    (0x2163, 5271),
+    (0x2164, 5272),
+    (0x2165, 5273),
+    (0x2166, 5274),
+    (0x2167, 5275),
+    (0x2168, 5276),
+    (0x2169, 5277),
+    (0x2170, 10916),
+    (0x2171, 10917),
+    (0x2172, 10918),
+    (0x2173, 10919),
+    (0x2174, 10920),
+    (0x2175, 10921),
+    (0x2176, 10922),
+    (0x2177, 10923),
+    (0x2178, 10924),
+    (0x2179, 10925),
+    (0x2190, 5172),
+    (0x2191, 5170),
+    (0x2192, 5173),
+    (0x2193, 5171),
+    (0x2196, 5174),
+    (0x2197, 5175),
+    (0x2198, 5177),
+    (0x2199, 5176),
+    (0x21B8, 11202),
+    (0x21B9, 11203),
+    (0x21E7, 11201),
+    (0x2215, 5182),
+    (0x221A, 5138),
+    (0x221E, 5145),
+    (0x221F, 5158),
+    (0x2220, 5157),
+    (0x2223, 5179),
+    (0x2225, 5178),
+    (0x2229, 5154),
+    (0x222A, 5155),
+    (0x222B, 5162),
+    (0x222E, 5163),
+    (0x2234, 5165),
+    (0x2235, 5164),
+    (0x2252, 5146),
+    (0x2260, 5144),
+    (0x2261, 5147),
+    (0x2266, 5142),
+    (0x2267, 5143),
+    (0x2295, 5168),
+    (0x2299, 5169),
+    (0x22A5, 5156),
+    (0x22BF, 5159),
+    (0x2400, 5432),
+    (0x2401, 5433),
+    (0x2402, 5434),
+    (0x2403, 5435),
+    (0x2404, 5436),
+    (0x2405, 5437),
+    (0x2406, 5438),
+    (0x2407, 5439),
+    (0x2408, 5440),
+    (0x2409, 5441),
+    (0x240A, 5442),
+    (0x240B, 5443),
+    (0x240C, 5444),
+    (0x240D, 5445),
+    (0x240E, 5446),
+    (0x240F, 5447),
+    (0x2410, 5448),
+    (0x2411, 5449),
+    (0x2412, 5450),
+    (0x2413, 5451),
+    (0x2414, 5452),
+    (0x2415, 5453),
+    (0x2416, 5454),
+    (0x2417, 5455),
+    (0x2418, 5456),
+    (0x2419, 5457),
+    (0x241A, 5458),
+    (0x241B, 5459),
+    (0x241C, 5460),
+    (0x241D, 5461),
+    (0x241E, 5462),
+    (0x241F, 5463),
+    (0x2421, 5464),
+    (0x2460, 10896),
+    (0x2461, 10897),
+    (0x2462, 10898),
+    (0x2463, 10899),
+    (0x2464, 10900),
+    (0x2465, 10901),
+    (0x2466, 10902),
+    (0x2467, 10903),
+    (0x2468, 10904),
+    (0x2469, 10905),
+    (0x2474, 10906),
+    (0x2475, 10907),
+    (0x2476, 10908),
+    (0x2477, 10909),
+    (0x2478, 10910),
+    (0x2479, 10911),
+    (0x247A, 10912),
+    (0x247B, 10913),
+    (0x247C, 10914),
+    (0x247D, 10915),
+    (0x2500, 5236),
+    (0x2502, 5237),
+    (0x250C, 5239),
+    (0x2510, 5240),
+    (0x2514, 5241),
+    (0x2518, 5242),
+    (0x251C, 5234),
+    (0x2524, 5233),
+    (0x252C, 5232),
+    (0x2534, 5231),
+    (0x253C, 5230),
+    (0x2550, 18991),
+    (0x2551, 18990),
+    (0x2552, 18972),
+    (0x2553, 18981),
+    (0x2554, 18963),
+    (0x2555, 18974),
+    (0x2556, 18983),
+    (0x2557, 18965),
+    (0x2558, 18978),
+    (0x2559, 18987),
+    (0x255A, 18969),
+    (0x255B, 18980),
+    (0x255C, 18989),
+    (0x255D, 18971),
+    (0x255E, 18975),
+    (0x255F, 18984),
+    (0x2560, 18966),
+    (0x2561, 18977),
+    (0x2562, 18986),
+    (0x2563, 18968),
+    (0x2564, 18973),
+    (0x2565, 18982),
+    (0x2566, 18964),
+    (0x2567, 18979),
+    (0x2568, 18988),
+    (0x2569, 18970),
+    (0x256A, 18976),
+    (0x256B, 18985),
+    (0x256C, 18967),
+    (0x256D, 5243),
+    (0x256E, 5244),
+    (0x256F, 5246),
+    (0x2570, 5245),
+    (0x2571, 5255),
+    (0x2572, 5256),
+    (0x2573, 5257),
+    (0x2574, 5050),
+    (0x2581, 5215),
+    (0x2582, 5216),
+    (0x2583, 5217),
+    (0x2584, 5218),
+    (0x2585, 5219),
+    (0x2586, 5220),
+    (0x2587, 5221),
+    (0x2588, 5222),
+    (0x2589, 5229),
+    (0x258A, 5228),
+    (0x258B, 5227),
+    (0x258C, 5226),
+    (0x258D, 5225),
+    (0x258E, 5224),
+    (0x258F, 5223),
+    (0x2594, 5235),
+    (0x2595, 5238),
+    (0x25A0, 5115),
+    (0x25A1, 5114),
+    (0x25B2, 5108),
+    (0x25B3, 5107),
+    (0x25BC, 5117),
+    (0x25BD, 5116),
+    (0x25C6, 5113),
+    (0x25C7, 5112),
+    (0x25CB, 5105),
+    (0x25CE, 5109),
+    (0x25CF, 5106),
+    (0x25E2, 5251),
+    (0x25E3, 5252),
+    (0x25E4, 5254),
+    (0x25E5, 5253),
+    (0x2605, 5111),
+    (0x2606, 5110),
+    (0x2640, 5166),
+    (0x2642, 5167),
+    (0x273D, 10965),
+    (0x2E80, 11263),
+    (0x2E84, 11264),
+    (0x2E86, 11265),
+    (0x2E87, 11266),
+    (0x2E88, 11267),
+    (0x2E8A, 11268),
+    (0x2E8C, 11269),
+    (0x2E8D, 11270),
+    (0x2E95, 11271),
+    (0x2E9C, 11272),
+    (0x2E9D, 11273),
+    (0x2EA5, 11274),
+    (0x2EA7, 11275),
+    (0x2EAA, 11276),
+    (0x2EAC, 11277),
+    (0x2EAE, 11278),
+    (0x2EB6, 11279),
+    (0x2EBC, 11280),
+    (0x2EBE, 11281),
+    (0x2EC6, 11282),
+    (0x2ECA, 11283),
+    (0x2ECC, 11284),
+    (0x2ECD, 11285),
+    (0x2ECF, 11286),
+    (0x2ED6, 11287),
+    (0x2ED7, 11288),
+    (0x2EDE, 11289),
+    (0x2EE3, 11290),
+    (0x2F33, 10940),
+    (0x3000, 5024),
+    (0x3001, 5026),
+    (0x3002, 5027),
+    (0x3003, 5104),
+    (0x3005, 10959),
+    (0x3006, 10960),
+    (0x3007, 10961),
+    (0x3008, 5073),
+    (0x3009, 5074),
+    (0x300A, 5069),
+    (0x300B, 5070),
+    (0x300C, 5077),
+    (0x300D, 5078),
+    (0x300E, 5081),
+    (0x300F, 5082),
+    (0x3010, 5065),
+    (0x3011, 5066),
+    (0x3012, 5186),
+    (0x3014, 5061),
+    (0x3015, 5062),
+    (0x301D, 5095),
+    (0x301E, 5096),
+    (0x3021, 5278),
+    (0x3022, 5279),
+    (0x3023, 5280),
+    (0x3024, 5281),
+    (0x3025, 5282),
+    (0x3026, 5283),
+    (0x3027, 5284),
+    (0x3028, 5285),
+    (0x3029, 5286),
+    (0x3041, 10966),
+    (0x3042, 10967),
+    (0x3043, 10968),
+    (0x3044, 10969),
+    (0x3045, 10970),
+    (0x3046, 10971),
+    (0x3047, 10972),
+    (0x3048, 10973),
+    (0x3049, 10974),
+    (0x304A, 10975),
+    (0x304B, 10976),
+    (0x304C, 10977),
+    (0x304D, 10978),
+    (0x304E, 10979),
+    (0x304F, 10980),
+    (0x3050, 10981),
+    (0x3051, 10982),
+    (0x3052, 10983),
+    (0x3053, 10984),
+    (0x3054, 10985),
+    (0x3055, 10986),
+    (0x3056, 10987),
+    (0x3057, 10988),
+    (0x3058, 10989),
+    (0x3059, 10990),
+    (0x305A, 10991),
+    (0x305B, 10992),
+    (0x305C, 10993),
+    (0x305D, 10994),
+    (0x305E, 10995),
+    (0x305F, 10996),
+    (0x3060, 10997),
+    (0x3061, 10998),
+    (0x3062, 10999),
+    (0x3063, 11000),
+    (0x3064, 11001),
+    (0x3065, 11002),
+    (0x3066, 11003),
+    (0x3067, 11004),
+    (0x3068, 11005),
+    (0x3069, 11006),
+    (0x306A, 11007),
+    (0x306B, 11008),
+    (0x306C, 11009),
+    (0x306D, 11010),
+    (0x306E, 11011),
+    (0x306F, 11012),
+    (0x3070, 11013),
+    (0x3071, 11014),
+    (0x3072, 11015),
+    (0x3073, 11016),
+    (0x3074, 11017),
+    (0x3075, 11018),
+    (0x3076, 11019),
+    (0x3077, 11020),
+    (0x3078, 11021),
+    (0x3079, 11022),
+    (0x307A, 11023),
+    (0x307B, 11024),
+    (0x307C, 11025),
+    (0x307D, 11026),
+    (0x307E, 11027),
+    (0x307F, 11028),
+    (0x3080, 11029),
+    (0x3081, 11030),
+    (0x3082, 11031),
+    (0x3083, 11032),
+    (0x3084, 11033),
+    (0x3085, 11034),
+    (0x3086, 11035),
+    (0x3087, 11036),
+    (0x3088, 11037),
+    (0x3089, 11038),
+    (0x308A, 11039),
+    (0x308B, 11040),
+    (0x308C, 11041),
+    (0x308D, 11042),
+    (0x308E, 11043),
+    (0x308F, 11044),
+    (0x3090, 11045),
+    (0x3091, 11046),
+    (0x3092, 11047),
+    (0x3093, 11048),
+    (0x309B, 11261),
+    (0x309C, 11262),
+    (0x309D, 10955),
+    (0x309E, 10956),
+    (0x30A1, 11049),
+    (0x30A2, 11050),
+    (0x30A3, 11051),
+    (0x30A4, 11052),
+    (0x30A5, 11053),
+    (0x30A6, 11054),
+    (0x30A7, 11055),
+    (0x30A8, 11056),
+    (0x30A9, 11057),
+    (0x30AA, 11058),
+    (0x30AB, 11059),
+    (0x30AC, 11060),
+    (0x30AD, 11061),
+    (0x30AE, 11062),
+    (0x30AF, 11063),
+    (0x30B0, 11064),
+    (0x30B1, 11065),
+    (0x30B2, 11066),
+    (0x30B3, 11067),
+    (0x30B4, 11068),
+    (0x30B5, 11069),
+    (0x30B6, 11070),
+    (0x30B7, 11071),
+    (0x30B8, 11072),
+    (0x30B9, 11073),
+    (0x30BA, 11074),
+    (0x30BB, 11075),
+    (0x30BC, 11076),
+    (0x30BD, 11077),
+    (0x30BE, 11078),
+    (0x30BF, 11079),
+    (0x30C0, 11080),
+    (0x30C1, 11081),
+    (0x30C2, 11082),
+    (0x30C3, 11083),
+    (0x30C4, 11084),
+    (0x30C5, 11085),
+    (0x30C6, 11086),
+    (0x30C7, 11087),
+    (0x30C8, 11088),
+    (0x30C9, 11089),
+    (0x30CA, 11090),
+    (0x30CB, 11091),
+    (0x30CC, 11092),
+    (0x30CD, 11093),
+    (0x30CE, 11094),
+    (0x30CF, 11095),
+    (0x30D0, 11096),
+    (0x30D1, 11097),
+    (0x30D2, 11098),
+    (0x30D3, 11099),
+    (0x30D4, 11100),
+    (0x30D5, 11101),
+    (0x30D6, 11102),
+    (0x30D7, 11103),
+    (0x30D8, 11104),
+    (0x30D9, 11105),
+    (0x30DA, 11106),
+    (0x30DB, 11107),
+    (0x30DC, 11108),
+    (0x30DD, 11109),
+    (0x30DE, 11110),
+    (0x30DF, 11111),
+    (0x30E0, 11112),
+    (0x30E1, 11113),
+    (0x30E2, 11114),
+    (0x30E3, 11115),
+    (0x30E4, 11116),
+    (0x30E5, 11117),
+    (0x30E6, 11118),
+    (0x30E7, 11119),
+    (0x30E8, 11120),
+    (0x30E9, 11121),
+    (0x30EA, 11122),
+    (0x30EB, 11123),
+    (0x30EC, 11124),
+    (0x30ED, 11125),
+    (0x30EE, 11126),
+    (0x30EF, 11127),
+    (0x30F0, 11128),
+    (0x30F1, 11129),
+    (0x30F2, 11130),
+    (0x30F3, 11131),
+    (0x30F4, 11132),
+    (0x30F5, 11133),
+    (0x30F6, 11134),
+    (0x30FC, 10962),
+    (0x30FD, 10953),
+    (0x30FE, 10954),
+    (0x3105, 5390),
+    (0x3106, 5391),
+    (0x3107, 5392),
+    (0x3108, 5393),
+    (0x3109, 5394),
+    (0x310A, 5395),
+    (0x310B, 5396),
+    (0x310C, 5397),
+    (0x310D, 5398),
+    (0x310E, 5399),
+    (0x310F, 5400),
+    (0x3110, 5401),
+    (0x3111, 5402),
+    (0x3112, 5403),
+    (0x3113, 5404),
+    (0x3114, 5405),
+    (0x3115, 5406),
+    (0x3116, 5407),
+    (0x3117, 5408),
+    (0x3118, 5409),
+    (0x3119, 5410),
+    (0x311A, 5411),
+    (0x311B, 5412),
+    (0x311C, 5413),
+    (0x311D, 5414),
+    (0x311E, 5415),
+    (0x311F, 5416),
+    (0x3120, 5417),
+    (0x3121, 5418),
+    (0x3122, 5419),
+    (0x3123, 5420),
+    (0x3124, 5421),
+    (0x3125, 5422),
+    (0x3126, 5423),
+    (0x3127, 5424),
+    (0x3128, 5425),
+    (0x3129, 5426),
+    (0x31CF, 11204),
+    (0x3231, 11258),
+    (0x32A3, 5118),
+    (0x338E, 5202),
+    (0x338F, 5203),
+    (0x339C, 5197),
+    (0x339D, 5198),
+    (0x339E, 5199),
+    (0x33A1, 5201),
+    (0x33C4, 5204),
+    (0x33CE, 5200),
+    (0x33D1, 5161),
+    (0x33D2, 5160),
+    (0x33D5, 5196),
+    (0x347A, 19037),
+    (0x3561, 19202),
+    (0x35A1, 19212),
+    (0x3623, 19184),
+    (0x3639, 19349),
+    (0x3649, 19005),
+    (0x36B1, 19191),
+    (0x36C3, 19215),
+    (0x36C5, 19661),
+    (0x36E5, 19532),
+    (0x36E6, 19238),
+    (0x3708, 19241),
+    (0x370D, 19566),
+    (0x3730, 19681),
+    (0x3732, 19249),
+    (0x3740, 19512),
+    (0x3743, 19040),
+    (0x37F8, 19724),
+    (0x382D, 19290),
+    (0x3836, 19295),
+    (0x38A0, 19313),
+    (0x38CC, 19104),
+    (0x3932, 19379),
+    (0x3963, 19347),
+    (0x3A01, 19478),
+    (0x3A17, 19393),
+    (0x3A57, 19409),
+    (0x3A66, 19407),
+    (0x3AAB, 19131),
+    (0x3ADE, 19320),
+    (0x3B2B, 19451),
+    (0x3B80, 19480),
+    (0x3BA1, 19487),
+    (0x3BC2, 19296),
+    (0x3BF3, 19497),
+    (0x3C0D, 19501),
+    (0x3CDC, 19064),
+    (0x3CEB, 19533),
+    (0x3D32, 19477),
+    (0x3D3B, 19579),
+    (0x3D4E, 19576),
+    (0x3D5F, 19336),
+    (0x3D85, 19247),
+    (0x3DB4, 19152),
+    (0x3DC7, 19348),
+    (0x3DE8, 19603),
+    (0x3E40, 19353),
+    (0x3E55, 19638),
+    (0x3EA8, 19778),
+    (0x3EAA, 19103),
+    (0x3ED7, 19671),
+    (0x3F04, 19709),
+    (0x3F59, 19123),
+    (0x4004, 19257),
+    (0x40D8, 19422),
+    (0x4104, 19602),
+    (0x42B5, 19002),
+    (0x42FC, 19484),
+    (0x4491, 11209),
+    (0x44A2, 19107),
+    (0x44C5, 19526),
+    (0x44E9, 19460),
+    (0x4552, 19165),
+    (0x4555, 19572),
+    (0x47A6, 19149),
+    (0x485D, 19258),
+    (0x48AE, 19321),
+    (0x48DD, 19276),
+    (0x48ED, 19647),
+    (0x4953, 19686),
+    (0x4B37, 19420),
+    (0x4CED, 19157),
+    (0x4D10, 19267),
+    (0x4E00, 5495),
+    (0x4E01, 5497),
+    (0x4E03, 5498),
+    (0x4E07, 11309),
+    (0x4E08, 5517),
+    (0x4E09, 5515),
+    (0x4E0A, 5518),
+    (0x4E0B, 5516),
+    (0x4E0C, 11310),
+    (0x4E0D, 5560),
+    (0x4E0E, 11319),
+    (0x4E0F, 11317),
+    (0x4E10, 5559),
+    (0x4E11, 5558),
+    (0x4E14, 5654),
+    (0x4E15, 5653),
+    (0x4E16, 5652),
+    (0x4E18, 5655),
+    (0x4E19, 5651),
+    (0x4E1E, 5778),
+    (0x4E1F, 5779),
+    (0x4E26, 6220),
+    (0x4E2B, 5519),
+    (0x4E2D, 5561),
+    (0x4E2E, 11320),
+    (0x4E30, 5562),
+    (0x4E31, 11339),
+    (0x4E32, 5945),
+    (0x4E33, 11697),
+    (0x4E36, 10926),
+    (0x4E38, 5520),
+    (0x4E39, 5563),
+    (0x4E3B, 5656),
+    (0x4E3C, 11340),
+    (0x4E3F, 10927),
+    (0x4E42, 11304),
+    (0x4E43, 5499),
+    (0x4E45, 5522),
+    (0x4E47, 11311),
+    (0x4E48, 5523),
+    (0x4E49, 19128),
+    (0x4E4B, 5564),
+    (0x4E4D, 5657),
+    (0x4E4E, 5659),
+    (0x4E4F, 5658),
+    (0x4E52, 5780),
+    (0x4E53, 5781),
+    (0x4E56, 6221),
+    (0x4E58, 6998),
+    (0x4E59, 5496),
+    (0x4E5A, 11206),
+    (0x4E5C, 11305),
+    (0x4E5D, 5500),
+    (0x4E5E, 5525),
+    (0x4E5F, 5524),
+    (0x4E69, 5782),
+    (0x4E73, 6222),
+    (0x4E7E, 7455),
+    (0x4E7F, 13042),
+    (0x4E82, 8418),
+    (0x4E83, 14366),
+    (0x4E84, 14367),
+    (0x4E85, 10928),
+    (0x4E86, 5501),
+    (0x4E88, 5566),
+    (0x4E8B, 6223),
+    (0x4E8C, 5502),
+    (0x4E8D, 11312),
+    (0x4E8E, 5526),
+    (0x4E91, 5567),
+    (0x4E92, 5569),
+    (0x4E93, 11321),
+    (0x4E94, 5570),
+    (0x4E95, 5568),
+    (0x4E99, 5783),
+    (0x4E9B, 6224),
+    (0x4E9E, 6225),
+    (0x4E9F, 6599),
+    (0x4EA0, 10929),
+    (0x4EA1, 5527),
+    (0x4EA2, 5571),
+    (0x4EA4, 5784),
+    (0x4EA5, 5786),
+    (0x4EA6, 5785),
+    (0x4EA8, 5946),
+    (0x4EAB, 6226),
+    (0x4EAC, 6227),
+    (0x4EAD, 6600),
+    (0x4EAE, 6601),
+    (0x4EB3, 6999),
+    (0x4EB6, 14368),
+    (0x4EB7, 19304),
+    (0x4EB9, 18485),
+    (0x4EBA, 5503),
+    (0x4EC0, 5573),
+    (0x4EC1, 5572),
+    (0x4EC2, 11322),
+    (0x4EC3, 5574),
+    (0x4EC4, 5580),
+    (0x4EC6, 5575),
+    (0x4EC7, 5576),
+    (0x4EC8, 11324),
+    (0x4EC9, 11323),
+    (0x4ECA, 5578),
+    (0x4ECB, 5579),
+    (0x4ECD, 5577),
+    (0x4ED4, 5662),
+    (0x4ED5, 5663),
+    (0x4ED6, 5664),
+    (0x4ED7, 5665),
+    (0x4ED8, 5661),
+    (0x4ED9, 5668),
+    (0x4EDA, 11346),
+    (0x4EDC, 11342),
+    (0x4EDD, 10958),
+    (0x4EDE, 5669),
+    (0x4EDF, 5685),
+    (0x4EE1, 11344),
+    (0x4EE3, 5666),
+    (0x4EE4, 5667),
+    (0x4EE5, 5660),
+    (0x4EE8, 11341),
+    (0x4EE9, 11343),
+    (0x4EF0, 5799),
+    (0x4EF1, 11382),
+    (0x4EF2, 5796),
+    (0x4EF3, 5800),
+    (0x4EF4, 11392),
+    (0x4EF5, 11380),
+    (0x4EF6, 5797),
+    (0x4EF7, 11384),
+    (0x4EFB, 5798),
+    (0x4EFD, 5801),
+    (0x4EFF, 5787),
+    (0x4F00, 11383),
+    (0x4F01, 5802),
+    (0x4F02, 11387),
+    (0x4F04, 11391),
+    (0x4F05, 11388),
+    (0x4F08, 11385),
+    (0x4F09, 5788),
+    (0x4F0A, 5790),
+    (0x4F0B, 5803),
+    (0x4F0D, 5792),
+    (0x4F0E, 11377),
+    (0x4F0F, 5795),
+    (0x4F10, 5793),
+    (0x4F11, 5794),
+    (0x4F12, 11393),
+    (0x4F13, 11390),
+    (0x4F14, 11381),
+    (0x4F15, 5791),
+    (0x4F18, 11378),
+    (0x4F19, 5789),
+    (0x4F1D, 11386),
+    (0x4F22, 11389),
+    (0x4F2C, 11379),
+    (0x4F2D, 11499),
+    (0x4F2F, 5968),
+    (0x4F30, 5955),
+    (0x4F33, 11500),
+    (0x4F34, 5952),
+    (0x4F36, 5970),
+    (0x4F38, 5960),
+    (0x4F3A, 5959),
+    (0x4F3B, 11488),
+    (0x4F3C, 5963),
+    (0x4F3D, 5958),
+    (0x4F3E, 11493),
+    (0x4F3F, 11501),
+    (0x4F41, 11497),
+    (0x4F43, 5961),
+    (0x4F46, 5964),
+    (0x4F47, 5949),
+    (0x4F48, 5973),
+    (0x4F49, 11490),
+    (0x4F4C, 11709),
+    (0x4F4D, 5947),
+    (0x4F4E, 5969),
+    (0x4F4F, 5948),
+    (0x4F50, 5956),
+    (0x4F51, 5957),
+    (0x4F52, 11495),
+    (0x4F53, 11491),
+    (0x4F54, 5962),
+    (0x4F55, 5954),
+    (0x4F56, 11487),
+    (0x4F57, 5950),
+    (0x4F58, 11498),
+    (0x4F59, 5971),
+    (0x4F5A, 5974),
+    (0x4F5B, 5953),
+    (0x4F5C, 5966),
+    (0x4F5D, 5972),
+    (0x4F5E, 5951),
+    (0x4F5F, 11496),
+    (0x4F60, 5967),
+    (0x4F61, 11502),
+    (0x4F62, 11489),
+    (0x4F63, 5965),
+    (0x4F64, 11492),
+    (0x4F67, 11494),
+    (0x4F69, 6241),
+    (0x4F6A, 11711),
+    (0x4F6B, 11723),
+    (0x4F6C, 6233),
+    (0x4F6E, 11724),
+    (0x4F6F, 6228),
+    (0x4F70, 6238),
+    (0x4F73, 6231),
+    (0x4F74, 11705),
+    (0x4F75, 6239),
+    (0x4F76, 11704),
+    (0x4F77, 11708),
+    (0x4F78, 11715),
+    (0x4F79, 11713),
+    (0x4F7A, 6247),
+    (0x4F7B, 6242),
+    (0x4F7C, 11699),
+    (0x4F7D, 11701),
+    (0x4F7E, 6244),
+    (0x4F7F, 6232),
+    (0x4F80, 11702),
+    (0x4F81, 11714),
+    (0x4F82, 11721),
+    (0x4F83, 6237),
+    (0x4F84, 11707),
+    (0x4F85, 11700),
+    (0x4F86, 6236),
+    (0x4F87, 11703),
+    (0x4F88, 6240),
+    (0x4F89, 11706),
+    (0x4F8B, 6235),
+    (0x4F8D, 6230),
+    (0x4F8F, 6245),
+    (0x4F90, 11716),
+    (0x4F91, 6246),
+    (0x4F92, 11720),
+    (0x4F94, 11718),
+    (0x4F95, 11722),
+    (0x4F96, 6243),
+    (0x4F97, 11710),
+    (0x4F98, 11698),
+    (0x4F9A, 11712),
+    (0x4F9B, 6234),
+    (0x4F9C, 11717),
+    (0x4F9D, 6229),
+    (0x4F9E, 11719),
+    (0x4FAB, 19020),
+    (0x4FAE, 6616),
+    (0x4FAF, 6604),
+    (0x4FB2, 12058),
+    (0x4FB3, 12066),
+    (0x4FB5, 6603),
+    (0x4FB6, 6611),
+    (0x4FB7, 6623),
+    (0x4FB9, 12072),
+    (0x4FBA, 12070),
+    (0x4FBB, 12065),
+    (0x4FBF, 6605),
+    (0x4FC0, 12071),
+    (0x4FC1, 12061),
+    (0x4FC2, 6619),
+    (0x4FC3, 6610),
+    (0x4FC4, 6618),
+    (0x4FC5, 12056),
+    (0x4FC7, 12068),
+    (0x4FC9, 12059),
+    (0x4FCA, 6614),
+    (0x4FCB, 12060),
+    (0x4FCD, 12055),
+    (0x4FCE, 6621),
+    (0x4FCF, 6608),
+    (0x4FD0, 6617),
+    (0x4FD1, 6607),
+    (0x4FD3, 12057),
+    (0x4FD4, 12062),
+    (0x4FD6, 12069),
+    (0x4FD7, 6615),
+    (0x4FD8, 6612),
+    (0x4FD9, 12064),
+    (0x4FDA, 6620),
+    (0x4FDB, 12067),
+    (0x4FDC, 12063),
+    (0x4FDD, 6609),
+    (0x4FDE, 6622),
+    (0x4FDF, 6613),
+    (0x4FE0, 6606),
+    (0x4FE1, 6602),
+    (0x4FEC, 12073),
+    (0x4FEE, 7025),
+    (0x4FEF, 7003),
+    (0x4FF1, 7019),
+    (0x4FF3, 7024),
+    (0x4FF4, 12513),
+    (0x4FF5, 12512),
+    (0x4FF6, 12517),
+    (0x4FF7, 12518),
+    (0x4FF8, 7006),
+    (0x4FFA, 7015),
+    (0x4FFE, 7028),
+    (0x5000, 7016),
+    (0x5005, 12506),
+    (0x5006, 7009),
+    (0x5007, 12507),
+    (0x5008, 19022),
+    (0x5009, 7030),
+    (0x500B, 7021),
+    (0x500C, 7000),
+    (0x500D, 7001),
+    (0x500E, 12526),
+    (0x500F, 7472),
+    (0x5011, 7014),
+    (0x5012, 7013),
+    (0x5013, 12508),
+    (0x5014, 7017),
+    (0x5015, 13058),
+    (0x5016, 7008),
+    (0x5017, 12519),
+    (0x5018, 7023),
+    (0x5019, 7022),
+    (0x501A, 7012),
+    (0x501B, 12511),
+    (0x501C, 12520),
+    (0x501E, 12505),
+    (0x501F, 7011),
+    (0x5020, 12521),
+    (0x5021, 7020),
+    (0x5022, 12509),
+    (0x5023, 7002),
+    (0x5025, 7005),
+    (0x5026, 7004),
+    (0x5027, 12522),
+    (0x5028, 7018),
+    (0x5029, 7007),
+    (0x502A, 7027),
+    (0x502B, 7029),
+    (0x502C, 12516),
+    (0x502D, 7026),
+    (0x502F, 12524),
+    (0x5030, 12510),
+    (0x5031, 12525),
+    (0x5033, 12514),
+    (0x5035, 12523),
+    (0x5037, 12515),
+    (0x503C, 7010),
+    (0x5040, 13066),
+    (0x5041, 13054),
+    (0x5043, 7460),
+    (0x5045, 13059),
+    (0x5046, 13065),
+    (0x5047, 7459),
+    (0x5048, 13052),
+    (0x5049, 7463),
+    (0x504A, 13056),
+    (0x504B, 13049),
+    (0x504C, 7461),
+    (0x504D, 13053),
+    (0x504E, 7466),
+    (0x504F, 7471),
+    (0x5051, 13070),
+    (0x5053, 13048),
+    (0x5055, 7467),
+    (0x5057, 13069),
+    (0x505A, 7462),
+    (0x505B, 13055),
+    (0x505C, 7458),
+    (0x505D, 13050),
+    (0x505E, 13046),
+    (0x505F, 13060),
+    (0x5060, 13047),
+    (0x5061, 13045),
+    (0x5062, 13057),
+    (0x5063, 13063),
+    (0x5064, 13064),
+    (0x5065, 7464),
+    (0x5068, 13686),
+    (0x5069, 13061),
+    (0x506A, 13044),
+    (0x506B, 13062),
+    (0x506D, 7474),
+    (0x506E, 13067),
+    (0x506F, 7473),
+    (0x5070, 13043),
+    (0x5072, 13051),
+    (0x5073, 13068),
+    (0x5074, 7469),
+    (0x5075, 7468),
+    (0x5076, 7465),
+    (0x5077, 7470),
+    (0x507A, 7456),
+    (0x507D, 7457),
+    (0x5080, 7952),
+    (0x5082, 13689),
+    (0x5083, 13682),
+    (0x5085, 7949),
+    (0x5087, 13690),
+    (0x508B, 13680),
+    (0x508C, 13683),
+    (0x508D, 7948),
+    (0x508E, 13684),
+    (0x5090, 19490),
+    (0x5091, 7951),
+    (0x5092, 13688),
+    (0x5094, 13678),
+    (0x5095, 13677),
+    (0x5096, 7953),
+    (0x5098, 7954),
+    (0x5099, 7950),
+    (0x509A, 7955),
+    (0x509B, 13676),
+    (0x509C, 13687),
+    (0x509D, 13685),
+    (0x509E, 13679),
+    (0x50A2, 7947),
+    (0x50A3, 13681),
+    (0x50AC, 8425),
+    (0x50AD, 8419),
+    (0x50AE, 14372),
+    (0x50AF, 8428),
+    (0x50B0, 14378),
+    (0x50B1, 14381),
+    (0x50B2, 8421),
+    (0x50B3, 8422),
+    (0x50B4, 14375),
+    (0x50B5, 8420),
+    (0x50B6, 14384),
+    (0x50B7, 8426),
+    (0x50B8, 14385),
+    (0x50BA, 14380),
+    (0x50BB, 8427),
+    (0x50BD, 14369),
+    (0x50BE, 8424),
+    (0x50BF, 14370),
+    (0x50C1, 14379),
+    (0x50C2, 14377),
+    (0x50C4, 14373),
+    (0x50C5, 8423),
+    (0x50C6, 14371),
+    (0x50C7, 8429),
+    (0x50C8, 14376),
+    (0x50C9, 14383),
+    (0x50CA, 14374),
+    (0x50CB, 14382),
+    (0x50CE, 8876),
+    (0x50CF, 8873),
+    (0x50D1, 8874),
+    (0x50D3, 15066),
+    (0x50D4, 15058),
+    (0x50D5, 8872),
+    (0x50D6, 8869),
+    (0x50D7, 15059),
+    (0x50DA, 8871),
+    (0x50DB, 15062),
+    (0x50DD, 15064),
+    (0x50E0, 15071),
+    (0x50E1, 19361),
+    (0x50E3, 15070),
+    (0x50E4, 15065),
+    (0x50E5, 8868),
+    (0x50E6, 15057),
+    (0x50E7, 8866),
+    (0x50E8, 15060),
+    (0x50E9, 8877),
+    (0x50EA, 15063),
+    (0x50EC, 15067),
+    (0x50ED, 8870),
+    (0x50EE, 8867),
+    (0x50EF, 15069),
+    (0x50F0, 15068),
+    (0x50F1, 8875),
+    (0x50F3, 15061),
+    (0x50F5, 9256),
+    (0x50F6, 15681),
+    (0x50F8, 15678),
+    (0x50F9, 9257),
+    (0x50FB, 9255),
+    (0x50FD, 15685),
+    (0x50FE, 15682),
+    (0x50FF, 15675),
+    (0x5100, 9254),
+    (0x5101, 19036),
+    (0x5102, 9258),
+    (0x5103, 15676),
+    (0x5104, 9253),
+    (0x5105, 9261),
+    (0x5106, 15679),
+    (0x5107, 15680),
+    (0x5108, 9259),
+    (0x5109, 9260),
+    (0x510A, 15686),
+    (0x510B, 15683),
+    (0x510C, 15684),
+    (0x510E, 19038),
+    (0x5110, 9649),
+    (0x5111, 16321),
+    (0x5112, 9646),
+    (0x5113, 16318),
+    (0x5114, 9648),
+    (0x5115, 9650),
+    (0x5117, 16319),
+    (0x5118, 9647),
+    (0x511A, 16320),
+    (0x511C, 16317),
+    (0x511F, 9945),
+    (0x5120, 16890),
+    (0x5121, 9946),
+    (0x5122, 16888),
+    (0x5124, 16889),
+    (0x5125, 16887),
+    (0x5126, 16886),
+    (0x5129, 16891),
+    (0x512A, 9944),
+    (0x512D, 17356),
+    (0x512E, 17357),
+    (0x5130, 15677),
+    (0x5131, 17355),
+    (0x5132, 9947),
+    (0x5133, 10393),
+    (0x5134, 17721),
+    (0x5135, 17722),
+    (0x5137, 10636),
+    (0x5138, 10637),
+    (0x5139, 18307),
+    (0x513A, 18306),
+    (0x513B, 10720),
+    (0x513C, 10719),
+    (0x513D, 18636),
+    (0x513F, 5504),
+    (0x5140, 5528),
+    (0x5141, 5582),
+    (0x5143, 5581),
+    (0x5144, 5671),
+    (0x5145, 5670),
+    (0x5146, 5806),
+    (0x5147, 5805),
+    (0x5148, 5807),
+    (0x5149, 5804),
+    (0x514B, 5976),
+    (0x514C, 5975),
+    (0x514D, 5977),
+    (0x5152, 6249),
+    (0x5154, 6248),
+    (0x5155, 6250),
+    (0x5157, 6624),
+    (0x5159, 5206),
+    (0x515A, 12527),
+    (0x515B, 5207),
+    (0x515C, 7475),
+    (0x515D, 5209),
+    (0x515E, 5208),
+    (0x515F, 13691),
+    (0x5160, 19044),
+    (0x5161, 5210),
+    (0x5162, 8878),
+    (0x5163, 5211),
+    (0x5165, 5505),
+    (0x5167, 5583),
+    (0x5168, 5808),
+    (0x5169, 6251),
+    (0x516A, 19046),
+    (0x516B, 5506),
+    (0x516C, 5586),
+    (0x516D, 5584),
+    (0x516E, 5585),
+    (0x5171, 5809),
+    (0x5175, 5978),
+    (0x5176, 6253),
+    (0x5177, 6252),
+    (0x5178, 6254),
+    (0x517C, 7031),
+    (0x5180, 9651),
+    (0x5182, 10930),
+    (0x5187, 11318),
+    (0x5188, 11211),
+    (0x5189, 5672),
+    (0x518A, 5673),
+    (0x518D, 5810),
+    (0x518F, 11503),
+    (0x5191, 6626),
+    (0x5192, 6625),
+    (0x5193, 12529),
+    (0x5194, 12528),
+    (0x5195, 7476),
+    (0x5196, 10931),
+    (0x5197, 5587),
+    (0x5198, 11325),
+    (0x519E, 11725),
+    (0x51A0, 6627),
+    (0x51A2, 7034),
+    (0x51A4, 7032),
+    (0x51A5, 7033),
+    (0x51AA, 9652),
+    (0x51AB, 10932),
+    (0x51AC, 5674),
+    (0x51B0, 5811),
+    (0x51B1, 11394),
+    (0x51B2, 19058),
+    (0x51B3, 19067),
+    (0x51B5, 19004),
+    (0x51B6, 5979),
+    (0x51B7, 5980),
+    (0x51B8, 19059),
+    (0x51B9, 11504),
+    (0x51BC, 11726),
+    (0x51BD, 6255),
+    (0x51BE, 11727),
+    (0x51C4, 12531),
+    (0x51C5, 12532),
+    (0x51C6, 7037),
+    (0x51C7, 19552),
+    (0x51C8, 12533),
+    (0x51C9, 19061),
+    (0x51CA, 12530),
+    (0x51CB, 7038),
+    (0x51CC, 7036),
+    (0x51CD, 7035),
+    (0x51CE, 12534),
+    (0x51CF, 19062),
+    (0x51D0, 13071),
+    (0x51D1, 19063),
+    (0x51D3, 19065),
+    (0x51D4, 13692),
+    (0x51D7, 14386),
+    (0x51D8, 15072),
+    (0x51DC, 9262),
+    (0x51DD, 9653),
+    (0x51DE, 16322),
+    (0x51DF, 19589),
+    (0x51E0, 5507),
+    (0x51E1, 5521),
+    (0x51E2, 19068),
+    (0x51ED, 19070),
+    (0x51F0, 7477),
+    (0x51F1, 7957),
+    (0x51F3, 8879),
+    (0x51F4, 19369),
+    (0x51F5, 11306),
+    (0x51F6, 5588),
+    (0x51F8, 5677),
+    (0x51F9, 5675),
+    (0x51FA, 5676),
+    (0x51FD, 6256),
+    (0x5200, 5508),
+    (0x5201, 5509),
+    (0x5202, 11208),
+    (0x5203, 5529),
+    (0x5206, 5589),
+    (0x5207, 5590),
+    (0x5208, 5591),
+    (0x5209, 11396),
+    (0x520A, 5678),
+    (0x520B, 19075),
+    (0x520C, 11347),
+    (0x520E, 5815),
+    (0x5210, 11397),
+    (0x5211, 5813),
+    (0x5212, 5814),
+    (0x5213, 11395),
+    (0x5216, 5816),
+    (0x5217, 5812),
+    (0x521C, 11505),
+    (0x521D, 6575),
+    (0x521E, 11506),
+    (0x5221, 11507),
+    (0x5224, 5982),
+    (0x5225, 5981),
+    (0x5226, 19076),
+    (0x5228, 5985),
+    (0x5229, 5983),
+    (0x522A, 5984),
+    (0x522E, 6262),
+    (0x5230, 6261),
+    (0x5231, 11732),
+    (0x5232, 11729),
+    (0x5233, 11730),
+    (0x5235, 11728),
+    (0x5236, 6263),
+    (0x5237, 6259),
+    (0x5238, 6258),
+    (0x523A, 6260),
+    (0x523B, 6257),
+    (0x523C, 19077),
+    (0x5241, 6264),
+    (0x5243, 6629),
+    (0x5244, 12074),
+    (0x5246, 11731),
+    (0x5247, 6634),
+    (0x5249, 12075),
+    (0x524A, 6630),
+    (0x524B, 6633),
+    (0x524C, 6632),
+    (0x524D, 6631),
+    (0x524E, 6628),
+    (0x5252, 12537),
+    (0x5254, 7041),
+    (0x5255, 12540),
+    (0x5256, 7039),
+    (0x5257, 19079),
+    (0x525A, 12536),
+    (0x525B, 7042),
+    (0x525C, 7040),
+    (0x525D, 7043),
+    (0x525E, 12538),
+    (0x525F, 12539),
+    (0x5261, 12535),
+    (0x5262, 12541),
+    (0x5269, 7961),
+    (0x526A, 7478),
+    (0x526B, 13072),
+    (0x526C, 13074),
+    (0x526D, 13073),
+    (0x526E, 13075),
+    (0x526F, 7479),
+    (0x5272, 7958),
+    (0x5274, 7959),
+    (0x5275, 7960),
+    (0x5277, 8431),
+    (0x5278, 14388),
+    (0x527A, 14387),
+    (0x527B, 14389),
+    (0x527C, 14390),
+    (0x527D, 8432),
+    (0x527F, 8430),
+    (0x5280, 15073),
+    (0x5281, 15074),
+    (0x5282, 8881),
+    (0x5283, 8880),
+    (0x5284, 15412),
+    (0x5287, 9263),
+    (0x5288, 9264),
+    (0x5289, 9265),
+    (0x528A, 9267),
+    (0x528B, 15687),
+    (0x528C, 15688),
+    (0x528D, 9266),
+    (0x5291, 9654),
+    (0x5293, 9655),
+    (0x5294, 19080),
+    (0x5296, 17723),
+    (0x5297, 18309),
+    (0x5298, 18308),
+    (0x5299, 18637),
+    (0x529B, 5510),
+    (0x529F, 5680),
+    (0x52A0, 5679),
+    (0x52A3, 5817),
+    (0x52A6, 11398),
+    (0x52A9, 5987),
+    (0x52AA, 5988),
+    (0x52AB, 5986),
+    (0x52AC, 5989),
+    (0x52AD, 11508),
+    (0x52AE, 11509),
+    (0x52B5, 19078),
+    (0x52B9, 19081),
+    (0x52BB, 6266),
+    (0x52BC, 11733),
+    (0x52BE, 6265),
+    (0x52C0, 12076),
+    (0x52C1, 6638),
+    (0x52C2, 12077),
+    (0x52C3, 6637),
+    (0x52C5, 19082),
+    (0x52C7, 6635),
+    (0x52C9, 6636),
+    (0x52CD, 12542),
+    (0x52D0, 19252),
+    (0x52D2, 7480),
+    (0x52D3, 13077),
+    (0x52D5, 7483),
+    (0x52D6, 13076),
+    (0x52D7, 7667),
+    (0x52D8, 7482),
+    (0x52D9, 7481),
+    (0x52DB, 7964),
+    (0x52DD, 7963),
+    (0x52DE, 7962),
+    (0x52DF, 8433),
+    (0x52E0, 19085),
+    (0x52E2, 8436),
+    (0x52E3, 8437),
+    (0x52E4, 8435),
+    (0x52E6, 8434),
+    (0x52E9, 15075),
+    (0x52EB, 15076),
+    (0x52EF, 15690),
+    (0x52F0, 9268),
+    (0x52F1, 15689),
+    (0x52F3, 9656),
+    (0x52F4, 16892),
+    (0x52F5, 9948),
+    (0x52F7, 17724),
+    (0x52F8, 10540),
+    (0x52F9, 10933),
+    (0x52FA, 5530),
+    (0x52FB, 5592),
+    (0x52FC, 11326),
+    (0x52FE, 5593),
+    (0x52FF, 5594),
+    (0x5305, 5681),
+    (0x5306, 5682),
+    (0x5308, 5818),
+    (0x5309, 11510),
+    (0x530A, 11734),
+    (0x530B, 11735),
+    (0x530D, 6639),
+    (0x530E, 12543),
+    (0x530F, 7485),
+    (0x5310, 7484),
+    (0x5311, 13694),
+    (0x5312, 13693),
+    (0x5315, 5511),
+    (0x5316, 5595),
+    (0x5317, 5683),
+    (0x5319, 7486),
+    (0x531A, 11307),
+    (0x531C, 11348),
+    (0x531D, 5684),
+    (0x531F, 11400),
+    (0x5320, 5820),
+    (0x5321, 5819),
+    (0x5322, 11399),
+    (0x5323, 5990),
+    (0x532A, 7044),
+    (0x532D, 13078),
+    (0x532F, 8438),
+    (0x5330, 15077),
+    (0x5331, 8882),
+    (0x5333, 19095),
+    (0x5334, 16323),
+    (0x5337, 18053),
+    (0x5338, 10934),
+    (0x5339, 5596),
+    (0x533C, 11736),
+    (0x533D, 12078),
+    (0x533E, 7489),
+    (0x533F, 7487),
+    (0x5340, 7488),
+    (0x5341, 5512),
+    (0x5342, 19069),
+    (0x5343, 5531),
+    (0x5344, 5288),
+    (0x5345, 5599),
+    (0x5347, 5598),
+    (0x5348, 5597),
+    (0x5349, 5687),
+    (0x534A, 5686),
+    (0x534C, 11349),
+    (0x534D, 11401),
+    (0x5351, 6270),
+    (0x5352, 6267),
+    (0x5353, 6269),
+    (0x5354, 6268),
+    (0x5357, 6640),
+    (0x535A, 7965),
+    (0x535C, 5513),
+    (0x535E, 5600),
+    (0x5360, 5689),
+    (0x5361, 5688),
+    (0x5363, 11511),
+    (0x5366, 6271),
+    (0x5369, 10935),
+    (0x536C, 11327),
+    (0x536D, 19108),
+    (0x536E, 5691),
+    (0x536F, 5690),
+    (0x5370, 5821),
+    (0x5371, 5822),
+    (0x5372, 11512),
+    (0x5373, 5991),
+    (0x5374, 19109),
+    (0x5375, 5992),
+    (0x5377, 6272),
+    (0x5378, 6273),
+    (0x5379, 6274),
+    (0x537B, 6641),
+    (0x537C, 12079),
+    (0x537E, 19111),
+    (0x537F, 7045),
+    (0x5382, 11308),
+    (0x5384, 5601),
+    (0x538A, 11402),
+    (0x538E, 11513),
+    (0x538F, 11514),
+    (0x5392, 11737),
+    (0x5393, 19116),
+    (0x5394, 11738),
+    (0x5396, 12081),
+    (0x5397, 12080),
+    (0x5398, 12083),
+    (0x5399, 12082),
+    (0x539A, 6642),
+    (0x539C, 13079),
+    (0x539D, 7047),
+    (0x539E, 12544),
+    (0x539F, 7046),
+    (0x53A0, 19118),
+    (0x53A4, 13695),
+    (0x53A5, 7966),
+    (0x53A6, 19303),
+    (0x53A7, 13696),
+    (0x53A8, 19306),
+    (0x53AB, 19119),
+    (0x53AC, 15078),
+    (0x53AD, 8883),
+    (0x53AE, 19120),
+    (0x53B2, 9269),
+    (0x53B4, 17725),
+    (0x53B6, 10936),
+    (0x53B9, 11328),
+    (0x53BB, 5692),
+    (0x53C1, 19125),
+    (0x53C3, 7490),
+    (0x53C5, 19126),
+    (0x53C8, 5514),
+    (0x53C9, 5532),
+    (0x53CA, 5603),
+    (0x53CB, 5602),
+    (0x53CD, 5604),
+    (0x53D4, 6276),
+    (0x53D6, 6275),
+    (0x53D7, 6277),
+    (0x53D9, 19130),
+    (0x53DB, 6643),
+    (0x53DF, 7048),
+    (0x53E0, 19133),
+    (0x53E1, 16324),
+    (0x53E2, 10220),
+    (0x53E3, 5533),
+    (0x53E4, 5694),
+    (0x53E5, 5709),
+    (0x53E6, 5704),
+    (0x53E8, 5699),
+    (0x53E9, 5698),
+    (0x53EA, 5705),
+    (0x53EB, 5703),
+    (0x53EC, 5696),
+    (0x53ED, 5710),
+    (0x53EE, 5697),
+    (0x53EF, 5693),
+    (0x53F0, 5708),
+    (0x53F1, 5707),
+    (0x53F2, 5706),
+    (0x53F3, 5695),
+    (0x53F5, 5702),
+    (0x53F6, 19136),
+    (0x53F8, 5701),
+    (0x53FB, 5711),
+    (0x53FC, 5700),
+    (0x53FE, 19179),
+    (0x5401, 5828),
+    (0x5403, 5834),
+    (0x5404, 5830),
+    (0x5406, 5836),
+    (0x5407, 11403),
+    (0x5408, 5833),
+    (0x5409, 5823),
+    (0x540A, 5826),
+    (0x540B, 5829),
+    (0x540C, 5825),
+    (0x540D, 5832),
+    (0x540E, 5835),
+    (0x540F, 5824),
+    (0x5410, 5827),
+    (0x5411, 5831),
+    (0x5412, 5837),
+    (0x5413, 19138),
+    (0x5418, 11523),
+    (0x5419, 11520),
+    (0x541B, 6005),
+    (0x541C, 11521),
+    (0x541D, 5993),
+    (0x541E, 5995),
+    (0x541F, 6019),
+    (0x5420, 6014),
+    (0x5421, 19513),
+    (0x5424, 11528),
+    (0x5425, 11522),
+    (0x5426, 5997),
+    (0x5427, 5999),
+    (0x5428, 11527),
+    (0x5429, 6006),
+    (0x542A, 11517),
+    (0x542B, 6018),
+    (0x542C, 6020),
+    (0x542D, 5994),
+    (0x542E, 6011),
+    (0x542F, 19158),
+    (0x5430, 11515),
+    (0x5431, 6017),
+    (0x5433, 6002),
+    (0x5435, 6012),
+    (0x5436, 6013),
+    (0x5437, 11516),
+    (0x5438, 6010),
+    (0x5439, 6008),
+    (0x543B, 6009),
+    (0x543C, 6015),
+    (0x543D, 11524),
+    (0x543E, 5996),
+    (0x5440, 6016),
+    (0x5441, 11526),
+    (0x5442, 6004),
+    (0x5443, 6001),
+    (0x5445, 11519),
+    (0x5446, 6000),
+    (0x5447, 11529),
+    (0x5448, 6003),
+    (0x544A, 6007),
+    (0x544E, 5998),
+    (0x544F, 11525),
+    (0x5454, 11518),
+    (0x5460, 11755),
+    (0x5461, 11754),
+    (0x5462, 6295),
+    (0x5463, 11757),
+    (0x5464, 11759),
+    (0x5465, 11748),
+    (0x5466, 11751),
+    (0x5467, 11758),
+    (0x5468, 6296),
+    (0x546B, 11745),
+    (0x546C, 11749),
+    (0x546D, 19143),
+    (0x546F, 11753),
+    (0x5470, 12098),
+    (0x5471, 6291),
+    (0x5472, 12102),
+    (0x5473, 6278),
+    (0x5474, 11750),
+    (0x5475, 6279),
+    (0x5476, 6292),
+    (0x5477, 6285),
+    (0x5478, 6281),
+    (0x547A, 11746),
+    (0x547B, 6284),
+    (0x547C, 6289),
+    (0x547D, 6298),
+    (0x547E, 11747),
+    (0x547F, 11740),
+    (0x5480, 6283),
+    (0x5481, 11741),
+    (0x5482, 11743),
+    (0x5484, 6286),
+    (0x5486, 6288),
+    (0x5487, 11739),
+    (0x5488, 11744),
+    (0x548B, 6297),
+    (0x548C, 6293),
+    (0x548D, 11752),
+    (0x548E, 6299),
+    (0x548F, 19147),
+    (0x5490, 6290),
+    (0x5491, 11742),
+    (0x5492, 6287),
+    (0x5495, 6282),
+    (0x5496, 6280),
+    (0x5498, 11756),
+    (0x549A, 6294),
+    (0x54A0, 12097),
+    (0x54A1, 12085),
+    (0x54A2, 12100),
+    (0x54A4, 19148),
+    (0x54A5, 12087),
+    (0x54A6, 6650),
+    (0x54A7, 6664),
+    (0x54A8, 6646),
+    (0x54A9, 6663),
+    (0x54AA, 6655),
+    (0x54AB, 6660),
+    (0x54AC, 6644),
+    (0x54AD, 12086),
+    (0x54AE, 12092),
+    (0x54AF, 6659),
+    (0x54B0, 12104),
+    (0x54B1, 6661),
+    (0x54B3, 6651),
+    (0x54B6, 12094),
+    (0x54B7, 12091),
+    (0x54B8, 6649),
+    (0x54BA, 12084),
+    (0x54BB, 6662),
+    (0x54BC, 12099),
+    (0x54BD, 6654),
+    (0x54BE, 12101),
+    (0x54BF, 6665),
+    (0x54C0, 6645),
+    (0x54C1, 6656),
+    (0x54C2, 6653),
+    (0x54C3, 12089),
+    (0x54C4, 6657),
+    (0x54C5, 12095),
+    (0x54C6, 12096),
+    (0x54C7, 6652),
+    (0x54C8, 6658),
+    (0x54C9, 6648),
+    (0x54CE, 6647),
+    (0x54CF, 12088),
+    (0x54D6, 12093),
+    (0x54DE, 12103),
+    (0x54E0, 12564),
+    (0x54E1, 7061),
+    (0x54E2, 12546),
+    (0x54E4, 12551),
+    (0x54E5, 7054),
+    (0x54E6, 7065),
+    (0x54E7, 12549),
+    (0x54E8, 7049),
+    (0x54E9, 7059),
+    (0x54EA, 7064),
+    (0x54EB, 12556),
+    (0x54ED, 7060),
+    (0x54EE, 7063),
+    (0x54F1, 12559),
+    (0x54F2, 7055),
+    (0x54F3, 12550),
+    (0x54F7, 12562),
+    (0x54F8, 12563),
+    (0x54FA, 7057),
+    (0x54FB, 12561),
+    (0x54FC, 7053),
+    (0x54FD, 7068),
+    (0x54FF, 12553),
+    (0x5501, 7051),
+    (0x5503, 12566),
+    (0x5504, 12554),
+    (0x5505, 12558),
+    (0x5506, 7056),
+    (0x5507, 7067),
+    (0x5508, 12555),
+    (0x5509, 7062),
+    (0x550A, 12560),
+    (0x550B, 12567),
+    (0x550C, 13094),
+    (0x550E, 12565),
+    (0x550F, 7069),
+    (0x5510, 7050),
+    (0x5511, 12557),
+    (0x5512, 12548),
+    (0x5514, 7058),
+    (0x5517, 12547),
+    (0x551A, 12552),
+    (0x5526, 12545),
+    (0x5527, 7066),
+    (0x552A, 13086),
+    (0x552B, 19140),
+    (0x552C, 7509),
+    (0x552D, 13100),
+    (0x552E, 7507),
+    (0x552F, 7504),
+    (0x5530, 13091),
+    (0x5531, 7500),
+    (0x5532, 13095),
+    (0x5533, 7511),
+    (0x5534, 13085),
+    (0x5535, 13090),
+    (0x5536, 13089),
+    (0x5537, 7052),
+    (0x5538, 7506),
+    (0x5539, 13098),
+    (0x553B, 13101),
+    (0x553C, 13082),
+    (0x553E, 7984),
+    (0x5540, 13102),
+    (0x5541, 7512),
+    (0x5543, 7498),
+    (0x5544, 7495),
+    (0x5545, 13093),
+    (0x5546, 7492),
+    (0x5547, 19156),
+    (0x5548, 13099),
+    (0x5549, 19090),
+    (0x554A, 7499),
+    (0x554B, 13103),
+    (0x554D, 13083),
+    (0x554E, 13097),
+    (0x554F, 7502),
+    (0x5550, 13084),
+    (0x5551, 13087),
+    (0x5552, 13092),
+    (0x5553, 19219),
+    (0x5555, 7503),
+    (0x5556, 7501),
+    (0x5557, 7513),
+    (0x555C, 7508),
+    (0x555D, 19146),
+    (0x555E, 7496),
+    (0x555F, 7648),
+    (0x5561, 7497),
+    (0x5562, 13088),
+    (0x5563, 7510),
+    (0x5564, 7505),
+    (0x5565, 13096),
+    (0x5566, 7494),
+    (0x556A, 7493),
+    (0x5575, 13080),
+    (0x5576, 13081),
+    (0x5577, 13701),
+    (0x557B, 7967),
+    (0x557C, 7970),
+    (0x557D, 13712),
+    (0x557E, 7990),
+    (0x557F, 13715),
+    (0x5580, 7968),
+    (0x5581, 13708),
+    (0x5582, 7974),
+    (0x5583, 7980),
+    (0x5584, 8255),
+    (0x5586, 19160),
+    (0x5587, 7978),
+    (0x5588, 13705),
+    (0x5589, 7991),
+    (0x558A, 7971),
+    (0x558B, 7979),
+    (0x558C, 13713),
+    (0x558D, 14404),
+    (0x558E, 13718),
+    (0x558F, 13706),
+    (0x5591, 13697),
+    (0x5592, 13710),
+    (0x5593, 13704),
+    (0x5594, 7977),
+    (0x5595, 13716),
+    (0x5598, 7973),
+    (0x5599, 7993),
+    (0x559A, 7986),
+    (0x559C, 7975),
+    (0x559D, 7972),
+    (0x559F, 7983),
+    (0x55A1, 13717),
+    (0x55A2, 13703),
+    (0x55A3, 13709),
+    (0x55A4, 13711),
+    (0x55A5, 13699),
+    (0x55A6, 13714),
+    (0x55A7, 7969),
+    (0x55A8, 13698),
+    (0x55A9, 19161),
+    (0x55AA, 7976),
+    (0x55AB, 7992),
+    (0x55AC, 7988),
+    (0x55AD, 13700),
+    (0x55AE, 7982),
+    (0x55B1, 7989),
+    (0x55B2, 7985),
+    (0x55B3, 7981),
+    (0x55B5, 13707),
+    (0x55BB, 7987),
+    (0x55BF, 14402),
+    (0x55C0, 14398),
+    (0x55C2, 14413),
+    (0x55C3, 14391),
+    (0x55C4, 14400),
+    (0x55C5, 8452),
+    (0x55C6, 8453),
+    (0x55C7, 8445),
+    (0x55C8, 14409),
+    (0x55C9, 8455),
+    (0x55CA, 14396),
+    (0x55CB, 14395),
+    (0x55CC, 14393),
+    (0x55CD, 14411),
+    (0x55CE, 8443),
+    (0x55CF, 14405),
+    (0x55D0, 14394),
+    (0x55D1, 8446),
+    (0x55D2, 14403),
+    (0x55D3, 8441),
+    (0x55D4, 14399),
+    (0x55D5, 14406),
+    (0x55D6, 14408),
+    (0x55D9, 14412),
+    (0x55DA, 8450),
+    (0x55DB, 14392),
+    (0x55DC, 8444),
+    (0x55DD, 14397),
+    (0x55DF, 8439),
+    (0x55E1, 8451),
+    (0x55E2, 14407),
+    (0x55E3, 8447),
+    (0x55E4, 8448),
+    (0x55E5, 8454),
+    (0x55E6, 8442),
+    (0x55E7, 5212),
+    (0x55E8, 8440),
+    (0x55E9, 14401),
+    (0x55EF, 8449),
+    (0x55F2, 14410),
+    (0x55F6, 8899),
+    (0x55F7, 8894),
+    (0x55F9, 15093),
+    (0x55FA, 15089),
+    (0x55FC, 15083),
+    (0x55FD, 8888),
+    (0x55FE, 8884),
+    (0x55FF, 15092),
+    (0x5600, 8885),
+    (0x5601, 15086),
+    (0x5602, 15088),
+    (0x5604, 15091),
+    (0x5605, 19162),
+    (0x5606, 8890),
+    (0x5608, 8897),
+    (0x5609, 8891),
+    (0x560C, 15081),
+    (0x560D, 8892),
+    (0x560E, 8893),
+    (0x560F, 15084),
+    (0x5610, 8898),
+    (0x5612, 15082),
+    (0x5613, 15087),
+    (0x5614, 8889),
+    (0x5615, 15080),
+    (0x5616, 8895),
+    (0x5617, 8887),
+    (0x561B, 8886),
+    (0x561C, 15085),
+    (0x561D, 15090),
+    (0x561F, 8896),
+    (0x5627, 15079),
+    (0x5629, 9276),
+    (0x562A, 15707),
+    (0x562C, 15704),
+    (0x562E, 9270),
+    (0x562F, 9282),
+    (0x5630, 9283),
+    (0x5632, 9273),
+    (0x5633, 15702),
+    (0x5634, 9275),
+    (0x5635, 15694),
+    (0x5636, 9281),
+    (0x5637, 19169),
+    (0x5638, 15706),
+    (0x5639, 9272),
+    (0x563A, 15708),
+    (0x563B, 9271),
+    (0x563D, 15703),
+    (0x563E, 15705),
+    (0x563F, 9274),
+    (0x5640, 15701),
+    (0x5641, 15695),
+    (0x5642, 15692),
+    (0x5645, 13702),
+    (0x5646, 15698),
+    (0x5648, 15691),
+    (0x5649, 15697),
+    (0x564A, 15696),
+    (0x564C, 15693),
+    (0x564D, 19174),
+    (0x564E, 9278),
+    (0x564F, 19175),
+    (0x5653, 9277),
+    (0x5657, 9279),
+    (0x5658, 15699),
+    (0x5659, 9657),
+    (0x565A, 15700),
+    (0x565E, 16333),
+    (0x5660, 16326),
+    (0x5662, 9669),
+    (0x5663, 16330),
+    (0x5664, 9661),
+    (0x5665, 9665),
+    (0x5666, 16329),
+    (0x5668, 9664),
+    (0x5669, 9660),
+    (0x566A, 9663),
+    (0x566B, 9658),
+    (0x566C, 9668),
+    (0x566D, 16331),
+    (0x566E, 16327),
+    (0x566F, 9667),
+    (0x5670, 16325),
+    (0x5671, 9666),
+    (0x5672, 16332),
+    (0x5673, 16328),
+    (0x5674, 9280),
+    (0x5676, 9670),
+    (0x5677, 16334),
+    (0x5678, 9662),
+    (0x5679, 9659),
+    (0x567E, 16899),
+    (0x567F, 16901),
+    (0x5680, 9950),
+    (0x5681, 16902),
+    (0x5682, 16900),
+    (0x5683, 16898),
+    (0x5684, 16897),
+    (0x5685, 9952),
+    (0x5686, 16896),
+    (0x5687, 9953),
+    (0x568C, 16894),
+    (0x568D, 16895),
+    (0x568E, 9949),
+    (0x568F, 9954),
+    (0x5690, 9951),
+    (0x5693, 16893),
+    (0x5695, 10221),
+    (0x5697, 17360),
+    (0x5698, 17358),
+    (0x5699, 17363),
+    (0x569A, 17361),
+    (0x569C, 17359),
+    (0x569D, 17362),
+    (0x56A5, 10394),
+    (0x56A6, 17728),
+    (0x56A7, 17729),
+    (0x56A8, 10541),
+    (0x56AA, 17730),
+    (0x56AB, 17726),
+    (0x56AC, 17731),
+    (0x56AD, 17727),
+    (0x56AE, 10222),
+    (0x56B2, 18054),
+    (0x56B3, 18056),
+    (0x56B4, 10544),
+    (0x56B5, 18055),
+    (0x56B6, 10543),
+    (0x56B7, 10542),
+    (0x56BC, 10545),
+    (0x56BD, 18311),
+    (0x56BE, 18312),
+    (0x56C0, 10639),
+    (0x56C1, 10638),
+    (0x56C2, 10640),
+    (0x56C3, 18310),
+    (0x56C5, 18487),
+    (0x56C6, 18486),
+    (0x56C8, 10721),
+    (0x56C9, 10723),
+    (0x56CA, 10722),
+    (0x56CB, 18488),
+    (0x56CC, 10778),
+    (0x56CD, 18751),
+    (0x56D1, 10819),
+    (0x56D3, 18750),
+    (0x56D4, 18823),
+    (0x56D7, 11313),
+    (0x56DA, 5713),
+    (0x56DB, 5712),
+    (0x56DD, 5840),
+    (0x56DE, 5839),
+    (0x56DF, 11405),
+    (0x56E0, 5838),
+    (0x56E1, 11404),
+    (0x56E4, 6023),
+    (0x56E5, 11532),
+    (0x56E7, 11531),
+    (0x56EA, 6021),
+    (0x56EB, 6024),
+    (0x56ED, 19182),
+    (0x56EE, 11530),
+    (0x56EF, 19181),
+    (0x56F0, 6022),
+    (0x56F1, 19177),
+    (0x56F7, 11760),
+    (0x56F9, 11761),
+    (0x56FA, 6300),
+    (0x56FF, 6666),
+    (0x5700, 19180),
+    (0x5701, 12568),
+    (0x5702, 12569),
+    (0x5703, 7070),
+    (0x5704, 7071),
+    (0x5707, 13105),
+    (0x5708, 7514),
+    (0x5709, 7516),
+    (0x570A, 13104),
+    (0x570B, 7515),
+    (0x570C, 13719),
+    (0x570D, 7994),
+    (0x5712, 8456),
+    (0x5713, 8457),
+    (0x5714, 14414),
+    (0x5716, 8901),
+    (0x5718, 8900),
+    (0x571A, 15709),
+    (0x571B, 16336),
+    (0x571C, 16335),
+    (0x571E, 18883),
+    (0x571F, 5534),
+    (0x5720, 11329),
+    (0x5722, 11350),
+    (0x5723, 11351),
+    (0x5728, 5843),
+    (0x5729, 5847),
+    (0x572A, 11407),
+    (0x572C, 5845),
+    (0x572D, 5844),
+    (0x572E, 11406),
+    (0x572F, 5846),
+    (0x5730, 5842),
+    (0x5733, 5841),
+    (0x5734, 11408),
+    (0x573B, 6034),
+    (0x573E, 6031),
+    (0x5740, 6027),
+    (0x5741, 11533),
+    (0x5742, 19190),
+    (0x5743, 19208),
+    (0x5745, 11534),
+    (0x5746, 19186),
+    (0x5747, 6029),
+    (0x5749, 11536),
+    (0x574A, 6025),
+    (0x574B, 11537),
+    (0x574C, 11535),
+    (0x574D, 6028),
+    (0x574E, 6030),
+    (0x574F, 6033),
+    (0x5750, 6032),
+    (0x5751, 6026),
+    (0x5752, 11538),
+    (0x5761, 6305),
+    (0x5762, 11774),
+    (0x5764, 6307),
+    (0x5766, 6306),
+    (0x5768, 11775),
+    (0x5769, 6304),
+    (0x576A, 6303),
+    (0x576B, 11765),
+    (0x576D, 11764),
+    (0x576F, 11762),
+    (0x5770, 11767),
+    (0x5771, 11766),
+    (0x5772, 11763),
+    (0x5773, 11772),
+    (0x5774, 11773),
+    (0x5775, 11770),
+    (0x5776, 11768),
+    (0x5777, 6302),
+    (0x577B, 11771),
+    (0x577C, 6308),
+    (0x577D, 11776),
+    (0x5780, 11769),
+    (0x5782, 6667),
+    (0x5783, 6301),
+    (0x578B, 6668),
+    (0x578C, 12109),
+    (0x578F, 12115),
+    (0x5790, 19350),
+    (0x5793, 6674),
+    (0x5794, 12113),
+    (0x5795, 12119),
+    (0x5797, 12110),
+    (0x5798, 12114),
+    (0x5799, 12116),
+    (0x579A, 12118),
+    (0x579B, 12112),
+    (0x579D, 12111),
+    (0x579E, 12106),
+    (0x579F, 12107),
+    (0x57A0, 6669),
+    (0x57A2, 6671),
+    (0x57A3, 6670),
+    (0x57A4, 12108),
+    (0x57A5, 12117),
+    (0x57AE, 6673),
+    (0x57B5, 12105),
+    (0x57B6, 12579),
+    (0x57B8, 12578),
+    (0x57B9, 12583),
+    (0x57BA, 12574),
+    (0x57BC, 12577),
+    (0x57BD, 12576),
+    (0x57BF, 12580),
+    (0x57C1, 12584),
+    (0x57C2, 7072),
+    (0x57C3, 7075),
+    (0x57C6, 12575),
+    (0x57C7, 12581),
+    (0x57CB, 7074),
+    (0x57CC, 12570),
+    (0x57CE, 6672),
+    (0x57CF, 13119),
+    (0x57D0, 12582),
+    (0x57D2, 12573),
+    (0x57D4, 7073),
+    (0x57D5, 12572),
+    (0x57DC, 13110),
+    (0x57DF, 7517),
+    (0x57E0, 7521),
+    (0x57E1, 13126),
+    (0x57E2, 13108),
+    (0x57E3, 13122),
+    (0x57E4, 7522),
+    (0x57E5, 13124),
+    (0x57E6, 19193),
+    (0x57E7, 13130),
+    (0x57E9, 13134),
+    (0x57EC, 13125),
+    (0x57ED, 13113),
+    (0x57EE, 13121),
+    (0x57F0, 13135),
+    (0x57F1, 13133),
+    (0x57F2, 13123),
+    (0x57F3, 13118),
+    (0x57F4, 13111),
+    (0x57F5, 13727),
+    (0x57F6, 13109),
+    (0x57F7, 7526),
+    (0x57F8, 13116),
+    (0x57F9, 7527),
+    (0x57FA, 7523),
+    (0x57FB, 13106),
+    (0x57FC, 13128),
+    (0x57FD, 13114),
+    (0x57FE, 19129),
+    (0x5800, 13112),
+    (0x5801, 13131),
+    (0x5802, 7524),
+    (0x5803, 19195),
+    (0x5804, 13137),
+    (0x5805, 7518),
+    (0x5806, 7520),
+    (0x5807, 13120),
+    (0x5808, 13115),
+    (0x5809, 7076),
+    (0x580A, 7519),
+    (0x580B, 13117),
+    (0x580C, 13132),
+    (0x580D, 13136),
+    (0x580E, 13127),
+    (0x5810, 13129),
+    (0x5814, 13107),
+    (0x5819, 13722),
+    (0x581B, 13731),
+    (0x581C, 13730),
+    (0x581D, 8002),
+    (0x581E, 13723),
+    (0x5820, 8003),
+    (0x5821, 8001),
+    (0x5823, 13725),
+    (0x5824, 7998),
+    (0x5825, 13729),
+    (0x5826, 19198),
+    (0x5827, 13724),
+    (0x5828, 13726),
+    (0x5829, 13720),
+    (0x582A, 7996),
+    (0x582C, 13739),
+    (0x582D, 13738),
+    (0x582E, 13735),
+    (0x582F, 7995),
+    (0x5830, 7999),
+    (0x5831, 8000),
+    (0x5832, 12571),
+    (0x5833, 13732),
+    (0x5834, 7997),
+    (0x5835, 7525),
+    (0x5836, 13734),
+    (0x5837, 13721),
+    (0x5838, 13737),
+    (0x5839, 13736),
+    (0x583B, 13740),
+    (0x583D, 14428),
+    (0x583F, 13733),
+    (0x5848, 13728),
+    (0x5849, 14420),
+    (0x584A, 8467),
+    (0x584B, 8470),
+    (0x584C, 8465),
+    (0x584D, 14419),
+    (0x584E, 14423),
+    (0x584F, 14418),
+    (0x5851, 8459),
+    (0x5852, 8469),
+    (0x5853, 14415),
+    (0x5854, 8463),
+    (0x5855, 14422),
+    (0x5857, 8461),
+    (0x5858, 8460),
+    (0x5859, 14425),
+    (0x585A, 8462),
+    (0x585B, 14427),
+    (0x585C, 19200),
+    (0x585D, 14424),
+    (0x585E, 8458),
+    (0x5862, 8468),
+    (0x5863, 14429),
+    (0x5864, 14417),
+    (0x5865, 14426),
+    (0x5868, 14416),
+    (0x586B, 8464),
+    (0x586D, 8466),
+    (0x586F, 14421),
+    (0x5871, 14430),
+    (0x5874, 15101),
+    (0x5875, 8902),
+    (0x5876, 15107),
+    (0x5879, 8907),
+    (0x587A, 15103),
+    (0x587B, 15110),
+    (0x587C, 15095),
+    (0x587D, 8909),
+    (0x587E, 8903),
+    (0x587F, 15100),
+    (0x5880, 9284),
+    (0x5881, 15099),
+    (0x5882, 15108),
+    (0x5883, 8904),
+    (0x5885, 8908),
+    (0x5886, 15098),
+    (0x5887, 15104),
+    (0x5888, 15109),
+    (0x5889, 15094),
+    (0x588A, 8906),
+    (0x588B, 15102),
+    (0x588E, 15106),
+    (0x588F, 15112),
+    (0x5890, 15096),
+    (0x5891, 15105),
+    (0x5893, 8905),
+    (0x5894, 15111),
+    (0x5898, 15097),
+    (0x589C, 9288),
+    (0x589D, 15711),
+    (0x589E, 9286),
+    (0x589F, 9285),
+    (0x58A0, 15713),
+    (0x58A1, 15718),
+    (0x58A3, 15714),
+    (0x58A5, 15717),
+    (0x58A6, 9291),
+    (0x58A8, 9644),
+    (0x58A9, 9290),
+    (0x58AA, 19201),
+    (0x58AB, 15710),
+    (0x58AC, 15716),
+    (0x58AE, 9289),
+    (0x58AF, 15715),
+    (0x58B1, 15712),
+    (0x58B3, 9287),
+    (0x58BA, 16341),
+    (0x58BB, 18959),
+    (0x58BC, 16343),
+    (0x58BD, 16338),
+    (0x58BE, 9672),
+    (0x58BF, 16340),
+    (0x58C1, 9671),
+    (0x58C2, 16342),
+    (0x58C5, 9674),
+    (0x58C6, 16344),
+    (0x58C7, 9673),
+    (0x58C8, 16337),
+    (0x58C9, 16339),
+    (0x58CE, 9958),
+    (0x58CF, 16905),
+    (0x58D1, 9957),
+    (0x58D2, 16906),
+    (0x58D3, 9956),
+    (0x58D4, 16904),
+    (0x58D5, 9955),
+    (0x58D6, 16903),
+    (0x58D8, 10224),
+    (0x58D9, 10223),
+    (0x58DA, 17732),
+    (0x58DB, 17734),
+    (0x58DC, 19204),
+    (0x58DD, 17733),
+    (0x58DE, 10395),
+    (0x58DF, 10396),
+    (0x58E0, 19203),
+    (0x58E2, 10397),
+    (0x58E3, 18057),
+    (0x58E4, 10546),
+    (0x58E7, 18639),
+    (0x58E8, 18638),
+    (0x58E9, 10820),
+    (0x58EB, 5535),
+    (0x58EC, 5605),
+    (0x58EF, 6035),
+    (0x58F4, 12120),
+    (0x58F9, 8004),
+    (0x58FA, 8005),
+    (0x58FB, 19206),
+    (0x58FC, 14431),
+    (0x58FD, 8910),
+    (0x58FE, 15113),
+    (0x58FF, 15719),
+    (0x5903, 11330),
+    (0x5906, 11539),
+    (0x590A, 10937),
+    (0x590C, 11777),
+    (0x590D, 12121),
+    (0x590E, 12585),
+    (0x590F, 7077),
+    (0x5911, 19627),
+    (0x5912, 17735),
+    (0x5914, 10641),
+    (0x5915, 5536),
+    (0x5916, 5714),
+    (0x5917, 11352),
+    (0x5919, 5848),
+    (0x591A, 5849),
+    (0x591C, 6309),
+    (0x591F, 19213),
+    (0x5920, 7528),
+    (0x5922, 8912),
+    (0x5924, 8913),
+    (0x5925, 8911),
+    (0x5927, 5537),
+    (0x5929, 5606),
+    (0x592A, 5608),
+    (0x592B, 5607),
+    (0x592C, 11331),
+    (0x592D, 5609),
+    (0x592E, 5715),
+    (0x592F, 11353),
+    (0x5931, 5716),
+    (0x5937, 5850),
+    (0x5938, 5851),
+    (0x593C, 11409),
+    (0x593E, 6036),
+    (0x5940, 11540),
+    (0x5944, 6313),
+    (0x5945, 11778),
+    (0x5947, 6311),
+    (0x5948, 6312),
+    (0x5949, 6310),
+    (0x594A, 12586),
+    (0x594E, 6678),
+    (0x594F, 6677),
+    (0x5950, 6679),
+    (0x5951, 6676),
+    (0x5953, 12122),
+    (0x5954, 6314),
+    (0x5955, 6675),
+    (0x5957, 7078),
+    (0x5958, 7079),
+    (0x595A, 7080),
+    (0x595C, 13138),
+    (0x5960, 8006),
+    (0x5961, 13741),
+    (0x5962, 7529),
+    (0x5967, 8471),
+    (0x5969, 8915),
+    (0x596A, 8914),
+    (0x596B, 15114),
+    (0x596D, 9292),
+    (0x596E, 9675),
+    (0x5970, 17364),
+    (0x5971, 18489),
+    (0x5972, 18640),
+    (0x5973, 5538),
+    (0x5974, 5717),
+    (0x5976, 5718),
+    (0x5977, 11415),
+    (0x5978, 5853),
+    (0x5979, 5856),
+    (0x597B, 11413),
+    (0x597C, 11411),
+    (0x597D, 5855),
+    (0x597E, 11414),
+    (0x597F, 11416),
+    (0x5980, 11410),
+    (0x5981, 5858),
+    (0x5982, 5857),
+    (0x5983, 5854),
+    (0x5984, 5852),
+    (0x5985, 11412),
+    (0x598A, 6047),
+    (0x598D, 6044),
+    (0x598E, 11545),
+    (0x598F, 11548),
+    (0x5990, 11547),
+    (0x5992, 6038),
+    (0x5993, 6046),
+    (0x5996, 6043),
+    (0x5997, 11544),
+    (0x5998, 11542),
+    (0x5999, 6042),
+    (0x599D, 6037),
+    (0x599E, 6040),
+    (0x59A0, 11543),
+    (0x59A1, 11550),
+    (0x59A2, 11546),
+    (0x59A3, 6041),
+    (0x59A4, 6045),
+    (0x59A5, 6048),
+    (0x59A6, 11541),
+    (0x59A7, 11549),
+    (0x59A8, 6039),
+    (0x59AE, 6319),
+    (0x59AF, 6327),
+    (0x59B1, 11790),
+    (0x59B2, 11783),
+    (0x59B3, 6328),
+    (0x59B4, 11794),
+    (0x59B5, 11779),
+    (0x59B6, 11786),
+    (0x59B9, 6318),
+    (0x59BA, 11780),
+    (0x59BB, 6316),
+    (0x59BC, 11787),
+    (0x59BD, 11791),
+    (0x59BE, 6315),
+    (0x59C0, 11792),
+    (0x59C1, 11785),
+    (0x59C3, 11788),
+    (0x59C5, 6330),
+    (0x59C6, 6321),
+    (0x59C7, 11795),
+    (0x59C8, 11793),
+    (0x59C9, 19222),
+    (0x59CA, 6326),
+    (0x59CB, 6324),
+    (0x59CC, 11784),
+    (0x59CD, 6323),
+    (0x59CE, 11782),
+    (0x59CF, 11781),
+    (0x59D0, 6322),
+    (0x59D1, 6320),
+    (0x59D2, 6329),
+    (0x59D3, 6325),
+    (0x59D4, 6317),
+    (0x59D6, 11789),
+    (0x59D8, 6681),
+    (0x59D9, 19227),
+    (0x59DA, 6688),
+    (0x59DB, 12136),
+    (0x59DC, 6680),
+    (0x59DD, 12128),
+    (0x59DE, 12124),
+    (0x59E0, 12140),
+    (0x59E1, 12123),
+    (0x59E3, 6683),
+    (0x59E4, 12133),
+    (0x59E5, 6686),
+    (0x59E6, 6689),
+    (0x59E8, 6684),
+    (0x59E9, 12137),
+    (0x59EA, 6687),
+    (0x59EC, 7087),
+    (0x59ED, 12143),
+    (0x59EE, 12125),
+    (0x59F1, 12127),
+    (0x59F2, 12134),
+    (0x59F3, 12138),
+    (0x59F4, 12142),
+    (0x59F5, 12139),
+    (0x59F6, 12132),
+    (0x59F7, 12135),
+    (0x59F9, 19234),
+    (0x59FA, 12129),
+    (0x59FB, 6691),
+    (0x59FC, 12131),
+    (0x59FD, 12130),
+    (0x59FE, 12141),
+    (0x59FF, 6682),
+    (0x5A00, 12126),
+    (0x5A01, 6690),
+    (0x5A03, 6685),
+    (0x5A09, 7093),
+    (0x5A0A, 12594),
+    (0x5A0C, 7092),
+    (0x5A0F, 12592),
+    (0x5A11, 7081),
+    (0x5A13, 7086),
+    (0x5A15, 12591),
+    (0x5A16, 12588),
+    (0x5A17, 12593),
+    (0x5A18, 7082),
+    (0x5A19, 12587),
+    (0x5A1B, 7085),
+    (0x5A1C, 7083),
+    (0x5A1E, 12595),
+    (0x5A1F, 7084),
+    (0x5A20, 7088),
+    (0x5A23, 7089),
+    (0x5A24, 19218),
+    (0x5A25, 7091),
+    (0x5A29, 7090),
+    (0x5A2D, 12589),
+    (0x5A2E, 12590),
+    (0x5A33, 12596),
+    (0x5A35, 13145),
+    (0x5A36, 7530),
+    (0x5A37, 13755),
+    (0x5A38, 13144),
+    (0x5A39, 13163),
+    (0x5A3C, 7536),
+    (0x5A3E, 13161),
+    (0x5A40, 7535),
+    (0x5A41, 7531),
+    (0x5A42, 13170),
+    (0x5A43, 13154),
+    (0x5A44, 13157),
+    (0x5A46, 7539),
+    (0x5A47, 13167),
+    (0x5A48, 13159),
+    (0x5A49, 7532),
+    (0x5A4A, 7540),
+    (0x5A4C, 13164),
+    (0x5A4D, 13162),
+    (0x5A50, 13147),
+    (0x5A51, 13168),
+    (0x5A52, 13156),
+    (0x5A53, 13151),
+    (0x5A55, 13141),
+    (0x5A56, 13169),
+    (0x5A57, 13153),
+    (0x5A58, 13140),
+    (0x5A5A, 7538),
+    (0x5A5B, 13158),
+    (0x5A5C, 13171),
+    (0x5A5D, 13155),
+    (0x5A5E, 13143),
+    (0x5A5F, 13148),
+    (0x5A60, 13139),
+    (0x5A62, 7537),
+    (0x5A63, 19237),
+    (0x5A64, 13152),
+    (0x5A65, 13149),
+    (0x5A66, 7533),
+    (0x5A67, 13142),
+    (0x5A69, 13166),
+    (0x5A6A, 7534),
+    (0x5A6C, 13150),
+    (0x5A6D, 13146),
+    (0x5A70, 13165),
+    (0x5A77, 8007),
+    (0x5A78, 13748),
+    (0x5A7A, 13745),
+    (0x5A7B, 13762),
+    (0x5A7C, 13750),
+    (0x5A7D, 13763),
+    (0x5A7F, 8009),
+    (0x5A83, 13759),
+    (0x5A84, 13756),
+    (0x5A88, 19609),
+    (0x5A8A, 13757),
+    (0x5A8B, 13760),
+    (0x5A8C, 13764),
+    (0x5A8E, 13160),
+    (0x5A8F, 13766),
+    (0x5A90, 14451),
+    (0x5A91, 19563),
+    (0x5A92, 8010),
+    (0x5A93, 13767),
+    (0x5A94, 13743),
+    (0x5A95, 13753),
+    (0x5A96, 19242),
+    (0x5A97, 13758),
+    (0x5A9A, 8008),
+    (0x5A9B, 8011),
+    (0x5A9C, 13765),
+    (0x5A9D, 13768),
+    (0x5A9E, 13747),
+    (0x5A9F, 13744),
+    (0x5AA0, 19362),
+    (0x5AA2, 13746),
+    (0x5AA5, 13751),
+    (0x5AA6, 13749),
+    (0x5AA7, 8012),
+    (0x5AA9, 13761),
+    (0x5AAB, 19236),
+    (0x5AAC, 13752),
+    (0x5AAE, 13754),
+    (0x5AAF, 13742),
+    (0x5AB0, 14439),
+    (0x5AB1, 14437),
+    (0x5AB2, 8480),
+    (0x5AB3, 8478),
+    (0x5AB4, 14447),
+    (0x5AB5, 14438),
+    (0x5AB6, 14448),
+    (0x5AB7, 14444),
+    (0x5AB8, 14436),
+    (0x5AB9, 14450),
+    (0x5ABA, 14435),
+    (0x5ABB, 14442),
+    (0x5ABC, 8477),
+    (0x5ABD, 8476),
+    (0x5ABE, 8475),
+    (0x5ABF, 14440),
+    (0x5AC0, 14445),
+    (0x5AC1, 8472),
+    (0x5AC2, 8479),
+    (0x5AC4, 14433),
+    (0x5AC6, 14443),
+    (0x5AC7, 14432),
+    (0x5AC8, 14441),
+    (0x5AC9, 8473),
+    (0x5ACA, 14446),
+    (0x5ACB, 14434),
+    (0x5ACC, 8474),
+    (0x5ACD, 14449),
+    (0x5AD3, 19244),
+    (0x5AD5, 15118),
+    (0x5AD6, 8920),
+    (0x5AD7, 8919),
+    (0x5AD8, 8921),
+    (0x5AD9, 15130),
+    (0x5ADA, 15120),
+    (0x5ADB, 15126),
+    (0x5ADC, 15115),
+    (0x5ADD, 15129),
+    (0x5ADE, 15128),
+    (0x5ADF, 15132),
+    (0x5AE0, 15125),
+    (0x5AE1, 8916),
+    (0x5AE2, 15124),
+    (0x5AE3, 8922),
+    (0x5AE5, 15117),
+    (0x5AE6, 8917),
+    (0x5AE8, 15131),
+    (0x5AE9, 8918),
+    (0x5AEA, 15119),
+    (0x5AEB, 15122),
+    (0x5AEC, 15127),
+    (0x5AED, 15121),
+    (0x5AEE, 15116),
+    (0x5AF3, 15123),
+    (0x5AF4, 15721),
+    (0x5AF5, 9296),
+    (0x5AF6, 15724),
+    (0x5AF7, 15723),
+    (0x5AF8, 15726),
+    (0x5AF9, 15728),
+    (0x5AFA, 18962),
+    (0x5AFB, 9294),
+    (0x5AFD, 15722),
+    (0x5AFF, 15720),
+    (0x5B01, 15729),
+    (0x5B02, 15727),
+    (0x5B03, 15725),
+    (0x5B05, 15731),
+    (0x5B07, 15730),
+    (0x5B08, 9298),
+    (0x5B09, 9293),
+    (0x5B0B, 9295),
+    (0x5B0C, 9297),
+    (0x5B0F, 15732),
+    (0x5B10, 16351),
+    (0x5B13, 16350),
+    (0x5B14, 16349),
+    (0x5B16, 16352),
+    (0x5B17, 16345),
+    (0x5B19, 16346),
+    (0x5B1A, 16354),
+    (0x5B1B, 16347),
+    (0x5B1D, 9676),
+    (0x5B1E, 16356),
+    (0x5B20, 16355),
+    (0x5B21, 16348),
+    (0x5B23, 16910),
+    (0x5B24, 9961),
+    (0x5B25, 16908),
+    (0x5B26, 16913),
+    (0x5B27, 16912),
+    (0x5B28, 16353),
+    (0x5B2A, 9960),
+    (0x5B2C, 16911),
+    (0x5B2D, 16907),
+    (0x5B2E, 16915),
+    (0x5B2F, 16914),
+    (0x5B30, 9959),
+    (0x5B32, 16909),
+    (0x5B34, 9677),
+    (0x5B38, 10225),
+    (0x5B3C, 17365),
+    (0x5B3D, 17736),
+    (0x5B3E, 17737),
+    (0x5B3F, 17738),
+    (0x5B40, 10547),
+    (0x5B43, 10548),
+    (0x5B45, 18058),
+    (0x5B46, 19691),
+    (0x5B47, 18314),
+    (0x5B48, 18313),
+    (0x5B4B, 18490),
+    (0x5B4C, 18491),
+    (0x5B4D, 18641),
+    (0x5B4E, 18752),
+    (0x5B50, 5539),
+    (0x5B51, 5540),
+    (0x5B53, 5541),
+    (0x5B54, 5610),
+    (0x5B55, 5719),
+    (0x5B56, 11417),
+    (0x5B57, 5859),
+    (0x5B58, 5860),
+    (0x5B5A, 6051),
+    (0x5B5B, 6052),
+    (0x5B5C, 6050),
+    (0x5B5D, 6049),
+    (0x5B5F, 6331),
+    (0x5B62, 11796),
+    (0x5B63, 6333),
+    (0x5B64, 6332),
+    (0x5B65, 11797),
+    (0x5B69, 6692),
+    (0x5B6B, 7094),
+    (0x5B6C, 12597),
+    (0x5B6E, 13173),
+    (0x5B70, 7541),
+    (0x5B71, 8014),
+    (0x5B72, 13172),
+    (0x5B73, 8013),
+    (0x5B75, 8923),
+    (0x5B76, 19253),
+    (0x5B77, 15133),
+    (0x5B78, 9678),
+    (0x5B7A, 9962),
+    (0x5B7B, 16916),
+    (0x5B7C, 19255),
+    (0x5B7D, 10549),
+    (0x5B7F, 10724),
+    (0x5B80, 10938),
+    (0x5B81, 11354),
+    (0x5B82, 19054),
+    (0x5B83, 5720),
+    (0x5B84, 11355),
+    (0x5B85, 5863),
+    (0x5B87, 5861),
+    (0x5B88, 5862),
+    (0x5B89, 5864),
+    (0x5B8B, 6054),
+    (0x5B8C, 6053),
+    (0x5B8E, 11551),
+    (0x5B8F, 6055),
+    (0x5B92, 11552),
+    (0x5B93, 11798),
+    (0x5B95, 11799),
+    (0x5B97, 6334),
+    (0x5B98, 6336),
+    (0x5B99, 6338),
+    (0x5B9A, 6335),
+    (0x5B9B, 6339),
+    (0x5B9C, 6337),
+    (0x5B9D, 19266),
+    (0x5BA2, 6696),
+    (0x5BA3, 6693),
+    (0x5BA4, 6695),
+    (0x5BA5, 6697),
+    (0x5BA6, 6694),
+    (0x5BA7, 12598),
+    (0x5BA8, 12144),
+    (0x5BAA, 19372),
+    (0x5BAC, 12600),
+    (0x5BAD, 12599),
+    (0x5BAE, 7100),
+    (0x5BB0, 7096),
+    (0x5BB3, 7097),
+    (0x5BB4, 7099),
+    (0x5BB5, 7101),
+    (0x5BB6, 7098),
+    (0x5BB8, 7103),
+    (0x5BB9, 7102),
+    (0x5BBF, 7546),
+    (0x5BC0, 13175),
+    (0x5BC1, 13174),
+    (0x5BC2, 7545),
+    (0x5BC4, 7544),
+    (0x5BC5, 7543),
+    (0x5BC6, 7547),
+    (0x5BC7, 7542),
+    (0x5BCA, 13774),
+    (0x5BCB, 13771),
+    (0x5BCC, 8016),
+    (0x5BCD, 13770),
+    (0x5BCE, 13775),
+    (0x5BD0, 8018),
+    (0x5BD1, 13773),
+    (0x5BD2, 8015),
+    (0x5BD3, 8017),
+    (0x5BD4, 13772),
+    (0x5BD5, 19260),
+    (0x5BD6, 14452),
+    (0x5BD7, 19701),
+    (0x5BD8, 14453),
+    (0x5BD9, 14454),
+    (0x5BDE, 8924),
+    (0x5BDF, 8932),
+    (0x5BE0, 15134),
+    (0x5BE1, 8926),
+    (0x5BE2, 8930),
+    (0x5BE3, 15135),
+    (0x5BE4, 8931),
+    (0x5BE5, 8927),
+    (0x5BE6, 8928),
+    (0x5BE7, 8925),
+    (0x5BE8, 8929),
+    (0x5BE9, 9301),
+    (0x5BEA, 13769),
+    (0x5BEB, 9302),
+    (0x5BEC, 9300),
+    (0x5BEE, 9299),
+    (0x5BEF, 16357),
+    (0x5BF0, 9679),
+    (0x5BF1, 16917),
+    (0x5BF2, 16918),
+    (0x5BF3, 19265),
+    (0x5BF5, 10398),
+    (0x5BF6, 10550),
+    (0x5BF8, 5542),
+    (0x5BFA, 5865),
+    (0x5BFF, 19207),
+    (0x5C01, 6698),
+    (0x5C03, 12601),
+    (0x5C04, 7104),
+    (0x5C05, 19268),
+    (0x5C07, 7550),
+    (0x5C08, 7549),
+    (0x5C09, 7548),
+    (0x5C0A, 8019),
+    (0x5C0B, 8020),
+    (0x5C0C, 13776),
+    (0x5C0D, 8933),
+    (0x5C0E, 9680),
+    (0x5C0F, 5543),
+    (0x5C10, 11332),
+    (0x5C11, 5611),
+    (0x5C12, 11356),
+    (0x5C13, 19270),
+    (0x5C14, 19272),
+    (0x5C15, 11418),
+    (0x5C16, 5866),
+    (0x5C1A, 6340),
+    (0x5C1F, 14455),
+    (0x5C22, 5544),
+    (0x5C24, 5612),
+    (0x5C25, 11419),
+    (0x5C28, 11553),
+    (0x5C2A, 11554),
+    (0x5C2C, 6056),
+    (0x5C30, 13777),
+    (0x5C31, 8021),
+    (0x5C33, 14456),
+    (0x5C37, 9963),
+    (0x5C38, 5545),
+    (0x5C39, 5565),
+    (0x5C3A, 5613),
+    (0x5C3B, 11357),
+    (0x5C3C, 5721),
+    (0x5C3E, 6060),
+    (0x5C3F, 6059),
+    (0x5C40, 6057),
+    (0x5C41, 6058),
+    (0x5C44, 11800),
+    (0x5C45, 6342),
+    (0x5C46, 6343),
+    (0x5C47, 11801),
+    (0x5C48, 6341),
+    (0x5C49, 19275),
+    (0x5C4B, 6702),
+    (0x5C4C, 12145),
+    (0x5C4D, 6701),
+    (0x5C4E, 6699),
+    (0x5C4F, 6700),
+    (0x5C50, 7107),
+    (0x5C51, 7105),
+    (0x5C54, 12603),
+    (0x5C55, 7106),
+    (0x5C56, 12602),
+    (0x5C58, 7095),
+    (0x5C59, 13176),
+    (0x5C5C, 7552),
+    (0x5C5D, 7553),
+    (0x5C60, 7551),
+    (0x5C62, 8934),
+    (0x5C63, 15136),
+    (0x5C64, 9303),
+    (0x5C65, 9304),
+    (0x5C67, 15733),
+    (0x5C68, 9964),
+    (0x5C69, 17366),
+    (0x5C6A, 17367),
+    (0x5C6C, 10642),
+    (0x5C6D, 18753),
+    (0x5C6E, 11315),
+    (0x5C6F, 5614),
+    (0x5C71, 5546),
+    (0x5C73, 11359),
+    (0x5C74, 11358),
+    (0x5C79, 5867),
+    (0x5C7A, 11421),
+    (0x5C7B, 11422),
+    (0x5C7C, 11420),
+    (0x5C7E, 11423),
+    (0x5C85, 19277),
+    (0x5C86, 11562),
+    (0x5C88, 11557),
+    (0x5C89, 11559),
+    (0x5C8A, 11561),
+    (0x5C8B, 11558),
+    (0x5C8C, 6064),
+    (0x5C8D, 11555),
+    (0x5C8F, 11556),
+    (0x5C90, 6061),
+    (0x5C91, 6062),
+    (0x5C92, 11560),
+    (0x5C93, 11563),
+    (0x5C94, 6063),
+    (0x5C95, 11564),
+    (0x5C9C, 19612),
+    (0x5C9D, 11815),
+    (0x5C9F, 11809),
+    (0x5CA0, 11804),
+    (0x5CA1, 6345),
+    (0x5CA2, 11812),
+    (0x5CA3, 11810),
+    (0x5CA4, 11803),
+    (0x5CA5, 11816),
+    (0x5CA6, 11819),
+    (0x5CA7, 11814),
+    (0x5CA8, 11807),
+    (0x5CA9, 6347),
+    (0x5CAA, 11813),
+    (0x5CAB, 6348),
+    (0x5CAC, 11808),
+    (0x5CAD, 11811),
+    (0x5CAE, 11802),
+    (0x5CAF, 11806),
+    (0x5CB0, 11818),
+    (0x5CB1, 6349),
+    (0x5CB3, 6350),
+    (0x5CB5, 11805),
+    (0x5CB6, 11817),
+    (0x5CB7, 6344),
+    (0x5CB8, 6346),
+    (0x5CBA, 19287),
+    (0x5CC6, 12162),
+    (0x5CC7, 12155),
+    (0x5CC8, 12161),
+    (0x5CC9, 12154),
+    (0x5CCA, 12156),
+    (0x5CCB, 12150),
+    (0x5CCC, 12148),
+    (0x5CCE, 12163),
+    (0x5CCF, 12160),
+    (0x5CD0, 12146),
+    (0x5CD2, 6704),
+    (0x5CD3, 12158),
+    (0x5CD4, 12159),
+    (0x5CD6, 12157),
+    (0x5CD7, 12149),
+    (0x5CD8, 12147),
+    (0x5CD9, 6703),
+    (0x5CDA, 12153),
+    (0x5CDB, 12151),
+    (0x5CDE, 12152),
+    (0x5CDF, 12164),
+    (0x5CE8, 7112),
+    (0x5CE9, 19278),
+    (0x5CEA, 7111),
+    (0x5CEC, 12604),
+    (0x5CED, 7108),
+    (0x5CEE, 12606),
+    (0x5CEF, 19279),
+    (0x5CF0, 7113),
+    (0x5CF1, 12607),
+    (0x5CF4, 7116),
+    (0x5CF6, 7114),
+    (0x5CF7, 12608),
+    (0x5CF8, 12165),
+    (0x5CF9, 12610),
+    (0x5CFB, 7110),
+    (0x5CFD, 7109),
+    (0x5CFF, 12605),
+    (0x5D00, 12609),
+    (0x5D01, 7115),
+    (0x5D06, 7555),
+    (0x5D07, 7554),
+    (0x5D0B, 13178),
+    (0x5D0C, 13182),
+    (0x5D0D, 13184),
+    (0x5D0E, 7556),
+    (0x5D0F, 13187),
+    (0x5D10, 19283),
+    (0x5D11, 7560),
+    (0x5D12, 13189),
+    (0x5D14, 7562),
+    (0x5D16, 7558),
+    (0x5D17, 7566),
+    (0x5D18, 19284),
+    (0x5D19, 7563),
+    (0x5D1A, 13180),
+    (0x5D1B, 7557),
+    (0x5D1D, 13179),
+    (0x5D1E, 13177),
+    (0x5D1F, 13191),
+    (0x5D20, 13181),
+    (0x5D22, 7559),
+    (0x5D23, 13190),
+    (0x5D24, 7564),
+    (0x5D25, 13186),
+    (0x5D26, 13185),
+    (0x5D27, 7565),
+    (0x5D28, 13183),
+    (0x5D29, 7561),
+    (0x5D2E, 13192),
+    (0x5D30, 13188),
+    (0x5D31, 13792),
+    (0x5D32, 13799),
+    (0x5D33, 13788),
+    (0x5D34, 8024),
+    (0x5D35, 13784),
+    (0x5D36, 13800),
+    (0x5D37, 13778),
+    (0x5D38, 13797),
+    (0x5D39, 13795),
+    (0x5D3A, 13789),
+    (0x5D3C, 13798),
+    (0x5D3D, 13791),
+    (0x5D3F, 13783),
+    (0x5D40, 13801),
+    (0x5D41, 13781),
+    (0x5D42, 13794),
+    (0x5D43, 13779),
+    (0x5D45, 13802),
+    (0x5D46, 19285),
+    (0x5D47, 8025),
+    (0x5D49, 13796),
+    (0x5D4A, 14459),
+    (0x5D4B, 13782),
+    (0x5D4C, 8022),
+    (0x5D4E, 13786),
+    (0x5D50, 8023),
+    (0x5D51, 13785),
+    (0x5D52, 13790),
+    (0x5D55, 13787),
+    (0x5D59, 13793),
+    (0x5D5E, 14463),
+    (0x5D62, 14466),
+    (0x5D63, 14458),
+    (0x5D65, 14460),
+    (0x5D67, 14465),
+    (0x5D68, 14464),
+    (0x5D69, 8481),
+    (0x5D6B, 13780),
+    (0x5D6C, 14462),
+    (0x5D6F, 8482),
+    (0x5D71, 14457),
+    (0x5D72, 14461),
+    (0x5D77, 15143),
+    (0x5D79, 15150),
+    (0x5D7A, 15141),
+    (0x5D7C, 15148),
+    (0x5D7D, 15139),
+    (0x5D7E, 15147),
+    (0x5D7F, 15151),
+    (0x5D80, 15138),
+    (0x5D81, 15142),
+    (0x5D82, 15137),
+    (0x5D84, 8935),
+    (0x5D86, 15140),
+    (0x5D87, 8936),
+    (0x5D88, 15146),
+    (0x5D89, 15145),
+    (0x5D8A, 15144),
+    (0x5D8B, 19280),
+    (0x5D8D, 15149),
+    (0x5D92, 15737),
+    (0x5D93, 15739),
+    (0x5D94, 9306),
+    (0x5D95, 15740),
+    (0x5D97, 15735),
+    (0x5D99, 15734),
+    (0x5D9A, 15744),
+    (0x5D9C, 15742),
+    (0x5D9D, 9305),
+    (0x5D9E, 15745),
+    (0x5D9F, 15736),
+    (0x5DA0, 15741),
+    (0x5DA1, 15743),
+    (0x5DA2, 15738),
+    (0x5DA7, 16361),
+    (0x5DA8, 16366),
+    (0x5DA9, 16360),
+    (0x5DAA, 16365),
+    (0x5DAC, 16358),
+    (0x5DAD, 16368),
+    (0x5DAE, 16364),
+    (0x5DAF, 16369),
+    (0x5DB0, 16363),
+    (0x5DB1, 16359),
+    (0x5DB2, 16367),
+    (0x5DB4, 16370),
+    (0x5DB5, 16362),
+    (0x5DB6, 19335),
+    (0x5DB7, 16919),
+    (0x5DB8, 9968),
+    (0x5DBA, 9966),
+    (0x5DBC, 9965),
+    (0x5DBD, 9967),
+    (0x5DC0, 17368),
+    (0x5DC2, 17634),
+    (0x5DC3, 17739),
+    (0x5DC6, 18059),
+    (0x5DC7, 18060),
+    (0x5DC9, 10551),
+    (0x5DCB, 18315),
+    (0x5DCD, 10643),
+    (0x5DCF, 18316),
+    (0x5DD1, 18493),
+    (0x5DD2, 10726),
+    (0x5DD4, 10725),
+    (0x5DD5, 18492),
+    (0x5DD6, 10779),
+    (0x5DD7, 19288),
+    (0x5DD8, 18642),
+    (0x5DDB, 10939),
+    (0x5DDD, 5547),
+    (0x5DDE, 5868),
+    (0x5DDF, 11424),
+    (0x5DE0, 11565),
+    (0x5DE1, 6206),
+    (0x5DE2, 7567),
+    (0x5DE5, 5548),
+    (0x5DE6, 5724),
+    (0x5DE7, 5723),
+    (0x5DE8, 5722),
+    (0x5DEB, 6065),
+    (0x5DEE, 7117),
+    (0x5DF0, 14467),
+    (0x5DF1, 5549),
+    (0x5DF2, 5550),
+    (0x5DF3, 5551),
+    (0x5DF4, 5615),
+    (0x5DF7, 6705),
+    (0x5DF9, 12166),
+    (0x5DFD, 8026),
+    (0x5DFE, 5552),
+    (0x5DFF, 11333),
+    (0x5E02, 5725),
+    (0x5E03, 5726),
+    (0x5E04, 11360),
+    (0x5E06, 5869),
+    (0x5E09, 19308),
+    (0x5E0A, 11566),
+    (0x5E0C, 6066),
+    (0x5E0E, 11567),
+    (0x5E11, 6356),
+    (0x5E14, 11821),
+    (0x5E15, 6354),
+    (0x5E16, 6353),
+    (0x5E17, 11820),
+    (0x5E18, 6351),
+    (0x5E19, 11822),
+    (0x5E1A, 6352),
+    (0x5E1B, 6355),
+    (0x5E1D, 6706),
+    (0x5E1F, 6708),
+    (0x5E20, 12170),
+    (0x5E21, 12167),
+    (0x5E22, 12168),
+    (0x5E23, 12169),
+    (0x5E24, 12171),
+    (0x5E25, 6707),
+    (0x5E28, 12612),
+    (0x5E29, 12611),
+    (0x5E2B, 7119),
+    (0x5E2D, 7118),
+    (0x5E2E, 19297),
+    (0x5E33, 7570),
+    (0x5E34, 13194),
+    (0x5E36, 7569),
+    (0x5E37, 7571),
+    (0x5E38, 7568),
+    (0x5E3D, 8028),
+    (0x5E3E, 13193),
+    (0x5E40, 8029),
+    (0x5E41, 13804),
+    (0x5E43, 8030),
+    (0x5E44, 13803),
+    (0x5E45, 8027),
+    (0x5E4A, 14470),
+    (0x5E4B, 14472),
+    (0x5E4C, 8483),
+    (0x5E4D, 14471),
+    (0x5E4E, 14469),
+    (0x5E4F, 14468),
+    (0x5E53, 15154),
+    (0x5E54, 8941),
+    (0x5E55, 8939),
+    (0x5E57, 8940),
+    (0x5E58, 15152),
+    (0x5E59, 15153),
+    (0x5E5B, 8937),
+    (0x5E5C, 15749),
+    (0x5E5D, 15747),
+    (0x5E5F, 9308),
+    (0x5E60, 15748),
+    (0x5E61, 9309),
+    (0x5E62, 9307),
+    (0x5E63, 8938),
+    (0x5E66, 16373),
+    (0x5E67, 16371),
+    (0x5E68, 16372),
+    (0x5E69, 15746),
+    (0x5E6A, 16921),
+    (0x5E6B, 9969),
+    (0x5E6C, 16920),
+    (0x5E6D, 17369),
+    (0x5E6E, 17370),
+    (0x5E6F, 16374),
+    (0x5E70, 17740),
+    (0x5E72, 5553),
+    (0x5E73, 5727),
+    (0x5E74, 5871),
+    (0x5E75, 11425),
+    (0x5E76, 5870),
+    (0x5E78, 6357),
+    (0x5E79, 8484),
+    (0x5E7A, 19300),
+    (0x5E7B, 5616),
+    (0x5E7C, 5728),
+    (0x5E7D, 6709),
+    (0x5E7E, 8031),
+    (0x5E7F, 10941),
+    (0x5E80, 11361),
+    (0x5E82, 11362),
+    (0x5E83, 19251),
+    (0x5E84, 11426),
+    (0x5E87, 6068),
+    (0x5E88, 11571),
+    (0x5E89, 11569),
+    (0x5E8A, 6069),
+    (0x5E8B, 11568),
+    (0x5E8C, 11570),
+    (0x5E8D, 11572),
+    (0x5E8F, 6067),
+    (0x5E95, 6361),
+    (0x5E96, 6362),
+    (0x5E97, 6359),
+    (0x5E9A, 6358),
+    (0x5E9B, 12175),
+    (0x5E9C, 6360),
+    (0x5EA0, 6710),
+    (0x5EA2, 12174),
+    (0x5EA3, 12176),
+    (0x5EA4, 12173),
+    (0x5EA5, 12177),
+    (0x5EA6, 6711),
+    (0x5EA7, 7122),
+    (0x5EA8, 12613),
+    (0x5EAA, 12615),
+    (0x5EAB, 7120),
+    (0x5EAC, 12616),
+    (0x5EAD, 7121),
+    (0x5EAE, 12614),
+    (0x5EB0, 12172),
+    (0x5EB1, 13195),
+    (0x5EB2, 13198),
+    (0x5EB3, 13199),
+    (0x5EB4, 13196),
+    (0x5EB5, 7575),
+    (0x5EB6, 7574),
+    (0x5EB7, 7572),
+    (0x5EB8, 7573),
+    (0x5EB9, 13197),
+    (0x5EBE, 7576),
+    (0x5EC1, 8033),
+    (0x5EC2, 8034),
+    (0x5EC4, 8035),
+    (0x5EC5, 14473),
+    (0x5EC6, 14475),
+    (0x5EC7, 14477),
+    (0x5EC8, 8486),
+    (0x5EC9, 8485),
+    (0x5ECA, 8032),
+    (0x5ECB, 14476),
+    (0x5ECC, 14474),
+    (0x5ECE, 15158),
+    (0x5ED0, 19305),
+    (0x5ED1, 15156),
+    (0x5ED2, 15162),
+    (0x5ED3, 8942),
+    (0x5ED4, 15163),
+    (0x5ED5, 15160),
+    (0x5ED6, 8943),
+    (0x5ED7, 15157),
+    (0x5ED8, 15155),
+    (0x5ED9, 15161),
+    (0x5EDA, 9311),
+    (0x5EDB, 15751),
+    (0x5EDC, 15159),
+    (0x5EDD, 9313),
+    (0x5EDE, 15752),
+    (0x5EDF, 9312),
+    (0x5EE0, 9315),
+    (0x5EE1, 15753),
+    (0x5EE2, 9310),
+    (0x5EE3, 9314),
+    (0x5EE5, 16379),
+    (0x5EE6, 16377),
+    (0x5EE7, 16376),
+    (0x5EE8, 16378),
+    (0x5EE9, 16375),
+    (0x5EEC, 10400),
+    (0x5EEE, 18061),
+    (0x5EEF, 18062),
+    (0x5EF1, 18317),
+    (0x5EF2, 18494),
+    (0x5EF3, 10854),
+    (0x5EF4, 10942),
+    (0x5EF6, 6363),
+    (0x5EF7, 6070),
+    (0x5EF9, 19311),
+    (0x5EFA, 6712),
+    (0x5EFB, 19312),
+    (0x5EFC, 19314),
+    (0x5EFE, 5554),
+    (0x5EFF, 5617),
+    (0x5F01, 5729),
+    (0x5F02, 11427),
+    (0x5F04, 6071),
+    (0x5F05, 11573),
+    (0x5F07, 12178),
+    (0x5F08, 6713),
+    (0x5F0A, 8944),
+    (0x5F0B, 5555),
+    (0x5F0D, 19317),
+    (0x5F0F, 5872),
+    (0x5F12, 8487),
+    (0x5F13, 5556),
+    (0x5F14, 5618),
+    (0x5F15, 5619),
+    (0x5F17, 5731),
+    (0x5F18, 5730),
+    (0x5F1A, 11428),
+    (0x5F1B, 5873),
+    (0x5F1D, 11574),
+    (0x5F1F, 6072),
+    (0x5F22, 11824),
+    (0x5F23, 11825),
+    (0x5F24, 11826),
+    (0x5F26, 6364),
+    (0x5F27, 6365),
+    (0x5F28, 11823),
+    (0x5F29, 6366),
+    (0x5F2D, 6714),
+    (0x5F2E, 12179),
+    (0x5F30, 12618),
+    (0x5F31, 7123),
+    (0x5F33, 12617),
+    (0x5F35, 7577),
+    (0x5F36, 13200),
+    (0x5F37, 7578),
+    (0x5F38, 13201),
+    (0x5F3A, 19323),
+    (0x5F3C, 8036),
+    (0x5F40, 14478),
+    (0x5F43, 15165),
+    (0x5F44, 15164),
+    (0x5F46, 8945),
+    (0x5F48, 9316),
+    (0x5F49, 15754),
+    (0x5F4A, 9681),
+    (0x5F4B, 16380),
+    (0x5F4C, 9970),
+    (0x5F4E, 10727),
+    (0x5F4F, 18644),
+    (0x5F50, 10943),
+    (0x5F54, 11827),
+    (0x5F56, 12180),
+    (0x5F57, 7579),
+    (0x5F58, 13805),
+    (0x5F59, 8488),
+    (0x5F5D, 10226),
+    (0x5F61, 10944),
+    (0x5F62, 6074),
+    (0x5F63, 19328),
+    (0x5F64, 6073),
+    (0x5F65, 6715),
+    (0x5F67, 12619),
+    (0x5F69, 7581),
+    (0x5F6A, 7853),
+    (0x5F6B, 7582),
+    (0x5F6C, 7580),
+    (0x5F6D, 8037),
+    (0x5F6F, 15166),
+    (0x5F70, 8946),
+    (0x5F71, 9317),
+    (0x5F72, 19331),
+    (0x5F73, 11316),
+    (0x5F74, 11429),
+    (0x5F76, 11576),
+    (0x5F77, 6075),
+    (0x5F78, 11575),
+    (0x5F79, 6076),
+    (0x5F7B, 19074),
+    (0x5F7C, 6370),
+    (0x5F7D, 11830),
+    (0x5F7E, 11829),
+    (0x5F7F, 6369),
+    (0x5F80, 6367),
+    (0x5F81, 6368),
+    (0x5F82, 11828),
+    (0x5F85, 6717),
+    (0x5F86, 12181),
+    (0x5F87, 6720),
+    (0x5F88, 6716),
+    (0x5F89, 6722),
+    (0x5F8A, 6718),
+    (0x5F8B, 6719),
+    (0x5F8C, 6721),
+    (0x5F90, 7126),
+    (0x5F91, 7125),
+    (0x5F92, 7124),
+    (0x5F96, 13203),
+    (0x5F97, 7583),
+    (0x5F98, 7586),
+    (0x5F99, 7584),
+    (0x5F9B, 13202),
+    (0x5F9C, 7589),
+    (0x5F9E, 7585),
+    (0x5F9F, 13204),
+    (0x5FA0, 7588),
+    (0x5FA1, 7587),
+    (0x5FA4, 19030),
+    (0x5FA5, 13807),
+    (0x5FA6, 13806),
+    (0x5FA7, 19334),
+    (0x5FA8, 8040),
+    (0x5FA9, 8038),
+    (0x5FAA, 8039),
+    (0x5FAB, 13808),
+    (0x5FAC, 8489),
+    (0x5FAD, 14480),
+    (0x5FAE, 8490),
+    (0x5FAF, 14479),
+    (0x5FB2, 15755),
+    (0x5FB5, 9319),
+    (0x5FB6, 15167),
+    (0x5FB7, 9318),
+    (0x5FB9, 8947),
+    (0x5FBB, 16923),
+    (0x5FBC, 16381),
+    (0x5FBD, 9971),
+    (0x5FBE, 16922),
+    (0x5FBF, 17741),
+    (0x5FC0, 18063),
+    (0x5FC1, 18064),
+    (0x5FC3, 5620),
+    (0x5FC5, 5732),
+    (0x5FC9, 11363),
+    (0x5FCC, 6078),
+    (0x5FCD, 6080),
+    (0x5FCF, 11432),
+    (0x5FD0, 11579),
+    (0x5FD1, 11578),
+    (0x5FD2, 11577),
+    (0x5FD4, 11431),
+    (0x5FD5, 11430),
+    (0x5FD6, 5875),
+    (0x5FD7, 6079),
+    (0x5FD8, 6077),
+    (0x5FD9, 5874),
+    (0x5FDD, 6371),
+    (0x5FDE, 11831),
+    (0x5FDF, 19426),
+    (0x5FE0, 6372),
+    (0x5FE1, 11584),
+    (0x5FE3, 11586),
+    (0x5FE4, 11585),
+    (0x5FE5, 11832),
+    (0x5FE8, 11581),
+    (0x5FEA, 6084),
+    (0x5FEB, 6082),
+    (0x5FED, 11580),
+    (0x5FEE, 11582),
+    (0x5FEF, 11588),
+    (0x5FF1, 6081),
+    (0x5FF3, 11583),
+    (0x5FF4, 11592),
+    (0x5FF5, 6374),
+    (0x5FF7, 11589),
+    (0x5FF8, 6083),
+    (0x5FFA, 11587),
+    (0x5FFB, 11590),
+    (0x5FFD, 6373),
+    (0x5FFF, 6375),
+    (0x6000, 11591),
+    (0x6009, 11852),
+    (0x600A, 11839),
+    (0x600B, 11837),
+    (0x600C, 11851),
+    (0x600D, 11846),
+    (0x600E, 6727),
+    (0x600F, 6376),
+    (0x6010, 11847),
+    (0x6011, 11850),
+    (0x6012, 6723),
+    (0x6013, 11849),
+    (0x6014, 6377),
+    (0x6015, 6382),
+    (0x6016, 6380),
+    (0x6017, 11840),
+    (0x6019, 11835),
+    (0x601A, 11842),
+    (0x601B, 6387),
+    (0x601C, 11853),
+    (0x601D, 6724),
+    (0x601E, 11843),
+    (0x6020, 6725),
+    (0x6021, 6383),
+    (0x6022, 11845),
+    (0x6023, 19382),
+    (0x6024, 12196),
+    (0x6025, 6726),
+    (0x6026, 11834),
+    (0x6027, 6384),
+    (0x6028, 6728),
+    (0x6029, 6385),
+    (0x602A, 6381),
+    (0x602B, 6386),
+    (0x602C, 11844),
+    (0x602D, 11833),
+    (0x602E, 11848),
+    (0x602F, 6378),
+    (0x6031, 19344),
+    (0x6032, 11836),
+    (0x6033, 11841),
+    (0x6034, 11838),
+    (0x6035, 6379),
+    (0x6037, 12182),
+    (0x6039, 12183),
+    (0x6040, 12193),
+    (0x6041, 12623),
+    (0x6042, 12194),
+    (0x6043, 6734),
+    (0x6044, 12197),
+    (0x6045, 12187),
+    (0x6046, 6733),
+    (0x6047, 12189),
+    (0x6049, 12190),
+    (0x604A, 19102),
+    (0x604C, 12192),
+    (0x604D, 6729),
+    (0x6050, 7130),
+    (0x6052, 18960),
+    (0x6053, 12188),
+    (0x6054, 12184),
+    (0x6055, 7131),
+    (0x6058, 12198),
+    (0x6059, 7127),
+    (0x605A, 12621),
+    (0x605B, 12191),
+    (0x605D, 12620),
+    (0x605E, 12186),
+    (0x605F, 12195),
+    (0x6062, 6732),
+    (0x6063, 7128),
+    (0x6064, 6738),
+    (0x6065, 7129),
+    (0x6066, 12199),
+    (0x6067, 12622),
+    (0x6068, 6731),
+    (0x6069, 7133),
+    (0x606A, 6737),
+    (0x606B, 6736),
+    (0x606C, 6735),
+    (0x606D, 7132),
+    (0x606E, 12200),
+    (0x606F, 7134),
+    (0x6070, 6730),
+    (0x6072, 12185),
+    (0x607F, 7590),
+    (0x6080, 12626),
+    (0x6081, 12628),
+    (0x6083, 12630),
+    (0x6084, 7135),
+    (0x6085, 7141),
+    (0x6086, 13207),
+    (0x6087, 12634),
+    (0x6088, 12625),
+    (0x6089, 7592),
+    (0x608A, 13205),
+    (0x608C, 7140),
+    (0x608D, 7138),
+    (0x608E, 12636),
+    (0x6090, 13206),
+    (0x6092, 12627),
+    (0x6094, 7139),
+    (0x6095, 12631),
+    (0x6096, 7142),
+    (0x6097, 12633),
+    (0x609A, 7137),
+    (0x609B, 12632),
+    (0x609C, 12635),
+    (0x609D, 12629),
+    (0x609E, 19354),
+    (0x609F, 7136),
+    (0x60A0, 7593),
+    (0x60A2, 12624),
+    (0x60A3, 7591),
+    (0x60A4, 19355),
+    (0x60A8, 7594),
+    (0x60B0, 13209),
+    (0x60B1, 13218),
+    (0x60B2, 8043),
+    (0x60B3, 19356),
+    (0x60B4, 7596),
+    (0x60B5, 7601),
+    (0x60B6, 8044),
+    (0x60B7, 13220),
+    (0x60B8, 7608),
+    (0x60B9, 13810),
+    (0x60BA, 13210),
+    (0x60BB, 7600),
+    (0x60BC, 7603),
+    (0x60BD, 7598),
+    (0x60BE, 13208),
+    (0x60BF, 13222),
+    (0x60C0, 13225),
+    (0x60C1, 13825),
+    (0x60C3, 13223),
+    (0x60C4, 13814),
+    (0x60C5, 7599),
+    (0x60C6, 7606),
+    (0x60C7, 7610),
+    (0x60C8, 13217),
+    (0x60C9, 13809),
+    (0x60CA, 13221),
+    (0x60CB, 7595),
+    (0x60CC, 13811),
+    (0x60CD, 13224),
+    (0x60CE, 13813),
+    (0x60CF, 13213),
+    (0x60D1, 8041),
+    (0x60D3, 13211),
+    (0x60D4, 13212),
+    (0x60D5, 7605),
+    (0x60D8, 7604),
+    (0x60D9, 13215),
+    (0x60DA, 7609),
+    (0x60DB, 13219),
+    (0x60DC, 7602),
+    (0x60DD, 13216),
+    (0x60DE, 19504),
+    (0x60DF, 7607),
+    (0x60E0, 8045),
+    (0x60E1, 8042),
+    (0x60E2, 13812),
+    (0x60E4, 13214),
+    (0x60E6, 7597),
+    (0x60F0, 8050),
+    (0x60F1, 8054),
+    (0x60F2, 13816),
+    (0x60F3, 8495),
+    (0x60F4, 8052),
+    (0x60F5, 13820),
+    (0x60F6, 8056),
+    (0x60F7, 14481),
+    (0x60F8, 13822),
+    (0x60F9, 8497),
+    (0x60FA, 8048),
+    (0x60FB, 8051),
+    (0x60FC, 13823),
+    (0x60FE, 13824),
+    (0x60FF, 13830),
+    (0x6100, 8058),
+    (0x6101, 8498),
+    (0x6103, 13826),
+    (0x6104, 13831),
+    (0x6105, 13819),
+    (0x6106, 8508),
+    (0x6108, 8499),
+    (0x6109, 8057),
+    (0x610A, 13817),
+    (0x610B, 13832),
+    (0x610D, 8507),
+    (0x610E, 8055),
+    (0x610F, 8492),
+    (0x6110, 13829),
+    (0x6112, 8059),
+    (0x6113, 13821),
+    (0x6114, 13815),
+    (0x6115, 8049),
+    (0x6116, 13818),
+    (0x6118, 13827),
+    (0x611A, 8491),
+    (0x611B, 8496),
+    (0x611C, 8046),
+    (0x611D, 13828),
+    (0x611F, 8494),
+    (0x6123, 8047),
+    (0x6127, 8506),
+    (0x6128, 15169),
+    (0x6129, 14492),
+    (0x612B, 14484),
+    (0x612C, 15168),
+    (0x612E, 14488),
+    (0x612F, 14490),
+    (0x6132, 14487),
+    (0x6134, 8505),
+    (0x6136, 14486),
+    (0x6137, 8509),
+    (0x613B, 15184),
+    (0x613E, 8504),
+    (0x613F, 8949),
+    (0x6140, 14493),
+    (0x6141, 15170),
+    (0x6142, 19365),
+    (0x6144, 8502),
+    (0x6145, 14485),
+    (0x6146, 14489),
+    (0x6147, 8948),
+    (0x6148, 8493),
+    (0x6149, 14482),
+    (0x614A, 14483),
+    (0x614B, 8950),
+    (0x614C, 8501),
+    (0x614D, 8503),
+    (0x614E, 8500),
+    (0x614F, 14491),
+    (0x6152, 15174),
+    (0x6153, 15175),
+    (0x6154, 15180),
+    (0x6155, 9324),
+    (0x6156, 15187),
+    (0x6158, 8956),
+    (0x615A, 8955),
+    (0x615B, 15182),
+    (0x615C, 19383),
+    (0x615D, 9323),
+    (0x615E, 15171),
+    (0x615F, 8954),
+    (0x6160, 19261),
+    (0x6161, 15186),
+    (0x6162, 8952),
+    (0x6163, 8953),
+    (0x6164, 19363),
+    (0x6165, 15183),
+    (0x6166, 15772),
+    (0x6167, 9321),
+    (0x6168, 8053),
+    (0x616A, 15185),
+    (0x616B, 9328),
+    (0x616C, 15177),
+    (0x616E, 9322),
+    (0x6170, 9327),
+    (0x6171, 15172),
+    (0x6172, 15176),
+    (0x6173, 15173),
+    (0x6174, 15179),
+    (0x6175, 8957),
+    (0x6176, 9320),
+    (0x6177, 8951),
+    (0x6179, 15758),
+    (0x617A, 15181),
+    (0x617C, 9326),
+    (0x617E, 9329),
+    (0x6180, 15178),
+    (0x6181, 19368),
+    (0x6182, 9325),
+    (0x6183, 15757),
+    (0x6187, 19371),
+    (0x6189, 15762),
+    (0x618A, 9685),
+    (0x618B, 15756),
+    (0x618C, 16393),
+    (0x618D, 15771),
+    (0x618E, 9333),
+    (0x6190, 9331),
+    (0x6191, 9683),
+    (0x6192, 15768),
+    (0x6193, 15764),
+    (0x6194, 9337),
+    (0x6196, 16384),
+    (0x619A, 9335),
+    (0x619B, 15763),
+    (0x619D, 16382),
+    (0x619F, 15767),
+    (0x61A1, 15770),
+    (0x61A2, 15761),
+    (0x61A4, 9336),
+    (0x61A7, 9330),
+    (0x61A8, 16383),
+    (0x61A9, 9684),
+    (0x61AA, 15769),
+    (0x61AB, 9332),
+    (0x61AC, 9334),
+    (0x61AD, 15766),
+    (0x61AE, 9338),
+    (0x61AF, 15765),
+    (0x61B0, 15760),
+    (0x61B1, 15759),
+    (0x61B2, 9682),
+    (0x61B3, 15773),
+    (0x61B4, 16386),
+    (0x61B5, 16925),
+    (0x61B6, 9687),
+    (0x61B8, 16392),
+    (0x61BA, 16390),
+    (0x61BC, 16926),
+    (0x61BE, 9688),
+    (0x61BF, 16391),
+    (0x61C1, 16388),
+    (0x61C2, 9973),
+    (0x61C3, 16924),
+    (0x61C5, 16385),
+    (0x61C6, 16387),
+    (0x61C7, 9974),
+    (0x61C8, 9690),
+    (0x61C9, 9972),
+    (0x61CA, 9689),
+    (0x61CB, 9976),
+    (0x61CC, 16389),
+    (0x61CD, 9686),
+    (0x61D0, 19378),
+    (0x61D3, 19375),
+    (0x61D6, 17379),
+    (0x61D8, 17371),
+    (0x61DE, 16932),
+    (0x61DF, 17372),
+    (0x61E0, 16928),
+    (0x61E3, 10227),
+    (0x61E4, 16930),
+    (0x61E5, 16929),
+    (0x61E6, 9975),
+    (0x61E7, 16927),
+    (0x61E8, 16931),
+    (0x61E9, 17380),
+    (0x61EA, 17376),
+    (0x61EB, 17378),
+    (0x61ED, 17373),
+    (0x61EE, 17374),
+    (0x61F0, 17377),
+    (0x61F1, 17375),
+    (0x61F2, 10401),
+    (0x61F5, 10404),
+    (0x61F6, 10403),
+    (0x61F7, 10402),
+    (0x61F8, 10552),
+    (0x61F9, 18065),
+    (0x61FA, 10553),
+    (0x61FB, 17742),
+    (0x61FC, 10644),
+    (0x61FD, 18318),
+    (0x61FE, 10645),
+    (0x61FF, 10728),
+    (0x6200, 10780),
+    (0x6201, 18645),
+    (0x6203, 18646),
+    (0x6204, 18647),
+    (0x6207, 18928),
+    (0x6208, 5621),
+    (0x6209, 11364),
+    (0x620A, 5733),
+    (0x620C, 5877),
+    (0x620D, 5878),
+    (0x620E, 5876),
+    (0x6210, 5879),
+    (0x6211, 6086),
+    (0x6212, 6085),
+    (0x6214, 11854),
+    (0x6215, 6389),
+    (0x6216, 6388),
+    (0x6219, 12637),
+    (0x621A, 7611),
+    (0x621B, 7612),
+    (0x621F, 8060),
+    (0x6220, 14494),
+    (0x6221, 8510),
+    (0x6222, 8511),
+    (0x6223, 14496),
+    (0x6224, 14498),
+    (0x6225, 14497),
+    (0x6227, 15189),
+    (0x6229, 15188),
+    (0x622A, 8958),
+    (0x622B, 15190),
+    (0x622D, 15774),
+    (0x622E, 9339),
+    (0x6230, 9691),
+    (0x6232, 9977),
+    (0x6233, 10228),
+    (0x6234, 9978),
+    (0x6236, 5622),
+    (0x623A, 11593),
+    (0x623D, 11855),
+    (0x623E, 6391),
+    (0x623F, 6390),
+    (0x6240, 6392),
+    (0x6241, 6739),
+    (0x6242, 12201),
+    (0x6243, 12202),
+    (0x6246, 12638),
+    (0x6247, 7143),
+    (0x6248, 7613),
+    (0x6249, 8061),
+    (0x624A, 13833),
+    (0x624B, 5623),
+    (0x624D, 5557),
+    (0x624E, 5624),
+    (0x6250, 11365),
+    (0x6251, 5737),
+    (0x6252, 5736),
+    (0x6253, 5734),
+    (0x6254, 5735),
+    (0x6258, 5882),
+    (0x6259, 11439),
+    (0x625A, 11441),
+    (0x625B, 5881),
+    (0x625C, 11433),
+    (0x625E, 11434),
+    (0x6260, 11440),
+    (0x6261, 11436),
+    (0x6262, 11438),
+    (0x6263, 5880),
+    (0x6264, 11435),
+    (0x6265, 11442),
+    (0x6266, 11437),
+    (0x626D, 6093),
+    (0x626E, 6102),
+    (0x626F, 6100),
+    (0x6270, 11603),
+    (0x6271, 11600),
+    (0x6272, 11608),
+    (0x6273, 6098),
+    (0x6274, 11609),
+    (0x6276, 6091),
+    (0x6277, 11606),
+    (0x6279, 6097),
+    (0x627A, 11602),
+    (0x627B, 11601),
+    (0x627C, 6095),
+    (0x627D, 11607),
+    (0x627E, 6096),
+    (0x627F, 6393),
+    (0x6280, 6090),
+    (0x6281, 11604),
+    (0x6283, 11594),
+    (0x6284, 6087),
+    (0x6286, 6106),
+    (0x6287, 11599),
+    (0x6288, 11605),
+    (0x6289, 6092),
+    (0x628A, 6094),
+    (0x628C, 11595),
+    (0x628E, 11596),
+    (0x628F, 11597),
+    (0x6291, 6105),
+    (0x6292, 6099),
+    (0x6293, 6104),
+    (0x6294, 11598),
+    (0x6295, 6103),
+    (0x6296, 6089),
+    (0x6297, 6088),
+    (0x6298, 6101),
+    (0x62A8, 6407),
+    (0x62A9, 11867),
+    (0x62AA, 11860),
+    (0x62AB, 6402),
+    (0x62AC, 6421),
+    (0x62AD, 11856),
+    (0x62AE, 11863),
+    (0x62AF, 11865),
+    (0x62B0, 11868),
+    (0x62B1, 6416),
+    (0x62B3, 11864),
+    (0x62B4, 11857),
+    (0x62B5, 6414),
+    (0x62B6, 11861),
+    (0x62B8, 11869),
+    (0x62B9, 6399),
+    (0x62BB, 11866),
+    (0x62BC, 6409),
+    (0x62BD, 6408),
+    (0x62BE, 11859),
+    (0x62BF, 6397),
+    (0x62C2, 6398),
+    (0x62C4, 6396),
+    (0x62C5, 19387),
+    (0x62C6, 6420),
+    (0x62C7, 6412),
+    (0x62C8, 6406),
+    (0x62C9, 6394),
+    (0x62CA, 11862),
+    (0x62CB, 6405),
+    (0x62CC, 6395),
+    (0x62CD, 6413),
+    (0x62CE, 6422),
+    (0x62CF, 12203),
+    (0x62D0, 6410),
+    (0x62D1, 11858),
+    (0x62D2, 6400),
+    (0x62D3, 6403),
+    (0x62D4, 6404),
+    (0x62D5, 19389),
+    (0x62D6, 6418),
+    (0x62D7, 6419),
+    (0x62D8, 6417),
+    (0x62D9, 6411),
+    (0x62DA, 6415),
+    (0x62DB, 6401),
+    (0x62DC, 6740),
+    (0x62DF, 19014),
+    (0x62EB, 12209),
+    (0x62EC, 6752),
+    (0x62ED, 6744),
+    (0x62EE, 6746),
+    (0x62EF, 6751),
+    (0x62F0, 12221),
+    (0x62F1, 6749),
+    (0x62F2, 12639),
+    (0x62F3, 7144),
+    (0x62F4, 6754),
+    (0x62F5, 12206),
+    (0x62F6, 12214),
+    (0x62F7, 6750),
+    (0x62F8, 12213),
+    (0x62F9, 12210),
+    (0x62FA, 12218),
+    (0x62FB, 12220),
+    (0x62FC, 6743),
+    (0x62FD, 6747),
+    (0x62FE, 6753),
+    (0x62FF, 7146),
+    (0x6300, 12215),
+    (0x6301, 6745),
+    (0x6302, 6756),
+    (0x6303, 12208),
+    (0x6307, 6748),
+    (0x6308, 7145),
+    (0x6309, 6742),
+    (0x630B, 12205),
+    (0x630C, 12212),
+    (0x630D, 12204),
+    (0x630E, 12207),
+    (0x630F, 12211),
+    (0x6310, 12640),
+    (0x6311, 6755),
+    (0x6313, 12216),
+    (0x6314, 12217),
+    (0x6315, 12219),
+    (0x6316, 6741),
+    (0x6328, 7160),
+    (0x6329, 12652),
+    (0x632A, 7158),
+    (0x632B, 7159),
+    (0x632C, 12642),
+    (0x632D, 12658),
+    (0x632F, 7149),
+    (0x6332, 13226),
+    (0x6333, 12660),
+    (0x6334, 12654),
+    (0x6336, 12645),
+    (0x6338, 12663),
+    (0x6339, 12648),
+    (0x633A, 7155),
+    (0x633B, 13249),
+    (0x633C, 12651),
+    (0x633D, 7157),
+    (0x633E, 7148),
+    (0x6340, 12665),
+    (0x6341, 12653),
+    (0x6342, 7151),
+    (0x6343, 12646),
+    (0x6344, 12643),
+    (0x6345, 12644),
+    (0x6346, 7152),
+    (0x6347, 12659),
+    (0x6348, 12666),
+    (0x6349, 7154),
+    (0x634A, 12650),
+    (0x634B, 12649),
+    (0x634C, 7162),
+    (0x634D, 7161),
+    (0x634E, 7147),
+    (0x634F, 7153),
+    (0x6350, 7156),
+    (0x6351, 12662),
+    (0x6354, 12656),
+    (0x6355, 7150),
+    (0x6356, 12641),
+    (0x6357, 12664),
+    (0x6358, 12655),
+    (0x6359, 12657),
+    (0x635A, 12661),
+    (0x6365, 13227),
+    (0x6367, 7621),
+    (0x6368, 7641),
+    (0x6369, 7640),
+    (0x636B, 7629),
+    (0x636C, 19391),
+    (0x636D, 13245),
+    (0x636E, 13241),
+    (0x636F, 13238),
+    (0x6370, 13256),
+    (0x6371, 7624),
+    (0x6372, 7616),
+    (0x6375, 13243),
+    (0x6376, 8077),
+    (0x6377, 7620),
+    (0x6378, 13251),
+    (0x637A, 7642),
+    (0x637B, 7639),
+    (0x637C, 13247),
+    (0x637D, 13230),
+    (0x6380, 7638),
+    (0x6381, 13253),
+    (0x6382, 13229),
+    (0x6383, 7627),
+    (0x6384, 7631),
+    (0x6385, 13252),
+    (0x6387, 13239),
+    (0x6388, 7632),
+    (0x6389, 7626),
+    (0x638A, 13228),
+    (0x638B, 19385),
+    (0x638C, 8063),
+    (0x638D, 13255),
+    (0x638E, 13237),
+    (0x638F, 7637),
+    (0x6390, 13240),
+    (0x6391, 13254),
+    (0x6392, 7636),
+    (0x6394, 13834),
+    (0x6396, 7617),
+    (0x6397, 13235),
+    (0x6398, 7622),
+    (0x6399, 7633),
+    (0x639B, 7628),
+    (0x639C, 13244),
+    (0x639D, 13234),
+    (0x639E, 13232),
+    (0x639F, 13250),
+    (0x63A0, 7614),
+    (0x63A1, 7634),
+    (0x63A2, 7618),
+    (0x63A3, 8062),
+    (0x63A4, 13248),
+    (0x63A5, 7619),
+    (0x63A7, 7615),
+    (0x63A8, 7630),
+    (0x63A9, 7625),
+    (0x63AA, 7623),
+    (0x63AB, 13236),
+    (0x63AC, 7635),
+    (0x63AD, 13233),
+    (0x63AE, 13246),
+    (0x63AF, 13242),
+    (0x63B0, 13836),
+    (0x63B1, 13835),
+    (0x63BD, 13231),
+    (0x63BE, 13852),
+    (0x63C0, 8065),
+    (0x63C2, 13858),
+    (0x63C3, 13841),
+    (0x63C4, 13855),
+    (0x63C5, 14499),
+    (0x63C6, 8068),
+    (0x63C7, 13859),
+    (0x63C8, 13862),
+    (0x63C9, 8067),
+    (0x63CA, 13844),
+    (0x63CB, 13861),
+    (0x63CC, 13860),
+    (0x63CD, 8069),
+    (0x63CE, 13837),
+    (0x63CF, 8064),
+    (0x63D0, 8072),
+    (0x63D2, 8070),
+    (0x63D3, 13857),
+    (0x63D5, 13847),
+    (0x63D6, 8074),
+    (0x63D7, 13864),
+    (0x63D8, 13856),
+    (0x63D9, 13865),
+    (0x63DA, 8082),
+    (0x63DB, 8080),
+    (0x63DC, 13854),
+    (0x63DD, 13853),
+    (0x63DF, 13851),
+    (0x63E0, 13845),
+    (0x63E1, 8073),
+    (0x63E3, 8071),
+    (0x63E4, 12647),
+    (0x63E5, 13838),
+    (0x63E7, 14529),
+    (0x63E8, 13839),
+    (0x63E9, 8066),
+    (0x63EA, 8079),
+    (0x63EB, 14501),
+    (0x63ED, 8075),
+    (0x63EE, 8076),
+    (0x63EF, 13840),
+    (0x63F0, 13863),
+    (0x63F1, 14500),
+    (0x63F2, 13848),
+    (0x63F3, 13843),
+    (0x63F4, 8078),
+    (0x63F5, 13849),
+    (0x63F6, 13846),
+    (0x63F8, 19395),
+    (0x63F9, 8083),
+    (0x6406, 8526),
+    (0x6409, 14504),
+    (0x640A, 14523),
+    (0x640B, 14528),
+    (0x640C, 14516),
+    (0x640D, 8522),
+    (0x640E, 14533),
+    (0x640F, 8519),
+    (0x6410, 14502),
+    (0x6412, 14503),
+    (0x6413, 8512),
+    (0x6414, 8521),
+    (0x6415, 14510),
+    (0x6416, 8524),
+    (0x6417, 8525),
+    (0x6418, 14511),
+    (0x641A, 14524),
+    (0x641B, 14530),
+    (0x641C, 8520),
+    (0x641E, 8514),
+    (0x641F, 14509),
+    (0x6420, 14505),
+    (0x6421, 14532),
+    (0x6422, 14514),
+    (0x6423, 14515),
+    (0x6424, 14506),
+    (0x6425, 14526),
+    (0x6426, 14517),
+    (0x6427, 14527),
+    (0x6428, 14519),
+    (0x642A, 8515),
+    (0x642B, 15191),
+    (0x642C, 8518),
+    (0x642D, 8516),
+    (0x642E, 14531),
+    (0x642F, 14522),
+    (0x6430, 14518),
+    (0x6433, 14507),
+    (0x6434, 8968),
+    (0x6435, 14521),
+    (0x6436, 8523),
+    (0x6437, 14513),
+    (0x6438, 19394),
+    (0x6439, 14512),
+    (0x643A, 19408),
+    (0x643D, 8517),
+    (0x643E, 8513),
+    (0x643F, 15212),
+    (0x6440, 14525),
+    (0x6441, 14520),
+    (0x6443, 14508),
+    (0x644B, 15207),
+    (0x644D, 15192),
+    (0x644E, 15203),
+    (0x6450, 15210),
+    (0x6451, 8966),
+    (0x6452, 8081),
+    (0x6453, 15208),
+    (0x6454, 8961),
+    (0x6458, 8960),
+    (0x6459, 15215),
+    (0x645B, 15193),
+    (0x645C, 15206),
+    (0x645D, 15194),
+    (0x645E, 15205),
+    (0x645F, 8964),
+    (0x6460, 15209),
+    (0x6461, 13850),
+    (0x6465, 15216),
+    (0x6466, 15201),
+    (0x6467, 8967),
+    (0x6468, 15788),
+    (0x6469, 9340),
+    (0x646B, 15214),
+    (0x646C, 15213),
+    (0x646D, 8969),
+    (0x646E, 15775),
+    (0x646F, 9341),
+    (0x6470, 15776),
+    (0x6472, 15197),
+    (0x6473, 15198),
+    (0x6474, 15195),
+    (0x6475, 15200),
+    (0x6476, 15196),
+    (0x6477, 15217),
+    (0x6478, 8963),
+    (0x6479, 9342),
+    (0x647A, 8965),
+    (0x647B, 8970),
+    (0x647D, 15199),
+    (0x647F, 15211),
+    (0x6482, 15204),
+    (0x6485, 15779),
+    (0x6487, 8959),
+    (0x6488, 9345),
+    (0x6489, 16400),
+    (0x648A, 15784),
+    (0x648B, 15783),
+    (0x648C, 15785),
+    (0x648F, 15782),
+    (0x6490, 9346),
+    (0x6492, 9352),
+    (0x6493, 9349),
+    (0x6495, 9350),
+    (0x6496, 15777),
+    (0x6497, 15780),
+    (0x6498, 15790),
+    (0x6499, 9358),
+    (0x649A, 9356),
+    (0x649C, 15781),
+    (0x649D, 13842),
+    (0x649E, 9343),
+    (0x649F, 15787),
+    (0x64A0, 15778),
+    (0x64A2, 9359),
+    (0x64A3, 15786),
+    (0x64A4, 8962),
+    (0x64A5, 9348),
+    (0x64A6, 15202),
+    (0x64A9, 9351),
+    (0x64AB, 9355),
+    (0x64AC, 9357),
+    (0x64AD, 9354),
+    (0x64AE, 9353),
+    (0x64AF, 19739),
+    (0x64B0, 9347),
+    (0x64B1, 15789),
+    (0x64B2, 9344),
+    (0x64B3, 9360),
+    (0x64BB, 9695),
+    (0x64BC, 9696),
+    (0x64BD, 16399),
+    (0x64BE, 9705),
+    (0x64BF, 9702),
+    (0x64C1, 9693),
+    (0x64C2, 9700),
+    (0x64C3, 16401),
+    (0x64C4, 9698),
+    (0x64C5, 9692),
+    (0x64C7, 9699),
+    (0x64C9, 16398),
+    (0x64CA, 9980),
+    (0x64CB, 9694),
+    (0x64CD, 9701),
+    (0x64CE, 9979),
+    (0x64CF, 16397),
+    (0x64D0, 16396),
+    (0x64D2, 9703),
+    (0x64D4, 9704),
+    (0x64D6, 16395),
+    (0x64D7, 16394),
+    (0x64D8, 9981),
+    (0x64D9, 16404),
+    (0x64DA, 9697),
+    (0x64DB, 16402),
+    (0x64E0, 9982),
+    (0x64E1, 19404),
+    (0x64E2, 9987),
+    (0x64E3, 16935),
+    (0x64E4, 16937),
+    (0x64E5, 19405),
+    (0x64E6, 9984),
+    (0x64E8, 16938),
+    (0x64E9, 16934),
+    (0x64EB, 16936),
+    (0x64EC, 9985),
+    (0x64ED, 9988),
+    (0x64EF, 16933),
+    (0x64F0, 9983),
+    (0x64F1, 9986),
+    (0x64F2, 10230),
+    (0x64F3, 16403),
+    (0x64F4, 10229),
+    (0x64F7, 10235),
+    (0x64F8, 17384),
+    (0x64FA, 10233),
+    (0x64FB, 10234),
+    (0x64FC, 17387),
+    (0x64FD, 17383),
+    (0x64FE, 10231),
+    (0x64FF, 17381),
+    (0x6500, 10405),
+    (0x6501, 17385),
+    (0x6503, 17386),
+    (0x6504, 17382),
+    (0x6506, 10232),
+    (0x6507, 17743),
+    (0x6509, 17746),
+    (0x650C, 17747),
+    (0x650D, 17745),
+    (0x650E, 17748),
+    (0x650F, 10406),
+    (0x6510, 17744),
+    (0x6513, 18069),
+    (0x6514, 10555),
+    (0x6515, 18068),
+    (0x6516, 18067),
+    (0x6517, 18066),
+    (0x6518, 10554),
+    (0x6519, 10556),
+    (0x651B, 18319),
+    (0x651C, 10647),
+    (0x651D, 10646),
+    (0x651E, 19384),
+    (0x6520, 18496),
+    (0x6521, 18495),
+    (0x6522, 18498),
+    (0x6523, 10781),
+    (0x6524, 10729),
+    (0x6525, 18649),
+    (0x6526, 18497),
+    (0x6529, 18648),
+    (0x652A, 10783),
+    (0x652B, 10782),
+    (0x652C, 10821),
+    (0x652D, 18754),
+    (0x652E, 18824),
+    (0x652F, 5625),
+    (0x6532, 13866),
+    (0x6533, 16405),
+    (0x6534, 10945),
+    (0x6536, 5883),
+    (0x6537, 11610),
+    (0x6538, 6109),
+    (0x6539, 6107),
+    (0x653B, 6108),
+    (0x653D, 11870),
+    (0x653E, 6423),
+    (0x653F, 6757),
+    (0x6541, 12222),
+    (0x6543, 12223),
+    (0x6545, 6758),
+    (0x6546, 12668),
+    (0x6548, 7163),
+    (0x6549, 7164),
+    (0x654A, 12667),
+    (0x654D, 19410),
+    (0x654F, 7649),
+    (0x6551, 7645),
+    (0x6553, 13257),
+    (0x6554, 7652),
+    (0x6555, 7651),
+    (0x6556, 7644),
+    (0x6557, 7647),
+    (0x6558, 7650),
+    (0x6559, 7646),
+    (0x655C, 13870),
+    (0x655D, 7643),
+    (0x655E, 8084),
+    (0x655F, 19416),
+    (0x6562, 8086),
+    (0x6563, 8087),
+    (0x6564, 13869),
+    (0x6565, 13872),
+    (0x6566, 8085),
+    (0x6567, 13867),
+    (0x6568, 13871),
+    (0x656A, 13868),
+    (0x656C, 8527),
+    (0x656D, 19415),
+    (0x656F, 14534),
+    (0x6572, 8971),
+    (0x6573, 15218),
+    (0x6574, 9706),
+    (0x6575, 9361),
+    (0x6576, 15791),
+    (0x6577, 9362),
+    (0x6578, 9363),
+    (0x6579, 15793),
+    (0x657A, 15792),
+    (0x657B, 15794),
+    (0x657C, 16407),
+    (0x657F, 16406),
+    (0x6580, 16940),
+    (0x6581, 16939),
+    (0x6582, 9989),
+    (0x6583, 9990),
+    (0x6584, 17749),
+    (0x6585, 19414),
+    (0x6587, 5626),
+    (0x6588, 19254),
+    (0x658C, 13873),
+    (0x6590, 8089),
+    (0x6591, 8088),
+    (0x6592, 14535),
+    (0x6594, 17388),
+    (0x6595, 10648),
+    (0x6596, 18650),
+    (0x6597, 5627),
+    (0x6599, 7165),
+    (0x659B, 7654),
+    (0x659C, 7653),
+    (0x659D, 13874),
+    (0x659E, 13875),
+    (0x659F, 8528),
+    (0x65A0, 15219),
+    (0x65A1, 8972),
+    (0x65A2, 16408),
+    (0x65A4, 5628),
+    (0x65A5, 5738),
+    (0x65A7, 6424),
+    (0x65A8, 11871),
+    (0x65AA, 12224),
+    (0x65AB, 6759),
+    (0x65AC, 7655),
+    (0x65AE, 13876),
+    (0x65AF, 8090),
+    (0x65B0, 8529),
+    (0x65B2, 15795),
+    (0x65B3, 15796),
+    (0x65B5, 19418),
+    (0x65B6, 16941),
+    (0x65B7, 10236),
+    (0x65B8, 18825),
+    (0x65B9, 5629),
+    (0x65BB, 11872),
+    (0x65BC, 6425),
+    (0x65BD, 6760),
+    (0x65BF, 12225),
+    (0x65C1, 7166),
+    (0x65C2, 12672),
+    (0x65C3, 12670),
+    (0x65C4, 12671),
+    (0x65C5, 7167),
+    (0x65C6, 12669),
+    (0x65CB, 7657),
+    (0x65CC, 7658),
+    (0x65CD, 13258),
+    (0x65CE, 7659),
+    (0x65CF, 7656),
+    (0x65D0, 13877),
+    (0x65D1, 19421),
+    (0x65D2, 13878),
+    (0x65D3, 14536),
+    (0x65D6, 8974),
+    (0x65D7, 8973),
+    (0x65DA, 16942),
+    (0x65DB, 17389),
+    (0x65DD, 17751),
+    (0x65DE, 17750),
+    (0x65DF, 18070),
+    (0x65E0, 10946),
+    (0x65E1, 11334),
+    (0x65E2, 6761),
+    (0x65E3, 19425),
+    (0x65E5, 5630),
+    (0x65E6, 5739),
+    (0x65E8, 5885),
+    (0x65E9, 5884),
+    (0x65EC, 5886),
+    (0x65ED, 5887),
+    (0x65EE, 11444),
+    (0x65EF, 11443),
+    (0x65F0, 11611),
+    (0x65F1, 6110),
+    (0x65F2, 11614),
+    (0x65F3, 11613),
+    (0x65F4, 11612),
+    (0x65F5, 11615),
+    (0x65FA, 6426),
+    (0x65FB, 11878),
+    (0x65FC, 11874),
+    (0x65FD, 11883),
+    (0x6600, 6433),
+    (0x6602, 6431),
+    (0x6603, 11879),
+    (0x6604, 11875),
+    (0x6605, 11882),
+    (0x6606, 6430),
+    (0x6607, 6437),
+    (0x6608, 11877),
+    (0x6609, 11873),
+    (0x660A, 6436),
+    (0x660B, 11880),
+    (0x660C, 6429),
+    (0x660D, 11881),
+    (0x660E, 6432),
+    (0x660F, 6434),
+    (0x6610, 11885),
+    (0x6611, 11884),
+    (0x6612, 11876),
+    (0x6613, 6428),
+    (0x6614, 6427),
+    (0x6615, 6435),
+    (0x6618, 19428),
+    (0x661C, 12230),
+    (0x661D, 12236),
+    (0x661F, 6767),
+    (0x6620, 6764),
+    (0x6621, 12227),
+    (0x6622, 12232),
+    (0x6623, 19660),
+    (0x6624, 6770),
+    (0x6625, 6762),
+    (0x6626, 12231),
+    (0x6627, 6765),
+    (0x6628, 6768),
+    (0x662B, 12234),
+    (0x662D, 6763),
+    (0x662E, 12239),
+    (0x662F, 6766),
+    (0x6631, 6769),
+    (0x6632, 12228),
+    (0x6633, 12233),
+    (0x6634, 12237),
+    (0x6635, 12229),
+    (0x6636, 12226),
+    (0x6639, 12238),
+    (0x663A, 12235),
+    (0x6641, 7175),
+    (0x6642, 7168),
+    (0x6643, 7171),
+    (0x6644, 19431),
+    (0x6645, 7174),
+    (0x6647, 12675),
+    (0x6649, 7169),
+    (0x664A, 12673),
+    (0x664B, 19434),
+    (0x664C, 7173),
+    (0x664F, 7170),
+    (0x6651, 12676),
+    (0x6652, 7172),
+    (0x6657, 19141),
+    (0x6659, 13262),
+    (0x665A, 7661),
+    (0x665B, 13261),
+    (0x665C, 13263),
+    (0x665D, 7660),
+    (0x665E, 7665),
+    (0x665F, 12674),
+    (0x6661, 13260),
+    (0x6662, 13264),
+    (0x6664, 7662),
+    (0x6665, 13259),
+    (0x6666, 7664),
+    (0x6667, 19436),
+    (0x6668, 7663),
+    (0x666A, 13885),
+    (0x666C, 13880),
+    (0x666E, 8091),
+    (0x666F, 8095),
+    (0x6670, 8092),
+    (0x6671, 13883),
+    (0x6672, 13886),
+    (0x6673, 19438),
+    (0x6674, 8093),
+    (0x6676, 8094),
+    (0x6677, 8099),
+    (0x6678, 14545),
+    (0x6679, 13884),
+    (0x667A, 8097),
+    (0x667B, 13881),
+    (0x667C, 13879),
+    (0x667E, 8098),
+    (0x6680, 13882),
+    (0x6684, 8535),
+    (0x6685, 19345),
+    (0x6686, 14537),
+    (0x6687, 8532),
+    (0x6688, 8533),
+    (0x6689, 8531),
+    (0x668A, 14542),
+    (0x668B, 14541),
+    (0x668C, 14538),
+    (0x668D, 8537),
+    (0x6690, 14540),
+    (0x6691, 8096),
+    (0x6694, 14544),
+    (0x6695, 14539),
+    (0x6696, 8534),
+    (0x6697, 8530),
+    (0x6698, 8536),
+    (0x6699, 14543),
+    (0x669D, 8977),
+    (0x669F, 15222),
+    (0x66A0, 15221),
+    (0x66A1, 15220),
+    (0x66A2, 8975),
+    (0x66A4, 19172),
+    (0x66A8, 8976),
+    (0x66A9, 15799),
+    (0x66AA, 15802),
+    (0x66AB, 9365),
+    (0x66AD, 19173),
+    (0x66AE, 9364),
+    (0x66AF, 15803),
+    (0x66B0, 15798),
+    (0x66B1, 9367),
+    (0x66B2, 15800),
+    (0x66B3, 19167),
+    (0x66B4, 9366),
+    (0x66B5, 15797),
+    (0x66B7, 15801),
+    (0x66B8, 9712),
+    (0x66B9, 9709),
+    (0x66BA, 16417),
+    (0x66BB, 16416),
+    (0x66BD, 16415),
+    (0x66BE, 16410),
+    (0x66C0, 16411),
+    (0x66C4, 9710),
+    (0x66C6, 9707),
+    (0x66C7, 9711),
+    (0x66C8, 16409),
+    (0x66C9, 9708),
+    (0x66CA, 16412),
+    (0x66CB, 16413),
+    (0x66CC, 16418),
+    (0x66CD, 19170),
+    (0x66CF, 16414),
+    (0x66D2, 16943),
+    (0x66D6, 9992),
+    (0x66D8, 17392),
+    (0x66D9, 9991),
+    (0x66DA, 17390),
+    (0x66DB, 17391),
+    (0x66DC, 10237),
+    (0x66DD, 10408),
+    (0x66DE, 17752),
+    (0x66E0, 10407),
+    (0x66E3, 18072),
+    (0x66E4, 18073),
+    (0x66E6, 10557),
+    (0x66E8, 18071),
+    (0x66E9, 10649),
+    (0x66EB, 18651),
+    (0x66EC, 10784),
+    (0x66ED, 18755),
+    (0x66EE, 18756),
+    (0x66F0, 5631),
+    (0x66F2, 5888),
+    (0x66F3, 5889),
+    (0x66F4, 6111),
+    (0x66F6, 11886),
+    (0x66F7, 6771),
+    (0x66F8, 7176),
+    (0x66F9, 7666),
+    (0x66FC, 7491),
+    (0x66FE, 8100),
+    (0x66FF, 8101),
+    (0x6700, 7956),
+    (0x6701, 13887),
+    (0x6702, 19448),
+    (0x6703, 8538),
+    (0x6704, 15224),
+    (0x6705, 15223),
+    (0x6708, 5632),
+    (0x6709, 5890),
+    (0x670A, 11887),
+    (0x670B, 6439),
+    (0x670D, 6438),
+    (0x670F, 12240),
+    (0x6710, 12241),
+    (0x6712, 12677),
+    (0x6713, 12678),
+    (0x6714, 7177),
+    (0x6715, 7178),
+    (0x6717, 7179),
+    (0x6718, 13265),
+    (0x671B, 7668),
+    (0x671D, 8103),
+    (0x671F, 8102),
+    (0x6720, 14546),
+    (0x6721, 14816),
+    (0x6722, 15225),
+    (0x6723, 16419),
+    (0x6726, 10238),
+    (0x6727, 10558),
+    (0x6728, 5633),
+    (0x672A, 5742),
+    (0x672B, 5743),
+    (0x672C, 5741),
+    (0x672D, 5744),
+    (0x672E, 5740),
+    (0x6731, 5893),
+    (0x6733, 11452),
+    (0x6734, 5892),
+    (0x6735, 5894),
+    (0x6738, 11447),
+    (0x6739, 11446),
+    (0x673A, 11449),
+    (0x673B, 11448),
+    (0x673C, 11451),
+    (0x673D, 5891),
+    (0x673E, 11445),
+    (0x673F, 11450),
+    (0x6744, 19013),
+    (0x6745, 11616),
+    (0x6746, 6121),
+    (0x6747, 11617),
+    (0x6748, 11621),
+    (0x6749, 6120),
+    (0x674B, 11625),
+    (0x674C, 11620),
+    (0x674D, 11623),
+    (0x674E, 6113),
+    (0x674F, 6114),
+    (0x6750, 6115),
+    (0x6751, 6116),
+    (0x6753, 6123),
+    (0x6755, 11619),
+    (0x6756, 6118),
+    (0x6757, 6124),
+    (0x6759, 11618),
+    (0x675A, 11624),
+    (0x675C, 6117),
+    (0x675D, 11622),
+    (0x675E, 6119),
+    (0x675F, 6112),
+    (0x6760, 6122),
+    (0x6762, 19456),
+    (0x6767, 19455),
+    (0x676A, 6460),
+    (0x676C, 11889),
+    (0x676D, 6440),
+    (0x676F, 6450),
+    (0x6770, 6451),
+    (0x6771, 6443),
+    (0x6772, 6461),
+    (0x6773, 6445),
+    (0x6774, 11897),
+    (0x6775, 6456),
+    (0x6776, 11892),
+    (0x6777, 6446),
+    (0x6778, 11907),
+    (0x6779, 11908),
+    (0x677A, 11900),
+    (0x677B, 11893),
+    (0x677C, 6459),
+    (0x677D, 11905),
+    (0x677E, 6454),
+    (0x677F, 6452),
+    (0x6781, 11906),
+    (0x6783, 11904),
+    (0x6784, 11896),
+    (0x6785, 11888),
+    (0x6786, 11895),
+    (0x6787, 6447),
+    (0x6789, 6453),
+    (0x678B, 6441),
+    (0x678C, 11899),
+    (0x678D, 11898),
+    (0x678E, 11890),
+    (0x6790, 6455),
+    (0x6791, 11902),
+    (0x6792, 11891),
+    (0x6793, 6458),
+    (0x6794, 11909),
+    (0x6795, 6442),
+    (0x6797, 6449),
+    (0x6798, 11894),
+    (0x6799, 11903),
+    (0x679A, 6457),
+    (0x679C, 6444),
+    (0x679D, 6448),
+    (0x679F, 11901),
+    (0x67AC, 19481),
+    (0x67AE, 12273),
+    (0x67AF, 6779),
+    (0x67B0, 6792),
+    (0x67B1, 19498),
+    (0x67B2, 12268),
+    (0x67B3, 12258),
+    (0x67B4, 6785),
+    (0x67B5, 12256),
+    (0x67B6, 6778),
+    (0x67B7, 12251),
+    (0x67B8, 6788),
+    (0x67B9, 12264),
+    (0x67BA, 12245),
+    (0x67BB, 12247),
+    (0x67C0, 12250),
+    (0x67C1, 12242),
+    (0x67C2, 12263),
+    (0x67C3, 12279),
+    (0x67C4, 6783),
+    (0x67C5, 12252),
+    (0x67C6, 12270),
+    (0x67C8, 12244),
+    (0x67C9, 12277),
+    (0x67CA, 12278),
+    (0x67CB, 12281),
+    (0x67CC, 12272),
+    (0x67CD, 12257),
+    (0x67CE, 12265),
+    (0x67CF, 6789),
+    (0x67D0, 6776),
+    (0x67D1, 6784),
+    (0x67D2, 6796),
+    (0x67D3, 6773),
+    (0x67D4, 6775),
+    (0x67D7, 19459),
+    (0x67D8, 12249),
+    (0x67D9, 6793),
+    (0x67DA, 6786),
+    (0x67DB, 12275),
+    (0x67DC, 12246),
+    (0x67DD, 6795),
+    (0x67DE, 6790),
+    (0x67DF, 12255),
+    (0x67E2, 6794),
+    (0x67E3, 12262),
+    (0x67E4, 12254),
+    (0x67E5, 6787),
+    (0x67E6, 12274),
+    (0x67E7, 12266),
+    (0x67E9, 6781),
+    (0x67EA, 12280),
+    (0x67EB, 12253),
+    (0x67EC, 6777),
+    (0x67ED, 12271),
+    (0x67EE, 12261),
+    (0x67EF, 6782),
+    (0x67F0, 12267),
+    (0x67F1, 6774),
+    (0x67F2, 12243),
+    (0x67F3, 6791),
+    (0x67F4, 7194),
+    (0x67F5, 6780),
+    (0x67F6, 12260),
+    (0x67F7, 12259),
+    (0x67F8, 12248),
+    (0x67FA, 12276),
+    (0x67FC, 12269),
+    (0x67FF, 6772),
+    (0x6801, 19464),
+    (0x680D, 19640),
+    (0x6812, 12698),
+    (0x6813, 7201),
+    (0x6814, 12699),
+    (0x6816, 12687),
+    (0x6817, 7190),
+    (0x6818, 7202),
+    (0x681A, 12680),
+    (0x681B, 19100),
+    (0x681C, 12689),
+    (0x681D, 12697),
+    (0x681F, 12679),
+    (0x6820, 12706),
+    (0x6821, 7180),
+    (0x6822, 19461),
+    (0x6825, 12705),
+    (0x6826, 12700),
+    (0x6828, 12701),
+    (0x6829, 7188),
+    (0x682A, 7199),
+    (0x682B, 12691),
+    (0x682D, 12692),
+    (0x682E, 12702),
+    (0x682F, 12693),
+    (0x6831, 12688),
+    (0x6832, 12682),
+    (0x6833, 12683),
+    (0x6834, 12696),
+    (0x6835, 12690),
+    (0x6837, 19539),
+    (0x6838, 7181),
+    (0x6839, 7185),
+    (0x683A, 12704),
+    (0x683B, 12684),
+    (0x683C, 7197),
+    (0x683D, 7193),
+    (0x683E, 19315),
+    (0x6840, 7196),
+    (0x6841, 7203),
+    (0x6842, 7186),
+    (0x6843, 7198),
+    (0x6844, 12695),
+    (0x6845, 7200),
+    (0x6846, 7183),
+    (0x6848, 7182),
+    (0x6849, 12681),
+    (0x684B, 12685),
+    (0x684C, 7191),
+    (0x684D, 12703),
+    (0x684E, 12694),
+    (0x684F, 12686),
+    (0x6850, 7195),
+    (0x6851, 7192),
+    (0x6853, 7184),
+    (0x6854, 7187),
+    (0x685D, 19467),
+    (0x686B, 13286),
+    (0x686D, 13270),
+    (0x686E, 13271),
+    (0x686F, 13275),
+    (0x6871, 13290),
+    (0x6872, 13287),
+    (0x6874, 13280),
+    (0x6875, 13279),
+    (0x6876, 7675),
+    (0x6877, 13283),
+    (0x6878, 13298),
+    (0x6879, 13266),
+    (0x687B, 13299),
+    (0x687C, 13285),
+    (0x687D, 13303),
+    (0x687E, 13291),
+    (0x687F, 7674),
+    (0x6880, 13289),
+    (0x6881, 7669),
+    (0x6882, 7690),
+    (0x6883, 7680),
+    (0x6885, 7684),
+    (0x6886, 7683),
+    (0x6887, 13267),
+    (0x6889, 13296),
+    (0x688A, 13302),
+    (0x688B, 13294),
+    (0x688C, 13301),
+    (0x688F, 13282),
+    (0x6890, 13268),
+    (0x6891, 13300),
+    (0x6892, 13284),
+    (0x6893, 7672),
+    (0x6894, 7685),
+    (0x6896, 13293),
+    (0x6897, 7678),
+    (0x689B, 13292),
+    (0x689C, 13269),
+    (0x689D, 7686),
+    (0x689F, 7688),
+    (0x68A0, 13295),
+    (0x68A1, 7689),
+    (0x68A2, 7671),
+    (0x68A3, 13276),
+    (0x68A4, 13297),
+    (0x68A6, 19214),
+    (0x68A7, 7677),
+    (0x68A8, 7687),
+    (0x68A9, 13278),
+    (0x68AA, 13288),
+    (0x68AB, 13273),
+    (0x68AC, 13277),
+    (0x68AD, 7682),
+    (0x68AE, 13272),
+    (0x68AF, 7670),
+    (0x68B0, 7679),
+    (0x68B1, 7676),
+    (0x68B2, 13281),
+    (0x68B3, 7189),
+    (0x68B4, 13910),
+    (0x68B5, 7673),
+    (0x68BD, 19635),
+    (0x68C3, 19473),
+    (0x68C4, 7681),
+    (0x68C6, 13913),
+    (0x68C7, 13935),
+    (0x68C8, 13924),
+    (0x68C9, 8123),
+    (0x68CA, 19751),
+    (0x68CB, 8118),
+    (0x68CC, 13907),
+    (0x68CD, 8119),
+    (0x68CE, 13923),
+    (0x68D0, 13916),
+    (0x68D1, 13929),
+    (0x68D2, 8115),
+    (0x68D3, 13889),
+    (0x68D4, 13931),
+    (0x68D5, 8105),
+    (0x68D6, 13897),
+    (0x68D7, 8108),
+    (0x68D8, 8107),
+    (0x68DA, 8124),
+    (0x68DC, 13891),
+    (0x68DD, 13925),
+    (0x68DE, 13926),
+    (0x68DF, 8110),
+    (0x68E0, 8106),
+    (0x68E1, 13905),
+    (0x68E3, 8117),
+    (0x68E4, 13900),
+    (0x68E6, 13927),
+    (0x68E7, 8113),
+    (0x68E8, 13919),
+    (0x68E9, 13932),
+    (0x68EA, 13894),
+    (0x68EB, 13899),
+    (0x68EC, 13893),
+    (0x68EE, 8112),
+    (0x68EF, 13912),
+    (0x68F0, 14568),
+    (0x68F1, 13895),
+    (0x68F2, 8116),
+    (0x68F3, 13904),
+    (0x68F4, 13928),
+    (0x68F5, 8111),
+    (0x68F6, 13901),
+    (0x68F7, 13898),
+    (0x68F8, 13915),
+    (0x68F9, 8114),
+    (0x68FA, 8104),
+    (0x68FB, 8126),
+    (0x68FC, 13918),
+    (0x68FD, 13917),
+    (0x6900, 19476),
+    (0x6901, 19475),
+    (0x6902, 19769),
+    (0x6903, 19667),
+    (0x6904, 13890),
+    (0x6905, 8109),
+    (0x6906, 13930),
+    (0x6907, 13906),
+    (0x6908, 13908),
+    (0x690A, 13921),
+    (0x690B, 13920),
+    (0x690C, 13888),
+    (0x690D, 8120),
+    (0x690E, 8122),
+    (0x690F, 13896),
+    (0x6910, 13903),
+    (0x6911, 13911),
+    (0x6912, 8121),
+    (0x6913, 13902),
+    (0x6914, 13914),
+    (0x6915, 13933),
+    (0x6917, 13922),
+    (0x6925, 13934),
+    (0x692A, 13892),
+    (0x692F, 14588),
+    (0x6930, 8546),
+    (0x6932, 14586),
+    (0x6933, 14565),
+    (0x6934, 14570),
+    (0x6935, 14563),
+    (0x6936, 19485),
+    (0x6937, 14582),
+    (0x6938, 14549),
+    (0x6939, 14556),
+    (0x693B, 14580),
+    (0x693C, 14590),
+    (0x693D, 14566),
+    (0x693E, 19072),
+    (0x693F, 14553),
+    (0x6940, 14572),
+    (0x6941, 14577),
+    (0x6942, 14557),
+    (0x6944, 14574),
+    (0x6945, 14554),
+    (0x6948, 14561),
+    (0x6949, 14562),
+    (0x694A, 8548),
+    (0x694B, 14581),
+    (0x694C, 14579),
+    (0x694E, 14550),
+    (0x694F, 14584),
+    (0x6951, 14585),
+    (0x6952, 14587),
+    (0x6953, 8552),
+    (0x6954, 8544),
+    (0x6956, 13274),
+    (0x6957, 14558),
+    (0x6958, 14576),
+    (0x6959, 14559),
+    (0x695A, 8541),
+    (0x695B, 8557),
+    (0x695C, 14583),
+    (0x695D, 8555),
+    (0x695E, 8551),
+    (0x695F, 14548),
+    (0x6960, 8543),
+    (0x6961, 19482),
+    (0x6962, 14551),
+    (0x6963, 8556),
+    (0x6965, 14567),
+    (0x6966, 14547),
+    (0x6968, 8549),
+    (0x6969, 14571),
+    (0x696A, 14555),
+    (0x696B, 8550),
+    (0x696C, 14564),
+    (0x696D, 8540),
+    (0x696E, 8125),
+    (0x696F, 14573),
+    (0x6970, 13909),
+    (0x6971, 14552),
+    (0x6973, 19472),
+    (0x6974, 14578),
+    (0x6975, 8545),
+    (0x6976, 14575),
+    (0x6977, 8542),
+    (0x6978, 14569),
+    (0x6979, 8553),
+    (0x697A, 14560),
+    (0x697B, 14589),
+    (0x6982, 8547),
+    (0x6983, 14701),
+    (0x6986, 8554),
+    (0x698D, 15239),
+    (0x698E, 15237),
+    (0x6990, 15259),
+    (0x6991, 15235),
+    (0x6993, 15253),
+    (0x6994, 8539),
+    (0x6995, 8980),
+    (0x6996, 15231),
+    (0x6997, 15258),
+    (0x6998, 19486),
+    (0x6999, 15236),
+    (0x699A, 15250),
+    (0x699B, 8985),
+    (0x699C, 8978),
+    (0x699E, 15256),
+    (0x69A0, 15229),
+    (0x69A1, 15255),
+    (0x69A3, 8996),
+    (0x69A4, 15246),
+    (0x69A5, 15262),
+    (0x69A6, 8994),
+    (0x69A7, 15238),
+    (0x69A8, 8979),
+    (0x69A9, 15240),
+    (0x69AA, 15254),
+    (0x69AB, 8988),
+    (0x69AC, 15233),
+    (0x69AD, 8992),
+    (0x69AE, 8982),
+    (0x69AF, 15242),
+    (0x69B0, 15232),
+    (0x69B1, 15226),
+    (0x69B3, 15252),
+    (0x69B4, 8989),
+    (0x69B5, 15261),
+    (0x69B6, 15227),
+    (0x69B7, 8986),
+    (0x69B9, 15248),
+    (0x69BB, 8987),
+    (0x69BC, 15234),
+    (0x69BD, 15245),
+    (0x69BE, 15241),
+    (0x69BF, 15243),
+    (0x69C1, 8981),
+    (0x69C2, 15260),
+    (0x69C3, 8995),
+    (0x69C4, 15244),
+    (0x69C6, 15263),
+    (0x69C9, 15228),
+    (0x69CA, 15249),
+    (0x69CB, 8984),
+    (0x69CC, 8993),
+    (0x69CD, 8991),
+    (0x69CE, 15230),
+    (0x69CF, 15251),
+    (0x69D0, 8990),
+    (0x69D3, 8983),
+    (0x69D4, 15247),
+    (0x69D9, 15257),
+    (0x69E1, 19469),
+    (0x69E2, 15815),
+    (0x69E4, 15811),
+    (0x69E5, 15807),
+    (0x69E6, 15827),
+    (0x69E7, 15824),
+    (0x69E8, 9370),
+    (0x69EB, 15831),
+    (0x69EC, 15814),
+    (0x69ED, 9381),
+    (0x69EE, 15821),
+    (0x69F1, 15810),
+    (0x69F2, 15820),
+    (0x69F3, 9378),
+    (0x69F4, 15840),
+    (0x69F6, 15837),
+    (0x69F7, 15823),
+    (0x69F8, 15808),
+    (0x69F9, 19491),
+    (0x69FA, 19452),
+    (0x69FB, 15828),
+    (0x69FC, 15830),
+    (0x69FD, 9374),
+    (0x69FE, 15818),
+    (0x69FF, 15813),
+    (0x6A00, 15804),
+    (0x6A01, 9371),
+    (0x6A02, 9379),
+    (0x6A04, 15833),
+    (0x6A05, 9380),
+    (0x6A06, 15805),
+    (0x6A07, 15839),
+    (0x6A08, 15826),
+    (0x6A09, 15832),
+    (0x6A0A, 9377),
+    (0x6A0B, 19470),
+    (0x6A0D, 15829),
+    (0x6A0F, 15836),
+    (0x6A11, 9382),
+    (0x6A13, 9376),
+    (0x6A14, 15822),
+    (0x6A15, 15809),
+    (0x6A16, 15841),
+    (0x6A17, 15806),
+    (0x6A18, 15834),
+    (0x6A19, 9373),
+    (0x6A1B, 15816),
+    (0x6A1D, 15817),
+    (0x6A1E, 9372),
+    (0x6A1F, 9369),
+    (0x6A20, 15812),
+    (0x6A21, 9375),
+    (0x6A23, 9368),
+    (0x6A25, 15835),
+    (0x6A26, 15838),
+    (0x6A27, 15819),
+    (0x6A28, 16432),
+    (0x6A2C, 19684),
+    (0x6A32, 16424),
+    (0x6A34, 16420),
+    (0x6A35, 9725),
+    (0x6A38, 9714),
+    (0x6A39, 9719),
+    (0x6A3A, 9715),
+    (0x6A3B, 16434),
+    (0x6A3C, 16445),
+    (0x6A3D, 9713),
+    (0x6A3E, 16426),
+    (0x6A3F, 16435),
+    (0x6A40, 15825),
+    (0x6A41, 16436),
+    (0x6A44, 9720),
+    (0x6A45, 19494),
+    (0x6A46, 16451),
+    (0x6A47, 9724),
+    (0x6A48, 9727),
+    (0x6A49, 16422),
+    (0x6A4B, 9723),
+    (0x6A4D, 16449),
+    (0x6A4E, 16450),
+    (0x6A4F, 16440),
+    (0x6A50, 16439),
+    (0x6A51, 16431),
+    (0x6A54, 16441),
+    (0x6A55, 16448),
+    (0x6A56, 16447),
+    (0x6A58, 9718),
+    (0x6A59, 9716),
+    (0x6A5A, 16433),
+    (0x6A5B, 16430),
+    (0x6A5D, 16427),
+    (0x6A5E, 16446),
+    (0x6A5F, 9726),
+    (0x6A60, 16444),
+    (0x6A61, 9722),
+    (0x6A62, 9721),
+    (0x6A64, 16438),
+    (0x6A66, 16421),
+    (0x6A67, 16423),
+    (0x6A68, 16425),
+    (0x6A69, 16443),
+    (0x6A6A, 16437),
+    (0x6A6B, 9717),
+    (0x6A6D, 16428),
+    (0x6A6F, 16442),
+    (0x6A76, 16429),
+    (0x6A7E, 10000),
+    (0x6A7F, 16961),
+    (0x6A80, 9993),
+    (0x6A81, 16946),
+    (0x6A83, 16957),
+    (0x6A84, 9995),
+    (0x6A85, 16964),
+    (0x6A87, 16953),
+    (0x6A89, 16948),
+    (0x6A8A, 19298),
+    (0x6A8C, 16965),
+    (0x6A8D, 16944),
+    (0x6A8E, 16955),
+    (0x6A90, 10002),
+    (0x6A91, 16960),
+    (0x6A92, 16966),
+    (0x6A93, 16954),
+    (0x6A94, 9994),
+    (0x6A95, 16956),
+    (0x6A96, 16945),
+    (0x6A97, 10001),
+    (0x6A9A, 16963),
+    (0x6A9B, 16950),
+    (0x6A9C, 9997),
+    (0x6A9D, 19496),
+    (0x6A9E, 16952),
+    (0x6A9F, 16949),
+    (0x6AA0, 10003),
+    (0x6AA1, 16951),
+    (0x6AA2, 9996),
+    (0x6AA3, 9999),
+    (0x6AA4, 16959),
+    (0x6AA5, 16947),
+    (0x6AA6, 16962),
+    (0x6AA8, 16958),
+    (0x6AAC, 10240),
+    (0x6AAD, 17403),
+    (0x6AAE, 10245),
+    (0x6AAF, 10246),
+    (0x6AB3, 10239),
+    (0x6AB4, 17402),
+    (0x6AB6, 17399),
+    (0x6AB7, 17400),
+    (0x6AB8, 10243),
+    (0x6AB9, 17394),
+    (0x6ABA, 17398),
+    (0x6ABB, 10242),
+    (0x6ABD, 17395),
+    (0x6AC2, 10244),
+    (0x6AC3, 10241),
+    (0x6AC5, 17393),
+    (0x6AC6, 17397),
+    (0x6AC7, 17401),
+    (0x6AC8, 19499),
+    (0x6ACB, 17758),
+    (0x6ACC, 17755),
+    (0x6ACD, 17764),
+    (0x6ACF, 17763),
+    (0x6AD0, 17761),
+    (0x6AD1, 17756),
+    (0x6AD3, 10412),
+    (0x6AD9, 17757),
+    (0x6ADA, 10411),
+    (0x6ADB, 9998),
+    (0x6ADC, 17760),
+    (0x6ADD, 10410),
+    (0x6ADE, 17765),
+    (0x6ADF, 17759),
+    (0x6AE0, 17754),
+    (0x6AE1, 17396),
+    (0x6AE5, 10409),
+    (0x6AE7, 17753),
+    (0x6AE8, 18077),
+    (0x6AEA, 18076),
+    (0x6AEB, 17762),
+    (0x6AEC, 10559),
+    (0x6AEE, 18080),
+    (0x6AEF, 18081),
+    (0x6AF0, 18075),
+    (0x6AF1, 18079),
+    (0x6AF3, 18074),
+    (0x6AF8, 18323),
+    (0x6AF9, 18078),
+    (0x6AFA, 10652),
+    (0x6AFB, 10650),
+    (0x6AFC, 18321),
+    (0x6B00, 18324),
+    (0x6B02, 18320),
+    (0x6B03, 18322),
+    (0x6B04, 10651),
+    (0x6B08, 18500),
+    (0x6B09, 18501),
+    (0x6B0A, 10730),
+    (0x6B0B, 18499),
+    (0x6B0F, 18654),
+    (0x6B10, 10785),
+    (0x6B11, 18652),
+    (0x6B12, 18653),
+    (0x6B13, 18757),
+    (0x6B16, 10855),
+    (0x6B17, 18828),
+    (0x6B18, 18826),
+    (0x6B19, 18827),
+    (0x6B1A, 18829),
+    (0x6B1D, 19502),
+    (0x6B1E, 18929),
+    (0x6B20, 5634),
+    (0x6B21, 5895),
+    (0x6B23, 6462),
+    (0x6B25, 11910),
+    (0x6B28, 12282),
+    (0x6B2C, 12707),
+    (0x6B2D, 12709),
+    (0x6B2F, 12708),
+    (0x6B31, 12710),
+    (0x6B32, 7691),
+    (0x6B33, 13305),
+    (0x6B34, 12711),
+    (0x6B35, 19505),
+    (0x6B36, 13304),
+    (0x6B37, 13306),
+    (0x6B38, 13307),
+    (0x6B39, 13936),
+    (0x6B3A, 8128),
+    (0x6B3B, 13937),
+    (0x6B3C, 13939),
+    (0x6B3D, 8129),
+    (0x6B3E, 8127),
+    (0x6B3F, 13938),
+    (0x6B41, 14596),
+    (0x6B42, 14594),
+    (0x6B43, 14593),
+    (0x6B45, 14592),
+    (0x6B46, 14591),
+    (0x6B47, 8558),
+    (0x6B48, 14595),
+    (0x6B49, 8997),
+    (0x6B4A, 15264),
+    (0x6B4B, 15266),
+    (0x6B4C, 8998),
+    (0x6B4D, 15265),
+    (0x6B4E, 9384),
+    (0x6B50, 9383),
+    (0x6B51, 15842),
+    (0x6B54, 16453),
+    (0x6B55, 16452),
+    (0x6B56, 16454),
+    (0x6B59, 9728),
+    (0x6B5B, 16967),
+    (0x6B5C, 10004),
+    (0x6B5E, 17404),
+    (0x6B5F, 10247),
+    (0x6B60, 17766),
+    (0x6B61, 10731),
+    (0x6B62, 5635),
+    (0x6B63, 5745),
+    (0x6B64, 5896),
+    (0x6B65, 6125),
+    (0x6B66, 6463),
+    (0x6B67, 6464),
+    (0x6B6A, 6797),
+    (0x6B6D, 12712),
+    (0x6B72, 8559),
+    (0x6B74, 19506),
+    (0x6B76, 15968),
+    (0x6B77, 9729),
+    (0x6B78, 10248),
+    (0x6B79, 5636),
+    (0x6B7B, 5897),
+    (0x6B7E, 11912),
+    (0x6B7F, 6465),
+    (0x6B80, 11911),
+    (0x6B82, 12283),
+    (0x6B83, 6798),
+    (0x6B84, 12284),
+    (0x6B86, 6799),
+    (0x6B88, 12714),
+    (0x6B89, 7205),
+    (0x6B8A, 7204),
+    (0x6B8C, 13312),
+    (0x6B8D, 13310),
+    (0x6B8E, 13311),
+    (0x6B8F, 13309),
+    (0x6B91, 13308),
+    (0x6B94, 13940),
+    (0x6B95, 13943),
+    (0x6B96, 8131),
+    (0x6B97, 13941),
+    (0x6B98, 8130),
+    (0x6B99, 13942),
+    (0x6B9B, 14597),
+    (0x6B9E, 15267),
+    (0x6B9F, 15268),
+    (0x6BA0, 15269),
+    (0x6BA2, 15845),
+    (0x6BA3, 15844),
+    (0x6BA4, 9385),
+    (0x6BA5, 15843),
+    (0x6BA6, 15846),
+    (0x6BA7, 16455),
+    (0x6BAA, 16456),
+    (0x6BAB, 16457),
+    (0x6BAD, 16968),
+    (0x6BAE, 10005),
+    (0x6BAF, 10249),
+    (0x6BB0, 17767),
+    (0x6BB2, 10653),
+    (0x6BB3, 11335),
+    (0x6BB5, 6800),
+    (0x6BB6, 12285),
+    (0x6BB7, 7206),
+    (0x6BBA, 7692),
+    (0x6BBC, 8132),
+    (0x6BBD, 13944),
+    (0x6BBF, 8561),
+    (0x6BC0, 8560),
+    (0x6BC3, 15270),
+    (0x6BC4, 15271),
+    (0x6BC5, 9386),
+    (0x6BC6, 9387),
+    (0x6BC7, 16459),
+    (0x6BC8, 16458),
+    (0x6BC9, 17405),
+    (0x6BCA, 18655),
+    (0x6BCB, 5637),
+    (0x6BCC, 11336),
+    (0x6BCD, 5746),
+    (0x6BCF, 6126),
+    (0x6BD0, 11626),
+    (0x6BD2, 6801),
+    (0x6BD3, 8562),
+    (0x6BD4, 5638),
+    (0x6BD6, 12286),
+    (0x6BD7, 6802),
+    (0x6BD8, 12287),
+    (0x6BDA, 10006),
+    (0x6BDB, 5639),
+    (0x6BDC, 19517),
+    (0x6BDE, 11913),
+    (0x6BE0, 12288),
+    (0x6BE1, 19515),
+    (0x6BE2, 12719),
+    (0x6BE3, 12718),
+    (0x6BE4, 12716),
+    (0x6BE6, 12715),
+    (0x6BE7, 12720),
+    (0x6BE8, 12717),
+    (0x6BEB, 7693),
+    (0x6BEC, 7694),
+    (0x6BEF, 8133),
+    (0x6BF0, 13945),
+    (0x6BF2, 13946),
+    (0x6BF3, 13947),
+    (0x6BF7, 14602),
+    (0x6BF8, 14603),
+    (0x6BF9, 14601),
+    (0x6BFB, 14599),
+    (0x6BFC, 14600),
+    (0x6BFD, 8563),
+    (0x6BFE, 15272),
+    (0x6BFF, 15849),
+    (0x6C00, 15848),
+    (0x6C01, 15847),
+    (0x6C02, 15850),
+    (0x6C03, 16461),
+    (0x6C04, 16460),
+    (0x6C05, 9730),
+    (0x6C06, 16462),
+    (0x6C08, 10007),
+    (0x6C09, 16969),
+    (0x6C0B, 17406),
+    (0x6C0C, 17768),
+    (0x6C0D, 18502),
+    (0x6C0F, 5640),
+    (0x6C10, 5748),
+    (0x6C11, 5747),
+    (0x6C13, 6466),
+    (0x6C14, 11337),
+    (0x6C15, 11366),
+    (0x6C16, 5898),
+    (0x6C18, 11453),
+    (0x6C19, 11627),
+    (0x6C1A, 11628),
+    (0x6C1B, 6467),
+    (0x6C1D, 11914),
+    (0x6C1F, 6803),
+    (0x6C20, 12289),
+    (0x6C21, 12290),
+    (0x6C23, 7207),
+    (0x6C24, 7211),
+    (0x6C25, 12721),
+    (0x6C26, 7210),
+    (0x6C27, 7208),
+    (0x6C28, 7209),
+    (0x6C2A, 13313),
+    (0x6C2B, 7695),
+    (0x6C2C, 8136),
+    (0x6C2E, 8134),
+    (0x6C2F, 8135),
+    (0x6C30, 13948),
+    (0x6C33, 8999),
+    (0x6C34, 5641),
+    (0x6C36, 11367),
+    (0x6C37, 19518),
+    (0x6C38, 5749),
+    (0x6C3B, 11370),
+    (0x6C3E, 5752),
+    (0x6C3F, 11369),
+    (0x6C40, 5751),
+    (0x6C41, 5750),
+    (0x6C42, 6127),
+    (0x6C43, 11368),
+    (0x6C46, 11454),
+    (0x6C49, 19127),
+    (0x6C4A, 11458),
+    (0x6C4B, 11460),
+    (0x6C4C, 11461),
+    (0x6C4D, 5908),
+    (0x6C4E, 5909),
+    (0x6C4F, 11457),
+    (0x6C50, 5904),
+    (0x6C52, 11455),
+    (0x6C54, 11459),
+    (0x6C55, 5905),
+    (0x6C57, 5900),
+    (0x6C59, 5901),
+    (0x6C5A, 19522),
+    (0x6C5B, 5907),
+    (0x6C5C, 11456),
+    (0x6C5D, 5899),
+    (0x6C5E, 6128),
+    (0x6C5F, 5902),
+    (0x6C60, 5903),
+    (0x6C61, 5906),
+    (0x6C65, 11645),
+    (0x6C66, 11643),
+    (0x6C67, 11630),
+    (0x6C68, 6140),
+    (0x6C69, 11637),
+    (0x6C6A, 6135),
+    (0x6C6B, 11631),
+    (0x6C6D, 11639),
+    (0x6C6E, 19188),
+    (0x6C6F, 11636),
+    (0x6C70, 6138),
+    (0x6C71, 11635),
+    (0x6C72, 6145),
+    (0x6C73, 11644),
+    (0x6C74, 6147),
+    (0x6C76, 6149),
+    (0x6C78, 11629),
+    (0x6C79, 19524),
+    (0x6C7A, 6136),
+    (0x6C7B, 11646),
+    (0x6C7D, 6143),
+    (0x6C7E, 6146),
+    (0x6C80, 11937),
+    (0x6C81, 6130),
+    (0x6C82, 6153),
+    (0x6C83, 6144),
+    (0x6C84, 11632),
+    (0x6C85, 6133),
+    (0x6C86, 6148),
+    (0x6C87, 11640),
+    (0x6C88, 6131),
+    (0x6C89, 6132),
+    (0x6C8A, 11935),
+    (0x6C8B, 11633),
+    (0x6C8C, 6139),
+    (0x6C8D, 6150),
+    (0x6C8E, 11647),
+    (0x6C8F, 11634),
+    (0x6C90, 6137),
+    (0x6C92, 6142),
+    (0x6C93, 11915),
+    (0x6C94, 6151),
+    (0x6C95, 11641),
+    (0x6C96, 6141),
+    (0x6C98, 6152),
+    (0x6C99, 6129),
+    (0x6C9A, 11638),
+    (0x6C9B, 6134),
+    (0x6C9C, 11642),
+    (0x6C9D, 11936),
+    (0x6CAB, 6479),
+    (0x6CAC, 6495),
+    (0x6CAD, 11922),
+    (0x6CAE, 6486),
+    (0x6CB0, 11943),
+    (0x6CB1, 6471),
+    (0x6CB3, 6474),
+    (0x6CB4, 11934),
+    (0x6CB6, 11920),
+    (0x6CB7, 11924),
+    (0x6CB8, 6482),
+    (0x6CB9, 6484),
+    (0x6CBA, 11927),
+    (0x6CBB, 6491),
+    (0x6CBC, 6477),
+    (0x6CBD, 6475),
+    (0x6CBE, 6476),
+    (0x6CBF, 6490),
+    (0x6CC0, 11939),
+    (0x6CC1, 6485),
+    (0x6CC2, 11926),
+    (0x6CC3, 11928),
+    (0x6CC4, 6483),
+    (0x6CC5, 6488),
+    (0x6CC6, 11929),
+    (0x6CC7, 11942),
+    (0x6CC9, 6804),
+    (0x6CCA, 6494),
+    (0x6CCB, 19098),
+    (0x6CCC, 6472),
+    (0x6CCD, 11941),
+    (0x6CCF, 11945),
+    (0x6CD0, 11925),
+    (0x6CD1, 11947),
+    (0x6CD2, 11932),
+    (0x6CD3, 6481),
+    (0x6CD4, 11921),
+    (0x6CD5, 6480),
+    (0x6CD6, 6498),
+    (0x6CD7, 6487),
+    (0x6CD9, 11919),
+    (0x6CDA, 12299),
+    (0x6CDB, 6493),
+    (0x6CDC, 6497),
+    (0x6CDD, 11933),
+    (0x6CDE, 11938),
+    (0x6CE0, 6499),
+    (0x6CE1, 6492),
+    (0x6CE2, 6478),
+    (0x6CE3, 6468),
+    (0x6CE5, 6473),
+    (0x6CE7, 11923),
+    (0x6CE8, 6469),
+    (0x6CE9, 11946),
+    (0x6CEB, 11917),
+    (0x6CEC, 11916),
+    (0x6CED, 11930),
+    (0x6CEE, 11918),
+    (0x6CEF, 6496),
+    (0x6CF0, 7212),
+    (0x6CF1, 6489),
+    (0x6CF2, 11931),
+    (0x6CF3, 6470),
+    (0x6CF5, 6819),
+    (0x6CF9, 11944),
+    (0x6D00, 12306),
+    (0x6D01, 12309),
+    (0x6D03, 12312),
+    (0x6D04, 12301),
+    (0x6D07, 12315),
+    (0x6D08, 12318),
+    (0x6D09, 12320),
+    (0x6D0A, 12298),
+    (0x6D0B, 6805),
+    (0x6D0C, 6810),
+    (0x6D0D, 12726),
+    (0x6D0E, 6826),
+    (0x6D0F, 12313),
+    (0x6D10, 12321),
+    (0x6D11, 12305),
+    (0x6D12, 12297),
+    (0x6D16, 12753),
+    (0x6D17, 6813),
+    (0x6D18, 12310),
+    (0x6D19, 12302),
+    (0x6D1A, 12304),
+    (0x6D1B, 6818),
+    (0x6D1D, 12307),
+    (0x6D1E, 6812),
+    (0x6D1F, 12294),
+    (0x6D20, 12316),
+    (0x6D22, 12319),
+    (0x6D25, 6809),
+    (0x6D27, 6821),
+    (0x6D28, 12291),
+    (0x6D29, 6823),
+    (0x6D2A, 6807),
+    (0x6D2B, 6827),
+    (0x6D2C, 12317),
+    (0x6D2D, 12293),
+    (0x6D2E, 6824),
+    (0x6D2F, 12747),
+    (0x6D30, 11940),
+    (0x6D31, 6811),
+    (0x6D32, 6806),
+    (0x6D33, 12300),
+    (0x6D34, 12292),
+    (0x6D35, 6825),
+    (0x6D36, 6817),
+    (0x6D37, 12311),
+    (0x6D38, 6822),
+    (0x6D39, 6820),
+    (0x6D3A, 12303),
+    (0x6D3B, 6814),
+    (0x6D3C, 12295),
+    (0x6D3D, 6815),
+    (0x6D3E, 6816),
+    (0x6D3F, 12296),
+    (0x6D40, 12314),
+    (0x6D41, 6808),
+    (0x6D42, 12308),
+    (0x6D58, 12729),
+    (0x6D59, 7220),
+    (0x6D5A, 7225),
+    (0x6D5B, 19142),
+    (0x6D5C, 19544),
+    (0x6D5E, 12738),
+    (0x6D5F, 12744),
+    (0x6D60, 12740),
+    (0x6D61, 12727),
+    (0x6D62, 12730),
+    (0x6D63, 12723),
+    (0x6D64, 12724),
+    (0x6D65, 7232),
+    (0x6D66, 7217),
+    (0x6D67, 12739),
+    (0x6D68, 12748),
+    (0x6D69, 7227),
+    (0x6D6A, 7213),
+    (0x6D6C, 7222),
+    (0x6D6D, 12731),
+    (0x6D6E, 7224),
+    (0x6D6F, 12732),
+    (0x6D70, 12742),
+    (0x6D71, 19231),
+    (0x6D74, 7226),
+    (0x6D75, 12757),
+    (0x6D76, 12725),
+    (0x6D77, 7219),
+    (0x6D78, 7218),
+    (0x6D79, 7230),
+    (0x6D7A, 12722),
+    (0x6D7B, 12755),
+    (0x6D7C, 12743),
+    (0x6D7D, 12756),
+    (0x6D7E, 12750),
+    (0x6D7F, 12736),
+    (0x6D80, 12751),
+    (0x6D82, 12745),
+    (0x6D83, 12754),
+    (0x6D84, 12752),
+    (0x6D85, 7231),
+    (0x6D86, 12737),
+    (0x6D87, 7216),
+    (0x6D88, 7215),
+    (0x6D89, 7223),
+    (0x6D8A, 7229),
+    (0x6D8B, 12749),
+    (0x6D8C, 7228),
+    (0x6D8D, 12734),
+    (0x6D8E, 7696),
+    (0x6D90, 12758),
+    (0x6D91, 12733),
+    (0x6D92, 12728),
+    (0x6D93, 7221),
+    (0x6D94, 7233),
+    (0x6D95, 7214),
+    (0x6D96, 19543),
+    (0x6D97, 12741),
+    (0x6D98, 12746),
+    (0x6DAA, 7730),
+    (0x6DAB, 13315),
+    (0x6DAC, 13319),
+    (0x6DAE, 7711),
+    (0x6DAF, 7709),
+    (0x6DB2, 7700),
+    (0x6DB3, 13317),
+    (0x6DB4, 13316),
+    (0x6DB5, 7720),
+    (0x6DB7, 13322),
+    (0x6DB8, 7714),
+    (0x6DBA, 13340),
+    (0x6DBB, 13352),
+    (0x6DBC, 7697),
+    (0x6DBD, 13337),
+    (0x6DBE, 13330),
+    (0x6DBF, 7732),
+    (0x6DC0, 13314),
+    (0x6DC2, 13342),
+    (0x6DC4, 7729),
+    (0x6DC5, 7717),
+    (0x6DC6, 7728),
+    (0x6DC7, 7707),
+    (0x6DC8, 13326),
+    (0x6DC9, 13344),
+    (0x6DCA, 13336),
+    (0x6DCB, 7708),
+    (0x6DCC, 7702),
+    (0x6DCD, 13350),
+    (0x6DCF, 13343),
+    (0x6DD0, 13345),
+    (0x6DD1, 7710),
+    (0x6DD2, 7718),
+    (0x6DD3, 13347),
+    (0x6DD4, 13324),
+    (0x6DD5, 13341),
+    (0x6DD6, 13329),
+    (0x6DD7, 13349),
+    (0x6DD8, 7723),
+    (0x6DD9, 7699),
+    (0x6DDA, 7721),
+    (0x6DDB, 13334),
+    (0x6DDC, 13332),
+    (0x6DDD, 13333),
+    (0x6DDE, 7712),
+    (0x6DDF, 13328),
+    (0x6DE0, 13327),
+    (0x6DE1, 7701),
+    (0x6DE2, 13321),
+    (0x6DE3, 13351),
+    (0x6DE4, 7703),
+    (0x6DE5, 13331),
+    (0x6DE6, 7733),
+    (0x6DE8, 7727),
+    (0x6DE9, 13320),
+    (0x6DEA, 7724),
+    (0x6DEB, 7722),
+    (0x6DEC, 7731),
+    (0x6DED, 13338),
+    (0x6DEE, 7726),
+    (0x6DEF, 12735),
+    (0x6DF0, 13339),
+    (0x6DF1, 7725),
+    (0x6DF2, 13346),
+    (0x6DF3, 7698),
+    (0x6DF4, 13335),
+    (0x6DF5, 7716),
+    (0x6DF6, 13323),
+    (0x6DF7, 7715),
+    (0x6DF9, 7713),
+    (0x6DFA, 7705),
+    (0x6DFB, 7704),
+    (0x6DFC, 13949),
+    (0x6DFD, 13348),
+    (0x6E00, 13325),
+    (0x6E03, 13970),
+    (0x6E05, 7706),
+    (0x6E19, 8165),
+    (0x6E1A, 7719),
+    (0x6E1B, 8147),
+    (0x6E1C, 13964),
+    (0x6E1D, 8161),
+    (0x6E1F, 13952),
+    (0x6E20, 8144),
+    (0x6E21, 8140),
+    (0x6E22, 13982),
+    (0x6E23, 8146),
+    (0x6E24, 8150),
+    (0x6E25, 8145),
+    (0x6E26, 8154),
+    (0x6E27, 13986),
+    (0x6E28, 13977),
+    (0x6E2A, 19776),
+    (0x6E2B, 13959),
+    (0x6E2C, 8159),
+    (0x6E2D, 8153),
+    (0x6E2E, 13971),
+    (0x6E2F, 8137),
+    (0x6E30, 13983),
+    (0x6E31, 13976),
+    (0x6E32, 8141),
+    (0x6E33, 13965),
+    (0x6E34, 8156),
+    (0x6E35, 13994),
+    (0x6E36, 13995),
+    (0x6E38, 8138),
+    (0x6E39, 13981),
+    (0x6E3A, 8158),
+    (0x6E3B, 13969),
+    (0x6E3C, 13955),
+    (0x6E3D, 13956),
+    (0x6E3E, 8162),
+    (0x6E3F, 13960),
+    (0x6E40, 13967),
+    (0x6E41, 13961),
+    (0x6E43, 8160),
+    (0x6E44, 8168),
+    (0x6E45, 13957),
+    (0x6E46, 13950),
+    (0x6E47, 13951),
+    (0x6E49, 13953),
+    (0x6E4A, 8143),
+    (0x6E4B, 13966),
+    (0x6E4D, 8157),
+    (0x6E4E, 8166),
+    (0x6E4F, 19562),
+    (0x6E50, 19462),
+    (0x6E51, 13968),
+    (0x6E52, 13992),
+    (0x6E53, 13984),
+    (0x6E54, 8139),
+    (0x6E55, 13990),
+    (0x6E56, 8151),
+    (0x6E58, 8149),
+    (0x6E59, 19216),
+    (0x6E5A, 13996),
+    (0x6E5B, 8148),
+    (0x6E5C, 13974),
+    (0x6E5D, 13962),
+    (0x6E5E, 13972),
+    (0x6E5F, 8171),
+    (0x6E60, 13978),
+    (0x6E61, 13975),
+    (0x6E62, 13958),
+    (0x6E63, 8167),
+    (0x6E64, 13988),
+    (0x6E65, 13985),
+    (0x6E66, 13993),
+    (0x6E67, 8142),
+    (0x6E68, 13973),
+    (0x6E69, 8170),
+    (0x6E6B, 13980),
+    (0x6E6E, 8152),
+    (0x6E6F, 8155),
+    (0x6E71, 13979),
+    (0x6E72, 8169),
+    (0x6E73, 13963),
+    (0x6E74, 13318),
+    (0x6E77, 13989),
+    (0x6E78, 13987),
+    (0x6E79, 13991),
+    (0x6E7C, 19545),
+    (0x6E88, 13954),
+    (0x6E89, 8164),
+    (0x6E8D, 14634),
+    (0x6E8E, 14633),
+    (0x6E8F, 14607),
+    (0x6E90, 8569),
+    (0x6E92, 14632),
+    (0x6E93, 14610),
+    (0x6E94, 14611),
+    (0x6E96, 8579),
+    (0x6E97, 14641),
+    (0x6E98, 8574),
+    (0x6E99, 14631),
+    (0x6E9B, 14604),
+    (0x6E9C, 8580),
+    (0x6E9D, 8570),
+    (0x6E9E, 14619),
+    (0x6E9F, 14609),
+    (0x6EA0, 14612),
+    (0x6EA1, 14636),
+    (0x6EA2, 8564),
+    (0x6EA3, 14643),
+    (0x6EA4, 14635),
+    (0x6EA5, 8573),
+    (0x6EA6, 14624),
+    (0x6EA7, 8584),
+    (0x6EAA, 8583),
+    (0x6EAB, 8577),
+    (0x6EAE, 14642),
+    (0x6EAF, 8565),
+    (0x6EB0, 14622),
+    (0x6EB1, 14613),
+    (0x6EB2, 14626),
+    (0x6EB3, 14638),
+    (0x6EB4, 8585),
+    (0x6EB5, 19508),
+    (0x6EB6, 8567),
+    (0x6EB7, 14621),
+    (0x6EB9, 14614),
+    (0x6EBA, 8576),
+    (0x6EBC, 8575),
+    (0x6EBD, 14617),
+    (0x6EBE, 14627),
+    (0x6EBF, 14637),
+    (0x6EC0, 14608),
+    (0x6EC1, 14618),
+    (0x6EC2, 8568),
+    (0x6EC3, 14628),
+    (0x6EC4, 8581),
+    (0x6EC5, 8572),
+    (0x6EC6, 14615),
+    (0x6EC7, 8571),
+    (0x6EC8, 14606),
+    (0x6EC9, 14620),
+    (0x6ECA, 14640),
+    (0x6ECB, 8163),
+    (0x6ECC, 9027),
+    (0x6ECD, 14623),
+    (0x6ECE, 15273),
+    (0x6ECF, 14625),
+    (0x6ED0, 14639),
+    (0x6ED1, 8578),
+    (0x6ED2, 14616),
+    (0x6ED3, 8566),
+    (0x6ED4, 8582),
+    (0x6ED5, 9405),
+    (0x6ED6, 14605),
+    (0x6ED8, 14630),
+    (0x6ED9, 19091),
+    (0x6EDB, 19033),
+    (0x6EDC, 14629),
+    (0x6EEB, 15301),
+    (0x6EEC, 9024),
+    (0x6EED, 15290),
+    (0x6EEE, 15295),
+    (0x6EEF, 9013),
+    (0x6EF1, 15275),
+    (0x6EF2, 9026),
+    (0x6EF4, 9004),
+    (0x6EF5, 15274),
+    (0x6EF6, 15307),
+    (0x6EF7, 9028),
+    (0x6EF8, 15278),
+    (0x6EF9, 15294),
+    (0x6EFA, 19569),
+    (0x6EFB, 15280),
+    (0x6EFC, 15310),
+    (0x6EFD, 15306),
+    (0x6EFE, 9002),
+    (0x6EFF, 9012),
+    (0x6F00, 15876),
+    (0x6F01, 9025),
+    (0x6F02, 9010),
+    (0x6F03, 15276),
+    (0x6F04, 19546),
+    (0x6F05, 15305),
+    (0x6F06, 9014),
+    (0x6F07, 15302),
+    (0x6F08, 15315),
+    (0x6F09, 15282),
+    (0x6F0A, 15291),
+    (0x6F0D, 15313),
+    (0x6F0E, 15303),
+    (0x6F0F, 9009),
+    (0x6F12, 15289),
+    (0x6F13, 9003),
+    (0x6F14, 9001),
+    (0x6F15, 9019),
+    (0x6F16, 19411),
+    (0x6F18, 15287),
+    (0x6F19, 15284),
+    (0x6F1A, 15285),
+    (0x6F1C, 15309),
+    (0x6F1E, 15314),
+    (0x6F1F, 15312),
+    (0x6F20, 9007),
+    (0x6F21, 15316),
+    (0x6F22, 9011),
+    (0x6F23, 9018),
+    (0x6F25, 15277),
+    (0x6F26, 15852),
+    (0x6F27, 15286),
+    (0x6F29, 9005),
+    (0x6F2A, 9023),
+    (0x6F2B, 9020),
+    (0x6F2C, 9008),
+    (0x6F2D, 15296),
+    (0x6F2E, 15281),
+    (0x6F2F, 9021),
+    (0x6F30, 15298),
+    (0x6F31, 9015),
+    (0x6F32, 9017),
+    (0x6F33, 9000),
+    (0x6F35, 15300),
+    (0x6F36, 15292),
+    (0x6F37, 15279),
+    (0x6F38, 9016),
+    (0x6F39, 15308),
+    (0x6F3A, 15311),
+    (0x6F3B, 15288),
+    (0x6F3C, 15299),
+    (0x6F3D, 19641),
+    (0x6F3E, 9006),
+    (0x6F3F, 9388),
+    (0x6F40, 15297),
+    (0x6F41, 15851),
+    (0x6F43, 15304),
+    (0x6F44, 19574),
+    (0x6F4E, 15283),
+    (0x6F4F, 15861),
+    (0x6F50, 15871),
+    (0x6F51, 9391),
+    (0x6F52, 15870),
+    (0x6F53, 15882),
+    (0x6F54, 9393),
+    (0x6F55, 15868),
+    (0x6F57, 15872),
+    (0x6F58, 9404),
+    (0x6F5A, 15863),
+    (0x6F5B, 9396),
+    (0x6F5C, 19575),
+    (0x6F5D, 15875),
+    (0x6F5E, 16470),
+    (0x6F5F, 9408),
+    (0x6F60, 9407),
+    (0x6F61, 15877),
+    (0x6F62, 15860),
+    (0x6F63, 15887),
+    (0x6F64, 9402),
+    (0x6F66, 9392),
+    (0x6F67, 15880),
+    (0x6F69, 15884),
+    (0x6F6A, 15889),
+    (0x6F6B, 15878),
+    (0x6F6C, 15866),
+    (0x6F6D, 9395),
+    (0x6F6E, 9398),
+    (0x6F6F, 9406),
+    (0x6F70, 9401),
+    (0x6F72, 15869),
+    (0x6F73, 15293),
+    (0x6F74, 19577),
+    (0x6F76, 15865),
+    (0x6F77, 15888),
+    (0x6F78, 9397),
+    (0x6F7A, 9400),
+    (0x6F7B, 15890),
+    (0x6F7C, 9389),
+    (0x6F7D, 15879),
+    (0x6F7E, 15853),
+    (0x6F7F, 15885),
+    (0x6F80, 10016),
+    (0x6F82, 15867),
+    (0x6F84, 9390),
+    (0x6F85, 15862),
+    (0x6F86, 9394),
+    (0x6F87, 15854),
+    (0x6F88, 9022),
+    (0x6F89, 15858),
+    (0x6F8A, 19399),
+    (0x6F8B, 15883),
+    (0x6F8C, 15859),
+    (0x6F8D, 15857),
+    (0x6F8E, 9399),
+    (0x6F90, 15881),
+    (0x6F92, 15856),
+    (0x6F93, 15874),
+    (0x6F94, 15873),
+    (0x6F95, 15886),
+    (0x6F96, 15864),
+    (0x6F97, 9403),
+    (0x6F9D, 19567),
+    (0x6F9E, 16473),
+    (0x6F9F, 19580),
+    (0x6FA0, 9743),
+    (0x6FA1, 9733),
+    (0x6FA2, 16485),
+    (0x6FA3, 16465),
+    (0x6FA4, 9735),
+    (0x6FA5, 16477),
+    (0x6FA6, 9742),
+    (0x6FA7, 9737),
+    (0x6FA8, 16475),
+    (0x6FA9, 16971),
+    (0x6FAA, 16481),
+    (0x6FAB, 16487),
+    (0x6FAC, 16480),
+    (0x6FAD, 16463),
+    (0x6FAE, 16478),
+    (0x6FAF, 16489),
+    (0x6FB0, 16491),
+    (0x6FB1, 9732),
+    (0x6FB2, 16490),
+    (0x6FB3, 9738),
+    (0x6FB4, 9744),
+    (0x6FB6, 9741),
+    (0x6FB8, 16484),
+    (0x6FB9, 9740),
+    (0x6FBA, 16479),
+    (0x6FBC, 16467),
+    (0x6FBD, 16472),
+    (0x6FBF, 16483),
+    (0x6FC0, 9739),
+    (0x6FC1, 9736),
+    (0x6FC2, 9731),
+    (0x6FC3, 9734),
+    (0x6FC4, 16471),
+    (0x6FC6, 15855),
+    (0x6FC7, 16466),
+    (0x6FC8, 16469),
+    (0x6FC9, 16486),
+    (0x6FCA, 16474),
+    (0x6FCB, 16464),
+    (0x6FCC, 16970),
+    (0x6FCD, 16488),
+    (0x6FCE, 16468),
+    (0x6FCF, 16482),
+    (0x6FD3, 19582),
+    (0x6FD4, 16973),
+    (0x6FD5, 10020),
+    (0x6FD8, 10008),
+    (0x6FDB, 10012),
+    (0x6FDC, 16975),
+    (0x6FDD, 16981),
+    (0x6FDE, 16979),
+    (0x6FDF, 10010),
+    (0x6FE0, 10011),
+    (0x6FE1, 10018),
+    (0x6FE2, 16982),
+    (0x6FE3, 16974),
+    (0x6FE4, 10013),
+    (0x6FE6, 16978),
+    (0x6FE7, 16977),
+    (0x6FE8, 16983),
+    (0x6FE9, 10019),
+    (0x6FEB, 10014),
+    (0x6FEC, 10017),
+    (0x6FED, 16976),
+    (0x6FEE, 10021),
+    (0x6FEF, 10015),
+    (0x6FF0, 10022),
+    (0x6FF1, 10009),
+    (0x6FF2, 16980),
+    (0x6FF4, 16972),
+    (0x6FF7, 17419),
+    (0x6FF8, 19565),
+    (0x6FFA, 10254),
+    (0x6FFB, 17416),
+    (0x6FFC, 17418),
+    (0x6FFE, 10252),
+    (0x6FFF, 17414),
+    (0x7000, 17415),
+    (0x7001, 17410),
+    (0x7004, 16476),
+    (0x7005, 17411),
+    (0x7006, 10253),
+    (0x7007, 17407),
+    (0x7009, 10250),
+    (0x700A, 17420),
+    (0x700B, 10251),
+    (0x700C, 17408),
+    (0x700D, 17409),
+    (0x700E, 17413),
+    (0x700F, 10256),
+    (0x7011, 10255),
+    (0x7014, 17412),
+    (0x7015, 10418),
+    (0x7016, 17772),
+    (0x7017, 17778),
+    (0x7018, 10419),
+    (0x7019, 17769),
+    (0x701A, 10416),
+    (0x701B, 10413),
+    (0x701C, 17780),
+    (0x701D, 10417),
+    (0x701F, 10414),
+    (0x7020, 17771),
+    (0x7021, 17774),
+    (0x7022, 17775),
+    (0x7023, 17776),
+    (0x7024, 17779),
+    (0x7026, 17417),
+    (0x7027, 17770),
+    (0x7028, 10415),
+    (0x7029, 17777),
+    (0x702A, 17781),
+    (0x702B, 17773),
+    (0x702F, 18084),
+    (0x7030, 10561),
+    (0x7031, 18087),
+    (0x7032, 10562),
+    (0x7033, 18095),
+    (0x7034, 18086),
+    (0x7035, 18083),
+    (0x7037, 18085),
+    (0x7038, 18089),
+    (0x7039, 18092),
+    (0x703A, 18091),
+    (0x703B, 18094),
+    (0x703C, 18082),
+    (0x703E, 10560),
+    (0x703F, 18090),
+    (0x7040, 18093),
+    (0x7041, 18096),
+    (0x7042, 18088),
+    (0x7043, 18325),
+    (0x7044, 18326),
+    (0x7045, 18330),
+    (0x7046, 18331),
+    (0x7048, 18328),
+    (0x7049, 18329),
+    (0x704A, 18327),
+    (0x704B, 19594),
+    (0x704C, 10654),
+    (0x7051, 10732),
+    (0x7052, 18506),
+    (0x7055, 18503),
+    (0x7056, 18504),
+    (0x7057, 18505),
+    (0x7058, 10733),
+    (0x705A, 18657),
+    (0x705B, 18656),
+    (0x705D, 18760),
+    (0x705E, 10822),
+    (0x705F, 18758),
+    (0x7060, 18761),
+    (0x7061, 18759),
+    (0x7062, 18830),
+    (0x7063, 10856),
+    (0x7064, 10870),
+    (0x7065, 18905),
+    (0x7066, 18884),
+    (0x7068, 18904),
+    (0x7069, 18950),
+    (0x706A, 18951),
+    (0x706B, 5642),
+    (0x7070, 5910),
+    (0x7071, 11462),
+    (0x7074, 11648),
+    (0x7076, 6154),
+    (0x7078, 6157),
+    (0x7079, 19139),
+    (0x707A, 11649),
+    (0x707C, 6155),
+    (0x707D, 6156),
+    (0x707E, 19595),
+    (0x7081, 19597),
+    (0x7082, 11956),
+    (0x7083, 11958),
+    (0x7084, 11953),
+    (0x7085, 11950),
+    (0x7086, 11952),
+    (0x708A, 6503),
+    (0x708B, 19189),
+    (0x708E, 6501),
+    (0x7091, 11954),
+    (0x7092, 6502),
+    (0x7093, 11951),
+    (0x7094, 11948),
+    (0x7095, 6500),
+    (0x7096, 11955),
+    (0x7098, 11949),
+    (0x7099, 6504),
+    (0x709A, 11957),
+    (0x709F, 12323),
+    (0x70A1, 12327),
+    (0x70A4, 6836),
+    (0x70A7, 19596),
+    (0x70A9, 12330),
+    (0x70AB, 6828),
+    (0x70AC, 6831),
+    (0x70AD, 6833),
+    (0x70AE, 6835),
+    (0x70AF, 6832),
+    (0x70B0, 12326),
+    (0x70B1, 12325),
+    (0x70B3, 6830),
+    (0x70B4, 12328),
+    (0x70B5, 12329),
+    (0x70B7, 12322),
+    (0x70B8, 6834),
+    (0x70BA, 6829),
+    (0x70BD, 19623),
+    (0x70BE, 12324),
+    (0x70C5, 12772),
+    (0x70C6, 12773),
+    (0x70C7, 12774),
+    (0x70C8, 7238),
+    (0x70CA, 7234),
+    (0x70CB, 12763),
+    (0x70CC, 19598),
+    (0x70CD, 12771),
+    (0x70CE, 12776),
+    (0x70CF, 7239),
+    (0x70D1, 12761),
+    (0x70D2, 12767),
+    (0x70D3, 12760),
+    (0x70D4, 12770),
+    (0x70D5, 19599),
+    (0x70D6, 19600),
+    (0x70D7, 12766),
+    (0x70D8, 7235),
+    (0x70D9, 7237),
+    (0x70DA, 12775),
+    (0x70DC, 12759),
+    (0x70DD, 12762),
+    (0x70DE, 12768),
+    (0x70DF, 19601),
+    (0x70E0, 12769),
+    (0x70E1, 12777),
+    (0x70E2, 12765),
+    (0x70E4, 7236),
+    (0x70EF, 7738),
+    (0x70F0, 13359),
+    (0x70F3, 13361),
+    (0x70F4, 13357),
+    (0x70F6, 13369),
+    (0x70F7, 13355),
+    (0x70F8, 13368),
+    (0x70F9, 7734),
+    (0x70FA, 13353),
+    (0x70FB, 14000),
+    (0x70FC, 13363),
+    (0x70FD, 7737),
+    (0x70FF, 13364),
+    (0x7100, 13367),
+    (0x7102, 13371),
+    (0x7104, 13360),
+    (0x7106, 13365),
+    (0x7109, 7735),
+    (0x710A, 7736),
+    (0x710B, 13370),
+    (0x710C, 13358),
+    (0x710D, 13354),
+    (0x710E, 13372),
+    (0x7110, 13362),
+    (0x7113, 13366),
+    (0x7117, 13356),
+    (0x7119, 8172),
+    (0x711A, 8173),
+    (0x711B, 14010),
+    (0x711C, 8179),
+    (0x711E, 13998),
+    (0x711F, 14007),
+    (0x7120, 13997),
+    (0x7121, 8176),
+    (0x7122, 14005),
+    (0x7123, 14003),
+    (0x7125, 14004),
+    (0x7126, 8174),
+    (0x7128, 14008),
+    (0x712B, 19607),
+    (0x712C, 19617),
+    (0x712E, 14001),
+    (0x712F, 13999),
+    (0x7130, 8175),
+    (0x7131, 14002),
+    (0x7132, 14006),
+    (0x7136, 8177),
+    (0x713A, 14009),
+    (0x7141, 14649),
+    (0x7142, 14656),
+    (0x7143, 14658),
+    (0x7144, 14664),
+    (0x7145, 19608),
+    (0x7146, 8598),
+    (0x7147, 14644),
+    (0x7149, 8590),
+    (0x714A, 19610),
+    (0x714B, 14659),
+    (0x714C, 8595),
+    (0x714D, 14665),
+    (0x714E, 8586),
+    (0x714F, 19614),
+    (0x7150, 14662),
+    (0x7152, 14646),
+    (0x7153, 14663),
+    (0x7154, 14645),
+    (0x7156, 8600),
+    (0x7158, 14657),
+    (0x7159, 8587),
+    (0x715A, 14666),
+    (0x715C, 8592),
+    (0x715D, 14650),
+    (0x715E, 8597),
+    (0x715F, 14661),
+    (0x7160, 14648),
+    (0x7161, 14655),
+    (0x7162, 14651),
+    (0x7163, 14647),
+    (0x7164, 8589),
+    (0x7165, 8596),
+    (0x7166, 8594),
+    (0x7167, 8591),
+    (0x7168, 8599),
+    (0x7169, 8588),
+    (0x716A, 14654),
+    (0x716C, 8593),
+    (0x716E, 8178),
+    (0x7170, 14660),
+    (0x7172, 14652),
+    (0x7178, 14653),
+    (0x717B, 15324),
+    (0x717D, 9031),
+    (0x7180, 15320),
+    (0x7181, 15326),
+    (0x7182, 15322),
+    (0x7184, 9033),
+    (0x7185, 15321),
+    (0x7186, 15325),
+    (0x7187, 15317),
+    (0x7189, 15319),
+    (0x718A, 9032),
+    (0x718F, 15323),
+    (0x7190, 15318),
+    (0x7192, 9034),
+    (0x7194, 9029),
+    (0x7196, 19605),
+    (0x7197, 15327),
+    (0x7199, 9030),
+    (0x719A, 15896),
+    (0x719B, 15893),
+    (0x719C, 15905),
+    (0x719D, 15899),
+    (0x719E, 15901),
+    (0x719F, 9409),
+    (0x71A0, 15895),
+    (0x71A1, 15903),
+    (0x71A4, 15902),
+    (0x71A5, 15900),
+    (0x71A7, 15906),
+    (0x71A8, 9412),
+    (0x71A9, 15897),
+    (0x71AA, 15904),
+    (0x71AC, 9410),
+    (0x71AF, 15892),
+    (0x71B0, 15894),
+    (0x71B1, 9411),
+    (0x71B2, 15891),
+    (0x71B3, 15907),
+    (0x71B4, 19604),
+    (0x71B5, 15898),
+    (0x71B8, 16495),
+    (0x71B9, 9751),
+    (0x71BA, 19621),
+    (0x71BC, 16506),
+    (0x71BD, 16504),
+    (0x71BE, 9745),
+    (0x71BF, 16494),
+    (0x71C0, 16497),
+    (0x71C1, 16498),
+    (0x71C2, 16493),
+    (0x71C3, 9755),
+    (0x71C4, 9756),
+    (0x71C5, 16492),
+    (0x71C6, 16507),
+    (0x71C7, 16502),
+    (0x71C8, 9749),
+    (0x71C9, 9746),
+    (0x71CA, 16501),
+    (0x71CB, 16499),
+    (0x71CE, 9752),
+    (0x71CF, 16503),
+    (0x71D0, 9747),
+    (0x71D2, 9748),
+    (0x71D4, 16500),
+    (0x71D5, 9750),
+    (0x71D6, 16496),
+    (0x71D8, 16505),
+    (0x71D9, 9753),
+    (0x71DA, 16508),
+    (0x71DB, 16509),
+    (0x71DC, 9754),
+    (0x71DD, 19106),
+    (0x71DF, 10024),
+    (0x71E0, 10031),
+    (0x71E1, 16984),
+    (0x71E2, 16990),
+    (0x71E4, 16988),
+    (0x71E5, 10027),
+    (0x71E6, 10026),
+    (0x71E7, 10023),
+    (0x71E8, 16986),
+    (0x71EC, 10029),
+    (0x71ED, 10028),
+    (0x71EE, 10025),
+    (0x71F0, 16989),
+    (0x71F1, 16985),
+    (0x71F2, 16987),
+    (0x71F4, 10030),
+    (0x71F8, 10260),
+    (0x71F9, 17423),
+    (0x71FB, 10257),
+    (0x71FC, 10258),
+    (0x71FD, 17425),
+    (0x71FE, 10259),
+    (0x71FF, 17422),
+    (0x7201, 17421),
+    (0x7202, 17785),
+    (0x7203, 17424),
+    (0x7205, 17786),
+    (0x7206, 10420),
+    (0x7207, 17784),
+    (0x720A, 17783),
+    (0x720C, 17782),
+    (0x720D, 10421),
+    (0x720E, 19624),
+    (0x7210, 10563),
+    (0x7213, 18097),
+    (0x7214, 18098),
+    (0x7215, 19626),
+    (0x7219, 18334),
+    (0x721A, 18333),
+    (0x721B, 10655),
+    (0x721D, 18332),
+    (0x721E, 18507),
+    (0x721F, 18508),
+    (0x7222, 18658),
+    (0x7223, 18762),
+    (0x7224, 19629),
+    (0x7226, 18831),
+    (0x7227, 18930),
+    (0x7228, 10890),
+    (0x7229, 18940),
+    (0x722A, 5643),
+    (0x722C, 6505),
+    (0x722D, 6506),
+    (0x722E, 19632),
+    (0x7230, 6837),
+    (0x7235, 10032),
+    (0x7236, 5644),
+    (0x7238, 6507),
+    (0x7239, 7240),
+    (0x723A, 8601),
+    (0x723B, 5645),
+    (0x723D, 7739),
+    (0x723E, 9035),
+    (0x723F, 11338),
+    (0x7240, 19633),
+    (0x7241, 12331),
+    (0x7242, 12778),
+    (0x7244, 15328),
+    (0x7246, 10033),
+    (0x7247, 5646),
+    (0x7248, 6508),
+    (0x7249, 12332),
+    (0x724A, 12333),
+    (0x724B, 14011),
+    (0x724C, 8180),
+    (0x724F, 14667),
+    (0x7252, 8602),
+    (0x7253, 15329),
+    (0x7255, 19636),
+    (0x7256, 9413),
+    (0x7257, 19637),
+    (0x7258, 10422),
+    (0x7259, 5647),
+    (0x725A, 14012),
+    (0x725B, 5648),
+    (0x725D, 5912),
+    (0x725E, 11463),
+    (0x725F, 5911),
+    (0x7260, 6160),
+    (0x7261, 6159),
+    (0x7262, 6158),
+    (0x7263, 11650),
+    (0x7267, 6509),
+    (0x7269, 6510),
+    (0x726A, 11959),
+    (0x726C, 12334),
+    (0x726E, 12337),
+    (0x726F, 6839),
+    (0x7270, 12335),
+    (0x7272, 6838),
+    (0x7273, 12336),
+    (0x7274, 6840),
+    (0x7276, 12781),
+    (0x7277, 12780),
+    (0x7278, 12779),
+    (0x7279, 7241),
+    (0x727B, 13374),
+    (0x727C, 13375),
+    (0x727D, 7740),
+    (0x727E, 13373),
+    (0x727F, 13376),
+    (0x7280, 8182),
+    (0x7281, 7741),
+    (0x7282, 19642),
+    (0x7284, 8181),
+    (0x7285, 14016),
+    (0x7286, 14015),
+    (0x7288, 14013),
+    (0x7289, 14014),
+    (0x728B, 14017),
+    (0x728C, 14669),
+    (0x728D, 14668),
+    (0x728E, 14672),
+    (0x7290, 14671),
+    (0x7291, 14670),
+    (0x7292, 9036),
+    (0x7293, 15332),
+    (0x7295, 15331),
+    (0x7296, 9037),
+    (0x7297, 15330),
+    (0x7298, 15908),
+    (0x729A, 15909),
+    (0x729B, 9414),
+    (0x729D, 16510),
+    (0x729E, 16511),
+    (0x72A1, 17791),
+    (0x72A2, 10423),
+    (0x72A3, 17790),
+    (0x72A4, 17789),
+    (0x72A5, 17787),
+    (0x72A6, 17788),
+    (0x72A7, 10656),
+    (0x72A8, 18099),
+    (0x72A9, 18509),
+    (0x72AA, 18832),
+    (0x72AC, 5649),
+    (0x72AE, 11371),
+    (0x72AF, 5753),
+    (0x72B0, 11372),
+    (0x72B4, 11464),
+    (0x72B5, 11465),
+    (0x72BA, 11656),
+    (0x72BD, 11652),
+    (0x72BF, 11651),
+    (0x72C0, 6511),
+    (0x72C1, 11655),
+    (0x72C2, 6162),
+    (0x72C3, 11653),
+    (0x72C4, 6161),
+    (0x72C5, 11657),
+    (0x72C6, 11654),
+    (0x72C9, 11963),
+    (0x72CA, 12338),
+    (0x72CB, 11961),
+    (0x72CC, 11968),
+    (0x72CE, 6512),
+    (0x72D0, 6515),
+    (0x72D1, 11969),
+    (0x72D2, 11965),
+    (0x72D4, 11966),
+    (0x72D6, 11960),
+    (0x72D7, 6514),
+    (0x72D8, 11962),
+    (0x72D9, 6513),
+    (0x72DA, 11967),
+    (0x72DC, 11964),
+    (0x72DF, 12342),
+    (0x72E0, 6842),
+    (0x72E1, 6843),
+    (0x72E3, 12345),
+    (0x72E4, 12339),
+    (0x72E6, 12344),
+    (0x72E8, 12340),
+    (0x72E9, 6841),
+    (0x72EA, 12343),
+    (0x72EB, 12341),
+    (0x72F3, 12787),
+    (0x72F4, 12784),
+    (0x72F6, 12786),
+    (0x72F7, 7246),
+    (0x72F8, 7245),
+    (0x72F9, 7243),
+    (0x72FA, 12783),
+    (0x72FB, 12788),
+    (0x72FC, 7242),
+    (0x72FD, 7244),
+    (0x72FE, 12785),
+    (0x72FF, 13384),
+    (0x7300, 12782),
+    (0x7301, 12789),
+    (0x7307, 13379),
+    (0x7308, 13383),
+    (0x730A, 13382),
+    (0x730B, 14019),
+    (0x730C, 14030),
+    (0x730F, 13385),
+    (0x7311, 13380),
+    (0x7312, 14018),
+    (0x7313, 7745),
+    (0x7316, 7744),
+    (0x7317, 13378),
+    (0x7318, 13381),
+    (0x7319, 7746),
+    (0x731B, 7743),
+    (0x731C, 7742),
+    (0x731D, 13377),
+    (0x731E, 13386),
+    (0x7322, 14021),
+    (0x7323, 14028),
+    (0x7325, 8184),
+    (0x7326, 14027),
+    (0x7327, 14024),
+    (0x7328, 19649),
+    (0x7329, 8186),
+    (0x732A, 19643),
+    (0x732B, 19644),
+    (0x732D, 14026),
+    (0x732E, 19650),
+    (0x7330, 14020),
+    (0x7331, 14022),
+    (0x7332, 14025),
+    (0x7333, 14023),
+    (0x7334, 8185),
+    (0x7335, 14029),
+    (0x7336, 8183),
+    (0x7337, 8603),
+    (0x733A, 14676),
+    (0x733B, 14675),
+    (0x733C, 14673),
+    (0x733E, 8606),
+    (0x733F, 8605),
+    (0x7340, 14677),
+    (0x7342, 14674),
+    (0x7343, 15333),
+    (0x7344, 9038),
+    (0x7345, 8604),
+    (0x7349, 14679),
+    (0x734A, 14678),
+    (0x734C, 15336),
+    (0x734D, 15334),
+    (0x734E, 9415),
+    (0x7350, 9039),
+    (0x7351, 15335),
+    (0x7352, 15911),
+    (0x7357, 9416),
+    (0x7358, 15910),
+    (0x7359, 15919),
+    (0x735A, 15918),
+    (0x735B, 15916),
+    (0x735D, 15915),
+    (0x735E, 15912),
+    (0x735F, 15913),
+    (0x7360, 15914),
+    (0x7361, 15917),
+    (0x7362, 15920),
+    (0x7365, 16516),
+    (0x7366, 16513),
+    (0x7367, 16514),
+    (0x7368, 9757),
+    (0x7369, 16512),
+    (0x736A, 16518),
+    (0x736B, 16517),
+    (0x736C, 16515),
+    (0x736E, 16992),
+    (0x736F, 16993),
+    (0x7370, 10034),
+    (0x7372, 10035),
+    (0x7373, 16991),
+    (0x7375, 10262),
+    (0x7376, 17426),
+    (0x7377, 10261),
+    (0x7378, 10424),
+    (0x737A, 10425),
+    (0x737B, 10564),
+    (0x737C, 18101),
+    (0x737D, 18100),
+    (0x737E, 18335),
+    (0x737F, 18510),
+    (0x7380, 10734),
+    (0x7381, 18660),
+    (0x7382, 18659),
+    (0x7383, 18661),
+    (0x7384, 5754),
+    (0x7385, 12346),
+    (0x7386, 7247),
+    (0x7387, 7747),
+    (0x7388, 13387),
+    (0x7389, 5755),
+    (0x738A, 11373),
+    (0x738B, 5650),
+    (0x738E, 11466),
+    (0x7392, 11662),
+    (0x7393, 11660),
+    (0x7394, 11661),
+    (0x7395, 11658),
+    (0x7396, 6163),
+    (0x7397, 11659),
+    (0x739C, 19124),
+    (0x739D, 11977),
+    (0x739F, 6518),
+    (0x73A0, 11975),
+    (0x73A1, 11971),
+    (0x73A2, 11974),
+    (0x73A4, 11970),
+    (0x73A5, 6520),
+    (0x73A6, 11973),
+    (0x73A7, 19121),
+    (0x73A8, 6517),
+    (0x73A9, 6516),
+    (0x73AA, 19652),
+    (0x73AB, 6519),
+    (0x73AC, 11976),
+    (0x73AD, 11972),
+    (0x73B2, 6847),
+    (0x73B3, 6850),
+    (0x73B4, 12354),
+    (0x73B5, 12353),
+    (0x73B6, 12352),
+    (0x73B7, 6844),
+    (0x73B8, 12361),
+    (0x73B9, 12351),
+    (0x73BB, 6846),
+    (0x73BC, 12794),
+    (0x73BE, 12358),
+    (0x73BF, 12356),
+    (0x73C0, 6849),
+    (0x73C2, 12348),
+    (0x73C3, 12359),
+    (0x73C5, 12350),
+    (0x73C6, 12360),
+    (0x73C7, 12357),
+    (0x73C8, 12349),
+    (0x73C9, 19655),
+    (0x73CA, 6845),
+    (0x73CB, 12362),
+    (0x73CC, 12347),
+    (0x73CD, 6848),
+    (0x73CE, 19271),
+    (0x73CF, 19651),
+    (0x73D2, 12799),
+    (0x73D3, 12790),
+    (0x73D4, 12801),
+    (0x73D5, 19101),
+    (0x73D6, 12793),
+    (0x73D7, 12804),
+    (0x73D8, 12805),
+    (0x73D9, 12791),
+    (0x73DA, 12803),
+    (0x73DB, 12800),
+    (0x73DC, 12798),
+    (0x73DD, 12802),
+    (0x73DE, 7253),
+    (0x73E0, 7251),
+    (0x73E3, 12796),
+    (0x73E4, 19677),
+    (0x73E5, 12792),
+    (0x73E7, 12795),
+    (0x73E8, 12806),
+    (0x73E9, 12797),
+    (0x73EA, 7252),
+    (0x73EB, 12355),
+    (0x73ED, 7248),
+    (0x73EE, 7250),
+    (0x73EF, 19003),
+    (0x73F4, 13401),
+    (0x73F5, 13390),
+    (0x73F6, 13388),
+    (0x73F7, 19665),
+    (0x73F8, 13389),
+    (0x73FA, 13396),
+    (0x73FC, 13397),
+    (0x73FD, 13393),
+    (0x73FE, 7752),
+    (0x73FF, 13398),
+    (0x7400, 13395),
+    (0x7401, 13392),
+    (0x7403, 7750),
+    (0x7404, 13391),
+    (0x7405, 7748),
+    (0x7406, 7751),
+    (0x7407, 13394),
+    (0x7408, 13402),
+    (0x7409, 7249),
+    (0x740A, 7749),
+    (0x740B, 13400),
+    (0x740C, 13399),
+    (0x740D, 7753),
+    (0x7415, 19666),
+    (0x7416, 14035),
+    (0x7417, 19159),
+    (0x741A, 14036),
+    (0x741B, 8196),
+    (0x741D, 14042),
+    (0x7420, 14044),
+    (0x7421, 14037),
+    (0x7422, 8190),
+    (0x7423, 14041),
+    (0x7424, 14040),
+    (0x7425, 8191),
+    (0x7426, 8197),
+    (0x7428, 8198),
+    (0x7429, 14043),
+    (0x742A, 8188),
+    (0x742B, 14034),
+    (0x742C, 14032),
+    (0x742D, 14038),
+    (0x742E, 14031),
+    (0x742F, 8195),
+    (0x7430, 14033),
+    (0x7431, 14039),
+    (0x7432, 14045),
+    (0x7433, 8189),
+    (0x7434, 8194),
+    (0x7435, 8192),
+    (0x7436, 8193),
+    (0x7439, 19669),
+    (0x743A, 8187),
+    (0x743F, 8613),
+    (0x7440, 14686),
+    (0x7441, 8612),
+    (0x7442, 14690),
+    (0x7444, 14680),
+    (0x7446, 14691),
+    (0x7447, 19676),
+    (0x7449, 19656),
+    (0x744A, 14681),
+    (0x744B, 14682),
+    (0x744D, 14692),
+    (0x744E, 14689),
+    (0x744F, 14687),
+    (0x7450, 14688),
+    (0x7451, 14684),
+    (0x7452, 14683),
+    (0x7453, 19559),
+    (0x7454, 14693),
+    (0x7455, 8609),
+    (0x7457, 14685),
+    (0x7459, 8614),
+    (0x745A, 8608),
+    (0x745B, 8615),
+    (0x745C, 8616),
+    (0x745D, 19712),
+    (0x745E, 8611),
+    (0x745F, 8610),
+    (0x7460, 19674),
+    (0x7462, 15337),
+    (0x7463, 9041),
+    (0x7464, 9040),
+    (0x7465, 19243),
+    (0x7467, 15342),
+    (0x7469, 9417),
+    (0x746A, 9042),
+    (0x746C, 19680),
+    (0x746D, 9044),
+    (0x746E, 15343),
+    (0x746F, 8607),
+    (0x7470, 9043),
+    (0x7471, 15339),
+    (0x7472, 15341),
+    (0x7473, 15338),
+    (0x7474, 19682),
+    (0x7475, 15340),
+    (0x7476, 19678),
+    (0x7479, 15930),
+    (0x747C, 15929),
+    (0x747D, 15926),
+    (0x747E, 9420),
+    (0x747F, 16519),
+    (0x7480, 9421),
+    (0x7481, 15925),
+    (0x7482, 19685),
+    (0x7483, 9419),
+    (0x7485, 15927),
+    (0x7486, 15924),
+    (0x7487, 15921),
+    (0x7488, 15928),
+    (0x7489, 15922),
+    (0x748A, 15923),
+    (0x748B, 9418),
+    (0x7490, 16997),
+    (0x7492, 16523),
+    (0x7494, 16522),
+    (0x7495, 16524),
+    (0x7497, 16994),
+    (0x7498, 9760),
+    (0x749A, 16520),
+    (0x749C, 9758),
+    (0x749E, 9762),
+    (0x749F, 9761),
+    (0x74A0, 16521),
+    (0x74A1, 16525),
+    (0x74A3, 9759),
+    (0x74A4, 19360),
+    (0x74A5, 17001),
+    (0x74A6, 10038),
+    (0x74A7, 10263),
+    (0x74A8, 10039),
+    (0x74A9, 10036),
+    (0x74AA, 16998),
+    (0x74AB, 16996),
+    (0x74AD, 16999),
+    (0x74AF, 17002),
+    (0x74B0, 10037),
+    (0x74B1, 17000),
+    (0x74B2, 16995),
+    (0x74B5, 17429),
+    (0x74B6, 17432),
+    (0x74B7, 17794),
+    (0x74B8, 17427),
+    (0x74BA, 18102),
+    (0x74BB, 17433),
+    (0x74BD, 10426),
+    (0x74BE, 17431),
+    (0x74BF, 10264),
+    (0x74C0, 17428),
+    (0x74C1, 17430),
+    (0x74C2, 17434),
+    (0x74C3, 17795),
+    (0x74C5, 17793),
+    (0x74C8, 19694),
+    (0x74CA, 10427),
+    (0x74CB, 17792),
+    (0x74CF, 10565),
+    (0x74D4, 10658),
+    (0x74D5, 18512),
+    (0x74D6, 10657),
+    (0x74D7, 18514),
+    (0x74D8, 18511),
+    (0x74D9, 18513),
+    (0x74DA, 10786),
+    (0x74DB, 18763),
+    (0x74DC, 5756),
+    (0x74DD, 11978),
+    (0x74DE, 12807),
+    (0x74DF, 12808),
+    (0x74E0, 7754),
+    (0x74E1, 14694),
+    (0x74E2, 9763),
+    (0x74E3, 10428),
+    (0x74E4, 10735),
+    (0x74E5, 18764),
+    (0x74E6, 5757),
+    (0x74E8, 11979),
+    (0x74E9, 5213),
+    (0x74EC, 12363),
+    (0x74EE, 12364),
+    (0x74F4, 12809),
+    (0x74F5, 12810),
+    (0x74F6, 7755),
+    (0x74F7, 7756),
+    (0x74FB, 14046),
+    (0x74FD, 14697),
+    (0x74FE, 14696),
+    (0x74FF, 14695),
+    (0x7500, 15344),
+    (0x7502, 15345),
+    (0x7503, 15346),
+    (0x7504, 9045),
+    (0x7507, 15932),
+    (0x7508, 15931),
+    (0x750B, 16526),
+    (0x750C, 9764),
+    (0x750D, 9765),
+    (0x750E, 19696),
+    (0x750F, 17006),
+    (0x7510, 17003),
+    (0x7511, 17004),
+    (0x7512, 17005),
+    (0x7513, 17436),
+    (0x7514, 17435),
+    (0x7515, 10265),
+    (0x7516, 17796),
+    (0x7517, 18336),
+    (0x7518, 5758),
+    (0x751A, 6851),
+    (0x751C, 7757),
+    (0x751D, 14698),
+    (0x751E, 19698),
+    (0x751F, 5759),
+    (0x7521, 12811),
+    (0x7522, 7758),
+    (0x7525, 8199),
+    (0x7526, 8200),
+    (0x7528, 5760),
+    (0x7529, 5761),
+    (0x752A, 11467),
+    (0x752B, 6165),
+    (0x752C, 6164),
+    (0x752D, 6852),
+    (0x752E, 12365),
+    (0x752F, 14047),
+    (0x7530, 5762),
+    (0x7531, 5763),
+    (0x7532, 5764),
+    (0x7533, 5765),
+    (0x7537, 6166),
+    (0x7538, 6167),
+    (0x7539, 11664),
+    (0x753A, 11663),
+    (0x753D, 6521),
+    (0x753E, 11982),
+    (0x753F, 11980),
+    (0x7540, 11981),
+    (0x7547, 12366),
+    (0x7548, 12367),
+    (0x754A, 19705),
+    (0x754B, 6856),
+    (0x754C, 6854),
+    (0x754D, 19704),
+    (0x754E, 6855),
+    (0x754F, 6853),
+    (0x7554, 7254),
+    (0x7559, 7258),
+    (0x755A, 7257),
+    (0x755B, 12812),
+    (0x755C, 7256),
+    (0x755D, 7255),
+    (0x755F, 12813),
+    (0x7562, 7761),
+    (0x7563, 13404),
+    (0x7564, 13403),
+    (0x7565, 7759),
+    (0x7566, 7760),
+    (0x7567, 19706),
+    (0x756A, 8202),
+    (0x756B, 8201),
+    (0x756C, 14049),
+    (0x756E, 19707),
+    (0x756F, 14048),
+    (0x7570, 7762),
+    (0x7576, 8617),
+    (0x7577, 14700),
+    (0x7578, 8618),
+    (0x7579, 14699),
+    (0x757D, 15347),
+    (0x757E, 15933),
+    (0x757F, 9422),
+    (0x7580, 16527),
+    (0x7584, 17007),
+    (0x7586, 10430),
+    (0x7587, 10429),
+    (0x758A, 10736),
+    (0x758B, 5766),
+    (0x758C, 11983),
+    (0x758E, 19711),
+    (0x758F, 7763),
+    (0x7590, 15348),
+    (0x7591, 9046),
+    (0x7592, 10947),
+    (0x7594, 11665),
+    (0x7595, 11666),
+    (0x7598, 11984),
+    (0x7599, 6523),
+    (0x759A, 6524),
+    (0x759D, 6522),
+    (0x759E, 19713),
+    (0x75A2, 6860),
+    (0x75A3, 6861),
+    (0x75A4, 6858),
+    (0x75A5, 6859),
+    (0x75A7, 12368),
+    (0x75AA, 12369),
+    (0x75AB, 6857),
+    (0x75B0, 12814),
+    (0x75B2, 7262),
+    (0x75B3, 7263),
+    (0x75B4, 19714),
+    (0x75B5, 7766),
+    (0x75B6, 12820),
+    (0x75B8, 7268),
+    (0x75B9, 7266),
+    (0x75BA, 12821),
+    (0x75BB, 12816),
+    (0x75BC, 7265),
+    (0x75BD, 7264),
+    (0x75BE, 7259),
+    (0x75BF, 12819),
+    (0x75C0, 12818),
+    (0x75C1, 12815),
+    (0x75C2, 7267),
+    (0x75C4, 12817),
+    (0x75C5, 7260),
+    (0x75C7, 7261),
+    (0x75CA, 7767),
+    (0x75CB, 13408),
+    (0x75CC, 13409),
+    (0x75CD, 7768),
+    (0x75CE, 13405),
+    (0x75CF, 13407),
+    (0x75D0, 13411),
+    (0x75D1, 13410),
+    (0x75D2, 13406),
+    (0x75D4, 7764),
+    (0x75D5, 7765),
+    (0x75D7, 14057),
+    (0x75D8, 8207),
+    (0x75D9, 8206),
+    (0x75DA, 14051),
+    (0x75DB, 8204),
+    (0x75DD, 14054),
+    (0x75DE, 8208),
+    (0x75DF, 14055),
+    (0x75E0, 8209),
+    (0x75E1, 14052),
+    (0x75E2, 8203),
+    (0x75E3, 8205),
+    (0x75E4, 14056),
+    (0x75E6, 14053),
+    (0x75E7, 14050),
+    (0x75ED, 14713),
+    (0x75EF, 14702),
+    (0x75F0, 8620),
+    (0x75F1, 8623),
+    (0x75F2, 8622),
+    (0x75F3, 8627),
+    (0x75F4, 8626),
+    (0x75F5, 14714),
+    (0x75F6, 14712),
+    (0x75F7, 14705),
+    (0x75F8, 14709),
+    (0x75F9, 14708),
+    (0x75FA, 8624),
+    (0x75FB, 14711),
+    (0x75FC, 14707),
+    (0x75FD, 14715),
+    (0x75FE, 14706),
+    (0x75FF, 8625),
+    (0x7600, 8619),
+    (0x7601, 8621),
+    (0x7602, 19715),
+    (0x7603, 14704),
+    (0x7608, 15350),
+    (0x7609, 9050),
+    (0x760A, 15354),
+    (0x760B, 9049),
+    (0x760C, 15351),
+    (0x760D, 9048),
+    (0x760F, 14703),
+    (0x7610, 14710),
+    (0x7611, 15353),
+    (0x7613, 9051),
+    (0x7614, 15355),
+    (0x7615, 15352),
+    (0x7616, 15349),
+    (0x7619, 15936),
+    (0x761A, 15940),
+    (0x761B, 15942),
+    (0x761C, 15938),
+    (0x761D, 15937),
+    (0x761E, 15935),
+    (0x761F, 9425),
+    (0x7620, 9423),
+    (0x7621, 9428),
+    (0x7622, 9429),
+    (0x7623, 15939),
+    (0x7624, 9426),
+    (0x7625, 15934),
+    (0x7626, 9427),
+    (0x7627, 9047),
+    (0x7628, 15941),
+    (0x7629, 9424),
+    (0x762C, 19716),
+    (0x762D, 16529),
+    (0x762F, 16528),
+    (0x7630, 16536),
+    (0x7631, 16530),
+    (0x7632, 16535),
+    (0x7633, 16532),
+    (0x7634, 9766),
+    (0x7635, 16534),
+    (0x7638, 9767),
+    (0x763A, 9768),
+    (0x763C, 16533),
+    (0x763D, 16531),
+    (0x7642, 10041),
+    (0x7643, 17008),
+    (0x7646, 10040),
+    (0x7647, 17011),
+    (0x7648, 17009),
+    (0x7649, 17010),
+    (0x764C, 10042),
+    (0x764F, 19718),
+    (0x7650, 17440),
+    (0x7651, 19717),
+    (0x7652, 10268),
+    (0x7653, 17441),
+    (0x7656, 10266),
+    (0x7657, 17442),
+    (0x7658, 10267),
+    (0x7659, 17439),
+    (0x765A, 17443),
+    (0x765C, 17437),
+    (0x765F, 10431),
+    (0x7660, 17797),
+    (0x7661, 10432),
+    (0x7662, 10566),
+    (0x7664, 17438),
+    (0x7665, 10567),
+    (0x7669, 10659),
+    (0x766A, 18337),
+    (0x766C, 10738),
+    (0x766D, 18515),
+    (0x766E, 10737),
+    (0x766F, 19719),
+    (0x7670, 18662),
+    (0x7671, 10823),
+    (0x7672, 10824),
+    (0x7675, 18946),
+    (0x7676, 10948),
+    (0x7678, 6862),
+    (0x7679, 12370),
+    (0x767B, 8210),
+    (0x767C, 8211),
+    (0x767D, 5767),
+    (0x767E, 5913),
+    (0x767F, 11468),
+    (0x7681, 11667),
+    (0x7682, 6168),
+    (0x7684, 6525),
+    (0x7686, 6863),
+    (0x7687, 6864),
+    (0x7688, 6865),
+    (0x7689, 13413),
+    (0x768A, 12822),
+    (0x768B, 7269),
+    (0x768E, 7769),
+    (0x768F, 13412),
+    (0x7690, 19722),
+    (0x7692, 14059),
+    (0x7693, 8213),
+    (0x7695, 14058),
+    (0x7696, 8212),
+    (0x7699, 14716),
+    (0x769A, 9430),
+    (0x769B, 15946),
+    (0x769C, 15943),
+    (0x769D, 15944),
+    (0x769E, 15945),
+    (0x76A1, 19727),
+    (0x76A4, 17012),
+    (0x76A5, 19728),
+    (0x76A6, 17444),
+    (0x76AA, 18104),
+    (0x76AB, 18103),
+    (0x76AD, 18516),
+    (0x76AE, 5768),
+    (0x76AF, 11985),
+    (0x76B0, 7270),
+    (0x76B4, 8214),
+    (0x76B5, 14717),
+    (0x76B7, 19729),
+    (0x76B8, 15356),
+    (0x76BA, 9431),
+    (0x76BB, 16537),
+    (0x76BD, 17445),
+    (0x76BE, 18105),
+    (0x76BF, 5769),
+    (0x76C2, 6526),
+    (0x76C3, 6868),
+    (0x76C4, 12371),
+    (0x76C5, 6869),
+    (0x76C6, 6867),
+    (0x76C8, 6866),
+    (0x76C9, 12823),
+    (0x76CA, 7271),
+    (0x76CC, 19730),
+    (0x76CD, 7272),
+    (0x76CE, 7273),
+    (0x76D2, 7771),
+    (0x76D3, 13414),
+    (0x76D4, 7770),
+    (0x76DA, 14060),
+    (0x76DB, 7772),
+    (0x76DC, 8215),
+    (0x76DD, 14718),
+    (0x76DE, 8628),
+    (0x76DF, 8629),
+    (0x76E1, 9052),
+    (0x76E3, 9053),
+    (0x76E4, 9432),
+    (0x76E5, 9770),
+    (0x76E6, 16538),
+    (0x76E7, 9769),
+    (0x76E9, 17013),
+    (0x76EA, 10043),
+    (0x76EC, 17446),
+    (0x76ED, 18106),
+    (0x76EE, 5770),
+    (0x76EF, 6169),
+    (0x76F0, 11988),
+    (0x76F1, 11987),
+    (0x76F2, 6527),
+    (0x76F3, 11986),
+    (0x76F4, 6528),
+    (0x76F5, 11989),
+    (0x76F7, 12377),
+    (0x76F8, 6872),
+    (0x76F9, 6871),
+    (0x76FA, 12379),
+    (0x76FB, 12378),
+    (0x76FC, 6876),
+    (0x76FE, 6875),
+    (0x7701, 6870),
+    (0x7703, 12373),
+    (0x7704, 12374),
+    (0x7705, 12375),
+    (0x7707, 6877),
+    (0x7708, 12372),
+    (0x7709, 6873),
+    (0x770A, 12376),
+    (0x770B, 6874),
+    (0x7710, 12826),
+    (0x7711, 12830),
+    (0x7712, 12828),
+    (0x7713, 12827),
+    (0x7715, 12831),
+    (0x7719, 12832),
+    (0x771A, 12833),
+    (0x771B, 12825),
+    (0x771D, 12824),
+    (0x771E, 19736),
+    (0x771F, 7275),
+    (0x7720, 7276),
+    (0x7722, 12834),
+    (0x7723, 12829),
+    (0x7725, 13423),
+    (0x7726, 19737),
+    (0x7727, 12835),
+    (0x7728, 7277),
+    (0x7729, 7274),
+    (0x772D, 13417),
+    (0x772F, 13416),
+    (0x7731, 13418),
+    (0x7732, 13419),
+    (0x7733, 13421),
+    (0x7734, 13420),
+    (0x7735, 13425),
+    (0x7736, 7776),
+    (0x7737, 7773),
+    (0x7738, 7777),
+    (0x7739, 13415),
+    (0x773A, 7778),
+    (0x773B, 13424),
+    (0x773C, 7775),
+    (0x773D, 13422),
+    (0x773E, 7774),
+    (0x7740, 19738),
+    (0x7744, 14063),
+    (0x7745, 14065),
+    (0x7746, 14061),
+    (0x7747, 14062),
+    (0x774A, 14066),
+    (0x774B, 14068),
+    (0x774C, 14069),
+    (0x774D, 14064),
+    (0x774E, 14067),
+    (0x774F, 8216),
+    (0x7752, 14722),
+    (0x7754, 14727),
+    (0x7755, 14719),
+    (0x7756, 14723),
+    (0x7758, 19741),
+    (0x7759, 14728),
+    (0x775A, 14724),
+    (0x775B, 8630),
+    (0x775C, 8638),
+    (0x775E, 8633),
+    (0x775F, 14720),
+    (0x7760, 14721),
+    (0x7761, 9057),
+    (0x7762, 8641),
+    (0x7763, 8634),
+    (0x7765, 8639),
+    (0x7766, 8632),
+    (0x7767, 14726),
+    (0x7768, 8640),
+    (0x7769, 14725),
+    (0x776A, 8636),
+    (0x776B, 8631),
+    (0x776C, 8637),
+    (0x776D, 14729),
+    (0x776E, 15361),
+    (0x776F, 15363),
+    (0x7779, 8635),
+    (0x777C, 15358),
+    (0x777D, 9055),
+    (0x777E, 15364),
+    (0x777F, 9056),
+    (0x7780, 15362),
+    (0x7781, 15357),
+    (0x7782, 15360),
+    (0x7783, 15365),
+    (0x7784, 9054),
+    (0x7785, 15359),
+    (0x7787, 9434),
+    (0x7788, 15950),
+    (0x7789, 15949),
+    (0x778B, 9437),
+    (0x778C, 9435),
+    (0x778D, 15947),
+    (0x778E, 9433),
+    (0x778F, 15948),
+    (0x7791, 9436),
+    (0x7795, 16546),
+    (0x7797, 16548),
+    (0x7799, 16547),
+    (0x779A, 16539),
+    (0x779B, 16543),
+    (0x779C, 16542),
+    (0x779D, 16540),
+    (0x779E, 9772),
+    (0x779F, 9773),
+    (0x77A0, 9771),
+    (0x77A1, 16541),
+    (0x77A2, 16544),
+    (0x77A3, 16545),
+    (0x77A5, 9774),
+    (0x77A7, 10048),
+    (0x77A8, 17021),
+    (0x77AA, 10045),
+    (0x77AB, 17015),
+    (0x77AC, 10047),
+    (0x77AD, 10049),
+    (0x77AF, 19743),
+    (0x77B0, 10046),
+    (0x77B1, 17020),
+    (0x77B2, 17016),
+    (0x77B3, 10044),
+    (0x77B4, 17019),
+    (0x77B5, 17014),
+    (0x77B6, 17018),
+    (0x77B7, 17017),
+    (0x77BA, 17448),
+    (0x77BB, 10271),
+    (0x77BC, 10272),
+    (0x77BD, 10269),
+    (0x77BF, 10270),
+    (0x77C2, 17447),
+    (0x77C4, 17800),
+    (0x77C5, 19445),
+    (0x77C7, 10433),
+    (0x77C9, 17798),
+    (0x77CA, 17799),
+    (0x77CC, 18107),
+    (0x77CD, 18110),
+    (0x77CE, 18108),
+    (0x77CF, 18109),
+    (0x77D0, 18338),
+    (0x77D3, 10660),
+    (0x77D4, 18663),
+    (0x77D5, 18765),
+    (0x77D7, 10825),
+    (0x77D8, 18833),
+    (0x77D9, 18834),
+    (0x77DA, 10871),
+    (0x77DB, 5771),
+    (0x77DC, 6878),
+    (0x77DE, 14070),
+    (0x77E0, 14730),
+    (0x77E2, 5772),
+    (0x77E3, 6170),
+    (0x77E5, 6529),
+    (0x77E6, 19115),
+    (0x77E7, 12380),
+    (0x77E8, 12381),
+    (0x77E9, 7278),
+    (0x77EC, 14071),
+    (0x77ED, 8217),
+    (0x77EE, 8642),
+    (0x77EF, 10050),
+    (0x77F0, 17022),
+    (0x77F1, 17801),
+    (0x77F2, 18111),
+    (0x77F3, 5773),
+    (0x77F4, 19747),
+    (0x77F7, 11995),
+    (0x77F8, 11990),
+    (0x77F9, 11992),
+    (0x77FA, 11994),
+    (0x77FB, 11993),
+    (0x77FC, 11991),
+    (0x77FD, 6530),
+    (0x7802, 6879),
+    (0x7803, 12390),
+    (0x7805, 12385),
+    (0x7806, 12382),
+    (0x7809, 12389),
+    (0x780C, 6881),
+    (0x780D, 6882),
+    (0x780E, 12388),
+    (0x780F, 12387),
+    (0x7810, 12386),
+    (0x7811, 12383),
+    (0x7812, 12384),
+    (0x7813, 12391),
+    (0x7814, 6880),
+    (0x781D, 7282),
+    (0x781F, 7288),
+    (0x7820, 7287),
+    (0x7821, 12844),
+    (0x7822, 12838),
+    (0x7823, 12836),
+    (0x7825, 7285),
+    (0x7826, 13432),
+    (0x7827, 7280),
+    (0x7828, 12841),
+    (0x7829, 12845),
+    (0x782A, 12847),
+    (0x782B, 12843),
+    (0x782C, 12837),
+    (0x782D, 7286),
+    (0x782E, 12842),
+    (0x782F, 12840),
+    (0x7830, 7279),
+    (0x7831, 12848),
+    (0x7832, 7289),
+    (0x7833, 12846),
+    (0x7834, 7283),
+    (0x7835, 12839),
+    (0x7837, 7284),
+    (0x7838, 7281),
+    (0x783C, 19012),
+    (0x7843, 7780),
+    (0x7845, 13433),
+    (0x7848, 13426),
+    (0x7849, 13428),
+    (0x784A, 13430),
+    (0x784C, 13431),
+    (0x784D, 13429),
+    (0x784E, 7781),
+    (0x7850, 13434),
+    (0x7852, 13427),
+    (0x785C, 14075),
+    (0x785D, 8218),
+    (0x785E, 14083),
+    (0x7860, 14072),
+    (0x7862, 14084),
+    (0x7864, 14073),
+    (0x7865, 14074),
+    (0x7868, 14082),
+    (0x7869, 14081),
+    (0x786A, 14078),
+    (0x786B, 7779),
+    (0x786C, 8219),
+    (0x786D, 14076),
+    (0x786E, 14079),
+    (0x786F, 8220),
+    (0x7870, 14080),
+    (0x7871, 14077),
+    (0x7879, 14741),
+    (0x787B, 14745),
+    (0x787C, 8649),
+    (0x787E, 15371),
+    (0x787F, 8652),
+    (0x7880, 14743),
+    (0x7881, 18956),
+    (0x7883, 14740),
+    (0x7884, 14735),
+    (0x7885, 14737),
+    (0x7886, 14738),
+    (0x7887, 14731),
+    (0x7889, 8648),
+    (0x788C, 8647),
+    (0x788E, 8643),
+    (0x788F, 14734),
+    (0x7891, 8650),
+    (0x7893, 8651),
+    (0x7894, 14733),
+    (0x7895, 14736),
+    (0x7896, 14744),
+    (0x7897, 8645),
+    (0x7898, 8646),
+    (0x7899, 14742),
+    (0x789A, 14732),
+    (0x789E, 15373),
+    (0x789F, 9059),
+    (0x78A0, 15375),
+    (0x78A1, 14739),
+    (0x78A2, 15377),
+    (0x78A3, 9063),
+    (0x78A4, 15378),
+    (0x78A5, 15374),
+    (0x78A7, 9060),
+    (0x78A8, 15370),
+    (0x78A9, 9062),
+    (0x78AA, 15367),
+    (0x78AB, 15372),
+    (0x78AC, 15376),
+    (0x78AD, 15369),
+    (0x78AF, 19752),
+    (0x78B0, 8644),
+    (0x78B1, 19760),
+    (0x78B2, 15366),
+    (0x78B3, 9061),
+    (0x78B4, 15368),
+    (0x78BA, 9440),
+    (0x78BB, 15952),
+    (0x78BC, 9444),
+    (0x78BE, 9442),
+    (0x78C1, 9058),
+    (0x78C3, 15959),
+    (0x78C4, 15960),
+    (0x78C5, 9439),
+    (0x78C7, 19753),
+    (0x78C8, 15958),
+    (0x78C9, 15961),
+    (0x78CA, 9441),
+    (0x78CB, 9438),
+    (0x78CC, 15954),
+    (0x78CD, 15951),
+    (0x78CE, 15956),
+    (0x78CF, 15953),
+    (0x78D0, 9445),
+    (0x78D1, 15955),
+    (0x78D3, 19754),
+    (0x78D4, 15957),
+    (0x78D5, 9443),
+    (0x78D7, 19758),
+    (0x78DA, 9776),
+    (0x78DB, 16555),
+    (0x78DD, 16549),
+    (0x78DE, 16553),
+    (0x78DF, 16559),
+    (0x78E0, 16560),
+    (0x78E1, 16556),
+    (0x78E2, 16557),
+    (0x78E3, 16554),
+    (0x78E5, 16551),
+    (0x78E7, 9778),
+    (0x78E8, 9775),
+    (0x78E9, 16550),
+    (0x78EA, 16552),
+    (0x78EC, 9777),
+    (0x78ED, 16558),
+    (0x78EF, 10054),
+    (0x78F1, 19176),
+    (0x78F2, 17028),
+    (0x78F3, 17023),
+    (0x78F4, 10053),
+    (0x78F7, 10051),
+    (0x78F9, 17030),
+    (0x78FA, 10052),
+    (0x78FB, 17026),
+    (0x78FC, 17027),
+    (0x78FD, 17024),
+    (0x78FE, 17031),
+    (0x78FF, 17449),
+    (0x7901, 10055),
+    (0x7902, 17025),
+    (0x7904, 17032),
+    (0x7905, 17029),
+    (0x7909, 17453),
+    (0x790C, 17450),
+    (0x790E, 10273),
+    (0x7910, 17454),
+    (0x7911, 17456),
+    (0x7912, 17455),
+    (0x7913, 17451),
+    (0x7914, 17452),
+    (0x7917, 17806),
+    (0x7919, 10434),
+    (0x791B, 17803),
+    (0x791C, 17805),
+    (0x791D, 17802),
+    (0x791E, 17807),
+    (0x7921, 17804),
+    (0x7923, 18113),
+    (0x7924, 18116),
+    (0x7925, 18112),
+    (0x7926, 10568),
+    (0x7927, 18114),
+    (0x7928, 18115),
+    (0x7929, 18117),
+    (0x792A, 10569),
+    (0x792B, 10571),
+    (0x792C, 10570),
+    (0x792D, 18339),
+    (0x792E, 19756),
+    (0x792F, 18341),
+    (0x7931, 18340),
+    (0x7934, 19759),
+    (0x7935, 18517),
+    (0x7938, 18766),
+    (0x7939, 18835),
+    (0x793A, 5774),
+    (0x793C, 19774),
+    (0x793D, 11668),
+    (0x793E, 6531),
+    (0x793F, 11997),
+    (0x7940, 6532),
+    (0x7941, 6533),
+    (0x7942, 11996),
+    (0x7944, 12396),
+    (0x7945, 12395),
+    (0x7946, 6883),
+    (0x7947, 6886),
+    (0x7948, 6885),
+    (0x7949, 6884),
+    (0x794A, 12392),
+    (0x794B, 12394),
+    (0x794C, 12393),
+    (0x794F, 12851),
+    (0x7950, 7291),
+    (0x7951, 12855),
+    (0x7952, 12854),
+    (0x7953, 12853),
+    (0x7954, 12849),
+    (0x7955, 7290),
+    (0x7956, 7294),
+    (0x7957, 7297),
+    (0x795A, 7298),
+    (0x795B, 12850),
+    (0x795C, 12852),
+    (0x795D, 7296),
+    (0x795E, 7295),
+    (0x795F, 7293),
+    (0x7960, 7292),
+    (0x7961, 13441),
+    (0x7963, 13439),
+    (0x7964, 13435),
+    (0x7965, 7782),
+    (0x7967, 13436),
+    (0x7968, 7783),
+    (0x7969, 13437),
+    (0x796A, 13438),
+    (0x796B, 13440),
+    (0x796D, 7784),
+    (0x7970, 14088),
+    (0x7971, 19352),
+    (0x7972, 14087),
+    (0x7973, 14086),
+    (0x7974, 14085),
+    (0x7979, 14749),
+    (0x797A, 8653),
+    (0x797C, 14746),
+    (0x797D, 14748),
+    (0x797F, 8654),
+    (0x7980, 19770),
+    (0x7981, 8655),
+    (0x7982, 14747),
+    (0x7986, 19767),
+    (0x7988, 15387),
+    (0x798A, 15380),
+    (0x798B, 15381),
+    (0x798D, 9066),
+    (0x798E, 9064),
+    (0x798F, 9065),
+    (0x7990, 15389),
+    (0x7992, 15388),
+    (0x7993, 15385),
+    (0x7994, 15384),
+    (0x7995, 15383),
+    (0x7996, 15382),
+    (0x7997, 15386),
+    (0x7998, 15379),
+    (0x799A, 15962),
+    (0x799B, 15967),
+    (0x799C, 15965),
+    (0x799D, 19772),
+    (0x79A0, 15964),
+    (0x79A1, 15963),
+    (0x79A2, 15966),
+    (0x79A4, 16561),
+    (0x79A6, 9779),
+    (0x79A7, 10056),
+    (0x79A8, 17034),
+    (0x79A9, 19775),
+    (0x79AA, 10057),
+    (0x79AB, 17033),
+    (0x79AC, 17458),
+    (0x79AD, 17457),
+    (0x79AE, 10274),
+    (0x79B0, 17808),
+    (0x79B1, 10435),
+    (0x79B2, 18118),
+    (0x79B3, 10739),
+    (0x79B4, 18518),
+    (0x79B6, 18768),
+    (0x79B7, 18767),
+    (0x79B8, 11374),
+    (0x79B9, 6887),
+    (0x79BA, 6888),
+    (0x79BB, 13442),
+    (0x79BD, 8657),
+    (0x79BE, 5775),
+    (0x79BF, 6173),
+    (0x79C0, 6172),
+    (0x79C1, 6171),
+    (0x79C5, 11998),
+    (0x79C6, 19779),
+    (0x79C8, 6535),
+    (0x79C9, 6534),
+    (0x79CB, 6891),
+    (0x79CC, 19561),
+    (0x79CD, 12398),
+    (0x79CE, 12401),
+    (0x79CF, 12399),
+    (0x79D1, 6889),
+    (0x79D2, 6890),
+    (0x79D4, 19781),
+    (0x79D5, 12397),
+    (0x79D6, 12400),
+    (0x79D8, 7305),
+    (0x79DC, 12862),
+    (0x79DD, 12864),
+    (0x79DE, 12863),
+    (0x79DF, 7302),
+    (0x79E0, 12858),
+    (0x79E3, 7300),
+    (0x79E4, 7299),
+    (0x79E6, 7303),
+    (0x79E7, 7301),
+    (0x79E9, 7304),
+    (0x79EA, 12861),
+    (0x79EB, 12856),
+    (0x79EC, 12857),
+    (0x79ED, 12860),
+    (0x79EE, 12859),
+    (0x79F6, 13445),
+    (0x79F7, 13446),
+    (0x79F8, 13444),
+    (0x79FA, 13443),
+    (0x79FB, 7785),
+    (0x7A00, 8225),
+    (0x7A02, 14089),
+    (0x7A03, 14091),
+    (0x7A04, 14093),
+    (0x7A05, 8224),
+    (0x7A08, 8222),
+    (0x7A0A, 14090),
+    (0x7A0B, 8223),
+    (0x7A0C, 14092),
+    (0x7A0D, 8221),
+    (0x7A10, 14759),
+    (0x7A11, 14750),
+    (0x7A12, 14753),
+    (0x7A13, 14757),
+    (0x7A14, 8661),
+    (0x7A15, 14755),
+    (0x7A17, 14754),
+    (0x7A18, 14751),
+    (0x7A19, 14752),
+    (0x7A1A, 8659),
+    (0x7A1B, 14758),
+    (0x7A1C, 8658),
+    (0x7A1E, 8663),
+    (0x7A1F, 8662),
+    (0x7A20, 8660),
+    (0x7A22, 14756),
+    (0x7A26, 15395),
+    (0x7A28, 15394),
+    (0x7A2B, 15390),
+    (0x7A2E, 9067),
+    (0x7A2F, 15393),
+    (0x7A30, 15392),
+    (0x7A31, 9068),
+    (0x7A37, 9450),
+    (0x7A39, 15969),
+    (0x7A3B, 9451),
+    (0x7A3C, 9447),
+    (0x7A3D, 9449),
+    (0x7A3F, 9446),
+    (0x7A40, 9448),
+    (0x7A44, 16562),
+    (0x7A46, 9782),
+    (0x7A47, 16564),
+    (0x7A48, 16563),
+    (0x7A4A, 15391),
+    (0x7A4B, 9784),
+    (0x7A4C, 9783),
+    (0x7A4D, 9780),
+    (0x7A4E, 9781),
+    (0x7A54, 17039),
+    (0x7A56, 17037),
+    (0x7A57, 10058),
+    (0x7A58, 17038),
+    (0x7A5A, 17040),
+    (0x7A5B, 17036),
+    (0x7A5C, 17035),
+    (0x7A5F, 17459),
+    (0x7A60, 10277),
+    (0x7A61, 10275),
+    (0x7A62, 10276),
+    (0x7A67, 17809),
+    (0x7A68, 17810),
+    (0x7A69, 10437),
+    (0x7A6B, 10436),
+    (0x7A6C, 18120),
+    (0x7A6D, 18121),
+    (0x7A6E, 18119),
+    (0x7A70, 18519),
+    (0x7A71, 18520),
+    (0x7A74, 5776),
+    (0x7A75, 11469),
+    (0x7A76, 6174),
+    (0x7A78, 11999),
+    (0x7A79, 6537),
+    (0x7A7A, 6536),
+    (0x7A7B, 12000),
+    (0x7A7E, 12403),
+    (0x7A7F, 6892),
+    (0x7A80, 12402),
+    (0x7A81, 6893),
+    (0x7A84, 7306),
+    (0x7A85, 12867),
+    (0x7A86, 12865),
+    (0x7A87, 12871),
+    (0x7A88, 7307),
+    (0x7A89, 12866),
+    (0x7A8A, 12870),
+    (0x7A8B, 12868),
+    (0x7A8C, 12869),
+    (0x7A8F, 13447),
+    (0x7A90, 13449),
+    (0x7A92, 7786),
+    (0x7A94, 13448),
+    (0x7A95, 7787),
+    (0x7A96, 8228),
+    (0x7A97, 8227),
+    (0x7A98, 8226),
+    (0x7A99, 14094),
+    (0x7A9E, 14762),
+    (0x7A9F, 8664),
+    (0x7AA0, 8665),
+    (0x7AA2, 14761),
+    (0x7AA3, 14760),
+    (0x7AA8, 15396),
+    (0x7AA9, 9070),
+    (0x7AAA, 9069),
+    (0x7AAB, 15397),
+    (0x7AAC, 15398),
+    (0x7AAE, 9453),
+    (0x7AAF, 9452),
+    (0x7AB1, 16568),
+    (0x7AB2, 15970),
+    (0x7AB3, 15972),
+    (0x7AB4, 15971),
+    (0x7AB5, 16567),
+    (0x7AB6, 16565),
+    (0x7AB7, 16569),
+    (0x7AB8, 16566),
+    (0x7ABA, 9785),
+    (0x7ABE, 17041),
+    (0x7ABF, 10059),
+    (0x7AC0, 17042),
+    (0x7AC1, 17043),
+    (0x7AC4, 10278),
+    (0x7AC5, 10279),
+    (0x7AC7, 10572),
+    (0x7ACA, 10787),
+    (0x7ACB, 5777),
+    (0x7AD1, 12404),
+    (0x7AD8, 12872),
+    (0x7AD9, 7308),
+    (0x7ADF, 7938),
+    (0x7AE0, 7937),
+    (0x7AE3, 8230),
+    (0x7AE4, 14096),
+    (0x7AE5, 8229),
+    (0x7AE6, 14095),
+    (0x7AEB, 14763),
+    (0x7AED, 9071),
+    (0x7AEE, 15399),
+    (0x7AEF, 9072),
+    (0x7AF6, 10573),
+    (0x7AF7, 18122),
+    (0x7AF9, 5914),
+    (0x7AFA, 6538),
+    (0x7AFB, 12001),
+    (0x7AFD, 6895),
+    (0x7AFF, 6894),
+    (0x7B00, 12405),
+    (0x7B01, 12406),
+    (0x7B04, 12874),
+    (0x7B05, 12876),
+    (0x7B06, 7309),
+    (0x7B08, 12878),
+    (0x7B09, 12881),
+    (0x7B0A, 12879),
+    (0x7B0E, 12880),
+    (0x7B0F, 12877),
+    (0x7B10, 12873),
+    (0x7B11, 7310),
+    (0x7B12, 12882),
+    (0x7B13, 12875),
+    (0x7B18, 13458),
+    (0x7B19, 7793),
+    (0x7B1A, 13467),
+    (0x7B1B, 7790),
+    (0x7B1D, 13460),
+    (0x7B1E, 7794),
+    (0x7B20, 7788),
+    (0x7B22, 13455),
+    (0x7B23, 13468),
+    (0x7B24, 13456),
+    (0x7B25, 13453),
+    (0x7B26, 7792),
+    (0x7B28, 7789),
+    (0x7B2A, 13459),
+    (0x7B2B, 13462),
+    (0x7B2C, 7791),
+    (0x7B2D, 13463),
+    (0x7B2E, 7795),
+    (0x7B2F, 13464),
+    (0x7B30, 13454),
+    (0x7B31, 13461),
+    (0x7B32, 13465),
+    (0x7B33, 13457),
+    (0x7B34, 13452),
+    (0x7B35, 13450),
+    (0x7B38, 13466),
+    (0x7B3B, 14098),
+    (0x7B40, 14103),
+    (0x7B44, 14099),
+    (0x7B45, 14105),
+    (0x7B46, 8233),
+    (0x7B47, 13451),
+    (0x7B48, 14100),
+    (0x7B49, 8231),
+    (0x7B4A, 14097),
+    (0x7B4B, 8238),
+    (0x7B4C, 14101),
+    (0x7B4D, 8237),
+    (0x7B4E, 14102),
+    (0x7B4F, 8239),
+    (0x7B50, 8234),
+    (0x7B51, 8240),
+    (0x7B52, 8235),
+    (0x7B54, 8236),
+    (0x7B56, 8232),
+    (0x7B58, 14104),
+    (0x7B60, 8668),
+    (0x7B61, 14774),
+    (0x7B63, 14777),
+    (0x7B64, 14765),
+    (0x7B65, 14770),
+    (0x7B66, 14764),
+    (0x7B67, 8670),
+    (0x7B69, 14768),
+    (0x7B6D, 14766),
+    (0x7B6E, 8669),
+    (0x7B70, 14773),
+    (0x7B71, 14772),
+    (0x7B72, 14769),
+    (0x7B73, 14771),
+    (0x7B74, 14767),
+    (0x7B75, 9076),
+    (0x7B76, 14776),
+    (0x7B77, 8666),
+    (0x7B78, 14775),
+    (0x7B82, 15415),
+    (0x7B84, 9083),
+    (0x7B85, 15410),
+    (0x7B87, 9082),
+    (0x7B88, 15400),
+    (0x7B8A, 15402),
+    (0x7B8B, 9075),
+    (0x7B8C, 15407),
+    (0x7B8D, 15406),
+    (0x7B8E, 15409),
+    (0x7B8F, 9080),
+    (0x7B90, 15404),
+    (0x7B91, 15403),
+    (0x7B94, 9079),
+    (0x7B95, 9074),
+    (0x7B96, 15405),
+    (0x7B97, 9077),
+    (0x7B98, 15411),
+    (0x7B99, 15413),
+    (0x7B9B, 15408),
+    (0x7B9C, 15401),
+    (0x7B9D, 9078),
+    (0x7BA0, 9461),
+    (0x7BA1, 9073),
+    (0x7BA4, 15414),
+    (0x7BAC, 15976),
+    (0x7BAD, 9454),
+    (0x7BAF, 15978),
+    (0x7BB1, 9455),
+    (0x7BB4, 9457),
+    (0x7BB5, 15981),
+    (0x7BB7, 15973),
+    (0x7BB8, 9081),
+    (0x7BB9, 15979),
+    (0x7BBE, 15975),
+    (0x7BC0, 8667),
+    (0x7BC1, 9460),
+    (0x7BC4, 9456),
+    (0x7BC6, 9458),
+    (0x7BC7, 9459),
+    (0x7BC9, 9788),
+    (0x7BCA, 15980),
+    (0x7BCB, 15974),
+    (0x7BCC, 9462),
+    (0x7BCE, 15977),
+    (0x7BD4, 16579),
+    (0x7BD5, 16574),
+    (0x7BD8, 16584),
+    (0x7BD9, 9786),
+    (0x7BDA, 16576),
+    (0x7BDB, 9790),
+    (0x7BDC, 16582),
+    (0x7BDD, 16573),
+    (0x7BDE, 16570),
+    (0x7BDF, 16585),
+    (0x7BE0, 10065),
+    (0x7BE1, 9791),
+    (0x7BE2, 16581),
+    (0x7BE3, 16571),
+    (0x7BE4, 9789),
+    (0x7BE5, 16575),
+    (0x7BE6, 9793),
+    (0x7BE7, 16572),
+    (0x7BE8, 16577),
+    (0x7BE9, 9792),
+    (0x7BEA, 16580),
+    (0x7BEB, 16583),
+    (0x7BF0, 17061),
+    (0x7BF1, 17062),
+    (0x7BF2, 17046),
+    (0x7BF3, 17053),
+    (0x7BF4, 17051),
+    (0x7BF7, 10063),
+    (0x7BF8, 17058),
+    (0x7BF9, 16578),
+    (0x7BFB, 17049),
+    (0x7BFD, 17059),
+    (0x7BFE, 10062),
+    (0x7BFF, 17048),
+    (0x7C00, 17047),
+    (0x7C01, 17057),
+    (0x7C02, 17054),
+    (0x7C03, 17056),
+    (0x7C05, 17044),
+    (0x7C06, 17060),
+    (0x7C07, 10060),
+    (0x7C09, 17055),
+    (0x7C0A, 17064),
+    (0x7C0B, 17052),
+    (0x7C0C, 10064),
+    (0x7C0D, 10061),
+    (0x7C0E, 17050),
+    (0x7C0F, 17045),
+    (0x7C10, 17063),
+    (0x7C11, 9787),
+    (0x7C15, 19083),
+    (0x7C19, 17462),
+    (0x7C1C, 17460),
+    (0x7C1D, 17466),
+    (0x7C1E, 10283),
+    (0x7C1F, 17464),
+    (0x7C20, 17463),
+    (0x7C21, 10285),
+    (0x7C22, 17469),
+    (0x7C23, 10284),
+    (0x7C25, 17470),
+    (0x7C26, 17467),
+    (0x7C27, 10281),
+    (0x7C28, 17468),
+    (0x7C29, 17461),
+    (0x7C2A, 10282),
+    (0x7C2B, 10280),
+    (0x7C2C, 17814),
+    (0x7C2D, 17465),
+    (0x7C30, 17471),
+    (0x7C33, 17811),
+    (0x7C37, 10442),
+    (0x7C38, 10440),
+    (0x7C39, 17813),
+    (0x7C3B, 17815),
+    (0x7C3C, 17812),
+    (0x7C3D, 10441),
+    (0x7C3E, 10438),
+    (0x7C3F, 10439),
+    (0x7C40, 10443),
+    (0x7C43, 10575),
+    (0x7C45, 18127),
+    (0x7C47, 18126),
+    (0x7C48, 18124),
+    (0x7C49, 18123),
+    (0x7C4A, 18125),
+    (0x7C4C, 10574),
+    (0x7C4D, 10576),
+    (0x7C50, 10661),
+    (0x7C53, 18343),
+    (0x7C54, 18342),
+    (0x7C57, 18521),
+    (0x7C59, 18523),
+    (0x7C5A, 18525),
+    (0x7C5B, 18524),
+    (0x7C5C, 18522),
+    (0x7C5F, 10741),
+    (0x7C60, 10740),
+    (0x7C63, 10789),
+    (0x7C64, 10788),
+    (0x7C65, 10790),
+    (0x7C66, 18665),
+    (0x7C67, 18664),
+    (0x7C69, 18836),
+    (0x7C6A, 18769),
+    (0x7C6B, 18837),
+    (0x7C6C, 10857),
+    (0x7C6E, 10858),
+    (0x7C6F, 18885),
+    (0x7C72, 10895),
+    (0x7C73, 5915),
+    (0x7C75, 12002),
+    (0x7C78, 12408),
+    (0x7C79, 12409),
+    (0x7C7A, 12407),
+    (0x7C7D, 6896),
+    (0x7C7F, 12410),
+    (0x7C80, 12411),
+    (0x7C81, 12412),
+    (0x7C84, 12883),
+    (0x7C85, 12889),
+    (0x7C88, 12887),
+    (0x7C89, 7311),
+    (0x7C8A, 12885),
+    (0x7C8C, 12886),
+    (0x7C8D, 12888),
+    (0x7C91, 12884),
+    (0x7C92, 7796),
+    (0x7C94, 13469),
+    (0x7C95, 7798),
+    (0x7C96, 13471),
+    (0x7C97, 7797),
+    (0x7C98, 13470),
+    (0x7C9E, 14107),
+    (0x7C9F, 8241),
+    (0x7CA1, 14109),
+    (0x7CA2, 14106),
+    (0x7CA3, 13472),
+    (0x7CA5, 8242),
+    (0x7CA7, 18961),
+    (0x7CA8, 14108),
+    (0x7CAF, 14780),
+    (0x7CB1, 8671),
+    (0x7CB2, 14778),
+    (0x7CB3, 8672),
+    (0x7CB4, 14779),
+    (0x7CB5, 8673),
+    (0x7CB9, 9084),
+    (0x7CBA, 15419),
+    (0x7CBB, 15416),
+    (0x7CBC, 15418),
+    (0x7CBD, 9085),
+    (0x7CBE, 9086),
+    (0x7CBF, 15417),
+    (0x7CC5, 15982),
+    (0x7CC8, 15983),
+    (0x7CCA, 9463),
+    (0x7CCB, 15985),
+    (0x7CCC, 15984),
+    (0x7CCE, 5214),
+    (0x7CD0, 16589),
+    (0x7CD1, 16590),
+    (0x7CD2, 16586),
+    (0x7CD4, 16587),
+    (0x7CD5, 9794),
+    (0x7CD6, 9795),
+    (0x7CD7, 16588),
+    (0x7CD9, 10071),
+    (0x7CDC, 10067),
+    (0x7CDD, 10072),
+    (0x7CDE, 10068),
+    (0x7CDF, 10070),
+    (0x7CE0, 10066),
+    (0x7CE2, 10069),
+    (0x7CE7, 10286),
+    (0x7CE8, 17065),
+    (0x7CEA, 17817),
+    (0x7CEC, 17816),
+    (0x7CEE, 18128),
+    (0x7CEF, 10577),
+    (0x7CF0, 10578),
+    (0x7CF1, 18527),
+    (0x7CF2, 18344),
+    (0x7CF4, 18526),
+    (0x7CF6, 18838),
+    (0x7CF7, 18906),
+    (0x7CF8, 5916),
+    (0x7CFB, 6175),
+    (0x7CFD, 12003),
+    (0x7CFE, 6539),
+    (0x7D00, 6899),
+    (0x7D01, 12415),
+    (0x7D02, 6897),
+    (0x7D03, 12413),
+    (0x7D04, 6902),
+    (0x7D05, 6898),
+    (0x7D06, 6903),
+    (0x7D07, 6901),
+    (0x7D08, 12414),
+    (0x7D09, 6900),
+    (0x7D0A, 7315),
+    (0x7D0B, 7314),
+    (0x7D0C, 12900),
+    (0x7D0D, 7323),
+    (0x7D0E, 12893),
+    (0x7D0F, 12899),
+    (0x7D10, 7319),
+    (0x7D11, 12892),
+    (0x7D12, 12898),
+    (0x7D13, 12896),
+    (0x7D14, 7318),
+    (0x7D15, 7320),
+    (0x7D16, 12895),
+    (0x7D17, 7313),
+    (0x7D18, 12894),
+    (0x7D19, 7324),
+    (0x7D1A, 7321),
+    (0x7D1B, 7325),
+    (0x7D1C, 7322),
+    (0x7D1D, 12891),
+    (0x7D1E, 12890),
+    (0x7D1F, 12897),
+    (0x7D20, 7316),
+    (0x7D21, 7312),
+    (0x7D22, 7317),
+    (0x7D28, 13487),
+    (0x7D29, 13480),
+    (0x7D2B, 8247),
+    (0x7D2C, 13479),
+    (0x7D2E, 7802),
+    (0x7D2F, 7809),
+    (0x7D30, 7806),
+    (0x7D31, 7812),
+    (0x7D32, 7811),
+    (0x7D33, 7807),
+    (0x7D35, 13473),
+    (0x7D36, 13476),
+    (0x7D38, 13475),
+    (0x7D39, 7803),
+    (0x7D3A, 13477),
+    (0x7D3B, 13486),
+    (0x7D3C, 7804),
+    (0x7D3D, 13474),
+    (0x7D3E, 13483),
+    (0x7D3F, 13484),
+    (0x7D40, 7805),
+    (0x7D41, 13481),
+    (0x7D42, 7810),
+    (0x7D43, 7800),
+    (0x7D44, 7808),
+    (0x7D45, 13478),
+    (0x7D46, 7799),
+    (0x7D47, 13482),
+    (0x7D4A, 13485),
+    (0x7D4E, 14126),
+    (0x7D4F, 14117),
+    (0x7D50, 8244),
+    (0x7D51, 14124),
+    (0x7D52, 14121),
+    (0x7D53, 14113),
+    (0x7D54, 14122),
+    (0x7D55, 8246),
+    (0x7D56, 14114),
+    (0x7D58, 14110),
+    (0x7D5B, 8679),
+    (0x7D5C, 14119),
+    (0x7D5E, 8243),
+    (0x7D5F, 14125),
+    (0x7D61, 8250),
+    (0x7D62, 8252),
+    (0x7D63, 14112),
+    (0x7D66, 8251),
+    (0x7D67, 14115),
+    (0x7D68, 8245),
+    (0x7D69, 14123),
+    (0x7D6A, 14116),
+    (0x7D6B, 14120),
+    (0x7D6D, 14118),
+    (0x7D6E, 8248),
+    (0x7D6F, 14111),
+    (0x7D70, 8253),
+    (0x7D71, 7801),
+    (0x7D72, 8249),
+    (0x7D73, 8254),
+    (0x7D79, 8675),
+    (0x7D7A, 14787),
+    (0x7D7B, 14789),
+    (0x7D7C, 14791),
+    (0x7D7D, 14795),
+    (0x7D7F, 14785),
+    (0x7D80, 14783),
+    (0x7D81, 8677),
+    (0x7D83, 14790),
+    (0x7D84, 14794),
+    (0x7D85, 14786),
+    (0x7D86, 14782),
+    (0x7D88, 14781),
+    (0x7D8C, 14792),
+    (0x7D8D, 14784),
+    (0x7D8E, 14788),
+    (0x7D8F, 8678),
+    (0x7D91, 8676),
+    (0x7D92, 14796),
+    (0x7D93, 8674),
+    (0x7D94, 14793),
+    (0x7D96, 15436),
+    (0x7D9C, 9089),
+    (0x7D9D, 15428),
+    (0x7D9E, 9476),
+    (0x7D9F, 15438),
+    (0x7DA0, 9092),
+    (0x7DA1, 15442),
+    (0x7DA2, 9098),
+    (0x7DA3, 15423),
+    (0x7DA6, 15439),
+    (0x7DA7, 15420),
+    (0x7DA9, 15441),
+    (0x7DAA, 15424),
+    (0x7DAC, 9105),
+    (0x7DAD, 9102),
+    (0x7DAE, 15440),
+    (0x7DAF, 15434),
+    (0x7DB0, 9088),
+    (0x7DB1, 9096),
+    (0x7DB2, 9095),
+    (0x7DB4, 9094),
+    (0x7DB5, 9100),
+    (0x7DB7, 15421),
+    (0x7DB8, 9101),
+    (0x7DB9, 15435),
+    (0x7DBA, 9097),
+    (0x7DBB, 9087),
+    (0x7DBC, 15437),
+    (0x7DBD, 9090),
+    (0x7DBE, 9091),
+    (0x7DBF, 9099),
+    (0x7DC0, 15426),
+    (0x7DC1, 15425),
+    (0x7DC2, 15422),
+    (0x7DC4, 15430),
+    (0x7DC5, 15427),
+    (0x7DC6, 15431),
+    (0x7DC7, 9104),
+    (0x7DC9, 15443),
+    (0x7DCA, 9093),
+    (0x7DCB, 15432),
+    (0x7DCC, 15433),
+    (0x7DCE, 15429),
+    (0x7DD2, 9103),
+    (0x7DD7, 15990),
+    (0x7DD8, 9468),
+    (0x7DD9, 9477),
+    (0x7DDA, 9473),
+    (0x7DDB, 15987),
+    (0x7DDD, 9470),
+    (0x7DDE, 9474),
+    (0x7DDF, 15999),
+    (0x7DE0, 9464),
+    (0x7DE1, 15991),
+    (0x7DE3, 9472),
+    (0x7DE6, 15994),
+    (0x7DE7, 15989),
+    (0x7DE8, 9471),
+    (0x7DE9, 9475),
+    (0x7DEA, 15988),
+    (0x7DEC, 9469),
+    (0x7DEE, 15998),
+    (0x7DEF, 9466),
+    (0x7DF0, 15997),
+    (0x7DF1, 15996),
+    (0x7DF2, 9478),
+    (0x7DF3, 15750),
+    (0x7DF4, 9465),
+    (0x7DF6, 15995),
+    (0x7DF7, 15986),
+    (0x7DF9, 9479),
+    (0x7DFA, 15993),
+    (0x7DFB, 9467),
+    (0x7E03, 15992),
+    (0x7E08, 9798),
+    (0x7E09, 9803),
+    (0x7E0A, 9796),
+    (0x7E0B, 16603),
+    (0x7E0C, 16594),
+    (0x7E0D, 16606),
+    (0x7E0E, 16598),
+    (0x7E0F, 16604),
+    (0x7E10, 9804),
+    (0x7E11, 9797),
+    (0x7E12, 16591),
+    (0x7E13, 16597),
+    (0x7E14, 16607),
+    (0x7E15, 16600),
+    (0x7E16, 16605),
+    (0x7E17, 16593),
+    (0x7E1A, 16601),
+    (0x7E1B, 9799),
+    (0x7E1C, 16599),
+    (0x7E1D, 9802),
+    (0x7E1E, 9801),
+    (0x7E1F, 16595),
+    (0x7E20, 16596),
+    (0x7E21, 16592),
+    (0x7E22, 16602),
+    (0x7E23, 9800),
+    (0x7E24, 16609),
+    (0x7E25, 16608),
+    (0x7E29, 17076),
+    (0x7E2A, 17072),
+    (0x7E2B, 10079),
+    (0x7E2D, 17066),
+    (0x7E2E, 10073),
+    (0x7E2F, 10089),
+    (0x7E30, 17078),
+    (0x7E31, 10081),
+    (0x7E32, 10077),
+    (0x7E33, 17069),
+    (0x7E34, 10084),
+    (0x7E35, 10087),
+    (0x7E36, 17080),
+    (0x7E37, 10076),
+    (0x7E38, 17071),
+    (0x7E39, 10085),
+    (0x7E3A, 17082),
+    (0x7E3B, 17079),
+    (0x7E3C, 17067),
+    (0x7E3D, 10080),
+    (0x7E3E, 10074),
+    (0x7E3F, 10088),
+    (0x7E40, 17074),
+    (0x7E41, 10083),
+    (0x7E42, 17068),
+    (0x7E43, 10078),
+    (0x7E44, 17081),
+    (0x7E45, 10082),
+    (0x7E46, 10075),
+    (0x7E47, 17075),
+    (0x7E48, 10086),
+    (0x7E49, 17073),
+    (0x7E4C, 17077),
+    (0x7E50, 17473),
+    (0x7E51, 17479),
+    (0x7E52, 10292),
+    (0x7E53, 17482),
+    (0x7E54, 10287),
+    (0x7E55, 10288),
+    (0x7E56, 17474),
+    (0x7E57, 17481),
+    (0x7E58, 17476),
+    (0x7E59, 10293),
+    (0x7E5A, 10290),
+    (0x7E5C, 17472),
+    (0x7E5E, 10289),
+    (0x7E5F, 17478),
+    (0x7E60, 17480),
+    (0x7E61, 10291),
+    (0x7E62, 17477),
+    (0x7E63, 17475),
+    (0x7E68, 17827),
+    (0x7E69, 10447),
+    (0x7E6A, 10448),
+    (0x7E6B, 10444),
+    (0x7E6D, 10445),
+    (0x7E6F, 17823),
+    (0x7E70, 17821),
+    (0x7E72, 17825),
+    (0x7E73, 10450),
+    (0x7E74, 17826),
+    (0x7E75, 17819),
+    (0x7E76, 17818),
+    (0x7E77, 17822),
+    (0x7E78, 17820),
+    (0x7E79, 10446),
+    (0x7E7A, 17824),
+    (0x7E7B, 18129),
+    (0x7E7C, 10581),
+    (0x7E7D, 10580),
+    (0x7E7E, 18130),
+    (0x7E80, 18132),
+    (0x7E81, 18131),
+    (0x7E82, 10582),
+    (0x7E86, 18349),
+    (0x7E87, 18346),
+    (0x7E88, 18347),
+    (0x7E8A, 18345),
+    (0x7E8B, 18348),
+    (0x7E8C, 10663),
+    (0x7E8D, 18350),
+    (0x7E8F, 10662),
+    (0x7E91, 18528),
+    (0x7E93, 10791),
+    (0x7E94, 10793),
+    (0x7E95, 18666),
+    (0x7E96, 10792),
+    (0x7E97, 18770),
+    (0x7E98, 18840),
+    (0x7E99, 18842),
+    (0x7E9A, 18839),
+    (0x7E9B, 18841),
+    (0x7E9C, 10877),
+    (0x7F36, 5917),
+    (0x7F38, 6904),
+    (0x7F39, 12764),
+    (0x7F3A, 7326),
+    (0x7F3D, 7813),
+    (0x7F3E, 14127),
+    (0x7F3F, 14128),
+    (0x7F43, 16610),
+    (0x7F44, 10090),
+    (0x7F45, 17083),
+    (0x7F48, 10294),
+    (0x7F4A, 17829),
+    (0x7F4B, 17828),
+    (0x7F4C, 10583),
+    (0x7F4D, 18351),
+    (0x7F4F, 18529),
+    (0x7F50, 10826),
+    (0x7F51, 11470),
+    (0x7F54, 6540),
+    (0x7F55, 6176),
+    (0x7F58, 12416),
+    (0x7F5B, 12906),
+    (0x7F5C, 12901),
+    (0x7F5D, 12905),
+    (0x7F5E, 12903),
+    (0x7F5F, 7327),
+    (0x7F60, 12904),
+    (0x7F61, 12902),
+    (0x7F63, 13488),
+    (0x7F65, 14129),
+    (0x7F66, 14130),
+    (0x7F67, 14799),
+    (0x7F68, 14800),
+    (0x7F69, 8681),
+    (0x7F6A, 8682),
+    (0x7F6B, 14798),
+    (0x7F6C, 14801),
+    (0x7F6D, 14797),
+    (0x7F6E, 8680),
+    (0x7F70, 9106),
+    (0x7F72, 8683),
+    (0x7F73, 15444),
+    (0x7F75, 9480),
+    (0x7F76, 16000),
+    (0x7F77, 9481),
+    (0x7F79, 9805),
+    (0x7F7A, 16613),
+    (0x7F7B, 16611),
+    (0x7F7C, 16612),
+    (0x7F7D, 17086),
+    (0x7F7E, 17085),
+    (0x7F7F, 17084),
+    (0x7F83, 17830),
+    (0x7F85, 10449),
+    (0x7F86, 17831),
+    (0x7F87, 18530),
+    (0x7F88, 10827),
+    (0x7F89, 18771),
+    (0x7F8A, 5918),
+    (0x7F8B, 6542),
+    (0x7F8C, 6541),
+    (0x7F8D, 12418),
+    (0x7F8E, 6905),
+    (0x7F8F, 19538),
+    (0x7F91, 12417),
+    (0x7F92, 12908),
+    (0x7F94, 7328),
+    (0x7F95, 13489),
+    (0x7F96, 12907),
+    (0x7F9A, 7815),
+    (0x7F9B, 13492),
+    (0x7F9C, 13490),
+    (0x7F9D, 13491),
+    (0x7F9E, 7814),
+    (0x7FA0, 14132),
+    (0x7FA1, 14133),
+    (0x7FA2, 14131),
+    (0x7FA4, 8686),
+    (0x7FA5, 14803),
+    (0x7FA6, 14802),
+    (0x7FA7, 14804),
+    (0x7FA8, 8685),
+    (0x7FA9, 8684),
+    (0x7FAC, 16001),
+    (0x7FAD, 16003),
+    (0x7FAF, 9482),
+    (0x7FB0, 16002),
+    (0x7FB1, 16614),
+    (0x7FB2, 9806),
+    (0x7FB3, 17484),
+    (0x7FB5, 17483),
+    (0x7FB6, 10451),
+    (0x7FB7, 17832),
+    (0x7FB8, 10453),
+    (0x7FB9, 10452),
+    (0x7FBA, 18133),
+    (0x7FBB, 18352),
+    (0x7FBC, 10664),
+    (0x7FBD, 5919),
+    (0x7FBE, 12419),
+    (0x7FBF, 6906),
+    (0x7FC0, 12911),
+    (0x7FC1, 7330),
+    (0x7FC2, 12910),
+    (0x7FC3, 12909),
+    (0x7FC5, 7329),
+    (0x7FC7, 13498),
+    (0x7FC9, 13500),
+    (0x7FCA, 13493),
+    (0x7FCB, 13494),
+    (0x7FCC, 7816),
+    (0x7FCD, 13495),
+    (0x7FCE, 7817),
+    (0x7FCF, 13499),
+    (0x7FD0, 13496),
+    (0x7FD1, 13497),
+    (0x7FD2, 7818),
+    (0x7FD4, 8256),
+    (0x7FD5, 8257),
+    (0x7FD7, 14134),
+    (0x7FDB, 14805),
+    (0x7FDC, 14806),
+    (0x7FDE, 15448),
+    (0x7FDF, 9109),
+    (0x7FE0, 9107),
+    (0x7FE1, 9108),
+    (0x7FE2, 15445),
+    (0x7FE3, 15446),
+    (0x7FE5, 15447),
+    (0x7FE6, 16008),
+    (0x7FE8, 16009),
+    (0x7FE9, 9483),
+    (0x7FEA, 16006),
+    (0x7FEB, 16005),
+    (0x7FEC, 16007),
+    (0x7FED, 16004),
+    (0x7FEE, 9809),
+    (0x7FEF, 16615),
+    (0x7FF0, 9807),
+    (0x7FF1, 9808),
+    (0x7FF2, 17088),
+    (0x7FF3, 10091),
+    (0x7FF4, 17087),
+    (0x7FF5, 17340),
+    (0x7FF7, 17485),
+    (0x7FF8, 17486),
+    (0x7FF9, 10295),
+    (0x7FFB, 10296),
+    (0x7FFC, 10092),
+    (0x7FFD, 17833),
+    (0x7FFE, 17834),
+    (0x7FFF, 18134),
+    (0x8000, 10584),
+    (0x8001, 5920),
+    (0x8003, 5921),
+    (0x8004, 6907),
+    (0x8005, 6543),
+    (0x8006, 7331),
+    (0x8007, 12420),
+    (0x800B, 8258),
+    (0x800C, 5922),
+    (0x800D, 6909),
+    (0x800E, 12421),
+    (0x800F, 12422),
+    (0x8010, 6908),
+    (0x8011, 6910),
+    (0x8012, 5923),
+    (0x8014, 12423),
+    (0x8015, 7333),
+    (0x8016, 12912),
+    (0x8017, 7335),
+    (0x8018, 7332),
+    (0x8019, 7334),
+    (0x801B, 13503),
+    (0x801C, 7819),
+    (0x801E, 13502),
+    (0x801F, 13501),
+    (0x8021, 14807),
+    (0x8024, 15449),
+    (0x8026, 9484),
+    (0x8028, 9810),
+    (0x8029, 16617),
+    (0x802A, 16616),
+    (0x802C, 17089),
+    (0x8030, 18353),
+    (0x8033, 5924),
+    (0x8034, 11669),
+    (0x8035, 12004),
+    (0x8036, 6911),
+    (0x8037, 12424),
+    (0x8039, 12914),
+    (0x803D, 7336),
+    (0x803E, 12913),
+    (0x803F, 7337),
+    (0x8043, 13505),
+    (0x8046, 7821),
+    (0x8047, 13504),
+    (0x8048, 13506),
+    (0x804A, 7820),
+    (0x804F, 14136),
+    (0x8050, 14137),
+    (0x8051, 14135),
+    (0x8052, 8259),
+    (0x8056, 8687),
+    (0x8058, 8688),
+    (0x805A, 9111),
+    (0x805C, 15451),
+    (0x805D, 15450),
+    (0x805E, 9110),
+    (0x8064, 16010),
+    (0x8067, 16011),
+    (0x806C, 16618),
+    (0x806F, 10096),
+    (0x8070, 10095),
+    (0x8071, 10093),
+    (0x8072, 10094),
+    (0x8073, 10097),
+    (0x8075, 17487),
+    (0x8076, 10298),
+    (0x8077, 10297),
+    (0x8078, 17835),
+    (0x8079, 18135),
+    (0x807D, 10743),
+    (0x807E, 10742),
+    (0x807F, 5925),
+    (0x8082, 12713),
+    (0x8084, 8690),
+    (0x8085, 8260),
+    (0x8086, 8689),
+    (0x8087, 9112),
+    (0x8089, 5926),
+    (0x808A, 11375),
+    (0x808B, 5927),
+    (0x808C, 5928),
+    (0x808F, 12005),
+    (0x8090, 11672),
+    (0x8092, 11673),
+    (0x8093, 6178),
+    (0x8095, 11670),
+    (0x8096, 6177),
+    (0x8098, 6180),
+    (0x8099, 11671),
+    (0x809A, 6182),
+    (0x809B, 6181),
+    (0x809C, 11674),
+    (0x809D, 6179),
+    (0x80A1, 6548),
+    (0x80A2, 6546),
+    (0x80A3, 12007),
+    (0x80A5, 6545),
+    (0x80A9, 6550),
+    (0x80AA, 6552),
+    (0x80AB, 6549),
+    (0x80AD, 12010),
+    (0x80AE, 12006),
+    (0x80AF, 6553),
+    (0x80B1, 6547),
+    (0x80B2, 6183),
+    (0x80B4, 6551),
+    (0x80B5, 12009),
+    (0x80B8, 12008),
+    (0x80BA, 6544),
+    (0x80C2, 12430),
+    (0x80C3, 6915),
+    (0x80C4, 6916),
+    (0x80C5, 12432),
+    (0x80C7, 12426),
+    (0x80C8, 12429),
+    (0x80C9, 12438),
+    (0x80CA, 12436),
+    (0x80CC, 6917),
+    (0x80CD, 12442),
+    (0x80CE, 6920),
+    (0x80CF, 12439),
+    (0x80D0, 12431),
+    (0x80D1, 12428),
+    (0x80D4, 14139),
+    (0x80D5, 12437),
+    (0x80D6, 6912),
+    (0x80D7, 12440),
+    (0x80D8, 12425),
+    (0x80D9, 12434),
+    (0x80DA, 6914),
+    (0x80DB, 6919),
+    (0x80DC, 12435),
+    (0x80DD, 6923),
+    (0x80DE, 6921),
+    (0x80E0, 12427),
+    (0x80E1, 6918),
+    (0x80E3, 12433),
+    (0x80E4, 6922),
+    (0x80E5, 6913),
+    (0x80E6, 12441),
+    (0x80ED, 7342),
+    (0x80EF, 7351),
+    (0x80F0, 7340),
+    (0x80F1, 7338),
+    (0x80F2, 12916),
+    (0x80F3, 7346),
+    (0x80F4, 7343),
+    (0x80F5, 12918),
+    (0x80F8, 7345),
+    (0x80F9, 12917),
+    (0x80FA, 12915),
+    (0x80FB, 12920),
+    (0x80FC, 7350),
+    (0x80FD, 7348),
+    (0x80FE, 14138),
+    (0x8100, 12921),
+    (0x8101, 12919),
+    (0x8102, 7339),
+    (0x8105, 7341),
+    (0x8106, 7344),
+    (0x8108, 7347),
+    (0x810A, 7349),
+    (0x8115, 13516),
+    (0x8116, 7823),
+    (0x8118, 13507),
+    (0x8119, 13509),
+    (0x811B, 13510),
+    (0x811D, 13518),
+    (0x811E, 13514),
+    (0x811F, 13512),
+    (0x8121, 13515),
+    (0x8122, 13519),
+    (0x8123, 7824),
+    (0x8124, 7828),
+    (0x8125, 13508),
+    (0x8127, 13517),
+    (0x8129, 7826),
+    (0x812B, 7825),
+    (0x812C, 13513),
+    (0x812D, 13511),
+    (0x812F, 7822),
+    (0x8130, 7827),
+    (0x8139, 8266),
+    (0x813A, 14147),
+    (0x813D, 14145),
+    (0x813E, 8268),
+    (0x8143, 14140),
+    (0x8144, 14821),
+    (0x8146, 8267),
+    (0x8147, 14144),
+    (0x814A, 14141),
+    (0x814B, 8263),
+    (0x814C, 8269),
+    (0x814D, 14146),
+    (0x814E, 8265),
+    (0x814F, 14143),
+    (0x8150, 9113),
+    (0x8151, 8264),
+    (0x8152, 14142),
+    (0x8153, 8270),
+    (0x8154, 8262),
+    (0x8155, 8261),
+    (0x815B, 14813),
+    (0x815C, 14811),
+    (0x815E, 14817),
+    (0x8160, 14809),
+    (0x8161, 14822),
+    (0x8162, 14814),
+    (0x8164, 14808),
+    (0x8165, 8694),
+    (0x8166, 8700),
+    (0x8167, 14819),
+    (0x8169, 14812),
+    (0x816B, 8697),
+    (0x816E, 8695),
+    (0x816F, 14820),
+    (0x8170, 8692),
+    (0x8171, 8691),
+    (0x8172, 14815),
+    (0x8173, 8696),
+    (0x8174, 8271),
+    (0x8176, 14818),
+    (0x8177, 14810),
+    (0x8178, 8693),
+    (0x8179, 8698),
+    (0x817A, 8699),
+    (0x817F, 9118),
+    (0x8180, 9114),
+    (0x8182, 9119),
+    (0x8183, 15454),
+    (0x8186, 15453),
+    (0x8187, 15455),
+    (0x8188, 9116),
+    (0x8189, 15452),
+    (0x818A, 9117),
+    (0x818B, 15458),
+    (0x818C, 15457),
+    (0x818D, 15456),
+    (0x818F, 9115),
+    (0x8195, 16015),
+    (0x8197, 16018),
+    (0x8198, 9490),
+    (0x8199, 16017),
+    (0x819A, 9489),
+    (0x819B, 9485),
+    (0x819C, 9486),
+    (0x819D, 9487),
+    (0x819E, 16014),
+    (0x819F, 16013),
+    (0x81A0, 9488),
+    (0x81A2, 16016),
+    (0x81A3, 16012),
+    (0x81A6, 16620),
+    (0x81A7, 16630),
+    (0x81A8, 9813),
+    (0x81A9, 9812),
+    (0x81AB, 16624),
+    (0x81AC, 16626),
+    (0x81AE, 16621),
+    (0x81B0, 16625),
+    (0x81B1, 16619),
+    (0x81B2, 16628),
+    (0x81B3, 9811),
+    (0x81B4, 16627),
+    (0x81B5, 16623),
+    (0x81B7, 16629),
+    (0x81B9, 16622),
+    (0x81BA, 10100),
+    (0x81BB, 17090),
+    (0x81BC, 17096),
+    (0x81BD, 10104),
+    (0x81BE, 10106),
+    (0x81BF, 10103),
+    (0x81C0, 10102),
+    (0x81C2, 10101),
+    (0x81C3, 10099),
+    (0x81C4, 17091),
+    (0x81C5, 17094),
+    (0x81C6, 10098),
+    (0x81C7, 17095),
+    (0x81C9, 10105),
+    (0x81CA, 17093),
+    (0x81CC, 17092),
+    (0x81CD, 10299),
+    (0x81CF, 10300),
+    (0x81D0, 17490),
+    (0x81D1, 17488),
+    (0x81D2, 17489),
+    (0x81D5, 17837),
+    (0x81D7, 17836),
+    (0x81D8, 10454),
+    (0x81D9, 18137),
+    (0x81DA, 10585),
+    (0x81DB, 18136),
+    (0x81DD, 18354),
+    (0x81DE, 18531),
+    (0x81DF, 10744),
+    (0x81E0, 18843),
+    (0x81E1, 18844),
+    (0x81E2, 10794),
+    (0x81E3, 5929),
+    (0x81E5, 6554),
+    (0x81E6, 14148),
+    (0x81E7, 9120),
+    (0x81E8, 10107),
+    (0x81E9, 17097),
+    (0x81EA, 5930),
+    (0x81EC, 7353),
+    (0x81ED, 7352),
+    (0x81EE, 14149),
+    (0x81EF, 19723),
+    (0x81F2, 16631),
+    (0x81F3, 5931),
+    (0x81F4, 6924),
+    (0x81F7, 14150),
+    (0x81F8, 14151),
+    (0x81F9, 14152),
+    (0x81FA, 9121),
+    (0x81FB, 9814),
+    (0x81FC, 5932),
+    (0x81FE, 6555),
+    (0x81FF, 12443),
+    (0x8200, 7354),
+    (0x8201, 12922),
+    (0x8202, 7829),
+    (0x8204, 14153),
+    (0x8205, 8701),
+    (0x8207, 9122),
+    (0x8208, 9815),
+    (0x8209, 10108),
+    (0x820A, 10301),
+    (0x820B, 18138),
+    (0x820C, 5933),
+    (0x820D, 6556),
+    (0x8210, 7355),
+    (0x8211, 13520),
+    (0x8212, 8272),
+    (0x8214, 9123),
+    (0x8215, 15459),
+    (0x8216, 16019),
+    (0x821B, 5934),
+    (0x821C, 8273),
+    (0x821D, 14823),
+    (0x821E, 9124),
+    (0x821F, 5935),
+    (0x8220, 12011),
+    (0x8221, 12444),
+    (0x8222, 6925),
+    (0x8225, 12924),
+    (0x8226, 19523),
+    (0x8228, 7358),
+    (0x822A, 7356),
+    (0x822B, 7357),
+    (0x822C, 7359),
+    (0x822F, 12923),
+    (0x8232, 13525),
+    (0x8233, 13522),
+    (0x8234, 13524),
+    (0x8235, 7830),
+    (0x8236, 7832),
+    (0x8237, 7831),
+    (0x8238, 13521),
+    (0x8239, 7833),
+    (0x823A, 13523),
+    (0x823C, 14154),
+    (0x823D, 14155),
+    (0x823F, 14156),
+    (0x8240, 14826),
+    (0x8242, 14827),
+    (0x8244, 14825),
+    (0x8245, 14828),
+    (0x8247, 8702),
+    (0x8249, 14824),
+    (0x824B, 9125),
+    (0x824E, 16024),
+    (0x824F, 16020),
+    (0x8250, 16023),
+    (0x8251, 16025),
+    (0x8252, 16022),
+    (0x8253, 16021),
+    (0x8255, 16632),
+    (0x8256, 16633),
+    (0x8257, 16634),
+    (0x8258, 9816),
+    (0x8259, 9817),
+    (0x825A, 17099),
+    (0x825B, 17098),
+    (0x825C, 17100),
+    (0x825E, 17492),
+    (0x825F, 17491),
+    (0x8261, 17839),
+    (0x8263, 17840),
+    (0x8264, 17838),
+    (0x8266, 10586),
+    (0x8268, 18139),
+    (0x8269, 18140),
+    (0x826B, 18532),
+    (0x826C, 18667),
+    (0x826D, 18772),
+    (0x826E, 5936),
+    (0x826F, 6184),
+    (0x8271, 10109),
+    (0x8272, 5937),
+    (0x8274, 13526),
+    (0x8275, 14157),
+    (0x8277, 10834),
+    (0x8278, 11471),
+    (0x827C, 11472),
+    (0x827D, 11474),
+    (0x827E, 5938),
+    (0x827F, 11475),
+    (0x8280, 11473),
+    (0x8283, 11682),
+    (0x8284, 11683),
+    (0x8285, 11677),
+    (0x8287, 19294),
+    (0x828A, 11681),
+    (0x828B, 6186),
+    (0x828D, 6187),
+    (0x828E, 11678),
+    (0x828F, 11676),
+    (0x8290, 11675),
+    (0x8291, 11679),
+    (0x8292, 6185),
+    (0x8293, 11680),
+    (0x8294, 12445),
+    (0x8298, 12016),
+    (0x8299, 6559),
+    (0x829A, 12015),
+    (0x829B, 12017),
+    (0x829D, 6558),
+    (0x829E, 12022),
+    (0x829F, 6562),
+    (0x82A0, 12012),
+    (0x82A1, 12026),
+    (0x82A2, 12032),
+    (0x82A3, 6569),
+    (0x82A4, 12029),
+    (0x82A5, 6566),
+    (0x82A7, 12019),
+    (0x82A8, 12025),
+    (0x82A9, 12027),
+    (0x82AB, 12014),
+    (0x82AC, 6565),
+    (0x82AD, 6560),
+    (0x82AE, 12020),
+    (0x82AF, 6567),
+    (0x82B0, 6570),
+    (0x82B1, 6564),
+    (0x82B3, 6557),
+    (0x82B4, 12024),
+    (0x82B5, 12018),
+    (0x82B6, 12031),
+    (0x82B7, 6572),
+    (0x82B8, 6568),
+    (0x82B9, 6563),
+    (0x82BA, 12023),
+    (0x82BB, 7360),
+    (0x82BC, 12021),
+    (0x82BD, 6561),
+    (0x82BE, 6571),
+    (0x82C0, 12013),
+    (0x82C2, 12028),
+    (0x82C3, 12030),
+    (0x82D1, 6942),
+    (0x82D2, 6936),
+    (0x82D3, 6944),
+    (0x82D4, 6941),
+    (0x82D5, 12452),
+    (0x82D6, 12455),
+    (0x82D7, 6937),
+    (0x82D9, 12446),
+    (0x82DB, 6930),
+    (0x82DC, 6940),
+    (0x82DE, 6943),
+    (0x82DF, 6945),
+    (0x82E0, 12467),
+    (0x82E1, 12458),
+    (0x82E3, 6929),
+    (0x82E4, 12466),
+    (0x82E5, 6933),
+    (0x82E6, 6931),
+    (0x82E7, 6926),
+    (0x82E8, 12450),
+    (0x82EA, 12465),
+    (0x82EB, 12454),
+    (0x82EC, 12457),
+    (0x82ED, 12470),
+    (0x82EE, 19010),
+    (0x82EF, 6946),
+    (0x82F0, 12464),
+    (0x82F1, 6938),
+    (0x82F2, 12459),
+    (0x82F3, 12469),
+    (0x82F4, 12456),
+    (0x82F5, 12460),
+    (0x82F6, 12463),
+    (0x82F9, 12448),
+    (0x82FA, 12468),
+    (0x82FB, 12462),
+    (0x82FC, 19289),
+    (0x82FE, 12447),
+    (0x8300, 12451),
+    (0x8301, 6939),
+    (0x8302, 6934),
+    (0x8303, 6927),
+    (0x8304, 6932),
+    (0x8305, 6928),
+    (0x8306, 6947),
+    (0x8307, 12449),
+    (0x8308, 12941),
+    (0x8309, 6935),
+    (0x830C, 12461),
+    (0x830D, 12090),
+    (0x8316, 12944),
+    (0x8317, 7374),
+    (0x8319, 12928),
+    (0x831B, 12939),
+    (0x831C, 12935),
+    (0x831E, 12954),
+    (0x8320, 12946),
+    (0x8322, 12936),
+    (0x8324, 12945),
+    (0x8325, 12930),
+    (0x8326, 12934),
+    (0x8327, 12957),
+    (0x8328, 7377),
+    (0x8329, 12949),
+    (0x832A, 12940),
+    (0x832B, 7361),
+    (0x832C, 12955),
+    (0x832D, 12926),
+    (0x832F, 12948),
+    (0x8331, 7376),
+    (0x8332, 7371),
+    (0x8333, 12925),
+    (0x8334, 7369),
+    (0x8335, 7368),
+    (0x8336, 7373),
+    (0x8337, 12947),
+    (0x8338, 7365),
+    (0x8339, 7372),
+    (0x833A, 12453),
+    (0x833B, 14158),
+    (0x833C, 12942),
+    (0x833F, 12932),
+    (0x8340, 7375),
+    (0x8341, 12933),
+    (0x8342, 12937),
+    (0x8343, 7378),
+    (0x8344, 12927),
+    (0x8345, 12951),
+    (0x8347, 12950),
+    (0x8348, 12958),
+    (0x8349, 7367),
+    (0x834A, 7364),
+    (0x834B, 12956),
+    (0x834C, 12952),
+    (0x834D, 12943),
+    (0x834E, 12938),
+    (0x834F, 7370),
+    (0x8350, 7366),
+    (0x8351, 12929),
+    (0x8352, 7362),
+    (0x8353, 12953),
+    (0x8354, 7363),
+    (0x8356, 12931),
+    (0x8363, 19489),
+    (0x8373, 13532),
+    (0x8374, 13534),
+    (0x8375, 13539),
+    (0x8376, 13559),
+    (0x8377, 7847),
+    (0x8378, 7837),
+    (0x837A, 13531),
+    (0x837B, 7848),
+    (0x837C, 7849),
+    (0x837D, 13542),
+    (0x837E, 13549),
+    (0x837F, 13555),
+    (0x8381, 13536),
+    (0x8383, 13543),
+    (0x8386, 7850),
+    (0x8387, 13557),
+    (0x8388, 13552),
+    (0x8389, 7845),
+    (0x838A, 7843),
+    (0x838B, 13548),
+    (0x838C, 13544),
+    (0x838D, 13530),
+    (0x838E, 7834),
+    (0x838F, 13535),
+    (0x8390, 13527),
+    (0x8392, 7842),
+    (0x8393, 7844),
+    (0x8394, 13540),
+    (0x8395, 13537),
+    (0x8396, 7839),
+    (0x8397, 13553),
+    (0x8398, 7836),
+    (0x8399, 13538),
+    (0x839A, 13560),
+    (0x839B, 13546),
+    (0x839D, 13545),
+    (0x839E, 7835),
+    (0x83A0, 7846),
+    (0x83A2, 7838),
+    (0x83A3, 13528),
+    (0x83A4, 13533),
+    (0x83A5, 13550),
+    (0x83A6, 13556),
+    (0x83A7, 7851),
+    (0x83A8, 13529),
+    (0x83A9, 13541),
+    (0x83AA, 13547),
+    (0x83AB, 7841),
+    (0x83AE, 13558),
+    (0x83AF, 13551),
+    (0x83B0, 13554),
+    (0x83B9, 19679),
+    (0x83BD, 7840),
+    (0x83BF, 14174),
+    (0x83C0, 14162),
+    (0x83C1, 8281),
+    (0x83C2, 14191),
+    (0x83C3, 14199),
+    (0x83C4, 14202),
+    (0x83C5, 8279),
+    (0x83C6, 14170),
+    (0x83C7, 14195),
+    (0x83C8, 14171),
+    (0x83C9, 14185),
+    (0x83CA, 8292),
+    (0x83CB, 14181),
+    (0x83CC, 8289),
+    (0x83CD, 19071),
+    (0x83CE, 14182),
+    (0x83CF, 14159),
+    (0x83D1, 14196),
+    (0x83D4, 8298),
+    (0x83D5, 14193),
+    (0x83D6, 14183),
+    (0x83D7, 14204),
+    (0x83D8, 14178),
+    (0x83D9, 14860),
+    (0x83DB, 14207),
+    (0x83DC, 8296),
+    (0x83DD, 14176),
+    (0x83DE, 14188),
+    (0x83DF, 8299),
+    (0x83E0, 8278),
+    (0x83E1, 14180),
+    (0x83E2, 14205),
+    (0x83E3, 14173),
+    (0x83E4, 14166),
+    (0x83E5, 14177),
+    (0x83E7, 14165),
+    (0x83E8, 14163),
+    (0x83E9, 8274),
+    (0x83EA, 14197),
+    (0x83EB, 14172),
+    (0x83EC, 14200),
+    (0x83EE, 14201),
+    (0x83EF, 8282),
+    (0x83F0, 8287),
+    (0x83F1, 8283),
+    (0x83F2, 8291),
+    (0x83F3, 14192),
+    (0x83F4, 8284),
+    (0x83F5, 14184),
+    (0x83F6, 14168),
+    (0x83F8, 8276),
+    (0x83F9, 14160),
+    (0x83FA, 14194),
+    (0x83FB, 14203),
+    (0x83FC, 14167),
+    (0x83FD, 8290),
+    (0x83FE, 14208),
+    (0x83FF, 14179),
+    (0x8401, 14175),
+    (0x8403, 8275),
+    (0x8404, 8295),
+    (0x8406, 14190),
+    (0x8407, 8297),
+    (0x8409, 14186),
+    (0x840A, 8286),
+    (0x840B, 8280),
+    (0x840C, 8288),
+    (0x840D, 8277),
+    (0x840E, 8294),
+    (0x840F, 14187),
+    (0x8410, 14169),
+    (0x8411, 14189),
+    (0x8412, 14164),
+    (0x8413, 14198),
+    (0x8416, 19041),
+    (0x841B, 14206),
+    (0x8423, 14161),
+    (0x8424, 19364),
+    (0x8429, 14859),
+    (0x842B, 14881),
+    (0x842C, 8656),
+    (0x842D, 14864),
+    (0x842E, 19555),
+    (0x842F, 14862),
+    (0x8430, 14841),
+    (0x8431, 8706),
+    (0x8432, 14857),
+    (0x8433, 14877),
+    (0x8434, 14853),
+    (0x8435, 8714),
+    (0x8436, 14876),
+    (0x8437, 14851),
+    (0x8438, 8293),
+    (0x8439, 14867),
+    (0x843A, 14852),
+    (0x843B, 14874),
+    (0x843C, 8713),
+    (0x843D, 8705),
+    (0x843F, 14830),
+    (0x8440, 14838),
+    (0x8442, 14863),
+    (0x8443, 14855),
+    (0x8444, 14880),
+    (0x8445, 14858),
+    (0x8446, 8719),
+    (0x8447, 14875),
+    (0x8449, 8710),
+    (0x844B, 14861),
+    (0x844C, 14869),
+    (0x844D, 14842),
+    (0x844E, 14868),
+    (0x8450, 14885),
+    (0x8451, 14837),
+    (0x8452, 14870),
+    (0x8454, 14883),
+    (0x8456, 14831),
+    (0x8457, 8285),
+    (0x8459, 14845),
+    (0x845A, 14844),
+    (0x845B, 8712),
+    (0x845D, 14848),
+    (0x845E, 14850),
+    (0x845F, 14865),
+    (0x8460, 14882),
+    (0x8461, 8715),
+    (0x8462, 19732),
+    (0x8463, 8716),
+    (0x8465, 14836),
+    (0x8466, 8708),
+    (0x8467, 14840),
+    (0x8468, 14878),
+    (0x8469, 8717),
+    (0x846B, 8709),
+    (0x846C, 8711),
+    (0x846D, 8718),
+    (0x846E, 14884),
+    (0x846F, 14871),
+    (0x8470, 14866),
+    (0x8473, 14847),
+    (0x8474, 14846),
+    (0x8475, 8707),
+    (0x8476, 14832),
+    (0x8477, 8704),
+    (0x8478, 14856),
+    (0x8479, 14833),
+    (0x847A, 14854),
+    (0x847D, 14843),
+    (0x847E, 14879),
+    (0x8482, 8703),
+    (0x8486, 14839),
+    (0x848D, 14835),
+    (0x848E, 14873),
+    (0x848F, 14834),
+    (0x8490, 9138),
+    (0x8491, 15499),
+    (0x8494, 15482),
+    (0x8497, 15460),
+    (0x8498, 15490),
+    (0x8499, 9130),
+    (0x849A, 15475),
+    (0x849B, 15485),
+    (0x849C, 9133),
+    (0x849D, 15478),
+    (0x849E, 9131),
+    (0x849F, 15463),
+    (0x84A0, 15493),
+    (0x84A1, 15462),
+    (0x84A2, 15481),
+    (0x84A4, 15461),
+    (0x84A7, 15479),
+    (0x84A8, 15488),
+    (0x84A9, 15486),
+    (0x84AA, 15474),
+    (0x84AB, 15469),
+    (0x84AC, 15467),
+    (0x84AE, 15468),
+    (0x84AF, 15487),
+    (0x84B0, 15498),
+    (0x84B1, 15476),
+    (0x84B2, 9132),
+    (0x84B4, 15471),
+    (0x84B6, 15491),
+    (0x84B8, 9135),
+    (0x84B9, 15470),
+    (0x84BA, 15464),
+    (0x84BB, 15480),
+    (0x84BC, 9139),
+    (0x84BF, 9127),
+    (0x84C0, 9136),
+    (0x84C1, 15472),
+    (0x84C2, 15466),
+    (0x84C4, 9129),
+    (0x84C5, 14872),
+    (0x84C6, 9128),
+    (0x84C7, 15483),
+    (0x84C9, 9126),
+    (0x84CA, 9141),
+    (0x84CB, 9134),
+    (0x84CC, 15484),
+    (0x84CD, 15473),
+    (0x84CE, 15465),
+    (0x84CF, 15492),
+    (0x84D0, 15477),
+    (0x84D1, 9140),
+    (0x84D2, 15496),
+    (0x84D3, 9137),
+    (0x84D4, 15495),
+    (0x84D6, 15489),
+    (0x84D7, 15494),
+    (0x84DB, 15497),
+    (0x84E7, 16065),
+    (0x84E8, 16066),
+    (0x84E9, 16053),
+    (0x84EA, 16052),
+    (0x84EB, 16048),
+    (0x84EC, 9502),
+    (0x84EE, 9494),
+    (0x84EF, 16068),
+    (0x84F0, 16067),
+    (0x84F1, 14829),
+    (0x84F2, 16045),
+    (0x84F3, 16049),
+    (0x84F4, 16043),
+    (0x84F6, 16062),
+    (0x84F7, 16047),
+    (0x84F9, 16069),
+    (0x84FA, 16040),
+    (0x84FB, 16038),
+    (0x84FC, 16050),
+    (0x84FD, 16060),
+    (0x84FE, 16055),
+    (0x84FF, 9504),
+    (0x8500, 16029),
+    (0x8502, 16059),
+    (0x8505, 19221),
+    (0x8506, 9505),
+    (0x8507, 14849),
+    (0x8508, 16041),
+    (0x8509, 16032),
+    (0x850A, 16035),
+    (0x850B, 16073),
+    (0x850C, 16042),
+    (0x850D, 16033),
+    (0x850E, 16031),
+    (0x850F, 16028),
+    (0x8511, 9498),
+    (0x8512, 16051),
+    (0x8513, 9497),
+    (0x8514, 9501),
+    (0x8515, 16046),
+    (0x8516, 16054),
+    (0x8517, 9491),
+    (0x8518, 16070),
+    (0x8519, 16074),
+    (0x851A, 9493),
+    (0x851C, 16037),
+    (0x851D, 16057),
+    (0x851E, 16061),
+    (0x851F, 16034),
+    (0x8520, 16071),
+    (0x8521, 9500),
+    (0x8523, 9499),
+    (0x8524, 16026),
+    (0x8525, 9503),
+    (0x8526, 16064),
+    (0x8527, 16036),
+    (0x8528, 16056),
+    (0x8529, 16030),
+    (0x852A, 16044),
+    (0x852B, 16039),
+    (0x852C, 9495),
+    (0x852D, 9496),
+    (0x852E, 16058),
+    (0x852F, 16075),
+    (0x8530, 16072),
+    (0x8531, 16063),
+    (0x8533, 19550),
+    (0x853B, 16027),
+    (0x853D, 9492),
+    (0x853E, 16651),
+    (0x8540, 16642),
+    (0x8541, 16645),
+    (0x8542, 19084),
+    (0x8543, 9823),
+    (0x8544, 16647),
+    (0x8545, 16636),
+    (0x8546, 16643),
+    (0x8547, 16649),
+    (0x8548, 9820),
+    (0x8549, 9824),
+    (0x854A, 9818),
+    (0x854C, 19001),
+    (0x854D, 16638),
+    (0x854E, 16654),
+    (0x8551, 16648),
+    (0x8553, 16639),
+    (0x8554, 16663),
+    (0x8555, 16657),
+    (0x8556, 16635),
+    (0x8557, 17119),
+    (0x8558, 16641),
+    (0x8559, 9819),
+    (0x855B, 16652),
+    (0x855D, 16662),
+    (0x855E, 9827),
+    (0x8560, 16659),
+    (0x8561, 16640),
+    (0x8562, 16646),
+    (0x8563, 16650),
+    (0x8564, 16644),
+    (0x8565, 16664),
+    (0x8566, 16661),
+    (0x8567, 16658),
+    (0x8568, 9821),
+    (0x8569, 9822),
+    (0x856A, 9826),
+    (0x856B, 16637),
+    (0x856C, 16665),
+    (0x856D, 9825),
+    (0x856E, 16655),
+    (0x8571, 16653),
+    (0x8575, 16656),
+    (0x8576, 17132),
+    (0x8577, 17113),
+    (0x8578, 17118),
+    (0x8579, 17131),
+    (0x857A, 17117),
+    (0x857B, 17109),
+    (0x857C, 17114),
+    (0x857E, 10112),
+    (0x8580, 17102),
+    (0x8581, 17126),
+    (0x8582, 17128),
+    (0x8583, 17101),
+    (0x8584, 10111),
+    (0x8585, 17130),
+    (0x8586, 17122),
+    (0x8587, 10118),
+    (0x8588, 17129),
+    (0x8589, 17115),
+    (0x858A, 10120),
+    (0x858B, 17107),
+    (0x858C, 16660),
+    (0x858D, 17123),
+    (0x858E, 17120),
+    (0x858F, 17103),
+    (0x8590, 17134),
+    (0x8591, 10114),
+    (0x8594, 10115),
+    (0x8595, 17105),
+    (0x8596, 17121),
+    (0x8598, 17133),
+    (0x8599, 17124),
+    (0x859A, 17111),
+    (0x859B, 10117),
+    (0x859C, 10113),
+    (0x859D, 17125),
+    (0x859E, 17112),
+    (0x859F, 17135),
+    (0x85A0, 17106),
+    (0x85A1, 17116),
+    (0x85A2, 17127),
+    (0x85A3, 17108),
+    (0x85A4, 17110),
+    (0x85A6, 10310),
+    (0x85A7, 17104),
+    (0x85A8, 10119),
+    (0x85A9, 10303),
+    (0x85AA, 10110),
+    (0x85AF, 10116),
+    (0x85B0, 10307),
+    (0x85B1, 17508),
+    (0x85B3, 17498),
+    (0x85B4, 17493),
+    (0x85B5, 17499),
+    (0x85B6, 17509),
+    (0x85B7, 17513),
+    (0x85B8, 17512),
+    (0x85B9, 10309),
+    (0x85BA, 10308),
+    (0x85BD, 17500),
+    (0x85BE, 17514),
+    (0x85BF, 17503),
+    (0x85C0, 17495),
+    (0x85C2, 17497),
+    (0x85C3, 17496),
+    (0x85C4, 17502),
+    (0x85C5, 17507),
+    (0x85C6, 17494),
+    (0x85C7, 17501),
+    (0x85C8, 17506),
+    (0x85C9, 10306),
+    (0x85CB, 17504),
+    (0x85CD, 10304),
+    (0x85CE, 17505),
+    (0x85CF, 10302),
+    (0x85D0, 10305),
+    (0x85D1, 17856),
+    (0x85D2, 17510),
+    (0x85D5, 10458),
+    (0x85D7, 17848),
+    (0x85D8, 17852),
+    (0x85D9, 17844),
+    (0x85DA, 17847),
+    (0x85DC, 17855),
+    (0x85DD, 10456),
+    (0x85DE, 17860),
+    (0x85DF, 17853),
+    (0x85E1, 17845),
+    (0x85E2, 17861),
+    (0x85E3, 17854),
+    (0x85E4, 10459),
+    (0x85E5, 10460),
+    (0x85E6, 17858),
+    (0x85E8, 17846),
+    (0x85E9, 10455),
+    (0x85EA, 10457),
+    (0x85EB, 17841),
+    (0x85EC, 17849),
+    (0x85ED, 17843),
+    (0x85EF, 17859),
+    (0x85F0, 17857),
+    (0x85F1, 17842),
+    (0x85F2, 17850),
+    (0x85F6, 18147),
+    (0x85F7, 10461),
+    (0x85F8, 17851),
+    (0x85F9, 10588),
+    (0x85FA, 10590),
+    (0x85FB, 10587),
+    (0x85FD, 18152),
+    (0x85FE, 18144),
+    (0x85FF, 18142),
+    (0x8600, 18146),
+    (0x8601, 18143),
+    (0x8604, 18148),
+    (0x8605, 18150),
+    (0x8606, 10591),
+    (0x8607, 10593),
+    (0x8609, 18149),
+    (0x860A, 10594),
+    (0x860B, 10592),
+    (0x860C, 18151),
+    (0x860D, 19086),
+    (0x8611, 10589),
+    (0x8617, 10665),
+    (0x8618, 18355),
+    (0x8619, 18361),
+    (0x861A, 10667),
+    (0x861B, 18145),
+    (0x861C, 18360),
+    (0x861E, 18367),
+    (0x861F, 18358),
+    (0x8620, 18365),
+    (0x8621, 18364),
+    (0x8622, 18141),
+    (0x8623, 18359),
+    (0x8624, 17511),
+    (0x8625, 18368),
+    (0x8626, 18357),
+    (0x8627, 18362),
+    (0x8629, 18366),
+    (0x862A, 18356),
+    (0x862C, 18536),
+    (0x862D, 10666),
+    (0x862E, 18363),
+    (0x8631, 18672),
+    (0x8632, 18537),
+    (0x8633, 18535),
+    (0x8634, 18533),
+    (0x8635, 18534),
+    (0x8636, 18538),
+    (0x8638, 10795),
+    (0x8639, 18670),
+    (0x863A, 18668),
+    (0x863B, 18673),
+    (0x863C, 18671),
+    (0x863E, 18674),
+    (0x863F, 10796),
+    (0x8640, 18669),
+    (0x8643, 18773),
+    (0x8646, 18845),
+    (0x8647, 18846),
+    (0x8648, 18847),
+    (0x864B, 18941),
+    (0x864C, 18931),
+    (0x864D, 11476),
+    (0x864E, 6573),
+    (0x8650, 6948),
+    (0x8652, 12960),
+    (0x8653, 12959),
+    (0x8654, 7379),
+    (0x8655, 7852),
+    (0x8656, 13562),
+    (0x8659, 13561),
+    (0x865B, 8300),
+    (0x865C, 8721),
+    (0x865E, 8720),
+    (0x865F, 8722),
+    (0x8661, 15500),
+    (0x8662, 16076),
+    (0x8663, 16666),
+    (0x8664, 16668),
+    (0x8665, 16667),
+    (0x8667, 10121),
+    (0x8668, 17136),
+    (0x8669, 17515),
+    (0x866A, 18907),
+    (0x866B, 5939),
+    (0x866D, 12035),
+    (0x866E, 12036),
+    (0x866F, 12034),
+    (0x8670, 12033),
+    (0x8671, 6574),
+    (0x8673, 12474),
+    (0x8674, 12472),
+    (0x8677, 12471),
+    (0x8679, 6949),
+    (0x867A, 6951),
+    (0x867B, 6950),
+    (0x867C, 12473),
+    (0x8685, 12972),
+    (0x8686, 12969),
+    (0x8687, 12967),
+    (0x868A, 7380),
+    (0x868B, 12970),
+    (0x868C, 7385),
+    (0x868D, 12964),
+    (0x868E, 12979),
+    (0x8690, 12981),
+    (0x8691, 12965),
+    (0x8693, 7382),
+    (0x8694, 12982),
+    (0x8695, 12977),
+    (0x8696, 12963),
+    (0x8697, 12968),
+    (0x8698, 12978),
+    (0x8699, 12974),
+    (0x869A, 12971),
+    (0x869C, 7387),
+    (0x869D, 12980),
+    (0x869E, 12966),
+    (0x86A1, 12975),
+    (0x86A2, 12961),
+    (0x86A3, 7386),
+    (0x86A4, 7383),
+    (0x86A5, 12973),
+    (0x86A7, 12976),
+    (0x86A8, 12962),
+    (0x86A9, 7384),
+    (0x86AA, 7381),
+    (0x86AF, 7862),
+    (0x86B0, 13569),
+    (0x86B1, 7861),
+    (0x86B3, 13572),
+    (0x86B4, 13575),
+    (0x86B5, 7858),
+    (0x86B6, 7856),
+    (0x86B7, 13564),
+    (0x86B8, 13573),
+    (0x86B9, 13571),
+    (0x86BA, 13568),
+    (0x86BB, 13576),
+    (0x86BC, 13577),
+    (0x86BD, 13579),
+    (0x86BE, 13580),
+    (0x86BF, 13563),
+    (0x86C0, 7855),
+    (0x86C1, 13566),
+    (0x86C2, 13565),
+    (0x86C3, 13578),
+    (0x86C4, 7857),
+    (0x86C5, 13567),
+    (0x86C6, 7859),
+    (0x86C7, 7854),
+    (0x86C8, 13570),
+    (0x86C9, 7863),
+    (0x86CB, 7860),
+    (0x86CC, 13574),
+    (0x86D0, 8307),
+    (0x86D1, 14223),
+    (0x86D3, 14212),
+    (0x86D4, 8304),
+    (0x86D6, 14891),
+    (0x86D7, 14221),
+    (0x86D8, 14209),
+    (0x86D9, 8302),
+    (0x86DA, 14214),
+    (0x86DB, 8305),
+    (0x86DC, 14218),
+    (0x86DD, 14216),
+    (0x86DE, 8308),
+    (0x86DF, 8301),
+    (0x86E2, 14210),
+    (0x86E3, 14213),
+    (0x86E4, 8306),
+    (0x86E6, 14211),
+    (0x86E8, 14222),
+    (0x86E9, 14220),
+    (0x86EA, 14215),
+    (0x86EB, 14217),
+    (0x86EC, 14219),
+    (0x86ED, 8303),
+    (0x86F5, 14892),
+    (0x86F6, 14898),
+    (0x86F7, 14888),
+    (0x86F8, 14894),
+    (0x86F9, 8723),
+    (0x86FA, 14890),
+    (0x86FB, 8729),
+    (0x86FE, 8728),
+    (0x8700, 8727),
+    (0x8701, 14897),
+    (0x8702, 8730),
+    (0x8703, 8731),
+    (0x8704, 14887),
+    (0x8705, 14900),
+    (0x8706, 8732),
+    (0x8707, 8726),
+    (0x8708, 8725),
+    (0x8709, 14896),
+    (0x870A, 8733),
+    (0x870B, 14886),
+    (0x870C, 14889),
+    (0x870D, 14899),
+    (0x870E, 14895),
+    (0x8711, 15532),
+    (0x8712, 15521),
+    (0x8713, 8724),
+    (0x8718, 9148),
+    (0x8719, 15509),
+    (0x871A, 15530),
+    (0x871B, 15510),
+    (0x871C, 9143),
+    (0x871E, 15507),
+    (0x8720, 15516),
+    (0x8721, 15508),
+    (0x8722, 9145),
+    (0x8723, 15502),
+    (0x8724, 15529),
+    (0x8725, 9146),
+    (0x8726, 15526),
+    (0x8727, 15527),
+    (0x8728, 15503),
+    (0x8729, 9151),
+    (0x872A, 15518),
+    (0x872C, 15512),
+    (0x872D, 15519),
+    (0x872E, 15506),
+    (0x8730, 15531),
+    (0x8731, 15523),
+    (0x8732, 15517),
+    (0x8733, 15501),
+    (0x8734, 9147),
+    (0x8735, 15524),
+    (0x8737, 9150),
+    (0x8738, 15528),
+    (0x873A, 15522),
+    (0x873B, 9144),
+    (0x873C, 15520),
+    (0x873E, 15514),
+    (0x873F, 9142),
+    (0x8740, 15505),
+    (0x8741, 15513),
+    (0x8742, 15525),
+    (0x8743, 15511),
+    (0x8746, 15515),
+    (0x874C, 9515),
+    (0x874D, 14893),
+    (0x874E, 16094),
+    (0x874F, 16103),
+    (0x8750, 16093),
+    (0x8751, 16089),
+    (0x8752, 16086),
+    (0x8753, 9516),
+    (0x8754, 16084),
+    (0x8755, 9149),
+    (0x8756, 16077),
+    (0x8757, 9514),
+    (0x8758, 16083),
+    (0x8759, 9513),
+    (0x875A, 16088),
+    (0x875B, 16085),
+    (0x875C, 16101),
+    (0x875D, 16096),
+    (0x875E, 16090),
+    (0x875F, 16095),
+    (0x8760, 9509),
+    (0x8761, 16087),
+    (0x8762, 16106),
+    (0x8763, 16078),
+    (0x8764, 16079),
+    (0x8765, 16102),
+    (0x8766, 9510),
+    (0x8767, 16107),
+    (0x8768, 9512),
+    (0x8769, 16108),
+    (0x876A, 16092),
+    (0x876B, 15504),
+    (0x876C, 16098),
+    (0x876D, 16091),
+    (0x876E, 16100),
+    (0x876F, 16097),
+    (0x8773, 16082),
+    (0x8774, 9507),
+    (0x8775, 16105),
+    (0x8776, 9508),
+    (0x8777, 16080),
+    (0x8778, 9511),
+    (0x8779, 16678),
+    (0x877A, 16099),
+    (0x877B, 16104),
+    (0x877D, 19055),
+    (0x8781, 16675),
+    (0x8782, 9506),
+    (0x8783, 9828),
+    (0x8784, 16685),
+    (0x8785, 16681),
+    (0x8787, 16679),
+    (0x8788, 16674),
+    (0x8789, 16689),
+    (0x878D, 9832),
+    (0x878F, 16670),
+    (0x8790, 16682),
+    (0x8791, 16683),
+    (0x8792, 16673),
+    (0x8793, 16672),
+    (0x8794, 16686),
+    (0x8796, 16676),
+    (0x8797, 16671),
+    (0x8798, 16677),
+    (0x879A, 16688),
+    (0x879B, 16669),
+    (0x879C, 16687),
+    (0x879D, 16684),
+    (0x879E, 9830),
+    (0x879F, 9829),
+    (0x87A2, 9831),
+    (0x87A3, 16680),
+    (0x87A4, 17532),
+    (0x87AA, 17138),
+    (0x87AB, 10127),
+    (0x87AC, 17142),
+    (0x87AD, 17139),
+    (0x87AE, 17146),
+    (0x87AF, 17152),
+    (0x87B0, 17141),
+    (0x87B2, 17161),
+    (0x87B3, 10124),
+    (0x87B4, 17155),
+    (0x87B5, 17144),
+    (0x87B6, 17156),
+    (0x87B7, 17151),
+    (0x87B8, 17158),
+    (0x87B9, 17143),
+    (0x87BA, 10129),
+    (0x87BB, 10128),
+    (0x87BC, 17145),
+    (0x87BD, 17159),
+    (0x87BE, 17137),
+    (0x87BF, 17157),
+    (0x87C0, 10122),
+    (0x87C2, 17149),
+    (0x87C3, 17148),
+    (0x87C4, 17153),
+    (0x87C5, 17140),
+    (0x87C6, 10126),
+    (0x87C8, 10130),
+    (0x87C9, 17147),
+    (0x87CA, 17154),
+    (0x87CB, 10131),
+    (0x87CC, 17150),
+    (0x87D1, 10123),
+    (0x87D2, 10125),
+    (0x87D3, 17528),
+    (0x87D4, 17526),
+    (0x87D7, 17533),
+    (0x87D8, 17530),
+    (0x87D9, 17534),
+    (0x87DB, 17519),
+    (0x87DC, 17527),
+    (0x87DD, 17538),
+    (0x87DE, 17160),
+    (0x87DF, 17523),
+    (0x87E0, 10314),
+    (0x87E1, 16081),
+    (0x87E2, 17518),
+    (0x87E3, 17531),
+    (0x87E4, 17525),
+    (0x87E5, 17522),
+    (0x87E6, 17517),
+    (0x87E7, 17516),
+    (0x87E8, 17537),
+    (0x87EA, 17521),
+    (0x87EB, 17520),
+    (0x87EC, 10312),
+    (0x87ED, 17529),
+    (0x87EF, 10311),
+    (0x87F2, 10313),
+    (0x87F3, 17524),
+    (0x87F4, 17536),
+    (0x87F6, 17865),
+    (0x87F7, 17866),
+    (0x87F9, 10465),
+    (0x87FA, 17863),
+    (0x87FB, 10462),
+    (0x87FC, 17871),
+    (0x87FE, 10466),
+    (0x87FF, 17873),
+    (0x8800, 17862),
+    (0x8801, 17535),
+    (0x8802, 17875),
+    (0x8803, 17864),
+    (0x8805, 10463),
+    (0x8806, 17870),
+    (0x8808, 17872),
+    (0x8809, 17867),
+    (0x880A, 17874),
+    (0x880B, 17869),
+    (0x880C, 17868),
+    (0x880D, 10464),
+    (0x8810, 18154),
+    (0x8811, 18155),
+    (0x8813, 18157),
+    (0x8814, 10595),
+    (0x8815, 10596),
+    (0x8816, 18158),
+    (0x8817, 18156),
+    (0x8819, 18153),
+    (0x881B, 18371),
+    (0x881C, 18374),
+    (0x881D, 18370),
+    (0x881F, 10671),
+    (0x8820, 18372),
+    (0x8821, 10670),
+    (0x8822, 10669),
+    (0x8823, 10668),
+    (0x8824, 18373),
+    (0x8825, 18543),
+    (0x8826, 18541),
+    (0x8828, 18540),
+    (0x8829, 18369),
+    (0x882A, 18542),
+    (0x882B, 18375),
+    (0x882C, 18539),
+    (0x882E, 18677),
+    (0x882F, 18643),
+    (0x8830, 18675),
+    (0x8831, 10797),
+    (0x8832, 18676),
+    (0x8833, 18678),
+    (0x8835, 18776),
+    (0x8836, 10828),
+    (0x8837, 18775),
+    (0x8838, 18774),
+    (0x8839, 10829),
+    (0x883B, 10859),
+    (0x883C, 18886),
+    (0x883D, 18909),
+    (0x883E, 18908),
+    (0x883F, 18910),
+    (0x8840, 5940),
+    (0x8841, 12475),
+    (0x8843, 12983),
+    (0x8844, 12984),
+    (0x8848, 14224),
+    (0x884A, 18376),
+    (0x884B, 18777),
+    (0x884C, 5941),
+    (0x884D, 6952),
+    (0x884E, 12476),
+    (0x8852, 13581),
+    (0x8853, 7864),
+    (0x8855, 14226),
+    (0x8856, 14225),
+    (0x8857, 8309),
+    (0x8859, 8734),
+    (0x885A, 16109),
+    (0x885B, 9517),
+    (0x885D, 9518),
+    (0x8861, 9833),
+    (0x8862, 10830),
+    (0x8863, 5942),
+    (0x8867, 12477),
+    (0x8868, 6576),
+    (0x8869, 12479),
+    (0x886A, 12478),
+    (0x886B, 6953),
+    (0x886D, 12985),
+    (0x886F, 12992),
+    (0x8870, 7388),
+    (0x8871, 12990),
+    (0x8872, 12988),
+    (0x8874, 12995),
+    (0x8875, 12986),
+    (0x8876, 12987),
+    (0x8877, 7389),
+    (0x8879, 7393),
+    (0x887C, 12996),
+    (0x887D, 7392),
+    (0x887E, 12994),
+    (0x887F, 12991),
+    (0x8880, 12989),
+    (0x8881, 7390),
+    (0x8882, 7391),
+    (0x8883, 12993),
+    (0x8884, 19763),
+    (0x8888, 7866),
+    (0x8889, 13582),
+    (0x888B, 7871),
+    (0x888C, 13598),
+    (0x888D, 7870),
+    (0x888E, 13600),
+    (0x8891, 13588),
+    (0x8892, 7868),
+    (0x8893, 13599),
+    (0x8895, 13583),
+    (0x8896, 7869),
+    (0x8897, 13595),
+    (0x8898, 13591),
+    (0x8899, 13593),
+    (0x889A, 13587),
+    (0x889B, 13594),
+    (0x889E, 7865),
+    (0x889F, 13590),
+    (0x88A1, 13589),
+    (0x88A2, 13585),
+    (0x88A4, 13596),
+    (0x88A7, 13592),
+    (0x88A8, 13584),
+    (0x88AA, 13586),
+    (0x88AB, 7867),
+    (0x88AC, 13597),
+    (0x88B1, 8312),
+    (0x88B2, 14237),
+    (0x88B6, 14233),
+    (0x88B7, 14235),
+    (0x88B8, 14230),
+    (0x88B9, 14229),
+    (0x88BA, 14227),
+    (0x88BC, 14234),
+    (0x88BD, 14236),
+    (0x88BE, 14232),
+    (0x88C0, 14231),
+    (0x88C1, 8310),
+    (0x88C2, 8311),
+    (0x88C9, 14239),
+    (0x88CA, 8742),
+    (0x88CB, 14902),
+    (0x88CC, 14908),
+    (0x88CD, 14903),
+    (0x88CE, 14904),
+    (0x88CF, 18958),
+    (0x88D0, 14909),
+    (0x88D2, 8744),
+    (0x88D4, 8736),
+    (0x88D5, 8743),
+    (0x88D6, 14901),
+    (0x88D7, 14228),
+    (0x88D8, 8739),
+    (0x88D9, 8737),
+    (0x88DA, 14907),
+    (0x88DB, 14906),
+    (0x88DC, 8738),
+    (0x88DD, 8740),
+    (0x88DE, 14905),
+    (0x88DF, 8735),
+    (0x88E1, 8741),
+    (0x88E7, 15534),
+    (0x88E8, 9158),
+    (0x88EB, 15545),
+    (0x88EC, 15544),
+    (0x88EE, 15539),
+    (0x88EF, 9160),
+    (0x88F0, 15543),
+    (0x88F1, 15535),
+    (0x88F2, 15536),
+    (0x88F3, 9152),
+    (0x88F4, 9154),
+    (0x88F6, 15541),
+    (0x88F7, 15533),
+    (0x88F8, 9156),
+    (0x88F9, 9155),
+    (0x88FA, 15537),
+    (0x88FB, 15542),
+    (0x88FC, 15540),
+    (0x88FD, 9157),
+    (0x88FE, 15538),
+    (0x8900, 19768),
+    (0x8901, 14238),
+    (0x8902, 9153),
+    (0x8905, 16110),
+    (0x8906, 16117),
+    (0x8907, 9520),
+    (0x8909, 16121),
+    (0x890A, 9524),
+    (0x890B, 16113),
+    (0x890C, 16111),
+    (0x890E, 16120),
+    (0x8910, 9519),
+    (0x8911, 16119),
+    (0x8912, 9521),
+    (0x8913, 9522),
+    (0x8914, 16112),
+    (0x8915, 9523),
+    (0x8916, 16118),
+    (0x8917, 16114),
+    (0x8918, 16115),
+    (0x8919, 16116),
+    (0x891A, 9159),
+    (0x891E, 16690),
+    (0x891F, 16702),
+    (0x8921, 9838),
+    (0x8922, 16697),
+    (0x8923, 16699),
+    (0x8925, 9836),
+    (0x8926, 16691),
+    (0x8927, 16695),
+    (0x8929, 16698),
+    (0x892A, 9834),
+    (0x892B, 9837),
+    (0x892C, 16701),
+    (0x892D, 16693),
+    (0x892E, 16694),
+    (0x892F, 16700),
+    (0x8930, 16692),
+    (0x8931, 16696),
+    (0x8932, 9835),
+    (0x8933, 17163),
+    (0x8935, 17162),
+    (0x8936, 10133),
+    (0x8937, 17168),
+    (0x8938, 10135),
+    (0x893B, 10132),
+    (0x893C, 17164),
+    (0x893D, 10136),
+    (0x893E, 17165),
+    (0x8941, 17166),
+    (0x8942, 17169),
+    (0x8944, 10134),
+    (0x8946, 17543),
+    (0x8949, 17546),
+    (0x894B, 17540),
+    (0x894C, 17542),
+    (0x894F, 17541),
+    (0x8950, 17544),
+    (0x8951, 17545),
+    (0x8952, 17167),
+    (0x8953, 17539),
+    (0x8956, 10469),
+    (0x8957, 17879),
+    (0x8958, 17882),
+    (0x8959, 17884),
+    (0x895A, 17877),
+    (0x895B, 17878),
+    (0x895C, 17881),
+    (0x895D, 17883),
+    (0x895E, 10470),
+    (0x895F, 10468),
+    (0x8960, 10467),
+    (0x8961, 17880),
+    (0x8962, 17876),
+    (0x8963, 18159),
+    (0x8964, 10597),
+    (0x8966, 18160),
+    (0x8969, 18378),
+    (0x896A, 10672),
+    (0x896B, 18380),
+    (0x896C, 10673),
+    (0x896D, 18377),
+    (0x896E, 18379),
+    (0x896F, 10746),
+    (0x8971, 18544),
+    (0x8972, 10745),
+    (0x8973, 18681),
+    (0x8974, 18680),
+    (0x8976, 18679),
+    (0x8979, 18848),
+    (0x897A, 18849),
+    (0x897B, 18851),
+    (0x897C, 18850),
+    (0x897E, 11477),
+    (0x897F, 5943),
+    (0x8981, 6954),
+    (0x8982, 13601),
+    (0x8983, 8313),
+    (0x8985, 14910),
+    (0x8986, 10315),
+    (0x8988, 17885),
+    (0x898B, 6188),
+    (0x898F, 7873),
+    (0x8993, 7872),
+    (0x8995, 14240),
+    (0x8996, 8314),
+    (0x8997, 14242),
+    (0x8998, 14241),
+    (0x899B, 14911),
+    (0x899C, 8745),
+    (0x899D, 15546),
+    (0x899E, 15549),
+    (0x899F, 15548),
+    (0x89A1, 15547),
+    (0x89A2, 16122),
+    (0x89A3, 16124),
+    (0x89A4, 16123),
+    (0x89A6, 9840),
+    (0x89AA, 9839),
+    (0x89AC, 10137),
+    (0x89AD, 17170),
+    (0x89AE, 17172),
+    (0x89AF, 17171),
+    (0x89B2, 10316),
+    (0x89B6, 17887),
+    (0x89B7, 17886),
+    (0x89B9, 18161),
+    (0x89BA, 10598),
+    (0x89BD, 10674),
+    (0x89BE, 18546),
+    (0x89BF, 18545),
+    (0x89C0, 10860),
+    (0x89D2, 6189),
+    (0x89D3, 12480),
+    (0x89D4, 6955),
+    (0x89D5, 13604),
+    (0x89D6, 13602),
+    (0x89D9, 13603),
+    (0x89DA, 14244),
+    (0x89DB, 14245),
+    (0x89DC, 14918),
+    (0x89DD, 14243),
+    (0x89DF, 14912),
+    (0x89E0, 14916),
+    (0x89E1, 14915),
+    (0x89E2, 14917),
+    (0x89E3, 8746),
+    (0x89E4, 14914),
+    (0x89E5, 14913),
+    (0x89E6, 14919),
+    (0x89E8, 15552),
+    (0x89E9, 15550),
+    (0x89EB, 15551),
+    (0x89EC, 16127),
+    (0x89ED, 16125),
+    (0x89F0, 16126),
+    (0x89F1, 16703),
+    (0x89F2, 17173),
+    (0x89F3, 17174),
+    (0x89F4, 10317),
+    (0x89F6, 17888),
+    (0x89F7, 18162),
+    (0x89F8, 10599),
+    (0x89FA, 18381),
+    (0x89FB, 18547),
+    (0x89FC, 10747),
+    (0x89FE, 18682),
+    (0x89FF, 18852),
+    (0x8A00, 6190),
+    (0x8A02, 6957),
+    (0x8A03, 6958),
+    (0x8A04, 12481),
+    (0x8A07, 12482),
+    (0x8A08, 6956),
+    (0x8A0A, 7399),
+    (0x8A0C, 7397),
+    (0x8A0E, 7396),
+    (0x8A0F, 7403),
+    (0x8A10, 7395),
+    (0x8A11, 7404),
+    (0x8A12, 12997),
+    (0x8A13, 7401),
+    (0x8A15, 7398),
+    (0x8A16, 7402),
+    (0x8A17, 7400),
+    (0x8A18, 7394),
+    (0x8A1B, 7881),
+    (0x8A1D, 7875),
+    (0x8A1E, 13608),
+    (0x8A1F, 7880),
+    (0x8A22, 7882),
+    (0x8A23, 7876),
+    (0x8A25, 7877),
+    (0x8A27, 13606),
+    (0x8A2A, 7874),
+    (0x8A2C, 13607),
+    (0x8A2D, 7879),
+    (0x8A30, 13605),
+    (0x8A31, 7878),
+    (0x8A34, 8325),
+    (0x8A36, 8327),
+    (0x8A39, 14248),
+    (0x8A3A, 8326),
+    (0x8A3B, 8315),
+    (0x8A3C, 8319),
+    (0x8A3E, 8765),
+    (0x8A3F, 14924),
+    (0x8A40, 14250),
+    (0x8A41, 8320),
+    (0x8A44, 14253),
+    (0x8A45, 14254),
+    (0x8A46, 8324),
+    (0x8A48, 14256),
+    (0x8A4A, 14258),
+    (0x8A4C, 14259),
+    (0x8A4D, 14247),
+    (0x8A4E, 14246),
+    (0x8A4F, 14260),
+    (0x8A50, 8323),
+    (0x8A51, 14257),
+    (0x8A52, 14255),
+    (0x8A54, 8321),
+    (0x8A55, 8317),
+    (0x8A56, 8328),
+    (0x8A57, 14251),
+    (0x8A58, 14252),
+    (0x8A59, 14249),
+    (0x8A5B, 8322),
+    (0x8A5E, 8318),
+    (0x8A60, 8316),
+    (0x8A61, 14923),
+    (0x8A62, 8760),
+    (0x8A63, 8755),
+    (0x8A66, 8750),
+    (0x8A68, 8766),
+    (0x8A69, 8751),
+    (0x8A6B, 8747),
+    (0x8A6C, 8762),
+    (0x8A6D, 8759),
+    (0x8A6E, 8761),
+    (0x8A70, 8752),
+    (0x8A71, 8757),
+    (0x8A72, 8748),
+    (0x8A73, 8749),
+    (0x8A74, 14931),
+    (0x8A75, 14928),
+    (0x8A76, 14920),
+    (0x8A77, 14925),
+    (0x8A79, 8763),
+    (0x8A7A, 14932),
+    (0x8A7B, 8764),
+    (0x8A7C, 8754),
+    (0x8A7F, 14922),
+    (0x8A81, 14930),
+    (0x8A82, 14926),
+    (0x8A83, 14929),
+    (0x8A84, 14927),
+    (0x8A85, 8758),
+    (0x8A86, 14921),
+    (0x8A87, 8753),
+    (0x8A8B, 15555),
+    (0x8A8C, 9162),
+    (0x8A8D, 9165),
+    (0x8A8F, 15557),
+    (0x8A91, 9173),
+    (0x8A92, 15556),
+    (0x8A93, 9167),
+    (0x8A95, 9529),
+    (0x8A96, 15558),
+    (0x8A98, 9172),
+    (0x8A99, 15554),
+    (0x8A9A, 9174),
+    (0x8A9E, 9163),
+    (0x8AA0, 8756),
+    (0x8AA1, 9166),
+    (0x8AA3, 9164),
+    (0x8AA4, 9168),
+    (0x8AA5, 9170),
+    (0x8AA6, 9161),
+    (0x8AA7, 9175),
+    (0x8AA8, 9171),
+    (0x8AAA, 9169),
+    (0x8AAB, 15553),
+    (0x8AB0, 9536),
+    (0x8AB2, 9532),
+    (0x8AB6, 9539),
+    (0x8AB8, 16130),
+    (0x8AB9, 9540),
+    (0x8ABA, 16142),
+    (0x8ABB, 16135),
+    (0x8ABC, 9525),
+    (0x8ABD, 16143),
+    (0x8ABE, 16137),
+    (0x8ABF, 9535),
+    (0x8AC0, 16138),
+    (0x8AC2, 9534),
+    (0x8AC3, 16141),
+    (0x8AC4, 9528),
+    (0x8AC5, 16139),
+    (0x8AC6, 16129),
+    (0x8AC7, 9527),
+    (0x8AC8, 16714),
+    (0x8AC9, 9533),
+    (0x8ACB, 9530),
+    (0x8ACD, 9538),
+    (0x8ACF, 16128),
+    (0x8AD1, 16132),
+    (0x8AD2, 9526),
+    (0x8AD3, 16131),
+    (0x8AD4, 16133),
+    (0x8AD5, 16134),
+    (0x8AD6, 9537),
+    (0x8AD7, 16136),
+    (0x8AD8, 16140),
+    (0x8AD9, 16144),
+    (0x8ADB, 9541),
+    (0x8ADC, 9846),
+    (0x8ADD, 16709),
+    (0x8ADE, 16715),
+    (0x8ADF, 16712),
+    (0x8AE0, 16704),
+    (0x8AE1, 16716),
+    (0x8AE2, 16705),
+    (0x8AE4, 16711),
+    (0x8AE6, 9841),
+    (0x8AE7, 9847),
+    (0x8AE8, 16717),
+    (0x8AEB, 9843),
+    (0x8AED, 9853),
+    (0x8AEE, 9848),
+    (0x8AEF, 16719),
+    (0x8AF0, 16713),
+    (0x8AF1, 9844),
+    (0x8AF2, 16706),
+    (0x8AF3, 9854),
+    (0x8AF4, 16707),
+    (0x8AF5, 16708),
+    (0x8AF6, 9855),
+    (0x8AF7, 9852),
+    (0x8AF8, 9531),
+    (0x8AFA, 9842),
+    (0x8AFB, 16720),
+    (0x8AFC, 9856),
+    (0x8AFE, 9849),
+    (0x8AFF, 16718),
+    (0x8B00, 9845),
+    (0x8B01, 9850),
+    (0x8B02, 9851),
+    (0x8B04, 10145),
+    (0x8B05, 17179),
+    (0x8B06, 17188),
+    (0x8B07, 17185),
+    (0x8B08, 17187),
+    (0x8B0A, 10142),
+    (0x8B0B, 17180),
+    (0x8B0D, 17186),
+    (0x8B0E, 10138),
+    (0x8B0F, 17182),
+    (0x8B10, 10146),
+    (0x8B11, 17178),
+    (0x8B12, 17183),
+    (0x8B13, 17190),
+    (0x8B14, 16710),
+    (0x8B15, 17184),
+    (0x8B16, 17177),
+    (0x8B17, 10139),
+    (0x8B18, 17176),
+    (0x8B19, 10140),
+    (0x8B1A, 17191),
+    (0x8B1B, 10141),
+    (0x8B1C, 17189),
+    (0x8B1D, 10144),
+    (0x8B1E, 17175),
+    (0x8B20, 10143),
+    (0x8B22, 17181),
+    (0x8B23, 17549),
+    (0x8B24, 17563),
+    (0x8B25, 17558),
+    (0x8B26, 17560),
+    (0x8B27, 17548),
+    (0x8B28, 10318),
+    (0x8B2A, 17547),
+    (0x8B2B, 10321),
+    (0x8B2C, 10320),
+    (0x8B2E, 17562),
+    (0x8B2F, 17554),
+    (0x8B30, 17551),
+    (0x8B31, 17557),
+    (0x8B33, 17550),
+    (0x8B35, 17552),
+    (0x8B36, 17561),
+    (0x8B37, 17559),
+    (0x8B39, 10319),
+    (0x8B3A, 17566),
+    (0x8B3B, 17564),
+    (0x8B3C, 17555),
+    (0x8B3D, 17565),
+    (0x8B3E, 17556),
+    (0x8B40, 17892),
+    (0x8B41, 10471),
+    (0x8B42, 17899),
+    (0x8B45, 18384),
+    (0x8B46, 10478),
+    (0x8B47, 17553),
+    (0x8B48, 17890),
+    (0x8B49, 10474),
+    (0x8B4A, 17891),
+    (0x8B4B, 17896),
+    (0x8B4E, 10476),
+    (0x8B4F, 10477),
+    (0x8B50, 17889),
+    (0x8B51, 17898),
+    (0x8B52, 17900),
+    (0x8B53, 17893),
+    (0x8B54, 17895),
+    (0x8B55, 17897),
+    (0x8B56, 17894),
+    (0x8B57, 17901),
+    (0x8B58, 10473),
+    (0x8B59, 10479),
+    (0x8B5A, 10475),
+    (0x8B5C, 10472),
+    (0x8B5D, 18165),
+    (0x8B5F, 10604),
+    (0x8B60, 18163),
+    (0x8B63, 18167),
+    (0x8B65, 18168),
+    (0x8B66, 10602),
+    (0x8B67, 18169),
+    (0x8B68, 18166),
+    (0x8B6A, 18164),
+    (0x8B6B, 10605),
+    (0x8B6C, 10601),
+    (0x8B6D, 18170),
+    (0x8B6F, 10603),
+    (0x8B70, 10600),
+    (0x8B74, 10675),
+    (0x8B77, 10676),
+    (0x8B78, 18383),
+    (0x8B79, 18382),
+    (0x8B7A, 18385),
+    (0x8B7B, 18386),
+    (0x8B7D, 10677),
+    (0x8B7E, 18548),
+    (0x8B7F, 18553),
+    (0x8B80, 10748),
+    (0x8B82, 18550),
+    (0x8B84, 18549),
+    (0x8B85, 18552),
+    (0x8B86, 18551),
+    (0x8B88, 18686),
+    (0x8B8A, 10798),
+    (0x8B8B, 18685),
+    (0x8B8C, 18683),
+    (0x8B8E, 18684),
+    (0x8B92, 10832),
+    (0x8B93, 10831),
+    (0x8B94, 18778),
+    (0x8B95, 18779),
+    (0x8B96, 10833),
+    (0x8B98, 18853),
+    (0x8B99, 18854),
+    (0x8B9A, 10872),
+    (0x8B9C, 10878),
+    (0x8B9E, 18911),
+    (0x8B9F, 18942),
+    (0x8C37, 6191),
+    (0x8C39, 13609),
+    (0x8C3B, 13610),
+    (0x8C3C, 14933),
+    (0x8C3D, 15559),
+    (0x8C3E, 16145),
+    (0x8C3F, 10148),
+    (0x8C41, 10147),
+    (0x8C42, 17567),
+    (0x8C43, 17902),
+    (0x8C45, 18687),
+    (0x8C46, 6192),
+    (0x8C47, 12998),
+    (0x8C48, 7405),
+    (0x8C49, 7883),
+    (0x8C4A, 14935),
+    (0x8C4B, 14934),
+    (0x8C4C, 9542),
+    (0x8C4D, 16146),
+    (0x8C4E, 9543),
+    (0x8C4F, 17192),
+    (0x8C50, 10322),
+    (0x8C54, 10887),
+    (0x8C55, 6193),
+    (0x8C56, 12037),
+    (0x8C57, 12999),
+    (0x8C5A, 7884),
+    (0x8C5C, 13611),
+    (0x8C5D, 13612),
+    (0x8C5F, 14261),
+    (0x8C61, 8329),
+    (0x8C62, 8767),
+    (0x8C64, 14937),
+    (0x8C65, 14936),
+    (0x8C66, 14938),
+    (0x8C68, 15560),
+    (0x8C69, 15561),
+    (0x8C6A, 9176),
+    (0x8C6B, 9857),
+    (0x8C6C, 9544),
+    (0x8C6D, 9858),
+    (0x8C6F, 17196),
+    (0x8C70, 17193),
+    (0x8C71, 17195),
+    (0x8C72, 17194),
+    (0x8C73, 10149),
+    (0x8C75, 17568),
+    (0x8C76, 17904),
+    (0x8C77, 17903),
+    (0x8C78, 11684),
+    (0x8C79, 7407),
+    (0x8C7A, 7406),
+    (0x8C7B, 13000),
+    (0x8C7D, 13613),
+    (0x8C80, 14263),
+    (0x8C81, 14262),
+    (0x8C82, 8330),
+    (0x8C84, 14940),
+    (0x8C85, 14941),
+    (0x8C86, 14939),
+    (0x8C89, 8769),
+    (0x8C8A, 8768),
+    (0x8C8C, 9178),
+    (0x8C8D, 9177),
+    (0x8C8F, 16147),
+    (0x8C90, 16723),
+    (0x8C91, 16721),
+    (0x8C92, 16722),
+    (0x8C93, 9859),
+    (0x8C94, 17198),
+    (0x8C95, 17197),
+    (0x8C97, 17571),
+    (0x8C98, 17570),
+    (0x8C99, 17569),
+    (0x8C9A, 17905),
+    (0x8C9C, 18912),
+    (0x8C9D, 6194),
+    (0x8C9E, 6959),
+    (0x8CA0, 6960),
+    (0x8CA1, 7408),
+    (0x8CA2, 7409),
+    (0x8CA3, 13002),
+    (0x8CA4, 13001),
+    (0x8CA5, 13614),
+    (0x8CA7, 7890),
+    (0x8CA8, 7888),
+    (0x8CA9, 7885),
+    (0x8CAA, 7889),
+    (0x8CAB, 7887),
+    (0x8CAC, 7886),
+    (0x8CAF, 8331),
+    (0x8CB0, 14266),
+    (0x8CB2, 8774),
+    (0x8CB3, 8333),
+    (0x8CB4, 8338),
+    (0x8CB5, 14268),
+    (0x8CB6, 8340),
+    (0x8CB7, 8339),
+    (0x8CB8, 8342),
+    (0x8CB9, 14267),
+    (0x8CBA, 14264),
+    (0x8CBB, 8336),
+    (0x8CBC, 8332),
+    (0x8CBD, 8334),
+    (0x8CBE, 14265),
+    (0x8CBF, 8341),
+    (0x8CC0, 8337),
+    (0x8CC1, 8335),
+    (0x8CC2, 8776),
+    (0x8CC3, 8775),
+    (0x8CC4, 8773),
+    (0x8CC5, 8777),
+    (0x8CC7, 8771),
+    (0x8CC8, 8772),
+    (0x8CCA, 8770),
+    (0x8CCC, 14942),
+    (0x8CCF, 15563),
+    (0x8CD1, 9180),
+    (0x8CD2, 9181),
+    (0x8CD3, 9179),
+    (0x8CD5, 15562),
+    (0x8CD7, 15564),
+    (0x8CD9, 16150),
+    (0x8CDA, 16152),
+    (0x8CDC, 9553),
+    (0x8CDD, 16153),
+    (0x8CDE, 9546),
+    (0x8CDF, 16149),
+    (0x8CE0, 9545),
+    (0x8CE1, 9555),
+    (0x8CE2, 9551),
+    (0x8CE3, 9552),
+    (0x8CE4, 9548),
+    (0x8CE5, 16148),
+    (0x8CE6, 9547),
+    (0x8CE7, 16154),
+    (0x8CE8, 16151),
+    (0x8CEA, 9554),
+    (0x8CEC, 9549),
+    (0x8CED, 9550),
+    (0x8CEE, 16725),
+    (0x8CF0, 16727),
+    (0x8CF1, 16726),
+    (0x8CF3, 16728),
+    (0x8CF4, 9860),
+    (0x8CF5, 16724),
+    (0x8CF8, 10153),
+    (0x8CF9, 17199),
+    (0x8CFA, 10150),
+    (0x8CFB, 10154),
+    (0x8CFC, 10152),
+    (0x8CFD, 10151),
+    (0x8CFE, 17572),
+    (0x8D00, 17575),
+    (0x8D02, 17574),
+    (0x8D04, 17573),
+    (0x8D05, 10323),
+    (0x8D06, 17906),
+    (0x8D07, 17907),
+    (0x8D08, 10480),
+    (0x8D09, 17908),
+    (0x8D0A, 10481),
+    (0x8D0D, 10607),
+    (0x8D0F, 10606),
+    (0x8D10, 18387),
+    (0x8D13, 10678),
+    (0x8D14, 18388),
+    (0x8D15, 18554),
+    (0x8D16, 10749),
+    (0x8D17, 10750),
+    (0x8D19, 18688),
+    (0x8D1B, 10835),
+    (0x8D64, 6195),
+    (0x8D66, 7892),
+    (0x8D67, 7891),
+    (0x8D68, 14943),
+    (0x8D69, 14944),
+    (0x8D6B, 9182),
+    (0x8D6C, 16729),
+    (0x8D6D, 9556),
+    (0x8D6E, 16730),
+    (0x8D6F, 17200),
+    (0x8D70, 6196),
+    (0x8D72, 12483),
+    (0x8D73, 6962),
+    (0x8D74, 6961),
+    (0x8D76, 13003),
+    (0x8D77, 7410),
+    (0x8D78, 13004),
+    (0x8D79, 13617),
+    (0x8D7B, 13616),
+    (0x8D7D, 13615),
+    (0x8D80, 14270),
+    (0x8D81, 8345),
+    (0x8D84, 14269),
+    (0x8D85, 8344),
+    (0x8D89, 14271),
+    (0x8D8A, 8343),
+    (0x8D8C, 14946),
+    (0x8D8D, 14949),
+    (0x8D8E, 14947),
+    (0x8D8F, 14948),
+    (0x8D90, 14952),
+    (0x8D91, 14945),
+    (0x8D92, 14953),
+    (0x8D93, 14950),
+    (0x8D94, 14951),
+    (0x8D95, 9184),
+    (0x8D96, 15565),
+    (0x8D99, 9183),
+    (0x8D9B, 16158),
+    (0x8D9C, 16156),
+    (0x8D9F, 9557),
+    (0x8DA0, 16155),
+    (0x8DA1, 16157),
+    (0x8DA3, 9558),
+    (0x8DA5, 16731),
+    (0x8DA7, 16732),
+    (0x8DA8, 10155),
+    (0x8DAA, 17910),
+    (0x8DAB, 17912),
+    (0x8DAC, 17909),
+    (0x8DAD, 17911),
+    (0x8DAE, 18171),
+    (0x8DAF, 18389),
+    (0x8DB2, 18887),
+    (0x8DB3, 6197),
+    (0x8DB4, 6963),
+    (0x8DB5, 13005),
+    (0x8DB6, 13007),
+    (0x8DB7, 13006),
+    (0x8DB9, 13620),
+    (0x8DBA, 7894),
+    (0x8DBC, 13618),
+    (0x8DBE, 7893),
+    (0x8DBF, 13621),
+    (0x8DC1, 13622),
+    (0x8DC2, 13619),
+    (0x8DC5, 14283),
+    (0x8DC6, 8353),
+    (0x8DC7, 14275),
+    (0x8DC8, 14281),
+    (0x8DCB, 8348),
+    (0x8DCC, 8351),
+    (0x8DCD, 14274),
+    (0x8DCE, 8346),
+    (0x8DCF, 14278),
+    (0x8DD0, 14959),
+    (0x8DD1, 8350),
+    (0x8DD3, 14273),
+    (0x8DD5, 14279),
+    (0x8DD6, 14276),
+    (0x8DD7, 14282),
+    (0x8DD8, 14272),
+    (0x8DD9, 14280),
+    (0x8DDA, 8349),
+    (0x8DDB, 8352),
+    (0x8DDC, 14277),
+    (0x8DDD, 8347),
+    (0x8DDF, 8779),
+    (0x8DE0, 14955),
+    (0x8DE1, 8778),
+    (0x8DE2, 14962),
+    (0x8DE3, 14961),
+    (0x8DE4, 8785),
+    (0x8DE6, 8786),
+    (0x8DE7, 14963),
+    (0x8DE8, 8780),
+    (0x8DE9, 14960),
+    (0x8DEA, 8784),
+    (0x8DEB, 14965),
+    (0x8DEC, 14956),
+    (0x8DEE, 14958),
+    (0x8DEF, 8781),
+    (0x8DF0, 14954),
+    (0x8DF1, 14957),
+    (0x8DF2, 14964),
+    (0x8DF3, 8782),
+    (0x8DF4, 14966),
+    (0x8DFA, 8783),
+    (0x8DFC, 9185),
+    (0x8DFD, 15570),
+    (0x8DFE, 15576),
+    (0x8DFF, 15568),
+    (0x8E00, 15577),
+    (0x8E02, 15567),
+    (0x8E03, 15572),
+    (0x8E04, 15578),
+    (0x8E05, 15575),
+    (0x8E06, 15574),
+    (0x8E07, 15573),
+    (0x8E09, 15566),
+    (0x8E0A, 15571),
+    (0x8E0D, 15569),
+    (0x8E0F, 9563),
+    (0x8E10, 9560),
+    (0x8E11, 16167),
+    (0x8E12, 16172),
+    (0x8E13, 16174),
+    (0x8E14, 16171),
+    (0x8E15, 16164),
+    (0x8E16, 16166),
+    (0x8E17, 16176),
+    (0x8E18, 16173),
+    (0x8E19, 16168),
+    (0x8E1A, 16177),
+    (0x8E1B, 16165),
+    (0x8E1C, 16175),
+    (0x8E1D, 9561),
+    (0x8E1E, 9567),
+    (0x8E1F, 9565),
+    (0x8E20, 16159),
+    (0x8E21, 9566),
+    (0x8E22, 9562),
+    (0x8E23, 16160),
+    (0x8E24, 16162),
+    (0x8E25, 16161),
+    (0x8E26, 16169),
+    (0x8E27, 16170),
+    (0x8E29, 9564),
+    (0x8E2B, 9559),
+    (0x8E2E, 16163),
+    (0x8E30, 16742),
+    (0x8E31, 9862),
+    (0x8E33, 16733),
+    (0x8E34, 9863),
+    (0x8E35, 9866),
+    (0x8E36, 16738),
+    (0x8E38, 16735),
+    (0x8E39, 9865),
+    (0x8E3C, 16739),
+    (0x8E3D, 16740),
+    (0x8E3E, 16734),
+    (0x8E3F, 16743),
+    (0x8E40, 16736),
+    (0x8E41, 16741),
+    (0x8E42, 9864),
+    (0x8E44, 9861),
+    (0x8E45, 16737),
+    (0x8E47, 17206),
+    (0x8E48, 10158),
+    (0x8E49, 10156),
+    (0x8E4A, 10159),
+    (0x8E4B, 10157),
+    (0x8E4C, 17205),
+    (0x8E4D, 17202),
+    (0x8E4E, 17201),
+    (0x8E50, 17204),
+    (0x8E53, 17203),
+    (0x8E54, 17589),
+    (0x8E55, 10329),
+    (0x8E56, 17580),
+    (0x8E57, 17579),
+    (0x8E59, 10324),
+    (0x8E5A, 17585),
+    (0x8E5B, 17584),
+    (0x8E5C, 17576),
+    (0x8E5D, 17587),
+    (0x8E5E, 17581),
+    (0x8E5F, 10328),
+    (0x8E60, 17578),
+    (0x8E61, 17586),
+    (0x8E62, 17577),
+    (0x8E63, 10325),
+    (0x8E64, 10327),
+    (0x8E65, 17582),
+    (0x8E66, 10326),
+    (0x8E67, 17583),
+    (0x8E69, 17588),
+    (0x8E6A, 17916),
+    (0x8E6C, 10486),
+    (0x8E6D, 17913),
+    (0x8E6F, 17917),
+    (0x8E72, 10483),
+    (0x8E73, 17915),
+    (0x8E74, 10488),
+    (0x8E76, 10485),
+    (0x8E78, 17914),
+    (0x8E7A, 10487),
+    (0x8E7B, 17918),
+    (0x8E7C, 10482),
+    (0x8E81, 10609),
+    (0x8E82, 10611),
+    (0x8E84, 18174),
+    (0x8E85, 10610),
+    (0x8E86, 18172),
+    (0x8E87, 10484),
+    (0x8E88, 18173),
+    (0x8E89, 10608),
+    (0x8E8A, 10679),
+    (0x8E8B, 10681),
+    (0x8E8C, 18391),
+    (0x8E8D, 10680),
+    (0x8E8E, 18390),
+    (0x8E90, 18559),
+    (0x8E91, 10751),
+    (0x8E92, 18558),
+    (0x8E93, 10752),
+    (0x8E94, 18556),
+    (0x8E95, 18555),
+    (0x8E96, 18560),
+    (0x8E97, 18561),
+    (0x8E98, 18689),
+    (0x8E9A, 18557),
+    (0x8E9D, 18783),
+    (0x8E9E, 18780),
+    (0x8E9F, 18781),
+    (0x8EA0, 18782),
+    (0x8EA1, 10861),
+    (0x8EA3, 18857),
+    (0x8EA4, 18856),
+    (0x8EA5, 18855),
+    (0x8EA6, 18888),
+    (0x8EA8, 18932),
+    (0x8EA9, 18913),
+    (0x8EAA, 10879),
+    (0x8EAB, 6198),
+    (0x8EAC, 7411),
+    (0x8EB2, 8787),
+    (0x8EBA, 9568),
+    (0x8EBD, 16744),
+    (0x8EC0, 10330),
+    (0x8EC2, 17919),
+    (0x8EC9, 18914),
+    (0x8ECA, 6199),
+    (0x8ECB, 6577),
+    (0x8ECC, 6965),
+    (0x8ECD, 6964),
+    (0x8ECF, 7414),
+    (0x8ED1, 13008),
+    (0x8ED2, 7412),
+    (0x8ED3, 13009),
+    (0x8ED4, 7413),
+    (0x8ED7, 13627),
+    (0x8ED8, 13623),
+    (0x8EDB, 7895),
+    (0x8EDC, 13626),
+    (0x8EDD, 13625),
+    (0x8EDE, 13624),
+    (0x8EDF, 7896),
+    (0x8EE0, 13628),
+    (0x8EE1, 13629),
+    (0x8EE5, 14290),
+    (0x8EE6, 14288),
+    (0x8EE7, 14292),
+    (0x8EE8, 14293),
+    (0x8EE9, 14299),
+    (0x8EEB, 14295),
+    (0x8EEC, 14297),
+    (0x8EEE, 14289),
+    (0x8EEF, 14284),
+    (0x8EF1, 14296),
+    (0x8EF4, 14298),
+    (0x8EF5, 14291),
+    (0x8EF6, 14294),
+    (0x8EF7, 14285),
+    (0x8EF8, 8355),
+    (0x8EF9, 14287),
+    (0x8EFA, 14286),
+    (0x8EFB, 8354),
+    (0x8EFC, 8356),
+    (0x8EFE, 8790),
+    (0x8EFF, 14968),
+    (0x8F00, 14970),
+    (0x8F01, 14969),
+    (0x8F02, 14974),
+    (0x8F03, 8788),
+    (0x8F05, 14971),
+    (0x8F06, 14967),
+    (0x8F07, 14972),
+    (0x8F08, 14973),
+    (0x8F09, 8789),
+    (0x8F0A, 8791),
+    (0x8F0B, 14975),
+    (0x8F0D, 15582),
+    (0x8F0E, 15581),
+    (0x8F10, 15579),
+    (0x8F11, 15580),
+    (0x8F12, 9187),
+    (0x8F13, 9189),
+    (0x8F14, 9186),
+    (0x8F15, 9188),
+    (0x8F16, 16184),
+    (0x8F17, 16185),
+    (0x8F18, 16180),
+    (0x8F1A, 16181),
+    (0x8F1B, 9570),
+    (0x8F1C, 9575),
+    (0x8F1D, 9569),
+    (0x8F1E, 9576),
+    (0x8F1F, 9571),
+    (0x8F20, 16182),
+    (0x8F23, 16183),
+    (0x8F24, 16179),
+    (0x8F25, 9577),
+    (0x8F26, 9573),
+    (0x8F29, 9572),
+    (0x8F2A, 9574),
+    (0x8F2C, 16178),
+    (0x8F2E, 16746),
+    (0x8F2F, 9868),
+    (0x8F32, 16748),
+    (0x8F33, 9870),
+    (0x8F34, 16751),
+    (0x8F35, 16747),
+    (0x8F36, 16745),
+    (0x8F37, 16750),
+    (0x8F38, 9869),
+    (0x8F39, 16749),
+    (0x8F3B, 9867),
+    (0x8F3E, 10161),
+    (0x8F3F, 10164),
+    (0x8F40, 17208),
+    (0x8F42, 10162),
+    (0x8F43, 17207),
+    (0x8F44, 10160),
+    (0x8F45, 10163),
+    (0x8F46, 17590),
+    (0x8F47, 17591),
+    (0x8F48, 17592),
+    (0x8F49, 10331),
+    (0x8F4B, 17593),
+    (0x8F4D, 10332),
+    (0x8F4E, 10490),
+    (0x8F4F, 17922),
+    (0x8F50, 17923),
+    (0x8F51, 17921),
+    (0x8F52, 17920),
+    (0x8F53, 17924),
+    (0x8F54, 10489),
+    (0x8F55, 18178),
+    (0x8F56, 18176),
+    (0x8F57, 18177),
+    (0x8F58, 18179),
+    (0x8F59, 18175),
+    (0x8F5A, 18180),
+    (0x8F5B, 18393),
+    (0x8F5D, 18394),
+    (0x8F5E, 18392),
+    (0x8F5F, 10682),
+    (0x8F60, 18562),
+    (0x8F61, 10753),
+    (0x8F62, 18563),
+    (0x8F63, 18691),
+    (0x8F64, 18690),
+    (0x8F9B, 6200),
+    (0x8F9C, 8357),
+    (0x8F9F, 8792),
+    (0x8FA3, 9190),
+    (0x8FA6, 9872),
+    (0x8FA8, 9871),
+    (0x8FAD, 10491),
+    (0x8FAE, 10579),
+    (0x8FAF, 10683),
+    (0x8FB0, 6201),
+    (0x8FB1, 7415),
+    (0x8FB2, 8793),
+    (0x8FB4, 17925),
+    (0x8FB5, 10949),
+    (0x8FB8, 19762),
+    (0x8FBF, 11686),
+    (0x8FC2, 6202),
+    (0x8FC4, 6205),
+    (0x8FC5, 6204),
+    (0x8FC6, 6203),
+    (0x8FC9, 11685),
+    (0x8FCB, 12039),
+    (0x8FCD, 12041),
+    (0x8FCE, 6578),
+    (0x8FD1, 6580),
+    (0x8FD2, 12038),
+    (0x8FD3, 12040),
+    (0x8FD4, 6579),
+    (0x8FD5, 12043),
+    (0x8FD6, 12042),
+    (0x8FD7, 12044),
+    (0x8FE0, 12487),
+    (0x8FE1, 12485),
+    (0x8FE2, 6968),
+    (0x8FE3, 12484),
+    (0x8FE4, 6973),
+    (0x8FE5, 6970),
+    (0x8FE6, 6967),
+    (0x8FE8, 6974),
+    (0x8FEA, 6969),
+    (0x8FEB, 6972),
+    (0x8FED, 6971),
+    (0x8FEE, 12486),
+    (0x8FF0, 6966),
+    (0x8FF4, 7421),
+    (0x8FF5, 13011),
+    (0x8FF6, 13017),
+    (0x8FF7, 7418),
+    (0x8FF8, 7425),
+    (0x8FFA, 7420),
+    (0x8FFB, 13014),
+    (0x8FFC, 13016),
+    (0x8FFD, 7423),
+    (0x8FFE, 13010),
+    (0x8FFF, 13013),
+    (0x9000, 7419),
+    (0x9001, 7416),
+    (0x9002, 13012),
+    (0x9003, 7422),
+    (0x9004, 13015),
+    (0x9005, 7424),
+    (0x9006, 7417),
+    (0x900B, 13631),
+    (0x900C, 13634),
+    (0x900D, 7898),
+    (0x900F, 7908),
+    (0x9010, 7904),
+    (0x9011, 13632),
+    (0x9014, 7912),
+    (0x9015, 7905),
+    (0x9016, 7910),
+    (0x9017, 7900),
+    (0x9019, 7897),
+    (0x901A, 7899),
+    (0x901B, 7911),
+    (0x901C, 13633),
+    (0x901D, 7903),
+    (0x901E, 7906),
+    (0x901F, 7902),
+    (0x9020, 7907),
+    (0x9021, 13635),
+    (0x9022, 7909),
+    (0x9023, 7901),
+    (0x9024, 13630),
+    (0x902D, 14300),
+    (0x902E, 8358),
+    (0x902F, 14302),
+    (0x9031, 8360),
+    (0x9032, 8362),
+    (0x9034, 14301),
+    (0x9035, 8359),
+    (0x9036, 8363),
+    (0x9038, 8361),
+    (0x903C, 8799),
+    (0x903D, 14980),
+    (0x903E, 8807),
+    (0x903F, 14977),
+    (0x9041, 8808),
+    (0x9042, 8797),
+    (0x9044, 14978),
+    (0x9047, 8802),
+    (0x9049, 14979),
+    (0x904A, 8795),
+    (0x904B, 8794),
+    (0x904D, 8805),
+    (0x904E, 8804),
+    (0x904F, 8803),
+    (0x9050, 8801),
+    (0x9051, 8806),
+    (0x9052, 14976),
+    (0x9053, 8796),
+    (0x9054, 8798),
+    (0x9055, 8800),
+    (0x9058, 9192),
+    (0x9059, 9195),
+    (0x905B, 9199),
+    (0x905C, 9193),
+    (0x905D, 9198),
+    (0x905E, 9196),
+    (0x9060, 9191),
+    (0x9062, 9197),
+    (0x9063, 9194),
+    (0x9067, 16189),
+    (0x9068, 9580),
+    (0x9069, 9578),
+    (0x906B, 16190),
+    (0x906D, 9581),
+    (0x906E, 9579),
+    (0x906F, 16188),
+    (0x9070, 16187),
+    (0x9072, 9876),
+    (0x9073, 16186),
+    (0x9074, 9874),
+    (0x9075, 9873),
+    (0x9076, 16752),
+    (0x9077, 9582),
+    (0x9078, 9875),
+    (0x9079, 16753),
+    (0x907A, 9878),
+    (0x907B, 16754),
+    (0x907C, 9877),
+    (0x907D, 10166),
+    (0x907E, 17210),
+    (0x907F, 10165),
+    (0x9080, 10170),
+    (0x9081, 10168),
+    (0x9082, 10169),
+    (0x9083, 10334),
+    (0x9084, 10167),
+    (0x9085, 17209),
+    (0x9086, 16755),
+    (0x9087, 10333),
+    (0x9088, 10335),
+    (0x908A, 10492),
+    (0x908B, 10493),
+    (0x908D, 18181),
+    (0x908F, 10800),
+    (0x9090, 10799),
+    (0x9091, 6207),
+    (0x9094, 11482),
+    (0x9095, 7426),
+    (0x9097, 11479),
+    (0x9098, 11480),
+    (0x9099, 11478),
+    (0x909B, 11481),
+    (0x909E, 11690),
+    (0x909F, 11687),
+    (0x90A0, 11692),
+    (0x90A1, 11688),
+    (0x90A2, 6208),
+    (0x90A3, 6211),
+    (0x90A5, 11689),
+    (0x90A6, 6210),
+    (0x90A7, 11691),
+    (0x90AA, 6209),
+    (0x90AF, 12047),
+    (0x90B0, 12049),
+    (0x90B1, 6583),
+    (0x90B2, 12045),
+    (0x90B3, 12048),
+    (0x90B4, 12046),
+    (0x90B5, 6581),
+    (0x90B6, 6584),
+    (0x90B8, 6582),
+    (0x90BD, 12489),
+    (0x90BE, 12493),
+    (0x90BF, 12490),
+    (0x90C1, 6977),
+    (0x90C3, 6978),
+    (0x90C5, 12492),
+    (0x90C7, 12494),
+    (0x90C8, 12496),
+    (0x90CA, 6975),
+    (0x90CB, 12495),
+    (0x90CE, 6976),
+    (0x90D4, 13642),
+    (0x90D5, 12491),
+    (0x90D6, 13018),
+    (0x90D7, 13027),
+    (0x90D8, 13025),
+    (0x90D9, 13020),
+    (0x90DA, 13021),
+    (0x90DB, 13026),
+    (0x90DC, 13028),
+    (0x90DD, 7428),
+    (0x90DF, 13023),
+    (0x90E0, 13019),
+    (0x90E1, 7427),
+    (0x90E2, 7429),
+    (0x90E3, 13022),
+    (0x90E4, 13029),
+    (0x90E5, 13024),
+    (0x90E8, 7913),
+    (0x90E9, 13645),
+    (0x90EA, 13637),
+    (0x90EB, 13643),
+    (0x90EC, 13644),
+    (0x90ED, 7914),
+    (0x90EF, 13636),
+    (0x90F0, 13638),
+    (0x90F1, 12488),
+    (0x90F2, 13640),
+    (0x90F3, 13641),
+    (0x90F4, 13639),
+    (0x90F5, 8365),
+    (0x90F9, 14309),
+    (0x90FA, 16756),
+    (0x90FB, 14310),
+    (0x90FC, 14307),
+    (0x90FD, 7915),
+    (0x90FE, 8367),
+    (0x90FF, 14306),
+    (0x9100, 14312),
+    (0x9101, 14311),
+    (0x9102, 8364),
+    (0x9103, 14315),
+    (0x9104, 14305),
+    (0x9105, 14314),
+    (0x9106, 14303),
+    (0x9107, 14313),
+    (0x9108, 14308),
+    (0x9109, 8366),
+    (0x910B, 14987),
+    (0x910D, 14982),
+    (0x910E, 14988),
+    (0x910F, 14983),
+    (0x9110, 14981),
+    (0x9111, 14984),
+    (0x9112, 8809),
+    (0x9114, 14986),
+    (0x9116, 14985),
+    (0x9117, 8810),
+    (0x9118, 9201),
+    (0x9119, 9200),
+    (0x911A, 15589),
+    (0x911B, 15592),
+    (0x911C, 15584),
+    (0x911D, 15588),
+    (0x911E, 9202),
+    (0x911F, 15587),
+    (0x9120, 15585),
+    (0x9121, 15591),
+    (0x9122, 15586),
+    (0x9123, 15583),
+    (0x9124, 15590),
+    (0x9126, 16196),
+    (0x9127, 9585),
+    (0x9128, 17594),
+    (0x9129, 16193),
+    (0x912A, 16194),
+    (0x912B, 16192),
+    (0x912C, 14304),
+    (0x912D, 9584),
+    (0x912E, 16197),
+    (0x912F, 16191),
+    (0x9130, 9583),
+    (0x9131, 9586),
+    (0x9132, 16195),
+    (0x9133, 16757),
+    (0x9134, 9879),
+    (0x9135, 16758),
+    (0x9136, 16759),
+    (0x9138, 17211),
+    (0x9139, 10171),
+    (0x913A, 17595),
+    (0x913B, 17596),
+    (0x913E, 17597),
+    (0x913F, 17927),
+    (0x9140, 17926),
+    (0x9141, 18183),
+    (0x9143, 18182),
+    (0x9144, 18396),
+    (0x9145, 18397),
+    (0x9146, 18395),
+    (0x9147, 18564),
+    (0x9148, 10754),
+    (0x9149, 6212),
+    (0x914A, 6980),
+    (0x914B, 6979),
+    (0x914C, 7432),
+    (0x914D, 7431),
+    (0x914E, 13031),
+    (0x914F, 13032),
+    (0x9150, 13030),
+    (0x9152, 7430),
+    (0x9153, 13649),
+    (0x9155, 13650),
+    (0x9156, 13646),
+    (0x9157, 7916),
+    (0x9158, 13647),
+    (0x915A, 13648),
+    (0x915F, 14318),
+    (0x9160, 14320),
+    (0x9161, 14316),
+    (0x9162, 14319),
+    (0x9163, 8368),
+    (0x9164, 14317),
+    (0x9165, 8369),
+    (0x9168, 14495),
+    (0x9169, 8813),
+    (0x916A, 8812),
+    (0x916C, 8811),
+    (0x916E, 14989),
+    (0x916F, 14990),
+    (0x9172, 15594),
+    (0x9173, 15596),
+    (0x9174, 9206),
+    (0x9175, 9203),
+    (0x9177, 9205),
+    (0x9178, 9204),
+    (0x9179, 15595),
+    (0x917A, 15593),
+    (0x9180, 16204),
+    (0x9181, 16201),
+    (0x9182, 16202),
+    (0x9183, 9590),
+    (0x9184, 16203),
+    (0x9185, 16198),
+    (0x9186, 16199),
+    (0x9187, 9587),
+    (0x9189, 9588),
+    (0x918A, 16200),
+    (0x918B, 9589),
+    (0x918D, 16763),
+    (0x918F, 16764),
+    (0x9190, 16761),
+    (0x9191, 16762),
+    (0x9192, 9880),
+    (0x9193, 16760),
+    (0x9199, 17215),
+    (0x919A, 17212),
+    (0x919B, 17214),
+    (0x919C, 10174),
+    (0x919D, 17218),
+    (0x919E, 10173),
+    (0x919F, 17216),
+    (0x91A0, 17219),
+    (0x91A1, 17217),
+    (0x91A2, 17213),
+    (0x91A3, 10172),
+    (0x91A5, 17599),
+    (0x91A7, 17600),
+    (0x91A8, 17598),
+    (0x91AA, 17602),
+    (0x91AB, 10336),
+    (0x91AC, 10337),
+    (0x91AD, 17929),
+    (0x91AE, 10495),
+    (0x91AF, 17601),
+    (0x91B0, 17928),
+    (0x91B1, 10494),
+    (0x91B2, 18186),
+    (0x91B3, 18187),
+    (0x91B4, 10612),
+    (0x91B5, 18185),
+    (0x91B7, 18184),
+    (0x91B9, 18398),
+    (0x91BA, 10684),
+    (0x91BC, 18692),
+    (0x91BD, 18785),
+    (0x91BE, 18784),
+    (0x91C0, 10836),
+    (0x91C1, 10862),
+    (0x91C2, 18786),
+    (0x91C3, 18889),
+    (0x91C5, 10880),
+    (0x91C6, 6213),
+    (0x91C7, 6585),
+    (0x91C9, 8814),
+    (0x91CB, 10613),
+    (0x91CC, 6214),
+    (0x91CD, 6981),
+    (0x91CE, 7917),
+    (0x91CF, 8370),
+    (0x91D0, 10338),
+    (0x91D1, 6586),
+    (0x91D3, 12498),
+    (0x91D4, 12497),
+    (0x91D5, 13033),
+    (0x91D6, 19341),
+    (0x91D7, 7435),
+    (0x91D8, 7433),
+    (0x91D9, 7437),
+    (0x91DA, 13035),
+    (0x91DC, 7436),
+    (0x91DD, 7434),
+    (0x91E2, 13034),
+    (0x91E3, 7920),
+    (0x91E4, 13656),
+    (0x91E6, 7919),
+    (0x91E7, 7921),
+    (0x91E8, 13661),
+    (0x91E9, 7923),
+    (0x91EA, 13658),
+    (0x91EB, 13659),
+    (0x91EC, 13651),
+    (0x91ED, 7922),
+    (0x91EE, 13662),
+    (0x91F1, 13653),
+    (0x91F3, 13654),
+    (0x91F4, 13652),
+    (0x91F5, 7918),
+    (0x91F7, 13660),
+    (0x91F8, 13655),
+    (0x91F9, 13657),
+    (0x91FD, 14332),
+    (0x91FF, 14331),
+    (0x9200, 14329),
+    (0x9201, 14321),
+    (0x9202, 14336),
+    (0x9203, 14324),
+    (0x9204, 14334),
+    (0x9205, 14341),
+    (0x9206, 14333),
+    (0x9207, 8378),
+    (0x9209, 8374),
+    (0x920A, 14322),
+    (0x920C, 14328),
+    (0x920D, 8376),
+    (0x920F, 14327),
+    (0x9210, 8377),
+    (0x9211, 8379),
+    (0x9212, 14330),
+    (0x9214, 8371),
+    (0x9215, 8372),
+    (0x9216, 14342),
+    (0x9217, 14340),
+    (0x9219, 14339),
+    (0x921A, 14325),
+    (0x921C, 14337),
+    (0x921E, 8375),
+    (0x9223, 8373),
+    (0x9224, 14338),
+    (0x9225, 14323),
+    (0x9226, 14326),
+    (0x9227, 14335),
+    (0x922D, 15631),
+    (0x922E, 15000),
+    (0x9230, 14993),
+    (0x9231, 15012),
+    (0x9232, 15021),
+    (0x9233, 14996),
+    (0x9234, 8825),
+    (0x9236, 15009),
+    (0x9237, 8815),
+    (0x9238, 8817),
+    (0x9239, 8829),
+    (0x923A, 14994),
+    (0x923C, 19463),
+    (0x923D, 8818),
+    (0x923E, 8820),
+    (0x923F, 8830),
+    (0x9240, 8819),
+    (0x9245, 8828),
+    (0x9246, 15002),
+    (0x9248, 14991),
+    (0x9249, 8826),
+    (0x924A, 15001),
+    (0x924B, 8822),
+    (0x924C, 15019),
+    (0x924D, 8827),
+    (0x924E, 15017),
+    (0x924F, 15005),
+    (0x9250, 15015),
+    (0x9251, 8824),
+    (0x9252, 14992),
+    (0x9253, 15018),
+    (0x9254, 15013),
+    (0x9256, 15020),
+    (0x9257, 8816),
+    (0x925A, 8831),
+    (0x925B, 8821),
+    (0x925E, 14998),
+    (0x9260, 15006),
+    (0x9261, 15010),
+    (0x9263, 15014),
+    (0x9264, 8823),
+    (0x9265, 14997),
+    (0x9266, 14995),
+    (0x9267, 15007),
+    (0x926C, 15004),
+    (0x926D, 15003),
+    (0x926F, 15008),
+    (0x9270, 15011),
+    (0x9272, 15016),
+    (0x9276, 15599),
+    (0x9278, 9207),
+    (0x9279, 15609),
+    (0x927A, 15601),
+    (0x927B, 9212),
+    (0x927C, 9216),
+    (0x927D, 15618),
+    (0x927E, 15626),
+    (0x927F, 15611),
+    (0x9280, 9208),
+    (0x9282, 15615),
+    (0x9283, 14999),
+    (0x9285, 9209),
+    (0x9286, 15622),
+    (0x9287, 15627),
+    (0x9288, 15619),
+    (0x928A, 15621),
+    (0x928B, 15630),
+    (0x928C, 15623),
+    (0x928D, 15605),
+    (0x928E, 15614),
+    (0x9291, 9217),
+    (0x9293, 9213),
+    (0x9294, 15603),
+    (0x9295, 15616),
+    (0x9296, 9211),
+    (0x9297, 15610),
+    (0x9298, 9210),
+    (0x9299, 15624),
+    (0x929A, 15607),
+    (0x929B, 15600),
+    (0x929C, 9214),
+    (0x929D, 15629),
+    (0x92A0, 15602),
+    (0x92A1, 15620),
+    (0x92A2, 15617),
+    (0x92A3, 15612),
+    (0x92A4, 15598),
+    (0x92A5, 15597),
+    (0x92A6, 15606),
+    (0x92A7, 15625),
+    (0x92A8, 9215),
+    (0x92A9, 15628),
+    (0x92AA, 15604),
+    (0x92AB, 15608),
+    (0x92AC, 9595),
+    (0x92B2, 9603),
+    (0x92B3, 9598),
+    (0x92B4, 16237),
+    (0x92B5, 16234),
+    (0x92B6, 16210),
+    (0x92B7, 9593),
+    (0x92B9, 18957),
+    (0x92BB, 9592),
+    (0x92BC, 9599),
+    (0x92C0, 16208),
+    (0x92C1, 9597),
+    (0x92C2, 16220),
+    (0x92C3, 16206),
+    (0x92C4, 16207),
+    (0x92C5, 9591),
+    (0x92C6, 16236),
+    (0x92C7, 9601),
+    (0x92C8, 16223),
+    (0x92C9, 16228),
+    (0x92CA, 16222),
+    (0x92CB, 16782),
+    (0x92CC, 16218),
+    (0x92CD, 16226),
+    (0x92CE, 16224),
+    (0x92CF, 16211),
+    (0x92D0, 16205),
+    (0x92D1, 16232),
+    (0x92D2, 9600),
+    (0x92D3, 16233),
+    (0x92D5, 16227),
+    (0x92D7, 16216),
+    (0x92D8, 16214),
+    (0x92D9, 16209),
+    (0x92DB, 18998),
+    (0x92DD, 16217),
+    (0x92DE, 16230),
+    (0x92DF, 16213),
+    (0x92E0, 16229),
+    (0x92E1, 16235),
+    (0x92E4, 9596),
+    (0x92E6, 16225),
+    (0x92E7, 16231),
+    (0x92E8, 16221),
+    (0x92E9, 16215),
+    (0x92EA, 9594),
+    (0x92EE, 15613),
+    (0x92EF, 16219),
+    (0x92F0, 9602),
+    (0x92F1, 16212),
+    (0x92F7, 16788),
+    (0x92F8, 9883),
+    (0x92F9, 16787),
+    (0x92FA, 16784),
+    (0x92FB, 16803),
+    (0x92FC, 9887),
+    (0x92FE, 16800),
+    (0x92FF, 16792),
+    (0x9300, 16802),
+    (0x9301, 16777),
+    (0x9302, 16790),
+    (0x9304, 9889),
+    (0x9306, 16769),
+    (0x9308, 16767),
+    (0x9309, 16801),
+    (0x930B, 16799),
+    (0x930C, 16798),
+    (0x930D, 16781),
+    (0x930E, 16780),
+    (0x930F, 16770),
+    (0x9310, 9891),
+    (0x9312, 16776),
+    (0x9313, 16786),
+    (0x9314, 16797),
+    (0x9315, 9894),
+    (0x9316, 16804),
+    (0x9318, 10182),
+    (0x9319, 9896),
+    (0x931A, 9890),
+    (0x931B, 16774),
+    (0x931D, 16783),
+    (0x931E, 16766),
+    (0x931F, 16768),
+    (0x9320, 9881),
+    (0x9321, 9893),
+    (0x9322, 9886),
+    (0x9323, 16775),
+    (0x9324, 16791),
+    (0x9325, 16785),
+    (0x9326, 9892),
+    (0x9327, 16765),
+    (0x9328, 10177),
+    (0x9329, 16793),
+    (0x932A, 16796),
+    (0x932B, 9888),
+    (0x932D, 16779),
+    (0x932E, 9895),
+    (0x932F, 9885),
+    (0x9333, 9884),
+    (0x9334, 16789),
+    (0x9335, 16795),
+    (0x9336, 9882),
+    (0x9338, 16772),
+    (0x9339, 16794),
+    (0x933C, 16773),
+    (0x9340, 19332),
+    (0x9341, 19630),
+    (0x9344, 19008),
+    (0x9346, 16778),
+    (0x9347, 17225),
+    (0x9349, 17230),
+    (0x934A, 10179),
+    (0x934B, 10181),
+    (0x934C, 17236),
+    (0x934D, 10175),
+    (0x934E, 17251),
+    (0x934F, 17242),
+    (0x9350, 17231),
+    (0x9351, 17232),
+    (0x9352, 17241),
+    (0x9354, 10188),
+    (0x9355, 17240),
+    (0x9356, 17224),
+    (0x9357, 17239),
+    (0x9358, 17227),
+    (0x9359, 17252),
+    (0x935A, 10187),
+    (0x935B, 10185),
+    (0x935C, 17228),
+    (0x935E, 17247),
+    (0x9360, 17233),
+    (0x9361, 17246),
+    (0x9362, 19615),
+    (0x9363, 17248),
+    (0x9364, 17223),
+    (0x9365, 10180),
+    (0x9367, 17249),
+    (0x936A, 17237),
+    (0x936C, 10184),
+    (0x936D, 17234),
+    (0x9370, 10186),
+    (0x9371, 17243),
+    (0x9375, 10178),
+    (0x9376, 17229),
+    (0x9377, 17244),
+    (0x9379, 17238),
+    (0x937A, 16771),
+    (0x937B, 17245),
+    (0x937C, 17226),
+    (0x937E, 10183),
+    (0x9380, 17250),
+    (0x9382, 10176),
+    (0x9383, 17221),
+    (0x9385, 19703),
+    (0x9388, 17616),
+    (0x9389, 17609),
+    (0x938A, 10340),
+    (0x938C, 17604),
+    (0x938D, 17619),
+    (0x938E, 17611),
+    (0x938F, 17235),
+    (0x9391, 17621),
+    (0x9392, 17605),
+    (0x9394, 10339),
+    (0x9395, 17615),
+    (0x9396, 10341),
+    (0x9397, 10349),
+    (0x9398, 10347),
+    (0x9399, 17617),
+    (0x939A, 10348),
+    (0x939B, 17607),
+    (0x939D, 17608),
+    (0x939E, 17613),
+    (0x939F, 17618),
+    (0x93A1, 17220),
+    (0x93A2, 10342),
+    (0x93A3, 17626),
+    (0x93A4, 17623),
+    (0x93A5, 17627),
+    (0x93A6, 17614),
+    (0x93A7, 17610),
+    (0x93A8, 17624),
+    (0x93A9, 17940),
+    (0x93AA, 17612),
+    (0x93AC, 10345),
+    (0x93AE, 10344),
+    (0x93AF, 17222),
+    (0x93B0, 10346),
+    (0x93B1, 17620),
+    (0x93B2, 17622),
+    (0x93B3, 10343),
+    (0x93B4, 17625),
+    (0x93B5, 17603),
+    (0x93B7, 17606),
+    (0x93C0, 17949),
+    (0x93C2, 17933),
+    (0x93C3, 10499),
+    (0x93C4, 17947),
+    (0x93C7, 17931),
+    (0x93C8, 10500),
+    (0x93CA, 17942),
+    (0x93CC, 17938),
+    (0x93CD, 10505),
+    (0x93CE, 17948),
+    (0x93CF, 17932),
+    (0x93D0, 17935),
+    (0x93D1, 10497),
+    (0x93D2, 17950),
+    (0x93D3, 19211),
+    (0x93D4, 17943),
+    (0x93D5, 17946),
+    (0x93D6, 10503),
+    (0x93D7, 10508),
+    (0x93D8, 10506),
+    (0x93D9, 17939),
+    (0x93DA, 17934),
+    (0x93DC, 10501),
+    (0x93DD, 10502),
+    (0x93DE, 17930),
+    (0x93DF, 10498),
+    (0x93E1, 10496),
+    (0x93E2, 10504),
+    (0x93E3, 17945),
+    (0x93E4, 10507),
+    (0x93E6, 17941),
+    (0x93E7, 17951),
+    (0x93E8, 10509),
+    (0x93EC, 17937),
+    (0x93EE, 17944),
+    (0x93F0, 19573),
+    (0x93F1, 19683),
+    (0x93F5, 18200),
+    (0x93F6, 18214),
+    (0x93F7, 18202),
+    (0x93F8, 18209),
+    (0x93F9, 17936),
+    (0x93FA, 18207),
+    (0x93FB, 18190),
+    (0x93FC, 18212),
+    (0x93FD, 10616),
+    (0x93FE, 18194),
+    (0x93FF, 18211),
+    (0x9400, 18201),
+    (0x9403, 10615),
+    (0x9406, 18216),
+    (0x9407, 18203),
+    (0x9409, 18208),
+    (0x940A, 18210),
+    (0x940B, 18188),
+    (0x940C, 18213),
+    (0x940D, 18199),
+    (0x940E, 18204),
+    (0x940F, 18192),
+    (0x9410, 18196),
+    (0x9411, 18215),
+    (0x9412, 18206),
+    (0x9413, 18189),
+    (0x9414, 18193),
+    (0x9415, 18195),
+    (0x9416, 18205),
+    (0x9418, 10614),
+    (0x9419, 18198),
+    (0x941B, 19316),
+    (0x9420, 18191),
+    (0x9428, 18197),
+    (0x9429, 18402),
+    (0x942A, 18407),
+    (0x942B, 10691),
+    (0x942C, 18409),
+    (0x942E, 10685),
+    (0x9430, 18405),
+    (0x9431, 18411),
+    (0x9432, 10690),
+    (0x9433, 10686),
+    (0x9435, 10687),
+    (0x9436, 18401),
+    (0x9437, 18408),
+    (0x9438, 10689),
+    (0x9439, 18406),
+    (0x943A, 10688),
+    (0x943B, 18400),
+    (0x943C, 18404),
+    (0x943D, 18403),
+    (0x943F, 18399),
+    (0x9440, 18410),
+    (0x9442, 19625),
+    (0x9443, 19628),
+    (0x9444, 10755),
+    (0x9445, 18571),
+    (0x9446, 18574),
+    (0x9447, 18570),
+    (0x9448, 18572),
+    (0x9449, 18573),
+    (0x944A, 18567),
+    (0x944B, 18568),
+    (0x944C, 18565),
+    (0x944F, 18569),
+    (0x9450, 18566),
+    (0x9451, 10756),
+    (0x9452, 10757),
+    (0x9455, 18694),
+    (0x9457, 18696),
+    (0x945D, 18695),
+    (0x945E, 18697),
+    (0x9460, 10802),
+    (0x9462, 18693),
+    (0x9463, 10801),
+    (0x9464, 10803),
+    (0x9468, 18788),
+    (0x9469, 18789),
+    (0x946A, 10837),
+    (0x946B, 18787),
+    (0x946D, 18859),
+    (0x946E, 18858),
+    (0x946F, 18860),
+    (0x9470, 10864),
+    (0x9471, 18861),
+    (0x9472, 10863),
+    (0x9473, 18862),
+    (0x9474, 18890),
+    (0x9475, 18893),
+    (0x9476, 18892),
+    (0x9477, 10873),
+    (0x9478, 18891),
+    (0x947B, 19406),
+    (0x947C, 10883),
+    (0x947D, 10881),
+    (0x947E, 10882),
+    (0x947F, 10888),
+    (0x9480, 18934),
+    (0x9481, 18935),
+    (0x9482, 18933),
+    (0x9483, 18943),
+    (0x9577, 6587),
+    (0x957A, 13663),
+    (0x957B, 14343),
+    (0x957C, 16238),
+    (0x957D, 17952),
+    (0x9580, 6588),
+    (0x9582, 6982),
+    (0x9583, 7438),
+    (0x9586, 13664),
+    (0x9588, 13665),
+    (0x9589, 7924),
+    (0x958B, 8382),
+    (0x958C, 14345),
+    (0x958D, 14344),
+    (0x958E, 8386),
+    (0x958F, 8381),
+    (0x9590, 14346),
+    (0x9591, 8383),
+    (0x9592, 8385),
+    (0x9593, 8384),
+    (0x9594, 8380),
+    (0x9598, 8832),
+    (0x959B, 15025),
+    (0x959C, 15023),
+    (0x959E, 15024),
+    (0x959F, 15022),
+    (0x95A1, 9218),
+    (0x95A3, 9221),
+    (0x95A4, 9223),
+    (0x95A5, 9222),
+    (0x95A8, 9219),
+    (0x95A9, 9220),
+    (0x95AB, 16240),
+    (0x95AC, 16239),
+    (0x95AD, 9604),
+    (0x95AE, 16241),
+    (0x95B0, 16242),
+    (0x95B1, 9605),
+    (0x95B5, 16812),
+    (0x95B6, 16810),
+    (0x95B7, 17258),
+    (0x95B9, 16808),
+    (0x95BA, 16809),
+    (0x95BB, 9897),
+    (0x95BC, 16805),
+    (0x95BD, 16813),
+    (0x95BE, 16807),
+    (0x95BF, 16811),
+    (0x95C0, 17254),
+    (0x95C3, 17256),
+    (0x95C5, 17257),
+    (0x95C6, 10193),
+    (0x95C7, 17253),
+    (0x95C8, 10192),
+    (0x95C9, 17255),
+    (0x95CA, 10189),
+    (0x95CB, 10190),
+    (0x95CC, 10191),
+    (0x95CD, 16806),
+    (0x95D0, 10352),
+    (0x95D1, 17630),
+    (0x95D2, 17628),
+    (0x95D3, 17629),
+    (0x95D4, 10350),
+    (0x95D5, 10353),
+    (0x95D6, 10351),
+    (0x95DA, 17953),
+    (0x95DB, 17954),
+    (0x95DC, 10510),
+    (0x95DE, 18217),
+    (0x95DF, 18219),
+    (0x95E0, 18218),
+    (0x95E1, 10617),
+    (0x95E2, 10692),
+    (0x95E3, 18414),
+    (0x95E4, 18413),
+    (0x95E5, 18412),
+    (0x961C, 6589),
+    (0x961E, 11376),
+    (0x9620, 11485),
+    (0x9621, 5944),
+    (0x9622, 11483),
+    (0x9623, 11486),
+    (0x9624, 11484),
+    (0x9628, 11694),
+    (0x962A, 6218),
+    (0x962C, 6219),
+    (0x962D, 11696),
+    (0x962E, 6216),
+    (0x962F, 11695),
+    (0x9630, 11693),
+    (0x9631, 6217),
+    (0x9632, 6215),
+    (0x9639, 12050),
+    (0x963A, 12053),
+    (0x963B, 6592),
+    (0x963C, 12052),
+    (0x963D, 12051),
+    (0x963F, 6591),
+    (0x9640, 6590),
+    (0x9642, 6594),
+    (0x9643, 12054),
+    (0x9644, 6593),
+    (0x964A, 12503),
+    (0x964B, 6984),
+    (0x964C, 6985),
+    (0x964D, 6986),
+    (0x964E, 12504),
+    (0x964F, 12500),
+    (0x9650, 6983),
+    (0x9651, 12501),
+    (0x9653, 12502),
+    (0x9654, 12499),
+    (0x9658, 7445),
+    (0x965B, 7442),
+    (0x965C, 13036),
+    (0x965D, 7443),
+    (0x965E, 7446),
+    (0x965F, 13037),
+    (0x9661, 7441),
+    (0x9662, 7439),
+    (0x9663, 7440),
+    (0x9664, 7444),
+    (0x966A, 7925),
+    (0x966B, 13668),
+    (0x966C, 7933),
+    (0x966D, 13667),
+    (0x966F, 13670),
+    (0x9670, 7929),
+    (0x9671, 13669),
+    (0x9672, 8394),
+    (0x9673, 7927),
+    (0x9674, 7930),
+    (0x9675, 7926),
+    (0x9676, 7931),
+    (0x9677, 7932),
+    (0x9678, 7928),
+    (0x967C, 13666),
+    (0x967D, 8390),
+    (0x967E, 14348),
+    (0x9680, 14352),
+    (0x9683, 14351),
+    (0x9684, 8395),
+    (0x9685, 8391),
+    (0x9686, 8392),
+    (0x9687, 14347),
+    (0x9688, 14349),
+    (0x9689, 14350),
+    (0x968A, 8387),
+    (0x968B, 8389),
+    (0x968D, 8393),
+    (0x968E, 8388),
+    (0x9691, 15028),
+    (0x9692, 15026),
+    (0x9693, 15027),
+    (0x9694, 8834),
+    (0x9695, 8835),
+    (0x9697, 15029),
+    (0x9698, 8833),
+    (0x9699, 9224),
+    (0x969B, 9226),
+    (0x969C, 9225),
+    (0x969E, 15632),
+    (0x96A1, 15633),
+    (0x96A2, 16244),
+    (0x96A4, 16243),
+    (0x96A5, 19755),
+    (0x96A7, 9898),
+    (0x96A8, 9899),
+    (0x96A9, 16814),
+    (0x96AA, 9900),
+    (0x96AC, 17261),
+    (0x96AE, 17259),
+    (0x96B0, 17260),
+    (0x96B1, 10194),
+    (0x96B3, 17631),
+    (0x96B4, 10511),
+    (0x96B6, 10950),
+    (0x96B8, 10195),
+    (0x96B9, 6595),
+    (0x96BB, 7447),
+    (0x96BC, 13038),
+    (0x96BF, 13671),
+    (0x96C0, 7934),
+    (0x96C1, 8396),
+    (0x96C2, 14353),
+    (0x96C3, 14355),
+    (0x96C4, 8398),
+    (0x96C5, 8397),
+    (0x96C6, 8399),
+    (0x96C7, 8400),
+    (0x96C8, 14354),
+    (0x96C9, 8838),
+    (0x96CA, 8839),
+    (0x96CB, 8837),
+    (0x96CC, 9227),
+    (0x96CD, 8836),
+    (0x96CE, 15030),
+    (0x96D2, 9228),
+    (0x96D3, 16245),
+    (0x96D4, 16815),
+    (0x96D5, 9901),
+    (0x96D6, 10196),
+    (0x96D7, 17632),
+    (0x96D8, 17636),
+    (0x96D9, 10356),
+    (0x96DA, 17633),
+    (0x96DB, 10357),
+    (0x96DC, 10355),
+    (0x96DD, 17637),
+    (0x96DE, 10358),
+    (0x96DF, 17635),
+    (0x96E1, 17955),
+    (0x96E2, 10354),
+    (0x96E3, 10512),
+    (0x96E5, 18790),
+    (0x96E8, 6596),
+    (0x96E9, 7936),
+    (0x96EA, 7935),
+    (0x96EF, 8401),
+    (0x96F0, 14357),
+    (0x96F1, 14356),
+    (0x96F2, 8402),
+    (0x96F5, 15034),
+    (0x96F6, 8843),
+    (0x96F7, 8840),
+    (0x96F8, 15033),
+    (0x96F9, 8842),
+    (0x96FA, 15031),
+    (0x96FB, 8841),
+    (0x96FD, 15032),
+    (0x96FF, 15634),
+    (0x9700, 9229),
+    (0x9702, 16248),
+    (0x9704, 9606),
+    (0x9705, 16246),
+    (0x9706, 9607),
+    (0x9707, 9608),
+    (0x9708, 16247),
+    (0x9709, 9609),
+    (0x970B, 16816),
+    (0x970D, 9905),
+    (0x970E, 9902),
+    (0x970F, 9907),
+    (0x9710, 16818),
+    (0x9711, 9903),
+    (0x9712, 16817),
+    (0x9713, 9906),
+    (0x9716, 9904),
+    (0x9718, 17264),
+    (0x9719, 17266),
+    (0x971C, 10197),
+    (0x971D, 17265),
+    (0x971E, 10198),
+    (0x971F, 17263),
+    (0x9720, 17262),
+    (0x9722, 17639),
+    (0x9723, 17638),
+    (0x9724, 10359),
+    (0x9725, 17640),
+    (0x9726, 17960),
+    (0x9727, 10514),
+    (0x9728, 17959),
+    (0x9729, 17956),
+    (0x972A, 10513),
+    (0x972B, 17957),
+    (0x972C, 17958),
+    (0x972E, 18220),
+    (0x972F, 18221),
+    (0x9730, 10618),
+    (0x9732, 10695),
+    (0x9735, 18415),
+    (0x9738, 10693),
+    (0x9739, 10694),
+    (0x973A, 18416),
+    (0x973D, 10758),
+    (0x973E, 10759),
+    (0x973F, 18575),
+    (0x9742, 10838),
+    (0x9743, 18792),
+    (0x9744, 10840),
+    (0x9746, 18791),
+    (0x9747, 18793),
+    (0x9748, 10839),
+    (0x9749, 18863),
+    (0x974B, 18915),
+    (0x9752, 6597),
+    (0x9756, 8844),
+    (0x9758, 15635),
+    (0x975A, 16249),
+    (0x975B, 9908),
+    (0x975C, 9909),
+    (0x975E, 6598),
+    (0x9760, 9610),
+    (0x9761, 10515),
+    (0x9762, 6987),
+    (0x9766, 9910),
+    (0x9768, 10804),
+    (0x9769, 6988),
+    (0x976A, 13672),
+    (0x976C, 14358),
+    (0x976E, 14360),
+    (0x9770, 14359),
+    (0x9772, 15038),
+    (0x9773, 15035),
+    (0x9774, 8845),
+    (0x9776, 8846),
+    (0x9777, 15036),
+    (0x9778, 15037),
+    (0x977A, 15637),
+    (0x977B, 15642),
+    (0x977C, 9230),
+    (0x977D, 15636),
+    (0x977E, 15638),
+    (0x977F, 15645),
+    (0x9780, 15640),
+    (0x9781, 15644),
+    (0x9782, 15641),
+    (0x9783, 15639),
+    (0x9784, 15643),
+    (0x9785, 9231),
+    (0x9787, 19178),
+    (0x9788, 16252),
+    (0x978A, 16250),
+    (0x978B, 9612),
+    (0x978D, 9611),
+    (0x978E, 16251),
+    (0x978F, 9613),
+    (0x9794, 16821),
+    (0x9797, 16820),
+    (0x9798, 9911),
+    (0x9799, 16819),
+    (0x979A, 17267),
+    (0x979C, 17269),
+    (0x979D, 17271),
+    (0x979E, 17270),
+    (0x97A0, 10199),
+    (0x97A1, 17268),
+    (0x97A2, 17647),
+    (0x97A3, 10360),
+    (0x97A4, 17645),
+    (0x97A5, 17648),
+    (0x97A6, 10361),
+    (0x97A8, 17643),
+    (0x97AA, 17646),
+    (0x97AB, 17644),
+    (0x97AC, 17641),
+    (0x97AD, 10362),
+    (0x97AE, 17642),
+    (0x97B3, 17961),
+    (0x97B6, 17963),
+    (0x97B7, 17962),
+    (0x97B9, 18222),
+    (0x97BB, 18223),
+    (0x97BD, 19329),
+    (0x97BF, 18417),
+    (0x97C1, 10761),
+    (0x97C3, 10760),
+    (0x97C4, 18698),
+    (0x97C5, 18699),
+    (0x97C6, 10841),
+    (0x97C7, 18794),
+    (0x97C9, 10874),
+    (0x97CB, 6989),
+    (0x97CC, 8403),
+    (0x97CD, 15647),
+    (0x97CE, 15646),
+    (0x97CF, 16254),
+    (0x97D0, 16253),
+    (0x97D3, 10200),
+    (0x97D4, 17273),
+    (0x97D5, 17272),
+    (0x97D6, 17651),
+    (0x97D7, 17649),
+    (0x97D8, 17652),
+    (0x97D9, 17650),
+    (0x97DC, 10516),
+    (0x97DD, 17964),
+    (0x97DE, 17965),
+    (0x97DF, 17966),
+    (0x97E1, 18418),
+    (0x97E3, 18576),
+    (0x97E5, 18795),
+    (0x97ED, 6990),
+    (0x97F0, 16822),
+    (0x97F1, 17274),
+    (0x97F3, 6991),
+    (0x97F6, 9232),
+    (0x97F8, 16823),
+    (0x97F9, 10363),
+    (0x97FA, 17653),
+    (0x97FB, 10517),
+    (0x97FD, 18224),
+    (0x97FE, 18225),
+    (0x97FF, 10696),
+    (0x9800, 18700),
+    (0x9801, 6992),
+    (0x9802, 7939),
+    (0x9803, 7940),
+    (0x9804, 13673),
+    (0x9805, 8404),
+    (0x9806, 8405),
+    (0x9807, 14361),
+    (0x9808, 8406),
+    (0x980A, 8850),
+    (0x980C, 8852),
+    (0x980D, 15040),
+    (0x980E, 15041),
+    (0x980F, 15039),
+    (0x9810, 8847),
+    (0x9811, 8848),
+    (0x9812, 8851),
+    (0x9813, 8849),
+    (0x9814, 19401),
+    (0x9816, 15648),
+    (0x9817, 9233),
+    (0x9818, 9234),
+    (0x981B, 16261),
+    (0x981C, 9616),
+    (0x981D, 16256),
+    (0x981E, 16255),
+    (0x9820, 16260),
+    (0x9821, 9614),
+    (0x9824, 9918),
+    (0x9826, 16257),
+    (0x9827, 16262),
+    (0x9828, 16259),
+    (0x9829, 16258),
+    (0x982B, 9615),
+    (0x982D, 9916),
+    (0x982F, 16825),
+    (0x9830, 9912),
+    (0x9832, 16826),
+    (0x9835, 16824),
+    (0x9837, 9915),
+    (0x9838, 9913),
+    (0x9839, 9917),
+    (0x983B, 9914),
+    (0x9841, 17275),
+    (0x9843, 17280),
+    (0x9844, 17276),
+    (0x9845, 17279),
+    (0x9846, 10201),
+    (0x9848, 17070),
+    (0x9849, 17278),
+    (0x984A, 17277),
+    (0x984C, 10366),
+    (0x984D, 10364),
+    (0x984E, 10367),
+    (0x984F, 10365),
+    (0x9850, 17654),
+    (0x9851, 17655),
+    (0x9852, 17656),
+    (0x9853, 10368),
+    (0x9857, 17970),
+    (0x9858, 10519),
+    (0x9859, 17968),
+    (0x985B, 10520),
+    (0x985C, 17967),
+    (0x985D, 17969),
+    (0x985E, 10518),
+    (0x985F, 18229),
+    (0x9860, 18226),
+    (0x9862, 18227),
+    (0x9863, 18228),
+    (0x9864, 18419),
+    (0x9865, 10698),
+    (0x9867, 10697),
+    (0x9869, 18578),
+    (0x986A, 18577),
+    (0x986B, 10762),
+    (0x986C, 19039),
+    (0x986F, 10805),
+    (0x9870, 10842),
+    (0x9871, 10865),
+    (0x9872, 18864),
+    (0x9873, 18916),
+    (0x9874, 18917),
+    (0x98A8, 6993),
+    (0x98A9, 14362),
+    (0x98AC, 15042),
+    (0x98AD, 15649),
+    (0x98AE, 15650),
+    (0x98AF, 9235),
+    (0x98B1, 9236),
+    (0x98B2, 16263),
+    (0x98B3, 9617),
+    (0x98B6, 10202),
+    (0x98B8, 17657),
+    (0x98BA, 10369),
+    (0x98BB, 17973),
+    (0x98BC, 10521),
+    (0x98BD, 17972),
+    (0x98BE, 17974),
+    (0x98BF, 17971),
+    (0x98C0, 18422),
+    (0x98C1, 18230),
+    (0x98C2, 18231),
+    (0x98C4, 10619),
+    (0x98C6, 18421),
+    (0x98C9, 18420),
+    (0x98CB, 18579),
+    (0x98CC, 18918),
+    (0x98DB, 6994),
+    (0x98DF, 6995),
+    (0x98E2, 7448),
+    (0x98E3, 13039),
+    (0x98E5, 13674),
+    (0x98E7, 8407),
+    (0x98E9, 8410),
+    (0x98EA, 8408),
+    (0x98EB, 14363),
+    (0x98ED, 8412),
+    (0x98EF, 8409),
+    (0x98F2, 8411),
+    (0x98F4, 8854),
+    (0x98F6, 15043),
+    (0x98F9, 15044),
+    (0x98FA, 16265),
+    (0x98FC, 8853),
+    (0x98FD, 8855),
+    (0x98FE, 8856),
+    (0x9900, 15652),
+    (0x9902, 15651),
+    (0x9903, 9237),
+    (0x9905, 9238),
+    (0x9907, 15653),
+    (0x9908, 16264),
+    (0x9909, 9240),
+    (0x990A, 9618),
+    (0x990C, 9239),
+    (0x9910, 9919),
+    (0x9911, 16266),
+    (0x9912, 9620),
+    (0x9913, 9619),
+    (0x9914, 16267),
+    (0x9915, 16270),
+    (0x9916, 16268),
+    (0x9917, 16269),
+    (0x9918, 9621),
+    (0x991A, 9924),
+    (0x991B, 9922),
+    (0x991E, 9921),
+    (0x991F, 16828),
+    (0x9921, 9923),
+    (0x9924, 16827),
+    (0x9925, 17281),
+    (0x9927, 16829),
+    (0x9928, 9920),
+    (0x9929, 16830),
+    (0x992A, 17284),
+    (0x992B, 17282),
+    (0x992C, 17283),
+    (0x992D, 17288),
+    (0x992E, 10373),
+    (0x992F, 17287),
+    (0x9930, 17290),
+    (0x9931, 17289),
+    (0x9932, 17286),
+    (0x9933, 17285),
+    (0x9935, 10203),
+    (0x993A, 17660),
+    (0x993C, 17659),
+    (0x993D, 10372),
+    (0x993E, 10370),
+    (0x993F, 10371),
+    (0x9941, 17658),
+    (0x9943, 17977),
+    (0x9945, 10522),
+    (0x9947, 17976),
+    (0x9948, 17975),
+    (0x9949, 10523),
+    (0x994B, 18236),
+    (0x994C, 18235),
+    (0x994E, 18233),
+    (0x9950, 18232),
+    (0x9951, 10621),
+    (0x9952, 10620),
+    (0x9953, 18237),
+    (0x9954, 18580),
+    (0x9955, 10763),
+    (0x9956, 18424),
+    (0x9957, 10699),
+    (0x9958, 18423),
+    (0x9959, 18234),
+    (0x995B, 18581),
+    (0x995C, 10806),
+    (0x995E, 10866),
+    (0x995F, 18865),
+    (0x9961, 18919),
+    (0x9996, 6996),
+    (0x9997, 13675),
+    (0x9998, 17291),
+    (0x9999, 6997),
+    (0x999C, 15655),
+    (0x999D, 15654),
+    (0x999E, 16831),
+    (0x99A1, 17293),
+    (0x99A3, 17292),
+    (0x99A4, 19447),
+    (0x99A5, 10374),
+    (0x99A6, 17978),
+    (0x99A7, 17979),
+    (0x99A8, 10622),
+    (0x99AB, 18920),
+    (0x99AC, 7449),
+    (0x99AD, 8414),
+    (0x99AE, 8413),
+    (0x99AF, 15045),
+    (0x99B0, 15047),
+    (0x99B1, 8858),
+    (0x99B2, 15046),
+    (0x99B3, 8857),
+    (0x99B4, 8859),
+    (0x99B5, 15048),
+    (0x99B9, 15657),
+    (0x99BA, 15659),
+    (0x99BB, 15658),
+    (0x99BD, 15661),
+    (0x99C1, 9241),
+    (0x99C2, 15660),
+    (0x99C3, 15656),
+    (0x99C7, 15662),
+    (0x99C9, 16277),
+    (0x99CB, 16280),
+    (0x99CC, 16282),
+    (0x99CD, 16272),
+    (0x99CE, 16276),
+    (0x99CF, 16273),
+    (0x99D0, 9623),
+    (0x99D1, 9626),
+    (0x99D2, 9628),
+    (0x99D3, 16274),
+    (0x99D4, 16275),
+    (0x99D5, 9627),
+    (0x99D6, 16278),
+    (0x99D7, 16281),
+    (0x99D8, 16279),
+    (0x99D9, 9629),
+    (0x99DB, 9625),
+    (0x99DC, 16271),
+    (0x99DD, 9622),
+    (0x99DF, 9624),
+    (0x99E2, 9926),
+    (0x99E3, 16837),
+    (0x99E4, 16835),
+    (0x99E5, 16834),
+    (0x99E7, 16840),
+    (0x99E9, 16839),
+    (0x99EA, 16838),
+    (0x99EC, 16833),
+    (0x99ED, 9925),
+    (0x99EE, 16832),
+    (0x99F0, 16836),
+    (0x99F1, 9927),
+    (0x99F4, 17296),
+    (0x99F6, 17300),
+    (0x99F7, 17297),
+    (0x99F8, 17299),
+    (0x99F9, 17298),
+    (0x99FA, 17295),
+    (0x99FB, 17301),
+    (0x99FC, 17304),
+    (0x99FD, 17302),
+    (0x99FE, 17303),
+    (0x99FF, 10205),
+    (0x9A01, 10204),
+    (0x9A02, 17294),
+    (0x9A03, 17305),
+    (0x9A04, 17665),
+    (0x9A05, 17668),
+    (0x9A06, 17670),
+    (0x9A07, 17669),
+    (0x9A09, 17663),
+    (0x9A0A, 17667),
+    (0x9A0B, 17662),
+    (0x9A0D, 17664),
+    (0x9A0E, 10375),
+    (0x9A0F, 17661),
+    (0x9A11, 17666),
+    (0x9A14, 17992),
+    (0x9A15, 17981),
+    (0x9A16, 10524),
+    (0x9A19, 10525),
+    (0x9A1A, 17980),
+    (0x9A1B, 17985),
+    (0x9A1C, 17991),
+    (0x9A1D, 17983),
+    (0x9A1E, 17990),
+    (0x9A20, 17987),
+    (0x9A22, 17986),
+    (0x9A23, 17989),
+    (0x9A24, 17984),
+    (0x9A25, 17982),
+    (0x9A27, 17988),
+    (0x9A29, 18244),
+    (0x9A2A, 18242),
+    (0x9A2B, 10623),
+    (0x9A2C, 18241),
+    (0x9A2D, 18247),
+    (0x9A2E, 18245),
+    (0x9A30, 10624),
+    (0x9A31, 18240),
+    (0x9A32, 18238),
+    (0x9A34, 18239),
+    (0x9A35, 10626),
+    (0x9A36, 18243),
+    (0x9A37, 10625),
+    (0x9A38, 18246),
+    (0x9A39, 18425),
+    (0x9A3A, 18431),
+    (0x9A3D, 18426),
+    (0x9A3E, 10703),
+    (0x9A3F, 18432),
+    (0x9A40, 10702),
+    (0x9A41, 18430),
+    (0x9A42, 18429),
+    (0x9A43, 10701),
+    (0x9A44, 18428),
+    (0x9A45, 10700),
+    (0x9A46, 18427),
+    (0x9A48, 18587),
+    (0x9A49, 18589),
+    (0x9A4A, 18588),
+    (0x9A4C, 18585),
+    (0x9A4D, 10765),
+    (0x9A4E, 18582),
+    (0x9A4F, 18586),
+    (0x9A50, 18591),
+    (0x9A52, 18590),
+    (0x9A53, 18583),
+    (0x9A54, 18584),
+    (0x9A55, 10764),
+    (0x9A56, 18701),
+    (0x9A57, 10809),
+    (0x9A59, 18702),
+    (0x9A5A, 10807),
+    (0x9A5B, 10808),
+    (0x9A5E, 18796),
+    (0x9A5F, 10843),
+    (0x9A60, 18894),
+    (0x9A62, 10875),
+    (0x9A64, 18921),
+    (0x9A65, 10876),
+    (0x9A66, 18922),
+    (0x9A67, 18923),
+    (0x9A68, 18937),
+    (0x9A69, 18936),
+    (0x9A6A, 10891),
+    (0x9A6B, 18947),
+    (0x9AA8, 7450),
+    (0x9AAB, 15050),
+    (0x9AAD, 15049),
+    (0x9AAF, 9242),
+    (0x9AB0, 9243),
+    (0x9AB1, 15663),
+    (0x9AB3, 16283),
+    (0x9AB4, 16843),
+    (0x9AB7, 9630),
+    (0x9AB8, 9928),
+    (0x9AB9, 16841),
+    (0x9ABB, 16844),
+    (0x9ABC, 9929),
+    (0x9ABE, 17306),
+    (0x9ABF, 16842),
+    (0x9AC0, 17671),
+    (0x9AC1, 10376),
+    (0x9AC2, 17993),
+    (0x9AC6, 18250),
+    (0x9AC7, 18248),
+    (0x9ACA, 18249),
+    (0x9ACD, 18433),
+    (0x9ACF, 10704),
+    (0x9AD0, 18592),
+    (0x9AD1, 10812),
+    (0x9AD2, 10766),
+    (0x9AD3, 10810),
+    (0x9AD4, 10811),
+    (0x9AD5, 18797),
+    (0x9AD6, 10867),
+    (0x9AD8, 7451),
+    (0x9ADC, 17672),
+    (0x9ADF, 13040),
+    (0x9AE1, 8860),
+    (0x9AE3, 15664),
+    (0x9AE6, 9244),
+    (0x9AE7, 15665),
+    (0x9AEB, 16285),
+    (0x9AEC, 16284),
+    (0x9AED, 9931),
+    (0x9AEE, 9631),
+    (0x9AEF, 9632),
+    (0x9AF1, 16288),
+    (0x9AF2, 16287),
+    (0x9AF3, 16286),
+    (0x9AF6, 16845),
+    (0x9AF7, 16848),
+    (0x9AF9, 16847),
+    (0x9AFA, 16846),
+    (0x9AFB, 9930),
+    (0x9AFC, 17310),
+    (0x9AFD, 17308),
+    (0x9AFE, 17307),
+    (0x9B01, 17309),
+    (0x9B03, 10377),
+    (0x9B04, 17674),
+    (0x9B05, 17675),
+    (0x9B06, 10378),
+    (0x9B08, 17673),
+    (0x9B0A, 17995),
+    (0x9B0B, 17994),
+    (0x9B0C, 17997),
+    (0x9B0D, 10526),
+    (0x9B0E, 17996),
+    (0x9B10, 18251),
+    (0x9B11, 18253),
+    (0x9B12, 18252),
+    (0x9B15, 18434),
+    (0x9B16, 18437),
+    (0x9B17, 18435),
+    (0x9B18, 18436),
+    (0x9B19, 18593),
+    (0x9B1A, 10767),
+    (0x9B1E, 18703),
+    (0x9B1F, 18704),
+    (0x9B20, 18705),
+    (0x9B22, 10844),
+    (0x9B23, 10868),
+    (0x9B24, 18924),
+    (0x9B25, 7452),
+    (0x9B27, 9633),
+    (0x9B28, 9932),
+    (0x9B29, 17676),
+    (0x9B2B, 18594),
+    (0x9B2E, 18938),
+    (0x9B2F, 13041),
+    (0x9B31, 10892),
+    (0x9B32, 7453),
+    (0x9B33, 16849),
+    (0x9B35, 17677),
+    (0x9B37, 17998),
+    (0x9B3A, 18438),
+    (0x9B3B, 18595),
+    (0x9B3C, 7454),
+    (0x9B3E, 15666),
+    (0x9B3F, 15667),
+    (0x9B41, 9245),
+    (0x9B42, 9246),
+    (0x9B43, 16290),
+    (0x9B44, 9635),
+    (0x9B45, 9634),
+    (0x9B46, 16289),
+    (0x9B48, 17311),
+    (0x9B4A, 17678),
+    (0x9B4B, 17680),
+    (0x9B4C, 17679),
+    (0x9B4D, 10381),
+    (0x9B4E, 10380),
+    (0x9B4F, 10379),
+    (0x9B51, 10706),
+    (0x9B52, 18439),
+    (0x9B54, 10705),
+    (0x9B55, 18597),
+    (0x9B56, 18596),
+    (0x9B58, 10845),
+    (0x9B59, 18798),
+    (0x9B5A, 7941),
+    (0x9B5B, 15051),
+    (0x9B5F, 15670),
+    (0x9B60, 15668),
+    (0x9B61, 15669),
+    (0x9B64, 16299),
+    (0x9B66, 16294),
+    (0x9B67, 16291),
+    (0x9B68, 16298),
+    (0x9B6C, 16300),
+    (0x9B6F, 9637),
+    (0x9B70, 16297),
+    (0x9B71, 16293),
+    (0x9B74, 16292),
+    (0x9B75, 16296),
+    (0x9B76, 16295),
+    (0x9B77, 9636),
+    (0x9B7A, 16860),
+    (0x9B7B, 16855),
+    (0x9B7C, 16853),
+    (0x9B7D, 16862),
+    (0x9B7E, 16854),
+    (0x9B80, 16850),
+    (0x9B82, 16856),
+    (0x9B83, 19535),
+    (0x9B85, 16851),
+    (0x9B86, 17320),
+    (0x9B87, 16852),
+    (0x9B88, 16863),
+    (0x9B90, 16859),
+    (0x9B91, 9933),
+    (0x9B92, 16858),
+    (0x9B93, 16857),
+    (0x9B95, 16861),
+    (0x9B9A, 17312),
+    (0x9B9B, 17315),
+    (0x9B9E, 17314),
+    (0x9BA0, 17322),
+    (0x9BA1, 17317),
+    (0x9BA2, 17321),
+    (0x9BA4, 17319),
+    (0x9BA5, 17318),
+    (0x9BA6, 17316),
+    (0x9BA8, 17313),
+    (0x9BAA, 10208),
+    (0x9BAB, 10207),
+    (0x9BAD, 10209),
+    (0x9BAE, 10206),
+    (0x9BAF, 17323),
+    (0x9BB5, 17686),
+    (0x9BB6, 17689),
+    (0x9BB8, 17687),
+    (0x9BB9, 17691),
+    (0x9BBD, 17692),
+    (0x9BBF, 17684),
+    (0x9BC0, 10386),
+    (0x9BC1, 17685),
+    (0x9BC3, 17683),
+    (0x9BC4, 17690),
+    (0x9BC6, 17682),
+    (0x9BC7, 17681),
+    (0x9BC8, 10385),
+    (0x9BC9, 10383),
+    (0x9BCA, 10382),
+    (0x9BD3, 17688),
+    (0x9BD4, 18007),
+    (0x9BD5, 18013),
+    (0x9BD6, 10529),
+    (0x9BD7, 18008),
+    (0x9BD9, 18011),
+    (0x9BDA, 18015),
+    (0x9BDB, 10530),
+    (0x9BDC, 18010),
+    (0x9BDE, 18002),
+    (0x9BE0, 18001),
+    (0x9BE1, 18014),
+    (0x9BE2, 18005),
+    (0x9BE4, 18003),
+    (0x9BE5, 18012),
+    (0x9BE6, 18004),
+    (0x9BE7, 10528),
+    (0x9BE8, 10527),
+    (0x9BEA, 17999),
+    (0x9BEB, 18000),
+    (0x9BEC, 18009),
+    (0x9BF0, 18006),
+    (0x9BF7, 18256),
+    (0x9BF8, 18259),
+    (0x9BFD, 10384),
+    (0x9C05, 18257),
+    (0x9C06, 18263),
+    (0x9C07, 18261),
+    (0x9C08, 18255),
+    (0x9C09, 18266),
+    (0x9C0B, 18254),
+    (0x9C0D, 10628),
+    (0x9C0E, 18262),
+    (0x9C12, 18258),
+    (0x9C13, 10627),
+    (0x9C14, 18265),
+    (0x9C17, 18264),
+    (0x9C1C, 18442),
+    (0x9C1D, 18441),
+    (0x9C21, 18448),
+    (0x9C23, 18444),
+    (0x9C24, 18447),
+    (0x9C25, 10708),
+    (0x9C28, 18445),
+    (0x9C29, 18446),
+    (0x9C2B, 18440),
+    (0x9C2C, 18443),
+    (0x9C2D, 10707),
+    (0x9C31, 10769),
+    (0x9C32, 18608),
+    (0x9C33, 18603),
+    (0x9C34, 18607),
+    (0x9C36, 18610),
+    (0x9C37, 18606),
+    (0x9C39, 18602),
+    (0x9C3B, 10771),
+    (0x9C3C, 18605),
+    (0x9C3D, 18609),
+    (0x9C3E, 10770),
+    (0x9C3F, 18600),
+    (0x9C40, 18260),
+    (0x9C41, 18604),
+    (0x9C44, 18601),
+    (0x9C46, 18598),
+    (0x9C48, 18599),
+    (0x9C49, 10768),
+    (0x9C4A, 18709),
+    (0x9C4B, 18711),
+    (0x9C4C, 18714),
+    (0x9C4D, 18710),
+    (0x9C4E, 18715),
+    (0x9C50, 18708),
+    (0x9C52, 18706),
+    (0x9C54, 10813),
+    (0x9C55, 18712),
+    (0x9C56, 10815),
+    (0x9C57, 10814),
+    (0x9C58, 18707),
+    (0x9C59, 18713),
+    (0x9C5E, 18803),
+    (0x9C5F, 10846),
+    (0x9C60, 18804),
+    (0x9C62, 18802),
+    (0x9C63, 18799),
+    (0x9C66, 18801),
+    (0x9C67, 18800),
+    (0x9C68, 18866),
+    (0x9C6D, 18868),
+    (0x9C6E, 18867),
+    (0x9C71, 18897),
+    (0x9C73, 18896),
+    (0x9C74, 18895),
+    (0x9C75, 18898),
+    (0x9C77, 10884),
+    (0x9C78, 10885),
+    (0x9C79, 18944),
+    (0x9C7A, 18948),
+    (0x9CE5, 7942),
+    (0x9CE6, 14364),
+    (0x9CE7, 15054),
+    (0x9CE9, 8861),
+    (0x9CEA, 15052),
+    (0x9CED, 15053),
+    (0x9CF1, 15671),
+    (0x9CF2, 15672),
+    (0x9CF3, 9249),
+    (0x9CF4, 9247),
+    (0x9CF5, 15673),
+    (0x9CF6, 9248),
+    (0x9CF7, 16305),
+    (0x9CF9, 16308),
+    (0x9CFA, 16302),
+    (0x9CFB, 16309),
+    (0x9CFC, 16301),
+    (0x9CFD, 16303),
+    (0x9CFF, 16304),
+    (0x9D00, 16307),
+    (0x9D03, 9640),
+    (0x9D04, 16312),
+    (0x9D05, 16311),
+    (0x9D06, 9638),
+    (0x9D07, 16306),
+    (0x9D08, 16310),
+    (0x9D09, 9639),
+    (0x9D10, 16873),
+    (0x9D12, 9938),
+    (0x9D14, 16868),
+    (0x9D15, 9934),
+    (0x9D17, 16865),
+    (0x9D18, 16871),
+    (0x9D19, 16874),
+    (0x9D1B, 9939),
+    (0x9D1D, 16870),
+    (0x9D1E, 16867),
+    (0x9D1F, 16875),
+    (0x9D20, 16866),
+    (0x9D22, 16872),
+    (0x9D23, 9935),
+    (0x9D25, 16864),
+    (0x9D26, 9936),
+    (0x9D28, 9937),
+    (0x9D29, 16869),
+    (0x9D2D, 17341),
+    (0x9D2E, 17328),
+    (0x9D2F, 17329),
+    (0x9D30, 17332),
+    (0x9D31, 17330),
+    (0x9D33, 17324),
+    (0x9D34, 19060),
+    (0x9D36, 17327),
+    (0x9D37, 17337),
+    (0x9D38, 17331),
+    (0x9D3B, 10210),
+    (0x9D3D, 17339),
+    (0x9D3E, 17336),
+    (0x9D3F, 10211),
+    (0x9D40, 17338),
+    (0x9D41, 17325),
+    (0x9D42, 17334),
+    (0x9D43, 17335),
+    (0x9D45, 17333),
+    (0x9D4A, 17696),
+    (0x9D4B, 17698),
+    (0x9D4C, 17701),
+    (0x9D4F, 17695),
+    (0x9D51, 10387),
+    (0x9D52, 17703),
+    (0x9D53, 17694),
+    (0x9D54, 17704),
+    (0x9D56, 17700),
+    (0x9D57, 17702),
+    (0x9D58, 17706),
+    (0x9D59, 17699),
+    (0x9D5A, 17707),
+    (0x9D5B, 17697),
+    (0x9D5C, 17693),
+    (0x9D5D, 10388),
+    (0x9D5F, 17705),
+    (0x9D60, 10389),
+    (0x9D61, 10532),
+    (0x9D67, 17326),
+    (0x9D68, 18041),
+    (0x9D69, 18032),
+    (0x9D6A, 10534),
+    (0x9D6B, 18028),
+    (0x9D6C, 10535),
+    (0x9D6F, 18037),
+    (0x9D70, 18031),
+    (0x9D71, 18021),
+    (0x9D72, 10533),
+    (0x9D73, 18034),
+    (0x9D74, 18029),
+    (0x9D75, 18030),
+    (0x9D77, 18016),
+    (0x9D78, 18023),
+    (0x9D79, 18038),
+    (0x9D7B, 18035),
+    (0x9D7D, 18027),
+    (0x9D7F, 18039),
+    (0x9D80, 18022),
+    (0x9D81, 18017),
+    (0x9D82, 18036),
+    (0x9D84, 18019),
+    (0x9D85, 18033),
+    (0x9D86, 18024),
+    (0x9D87, 18040),
+    (0x9D88, 18020),
+    (0x9D89, 10531),
+    (0x9D8A, 18018),
+    (0x9D8B, 18025),
+    (0x9D8C, 18026),
+    (0x9D90, 18273),
+    (0x9D92, 18271),
+    (0x9D94, 18276),
+    (0x9D96, 18288),
+    (0x9D97, 18279),
+    (0x9D98, 18272),
+    (0x9D99, 18268),
+    (0x9D9A, 18281),
+    (0x9D9B, 18274),
+    (0x9D9C, 18277),
+    (0x9D9D, 18270),
+    (0x9D9E, 18284),
+    (0x9D9F, 18267),
+    (0x9DA0, 18275),
+    (0x9DA1, 18280),
+    (0x9DA2, 18282),
+    (0x9DA3, 18285),
+    (0x9DA4, 18269),
+    (0x9DA6, 18289),
+    (0x9DA7, 18290),
+    (0x9DA8, 18283),
+    (0x9DA9, 18287),
+    (0x9DAA, 18278),
+    (0x9DAC, 18464),
+    (0x9DAD, 18467),
+    (0x9DAF, 10709),
+    (0x9DB1, 18466),
+    (0x9DB2, 18471),
+    (0x9DB3, 18469),
+    (0x9DB4, 10710),
+    (0x9DB5, 18460),
+    (0x9DB6, 18450),
+    (0x9DB7, 18449),
+    (0x9DB8, 10712),
+    (0x9DB9, 18462),
+    (0x9DBA, 18463),
+    (0x9DBB, 18459),
+    (0x9DBC, 18451),
+    (0x9DBE, 18456),
+    (0x9DBF, 18286),
+    (0x9DC1, 18452),
+    (0x9DC2, 10711),
+    (0x9DC3, 18458),
+    (0x9DC5, 18457),
+    (0x9DC7, 18453),
+    (0x9DC8, 18465),
+    (0x9DCA, 18454),
+    (0x9DCB, 18615),
+    (0x9DCC, 18468),
+    (0x9DCD, 18470),
+    (0x9DCE, 18461),
+    (0x9DCF, 18455),
+    (0x9DD0, 18616),
+    (0x9DD1, 18618),
+    (0x9DD2, 18612),
+    (0x9DD3, 10772),
+    (0x9DD5, 18625),
+    (0x9DD6, 18623),
+    (0x9DD7, 10773),
+    (0x9DD8, 18622),
+    (0x9DD9, 18621),
+    (0x9DDA, 18614),
+    (0x9DDB, 18611),
+    (0x9DDC, 18617),
+    (0x9DDD, 18626),
+    (0x9DDE, 18613),
+    (0x9DDF, 18619),
+    (0x9DE1, 18724),
+    (0x9DE2, 18729),
+    (0x9DE3, 18719),
+    (0x9DE4, 18722),
+    (0x9DE5, 10816),
+    (0x9DE6, 18726),
+    (0x9DE8, 18733),
+    (0x9DE9, 18620),
+    (0x9DEB, 18720),
+    (0x9DEC, 18730),
+    (0x9DED, 18734),
+    (0x9DEE, 18725),
+    (0x9DEF, 18718),
+    (0x9DF0, 18728),
+    (0x9DF2, 18727),
+    (0x9DF3, 18732),
+    (0x9DF4, 18731),
+    (0x9DF5, 18624),
+    (0x9DF6, 18723),
+    (0x9DF7, 18717),
+    (0x9DF8, 18721),
+    (0x9DF9, 10847),
+    (0x9DFA, 10848),
+    (0x9DFB, 18716),
+    (0x9DFD, 18815),
+    (0x9DFE, 18806),
+    (0x9DFF, 18814),
+    (0x9E00, 18811),
+    (0x9E01, 18812),
+    (0x9E02, 18805),
+    (0x9E03, 18808),
+    (0x9E04, 18816),
+    (0x9E05, 18810),
+    (0x9E06, 18809),
+    (0x9E07, 18807),
+    (0x9E09, 18813),
+    (0x9E0B, 18869),
+    (0x9E0D, 18870),
+    (0x9E0F, 18872),
+    (0x9E10, 18871),
+    (0x9E11, 18874),
+    (0x9E12, 18873),
+    (0x9E13, 18900),
+    (0x9E14, 18899),
+    (0x9E15, 18925),
+    (0x9E17, 18926),
+    (0x9E19, 18939),
+    (0x9E1A, 10889),
+    (0x9E1B, 10893),
+    (0x9E1D, 18949),
+    (0x9E1E, 10894),
+    (0x9E75, 7943),
+    (0x9E79, 10629),
+    (0x9E7A, 18472),
+    (0x9E7C, 10849),
+    (0x9E7D, 10850),
+    (0x9E7F, 7944),
+    (0x9E80, 15055),
+    (0x9E82, 8862),
+    (0x9E83, 16313),
+    (0x9E86, 16877),
+    (0x9E87, 16878),
+    (0x9E88, 16876),
+    (0x9E89, 17343),
+    (0x9E8A, 17342),
+    (0x9E8B, 10212),
+    (0x9E8C, 17709),
+    (0x9E8D, 17344),
+    (0x9E8E, 17708),
+    (0x9E91, 18043),
+    (0x9E92, 10536),
+    (0x9E93, 10538),
+    (0x9E94, 18042),
+    (0x9E97, 10537),
+    (0x9E99, 18291),
+    (0x9E9A, 18293),
+    (0x9E9B, 18292),
+    (0x9E9C, 18473),
+    (0x9E9D, 10713),
+    (0x9E9F, 10817),
+    (0x9EA0, 18817),
+    (0x9EA1, 18875),
+    (0x9EA4, 18952),
+    (0x9EA5, 7945),
+    (0x9EA7, 15674),
+    (0x9EA9, 9641),
+    (0x9EAD, 16880),
+    (0x9EAE, 16879),
+    (0x9EB0, 17345),
+    (0x9EB4, 10539),
+    (0x9EB5, 10630),
+    (0x9EB6, 18627),
+    (0x9EB7, 18945),
+    (0x9EBB, 7946),
+    (0x9EBC, 9250),
+    (0x9EBE, 9642),
+    (0x9EC0, 18044),
+    (0x9EC2, 18735),
+    (0x9EC3, 8415),
+    (0x9EC8, 17346),
+    (0x9ECC, 10869),
+    (0x9ECD, 8416),
+    (0x9ECE, 9643),
+    (0x9ECF, 10213),
+    (0x9ED0, 18736),
+    (0x9ED1, 8417),
+    (0x9ED3, 16314),
+    (0x9ED4, 9941),
+    (0x9ED5, 16881),
+    (0x9ED6, 16882),
+    (0x9ED8, 9940),
+    (0x9EDA, 17347),
+    (0x9EDB, 10217),
+    (0x9EDC, 10215),
+    (0x9EDD, 10216),
+    (0x9EDE, 10214),
+    (0x9EDF, 17710),
+    (0x9EE0, 10390),
+    (0x9EE4, 18295),
+    (0x9EE5, 18294),
+    (0x9EE6, 18297),
+    (0x9EE7, 18296),
+    (0x9EE8, 10631),
+    (0x9EEB, 18474),
+    (0x9EED, 18476),
+    (0x9EEE, 18475),
+    (0x9EEF, 10714),
+    (0x9EF0, 18628),
+    (0x9EF2, 18737),
+    (0x9EF3, 18738),
+    (0x9EF4, 10818),
+    (0x9EF5, 18876),
+    (0x9EF6, 18901),
+    (0x9EF7, 10886),
+    (0x9EF9, 14365),
+    (0x9EFA, 16883),
+    (0x9EFB, 17348),
+    (0x9EFC, 18045),
+    (0x9EFD, 15056),
+    (0x9EFF, 17349),
+    (0x9F00, 17712),
+    (0x9F01, 17711),
+    (0x9F06, 18739),
+    (0x9F07, 10851),
+    (0x9F09, 18877),
+    (0x9F0A, 18902),
+    (0x9F0E, 8863),
+    (0x9F0F, 16315),
+    (0x9F10, 16316),
+    (0x9F12, 16884),
+    (0x9F13, 8864),
+    (0x9F15, 10391),
+    (0x9F16, 17713),
+    (0x9F18, 18478),
+    (0x9F19, 10715),
+    (0x9F1A, 18479),
+    (0x9F1B, 18477),
+    (0x9F1C, 18740),
+    (0x9F1E, 18818),
+    (0x9F20, 8865),
+    (0x9F22, 17352),
+    (0x9F23, 17351),
+    (0x9F24, 17350),
+    (0x9F25, 17714),
+    (0x9F28, 17718),
+    (0x9F29, 17717),
+    (0x9F2A, 17716),
+    (0x9F2B, 17715),
+    (0x9F2C, 10392),
+    (0x9F2D, 18046),
+    (0x9F2E, 18299),
+    (0x9F2F, 10632),
+    (0x9F30, 18298),
+    (0x9F31, 18480),
+    (0x9F32, 18631),
+    (0x9F33, 18630),
+    (0x9F34, 10774),
+    (0x9F35, 18629),
+    (0x9F36, 18743),
+    (0x9F37, 18742),
+    (0x9F38, 18741),
+    (0x9F3B, 9251),
+    (0x9F3D, 16885),
+    (0x9F3E, 10218),
+    (0x9F40, 18047),
+    (0x9F41, 18048),
+    (0x9F42, 18632),
+    (0x9F43, 18744),
+    (0x9F46, 18819),
+    (0x9F47, 18878),
+    (0x9F48, 18927),
+    (0x9F49, 18954),
+    (0x9F4A, 9252),
+    (0x9F4B, 10219),
+    (0x9F4C, 17719),
+    (0x9F4D, 18049),
+    (0x9F4E, 18481),
+    (0x9F4F, 18745),
+    (0x9F52, 9645),
+    (0x9F54, 17353),
+    (0x9F55, 17720),
+    (0x9F56, 18050),
+    (0x9F57, 18051),
+    (0x9F58, 18052),
+    (0x9F59, 18304),
+    (0x9F5B, 18300),
+    (0x9F5C, 10716),
+    (0x9F5D, 18303),
+    (0x9F5E, 18302),
+    (0x9F5F, 10633),
+    (0x9F60, 18301),
+    (0x9F61, 10635),
+    (0x9F63, 10634),
+    (0x9F64, 18483),
+    (0x9F65, 18482),
+    (0x9F66, 10717),
+    (0x9F67, 10718),
+    (0x9F6A, 10776),
+    (0x9F6B, 18633),
+    (0x9F6C, 10775),
+    (0x9F6E, 18748),
+    (0x9F6F, 18749),
+    (0x9F70, 18747),
+    (0x9F71, 18746),
+    (0x9F72, 10853),
+    (0x9F74, 18820),
+    (0x9F75, 18821),
+    (0x9F76, 18822),
+    (0x9F77, 10852),
+    (0x9F78, 18879),
+    (0x9F79, 18882),
+    (0x9F7A, 18881),
+    (0x9F7B, 18880),
+    (0x9F7E, 18953),
+    (0x9F8D, 9942),
+    (0x9F90, 10399),
+    (0x9F91, 18305),
+    (0x9F92, 18484),
+    (0x9F94, 10777),
+    (0x9F95, 18634),
+    (0x9F98, 18955),
+    (0x9F9C, 9943),
+    (0x9FA0, 17354),
+    (0x9FA2, 18635),
+    (0x9FA4, 18903),
+    (0x9FB0, 11210),
+    (0x9FB1, 11212),
+    (0xFA0C, 11314),
+    (0xFA0D, 14598),
+    (0xFE30, 5034),
+    (0xFE31, 5047),
+    (0xFE33, 5049),
+    (0xFE34, 5051),
+    (0xFE35, 5055),
+    (0xFE36, 5056),
+    (0xFE37, 5059),
+    (0xFE38, 5060),
+    (0xFE39, 5063),
+    (0xFE3A, 5064),
+    (0xFE3B, 5067),
+    (0xFE3C, 5068),
+    (0xFE3D, 5071),
+    (0xFE3E, 5072),
+    (0xFE3F, 5075),
+    (0xFE40, 5076),
+    (0xFE41, 5079),
+    (0xFE42, 5080),
+    (0xFE43, 5083),
+    (0xFE44, 5084),
+    (0xFE49, 5124),
+    (0xFE4A, 5125),
+    (0xFE4B, 5128),
+    (0xFE4C, 5129),
+    (0xFE4D, 5126),
+    (0xFE4E, 5127),
+    (0xFE4F, 5052),
+    (0xFE50, 5037),
+    (0xFE51, 5038),
+    (0xFE52, 5039),
+    (0xFE54, 5041),
+    (0xFE55, 5042),
+    (0xFE56, 5043),
+    (0xFE57, 5044),
+    (0xFE59, 5085),
+    (0xFE5A, 5086),
+    (0xFE5B, 5087),
+    (0xFE5C, 5088),
+    (0xFE5D, 5089),
+    (0xFE5E, 5090),
+    (0xFE5F, 5130),
+    (0xFE60, 5131),
+    (0xFE61, 5132),
+    (0xFE62, 5148),
+    (0xFE63, 5149),
+    (0xFE64, 5150),
+    (0xFE65, 5151),
+    (0xFE66, 5152),
+    (0xFE68, 5183),
+    (0xFE69, 5193),
+    (0xFE6A, 5194),
+    (0xFE6B, 5195),
+    (0xFF01, 5033),
+    (0xFF02, 11257),
+    (0xFF03, 5099),
+    (0xFF04, 5184),
+    (0xFF05, 5189),
+    (0xFF06, 5100),
+    (0xFF07, 11256),
+    (0xFF08, 5053),
+    (0xFF09, 5054),
+    (0xFF0A, 5101),
+    (0xFF0B, 5133),
+    (0xFF0C, 5025),
+    (0xFF0D, 5134),
+    (0xFF0E, 5028),
+    (0xFF0F, 5180),
+    (0xFF10, 5258),
+    (0xFF11, 5259),
+    (0xFF12, 5260),
+    (0xFF13, 5261),
+    (0xFF14, 5262),
+    (0xFF15, 5263),
+    (0xFF16, 5264),
+    (0xFF17, 5265),
+    (0xFF18, 5266),
+    (0xFF19, 5267),
+    (0xFF1A, 5031),
+    (0xFF1B, 5030),
+    (0xFF1C, 5139),
+    (0xFF1D, 5141),
+    (0xFF1E, 5140),
+    (0xFF1F, 5032),
+    (0xFF20, 5190),
+    (0xFF21, 5290),
+    (0xFF22, 5291),
+    (0xFF23, 5292),
+    (0xFF24, 5293),
+    (0xFF25, 5294),
+    (0xFF26, 5295),
+    (0xFF27, 5296),
+    (0xFF28, 5297),
+    (0xFF29, 5298),
+    (0xFF2A, 5299),
+    (0xFF2B, 5300),
+    (0xFF2C, 5301),
+    (0xFF2D, 5302),
+    (0xFF2E, 5303),
+    (0xFF2F, 5304),
+    (0xFF30, 5305),
+    (0xFF31, 5306),
+    (0xFF32, 5307),
+    (0xFF33, 5308),
+    (0xFF34, 5309),
+    (0xFF35, 5310),
+    (0xFF36, 5311),
+    (0xFF37, 5312),
+    (0xFF38, 5313),
+    (0xFF39, 5314),
+    (0xFF3A, 5315),
+    (0xFF3B, 10963),
+    (0xFF3C, 5181),
+    (0xFF3D, 10964),
+    (0xFF3F, 5122),
+    (0xFF41, 5316),
+    (0xFF42, 5317),
+    (0xFF43, 5318),
+    (0xFF44, 5319),
+    (0xFF45, 5320),
+    (0xFF46, 5321),
+    (0xFF47, 5322),
+    (0xFF48, 5323),
+    (0xFF49, 5324),
+    (0xFF4A, 5325),
+    (0xFF4B, 5326),
+    (0xFF4C, 5327),
+    (0xFF4D, 5328),
+    (0xFF4E, 5329),
+    (0xFF4F, 5330),
+    (0xFF50, 5331),
+    (0xFF51, 5332),
+    (0xFF52, 5333),
+    (0xFF53, 5334),
+    (0xFF54, 5335),
+    (0xFF55, 5336),
+    (0xFF56, 5337),
+    (0xFF57, 5338),
+    (0xFF58, 5339),
+    (0xFF59, 5340),
+    (0xFF5A, 5341),
+    (0xFF5B, 5057),
+    (0xFF5C, 5045),
+    (0xFF5D, 5058),
+    (0xFF5E, 5153),
+    (0xFFE0, 5187),
+    (0xFFE1, 5188),
+    (0xFFE2, 11254),
+    (0xFFE3, 5121),
+    (0xFFE4, 11255),
+    (0xFFE5, 5185),
+    (0xFFED, 18996),
+    (0x2008A, 11207),
+    (0x200CC, 11205),
+    (0x20118, 19386),
+    (0x201C1, 19318),
+    (0x20204, 19259),
+    (0x2029E, 19342),
+    (0x202A0, 19017),
+    (0x202E5, 19049),
+    (0x20347, 19032),
+    (0x2037E, 19029),
+    (0x20380, 19031),
+    (0x203B5, 19510),
+    (0x203C9, 19488),
+    (0x2041F, 19034),
+    (0x20487, 19043),
+    (0x20547, 18997),
+    (0x2058E, 19051),
+    (0x205A5, 19264),
+    (0x205B3, 19056),
+    (0x205C3, 19670),
+    (0x205DF, 18999),
+    (0x20656, 19370),
+    (0x20923, 19503),
+    (0x20954, 19093),
+    (0x20B8F, 19132),
+    (0x20BE2, 19097),
+    (0x20BFF, 19047),
+    (0x20C3A, 19653),
+    (0x20C77, 19137),
+    (0x20CD3, 19302),
+    (0x20D32, 19534),
+    (0x20D4D, 19153),
+    (0x20D4E, 19223),
+    (0x20D74, 19145),
+    (0x20E75, 19435),
+    (0x20EDD, 19151),
+    (0x210B4, 19168),
+    (0x2123C, 19205),
+    (0x2124F, 19185),
+    (0x21305, 19019),
+    (0x2133A, 19322),
+    (0x21376, 19749),
+    (0x2138E, 19396),
+    (0x21416, 19194),
+    (0x21454, 19196),
+    (0x21497, 19570),
+    (0x21596, 19113),
+    (0x21613, 19114),
+    (0x2163E, 19217),
+    (0x21692, 19220),
+    (0x216B8, 19250),
+    (0x216C1, 19746),
+    (0x216D5, 19233),
+    (0x216E7, 19658),
+    (0x216E8, 19105),
+    (0x2170D, 19150),
+    (0x21726, 19099),
+    (0x21770, 19388),
+    (0x21771, 19307),
+    (0x217B2, 19229),
+    (0x217DC, 19226),
+    (0x217FB, 19228),
+    (0x217FC, 19397),
+    (0x21828, 19232),
+    (0x21829, 19423),
+    (0x21877, 19346),
+    (0x2189E, 19693),
+    (0x218D7, 19163),
+    (0x21911, 19248),
+    (0x21958, 19511),
+    (0x21983, 19053),
+    (0x21988, 19695),
+    (0x219DB, 19009),
+    (0x21A34, 19262),
+    (0x21A4B, 19700),
+    (0x21B44, 19269),
+    (0x21CA5, 19273),
+    (0x21DF9, 19281),
+    (0x21E1C, 19735),
+    (0x21E37, 19282),
+    (0x21E3D, 19440),
+    (0x21EA4, 19286),
+    (0x21F6A, 19338),
+    (0x22049, 19292),
+    (0x220FC, 19048),
+    (0x2212A, 19493),
+    (0x22173, 19293),
+    (0x223D0, 19325),
+    (0x22471, 19327),
+    (0x2248B, 19519),
+    (0x22491, 19537),
+    (0x224ED, 19021),
+    (0x22513, 19027),
+    (0x22530, 19050),
+    (0x22554, 19246),
+    (0x2261C, 19766),
+    (0x22668, 19340),
+    (0x22698, 19155),
+    (0x227B4, 19351),
+    (0x227CD, 19507),
+    (0x2285F, 19374),
+    (0x228AD, 19673),
+    (0x228C1, 19381),
+    (0x228F7, 19446),
+    (0x22980, 19380),
+    (0x22DA3, 19135),
+    (0x22E0D, 19390),
+    (0x22E36, 19400),
+    (0x23044, 19639),
+    (0x2304B, 19564),
+    (0x2307E, 19417),
+    (0x231A4, 19432),
+    (0x231A5, 19433),
+    (0x231C8, 19443),
+    (0x231F7, 19429),
+    (0x231F8, 19430),
+    (0x231F9, 19536),
+    (0x23231, 19441),
+    (0x23233, 19052),
+    (0x2328A, 19171),
+    (0x232AC, 19742),
+    (0x233B4, 19045),
+    (0x233E6, 19465),
+    (0x23400, 19427),
+    (0x2346F, 19468),
+    (0x235CD, 19474),
+    (0x23600, 19025),
+    (0x2363C, 19479),
+    (0x23659, 19492),
+    (0x23703, 19495),
+    (0x2372D, 19073),
+    (0x237C2, 19453),
+    (0x23ADB, 19509),
+    (0x23B5A, 19514),
+    (0x23C99, 19057),
+    (0x23CC8, 19011),
+    (0x23CC9, 19531),
+    (0x23DBC, 19525),
+    (0x23DBD, 19527),
+    (0x23E2F, 19558),
+    (0x23E30, 19568),
+    (0x23EFC, 19516),
+    (0x23F80, 19092),
+    (0x23F82, 19560),
+    (0x23FB4, 19587),
+    (0x23FB7, 19373),
+    (0x23FC5, 19000),
+    (0x23FEB, 19134),
+    (0x23FEC, 19094),
+    (0x23FF0, 19586),
+    (0x24039, 19585),
+    (0x2403A, 19164),
+    (0x2403D, 19571),
+    (0x24085, 19548),
+    (0x2408C, 19402),
+    (0x24091, 19583),
+    (0x2413F, 19588),
+    (0x24140, 19592),
+    (0x24144, 19581),
+    (0x24155, 19584),
+    (0x24156, 19590),
+    (0x24157, 19591),
+    (0x2415F, 19688),
+    (0x241A4, 19528),
+    (0x241A5, 19187),
+    (0x241CD, 19457),
+    (0x241E2, 19657),
+    (0x24277, 19606),
+    (0x24278, 19210),
+    (0x242C1, 19616),
+    (0x24363, 19197),
+    (0x24365, 19613),
+    (0x2439C, 19449),
+    (0x24435, 19166),
+    (0x2445A, 19618),
+    (0x244BC, 19301),
+    (0x24823, 19645),
+    (0x248F1, 19520),
+    (0x24900, 19530),
+    (0x24901, 19291),
+    (0x2490C, 19529),
+    (0x24933, 19015),
+    (0x24940, 19419),
+    (0x24942, 19006),
+    (0x24974, 19634),
+    (0x2497F, 19547),
+    (0x24982, 19357),
+    (0x2498D, 19663),
+    (0x2498F, 19358),
+    (0x249A4, 19042),
+    (0x249A9, 19239),
+    (0x249B7, 19662),
+    (0x249DF, 19392),
+    (0x249FB, 19664),
+    (0x24A12, 19750),
+    (0x24A21, 19450),
+    (0x24A22, 19620),
+    (0x24A23, 19413),
+    (0x24A24, 19659),
+    (0x24A26, 19668),
+    (0x24A27, 19619),
+    (0x24A28, 19412),
+    (0x24A79, 19689),
+    (0x24A8C, 19687),
+    (0x24AA6, 19066),
+    (0x24AB1, 19026),
+    (0x24BF5, 19199),
+    (0x24D13, 19710),
+    (0x24F82, 19708),
+    (0x2509D, 19733),
+    (0x2517D, 19734),
+    (0x251E6, 19437),
+    (0x25220, 19740),
+    (0x25250, 19337),
+    (0x25313, 19444),
+    (0x255E0, 19757),
+    (0x25605, 19631),
+    (0x2571D, 19403),
+    (0x25772, 19122),
+    (0x25857, 19771),
+    (0x259CC, 19263),
+    (0x26083, 19765),
+    (0x261DD, 19593),
+    (0x263F5, 19721),
+    (0x26888, 19324),
+    (0x2690E, 19726),
+    (0x26911, 19725),
+    (0x26A2E, 19654),
+    (0x26B13, 19087),
+    (0x26B28, 19274),
+    (0x26B51, 19521),
+    (0x26B53, 19144),
+    (0x26BB3, 19018),
+    (0x26C7E, 19192),
+    (0x26C81, 19224),
+    (0x26D25, 19540),
+    (0x26D29, 19023),
+    (0x26D2A, 19225),
+    (0x26DA0, 19466),
+    (0x26DA1, 19541),
+    (0x26DA6, 19230),
+    (0x26DEB, 19542),
+    (0x26E45, 19235),
+    (0x26E6E, 19330),
+    (0x26E72, 19549),
+    (0x26ED2, 19367),
+    (0x26F74, 19551),
+    (0x26F9F, 19731),
+    (0x26FA1, 19245),
+    (0x270F8, 19339),
+    (0x27126, 19777),
+    (0x27607, 11213),
+    (0x2760C, 19761),
+    (0x27A0E, 19256),
+    (0x27A53, 19359),
+    (0x27A84, 19024),
+    (0x27B39, 19773),
+    (0x28482, 19310),
+    (0x285F4, 19442),
+    (0x286AB, 19110),
+    (0x28804, 19648),
+    (0x2882B, 19646),
+    (0x28964, 19744),
+    (0x28968, 19745),
+    (0x289AA, 19016),
+    (0x289BC, 19154),
+    (0x289E4, 19007),
+    (0x28A29, 19343),
+    (0x28A36, 19333),
+    (0x28A4A, 19483),
+    (0x28A9B, 19117),
+    (0x28ADE, 19089),
+    (0x28ADF, 19471),
+    (0x28B21, 19556),
+    (0x28B2B, 19764),
+    (0x28B66, 19183),
+    (0x28B8F, 19690),
+    (0x28B9D, 19376),
+    (0x28BE8, 19622),
+    (0x28C03, 19692),
+    (0x28EAC, 19702),
+    (0x28EB2, 19675),
+    (0x28ED9, 19699),
+    (0x290ED, 19458),
+    (0x2910D, 19780),
+    (0x29170, 19578),
+    (0x2919C, 19500),
+    (0x2995D, 19377),
+    (0x2A150, 19209),
+    (0x2F894, 19319),
+    (0x2F8A6, 19366),
+];
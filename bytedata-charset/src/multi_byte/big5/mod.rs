@@ -1,13 +1,17 @@
 mod big5_cp;
+mod cp_big5;
 
-/// BIG5 encoding. A legacy encoding for simplified Chinese characters.
+/// Big5 encoding. A legacy encoding for traditional Chinese characters.
+///
+/// This follows the WHATWG definition of Big5, which includes the Hong Kong Supplementary Character Set (HKSCS) extensions.
+/// Four of the HKSCS byte sequences decode into a pair of code points, a base letter followed by a combining diacritic, see [`DecodeResult::CharPair`].
+/// As in the WHATWG specification, the HKSCS extensions are only used when decoding.
 ///
 /// Use [`UTF-8`] or [`UTF-16`] instead if possible.
-/// (GB18030 is an encoding backward compatible with BIG5 but support a much larger mapping against Unicode.)
 ///
 /// [`UTF-8`]: crate::Utf8Encoding
 /// [`UTF-16`]: crate::Utf16Encoding
-/// [`GB18030`]: crate::multi_byte::Gb18030Encoding
+/// [`DecodeResult::CharPair`]: crate::DecodeResult::CharPair
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
 #[non_exhaustive]
 #[repr(transparent)]
@@ -33,30 +37,40 @@ impl Big5Encoding {
         if bytes.is_empty() {
             return crate::DecodeResult::Empty;
         }
-        match core::str::from_utf8(bytes) {
-            Ok(st) => crate::DecodeResult::Utf8(st.len() as u64),
-            Err(err) => {
-                let vp = err.valid_up_to();
-                if vp != 0 {
-                    return crate::DecodeResult::Utf8(vp as u64);
-                }
-                if err.error_len().is_some() {
-                    crate::DecodeResult::InvalidChar(bytes[0] as u32, 1)
-                } else {
-                    crate::DecodeResult::Incomplete
+        let byt = bytes[0];
+        if byt < 0x80 {
+            let mut i = 1;
+            while i < bytes.len() {
+                if bytes[i] & 0b1000_0000 == 0b1000_0000 {
+                    break;
                 }
+                i += 1;
             }
+            return crate::DecodeResult::Utf8(i as u64);
         }
+        decode_const_inner(bytes)
     }
 
     /// Encode a BIG5 character sequence.
     #[inline]
     #[must_use]
     pub const fn encode_const(&self, chars: &str) -> crate::EncodeResult {
-        if chars.is_empty() {
+        let bytes = chars.as_bytes();
+        if bytes.is_empty() {
             return crate::EncodeResult::Empty;
         }
-        crate::EncodeResult::Utf8(chars.len() as u64)
+        let byt = bytes[0];
+        if byt < 0x80 {
+            let mut i = 1;
+            while i < bytes.len() {
+                if bytes[i] & 0b1000_0000 == 0b1000_0000 {
+                    break;
+                }
+                i += 1;
+            }
+            return crate::EncodeResult::Utf8(i as u64);
+        }
+        encode_const_inner(bytes)
     }
 
     /// Detect if the given bytes are BIG5 encoded.
@@ -117,8 +131,152 @@ impl crate::CharsetEncoding for Big5Encoding {
     }
 }
 
-const fn detect_const_inner(bytes: &[u8]) -> crate::detect::DetectionResult {
-    crate::detect::DetectionResult::Incomplete // TODO: implement
+/// Detects Big5 by validating the byte structure and that every double-byte sequence is mapped in the index.
+const fn detect_const_inner(mut bytes: &[u8]) -> crate::detect::DetectionResult {
+    const MIN_LEN: usize = 4;
+    let mut offset = 0;
+    loop {
+        if bytes.is_empty() {
+            if offset < MIN_LEN {
+                return crate::detect::DetectionResult::Incomplete;
+            }
+            return crate::detect::DetectionResult::Tentative;
+        }
+        let b0 = bytes[0];
+        if b0 < 0x80 {
+            bytes = bytedata::const_or_bytes(bytedata::const_slice(bytes, 1..bytes.len()), b"");
+            offset += 1;
+            continue;
+        }
+        match decode_const_inner(bytes) {
+            crate::DecodeResult::Char(_, len) | crate::DecodeResult::CharPair(_, _, len) => {
+                let len = len as usize;
+                offset += len;
+                bytes =
+                    bytedata::const_or_bytes(bytedata::const_slice(bytes, len..bytes.len()), b"");
+            }
+            crate::DecodeResult::InvalidChar(_, _) => {
+                return crate::detect::DetectionResult::Irrelevant;
+            }
+            crate::DecodeResult::Utf8(_)
+            | crate::DecodeResult::Empty
+            | crate::DecodeResult::Incomplete => {
+                if offset < MIN_LEN {
+                    return crate::detect::DetectionResult::Incomplete;
+                }
+                return crate::detect::DetectionResult::Tentative;
+            }
+        }
+    }
+}
+
+/// Decodes a Big5 byte sequence. This should not be called directly without first validating the fist byte isn't ASCII.
+#[inline]
+#[expect(clippy::missing_asserts_for_indexing)]
+const fn decode_const_inner(bytes: &[u8]) -> crate::DecodeResult {
+    let lead = bytes[0];
+    if lead < 0x81 || lead == 0xFF {
+        return crate::DecodeResult::InvalidChar(lead as u32, 1);
+    }
+    if bytes.len() < 2 {
+        return crate::DecodeResult::Incomplete;
+    }
+    let trail = bytes[1];
+    if (trail >= 0x40 && trail <= 0x7E) || (trail >= 0xA1 && trail <= 0xFE) {
+        let offset = if trail < 0x7F { 0x40 } else { 0x62 };
+        let ptr = (lead as usize - 0x81) * 157 + trail as usize - offset; // 0 - 19_781
+        match ptr {
+            1133 => return crate::DecodeResult::CharPair('\u{00CA}', '\u{0304}', 2),
+            1135 => return crate::DecodeResult::CharPair('\u{00CA}', '\u{030C}', 2),
+            1164 => return crate::DecodeResult::CharPair('\u{00EA}', '\u{0304}', 2),
+            1166 => return crate::DecodeResult::CharPair('\u{00EA}', '\u{030C}', 2),
+            _ => {}
+        }
+        if ptr >= 942 {
+            let ch = big5_cp::BIG5_CP[ptr - 942];
+            if ch != '\0' {
+                return crate::DecodeResult::Char(ch, 2);
+            }
+        }
+    }
+    if trail < 0x80 {
+        // an ASCII trail byte is not consumed by an invalid sequence
+        return crate::DecodeResult::InvalidChar(lead as u32, 1);
+    }
+    crate::DecodeResult::InvalidChar(((lead as u32) << 8_i32) | trail as u32, 2)
+}
+
+#[inline]
+const fn encode_const_inner(mut bytes: &[u8]) -> crate::EncodeResult {
+    let mut chunk = [0_u8; 14];
+    let mut chunk_len = 0_usize;
+    let mut consumed = 0_usize;
+    loop {
+        let (ch, ch_len) = bytedata::const_utf8_char_next(bytes);
+        if ch_len == 0 || ch < 0x80 {
+            // ASCII is left for the next call to return as a UTF-8 compatible run
+            break;
+        }
+        let Some(ptr) = encode_ptr(ch) else {
+            if chunk_len != 0 {
+                break;
+            }
+            let Some(ch) = char::from_u32(ch) else {
+                unreachable!();
+            };
+            #[expect(clippy::cast_possible_truncation)]
+            return crate::EncodeResult::InvalidChar(ch, ch_len as u16);
+        };
+        #[expect(clippy::integer_division)]
+        let lead = ptr / 157 + 0x81;
+        let trail = ptr % 157;
+        let offset = if trail < 0x3F { 0x40 } else { 0x62 };
+        #[expect(clippy::cast_possible_truncation)]
+        {
+            chunk[chunk_len] = lead as u8;
+            chunk[chunk_len + 1] = (trail + offset) as u8;
+        };
+        chunk_len += 2;
+        consumed += ch_len as usize;
+        if chunk_len > 10 {
+            break;
+        }
+        bytes = bytedata::const_or_bytes(
+            bytedata::const_slice(bytes, (ch_len as usize)..bytes.len()),
+            b"",
+        );
+    }
+    if chunk_len == 0 {
+        return crate::EncodeResult::Empty;
+    }
+    #[expect(clippy::cast_possible_truncation)]
+    crate::EncodeResult::Chunk(
+        bytedata::ByteChunk::from_slice(bytedata::const_or_bytes(
+            bytedata::const_slice(&chunk, 0..chunk_len),
+            b"",
+        )),
+        consumed as u16,
+    )
+}
+
+/// Find the Big5 pointer used to encode a code point.
+const fn encode_ptr(cp: u32) -> Option<u16> {
+    let arr = &cp_big5::BIG5_CP_TO_PTR;
+    let mut base = 0;
+    let mut lim = arr.len();
+    while base < lim {
+        #[expect(clippy::integer_division)]
+        let mid = base + (lim - base) / 2;
+        let (mid_cp, mid_ptr) = arr[mid];
+        if mid_cp < cp {
+            base = mid + 1;
+        } else if mid_cp > cp {
+            lim = mid;
+        } else {
+            return Some(mid_ptr);
+        }
+    }
+    None
 }
 
 #[cfg(test)]
@@ -170,3 +328,162 @@ fn gen_index() {
         }
     }
 }
+
+#[cfg(test)]
+#[test]
+#[ignore]
+fn gen_reverse_index() {
+    use std::io::{BufRead, Write};
+    let infile = "src/multi_byte/big5/index.big5.txt";
+    let infile = match std::fs::File::open(&infile) {
+        Ok(file) => file,
+        Err(err) => panic!("{err:?} for file {infile:?}"),
+    };
+    let infile = std::io::BufReader::new(infile);
+    let outfile = "src/multi_byte/big5/cp_big5_gen.rs";
+    let mut outfile = match std::fs::File::create(outfile) {
+        Ok(file) => file,
+        Err(err) => panic!("{err:?} for file {outfile:?}"),
+    };
+    // the encoder excludes the HKSCS extensions and uses the last pointer for some duplicated code points
+    let mut index = std::collections::BTreeMap::new();
+    for line in infile.lines() {
+        let line = line.unwrap();
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let mut line = line.split_whitespace();
+        let pointer = line.next().unwrap();
+        let codepoint = line.next().unwrap();
+        assert_eq!(&codepoint[..2], "0x");
+        let pointer = u16::from_str_radix(pointer, 10).unwrap();
+        let codepoint = u32::from_str_radix(&codepoint[2..], 16).unwrap();
+        if pointer < (0xA1 - 0x81) * 157 {
+            continue;
+        }
+        let use_last = matches!(
+            codepoint,
+            0x2550 | 0x255E | 0x2561 | 0x256A | 0x5341 | 0x5345
+        );
+        if use_last || !index.contains_key(&codepoint) {
+            index.insert(codepoint, pointer);
+        }
+    }
+    for (codepoint, pointer) in index {
+        write!(outfile, "    (0x{codepoint:04X}, {pointer}),\n").unwrap();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_big5_decode() {
+        assert_eq!(
+            BIG5.decode_const(b"ab\xA4\x40"),
+            crate::DecodeResult::Utf8(2)
+        );
+        assert_eq!(
+            BIG5.decode_const(b"\xA4\x40"),
+            crate::DecodeResult::Char('\u{4E00}', 2)
+        );
+        assert_eq!(
+            BIG5.decode_const(b"\x87\x40"),
+            crate::DecodeResult::Char('\u{43F0}', 2)
+        );
+        assert_eq!(
+            BIG5.decode_const(b"\xFE\xFE"),
+            crate::DecodeResult::Char('\u{79D4}', 2)
+        );
+        assert_eq!(
+            BIG5.decode_const(b"\x88\x62"),
+            crate::DecodeResult::CharPair('\u{00CA}', '\u{0304}', 2)
+        );
+        assert_eq!(
+            BIG5.decode_const(b"\x88\x64"),
+            crate::DecodeResult::CharPair('\u{00CA}', '\u{030C}', 2)
+        );
+        assert_eq!(
+            BIG5.decode_const(b"\x88\xA3"),
+            crate::DecodeResult::CharPair('\u{00EA}', '\u{0304}', 2)
+        );
+        assert_eq!(
+            BIG5.decode_const(b"\x88\xA5"),
+            crate::DecodeResult::CharPair('\u{00EA}', '\u{030C}', 2)
+        );
+        assert_eq!(BIG5.decode_const(b"\xA4"), crate::DecodeResult::Incomplete);
+        assert_eq!(
+            BIG5.decode_const(b"\x80"),
+            crate::DecodeResult::InvalidChar(0x80, 1)
+        );
+        assert_eq!(
+            BIG5.decode_const(b"\x81\x41"),
+            crate::DecodeResult::InvalidChar(0x81, 1)
+        );
+        assert_eq!(
+            BIG5.decode_const(b"\x81\xA1"),
+            crate::DecodeResult::InvalidChar(0x81A1, 2)
+        );
+    }
+
+    #[test]
+    fn test_big5_encode() {
+        assert_eq!(
+            BIG5.encode_const("ab\u{4E00}"),
+            crate::EncodeResult::Utf8(2)
+        );
+        assert_eq!(
+            BIG5.encode_const("\u{4E00}\u{4E59}a"),
+            crate::EncodeResult::Chunk(bytedata::ByteChunk::from_slice(b"\xA4\x40\xA4\x41"), 6)
+        );
+        // the HKSCS extensions are not used for encoding
+        assert_eq!(
+            BIG5.encode_const("\u{43F0}"),
+            crate::EncodeResult::InvalidChar('\u{43F0}', 3)
+        );
+        // duplicated code points encode to the last pointer
+        assert_eq!(
+            BIG5.encode_const("\u{2550}\u{5341}"),
+            crate::EncodeResult::Chunk(bytedata::ByteChunk::from_slice(b"\xF9\xF9\xA4\x51"), 6)
+        );
+    }
+
+    #[test]
+    fn test_big5_roundtrip() {
+        for (i, &ch) in big5_cp::BIG5_CP.iter().enumerate() {
+            let ptr = i + 942;
+            if ch == '\0' || ptr < (0xA1 - 0x81) * 157 {
+                continue;
+            }
+            let mut buf = [0_u8; 4];
+            let crate::EncodeResult::Chunk(chunk, _) = BIG5.encode_const(ch.encode_utf8(&mut buf))
+            else {
+                panic!("failed to encode {ch:?}");
+            };
+            assert_eq!(
+                BIG5.decode_const(chunk.as_slice()),
+                crate::DecodeResult::Char(ch, 2),
+                "roundtrip of pointer {ptr}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_big5_detect() {
+        use crate::detect::DetectionResult;
+        assert_eq!(
+            Big5Encoding::detect_const("\u{7E41}\u{9AD4}\u{4E2D}\u{6587}".as_bytes()),
+            DetectionResult::Irrelevant
+        );
+        assert_eq!(
+            Big5Encoding::detect_const(b"\xC1\x63\xC5\xE9\xA4\xA4\xA4\xE5"),
+            DetectionResult::Tentative
+        );
+        assert_eq!(
+            Big5Encoding::detect_const(b"\xC1"),
+            DetectionResult::Incomplete
+        );
+    }
+}
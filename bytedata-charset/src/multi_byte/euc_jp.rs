@@ -0,0 +1,379 @@
+/// EUC-JP encoding. A legacy encoding for Japanese characters.
+///
+/// This follows the WHATWG definition of EUC-JP, which decodes JIS X 0208, half-width katakana and JIS X 0212 but only encodes using the former two.
+///
+/// Use [`UTF-8`] or [`UTF-16`] instead if possible.
+///
+/// [`UTF-8`]: crate::Utf8Encoding
+/// [`UTF-16`]: crate::Utf16Encoding
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+#[non_exhaustive]
+#[repr(transparent)]
+#[cfg_attr(docsrs, doc(cfg(feature = "euc-jp")))]
+pub struct EucJpEncoding;
+
+/// EUC-JP encoding.
+#[cfg_attr(docsrs, doc(cfg(feature = "euc-jp")))]
+pub static EUC_JP: EucJpEncoding = EucJpEncoding::new();
+
+impl EucJpEncoding {
+    /// Create a new EUC-JP encoding instance.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self
+    }
+
+    /// Decode an EUC-JP byte sequence.
+    #[inline]
+    #[must_use]
+    pub const fn decode_const(&self, bytes: &[u8]) -> crate::DecodeResult {
+        if bytes.is_empty() {
+            return crate::DecodeResult::Empty;
+        }
+        let byt = bytes[0];
+        if byt < 0x80 {
+            let mut i = 1;
+            while i < bytes.len() {
+                if bytes[i] & 0b1000_0000 == 0b1000_0000 {
+                    break;
+                }
+                i += 1;
+            }
+            return crate::DecodeResult::Utf8(i as u64);
+        }
+        decode_const_inner(bytes)
+    }
+
+    /// Encode an EUC-JP character sequence.
+    #[inline]
+    #[must_use]
+    pub const fn encode_const(&self, chars: &str) -> crate::EncodeResult {
+        let bytes = chars.as_bytes();
+        if bytes.is_empty() {
+            return crate::EncodeResult::Empty;
+        }
+        let byt = bytes[0];
+        if byt < 0x80 {
+            let mut i = 1;
+            while i < bytes.len() {
+                if bytes[i] & 0b1000_0000 == 0b1000_0000 {
+                    break;
+                }
+                i += 1;
+            }
+            return crate::EncodeResult::Utf8(i as u64);
+        }
+        encode_const_inner(bytes)
+    }
+
+    /// Detect if the given bytes are EUC-JP encoded.
+    ///
+    /// Only the byte structure is validated, so Chinese and Korean texts encoded using GB18030, GBK or EUC-KR are often detected as tentative EUC-JP too.
+    #[inline]
+    #[must_use]
+    pub const fn detect_const(bytes: &[u8]) -> crate::detect::DetectionResult {
+        if bytes.is_empty() {
+            return crate::detect::DetectionResult::Incomplete;
+        }
+        detect_const_inner(bytes)
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "euc-jp")))]
+impl crate::Charset for EucJpEncoding {
+    const CHARSET_NAME: &'static str = "euc-jp";
+
+    #[inline]
+    fn size_hint(&self) -> (u16, u16) {
+        (1, 3)
+    }
+
+    #[inline]
+    fn charset_alias(&self) -> &[&'static str] {
+        &[
+            // IANA
+            Self::CHARSET_NAME,
+            "cseucpkdfmtjapanese",
+            // code pages
+            "cp51932",
+            "windows-51932",
+            // other
+            "x-euc-jp",
+        ]
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "euc-jp")))]
+impl crate::detect::CharsetDetector for EucJpEncoding {
+    #[inline]
+    fn detect(&self, bytes: &[u8]) -> crate::detect::DetectionResult {
+        Self::detect_const(bytes)
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "euc-jp")))]
+impl crate::CharsetDecoding for EucJpEncoding {
+    #[inline]
+    fn decode(&self, bytes: &[u8]) -> crate::DecodeResult {
+        self.decode_const(bytes)
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "euc-jp")))]
+impl crate::CharsetEncoding for EucJpEncoding {
+    #[inline]
+    fn encode(&self, chars: &str) -> crate::EncodeResult {
+        self.encode_const(chars)
+    }
+}
+
+/// Detects EUC-JP by validating the byte structure.
+const fn detect_const_inner(mut bytes: &[u8]) -> crate::detect::DetectionResult {
+    const MIN_LEN: usize = 4;
+    let mut offset = 0;
+    loop {
+        if bytes.is_empty() {
+            break;
+        }
+        let b0 = bytes[0];
+        if b0 < 0x80 {
+            bytes = bytedata::const_or_bytes(bytedata::const_slice(bytes, 1..bytes.len()), b"");
+            offset += 1;
+            continue;
+        }
+        match decode_const_inner(bytes) {
+            crate::DecodeResult::Char(_, len) => {
+                let len = len as usize;
+                offset += len;
+                bytes =
+                    bytedata::const_or_bytes(bytedata::const_slice(bytes, len..bytes.len()), b"");
+            }
+            crate::DecodeResult::InvalidChar(_, _) | crate::DecodeResult::CharPair(_, _, _) => {
+                return crate::detect::DetectionResult::Irrelevant;
+            }
+            crate::DecodeResult::Utf8(_)
+            | crate::DecodeResult::Empty
+            | crate::DecodeResult::Incomplete => break,
+        }
+    }
+    if offset < MIN_LEN {
+        return crate::detect::DetectionResult::Incomplete;
+    }
+    crate::detect::DetectionResult::Tentative
+}
+
+/// The result of an invalid sequence where `trail` is the last byte inspected. An ASCII byte is not consumed by an invalid sequence.
+const fn invalid_trail(prefix: u32, len: u32, trail: u8) -> crate::DecodeResult {
+    if trail < 0x80 {
+        return crate::DecodeResult::InvalidChar(prefix, len);
+    }
+    crate::DecodeResult::InvalidChar((prefix << 8_i32) | trail as u32, len + 1)
+}
+
+/// Decodes an EUC-JP byte sequence. This should not be called directly without first validating the fist byte isn't ASCII.
+#[inline]
+#[expect(clippy::missing_asserts_for_indexing)]
+const fn decode_const_inner(bytes: &[u8]) -> crate::DecodeResult {
+    let lead = bytes[0];
+    if lead == 0x8E {
+        // half-width katakana
+        if bytes.len() < 2 {
+            return crate::DecodeResult::Incomplete;
+        }
+        let byte = bytes[1];
+        if byte >= 0xA1 && byte <= 0xDF {
+            let Some(ch) = char::from_u32(0xFF61 - 0xA1 + byte as u32) else {
+                unreachable!();
+            };
+            return crate::DecodeResult::Char(ch, 2);
+        }
+        return invalid_trail(lead as u32, 1, byte);
+    }
+    if lead == 0x8F {
+        // JIS X 0212
+        if bytes.len() < 2 {
+            return crate::DecodeResult::Incomplete;
+        }
+        let second = bytes[1];
+        if second < 0xA1 || second > 0xFE {
+            return invalid_trail(lead as u32, 1, second);
+        }
+        if bytes.len() < 3 {
+            return crate::DecodeResult::Incomplete;
+        }
+        let third = bytes[2];
+        if third >= 0xA1 && third <= 0xFE {
+            let ptr = (second as usize - 0xA1) * 94 + third as usize - 0xA1;
+            if let Some(ch) = super::jis0212::decode_ptr(ptr) {
+                return crate::DecodeResult::Char(ch, 3);
+            }
+        }
+        return invalid_trail(((lead as u32) << 8_i32) | second as u32, 2, third);
+    }
+    if lead < 0xA1 || lead > 0xFE {
+        return crate::DecodeResult::InvalidChar(lead as u32, 1);
+    }
+    if bytes.len() < 2 {
+        return crate::DecodeResult::Incomplete;
+    }
+    let trail = bytes[1];
+    if trail >= 0xA1 && trail <= 0xFE {
+        let ptr = (lead as usize - 0xA1) * 94 + trail as usize - 0xA1; // 0 - 8_835
+        if let Some(ch) = super::jis0208::decode_ptr(ptr) {
+            return crate::DecodeResult::Char(ch, 2);
+        }
+    }
+    invalid_trail(lead as u32, 1, trail)
+}
+
+#[inline]
+const fn encode_const_inner(mut bytes: &[u8]) -> crate::EncodeResult {
+    let mut chunk = [0_u8; 14];
+    let mut chunk_len = 0_usize;
+    let mut consumed = 0_usize;
+    loop {
+        let (ch, ch_len) = bytedata::const_utf8_char_next(bytes);
+        if ch_len == 0 || ch < 0x80 {
+            // ASCII is left for the next call to return as a UTF-8 compatible run
+            break;
+        }
+        if ch == 0xA5 || ch == 0x203E {
+            chunk[chunk_len] = if ch == 0xA5 { 0x5C } else { 0x7E };
+            chunk_len += 1;
+        } else if ch >= 0xFF61 && ch <= 0xFF9F {
+            chunk[chunk_len] = 0x8E;
+            #[expect(clippy::cast_possible_truncation)]
+            {
+                chunk[chunk_len + 1] = (ch - 0xFF61 + 0xA1) as u8;
+            };
+            chunk_len += 2;
+        } else {
+            let cp = if ch == 0x2212 { 0xFF0D } else { ch };
+            let Some(ptr) = super::jis0208::encode_ptr(cp) else {
+                if chunk_len != 0 {
+                    break;
+                }
+                let Some(ch) = char::from_u32(ch) else {
+                    unreachable!();
+                };
+                #[expect(clippy::cast_possible_truncation)]
+                return crate::EncodeResult::InvalidChar(ch, ch_len as u16);
+            };
+            #[expect(clippy::cast_possible_truncation, clippy::integer_division)]
+            {
+                chunk[chunk_len] = (ptr / 94 + 0xA1) as u8;
+                chunk[chunk_len + 1] = (ptr % 94 + 0xA1) as u8;
+            };
+            chunk_len += 2;
+        }
+        consumed += ch_len as usize;
+        if chunk_len > 10 {
+            break;
+        }
+        bytes = bytedata::const_or_bytes(
+            bytedata::const_slice(bytes, (ch_len as usize)..bytes.len()),
+            b"",
+        );
+    }
+    if chunk_len == 0 {
+        return crate::EncodeResult::Empty;
+    }
+    #[expect(clippy::cast_possible_truncation)]
+    crate::EncodeResult::Chunk(
+        bytedata::ByteChunk::from_slice(bytedata::const_or_bytes(
+            bytedata::const_slice(&chunk, 0..chunk_len),
+            b"",
+        )),
+        consumed as u16,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_euc_jp_decode() {
+        assert_eq!(
+            EUC_JP.decode_const(b"ab\xA4\xA2"),
+            crate::DecodeResult::Utf8(2)
+        );
+        assert_eq!(
+            EUC_JP.decode_const(b"\xA4\xA2"),
+            crate::DecodeResult::Char('\u{3042}', 2)
+        );
+        assert_eq!(
+            EUC_JP.decode_const(b"\x8E\xB1"),
+            crate::DecodeResult::Char('\u{FF71}', 2)
+        );
+        assert_eq!(
+            EUC_JP.decode_const(b"\x8F\xB0\xA1"),
+            crate::DecodeResult::Char('\u{4E02}', 3)
+        );
+        assert_eq!(
+            EUC_JP.decode_const(b"\x8F\xB0"),
+            crate::DecodeResult::Incomplete
+        );
+        assert_eq!(
+            EUC_JP.decode_const(b"\x8F\xA1\xA1"),
+            crate::DecodeResult::InvalidChar(0x008F_A1A1, 3)
+        );
+        assert_eq!(
+            EUC_JP.decode_const(b"\x8F\xA1a"),
+            crate::DecodeResult::InvalidChar(0x8FA1, 2)
+        );
+        assert_eq!(
+            EUC_JP.decode_const(b"\x8E\xE0"),
+            crate::DecodeResult::InvalidChar(0x8EE0, 2)
+        );
+        assert_eq!(
+            EUC_JP.decode_const(b"\xA4a"),
+            crate::DecodeResult::InvalidChar(0xA4, 1)
+        );
+    }
+
+    #[test]
+    fn test_euc_jp_encode() {
+        assert_eq!(
+            EUC_JP.encode_const("\u{65E5}\u{672C}\u{8A9E}a"),
+            crate::EncodeResult::Chunk(
+                bytedata::ByteChunk::from_slice(b"\xC6\xFC\xCB\xDC\xB8\xEC"),
+                9
+            )
+        );
+        assert_eq!(
+            EUC_JP.encode_const("\u{A5}\u{FF71}\u{2212}"),
+            crate::EncodeResult::Chunk(bytedata::ByteChunk::from_slice(b"\x5C\x8E\xB1\xA1\xDD"), 8)
+        );
+        // JIS X 0212 is not used for encoding
+        assert_eq!(
+            EUC_JP.encode_const("\u{4E02}"),
+            crate::EncodeResult::InvalidChar('\u{4E02}', 3)
+        );
+    }
+
+    #[test]
+    fn test_euc_jp_detect() {
+        use crate::detect::DetectionResult;
+        // "にほんごのぶんしょうです" in EUC-JP
+        assert_eq!(
+            EucJpEncoding::detect_const(
+                b"\xA4\xCB\xA4\xDB\xA4\xF3\xA4\xB4\xA4\xCE\xA4\xD6\xA4\xF3\xA4\xB7\xA4\xE7\xA4\xA6\xA4\xC7\xA4\xB9"
+            ),
+            DetectionResult::Tentative
+        );
+        // "日本国憲法第九条" in EUC-JP, kanji-heavy texts such as headlines do not need any kana
+        assert_eq!(
+            EucJpEncoding::detect_const(
+                b"\xC6\xFC\xCB\xDC\xB9\xF1\xB7\xFB\xCB\xA1\xC2\xE8\xB6\xE5\xBE\xF2"
+            ),
+            DetectionResult::Tentative
+        );
+        // a lead byte of 0xFF is never valid
+        assert_eq!(
+            EucJpEncoding::detect_const(b"\xA4\xCB\xFF\xA4\xDB"),
+            DetectionResult::Irrelevant
+        );
+    }
+}
@@ -0,0 +1,23755 @@
+/// Based on <https://encoding.spec.whatwg.org/index-euc-kr.txt>.
+/// - Identifier: 1d97134cbf187263585bc8f593ca4196654ed4c7a673f5672eaad4f5d9fdc4ba
+/// - Date: 2014-12-19
+pub(super) static EUC_KR_PTR_TO_CP: [u16; 23750] = [
+    0xAC02, // 0
+    0xAC03, // 1
+    0xAC05, // 2
+    0xAC06, // 3
+    0xAC0B, // 4
+    0xAC0C, // 5
+    0xAC0D, // 6
+    0xAC0E, // 7
+    0xAC0F, // 8
+    0xAC18, // 9
+    0xAC1E, // 10
+    0xAC1F, // 11
+    0xAC21, // 12
+    0xAC22, // 13
+    0xAC23, // 14
+    0xAC25, // 15
+    0xAC26, // 16
+    0xAC27, // 17
+    0xAC28, // 18
+    0xAC29, // 19
+    0xAC2A, // 20
+    0xAC2B, // 21
+    0xAC2E, // 22
+    0xAC32, // 23
+    0xAC33, // 24
+    0xAC34, // 25
+    0x0000, // 26
+    0x0000, // 27
+    0x0000, // 28
+    0x0000, // 29
+    0x0000, // 30
+    0x0000, // 31
+    0xAC35, // 32
+    0xAC36, // 33
+    0xAC37, // 34
+    0xAC3A, // 35
+    0xAC3B, // 36
+    0xAC3D, // 37
+    0xAC3E, // 38
+    0xAC3F, // 39
+    0xAC41, // 40
+    0xAC42, // 41
+    0xAC43, // 42
+    0xAC44, // 43
+    0xAC45, // 44
+    0xAC46, // 45
+    0xAC47, // 46
+    0xAC48, // 47
+    0xAC49, // 48
+    0xAC4A, // 49
+    0xAC4C, // 50
+    0xAC4E, // 51
+    0xAC4F, // 52
+    0xAC50, // 53
+    0xAC51, // 54
+    0xAC52, // 55
+    0xAC53, // 56
+    0xAC55, // 57
+    0x0000, // 58
+    0x0000, // 59
+    0x0000, // 60
+    0x0000, // 61
+    0x0000, // 62
+    0x0000, // 63
+    0xAC56, // 64
+    0xAC57, // 65
+    0xAC59, // 66
+    0xAC5A, // 67
+    0xAC5B, // 68
+    0xAC5D, // 69
+    0xAC5E, // 70
+    0xAC5F, // 71
+    0xAC60, // 72
+    0xAC61, // 73
+    0xAC62, // 74
+    0xAC63, // 75
+    0xAC64, // 76
+    0xAC65, // 77
+    0xAC66, // 78
+    0xAC67, // 79
+    0xAC68, // 80
+    0xAC69, // 81
+    0xAC6A, // 82
+    0xAC6B, // 83
+    0xAC6C, // 84
+    0xAC6D, // 85
+    0xAC6E, // 86
+    0xAC6F, // 87
+    0xAC72, // 88
+    0xAC73, // 89
+    0xAC75, // 90
+    0xAC76, // 91
+    0xAC79, // 92
+    0xAC7B, // 93
+    0xAC7C, // 94
+    0xAC7D, // 95
+    0xAC7E, // 96
+    0xAC7F, // 97
+    0xAC82, // 98
+    0xAC87, // 99
+    0xAC88, // 100
+    0xAC8D, // 101
+    0xAC8E, // 102
+    0xAC8F, // 103
+    0xAC91, // 104
+    0xAC92, // 105
+    0xAC93, // 106
+    0xAC95, // 107
+    0xAC96, // 108
+    0xAC97, // 109
+    0xAC98, // 110
+    0xAC99, // 111
+    0xAC9A, // 112
+    0xAC9B, // 113
+    0xAC9E, // 114
+    0xACA2, // 115
+    0xACA3, // 116
+    0xACA4, // 117
+    0xACA5, // 118
+    0xACA6, // 119
+    0xACA7, // 120
+    0xACAB, // 121
+    0xACAD, // 122
+    0xACAE, // 123
+    0xACB1, // 124
+    0xACB2, // 125
+    0xACB3, // 126
+    0xACB4, // 127
+    0xACB5, // 128
+    0xACB6, // 129
+    0xACB7, // 130
+    0xACBA, // 131
+    0xACBE, // 132
+    0xACBF, // 133
+    0xACC0, // 134
+    0xACC2, // 135
+    0xACC3, // 136
+    0xACC5, // 137
+    0xACC6, // 138
+    0xACC7, // 139
+    0xACC9, // 140
+    0xACCA, // 141
+    0xACCB, // 142
+    0xACCD, // 143
+    0xACCE, // 144
+    0xACCF, // 145
+    0xACD0, // 146
+    0xACD1, // 147
+    0xACD2, // 148
+    0xACD3, // 149
+    0xACD4, // 150
+    0xACD6, // 151
+    0xACD8, // 152
+    0xACD9, // 153
+    0xACDA, // 154
+    0xACDB, // 155
+    0xACDC, // 156
+    0xACDD, // 157
+    0xACDE, // 158
+    0xACDF, // 159
+    0xACE2, // 160
+    0xACE3, // 161
+    0xACE5, // 162
+    0xACE6, // 163
+    0xACE9, // 164
+    0xACEB, // 165
+    0xACED, // 166
+    0xACEE, // 167
+    0xACF2, // 168
+    0xACF4, // 169
+    0xACF7, // 170
+    0xACF8, // 171
+    0xACF9, // 172
+    0xACFA, // 173
+    0xACFB, // 174
+    0xACFE, // 175
+    0xACFF, // 176
+    0xAD01, // 177
+    0xAD02, // 178
+    0xAD03, // 179
+    0xAD05, // 180
+    0xAD07, // 181
+    0xAD08, // 182
+    0xAD09, // 183
+    0xAD0A, // 184
+    0xAD0B, // 185
+    0xAD0E, // 186
+    0xAD10, // 187
+    0xAD12, // 188
+    0xAD13, // 189
+    0xAD14, // 190
+    0xAD15, // 191
+    0xAD16, // 192
+    0xAD17, // 193
+    0xAD19, // 194
+    0xAD1A, // 195
+    0xAD1B, // 196
+    0xAD1D, // 197
+    0xAD1E, // 198
+    0xAD1F, // 199
+    0xAD21, // 200
+    0xAD22, // 201
+    0xAD23, // 202
+    0xAD24, // 203
+    0xAD25, // 204
+    0xAD26, // 205
+    0xAD27, // 206
+    0xAD28, // 207
+    0xAD2A, // 208
+    0xAD2B, // 209
+    0xAD2E, // 210
+    0xAD2F, // 211
+    0xAD30, // 212
+    0xAD31, // 213
+    0xAD32, // 214
+    0xAD33, // 215
+    0x0000, // 216
+    0x0000, // 217
+    0x0000, // 218
+    0x0000, // 219
+    0x0000, // 220
+    0x0000, // 221
+    0xAD36, // 222
+    0xAD37, // 223
+    0xAD39, // 224
+    0xAD3A, // 225
+    0xAD3B, // 226
+    0xAD3D, // 227
+    0xAD3E, // 228
+    0xAD3F, // 229
+    0xAD40, // 230
+    0xAD41, // 231
+    0xAD42, // 232
+    0xAD43, // 233
+    0xAD46, // 234
+    0xAD48, // 235
+    0xAD4A, // 236
+    0xAD4B, // 237
+    0xAD4C, // 238
+    0xAD4D, // 239
+    0xAD4E, // 240
+    0xAD4F, // 241
+    0xAD51, // 242
+    0xAD52, // 243
+    0xAD53, // 244
+    0xAD55, // 245
+    0xAD56, // 246
+    0xAD57, // 247
+    0x0000, // 248
+    0x0000, // 249
+    0x0000, // 250
+    0x0000, // 251
+    0x0000, // 252
+    0x0000, // 253
+    0xAD59, // 254
+    0xAD5A, // 255
+    0xAD5B, // 256
+    0xAD5C, // 257
+    0xAD5D, // 258
+    0xAD5E, // 259
+    0xAD5F, // 260
+    0xAD60, // 261
+    0xAD62, // 262
+    0xAD64, // 263
+    0xAD65, // 264
+    0xAD66, // 265
+    0xAD67, // 266
+    0xAD68, // 267
+    0xAD69, // 268
+    0xAD6A, // 269
+    0xAD6B, // 270
+    0xAD6E, // 271
+    0xAD6F, // 272
+    0xAD71, // 273
+    0xAD72, // 274
+    0xAD77, // 275
+    0xAD78, // 276
+    0xAD79, // 277
+    0xAD7A, // 278
+    0xAD7E, // 279
+    0xAD80, // 280
+    0xAD83, // 281
+    0xAD84, // 282
+    0xAD85, // 283
+    0xAD86, // 284
+    0xAD87, // 285
+    0xAD8A, // 286
+    0xAD8B, // 287
+    0xAD8D, // 288
+    0xAD8E, // 289
+    0xAD8F, // 290
+    0xAD91, // 291
+    0xAD92, // 292
+    0xAD93, // 293
+    0xAD94, // 294
+    0xAD95, // 295
+    0xAD96, // 296
+    0xAD97, // 297
+    0xAD98, // 298
+    0xAD99, // 299
+    0xAD9A, // 300
+    0xAD9B, // 301
+    0xAD9E, // 302
+    0xAD9F, // 303
+    0xADA0, // 304
+    0xADA1, // 305
+    0xADA2, // 306
+    0xADA3, // 307
+    0xADA5, // 308
+    0xADA6, // 309
+    0xADA7, // 310
+    0xADA8, // 311
+    0xADA9, // 312
+    0xADAA, // 313
+    0xADAB, // 314
+    0xADAC, // 315
+    0xADAD, // 316
+    0xADAE, // 317
+    0xADAF, // 318
+    0xADB0, // 319
+    0xADB1, // 320
+    0xADB2, // 321
+    0xADB3, // 322
+    0xADB4, // 323
+    0xADB5, // 324
+    0xADB6, // 325
+    0xADB8, // 326
+    0xADB9, // 327
+    0xADBA, // 328
+    0xADBB, // 329
+    0xADBC, // 330
+    0xADBD, // 331
+    0xADBE, // 332
+    0xADBF, // 333
+    0xADC2, // 334
+    0xADC3, // 335
+    0xADC5, // 336
+    0xADC6, // 337
+    0xADC7, // 338
+    0xADC9, // 339
+    0xADCA, // 340
+    0xADCB, // 341
+    0xADCC, // 342
+    0xADCD, // 343
+    0xADCE, // 344
+    0xADCF, // 345
+    0xADD2, // 346
+    0xADD4, // 347
+    0xADD5, // 348
+    0xADD6, // 349
+    0xADD7, // 350
+    0xADD8, // 351
+    0xADD9, // 352
+    0xADDA, // 353
+    0xADDB, // 354
+    0xADDD, // 355
+    0xADDE, // 356
+    0xADDF, // 357
+    0xADE1, // 358
+    0xADE2, // 359
+    0xADE3, // 360
+    0xADE5, // 361
+    0xADE6, // 362
+    0xADE7, // 363
+    0xADE8, // 364
+    0xADE9, // 365
+    0xADEA, // 366
+    0xADEB, // 367
+    0xADEC, // 368
+    0xADED, // 369
+    0xADEE, // 370
+    0xADEF, // 371
+    0xADF0, // 372
+    0xADF1, // 373
+    0xADF2, // 374
+    0xADF3, // 375
+    0xADF4, // 376
+    0xADF5, // 377
+    0xADF6, // 378
+    0xADF7, // 379
+    0xADFA, // 380
+    0xADFB, // 381
+    0xADFD, // 382
+    0xADFE, // 383
+    0xAE02, // 384
+    0xAE03, // 385
+    0xAE04, // 386
+    0xAE05, // 387
+    0xAE06, // 388
+    0xAE07, // 389
+    0xAE0A, // 390
+    0xAE0C, // 391
+    0xAE0E, // 392
+    0xAE0F, // 393
+    0xAE10, // 394
+    0xAE11, // 395
+    0xAE12, // 396
+    0xAE13, // 397
+    0xAE15, // 398
+    0xAE16, // 399
+    0xAE17, // 400
+    0xAE18, // 401
+    0xAE19, // 402
+    0xAE1A, // 403
+    0xAE1B, // 404
+    0xAE1C, // 405
+    0x0000, // 406
+    0x0000, // 407
+    0x0000, // 408
+    0x0000, // 409
+    0x0000, // 410
+    0x0000, // 411
+    0xAE1D, // 412
+    0xAE1E, // 413
+    0xAE1F, // 414
+    0xAE20, // 415
+    0xAE21, // 416
+    0xAE22, // 417
+    0xAE23, // 418
+    0xAE24, // 419
+    0xAE25, // 420
+    0xAE26, // 421
+    0xAE27, // 422
+    0xAE28, // 423
+    0xAE29, // 424
+    0xAE2A, // 425
+    0xAE2B, // 426
+    0xAE2C, // 427
+    0xAE2D, // 428
+    0xAE2E, // 429
+    0xAE2F, // 430
+    0xAE32, // 431
+    0xAE33, // 432
+    0xAE35, // 433
+    0xAE36, // 434
+    0xAE39, // 435
+    0xAE3B, // 436
+    0xAE3C, // 437
+    0x0000, // 438
+    0x0000, // 439
+    0x0000, // 440
+    0x0000, // 441
+    0x0000, // 442
+    0x0000, // 443
+    0xAE3D, // 444
+    0xAE3E, // 445
+    0xAE3F, // 446
+    0xAE42, // 447
+    0xAE44, // 448
+    0xAE47, // 449
+    0xAE48, // 450
+    0xAE49, // 451
+    0xAE4B, // 452
+    0xAE4F, // 453
+    0xAE51, // 454
+    0xAE52, // 455
+    0xAE53, // 456
+    0xAE55, // 457
+    0xAE57, // 458
+    0xAE58, // 459
+    0xAE59, // 460
+    0xAE5A, // 461
+    0xAE5B, // 462
+    0xAE5E, // 463
+    0xAE62, // 464
+    0xAE63, // 465
+    0xAE64, // 466
+    0xAE66, // 467
+    0xAE67, // 468
+    0xAE6A, // 469
+    0xAE6B, // 470
+    0xAE6D, // 471
+    0xAE6E, // 472
+    0xAE6F, // 473
+    0xAE71, // 474
+    0xAE72, // 475
+    0xAE73, // 476
+    0xAE74, // 477
+    0xAE75, // 478
+    0xAE76, // 479
+    0xAE77, // 480
+    0xAE7A, // 481
+    0xAE7E, // 482
+    0xAE7F, // 483
+    0xAE80, // 484
+    0xAE81, // 485
+    0xAE82, // 486
+    0xAE83, // 487
+    0xAE86, // 488
+    0xAE87, // 489
+    0xAE88, // 490
+    0xAE89, // 491
+    0xAE8A, // 492
+    0xAE8B, // 493
+    0xAE8D, // 494
+    0xAE8E, // 495
+    0xAE8F, // 496
+    0xAE90, // 497
+    0xAE91, // 498
+    0xAE92, // 499
+    0xAE93, // 500
+    0xAE94, // 501
+    0xAE95, // 502
+    0xAE96, // 503
+    0xAE97, // 504
+    0xAE98, // 505
+    0xAE99, // 506
+    0xAE9A, // 507
+    0xAE9B, // 508
+    0xAE9C, // 509
+    0xAE9D, // 510
+    0xAE9E, // 511
+    0xAE9F, // 512
+    0xAEA0, // 513
+    0xAEA1, // 514
+    0xAEA2, // 515
+    0xAEA3, // 516
+    0xAEA4, // 517
+    0xAEA5, // 518
+    0xAEA6, // 519
+    0xAEA7, // 520
+    0xAEA8, // 521
+    0xAEA9, // 522
+    0xAEAA, // 523
+    0xAEAB, // 524
+    0xAEAC, // 525
+    0xAEAD, // 526
+    0xAEAE, // 527
+    0xAEAF, // 528
+    0xAEB0, // 529
+    0xAEB1, // 530
+    0xAEB2, // 531
+    0xAEB3, // 532
+    0xAEB4, // 533
+    0xAEB5, // 534
+    0xAEB6, // 535
+    0xAEB7, // 536
+    0xAEB8, // 537
+    0xAEB9, // 538
+    0xAEBA, // 539
+    0xAEBB, // 540
+    0xAEBF, // 541
+    0xAEC1, // 542
+    0xAEC2, // 543
+    0xAEC3, // 544
+    0xAEC5, // 545
+    0xAEC6, // 546
+    0xAEC7, // 547
+    0xAEC8, // 548
+    0xAEC9, // 549
+    0xAECA, // 550
+    0xAECB, // 551
+    0xAECE, // 552
+    0xAED2, // 553
+    0xAED3, // 554
+    0xAED4, // 555
+    0xAED5, // 556
+    0xAED6, // 557
+    0xAED7, // 558
+    0xAEDA, // 559
+    0xAEDB, // 560
+    0xAEDD, // 561
+    0xAEDE, // 562
+    0xAEDF, // 563
+    0xAEE0, // 564
+    0xAEE1, // 565
+    0xAEE2, // 566
+    0xAEE3, // 567
+    0xAEE4, // 568
+    0xAEE5, // 569
+    0xAEE6, // 570
+    0xAEE7, // 571
+    0xAEE9, // 572
+    0xAEEA, // 573
+    0xAEEC, // 574
+    0xAEEE, // 575
+    0xAEEF, // 576
+    0xAEF0, // 577
+    0xAEF1, // 578
+    0xAEF2, // 579
+    0xAEF3, // 580
+    0xAEF5, // 581
+    0xAEF6, // 582
+    0xAEF7, // 583
+    0xAEF9, // 584
+    0xAEFA, // 585
+    0xAEFB, // 586
+    0xAEFD, // 587
+    0xAEFE, // 588
+    0xAEFF, // 589
+    0xAF00, // 590
+    0xAF01, // 591
+    0xAF02, // 592
+    0xAF03, // 593
+    0xAF04, // 594
+    0xAF05, // 595
+    0x0000, // 596
+    0x0000, // 597
+    0x0000, // 598
+    0x0000, // 599
+    0x0000, // 600
+    0x0000, // 601
+    0xAF06, // 602
+    0xAF09, // 603
+    0xAF0A, // 604
+    0xAF0B, // 605
+    0xAF0C, // 606
+    0xAF0E, // 607
+    0xAF0F, // 608
+    0xAF11, // 609
+    0xAF12, // 610
+    0xAF13, // 611
+    0xAF14, // 612
+    0xAF15, // 613
+    0xAF16, // 614
+    0xAF17, // 615
+    0xAF18, // 616
+    0xAF19, // 617
+    0xAF1A, // 618
+    0xAF1B, // 619
+    0xAF1C, // 620
+    0xAF1D, // 621
+    0xAF1E, // 622
+    0xAF1F, // 623
+    0xAF20, // 624
+    0xAF21, // 625
+    0xAF22, // 626
+    0xAF23, // 627
+    0x0000, // 628
+    0x0000, // 629
+    0x0000, // 630
+    0x0000, // 631
+    0x0000, // 632
+    0x0000, // 633
+    0xAF24, // 634
+    0xAF25, // 635
+    0xAF26, // 636
+    0xAF27, // 637
+    0xAF28, // 638
+    0xAF29, // 639
+    0xAF2A, // 640
+    0xAF2B, // 641
+    0xAF2E, // 642
+    0xAF2F, // 643
+    0xAF31, // 644
+    0xAF33, // 645
+    0xAF35, // 646
+    0xAF36, // 647
+    0xAF37, // 648
+    0xAF38, // 649
+    0xAF39, // 650
+    0xAF3A, // 651
+    0xAF3B, // 652
+    0xAF3E, // 653
+    0xAF40, // 654
+    0xAF44, // 655
+    0xAF45, // 656
+    0xAF46, // 657
+    0xAF47, // 658
+    0xAF4A, // 659
+    0xAF4B, // 660
+    0xAF4C, // 661
+    0xAF4D, // 662
+    0xAF4E, // 663
+    0xAF4F, // 664
+    0xAF51, // 665
+    0xAF52, // 666
+    0xAF53, // 667
+    0xAF54, // 668
+    0xAF55, // 669
+    0xAF56, // 670
+    0xAF57, // 671
+    0xAF58, // 672
+    0xAF59, // 673
+    0xAF5A, // 674
+    0xAF5B, // 675
+    0xAF5E, // 676
+    0xAF5F, // 677
+    0xAF60, // 678
+    0xAF61, // 679
+    0xAF62, // 680
+    0xAF63, // 681
+    0xAF66, // 682
+    0xAF67, // 683
+    0xAF68, // 684
+    0xAF69, // 685
+    0xAF6A, // 686
+    0xAF6B, // 687
+    0xAF6C, // 688
+    0xAF6D, // 689
+    0xAF6E, // 690
+    0xAF6F, // 691
+    0xAF70, // 692
+    0xAF71, // 693
+    0xAF72, // 694
+    0xAF73, // 695
+    0xAF74, // 696
+    0xAF75, // 697
+    0xAF76, // 698
+    0xAF77, // 699
+    0xAF78, // 700
+    0xAF7A, // 701
+    0xAF7B, // 702
+    0xAF7C, // 703
+    0xAF7D, // 704
+    0xAF7E, // 705
+    0xAF7F, // 706
+    0xAF81, // 707
+    0xAF82, // 708
+    0xAF83, // 709
+    0xAF85, // 710
+    0xAF86, // 711
+    0xAF87, // 712
+    0xAF89, // 713
+    0xAF8A, // 714
+    0xAF8B, // 715
+    0xAF8C, // 716
+    0xAF8D, // 717
+    0xAF8E, // 718
+    0xAF8F, // 719
+    0xAF92, // 720
+    0xAF93, // 721
+    0xAF94, // 722
+    0xAF96, // 723
+    0xAF97, // 724
+    0xAF98, // 725
+    0xAF99, // 726
+    0xAF9A, // 727
+    0xAF9B, // 728
+    0xAF9D, // 729
+    0xAF9E, // 730
+    0xAF9F, // 731
+    0xAFA0, // 732
+    0xAFA1, // 733
+    0xAFA2, // 734
+    0xAFA3, // 735
+    0xAFA4, // 736
+    0xAFA5, // 737
+    0xAFA6, // 738
+    0xAFA7, // 739
+    0xAFA8, // 740
+    0xAFA9, // 741
+    0xAFAA, // 742
+    0xAFAB, // 743
+    0xAFAC, // 744
+    0xAFAD, // 745
+    0xAFAE, // 746
+    0xAFAF, // 747
+    0xAFB0, // 748
+    0xAFB1, // 749
+    0xAFB2, // 750
+    0xAFB3, // 751
+    0xAFB4, // 752
+    0xAFB5, // 753
+    0xAFB6, // 754
+    0xAFB7, // 755
+    0xAFBA, // 756
+    0xAFBB, // 757
+    0xAFBD, // 758
+    0xAFBE, // 759
+    0xAFBF, // 760
+    0xAFC1, // 761
+    0xAFC2, // 762
+    0xAFC3, // 763
+    0xAFC4, // 764
+    0xAFC5, // 765
+    0xAFC6, // 766
+    0xAFCA, // 767
+    0xAFCC, // 768
+    0xAFCF, // 769
+    0xAFD0, // 770
+    0xAFD1, // 771
+    0xAFD2, // 772
+    0xAFD3, // 773
+    0xAFD5, // 774
+    0xAFD6, // 775
+    0xAFD7, // 776
+    0xAFD8, // 777
+    0xAFD9, // 778
+    0xAFDA, // 779
+    0xAFDB, // 780
+    0xAFDD, // 781
+    0xAFDE, // 782
+    0xAFDF, // 783
+    0xAFE0, // 784
+    0xAFE1, // 785
+    0x0000, // 786
+    0x0000, // 787
+    0x0000, // 788
+    0x0000, // 789
+    0x0000, // 790
+    0x0000, // 791
+    0xAFE2, // 792
+    0xAFE3, // 793
+    0xAFE4, // 794
+    0xAFE5, // 795
+    0xAFE6, // 796
+    0xAFE7, // 797
+    0xAFEA, // 798
+    0xAFEB, // 799
+    0xAFEC, // 800
+    0xAFED, // 801
+    0xAFEE, // 802
+    0xAFEF, // 803
+    0xAFF2, // 804
+    0xAFF3, // 805
+    0xAFF5, // 806
+    0xAFF6, // 807
+    0xAFF7, // 808
+    0xAFF9, // 809
+    0xAFFA, // 810
+    0xAFFB, // 811
+    0xAFFC, // 812
+    0xAFFD, // 813
+    0xAFFE, // 814
+    0xAFFF, // 815
+    0xB002, // 816
+    0xB003, // 817
+    0x0000, // 818
+    0x0000, // 819
+    0x0000, // 820
+    0x0000, // 821
+    0x0000, // 822
+    0x0000, // 823
+    0xB005, // 824
+    0xB006, // 825
+    0xB007, // 826
+    0xB008, // 827
+    0xB009, // 828
+    0xB00A, // 829
+    0xB00B, // 830
+    0xB00D, // 831
+    0xB00E, // 832
+    0xB00F, // 833
+    0xB011, // 834
+    0xB012, // 835
+    0xB013, // 836
+    0xB015, // 837
+    0xB016, // 838
+    0xB017, // 839
+    0xB018, // 840
+    0xB019, // 841
+    0xB01A, // 842
+    0xB01B, // 843
+    0xB01E, // 844
+    0xB01F, // 845
+    0xB020, // 846
+    0xB021, // 847
+    0xB022, // 848
+    0xB023, // 849
+    0xB024, // 850
+    0xB025, // 851
+    0xB026, // 852
+    0xB027, // 853
+    0xB029, // 854
+    0xB02A, // 855
+    0xB02B, // 856
+    0xB02C, // 857
+    0xB02D, // 858
+    0xB02E, // 859
+    0xB02F, // 860
+    0xB030, // 861
+    0xB031, // 862
+    0xB032, // 863
+    0xB033, // 864
+    0xB034, // 865
+    0xB035, // 866
+    0xB036, // 867
+    0xB037, // 868
+    0xB038, // 869
+    0xB039, // 870
+    0xB03A, // 871
+    0xB03B, // 872
+    0xB03C, // 873
+    0xB03D, // 874
+    0xB03E, // 875
+    0xB03F, // 876
+    0xB040, // 877
+    0xB041, // 878
+    0xB042, // 879
+    0xB043, // 880
+    0xB046, // 881
+    0xB047, // 882
+    0xB049, // 883
+    0xB04B, // 884
+    0xB04D, // 885
+    0xB04F, // 886
+    0xB050, // 887
+    0xB051, // 888
+    0xB052, // 889
+    0xB056, // 890
+    0xB058, // 891
+    0xB05A, // 892
+    0xB05B, // 893
+    0xB05C, // 894
+    0xB05E, // 895
+    0xB05F, // 896
+    0xB060, // 897
+    0xB061, // 898
+    0xB062, // 899
+    0xB063, // 900
+    0xB064, // 901
+    0xB065, // 902
+    0xB066, // 903
+    0xB067, // 904
+    0xB068, // 905
+    0xB069, // 906
+    0xB06A, // 907
+    0xB06B, // 908
+    0xB06C, // 909
+    0xB06D, // 910
+    0xB06E, // 911
+    0xB06F, // 912
+    0xB070, // 913
+    0xB071, // 914
+    0xB072, // 915
+    0xB073, // 916
+    0xB074, // 917
+    0xB075, // 918
+    0xB076, // 919
+    0xB077, // 920
+    0xB078, // 921
+    0xB079, // 922
+    0xB07A, // 923
+    0xB07B, // 924
+    0xB07E, // 925
+    0xB07F, // 926
+    0xB081, // 927
+    0xB082, // 928
+    0xB083, // 929
+    0xB085, // 930
+    0xB086, // 931
+    0xB087, // 932
+    0xB088, // 933
+    0xB089, // 934
+    0xB08A, // 935
+    0xB08B, // 936
+    0xB08E, // 937
+    0xB090, // 938
+    0xB092, // 939
+    0xB093, // 940
+    0xB094, // 941
+    0xB095, // 942
+    0xB096, // 943
+    0xB097, // 944
+    0xB09B, // 945
+    0xB09D, // 946
+    0xB09E, // 947
+    0xB0A3, // 948
+    0xB0A4, // 949
+    0xB0A5, // 950
+    0xB0A6, // 951
+    0xB0A7, // 952
+    0xB0AA, // 953
+    0xB0B0, // 954
+    0xB0B2, // 955
+    0xB0B6, // 956
+    0xB0B7, // 957
+    0xB0B9, // 958
+    0xB0BA, // 959
+    0xB0BB, // 960
+    0xB0BD, // 961
+    0xB0BE, // 962
+    0xB0BF, // 963
+    0xB0C0, // 964
+    0xB0C1, // 965
+    0xB0C2, // 966
+    0xB0C3, // 967
+    0xB0C6, // 968
+    0xB0CA, // 969
+    0xB0CB, // 970
+    0xB0CC, // 971
+    0xB0CD, // 972
+    0xB0CE, // 973
+    0xB0CF, // 974
+    0xB0D2, // 975
+    0x0000, // 976
+    0x0000, // 977
+    0x0000, // 978
+    0x0000, // 979
+    0x0000, // 980
+    0x0000, // 981
+    0xB0D3, // 982
+    0xB0D5, // 983
+    0xB0D6, // 984
+    0xB0D7, // 985
+    0xB0D9, // 986
+    0xB0DA, // 987
+    0xB0DB, // 988
+    0xB0DC, // 989
+    0xB0DD, // 990
+    0xB0DE, // 991
+    0xB0DF, // 992
+    0xB0E1, // 993
+    0xB0E2, // 994
+    0xB0E3, // 995
+    0xB0E4, // 996
+    0xB0E6, // 997
+    0xB0E7, // 998
+    0xB0E8, // 999
+    0xB0E9, // 1000
+    0xB0EA, // 1001
+    0xB0EB, // 1002
+    0xB0EC, // 1003
+    0xB0ED, // 1004
+    0xB0EE, // 1005
+    0xB0EF, // 1006
+    0xB0F0, // 1007
+    0x0000, // 1008
+    0x0000, // 1009
+    0x0000, // 1010
+    0x0000, // 1011
+    0x0000, // 1012
+    0x0000, // 1013
+    0xB0F1, // 1014
+    0xB0F2, // 1015
+    0xB0F3, // 1016
+    0xB0F4, // 1017
+    0xB0F5, // 1018
+    0xB0F6, // 1019
+    0xB0F7, // 1020
+    0xB0F8, // 1021
+    0xB0F9, // 1022
+    0xB0FA, // 1023
+    0xB0FB, // 1024
+    0xB0FC, // 1025
+    0xB0FD, // 1026
+    0xB0FE, // 1027
+    0xB0FF, // 1028
+    0xB100, // 1029
+    0xB101, // 1030
+    0xB102, // 1031
+    0xB103, // 1032
+    0xB104, // 1033
+    0xB105, // 1034
+    0xB106, // 1035
+    0xB107, // 1036
+    0xB10A, // 1037
+    0xB10D, // 1038
+    0xB10E, // 1039
+    0xB10F, // 1040
+    0xB111, // 1041
+    0xB114, // 1042
+    0xB115, // 1043
+    0xB116, // 1044
+    0xB117, // 1045
+    0xB11A, // 1046
+    0xB11E, // 1047
+    0xB11F, // 1048
+    0xB120, // 1049
+    0xB121, // 1050
+    0xB122, // 1051
+    0xB126, // 1052
+    0xB127, // 1053
+    0xB129, // 1054
+    0xB12A, // 1055
+    0xB12B, // 1056
+    0xB12D, // 1057
+    0xB12E, // 1058
+    0xB12F, // 1059
+    0xB130, // 1060
+    0xB131, // 1061
+    0xB132, // 1062
+    0xB133, // 1063
+    0xB136, // 1064
+    0xB13A, // 1065
+    0xB13B, // 1066
+    0xB13C, // 1067
+    0xB13D, // 1068
+    0xB13E, // 1069
+    0xB13F, // 1070
+    0xB142, // 1071
+    0xB143, // 1072
+    0xB145, // 1073
+    0xB146, // 1074
+    0xB147, // 1075
+    0xB149, // 1076
+    0xB14A, // 1077
+    0xB14B, // 1078
+    0xB14C, // 1079
+    0xB14D, // 1080
+    0xB14E, // 1081
+    0xB14F, // 1082
+    0xB152, // 1083
+    0xB153, // 1084
+    0xB156, // 1085
+    0xB157, // 1086
+    0xB159, // 1087
+    0xB15A, // 1088
+    0xB15B, // 1089
+    0xB15D, // 1090
+    0xB15E, // 1091
+    0xB15F, // 1092
+    0xB161, // 1093
+    0xB162, // 1094
+    0xB163, // 1095
+    0xB164, // 1096
+    0xB165, // 1097
+    0xB166, // 1098
+    0xB167, // 1099
+    0xB168, // 1100
+    0xB169, // 1101
+    0xB16A, // 1102
+    0xB16B, // 1103
+    0xB16C, // 1104
+    0xB16D, // 1105
+    0xB16E, // 1106
+    0xB16F, // 1107
+    0xB170, // 1108
+    0xB171, // 1109
+    0xB172, // 1110
+    0xB173, // 1111
+    0xB174, // 1112
+    0xB175, // 1113
+    0xB176, // 1114
+    0xB177, // 1115
+    0xB17A, // 1116
+    0xB17B, // 1117
+    0xB17D, // 1118
+    0xB17E, // 1119
+    0xB17F, // 1120
+    0xB181, // 1121
+    0xB183, // 1122
+    0xB184, // 1123
+    0xB185, // 1124
+    0xB186, // 1125
+    0xB187, // 1126
+    0xB18A, // 1127
+    0xB18C, // 1128
+    0xB18E, // 1129
+    0xB18F, // 1130
+    0xB190, // 1131
+    0xB191, // 1132
+    0xB195, // 1133
+    0xB196, // 1134
+    0xB197, // 1135
+    0xB199, // 1136
+    0xB19A, // 1137
+    0xB19B, // 1138
+    0xB19D, // 1139
+    0xB19E, // 1140
+    0xB19F, // 1141
+    0xB1A0, // 1142
+    0xB1A1, // 1143
+    0xB1A2, // 1144
+    0xB1A3, // 1145
+    0xB1A4, // 1146
+    0xB1A5, // 1147
+    0xB1A6, // 1148
+    0xB1A7, // 1149
+    0xB1A9, // 1150
+    0xB1AA, // 1151
+    0xB1AB, // 1152
+    0xB1AC, // 1153
+    0xB1AD, // 1154
+    0xB1AE, // 1155
+    0xB1AF, // 1156
+    0xB1B0, // 1157
+    0xB1B1, // 1158
+    0xB1B2, // 1159
+    0xB1B3, // 1160
+    0xB1B4, // 1161
+    0xB1B5, // 1162
+    0xB1B6, // 1163
+    0xB1B7, // 1164
+    0xB1B8, // 1165
+    0x0000, // 1166
+    0x0000, // 1167
+    0x0000, // 1168
+    0x0000, // 1169
+    0x0000, // 1170
+    0x0000, // 1171
+    0xB1B9, // 1172
+    0xB1BA, // 1173
+    0xB1BB, // 1174
+    0xB1BC, // 1175
+    0xB1BD, // 1176
+    0xB1BE, // 1177
+    0xB1BF, // 1178
+    0xB1C0, // 1179
+    0xB1C1, // 1180
+    0xB1C2, // 1181
+    0xB1C3, // 1182
+    0xB1C4, // 1183
+    0xB1C5, // 1184
+    0xB1C6, // 1185
+    0xB1C7, // 1186
+    0xB1C8, // 1187
+    0xB1C9, // 1188
+    0xB1CA, // 1189
+    0xB1CB, // 1190
+    0xB1CD, // 1191
+    0xB1CE, // 1192
+    0xB1CF, // 1193
+    0xB1D1, // 1194
+    0xB1D2, // 1195
+    0xB1D3, // 1196
+    0xB1D5, // 1197
+    0x0000, // 1198
+    0x0000, // 1199
+    0x0000, // 1200
+    0x0000, // 1201
+    0x0000, // 1202
+    0x0000, // 1203
+    0xB1D6, // 1204
+    0xB1D7, // 1205
+    0xB1D8, // 1206
+    0xB1D9, // 1207
+    0xB1DA, // 1208
+    0xB1DB, // 1209
+    0xB1DE, // 1210
+    0xB1E0, // 1211
+    0xB1E1, // 1212
+    0xB1E2, // 1213
+    0xB1E3, // 1214
+    0xB1E4, // 1215
+    0xB1E5, // 1216
+    0xB1E6, // 1217
+    0xB1E7, // 1218
+    0xB1EA, // 1219
+    0xB1EB, // 1220
+    0xB1ED, // 1221
+    0xB1EE, // 1222
+    0xB1EF, // 1223
+    0xB1F1, // 1224
+    0xB1F2, // 1225
+    0xB1F3, // 1226
+    0xB1F4, // 1227
+    0xB1F5, // 1228
+    0xB1F6, // 1229
+    0xB1F7, // 1230
+    0xB1F8, // 1231
+    0xB1FA, // 1232
+    0xB1FC, // 1233
+    0xB1FE, // 1234
+    0xB1FF, // 1235
+    0xB200, // 1236
+    0xB201, // 1237
+    0xB202, // 1238
+    0xB203, // 1239
+    0xB206, // 1240
+    0xB207, // 1241
+    0xB209, // 1242
+    0xB20A, // 1243
+    0xB20D, // 1244
+    0xB20E, // 1245
+    0xB20F, // 1246
+    0xB210, // 1247
+    0xB211, // 1248
+    0xB212, // 1249
+    0xB213, // 1250
+    0xB216, // 1251
+    0xB218, // 1252
+    0xB21A, // 1253
+    0xB21B, // 1254
+    0xB21C, // 1255
+    0xB21D, // 1256
+    0xB21E, // 1257
+    0xB21F, // 1258
+    0xB221, // 1259
+    0xB222, // 1260
+    0xB223, // 1261
+    0xB224, // 1262
+    0xB225, // 1263
+    0xB226, // 1264
+    0xB227, // 1265
+    0xB228, // 1266
+    0xB229, // 1267
+    0xB22A, // 1268
+    0xB22B, // 1269
+    0xB22C, // 1270
+    0xB22D, // 1271
+    0xB22E, // 1272
+    0xB22F, // 1273
+    0xB230, // 1274
+    0xB231, // 1275
+    0xB232, // 1276
+    0xB233, // 1277
+    0xB235, // 1278
+    0xB236, // 1279
+    0xB237, // 1280
+    0xB238, // 1281
+    0xB239, // 1282
+    0xB23A, // 1283
+    0xB23B, // 1284
+    0xB23D, // 1285
+    0xB23E, // 1286
+    0xB23F, // 1287
+    0xB240, // 1288
+    0xB241, // 1289
+    0xB242, // 1290
+    0xB243, // 1291
+    0xB244, // 1292
+    0xB245, // 1293
+    0xB246, // 1294
+    0xB247, // 1295
+    0xB248, // 1296
+    0xB249, // 1297
+    0xB24A, // 1298
+    0xB24B, // 1299
+    0xB24C, // 1300
+    0xB24D, // 1301
+    0xB24E, // 1302
+    0xB24F, // 1303
+    0xB250, // 1304
+    0xB251, // 1305
+    0xB252, // 1306
+    0xB253, // 1307
+    0xB254, // 1308
+    0xB255, // 1309
+    0xB256, // 1310
+    0xB257, // 1311
+    0xB259, // 1312
+    0xB25A, // 1313
+    0xB25B, // 1314
+    0xB25D, // 1315
+    0xB25E, // 1316
+    0xB25F, // 1317
+    0xB261, // 1318
+    0xB262, // 1319
+    0xB263, // 1320
+    0xB264, // 1321
+    0xB265, // 1322
+    0xB266, // 1323
+    0xB267, // 1324
+    0xB26A, // 1325
+    0xB26B, // 1326
+    0xB26C, // 1327
+    0xB26D, // 1328
+    0xB26E, // 1329
+    0xB26F, // 1330
+    0xB270, // 1331
+    0xB271, // 1332
+    0xB272, // 1333
+    0xB273, // 1334
+    0xB276, // 1335
+    0xB277, // 1336
+    0xB278, // 1337
+    0xB279, // 1338
+    0xB27A, // 1339
+    0xB27B, // 1340
+    0xB27D, // 1341
+    0xB27E, // 1342
+    0xB27F, // 1343
+    0xB280, // 1344
+    0xB281, // 1345
+    0xB282, // 1346
+    0xB283, // 1347
+    0xB286, // 1348
+    0xB287, // 1349
+    0xB288, // 1350
+    0xB28A, // 1351
+    0xB28B, // 1352
+    0xB28C, // 1353
+    0xB28D, // 1354
+    0xB28E, // 1355
+    0x0000, // 1356
+    0x0000, // 1357
+    0x0000, // 1358
+    0x0000, // 1359
+    0x0000, // 1360
+    0x0000, // 1361
+    0xB28F, // 1362
+    0xB292, // 1363
+    0xB293, // 1364
+    0xB295, // 1365
+    0xB296, // 1366
+    0xB297, // 1367
+    0xB29B, // 1368
+    0xB29C, // 1369
+    0xB29D, // 1370
+    0xB29E, // 1371
+    0xB29F, // 1372
+    0xB2A2, // 1373
+    0xB2A4, // 1374
+    0xB2A7, // 1375
+    0xB2A8, // 1376
+    0xB2A9, // 1377
+    0xB2AB, // 1378
+    0xB2AD, // 1379
+    0xB2AE, // 1380
+    0xB2AF, // 1381
+    0xB2B1, // 1382
+    0xB2B2, // 1383
+    0xB2B3, // 1384
+    0xB2B5, // 1385
+    0xB2B6, // 1386
+    0xB2B7, // 1387
+    0x0000, // 1388
+    0x0000, // 1389
+    0x0000, // 1390
+    0x0000, // 1391
+    0x0000, // 1392
+    0x0000, // 1393
+    0xB2B8, // 1394
+    0xB2B9, // 1395
+    0xB2BA, // 1396
+    0xB2BB, // 1397
+    0xB2BC, // 1398
+    0xB2BD, // 1399
+    0xB2BE, // 1400
+    0xB2BF, // 1401
+    0xB2C0, // 1402
+    0xB2C1, // 1403
+    0xB2C2, // 1404
+    0xB2C3, // 1405
+    0xB2C4, // 1406
+    0xB2C5, // 1407
+    0xB2C6, // 1408
+    0xB2C7, // 1409
+    0xB2CA, // 1410
+    0xB2CB, // 1411
+    0xB2CD, // 1412
+    0xB2CE, // 1413
+    0xB2CF, // 1414
+    0xB2D1, // 1415
+    0xB2D3, // 1416
+    0xB2D4, // 1417
+    0xB2D5, // 1418
+    0xB2D6, // 1419
+    0xB2D7, // 1420
+    0xB2DA, // 1421
+    0xB2DC, // 1422
+    0xB2DE, // 1423
+    0xB2DF, // 1424
+    0xB2E0, // 1425
+    0xB2E1, // 1426
+    0xB2E3, // 1427
+    0xB2E7, // 1428
+    0xB2E9, // 1429
+    0xB2EA, // 1430
+    0xB2F0, // 1431
+    0xB2F1, // 1432
+    0xB2F2, // 1433
+    0xB2F6, // 1434
+    0xB2FC, // 1435
+    0xB2FD, // 1436
+    0xB2FE, // 1437
+    0xB302, // 1438
+    0xB303, // 1439
+    0xB305, // 1440
+    0xB306, // 1441
+    0xB307, // 1442
+    0xB309, // 1443
+    0xB30A, // 1444
+    0xB30B, // 1445
+    0xB30C, // 1446
+    0xB30D, // 1447
+    0xB30E, // 1448
+    0xB30F, // 1449
+    0xB312, // 1450
+    0xB316, // 1451
+    0xB317, // 1452
+    0xB318, // 1453
+    0xB319, // 1454
+    0xB31A, // 1455
+    0xB31B, // 1456
+    0xB31D, // 1457
+    0xB31E, // 1458
+    0xB31F, // 1459
+    0xB320, // 1460
+    0xB321, // 1461
+    0xB322, // 1462
+    0xB323, // 1463
+    0xB324, // 1464
+    0xB325, // 1465
+    0xB326, // 1466
+    0xB327, // 1467
+    0xB328, // 1468
+    0xB329, // 1469
+    0xB32A, // 1470
+    0xB32B, // 1471
+    0xB32C, // 1472
+    0xB32D, // 1473
+    0xB32E, // 1474
+    0xB32F, // 1475
+    0xB330, // 1476
+    0xB331, // 1477
+    0xB332, // 1478
+    0xB333, // 1479
+    0xB334, // 1480
+    0xB335, // 1481
+    0xB336, // 1482
+    0xB337, // 1483
+    0xB338, // 1484
+    0xB339, // 1485
+    0xB33A, // 1486
+    0xB33B, // 1487
+    0xB33C, // 1488
+    0xB33D, // 1489
+    0xB33E, // 1490
+    0xB33F, // 1491
+    0xB340, // 1492
+    0xB341, // 1493
+    0xB342, // 1494
+    0xB343, // 1495
+    0xB344, // 1496
+    0xB345, // 1497
+    0xB346, // 1498
+    0xB347, // 1499
+    0xB348, // 1500
+    0xB349, // 1501
+    0xB34A, // 1502
+    0xB34B, // 1503
+    0xB34C, // 1504
+    0xB34D, // 1505
+    0xB34E, // 1506
+    0xB34F, // 1507
+    0xB350, // 1508
+    0xB351, // 1509
+    0xB352, // 1510
+    0xB353, // 1511
+    0xB357, // 1512
+    0xB359, // 1513
+    0xB35A, // 1514
+    0xB35D, // 1515
+    0xB360, // 1516
+    0xB361, // 1517
+    0xB362, // 1518
+    0xB363, // 1519
+    0xB366, // 1520
+    0xB368, // 1521
+    0xB36A, // 1522
+    0xB36C, // 1523
+    0xB36D, // 1524
+    0xB36F, // 1525
+    0xB372, // 1526
+    0xB373, // 1527
+    0xB375, // 1528
+    0xB376, // 1529
+    0xB377, // 1530
+    0xB379, // 1531
+    0xB37A, // 1532
+    0xB37B, // 1533
+    0xB37C, // 1534
+    0xB37D, // 1535
+    0xB37E, // 1536
+    0xB37F, // 1537
+    0xB382, // 1538
+    0xB386, // 1539
+    0xB387, // 1540
+    0xB388, // 1541
+    0xB389, // 1542
+    0xB38A, // 1543
+    0xB38B, // 1544
+    0xB38D, // 1545
+    0x0000, // 1546
+    0x0000, // 1547
+    0x0000, // 1548
+    0x0000, // 1549
+    0x0000, // 1550
+    0x0000, // 1551
+    0xB38E, // 1552
+    0xB38F, // 1553
+    0xB391, // 1554
+    0xB392, // 1555
+    0xB393, // 1556
+    0xB395, // 1557
+    0xB396, // 1558
+    0xB397, // 1559
+    0xB398, // 1560
+    0xB399, // 1561
+    0xB39A, // 1562
+    0xB39B, // 1563
+    0xB39C, // 1564
+    0xB39D, // 1565
+    0xB39E, // 1566
+    0xB39F, // 1567
+    0xB3A2, // 1568
+    0xB3A3, // 1569
+    0xB3A4, // 1570
+    0xB3A5, // 1571
+    0xB3A6, // 1572
+    0xB3A7, // 1573
+    0xB3A9, // 1574
+    0xB3AA, // 1575
+    0xB3AB, // 1576
+    0xB3AD, // 1577
+    0x0000, // 1578
+    0x0000, // 1579
+    0x0000, // 1580
+    0x0000, // 1581
+    0x0000, // 1582
+    0x0000, // 1583
+    0xB3AE, // 1584
+    0xB3AF, // 1585
+    0xB3B0, // 1586
+    0xB3B1, // 1587
+    0xB3B2, // 1588
+    0xB3B3, // 1589
+    0xB3B4, // 1590
+    0xB3B5, // 1591
+    0xB3B6, // 1592
+    0xB3B7, // 1593
+    0xB3B8, // 1594
+    0xB3B9, // 1595
+    0xB3BA, // 1596
+    0xB3BB, // 1597
+    0xB3BC, // 1598
+    0xB3BD, // 1599
+    0xB3BE, // 1600
+    0xB3BF, // 1601
+    0xB3C0, // 1602
+    0xB3C1, // 1603
+    0xB3C2, // 1604
+    0xB3C3, // 1605
+    0xB3C6, // 1606
+    0xB3C7, // 1607
+    0xB3C9, // 1608
+    0xB3CA, // 1609
+    0xB3CD, // 1610
+    0xB3CF, // 1611
+    0xB3D1, // 1612
+    0xB3D2, // 1613
+    0xB3D3, // 1614
+    0xB3D6, // 1615
+    0xB3D8, // 1616
+    0xB3DA, // 1617
+    0xB3DC, // 1618
+    0xB3DE, // 1619
+    0xB3DF, // 1620
+    0xB3E1, // 1621
+    0xB3E2, // 1622
+    0xB3E3, // 1623
+    0xB3E5, // 1624
+    0xB3E6, // 1625
+    0xB3E7, // 1626
+    0xB3E9, // 1627
+    0xB3EA, // 1628
+    0xB3EB, // 1629
+    0xB3EC, // 1630
+    0xB3ED, // 1631
+    0xB3EE, // 1632
+    0xB3EF, // 1633
+    0xB3F0, // 1634
+    0xB3F1, // 1635
+    0xB3F2, // 1636
+    0xB3F3, // 1637
+    0xB3F4, // 1638
+    0xB3F5, // 1639
+    0xB3F6, // 1640
+    0xB3F7, // 1641
+    0xB3F8, // 1642
+    0xB3F9, // 1643
+    0xB3FA, // 1644
+    0xB3FB, // 1645
+    0xB3FD, // 1646
+    0xB3FE, // 1647
+    0xB3FF, // 1648
+    0xB400, // 1649
+    0xB401, // 1650
+    0xB402, // 1651
+    0xB403, // 1652
+    0xB404, // 1653
+    0xB405, // 1654
+    0xB406, // 1655
+    0xB407, // 1656
+    0xB408, // 1657
+    0xB409, // 1658
+    0xB40A, // 1659
+    0xB40B, // 1660
+    0xB40C, // 1661
+    0xB40D, // 1662
+    0xB40E, // 1663
+    0xB40F, // 1664
+    0xB411, // 1665
+    0xB412, // 1666
+    0xB413, // 1667
+    0xB414, // 1668
+    0xB415, // 1669
+    0xB416, // 1670
+    0xB417, // 1671
+    0xB419, // 1672
+    0xB41A, // 1673
+    0xB41B, // 1674
+    0xB41D, // 1675
+    0xB41E, // 1676
+    0xB41F, // 1677
+    0xB421, // 1678
+    0xB422, // 1679
+    0xB423, // 1680
+    0xB424, // 1681
+    0xB425, // 1682
+    0xB426, // 1683
+    0xB427, // 1684
+    0xB42A, // 1685
+    0xB42C, // 1686
+    0xB42D, // 1687
+    0xB42E, // 1688
+    0xB42F, // 1689
+    0xB430, // 1690
+    0xB431, // 1691
+    0xB432, // 1692
+    0xB433, // 1693
+    0xB435, // 1694
+    0xB436, // 1695
+    0xB437, // 1696
+    0xB438, // 1697
+    0xB439, // 1698
+    0xB43A, // 1699
+    0xB43B, // 1700
+    0xB43C, // 1701
+    0xB43D, // 1702
+    0xB43E, // 1703
+    0xB43F, // 1704
+    0xB440, // 1705
+    0xB441, // 1706
+    0xB442, // 1707
+    0xB443, // 1708
+    0xB444, // 1709
+    0xB445, // 1710
+    0xB446, // 1711
+    0xB447, // 1712
+    0xB448, // 1713
+    0xB449, // 1714
+    0xB44A, // 1715
+    0xB44B, // 1716
+    0xB44C, // 1717
+    0xB44D, // 1718
+    0xB44E, // 1719
+    0xB44F, // 1720
+    0xB452, // 1721
+    0xB453, // 1722
+    0xB455, // 1723
+    0xB456, // 1724
+    0xB457, // 1725
+    0xB459, // 1726
+    0xB45A, // 1727
+    0xB45B, // 1728
+    0xB45C, // 1729
+    0xB45D, // 1730
+    0xB45E, // 1731
+    0xB45F, // 1732
+    0xB462, // 1733
+    0xB464, // 1734
+    0xB466, // 1735
+    0x0000, // 1736
+    0x0000, // 1737
+    0x0000, // 1738
+    0x0000, // 1739
+    0x0000, // 1740
+    0x0000, // 1741
+    0xB467, // 1742
+    0xB468, // 1743
+    0xB469, // 1744
+    0xB46A, // 1745
+    0xB46B, // 1746
+    0xB46D, // 1747
+    0xB46E, // 1748
+    0xB46F, // 1749
+    0xB470, // 1750
+    0xB471, // 1751
+    0xB472, // 1752
+    0xB473, // 1753
+    0xB474, // 1754
+    0xB475, // 1755
+    0xB476, // 1756
+    0xB477, // 1757
+    0xB478, // 1758
+    0xB479, // 1759
+    0xB47A, // 1760
+    0xB47B, // 1761
+    0xB47C, // 1762
+    0xB47D, // 1763
+    0xB47E, // 1764
+    0xB47F, // 1765
+    0xB481, // 1766
+    0xB482, // 1767
+    0x0000, // 1768
+    0x0000, // 1769
+    0x0000, // 1770
+    0x0000, // 1771
+    0x0000, // 1772
+    0x0000, // 1773
+    0xB483, // 1774
+    0xB484, // 1775
+    0xB485, // 1776
+    0xB486, // 1777
+    0xB487, // 1778
+    0xB489, // 1779
+    0xB48A, // 1780
+    0xB48B, // 1781
+    0xB48C, // 1782
+    0xB48D, // 1783
+    0xB48E, // 1784
+    0xB48F, // 1785
+    0xB490, // 1786
+    0xB491, // 1787
+    0xB492, // 1788
+    0xB493, // 1789
+    0xB494, // 1790
+    0xB495, // 1791
+    0xB496, // 1792
+    0xB497, // 1793
+    0xB498, // 1794
+    0xB499, // 1795
+    0xB49A, // 1796
+    0xB49B, // 1797
+    0xB49C, // 1798
+    0xB49E, // 1799
+    0xB49F, // 1800
+    0xB4A0, // 1801
+    0xB4A1, // 1802
+    0xB4A2, // 1803
+    0xB4A3, // 1804
+    0xB4A5, // 1805
+    0xB4A6, // 1806
+    0xB4A7, // 1807
+    0xB4A9, // 1808
+    0xB4AA, // 1809
+    0xB4AB, // 1810
+    0xB4AD, // 1811
+    0xB4AE, // 1812
+    0xB4AF, // 1813
+    0xB4B0, // 1814
+    0xB4B1, // 1815
+    0xB4B2, // 1816
+    0xB4B3, // 1817
+    0xB4B4, // 1818
+    0xB4B6, // 1819
+    0xB4B8, // 1820
+    0xB4BA, // 1821
+    0xB4BB, // 1822
+    0xB4BC, // 1823
+    0xB4BD, // 1824
+    0xB4BE, // 1825
+    0xB4BF, // 1826
+    0xB4C1, // 1827
+    0xB4C2, // 1828
+    0xB4C3, // 1829
+    0xB4C5, // 1830
+    0xB4C6, // 1831
+    0xB4C7, // 1832
+    0xB4C9, // 1833
+    0xB4CA, // 1834
+    0xB4CB, // 1835
+    0xB4CC, // 1836
+    0xB4CD, // 1837
+    0xB4CE, // 1838
+    0xB4CF, // 1839
+    0xB4D1, // 1840
+    0xB4D2, // 1841
+    0xB4D3, // 1842
+    0xB4D4, // 1843
+    0xB4D6, // 1844
+    0xB4D7, // 1845
+    0xB4D8, // 1846
+    0xB4D9, // 1847
+    0xB4DA, // 1848
+    0xB4DB, // 1849
+    0xB4DE, // 1850
+    0xB4DF, // 1851
+    0xB4E1, // 1852
+    0xB4E2, // 1853
+    0xB4E5, // 1854
+    0xB4E7, // 1855
+    0xB4E8, // 1856
+    0xB4E9, // 1857
+    0xB4EA, // 1858
+    0xB4EB, // 1859
+    0xB4EE, // 1860
+    0xB4F0, // 1861
+    0xB4F2, // 1862
+    0xB4F3, // 1863
+    0xB4F4, // 1864
+    0xB4F5, // 1865
+    0xB4F6, // 1866
+    0xB4F7, // 1867
+    0xB4F9, // 1868
+    0xB4FA, // 1869
+    0xB4FB, // 1870
+    0xB4FC, // 1871
+    0xB4FD, // 1872
+    0xB4FE, // 1873
+    0xB4FF, // 1874
+    0xB500, // 1875
+    0xB501, // 1876
+    0xB502, // 1877
+    0xB503, // 1878
+    0xB504, // 1879
+    0xB505, // 1880
+    0xB506, // 1881
+    0xB507, // 1882
+    0xB508, // 1883
+    0xB509, // 1884
+    0xB50A, // 1885
+    0xB50B, // 1886
+    0xB50C, // 1887
+    0xB50D, // 1888
+    0xB50E, // 1889
+    0xB50F, // 1890
+    0xB510, // 1891
+    0xB511, // 1892
+    0xB512, // 1893
+    0xB513, // 1894
+    0xB516, // 1895
+    0xB517, // 1896
+    0xB519, // 1897
+    0xB51A, // 1898
+    0xB51D, // 1899
+    0xB51E, // 1900
+    0xB51F, // 1901
+    0xB520, // 1902
+    0xB521, // 1903
+    0xB522, // 1904
+    0xB523, // 1905
+    0xB526, // 1906
+    0xB52B, // 1907
+    0xB52C, // 1908
+    0xB52D, // 1909
+    0xB52E, // 1910
+    0xB52F, // 1911
+    0xB532, // 1912
+    0xB533, // 1913
+    0xB535, // 1914
+    0xB536, // 1915
+    0xB537, // 1916
+    0xB539, // 1917
+    0xB53A, // 1918
+    0xB53B, // 1919
+    0xB53C, // 1920
+    0xB53D, // 1921
+    0xB53E, // 1922
+    0xB53F, // 1923
+    0xB542, // 1924
+    0xB546, // 1925
+    0x0000, // 1926
+    0x0000, // 1927
+    0x0000, // 1928
+    0x0000, // 1929
+    0x0000, // 1930
+    0x0000, // 1931
+    0xB547, // 1932
+    0xB548, // 1933
+    0xB549, // 1934
+    0xB54A, // 1935
+    0xB54E, // 1936
+    0xB54F, // 1937
+    0xB551, // 1938
+    0xB552, // 1939
+    0xB553, // 1940
+    0xB555, // 1941
+    0xB556, // 1942
+    0xB557, // 1943
+    0xB558, // 1944
+    0xB559, // 1945
+    0xB55A, // 1946
+    0xB55B, // 1947
+    0xB55E, // 1948
+    0xB562, // 1949
+    0xB563, // 1950
+    0xB564, // 1951
+    0xB565, // 1952
+    0xB566, // 1953
+    0xB567, // 1954
+    0xB568, // 1955
+    0xB569, // 1956
+    0xB56A, // 1957
+    0x0000, // 1958
+    0x0000, // 1959
+    0x0000, // 1960
+    0x0000, // 1961
+    0x0000, // 1962
+    0x0000, // 1963
+    0xB56B, // 1964
+    0xB56C, // 1965
+    0xB56D, // 1966
+    0xB56E, // 1967
+    0xB56F, // 1968
+    0xB570, // 1969
+    0xB571, // 1970
+    0xB572, // 1971
+    0xB573, // 1972
+    0xB574, // 1973
+    0xB575, // 1974
+    0xB576, // 1975
+    0xB577, // 1976
+    0xB578, // 1977
+    0xB579, // 1978
+    0xB57A, // 1979
+    0xB57B, // 1980
+    0xB57C, // 1981
+    0xB57D, // 1982
+    0xB57E, // 1983
+    0xB57F, // 1984
+    0xB580, // 1985
+    0xB581, // 1986
+    0xB582, // 1987
+    0xB583, // 1988
+    0xB584, // 1989
+    0xB585, // 1990
+    0xB586, // 1991
+    0xB587, // 1992
+    0xB588, // 1993
+    0xB589, // 1994
+    0xB58A, // 1995
+    0xB58B, // 1996
+    0xB58C, // 1997
+    0xB58D, // 1998
+    0xB58E, // 1999
+    0xB58F, // 2000
+    0xB590, // 2001
+    0xB591, // 2002
+    0xB592, // 2003
+    0xB593, // 2004
+    0xB594, // 2005
+    0xB595, // 2006
+    0xB596, // 2007
+    0xB597, // 2008
+    0xB598, // 2009
+    0xB599, // 2010
+    0xB59A, // 2011
+    0xB59B, // 2012
+    0xB59C, // 2013
+    0xB59D, // 2014
+    0xB59E, // 2015
+    0xB59F, // 2016
+    0xB5A2, // 2017
+    0xB5A3, // 2018
+    0xB5A5, // 2019
+    0xB5A6, // 2020
+    0xB5A7, // 2021
+    0xB5A9, // 2022
+    0xB5AC, // 2023
+    0xB5AD, // 2024
+    0xB5AE, // 2025
+    0xB5AF, // 2026
+    0xB5B2, // 2027
+    0xB5B6, // 2028
+    0xB5B7, // 2029
+    0xB5B8, // 2030
+    0xB5B9, // 2031
+    0xB5BA, // 2032
+    0xB5BE, // 2033
+    0xB5BF, // 2034
+    0xB5C1, // 2035
+    0xB5C2, // 2036
+    0xB5C3, // 2037
+    0xB5C5, // 2038
+    0xB5C6, // 2039
+    0xB5C7, // 2040
+    0xB5C8, // 2041
+    0xB5C9, // 2042
+    0xB5CA, // 2043
+    0xB5CB, // 2044
+    0xB5CE, // 2045
+    0xB5D2, // 2046
+    0xB5D3, // 2047
+    0xB5D4, // 2048
+    0xB5D5, // 2049
+    0xB5D6, // 2050
+    0xB5D7, // 2051
+    0xB5D9, // 2052
+    0xB5DA, // 2053
+    0xB5DB, // 2054
+    0xB5DC, // 2055
+    0xB5DD, // 2056
+    0xB5DE, // 2057
+    0xB5DF, // 2058
+    0xB5E0, // 2059
+    0xB5E1, // 2060
+    0xB5E2, // 2061
+    0xB5E3, // 2062
+    0xB5E4, // 2063
+    0xB5E5, // 2064
+    0xB5E6, // 2065
+    0xB5E7, // 2066
+    0xB5E8, // 2067
+    0xB5E9, // 2068
+    0xB5EA, // 2069
+    0xB5EB, // 2070
+    0xB5ED, // 2071
+    0xB5EE, // 2072
+    0xB5EF, // 2073
+    0xB5F0, // 2074
+    0xB5F1, // 2075
+    0xB5F2, // 2076
+    0xB5F3, // 2077
+    0xB5F4, // 2078
+    0xB5F5, // 2079
+    0xB5F6, // 2080
+    0xB5F7, // 2081
+    0xB5F8, // 2082
+    0xB5F9, // 2083
+    0xB5FA, // 2084
+    0xB5FB, // 2085
+    0xB5FC, // 2086
+    0xB5FD, // 2087
+    0xB5FE, // 2088
+    0xB5FF, // 2089
+    0xB600, // 2090
+    0xB601, // 2091
+    0xB602, // 2092
+    0xB603, // 2093
+    0xB604, // 2094
+    0xB605, // 2095
+    0xB606, // 2096
+    0xB607, // 2097
+    0xB608, // 2098
+    0xB609, // 2099
+    0xB60A, // 2100
+    0xB60B, // 2101
+    0xB60C, // 2102
+    0xB60D, // 2103
+    0xB60E, // 2104
+    0xB60F, // 2105
+    0xB612, // 2106
+    0xB613, // 2107
+    0xB615, // 2108
+    0xB616, // 2109
+    0xB617, // 2110
+    0xB619, // 2111
+    0xB61A, // 2112
+    0xB61B, // 2113
+    0xB61C, // 2114
+    0xB61D, // 2115
+    0x0000, // 2116
+    0x0000, // 2117
+    0x0000, // 2118
+    0x0000, // 2119
+    0x0000, // 2120
+    0x0000, // 2121
+    0xB61E, // 2122
+    0xB61F, // 2123
+    0xB620, // 2124
+    0xB621, // 2125
+    0xB622, // 2126
+    0xB623, // 2127
+    0xB624, // 2128
+    0xB626, // 2129
+    0xB627, // 2130
+    0xB628, // 2131
+    0xB629, // 2132
+    0xB62A, // 2133
+    0xB62B, // 2134
+    0xB62D, // 2135
+    0xB62E, // 2136
+    0xB62F, // 2137
+    0xB630, // 2138
+    0xB631, // 2139
+    0xB632, // 2140
+    0xB633, // 2141
+    0xB635, // 2142
+    0xB636, // 2143
+    0xB637, // 2144
+    0xB638, // 2145
+    0xB639, // 2146
+    0xB63A, // 2147
+    0x0000, // 2148
+    0x0000, // 2149
+    0x0000, // 2150
+    0x0000, // 2151
+    0x0000, // 2152
+    0x0000, // 2153
+    0xB63B, // 2154
+    0xB63C, // 2155
+    0xB63D, // 2156
+    0xB63E, // 2157
+    0xB63F, // 2158
+    0xB640, // 2159
+    0xB641, // 2160
+    0xB642, // 2161
+    0xB643, // 2162
+    0xB644, // 2163
+    0xB645, // 2164
+    0xB646, // 2165
+    0xB647, // 2166
+    0xB649, // 2167
+    0xB64A, // 2168
+    0xB64B, // 2169
+    0xB64C, // 2170
+    0xB64D, // 2171
+    0xB64E, // 2172
+    0xB64F, // 2173
+    0xB650, // 2174
+    0xB651, // 2175
+    0xB652, // 2176
+    0xB653, // 2177
+    0xB654, // 2178
+    0xB655, // 2179
+    0xB656, // 2180
+    0xB657, // 2181
+    0xB658, // 2182
+    0xB659, // 2183
+    0xB65A, // 2184
+    0xB65B, // 2185
+    0xB65C, // 2186
+    0xB65D, // 2187
+    0xB65E, // 2188
+    0xB65F, // 2189
+    0xB660, // 2190
+    0xB661, // 2191
+    0xB662, // 2192
+    0xB663, // 2193
+    0xB665, // 2194
+    0xB666, // 2195
+    0xB667, // 2196
+    0xB669, // 2197
+    0xB66A, // 2198
+    0xB66B, // 2199
+    0xB66C, // 2200
+    0xB66D, // 2201
+    0xB66E, // 2202
+    0xB66F, // 2203
+    0xB670, // 2204
+    0xB671, // 2205
+    0xB672, // 2206
+    0xB673, // 2207
+    0xB674, // 2208
+    0xB675, // 2209
+    0xB676, // 2210
+    0xB677, // 2211
+    0xB678, // 2212
+    0xB679, // 2213
+    0xB67A, // 2214
+    0xB67B, // 2215
+    0xB67C, // 2216
+    0xB67D, // 2217
+    0xB67E, // 2218
+    0xB67F, // 2219
+    0xB680, // 2220
+    0xB681, // 2221
+    0xB682, // 2222
+    0xB683, // 2223
+    0xB684, // 2224
+    0xB685, // 2225
+    0xB686, // 2226
+    0xB687, // 2227
+    0xB688, // 2228
+    0xB689, // 2229
+    0xB68A, // 2230
+    0xB68B, // 2231
+    0xB68C, // 2232
+    0xB68D, // 2233
+    0xB68E, // 2234
+    0xB68F, // 2235
+    0xB690, // 2236
+    0xB691, // 2237
+    0xB692, // 2238
+    0xB693, // 2239
+    0xB694, // 2240
+    0xB695, // 2241
+    0xB696, // 2242
+    0xB697, // 2243
+    0xB698, // 2244
+    0xB699, // 2245
+    0xB69A, // 2246
+    0xB69B, // 2247
+    0xB69E, // 2248
+    0xB69F, // 2249
+    0xB6A1, // 2250
+    0xB6A2, // 2251
+    0xB6A3, // 2252
+    0xB6A5, // 2253
+    0xB6A6, // 2254
+    0xB6A7, // 2255
+    0xB6A8, // 2256
+    0xB6A9, // 2257
+    0xB6AA, // 2258
+    0xB6AD, // 2259
+    0xB6AE, // 2260
+    0xB6AF, // 2261
+    0xB6B0, // 2262
+    0xB6B2, // 2263
+    0xB6B3, // 2264
+    0xB6B4, // 2265
+    0xB6B5, // 2266
+    0xB6B6, // 2267
+    0xB6B7, // 2268
+    0xB6B8, // 2269
+    0xB6B9, // 2270
+    0xB6BA, // 2271
+    0xB6BB, // 2272
+    0xB6BC, // 2273
+    0xB6BD, // 2274
+    0xB6BE, // 2275
+    0xB6BF, // 2276
+    0xB6C0, // 2277
+    0xB6C1, // 2278
+    0xB6C2, // 2279
+    0xB6C3, // 2280
+    0xB6C4, // 2281
+    0xB6C5, // 2282
+    0xB6C6, // 2283
+    0xB6C7, // 2284
+    0xB6C8, // 2285
+    0xB6C9, // 2286
+    0xB6CA, // 2287
+    0xB6CB, // 2288
+    0xB6CC, // 2289
+    0xB6CD, // 2290
+    0xB6CE, // 2291
+    0xB6CF, // 2292
+    0xB6D0, // 2293
+    0xB6D1, // 2294
+    0xB6D2, // 2295
+    0xB6D3, // 2296
+    0xB6D5, // 2297
+    0xB6D6, // 2298
+    0xB6D7, // 2299
+    0xB6D8, // 2300
+    0xB6D9, // 2301
+    0xB6DA, // 2302
+    0xB6DB, // 2303
+    0xB6DC, // 2304
+    0xB6DD, // 2305
+    0x0000, // 2306
+    0x0000, // 2307
+    0x0000, // 2308
+    0x0000, // 2309
+    0x0000, // 2310
+    0x0000, // 2311
+    0xB6DE, // 2312
+    0xB6DF, // 2313
+    0xB6E0, // 2314
+    0xB6E1, // 2315
+    0xB6E2, // 2316
+    0xB6E3, // 2317
+    0xB6E4, // 2318
+    0xB6E5, // 2319
+    0xB6E6, // 2320
+    0xB6E7, // 2321
+    0xB6E8, // 2322
+    0xB6E9, // 2323
+    0xB6EA, // 2324
+    0xB6EB, // 2325
+    0xB6EC, // 2326
+    0xB6ED, // 2327
+    0xB6EE, // 2328
+    0xB6EF, // 2329
+    0xB6F1, // 2330
+    0xB6F2, // 2331
+    0xB6F3, // 2332
+    0xB6F5, // 2333
+    0xB6F6, // 2334
+    0xB6F7, // 2335
+    0xB6F9, // 2336
+    0xB6FA, // 2337
+    0x0000, // 2338
+    0x0000, // 2339
+    0x0000, // 2340
+    0x0000, // 2341
+    0x0000, // 2342
+    0x0000, // 2343
+    0xB6FB, // 2344
+    0xB6FC, // 2345
+    0xB6FD, // 2346
+    0xB6FE, // 2347
+    0xB6FF, // 2348
+    0xB702, // 2349
+    0xB703, // 2350
+    0xB704, // 2351
+    0xB706, // 2352
+    0xB707, // 2353
+    0xB708, // 2354
+    0xB709, // 2355
+    0xB70A, // 2356
+    0xB70B, // 2357
+    0xB70C, // 2358
+    0xB70D, // 2359
+    0xB70E, // 2360
+    0xB70F, // 2361
+    0xB710, // 2362
+    0xB711, // 2363
+    0xB712, // 2364
+    0xB713, // 2365
+    0xB714, // 2366
+    0xB715, // 2367
+    0xB716, // 2368
+    0xB717, // 2369
+    0xB718, // 2370
+    0xB719, // 2371
+    0xB71A, // 2372
+    0xB71B, // 2373
+    0xB71C, // 2374
+    0xB71D, // 2375
+    0xB71E, // 2376
+    0xB71F, // 2377
+    0xB720, // 2378
+    0xB721, // 2379
+    0xB722, // 2380
+    0xB723, // 2381
+    0xB724, // 2382
+    0xB725, // 2383
+    0xB726, // 2384
+    0xB727, // 2385
+    0xB72A, // 2386
+    0xB72B, // 2387
+    0xB72D, // 2388
+    0xB72E, // 2389
+    0xB731, // 2390
+    0xB732, // 2391
+    0xB733, // 2392
+    0xB734, // 2393
+    0xB735, // 2394
+    0xB736, // 2395
+    0xB737, // 2396
+    0xB73A, // 2397
+    0xB73C, // 2398
+    0xB73D, // 2399
+    0xB73E, // 2400
+    0xB73F, // 2401
+    0xB740, // 2402
+    0xB741, // 2403
+    0xB742, // 2404
+    0xB743, // 2405
+    0xB745, // 2406
+    0xB746, // 2407
+    0xB747, // 2408
+    0xB749, // 2409
+    0xB74A, // 2410
+    0xB74B, // 2411
+    0xB74D, // 2412
+    0xB74E, // 2413
+    0xB74F, // 2414
+    0xB750, // 2415
+    0xB751, // 2416
+    0xB752, // 2417
+    0xB753, // 2418
+    0xB756, // 2419
+    0xB757, // 2420
+    0xB758, // 2421
+    0xB759, // 2422
+    0xB75A, // 2423
+    0xB75B, // 2424
+    0xB75C, // 2425
+    0xB75D, // 2426
+    0xB75E, // 2427
+    0xB75F, // 2428
+    0xB761, // 2429
+    0xB762, // 2430
+    0xB763, // 2431
+    0xB765, // 2432
+    0xB766, // 2433
+    0xB767, // 2434
+    0xB769, // 2435
+    0xB76A, // 2436
+    0xB76B, // 2437
+    0xB76C, // 2438
+    0xB76D, // 2439
+    0xB76E, // 2440
+    0xB76F, // 2441
+    0xB772, // 2442
+    0xB774, // 2443
+    0xB776, // 2444
+    0xB777, // 2445
+    0xB778, // 2446
+    0xB779, // 2447
+    0xB77A, // 2448
+    0xB77B, // 2449
+    0xB77E, // 2450
+    0xB77F, // 2451
+    0xB781, // 2452
+    0xB782, // 2453
+    0xB783, // 2454
+    0xB785, // 2455
+    0xB786, // 2456
+    0xB787, // 2457
+    0xB788, // 2458
+    0xB789, // 2459
+    0xB78A, // 2460
+    0xB78B, // 2461
+    0xB78E, // 2462
+    0xB793, // 2463
+    0xB794, // 2464
+    0xB795, // 2465
+    0xB79A, // 2466
+    0xB79B, // 2467
+    0xB79D, // 2468
+    0xB79E, // 2469
+    0xB79F, // 2470
+    0xB7A1, // 2471
+    0xB7A2, // 2472
+    0xB7A3, // 2473
+    0xB7A4, // 2474
+    0xB7A5, // 2475
+    0xB7A6, // 2476
+    0xB7A7, // 2477
+    0xB7AA, // 2478
+    0xB7AE, // 2479
+    0xB7AF, // 2480
+    0xB7B0, // 2481
+    0xB7B1, // 2482
+    0xB7B2, // 2483
+    0xB7B3, // 2484
+    0xB7B6, // 2485
+    0xB7B7, // 2486
+    0xB7B9, // 2487
+    0xB7BA, // 2488
+    0xB7BB, // 2489
+    0xB7BC, // 2490
+    0xB7BD, // 2491
+    0xB7BE, // 2492
+    0xB7BF, // 2493
+    0xB7C0, // 2494
+    0xB7C1, // 2495
+    0x0000, // 2496
+    0x0000, // 2497
+    0x0000, // 2498
+    0x0000, // 2499
+    0x0000, // 2500
+    0x0000, // 2501
+    0xB7C2, // 2502
+    0xB7C3, // 2503
+    0xB7C4, // 2504
+    0xB7C5, // 2505
+    0xB7C6, // 2506
+    0xB7C8, // 2507
+    0xB7CA, // 2508
+    0xB7CB, // 2509
+    0xB7CC, // 2510
+    0xB7CD, // 2511
+    0xB7CE, // 2512
+    0xB7CF, // 2513
+    0xB7D0, // 2514
+    0xB7D1, // 2515
+    0xB7D2, // 2516
+    0xB7D3, // 2517
+    0xB7D4, // 2518
+    0xB7D5, // 2519
+    0xB7D6, // 2520
+    0xB7D7, // 2521
+    0xB7D8, // 2522
+    0xB7D9, // 2523
+    0xB7DA, // 2524
+    0xB7DB, // 2525
+    0xB7DC, // 2526
+    0xB7DD, // 2527
+    0x0000, // 2528
+    0x0000, // 2529
+    0x0000, // 2530
+    0x0000, // 2531
+    0x0000, // 2532
+    0x0000, // 2533
+    0xB7DE, // 2534
+    0xB7DF, // 2535
+    0xB7E0, // 2536
+    0xB7E1, // 2537
+    0xB7E2, // 2538
+    0xB7E3, // 2539
+    0xB7E4, // 2540
+    0xB7E5, // 2541
+    0xB7E6, // 2542
+    0xB7E7, // 2543
+    0xB7E8, // 2544
+    0xB7E9, // 2545
+    0xB7EA, // 2546
+    0xB7EB, // 2547
+    0xB7EE, // 2548
+    0xB7EF, // 2549
+    0xB7F1, // 2550
+    0xB7F2, // 2551
+    0xB7F3, // 2552
+    0xB7F5, // 2553
+    0xB7F6, // 2554
+    0xB7F7, // 2555
+    0xB7F8, // 2556
+    0xB7F9, // 2557
+    0xB7FA, // 2558
+    0xB7FB, // 2559
+    0xB7FE, // 2560
+    0xB802, // 2561
+    0xB803, // 2562
+    0xB804, // 2563
+    0xB805, // 2564
+    0xB806, // 2565
+    0xB80A, // 2566
+    0xB80B, // 2567
+    0xB80D, // 2568
+    0xB80E, // 2569
+    0xB80F, // 2570
+    0xB811, // 2571
+    0xB812, // 2572
+    0xB813, // 2573
+    0xB814, // 2574
+    0xB815, // 2575
+    0xB816, // 2576
+    0xB817, // 2577
+    0xB81A, // 2578
+    0xB81C, // 2579
+    0xB81E, // 2580
+    0xB81F, // 2581
+    0xB820, // 2582
+    0xB821, // 2583
+    0xB822, // 2584
+    0xB823, // 2585
+    0xB826, // 2586
+    0xB827, // 2587
+    0xB829, // 2588
+    0xB82A, // 2589
+    0xB82B, // 2590
+    0xB82D, // 2591
+    0xB82E, // 2592
+    0xB82F, // 2593
+    0xB830, // 2594
+    0xB831, // 2595
+    0xB832, // 2596
+    0xB833, // 2597
+    0xB836, // 2598
+    0xB83A, // 2599
+    0xB83B, // 2600
+    0xB83C, // 2601
+    0xB83D, // 2602
+    0xB83E, // 2603
+    0xB83F, // 2604
+    0xB841, // 2605
+    0xB842, // 2606
+    0xB843, // 2607
+    0xB845, // 2608
+    0xB846, // 2609
+    0xB847, // 2610
+    0xB848, // 2611
+    0xB849, // 2612
+    0xB84A, // 2613
+    0xB84B, // 2614
+    0xB84C, // 2615
+    0xB84D, // 2616
+    0xB84E, // 2617
+    0xB84F, // 2618
+    0xB850, // 2619
+    0xB852, // 2620
+    0xB854, // 2621
+    0xB855, // 2622
+    0xB856, // 2623
+    0xB857, // 2624
+    0xB858, // 2625
+    0xB859, // 2626
+    0xB85A, // 2627
+    0xB85B, // 2628
+    0xB85E, // 2629
+    0xB85F, // 2630
+    0xB861, // 2631
+    0xB862, // 2632
+    0xB863, // 2633
+    0xB865, // 2634
+    0xB866, // 2635
+    0xB867, // 2636
+    0xB868, // 2637
+    0xB869, // 2638
+    0xB86A, // 2639
+    0xB86B, // 2640
+    0xB86E, // 2641
+    0xB870, // 2642
+    0xB872, // 2643
+    0xB873, // 2644
+    0xB874, // 2645
+    0xB875, // 2646
+    0xB876, // 2647
+    0xB877, // 2648
+    0xB879, // 2649
+    0xB87A, // 2650
+    0xB87B, // 2651
+    0xB87D, // 2652
+    0xB87E, // 2653
+    0xB87F, // 2654
+    0xB880, // 2655
+    0xB881, // 2656
+    0xB882, // 2657
+    0xB883, // 2658
+    0xB884, // 2659
+    0xB885, // 2660
+    0xB886, // 2661
+    0xB887, // 2662
+    0xB888, // 2663
+    0xB889, // 2664
+    0xB88A, // 2665
+    0xB88B, // 2666
+    0xB88C, // 2667
+    0xB88E, // 2668
+    0xB88F, // 2669
+    0xB890, // 2670
+    0xB891, // 2671
+    0xB892, // 2672
+    0xB893, // 2673
+    0xB894, // 2674
+    0xB895, // 2675
+    0xB896, // 2676
+    0xB897, // 2677
+    0xB898, // 2678
+    0xB899, // 2679
+    0xB89A, // 2680
+    0xB89B, // 2681
+    0xB89C, // 2682
+    0xB89D, // 2683
+    0xB89E, // 2684
+    0xB89F, // 2685
+    0x0000, // 2686
+    0x0000, // 2687
+    0x0000, // 2688
+    0x0000, // 2689
+    0x0000, // 2690
+    0x0000, // 2691
+    0xB8A0, // 2692
+    0xB8A1, // 2693
+    0xB8A2, // 2694
+    0xB8A3, // 2695
+    0xB8A4, // 2696
+    0xB8A5, // 2697
+    0xB8A6, // 2698
+    0xB8A7, // 2699
+    0xB8A9, // 2700
+    0xB8AA, // 2701
+    0xB8AB, // 2702
+    0xB8AC, // 2703
+    0xB8AD, // 2704
+    0xB8AE, // 2705
+    0xB8AF, // 2706
+    0xB8B1, // 2707
+    0xB8B2, // 2708
+    0xB8B3, // 2709
+    0xB8B5, // 2710
+    0xB8B6, // 2711
+    0xB8B7, // 2712
+    0xB8B9, // 2713
+    0xB8BA, // 2714
+    0xB8BB, // 2715
+    0xB8BC, // 2716
+    0xB8BD, // 2717
+    0x0000, // 2718
+    0x0000, // 2719
+    0x0000, // 2720
+    0x0000, // 2721
+    0x0000, // 2722
+    0x0000, // 2723
+    0xB8BE, // 2724
+    0xB8BF, // 2725
+    0xB8C2, // 2726
+    0xB8C4, // 2727
+    0xB8C6, // 2728
+    0xB8C7, // 2729
+    0xB8C8, // 2730
+    0xB8C9, // 2731
+    0xB8CA, // 2732
+    0xB8CB, // 2733
+    0xB8CD, // 2734
+    0xB8CE, // 2735
+    0xB8CF, // 2736
+    0xB8D1, // 2737
+    0xB8D2, // 2738
+    0xB8D3, // 2739
+    0xB8D5, // 2740
+    0xB8D6, // 2741
+    0xB8D7, // 2742
+    0xB8D8, // 2743
+    0xB8D9, // 2744
+    0xB8DA, // 2745
+    0xB8DB, // 2746
+    0xB8DC, // 2747
+    0xB8DE, // 2748
+    0xB8E0, // 2749
+    0xB8E2, // 2750
+    0xB8E3, // 2751
+    0xB8E4, // 2752
+    0xB8E5, // 2753
+    0xB8E6, // 2754
+    0xB8E7, // 2755
+    0xB8EA, // 2756
+    0xB8EB, // 2757
+    0xB8ED, // 2758
+    0xB8EE, // 2759
+    0xB8EF, // 2760
+    0xB8F1, // 2761
+    0xB8F2, // 2762
+    0xB8F3, // 2763
+    0xB8F4, // 2764
+    0xB8F5, // 2765
+    0xB8F6, // 2766
+    0xB8F7, // 2767
+    0xB8FA, // 2768
+    0xB8FC, // 2769
+    0xB8FE, // 2770
+    0xB8FF, // 2771
+    0xB900, // 2772
+    0xB901, // 2773
+    0xB902, // 2774
+    0xB903, // 2775
+    0xB905, // 2776
+    0xB906, // 2777
+    0xB907, // 2778
+    0xB908, // 2779
+    0xB909, // 2780
+    0xB90A, // 2781
+    0xB90B, // 2782
+    0xB90C, // 2783
+    0xB90D, // 2784
+    0xB90E, // 2785
+    0xB90F, // 2786
+    0xB910, // 2787
+    0xB911, // 2788
+    0xB912, // 2789
+    0xB913, // 2790
+    0xB914, // 2791
+    0xB915, // 2792
+    0xB916, // 2793
+    0xB917, // 2794
+    0xB919, // 2795
+    0xB91A, // 2796
+    0xB91B, // 2797
+    0xB91C, // 2798
+    0xB91D, // 2799
+    0xB91E, // 2800
+    0xB91F, // 2801
+    0xB921, // 2802
+    0xB922, // 2803
+    0xB923, // 2804
+    0xB924, // 2805
+    0xB925, // 2806
+    0xB926, // 2807
+    0xB927, // 2808
+    0xB928, // 2809
+    0xB929, // 2810
+    0xB92A, // 2811
+    0xB92B, // 2812
+    0xB92C, // 2813
+    0xB92D, // 2814
+    0xB92E, // 2815
+    0xB92F, // 2816
+    0xB930, // 2817
+    0xB931, // 2818
+    0xB932, // 2819
+    0xB933, // 2820
+    0xB934, // 2821
+    0xB935, // 2822
+    0xB936, // 2823
+    0xB937, // 2824
+    0xB938, // 2825
+    0xB939, // 2826
+    0xB93A, // 2827
+    0xB93B, // 2828
+    0xB93E, // 2829
+    0xB93F, // 2830
+    0xB941, // 2831
+    0xB942, // 2832
+    0xB943, // 2833
+    0xB945, // 2834
+    0xB946, // 2835
+    0xB947, // 2836
+    0xB948, // 2837
+    0xB949, // 2838
+    0xB94A, // 2839
+    0xB94B, // 2840
+    0xB94D, // 2841
+    0xB94E, // 2842
+    0xB950, // 2843
+    0xB952, // 2844
+    0xB953, // 2845
+    0xB954, // 2846
+    0xB955, // 2847
+    0xB956, // 2848
+    0xB957, // 2849
+    0xB95A, // 2850
+    0xB95B, // 2851
+    0xB95D, // 2852
+    0xB95E, // 2853
+    0xB95F, // 2854
+    0xB961, // 2855
+    0xB962, // 2856
+    0xB963, // 2857
+    0xB964, // 2858
+    0xB965, // 2859
+    0xB966, // 2860
+    0xB967, // 2861
+    0xB96A, // 2862
+    0xB96C, // 2863
+    0xB96E, // 2864
+    0xB96F, // 2865
+    0xB970, // 2866
+    0xB971, // 2867
+    0xB972, // 2868
+    0xB973, // 2869
+    0xB976, // 2870
+    0xB977, // 2871
+    0xB979, // 2872
+    0xB97A, // 2873
+    0xB97B, // 2874
+    0xB97D, // 2875
+    0x0000, // 2876
+    0x0000, // 2877
+    0x0000, // 2878
+    0x0000, // 2879
+    0x0000, // 2880
+    0x0000, // 2881
+    0xB97E, // 2882
+    0xB97F, // 2883
+    0xB980, // 2884
+    0xB981, // 2885
+    0xB982, // 2886
+    0xB983, // 2887
+    0xB986, // 2888
+    0xB988, // 2889
+    0xB98B, // 2890
+    0xB98C, // 2891
+    0xB98F, // 2892
+    0xB990, // 2893
+    0xB991, // 2894
+    0xB992, // 2895
+    0xB993, // 2896
+    0xB994, // 2897
+    0xB995, // 2898
+    0xB996, // 2899
+    0xB997, // 2900
+    0xB998, // 2901
+    0xB999, // 2902
+    0xB99A, // 2903
+    0xB99B, // 2904
+    0xB99C, // 2905
+    0xB99D, // 2906
+    0xB99E, // 2907
+    0x0000, // 2908
+    0x0000, // 2909
+    0x0000, // 2910
+    0x0000, // 2911
+    0x0000, // 2912
+    0x0000, // 2913
+    0xB99F, // 2914
+    0xB9A0, // 2915
+    0xB9A1, // 2916
+    0xB9A2, // 2917
+    0xB9A3, // 2918
+    0xB9A4, // 2919
+    0xB9A5, // 2920
+    0xB9A6, // 2921
+    0xB9A7, // 2922
+    0xB9A8, // 2923
+    0xB9A9, // 2924
+    0xB9AA, // 2925
+    0xB9AB, // 2926
+    0xB9AE, // 2927
+    0xB9AF, // 2928
+    0xB9B1, // 2929
+    0xB9B2, // 2930
+    0xB9B3, // 2931
+    0xB9B5, // 2932
+    0xB9B6, // 2933
+    0xB9B7, // 2934
+    0xB9B8, // 2935
+    0xB9B9, // 2936
+    0xB9BA, // 2937
+    0xB9BB, // 2938
+    0xB9BE, // 2939
+    0xB9C0, // 2940
+    0xB9C2, // 2941
+    0xB9C3, // 2942
+    0xB9C4, // 2943
+    0xB9C5, // 2944
+    0xB9C6, // 2945
+    0xB9C7, // 2946
+    0xB9CA, // 2947
+    0xB9CB, // 2948
+    0xB9CD, // 2949
+    0xB9D3, // 2950
+    0xB9D4, // 2951
+    0xB9D5, // 2952
+    0xB9D6, // 2953
+    0xB9D7, // 2954
+    0xB9DA, // 2955
+    0xB9DC, // 2956
+    0xB9DF, // 2957
+    0xB9E0, // 2958
+    0xB9E2, // 2959
+    0xB9E6, // 2960
+    0xB9E7, // 2961
+    0xB9E9, // 2962
+    0xB9EA, // 2963
+    0xB9EB, // 2964
+    0xB9ED, // 2965
+    0xB9EE, // 2966
+    0xB9EF, // 2967
+    0xB9F0, // 2968
+    0xB9F1, // 2969
+    0xB9F2, // 2970
+    0xB9F3, // 2971
+    0xB9F6, // 2972
+    0xB9FB, // 2973
+    0xB9FC, // 2974
+    0xB9FD, // 2975
+    0xB9FE, // 2976
+    0xB9FF, // 2977
+    0xBA02, // 2978
+    0xBA03, // 2979
+    0xBA04, // 2980
+    0xBA05, // 2981
+    0xBA06, // 2982
+    0xBA07, // 2983
+    0xBA09, // 2984
+    0xBA0A, // 2985
+    0xBA0B, // 2986
+    0xBA0C, // 2987
+    0xBA0D, // 2988
+    0xBA0E, // 2989
+    0xBA0F, // 2990
+    0xBA10, // 2991
+    0xBA11, // 2992
+    0xBA12, // 2993
+    0xBA13, // 2994
+    0xBA14, // 2995
+    0xBA16, // 2996
+    0xBA17, // 2997
+    0xBA18, // 2998
+    0xBA19, // 2999
+    0xBA1A, // 3000
+    0xBA1B, // 3001
+    0xBA1C, // 3002
+    0xBA1D, // 3003
+    0xBA1E, // 3004
+    0xBA1F, // 3005
+    0xBA20, // 3006
+    0xBA21, // 3007
+    0xBA22, // 3008
+    0xBA23, // 3009
+    0xBA24, // 3010
+    0xBA25, // 3011
+    0xBA26, // 3012
+    0xBA27, // 3013
+    0xBA28, // 3014
+    0xBA29, // 3015
+    0xBA2A, // 3016
+    0xBA2B, // 3017
+    0xBA2C, // 3018
+    0xBA2D, // 3019
+    0xBA2E, // 3020
+    0xBA2F, // 3021
+    0xBA30, // 3022
+    0xBA31, // 3023
+    0xBA32, // 3024
+    0xBA33, // 3025
+    0xBA34, // 3026
+    0xBA35, // 3027
+    0xBA36, // 3028
+    0xBA37, // 3029
+    0xBA3A, // 3030
+    0xBA3B, // 3031
+    0xBA3D, // 3032
+    0xBA3E, // 3033
+    0xBA3F, // 3034
+    0xBA41, // 3035
+    0xBA43, // 3036
+    0xBA44, // 3037
+    0xBA45, // 3038
+    0xBA46, // 3039
+    0xBA47, // 3040
+    0xBA4A, // 3041
+    0xBA4C, // 3042
+    0xBA4F, // 3043
+    0xBA50, // 3044
+    0xBA51, // 3045
+    0xBA52, // 3046
+    0xBA56, // 3047
+    0xBA57, // 3048
+    0xBA59, // 3049
+    0xBA5A, // 3050
+    0xBA5B, // 3051
+    0xBA5D, // 3052
+    0xBA5E, // 3053
+    0xBA5F, // 3054
+    0xBA60, // 3055
+    0xBA61, // 3056
+    0xBA62, // 3057
+    0xBA63, // 3058
+    0xBA66, // 3059
+    0xBA6A, // 3060
+    0xBA6B, // 3061
+    0xBA6C, // 3062
+    0xBA6D, // 3063
+    0xBA6E, // 3064
+    0xBA6F, // 3065
+    0x0000, // 3066
+    0x0000, // 3067
+    0x0000, // 3068
+    0x0000, // 3069
+    0x0000, // 3070
+    0x0000, // 3071
+    0xBA72, // 3072
+    0xBA73, // 3073
+    0xBA75, // 3074
+    0xBA76, // 3075
+    0xBA77, // 3076
+    0xBA79, // 3077
+    0xBA7A, // 3078
+    0xBA7B, // 3079
+    0xBA7C, // 3080
+    0xBA7D, // 3081
+    0xBA7E, // 3082
+    0xBA7F, // 3083
+    0xBA80, // 3084
+    0xBA81, // 3085
+    0xBA82, // 3086
+    0xBA86, // 3087
+    0xBA88, // 3088
+    0xBA89, // 3089
+    0xBA8A, // 3090
+    0xBA8B, // 3091
+    0xBA8D, // 3092
+    0xBA8E, // 3093
+    0xBA8F, // 3094
+    0xBA90, // 3095
+    0xBA91, // 3096
+    0xBA92, // 3097
+    0x0000, // 3098
+    0x0000, // 3099
+    0x0000, // 3100
+    0x0000, // 3101
+    0x0000, // 3102
+    0x0000, // 3103
+    0xBA93, // 3104
+    0xBA94, // 3105
+    0xBA95, // 3106
+    0xBA96, // 3107
+    0xBA97, // 3108
+    0xBA98, // 3109
+    0xBA99, // 3110
+    0xBA9A, // 3111
+    0xBA9B, // 3112
+    0xBA9C, // 3113
+    0xBA9D, // 3114
+    0xBA9E, // 3115
+    0xBA9F, // 3116
+    0xBAA0, // 3117
+    0xBAA1, // 3118
+    0xBAA2, // 3119
+    0xBAA3, // 3120
+    0xBAA4, // 3121
+    0xBAA5, // 3122
+    0xBAA6, // 3123
+    0xBAA7, // 3124
+    0xBAAA, // 3125
+    0xBAAD, // 3126
+    0xBAAE, // 3127
+    0xBAAF, // 3128
+    0xBAB1, // 3129
+    0xBAB3, // 3130
+    0xBAB4, // 3131
+    0xBAB5, // 3132
+    0xBAB6, // 3133
+    0xBAB7, // 3134
+    0xBABA, // 3135
+    0xBABC, // 3136
+    0xBABE, // 3137
+    0xBABF, // 3138
+    0xBAC0, // 3139
+    0xBAC1, // 3140
+    0xBAC2, // 3141
+    0xBAC3, // 3142
+    0xBAC5, // 3143
+    0xBAC6, // 3144
+    0xBAC7, // 3145
+    0xBAC9, // 3146
+    0xBACA, // 3147
+    0xBACB, // 3148
+    0xBACC, // 3149
+    0xBACD, // 3150
+    0xBACE, // 3151
+    0xBACF, // 3152
+    0xBAD0, // 3153
+    0xBAD1, // 3154
+    0xBAD2, // 3155
+    0xBAD3, // 3156
+    0xBAD4, // 3157
+    0xBAD5, // 3158
+    0xBAD6, // 3159
+    0xBAD7, // 3160
+    0xBADA, // 3161
+    0xBADB, // 3162
+    0xBADC, // 3163
+    0xBADD, // 3164
+    0xBADE, // 3165
+    0xBADF, // 3166
+    0xBAE0, // 3167
+    0xBAE1, // 3168
+    0xBAE2, // 3169
+    0xBAE3, // 3170
+    0xBAE4, // 3171
+    0xBAE5, // 3172
+    0xBAE6, // 3173
+    0xBAE7, // 3174
+    0xBAE8, // 3175
+    0xBAE9, // 3176
+    0xBAEA, // 3177
+    0xBAEB, // 3178
+    0xBAEC, // 3179
+    0xBAED, // 3180
+    0xBAEE, // 3181
+    0xBAEF, // 3182
+    0xBAF0, // 3183
+    0xBAF1, // 3184
+    0xBAF2, // 3185
+    0xBAF3, // 3186
+    0xBAF4, // 3187
+    0xBAF5, // 3188
+    0xBAF6, // 3189
+    0xBAF7, // 3190
+    0xBAF8, // 3191
+    0xBAF9, // 3192
+    0xBAFA, // 3193
+    0xBAFB, // 3194
+    0xBAFD, // 3195
+    0xBAFE, // 3196
+    0xBAFF, // 3197
+    0xBB01, // 3198
+    0xBB02, // 3199
+    0xBB03, // 3200
+    0xBB05, // 3201
+    0xBB06, // 3202
+    0xBB07, // 3203
+    0xBB08, // 3204
+    0xBB09, // 3205
+    0xBB0A, // 3206
+    0xBB0B, // 3207
+    0xBB0C, // 3208
+    0xBB0E, // 3209
+    0xBB10, // 3210
+    0xBB12, // 3211
+    0xBB13, // 3212
+    0xBB14, // 3213
+    0xBB15, // 3214
+    0xBB16, // 3215
+    0xBB17, // 3216
+    0xBB19, // 3217
+    0xBB1A, // 3218
+    0xBB1B, // 3219
+    0xBB1D, // 3220
+    0xBB1E, // 3221
+    0xBB1F, // 3222
+    0xBB21, // 3223
+    0xBB22, // 3224
+    0xBB23, // 3225
+    0xBB24, // 3226
+    0xBB25, // 3227
+    0xBB26, // 3228
+    0xBB27, // 3229
+    0xBB28, // 3230
+    0xBB2A, // 3231
+    0xBB2C, // 3232
+    0xBB2D, // 3233
+    0xBB2E, // 3234
+    0xBB2F, // 3235
+    0xBB30, // 3236
+    0xBB31, // 3237
+    0xBB32, // 3238
+    0xBB33, // 3239
+    0xBB37, // 3240
+    0xBB39, // 3241
+    0xBB3A, // 3242
+    0xBB3F, // 3243
+    0xBB40, // 3244
+    0xBB41, // 3245
+    0xBB42, // 3246
+    0xBB43, // 3247
+    0xBB46, // 3248
+    0xBB48, // 3249
+    0xBB4A, // 3250
+    0xBB4B, // 3251
+    0xBB4C, // 3252
+    0xBB4E, // 3253
+    0xBB51, // 3254
+    0xBB52, // 3255
+    0x0000, // 3256
+    0x0000, // 3257
+    0x0000, // 3258
+    0x0000, // 3259
+    0x0000, // 3260
+    0x0000, // 3261
+    0xBB53, // 3262
+    0xBB55, // 3263
+    0xBB56, // 3264
+    0xBB57, // 3265
+    0xBB59, // 3266
+    0xBB5A, // 3267
+    0xBB5B, // 3268
+    0xBB5C, // 3269
+    0xBB5D, // 3270
+    0xBB5E, // 3271
+    0xBB5F, // 3272
+    0xBB60, // 3273
+    0xBB62, // 3274
+    0xBB64, // 3275
+    0xBB65, // 3276
+    0xBB66, // 3277
+    0xBB67, // 3278
+    0xBB68, // 3279
+    0xBB69, // 3280
+    0xBB6A, // 3281
+    0xBB6B, // 3282
+    0xBB6D, // 3283
+    0xBB6E, // 3284
+    0xBB6F, // 3285
+    0xBB70, // 3286
+    0xBB71, // 3287
+    0x0000, // 3288
+    0x0000, // 3289
+    0x0000, // 3290
+    0x0000, // 3291
+    0x0000, // 3292
+    0x0000, // 3293
+    0xBB72, // 3294
+    0xBB73, // 3295
+    0xBB74, // 3296
+    0xBB75, // 3297
+    0xBB76, // 3298
+    0xBB77, // 3299
+    0xBB78, // 3300
+    0xBB79, // 3301
+    0xBB7A, // 3302
+    0xBB7B, // 3303
+    0xBB7C, // 3304
+    0xBB7D, // 3305
+    0xBB7E, // 3306
+    0xBB7F, // 3307
+    0xBB80, // 3308
+    0xBB81, // 3309
+    0xBB82, // 3310
+    0xBB83, // 3311
+    0xBB84, // 3312
+    0xBB85, // 3313
+    0xBB86, // 3314
+    0xBB87, // 3315
+    0xBB89, // 3316
+    0xBB8A, // 3317
+    0xBB8B, // 3318
+    0xBB8D, // 3319
+    0xBB8E, // 3320
+    0xBB8F, // 3321
+    0xBB91, // 3322
+    0xBB92, // 3323
+    0xBB93, // 3324
+    0xBB94, // 3325
+    0xBB95, // 3326
+    0xBB96, // 3327
+    0xBB97, // 3328
+    0xBB98, // 3329
+    0xBB99, // 3330
+    0xBB9A, // 3331
+    0xBB9B, // 3332
+    0xBB9C, // 3333
+    0xBB9D, // 3334
+    0xBB9E, // 3335
+    0xBB9F, // 3336
+    0xBBA0, // 3337
+    0xBBA1, // 3338
+    0xBBA2, // 3339
+    0xBBA3, // 3340
+    0xBBA5, // 3341
+    0xBBA6, // 3342
+    0xBBA7, // 3343
+    0xBBA9, // 3344
+    0xBBAA, // 3345
+    0xBBAB, // 3346
+    0xBBAD, // 3347
+    0xBBAE, // 3348
+    0xBBAF, // 3349
+    0xBBB0, // 3350
+    0xBBB1, // 3351
+    0xBBB2, // 3352
+    0xBBB3, // 3353
+    0xBBB5, // 3354
+    0xBBB6, // 3355
+    0xBBB8, // 3356
+    0xBBB9, // 3357
+    0xBBBA, // 3358
+    0xBBBB, // 3359
+    0xBBBC, // 3360
+    0xBBBD, // 3361
+    0xBBBE, // 3362
+    0xBBBF, // 3363
+    0xBBC1, // 3364
+    0xBBC2, // 3365
+    0xBBC3, // 3366
+    0xBBC5, // 3367
+    0xBBC6, // 3368
+    0xBBC7, // 3369
+    0xBBC9, // 3370
+    0xBBCA, // 3371
+    0xBBCB, // 3372
+    0xBBCC, // 3373
+    0xBBCD, // 3374
+    0xBBCE, // 3375
+    0xBBCF, // 3376
+    0xBBD1, // 3377
+    0xBBD2, // 3378
+    0xBBD4, // 3379
+    0xBBD5, // 3380
+    0xBBD6, // 3381
+    0xBBD7, // 3382
+    0xBBD8, // 3383
+    0xBBD9, // 3384
+    0xBBDA, // 3385
+    0xBBDB, // 3386
+    0xBBDC, // 3387
+    0xBBDD, // 3388
+    0xBBDE, // 3389
+    0xBBDF, // 3390
+    0xBBE0, // 3391
+    0xBBE1, // 3392
+    0xBBE2, // 3393
+    0xBBE3, // 3394
+    0xBBE4, // 3395
+    0xBBE5, // 3396
+    0xBBE6, // 3397
+    0xBBE7, // 3398
+    0xBBE8, // 3399
+    0xBBE9, // 3400
+    0xBBEA, // 3401
+    0xBBEB, // 3402
+    0xBBEC, // 3403
+    0xBBED, // 3404
+    0xBBEE, // 3405
+    0xBBEF, // 3406
+    0xBBF0, // 3407
+    0xBBF1, // 3408
+    0xBBF2, // 3409
+    0xBBF3, // 3410
+    0xBBF4, // 3411
+    0xBBF5, // 3412
+    0xBBF6, // 3413
+    0xBBF7, // 3414
+    0xBBFA, // 3415
+    0xBBFB, // 3416
+    0xBBFD, // 3417
+    0xBBFE, // 3418
+    0xBC01, // 3419
+    0xBC03, // 3420
+    0xBC04, // 3421
+    0xBC05, // 3422
+    0xBC06, // 3423
+    0xBC07, // 3424
+    0xBC0A, // 3425
+    0xBC0E, // 3426
+    0xBC10, // 3427
+    0xBC12, // 3428
+    0xBC13, // 3429
+    0xBC19, // 3430
+    0xBC1A, // 3431
+    0xBC20, // 3432
+    0xBC21, // 3433
+    0xBC22, // 3434
+    0xBC23, // 3435
+    0xBC26, // 3436
+    0xBC28, // 3437
+    0xBC2A, // 3438
+    0xBC2B, // 3439
+    0xBC2C, // 3440
+    0xBC2E, // 3441
+    0xBC2F, // 3442
+    0xBC32, // 3443
+    0xBC33, // 3444
+    0xBC35, // 3445
+    0x0000, // 3446
+    0x0000, // 3447
+    0x0000, // 3448
+    0x0000, // 3449
+    0x0000, // 3450
+    0x0000, // 3451
+    0xBC36, // 3452
+    0xBC37, // 3453
+    0xBC39, // 3454
+    0xBC3A, // 3455
+    0xBC3B, // 3456
+    0xBC3C, // 3457
+    0xBC3D, // 3458
+    0xBC3E, // 3459
+    0xBC3F, // 3460
+    0xBC42, // 3461
+    0xBC46, // 3462
+    0xBC47, // 3463
+    0xBC48, // 3464
+    0xBC4A, // 3465
+    0xBC4B, // 3466
+    0xBC4E, // 3467
+    0xBC4F, // 3468
+    0xBC51, // 3469
+    0xBC52, // 3470
+    0xBC53, // 3471
+    0xBC54, // 3472
+    0xBC55, // 3473
+    0xBC56, // 3474
+    0xBC57, // 3475
+    0xBC58, // 3476
+    0xBC59, // 3477
+    0x0000, // 3478
+    0x0000, // 3479
+    0x0000, // 3480
+    0x0000, // 3481
+    0x0000, // 3482
+    0x0000, // 3483
+    0xBC5A, // 3484
+    0xBC5B, // 3485
+    0xBC5C, // 3486
+    0xBC5E, // 3487
+    0xBC5F, // 3488
+    0xBC60, // 3489
+    0xBC61, // 3490
+    0xBC62, // 3491
+    0xBC63, // 3492
+    0xBC64, // 3493
+    0xBC65, // 3494
+    0xBC66, // 3495
+    0xBC67, // 3496
+    0xBC68, // 3497
+    0xBC69, // 3498
+    0xBC6A, // 3499
+    0xBC6B, // 3500
+    0xBC6C, // 3501
+    0xBC6D, // 3502
+    0xBC6E, // 3503
+    0xBC6F, // 3504
+    0xBC70, // 3505
+    0xBC71, // 3506
+    0xBC72, // 3507
+    0xBC73, // 3508
+    0xBC74, // 3509
+    0xBC75, // 3510
+    0xBC76, // 3511
+    0xBC77, // 3512
+    0xBC78, // 3513
+    0xBC79, // 3514
+    0xBC7A, // 3515
+    0xBC7B, // 3516
+    0xBC7C, // 3517
+    0xBC7D, // 3518
+    0xBC7E, // 3519
+    0xBC7F, // 3520
+    0xBC80, // 3521
+    0xBC81, // 3522
+    0xBC82, // 3523
+    0xBC83, // 3524
+    0xBC86, // 3525
+    0xBC87, // 3526
+    0xBC89, // 3527
+    0xBC8A, // 3528
+    0xBC8D, // 3529
+    0xBC8F, // 3530
+    0xBC90, // 3531
+    0xBC91, // 3532
+    0xBC92, // 3533
+    0xBC93, // 3534
+    0xBC96, // 3535
+    0xBC98, // 3536
+    0xBC9B, // 3537
+    0xBC9C, // 3538
+    0xBC9D, // 3539
+    0xBC9E, // 3540
+    0xBC9F, // 3541
+    0xBCA2, // 3542
+    0xBCA3, // 3543
+    0xBCA5, // 3544
+    0xBCA6, // 3545
+    0xBCA9, // 3546
+    0xBCAA, // 3547
+    0xBCAB, // 3548
+    0xBCAC, // 3549
+    0xBCAD, // 3550
+    0xBCAE, // 3551
+    0xBCAF, // 3552
+    0xBCB2, // 3553
+    0xBCB6, // 3554
+    0xBCB7, // 3555
+    0xBCB8, // 3556
+    0xBCB9, // 3557
+    0xBCBA, // 3558
+    0xBCBB, // 3559
+    0xBCBE, // 3560
+    0xBCBF, // 3561
+    0xBCC1, // 3562
+    0xBCC2, // 3563
+    0xBCC3, // 3564
+    0xBCC5, // 3565
+    0xBCC6, // 3566
+    0xBCC7, // 3567
+    0xBCC8, // 3568
+    0xBCC9, // 3569
+    0xBCCA, // 3570
+    0xBCCB, // 3571
+    0xBCCC, // 3572
+    0xBCCE, // 3573
+    0xBCD2, // 3574
+    0xBCD3, // 3575
+    0xBCD4, // 3576
+    0xBCD6, // 3577
+    0xBCD7, // 3578
+    0xBCD9, // 3579
+    0xBCDA, // 3580
+    0xBCDB, // 3581
+    0xBCDD, // 3582
+    0xBCDE, // 3583
+    0xBCDF, // 3584
+    0xBCE0, // 3585
+    0xBCE1, // 3586
+    0xBCE2, // 3587
+    0xBCE3, // 3588
+    0xBCE4, // 3589
+    0xBCE5, // 3590
+    0xBCE6, // 3591
+    0xBCE7, // 3592
+    0xBCE8, // 3593
+    0xBCE9, // 3594
+    0xBCEA, // 3595
+    0xBCEB, // 3596
+    0xBCEC, // 3597
+    0xBCED, // 3598
+    0xBCEE, // 3599
+    0xBCEF, // 3600
+    0xBCF0, // 3601
+    0xBCF1, // 3602
+    0xBCF2, // 3603
+    0xBCF3, // 3604
+    0xBCF7, // 3605
+    0xBCF9, // 3606
+    0xBCFA, // 3607
+    0xBCFB, // 3608
+    0xBCFD, // 3609
+    0xBCFE, // 3610
+    0xBCFF, // 3611
+    0xBD00, // 3612
+    0xBD01, // 3613
+    0xBD02, // 3614
+    0xBD03, // 3615
+    0xBD06, // 3616
+    0xBD08, // 3617
+    0xBD0A, // 3618
+    0xBD0B, // 3619
+    0xBD0C, // 3620
+    0xBD0D, // 3621
+    0xBD0E, // 3622
+    0xBD0F, // 3623
+    0xBD11, // 3624
+    0xBD12, // 3625
+    0xBD13, // 3626
+    0xBD15, // 3627
+    0xBD16, // 3628
+    0xBD17, // 3629
+    0xBD18, // 3630
+    0xBD19, // 3631
+    0xBD1A, // 3632
+    0xBD1B, // 3633
+    0xBD1C, // 3634
+    0xBD1D, // 3635
+    0x0000, // 3636
+    0x0000, // 3637
+    0x0000, // 3638
+    0x0000, // 3639
+    0x0000, // 3640
+    0x0000, // 3641
+    0xBD1E, // 3642
+    0xBD1F, // 3643
+    0xBD20, // 3644
+    0xBD21, // 3645
+    0xBD22, // 3646
+    0xBD23, // 3647
+    0xBD25, // 3648
+    0xBD26, // 3649
+    0xBD27, // 3650
+    0xBD28, // 3651
+    0xBD29, // 3652
+    0xBD2A, // 3653
+    0xBD2B, // 3654
+    0xBD2D, // 3655
+    0xBD2E, // 3656
+    0xBD2F, // 3657
+    0xBD30, // 3658
+    0xBD31, // 3659
+    0xBD32, // 3660
+    0xBD33, // 3661
+    0xBD34, // 3662
+    0xBD35, // 3663
+    0xBD36, // 3664
+    0xBD37, // 3665
+    0xBD38, // 3666
+    0xBD39, // 3667
+    0x0000, // 3668
+    0x0000, // 3669
+    0x0000, // 3670
+    0x0000, // 3671
+    0x0000, // 3672
+    0x0000, // 3673
+    0xBD3A, // 3674
+    0xBD3B, // 3675
+    0xBD3C, // 3676
+    0xBD3D, // 3677
+    0xBD3E, // 3678
+    0xBD3F, // 3679
+    0xBD41, // 3680
+    0xBD42, // 3681
+    0xBD43, // 3682
+    0xBD44, // 3683
+    0xBD45, // 3684
+    0xBD46, // 3685
+    0xBD47, // 3686
+    0xBD4A, // 3687
+    0xBD4B, // 3688
+    0xBD4D, // 3689
+    0xBD4E, // 3690
+    0xBD4F, // 3691
+    0xBD51, // 3692
+    0xBD52, // 3693
+    0xBD53, // 3694
+    0xBD54, // 3695
+    0xBD55, // 3696
+    0xBD56, // 3697
+    0xBD57, // 3698
+    0xBD5A, // 3699
+    0xBD5B, // 3700
+    0xBD5C, // 3701
+    0xBD5D, // 3702
+    0xBD5E, // 3703
+    0xBD5F, // 3704
+    0xBD60, // 3705
+    0xBD61, // 3706
+    0xBD62, // 3707
+    0xBD63, // 3708
+    0xBD65, // 3709
+    0xBD66, // 3710
+    0xBD67, // 3711
+    0xBD69, // 3712
+    0xBD6A, // 3713
+    0xBD6B, // 3714
+    0xBD6C, // 3715
+    0xBD6D, // 3716
+    0xBD6E, // 3717
+    0xBD6F, // 3718
+    0xBD70, // 3719
+    0xBD71, // 3720
+    0xBD72, // 3721
+    0xBD73, // 3722
+    0xBD74, // 3723
+    0xBD75, // 3724
+    0xBD76, // 3725
+    0xBD77, // 3726
+    0xBD78, // 3727
+    0xBD79, // 3728
+    0xBD7A, // 3729
+    0xBD7B, // 3730
+    0xBD7C, // 3731
+    0xBD7D, // 3732
+    0xBD7E, // 3733
+    0xBD7F, // 3734
+    0xBD82, // 3735
+    0xBD83, // 3736
+    0xBD85, // 3737
+    0xBD86, // 3738
+    0xBD8B, // 3739
+    0xBD8C, // 3740
+    0xBD8D, // 3741
+    0xBD8E, // 3742
+    0xBD8F, // 3743
+    0xBD92, // 3744
+    0xBD94, // 3745
+    0xBD96, // 3746
+    0xBD97, // 3747
+    0xBD98, // 3748
+    0xBD9B, // 3749
+    0xBD9D, // 3750
+    0xBD9E, // 3751
+    0xBD9F, // 3752
+    0xBDA0, // 3753
+    0xBDA1, // 3754
+    0xBDA2, // 3755
+    0xBDA3, // 3756
+    0xBDA5, // 3757
+    0xBDA6, // 3758
+    0xBDA7, // 3759
+    0xBDA8, // 3760
+    0xBDA9, // 3761
+    0xBDAA, // 3762
+    0xBDAB, // 3763
+    0xBDAC, // 3764
+    0xBDAD, // 3765
+    0xBDAE, // 3766
+    0xBDAF, // 3767
+    0xBDB1, // 3768
+    0xBDB2, // 3769
+    0xBDB3, // 3770
+    0xBDB4, // 3771
+    0xBDB5, // 3772
+    0xBDB6, // 3773
+    0xBDB7, // 3774
+    0xBDB9, // 3775
+    0xBDBA, // 3776
+    0xBDBB, // 3777
+    0xBDBC, // 3778
+    0xBDBD, // 3779
+    0xBDBE, // 3780
+    0xBDBF, // 3781
+    0xBDC0, // 3782
+    0xBDC1, // 3783
+    0xBDC2, // 3784
+    0xBDC3, // 3785
+    0xBDC4, // 3786
+    0xBDC5, // 3787
+    0xBDC6, // 3788
+    0xBDC7, // 3789
+    0xBDC8, // 3790
+    0xBDC9, // 3791
+    0xBDCA, // 3792
+    0xBDCB, // 3793
+    0xBDCC, // 3794
+    0xBDCD, // 3795
+    0xBDCE, // 3796
+    0xBDCF, // 3797
+    0xBDD0, // 3798
+    0xBDD1, // 3799
+    0xBDD2, // 3800
+    0xBDD3, // 3801
+    0xBDD6, // 3802
+    0xBDD7, // 3803
+    0xBDD9, // 3804
+    0xBDDA, // 3805
+    0xBDDB, // 3806
+    0xBDDD, // 3807
+    0xBDDE, // 3808
+    0xBDDF, // 3809
+    0xBDE0, // 3810
+    0xBDE1, // 3811
+    0xBDE2, // 3812
+    0xBDE3, // 3813
+    0xBDE4, // 3814
+    0xBDE5, // 3815
+    0xBDE6, // 3816
+    0xBDE7, // 3817
+    0xBDE8, // 3818
+    0xBDEA, // 3819
+    0xBDEB, // 3820
+    0xBDEC, // 3821
+    0xBDED, // 3822
+    0xBDEE, // 3823
+    0xBDEF, // 3824
+    0xBDF1, // 3825
+    0x0000, // 3826
+    0x0000, // 3827
+    0x0000, // 3828
+    0x0000, // 3829
+    0x0000, // 3830
+    0x0000, // 3831
+    0xBDF2, // 3832
+    0xBDF3, // 3833
+    0xBDF5, // 3834
+    0xBDF6, // 3835
+    0xBDF7, // 3836
+    0xBDF9, // 3837
+    0xBDFA, // 3838
+    0xBDFB, // 3839
+    0xBDFC, // 3840
+    0xBDFD, // 3841
+    0xBDFE, // 3842
+    0xBDFF, // 3843
+    0xBE01, // 3844
+    0xBE02, // 3845
+    0xBE04, // 3846
+    0xBE06, // 3847
+    0xBE07, // 3848
+    0xBE08, // 3849
+    0xBE09, // 3850
+    0xBE0A, // 3851
+    0xBE0B, // 3852
+    0xBE0E, // 3853
+    0xBE0F, // 3854
+    0xBE11, // 3855
+    0xBE12, // 3856
+    0xBE13, // 3857
+    0x0000, // 3858
+    0x0000, // 3859
+    0x0000, // 3860
+    0x0000, // 3861
+    0x0000, // 3862
+    0x0000, // 3863
+    0xBE15, // 3864
+    0xBE16, // 3865
+    0xBE17, // 3866
+    0xBE18, // 3867
+    0xBE19, // 3868
+    0xBE1A, // 3869
+    0xBE1B, // 3870
+    0xBE1E, // 3871
+    0xBE20, // 3872
+    0xBE21, // 3873
+    0xBE22, // 3874
+    0xBE23, // 3875
+    0xBE24, // 3876
+    0xBE25, // 3877
+    0xBE26, // 3878
+    0xBE27, // 3879
+    0xBE28, // 3880
+    0xBE29, // 3881
+    0xBE2A, // 3882
+    0xBE2B, // 3883
+    0xBE2C, // 3884
+    0xBE2D, // 3885
+    0xBE2E, // 3886
+    0xBE2F, // 3887
+    0xBE30, // 3888
+    0xBE31, // 3889
+    0xBE32, // 3890
+    0xBE33, // 3891
+    0xBE34, // 3892
+    0xBE35, // 3893
+    0xBE36, // 3894
+    0xBE37, // 3895
+    0xBE38, // 3896
+    0xBE39, // 3897
+    0xBE3A, // 3898
+    0xBE3B, // 3899
+    0xBE3C, // 3900
+    0xBE3D, // 3901
+    0xBE3E, // 3902
+    0xBE3F, // 3903
+    0xBE40, // 3904
+    0xBE41, // 3905
+    0xBE42, // 3906
+    0xBE43, // 3907
+    0xBE46, // 3908
+    0xBE47, // 3909
+    0xBE49, // 3910
+    0xBE4A, // 3911
+    0xBE4B, // 3912
+    0xBE4D, // 3913
+    0xBE4F, // 3914
+    0xBE50, // 3915
+    0xBE51, // 3916
+    0xBE52, // 3917
+    0xBE53, // 3918
+    0xBE56, // 3919
+    0xBE58, // 3920
+    0xBE5C, // 3921
+    0xBE5D, // 3922
+    0xBE5E, // 3923
+    0xBE5F, // 3924
+    0xBE62, // 3925
+    0xBE63, // 3926
+    0xBE65, // 3927
+    0xBE66, // 3928
+    0xBE67, // 3929
+    0xBE69, // 3930
+    0xBE6B, // 3931
+    0xBE6C, // 3932
+    0xBE6D, // 3933
+    0xBE6E, // 3934
+    0xBE6F, // 3935
+    0xBE72, // 3936
+    0xBE76, // 3937
+    0xBE77, // 3938
+    0xBE78, // 3939
+    0xBE79, // 3940
+    0xBE7A, // 3941
+    0xBE7E, // 3942
+    0xBE7F, // 3943
+    0xBE81, // 3944
+    0xBE82, // 3945
+    0xBE83, // 3946
+    0xBE85, // 3947
+    0xBE86, // 3948
+    0xBE87, // 3949
+    0xBE88, // 3950
+    0xBE89, // 3951
+    0xBE8A, // 3952
+    0xBE8B, // 3953
+    0xBE8E, // 3954
+    0xBE92, // 3955
+    0xBE93, // 3956
+    0xBE94, // 3957
+    0xBE95, // 3958
+    0xBE96, // 3959
+    0xBE97, // 3960
+    0xBE9A, // 3961
+    0xBE9B, // 3962
+    0xBE9C, // 3963
+    0xBE9D, // 3964
+    0xBE9E, // 3965
+    0xBE9F, // 3966
+    0xBEA0, // 3967
+    0xBEA1, // 3968
+    0xBEA2, // 3969
+    0xBEA3, // 3970
+    0xBEA4, // 3971
+    0xBEA5, // 3972
+    0xBEA6, // 3973
+    0xBEA7, // 3974
+    0xBEA9, // 3975
+    0xBEAA, // 3976
+    0xBEAB, // 3977
+    0xBEAC, // 3978
+    0xBEAD, // 3979
+    0xBEAE, // 3980
+    0xBEAF, // 3981
+    0xBEB0, // 3982
+    0xBEB1, // 3983
+    0xBEB2, // 3984
+    0xBEB3, // 3985
+    0xBEB4, // 3986
+    0xBEB5, // 3987
+    0xBEB6, // 3988
+    0xBEB7, // 3989
+    0xBEB8, // 3990
+    0xBEB9, // 3991
+    0xBEBA, // 3992
+    0xBEBB, // 3993
+    0xBEBC, // 3994
+    0xBEBD, // 3995
+    0xBEBE, // 3996
+    0xBEBF, // 3997
+    0xBEC0, // 3998
+    0xBEC1, // 3999
+    0xBEC2, // 4000
+    0xBEC3, // 4001
+    0xBEC4, // 4002
+    0xBEC5, // 4003
+    0xBEC6, // 4004
+    0xBEC7, // 4005
+    0xBEC8, // 4006
+    0xBEC9, // 4007
+    0xBECA, // 4008
+    0xBECB, // 4009
+    0xBECC, // 4010
+    0xBECD, // 4011
+    0xBECE, // 4012
+    0xBECF, // 4013
+    0xBED2, // 4014
+    0xBED3, // 4015
+    0x0000, // 4016
+    0x0000, // 4017
+    0x0000, // 4018
+    0x0000, // 4019
+    0x0000, // 4020
+    0x0000, // 4021
+    0xBED5, // 4022
+    0xBED6, // 4023
+    0xBED9, // 4024
+    0xBEDA, // 4025
+    0xBEDB, // 4026
+    0xBEDC, // 4027
+    0xBEDD, // 4028
+    0xBEDE, // 4029
+    0xBEDF, // 4030
+    0xBEE1, // 4031
+    0xBEE2, // 4032
+    0xBEE6, // 4033
+    0xBEE7, // 4034
+    0xBEE8, // 4035
+    0xBEE9, // 4036
+    0xBEEA, // 4037
+    0xBEEB, // 4038
+    0xBEED, // 4039
+    0xBEEE, // 4040
+    0xBEEF, // 4041
+    0xBEF0, // 4042
+    0xBEF1, // 4043
+    0xBEF2, // 4044
+    0xBEF3, // 4045
+    0xBEF4, // 4046
+    0xBEF5, // 4047
+    0x0000, // 4048
+    0x0000, // 4049
+    0x0000, // 4050
+    0x0000, // 4051
+    0x0000, // 4052
+    0x0000, // 4053
+    0xBEF6, // 4054
+    0xBEF7, // 4055
+    0xBEF8, // 4056
+    0xBEF9, // 4057
+    0xBEFA, // 4058
+    0xBEFB, // 4059
+    0xBEFC, // 4060
+    0xBEFD, // 4061
+    0xBEFE, // 4062
+    0xBEFF, // 4063
+    0xBF00, // 4064
+    0xBF02, // 4065
+    0xBF03, // 4066
+    0xBF04, // 4067
+    0xBF05, // 4068
+    0xBF06, // 4069
+    0xBF07, // 4070
+    0xBF0A, // 4071
+    0xBF0B, // 4072
+    0xBF0C, // 4073
+    0xBF0D, // 4074
+    0xBF0E, // 4075
+    0xBF0F, // 4076
+    0xBF10, // 4077
+    0xBF11, // 4078
+    0xBF12, // 4079
+    0xBF13, // 4080
+    0xBF14, // 4081
+    0xBF15, // 4082
+    0xBF16, // 4083
+    0xBF17, // 4084
+    0xBF1A, // 4085
+    0xBF1E, // 4086
+    0xBF1F, // 4087
+    0xBF20, // 4088
+    0xBF21, // 4089
+    0xBF22, // 4090
+    0xBF23, // 4091
+    0xBF24, // 4092
+    0xBF25, // 4093
+    0xBF26, // 4094
+    0xBF27, // 4095
+    0xBF28, // 4096
+    0xBF29, // 4097
+    0xBF2A, // 4098
+    0xBF2B, // 4099
+    0xBF2C, // 4100
+    0xBF2D, // 4101
+    0xBF2E, // 4102
+    0xBF2F, // 4103
+    0xBF30, // 4104
+    0xBF31, // 4105
+    0xBF32, // 4106
+    0xBF33, // 4107
+    0xBF34, // 4108
+    0xBF35, // 4109
+    0xBF36, // 4110
+    0xBF37, // 4111
+    0xBF38, // 4112
+    0xBF39, // 4113
+    0xBF3A, // 4114
+    0xBF3B, // 4115
+    0xBF3C, // 4116
+    0xBF3D, // 4117
+    0xBF3E, // 4118
+    0xBF3F, // 4119
+    0xBF42, // 4120
+    0xBF43, // 4121
+    0xBF45, // 4122
+    0xBF46, // 4123
+    0xBF47, // 4124
+    0xBF49, // 4125
+    0xBF4A, // 4126
+    0xBF4B, // 4127
+    0xBF4C, // 4128
+    0xBF4D, // 4129
+    0xBF4E, // 4130
+    0xBF4F, // 4131
+    0xBF52, // 4132
+    0xBF53, // 4133
+    0xBF54, // 4134
+    0xBF56, // 4135
+    0xBF57, // 4136
+    0xBF58, // 4137
+    0xBF59, // 4138
+    0xBF5A, // 4139
+    0xBF5B, // 4140
+    0xBF5C, // 4141
+    0xBF5D, // 4142
+    0xBF5E, // 4143
+    0xBF5F, // 4144
+    0xBF60, // 4145
+    0xBF61, // 4146
+    0xBF62, // 4147
+    0xBF63, // 4148
+    0xBF64, // 4149
+    0xBF65, // 4150
+    0xBF66, // 4151
+    0xBF67, // 4152
+    0xBF68, // 4153
+    0xBF69, // 4154
+    0xBF6A, // 4155
+    0xBF6B, // 4156
+    0xBF6C, // 4157
+    0xBF6D, // 4158
+    0xBF6E, // 4159
+    0xBF6F, // 4160
+    0xBF70, // 4161
+    0xBF71, // 4162
+    0xBF72, // 4163
+    0xBF73, // 4164
+    0xBF74, // 4165
+    0xBF75, // 4166
+    0xBF76, // 4167
+    0xBF77, // 4168
+    0xBF78, // 4169
+    0xBF79, // 4170
+    0xBF7A, // 4171
+    0xBF7B, // 4172
+    0xBF7C, // 4173
+    0xBF7D, // 4174
+    0xBF7E, // 4175
+    0xBF7F, // 4176
+    0xBF80, // 4177
+    0xBF81, // 4178
+    0xBF82, // 4179
+    0xBF83, // 4180
+    0xBF84, // 4181
+    0xBF85, // 4182
+    0xBF86, // 4183
+    0xBF87, // 4184
+    0xBF88, // 4185
+    0xBF89, // 4186
+    0xBF8A, // 4187
+    0xBF8B, // 4188
+    0xBF8C, // 4189
+    0xBF8D, // 4190
+    0xBF8E, // 4191
+    0xBF8F, // 4192
+    0xBF90, // 4193
+    0xBF91, // 4194
+    0xBF92, // 4195
+    0xBF93, // 4196
+    0xBF95, // 4197
+    0xBF96, // 4198
+    0xBF97, // 4199
+    0xBF98, // 4200
+    0xBF99, // 4201
+    0xBF9A, // 4202
+    0xBF9B, // 4203
+    0xBF9C, // 4204
+    0xBF9D, // 4205
+    0x0000, // 4206
+    0x0000, // 4207
+    0x0000, // 4208
+    0x0000, // 4209
+    0x0000, // 4210
+    0x0000, // 4211
+    0xBF9E, // 4212
+    0xBF9F, // 4213
+    0xBFA0, // 4214
+    0xBFA1, // 4215
+    0xBFA2, // 4216
+    0xBFA3, // 4217
+    0xBFA4, // 4218
+    0xBFA5, // 4219
+    0xBFA6, // 4220
+    0xBFA7, // 4221
+    0xBFA8, // 4222
+    0xBFA9, // 4223
+    0xBFAA, // 4224
+    0xBFAB, // 4225
+    0xBFAC, // 4226
+    0xBFAD, // 4227
+    0xBFAE, // 4228
+    0xBFAF, // 4229
+    0xBFB1, // 4230
+    0xBFB2, // 4231
+    0xBFB3, // 4232
+    0xBFB4, // 4233
+    0xBFB5, // 4234
+    0xBFB6, // 4235
+    0xBFB7, // 4236
+    0xBFB8, // 4237
+    0x0000, // 4238
+    0x0000, // 4239
+    0x0000, // 4240
+    0x0000, // 4241
+    0x0000, // 4242
+    0x0000, // 4243
+    0xBFB9, // 4244
+    0xBFBA, // 4245
+    0xBFBB, // 4246
+    0xBFBC, // 4247
+    0xBFBD, // 4248
+    0xBFBE, // 4249
+    0xBFBF, // 4250
+    0xBFC0, // 4251
+    0xBFC1, // 4252
+    0xBFC2, // 4253
+    0xBFC3, // 4254
+    0xBFC4, // 4255
+    0xBFC6, // 4256
+    0xBFC7, // 4257
+    0xBFC8, // 4258
+    0xBFC9, // 4259
+    0xBFCA, // 4260
+    0xBFCB, // 4261
+    0xBFCE, // 4262
+    0xBFCF, // 4263
+    0xBFD1, // 4264
+    0xBFD2, // 4265
+    0xBFD3, // 4266
+    0xBFD5, // 4267
+    0xBFD6, // 4268
+    0xBFD7, // 4269
+    0xBFD8, // 4270
+    0xBFD9, // 4271
+    0xBFDA, // 4272
+    0xBFDB, // 4273
+    0xBFDD, // 4274
+    0xBFDE, // 4275
+    0xBFE0, // 4276
+    0xBFE2, // 4277
+    0xBFE3, // 4278
+    0xBFE4, // 4279
+    0xBFE5, // 4280
+    0xBFE6, // 4281
+    0xBFE7, // 4282
+    0xBFE8, // 4283
+    0xBFE9, // 4284
+    0xBFEA, // 4285
+    0xBFEB, // 4286
+    0xBFEC, // 4287
+    0xBFED, // 4288
+    0xBFEE, // 4289
+    0xBFEF, // 4290
+    0xBFF0, // 4291
+    0xBFF1, // 4292
+    0xBFF2, // 4293
+    0xBFF3, // 4294
+    0xBFF4, // 4295
+    0xBFF5, // 4296
+    0xBFF6, // 4297
+    0xBFF7, // 4298
+    0xBFF8, // 4299
+    0xBFF9, // 4300
+    0xBFFA, // 4301
+    0xBFFB, // 4302
+    0xBFFC, // 4303
+    0xBFFD, // 4304
+    0xBFFE, // 4305
+    0xBFFF, // 4306
+    0xC000, // 4307
+    0xC001, // 4308
+    0xC002, // 4309
+    0xC003, // 4310
+    0xC004, // 4311
+    0xC005, // 4312
+    0xC006, // 4313
+    0xC007, // 4314
+    0xC008, // 4315
+    0xC009, // 4316
+    0xC00A, // 4317
+    0xC00B, // 4318
+    0xC00C, // 4319
+    0xC00D, // 4320
+    0xC00E, // 4321
+    0xC00F, // 4322
+    0xC010, // 4323
+    0xC011, // 4324
+    0xC012, // 4325
+    0xC013, // 4326
+    0xC014, // 4327
+    0xC015, // 4328
+    0xC016, // 4329
+    0xC017, // 4330
+    0xC018, // 4331
+    0xC019, // 4332
+    0xC01A, // 4333
+    0xC01B, // 4334
+    0xC01C, // 4335
+    0xC01D, // 4336
+    0xC01E, // 4337
+    0xC01F, // 4338
+    0xC020, // 4339
+    0xC021, // 4340
+    0xC022, // 4341
+    0xC023, // 4342
+    0xC024, // 4343
+    0xC025, // 4344
+    0xC026, // 4345
+    0xC027, // 4346
+    0xC028, // 4347
+    0xC029, // 4348
+    0xC02A, // 4349
+    0xC02B, // 4350
+    0xC02C, // 4351
+    0xC02D, // 4352
+    0xC02E, // 4353
+    0xC02F, // 4354
+    0xC030, // 4355
+    0xC031, // 4356
+    0xC032, // 4357
+    0xC033, // 4358
+    0xC034, // 4359
+    0xC035, // 4360
+    0xC036, // 4361
+    0xC037, // 4362
+    0xC038, // 4363
+    0xC039, // 4364
+    0xC03A, // 4365
+    0xC03B, // 4366
+    0xC03D, // 4367
+    0xC03E, // 4368
+    0xC03F, // 4369
+    0xC040, // 4370
+    0xC041, // 4371
+    0xC042, // 4372
+    0xC043, // 4373
+    0xC044, // 4374
+    0xC045, // 4375
+    0xC046, // 4376
+    0xC047, // 4377
+    0xC048, // 4378
+    0xC049, // 4379
+    0xC04A, // 4380
+    0xC04B, // 4381
+    0xC04C, // 4382
+    0xC04D, // 4383
+    0xC04E, // 4384
+    0xC04F, // 4385
+    0xC050, // 4386
+    0xC052, // 4387
+    0xC053, // 4388
+    0xC054, // 4389
+    0xC055, // 4390
+    0xC056, // 4391
+    0xC057, // 4392
+    0xC059, // 4393
+    0xC05A, // 4394
+    0xC05B, // 4395
+    0x0000, // 4396
+    0x0000, // 4397
+    0x0000, // 4398
+    0x0000, // 4399
+    0x0000, // 4400
+    0x0000, // 4401
+    0xC05D, // 4402
+    0xC05E, // 4403
+    0xC05F, // 4404
+    0xC061, // 4405
+    0xC062, // 4406
+    0xC063, // 4407
+    0xC064, // 4408
+    0xC065, // 4409
+    0xC066, // 4410
+    0xC067, // 4411
+    0xC06A, // 4412
+    0xC06B, // 4413
+    0xC06C, // 4414
+    0xC06D, // 4415
+    0xC06E, // 4416
+    0xC06F, // 4417
+    0xC070, // 4418
+    0xC071, // 4419
+    0xC072, // 4420
+    0xC073, // 4421
+    0xC074, // 4422
+    0xC075, // 4423
+    0xC076, // 4424
+    0xC077, // 4425
+    0xC078, // 4426
+    0xC079, // 4427
+    0x0000, // 4428
+    0x0000, // 4429
+    0x0000, // 4430
+    0x0000, // 4431
+    0x0000, // 4432
+    0x0000, // 4433
+    0xC07A, // 4434
+    0xC07B, // 4435
+    0xC07C, // 4436
+    0xC07D, // 4437
+    0xC07E, // 4438
+    0xC07F, // 4439
+    0xC080, // 4440
+    0xC081, // 4441
+    0xC082, // 4442
+    0xC083, // 4443
+    0xC084, // 4444
+    0xC085, // 4445
+    0xC086, // 4446
+    0xC087, // 4447
+    0xC088, // 4448
+    0xC089, // 4449
+    0xC08A, // 4450
+    0xC08B, // 4451
+    0xC08C, // 4452
+    0xC08D, // 4453
+    0xC08E, // 4454
+    0xC08F, // 4455
+    0xC092, // 4456
+    0xC093, // 4457
+    0xC095, // 4458
+    0xC096, // 4459
+    0xC097, // 4460
+    0xC099, // 4461
+    0xC09A, // 4462
+    0xC09B, // 4463
+    0xC09C, // 4464
+    0xC09D, // 4465
+    0xC09E, // 4466
+    0xC09F, // 4467
+    0xC0A2, // 4468
+    0xC0A4, // 4469
+    0xC0A6, // 4470
+    0xC0A7, // 4471
+    0xC0A8, // 4472
+    0xC0A9, // 4473
+    0xC0AA, // 4474
+    0xC0AB, // 4475
+    0xC0AE, // 4476
+    0xC0B1, // 4477
+    0xC0B2, // 4478
+    0xC0B7, // 4479
+    0xC0B8, // 4480
+    0xC0B9, // 4481
+    0xC0BA, // 4482
+    0xC0BB, // 4483
+    0xC0BE, // 4484
+    0xC0C2, // 4485
+    0xC0C3, // 4486
+    0xC0C4, // 4487
+    0xC0C6, // 4488
+    0xC0C7, // 4489
+    0xC0CA, // 4490
+    0xC0CB, // 4491
+    0xC0CD, // 4492
+    0xC0CE, // 4493
+    0xC0CF, // 4494
+    0xC0D1, // 4495
+    0xC0D2, // 4496
+    0xC0D3, // 4497
+    0xC0D4, // 4498
+    0xC0D5, // 4499
+    0xC0D6, // 4500
+    0xC0D7, // 4501
+    0xC0DA, // 4502
+    0xC0DE, // 4503
+    0xC0DF, // 4504
+    0xC0E0, // 4505
+    0xC0E1, // 4506
+    0xC0E2, // 4507
+    0xC0E3, // 4508
+    0xC0E6, // 4509
+    0xC0E7, // 4510
+    0xC0E9, // 4511
+    0xC0EA, // 4512
+    0xC0EB, // 4513
+    0xC0ED, // 4514
+    0xC0EE, // 4515
+    0xC0EF, // 4516
+    0xC0F0, // 4517
+    0xC0F1, // 4518
+    0xC0F2, // 4519
+    0xC0F3, // 4520
+    0xC0F6, // 4521
+    0xC0F8, // 4522
+    0xC0FA, // 4523
+    0xC0FB, // 4524
+    0xC0FC, // 4525
+    0xC0FD, // 4526
+    0xC0FE, // 4527
+    0xC0FF, // 4528
+    0xC101, // 4529
+    0xC102, // 4530
+    0xC103, // 4531
+    0xC105, // 4532
+    0xC106, // 4533
+    0xC107, // 4534
+    0xC109, // 4535
+    0xC10A, // 4536
+    0xC10B, // 4537
+    0xC10C, // 4538
+    0xC10D, // 4539
+    0xC10E, // 4540
+    0xC10F, // 4541
+    0xC111, // 4542
+    0xC112, // 4543
+    0xC113, // 4544
+    0xC114, // 4545
+    0xC116, // 4546
+    0xC117, // 4547
+    0xC118, // 4548
+    0xC119, // 4549
+    0xC11A, // 4550
+    0xC11B, // 4551
+    0xC121, // 4552
+    0xC122, // 4553
+    0xC125, // 4554
+    0xC128, // 4555
+    0xC129, // 4556
+    0xC12A, // 4557
+    0xC12B, // 4558
+    0xC12E, // 4559
+    0xC132, // 4560
+    0xC133, // 4561
+    0xC134, // 4562
+    0xC135, // 4563
+    0xC137, // 4564
+    0xC13A, // 4565
+    0xC13B, // 4566
+    0xC13D, // 4567
+    0xC13E, // 4568
+    0xC13F, // 4569
+    0xC141, // 4570
+    0xC142, // 4571
+    0xC143, // 4572
+    0xC144, // 4573
+    0xC145, // 4574
+    0xC146, // 4575
+    0xC147, // 4576
+    0xC14A, // 4577
+    0xC14E, // 4578
+    0xC14F, // 4579
+    0xC150, // 4580
+    0xC151, // 4581
+    0xC152, // 4582
+    0xC153, // 4583
+    0xC156, // 4584
+    0xC157, // 4585
+    0x0000, // 4586
+    0x0000, // 4587
+    0x0000, // 4588
+    0x0000, // 4589
+    0x0000, // 4590
+    0x0000, // 4591
+    0xC159, // 4592
+    0xC15A, // 4593
+    0xC15B, // 4594
+    0xC15D, // 4595
+    0xC15E, // 4596
+    0xC15F, // 4597
+    0xC160, // 4598
+    0xC161, // 4599
+    0xC162, // 4600
+    0xC163, // 4601
+    0xC166, // 4602
+    0xC16A, // 4603
+    0xC16B, // 4604
+    0xC16C, // 4605
+    0xC16D, // 4606
+    0xC16E, // 4607
+    0xC16F, // 4608
+    0xC171, // 4609
+    0xC172, // 4610
+    0xC173, // 4611
+    0xC175, // 4612
+    0xC176, // 4613
+    0xC177, // 4614
+    0xC179, // 4615
+    0xC17A, // 4616
+    0xC17B, // 4617
+    0x0000, // 4618
+    0x0000, // 4619
+    0x0000, // 4620
+    0x0000, // 4621
+    0x0000, // 4622
+    0x0000, // 4623
+    0xC17C, // 4624
+    0xC17D, // 4625
+    0xC17E, // 4626
+    0xC17F, // 4627
+    0xC180, // 4628
+    0xC181, // 4629
+    0xC182, // 4630
+    0xC183, // 4631
+    0xC184, // 4632
+    0xC186, // 4633
+    0xC187, // 4634
+    0xC188, // 4635
+    0xC189, // 4636
+    0xC18A, // 4637
+    0xC18B, // 4638
+    0xC18F, // 4639
+    0xC191, // 4640
+    0xC192, // 4641
+    0xC193, // 4642
+    0xC195, // 4643
+    0xC197, // 4644
+    0xC198, // 4645
+    0xC199, // 4646
+    0xC19A, // 4647
+    0xC19B, // 4648
+    0xC19E, // 4649
+    0xC1A0, // 4650
+    0xC1A2, // 4651
+    0xC1A3, // 4652
+    0xC1A4, // 4653
+    0xC1A6, // 4654
+    0xC1A7, // 4655
+    0xC1AA, // 4656
+    0xC1AB, // 4657
+    0xC1AD, // 4658
+    0xC1AE, // 4659
+    0xC1AF, // 4660
+    0xC1B1, // 4661
+    0xC1B2, // 4662
+    0xC1B3, // 4663
+    0xC1B4, // 4664
+    0xC1B5, // 4665
+    0xC1B6, // 4666
+    0xC1B7, // 4667
+    0xC1B8, // 4668
+    0xC1B9, // 4669
+    0xC1BA, // 4670
+    0xC1BB, // 4671
+    0xC1BC, // 4672
+    0xC1BE, // 4673
+    0xC1BF, // 4674
+    0xC1C0, // 4675
+    0xC1C1, // 4676
+    0xC1C2, // 4677
+    0xC1C3, // 4678
+    0xC1C5, // 4679
+    0xC1C6, // 4680
+    0xC1C7, // 4681
+    0xC1C9, // 4682
+    0xC1CA, // 4683
+    0xC1CB, // 4684
+    0xC1CD, // 4685
+    0xC1CE, // 4686
+    0xC1CF, // 4687
+    0xC1D0, // 4688
+    0xC1D1, // 4689
+    0xC1D2, // 4690
+    0xC1D3, // 4691
+    0xC1D5, // 4692
+    0xC1D6, // 4693
+    0xC1D9, // 4694
+    0xC1DA, // 4695
+    0xC1DB, // 4696
+    0xC1DC, // 4697
+    0xC1DD, // 4698
+    0xC1DE, // 4699
+    0xC1DF, // 4700
+    0xC1E1, // 4701
+    0xC1E2, // 4702
+    0xC1E3, // 4703
+    0xC1E5, // 4704
+    0xC1E6, // 4705
+    0xC1E7, // 4706
+    0xC1E9, // 4707
+    0xC1EA, // 4708
+    0xC1EB, // 4709
+    0xC1EC, // 4710
+    0xC1ED, // 4711
+    0xC1EE, // 4712
+    0xC1EF, // 4713
+    0xC1F2, // 4714
+    0xC1F4, // 4715
+    0xC1F5, // 4716
+    0xC1F6, // 4717
+    0xC1F7, // 4718
+    0xC1F8, // 4719
+    0xC1F9, // 4720
+    0xC1FA, // 4721
+    0xC1FB, // 4722
+    0xC1FE, // 4723
+    0xC1FF, // 4724
+    0xC201, // 4725
+    0xC202, // 4726
+    0xC203, // 4727
+    0xC205, // 4728
+    0xC206, // 4729
+    0xC207, // 4730
+    0xC208, // 4731
+    0xC209, // 4732
+    0xC20A, // 4733
+    0xC20B, // 4734
+    0xC20E, // 4735
+    0xC210, // 4736
+    0xC212, // 4737
+    0xC213, // 4738
+    0xC214, // 4739
+    0xC215, // 4740
+    0xC216, // 4741
+    0xC217, // 4742
+    0xC21A, // 4743
+    0xC21B, // 4744
+    0xC21D, // 4745
+    0xC21E, // 4746
+    0xC221, // 4747
+    0xC222, // 4748
+    0xC223, // 4749
+    0xC224, // 4750
+    0xC225, // 4751
+    0xC226, // 4752
+    0xC227, // 4753
+    0xC22A, // 4754
+    0xC22C, // 4755
+    0xC22E, // 4756
+    0xC230, // 4757
+    0xC233, // 4758
+    0xC235, // 4759
+    0xC236, // 4760
+    0xC237, // 4761
+    0xC238, // 4762
+    0xC239, // 4763
+    0xC23A, // 4764
+    0xC23B, // 4765
+    0xC23C, // 4766
+    0xC23D, // 4767
+    0xC23E, // 4768
+    0xC23F, // 4769
+    0xC240, // 4770
+    0xC241, // 4771
+    0xC242, // 4772
+    0xC243, // 4773
+    0xC244, // 4774
+    0xC245, // 4775
+    0x0000, // 4776
+    0x0000, // 4777
+    0x0000, // 4778
+    0x0000, // 4779
+    0x0000, // 4780
+    0x0000, // 4781
+    0xC246, // 4782
+    0xC247, // 4783
+    0xC249, // 4784
+    0xC24A, // 4785
+    0xC24B, // 4786
+    0xC24C, // 4787
+    0xC24D, // 4788
+    0xC24E, // 4789
+    0xC24F, // 4790
+    0xC252, // 4791
+    0xC253, // 4792
+    0xC255, // 4793
+    0xC256, // 4794
+    0xC257, // 4795
+    0xC259, // 4796
+    0xC25A, // 4797
+    0xC25B, // 4798
+    0xC25C, // 4799
+    0xC25D, // 4800
+    0xC25E, // 4801
+    0xC25F, // 4802
+    0xC261, // 4803
+    0xC262, // 4804
+    0xC263, // 4805
+    0xC264, // 4806
+    0xC266, // 4807
+    0x0000, // 4808
+    0x0000, // 4809
+    0x0000, // 4810
+    0x0000, // 4811
+    0x0000, // 4812
+    0x0000, // 4813
+    0xC267, // 4814
+    0xC268, // 4815
+    0xC269, // 4816
+    0xC26A, // 4817
+    0xC26B, // 4818
+    0xC26E, // 4819
+    0xC26F, // 4820
+    0xC271, // 4821
+    0xC272, // 4822
+    0xC273, // 4823
+    0xC275, // 4824
+    0xC276, // 4825
+    0xC277, // 4826
+    0xC278, // 4827
+    0xC279, // 4828
+    0xC27A, // 4829
+    0xC27B, // 4830
+    0xC27E, // 4831
+    0xC280, // 4832
+    0xC282, // 4833
+    0xC283, // 4834
+    0xC284, // 4835
+    0xC285, // 4836
+    0xC286, // 4837
+    0xC287, // 4838
+    0xC28A, // 4839
+    0xC28B, // 4840
+    0xC28C, // 4841
+    0xC28D, // 4842
+    0xC28E, // 4843
+    0xC28F, // 4844
+    0xC291, // 4845
+    0xC292, // 4846
+    0xC293, // 4847
+    0xC294, // 4848
+    0xC295, // 4849
+    0xC296, // 4850
+    0xC297, // 4851
+    0xC299, // 4852
+    0xC29A, // 4853
+    0xC29C, // 4854
+    0xC29E, // 4855
+    0xC29F, // 4856
+    0xC2A0, // 4857
+    0xC2A1, // 4858
+    0xC2A2, // 4859
+    0xC2A3, // 4860
+    0xC2A6, // 4861
+    0xC2A7, // 4862
+    0xC2A9, // 4863
+    0xC2AA, // 4864
+    0xC2AB, // 4865
+    0xC2AE, // 4866
+    0xC2AF, // 4867
+    0xC2B0, // 4868
+    0xC2B1, // 4869
+    0xC2B2, // 4870
+    0xC2B3, // 4871
+    0xC2B6, // 4872
+    0xC2B8, // 4873
+    0xC2BA, // 4874
+    0xC2BB, // 4875
+    0xC2BC, // 4876
+    0xC2BD, // 4877
+    0xC2BE, // 4878
+    0xC2BF, // 4879
+    0xC2C0, // 4880
+    0xC2C1, // 4881
+    0xC2C2, // 4882
+    0xC2C3, // 4883
+    0xC2C4, // 4884
+    0xC2C5, // 4885
+    0xC2C6, // 4886
+    0xC2C7, // 4887
+    0xC2C8, // 4888
+    0xC2C9, // 4889
+    0xC2CA, // 4890
+    0xC2CB, // 4891
+    0xC2CC, // 4892
+    0xC2CD, // 4893
+    0xC2CE, // 4894
+    0xC2CF, // 4895
+    0xC2D0, // 4896
+    0xC2D1, // 4897
+    0xC2D2, // 4898
+    0xC2D3, // 4899
+    0xC2D4, // 4900
+    0xC2D5, // 4901
+    0xC2D6, // 4902
+    0xC2D7, // 4903
+    0xC2D8, // 4904
+    0xC2D9, // 4905
+    0xC2DA, // 4906
+    0xC2DB, // 4907
+    0xC2DE, // 4908
+    0xC2DF, // 4909
+    0xC2E1, // 4910
+    0xC2E2, // 4911
+    0xC2E5, // 4912
+    0xC2E6, // 4913
+    0xC2E7, // 4914
+    0xC2E8, // 4915
+    0xC2E9, // 4916
+    0xC2EA, // 4917
+    0xC2EE, // 4918
+    0xC2F0, // 4919
+    0xC2F2, // 4920
+    0xC2F3, // 4921
+    0xC2F4, // 4922
+    0xC2F5, // 4923
+    0xC2F7, // 4924
+    0xC2FA, // 4925
+    0xC2FD, // 4926
+    0xC2FE, // 4927
+    0xC2FF, // 4928
+    0xC301, // 4929
+    0xC302, // 4930
+    0xC303, // 4931
+    0xC304, // 4932
+    0xC305, // 4933
+    0xC306, // 4934
+    0xC307, // 4935
+    0xC30A, // 4936
+    0xC30B, // 4937
+    0xC30E, // 4938
+    0xC30F, // 4939
+    0xC310, // 4940
+    0xC311, // 4941
+    0xC312, // 4942
+    0xC316, // 4943
+    0xC317, // 4944
+    0xC319, // 4945
+    0xC31A, // 4946
+    0xC31B, // 4947
+    0xC31D, // 4948
+    0xC31E, // 4949
+    0xC31F, // 4950
+    0xC320, // 4951
+    0xC321, // 4952
+    0xC322, // 4953
+    0xC323, // 4954
+    0xC326, // 4955
+    0xC327, // 4956
+    0xC32A, // 4957
+    0xC32B, // 4958
+    0xC32C, // 4959
+    0xC32D, // 4960
+    0xC32E, // 4961
+    0xC32F, // 4962
+    0xC330, // 4963
+    0xC331, // 4964
+    0xC332, // 4965
+    0x0000, // 4966
+    0x0000, // 4967
+    0x0000, // 4968
+    0x0000, // 4969
+    0x0000, // 4970
+    0x0000, // 4971
+    0xC333, // 4972
+    0xC334, // 4973
+    0xC335, // 4974
+    0xC336, // 4975
+    0xC337, // 4976
+    0xC338, // 4977
+    0xC339, // 4978
+    0xC33A, // 4979
+    0xC33B, // 4980
+    0xC33C, // 4981
+    0xC33D, // 4982
+    0xC33E, // 4983
+    0xC33F, // 4984
+    0xC340, // 4985
+    0xC341, // 4986
+    0xC342, // 4987
+    0xC343, // 4988
+    0xC344, // 4989
+    0xC346, // 4990
+    0xC347, // 4991
+    0xC348, // 4992
+    0xC349, // 4993
+    0xC34A, // 4994
+    0xC34B, // 4995
+    0xC34C, // 4996
+    0xC34D, // 4997
+    0x0000, // 4998
+    0x0000, // 4999
+    0x0000, // 5000
+    0x0000, // 5001
+    0x0000, // 5002
+    0x0000, // 5003
+    0xC34E, // 5004
+    0xC34F, // 5005
+    0xC350, // 5006
+    0xC351, // 5007
+    0xC352, // 5008
+    0xC353, // 5009
+    0xC354, // 5010
+    0xC355, // 5011
+    0xC356, // 5012
+    0xC357, // 5013
+    0xC358, // 5014
+    0xC359, // 5015
+    0xC35A, // 5016
+    0xC35B, // 5017
+    0xC35C, // 5018
+    0xC35D, // 5019
+    0xC35E, // 5020
+    0xC35F, // 5021
+    0xC360, // 5022
+    0xC361, // 5023
+    0xC362, // 5024
+    0xC363, // 5025
+    0xC364, // 5026
+    0xC365, // 5027
+    0xC366, // 5028
+    0xC367, // 5029
+    0xC36A, // 5030
+    0xC36B, // 5031
+    0xC36D, // 5032
+    0xC36E, // 5033
+    0xC36F, // 5034
+    0xC371, // 5035
+    0xC373, // 5036
+    0xC374, // 5037
+    0xC375, // 5038
+    0xC376, // 5039
+    0xC377, // 5040
+    0xC37A, // 5041
+    0xC37B, // 5042
+    0xC37E, // 5043
+    0xC37F, // 5044
+    0xC380, // 5045
+    0xC381, // 5046
+    0xC382, // 5047
+    0xC383, // 5048
+    0xC385, // 5049
+    0xC386, // 5050
+    0xC387, // 5051
+    0xC389, // 5052
+    0xC38A, // 5053
+    0xC38B, // 5054
+    0xC38D, // 5055
+    0xC38E, // 5056
+    0xC38F, // 5057
+    0xC390, // 5058
+    0xC391, // 5059
+    0xC392, // 5060
+    0xC393, // 5061
+    0xC394, // 5062
+    0xC395, // 5063
+    0xC396, // 5064
+    0xC397, // 5065
+    0xC398, // 5066
+    0xC399, // 5067
+    0xC39A, // 5068
+    0xC39B, // 5069
+    0xC39C, // 5070
+    0xC39D, // 5071
+    0xC39E, // 5072
+    0xC39F, // 5073
+    0xC3A0, // 5074
+    0xC3A1, // 5075
+    0xC3A2, // 5076
+    0xC3A3, // 5077
+    0xC3A4, // 5078
+    0xC3A5, // 5079
+    0xC3A6, // 5080
+    0xC3A7, // 5081
+    0xC3A8, // 5082
+    0xC3A9, // 5083
+    0xC3AA, // 5084
+    0xC3AB, // 5085
+    0xC3AC, // 5086
+    0xC3AD, // 5087
+    0xC3AE, // 5088
+    0xC3AF, // 5089
+    0xC3B0, // 5090
+    0xC3B1, // 5091
+    0xC3B2, // 5092
+    0xC3B3, // 5093
+    0xC3B4, // 5094
+    0xC3B5, // 5095
+    0xC3B6, // 5096
+    0xC3B7, // 5097
+    0xC3B8, // 5098
+    0xC3B9, // 5099
+    0xC3BA, // 5100
+    0xC3BB, // 5101
+    0xC3BC, // 5102
+    0xC3BD, // 5103
+    0xC3BE, // 5104
+    0xC3BF, // 5105
+    0xC3C1, // 5106
+    0xC3C2, // 5107
+    0xC3C3, // 5108
+    0xC3C4, // 5109
+    0xC3C5, // 5110
+    0xC3C6, // 5111
+    0xC3C7, // 5112
+    0xC3C8, // 5113
+    0xC3C9, // 5114
+    0xC3CA, // 5115
+    0xC3CB, // 5116
+    0xC3CC, // 5117
+    0xC3CD, // 5118
+    0xC3CE, // 5119
+    0xC3CF, // 5120
+    0xC3D0, // 5121
+    0xC3D1, // 5122
+    0xC3D2, // 5123
+    0xC3D3, // 5124
+    0xC3D4, // 5125
+    0xC3D5, // 5126
+    0xC3D6, // 5127
+    0xC3D7, // 5128
+    0xC3DA, // 5129
+    0xC3DB, // 5130
+    0xC3DD, // 5131
+    0xC3DE, // 5132
+    0xC3E1, // 5133
+    0xC3E3, // 5134
+    0xC3E4, // 5135
+    0xC3E5, // 5136
+    0xC3E6, // 5137
+    0xC3E7, // 5138
+    0xC3EA, // 5139
+    0xC3EB, // 5140
+    0xC3EC, // 5141
+    0xC3EE, // 5142
+    0xC3EF, // 5143
+    0xC3F0, // 5144
+    0xC3F1, // 5145
+    0xC3F2, // 5146
+    0xC3F3, // 5147
+    0xC3F6, // 5148
+    0xC3F7, // 5149
+    0xC3F9, // 5150
+    0xC3FA, // 5151
+    0xC3FB, // 5152
+    0xC3FC, // 5153
+    0xC3FD, // 5154
+    0xC3FE, // 5155
+    0x0000, // 5156
+    0x0000, // 5157
+    0x0000, // 5158
+    0x0000, // 5159
+    0x0000, // 5160
+    0x0000, // 5161
+    0xC3FF, // 5162
+    0xC400, // 5163
+    0xC401, // 5164
+    0xC402, // 5165
+    0xC403, // 5166
+    0xC404, // 5167
+    0xC405, // 5168
+    0xC406, // 5169
+    0xC407, // 5170
+    0xC409, // 5171
+    0xC40A, // 5172
+    0xC40B, // 5173
+    0xC40C, // 5174
+    0xC40D, // 5175
+    0xC40E, // 5176
+    0xC40F, // 5177
+    0xC411, // 5178
+    0xC412, // 5179
+    0xC413, // 5180
+    0xC414, // 5181
+    0xC415, // 5182
+    0xC416, // 5183
+    0xC417, // 5184
+    0xC418, // 5185
+    0xC419, // 5186
+    0xC41A, // 5187
+    0x0000, // 5188
+    0x0000, // 5189
+    0x0000, // 5190
+    0x0000, // 5191
+    0x0000, // 5192
+    0x0000, // 5193
+    0xC41B, // 5194
+    0xC41C, // 5195
+    0xC41D, // 5196
+    0xC41E, // 5197
+    0xC41F, // 5198
+    0xC420, // 5199
+    0xC421, // 5200
+    0xC422, // 5201
+    0xC423, // 5202
+    0xC425, // 5203
+    0xC426, // 5204
+    0xC427, // 5205
+    0xC428, // 5206
+    0xC429, // 5207
+    0xC42A, // 5208
+    0xC42B, // 5209
+    0xC42D, // 5210
+    0xC42E, // 5211
+    0xC42F, // 5212
+    0xC431, // 5213
+    0xC432, // 5214
+    0xC433, // 5215
+    0xC435, // 5216
+    0xC436, // 5217
+    0xC437, // 5218
+    0xC438, // 5219
+    0xC439, // 5220
+    0xC43A, // 5221
+    0xC43B, // 5222
+    0xC43E, // 5223
+    0xC43F, // 5224
+    0xC440, // 5225
+    0xC441, // 5226
+    0xC442, // 5227
+    0xC443, // 5228
+    0xC444, // 5229
+    0xC445, // 5230
+    0xC446, // 5231
+    0xC447, // 5232
+    0xC449, // 5233
+    0xC44A, // 5234
+    0xC44B, // 5235
+    0xC44C, // 5236
+    0xC44D, // 5237
+    0xC44E, // 5238
+    0xC44F, // 5239
+    0xC450, // 5240
+    0xC451, // 5241
+    0xC452, // 5242
+    0xC453, // 5243
+    0xC454, // 5244
+    0xC455, // 5245
+    0xC456, // 5246
+    0xC457, // 5247
+    0xC458, // 5248
+    0xC459, // 5249
+    0xC45A, // 5250
+    0xC45B, // 5251
+    0xC45C, // 5252
+    0xC45D, // 5253
+    0xC45E, // 5254
+    0xC45F, // 5255
+    0xC460, // 5256
+    0xC461, // 5257
+    0xC462, // 5258
+    0xC463, // 5259
+    0xC466, // 5260
+    0xC467, // 5261
+    0xC469, // 5262
+    0xC46A, // 5263
+    0xC46B, // 5264
+    0xC46D, // 5265
+    0xC46E, // 5266
+    0xC46F, // 5267
+    0xC470, // 5268
+    0xC471, // 5269
+    0xC472, // 5270
+    0xC473, // 5271
+    0xC476, // 5272
+    0xC477, // 5273
+    0xC478, // 5274
+    0xC47A, // 5275
+    0xC47B, // 5276
+    0xC47C, // 5277
+    0xC47D, // 5278
+    0xC47E, // 5279
+    0xC47F, // 5280
+    0xC481, // 5281
+    0xC482, // 5282
+    0xC483, // 5283
+    0xC484, // 5284
+    0xC485, // 5285
+    0xC486, // 5286
+    0xC487, // 5287
+    0xC488, // 5288
+    0xC489, // 5289
+    0xC48A, // 5290
+    0xC48B, // 5291
+    0xC48C, // 5292
+    0xC48D, // 5293
+    0xC48E, // 5294
+    0xC48F, // 5295
+    0xC490, // 5296
+    0xC491, // 5297
+    0xC492, // 5298
+    0xC493, // 5299
+    0xC495, // 5300
+    0xC496, // 5301
+    0xC497, // 5302
+    0xC498, // 5303
+    0xC499, // 5304
+    0xC49A, // 5305
+    0xC49B, // 5306
+    0xC49D, // 5307
+    0xC49E, // 5308
+    0xC49F, // 5309
+    0xC4A0, // 5310
+    0xC4A1, // 5311
+    0xC4A2, // 5312
+    0xC4A3, // 5313
+    0xC4A4, // 5314
+    0xC4A5, // 5315
+    0xC4A6, // 5316
+    0xC4A7, // 5317
+    0xC4A8, // 5318
+    0xC4A9, // 5319
+    0xC4AA, // 5320
+    0xC4AB, // 5321
+    0xC4AC, // 5322
+    0xC4AD, // 5323
+    0xC4AE, // 5324
+    0xC4AF, // 5325
+    0xC4B0, // 5326
+    0xC4B1, // 5327
+    0xC4B2, // 5328
+    0xC4B3, // 5329
+    0xC4B4, // 5330
+    0xC4B5, // 5331
+    0xC4B6, // 5332
+    0xC4B7, // 5333
+    0xC4B9, // 5334
+    0xC4BA, // 5335
+    0xC4BB, // 5336
+    0xC4BD, // 5337
+    0xC4BE, // 5338
+    0xC4BF, // 5339
+    0xC4C0, // 5340
+    0xC4C1, // 5341
+    0xC4C2, // 5342
+    0xC4C3, // 5343
+    0xC4C4, // 5344
+    0xC4C5, // 5345
+    0x0000, // 5346
+    0x0000, // 5347
+    0x0000, // 5348
+    0x0000, // 5349
+    0x0000, // 5350
+    0x0000, // 5351
+    0xC4C6, // 5352
+    0xC4C7, // 5353
+    0xC4C8, // 5354
+    0xC4C9, // 5355
+    0xC4CA, // 5356
+    0xC4CB, // 5357
+    0xC4CC, // 5358
+    0xC4CD, // 5359
+    0xC4CE, // 5360
+    0xC4CF, // 5361
+    0xC4D0, // 5362
+    0xC4D1, // 5363
+    0xC4D2, // 5364
+    0xC4D3, // 5365
+    0xC4D4, // 5366
+    0xC4D5, // 5367
+    0xC4D6, // 5368
+    0xC4D7, // 5369
+    0xC4D8, // 5370
+    0xC4D9, // 5371
+    0xC4DA, // 5372
+    0xC4DB, // 5373
+    0xC4DC, // 5374
+    0xC4DD, // 5375
+    0xC4DE, // 5376
+    0xC4DF, // 5377
+    0x0000, // 5378
+    0x0000, // 5379
+    0x0000, // 5380
+    0x0000, // 5381
+    0x0000, // 5382
+    0x0000, // 5383
+    0xC4E0, // 5384
+    0xC4E1, // 5385
+    0xC4E2, // 5386
+    0xC4E3, // 5387
+    0xC4E4, // 5388
+    0xC4E5, // 5389
+    0xC4E6, // 5390
+    0xC4E7, // 5391
+    0xC4E8, // 5392
+    0xC4EA, // 5393
+    0xC4EB, // 5394
+    0xC4EC, // 5395
+    0xC4ED, // 5396
+    0xC4EE, // 5397
+    0xC4EF, // 5398
+    0xC4F2, // 5399
+    0xC4F3, // 5400
+    0xC4F5, // 5401
+    0xC4F6, // 5402
+    0xC4F7, // 5403
+    0xC4F9, // 5404
+    0xC4FB, // 5405
+    0xC4FC, // 5406
+    0xC4FD, // 5407
+    0xC4FE, // 5408
+    0xC502, // 5409
+    0xC503, // 5410
+    0xC504, // 5411
+    0xC505, // 5412
+    0xC506, // 5413
+    0xC507, // 5414
+    0xC508, // 5415
+    0xC509, // 5416
+    0xC50A, // 5417
+    0xC50B, // 5418
+    0xC50D, // 5419
+    0xC50E, // 5420
+    0xC50F, // 5421
+    0xC511, // 5422
+    0xC512, // 5423
+    0xC513, // 5424
+    0xC515, // 5425
+    0xC516, // 5426
+    0xC517, // 5427
+    0xC518, // 5428
+    0xC519, // 5429
+    0xC51A, // 5430
+    0xC51B, // 5431
+    0xC51D, // 5432
+    0xC51E, // 5433
+    0xC51F, // 5434
+    0xC520, // 5435
+    0xC521, // 5436
+    0xC522, // 5437
+    0xC523, // 5438
+    0xC524, // 5439
+    0xC525, // 5440
+    0xC526, // 5441
+    0xC527, // 5442
+    0xC52A, // 5443
+    0xC52B, // 5444
+    0xC52D, // 5445
+    0xC52E, // 5446
+    0xC52F, // 5447
+    0xC531, // 5448
+    0xC532, // 5449
+    0xC533, // 5450
+    0xC534, // 5451
+    0xC535, // 5452
+    0xC536, // 5453
+    0xC537, // 5454
+    0xC53A, // 5455
+    0xC53C, // 5456
+    0xC53E, // 5457
+    0xC53F, // 5458
+    0xC540, // 5459
+    0xC541, // 5460
+    0xC542, // 5461
+    0xC543, // 5462
+    0xC546, // 5463
+    0xC547, // 5464
+    0xC54B, // 5465
+    0xC54F, // 5466
+    0xC550, // 5467
+    0xC551, // 5468
+    0xC552, // 5469
+    0xC556, // 5470
+    0xC55A, // 5471
+    0xC55B, // 5472
+    0xC55C, // 5473
+    0xC55F, // 5474
+    0xC562, // 5475
+    0xC563, // 5476
+    0xC565, // 5477
+    0xC566, // 5478
+    0xC567, // 5479
+    0xC569, // 5480
+    0xC56A, // 5481
+    0xC56B, // 5482
+    0xC56C, // 5483
+    0xC56D, // 5484
+    0xC56E, // 5485
+    0xC56F, // 5486
+    0xC572, // 5487
+    0xC576, // 5488
+    0xC577, // 5489
+    0xC578, // 5490
+    0xC579, // 5491
+    0xC57A, // 5492
+    0xC57B, // 5493
+    0xC57E, // 5494
+    0xC57F, // 5495
+    0xC581, // 5496
+    0xC582, // 5497
+    0xC583, // 5498
+    0xC585, // 5499
+    0xC586, // 5500
+    0xC588, // 5501
+    0xC589, // 5502
+    0xC58A, // 5503
+    0xC58B, // 5504
+    0xC58E, // 5505
+    0xC590, // 5506
+    0xC592, // 5507
+    0xC593, // 5508
+    0xC594, // 5509
+    0xC596, // 5510
+    0xC599, // 5511
+    0xC59A, // 5512
+    0xC59B, // 5513
+    0xC59D, // 5514
+    0xC59E, // 5515
+    0xC59F, // 5516
+    0xC5A1, // 5517
+    0xC5A2, // 5518
+    0xC5A3, // 5519
+    0xC5A4, // 5520
+    0xC5A5, // 5521
+    0xC5A6, // 5522
+    0xC5A7, // 5523
+    0xC5A8, // 5524
+    0xC5AA, // 5525
+    0xC5AB, // 5526
+    0xC5AC, // 5527
+    0xC5AD, // 5528
+    0xC5AE, // 5529
+    0xC5AF, // 5530
+    0xC5B0, // 5531
+    0xC5B1, // 5532
+    0xC5B2, // 5533
+    0xC5B3, // 5534
+    0xC5B6, // 5535
+    0x0000, // 5536
+    0x0000, // 5537
+    0x0000, // 5538
+    0x0000, // 5539
+    0x0000, // 5540
+    0x0000, // 5541
+    0xC5B7, // 5542
+    0xC5BA, // 5543
+    0xC5BF, // 5544
+    0xC5C0, // 5545
+    0xC5C1, // 5546
+    0xC5C2, // 5547
+    0xC5C3, // 5548
+    0xC5CB, // 5549
+    0xC5CD, // 5550
+    0xC5CF, // 5551
+    0xC5D2, // 5552
+    0xC5D3, // 5553
+    0xC5D5, // 5554
+    0xC5D6, // 5555
+    0xC5D7, // 5556
+    0xC5D9, // 5557
+    0xC5DA, // 5558
+    0xC5DB, // 5559
+    0xC5DC, // 5560
+    0xC5DD, // 5561
+    0xC5DE, // 5562
+    0xC5DF, // 5563
+    0xC5E2, // 5564
+    0xC5E4, // 5565
+    0xC5E6, // 5566
+    0xC5E7, // 5567
+    0x0000, // 5568
+    0x0000, // 5569
+    0x0000, // 5570
+    0x0000, // 5571
+    0x0000, // 5572
+    0x0000, // 5573
+    0xC5E8, // 5574
+    0xC5E9, // 5575
+    0xC5EA, // 5576
+    0xC5EB, // 5577
+    0xC5EF, // 5578
+    0xC5F1, // 5579
+    0xC5F2, // 5580
+    0xC5F3, // 5581
+    0xC5F5, // 5582
+    0xC5F8, // 5583
+    0xC5F9, // 5584
+    0xC5FA, // 5585
+    0xC5FB, // 5586
+    0xC602, // 5587
+    0xC603, // 5588
+    0xC604, // 5589
+    0xC609, // 5590
+    0xC60A, // 5591
+    0xC60B, // 5592
+    0xC60D, // 5593
+    0xC60E, // 5594
+    0xC60F, // 5595
+    0xC611, // 5596
+    0xC612, // 5597
+    0xC613, // 5598
+    0xC614, // 5599
+    0xC615, // 5600
+    0xC616, // 5601
+    0xC617, // 5602
+    0xC61A, // 5603
+    0xC61D, // 5604
+    0xC61E, // 5605
+    0xC61F, // 5606
+    0xC620, // 5607
+    0xC621, // 5608
+    0xC622, // 5609
+    0xC623, // 5610
+    0xC626, // 5611
+    0xC627, // 5612
+    0xC629, // 5613
+    0xC62A, // 5614
+    0xC62B, // 5615
+    0xC62F, // 5616
+    0xC631, // 5617
+    0xC632, // 5618
+    0xC636, // 5619
+    0xC638, // 5620
+    0xC63A, // 5621
+    0xC63C, // 5622
+    0xC63D, // 5623
+    0xC63E, // 5624
+    0xC63F, // 5625
+    0xC642, // 5626
+    0xC643, // 5627
+    0xC645, // 5628
+    0xC646, // 5629
+    0xC647, // 5630
+    0xC649, // 5631
+    0xC64A, // 5632
+    0xC64B, // 5633
+    0xC64C, // 5634
+    0xC64D, // 5635
+    0xC64E, // 5636
+    0xC64F, // 5637
+    0xC652, // 5638
+    0xC656, // 5639
+    0xC657, // 5640
+    0xC658, // 5641
+    0xC659, // 5642
+    0xC65A, // 5643
+    0xC65B, // 5644
+    0xC65E, // 5645
+    0xC65F, // 5646
+    0xC661, // 5647
+    0xC662, // 5648
+    0xC663, // 5649
+    0xC664, // 5650
+    0xC665, // 5651
+    0xC666, // 5652
+    0xC667, // 5653
+    0xC668, // 5654
+    0xC669, // 5655
+    0xC66A, // 5656
+    0xC66B, // 5657
+    0xC66D, // 5658
+    0xC66E, // 5659
+    0xC670, // 5660
+    0xC672, // 5661
+    0xC673, // 5662
+    0xC674, // 5663
+    0xC675, // 5664
+    0xC676, // 5665
+    0xC677, // 5666
+    0xC67A, // 5667
+    0xC67B, // 5668
+    0xC67D, // 5669
+    0xC67E, // 5670
+    0xC67F, // 5671
+    0xC681, // 5672
+    0xC682, // 5673
+    0xC683, // 5674
+    0xC684, // 5675
+    0xC685, // 5676
+    0xC686, // 5677
+    0xC687, // 5678
+    0xC68A, // 5679
+    0xC68C, // 5680
+    0xC68E, // 5681
+    0xC68F, // 5682
+    0xC690, // 5683
+    0xC691, // 5684
+    0xC692, // 5685
+    0xC693, // 5686
+    0xC696, // 5687
+    0xC697, // 5688
+    0xC699, // 5689
+    0xC69A, // 5690
+    0xC69B, // 5691
+    0xC69D, // 5692
+    0xC69E, // 5693
+    0xC69F, // 5694
+    0xC6A0, // 5695
+    0xC6A1, // 5696
+    0xC6A2, // 5697
+    0xC6A3, // 5698
+    0xC6A6, // 5699
+    0xC6A8, // 5700
+    0xC6AA, // 5701
+    0xC6AB, // 5702
+    0xC6AC, // 5703
+    0xC6AD, // 5704
+    0xC6AE, // 5705
+    0xC6AF, // 5706
+    0xC6B2, // 5707
+    0xC6B3, // 5708
+    0xC6B5, // 5709
+    0xC6B6, // 5710
+    0xC6B7, // 5711
+    0xC6BB, // 5712
+    0xC6BC, // 5713
+    0xC6BD, // 5714
+    0xC6BE, // 5715
+    0xC6BF, // 5716
+    0xC6C2, // 5717
+    0xC6C4, // 5718
+    0xC6C6, // 5719
+    0xC6C7, // 5720
+    0xC6C8, // 5721
+    0xC6C9, // 5722
+    0xC6CA, // 5723
+    0xC6CB, // 5724
+    0xC6CE, // 5725
+    0x0000, // 5726
+    0x0000, // 5727
+    0x0000, // 5728
+    0x0000, // 5729
+    0x0000, // 5730
+    0x0000, // 5731
+    0xC6CF, // 5732
+    0xC6D1, // 5733
+    0xC6D2, // 5734
+    0xC6D3, // 5735
+    0xC6D5, // 5736
+    0xC6D6, // 5737
+    0xC6D7, // 5738
+    0xC6D8, // 5739
+    0xC6D9, // 5740
+    0xC6DA, // 5741
+    0xC6DB, // 5742
+    0xC6DE, // 5743
+    0xC6DF, // 5744
+    0xC6E2, // 5745
+    0xC6E3, // 5746
+    0xC6E4, // 5747
+    0xC6E5, // 5748
+    0xC6E6, // 5749
+    0xC6E7, // 5750
+    0xC6EA, // 5751
+    0xC6EB, // 5752
+    0xC6ED, // 5753
+    0xC6EE, // 5754
+    0xC6EF, // 5755
+    0xC6F1, // 5756
+    0xC6F2, // 5757
+    0x0000, // 5758
+    0x0000, // 5759
+    0x0000, // 5760
+    0x0000, // 5761
+    0x0000, // 5762
+    0x0000, // 5763
+    0xC6F3, // 5764
+    0xC6F4, // 5765
+    0xC6F5, // 5766
+    0xC6F6, // 5767
+    0xC6F7, // 5768
+    0xC6FA, // 5769
+    0xC6FB, // 5770
+    0xC6FC, // 5771
+    0xC6FE, // 5772
+    0xC6FF, // 5773
+    0xC700, // 5774
+    0xC701, // 5775
+    0xC702, // 5776
+    0xC703, // 5777
+    0xC706, // 5778
+    0xC707, // 5779
+    0xC709, // 5780
+    0xC70A, // 5781
+    0xC70B, // 5782
+    0xC70D, // 5783
+    0xC70E, // 5784
+    0xC70F, // 5785
+    0xC710, // 5786
+    0xC711, // 5787
+    0xC712, // 5788
+    0xC713, // 5789
+    0xC716, // 5790
+    0xC718, // 5791
+    0xC71A, // 5792
+    0xC71B, // 5793
+    0xC71C, // 5794
+    0xC71D, // 5795
+    0xC71E, // 5796
+    0xC71F, // 5797
+    0xC722, // 5798
+    0xC723, // 5799
+    0xC725, // 5800
+    0xC726, // 5801
+    0xC727, // 5802
+    0xC729, // 5803
+    0xC72A, // 5804
+    0xC72B, // 5805
+    0xC72C, // 5806
+    0xC72D, // 5807
+    0xC72E, // 5808
+    0xC72F, // 5809
+    0xC732, // 5810
+    0xC734, // 5811
+    0xC736, // 5812
+    0xC738, // 5813
+    0xC739, // 5814
+    0xC73A, // 5815
+    0xC73B, // 5816
+    0xC73E, // 5817
+    0xC73F, // 5818
+    0xC741, // 5819
+    0xC742, // 5820
+    0xC743, // 5821
+    0xC745, // 5822
+    0xC746, // 5823
+    0xC747, // 5824
+    0xC748, // 5825
+    0xC749, // 5826
+    0xC74B, // 5827
+    0xC74E, // 5828
+    0xC750, // 5829
+    0xC759, // 5830
+    0xC75A, // 5831
+    0xC75B, // 5832
+    0xC75D, // 5833
+    0xC75E, // 5834
+    0xC75F, // 5835
+    0xC761, // 5836
+    0xC762, // 5837
+    0xC763, // 5838
+    0xC764, // 5839
+    0xC765, // 5840
+    0xC766, // 5841
+    0xC767, // 5842
+    0xC769, // 5843
+    0xC76A, // 5844
+    0xC76C, // 5845
+    0xC76D, // 5846
+    0xC76E, // 5847
+    0xC76F, // 5848
+    0xC770, // 5849
+    0xC771, // 5850
+    0xC772, // 5851
+    0xC773, // 5852
+    0xC776, // 5853
+    0xC777, // 5854
+    0xC779, // 5855
+    0xC77A, // 5856
+    0xC77B, // 5857
+    0xC77F, // 5858
+    0xC780, // 5859
+    0xC781, // 5860
+    0xC782, // 5861
+    0xC786, // 5862
+    0xC78B, // 5863
+    0xC78C, // 5864
+    0xC78D, // 5865
+    0xC78F, // 5866
+    0xC792, // 5867
+    0xC793, // 5868
+    0xC795, // 5869
+    0xC799, // 5870
+    0xC79B, // 5871
+    0xC79C, // 5872
+    0xC79D, // 5873
+    0xC79E, // 5874
+    0xC79F, // 5875
+    0xC7A2, // 5876
+    0xC7A7, // 5877
+    0xC7A8, // 5878
+    0xC7A9, // 5879
+    0xC7AA, // 5880
+    0xC7AB, // 5881
+    0xC7AE, // 5882
+    0xC7AF, // 5883
+    0xC7B1, // 5884
+    0xC7B2, // 5885
+    0xC7B3, // 5886
+    0xC7B5, // 5887
+    0xC7B6, // 5888
+    0xC7B7, // 5889
+    0xC7B8, // 5890
+    0xC7B9, // 5891
+    0xC7BA, // 5892
+    0xC7BB, // 5893
+    0xC7BE, // 5894
+    0xC7C2, // 5895
+    0xC7C3, // 5896
+    0xC7C4, // 5897
+    0xC7C5, // 5898
+    0xC7C6, // 5899
+    0xC7C7, // 5900
+    0xC7CA, // 5901
+    0xC7CB, // 5902
+    0xC7CD, // 5903
+    0xC7CF, // 5904
+    0xC7D1, // 5905
+    0xC7D2, // 5906
+    0xC7D3, // 5907
+    0xC7D4, // 5908
+    0xC7D5, // 5909
+    0xC7D6, // 5910
+    0xC7D7, // 5911
+    0xC7D9, // 5912
+    0xC7DA, // 5913
+    0xC7DB, // 5914
+    0xC7DC, // 5915
+    0x0000, // 5916
+    0x0000, // 5917
+    0x0000, // 5918
+    0x0000, // 5919
+    0x0000, // 5920
+    0x0000, // 5921
+    0xC7DE, // 5922
+    0xC7DF, // 5923
+    0xC7E0, // 5924
+    0xC7E1, // 5925
+    0xC7E2, // 5926
+    0xC7E3, // 5927
+    0xC7E5, // 5928
+    0xC7E6, // 5929
+    0xC7E7, // 5930
+    0xC7E9, // 5931
+    0xC7EA, // 5932
+    0xC7EB, // 5933
+    0xC7ED, // 5934
+    0xC7EE, // 5935
+    0xC7EF, // 5936
+    0xC7F0, // 5937
+    0xC7F1, // 5938
+    0xC7F2, // 5939
+    0xC7F3, // 5940
+    0xC7F4, // 5941
+    0xC7F5, // 5942
+    0xC7F6, // 5943
+    0xC7F7, // 5944
+    0xC7F8, // 5945
+    0xC7F9, // 5946
+    0xC7FA, // 5947
+    0x0000, // 5948
+    0x0000, // 5949
+    0x0000, // 5950
+    0x0000, // 5951
+    0x0000, // 5952
+    0x0000, // 5953
+    0xC7FB, // 5954
+    0xC7FC, // 5955
+    0xC7FD, // 5956
+    0xC7FE, // 5957
+    0xC7FF, // 5958
+    0xC802, // 5959
+    0xC803, // 5960
+    0xC805, // 5961
+    0xC806, // 5962
+    0xC807, // 5963
+    0xC809, // 5964
+    0xC80B, // 5965
+    0xC80C, // 5966
+    0xC80D, // 5967
+    0xC80E, // 5968
+    0xC80F, // 5969
+    0xC812, // 5970
+    0xC814, // 5971
+    0xC817, // 5972
+    0xC818, // 5973
+    0xC819, // 5974
+    0xC81A, // 5975
+    0xC81B, // 5976
+    0xC81E, // 5977
+    0xC81F, // 5978
+    0xC821, // 5979
+    0xC822, // 5980
+    0xC823, // 5981
+    0xC825, // 5982
+    0xC826, // 5983
+    0xC827, // 5984
+    0xC828, // 5985
+    0xC829, // 5986
+    0xC82A, // 5987
+    0xC82B, // 5988
+    0xC82E, // 5989
+    0xC830, // 5990
+    0xC832, // 5991
+    0xC833, // 5992
+    0xC834, // 5993
+    0xC835, // 5994
+    0xC836, // 5995
+    0xC837, // 5996
+    0xC839, // 5997
+    0xC83A, // 5998
+    0xC83B, // 5999
+    0xC83D, // 6000
+    0xC83E, // 6001
+    0xC83F, // 6002
+    0xC841, // 6003
+    0xC842, // 6004
+    0xC843, // 6005
+    0xC844, // 6006
+    0xC845, // 6007
+    0xC846, // 6008
+    0xC847, // 6009
+    0xC84A, // 6010
+    0xC84B, // 6011
+    0xC84E, // 6012
+    0xC84F, // 6013
+    0xC850, // 6014
+    0xC851, // 6015
+    0xC852, // 6016
+    0xC853, // 6017
+    0xC855, // 6018
+    0xC856, // 6019
+    0xC857, // 6020
+    0xC858, // 6021
+    0xC859, // 6022
+    0xC85A, // 6023
+    0xC85B, // 6024
+    0xC85C, // 6025
+    0xC85D, // 6026
+    0xC85E, // 6027
+    0xC85F, // 6028
+    0xC860, // 6029
+    0xC861, // 6030
+    0xC862, // 6031
+    0xC863, // 6032
+    0xC864, // 6033
+    0xC865, // 6034
+    0xC866, // 6035
+    0xC867, // 6036
+    0xC868, // 6037
+    0xC869, // 6038
+    0xC86A, // 6039
+    0xC86B, // 6040
+    0xC86C, // 6041
+    0xC86D, // 6042
+    0xC86E, // 6043
+    0xC86F, // 6044
+    0xC872, // 6045
+    0xC873, // 6046
+    0xC875, // 6047
+    0xC876, // 6048
+    0xC877, // 6049
+    0xC879, // 6050
+    0xC87B, // 6051
+    0xC87C, // 6052
+    0xC87D, // 6053
+    0xC87E, // 6054
+    0xC87F, // 6055
+    0xC882, // 6056
+    0xC884, // 6057
+    0xC888, // 6058
+    0xC889, // 6059
+    0xC88A, // 6060
+    0xC88E, // 6061
+    0xC88F, // 6062
+    0xC890, // 6063
+    0xC891, // 6064
+    0xC892, // 6065
+    0xC893, // 6066
+    0xC895, // 6067
+    0xC896, // 6068
+    0xC897, // 6069
+    0xC898, // 6070
+    0xC899, // 6071
+    0xC89A, // 6072
+    0xC89B, // 6073
+    0xC89C, // 6074
+    0xC89E, // 6075
+    0xC8A0, // 6076
+    0xC8A2, // 6077
+    0xC8A3, // 6078
+    0xC8A4, // 6079
+    0xC8A5, // 6080
+    0xC8A6, // 6081
+    0xC8A7, // 6082
+    0xC8A9, // 6083
+    0xC8AA, // 6084
+    0xC8AB, // 6085
+    0xC8AC, // 6086
+    0xC8AD, // 6087
+    0xC8AE, // 6088
+    0xC8AF, // 6089
+    0xC8B0, // 6090
+    0xC8B1, // 6091
+    0xC8B2, // 6092
+    0xC8B3, // 6093
+    0xC8B4, // 6094
+    0xC8B5, // 6095
+    0xC8B6, // 6096
+    0xC8B7, // 6097
+    0xC8B8, // 6098
+    0xC8B9, // 6099
+    0xC8BA, // 6100
+    0xC8BB, // 6101
+    0xC8BE, // 6102
+    0xC8BF, // 6103
+    0xC8C0, // 6104
+    0xC8C1, // 6105
+    0x0000, // 6106
+    0x0000, // 6107
+    0x0000, // 6108
+    0x0000, // 6109
+    0x0000, // 6110
+    0x0000, // 6111
+    0xC8C2, // 6112
+    0xC8C3, // 6113
+    0xC8C5, // 6114
+    0xC8C6, // 6115
+    0xC8C7, // 6116
+    0xC8C9, // 6117
+    0xC8CA, // 6118
+    0xC8CB, // 6119
+    0xC8CD, // 6120
+    0xC8CE, // 6121
+    0xC8CF, // 6122
+    0xC8D0, // 6123
+    0xC8D1, // 6124
+    0xC8D2, // 6125
+    0xC8D3, // 6126
+    0xC8D6, // 6127
+    0xC8D8, // 6128
+    0xC8DA, // 6129
+    0xC8DB, // 6130
+    0xC8DC, // 6131
+    0xC8DD, // 6132
+    0xC8DE, // 6133
+    0xC8DF, // 6134
+    0xC8E2, // 6135
+    0xC8E3, // 6136
+    0xC8E5, // 6137
+    0x0000, // 6138
+    0x0000, // 6139
+    0x0000, // 6140
+    0x0000, // 6141
+    0x0000, // 6142
+    0x0000, // 6143
+    0xC8E6, // 6144
+    0xC8E7, // 6145
+    0xC8E8, // 6146
+    0xC8E9, // 6147
+    0xC8EA, // 6148
+    0xC8EB, // 6149
+    0xC8EC, // 6150
+    0xC8ED, // 6151
+    0xC8EE, // 6152
+    0xC8EF, // 6153
+    0xC8F0, // 6154
+    0xC8F1, // 6155
+    0xC8F2, // 6156
+    0xC8F3, // 6157
+    0xC8F4, // 6158
+    0xC8F6, // 6159
+    0xC8F7, // 6160
+    0xC8F8, // 6161
+    0xC8F9, // 6162
+    0xC8FA, // 6163
+    0xC8FB, // 6164
+    0xC8FE, // 6165
+    0xC8FF, // 6166
+    0xC901, // 6167
+    0xC902, // 6168
+    0xC903, // 6169
+    0xC907, // 6170
+    0xC908, // 6171
+    0xC909, // 6172
+    0xC90A, // 6173
+    0xC90B, // 6174
+    0xC90E, // 6175
+    0x3000, // 6176
+    0x3001, // 6177
+    0x3002, // 6178
+    0x00B7, // 6179
+    0x2025, // 6180
+    0x2026, // 6181
+    0x00A8, // 6182
+    0x3003, // 6183
+    0x00AD, // 6184
+    0x2015, // 6185
+    0x2225, // 6186
+    0xFF3C, // 6187
+    0x223C, // 6188
+    0x2018, // 6189
+    0x2019, // 6190
+    0x201C, // 6191
+    0x201D, // 6192
+    0x3014, // 6193
+    0x3015, // 6194
+    0x3008, // 6195
+    0x3009, // 6196
+    0x300A, // 6197
+    0x300B, // 6198
+    0x300C, // 6199
+    0x300D, // 6200
+    0x300E, // 6201
+    0x300F, // 6202
+    0x3010, // 6203
+    0x3011, // 6204
+    0x00B1, // 6205
+    0x00D7, // 6206
+    0x00F7, // 6207
+    0x2260, // 6208
+    0x2264, // 6209
+    0x2265, // 6210
+    0x221E, // 6211
+    0x2234, // 6212
+    0x00B0, // 6213
+    0x2032, // 6214
+    0x2033, // 6215
+    0x2103, // 6216
+    0x212B, // 6217
+    0xFFE0, // 6218
+    0xFFE1, // 6219
+    0xFFE5, // 6220
+    0x2642, // 6221
+    0x2640, // 6222
+    0x2220, // 6223
+    0x22A5, // 6224
+    0x2312, // 6225
+    0x2202, // 6226
+    0x2207, // 6227
+    0x2261, // 6228
+    0x2252, // 6229
+    0x00A7, // 6230
+    0x203B, // 6231
+    0x2606, // 6232
+    0x2605, // 6233
+    0x25CB, // 6234
+    0x25CF, // 6235
+    0x25CE, // 6236
+    0x25C7, // 6237
+    0x25C6, // 6238
+    0x25A1, // 6239
+    0x25A0, // 6240
+    0x25B3, // 6241
+    0x25B2, // 6242
+    0x25BD, // 6243
+    0x25BC, // 6244
+    0x2192, // 6245
+    0x2190, // 6246
+    0x2191, // 6247
+    0x2193, // 6248
+    0x2194, // 6249
+    0x3013, // 6250
+    0x226A, // 6251
+    0x226B, // 6252
+    0x221A, // 6253
+    0x223D, // 6254
+    0x221D, // 6255
+    0x2235, // 6256
+    0x222B, // 6257
+    0x222C, // 6258
+    0x2208, // 6259
+    0x220B, // 6260
+    0x2286, // 6261
+    0x2287, // 6262
+    0x2282, // 6263
+    0x2283, // 6264
+    0x222A, // 6265
+    0x2229, // 6266
+    0x2227, // 6267
+    0x2228, // 6268
+    0xFFE2, // 6269
+    0xC910, // 6270
+    0xC912, // 6271
+    0xC913, // 6272
+    0xC914, // 6273
+    0xC915, // 6274
+    0xC916, // 6275
+    0xC917, // 6276
+    0xC919, // 6277
+    0xC91A, // 6278
+    0xC91B, // 6279
+    0xC91C, // 6280
+    0xC91D, // 6281
+    0xC91E, // 6282
+    0xC91F, // 6283
+    0xC920, // 6284
+    0xC921, // 6285
+    0xC922, // 6286
+    0xC923, // 6287
+    0xC924, // 6288
+    0xC925, // 6289
+    0xC926, // 6290
+    0xC927, // 6291
+    0xC928, // 6292
+    0xC929, // 6293
+    0xC92A, // 6294
+    0xC92B, // 6295
+    0x0000, // 6296
+    0x0000, // 6297
+    0x0000, // 6298
+    0x0000, // 6299
+    0x0000, // 6300
+    0x0000, // 6301
+    0xC92D, // 6302
+    0xC92E, // 6303
+    0xC92F, // 6304
+    0xC930, // 6305
+    0xC931, // 6306
+    0xC932, // 6307
+    0xC933, // 6308
+    0xC935, // 6309
+    0xC936, // 6310
+    0xC937, // 6311
+    0xC938, // 6312
+    0xC939, // 6313
+    0xC93A, // 6314
+    0xC93B, // 6315
+    0xC93C, // 6316
+    0xC93D, // 6317
+    0xC93E, // 6318
+    0xC93F, // 6319
+    0xC940, // 6320
+    0xC941, // 6321
+    0xC942, // 6322
+    0xC943, // 6323
+    0xC944, // 6324
+    0xC945, // 6325
+    0xC946, // 6326
+    0xC947, // 6327
+    0x0000, // 6328
+    0x0000, // 6329
+    0x0000, // 6330
+    0x0000, // 6331
+    0x0000, // 6332
+    0x0000, // 6333
+    0xC948, // 6334
+    0xC949, // 6335
+    0xC94A, // 6336
+    0xC94B, // 6337
+    0xC94C, // 6338
+    0xC94D, // 6339
+    0xC94E, // 6340
+    0xC94F, // 6341
+    0xC952, // 6342
+    0xC953, // 6343
+    0xC955, // 6344
+    0xC956, // 6345
+    0xC957, // 6346
+    0xC959, // 6347
+    0xC95A, // 6348
+    0xC95B, // 6349
+    0xC95C, // 6350
+    0xC95D, // 6351
+    0xC95E, // 6352
+    0xC95F, // 6353
+    0xC962, // 6354
+    0xC964, // 6355
+    0xC965, // 6356
+    0xC966, // 6357
+    0xC967, // 6358
+    0xC968, // 6359
+    0xC969, // 6360
+    0xC96A, // 6361
+    0xC96B, // 6362
+    0xC96D, // 6363
+    0xC96E, // 6364
+    0xC96F, // 6365
+    0x21D2, // 6366
+    0x21D4, // 6367
+    0x2200, // 6368
+    0x2203, // 6369
+    0x00B4, // 6370
+    0xFF5E, // 6371
+    0x02C7, // 6372
+    0x02D8, // 6373
+    0x02DD, // 6374
+    0x02DA, // 6375
+    0x02D9, // 6376
+    0x00B8, // 6377
+    0x02DB, // 6378
+    0x00A1, // 6379
+    0x00BF, // 6380
+    0x02D0, // 6381
+    0x222E, // 6382
+    0x2211, // 6383
+    0x220F, // 6384
+    0x00A4, // 6385
+    0x2109, // 6386
+    0x2030, // 6387
+    0x25C1, // 6388
+    0x25C0, // 6389
+    0x25B7, // 6390
+    0x25B6, // 6391
+    0x2664, // 6392
+    0x2660, // 6393
+    0x2661, // 6394
+    0x2665, // 6395
+    0x2667, // 6396
+    0x2663, // 6397
+    0x2299, // 6398
+    0x25C8, // 6399
+    0x25A3, // 6400
+    0x25D0, // 6401
+    0x25D1, // 6402
+    0x2592, // 6403
+    0x25A4, // 6404
+    0x25A5, // 6405
+    0x25A8, // 6406
+    0x25A7, // 6407
+    0x25A6, // 6408
+    0x25A9, // 6409
+    0x2668, // 6410
+    0x260F, // 6411
+    0x260E, // 6412
+    0x261C, // 6413
+    0x261E, // 6414
+    0x00B6, // 6415
+    0x2020, // 6416
+    0x2021, // 6417
+    0x2195, // 6418
+    0x2197, // 6419
+    0x2199, // 6420
+    0x2196, // 6421
+    0x2198, // 6422
+    0x266D, // 6423
+    0x2669, // 6424
+    0x266A, // 6425
+    0x266C, // 6426
+    0x327F, // 6427
+    0x321C, // 6428
+    0x2116, // 6429
+    0x33C7, // 6430
+    0x2122, // 6431
+    0x33C2, // 6432
+    0x33D8, // 6433
+    0x2121, // 6434
+    0x20AC, // 6435
+    0x00AE, // 6436
+    0x0000, // 6437
+    0x0000, // 6438
+    0x0000, // 6439
+    0x0000, // 6440
+    0x0000, // 6441
+    0x0000, // 6442
+    0x0000, // 6443
+    0x0000, // 6444
+    0x0000, // 6445
+    0x0000, // 6446
+    0x0000, // 6447
+    0x0000, // 6448
+    0x0000, // 6449
+    0x0000, // 6450
+    0x0000, // 6451
+    0x0000, // 6452
+    0x0000, // 6453
+    0x0000, // 6454
+    0x0000, // 6455
+    0x0000, // 6456
+    0x0000, // 6457
+    0x0000, // 6458
+    0x0000, // 6459
+    0xC971, // 6460
+    0xC972, // 6461
+    0xC973, // 6462
+    0xC975, // 6463
+    0xC976, // 6464
+    0xC977, // 6465
+    0xC978, // 6466
+    0xC979, // 6467
+    0xC97A, // 6468
+    0xC97B, // 6469
+    0xC97D, // 6470
+    0xC97E, // 6471
+    0xC97F, // 6472
+    0xC980, // 6473
+    0xC981, // 6474
+    0xC982, // 6475
+    0xC983, // 6476
+    0xC984, // 6477
+    0xC985, // 6478
+    0xC986, // 6479
+    0xC987, // 6480
+    0xC98A, // 6481
+    0xC98B, // 6482
+    0xC98D, // 6483
+    0xC98E, // 6484
+    0xC98F, // 6485
+    0x0000, // 6486
+    0x0000, // 6487
+    0x0000, // 6488
+    0x0000, // 6489
+    0x0000, // 6490
+    0x0000, // 6491
+    0xC991, // 6492
+    0xC992, // 6493
+    0xC993, // 6494
+    0xC994, // 6495
+    0xC995, // 6496
+    0xC996, // 6497
+    0xC997, // 6498
+    0xC99A, // 6499
+    0xC99C, // 6500
+    0xC99E, // 6501
+    0xC99F, // 6502
+    0xC9A0, // 6503
+    0xC9A1, // 6504
+    0xC9A2, // 6505
+    0xC9A3, // 6506
+    0xC9A4, // 6507
+    0xC9A5, // 6508
+    0xC9A6, // 6509
+    0xC9A7, // 6510
+    0xC9A8, // 6511
+    0xC9A9, // 6512
+    0xC9AA, // 6513
+    0xC9AB, // 6514
+    0xC9AC, // 6515
+    0xC9AD, // 6516
+    0xC9AE, // 6517
+    0x0000, // 6518
+    0x0000, // 6519
+    0x0000, // 6520
+    0x0000, // 6521
+    0x0000, // 6522
+    0x0000, // 6523
+    0xC9AF, // 6524
+    0xC9B0, // 6525
+    0xC9B1, // 6526
+    0xC9B2, // 6527
+    0xC9B3, // 6528
+    0xC9B4, // 6529
+    0xC9B5, // 6530
+    0xC9B6, // 6531
+    0xC9B7, // 6532
+    0xC9B8, // 6533
+    0xC9B9, // 6534
+    0xC9BA, // 6535
+    0xC9BB, // 6536
+    0xC9BC, // 6537
+    0xC9BD, // 6538
+    0xC9BE, // 6539
+    0xC9BF, // 6540
+    0xC9C2, // 6541
+    0xC9C3, // 6542
+    0xC9C5, // 6543
+    0xC9C6, // 6544
+    0xC9C9, // 6545
+    0xC9CB, // 6546
+    0xC9CC, // 6547
+    0xC9CD, // 6548
+    0xC9CE, // 6549
+    0xC9CF, // 6550
+    0xC9D2, // 6551
+    0xC9D4, // 6552
+    0xC9D7, // 6553
+    0xC9D8, // 6554
+    0xC9DB, // 6555
+    0xFF01, // 6556
+    0xFF02, // 6557
+    0xFF03, // 6558
+    0xFF04, // 6559
+    0xFF05, // 6560
+    0xFF06, // 6561
+    0xFF07, // 6562
+    0xFF08, // 6563
+    0xFF09, // 6564
+    0xFF0A, // 6565
+    0xFF0B, // 6566
+    0xFF0C, // 6567
+    0xFF0D, // 6568
+    0xFF0E, // 6569
+    0xFF0F, // 6570
+    0xFF10, // 6571
+    0xFF11, // 6572
+    0xFF12, // 6573
+    0xFF13, // 6574
+    0xFF14, // 6575
+    0xFF15, // 6576
+    0xFF16, // 6577
+    0xFF17, // 6578
+    0xFF18, // 6579
+    0xFF19, // 6580
+    0xFF1A, // 6581
+    0xFF1B, // 6582
+    0xFF1C, // 6583
+    0xFF1D, // 6584
+    0xFF1E, // 6585
+    0xFF1F, // 6586
+    0xFF20, // 6587
+    0xFF21, // 6588
+    0xFF22, // 6589
+    0xFF23, // 6590
+    0xFF24, // 6591
+    0xFF25, // 6592
+    0xFF26, // 6593
+    0xFF27, // 6594
+    0xFF28, // 6595
+    0xFF29, // 6596
+    0xFF2A, // 6597
+    0xFF2B, // 6598
+    0xFF2C, // 6599
+    0xFF2D, // 6600
+    0xFF2E, // 6601
+    0xFF2F, // 6602
+    0xFF30, // 6603
+    0xFF31, // 6604
+    0xFF32, // 6605
+    0xFF33, // 6606
+    0xFF34, // 6607
+    0xFF35, // 6608
+    0xFF36, // 6609
+    0xFF37, // 6610
+    0xFF38, // 6611
+    0xFF39, // 6612
+    0xFF3A, // 6613
+    0xFF3B, // 6614
+    0xFFE6, // 6615
+    0xFF3D, // 6616
+    0xFF3E, // 6617
+    0xFF3F, // 6618
+    0xFF40, // 6619
+    0xFF41, // 6620
+    0xFF42, // 6621
+    0xFF43, // 6622
+    0xFF44, // 6623
+    0xFF45, // 6624
+    0xFF46, // 6625
+    0xFF47, // 6626
+    0xFF48, // 6627
+    0xFF49, // 6628
+    0xFF4A, // 6629
+    0xFF4B, // 6630
+    0xFF4C, // 6631
+    0xFF4D, // 6632
+    0xFF4E, // 6633
+    0xFF4F, // 6634
+    0xFF50, // 6635
+    0xFF51, // 6636
+    0xFF52, // 6637
+    0xFF53, // 6638
+    0xFF54, // 6639
+    0xFF55, // 6640
+    0xFF56, // 6641
+    0xFF57, // 6642
+    0xFF58, // 6643
+    0xFF59, // 6644
+    0xFF5A, // 6645
+    0xFF5B, // 6646
+    0xFF5C, // 6647
+    0xFF5D, // 6648
+    0xFFE3, // 6649
+    0xC9DE, // 6650
+    0xC9DF, // 6651
+    0xC9E1, // 6652
+    0xC9E3, // 6653
+    0xC9E5, // 6654
+    0xC9E6, // 6655
+    0xC9E8, // 6656
+    0xC9E9, // 6657
+    0xC9EA, // 6658
+    0xC9EB, // 6659
+    0xC9EE, // 6660
+    0xC9F2, // 6661
+    0xC9F3, // 6662
+    0xC9F4, // 6663
+    0xC9F5, // 6664
+    0xC9F6, // 6665
+    0xC9F7, // 6666
+    0xC9FA, // 6667
+    0xC9FB, // 6668
+    0xC9FD, // 6669
+    0xC9FE, // 6670
+    0xC9FF, // 6671
+    0xCA01, // 6672
+    0xCA02, // 6673
+    0xCA03, // 6674
+    0xCA04, // 6675
+    0x0000, // 6676
+    0x0000, // 6677
+    0x0000, // 6678
+    0x0000, // 6679
+    0x0000, // 6680
+    0x0000, // 6681
+    0xCA05, // 6682
+    0xCA06, // 6683
+    0xCA07, // 6684
+    0xCA0A, // 6685
+    0xCA0E, // 6686
+    0xCA0F, // 6687
+    0xCA10, // 6688
+    0xCA11, // 6689
+    0xCA12, // 6690
+    0xCA13, // 6691
+    0xCA15, // 6692
+    0xCA16, // 6693
+    0xCA17, // 6694
+    0xCA19, // 6695
+    0xCA1A, // 6696
+    0xCA1B, // 6697
+    0xCA1C, // 6698
+    0xCA1D, // 6699
+    0xCA1E, // 6700
+    0xCA1F, // 6701
+    0xCA20, // 6702
+    0xCA21, // 6703
+    0xCA22, // 6704
+    0xCA23, // 6705
+    0xCA24, // 6706
+    0xCA25, // 6707
+    0x0000, // 6708
+    0x0000, // 6709
+    0x0000, // 6710
+    0x0000, // 6711
+    0x0000, // 6712
+    0x0000, // 6713
+    0xCA26, // 6714
+    0xCA27, // 6715
+    0xCA28, // 6716
+    0xCA2A, // 6717
+    0xCA2B, // 6718
+    0xCA2C, // 6719
+    0xCA2D, // 6720
+    0xCA2E, // 6721
+    0xCA2F, // 6722
+    0xCA30, // 6723
+    0xCA31, // 6724
+    0xCA32, // 6725
+    0xCA33, // 6726
+    0xCA34, // 6727
+    0xCA35, // 6728
+    0xCA36, // 6729
+    0xCA37, // 6730
+    0xCA38, // 6731
+    0xCA39, // 6732
+    0xCA3A, // 6733
+    0xCA3B, // 6734
+    0xCA3C, // 6735
+    0xCA3D, // 6736
+    0xCA3E, // 6737
+    0xCA3F, // 6738
+    0xCA40, // 6739
+    0xCA41, // 6740
+    0xCA42, // 6741
+    0xCA43, // 6742
+    0xCA44, // 6743
+    0xCA45, // 6744
+    0xCA46, // 6745
+    0x3131, // 6746
+    0x3132, // 6747
+    0x3133, // 6748
+    0x3134, // 6749
+    0x3135, // 6750
+    0x3136, // 6751
+    0x3137, // 6752
+    0x3138, // 6753
+    0x3139, // 6754
+    0x313A, // 6755
+    0x313B, // 6756
+    0x313C, // 6757
+    0x313D, // 6758
+    0x313E, // 6759
+    0x313F, // 6760
+    0x3140, // 6761
+    0x3141, // 6762
+    0x3142, // 6763
+    0x3143, // 6764
+    0x3144, // 6765
+    0x3145, // 6766
+    0x3146, // 6767
+    0x3147, // 6768
+    0x3148, // 6769
+    0x3149, // 6770
+    0x314A, // 6771
+    0x314B, // 6772
+    0x314C, // 6773
+    0x314D, // 6774
+    0x314E, // 6775
+    0x314F, // 6776
+    0x3150, // 6777
+    0x3151, // 6778
+    0x3152, // 6779
+    0x3153, // 6780
+    0x3154, // 6781
+    0x3155, // 6782
+    0x3156, // 6783
+    0x3157, // 6784
+    0x3158, // 6785
+    0x3159, // 6786
+    0x315A, // 6787
+    0x315B, // 6788
+    0x315C, // 6789
+    0x315D, // 6790
+    0x315E, // 6791
+    0x315F, // 6792
+    0x3160, // 6793
+    0x3161, // 6794
+    0x3162, // 6795
+    0x3163, // 6796
+    0x3164, // 6797
+    0x3165, // 6798
+    0x3166, // 6799
+    0x3167, // 6800
+    0x3168, // 6801
+    0x3169, // 6802
+    0x316A, // 6803
+    0x316B, // 6804
+    0x316C, // 6805
+    0x316D, // 6806
+    0x316E, // 6807
+    0x316F, // 6808
+    0x3170, // 6809
+    0x3171, // 6810
+    0x3172, // 6811
+    0x3173, // 6812
+    0x3174, // 6813
+    0x3175, // 6814
+    0x3176, // 6815
+    0x3177, // 6816
+    0x3178, // 6817
+    0x3179, // 6818
+    0x317A, // 6819
+    0x317B, // 6820
+    0x317C, // 6821
+    0x317D, // 6822
+    0x317E, // 6823
+    0x317F, // 6824
+    0x3180, // 6825
+    0x3181, // 6826
+    0x3182, // 6827
+    0x3183, // 6828
+    0x3184, // 6829
+    0x3185, // 6830
+    0x3186, // 6831
+    0x3187, // 6832
+    0x3188, // 6833
+    0x3189, // 6834
+    0x318A, // 6835
+    0x318B, // 6836
+    0x318C, // 6837
+    0x318D, // 6838
+    0x318E, // 6839
+    0xCA47, // 6840
+    0xCA48, // 6841
+    0xCA49, // 6842
+    0xCA4A, // 6843
+    0xCA4B, // 6844
+    0xCA4E, // 6845
+    0xCA4F, // 6846
+    0xCA51, // 6847
+    0xCA52, // 6848
+    0xCA53, // 6849
+    0xCA55, // 6850
+    0xCA56, // 6851
+    0xCA57, // 6852
+    0xCA58, // 6853
+    0xCA59, // 6854
+    0xCA5A, // 6855
+    0xCA5B, // 6856
+    0xCA5E, // 6857
+    0xCA62, // 6858
+    0xCA63, // 6859
+    0xCA64, // 6860
+    0xCA65, // 6861
+    0xCA66, // 6862
+    0xCA67, // 6863
+    0xCA69, // 6864
+    0xCA6A, // 6865
+    0x0000, // 6866
+    0x0000, // 6867
+    0x0000, // 6868
+    0x0000, // 6869
+    0x0000, // 6870
+    0x0000, // 6871
+    0xCA6B, // 6872
+    0xCA6C, // 6873
+    0xCA6D, // 6874
+    0xCA6E, // 6875
+    0xCA6F, // 6876
+    0xCA70, // 6877
+    0xCA71, // 6878
+    0xCA72, // 6879
+    0xCA73, // 6880
+    0xCA74, // 6881
+    0xCA75, // 6882
+    0xCA76, // 6883
+    0xCA77, // 6884
+    0xCA78, // 6885
+    0xCA79, // 6886
+    0xCA7A, // 6887
+    0xCA7B, // 6888
+    0xCA7C, // 6889
+    0xCA7E, // 6890
+    0xCA7F, // 6891
+    0xCA80, // 6892
+    0xCA81, // 6893
+    0xCA82, // 6894
+    0xCA83, // 6895
+    0xCA85, // 6896
+    0xCA86, // 6897
+    0x0000, // 6898
+    0x0000, // 6899
+    0x0000, // 6900
+    0x0000, // 6901
+    0x0000, // 6902
+    0x0000, // 6903
+    0xCA87, // 6904
+    0xCA88, // 6905
+    0xCA89, // 6906
+    0xCA8A, // 6907
+    0xCA8B, // 6908
+    0xCA8C, // 6909
+    0xCA8D, // 6910
+    0xCA8E, // 6911
+    0xCA8F, // 6912
+    0xCA90, // 6913
+    0xCA91, // 6914
+    0xCA92, // 6915
+    0xCA93, // 6916
+    0xCA94, // 6917
+    0xCA95, // 6918
+    0xCA96, // 6919
+    0xCA97, // 6920
+    0xCA99, // 6921
+    0xCA9A, // 6922
+    0xCA9B, // 6923
+    0xCA9C, // 6924
+    0xCA9D, // 6925
+    0xCA9E, // 6926
+    0xCA9F, // 6927
+    0xCAA0, // 6928
+    0xCAA1, // 6929
+    0xCAA2, // 6930
+    0xCAA3, // 6931
+    0xCAA4, // 6932
+    0xCAA5, // 6933
+    0xCAA6, // 6934
+    0xCAA7, // 6935
+    0x2170, // 6936
+    0x2171, // 6937
+    0x2172, // 6938
+    0x2173, // 6939
+    0x2174, // 6940
+    0x2175, // 6941
+    0x2176, // 6942
+    0x2177, // 6943
+    0x2178, // 6944
+    0x2179, // 6945
+    0x0000, // 6946
+    0x0000, // 6947
+    0x0000, // 6948
+    0x0000, // 6949
+    0x0000, // 6950
+    0x2160, // 6951
+    0x2161, // 6952
+    0x2162, // 6953
+    0x2163, // 6954
+    0x2164, // 6955
+    0x2165, // 6956
+    0x2166, // 6957
+    0x2167, // 6958
+    0x2168, // 6959
+    0x2169, // 6960
+    0x0000, // 6961
+    0x0000, // 6962
+    0x0000, // 6963
+    0x0000, // 6964
+    0x0000, // 6965
+    0x0000, // 6966
+    0x0000, // 6967
+    0x0391, // 6968
+    0x0392, // 6969
+    0x0393, // 6970
+    0x0394, // 6971
+    0x0395, // 6972
+    0x0396, // 6973
+    0x0397, // 6974
+    0x0398, // 6975
+    0x0399, // 6976
+    0x039A, // 6977
+    0x039B, // 6978
+    0x039C, // 6979
+    0x039D, // 6980
+    0x039E, // 6981
+    0x039F, // 6982
+    0x03A0, // 6983
+    0x03A1, // 6984
+    0x03A3, // 6985
+    0x03A4, // 6986
+    0x03A5, // 6987
+    0x03A6, // 6988
+    0x03A7, // 6989
+    0x03A8, // 6990
+    0x03A9, // 6991
+    0x0000, // 6992
+    0x0000, // 6993
+    0x0000, // 6994
+    0x0000, // 6995
+    0x0000, // 6996
+    0x0000, // 6997
+    0x0000, // 6998
+    0x0000, // 6999
+    0x03B1, // 7000
+    0x03B2, // 7001
+    0x03B3, // 7002
+    0x03B4, // 7003
+    0x03B5, // 7004
+    0x03B6, // 7005
+    0x03B7, // 7006
+    0x03B8, // 7007
+    0x03B9, // 7008
+    0x03BA, // 7009
+    0x03BB, // 7010
+    0x03BC, // 7011
+    0x03BD, // 7012
+    0x03BE, // 7013
+    0x03BF, // 7014
+    0x03C0, // 7015
+    0x03C1, // 7016
+    0x03C3, // 7017
+    0x03C4, // 7018
+    0x03C5, // 7019
+    0x03C6, // 7020
+    0x03C7, // 7021
+    0x03C8, // 7022
+    0x03C9, // 7023
+    0x0000, // 7024
+    0x0000, // 7025
+    0x0000, // 7026
+    0x0000, // 7027
+    0x0000, // 7028
+    0x0000, // 7029
+    0xCAA8, // 7030
+    0xCAA9, // 7031
+    0xCAAA, // 7032
+    0xCAAB, // 7033
+    0xCAAC, // 7034
+    0xCAAD, // 7035
+    0xCAAE, // 7036
+    0xCAAF, // 7037
+    0xCAB0, // 7038
+    0xCAB1, // 7039
+    0xCAB2, // 7040
+    0xCAB3, // 7041
+    0xCAB4, // 7042
+    0xCAB5, // 7043
+    0xCAB6, // 7044
+    0xCAB7, // 7045
+    0xCAB8, // 7046
+    0xCAB9, // 7047
+    0xCABA, // 7048
+    0xCABB, // 7049
+    0xCABE, // 7050
+    0xCABF, // 7051
+    0xCAC1, // 7052
+    0xCAC2, // 7053
+    0xCAC3, // 7054
+    0xCAC5, // 7055
+    0x0000, // 7056
+    0x0000, // 7057
+    0x0000, // 7058
+    0x0000, // 7059
+    0x0000, // 7060
+    0x0000, // 7061
+    0xCAC6, // 7062
+    0xCAC7, // 7063
+    0xCAC8, // 7064
+    0xCAC9, // 7065
+    0xCACA, // 7066
+    0xCACB, // 7067
+    0xCACE, // 7068
+    0xCAD0, // 7069
+    0xCAD2, // 7070
+    0xCAD4, // 7071
+    0xCAD5, // 7072
+    0xCAD6, // 7073
+    0xCAD7, // 7074
+    0xCADA, // 7075
+    0xCADB, // 7076
+    0xCADC, // 7077
+    0xCADD, // 7078
+    0xCADE, // 7079
+    0xCADF, // 7080
+    0xCAE1, // 7081
+    0xCAE2, // 7082
+    0xCAE3, // 7083
+    0xCAE4, // 7084
+    0xCAE5, // 7085
+    0xCAE6, // 7086
+    0xCAE7, // 7087
+    0x0000, // 7088
+    0x0000, // 7089
+    0x0000, // 7090
+    0x0000, // 7091
+    0x0000, // 7092
+    0x0000, // 7093
+    0xCAE8, // 7094
+    0xCAE9, // 7095
+    0xCAEA, // 7096
+    0xCAEB, // 7097
+    0xCAED, // 7098
+    0xCAEE, // 7099
+    0xCAEF, // 7100
+    0xCAF0, // 7101
+    0xCAF1, // 7102
+    0xCAF2, // 7103
+    0xCAF3, // 7104
+    0xCAF5, // 7105
+    0xCAF6, // 7106
+    0xCAF7, // 7107
+    0xCAF8, // 7108
+    0xCAF9, // 7109
+    0xCAFA, // 7110
+    0xCAFB, // 7111
+    0xCAFC, // 7112
+    0xCAFD, // 7113
+    0xCAFE, // 7114
+    0xCAFF, // 7115
+    0xCB00, // 7116
+    0xCB01, // 7117
+    0xCB02, // 7118
+    0xCB03, // 7119
+    0xCB04, // 7120
+    0xCB05, // 7121
+    0xCB06, // 7122
+    0xCB07, // 7123
+    0xCB09, // 7124
+    0xCB0A, // 7125
+    0x2500, // 7126
+    0x2502, // 7127
+    0x250C, // 7128
+    0x2510, // 7129
+    0x2518, // 7130
+    0x2514, // 7131
+    0x251C, // 7132
+    0x252C, // 7133
+    0x2524, // 7134
+    0x2534, // 7135
+    0x253C, // 7136
+    0x2501, // 7137
+    0x2503, // 7138
+    0x250F, // 7139
+    0x2513, // 7140
+    0x251B, // 7141
+    0x2517, // 7142
+    0x2523, // 7143
+    0x2533, // 7144
+    0x252B, // 7145
+    0x253B, // 7146
+    0x254B, // 7147
+    0x2520, // 7148
+    0x252F, // 7149
+    0x2528, // 7150
+    0x2537, // 7151
+    0x253F, // 7152
+    0x251D, // 7153
+    0x2530, // 7154
+    0x2525, // 7155
+    0x2538, // 7156
+    0x2542, // 7157
+    0x2512, // 7158
+    0x2511, // 7159
+    0x251A, // 7160
+    0x2519, // 7161
+    0x2516, // 7162
+    0x2515, // 7163
+    0x250E, // 7164
+    0x250D, // 7165
+    0x251E, // 7166
+    0x251F, // 7167
+    0x2521, // 7168
+    0x2522, // 7169
+    0x2526, // 7170
+    0x2527, // 7171
+    0x2529, // 7172
+    0x252A, // 7173
+    0x252D, // 7174
+    0x252E, // 7175
+    0x2531, // 7176
+    0x2532, // 7177
+    0x2535, // 7178
+    0x2536, // 7179
+    0x2539, // 7180
+    0x253A, // 7181
+    0x253D, // 7182
+    0x253E, // 7183
+    0x2540, // 7184
+    0x2541, // 7185
+    0x2543, // 7186
+    0x2544, // 7187
+    0x2545, // 7188
+    0x2546, // 7189
+    0x2547, // 7190
+    0x2548, // 7191
+    0x2549, // 7192
+    0x254A, // 7193
+    0x0000, // 7194
+    0x0000, // 7195
+    0x0000, // 7196
+    0x0000, // 7197
+    0x0000, // 7198
+    0x0000, // 7199
+    0x0000, // 7200
+    0x0000, // 7201
+    0x0000, // 7202
+    0x0000, // 7203
+    0x0000, // 7204
+    0x0000, // 7205
+    0x0000, // 7206
+    0x0000, // 7207
+    0x0000, // 7208
+    0x0000, // 7209
+    0x0000, // 7210
+    0x0000, // 7211
+    0x0000, // 7212
+    0x0000, // 7213
+    0x0000, // 7214
+    0x0000, // 7215
+    0x0000, // 7216
+    0x0000, // 7217
+    0x0000, // 7218
+    0x0000, // 7219
+    0xCB0B, // 7220
+    0xCB0C, // 7221
+    0xCB0D, // 7222
+    0xCB0E, // 7223
+    0xCB0F, // 7224
+    0xCB11, // 7225
+    0xCB12, // 7226
+    0xCB13, // 7227
+    0xCB15, // 7228
+    0xCB16, // 7229
+    0xCB17, // 7230
+    0xCB19, // 7231
+    0xCB1A, // 7232
+    0xCB1B, // 7233
+    0xCB1C, // 7234
+    0xCB1D, // 7235
+    0xCB1E, // 7236
+    0xCB1F, // 7237
+    0xCB22, // 7238
+    0xCB23, // 7239
+    0xCB24, // 7240
+    0xCB25, // 7241
+    0xCB26, // 7242
+    0xCB27, // 7243
+    0xCB28, // 7244
+    0xCB29, // 7245
+    0x0000, // 7246
+    0x0000, // 7247
+    0x0000, // 7248
+    0x0000, // 7249
+    0x0000, // 7250
+    0x0000, // 7251
+    0xCB2A, // 7252
+    0xCB2B, // 7253
+    0xCB2C, // 7254
+    0xCB2D, // 7255
+    0xCB2E, // 7256
+    0xCB2F, // 7257
+    0xCB30, // 7258
+    0xCB31, // 7259
+    0xCB32, // 7260
+    0xCB33, // 7261
+    0xCB34, // 7262
+    0xCB35, // 7263
+    0xCB36, // 7264
+    0xCB37, // 7265
+    0xCB38, // 7266
+    0xCB39, // 7267
+    0xCB3A, // 7268
+    0xCB3B, // 7269
+    0xCB3C, // 7270
+    0xCB3D, // 7271
+    0xCB3E, // 7272
+    0xCB3F, // 7273
+    0xCB40, // 7274
+    0xCB42, // 7275
+    0xCB43, // 7276
+    0xCB44, // 7277
+    0x0000, // 7278
+    0x0000, // 7279
+    0x0000, // 7280
+    0x0000, // 7281
+    0x0000, // 7282
+    0x0000, // 7283
+    0xCB45, // 7284
+    0xCB46, // 7285
+    0xCB47, // 7286
+    0xCB4A, // 7287
+    0xCB4B, // 7288
+    0xCB4D, // 7289
+    0xCB4E, // 7290
+    0xCB4F, // 7291
+    0xCB51, // 7292
+    0xCB52, // 7293
+    0xCB53, // 7294
+    0xCB54, // 7295
+    0xCB55, // 7296
+    0xCB56, // 7297
+    0xCB57, // 7298
+    0xCB5A, // 7299
+    0xCB5B, // 7300
+    0xCB5C, // 7301
+    0xCB5E, // 7302
+    0xCB5F, // 7303
+    0xCB60, // 7304
+    0xCB61, // 7305
+    0xCB62, // 7306
+    0xCB63, // 7307
+    0xCB65, // 7308
+    0xCB66, // 7309
+    0xCB67, // 7310
+    0xCB68, // 7311
+    0xCB69, // 7312
+    0xCB6A, // 7313
+    0xCB6B, // 7314
+    0xCB6C, // 7315
+    0x3395, // 7316
+    0x3396, // 7317
+    0x3397, // 7318
+    0x2113, // 7319
+    0x3398, // 7320
+    0x33C4, // 7321
+    0x33A3, // 7322
+    0x33A4, // 7323
+    0x33A5, // 7324
+    0x33A6, // 7325
+    0x3399, // 7326
+    0x339A, // 7327
+    0x339B, // 7328
+    0x339C, // 7329
+    0x339D, // 7330
+    0x339E, // 7331
+    0x339F, // 7332
+    0x33A0, // 7333
+    0x33A1, // 7334
+    0x33A2, // 7335
+    0x33CA, // 7336
+    0x338D, // 7337
+    0x338E, // 7338
+    0x338F, // 7339
+    0x33CF, // 7340
+    0x3388, // 7341
+    0x3389, // 7342
+    0x33C8, // 7343
+    0x33A7, // 7344
+    0x33A8, // 7345
+    0x33B0, // 7346
+    0x33B1, // 7347
+    0x33B2, // 7348
+    0x33B3, // 7349
+    0x33B4, // 7350
+    0x33B5, // 7351
+    0x33B6, // 7352
+    0x33B7, // 7353
+    0x33B8, // 7354
+    0x33B9, // 7355
+    0x3380, // 7356
+    0x3381, // 7357
+    0x3382, // 7358
+    0x3383, // 7359
+    0x3384, // 7360
+    0x33BA, // 7361
+    0x33BB, // 7362
+    0x33BC, // 7363
+    0x33BD, // 7364
+    0x33BE, // 7365
+    0x33BF, // 7366
+    0x3390, // 7367
+    0x3391, // 7368
+    0x3392, // 7369
+    0x3393, // 7370
+    0x3394, // 7371
+    0x2126, // 7372
+    0x33C0, // 7373
+    0x33C1, // 7374
+    0x338A, // 7375
+    0x338B, // 7376
+    0x338C, // 7377
+    0x33D6, // 7378
+    0x33C5, // 7379
+    0x33AD, // 7380
+    0x33AE, // 7381
+    0x33AF, // 7382
+    0x33DB, // 7383
+    0x33A9, // 7384
+    0x33AA, // 7385
+    0x33AB, // 7386
+    0x33AC, // 7387
+    0x33DD, // 7388
+    0x33D0, // 7389
+    0x33D3, // 7390
+    0x33C3, // 7391
+    0x33C9, // 7392
+    0x33DC, // 7393
+    0x33C6, // 7394
+    0x0000, // 7395
+    0x0000, // 7396
+    0x0000, // 7397
+    0x0000, // 7398
+    0x0000, // 7399
+    0x0000, // 7400
+    0x0000, // 7401
+    0x0000, // 7402
+    0x0000, // 7403
+    0x0000, // 7404
+    0x0000, // 7405
+    0x0000, // 7406
+    0x0000, // 7407
+    0x0000, // 7408
+    0x0000, // 7409
+    0xCB6D, // 7410
+    0xCB6E, // 7411
+    0xCB6F, // 7412
+    0xCB70, // 7413
+    0xCB71, // 7414
+    0xCB72, // 7415
+    0xCB73, // 7416
+    0xCB74, // 7417
+    0xCB75, // 7418
+    0xCB76, // 7419
+    0xCB77, // 7420
+    0xCB7A, // 7421
+    0xCB7B, // 7422
+    0xCB7C, // 7423
+    0xCB7D, // 7424
+    0xCB7E, // 7425
+    0xCB7F, // 7426
+    0xCB80, // 7427
+    0xCB81, // 7428
+    0xCB82, // 7429
+    0xCB83, // 7430
+    0xCB84, // 7431
+    0xCB85, // 7432
+    0xCB86, // 7433
+    0xCB87, // 7434
+    0xCB88, // 7435
+    0x0000, // 7436
+    0x0000, // 7437
+    0x0000, // 7438
+    0x0000, // 7439
+    0x0000, // 7440
+    0x0000, // 7441
+    0xCB89, // 7442
+    0xCB8A, // 7443
+    0xCB8B, // 7444
+    0xCB8C, // 7445
+    0xCB8D, // 7446
+    0xCB8E, // 7447
+    0xCB8F, // 7448
+    0xCB90, // 7449
+    0xCB91, // 7450
+    0xCB92, // 7451
+    0xCB93, // 7452
+    0xCB94, // 7453
+    0xCB95, // 7454
+    0xCB96, // 7455
+    0xCB97, // 7456
+    0xCB98, // 7457
+    0xCB99, // 7458
+    0xCB9A, // 7459
+    0xCB9B, // 7460
+    0xCB9D, // 7461
+    0xCB9E, // 7462
+    0xCB9F, // 7463
+    0xCBA0, // 7464
+    0xCBA1, // 7465
+    0xCBA2, // 7466
+    0xCBA3, // 7467
+    0x0000, // 7468
+    0x0000, // 7469
+    0x0000, // 7470
+    0x0000, // 7471
+    0x0000, // 7472
+    0x0000, // 7473
+    0xCBA4, // 7474
+    0xCBA5, // 7475
+    0xCBA6, // 7476
+    0xCBA7, // 7477
+    0xCBA8, // 7478
+    0xCBA9, // 7479
+    0xCBAA, // 7480
+    0xCBAB, // 7481
+    0xCBAC, // 7482
+    0xCBAD, // 7483
+    0xCBAE, // 7484
+    0xCBAF, // 7485
+    0xCBB0, // 7486
+    0xCBB1, // 7487
+    0xCBB2, // 7488
+    0xCBB3, // 7489
+    0xCBB4, // 7490
+    0xCBB5, // 7491
+    0xCBB6, // 7492
+    0xCBB7, // 7493
+    0xCBB9, // 7494
+    0xCBBA, // 7495
+    0xCBBB, // 7496
+    0xCBBC, // 7497
+    0xCBBD, // 7498
+    0xCBBE, // 7499
+    0xCBBF, // 7500
+    0xCBC0, // 7501
+    0xCBC1, // 7502
+    0xCBC2, // 7503
+    0xCBC3, // 7504
+    0xCBC4, // 7505
+    0x00C6, // 7506
+    0x00D0, // 7507
+    0x00AA, // 7508
+    0x0126, // 7509
+    0x0000, // 7510
+    0x0132, // 7511
+    0x0000, // 7512
+    0x013F, // 7513
+    0x0141, // 7514
+    0x00D8, // 7515
+    0x0152, // 7516
+    0x00BA, // 7517
+    0x00DE, // 7518
+    0x0166, // 7519
+    0x014A, // 7520
+    0x0000, // 7521
+    0x3260, // 7522
+    0x3261, // 7523
+    0x3262, // 7524
+    0x3263, // 7525
+    0x3264, // 7526
+    0x3265, // 7527
+    0x3266, // 7528
+    0x3267, // 7529
+    0x3268, // 7530
+    0x3269, // 7531
+    0x326A, // 7532
+    0x326B, // 7533
+    0x326C, // 7534
+    0x326D, // 7535
+    0x326E, // 7536
+    0x326F, // 7537
+    0x3270, // 7538
+    0x3271, // 7539
+    0x3272, // 7540
+    0x3273, // 7541
+    0x3274, // 7542
+    0x3275, // 7543
+    0x3276, // 7544
+    0x3277, // 7545
+    0x3278, // 7546
+    0x3279, // 7547
+    0x327A, // 7548
+    0x327B, // 7549
+    0x24D0, // 7550
+    0x24D1, // 7551
+    0x24D2, // 7552
+    0x24D3, // 7553
+    0x24D4, // 7554
+    0x24D5, // 7555
+    0x24D6, // 7556
+    0x24D7, // 7557
+    0x24D8, // 7558
+    0x24D9, // 7559
+    0x24DA, // 7560
+    0x24DB, // 7561
+    0x24DC, // 7562
+    0x24DD, // 7563
+    0x24DE, // 7564
+    0x24DF, // 7565
+    0x24E0, // 7566
+    0x24E1, // 7567
+    0x24E2, // 7568
+    0x24E3, // 7569
+    0x24E4, // 7570
+    0x24E5, // 7571
+    0x24E6, // 7572
+    0x24E7, // 7573
+    0x24E8, // 7574
+    0x24E9, // 7575
+    0x2460, // 7576
+    0x2461, // 7577
+    0x2462, // 7578
+    0x2463, // 7579
+    0x2464, // 7580
+    0x2465, // 7581
+    0x2466, // 7582
+    0x2467, // 7583
+    0x2468, // 7584
+    0x2469, // 7585
+    0x246A, // 7586
+    0x246B, // 7587
+    0x246C, // 7588
+    0x246D, // 7589
+    0x246E, // 7590
+    0x00BD, // 7591
+    0x2153, // 7592
+    0x2154, // 7593
+    0x00BC, // 7594
+    0x00BE, // 7595
+    0x215B, // 7596
+    0x215C, // 7597
+    0x215D, // 7598
+    0x215E, // 7599
+    0xCBC5, // 7600
+    0xCBC6, // 7601
+    0xCBC7, // 7602
+    0xCBC8, // 7603
+    0xCBC9, // 7604
+    0xCBCA, // 7605
+    0xCBCB, // 7606
+    0xCBCC, // 7607
+    0xCBCD, // 7608
+    0xCBCE, // 7609
+    0xCBCF, // 7610
+    0xCBD0, // 7611
+    0xCBD1, // 7612
+    0xCBD2, // 7613
+    0xCBD3, // 7614
+    0xCBD5, // 7615
+    0xCBD6, // 7616
+    0xCBD7, // 7617
+    0xCBD8, // 7618
+    0xCBD9, // 7619
+    0xCBDA, // 7620
+    0xCBDB, // 7621
+    0xCBDC, // 7622
+    0xCBDD, // 7623
+    0xCBDE, // 7624
+    0xCBDF, // 7625
+    0x0000, // 7626
+    0x0000, // 7627
+    0x0000, // 7628
+    0x0000, // 7629
+    0x0000, // 7630
+    0x0000, // 7631
+    0xCBE0, // 7632
+    0xCBE1, // 7633
+    0xCBE2, // 7634
+    0xCBE3, // 7635
+    0xCBE5, // 7636
+    0xCBE6, // 7637
+    0xCBE8, // 7638
+    0xCBEA, // 7639
+    0xCBEB, // 7640
+    0xCBEC, // 7641
+    0xCBED, // 7642
+    0xCBEE, // 7643
+    0xCBEF, // 7644
+    0xCBF0, // 7645
+    0xCBF1, // 7646
+    0xCBF2, // 7647
+    0xCBF3, // 7648
+    0xCBF4, // 7649
+    0xCBF5, // 7650
+    0xCBF6, // 7651
+    0xCBF7, // 7652
+    0xCBF8, // 7653
+    0xCBF9, // 7654
+    0xCBFA, // 7655
+    0xCBFB, // 7656
+    0xCBFC, // 7657
+    0x0000, // 7658
+    0x0000, // 7659
+    0x0000, // 7660
+    0x0000, // 7661
+    0x0000, // 7662
+    0x0000, // 7663
+    0xCBFD, // 7664
+    0xCBFE, // 7665
+    0xCBFF, // 7666
+    0xCC00, // 7667
+    0xCC01, // 7668
+    0xCC02, // 7669
+    0xCC03, // 7670
+    0xCC04, // 7671
+    0xCC05, // 7672
+    0xCC06, // 7673
+    0xCC07, // 7674
+    0xCC08, // 7675
+    0xCC09, // 7676
+    0xCC0A, // 7677
+    0xCC0B, // 7678
+    0xCC0E, // 7679
+    0xCC0F, // 7680
+    0xCC11, // 7681
+    0xCC12, // 7682
+    0xCC13, // 7683
+    0xCC15, // 7684
+    0xCC16, // 7685
+    0xCC17, // 7686
+    0xCC18, // 7687
+    0xCC19, // 7688
+    0xCC1A, // 7689
+    0xCC1B, // 7690
+    0xCC1E, // 7691
+    0xCC1F, // 7692
+    0xCC20, // 7693
+    0xCC23, // 7694
+    0xCC24, // 7695
+    0x00E6, // 7696
+    0x0111, // 7697
+    0x00F0, // 7698
+    0x0127, // 7699
+    0x0131, // 7700
+    0x0133, // 7701
+    0x0138, // 7702
+    0x0140, // 7703
+    0x0142, // 7704
+    0x00F8, // 7705
+    0x0153, // 7706
+    0x00DF, // 7707
+    0x00FE, // 7708
+    0x0167, // 7709
+    0x014B, // 7710
+    0x0149, // 7711
+    0x3200, // 7712
+    0x3201, // 7713
+    0x3202, // 7714
+    0x3203, // 7715
+    0x3204, // 7716
+    0x3205, // 7717
+    0x3206, // 7718
+    0x3207, // 7719
+    0x3208, // 7720
+    0x3209, // 7721
+    0x320A, // 7722
+    0x320B, // 7723
+    0x320C, // 7724
+    0x320D, // 7725
+    0x320E, // 7726
+    0x320F, // 7727
+    0x3210, // 7728
+    0x3211, // 7729
+    0x3212, // 7730
+    0x3213, // 7731
+    0x3214, // 7732
+    0x3215, // 7733
+    0x3216, // 7734
+    0x3217, // 7735
+    0x3218, // 7736
+    0x3219, // 7737
+    0x321A, // 7738
+    0x321B, // 7739
+    0x249C, // 7740
+    0x249D, // 7741
+    0x249E, // 7742
+    0x249F, // 7743
+    0x24A0, // 7744
+    0x24A1, // 7745
+    0x24A2, // 7746
+    0x24A3, // 7747
+    0x24A4, // 7748
+    0x24A5, // 7749
+    0x24A6, // 7750
+    0x24A7, // 7751
+    0x24A8, // 7752
+    0x24A9, // 7753
+    0x24AA, // 7754
+    0x24AB, // 7755
+    0x24AC, // 7756
+    0x24AD, // 7757
+    0x24AE, // 7758
+    0x24AF, // 7759
+    0x24B0, // 7760
+    0x24B1, // 7761
+    0x24B2, // 7762
+    0x24B3, // 7763
+    0x24B4, // 7764
+    0x24B5, // 7765
+    0x2474, // 7766
+    0x2475, // 7767
+    0x2476, // 7768
+    0x2477, // 7769
+    0x2478, // 7770
+    0x2479, // 7771
+    0x247A, // 7772
+    0x247B, // 7773
+    0x247C, // 7774
+    0x247D, // 7775
+    0x247E, // 7776
+    0x247F, // 7777
+    0x2480, // 7778
+    0x2481, // 7779
+    0x2482, // 7780
+    0x00B9, // 7781
+    0x00B2, // 7782
+    0x00B3, // 7783
+    0x2074, // 7784
+    0x207F, // 7785
+    0x2081, // 7786
+    0x2082, // 7787
+    0x2083, // 7788
+    0x2084, // 7789
+    0xCC25, // 7790
+    0xCC26, // 7791
+    0xCC2A, // 7792
+    0xCC2B, // 7793
+    0xCC2D, // 7794
+    0xCC2F, // 7795
+    0xCC31, // 7796
+    0xCC32, // 7797
+    0xCC33, // 7798
+    0xCC34, // 7799
+    0xCC35, // 7800
+    0xCC36, // 7801
+    0xCC37, // 7802
+    0xCC3A, // 7803
+    0xCC3F, // 7804
+    0xCC40, // 7805
+    0xCC41, // 7806
+    0xCC42, // 7807
+    0xCC43, // 7808
+    0xCC46, // 7809
+    0xCC47, // 7810
+    0xCC49, // 7811
+    0xCC4A, // 7812
+    0xCC4B, // 7813
+    0xCC4D, // 7814
+    0xCC4E, // 7815
+    0x0000, // 7816
+    0x0000, // 7817
+    0x0000, // 7818
+    0x0000, // 7819
+    0x0000, // 7820
+    0x0000, // 7821
+    0xCC4F, // 7822
+    0xCC50, // 7823
+    0xCC51, // 7824
+    0xCC52, // 7825
+    0xCC53, // 7826
+    0xCC56, // 7827
+    0xCC5A, // 7828
+    0xCC5B, // 7829
+    0xCC5C, // 7830
+    0xCC5D, // 7831
+    0xCC5E, // 7832
+    0xCC5F, // 7833
+    0xCC61, // 7834
+    0xCC62, // 7835
+    0xCC63, // 7836
+    0xCC65, // 7837
+    0xCC67, // 7838
+    0xCC69, // 7839
+    0xCC6A, // 7840
+    0xCC6B, // 7841
+    0xCC6C, // 7842
+    0xCC6D, // 7843
+    0xCC6E, // 7844
+    0xCC6F, // 7845
+    0xCC71, // 7846
+    0xCC72, // 7847
+    0x0000, // 7848
+    0x0000, // 7849
+    0x0000, // 7850
+    0x0000, // 7851
+    0x0000, // 7852
+    0x0000, // 7853
+    0xCC73, // 7854
+    0xCC74, // 7855
+    0xCC76, // 7856
+    0xCC77, // 7857
+    0xCC78, // 7858
+    0xCC79, // 7859
+    0xCC7A, // 7860
+    0xCC7B, // 7861
+    0xCC7C, // 7862
+    0xCC7D, // 7863
+    0xCC7E, // 7864
+    0xCC7F, // 7865
+    0xCC80, // 7866
+    0xCC81, // 7867
+    0xCC82, // 7868
+    0xCC83, // 7869
+    0xCC84, // 7870
+    0xCC85, // 7871
+    0xCC86, // 7872
+    0xCC87, // 7873
+    0xCC88, // 7874
+    0xCC89, // 7875
+    0xCC8A, // 7876
+    0xCC8B, // 7877
+    0xCC8C, // 7878
+    0xCC8D, // 7879
+    0xCC8E, // 7880
+    0xCC8F, // 7881
+    0xCC90, // 7882
+    0xCC91, // 7883
+    0xCC92, // 7884
+    0xCC93, // 7885
+    0x3041, // 7886
+    0x3042, // 7887
+    0x3043, // 7888
+    0x3044, // 7889
+    0x3045, // 7890
+    0x3046, // 7891
+    0x3047, // 7892
+    0x3048, // 7893
+    0x3049, // 7894
+    0x304A, // 7895
+    0x304B, // 7896
+    0x304C, // 7897
+    0x304D, // 7898
+    0x304E, // 7899
+    0x304F, // 7900
+    0x3050, // 7901
+    0x3051, // 7902
+    0x3052, // 7903
+    0x3053, // 7904
+    0x3054, // 7905
+    0x3055, // 7906
+    0x3056, // 7907
+    0x3057, // 7908
+    0x3058, // 7909
+    0x3059, // 7910
+    0x305A, // 7911
+    0x305B, // 7912
+    0x305C, // 7913
+    0x305D, // 7914
+    0x305E, // 7915
+    0x305F, // 7916
+    0x3060, // 7917
+    0x3061, // 7918
+    0x3062, // 7919
+    0x3063, // 7920
+    0x3064, // 7921
+    0x3065, // 7922
+    0x3066, // 7923
+    0x3067, // 7924
+    0x3068, // 7925
+    0x3069, // 7926
+    0x306A, // 7927
+    0x306B, // 7928
+    0x306C, // 7929
+    0x306D, // 7930
+    0x306E, // 7931
+    0x306F, // 7932
+    0x3070, // 7933
+    0x3071, // 7934
+    0x3072, // 7935
+    0x3073, // 7936
+    0x3074, // 7937
+    0x3075, // 7938
+    0x3076, // 7939
+    0x3077, // 7940
+    0x3078, // 7941
+    0x3079, // 7942
+    0x307A, // 7943
+    0x307B, // 7944
+    0x307C, // 7945
+    0x307D, // 7946
+    0x307E, // 7947
+    0x307F, // 7948
+    0x3080, // 7949
+    0x3081, // 7950
+    0x3082, // 7951
+    0x3083, // 7952
+    0x3084, // 7953
+    0x3085, // 7954
+    0x3086, // 7955
+    0x3087, // 7956
+    0x3088, // 7957
+    0x3089, // 7958
+    0x308A, // 7959
+    0x308B, // 7960
+    0x308C, // 7961
+    0x308D, // 7962
+    0x308E, // 7963
+    0x308F, // 7964
+    0x3090, // 7965
+    0x3091, // 7966
+    0x3092, // 7967
+    0x3093, // 7968
+    0x0000, // 7969
+    0x0000, // 7970
+    0x0000, // 7971
+    0x0000, // 7972
+    0x0000, // 7973
+    0x0000, // 7974
+    0x0000, // 7975
+    0x0000, // 7976
+    0x0000, // 7977
+    0x0000, // 7978
+    0x0000, // 7979
+    0xCC94, // 7980
+    0xCC95, // 7981
+    0xCC96, // 7982
+    0xCC97, // 7983
+    0xCC9A, // 7984
+    0xCC9B, // 7985
+    0xCC9D, // 7986
+    0xCC9E, // 7987
+    0xCC9F, // 7988
+    0xCCA1, // 7989
+    0xCCA2, // 7990
+    0xCCA3, // 7991
+    0xCCA4, // 7992
+    0xCCA5, // 7993
+    0xCCA6, // 7994
+    0xCCA7, // 7995
+    0xCCAA, // 7996
+    0xCCAE, // 7997
+    0xCCAF, // 7998
+    0xCCB0, // 7999
+    0xCCB1, // 8000
+    0xCCB2, // 8001
+    0xCCB3, // 8002
+    0xCCB6, // 8003
+    0xCCB7, // 8004
+    0xCCB9, // 8005
+    0x0000, // 8006
+    0x0000, // 8007
+    0x0000, // 8008
+    0x0000, // 8009
+    0x0000, // 8010
+    0x0000, // 8011
+    0xCCBA, // 8012
+    0xCCBB, // 8013
+    0xCCBD, // 8014
+    0xCCBE, // 8015
+    0xCCBF, // 8016
+    0xCCC0, // 8017
+    0xCCC1, // 8018
+    0xCCC2, // 8019
+    0xCCC3, // 8020
+    0xCCC6, // 8021
+    0xCCC8, // 8022
+    0xCCCA, // 8023
+    0xCCCB, // 8024
+    0xCCCC, // 8025
+    0xCCCD, // 8026
+    0xCCCE, // 8027
+    0xCCCF, // 8028
+    0xCCD1, // 8029
+    0xCCD2, // 8030
+    0xCCD3, // 8031
+    0xCCD5, // 8032
+    0xCCD6, // 8033
+    0xCCD7, // 8034
+    0xCCD8, // 8035
+    0xCCD9, // 8036
+    0xCCDA, // 8037
+    0x0000, // 8038
+    0x0000, // 8039
+    0x0000, // 8040
+    0x0000, // 8041
+    0x0000, // 8042
+    0x0000, // 8043
+    0xCCDB, // 8044
+    0xCCDC, // 8045
+    0xCCDD, // 8046
+    0xCCDE, // 8047
+    0xCCDF, // 8048
+    0xCCE0, // 8049
+    0xCCE1, // 8050
+    0xCCE2, // 8051
+    0xCCE3, // 8052
+    0xCCE5, // 8053
+    0xCCE6, // 8054
+    0xCCE7, // 8055
+    0xCCE8, // 8056
+    0xCCE9, // 8057
+    0xCCEA, // 8058
+    0xCCEB, // 8059
+    0xCCED, // 8060
+    0xCCEE, // 8061
+    0xCCEF, // 8062
+    0xCCF1, // 8063
+    0xCCF2, // 8064
+    0xCCF3, // 8065
+    0xCCF4, // 8066
+    0xCCF5, // 8067
+    0xCCF6, // 8068
+    0xCCF7, // 8069
+    0xCCF8, // 8070
+    0xCCF9, // 8071
+    0xCCFA, // 8072
+    0xCCFB, // 8073
+    0xCCFC, // 8074
+    0xCCFD, // 8075
+    0x30A1, // 8076
+    0x30A2, // 8077
+    0x30A3, // 8078
+    0x30A4, // 8079
+    0x30A5, // 8080
+    0x30A6, // 8081
+    0x30A7, // 8082
+    0x30A8, // 8083
+    0x30A9, // 8084
+    0x30AA, // 8085
+    0x30AB, // 8086
+    0x30AC, // 8087
+    0x30AD, // 8088
+    0x30AE, // 8089
+    0x30AF, // 8090
+    0x30B0, // 8091
+    0x30B1, // 8092
+    0x30B2, // 8093
+    0x30B3, // 8094
+    0x30B4, // 8095
+    0x30B5, // 8096
+    0x30B6, // 8097
+    0x30B7, // 8098
+    0x30B8, // 8099
+    0x30B9, // 8100
+    0x30BA, // 8101
+    0x30BB, // 8102
+    0x30BC, // 8103
+    0x30BD, // 8104
+    0x30BE, // 8105
+    0x30BF, // 8106
+    0x30C0, // 8107
+    0x30C1, // 8108
+    0x30C2, // 8109
+    0x30C3, // 8110
+    0x30C4, // 8111
+    0x30C5, // 8112
+    0x30C6, // 8113
+    0x30C7, // 8114
+    0x30C8, // 8115
+    0x30C9, // 8116
+    0x30CA, // 8117
+    0x30CB, // 8118
+    0x30CC, // 8119
+    0x30CD, // 8120
+    0x30CE, // 8121
+    0x30CF, // 8122
+    0x30D0, // 8123
+    0x30D1, // 8124
+    0x30D2, // 8125
+    0x30D3, // 8126
+    0x30D4, // 8127
+    0x30D5, // 8128
+    0x30D6, // 8129
+    0x30D7, // 8130
+    0x30D8, // 8131
+    0x30D9, // 8132
+    0x30DA, // 8133
+    0x30DB, // 8134
+    0x30DC, // 8135
+    0x30DD, // 8136
+    0x30DE, // 8137
+    0x30DF, // 8138
+    0x30E0, // 8139
+    0x30E1, // 8140
+    0x30E2, // 8141
+    0x30E3, // 8142
+    0x30E4, // 8143
+    0x30E5, // 8144
+    0x30E6, // 8145
+    0x30E7, // 8146
+    0x30E8, // 8147
+    0x30E9, // 8148
+    0x30EA, // 8149
+    0x30EB, // 8150
+    0x30EC, // 8151
+    0x30ED, // 8152
+    0x30EE, // 8153
+    0x30EF, // 8154
+    0x30F0, // 8155
+    0x30F1, // 8156
+    0x30F2, // 8157
+    0x30F3, // 8158
+    0x30F4, // 8159
+    0x30F5, // 8160
+    0x30F6, // 8161
+    0x0000, // 8162
+    0x0000, // 8163
+    0x0000, // 8164
+    0x0000, // 8165
+    0x0000, // 8166
+    0x0000, // 8167
+    0x0000, // 8168
+    0x0000, // 8169
+    0xCCFE, // 8170
+    0xCCFF, // 8171
+    0xCD00, // 8172
+    0xCD02, // 8173
+    0xCD03, // 8174
+    0xCD04, // 8175
+    0xCD05, // 8176
+    0xCD06, // 8177
+    0xCD07, // 8178
+    0xCD0A, // 8179
+    0xCD0B, // 8180
+    0xCD0D, // 8181
+    0xCD0E, // 8182
+    0xCD0F, // 8183
+    0xCD11, // 8184
+    0xCD12, // 8185
+    0xCD13, // 8186
+    0xCD14, // 8187
+    0xCD15, // 8188
+    0xCD16, // 8189
+    0xCD17, // 8190
+    0xCD1A, // 8191
+    0xCD1C, // 8192
+    0xCD1E, // 8193
+    0xCD1F, // 8194
+    0xCD20, // 8195
+    0x0000, // 8196
+    0x0000, // 8197
+    0x0000, // 8198
+    0x0000, // 8199
+    0x0000, // 8200
+    0x0000, // 8201
+    0xCD21, // 8202
+    0xCD22, // 8203
+    0xCD23, // 8204
+    0xCD25, // 8205
+    0xCD26, // 8206
+    0xCD27, // 8207
+    0xCD29, // 8208
+    0xCD2A, // 8209
+    0xCD2B, // 8210
+    0xCD2D, // 8211
+    0xCD2E, // 8212
+    0xCD2F, // 8213
+    0xCD30, // 8214
+    0xCD31, // 8215
+    0xCD32, // 8216
+    0xCD33, // 8217
+    0xCD34, // 8218
+    0xCD35, // 8219
+    0xCD36, // 8220
+    0xCD37, // 8221
+    0xCD38, // 8222
+    0xCD3A, // 8223
+    0xCD3B, // 8224
+    0xCD3C, // 8225
+    0xCD3D, // 8226
+    0xCD3E, // 8227
+    0x0000, // 8228
+    0x0000, // 8229
+    0x0000, // 8230
+    0x0000, // 8231
+    0x0000, // 8232
+    0x0000, // 8233
+    0xCD3F, // 8234
+    0xCD40, // 8235
+    0xCD41, // 8236
+    0xCD42, // 8237
+    0xCD43, // 8238
+    0xCD44, // 8239
+    0xCD45, // 8240
+    0xCD46, // 8241
+    0xCD47, // 8242
+    0xCD48, // 8243
+    0xCD49, // 8244
+    0xCD4A, // 8245
+    0xCD4B, // 8246
+    0xCD4C, // 8247
+    0xCD4D, // 8248
+    0xCD4E, // 8249
+    0xCD4F, // 8250
+    0xCD50, // 8251
+    0xCD51, // 8252
+    0xCD52, // 8253
+    0xCD53, // 8254
+    0xCD54, // 8255
+    0xCD55, // 8256
+    0xCD56, // 8257
+    0xCD57, // 8258
+    0xCD58, // 8259
+    0xCD59, // 8260
+    0xCD5A, // 8261
+    0xCD5B, // 8262
+    0xCD5D, // 8263
+    0xCD5E, // 8264
+    0xCD5F, // 8265
+    0x0410, // 8266
+    0x0411, // 8267
+    0x0412, // 8268
+    0x0413, // 8269
+    0x0414, // 8270
+    0x0415, // 8271
+    0x0401, // 8272
+    0x0416, // 8273
+    0x0417, // 8274
+    0x0418, // 8275
+    0x0419, // 8276
+    0x041A, // 8277
+    0x041B, // 8278
+    0x041C, // 8279
+    0x041D, // 8280
+    0x041E, // 8281
+    0x041F, // 8282
+    0x0420, // 8283
+    0x0421, // 8284
+    0x0422, // 8285
+    0x0423, // 8286
+    0x0424, // 8287
+    0x0425, // 8288
+    0x0426, // 8289
+    0x0427, // 8290
+    0x0428, // 8291
+    0x0429, // 8292
+    0x042A, // 8293
+    0x042B, // 8294
+    0x042C, // 8295
+    0x042D, // 8296
+    0x042E, // 8297
+    0x042F, // 8298
+    0x0000, // 8299
+    0x0000, // 8300
+    0x0000, // 8301
+    0x0000, // 8302
+    0x0000, // 8303
+    0x0000, // 8304
+    0x0000, // 8305
+    0x0000, // 8306
+    0x0000, // 8307
+    0x0000, // 8308
+    0x0000, // 8309
+    0x0000, // 8310
+    0x0000, // 8311
+    0x0000, // 8312
+    0x0000, // 8313
+    0x0430, // 8314
+    0x0431, // 8315
+    0x0432, // 8316
+    0x0433, // 8317
+    0x0434, // 8318
+    0x0435, // 8319
+    0x0451, // 8320
+    0x0436, // 8321
+    0x0437, // 8322
+    0x0438, // 8323
+    0x0439, // 8324
+    0x043A, // 8325
+    0x043B, // 8326
+    0x043C, // 8327
+    0x043D, // 8328
+    0x043E, // 8329
+    0x043F, // 8330
+    0x0440, // 8331
+    0x0441, // 8332
+    0x0442, // 8333
+    0x0443, // 8334
+    0x0444, // 8335
+    0x0445, // 8336
+    0x0446, // 8337
+    0x0447, // 8338
+    0x0448, // 8339
+    0x0449, // 8340
+    0x044A, // 8341
+    0x044B, // 8342
+    0x044C, // 8343
+    0x044D, // 8344
+    0x044E, // 8345
+    0x044F, // 8346
+    0x0000, // 8347
+    0x0000, // 8348
+    0x0000, // 8349
+    0x0000, // 8350
+    0x0000, // 8351
+    0x0000, // 8352
+    0x0000, // 8353
+    0x0000, // 8354
+    0x0000, // 8355
+    0x0000, // 8356
+    0x0000, // 8357
+    0x0000, // 8358
+    0x0000, // 8359
+    0xCD61, // 8360
+    0xCD62, // 8361
+    0xCD63, // 8362
+    0xCD65, // 8363
+    0xCD66, // 8364
+    0xCD67, // 8365
+    0xCD68, // 8366
+    0xCD69, // 8367
+    0xCD6A, // 8368
+    0xCD6B, // 8369
+    0xCD6E, // 8370
+    0xCD70, // 8371
+    0xCD72, // 8372
+    0xCD73, // 8373
+    0xCD74, // 8374
+    0xCD75, // 8375
+    0xCD76, // 8376
+    0xCD77, // 8377
+    0xCD79, // 8378
+    0xCD7A, // 8379
+    0xCD7B, // 8380
+    0xCD7C, // 8381
+    0xCD7D, // 8382
+    0xCD7E, // 8383
+    0xCD7F, // 8384
+    0xCD80, // 8385
+    0x0000, // 8386
+    0x0000, // 8387
+    0x0000, // 8388
+    0x0000, // 8389
+    0x0000, // 8390
+    0x0000, // 8391
+    0xCD81, // 8392
+    0xCD82, // 8393
+    0xCD83, // 8394
+    0xCD84, // 8395
+    0xCD85, // 8396
+    0xCD86, // 8397
+    0xCD87, // 8398
+    0xCD89, // 8399
+    0xCD8A, // 8400
+    0xCD8B, // 8401
+    0xCD8C, // 8402
+    0xCD8D, // 8403
+    0xCD8E, // 8404
+    0xCD8F, // 8405
+    0xCD90, // 8406
+    0xCD91, // 8407
+    0xCD92, // 8408
+    0xCD93, // 8409
+    0xCD96, // 8410
+    0xCD97, // 8411
+    0xCD99, // 8412
+    0xCD9A, // 8413
+    0xCD9B, // 8414
+    0xCD9D, // 8415
+    0xCD9E, // 8416
+    0xCD9F, // 8417
+    0x0000, // 8418
+    0x0000, // 8419
+    0x0000, // 8420
+    0x0000, // 8421
+    0x0000, // 8422
+    0x0000, // 8423
+    0xCDA0, // 8424
+    0xCDA1, // 8425
+    0xCDA2, // 8426
+    0xCDA3, // 8427
+    0xCDA6, // 8428
+    0xCDA8, // 8429
+    0xCDAA, // 8430
+    0xCDAB, // 8431
+    0xCDAC, // 8432
+    0xCDAD, // 8433
+    0xCDAE, // 8434
+    0xCDAF, // 8435
+    0xCDB1, // 8436
+    0xCDB2, // 8437
+    0xCDB3, // 8438
+    0xCDB4, // 8439
+    0xCDB5, // 8440
+    0xCDB6, // 8441
+    0xCDB7, // 8442
+    0xCDB8, // 8443
+    0xCDB9, // 8444
+    0xCDBA, // 8445
+    0xCDBB, // 8446
+    0xCDBC, // 8447
+    0xCDBD, // 8448
+    0xCDBE, // 8449
+    0xCDBF, // 8450
+    0xCDC0, // 8451
+    0xCDC1, // 8452
+    0xCDC2, // 8453
+    0xCDC3, // 8454
+    0xCDC5, // 8455
+    0x0000, // 8456
+    0x0000, // 8457
+    0x0000, // 8458
+    0x0000, // 8459
+    0x0000, // 8460
+    0x0000, // 8461
+    0x0000, // 8462
+    0x0000, // 8463
+    0x0000, // 8464
+    0x0000, // 8465
+    0x0000, // 8466
+    0x0000, // 8467
+    0x0000, // 8468
+    0x0000, // 8469
+    0x0000, // 8470
+    0x0000, // 8471
+    0x0000, // 8472
+    0x0000, // 8473
+    0x0000, // 8474
+    0x0000, // 8475
+    0x0000, // 8476
+    0x0000, // 8477
+    0x0000, // 8478
+    0x0000, // 8479
+    0x0000, // 8480
+    0x0000, // 8481
+    0x0000, // 8482
+    0x0000, // 8483
+    0x0000, // 8484
+    0x0000, // 8485
+    0x0000, // 8486
+    0x0000, // 8487
+    0x0000, // 8488
+    0x0000, // 8489
+    0x0000, // 8490
+    0x0000, // 8491
+    0x0000, // 8492
+    0x0000, // 8493
+    0x0000, // 8494
+    0x0000, // 8495
+    0x0000, // 8496
+    0x0000, // 8497
+    0x0000, // 8498
+    0x0000, // 8499
+    0x0000, // 8500
+    0x0000, // 8501
+    0x0000, // 8502
+    0x0000, // 8503
+    0x0000, // 8504
+    0x0000, // 8505
+    0x0000, // 8506
+    0x0000, // 8507
+    0x0000, // 8508
+    0x0000, // 8509
+    0x0000, // 8510
+    0x0000, // 8511
+    0x0000, // 8512
+    0x0000, // 8513
+    0x0000, // 8514
+    0x0000, // 8515
+    0x0000, // 8516
+    0x0000, // 8517
+    0x0000, // 8518
+    0x0000, // 8519
+    0x0000, // 8520
+    0x0000, // 8521
+    0x0000, // 8522
+    0x0000, // 8523
+    0x0000, // 8524
+    0x0000, // 8525
+    0x0000, // 8526
+    0x0000, // 8527
+    0x0000, // 8528
+    0x0000, // 8529
+    0x0000, // 8530
+    0x0000, // 8531
+    0x0000, // 8532
+    0x0000, // 8533
+    0x0000, // 8534
+    0x0000, // 8535
+    0x0000, // 8536
+    0x0000, // 8537
+    0x0000, // 8538
+    0x0000, // 8539
+    0x0000, // 8540
+    0x0000, // 8541
+    0x0000, // 8542
+    0x0000, // 8543
+    0x0000, // 8544
+    0x0000, // 8545
+    0x0000, // 8546
+    0x0000, // 8547
+    0x0000, // 8548
+    0x0000, // 8549
+    0xCDC6, // 8550
+    0xCDC7, // 8551
+    0xCDC8, // 8552
+    0xCDC9, // 8553
+    0xCDCA, // 8554
+    0xCDCB, // 8555
+    0xCDCD, // 8556
+    0xCDCE, // 8557
+    0xCDCF, // 8558
+    0xCDD1, // 8559
+    0xCDD2, // 8560
+    0xCDD3, // 8561
+    0xCDD4, // 8562
+    0xCDD5, // 8563
+    0xCDD6, // 8564
+    0xCDD7, // 8565
+    0xCDD8, // 8566
+    0xCDD9, // 8567
+    0xCDDA, // 8568
+    0xCDDB, // 8569
+    0xCDDC, // 8570
+    0xCDDD, // 8571
+    0xCDDE, // 8572
+    0xCDDF, // 8573
+    0xCDE0, // 8574
+    0xCDE1, // 8575
+    0x0000, // 8576
+    0x0000, // 8577
+    0x0000, // 8578
+    0x0000, // 8579
+    0x0000, // 8580
+    0x0000, // 8581
+    0xCDE2, // 8582
+    0xCDE3, // 8583
+    0xCDE4, // 8584
+    0xCDE5, // 8585
+    0xCDE6, // 8586
+    0xCDE7, // 8587
+    0xCDE9, // 8588
+    0xCDEA, // 8589
+    0xCDEB, // 8590
+    0xCDED, // 8591
+    0xCDEE, // 8592
+    0xCDEF, // 8593
+    0xCDF1, // 8594
+    0xCDF2, // 8595
+    0xCDF3, // 8596
+    0xCDF4, // 8597
+    0xCDF5, // 8598
+    0xCDF6, // 8599
+    0xCDF7, // 8600
+    0xCDFA, // 8601
+    0xCDFC, // 8602
+    0xCDFE, // 8603
+    0xCDFF, // 8604
+    0xCE00, // 8605
+    0xCE01, // 8606
+    0xCE02, // 8607
+    0x0000, // 8608
+    0x0000, // 8609
+    0x0000, // 8610
+    0x0000, // 8611
+    0x0000, // 8612
+    0x0000, // 8613
+    0xCE03, // 8614
+    0xCE05, // 8615
+    0xCE06, // 8616
+    0xCE07, // 8617
+    0xCE09, // 8618
+    0xCE0A, // 8619
+    0xCE0B, // 8620
+    0xCE0D, // 8621
+    0xCE0E, // 8622
+    0xCE0F, // 8623
+    0xCE10, // 8624
+    0xCE11, // 8625
+    0xCE12, // 8626
+    0xCE13, // 8627
+    0xCE15, // 8628
+    0xCE16, // 8629
+    0xCE17, // 8630
+    0xCE18, // 8631
+    0xCE1A, // 8632
+    0xCE1B, // 8633
+    0xCE1C, // 8634
+    0xCE1D, // 8635
+    0xCE1E, // 8636
+    0xCE1F, // 8637
+    0xCE22, // 8638
+    0xCE23, // 8639
+    0xCE25, // 8640
+    0xCE26, // 8641
+    0xCE27, // 8642
+    0xCE29, // 8643
+    0xCE2A, // 8644
+    0xCE2B, // 8645
+    0x0000, // 8646
+    0x0000, // 8647
+    0x0000, // 8648
+    0x0000, // 8649
+    0x0000, // 8650
+    0x0000, // 8651
+    0x0000, // 8652
+    0x0000, // 8653
+    0x0000, // 8654
+    0x0000, // 8655
+    0x0000, // 8656
+    0x0000, // 8657
+    0x0000, // 8658
+    0x0000, // 8659
+    0x0000, // 8660
+    0x0000, // 8661
+    0x0000, // 8662
+    0x0000, // 8663
+    0x0000, // 8664
+    0x0000, // 8665
+    0x0000, // 8666
+    0x0000, // 8667
+    0x0000, // 8668
+    0x0000, // 8669
+    0x0000, // 8670
+    0x0000, // 8671
+    0x0000, // 8672
+    0x0000, // 8673
+    0x0000, // 8674
+    0x0000, // 8675
+    0x0000, // 8676
+    0x0000, // 8677
+    0x0000, // 8678
+    0x0000, // 8679
+    0x0000, // 8680
+    0x0000, // 8681
+    0x0000, // 8682
+    0x0000, // 8683
+    0x0000, // 8684
+    0x0000, // 8685
+    0x0000, // 8686
+    0x0000, // 8687
+    0x0000, // 8688
+    0x0000, // 8689
+    0x0000, // 8690
+    0x0000, // 8691
+    0x0000, // 8692
+    0x0000, // 8693
+    0x0000, // 8694
+    0x0000, // 8695
+    0x0000, // 8696
+    0x0000, // 8697
+    0x0000, // 8698
+    0x0000, // 8699
+    0x0000, // 8700
+    0x0000, // 8701
+    0x0000, // 8702
+    0x0000, // 8703
+    0x0000, // 8704
+    0x0000, // 8705
+    0x0000, // 8706
+    0x0000, // 8707
+    0x0000, // 8708
+    0x0000, // 8709
+    0x0000, // 8710
+    0x0000, // 8711
+    0x0000, // 8712
+    0x0000, // 8713
+    0x0000, // 8714
+    0x0000, // 8715
+    0x0000, // 8716
+    0x0000, // 8717
+    0x0000, // 8718
+    0x0000, // 8719
+    0x0000, // 8720
+    0x0000, // 8721
+    0x0000, // 8722
+    0x0000, // 8723
+    0x0000, // 8724
+    0x0000, // 8725
+    0x0000, // 8726
+    0x0000, // 8727
+    0x0000, // 8728
+    0x0000, // 8729
+    0x0000, // 8730
+    0x0000, // 8731
+    0x0000, // 8732
+    0x0000, // 8733
+    0x0000, // 8734
+    0x0000, // 8735
+    0x0000, // 8736
+    0x0000, // 8737
+    0x0000, // 8738
+    0x0000, // 8739
+    0xCE2C, // 8740
+    0xCE2D, // 8741
+    0xCE2E, // 8742
+    0xCE2F, // 8743
+    0xCE32, // 8744
+    0xCE34, // 8745
+    0xCE36, // 8746
+    0xCE37, // 8747
+    0xCE38, // 8748
+    0xCE39, // 8749
+    0xCE3A, // 8750
+    0xCE3B, // 8751
+    0xCE3C, // 8752
+    0xCE3D, // 8753
+    0xCE3E, // 8754
+    0xCE3F, // 8755
+    0xCE40, // 8756
+    0xCE41, // 8757
+    0xCE42, // 8758
+    0xCE43, // 8759
+    0xCE44, // 8760
+    0xCE45, // 8761
+    0xCE46, // 8762
+    0xCE47, // 8763
+    0xCE48, // 8764
+    0xCE49, // 8765
+    0x0000, // 8766
+    0x0000, // 8767
+    0x0000, // 8768
+    0x0000, // 8769
+    0x0000, // 8770
+    0x0000, // 8771
+    0xCE4A, // 8772
+    0xCE4B, // 8773
+    0xCE4C, // 8774
+    0xCE4D, // 8775
+    0xCE4E, // 8776
+    0xCE4F, // 8777
+    0xCE50, // 8778
+    0xCE51, // 8779
+    0xCE52, // 8780
+    0xCE53, // 8781
+    0xCE54, // 8782
+    0xCE55, // 8783
+    0xCE56, // 8784
+    0xCE57, // 8785
+    0xCE5A, // 8786
+    0xCE5B, // 8787
+    0xCE5D, // 8788
+    0xCE5E, // 8789
+    0xCE62, // 8790
+    0xCE63, // 8791
+    0xCE64, // 8792
+    0xCE65, // 8793
+    0xCE66, // 8794
+    0xCE67, // 8795
+    0xCE6A, // 8796
+    0xCE6C, // 8797
+    0x0000, // 8798
+    0x0000, // 8799
+    0x0000, // 8800
+    0x0000, // 8801
+    0x0000, // 8802
+    0x0000, // 8803
+    0xCE6E, // 8804
+    0xCE6F, // 8805
+    0xCE70, // 8806
+    0xCE71, // 8807
+    0xCE72, // 8808
+    0xCE73, // 8809
+    0xCE76, // 8810
+    0xCE77, // 8811
+    0xCE79, // 8812
+    0xCE7A, // 8813
+    0xCE7B, // 8814
+    0xCE7D, // 8815
+    0xCE7E, // 8816
+    0xCE7F, // 8817
+    0xCE80, // 8818
+    0xCE81, // 8819
+    0xCE82, // 8820
+    0xCE83, // 8821
+    0xCE86, // 8822
+    0xCE88, // 8823
+    0xCE8A, // 8824
+    0xCE8B, // 8825
+    0xCE8C, // 8826
+    0xCE8D, // 8827
+    0xCE8E, // 8828
+    0xCE8F, // 8829
+    0xCE92, // 8830
+    0xCE93, // 8831
+    0xCE95, // 8832
+    0xCE96, // 8833
+    0xCE97, // 8834
+    0xCE99, // 8835
+    0x0000, // 8836
+    0x0000, // 8837
+    0x0000, // 8838
+    0x0000, // 8839
+    0x0000, // 8840
+    0x0000, // 8841
+    0x0000, // 8842
+    0x0000, // 8843
+    0x0000, // 8844
+    0x0000, // 8845
+    0x0000, // 8846
+    0x0000, // 8847
+    0x0000, // 8848
+    0x0000, // 8849
+    0x0000, // 8850
+    0x0000, // 8851
+    0x0000, // 8852
+    0x0000, // 8853
+    0x0000, // 8854
+    0x0000, // 8855
+    0x0000, // 8856
+    0x0000, // 8857
+    0x0000, // 8858
+    0x0000, // 8859
+    0x0000, // 8860
+    0x0000, // 8861
+    0x0000, // 8862
+    0x0000, // 8863
+    0x0000, // 8864
+    0x0000, // 8865
+    0x0000, // 8866
+    0x0000, // 8867
+    0x0000, // 8868
+    0x0000, // 8869
+    0x0000, // 8870
+    0x0000, // 8871
+    0x0000, // 8872
+    0x0000, // 8873
+    0x0000, // 8874
+    0x0000, // 8875
+    0x0000, // 8876
+    0x0000, // 8877
+    0x0000, // 8878
+    0x0000, // 8879
+    0x0000, // 8880
+    0x0000, // 8881
+    0x0000, // 8882
+    0x0000, // 8883
+    0x0000, // 8884
+    0x0000, // 8885
+    0x0000, // 8886
+    0x0000, // 8887
+    0x0000, // 8888
+    0x0000, // 8889
+    0x0000, // 8890
+    0x0000, // 8891
+    0x0000, // 8892
+    0x0000, // 8893
+    0x0000, // 8894
+    0x0000, // 8895
+    0x0000, // 8896
+    0x0000, // 8897
+    0x0000, // 8898
+    0x0000, // 8899
+    0x0000, // 8900
+    0x0000, // 8901
+    0x0000, // 8902
+    0x0000, // 8903
+    0x0000, // 8904
+    0x0000, // 8905
+    0x0000, // 8906
+    0x0000, // 8907
+    0x0000, // 8908
+    0x0000, // 8909
+    0x0000, // 8910
+    0x0000, // 8911
+    0x0000, // 8912
+    0x0000, // 8913
+    0x0000, // 8914
+    0x0000, // 8915
+    0x0000, // 8916
+    0x0000, // 8917
+    0x0000, // 8918
+    0x0000, // 8919
+    0x0000, // 8920
+    0x0000, // 8921
+    0x0000, // 8922
+    0x0000, // 8923
+    0x0000, // 8924
+    0x0000, // 8925
+    0x0000, // 8926
+    0x0000, // 8927
+    0x0000, // 8928
+    0x0000, // 8929
+    0xCE9A, // 8930
+    0xCE9B, // 8931
+    0xCE9C, // 8932
+    0xCE9D, // 8933
+    0xCE9E, // 8934
+    0xCE9F, // 8935
+    0xCEA2, // 8936
+    0xCEA6, // 8937
+    0xCEA7, // 8938
+    0xCEA8, // 8939
+    0xCEA9, // 8940
+    0xCEAA, // 8941
+    0xCEAB, // 8942
+    0xCEAE, // 8943
+    0xCEAF, // 8944
+    0xCEB0, // 8945
+    0xCEB1, // 8946
+    0xCEB2, // 8947
+    0xCEB3, // 8948
+    0xCEB4, // 8949
+    0xCEB5, // 8950
+    0xCEB6, // 8951
+    0xCEB7, // 8952
+    0xCEB8, // 8953
+    0xCEB9, // 8954
+    0xCEBA, // 8955
+    0x0000, // 8956
+    0x0000, // 8957
+    0x0000, // 8958
+    0x0000, // 8959
+    0x0000, // 8960
+    0x0000, // 8961
+    0xCEBB, // 8962
+    0xCEBC, // 8963
+    0xCEBD, // 8964
+    0xCEBE, // 8965
+    0xCEBF, // 8966
+    0xCEC0, // 8967
+    0xCEC2, // 8968
+    0xCEC3, // 8969
+    0xCEC4, // 8970
+    0xCEC5, // 8971
+    0xCEC6, // 8972
+    0xCEC7, // 8973
+    0xCEC8, // 8974
+    0xCEC9, // 8975
+    0xCECA, // 8976
+    0xCECB, // 8977
+    0xCECC, // 8978
+    0xCECD, // 8979
+    0xCECE, // 8980
+    0xCECF, // 8981
+    0xCED0, // 8982
+    0xCED1, // 8983
+    0xCED2, // 8984
+    0xCED3, // 8985
+    0xCED4, // 8986
+    0xCED5, // 8987
+    0x0000, // 8988
+    0x0000, // 8989
+    0x0000, // 8990
+    0x0000, // 8991
+    0x0000, // 8992
+    0x0000, // 8993
+    0xCED6, // 8994
+    0xCED7, // 8995
+    0xCED8, // 8996
+    0xCED9, // 8997
+    0xCEDA, // 8998
+    0xCEDB, // 8999
+    0xCEDC, // 9000
+    0xCEDD, // 9001
+    0xCEDE, // 9002
+    0xCEDF, // 9003
+    0xCEE0, // 9004
+    0xCEE1, // 9005
+    0xCEE2, // 9006
+    0xCEE3, // 9007
+    0xCEE6, // 9008
+    0xCEE7, // 9009
+    0xCEE9, // 9010
+    0xCEEA, // 9011
+    0xCEED, // 9012
+    0xCEEE, // 9013
+    0xCEEF, // 9014
+    0xCEF0, // 9015
+    0xCEF1, // 9016
+    0xCEF2, // 9017
+    0xCEF3, // 9018
+    0xCEF6, // 9019
+    0xCEFA, // 9020
+    0xCEFB, // 9021
+    0xCEFC, // 9022
+    0xCEFD, // 9023
+    0xCEFE, // 9024
+    0xCEFF, // 9025
+    0xAC00, // 9026
+    0xAC01, // 9027
+    0xAC04, // 9028
+    0xAC07, // 9029
+    0xAC08, // 9030
+    0xAC09, // 9031
+    0xAC0A, // 9032
+    0xAC10, // 9033
+    0xAC11, // 9034
+    0xAC12, // 9035
+    0xAC13, // 9036
+    0xAC14, // 9037
+    0xAC15, // 9038
+    0xAC16, // 9039
+    0xAC17, // 9040
+    0xAC19, // 9041
+    0xAC1A, // 9042
+    0xAC1B, // 9043
+    0xAC1C, // 9044
+    0xAC1D, // 9045
+    0xAC20, // 9046
+    0xAC24, // 9047
+    0xAC2C, // 9048
+    0xAC2D, // 9049
+    0xAC2F, // 9050
+    0xAC30, // 9051
+    0xAC31, // 9052
+    0xAC38, // 9053
+    0xAC39, // 9054
+    0xAC3C, // 9055
+    0xAC40, // 9056
+    0xAC4B, // 9057
+    0xAC4D, // 9058
+    0xAC54, // 9059
+    0xAC58, // 9060
+    0xAC5C, // 9061
+    0xAC70, // 9062
+    0xAC71, // 9063
+    0xAC74, // 9064
+    0xAC77, // 9065
+    0xAC78, // 9066
+    0xAC7A, // 9067
+    0xAC80, // 9068
+    0xAC81, // 9069
+    0xAC83, // 9070
+    0xAC84, // 9071
+    0xAC85, // 9072
+    0xAC86, // 9073
+    0xAC89, // 9074
+    0xAC8A, // 9075
+    0xAC8B, // 9076
+    0xAC8C, // 9077
+    0xAC90, // 9078
+    0xAC94, // 9079
+    0xAC9C, // 9080
+    0xAC9D, // 9081
+    0xAC9F, // 9082
+    0xACA0, // 9083
+    0xACA1, // 9084
+    0xACA8, // 9085
+    0xACA9, // 9086
+    0xACAA, // 9087
+    0xACAC, // 9088
+    0xACAF, // 9089
+    0xACB0, // 9090
+    0xACB8, // 9091
+    0xACB9, // 9092
+    0xACBB, // 9093
+    0xACBC, // 9094
+    0xACBD, // 9095
+    0xACC1, // 9096
+    0xACC4, // 9097
+    0xACC8, // 9098
+    0xACCC, // 9099
+    0xACD5, // 9100
+    0xACD7, // 9101
+    0xACE0, // 9102
+    0xACE1, // 9103
+    0xACE4, // 9104
+    0xACE7, // 9105
+    0xACE8, // 9106
+    0xACEA, // 9107
+    0xACEC, // 9108
+    0xACEF, // 9109
+    0xACF0, // 9110
+    0xACF1, // 9111
+    0xACF3, // 9112
+    0xACF5, // 9113
+    0xACF6, // 9114
+    0xACFC, // 9115
+    0xACFD, // 9116
+    0xAD00, // 9117
+    0xAD04, // 9118
+    0xAD06, // 9119
+    0xCF02, // 9120
+    0xCF03, // 9121
+    0xCF05, // 9122
+    0xCF06, // 9123
+    0xCF07, // 9124
+    0xCF09, // 9125
+    0xCF0A, // 9126
+    0xCF0B, // 9127
+    0xCF0C, // 9128
+    0xCF0D, // 9129
+    0xCF0E, // 9130
+    0xCF0F, // 9131
+    0xCF12, // 9132
+    0xCF14, // 9133
+    0xCF16, // 9134
+    0xCF17, // 9135
+    0xCF18, // 9136
+    0xCF19, // 9137
+    0xCF1A, // 9138
+    0xCF1B, // 9139
+    0xCF1D, // 9140
+    0xCF1E, // 9141
+    0xCF1F, // 9142
+    0xCF21, // 9143
+    0xCF22, // 9144
+    0xCF23, // 9145
+    0x0000, // 9146
+    0x0000, // 9147
+    0x0000, // 9148
+    0x0000, // 9149
+    0x0000, // 9150
+    0x0000, // 9151
+    0xCF25, // 9152
+    0xCF26, // 9153
+    0xCF27, // 9154
+    0xCF28, // 9155
+    0xCF29, // 9156
+    0xCF2A, // 9157
+    0xCF2B, // 9158
+    0xCF2E, // 9159
+    0xCF32, // 9160
+    0xCF33, // 9161
+    0xCF34, // 9162
+    0xCF35, // 9163
+    0xCF36, // 9164
+    0xCF37, // 9165
+    0xCF39, // 9166
+    0xCF3A, // 9167
+    0xCF3B, // 9168
+    0xCF3C, // 9169
+    0xCF3D, // 9170
+    0xCF3E, // 9171
+    0xCF3F, // 9172
+    0xCF40, // 9173
+    0xCF41, // 9174
+    0xCF42, // 9175
+    0xCF43, // 9176
+    0xCF44, // 9177
+    0x0000, // 9178
+    0x0000, // 9179
+    0x0000, // 9180
+    0x0000, // 9181
+    0x0000, // 9182
+    0x0000, // 9183
+    0xCF45, // 9184
+    0xCF46, // 9185
+    0xCF47, // 9186
+    0xCF48, // 9187
+    0xCF49, // 9188
+    0xCF4A, // 9189
+    0xCF4B, // 9190
+    0xCF4C, // 9191
+    0xCF4D, // 9192
+    0xCF4E, // 9193
+    0xCF4F, // 9194
+    0xCF50, // 9195
+    0xCF51, // 9196
+    0xCF52, // 9197
+    0xCF53, // 9198
+    0xCF56, // 9199
+    0xCF57, // 9200
+    0xCF59, // 9201
+    0xCF5A, // 9202
+    0xCF5B, // 9203
+    0xCF5D, // 9204
+    0xCF5E, // 9205
+    0xCF5F, // 9206
+    0xCF60, // 9207
+    0xCF61, // 9208
+    0xCF62, // 9209
+    0xCF63, // 9210
+    0xCF66, // 9211
+    0xCF68, // 9212
+    0xCF6A, // 9213
+    0xCF6B, // 9214
+    0xCF6C, // 9215
+    0xAD0C, // 9216
+    0xAD0D, // 9217
+    0xAD0F, // 9218
+    0xAD11, // 9219
+    0xAD18, // 9220
+    0xAD1C, // 9221
+    0xAD20, // 9222
+    0xAD29, // 9223
+    0xAD2C, // 9224
+    0xAD2D, // 9225
+    0xAD34, // 9226
+    0xAD35, // 9227
+    0xAD38, // 9228
+    0xAD3C, // 9229
+    0xAD44, // 9230
+    0xAD45, // 9231
+    0xAD47, // 9232
+    0xAD49, // 9233
+    0xAD50, // 9234
+    0xAD54, // 9235
+    0xAD58, // 9236
+    0xAD61, // 9237
+    0xAD63, // 9238
+    0xAD6C, // 9239
+    0xAD6D, // 9240
+    0xAD70, // 9241
+    0xAD73, // 9242
+    0xAD74, // 9243
+    0xAD75, // 9244
+    0xAD76, // 9245
+    0xAD7B, // 9246
+    0xAD7C, // 9247
+    0xAD7D, // 9248
+    0xAD7F, // 9249
+    0xAD81, // 9250
+    0xAD82, // 9251
+    0xAD88, // 9252
+    0xAD89, // 9253
+    0xAD8C, // 9254
+    0xAD90, // 9255
+    0xAD9C, // 9256
+    0xAD9D, // 9257
+    0xADA4, // 9258
+    0xADB7, // 9259
+    0xADC0, // 9260
+    0xADC1, // 9261
+    0xADC4, // 9262
+    0xADC8, // 9263
+    0xADD0, // 9264
+    0xADD1, // 9265
+    0xADD3, // 9266
+    0xADDC, // 9267
+    0xADE0, // 9268
+    0xADE4, // 9269
+    0xADF8, // 9270
+    0xADF9, // 9271
+    0xADFC, // 9272
+    0xADFF, // 9273
+    0xAE00, // 9274
+    0xAE01, // 9275
+    0xAE08, // 9276
+    0xAE09, // 9277
+    0xAE0B, // 9278
+    0xAE0D, // 9279
+    0xAE14, // 9280
+    0xAE30, // 9281
+    0xAE31, // 9282
+    0xAE34, // 9283
+    0xAE37, // 9284
+    0xAE38, // 9285
+    0xAE3A, // 9286
+    0xAE40, // 9287
+    0xAE41, // 9288
+    0xAE43, // 9289
+    0xAE45, // 9290
+    0xAE46, // 9291
+    0xAE4A, // 9292
+    0xAE4C, // 9293
+    0xAE4D, // 9294
+    0xAE4E, // 9295
+    0xAE50, // 9296
+    0xAE54, // 9297
+    0xAE56, // 9298
+    0xAE5C, // 9299
+    0xAE5D, // 9300
+    0xAE5F, // 9301
+    0xAE60, // 9302
+    0xAE61, // 9303
+    0xAE65, // 9304
+    0xAE68, // 9305
+    0xAE69, // 9306
+    0xAE6C, // 9307
+    0xAE70, // 9308
+    0xAE78, // 9309
+    0xCF6D, // 9310
+    0xCF6E, // 9311
+    0xCF6F, // 9312
+    0xCF72, // 9313
+    0xCF73, // 9314
+    0xCF75, // 9315
+    0xCF76, // 9316
+    0xCF77, // 9317
+    0xCF79, // 9318
+    0xCF7A, // 9319
+    0xCF7B, // 9320
+    0xCF7C, // 9321
+    0xCF7D, // 9322
+    0xCF7E, // 9323
+    0xCF7F, // 9324
+    0xCF81, // 9325
+    0xCF82, // 9326
+    0xCF83, // 9327
+    0xCF84, // 9328
+    0xCF86, // 9329
+    0xCF87, // 9330
+    0xCF88, // 9331
+    0xCF89, // 9332
+    0xCF8A, // 9333
+    0xCF8B, // 9334
+    0xCF8D, // 9335
+    0x0000, // 9336
+    0x0000, // 9337
+    0x0000, // 9338
+    0x0000, // 9339
+    0x0000, // 9340
+    0x0000, // 9341
+    0xCF8E, // 9342
+    0xCF8F, // 9343
+    0xCF90, // 9344
+    0xCF91, // 9345
+    0xCF92, // 9346
+    0xCF93, // 9347
+    0xCF94, // 9348
+    0xCF95, // 9349
+    0xCF96, // 9350
+    0xCF97, // 9351
+    0xCF98, // 9352
+    0xCF99, // 9353
+    0xCF9A, // 9354
+    0xCF9B, // 9355
+    0xCF9C, // 9356
+    0xCF9D, // 9357
+    0xCF9E, // 9358
+    0xCF9F, // 9359
+    0xCFA0, // 9360
+    0xCFA2, // 9361
+    0xCFA3, // 9362
+    0xCFA4, // 9363
+    0xCFA5, // 9364
+    0xCFA6, // 9365
+    0xCFA7, // 9366
+    0xCFA9, // 9367
+    0x0000, // 9368
+    0x0000, // 9369
+    0x0000, // 9370
+    0x0000, // 9371
+    0x0000, // 9372
+    0x0000, // 9373
+    0xCFAA, // 9374
+    0xCFAB, // 9375
+    0xCFAC, // 9376
+    0xCFAD, // 9377
+    0xCFAE, // 9378
+    0xCFAF, // 9379
+    0xCFB1, // 9380
+    0xCFB2, // 9381
+    0xCFB3, // 9382
+    0xCFB4, // 9383
+    0xCFB5, // 9384
+    0xCFB6, // 9385
+    0xCFB7, // 9386
+    0xCFB8, // 9387
+    0xCFB9, // 9388
+    0xCFBA, // 9389
+    0xCFBB, // 9390
+    0xCFBC, // 9391
+    0xCFBD, // 9392
+    0xCFBE, // 9393
+    0xCFBF, // 9394
+    0xCFC0, // 9395
+    0xCFC1, // 9396
+    0xCFC2, // 9397
+    0xCFC3, // 9398
+    0xCFC5, // 9399
+    0xCFC6, // 9400
+    0xCFC7, // 9401
+    0xCFC8, // 9402
+    0xCFC9, // 9403
+    0xCFCA, // 9404
+    0xCFCB, // 9405
+    0xAE79, // 9406
+    0xAE7B, // 9407
+    0xAE7C, // 9408
+    0xAE7D, // 9409
+    0xAE84, // 9410
+    0xAE85, // 9411
+    0xAE8C, // 9412
+    0xAEBC, // 9413
+    0xAEBD, // 9414
+    0xAEBE, // 9415
+    0xAEC0, // 9416
+    0xAEC4, // 9417
+    0xAECC, // 9418
+    0xAECD, // 9419
+    0xAECF, // 9420
+    0xAED0, // 9421
+    0xAED1, // 9422
+    0xAED8, // 9423
+    0xAED9, // 9424
+    0xAEDC, // 9425
+    0xAEE8, // 9426
+    0xAEEB, // 9427
+    0xAEED, // 9428
+    0xAEF4, // 9429
+    0xAEF8, // 9430
+    0xAEFC, // 9431
+    0xAF07, // 9432
+    0xAF08, // 9433
+    0xAF0D, // 9434
+    0xAF10, // 9435
+    0xAF2C, // 9436
+    0xAF2D, // 9437
+    0xAF30, // 9438
+    0xAF32, // 9439
+    0xAF34, // 9440
+    0xAF3C, // 9441
+    0xAF3D, // 9442
+    0xAF3F, // 9443
+    0xAF41, // 9444
+    0xAF42, // 9445
+    0xAF43, // 9446
+    0xAF48, // 9447
+    0xAF49, // 9448
+    0xAF50, // 9449
+    0xAF5C, // 9450
+    0xAF5D, // 9451
+    0xAF64, // 9452
+    0xAF65, // 9453
+    0xAF79, // 9454
+    0xAF80, // 9455
+    0xAF84, // 9456
+    0xAF88, // 9457
+    0xAF90, // 9458
+    0xAF91, // 9459
+    0xAF95, // 9460
+    0xAF9C, // 9461
+    0xAFB8, // 9462
+    0xAFB9, // 9463
+    0xAFBC, // 9464
+    0xAFC0, // 9465
+    0xAFC7, // 9466
+    0xAFC8, // 9467
+    0xAFC9, // 9468
+    0xAFCB, // 9469
+    0xAFCD, // 9470
+    0xAFCE, // 9471
+    0xAFD4, // 9472
+    0xAFDC, // 9473
+    0xAFE8, // 9474
+    0xAFE9, // 9475
+    0xAFF0, // 9476
+    0xAFF1, // 9477
+    0xAFF4, // 9478
+    0xAFF8, // 9479
+    0xB000, // 9480
+    0xB001, // 9481
+    0xB004, // 9482
+    0xB00C, // 9483
+    0xB010, // 9484
+    0xB014, // 9485
+    0xB01C, // 9486
+    0xB01D, // 9487
+    0xB028, // 9488
+    0xB044, // 9489
+    0xB045, // 9490
+    0xB048, // 9491
+    0xB04A, // 9492
+    0xB04C, // 9493
+    0xB04E, // 9494
+    0xB053, // 9495
+    0xB054, // 9496
+    0xB055, // 9497
+    0xB057, // 9498
+    0xB059, // 9499
+    0xCFCC, // 9500
+    0xCFCD, // 9501
+    0xCFCE, // 9502
+    0xCFCF, // 9503
+    0xCFD0, // 9504
+    0xCFD1, // 9505
+    0xCFD2, // 9506
+    0xCFD3, // 9507
+    0xCFD4, // 9508
+    0xCFD5, // 9509
+    0xCFD6, // 9510
+    0xCFD7, // 9511
+    0xCFD8, // 9512
+    0xCFD9, // 9513
+    0xCFDA, // 9514
+    0xCFDB, // 9515
+    0xCFDC, // 9516
+    0xCFDD, // 9517
+    0xCFDE, // 9518
+    0xCFDF, // 9519
+    0xCFE2, // 9520
+    0xCFE3, // 9521
+    0xCFE5, // 9522
+    0xCFE6, // 9523
+    0xCFE7, // 9524
+    0xCFE9, // 9525
+    0x0000, // 9526
+    0x0000, // 9527
+    0x0000, // 9528
+    0x0000, // 9529
+    0x0000, // 9530
+    0x0000, // 9531
+    0xCFEA, // 9532
+    0xCFEB, // 9533
+    0xCFEC, // 9534
+    0xCFED, // 9535
+    0xCFEE, // 9536
+    0xCFEF, // 9537
+    0xCFF2, // 9538
+    0xCFF4, // 9539
+    0xCFF6, // 9540
+    0xCFF7, // 9541
+    0xCFF8, // 9542
+    0xCFF9, // 9543
+    0xCFFA, // 9544
+    0xCFFB, // 9545
+    0xCFFD, // 9546
+    0xCFFE, // 9547
+    0xCFFF, // 9548
+    0xD001, // 9549
+    0xD002, // 9550
+    0xD003, // 9551
+    0xD005, // 9552
+    0xD006, // 9553
+    0xD007, // 9554
+    0xD008, // 9555
+    0xD009, // 9556
+    0xD00A, // 9557
+    0x0000, // 9558
+    0x0000, // 9559
+    0x0000, // 9560
+    0x0000, // 9561
+    0x0000, // 9562
+    0x0000, // 9563
+    0xD00B, // 9564
+    0xD00C, // 9565
+    0xD00D, // 9566
+    0xD00E, // 9567
+    0xD00F, // 9568
+    0xD010, // 9569
+    0xD012, // 9570
+    0xD013, // 9571
+    0xD014, // 9572
+    0xD015, // 9573
+    0xD016, // 9574
+    0xD017, // 9575
+    0xD019, // 9576
+    0xD01A, // 9577
+    0xD01B, // 9578
+    0xD01C, // 9579
+    0xD01D, // 9580
+    0xD01E, // 9581
+    0xD01F, // 9582
+    0xD020, // 9583
+    0xD021, // 9584
+    0xD022, // 9585
+    0xD023, // 9586
+    0xD024, // 9587
+    0xD025, // 9588
+    0xD026, // 9589
+    0xD027, // 9590
+    0xD028, // 9591
+    0xD029, // 9592
+    0xD02A, // 9593
+    0xD02B, // 9594
+    0xD02C, // 9595
+    0xB05D, // 9596
+    0xB07C, // 9597
+    0xB07D, // 9598
+    0xB080, // 9599
+    0xB084, // 9600
+    0xB08C, // 9601
+    0xB08D, // 9602
+    0xB08F, // 9603
+    0xB091, // 9604
+    0xB098, // 9605
+    0xB099, // 9606
+    0xB09A, // 9607
+    0xB09C, // 9608
+    0xB09F, // 9609
+    0xB0A0, // 9610
+    0xB0A1, // 9611
+    0xB0A2, // 9612
+    0xB0A8, // 9613
+    0xB0A9, // 9614
+    0xB0AB, // 9615
+    0xB0AC, // 9616
+    0xB0AD, // 9617
+    0xB0AE, // 9618
+    0xB0AF, // 9619
+    0xB0B1, // 9620
+    0xB0B3, // 9621
+    0xB0B4, // 9622
+    0xB0B5, // 9623
+    0xB0B8, // 9624
+    0xB0BC, // 9625
+    0xB0C4, // 9626
+    0xB0C5, // 9627
+    0xB0C7, // 9628
+    0xB0C8, // 9629
+    0xB0C9, // 9630
+    0xB0D0, // 9631
+    0xB0D1, // 9632
+    0xB0D4, // 9633
+    0xB0D8, // 9634
+    0xB0E0, // 9635
+    0xB0E5, // 9636
+    0xB108, // 9637
+    0xB109, // 9638
+    0xB10B, // 9639
+    0xB10C, // 9640
+    0xB110, // 9641
+    0xB112, // 9642
+    0xB113, // 9643
+    0xB118, // 9644
+    0xB119, // 9645
+    0xB11B, // 9646
+    0xB11C, // 9647
+    0xB11D, // 9648
+    0xB123, // 9649
+    0xB124, // 9650
+    0xB125, // 9651
+    0xB128, // 9652
+    0xB12C, // 9653
+    0xB134, // 9654
+    0xB135, // 9655
+    0xB137, // 9656
+    0xB138, // 9657
+    0xB139, // 9658
+    0xB140, // 9659
+    0xB141, // 9660
+    0xB144, // 9661
+    0xB148, // 9662
+    0xB150, // 9663
+    0xB151, // 9664
+    0xB154, // 9665
+    0xB155, // 9666
+    0xB158, // 9667
+    0xB15C, // 9668
+    0xB160, // 9669
+    0xB178, // 9670
+    0xB179, // 9671
+    0xB17C, // 9672
+    0xB180, // 9673
+    0xB182, // 9674
+    0xB188, // 9675
+    0xB189, // 9676
+    0xB18B, // 9677
+    0xB18D, // 9678
+    0xB192, // 9679
+    0xB193, // 9680
+    0xB194, // 9681
+    0xB198, // 9682
+    0xB19C, // 9683
+    0xB1A8, // 9684
+    0xB1CC, // 9685
+    0xB1D0, // 9686
+    0xB1D4, // 9687
+    0xB1DC, // 9688
+    0xB1DD, // 9689
+    0xD02E, // 9690
+    0xD02F, // 9691
+    0xD030, // 9692
+    0xD031, // 9693
+    0xD032, // 9694
+    0xD033, // 9695
+    0xD036, // 9696
+    0xD037, // 9697
+    0xD039, // 9698
+    0xD03A, // 9699
+    0xD03B, // 9700
+    0xD03D, // 9701
+    0xD03E, // 9702
+    0xD03F, // 9703
+    0xD040, // 9704
+    0xD041, // 9705
+    0xD042, // 9706
+    0xD043, // 9707
+    0xD046, // 9708
+    0xD048, // 9709
+    0xD04A, // 9710
+    0xD04B, // 9711
+    0xD04C, // 9712
+    0xD04D, // 9713
+    0xD04E, // 9714
+    0xD04F, // 9715
+    0x0000, // 9716
+    0x0000, // 9717
+    0x0000, // 9718
+    0x0000, // 9719
+    0x0000, // 9720
+    0x0000, // 9721
+    0xD051, // 9722
+    0xD052, // 9723
+    0xD053, // 9724
+    0xD055, // 9725
+    0xD056, // 9726
+    0xD057, // 9727
+    0xD059, // 9728
+    0xD05A, // 9729
+    0xD05B, // 9730
+    0xD05C, // 9731
+    0xD05D, // 9732
+    0xD05E, // 9733
+    0xD05F, // 9734
+    0xD061, // 9735
+    0xD062, // 9736
+    0xD063, // 9737
+    0xD064, // 9738
+    0xD065, // 9739
+    0xD066, // 9740
+    0xD067, // 9741
+    0xD068, // 9742
+    0xD069, // 9743
+    0xD06A, // 9744
+    0xD06B, // 9745
+    0xD06E, // 9746
+    0xD06F, // 9747
+    0x0000, // 9748
+    0x0000, // 9749
+    0x0000, // 9750
+    0x0000, // 9751
+    0x0000, // 9752
+    0x0000, // 9753
+    0xD071, // 9754
+    0xD072, // 9755
+    0xD073, // 9756
+    0xD075, // 9757
+    0xD076, // 9758
+    0xD077, // 9759
+    0xD078, // 9760
+    0xD079, // 9761
+    0xD07A, // 9762
+    0xD07B, // 9763
+    0xD07E, // 9764
+    0xD07F, // 9765
+    0xD080, // 9766
+    0xD082, // 9767
+    0xD083, // 9768
+    0xD084, // 9769
+    0xD085, // 9770
+    0xD086, // 9771
+    0xD087, // 9772
+    0xD088, // 9773
+    0xD089, // 9774
+    0xD08A, // 9775
+    0xD08B, // 9776
+    0xD08C, // 9777
+    0xD08D, // 9778
+    0xD08E, // 9779
+    0xD08F, // 9780
+    0xD090, // 9781
+    0xD091, // 9782
+    0xD092, // 9783
+    0xD093, // 9784
+    0xD094, // 9785
+    0xB1DF, // 9786
+    0xB1E8, // 9787
+    0xB1E9, // 9788
+    0xB1EC, // 9789
+    0xB1F0, // 9790
+    0xB1F9, // 9791
+    0xB1FB, // 9792
+    0xB1FD, // 9793
+    0xB204, // 9794
+    0xB205, // 9795
+    0xB208, // 9796
+    0xB20B, // 9797
+    0xB20C, // 9798
+    0xB214, // 9799
+    0xB215, // 9800
+    0xB217, // 9801
+    0xB219, // 9802
+    0xB220, // 9803
+    0xB234, // 9804
+    0xB23C, // 9805
+    0xB258, // 9806
+    0xB25C, // 9807
+    0xB260, // 9808
+    0xB268, // 9809
+    0xB269, // 9810
+    0xB274, // 9811
+    0xB275, // 9812
+    0xB27C, // 9813
+    0xB284, // 9814
+    0xB285, // 9815
+    0xB289, // 9816
+    0xB290, // 9817
+    0xB291, // 9818
+    0xB294, // 9819
+    0xB298, // 9820
+    0xB299, // 9821
+    0xB29A, // 9822
+    0xB2A0, // 9823
+    0xB2A1, // 9824
+    0xB2A3, // 9825
+    0xB2A5, // 9826
+    0xB2A6, // 9827
+    0xB2AA, // 9828
+    0xB2AC, // 9829
+    0xB2B0, // 9830
+    0xB2B4, // 9831
+    0xB2C8, // 9832
+    0xB2C9, // 9833
+    0xB2CC, // 9834
+    0xB2D0, // 9835
+    0xB2D2, // 9836
+    0xB2D8, // 9837
+    0xB2D9, // 9838
+    0xB2DB, // 9839
+    0xB2DD, // 9840
+    0xB2E2, // 9841
+    0xB2E4, // 9842
+    0xB2E5, // 9843
+    0xB2E6, // 9844
+    0xB2E8, // 9845
+    0xB2EB, // 9846
+    0xB2EC, // 9847
+    0xB2ED, // 9848
+    0xB2EE, // 9849
+    0xB2EF, // 9850
+    0xB2F3, // 9851
+    0xB2F4, // 9852
+    0xB2F5, // 9853
+    0xB2F7, // 9854
+    0xB2F8, // 9855
+    0xB2F9, // 9856
+    0xB2FA, // 9857
+    0xB2FB, // 9858
+    0xB2FF, // 9859
+    0xB300, // 9860
+    0xB301, // 9861
+    0xB304, // 9862
+    0xB308, // 9863
+    0xB310, // 9864
+    0xB311, // 9865
+    0xB313, // 9866
+    0xB314, // 9867
+    0xB315, // 9868
+    0xB31C, // 9869
+    0xB354, // 9870
+    0xB355, // 9871
+    0xB356, // 9872
+    0xB358, // 9873
+    0xB35B, // 9874
+    0xB35C, // 9875
+    0xB35E, // 9876
+    0xB35F, // 9877
+    0xB364, // 9878
+    0xB365, // 9879
+    0xD095, // 9880
+    0xD096, // 9881
+    0xD097, // 9882
+    0xD098, // 9883
+    0xD099, // 9884
+    0xD09A, // 9885
+    0xD09B, // 9886
+    0xD09C, // 9887
+    0xD09D, // 9888
+    0xD09E, // 9889
+    0xD09F, // 9890
+    0xD0A0, // 9891
+    0xD0A1, // 9892
+    0xD0A2, // 9893
+    0xD0A3, // 9894
+    0xD0A6, // 9895
+    0xD0A7, // 9896
+    0xD0A9, // 9897
+    0xD0AA, // 9898
+    0xD0AB, // 9899
+    0xD0AD, // 9900
+    0xD0AE, // 9901
+    0xD0AF, // 9902
+    0xD0B0, // 9903
+    0xD0B1, // 9904
+    0xD0B2, // 9905
+    0x0000, // 9906
+    0x0000, // 9907
+    0x0000, // 9908
+    0x0000, // 9909
+    0x0000, // 9910
+    0x0000, // 9911
+    0xD0B3, // 9912
+    0xD0B6, // 9913
+    0xD0B8, // 9914
+    0xD0BA, // 9915
+    0xD0BB, // 9916
+    0xD0BC, // 9917
+    0xD0BD, // 9918
+    0xD0BE, // 9919
+    0xD0BF, // 9920
+    0xD0C2, // 9921
+    0xD0C3, // 9922
+    0xD0C5, // 9923
+    0xD0C6, // 9924
+    0xD0C7, // 9925
+    0xD0CA, // 9926
+    0xD0CB, // 9927
+    0xD0CC, // 9928
+    0xD0CD, // 9929
+    0xD0CE, // 9930
+    0xD0CF, // 9931
+    0xD0D2, // 9932
+    0xD0D6, // 9933
+    0xD0D7, // 9934
+    0xD0D8, // 9935
+    0xD0D9, // 9936
+    0xD0DA, // 9937
+    0x0000, // 9938
+    0x0000, // 9939
+    0x0000, // 9940
+    0x0000, // 9941
+    0x0000, // 9942
+    0x0000, // 9943
+    0xD0DB, // 9944
+    0xD0DE, // 9945
+    0xD0DF, // 9946
+    0xD0E1, // 9947
+    0xD0E2, // 9948
+    0xD0E3, // 9949
+    0xD0E5, // 9950
+    0xD0E6, // 9951
+    0xD0E7, // 9952
+    0xD0E8, // 9953
+    0xD0E9, // 9954
+    0xD0EA, // 9955
+    0xD0EB, // 9956
+    0xD0EE, // 9957
+    0xD0F2, // 9958
+    0xD0F3, // 9959
+    0xD0F4, // 9960
+    0xD0F5, // 9961
+    0xD0F6, // 9962
+    0xD0F7, // 9963
+    0xD0F9, // 9964
+    0xD0FA, // 9965
+    0xD0FB, // 9966
+    0xD0FC, // 9967
+    0xD0FD, // 9968
+    0xD0FE, // 9969
+    0xD0FF, // 9970
+    0xD100, // 9971
+    0xD101, // 9972
+    0xD102, // 9973
+    0xD103, // 9974
+    0xD104, // 9975
+    0xB367, // 9976
+    0xB369, // 9977
+    0xB36B, // 9978
+    0xB36E, // 9979
+    0xB370, // 9980
+    0xB371, // 9981
+    0xB374, // 9982
+    0xB378, // 9983
+    0xB380, // 9984
+    0xB381, // 9985
+    0xB383, // 9986
+    0xB384, // 9987
+    0xB385, // 9988
+    0xB38C, // 9989
+    0xB390, // 9990
+    0xB394, // 9991
+    0xB3A0, // 9992
+    0xB3A1, // 9993
+    0xB3A8, // 9994
+    0xB3AC, // 9995
+    0xB3C4, // 9996
+    0xB3C5, // 9997
+    0xB3C8, // 9998
+    0xB3CB, // 9999
+    0xB3CC, // 10000
+    0xB3CE, // 10001
+    0xB3D0, // 10002
+    0xB3D4, // 10003
+    0xB3D5, // 10004
+    0xB3D7, // 10005
+    0xB3D9, // 10006
+    0xB3DB, // 10007
+    0xB3DD, // 10008
+    0xB3E0, // 10009
+    0xB3E4, // 10010
+    0xB3E8, // 10011
+    0xB3FC, // 10012
+    0xB410, // 10013
+    0xB418, // 10014
+    0xB41C, // 10015
+    0xB420, // 10016
+    0xB428, // 10017
+    0xB429, // 10018
+    0xB42B, // 10019
+    0xB434, // 10020
+    0xB450, // 10021
+    0xB451, // 10022
+    0xB454, // 10023
+    0xB458, // 10024
+    0xB460, // 10025
+    0xB461, // 10026
+    0xB463, // 10027
+    0xB465, // 10028
+    0xB46C, // 10029
+    0xB480, // 10030
+    0xB488, // 10031
+    0xB49D, // 10032
+    0xB4A4, // 10033
+    0xB4A8, // 10034
+    0xB4AC, // 10035
+    0xB4B5, // 10036
+    0xB4B7, // 10037
+    0xB4B9, // 10038
+    0xB4C0, // 10039
+    0xB4C4, // 10040
+    0xB4C8, // 10041
+    0xB4D0, // 10042
+    0xB4D5, // 10043
+    0xB4DC, // 10044
+    0xB4DD, // 10045
+    0xB4E0, // 10046
+    0xB4E3, // 10047
+    0xB4E4, // 10048
+    0xB4E6, // 10049
+    0xB4EC, // 10050
+    0xB4ED, // 10051
+    0xB4EF, // 10052
+    0xB4F1, // 10053
+    0xB4F8, // 10054
+    0xB514, // 10055
+    0xB515, // 10056
+    0xB518, // 10057
+    0xB51B, // 10058
+    0xB51C, // 10059
+    0xB524, // 10060
+    0xB525, // 10061
+    0xB527, // 10062
+    0xB528, // 10063
+    0xB529, // 10064
+    0xB52A, // 10065
+    0xB530, // 10066
+    0xB531, // 10067
+    0xB534, // 10068
+    0xB538, // 10069
+    0xD105, // 10070
+    0xD106, // 10071
+    0xD107, // 10072
+    0xD108, // 10073
+    0xD109, // 10074
+    0xD10A, // 10075
+    0xD10B, // 10076
+    0xD10C, // 10077
+    0xD10E, // 10078
+    0xD10F, // 10079
+    0xD110, // 10080
+    0xD111, // 10081
+    0xD112, // 10082
+    0xD113, // 10083
+    0xD114, // 10084
+    0xD115, // 10085
+    0xD116, // 10086
+    0xD117, // 10087
+    0xD118, // 10088
+    0xD119, // 10089
+    0xD11A, // 10090
+    0xD11B, // 10091
+    0xD11C, // 10092
+    0xD11D, // 10093
+    0xD11E, // 10094
+    0xD11F, // 10095
+    0x0000, // 10096
+    0x0000, // 10097
+    0x0000, // 10098
+    0x0000, // 10099
+    0x0000, // 10100
+    0x0000, // 10101
+    0xD120, // 10102
+    0xD121, // 10103
+    0xD122, // 10104
+    0xD123, // 10105
+    0xD124, // 10106
+    0xD125, // 10107
+    0xD126, // 10108
+    0xD127, // 10109
+    0xD128, // 10110
+    0xD129, // 10111
+    0xD12A, // 10112
+    0xD12B, // 10113
+    0xD12C, // 10114
+    0xD12D, // 10115
+    0xD12E, // 10116
+    0xD12F, // 10117
+    0xD132, // 10118
+    0xD133, // 10119
+    0xD135, // 10120
+    0xD136, // 10121
+    0xD137, // 10122
+    0xD139, // 10123
+    0xD13B, // 10124
+    0xD13C, // 10125
+    0xD13D, // 10126
+    0xD13E, // 10127
+    0x0000, // 10128
+    0x0000, // 10129
+    0x0000, // 10130
+    0x0000, // 10131
+    0x0000, // 10132
+    0x0000, // 10133
+    0xD13F, // 10134
+    0xD142, // 10135
+    0xD146, // 10136
+    0xD147, // 10137
+    0xD148, // 10138
+    0xD149, // 10139
+    0xD14A, // 10140
+    0xD14B, // 10141
+    0xD14E, // 10142
+    0xD14F, // 10143
+    0xD151, // 10144
+    0xD152, // 10145
+    0xD153, // 10146
+    0xD155, // 10147
+    0xD156, // 10148
+    0xD157, // 10149
+    0xD158, // 10150
+    0xD159, // 10151
+    0xD15A, // 10152
+    0xD15B, // 10153
+    0xD15E, // 10154
+    0xD160, // 10155
+    0xD162, // 10156
+    0xD163, // 10157
+    0xD164, // 10158
+    0xD165, // 10159
+    0xD166, // 10160
+    0xD167, // 10161
+    0xD169, // 10162
+    0xD16A, // 10163
+    0xD16B, // 10164
+    0xD16D, // 10165
+    0xB540, // 10166
+    0xB541, // 10167
+    0xB543, // 10168
+    0xB544, // 10169
+    0xB545, // 10170
+    0xB54B, // 10171
+    0xB54C, // 10172
+    0xB54D, // 10173
+    0xB550, // 10174
+    0xB554, // 10175
+    0xB55C, // 10176
+    0xB55D, // 10177
+    0xB55F, // 10178
+    0xB560, // 10179
+    0xB561, // 10180
+    0xB5A0, // 10181
+    0xB5A1, // 10182
+    0xB5A4, // 10183
+    0xB5A8, // 10184
+    0xB5AA, // 10185
+    0xB5AB, // 10186
+    0xB5B0, // 10187
+    0xB5B1, // 10188
+    0xB5B3, // 10189
+    0xB5B4, // 10190
+    0xB5B5, // 10191
+    0xB5BB, // 10192
+    0xB5BC, // 10193
+    0xB5BD, // 10194
+    0xB5C0, // 10195
+    0xB5C4, // 10196
+    0xB5CC, // 10197
+    0xB5CD, // 10198
+    0xB5CF, // 10199
+    0xB5D0, // 10200
+    0xB5D1, // 10201
+    0xB5D8, // 10202
+    0xB5EC, // 10203
+    0xB610, // 10204
+    0xB611, // 10205
+    0xB614, // 10206
+    0xB618, // 10207
+    0xB625, // 10208
+    0xB62C, // 10209
+    0xB634, // 10210
+    0xB648, // 10211
+    0xB664, // 10212
+    0xB668, // 10213
+    0xB69C, // 10214
+    0xB69D, // 10215
+    0xB6A0, // 10216
+    0xB6A4, // 10217
+    0xB6AB, // 10218
+    0xB6AC, // 10219
+    0xB6B1, // 10220
+    0xB6D4, // 10221
+    0xB6F0, // 10222
+    0xB6F4, // 10223
+    0xB6F8, // 10224
+    0xB700, // 10225
+    0xB701, // 10226
+    0xB705, // 10227
+    0xB728, // 10228
+    0xB729, // 10229
+    0xB72C, // 10230
+    0xB72F, // 10231
+    0xB730, // 10232
+    0xB738, // 10233
+    0xB739, // 10234
+    0xB73B, // 10235
+    0xB744, // 10236
+    0xB748, // 10237
+    0xB74C, // 10238
+    0xB754, // 10239
+    0xB755, // 10240
+    0xB760, // 10241
+    0xB764, // 10242
+    0xB768, // 10243
+    0xB770, // 10244
+    0xB771, // 10245
+    0xB773, // 10246
+    0xB775, // 10247
+    0xB77C, // 10248
+    0xB77D, // 10249
+    0xB780, // 10250
+    0xB784, // 10251
+    0xB78C, // 10252
+    0xB78D, // 10253
+    0xB78F, // 10254
+    0xB790, // 10255
+    0xB791, // 10256
+    0xB792, // 10257
+    0xB796, // 10258
+    0xB797, // 10259
+    0xD16E, // 10260
+    0xD16F, // 10261
+    0xD170, // 10262
+    0xD171, // 10263
+    0xD172, // 10264
+    0xD173, // 10265
+    0xD174, // 10266
+    0xD175, // 10267
+    0xD176, // 10268
+    0xD177, // 10269
+    0xD178, // 10270
+    0xD179, // 10271
+    0xD17A, // 10272
+    0xD17B, // 10273
+    0xD17D, // 10274
+    0xD17E, // 10275
+    0xD17F, // 10276
+    0xD180, // 10277
+    0xD181, // 10278
+    0xD182, // 10279
+    0xD183, // 10280
+    0xD185, // 10281
+    0xD186, // 10282
+    0xD187, // 10283
+    0xD189, // 10284
+    0xD18A, // 10285
+    0x0000, // 10286
+    0x0000, // 10287
+    0x0000, // 10288
+    0x0000, // 10289
+    0x0000, // 10290
+    0x0000, // 10291
+    0xD18B, // 10292
+    0xD18C, // 10293
+    0xD18D, // 10294
+    0xD18E, // 10295
+    0xD18F, // 10296
+    0xD190, // 10297
+    0xD191, // 10298
+    0xD192, // 10299
+    0xD193, // 10300
+    0xD194, // 10301
+    0xD195, // 10302
+    0xD196, // 10303
+    0xD197, // 10304
+    0xD198, // 10305
+    0xD199, // 10306
+    0xD19A, // 10307
+    0xD19B, // 10308
+    0xD19C, // 10309
+    0xD19D, // 10310
+    0xD19E, // 10311
+    0xD19F, // 10312
+    0xD1A2, // 10313
+    0xD1A3, // 10314
+    0xD1A5, // 10315
+    0xD1A6, // 10316
+    0xD1A7, // 10317
+    0x0000, // 10318
+    0x0000, // 10319
+    0x0000, // 10320
+    0x0000, // 10321
+    0x0000, // 10322
+    0x0000, // 10323
+    0xD1A9, // 10324
+    0xD1AA, // 10325
+    0xD1AB, // 10326
+    0xD1AC, // 10327
+    0xD1AD, // 10328
+    0xD1AE, // 10329
+    0xD1AF, // 10330
+    0xD1B2, // 10331
+    0xD1B4, // 10332
+    0xD1B6, // 10333
+    0xD1B7, // 10334
+    0xD1B8, // 10335
+    0xD1B9, // 10336
+    0xD1BB, // 10337
+    0xD1BD, // 10338
+    0xD1BE, // 10339
+    0xD1BF, // 10340
+    0xD1C1, // 10341
+    0xD1C2, // 10342
+    0xD1C3, // 10343
+    0xD1C4, // 10344
+    0xD1C5, // 10345
+    0xD1C6, // 10346
+    0xD1C7, // 10347
+    0xD1C8, // 10348
+    0xD1C9, // 10349
+    0xD1CA, // 10350
+    0xD1CB, // 10351
+    0xD1CC, // 10352
+    0xD1CD, // 10353
+    0xD1CE, // 10354
+    0xD1CF, // 10355
+    0xB798, // 10356
+    0xB799, // 10357
+    0xB79C, // 10358
+    0xB7A0, // 10359
+    0xB7A8, // 10360
+    0xB7A9, // 10361
+    0xB7AB, // 10362
+    0xB7AC, // 10363
+    0xB7AD, // 10364
+    0xB7B4, // 10365
+    0xB7B5, // 10366
+    0xB7B8, // 10367
+    0xB7C7, // 10368
+    0xB7C9, // 10369
+    0xB7EC, // 10370
+    0xB7ED, // 10371
+    0xB7F0, // 10372
+    0xB7F4, // 10373
+    0xB7FC, // 10374
+    0xB7FD, // 10375
+    0xB7FF, // 10376
+    0xB800, // 10377
+    0xB801, // 10378
+    0xB807, // 10379
+    0xB808, // 10380
+    0xB809, // 10381
+    0xB80C, // 10382
+    0xB810, // 10383
+    0xB818, // 10384
+    0xB819, // 10385
+    0xB81B, // 10386
+    0xB81D, // 10387
+    0xB824, // 10388
+    0xB825, // 10389
+    0xB828, // 10390
+    0xB82C, // 10391
+    0xB834, // 10392
+    0xB835, // 10393
+    0xB837, // 10394
+    0xB838, // 10395
+    0xB839, // 10396
+    0xB840, // 10397
+    0xB844, // 10398
+    0xB851, // 10399
+    0xB853, // 10400
+    0xB85C, // 10401
+    0xB85D, // 10402
+    0xB860, // 10403
+    0xB864, // 10404
+    0xB86C, // 10405
+    0xB86D, // 10406
+    0xB86F, // 10407
+    0xB871, // 10408
+    0xB878, // 10409
+    0xB87C, // 10410
+    0xB88D, // 10411
+    0xB8A8, // 10412
+    0xB8B0, // 10413
+    0xB8B4, // 10414
+    0xB8B8, // 10415
+    0xB8C0, // 10416
+    0xB8C1, // 10417
+    0xB8C3, // 10418
+    0xB8C5, // 10419
+    0xB8CC, // 10420
+    0xB8D0, // 10421
+    0xB8D4, // 10422
+    0xB8DD, // 10423
+    0xB8DF, // 10424
+    0xB8E1, // 10425
+    0xB8E8, // 10426
+    0xB8E9, // 10427
+    0xB8EC, // 10428
+    0xB8F0, // 10429
+    0xB8F8, // 10430
+    0xB8F9, // 10431
+    0xB8FB, // 10432
+    0xB8FD, // 10433
+    0xB904, // 10434
+    0xB918, // 10435
+    0xB920, // 10436
+    0xB93C, // 10437
+    0xB93D, // 10438
+    0xB940, // 10439
+    0xB944, // 10440
+    0xB94C, // 10441
+    0xB94F, // 10442
+    0xB951, // 10443
+    0xB958, // 10444
+    0xB959, // 10445
+    0xB95C, // 10446
+    0xB960, // 10447
+    0xB968, // 10448
+    0xB969, // 10449
+    0xD1D0, // 10450
+    0xD1D1, // 10451
+    0xD1D2, // 10452
+    0xD1D3, // 10453
+    0xD1D4, // 10454
+    0xD1D5, // 10455
+    0xD1D6, // 10456
+    0xD1D7, // 10457
+    0xD1D9, // 10458
+    0xD1DA, // 10459
+    0xD1DB, // 10460
+    0xD1DC, // 10461
+    0xD1DD, // 10462
+    0xD1DE, // 10463
+    0xD1DF, // 10464
+    0xD1E0, // 10465
+    0xD1E1, // 10466
+    0xD1E2, // 10467
+    0xD1E3, // 10468
+    0xD1E4, // 10469
+    0xD1E5, // 10470
+    0xD1E6, // 10471
+    0xD1E7, // 10472
+    0xD1E8, // 10473
+    0xD1E9, // 10474
+    0xD1EA, // 10475
+    0x0000, // 10476
+    0x0000, // 10477
+    0x0000, // 10478
+    0x0000, // 10479
+    0x0000, // 10480
+    0x0000, // 10481
+    0xD1EB, // 10482
+    0xD1EC, // 10483
+    0xD1ED, // 10484
+    0xD1EE, // 10485
+    0xD1EF, // 10486
+    0xD1F0, // 10487
+    0xD1F1, // 10488
+    0xD1F2, // 10489
+    0xD1F3, // 10490
+    0xD1F5, // 10491
+    0xD1F6, // 10492
+    0xD1F7, // 10493
+    0xD1F9, // 10494
+    0xD1FA, // 10495
+    0xD1FB, // 10496
+    0xD1FC, // 10497
+    0xD1FD, // 10498
+    0xD1FE, // 10499
+    0xD1FF, // 10500
+    0xD200, // 10501
+    0xD201, // 10502
+    0xD202, // 10503
+    0xD203, // 10504
+    0xD204, // 10505
+    0xD205, // 10506
+    0xD206, // 10507
+    0x0000, // 10508
+    0x0000, // 10509
+    0x0000, // 10510
+    0x0000, // 10511
+    0x0000, // 10512
+    0x0000, // 10513
+    0xD208, // 10514
+    0xD20A, // 10515
+    0xD20B, // 10516
+    0xD20C, // 10517
+    0xD20D, // 10518
+    0xD20E, // 10519
+    0xD20F, // 10520
+    0xD211, // 10521
+    0xD212, // 10522
+    0xD213, // 10523
+    0xD214, // 10524
+    0xD215, // 10525
+    0xD216, // 10526
+    0xD217, // 10527
+    0xD218, // 10528
+    0xD219, // 10529
+    0xD21A, // 10530
+    0xD21B, // 10531
+    0xD21C, // 10532
+    0xD21D, // 10533
+    0xD21E, // 10534
+    0xD21F, // 10535
+    0xD220, // 10536
+    0xD221, // 10537
+    0xD222, // 10538
+    0xD223, // 10539
+    0xD224, // 10540
+    0xD225, // 10541
+    0xD226, // 10542
+    0xD227, // 10543
+    0xD228, // 10544
+    0xD229, // 10545
+    0xB96B, // 10546
+    0xB96D, // 10547
+    0xB974, // 10548
+    0xB975, // 10549
+    0xB978, // 10550
+    0xB97C, // 10551
+    0xB984, // 10552
+    0xB985, // 10553
+    0xB987, // 10554
+    0xB989, // 10555
+    0xB98A, // 10556
+    0xB98D, // 10557
+    0xB98E, // 10558
+    0xB9AC, // 10559
+    0xB9AD, // 10560
+    0xB9B0, // 10561
+    0xB9B4, // 10562
+    0xB9BC, // 10563
+    0xB9BD, // 10564
+    0xB9BF, // 10565
+    0xB9C1, // 10566
+    0xB9C8, // 10567
+    0xB9C9, // 10568
+    0xB9CC, // 10569
+    0xB9CE, // 10570
+    0xB9CF, // 10571
+    0xB9D0, // 10572
+    0xB9D1, // 10573
+    0xB9D2, // 10574
+    0xB9D8, // 10575
+    0xB9D9, // 10576
+    0xB9DB, // 10577
+    0xB9DD, // 10578
+    0xB9DE, // 10579
+    0xB9E1, // 10580
+    0xB9E3, // 10581
+    0xB9E4, // 10582
+    0xB9E5, // 10583
+    0xB9E8, // 10584
+    0xB9EC, // 10585
+    0xB9F4, // 10586
+    0xB9F5, // 10587
+    0xB9F7, // 10588
+    0xB9F8, // 10589
+    0xB9F9, // 10590
+    0xB9FA, // 10591
+    0xBA00, // 10592
+    0xBA01, // 10593
+    0xBA08, // 10594
+    0xBA15, // 10595
+    0xBA38, // 10596
+    0xBA39, // 10597
+    0xBA3C, // 10598
+    0xBA40, // 10599
+    0xBA42, // 10600
+    0xBA48, // 10601
+    0xBA49, // 10602
+    0xBA4B, // 10603
+    0xBA4D, // 10604
+    0xBA4E, // 10605
+    0xBA53, // 10606
+    0xBA54, // 10607
+    0xBA55, // 10608
+    0xBA58, // 10609
+    0xBA5C, // 10610
+    0xBA64, // 10611
+    0xBA65, // 10612
+    0xBA67, // 10613
+    0xBA68, // 10614
+    0xBA69, // 10615
+    0xBA70, // 10616
+    0xBA71, // 10617
+    0xBA74, // 10618
+    0xBA78, // 10619
+    0xBA83, // 10620
+    0xBA84, // 10621
+    0xBA85, // 10622
+    0xBA87, // 10623
+    0xBA8C, // 10624
+    0xBAA8, // 10625
+    0xBAA9, // 10626
+    0xBAAB, // 10627
+    0xBAAC, // 10628
+    0xBAB0, // 10629
+    0xBAB2, // 10630
+    0xBAB8, // 10631
+    0xBAB9, // 10632
+    0xBABB, // 10633
+    0xBABD, // 10634
+    0xBAC4, // 10635
+    0xBAC8, // 10636
+    0xBAD8, // 10637
+    0xBAD9, // 10638
+    0xBAFC, // 10639
+    0xD22A, // 10640
+    0xD22B, // 10641
+    0xD22E, // 10642
+    0xD22F, // 10643
+    0xD231, // 10644
+    0xD232, // 10645
+    0xD233, // 10646
+    0xD235, // 10647
+    0xD236, // 10648
+    0xD237, // 10649
+    0xD238, // 10650
+    0xD239, // 10651
+    0xD23A, // 10652
+    0xD23B, // 10653
+    0xD23E, // 10654
+    0xD240, // 10655
+    0xD242, // 10656
+    0xD243, // 10657
+    0xD244, // 10658
+    0xD245, // 10659
+    0xD246, // 10660
+    0xD247, // 10661
+    0xD249, // 10662
+    0xD24A, // 10663
+    0xD24B, // 10664
+    0xD24C, // 10665
+    0x0000, // 10666
+    0x0000, // 10667
+    0x0000, // 10668
+    0x0000, // 10669
+    0x0000, // 10670
+    0x0000, // 10671
+    0xD24D, // 10672
+    0xD24E, // 10673
+    0xD24F, // 10674
+    0xD250, // 10675
+    0xD251, // 10676
+    0xD252, // 10677
+    0xD253, // 10678
+    0xD254, // 10679
+    0xD255, // 10680
+    0xD256, // 10681
+    0xD257, // 10682
+    0xD258, // 10683
+    0xD259, // 10684
+    0xD25A, // 10685
+    0xD25B, // 10686
+    0xD25D, // 10687
+    0xD25E, // 10688
+    0xD25F, // 10689
+    0xD260, // 10690
+    0xD261, // 10691
+    0xD262, // 10692
+    0xD263, // 10693
+    0xD265, // 10694
+    0xD266, // 10695
+    0xD267, // 10696
+    0xD268, // 10697
+    0x0000, // 10698
+    0x0000, // 10699
+    0x0000, // 10700
+    0x0000, // 10701
+    0x0000, // 10702
+    0x0000, // 10703
+    0xD269, // 10704
+    0xD26A, // 10705
+    0xD26B, // 10706
+    0xD26C, // 10707
+    0xD26D, // 10708
+    0xD26E, // 10709
+    0xD26F, // 10710
+    0xD270, // 10711
+    0xD271, // 10712
+    0xD272, // 10713
+    0xD273, // 10714
+    0xD274, // 10715
+    0xD275, // 10716
+    0xD276, // 10717
+    0xD277, // 10718
+    0xD278, // 10719
+    0xD279, // 10720
+    0xD27A, // 10721
+    0xD27B, // 10722
+    0xD27C, // 10723
+    0xD27D, // 10724
+    0xD27E, // 10725
+    0xD27F, // 10726
+    0xD282, // 10727
+    0xD283, // 10728
+    0xD285, // 10729
+    0xD286, // 10730
+    0xD287, // 10731
+    0xD289, // 10732
+    0xD28A, // 10733
+    0xD28B, // 10734
+    0xD28C, // 10735
+    0xBB00, // 10736
+    0xBB04, // 10737
+    0xBB0D, // 10738
+    0xBB0F, // 10739
+    0xBB11, // 10740
+    0xBB18, // 10741
+    0xBB1C, // 10742
+    0xBB20, // 10743
+    0xBB29, // 10744
+    0xBB2B, // 10745
+    0xBB34, // 10746
+    0xBB35, // 10747
+    0xBB36, // 10748
+    0xBB38, // 10749
+    0xBB3B, // 10750
+    0xBB3C, // 10751
+    0xBB3D, // 10752
+    0xBB3E, // 10753
+    0xBB44, // 10754
+    0xBB45, // 10755
+    0xBB47, // 10756
+    0xBB49, // 10757
+    0xBB4D, // 10758
+    0xBB4F, // 10759
+    0xBB50, // 10760
+    0xBB54, // 10761
+    0xBB58, // 10762
+    0xBB61, // 10763
+    0xBB63, // 10764
+    0xBB6C, // 10765
+    0xBB88, // 10766
+    0xBB8C, // 10767
+    0xBB90, // 10768
+    0xBBA4, // 10769
+    0xBBA8, // 10770
+    0xBBAC, // 10771
+    0xBBB4, // 10772
+    0xBBB7, // 10773
+    0xBBC0, // 10774
+    0xBBC4, // 10775
+    0xBBC8, // 10776
+    0xBBD0, // 10777
+    0xBBD3, // 10778
+    0xBBF8, // 10779
+    0xBBF9, // 10780
+    0xBBFC, // 10781
+    0xBBFF, // 10782
+    0xBC00, // 10783
+    0xBC02, // 10784
+    0xBC08, // 10785
+    0xBC09, // 10786
+    0xBC0B, // 10787
+    0xBC0C, // 10788
+    0xBC0D, // 10789
+    0xBC0F, // 10790
+    0xBC11, // 10791
+    0xBC14, // 10792
+    0xBC15, // 10793
+    0xBC16, // 10794
+    0xBC17, // 10795
+    0xBC18, // 10796
+    0xBC1B, // 10797
+    0xBC1C, // 10798
+    0xBC1D, // 10799
+    0xBC1E, // 10800
+    0xBC1F, // 10801
+    0xBC24, // 10802
+    0xBC25, // 10803
+    0xBC27, // 10804
+    0xBC29, // 10805
+    0xBC2D, // 10806
+    0xBC30, // 10807
+    0xBC31, // 10808
+    0xBC34, // 10809
+    0xBC38, // 10810
+    0xBC40, // 10811
+    0xBC41, // 10812
+    0xBC43, // 10813
+    0xBC44, // 10814
+    0xBC45, // 10815
+    0xBC49, // 10816
+    0xBC4C, // 10817
+    0xBC4D, // 10818
+    0xBC50, // 10819
+    0xBC5D, // 10820
+    0xBC84, // 10821
+    0xBC85, // 10822
+    0xBC88, // 10823
+    0xBC8B, // 10824
+    0xBC8C, // 10825
+    0xBC8E, // 10826
+    0xBC94, // 10827
+    0xBC95, // 10828
+    0xBC97, // 10829
+    0xD28D, // 10830
+    0xD28E, // 10831
+    0xD28F, // 10832
+    0xD292, // 10833
+    0xD293, // 10834
+    0xD294, // 10835
+    0xD296, // 10836
+    0xD297, // 10837
+    0xD298, // 10838
+    0xD299, // 10839
+    0xD29A, // 10840
+    0xD29B, // 10841
+    0xD29D, // 10842
+    0xD29E, // 10843
+    0xD29F, // 10844
+    0xD2A1, // 10845
+    0xD2A2, // 10846
+    0xD2A3, // 10847
+    0xD2A5, // 10848
+    0xD2A6, // 10849
+    0xD2A7, // 10850
+    0xD2A8, // 10851
+    0xD2A9, // 10852
+    0xD2AA, // 10853
+    0xD2AB, // 10854
+    0xD2AD, // 10855
+    0x0000, // 10856
+    0x0000, // 10857
+    0x0000, // 10858
+    0x0000, // 10859
+    0x0000, // 10860
+    0x0000, // 10861
+    0xD2AE, // 10862
+    0xD2AF, // 10863
+    0xD2B0, // 10864
+    0xD2B2, // 10865
+    0xD2B3, // 10866
+    0xD2B4, // 10867
+    0xD2B5, // 10868
+    0xD2B6, // 10869
+    0xD2B7, // 10870
+    0xD2BA, // 10871
+    0xD2BB, // 10872
+    0xD2BD, // 10873
+    0xD2BE, // 10874
+    0xD2C1, // 10875
+    0xD2C3, // 10876
+    0xD2C4, // 10877
+    0xD2C5, // 10878
+    0xD2C6, // 10879
+    0xD2C7, // 10880
+    0xD2CA, // 10881
+    0xD2CC, // 10882
+    0xD2CD, // 10883
+    0xD2CE, // 10884
+    0xD2CF, // 10885
+    0xD2D0, // 10886
+    0xD2D1, // 10887
+    0x0000, // 10888
+    0x0000, // 10889
+    0x0000, // 10890
+    0x0000, // 10891
+    0x0000, // 10892
+    0x0000, // 10893
+    0xD2D2, // 10894
+    0xD2D3, // 10895
+    0xD2D5, // 10896
+    0xD2D6, // 10897
+    0xD2D7, // 10898
+    0xD2D9, // 10899
+    0xD2DA, // 10900
+    0xD2DB, // 10901
+    0xD2DD, // 10902
+    0xD2DE, // 10903
+    0xD2DF, // 10904
+    0xD2E0, // 10905
+    0xD2E1, // 10906
+    0xD2E2, // 10907
+    0xD2E3, // 10908
+    0xD2E6, // 10909
+    0xD2E7, // 10910
+    0xD2E8, // 10911
+    0xD2E9, // 10912
+    0xD2EA, // 10913
+    0xD2EB, // 10914
+    0xD2EC, // 10915
+    0xD2ED, // 10916
+    0xD2EE, // 10917
+    0xD2EF, // 10918
+    0xD2F2, // 10919
+    0xD2F3, // 10920
+    0xD2F5, // 10921
+    0xD2F6, // 10922
+    0xD2F7, // 10923
+    0xD2F9, // 10924
+    0xD2FA, // 10925
+    0xBC99, // 10926
+    0xBC9A, // 10927
+    0xBCA0, // 10928
+    0xBCA1, // 10929
+    0xBCA4, // 10930
+    0xBCA7, // 10931
+    0xBCA8, // 10932
+    0xBCB0, // 10933
+    0xBCB1, // 10934
+    0xBCB3, // 10935
+    0xBCB4, // 10936
+    0xBCB5, // 10937
+    0xBCBC, // 10938
+    0xBCBD, // 10939
+    0xBCC0, // 10940
+    0xBCC4, // 10941
+    0xBCCD, // 10942
+    0xBCCF, // 10943
+    0xBCD0, // 10944
+    0xBCD1, // 10945
+    0xBCD5, // 10946
+    0xBCD8, // 10947
+    0xBCDC, // 10948
+    0xBCF4, // 10949
+    0xBCF5, // 10950
+    0xBCF6, // 10951
+    0xBCF8, // 10952
+    0xBCFC, // 10953
+    0xBD04, // 10954
+    0xBD05, // 10955
+    0xBD07, // 10956
+    0xBD09, // 10957
+    0xBD10, // 10958
+    0xBD14, // 10959
+    0xBD24, // 10960
+    0xBD2C, // 10961
+    0xBD40, // 10962
+    0xBD48, // 10963
+    0xBD49, // 10964
+    0xBD4C, // 10965
+    0xBD50, // 10966
+    0xBD58, // 10967
+    0xBD59, // 10968
+    0xBD64, // 10969
+    0xBD68, // 10970
+    0xBD80, // 10971
+    0xBD81, // 10972
+    0xBD84, // 10973
+    0xBD87, // 10974
+    0xBD88, // 10975
+    0xBD89, // 10976
+    0xBD8A, // 10977
+    0xBD90, // 10978
+    0xBD91, // 10979
+    0xBD93, // 10980
+    0xBD95, // 10981
+    0xBD99, // 10982
+    0xBD9A, // 10983
+    0xBD9C, // 10984
+    0xBDA4, // 10985
+    0xBDB0, // 10986
+    0xBDB8, // 10987
+    0xBDD4, // 10988
+    0xBDD5, // 10989
+    0xBDD8, // 10990
+    0xBDDC, // 10991
+    0xBDE9, // 10992
+    0xBDF0, // 10993
+    0xBDF4, // 10994
+    0xBDF8, // 10995
+    0xBE00, // 10996
+    0xBE03, // 10997
+    0xBE05, // 10998
+    0xBE0C, // 10999
+    0xBE0D, // 11000
+    0xBE10, // 11001
+    0xBE14, // 11002
+    0xBE1C, // 11003
+    0xBE1D, // 11004
+    0xBE1F, // 11005
+    0xBE44, // 11006
+    0xBE45, // 11007
+    0xBE48, // 11008
+    0xBE4C, // 11009
+    0xBE4E, // 11010
+    0xBE54, // 11011
+    0xBE55, // 11012
+    0xBE57, // 11013
+    0xBE59, // 11014
+    0xBE5A, // 11015
+    0xBE5B, // 11016
+    0xBE60, // 11017
+    0xBE61, // 11018
+    0xBE64, // 11019
+    0xD2FB, // 11020
+    0xD2FC, // 11021
+    0xD2FD, // 11022
+    0xD2FE, // 11023
+    0xD2FF, // 11024
+    0xD302, // 11025
+    0xD304, // 11026
+    0xD306, // 11027
+    0xD307, // 11028
+    0xD308, // 11029
+    0xD309, // 11030
+    0xD30A, // 11031
+    0xD30B, // 11032
+    0xD30F, // 11033
+    0xD311, // 11034
+    0xD312, // 11035
+    0xD313, // 11036
+    0xD315, // 11037
+    0xD317, // 11038
+    0xD318, // 11039
+    0xD319, // 11040
+    0xD31A, // 11041
+    0xD31B, // 11042
+    0xD31E, // 11043
+    0xD322, // 11044
+    0xD323, // 11045
+    0x0000, // 11046
+    0x0000, // 11047
+    0x0000, // 11048
+    0x0000, // 11049
+    0x0000, // 11050
+    0x0000, // 11051
+    0xD324, // 11052
+    0xD326, // 11053
+    0xD327, // 11054
+    0xD32A, // 11055
+    0xD32B, // 11056
+    0xD32D, // 11057
+    0xD32E, // 11058
+    0xD32F, // 11059
+    0xD331, // 11060
+    0xD332, // 11061
+    0xD333, // 11062
+    0xD334, // 11063
+    0xD335, // 11064
+    0xD336, // 11065
+    0xD337, // 11066
+    0xD33A, // 11067
+    0xD33E, // 11068
+    0xD33F, // 11069
+    0xD340, // 11070
+    0xD341, // 11071
+    0xD342, // 11072
+    0xD343, // 11073
+    0xD346, // 11074
+    0xD347, // 11075
+    0xD348, // 11076
+    0xD349, // 11077
+    0x0000, // 11078
+    0x0000, // 11079
+    0x0000, // 11080
+    0x0000, // 11081
+    0x0000, // 11082
+    0x0000, // 11083
+    0xD34A, // 11084
+    0xD34B, // 11085
+    0xD34C, // 11086
+    0xD34D, // 11087
+    0xD34E, // 11088
+    0xD34F, // 11089
+    0xD350, // 11090
+    0xD351, // 11091
+    0xD352, // 11092
+    0xD353, // 11093
+    0xD354, // 11094
+    0xD355, // 11095
+    0xD356, // 11096
+    0xD357, // 11097
+    0xD358, // 11098
+    0xD359, // 11099
+    0xD35A, // 11100
+    0xD35B, // 11101
+    0xD35C, // 11102
+    0xD35D, // 11103
+    0xD35E, // 11104
+    0xD35F, // 11105
+    0xD360, // 11106
+    0xD361, // 11107
+    0xD362, // 11108
+    0xD363, // 11109
+    0xD364, // 11110
+    0xD365, // 11111
+    0xD366, // 11112
+    0xD367, // 11113
+    0xD368, // 11114
+    0xD369, // 11115
+    0xBE68, // 11116
+    0xBE6A, // 11117
+    0xBE70, // 11118
+    0xBE71, // 11119
+    0xBE73, // 11120
+    0xBE74, // 11121
+    0xBE75, // 11122
+    0xBE7B, // 11123
+    0xBE7C, // 11124
+    0xBE7D, // 11125
+    0xBE80, // 11126
+    0xBE84, // 11127
+    0xBE8C, // 11128
+    0xBE8D, // 11129
+    0xBE8F, // 11130
+    0xBE90, // 11131
+    0xBE91, // 11132
+    0xBE98, // 11133
+    0xBE99, // 11134
+    0xBEA8, // 11135
+    0xBED0, // 11136
+    0xBED1, // 11137
+    0xBED4, // 11138
+    0xBED7, // 11139
+    0xBED8, // 11140
+    0xBEE0, // 11141
+    0xBEE3, // 11142
+    0xBEE4, // 11143
+    0xBEE5, // 11144
+    0xBEEC, // 11145
+    0xBF01, // 11146
+    0xBF08, // 11147
+    0xBF09, // 11148
+    0xBF18, // 11149
+    0xBF19, // 11150
+    0xBF1B, // 11151
+    0xBF1C, // 11152
+    0xBF1D, // 11153
+    0xBF40, // 11154
+    0xBF41, // 11155
+    0xBF44, // 11156
+    0xBF48, // 11157
+    0xBF50, // 11158
+    0xBF51, // 11159
+    0xBF55, // 11160
+    0xBF94, // 11161
+    0xBFB0, // 11162
+    0xBFC5, // 11163
+    0xBFCC, // 11164
+    0xBFCD, // 11165
+    0xBFD0, // 11166
+    0xBFD4, // 11167
+    0xBFDC, // 11168
+    0xBFDF, // 11169
+    0xBFE1, // 11170
+    0xC03C, // 11171
+    0xC051, // 11172
+    0xC058, // 11173
+    0xC05C, // 11174
+    0xC060, // 11175
+    0xC068, // 11176
+    0xC069, // 11177
+    0xC090, // 11178
+    0xC091, // 11179
+    0xC094, // 11180
+    0xC098, // 11181
+    0xC0A0, // 11182
+    0xC0A1, // 11183
+    0xC0A3, // 11184
+    0xC0A5, // 11185
+    0xC0AC, // 11186
+    0xC0AD, // 11187
+    0xC0AF, // 11188
+    0xC0B0, // 11189
+    0xC0B3, // 11190
+    0xC0B4, // 11191
+    0xC0B5, // 11192
+    0xC0B6, // 11193
+    0xC0BC, // 11194
+    0xC0BD, // 11195
+    0xC0BF, // 11196
+    0xC0C0, // 11197
+    0xC0C1, // 11198
+    0xC0C5, // 11199
+    0xC0C8, // 11200
+    0xC0C9, // 11201
+    0xC0CC, // 11202
+    0xC0D0, // 11203
+    0xC0D8, // 11204
+    0xC0D9, // 11205
+    0xC0DB, // 11206
+    0xC0DC, // 11207
+    0xC0DD, // 11208
+    0xC0E4, // 11209
+    0xD36A, // 11210
+    0xD36B, // 11211
+    0xD36C, // 11212
+    0xD36D, // 11213
+    0xD36E, // 11214
+    0xD36F, // 11215
+    0xD370, // 11216
+    0xD371, // 11217
+    0xD372, // 11218
+    0xD373, // 11219
+    0xD374, // 11220
+    0xD375, // 11221
+    0xD376, // 11222
+    0xD377, // 11223
+    0xD378, // 11224
+    0xD379, // 11225
+    0xD37A, // 11226
+    0xD37B, // 11227
+    0xD37E, // 11228
+    0xD37F, // 11229
+    0xD381, // 11230
+    0xD382, // 11231
+    0xD383, // 11232
+    0xD385, // 11233
+    0xD386, // 11234
+    0xD387, // 11235
+    0x0000, // 11236
+    0x0000, // 11237
+    0x0000, // 11238
+    0x0000, // 11239
+    0x0000, // 11240
+    0x0000, // 11241
+    0xD388, // 11242
+    0xD389, // 11243
+    0xD38A, // 11244
+    0xD38B, // 11245
+    0xD38E, // 11246
+    0xD392, // 11247
+    0xD393, // 11248
+    0xD394, // 11249
+    0xD395, // 11250
+    0xD396, // 11251
+    0xD397, // 11252
+    0xD39A, // 11253
+    0xD39B, // 11254
+    0xD39D, // 11255
+    0xD39E, // 11256
+    0xD39F, // 11257
+    0xD3A1, // 11258
+    0xD3A2, // 11259
+    0xD3A3, // 11260
+    0xD3A4, // 11261
+    0xD3A5, // 11262
+    0xD3A6, // 11263
+    0xD3A7, // 11264
+    0xD3AA, // 11265
+    0xD3AC, // 11266
+    0xD3AE, // 11267
+    0x0000, // 11268
+    0x0000, // 11269
+    0x0000, // 11270
+    0x0000, // 11271
+    0x0000, // 11272
+    0x0000, // 11273
+    0xD3AF, // 11274
+    0xD3B0, // 11275
+    0xD3B1, // 11276
+    0xD3B2, // 11277
+    0xD3B3, // 11278
+    0xD3B5, // 11279
+    0xD3B6, // 11280
+    0xD3B7, // 11281
+    0xD3B9, // 11282
+    0xD3BA, // 11283
+    0xD3BB, // 11284
+    0xD3BD, // 11285
+    0xD3BE, // 11286
+    0xD3BF, // 11287
+    0xD3C0, // 11288
+    0xD3C1, // 11289
+    0xD3C2, // 11290
+    0xD3C3, // 11291
+    0xD3C6, // 11292
+    0xD3C7, // 11293
+    0xD3CA, // 11294
+    0xD3CB, // 11295
+    0xD3CC, // 11296
+    0xD3CD, // 11297
+    0xD3CE, // 11298
+    0xD3CF, // 11299
+    0xD3D1, // 11300
+    0xD3D2, // 11301
+    0xD3D3, // 11302
+    0xD3D4, // 11303
+    0xD3D5, // 11304
+    0xD3D6, // 11305
+    0xC0E5, // 11306
+    0xC0E8, // 11307
+    0xC0EC, // 11308
+    0xC0F4, // 11309
+    0xC0F5, // 11310
+    0xC0F7, // 11311
+    0xC0F9, // 11312
+    0xC100, // 11313
+    0xC104, // 11314
+    0xC108, // 11315
+    0xC110, // 11316
+    0xC115, // 11317
+    0xC11C, // 11318
+    0xC11D, // 11319
+    0xC11E, // 11320
+    0xC11F, // 11321
+    0xC120, // 11322
+    0xC123, // 11323
+    0xC124, // 11324
+    0xC126, // 11325
+    0xC127, // 11326
+    0xC12C, // 11327
+    0xC12D, // 11328
+    0xC12F, // 11329
+    0xC130, // 11330
+    0xC131, // 11331
+    0xC136, // 11332
+    0xC138, // 11333
+    0xC139, // 11334
+    0xC13C, // 11335
+    0xC140, // 11336
+    0xC148, // 11337
+    0xC149, // 11338
+    0xC14B, // 11339
+    0xC14C, // 11340
+    0xC14D, // 11341
+    0xC154, // 11342
+    0xC155, // 11343
+    0xC158, // 11344
+    0xC15C, // 11345
+    0xC164, // 11346
+    0xC165, // 11347
+    0xC167, // 11348
+    0xC168, // 11349
+    0xC169, // 11350
+    0xC170, // 11351
+    0xC174, // 11352
+    0xC178, // 11353
+    0xC185, // 11354
+    0xC18C, // 11355
+    0xC18D, // 11356
+    0xC18E, // 11357
+    0xC190, // 11358
+    0xC194, // 11359
+    0xC196, // 11360
+    0xC19C, // 11361
+    0xC19D, // 11362
+    0xC19F, // 11363
+    0xC1A1, // 11364
+    0xC1A5, // 11365
+    0xC1A8, // 11366
+    0xC1A9, // 11367
+    0xC1AC, // 11368
+    0xC1B0, // 11369
+    0xC1BD, // 11370
+    0xC1C4, // 11371
+    0xC1C8, // 11372
+    0xC1CC, // 11373
+    0xC1D4, // 11374
+    0xC1D7, // 11375
+    0xC1D8, // 11376
+    0xC1E0, // 11377
+    0xC1E4, // 11378
+    0xC1E8, // 11379
+    0xC1F0, // 11380
+    0xC1F1, // 11381
+    0xC1F3, // 11382
+    0xC1FC, // 11383
+    0xC1FD, // 11384
+    0xC200, // 11385
+    0xC204, // 11386
+    0xC20C, // 11387
+    0xC20D, // 11388
+    0xC20F, // 11389
+    0xC211, // 11390
+    0xC218, // 11391
+    0xC219, // 11392
+    0xC21C, // 11393
+    0xC21F, // 11394
+    0xC220, // 11395
+    0xC228, // 11396
+    0xC229, // 11397
+    0xC22B, // 11398
+    0xC22D, // 11399
+    0xD3D7, // 11400
+    0xD3D9, // 11401
+    0xD3DA, // 11402
+    0xD3DB, // 11403
+    0xD3DC, // 11404
+    0xD3DD, // 11405
+    0xD3DE, // 11406
+    0xD3DF, // 11407
+    0xD3E0, // 11408
+    0xD3E2, // 11409
+    0xD3E4, // 11410
+    0xD3E5, // 11411
+    0xD3E6, // 11412
+    0xD3E7, // 11413
+    0xD3E8, // 11414
+    0xD3E9, // 11415
+    0xD3EA, // 11416
+    0xD3EB, // 11417
+    0xD3EE, // 11418
+    0xD3EF, // 11419
+    0xD3F1, // 11420
+    0xD3F2, // 11421
+    0xD3F3, // 11422
+    0xD3F5, // 11423
+    0xD3F6, // 11424
+    0xD3F7, // 11425
+    0x0000, // 11426
+    0x0000, // 11427
+    0x0000, // 11428
+    0x0000, // 11429
+    0x0000, // 11430
+    0x0000, // 11431
+    0xD3F8, // 11432
+    0xD3F9, // 11433
+    0xD3FA, // 11434
+    0xD3FB, // 11435
+    0xD3FE, // 11436
+    0xD400, // 11437
+    0xD402, // 11438
+    0xD403, // 11439
+    0xD404, // 11440
+    0xD405, // 11441
+    0xD406, // 11442
+    0xD407, // 11443
+    0xD409, // 11444
+    0xD40A, // 11445
+    0xD40B, // 11446
+    0xD40C, // 11447
+    0xD40D, // 11448
+    0xD40E, // 11449
+    0xD40F, // 11450
+    0xD410, // 11451
+    0xD411, // 11452
+    0xD412, // 11453
+    0xD413, // 11454
+    0xD414, // 11455
+    0xD415, // 11456
+    0xD416, // 11457
+    0x0000, // 11458
+    0x0000, // 11459
+    0x0000, // 11460
+    0x0000, // 11461
+    0x0000, // 11462
+    0x0000, // 11463
+    0xD417, // 11464
+    0xD418, // 11465
+    0xD419, // 11466
+    0xD41A, // 11467
+    0xD41B, // 11468
+    0xD41C, // 11469
+    0xD41E, // 11470
+    0xD41F, // 11471
+    0xD420, // 11472
+    0xD421, // 11473
+    0xD422, // 11474
+    0xD423, // 11475
+    0xD424, // 11476
+    0xD425, // 11477
+    0xD426, // 11478
+    0xD427, // 11479
+    0xD428, // 11480
+    0xD429, // 11481
+    0xD42A, // 11482
+    0xD42B, // 11483
+    0xD42C, // 11484
+    0xD42D, // 11485
+    0xD42E, // 11486
+    0xD42F, // 11487
+    0xD430, // 11488
+    0xD431, // 11489
+    0xD432, // 11490
+    0xD433, // 11491
+    0xD434, // 11492
+    0xD435, // 11493
+    0xD436, // 11494
+    0xD437, // 11495
+    0xC22F, // 11496
+    0xC231, // 11497
+    0xC232, // 11498
+    0xC234, // 11499
+    0xC248, // 11500
+    0xC250, // 11501
+    0xC251, // 11502
+    0xC254, // 11503
+    0xC258, // 11504
+    0xC260, // 11505
+    0xC265, // 11506
+    0xC26C, // 11507
+    0xC26D, // 11508
+    0xC270, // 11509
+    0xC274, // 11510
+    0xC27C, // 11511
+    0xC27D, // 11512
+    0xC27F, // 11513
+    0xC281, // 11514
+    0xC288, // 11515
+    0xC289, // 11516
+    0xC290, // 11517
+    0xC298, // 11518
+    0xC29B, // 11519
+    0xC29D, // 11520
+    0xC2A4, // 11521
+    0xC2A5, // 11522
+    0xC2A8, // 11523
+    0xC2AC, // 11524
+    0xC2AD, // 11525
+    0xC2B4, // 11526
+    0xC2B5, // 11527
+    0xC2B7, // 11528
+    0xC2B9, // 11529
+    0xC2DC, // 11530
+    0xC2DD, // 11531
+    0xC2E0, // 11532
+    0xC2E3, // 11533
+    0xC2E4, // 11534
+    0xC2EB, // 11535
+    0xC2EC, // 11536
+    0xC2ED, // 11537
+    0xC2EF, // 11538
+    0xC2F1, // 11539
+    0xC2F6, // 11540
+    0xC2F8, // 11541
+    0xC2F9, // 11542
+    0xC2FB, // 11543
+    0xC2FC, // 11544
+    0xC300, // 11545
+    0xC308, // 11546
+    0xC309, // 11547
+    0xC30C, // 11548
+    0xC30D, // 11549
+    0xC313, // 11550
+    0xC314, // 11551
+    0xC315, // 11552
+    0xC318, // 11553
+    0xC31C, // 11554
+    0xC324, // 11555
+    0xC325, // 11556
+    0xC328, // 11557
+    0xC329, // 11558
+    0xC345, // 11559
+    0xC368, // 11560
+    0xC369, // 11561
+    0xC36C, // 11562
+    0xC370, // 11563
+    0xC372, // 11564
+    0xC378, // 11565
+    0xC379, // 11566
+    0xC37C, // 11567
+    0xC37D, // 11568
+    0xC384, // 11569
+    0xC388, // 11570
+    0xC38C, // 11571
+    0xC3C0, // 11572
+    0xC3D8, // 11573
+    0xC3D9, // 11574
+    0xC3DC, // 11575
+    0xC3DF, // 11576
+    0xC3E0, // 11577
+    0xC3E2, // 11578
+    0xC3E8, // 11579
+    0xC3E9, // 11580
+    0xC3ED, // 11581
+    0xC3F4, // 11582
+    0xC3F5, // 11583
+    0xC3F8, // 11584
+    0xC408, // 11585
+    0xC410, // 11586
+    0xC424, // 11587
+    0xC42C, // 11588
+    0xC430, // 11589
+    0xD438, // 11590
+    0xD439, // 11591
+    0xD43A, // 11592
+    0xD43B, // 11593
+    0xD43C, // 11594
+    0xD43D, // 11595
+    0xD43E, // 11596
+    0xD43F, // 11597
+    0xD441, // 11598
+    0xD442, // 11599
+    0xD443, // 11600
+    0xD445, // 11601
+    0xD446, // 11602
+    0xD447, // 11603
+    0xD448, // 11604
+    0xD449, // 11605
+    0xD44A, // 11606
+    0xD44B, // 11607
+    0xD44C, // 11608
+    0xD44D, // 11609
+    0xD44E, // 11610
+    0xD44F, // 11611
+    0xD450, // 11612
+    0xD451, // 11613
+    0xD452, // 11614
+    0xD453, // 11615
+    0x0000, // 11616
+    0x0000, // 11617
+    0x0000, // 11618
+    0x0000, // 11619
+    0x0000, // 11620
+    0x0000, // 11621
+    0xD454, // 11622
+    0xD455, // 11623
+    0xD456, // 11624
+    0xD457, // 11625
+    0xD458, // 11626
+    0xD459, // 11627
+    0xD45A, // 11628
+    0xD45B, // 11629
+    0xD45D, // 11630
+    0xD45E, // 11631
+    0xD45F, // 11632
+    0xD461, // 11633
+    0xD462, // 11634
+    0xD463, // 11635
+    0xD465, // 11636
+    0xD466, // 11637
+    0xD467, // 11638
+    0xD468, // 11639
+    0xD469, // 11640
+    0xD46A, // 11641
+    0xD46B, // 11642
+    0xD46C, // 11643
+    0xD46E, // 11644
+    0xD470, // 11645
+    0xD471, // 11646
+    0xD472, // 11647
+    0x0000, // 11648
+    0x0000, // 11649
+    0x0000, // 11650
+    0x0000, // 11651
+    0x0000, // 11652
+    0x0000, // 11653
+    0xD473, // 11654
+    0xD474, // 11655
+    0xD475, // 11656
+    0xD476, // 11657
+    0xD477, // 11658
+    0xD47A, // 11659
+    0xD47B, // 11660
+    0xD47D, // 11661
+    0xD47E, // 11662
+    0xD481, // 11663
+    0xD483, // 11664
+    0xD484, // 11665
+    0xD485, // 11666
+    0xD486, // 11667
+    0xD487, // 11668
+    0xD48A, // 11669
+    0xD48C, // 11670
+    0xD48E, // 11671
+    0xD48F, // 11672
+    0xD490, // 11673
+    0xD491, // 11674
+    0xD492, // 11675
+    0xD493, // 11676
+    0xD495, // 11677
+    0xD496, // 11678
+    0xD497, // 11679
+    0xD498, // 11680
+    0xD499, // 11681
+    0xD49A, // 11682
+    0xD49B, // 11683
+    0xD49C, // 11684
+    0xD49D, // 11685
+    0xC434, // 11686
+    0xC43C, // 11687
+    0xC43D, // 11688
+    0xC448, // 11689
+    0xC464, // 11690
+    0xC465, // 11691
+    0xC468, // 11692
+    0xC46C, // 11693
+    0xC474, // 11694
+    0xC475, // 11695
+    0xC479, // 11696
+    0xC480, // 11697
+    0xC494, // 11698
+    0xC49C, // 11699
+    0xC4B8, // 11700
+    0xC4BC, // 11701
+    0xC4E9, // 11702
+    0xC4F0, // 11703
+    0xC4F1, // 11704
+    0xC4F4, // 11705
+    0xC4F8, // 11706
+    0xC4FA, // 11707
+    0xC4FF, // 11708
+    0xC500, // 11709
+    0xC501, // 11710
+    0xC50C, // 11711
+    0xC510, // 11712
+    0xC514, // 11713
+    0xC51C, // 11714
+    0xC528, // 11715
+    0xC529, // 11716
+    0xC52C, // 11717
+    0xC530, // 11718
+    0xC538, // 11719
+    0xC539, // 11720
+    0xC53B, // 11721
+    0xC53D, // 11722
+    0xC544, // 11723
+    0xC545, // 11724
+    0xC548, // 11725
+    0xC549, // 11726
+    0xC54A, // 11727
+    0xC54C, // 11728
+    0xC54D, // 11729
+    0xC54E, // 11730
+    0xC553, // 11731
+    0xC554, // 11732
+    0xC555, // 11733
+    0xC557, // 11734
+    0xC558, // 11735
+    0xC559, // 11736
+    0xC55D, // 11737
+    0xC55E, // 11738
+    0xC560, // 11739
+    0xC561, // 11740
+    0xC564, // 11741
+    0xC568, // 11742
+    0xC570, // 11743
+    0xC571, // 11744
+    0xC573, // 11745
+    0xC574, // 11746
+    0xC575, // 11747
+    0xC57C, // 11748
+    0xC57D, // 11749
+    0xC580, // 11750
+    0xC584, // 11751
+    0xC587, // 11752
+    0xC58C, // 11753
+    0xC58D, // 11754
+    0xC58F, // 11755
+    0xC591, // 11756
+    0xC595, // 11757
+    0xC597, // 11758
+    0xC598, // 11759
+    0xC59C, // 11760
+    0xC5A0, // 11761
+    0xC5A9, // 11762
+    0xC5B4, // 11763
+    0xC5B5, // 11764
+    0xC5B8, // 11765
+    0xC5B9, // 11766
+    0xC5BB, // 11767
+    0xC5BC, // 11768
+    0xC5BD, // 11769
+    0xC5BE, // 11770
+    0xC5C4, // 11771
+    0xC5C5, // 11772
+    0xC5C6, // 11773
+    0xC5C7, // 11774
+    0xC5C8, // 11775
+    0xC5C9, // 11776
+    0xC5CA, // 11777
+    0xC5CC, // 11778
+    0xC5CE, // 11779
+    0xD49E, // 11780
+    0xD49F, // 11781
+    0xD4A0, // 11782
+    0xD4A1, // 11783
+    0xD4A2, // 11784
+    0xD4A3, // 11785
+    0xD4A4, // 11786
+    0xD4A5, // 11787
+    0xD4A6, // 11788
+    0xD4A7, // 11789
+    0xD4A8, // 11790
+    0xD4AA, // 11791
+    0xD4AB, // 11792
+    0xD4AC, // 11793
+    0xD4AD, // 11794
+    0xD4AE, // 11795
+    0xD4AF, // 11796
+    0xD4B0, // 11797
+    0xD4B1, // 11798
+    0xD4B2, // 11799
+    0xD4B3, // 11800
+    0xD4B4, // 11801
+    0xD4B5, // 11802
+    0xD4B6, // 11803
+    0xD4B7, // 11804
+    0xD4B8, // 11805
+    0x0000, // 11806
+    0x0000, // 11807
+    0x0000, // 11808
+    0x0000, // 11809
+    0x0000, // 11810
+    0x0000, // 11811
+    0xD4B9, // 11812
+    0xD4BA, // 11813
+    0xD4BB, // 11814
+    0xD4BC, // 11815
+    0xD4BD, // 11816
+    0xD4BE, // 11817
+    0xD4BF, // 11818
+    0xD4C0, // 11819
+    0xD4C1, // 11820
+    0xD4C2, // 11821
+    0xD4C3, // 11822
+    0xD4C4, // 11823
+    0xD4C5, // 11824
+    0xD4C6, // 11825
+    0xD4C7, // 11826
+    0xD4C8, // 11827
+    0xD4C9, // 11828
+    0xD4CA, // 11829
+    0xD4CB, // 11830
+    0xD4CD, // 11831
+    0xD4CE, // 11832
+    0xD4CF, // 11833
+    0xD4D1, // 11834
+    0xD4D2, // 11835
+    0xD4D3, // 11836
+    0xD4D5, // 11837
+    0x0000, // 11838
+    0x0000, // 11839
+    0x0000, // 11840
+    0x0000, // 11841
+    0x0000, // 11842
+    0x0000, // 11843
+    0xD4D6, // 11844
+    0xD4D7, // 11845
+    0xD4D8, // 11846
+    0xD4D9, // 11847
+    0xD4DA, // 11848
+    0xD4DB, // 11849
+    0xD4DD, // 11850
+    0xD4DE, // 11851
+    0xD4E0, // 11852
+    0xD4E1, // 11853
+    0xD4E2, // 11854
+    0xD4E3, // 11855
+    0xD4E4, // 11856
+    0xD4E5, // 11857
+    0xD4E6, // 11858
+    0xD4E7, // 11859
+    0xD4E9, // 11860
+    0xD4EA, // 11861
+    0xD4EB, // 11862
+    0xD4ED, // 11863
+    0xD4EE, // 11864
+    0xD4EF, // 11865
+    0xD4F1, // 11866
+    0xD4F2, // 11867
+    0xD4F3, // 11868
+    0xD4F4, // 11869
+    0xD4F5, // 11870
+    0xD4F6, // 11871
+    0xD4F7, // 11872
+    0xD4F9, // 11873
+    0xD4FA, // 11874
+    0xD4FC, // 11875
+    0xC5D0, // 11876
+    0xC5D1, // 11877
+    0xC5D4, // 11878
+    0xC5D8, // 11879
+    0xC5E0, // 11880
+    0xC5E1, // 11881
+    0xC5E3, // 11882
+    0xC5E5, // 11883
+    0xC5EC, // 11884
+    0xC5ED, // 11885
+    0xC5EE, // 11886
+    0xC5F0, // 11887
+    0xC5F4, // 11888
+    0xC5F6, // 11889
+    0xC5F7, // 11890
+    0xC5FC, // 11891
+    0xC5FD, // 11892
+    0xC5FE, // 11893
+    0xC5FF, // 11894
+    0xC600, // 11895
+    0xC601, // 11896
+    0xC605, // 11897
+    0xC606, // 11898
+    0xC607, // 11899
+    0xC608, // 11900
+    0xC60C, // 11901
+    0xC610, // 11902
+    0xC618, // 11903
+    0xC619, // 11904
+    0xC61B, // 11905
+    0xC61C, // 11906
+    0xC624, // 11907
+    0xC625, // 11908
+    0xC628, // 11909
+    0xC62C, // 11910
+    0xC62D, // 11911
+    0xC62E, // 11912
+    0xC630, // 11913
+    0xC633, // 11914
+    0xC634, // 11915
+    0xC635, // 11916
+    0xC637, // 11917
+    0xC639, // 11918
+    0xC63B, // 11919
+    0xC640, // 11920
+    0xC641, // 11921
+    0xC644, // 11922
+    0xC648, // 11923
+    0xC650, // 11924
+    0xC651, // 11925
+    0xC653, // 11926
+    0xC654, // 11927
+    0xC655, // 11928
+    0xC65C, // 11929
+    0xC65D, // 11930
+    0xC660, // 11931
+    0xC66C, // 11932
+    0xC66F, // 11933
+    0xC671, // 11934
+    0xC678, // 11935
+    0xC679, // 11936
+    0xC67C, // 11937
+    0xC680, // 11938
+    0xC688, // 11939
+    0xC689, // 11940
+    0xC68B, // 11941
+    0xC68D, // 11942
+    0xC694, // 11943
+    0xC695, // 11944
+    0xC698, // 11945
+    0xC69C, // 11946
+    0xC6A4, // 11947
+    0xC6A5, // 11948
+    0xC6A7, // 11949
+    0xC6A9, // 11950
+    0xC6B0, // 11951
+    0xC6B1, // 11952
+    0xC6B4, // 11953
+    0xC6B8, // 11954
+    0xC6B9, // 11955
+    0xC6BA, // 11956
+    0xC6C0, // 11957
+    0xC6C1, // 11958
+    0xC6C3, // 11959
+    0xC6C5, // 11960
+    0xC6CC, // 11961
+    0xC6CD, // 11962
+    0xC6D0, // 11963
+    0xC6D4, // 11964
+    0xC6DC, // 11965
+    0xC6DD, // 11966
+    0xC6E0, // 11967
+    0xC6E1, // 11968
+    0xC6E8, // 11969
+    0xD4FE, // 11970
+    0xD4FF, // 11971
+    0xD500, // 11972
+    0xD501, // 11973
+    0xD502, // 11974
+    0xD503, // 11975
+    0xD505, // 11976
+    0xD506, // 11977
+    0xD507, // 11978
+    0xD509, // 11979
+    0xD50A, // 11980
+    0xD50B, // 11981
+    0xD50D, // 11982
+    0xD50E, // 11983
+    0xD50F, // 11984
+    0xD510, // 11985
+    0xD511, // 11986
+    0xD512, // 11987
+    0xD513, // 11988
+    0xD516, // 11989
+    0xD518, // 11990
+    0xD519, // 11991
+    0xD51A, // 11992
+    0xD51B, // 11993
+    0xD51C, // 11994
+    0xD51D, // 11995
+    0x0000, // 11996
+    0x0000, // 11997
+    0x0000, // 11998
+    0x0000, // 11999
+    0x0000, // 12000
+    0x0000, // 12001
+    0xD51E, // 12002
+    0xD51F, // 12003
+    0xD520, // 12004
+    0xD521, // 12005
+    0xD522, // 12006
+    0xD523, // 12007
+    0xD524, // 12008
+    0xD525, // 12009
+    0xD526, // 12010
+    0xD527, // 12011
+    0xD528, // 12012
+    0xD529, // 12013
+    0xD52A, // 12014
+    0xD52B, // 12015
+    0xD52C, // 12016
+    0xD52D, // 12017
+    0xD52E, // 12018
+    0xD52F, // 12019
+    0xD530, // 12020
+    0xD531, // 12021
+    0xD532, // 12022
+    0xD533, // 12023
+    0xD534, // 12024
+    0xD535, // 12025
+    0xD536, // 12026
+    0xD537, // 12027
+    0x0000, // 12028
+    0x0000, // 12029
+    0x0000, // 12030
+    0x0000, // 12031
+    0x0000, // 12032
+    0x0000, // 12033
+    0xD538, // 12034
+    0xD539, // 12035
+    0xD53A, // 12036
+    0xD53B, // 12037
+    0xD53E, // 12038
+    0xD53F, // 12039
+    0xD541, // 12040
+    0xD542, // 12041
+    0xD543, // 12042
+    0xD545, // 12043
+    0xD546, // 12044
+    0xD547, // 12045
+    0xD548, // 12046
+    0xD549, // 12047
+    0xD54A, // 12048
+    0xD54B, // 12049
+    0xD54E, // 12050
+    0xD550, // 12051
+    0xD552, // 12052
+    0xD553, // 12053
+    0xD554, // 12054
+    0xD555, // 12055
+    0xD556, // 12056
+    0xD557, // 12057
+    0xD55A, // 12058
+    0xD55B, // 12059
+    0xD55D, // 12060
+    0xD55E, // 12061
+    0xD55F, // 12062
+    0xD561, // 12063
+    0xD562, // 12064
+    0xD563, // 12065
+    0xC6E9, // 12066
+    0xC6EC, // 12067
+    0xC6F0, // 12068
+    0xC6F8, // 12069
+    0xC6F9, // 12070
+    0xC6FD, // 12071
+    0xC704, // 12072
+    0xC705, // 12073
+    0xC708, // 12074
+    0xC70C, // 12075
+    0xC714, // 12076
+    0xC715, // 12077
+    0xC717, // 12078
+    0xC719, // 12079
+    0xC720, // 12080
+    0xC721, // 12081
+    0xC724, // 12082
+    0xC728, // 12083
+    0xC730, // 12084
+    0xC731, // 12085
+    0xC733, // 12086
+    0xC735, // 12087
+    0xC737, // 12088
+    0xC73C, // 12089
+    0xC73D, // 12090
+    0xC740, // 12091
+    0xC744, // 12092
+    0xC74A, // 12093
+    0xC74C, // 12094
+    0xC74D, // 12095
+    0xC74F, // 12096
+    0xC751, // 12097
+    0xC752, // 12098
+    0xC753, // 12099
+    0xC754, // 12100
+    0xC755, // 12101
+    0xC756, // 12102
+    0xC757, // 12103
+    0xC758, // 12104
+    0xC75C, // 12105
+    0xC760, // 12106
+    0xC768, // 12107
+    0xC76B, // 12108
+    0xC774, // 12109
+    0xC775, // 12110
+    0xC778, // 12111
+    0xC77C, // 12112
+    0xC77D, // 12113
+    0xC77E, // 12114
+    0xC783, // 12115
+    0xC784, // 12116
+    0xC785, // 12117
+    0xC787, // 12118
+    0xC788, // 12119
+    0xC789, // 12120
+    0xC78A, // 12121
+    0xC78E, // 12122
+    0xC790, // 12123
+    0xC791, // 12124
+    0xC794, // 12125
+    0xC796, // 12126
+    0xC797, // 12127
+    0xC798, // 12128
+    0xC79A, // 12129
+    0xC7A0, // 12130
+    0xC7A1, // 12131
+    0xC7A3, // 12132
+    0xC7A4, // 12133
+    0xC7A5, // 12134
+    0xC7A6, // 12135
+    0xC7AC, // 12136
+    0xC7AD, // 12137
+    0xC7B0, // 12138
+    0xC7B4, // 12139
+    0xC7BC, // 12140
+    0xC7BD, // 12141
+    0xC7BF, // 12142
+    0xC7C0, // 12143
+    0xC7C1, // 12144
+    0xC7C8, // 12145
+    0xC7C9, // 12146
+    0xC7CC, // 12147
+    0xC7CE, // 12148
+    0xC7D0, // 12149
+    0xC7D8, // 12150
+    0xC7DD, // 12151
+    0xC7E4, // 12152
+    0xC7E8, // 12153
+    0xC7EC, // 12154
+    0xC800, // 12155
+    0xC801, // 12156
+    0xC804, // 12157
+    0xC808, // 12158
+    0xC80A, // 12159
+    0xD564, // 12160
+    0xD566, // 12161
+    0xD567, // 12162
+    0xD56A, // 12163
+    0xD56C, // 12164
+    0xD56E, // 12165
+    0xD56F, // 12166
+    0xD570, // 12167
+    0xD571, // 12168
+    0xD572, // 12169
+    0xD573, // 12170
+    0xD576, // 12171
+    0xD577, // 12172
+    0xD579, // 12173
+    0xD57A, // 12174
+    0xD57B, // 12175
+    0xD57D, // 12176
+    0xD57E, // 12177
+    0xD57F, // 12178
+    0xD580, // 12179
+    0xD581, // 12180
+    0xD582, // 12181
+    0xD583, // 12182
+    0xD586, // 12183
+    0xD58A, // 12184
+    0xD58B, // 12185
+    0x0000, // 12186
+    0x0000, // 12187
+    0x0000, // 12188
+    0x0000, // 12189
+    0x0000, // 12190
+    0x0000, // 12191
+    0xD58C, // 12192
+    0xD58D, // 12193
+    0xD58E, // 12194
+    0xD58F, // 12195
+    0xD591, // 12196
+    0xD592, // 12197
+    0xD593, // 12198
+    0xD594, // 12199
+    0xD595, // 12200
+    0xD596, // 12201
+    0xD597, // 12202
+    0xD598, // 12203
+    0xD599, // 12204
+    0xD59A, // 12205
+    0xD59B, // 12206
+    0xD59C, // 12207
+    0xD59D, // 12208
+    0xD59E, // 12209
+    0xD59F, // 12210
+    0xD5A0, // 12211
+    0xD5A1, // 12212
+    0xD5A2, // 12213
+    0xD5A3, // 12214
+    0xD5A4, // 12215
+    0xD5A6, // 12216
+    0xD5A7, // 12217
+    0x0000, // 12218
+    0x0000, // 12219
+    0x0000, // 12220
+    0x0000, // 12221
+    0x0000, // 12222
+    0x0000, // 12223
+    0xD5A8, // 12224
+    0xD5A9, // 12225
+    0xD5AA, // 12226
+    0xD5AB, // 12227
+    0xD5AC, // 12228
+    0xD5AD, // 12229
+    0xD5AE, // 12230
+    0xD5AF, // 12231
+    0xD5B0, // 12232
+    0xD5B1, // 12233
+    0xD5B2, // 12234
+    0xD5B3, // 12235
+    0xD5B4, // 12236
+    0xD5B5, // 12237
+    0xD5B6, // 12238
+    0xD5B7, // 12239
+    0xD5B8, // 12240
+    0xD5B9, // 12241
+    0xD5BA, // 12242
+    0xD5BB, // 12243
+    0xD5BC, // 12244
+    0xD5BD, // 12245
+    0xD5BE, // 12246
+    0xD5BF, // 12247
+    0xD5C0, // 12248
+    0xD5C1, // 12249
+    0xD5C2, // 12250
+    0xD5C3, // 12251
+    0xD5C4, // 12252
+    0xD5C5, // 12253
+    0xD5C6, // 12254
+    0xD5C7, // 12255
+    0xC810, // 12256
+    0xC811, // 12257
+    0xC813, // 12258
+    0xC815, // 12259
+    0xC816, // 12260
+    0xC81C, // 12261
+    0xC81D, // 12262
+    0xC820, // 12263
+    0xC824, // 12264
+    0xC82C, // 12265
+    0xC82D, // 12266
+    0xC82F, // 12267
+    0xC831, // 12268
+    0xC838, // 12269
+    0xC83C, // 12270
+    0xC840, // 12271
+    0xC848, // 12272
+    0xC849, // 12273
+    0xC84C, // 12274
+    0xC84D, // 12275
+    0xC854, // 12276
+    0xC870, // 12277
+    0xC871, // 12278
+    0xC874, // 12279
+    0xC878, // 12280
+    0xC87A, // 12281
+    0xC880, // 12282
+    0xC881, // 12283
+    0xC883, // 12284
+    0xC885, // 12285
+    0xC886, // 12286
+    0xC887, // 12287
+    0xC88B, // 12288
+    0xC88C, // 12289
+    0xC88D, // 12290
+    0xC894, // 12291
+    0xC89D, // 12292
+    0xC89F, // 12293
+    0xC8A1, // 12294
+    0xC8A8, // 12295
+    0xC8BC, // 12296
+    0xC8BD, // 12297
+    0xC8C4, // 12298
+    0xC8C8, // 12299
+    0xC8CC, // 12300
+    0xC8D4, // 12301
+    0xC8D5, // 12302
+    0xC8D7, // 12303
+    0xC8D9, // 12304
+    0xC8E0, // 12305
+    0xC8E1, // 12306
+    0xC8E4, // 12307
+    0xC8F5, // 12308
+    0xC8FC, // 12309
+    0xC8FD, // 12310
+    0xC900, // 12311
+    0xC904, // 12312
+    0xC905, // 12313
+    0xC906, // 12314
+    0xC90C, // 12315
+    0xC90D, // 12316
+    0xC90F, // 12317
+    0xC911, // 12318
+    0xC918, // 12319
+    0xC92C, // 12320
+    0xC934, // 12321
+    0xC950, // 12322
+    0xC951, // 12323
+    0xC954, // 12324
+    0xC958, // 12325
+    0xC960, // 12326
+    0xC961, // 12327
+    0xC963, // 12328
+    0xC96C, // 12329
+    0xC970, // 12330
+    0xC974, // 12331
+    0xC97C, // 12332
+    0xC988, // 12333
+    0xC989, // 12334
+    0xC98C, // 12335
+    0xC990, // 12336
+    0xC998, // 12337
+    0xC999, // 12338
+    0xC99B, // 12339
+    0xC99D, // 12340
+    0xC9C0, // 12341
+    0xC9C1, // 12342
+    0xC9C4, // 12343
+    0xC9C7, // 12344
+    0xC9C8, // 12345
+    0xC9CA, // 12346
+    0xC9D0, // 12347
+    0xC9D1, // 12348
+    0xC9D3, // 12349
+    0xD5CA, // 12350
+    0xD5CB, // 12351
+    0xD5CD, // 12352
+    0xD5CE, // 12353
+    0xD5CF, // 12354
+    0xD5D1, // 12355
+    0xD5D3, // 12356
+    0xD5D4, // 12357
+    0xD5D5, // 12358
+    0xD5D6, // 12359
+    0xD5D7, // 12360
+    0xD5DA, // 12361
+    0xD5DC, // 12362
+    0xD5DE, // 12363
+    0xD5DF, // 12364
+    0xD5E0, // 12365
+    0xD5E1, // 12366
+    0xD5E2, // 12367
+    0xD5E3, // 12368
+    0xD5E6, // 12369
+    0xD5E7, // 12370
+    0xD5E9, // 12371
+    0xD5EA, // 12372
+    0xD5EB, // 12373
+    0xD5ED, // 12374
+    0xD5EE, // 12375
+    0x0000, // 12376
+    0x0000, // 12377
+    0x0000, // 12378
+    0x0000, // 12379
+    0x0000, // 12380
+    0x0000, // 12381
+    0xD5EF, // 12382
+    0xD5F0, // 12383
+    0xD5F1, // 12384
+    0xD5F2, // 12385
+    0xD5F3, // 12386
+    0xD5F6, // 12387
+    0xD5F8, // 12388
+    0xD5FA, // 12389
+    0xD5FB, // 12390
+    0xD5FC, // 12391
+    0xD5FD, // 12392
+    0xD5FE, // 12393
+    0xD5FF, // 12394
+    0xD602, // 12395
+    0xD603, // 12396
+    0xD605, // 12397
+    0xD606, // 12398
+    0xD607, // 12399
+    0xD609, // 12400
+    0xD60A, // 12401
+    0xD60B, // 12402
+    0xD60C, // 12403
+    0xD60D, // 12404
+    0xD60E, // 12405
+    0xD60F, // 12406
+    0xD612, // 12407
+    0x0000, // 12408
+    0x0000, // 12409
+    0x0000, // 12410
+    0x0000, // 12411
+    0x0000, // 12412
+    0x0000, // 12413
+    0xD616, // 12414
+    0xD617, // 12415
+    0xD618, // 12416
+    0xD619, // 12417
+    0xD61A, // 12418
+    0xD61B, // 12419
+    0xD61D, // 12420
+    0xD61E, // 12421
+    0xD61F, // 12422
+    0xD621, // 12423
+    0xD622, // 12424
+    0xD623, // 12425
+    0xD625, // 12426
+    0xD626, // 12427
+    0xD627, // 12428
+    0xD628, // 12429
+    0xD629, // 12430
+    0xD62A, // 12431
+    0xD62B, // 12432
+    0xD62C, // 12433
+    0xD62E, // 12434
+    0xD62F, // 12435
+    0xD630, // 12436
+    0xD631, // 12437
+    0xD632, // 12438
+    0xD633, // 12439
+    0xD634, // 12440
+    0xD635, // 12441
+    0xD636, // 12442
+    0xD637, // 12443
+    0xD63A, // 12444
+    0xD63B, // 12445
+    0xC9D5, // 12446
+    0xC9D6, // 12447
+    0xC9D9, // 12448
+    0xC9DA, // 12449
+    0xC9DC, // 12450
+    0xC9DD, // 12451
+    0xC9E0, // 12452
+    0xC9E2, // 12453
+    0xC9E4, // 12454
+    0xC9E7, // 12455
+    0xC9EC, // 12456
+    0xC9ED, // 12457
+    0xC9EF, // 12458
+    0xC9F0, // 12459
+    0xC9F1, // 12460
+    0xC9F8, // 12461
+    0xC9F9, // 12462
+    0xC9FC, // 12463
+    0xCA00, // 12464
+    0xCA08, // 12465
+    0xCA09, // 12466
+    0xCA0B, // 12467
+    0xCA0C, // 12468
+    0xCA0D, // 12469
+    0xCA14, // 12470
+    0xCA18, // 12471
+    0xCA29, // 12472
+    0xCA4C, // 12473
+    0xCA4D, // 12474
+    0xCA50, // 12475
+    0xCA54, // 12476
+    0xCA5C, // 12477
+    0xCA5D, // 12478
+    0xCA5F, // 12479
+    0xCA60, // 12480
+    0xCA61, // 12481
+    0xCA68, // 12482
+    0xCA7D, // 12483
+    0xCA84, // 12484
+    0xCA98, // 12485
+    0xCABC, // 12486
+    0xCABD, // 12487
+    0xCAC0, // 12488
+    0xCAC4, // 12489
+    0xCACC, // 12490
+    0xCACD, // 12491
+    0xCACF, // 12492
+    0xCAD1, // 12493
+    0xCAD3, // 12494
+    0xCAD8, // 12495
+    0xCAD9, // 12496
+    0xCAE0, // 12497
+    0xCAEC, // 12498
+    0xCAF4, // 12499
+    0xCB08, // 12500
+    0xCB10, // 12501
+    0xCB14, // 12502
+    0xCB18, // 12503
+    0xCB20, // 12504
+    0xCB21, // 12505
+    0xCB41, // 12506
+    0xCB48, // 12507
+    0xCB49, // 12508
+    0xCB4C, // 12509
+    0xCB50, // 12510
+    0xCB58, // 12511
+    0xCB59, // 12512
+    0xCB5D, // 12513
+    0xCB64, // 12514
+    0xCB78, // 12515
+    0xCB79, // 12516
+    0xCB9C, // 12517
+    0xCBB8, // 12518
+    0xCBD4, // 12519
+    0xCBE4, // 12520
+    0xCBE7, // 12521
+    0xCBE9, // 12522
+    0xCC0C, // 12523
+    0xCC0D, // 12524
+    0xCC10, // 12525
+    0xCC14, // 12526
+    0xCC1C, // 12527
+    0xCC1D, // 12528
+    0xCC21, // 12529
+    0xCC22, // 12530
+    0xCC27, // 12531
+    0xCC28, // 12532
+    0xCC29, // 12533
+    0xCC2C, // 12534
+    0xCC2E, // 12535
+    0xCC30, // 12536
+    0xCC38, // 12537
+    0xCC39, // 12538
+    0xCC3B, // 12539
+    0xD63D, // 12540
+    0xD63E, // 12541
+    0xD63F, // 12542
+    0xD641, // 12543
+    0xD642, // 12544
+    0xD643, // 12545
+    0xD644, // 12546
+    0xD646, // 12547
+    0xD647, // 12548
+    0xD64A, // 12549
+    0xD64C, // 12550
+    0xD64E, // 12551
+    0xD64F, // 12552
+    0xD650, // 12553
+    0xD652, // 12554
+    0xD653, // 12555
+    0xD656, // 12556
+    0xD657, // 12557
+    0xD659, // 12558
+    0xD65A, // 12559
+    0xD65B, // 12560
+    0xD65D, // 12561
+    0xD65E, // 12562
+    0xD65F, // 12563
+    0xD660, // 12564
+    0xD661, // 12565
+    0x0000, // 12566
+    0x0000, // 12567
+    0x0000, // 12568
+    0x0000, // 12569
+    0x0000, // 12570
+    0x0000, // 12571
+    0xD662, // 12572
+    0xD663, // 12573
+    0xD664, // 12574
+    0xD665, // 12575
+    0xD666, // 12576
+    0xD668, // 12577
+    0xD66A, // 12578
+    0xD66B, // 12579
+    0xD66C, // 12580
+    0xD66D, // 12581
+    0xD66E, // 12582
+    0xD66F, // 12583
+    0xD672, // 12584
+    0xD673, // 12585
+    0xD675, // 12586
+    0xD676, // 12587
+    0xD677, // 12588
+    0xD678, // 12589
+    0xD679, // 12590
+    0xD67A, // 12591
+    0xD67B, // 12592
+    0xD67C, // 12593
+    0xD67D, // 12594
+    0xD67E, // 12595
+    0xD67F, // 12596
+    0xD680, // 12597
+    0x0000, // 12598
+    0x0000, // 12599
+    0x0000, // 12600
+    0x0000, // 12601
+    0x0000, // 12602
+    0x0000, // 12603
+    0xD681, // 12604
+    0xD682, // 12605
+    0xD684, // 12606
+    0xD686, // 12607
+    0xD687, // 12608
+    0xD688, // 12609
+    0xD689, // 12610
+    0xD68A, // 12611
+    0xD68B, // 12612
+    0xD68E, // 12613
+    0xD68F, // 12614
+    0xD691, // 12615
+    0xD692, // 12616
+    0xD693, // 12617
+    0xD695, // 12618
+    0xD696, // 12619
+    0xD697, // 12620
+    0xD698, // 12621
+    0xD699, // 12622
+    0xD69A, // 12623
+    0xD69B, // 12624
+    0xD69C, // 12625
+    0xD69E, // 12626
+    0xD6A0, // 12627
+    0xD6A2, // 12628
+    0xD6A3, // 12629
+    0xD6A4, // 12630
+    0xD6A5, // 12631
+    0xD6A6, // 12632
+    0xD6A7, // 12633
+    0xD6A9, // 12634
+    0xD6AA, // 12635
+    0xCC3C, // 12636
+    0xCC3D, // 12637
+    0xCC3E, // 12638
+    0xCC44, // 12639
+    0xCC45, // 12640
+    0xCC48, // 12641
+    0xCC4C, // 12642
+    0xCC54, // 12643
+    0xCC55, // 12644
+    0xCC57, // 12645
+    0xCC58, // 12646
+    0xCC59, // 12647
+    0xCC60, // 12648
+    0xCC64, // 12649
+    0xCC66, // 12650
+    0xCC68, // 12651
+    0xCC70, // 12652
+    0xCC75, // 12653
+    0xCC98, // 12654
+    0xCC99, // 12655
+    0xCC9C, // 12656
+    0xCCA0, // 12657
+    0xCCA8, // 12658
+    0xCCA9, // 12659
+    0xCCAB, // 12660
+    0xCCAC, // 12661
+    0xCCAD, // 12662
+    0xCCB4, // 12663
+    0xCCB5, // 12664
+    0xCCB8, // 12665
+    0xCCBC, // 12666
+    0xCCC4, // 12667
+    0xCCC5, // 12668
+    0xCCC7, // 12669
+    0xCCC9, // 12670
+    0xCCD0, // 12671
+    0xCCD4, // 12672
+    0xCCE4, // 12673
+    0xCCEC, // 12674
+    0xCCF0, // 12675
+    0xCD01, // 12676
+    0xCD08, // 12677
+    0xCD09, // 12678
+    0xCD0C, // 12679
+    0xCD10, // 12680
+    0xCD18, // 12681
+    0xCD19, // 12682
+    0xCD1B, // 12683
+    0xCD1D, // 12684
+    0xCD24, // 12685
+    0xCD28, // 12686
+    0xCD2C, // 12687
+    0xCD39, // 12688
+    0xCD5C, // 12689
+    0xCD60, // 12690
+    0xCD64, // 12691
+    0xCD6C, // 12692
+    0xCD6D, // 12693
+    0xCD6F, // 12694
+    0xCD71, // 12695
+    0xCD78, // 12696
+    0xCD88, // 12697
+    0xCD94, // 12698
+    0xCD95, // 12699
+    0xCD98, // 12700
+    0xCD9C, // 12701
+    0xCDA4, // 12702
+    0xCDA5, // 12703
+    0xCDA7, // 12704
+    0xCDA9, // 12705
+    0xCDB0, // 12706
+    0xCDC4, // 12707
+    0xCDCC, // 12708
+    0xCDD0, // 12709
+    0xCDE8, // 12710
+    0xCDEC, // 12711
+    0xCDF0, // 12712
+    0xCDF8, // 12713
+    0xCDF9, // 12714
+    0xCDFB, // 12715
+    0xCDFD, // 12716
+    0xCE04, // 12717
+    0xCE08, // 12718
+    0xCE0C, // 12719
+    0xCE14, // 12720
+    0xCE19, // 12721
+    0xCE20, // 12722
+    0xCE21, // 12723
+    0xCE24, // 12724
+    0xCE28, // 12725
+    0xCE30, // 12726
+    0xCE31, // 12727
+    0xCE33, // 12728
+    0xCE35, // 12729
+    0xD6AB, // 12730
+    0xD6AD, // 12731
+    0xD6AE, // 12732
+    0xD6AF, // 12733
+    0xD6B1, // 12734
+    0xD6B2, // 12735
+    0xD6B3, // 12736
+    0xD6B4, // 12737
+    0xD6B5, // 12738
+    0xD6B6, // 12739
+    0xD6B7, // 12740
+    0xD6B8, // 12741
+    0xD6BA, // 12742
+    0xD6BC, // 12743
+    0xD6BD, // 12744
+    0xD6BE, // 12745
+    0xD6BF, // 12746
+    0xD6C0, // 12747
+    0xD6C1, // 12748
+    0xD6C2, // 12749
+    0xD6C3, // 12750
+    0xD6C6, // 12751
+    0xD6C7, // 12752
+    0xD6C9, // 12753
+    0xD6CA, // 12754
+    0xD6CB, // 12755
+    0x0000, // 12756
+    0x0000, // 12757
+    0x0000, // 12758
+    0x0000, // 12759
+    0x0000, // 12760
+    0x0000, // 12761
+    0xD6CD, // 12762
+    0xD6CE, // 12763
+    0xD6CF, // 12764
+    0xD6D0, // 12765
+    0xD6D2, // 12766
+    0xD6D3, // 12767
+    0xD6D5, // 12768
+    0xD6D6, // 12769
+    0xD6D8, // 12770
+    0xD6DA, // 12771
+    0xD6DB, // 12772
+    0xD6DC, // 12773
+    0xD6DD, // 12774
+    0xD6DE, // 12775
+    0xD6DF, // 12776
+    0xD6E1, // 12777
+    0xD6E2, // 12778
+    0xD6E3, // 12779
+    0xD6E5, // 12780
+    0xD6E6, // 12781
+    0xD6E7, // 12782
+    0xD6E9, // 12783
+    0xD6EA, // 12784
+    0xD6EB, // 12785
+    0xD6EC, // 12786
+    0xD6ED, // 12787
+    0x0000, // 12788
+    0x0000, // 12789
+    0x0000, // 12790
+    0x0000, // 12791
+    0x0000, // 12792
+    0x0000, // 12793
+    0xD6EE, // 12794
+    0xD6EF, // 12795
+    0xD6F1, // 12796
+    0xD6F2, // 12797
+    0xD6F3, // 12798
+    0xD6F4, // 12799
+    0xD6F6, // 12800
+    0xD6F7, // 12801
+    0xD6F8, // 12802
+    0xD6F9, // 12803
+    0xD6FA, // 12804
+    0xD6FB, // 12805
+    0xD6FE, // 12806
+    0xD6FF, // 12807
+    0xD701, // 12808
+    0xD702, // 12809
+    0xD703, // 12810
+    0xD705, // 12811
+    0xD706, // 12812
+    0xD707, // 12813
+    0xD708, // 12814
+    0xD709, // 12815
+    0xD70A, // 12816
+    0xD70B, // 12817
+    0xD70C, // 12818
+    0xD70D, // 12819
+    0xD70E, // 12820
+    0xD70F, // 12821
+    0xD710, // 12822
+    0xD712, // 12823
+    0xD713, // 12824
+    0xD714, // 12825
+    0xCE58, // 12826
+    0xCE59, // 12827
+    0xCE5C, // 12828
+    0xCE5F, // 12829
+    0xCE60, // 12830
+    0xCE61, // 12831
+    0xCE68, // 12832
+    0xCE69, // 12833
+    0xCE6B, // 12834
+    0xCE6D, // 12835
+    0xCE74, // 12836
+    0xCE75, // 12837
+    0xCE78, // 12838
+    0xCE7C, // 12839
+    0xCE84, // 12840
+    0xCE85, // 12841
+    0xCE87, // 12842
+    0xCE89, // 12843
+    0xCE90, // 12844
+    0xCE91, // 12845
+    0xCE94, // 12846
+    0xCE98, // 12847
+    0xCEA0, // 12848
+    0xCEA1, // 12849
+    0xCEA3, // 12850
+    0xCEA4, // 12851
+    0xCEA5, // 12852
+    0xCEAC, // 12853
+    0xCEAD, // 12854
+    0xCEC1, // 12855
+    0xCEE4, // 12856
+    0xCEE5, // 12857
+    0xCEE8, // 12858
+    0xCEEB, // 12859
+    0xCEEC, // 12860
+    0xCEF4, // 12861
+    0xCEF5, // 12862
+    0xCEF7, // 12863
+    0xCEF8, // 12864
+    0xCEF9, // 12865
+    0xCF00, // 12866
+    0xCF01, // 12867
+    0xCF04, // 12868
+    0xCF08, // 12869
+    0xCF10, // 12870
+    0xCF11, // 12871
+    0xCF13, // 12872
+    0xCF15, // 12873
+    0xCF1C, // 12874
+    0xCF20, // 12875
+    0xCF24, // 12876
+    0xCF2C, // 12877
+    0xCF2D, // 12878
+    0xCF2F, // 12879
+    0xCF30, // 12880
+    0xCF31, // 12881
+    0xCF38, // 12882
+    0xCF54, // 12883
+    0xCF55, // 12884
+    0xCF58, // 12885
+    0xCF5C, // 12886
+    0xCF64, // 12887
+    0xCF65, // 12888
+    0xCF67, // 12889
+    0xCF69, // 12890
+    0xCF70, // 12891
+    0xCF71, // 12892
+    0xCF74, // 12893
+    0xCF78, // 12894
+    0xCF80, // 12895
+    0xCF85, // 12896
+    0xCF8C, // 12897
+    0xCFA1, // 12898
+    0xCFA8, // 12899
+    0xCFB0, // 12900
+    0xCFC4, // 12901
+    0xCFE0, // 12902
+    0xCFE1, // 12903
+    0xCFE4, // 12904
+    0xCFE8, // 12905
+    0xCFF0, // 12906
+    0xCFF1, // 12907
+    0xCFF3, // 12908
+    0xCFF5, // 12909
+    0xCFFC, // 12910
+    0xD000, // 12911
+    0xD004, // 12912
+    0xD011, // 12913
+    0xD018, // 12914
+    0xD02D, // 12915
+    0xD034, // 12916
+    0xD035, // 12917
+    0xD038, // 12918
+    0xD03C, // 12919
+    0xD715, // 12920
+    0xD716, // 12921
+    0xD717, // 12922
+    0xD71A, // 12923
+    0xD71B, // 12924
+    0xD71D, // 12925
+    0xD71E, // 12926
+    0xD71F, // 12927
+    0xD721, // 12928
+    0xD722, // 12929
+    0xD723, // 12930
+    0xD724, // 12931
+    0xD725, // 12932
+    0xD726, // 12933
+    0xD727, // 12934
+    0xD72A, // 12935
+    0xD72C, // 12936
+    0xD72E, // 12937
+    0xD72F, // 12938
+    0xD730, // 12939
+    0xD731, // 12940
+    0xD732, // 12941
+    0xD733, // 12942
+    0xD736, // 12943
+    0xD737, // 12944
+    0xD739, // 12945
+    0x0000, // 12946
+    0x0000, // 12947
+    0x0000, // 12948
+    0x0000, // 12949
+    0x0000, // 12950
+    0x0000, // 12951
+    0xD73A, // 12952
+    0xD73B, // 12953
+    0xD73D, // 12954
+    0xD73E, // 12955
+    0xD73F, // 12956
+    0xD740, // 12957
+    0xD741, // 12958
+    0xD742, // 12959
+    0xD743, // 12960
+    0xD745, // 12961
+    0xD746, // 12962
+    0xD748, // 12963
+    0xD74A, // 12964
+    0xD74B, // 12965
+    0xD74C, // 12966
+    0xD74D, // 12967
+    0xD74E, // 12968
+    0xD74F, // 12969
+    0xD752, // 12970
+    0xD753, // 12971
+    0xD755, // 12972
+    0xD75A, // 12973
+    0xD75B, // 12974
+    0xD75C, // 12975
+    0xD75D, // 12976
+    0xD75E, // 12977
+    0x0000, // 12978
+    0x0000, // 12979
+    0x0000, // 12980
+    0x0000, // 12981
+    0x0000, // 12982
+    0x0000, // 12983
+    0xD75F, // 12984
+    0xD762, // 12985
+    0xD764, // 12986
+    0xD766, // 12987
+    0xD767, // 12988
+    0xD768, // 12989
+    0xD76A, // 12990
+    0xD76B, // 12991
+    0xD76D, // 12992
+    0xD76E, // 12993
+    0xD76F, // 12994
+    0xD771, // 12995
+    0xD772, // 12996
+    0xD773, // 12997
+    0xD775, // 12998
+    0xD776, // 12999
+    0xD777, // 13000
+    0xD778, // 13001
+    0xD779, // 13002
+    0xD77A, // 13003
+    0xD77B, // 13004
+    0xD77E, // 13005
+    0xD77F, // 13006
+    0xD780, // 13007
+    0xD782, // 13008
+    0xD783, // 13009
+    0xD784, // 13010
+    0xD785, // 13011
+    0xD786, // 13012
+    0xD787, // 13013
+    0xD78A, // 13014
+    0xD78B, // 13015
+    0xD044, // 13016
+    0xD045, // 13017
+    0xD047, // 13018
+    0xD049, // 13019
+    0xD050, // 13020
+    0xD054, // 13021
+    0xD058, // 13022
+    0xD060, // 13023
+    0xD06C, // 13024
+    0xD06D, // 13025
+    0xD070, // 13026
+    0xD074, // 13027
+    0xD07C, // 13028
+    0xD07D, // 13029
+    0xD081, // 13030
+    0xD0A4, // 13031
+    0xD0A5, // 13032
+    0xD0A8, // 13033
+    0xD0AC, // 13034
+    0xD0B4, // 13035
+    0xD0B5, // 13036
+    0xD0B7, // 13037
+    0xD0B9, // 13038
+    0xD0C0, // 13039
+    0xD0C1, // 13040
+    0xD0C4, // 13041
+    0xD0C8, // 13042
+    0xD0C9, // 13043
+    0xD0D0, // 13044
+    0xD0D1, // 13045
+    0xD0D3, // 13046
+    0xD0D4, // 13047
+    0xD0D5, // 13048
+    0xD0DC, // 13049
+    0xD0DD, // 13050
+    0xD0E0, // 13051
+    0xD0E4, // 13052
+    0xD0EC, // 13053
+    0xD0ED, // 13054
+    0xD0EF, // 13055
+    0xD0F0, // 13056
+    0xD0F1, // 13057
+    0xD0F8, // 13058
+    0xD10D, // 13059
+    0xD130, // 13060
+    0xD131, // 13061
+    0xD134, // 13062
+    0xD138, // 13063
+    0xD13A, // 13064
+    0xD140, // 13065
+    0xD141, // 13066
+    0xD143, // 13067
+    0xD144, // 13068
+    0xD145, // 13069
+    0xD14C, // 13070
+    0xD14D, // 13071
+    0xD150, // 13072
+    0xD154, // 13073
+    0xD15C, // 13074
+    0xD15D, // 13075
+    0xD15F, // 13076
+    0xD161, // 13077
+    0xD168, // 13078
+    0xD16C, // 13079
+    0xD17C, // 13080
+    0xD184, // 13081
+    0xD188, // 13082
+    0xD1A0, // 13083
+    0xD1A1, // 13084
+    0xD1A4, // 13085
+    0xD1A8, // 13086
+    0xD1B0, // 13087
+    0xD1B1, // 13088
+    0xD1B3, // 13089
+    0xD1B5, // 13090
+    0xD1BA, // 13091
+    0xD1BC, // 13092
+    0xD1C0, // 13093
+    0xD1D8, // 13094
+    0xD1F4, // 13095
+    0xD1F8, // 13096
+    0xD207, // 13097
+    0xD209, // 13098
+    0xD210, // 13099
+    0xD22C, // 13100
+    0xD22D, // 13101
+    0xD230, // 13102
+    0xD234, // 13103
+    0xD23C, // 13104
+    0xD23D, // 13105
+    0xD23F, // 13106
+    0xD241, // 13107
+    0xD248, // 13108
+    0xD25C, // 13109
+    0xD78D, // 13110
+    0xD78E, // 13111
+    0xD78F, // 13112
+    0xD791, // 13113
+    0xD792, // 13114
+    0xD793, // 13115
+    0xD794, // 13116
+    0xD795, // 13117
+    0xD796, // 13118
+    0xD797, // 13119
+    0xD79A, // 13120
+    0xD79C, // 13121
+    0xD79E, // 13122
+    0xD79F, // 13123
+    0xD7A0, // 13124
+    0xD7A1, // 13125
+    0xD7A2, // 13126
+    0xD7A3, // 13127
+    0x0000, // 13128
+    0x0000, // 13129
+    0x0000, // 13130
+    0x0000, // 13131
+    0x0000, // 13132
+    0x0000, // 13133
+    0x0000, // 13134
+    0x0000, // 13135
+    0x0000, // 13136
+    0x0000, // 13137
+    0x0000, // 13138
+    0x0000, // 13139
+    0x0000, // 13140
+    0x0000, // 13141
+    0x0000, // 13142
+    0x0000, // 13143
+    0x0000, // 13144
+    0x0000, // 13145
+    0x0000, // 13146
+    0x0000, // 13147
+    0x0000, // 13148
+    0x0000, // 13149
+    0x0000, // 13150
+    0x0000, // 13151
+    0x0000, // 13152
+    0x0000, // 13153
+    0x0000, // 13154
+    0x0000, // 13155
+    0x0000, // 13156
+    0x0000, // 13157
+    0x0000, // 13158
+    0x0000, // 13159
+    0x0000, // 13160
+    0x0000, // 13161
+    0x0000, // 13162
+    0x0000, // 13163
+    0x0000, // 13164
+    0x0000, // 13165
+    0x0000, // 13166
+    0x0000, // 13167
+    0x0000, // 13168
+    0x0000, // 13169
+    0x0000, // 13170
+    0x0000, // 13171
+    0x0000, // 13172
+    0x0000, // 13173
+    0x0000, // 13174
+    0x0000, // 13175
+    0x0000, // 13176
+    0x0000, // 13177
+    0x0000, // 13178
+    0x0000, // 13179
+    0x0000, // 13180
+    0x0000, // 13181
+    0x0000, // 13182
+    0x0000, // 13183
+    0x0000, // 13184
+    0x0000, // 13185
+    0x0000, // 13186
+    0x0000, // 13187
+    0x0000, // 13188
+    0x0000, // 13189
+    0x0000, // 13190
+    0x0000, // 13191
+    0x0000, // 13192
+    0x0000, // 13193
+    0x0000, // 13194
+    0x0000, // 13195
+    0x0000, // 13196
+    0x0000, // 13197
+    0x0000, // 13198
+    0x0000, // 13199
+    0x0000, // 13200
+    0x0000, // 13201
+    0x0000, // 13202
+    0x0000, // 13203
+    0x0000, // 13204
+    0x0000, // 13205
+    0xD264, // 13206
+    0xD280, // 13207
+    0xD281, // 13208
+    0xD284, // 13209
+    0xD288, // 13210
+    0xD290, // 13211
+    0xD291, // 13212
+    0xD295, // 13213
+    0xD29C, // 13214
+    0xD2A0, // 13215
+    0xD2A4, // 13216
+    0xD2AC, // 13217
+    0xD2B1, // 13218
+    0xD2B8, // 13219
+    0xD2B9, // 13220
+    0xD2BC, // 13221
+    0xD2BF, // 13222
+    0xD2C0, // 13223
+    0xD2C2, // 13224
+    0xD2C8, // 13225
+    0xD2C9, // 13226
+    0xD2CB, // 13227
+    0xD2D4, // 13228
+    0xD2D8, // 13229
+    0xD2DC, // 13230
+    0xD2E4, // 13231
+    0xD2E5, // 13232
+    0xD2F0, // 13233
+    0xD2F1, // 13234
+    0xD2F4, // 13235
+    0xD2F8, // 13236
+    0xD300, // 13237
+    0xD301, // 13238
+    0xD303, // 13239
+    0xD305, // 13240
+    0xD30C, // 13241
+    0xD30D, // 13242
+    0xD30E, // 13243
+    0xD310, // 13244
+    0xD314, // 13245
+    0xD316, // 13246
+    0xD31C, // 13247
+    0xD31D, // 13248
+    0xD31F, // 13249
+    0xD320, // 13250
+    0xD321, // 13251
+    0xD325, // 13252
+    0xD328, // 13253
+    0xD329, // 13254
+    0xD32C, // 13255
+    0xD330, // 13256
+    0xD338, // 13257
+    0xD339, // 13258
+    0xD33B, // 13259
+    0xD33C, // 13260
+    0xD33D, // 13261
+    0xD344, // 13262
+    0xD345, // 13263
+    0xD37C, // 13264
+    0xD37D, // 13265
+    0xD380, // 13266
+    0xD384, // 13267
+    0xD38C, // 13268
+    0xD38D, // 13269
+    0xD38F, // 13270
+    0xD390, // 13271
+    0xD391, // 13272
+    0xD398, // 13273
+    0xD399, // 13274
+    0xD39C, // 13275
+    0xD3A0, // 13276
+    0xD3A8, // 13277
+    0xD3A9, // 13278
+    0xD3AB, // 13279
+    0xD3AD, // 13280
+    0xD3B4, // 13281
+    0xD3B8, // 13282
+    0xD3BC, // 13283
+    0xD3C4, // 13284
+    0xD3C5, // 13285
+    0xD3C8, // 13286
+    0xD3C9, // 13287
+    0xD3D0, // 13288
+    0xD3D8, // 13289
+    0xD3E1, // 13290
+    0xD3E3, // 13291
+    0xD3EC, // 13292
+    0xD3ED, // 13293
+    0xD3F0, // 13294
+    0xD3F4, // 13295
+    0xD3FC, // 13296
+    0xD3FD, // 13297
+    0xD3FF, // 13298
+    0xD401, // 13299
+    0x0000, // 13300
+    0x0000, // 13301
+    0x0000, // 13302
+    0x0000, // 13303
+    0x0000, // 13304
+    0x0000, // 13305
+    0x0000, // 13306
+    0x0000, // 13307
+    0x0000, // 13308
+    0x0000, // 13309
+    0x0000, // 13310
+    0x0000, // 13311
+    0x0000, // 13312
+    0x0000, // 13313
+    0x0000, // 13314
+    0x0000, // 13315
+    0x0000, // 13316
+    0x0000, // 13317
+    0x0000, // 13318
+    0x0000, // 13319
+    0x0000, // 13320
+    0x0000, // 13321
+    0x0000, // 13322
+    0x0000, // 13323
+    0x0000, // 13324
+    0x0000, // 13325
+    0x0000, // 13326
+    0x0000, // 13327
+    0x0000, // 13328
+    0x0000, // 13329
+    0x0000, // 13330
+    0x0000, // 13331
+    0x0000, // 13332
+    0x0000, // 13333
+    0x0000, // 13334
+    0x0000, // 13335
+    0x0000, // 13336
+    0x0000, // 13337
+    0x0000, // 13338
+    0x0000, // 13339
+    0x0000, // 13340
+    0x0000, // 13341
+    0x0000, // 13342
+    0x0000, // 13343
+    0x0000, // 13344
+    0x0000, // 13345
+    0x0000, // 13346
+    0x0000, // 13347
+    0x0000, // 13348
+    0x0000, // 13349
+    0x0000, // 13350
+    0x0000, // 13351
+    0x0000, // 13352
+    0x0000, // 13353
+    0x0000, // 13354
+    0x0000, // 13355
+    0x0000, // 13356
+    0x0000, // 13357
+    0x0000, // 13358
+    0x0000, // 13359
+    0x0000, // 13360
+    0x0000, // 13361
+    0x0000, // 13362
+    0x0000, // 13363
+    0x0000, // 13364
+    0x0000, // 13365
+    0x0000, // 13366
+    0x0000, // 13367
+    0x0000, // 13368
+    0x0000, // 13369
+    0x0000, // 13370
+    0x0000, // 13371
+    0x0000, // 13372
+    0x0000, // 13373
+    0x0000, // 13374
+    0x0000, // 13375
+    0x0000, // 13376
+    0x0000, // 13377
+    0x0000, // 13378
+    0x0000, // 13379
+    0x0000, // 13380
+    0x0000, // 13381
+    0x0000, // 13382
+    0x0000, // 13383
+    0x0000, // 13384
+    0x0000, // 13385
+    0x0000, // 13386
+    0x0000, // 13387
+    0x0000, // 13388
+    0x0000, // 13389
+    0x0000, // 13390
+    0x0000, // 13391
+    0x0000, // 13392
+    0x0000, // 13393
+    0x0000, // 13394
+    0x0000, // 13395
+    0xD408, // 13396
+    0xD41D, // 13397
+    0xD440, // 13398
+    0xD444, // 13399
+    0xD45C, // 13400
+    0xD460, // 13401
+    0xD464, // 13402
+    0xD46D, // 13403
+    0xD46F, // 13404
+    0xD478, // 13405
+    0xD479, // 13406
+    0xD47C, // 13407
+    0xD47F, // 13408
+    0xD480, // 13409
+    0xD482, // 13410
+    0xD488, // 13411
+    0xD489, // 13412
+    0xD48B, // 13413
+    0xD48D, // 13414
+    0xD494, // 13415
+    0xD4A9, // 13416
+    0xD4CC, // 13417
+    0xD4D0, // 13418
+    0xD4D4, // 13419
+    0xD4DC, // 13420
+    0xD4DF, // 13421
+    0xD4E8, // 13422
+    0xD4EC, // 13423
+    0xD4F0, // 13424
+    0xD4F8, // 13425
+    0xD4FB, // 13426
+    0xD4FD, // 13427
+    0xD504, // 13428
+    0xD508, // 13429
+    0xD50C, // 13430
+    0xD514, // 13431
+    0xD515, // 13432
+    0xD517, // 13433
+    0xD53C, // 13434
+    0xD53D, // 13435
+    0xD540, // 13436
+    0xD544, // 13437
+    0xD54C, // 13438
+    0xD54D, // 13439
+    0xD54F, // 13440
+    0xD551, // 13441
+    0xD558, // 13442
+    0xD559, // 13443
+    0xD55C, // 13444
+    0xD560, // 13445
+    0xD565, // 13446
+    0xD568, // 13447
+    0xD569, // 13448
+    0xD56B, // 13449
+    0xD56D, // 13450
+    0xD574, // 13451
+    0xD575, // 13452
+    0xD578, // 13453
+    0xD57C, // 13454
+    0xD584, // 13455
+    0xD585, // 13456
+    0xD587, // 13457
+    0xD588, // 13458
+    0xD589, // 13459
+    0xD590, // 13460
+    0xD5A5, // 13461
+    0xD5C8, // 13462
+    0xD5C9, // 13463
+    0xD5CC, // 13464
+    0xD5D0, // 13465
+    0xD5D2, // 13466
+    0xD5D8, // 13467
+    0xD5D9, // 13468
+    0xD5DB, // 13469
+    0xD5DD, // 13470
+    0xD5E4, // 13471
+    0xD5E5, // 13472
+    0xD5E8, // 13473
+    0xD5EC, // 13474
+    0xD5F4, // 13475
+    0xD5F5, // 13476
+    0xD5F7, // 13477
+    0xD5F9, // 13478
+    0xD600, // 13479
+    0xD601, // 13480
+    0xD604, // 13481
+    0xD608, // 13482
+    0xD610, // 13483
+    0xD611, // 13484
+    0xD613, // 13485
+    0xD614, // 13486
+    0xD615, // 13487
+    0xD61C, // 13488
+    0xD620, // 13489
+    0x0000, // 13490
+    0x0000, // 13491
+    0x0000, // 13492
+    0x0000, // 13493
+    0x0000, // 13494
+    0x0000, // 13495
+    0x0000, // 13496
+    0x0000, // 13497
+    0x0000, // 13498
+    0x0000, // 13499
+    0x0000, // 13500
+    0x0000, // 13501
+    0x0000, // 13502
+    0x0000, // 13503
+    0x0000, // 13504
+    0x0000, // 13505
+    0x0000, // 13506
+    0x0000, // 13507
+    0x0000, // 13508
+    0x0000, // 13509
+    0x0000, // 13510
+    0x0000, // 13511
+    0x0000, // 13512
+    0x0000, // 13513
+    0x0000, // 13514
+    0x0000, // 13515
+    0x0000, // 13516
+    0x0000, // 13517
+    0x0000, // 13518
+    0x0000, // 13519
+    0x0000, // 13520
+    0x0000, // 13521
+    0x0000, // 13522
+    0x0000, // 13523
+    0x0000, // 13524
+    0x0000, // 13525
+    0x0000, // 13526
+    0x0000, // 13527
+    0x0000, // 13528
+    0x0000, // 13529
+    0x0000, // 13530
+    0x0000, // 13531
+    0x0000, // 13532
+    0x0000, // 13533
+    0x0000, // 13534
+    0x0000, // 13535
+    0x0000, // 13536
+    0x0000, // 13537
+    0x0000, // 13538
+    0x0000, // 13539
+    0x0000, // 13540
+    0x0000, // 13541
+    0x0000, // 13542
+    0x0000, // 13543
+    0x0000, // 13544
+    0x0000, // 13545
+    0x0000, // 13546
+    0x0000, // 13547
+    0x0000, // 13548
+    0x0000, // 13549
+    0x0000, // 13550
+    0x0000, // 13551
+    0x0000, // 13552
+    0x0000, // 13553
+    0x0000, // 13554
+    0x0000, // 13555
+    0x0000, // 13556
+    0x0000, // 13557
+    0x0000, // 13558
+    0x0000, // 13559
+    0x0000, // 13560
+    0x0000, // 13561
+    0x0000, // 13562
+    0x0000, // 13563
+    0x0000, // 13564
+    0x0000, // 13565
+    0x0000, // 13566
+    0x0000, // 13567
+    0x0000, // 13568
+    0x0000, // 13569
+    0x0000, // 13570
+    0x0000, // 13571
+    0x0000, // 13572
+    0x0000, // 13573
+    0x0000, // 13574
+    0x0000, // 13575
+    0x0000, // 13576
+    0x0000, // 13577
+    0x0000, // 13578
+    0x0000, // 13579
+    0x0000, // 13580
+    0x0000, // 13581
+    0x0000, // 13582
+    0x0000, // 13583
+    0x0000, // 13584
+    0x0000, // 13585
+    0xD624, // 13586
+    0xD62D, // 13587
+    0xD638, // 13588
+    0xD639, // 13589
+    0xD63C, // 13590
+    0xD640, // 13591
+    0xD645, // 13592
+    0xD648, // 13593
+    0xD649, // 13594
+    0xD64B, // 13595
+    0xD64D, // 13596
+    0xD651, // 13597
+    0xD654, // 13598
+    0xD655, // 13599
+    0xD658, // 13600
+    0xD65C, // 13601
+    0xD667, // 13602
+    0xD669, // 13603
+    0xD670, // 13604
+    0xD671, // 13605
+    0xD674, // 13606
+    0xD683, // 13607
+    0xD685, // 13608
+    0xD68C, // 13609
+    0xD68D, // 13610
+    0xD690, // 13611
+    0xD694, // 13612
+    0xD69D, // 13613
+    0xD69F, // 13614
+    0xD6A1, // 13615
+    0xD6A8, // 13616
+    0xD6AC, // 13617
+    0xD6B0, // 13618
+    0xD6B9, // 13619
+    0xD6BB, // 13620
+    0xD6C4, // 13621
+    0xD6C5, // 13622
+    0xD6C8, // 13623
+    0xD6CC, // 13624
+    0xD6D1, // 13625
+    0xD6D4, // 13626
+    0xD6D7, // 13627
+    0xD6D9, // 13628
+    0xD6E0, // 13629
+    0xD6E4, // 13630
+    0xD6E8, // 13631
+    0xD6F0, // 13632
+    0xD6F5, // 13633
+    0xD6FC, // 13634
+    0xD6FD, // 13635
+    0xD700, // 13636
+    0xD704, // 13637
+    0xD711, // 13638
+    0xD718, // 13639
+    0xD719, // 13640
+    0xD71C, // 13641
+    0xD720, // 13642
+    0xD728, // 13643
+    0xD729, // 13644
+    0xD72B, // 13645
+    0xD72D, // 13646
+    0xD734, // 13647
+    0xD735, // 13648
+    0xD738, // 13649
+    0xD73C, // 13650
+    0xD744, // 13651
+    0xD747, // 13652
+    0xD749, // 13653
+    0xD750, // 13654
+    0xD751, // 13655
+    0xD754, // 13656
+    0xD756, // 13657
+    0xD757, // 13658
+    0xD758, // 13659
+    0xD759, // 13660
+    0xD760, // 13661
+    0xD761, // 13662
+    0xD763, // 13663
+    0xD765, // 13664
+    0xD769, // 13665
+    0xD76C, // 13666
+    0xD770, // 13667
+    0xD774, // 13668
+    0xD77C, // 13669
+    0xD77D, // 13670
+    0xD781, // 13671
+    0xD788, // 13672
+    0xD789, // 13673
+    0xD78C, // 13674
+    0xD790, // 13675
+    0xD798, // 13676
+    0xD799, // 13677
+    0xD79B, // 13678
+    0xD79D, // 13679
+    0x0000, // 13680
+    0x0000, // 13681
+    0x0000, // 13682
+    0x0000, // 13683
+    0x0000, // 13684
+    0x0000, // 13685
+    0x0000, // 13686
+    0x0000, // 13687
+    0x0000, // 13688
+    0x0000, // 13689
+    0x0000, // 13690
+    0x0000, // 13691
+    0x0000, // 13692
+    0x0000, // 13693
+    0x0000, // 13694
+    0x0000, // 13695
+    0x0000, // 13696
+    0x0000, // 13697
+    0x0000, // 13698
+    0x0000, // 13699
+    0x0000, // 13700
+    0x0000, // 13701
+    0x0000, // 13702
+    0x0000, // 13703
+    0x0000, // 13704
+    0x0000, // 13705
+    0x0000, // 13706
+    0x0000, // 13707
+    0x0000, // 13708
+    0x0000, // 13709
+    0x0000, // 13710
+    0x0000, // 13711
+    0x0000, // 13712
+    0x0000, // 13713
+    0x0000, // 13714
+    0x0000, // 13715
+    0x0000, // 13716
+    0x0000, // 13717
+    0x0000, // 13718
+    0x0000, // 13719
+    0x0000, // 13720
+    0x0000, // 13721
+    0x0000, // 13722
+    0x0000, // 13723
+    0x0000, // 13724
+    0x0000, // 13725
+    0x0000, // 13726
+    0x0000, // 13727
+    0x0000, // 13728
+    0x0000, // 13729
+    0x0000, // 13730
+    0x0000, // 13731
+    0x0000, // 13732
+    0x0000, // 13733
+    0x0000, // 13734
+    0x0000, // 13735
+    0x0000, // 13736
+    0x0000, // 13737
+    0x0000, // 13738
+    0x0000, // 13739
+    0x0000, // 13740
+    0x0000, // 13741
+    0x0000, // 13742
+    0x0000, // 13743
+    0x0000, // 13744
+    0x0000, // 13745
+    0x0000, // 13746
+    0x0000, // 13747
+    0x0000, // 13748
+    0x0000, // 13749
+    0x0000, // 13750
+    0x0000, // 13751
+    0x0000, // 13752
+    0x0000, // 13753
+    0x0000, // 13754
+    0x0000, // 13755
+    0x0000, // 13756
+    0x0000, // 13757
+    0x0000, // 13758
+    0x0000, // 13759
+    0x0000, // 13760
+    0x0000, // 13761
+    0x0000, // 13762
+    0x0000, // 13763
+    0x0000, // 13764
+    0x0000, // 13765
+    0x0000, // 13766
+    0x0000, // 13767
+    0x0000, // 13768
+    0x0000, // 13769
+    0x0000, // 13770
+    0x0000, // 13771
+    0x0000, // 13772
+    0x0000, // 13773
+    0x0000, // 13774
+    0x0000, // 13775
+    0x0000, // 13776
+    0x0000, // 13777
+    0x0000, // 13778
+    0x0000, // 13779
+    0x0000, // 13780
+    0x0000, // 13781
+    0x0000, // 13782
+    0x0000, // 13783
+    0x0000, // 13784
+    0x0000, // 13785
+    0x0000, // 13786
+    0x0000, // 13787
+    0x0000, // 13788
+    0x0000, // 13789
+    0x0000, // 13790
+    0x0000, // 13791
+    0x0000, // 13792
+    0x0000, // 13793
+    0x0000, // 13794
+    0x0000, // 13795
+    0x0000, // 13796
+    0x0000, // 13797
+    0x0000, // 13798
+    0x0000, // 13799
+    0x0000, // 13800
+    0x0000, // 13801
+    0x0000, // 13802
+    0x0000, // 13803
+    0x0000, // 13804
+    0x0000, // 13805
+    0x0000, // 13806
+    0x0000, // 13807
+    0x0000, // 13808
+    0x0000, // 13809
+    0x0000, // 13810
+    0x0000, // 13811
+    0x0000, // 13812
+    0x0000, // 13813
+    0x0000, // 13814
+    0x0000, // 13815
+    0x0000, // 13816
+    0x0000, // 13817
+    0x0000, // 13818
+    0x0000, // 13819
+    0x0000, // 13820
+    0x0000, // 13821
+    0x0000, // 13822
+    0x0000, // 13823
+    0x0000, // 13824
+    0x0000, // 13825
+    0x0000, // 13826
+    0x0000, // 13827
+    0x0000, // 13828
+    0x0000, // 13829
+    0x0000, // 13830
+    0x0000, // 13831
+    0x0000, // 13832
+    0x0000, // 13833
+    0x0000, // 13834
+    0x0000, // 13835
+    0x0000, // 13836
+    0x0000, // 13837
+    0x0000, // 13838
+    0x0000, // 13839
+    0x0000, // 13840
+    0x0000, // 13841
+    0x0000, // 13842
+    0x0000, // 13843
+    0x0000, // 13844
+    0x0000, // 13845
+    0x0000, // 13846
+    0x0000, // 13847
+    0x0000, // 13848
+    0x0000, // 13849
+    0x0000, // 13850
+    0x0000, // 13851
+    0x0000, // 13852
+    0x0000, // 13853
+    0x0000, // 13854
+    0x0000, // 13855
+    0x0000, // 13856
+    0x0000, // 13857
+    0x0000, // 13858
+    0x0000, // 13859
+    0x0000, // 13860
+    0x0000, // 13861
+    0x0000, // 13862
+    0x0000, // 13863
+    0x0000, // 13864
+    0x0000, // 13865
+    0x0000, // 13866
+    0x0000, // 13867
+    0x0000, // 13868
+    0x0000, // 13869
+    0x0000, // 13870
+    0x0000, // 13871
+    0x0000, // 13872
+    0x0000, // 13873
+    0x0000, // 13874
+    0x0000, // 13875
+    0x0000, // 13876
+    0x0000, // 13877
+    0x0000, // 13878
+    0x0000, // 13879
+    0x0000, // 13880
+    0x0000, // 13881
+    0x0000, // 13882
+    0x0000, // 13883
+    0x0000, // 13884
+    0x0000, // 13885
+    0x0000, // 13886
+    0x0000, // 13887
+    0x0000, // 13888
+    0x0000, // 13889
+    0x0000, // 13890
+    0x0000, // 13891
+    0x0000, // 13892
+    0x0000, // 13893
+    0x0000, // 13894
+    0x0000, // 13895
+    0x0000, // 13896
+    0x0000, // 13897
+    0x0000, // 13898
+    0x0000, // 13899
+    0x0000, // 13900
+    0x0000, // 13901
+    0x0000, // 13902
+    0x0000, // 13903
+    0x0000, // 13904
+    0x0000, // 13905
+    0x0000, // 13906
+    0x0000, // 13907
+    0x0000, // 13908
+    0x0000, // 13909
+    0x0000, // 13910
+    0x0000, // 13911
+    0x0000, // 13912
+    0x0000, // 13913
+    0x0000, // 13914
+    0x0000, // 13915
+    0x0000, // 13916
+    0x0000, // 13917
+    0x0000, // 13918
+    0x0000, // 13919
+    0x0000, // 13920
+    0x0000, // 13921
+    0x0000, // 13922
+    0x0000, // 13923
+    0x0000, // 13924
+    0x0000, // 13925
+    0x0000, // 13926
+    0x0000, // 13927
+    0x0000, // 13928
+    0x0000, // 13929
+    0x0000, // 13930
+    0x0000, // 13931
+    0x0000, // 13932
+    0x0000, // 13933
+    0x0000, // 13934
+    0x0000, // 13935
+    0x0000, // 13936
+    0x0000, // 13937
+    0x0000, // 13938
+    0x0000, // 13939
+    0x0000, // 13940
+    0x0000, // 13941
+    0x0000, // 13942
+    0x0000, // 13943
+    0x0000, // 13944
+    0x0000, // 13945
+    0x0000, // 13946
+    0x0000, // 13947
+    0x0000, // 13948
+    0x0000, // 13949
+    0x0000, // 13950
+    0x0000, // 13951
+    0x0000, // 13952
+    0x0000, // 13953
+    0x0000, // 13954
+    0x0000, // 13955
+    0x0000, // 13956
+    0x0000, // 13957
+    0x0000, // 13958
+    0x0000, // 13959
+    0x0000, // 13960
+    0x0000, // 13961
+    0x0000, // 13962
+    0x0000, // 13963
+    0x0000, // 13964
+    0x0000, // 13965
+    0x4F3D, // 13966
+    0x4F73, // 13967
+    0x5047, // 13968
+    0x50F9, // 13969
+    0x52A0, // 13970
+    0x53EF, // 13971
+    0x5475, // 13972
+    0x54E5, // 13973
+    0x5609, // 13974
+    0x5AC1, // 13975
+    0x5BB6, // 13976
+    0x6687, // 13977
+    0x67B6, // 13978
+    0x67B7, // 13979
+    0x67EF, // 13980
+    0x6B4C, // 13981
+    0x73C2, // 13982
+    0x75C2, // 13983
+    0x7A3C, // 13984
+    0x82DB, // 13985
+    0x8304, // 13986
+    0x8857, // 13987
+    0x8888, // 13988
+    0x8A36, // 13989
+    0x8CC8, // 13990
+    0x8DCF, // 13991
+    0x8EFB, // 13992
+    0x8FE6, // 13993
+    0x99D5, // 13994
+    0x523B, // 13995
+    0x5374, // 13996
+    0x5404, // 13997
+    0x606A, // 13998
+    0x6164, // 13999
+    0x6BBC, // 14000
+    0x73CF, // 14001
+    0x811A, // 14002
+    0x89BA, // 14003
+    0x89D2, // 14004
+    0x95A3, // 14005
+    0x4F83, // 14006
+    0x520A, // 14007
+    0x58BE, // 14008
+    0x5978, // 14009
+    0x59E6, // 14010
+    0x5E72, // 14011
+    0x5E79, // 14012
+    0x61C7, // 14013
+    0x63C0, // 14014
+    0x6746, // 14015
+    0x67EC, // 14016
+    0x687F, // 14017
+    0x6F97, // 14018
+    0x764E, // 14019
+    0x770B, // 14020
+    0x78F5, // 14021
+    0x7A08, // 14022
+    0x7AFF, // 14023
+    0x7C21, // 14024
+    0x809D, // 14025
+    0x826E, // 14026
+    0x8271, // 14027
+    0x8AEB, // 14028
+    0x9593, // 14029
+    0x4E6B, // 14030
+    0x559D, // 14031
+    0x66F7, // 14032
+    0x6E34, // 14033
+    0x78A3, // 14034
+    0x7AED, // 14035
+    0x845B, // 14036
+    0x8910, // 14037
+    0x874E, // 14038
+    0x97A8, // 14039
+    0x52D8, // 14040
+    0x574E, // 14041
+    0x582A, // 14042
+    0x5D4C, // 14043
+    0x611F, // 14044
+    0x61BE, // 14045
+    0x6221, // 14046
+    0x6562, // 14047
+    0x67D1, // 14048
+    0x6A44, // 14049
+    0x6E1B, // 14050
+    0x7518, // 14051
+    0x75B3, // 14052
+    0x76E3, // 14053
+    0x77B0, // 14054
+    0x7D3A, // 14055
+    0x90AF, // 14056
+    0x9451, // 14057
+    0x9452, // 14058
+    0x9F95, // 14059
+    0x0000, // 14060
+    0x0000, // 14061
+    0x0000, // 14062
+    0x0000, // 14063
+    0x0000, // 14064
+    0x0000, // 14065
+    0x0000, // 14066
+    0x0000, // 14067
+    0x0000, // 14068
+    0x0000, // 14069
+    0x0000, // 14070
+    0x0000, // 14071
+    0x0000, // 14072
+    0x0000, // 14073
+    0x0000, // 14074
+    0x0000, // 14075
+    0x0000, // 14076
+    0x0000, // 14077
+    0x0000, // 14078
+    0x0000, // 14079
+    0x0000, // 14080
+    0x0000, // 14081
+    0x0000, // 14082
+    0x0000, // 14083
+    0x0000, // 14084
+    0x0000, // 14085
+    0x0000, // 14086
+    0x0000, // 14087
+    0x0000, // 14088
+    0x0000, // 14089
+    0x0000, // 14090
+    0x0000, // 14091
+    0x0000, // 14092
+    0x0000, // 14093
+    0x0000, // 14094
+    0x0000, // 14095
+    0x0000, // 14096
+    0x0000, // 14097
+    0x0000, // 14098
+    0x0000, // 14099
+    0x0000, // 14100
+    0x0000, // 14101
+    0x0000, // 14102
+    0x0000, // 14103
+    0x0000, // 14104
+    0x0000, // 14105
+    0x0000, // 14106
+    0x0000, // 14107
+    0x0000, // 14108
+    0x0000, // 14109
+    0x0000, // 14110
+    0x0000, // 14111
+    0x0000, // 14112
+    0x0000, // 14113
+    0x0000, // 14114
+    0x0000, // 14115
+    0x0000, // 14116
+    0x0000, // 14117
+    0x0000, // 14118
+    0x0000, // 14119
+    0x0000, // 14120
+    0x0000, // 14121
+    0x0000, // 14122
+    0x0000, // 14123
+    0x0000, // 14124
+    0x0000, // 14125
+    0x0000, // 14126
+    0x0000, // 14127
+    0x0000, // 14128
+    0x0000, // 14129
+    0x0000, // 14130
+    0x0000, // 14131
+    0x0000, // 14132
+    0x0000, // 14133
+    0x0000, // 14134
+    0x0000, // 14135
+    0x0000, // 14136
+    0x0000, // 14137
+    0x0000, // 14138
+    0x0000, // 14139
+    0x0000, // 14140
+    0x0000, // 14141
+    0x0000, // 14142
+    0x0000, // 14143
+    0x0000, // 14144
+    0x0000, // 14145
+    0x0000, // 14146
+    0x0000, // 14147
+    0x0000, // 14148
+    0x0000, // 14149
+    0x0000, // 14150
+    0x0000, // 14151
+    0x0000, // 14152
+    0x0000, // 14153
+    0x0000, // 14154
+    0x0000, // 14155
+    0x5323, // 14156
+    0x5CAC, // 14157
+    0x7532, // 14158
+    0x80DB, // 14159
+    0x9240, // 14160
+    0x9598, // 14161
+    0x525B, // 14162
+    0x5808, // 14163
+    0x59DC, // 14164
+    0x5CA1, // 14165
+    0x5D17, // 14166
+    0x5EB7, // 14167
+    0x5F3A, // 14168
+    0x5F4A, // 14169
+    0x6177, // 14170
+    0x6C5F, // 14171
+    0x757A, // 14172
+    0x7586, // 14173
+    0x7CE0, // 14174
+    0x7D73, // 14175
+    0x7DB1, // 14176
+    0x7F8C, // 14177
+    0x8154, // 14178
+    0x8221, // 14179
+    0x8591, // 14180
+    0x8941, // 14181
+    0x8B1B, // 14182
+    0x92FC, // 14183
+    0x964D, // 14184
+    0x9C47, // 14185
+    0x4ECB, // 14186
+    0x4EF7, // 14187
+    0x500B, // 14188
+    0x51F1, // 14189
+    0x584F, // 14190
+    0x6137, // 14191
+    0x613E, // 14192
+    0x6168, // 14193
+    0x6539, // 14194
+    0x69EA, // 14195
+    0x6F11, // 14196
+    0x75A5, // 14197
+    0x7686, // 14198
+    0x76D6, // 14199
+    0x7B87, // 14200
+    0x82A5, // 14201
+    0x84CB, // 14202
+    0xF900, // 14203
+    0x93A7, // 14204
+    0x958B, // 14205
+    0x5580, // 14206
+    0x5BA2, // 14207
+    0x5751, // 14208
+    0xF901, // 14209
+    0x7CB3, // 14210
+    0x7FB9, // 14211
+    0x91B5, // 14212
+    0x5028, // 14213
+    0x53BB, // 14214
+    0x5C45, // 14215
+    0x5DE8, // 14216
+    0x62D2, // 14217
+    0x636E, // 14218
+    0x64DA, // 14219
+    0x64E7, // 14220
+    0x6E20, // 14221
+    0x70AC, // 14222
+    0x795B, // 14223
+    0x8DDD, // 14224
+    0x8E1E, // 14225
+    0xF902, // 14226
+    0x907D, // 14227
+    0x9245, // 14228
+    0x92F8, // 14229
+    0x4E7E, // 14230
+    0x4EF6, // 14231
+    0x5065, // 14232
+    0x5DFE, // 14233
+    0x5EFA, // 14234
+    0x6106, // 14235
+    0x6957, // 14236
+    0x8171, // 14237
+    0x8654, // 14238
+    0x8E47, // 14239
+    0x9375, // 14240
+    0x9A2B, // 14241
+    0x4E5E, // 14242
+    0x5091, // 14243
+    0x6770, // 14244
+    0x6840, // 14245
+    0x5109, // 14246
+    0x528D, // 14247
+    0x5292, // 14248
+    0x6AA2, // 14249
+    0x0000, // 14250
+    0x0000, // 14251
+    0x0000, // 14252
+    0x0000, // 14253
+    0x0000, // 14254
+    0x0000, // 14255
+    0x0000, // 14256
+    0x0000, // 14257
+    0x0000, // 14258
+    0x0000, // 14259
+    0x0000, // 14260
+    0x0000, // 14261
+    0x0000, // 14262
+    0x0000, // 14263
+    0x0000, // 14264
+    0x0000, // 14265
+    0x0000, // 14266
+    0x0000, // 14267
+    0x0000, // 14268
+    0x0000, // 14269
+    0x0000, // 14270
+    0x0000, // 14271
+    0x0000, // 14272
+    0x0000, // 14273
+    0x0000, // 14274
+    0x0000, // 14275
+    0x0000, // 14276
+    0x0000, // 14277
+    0x0000, // 14278
+    0x0000, // 14279
+    0x0000, // 14280
+    0x0000, // 14281
+    0x0000, // 14282
+    0x0000, // 14283
+    0x0000, // 14284
+    0x0000, // 14285
+    0x0000, // 14286
+    0x0000, // 14287
+    0x0000, // 14288
+    0x0000, // 14289
+    0x0000, // 14290
+    0x0000, // 14291
+    0x0000, // 14292
+    0x0000, // 14293
+    0x0000, // 14294
+    0x0000, // 14295
+    0x0000, // 14296
+    0x0000, // 14297
+    0x0000, // 14298
+    0x0000, // 14299
+    0x0000, // 14300
+    0x0000, // 14301
+    0x0000, // 14302
+    0x0000, // 14303
+    0x0000, // 14304
+    0x0000, // 14305
+    0x0000, // 14306
+    0x0000, // 14307
+    0x0000, // 14308
+    0x0000, // 14309
+    0x0000, // 14310
+    0x0000, // 14311
+    0x0000, // 14312
+    0x0000, // 14313
+    0x0000, // 14314
+    0x0000, // 14315
+    0x0000, // 14316
+    0x0000, // 14317
+    0x0000, // 14318
+    0x0000, // 14319
+    0x0000, // 14320
+    0x0000, // 14321
+    0x0000, // 14322
+    0x0000, // 14323
+    0x0000, // 14324
+    0x0000, // 14325
+    0x0000, // 14326
+    0x0000, // 14327
+    0x0000, // 14328
+    0x0000, // 14329
+    0x0000, // 14330
+    0x0000, // 14331
+    0x0000, // 14332
+    0x0000, // 14333
+    0x0000, // 14334
+    0x0000, // 14335
+    0x0000, // 14336
+    0x0000, // 14337
+    0x0000, // 14338
+    0x0000, // 14339
+    0x0000, // 14340
+    0x0000, // 14341
+    0x0000, // 14342
+    0x0000, // 14343
+    0x0000, // 14344
+    0x0000, // 14345
+    0x77BC, // 14346
+    0x9210, // 14347
+    0x9ED4, // 14348
+    0x52AB, // 14349
+    0x602F, // 14350
+    0x8FF2, // 14351
+    0x5048, // 14352
+    0x61A9, // 14353
+    0x63ED, // 14354
+    0x64CA, // 14355
+    0x683C, // 14356
+    0x6A84, // 14357
+    0x6FC0, // 14358
+    0x8188, // 14359
+    0x89A1, // 14360
+    0x9694, // 14361
+    0x5805, // 14362
+    0x727D, // 14363
+    0x72AC, // 14364
+    0x7504, // 14365
+    0x7D79, // 14366
+    0x7E6D, // 14367
+    0x80A9, // 14368
+    0x898B, // 14369
+    0x8B74, // 14370
+    0x9063, // 14371
+    0x9D51, // 14372
+    0x6289, // 14373
+    0x6C7A, // 14374
+    0x6F54, // 14375
+    0x7D50, // 14376
+    0x7F3A, // 14377
+    0x8A23, // 14378
+    0x517C, // 14379
+    0x614A, // 14380
+    0x7B9D, // 14381
+    0x8B19, // 14382
+    0x9257, // 14383
+    0x938C, // 14384
+    0x4EAC, // 14385
+    0x4FD3, // 14386
+    0x501E, // 14387
+    0x50BE, // 14388
+    0x5106, // 14389
+    0x52C1, // 14390
+    0x52CD, // 14391
+    0x537F, // 14392
+    0x5770, // 14393
+    0x5883, // 14394
+    0x5E9A, // 14395
+    0x5F91, // 14396
+    0x6176, // 14397
+    0x61AC, // 14398
+    0x64CE, // 14399
+    0x656C, // 14400
+    0x666F, // 14401
+    0x66BB, // 14402
+    0x66F4, // 14403
+    0x6897, // 14404
+    0x6D87, // 14405
+    0x7085, // 14406
+    0x70F1, // 14407
+    0x749F, // 14408
+    0x74A5, // 14409
+    0x74CA, // 14410
+    0x75D9, // 14411
+    0x786C, // 14412
+    0x78EC, // 14413
+    0x7ADF, // 14414
+    0x7AF6, // 14415
+    0x7D45, // 14416
+    0x7D93, // 14417
+    0x8015, // 14418
+    0x803F, // 14419
+    0x811B, // 14420
+    0x8396, // 14421
+    0x8B66, // 14422
+    0x8F15, // 14423
+    0x9015, // 14424
+    0x93E1, // 14425
+    0x9803, // 14426
+    0x9838, // 14427
+    0x9A5A, // 14428
+    0x9BE8, // 14429
+    0x4FC2, // 14430
+    0x5553, // 14431
+    0x583A, // 14432
+    0x5951, // 14433
+    0x5B63, // 14434
+    0x5C46, // 14435
+    0x60B8, // 14436
+    0x6212, // 14437
+    0x6842, // 14438
+    0x68B0, // 14439
+    0x0000, // 14440
+    0x0000, // 14441
+    0x0000, // 14442
+    0x0000, // 14443
+    0x0000, // 14444
+    0x0000, // 14445
+    0x0000, // 14446
+    0x0000, // 14447
+    0x0000, // 14448
+    0x0000, // 14449
+    0x0000, // 14450
+    0x0000, // 14451
+    0x0000, // 14452
+    0x0000, // 14453
+    0x0000, // 14454
+    0x0000, // 14455
+    0x0000, // 14456
+    0x0000, // 14457
+    0x0000, // 14458
+    0x0000, // 14459
+    0x0000, // 14460
+    0x0000, // 14461
+    0x0000, // 14462
+    0x0000, // 14463
+    0x0000, // 14464
+    0x0000, // 14465
+    0x0000, // 14466
+    0x0000, // 14467
+    0x0000, // 14468
+    0x0000, // 14469
+    0x0000, // 14470
+    0x0000, // 14471
+    0x0000, // 14472
+    0x0000, // 14473
+    0x0000, // 14474
+    0x0000, // 14475
+    0x0000, // 14476
+    0x0000, // 14477
+    0x0000, // 14478
+    0x0000, // 14479
+    0x0000, // 14480
+    0x0000, // 14481
+    0x0000, // 14482
+    0x0000, // 14483
+    0x0000, // 14484
+    0x0000, // 14485
+    0x0000, // 14486
+    0x0000, // 14487
+    0x0000, // 14488
+    0x0000, // 14489
+    0x0000, // 14490
+    0x0000, // 14491
+    0x0000, // 14492
+    0x0000, // 14493
+    0x0000, // 14494
+    0x0000, // 14495
+    0x0000, // 14496
+    0x0000, // 14497
+    0x0000, // 14498
+    0x0000, // 14499
+    0x0000, // 14500
+    0x0000, // 14501
+    0x0000, // 14502
+    0x0000, // 14503
+    0x0000, // 14504
+    0x0000, // 14505
+    0x0000, // 14506
+    0x0000, // 14507
+    0x0000, // 14508
+    0x0000, // 14509
+    0x0000, // 14510
+    0x0000, // 14511
+    0x0000, // 14512
+    0x0000, // 14513
+    0x0000, // 14514
+    0x0000, // 14515
+    0x0000, // 14516
+    0x0000, // 14517
+    0x0000, // 14518
+    0x0000, // 14519
+    0x0000, // 14520
+    0x0000, // 14521
+    0x0000, // 14522
+    0x0000, // 14523
+    0x0000, // 14524
+    0x0000, // 14525
+    0x0000, // 14526
+    0x0000, // 14527
+    0x0000, // 14528
+    0x0000, // 14529
+    0x0000, // 14530
+    0x0000, // 14531
+    0x0000, // 14532
+    0x0000, // 14533
+    0x0000, // 14534
+    0x0000, // 14535
+    0x68E8, // 14536
+    0x6EAA, // 14537
+    0x754C, // 14538
+    0x7678, // 14539
+    0x78CE, // 14540
+    0x7A3D, // 14541
+    0x7CFB, // 14542
+    0x7E6B, // 14543
+    0x7E7C, // 14544
+    0x8A08, // 14545
+    0x8AA1, // 14546
+    0x8C3F, // 14547
+    0x968E, // 14548
+    0x9DC4, // 14549
+    0x53E4, // 14550
+    0x53E9, // 14551
+    0x544A, // 14552
+    0x5471, // 14553
+    0x56FA, // 14554
+    0x59D1, // 14555
+    0x5B64, // 14556
+    0x5C3B, // 14557
+    0x5EAB, // 14558
+    0x62F7, // 14559
+    0x6537, // 14560
+    0x6545, // 14561
+    0x6572, // 14562
+    0x66A0, // 14563
+    0x67AF, // 14564
+    0x69C1, // 14565
+    0x6CBD, // 14566
+    0x75FC, // 14567
+    0x7690, // 14568
+    0x777E, // 14569
+    0x7A3F, // 14570
+    0x7F94, // 14571
+    0x8003, // 14572
+    0x80A1, // 14573
+    0x818F, // 14574
+    0x82E6, // 14575
+    0x82FD, // 14576
+    0x83F0, // 14577
+    0x85C1, // 14578
+    0x8831, // 14579
+    0x88B4, // 14580
+    0x8AA5, // 14581
+    0xF903, // 14582
+    0x8F9C, // 14583
+    0x932E, // 14584
+    0x96C7, // 14585
+    0x9867, // 14586
+    0x9AD8, // 14587
+    0x9F13, // 14588
+    0x54ED, // 14589
+    0x659B, // 14590
+    0x66F2, // 14591
+    0x688F, // 14592
+    0x7A40, // 14593
+    0x8C37, // 14594
+    0x9D60, // 14595
+    0x56F0, // 14596
+    0x5764, // 14597
+    0x5D11, // 14598
+    0x6606, // 14599
+    0x68B1, // 14600
+    0x68CD, // 14601
+    0x6EFE, // 14602
+    0x7428, // 14603
+    0x889E, // 14604
+    0x9BE4, // 14605
+    0x6C68, // 14606
+    0xF904, // 14607
+    0x9AA8, // 14608
+    0x4F9B, // 14609
+    0x516C, // 14610
+    0x5171, // 14611
+    0x529F, // 14612
+    0x5B54, // 14613
+    0x5DE5, // 14614
+    0x6050, // 14615
+    0x606D, // 14616
+    0x62F1, // 14617
+    0x63A7, // 14618
+    0x653B, // 14619
+    0x73D9, // 14620
+    0x7A7A, // 14621
+    0x86A3, // 14622
+    0x8CA2, // 14623
+    0x978F, // 14624
+    0x4E32, // 14625
+    0x5BE1, // 14626
+    0x6208, // 14627
+    0x679C, // 14628
+    0x74DC, // 14629
+    0x0000, // 14630
+    0x0000, // 14631
+    0x0000, // 14632
+    0x0000, // 14633
+    0x0000, // 14634
+    0x0000, // 14635
+    0x0000, // 14636
+    0x0000, // 14637
+    0x0000, // 14638
+    0x0000, // 14639
+    0x0000, // 14640
+    0x0000, // 14641
+    0x0000, // 14642
+    0x0000, // 14643
+    0x0000, // 14644
+    0x0000, // 14645
+    0x0000, // 14646
+    0x0000, // 14647
+    0x0000, // 14648
+    0x0000, // 14649
+    0x0000, // 14650
+    0x0000, // 14651
+    0x0000, // 14652
+    0x0000, // 14653
+    0x0000, // 14654
+    0x0000, // 14655
+    0x0000, // 14656
+    0x0000, // 14657
+    0x0000, // 14658
+    0x0000, // 14659
+    0x0000, // 14660
+    0x0000, // 14661
+    0x0000, // 14662
+    0x0000, // 14663
+    0x0000, // 14664
+    0x0000, // 14665
+    0x0000, // 14666
+    0x0000, // 14667
+    0x0000, // 14668
+    0x0000, // 14669
+    0x0000, // 14670
+    0x0000, // 14671
+    0x0000, // 14672
+    0x0000, // 14673
+    0x0000, // 14674
+    0x0000, // 14675
+    0x0000, // 14676
+    0x0000, // 14677
+    0x0000, // 14678
+    0x0000, // 14679
+    0x0000, // 14680
+    0x0000, // 14681
+    0x0000, // 14682
+    0x0000, // 14683
+    0x0000, // 14684
+    0x0000, // 14685
+    0x0000, // 14686
+    0x0000, // 14687
+    0x0000, // 14688
+    0x0000, // 14689
+    0x0000, // 14690
+    0x0000, // 14691
+    0x0000, // 14692
+    0x0000, // 14693
+    0x0000, // 14694
+    0x0000, // 14695
+    0x0000, // 14696
+    0x0000, // 14697
+    0x0000, // 14698
+    0x0000, // 14699
+    0x0000, // 14700
+    0x0000, // 14701
+    0x0000, // 14702
+    0x0000, // 14703
+    0x0000, // 14704
+    0x0000, // 14705
+    0x0000, // 14706
+    0x0000, // 14707
+    0x0000, // 14708
+    0x0000, // 14709
+    0x0000, // 14710
+    0x0000, // 14711
+    0x0000, // 14712
+    0x0000, // 14713
+    0x0000, // 14714
+    0x0000, // 14715
+    0x0000, // 14716
+    0x0000, // 14717
+    0x0000, // 14718
+    0x0000, // 14719
+    0x0000, // 14720
+    0x0000, // 14721
+    0x0000, // 14722
+    0x0000, // 14723
+    0x0000, // 14724
+    0x0000, // 14725
+    0x79D1, // 14726
+    0x83D3, // 14727
+    0x8A87, // 14728
+    0x8AB2, // 14729
+    0x8DE8, // 14730
+    0x904E, // 14731
+    0x934B, // 14732
+    0x9846, // 14733
+    0x5ED3, // 14734
+    0x69E8, // 14735
+    0x85FF, // 14736
+    0x90ED, // 14737
+    0xF905, // 14738
+    0x51A0, // 14739
+    0x5B98, // 14740
+    0x5BEC, // 14741
+    0x6163, // 14742
+    0x68FA, // 14743
+    0x6B3E, // 14744
+    0x704C, // 14745
+    0x742F, // 14746
+    0x74D8, // 14747
+    0x7BA1, // 14748
+    0x7F50, // 14749
+    0x83C5, // 14750
+    0x89C0, // 14751
+    0x8CAB, // 14752
+    0x95DC, // 14753
+    0x9928, // 14754
+    0x522E, // 14755
+    0x605D, // 14756
+    0x62EC, // 14757
+    0x9002, // 14758
+    0x4F8A, // 14759
+    0x5149, // 14760
+    0x5321, // 14761
+    0x58D9, // 14762
+    0x5EE3, // 14763
+    0x66E0, // 14764
+    0x6D38, // 14765
+    0x709A, // 14766
+    0x72C2, // 14767
+    0x73D6, // 14768
+    0x7B50, // 14769
+    0x80F1, // 14770
+    0x945B, // 14771
+    0x5366, // 14772
+    0x639B, // 14773
+    0x7F6B, // 14774
+    0x4E56, // 14775
+    0x5080, // 14776
+    0x584A, // 14777
+    0x58DE, // 14778
+    0x602A, // 14779
+    0x6127, // 14780
+    0x62D0, // 14781
+    0x69D0, // 14782
+    0x9B41, // 14783
+    0x5B8F, // 14784
+    0x7D18, // 14785
+    0x80B1, // 14786
+    0x8F5F, // 14787
+    0x4EA4, // 14788
+    0x50D1, // 14789
+    0x54AC, // 14790
+    0x55AC, // 14791
+    0x5B0C, // 14792
+    0x5DA0, // 14793
+    0x5DE7, // 14794
+    0x652A, // 14795
+    0x654E, // 14796
+    0x6821, // 14797
+    0x6A4B, // 14798
+    0x72E1, // 14799
+    0x768E, // 14800
+    0x77EF, // 14801
+    0x7D5E, // 14802
+    0x7FF9, // 14803
+    0x81A0, // 14804
+    0x854E, // 14805
+    0x86DF, // 14806
+    0x8F03, // 14807
+    0x8F4E, // 14808
+    0x90CA, // 14809
+    0x9903, // 14810
+    0x9A55, // 14811
+    0x9BAB, // 14812
+    0x4E18, // 14813
+    0x4E45, // 14814
+    0x4E5D, // 14815
+    0x4EC7, // 14816
+    0x4FF1, // 14817
+    0x5177, // 14818
+    0x52FE, // 14819
+    0x0000, // 14820
+    0x0000, // 14821
+    0x0000, // 14822
+    0x0000, // 14823
+    0x0000, // 14824
+    0x0000, // 14825
+    0x0000, // 14826
+    0x0000, // 14827
+    0x0000, // 14828
+    0x0000, // 14829
+    0x0000, // 14830
+    0x0000, // 14831
+    0x0000, // 14832
+    0x0000, // 14833
+    0x0000, // 14834
+    0x0000, // 14835
+    0x0000, // 14836
+    0x0000, // 14837
+    0x0000, // 14838
+    0x0000, // 14839
+    0x0000, // 14840
+    0x0000, // 14841
+    0x0000, // 14842
+    0x0000, // 14843
+    0x0000, // 14844
+    0x0000, // 14845
+    0x0000, // 14846
+    0x0000, // 14847
+    0x0000, // 14848
+    0x0000, // 14849
+    0x0000, // 14850
+    0x0000, // 14851
+    0x0000, // 14852
+    0x0000, // 14853
+    0x0000, // 14854
+    0x0000, // 14855
+    0x0000, // 14856
+    0x0000, // 14857
+    0x0000, // 14858
+    0x0000, // 14859
+    0x0000, // 14860
+    0x0000, // 14861
+    0x0000, // 14862
+    0x0000, // 14863
+    0x0000, // 14864
+    0x0000, // 14865
+    0x0000, // 14866
+    0x0000, // 14867
+    0x0000, // 14868
+    0x0000, // 14869
+    0x0000, // 14870
+    0x0000, // 14871
+    0x0000, // 14872
+    0x0000, // 14873
+    0x0000, // 14874
+    0x0000, // 14875
+    0x0000, // 14876
+    0x0000, // 14877
+    0x0000, // 14878
+    0x0000, // 14879
+    0x0000, // 14880
+    0x0000, // 14881
+    0x0000, // 14882
+    0x0000, // 14883
+    0x0000, // 14884
+    0x0000, // 14885
+    0x0000, // 14886
+    0x0000, // 14887
+    0x0000, // 14888
+    0x0000, // 14889
+    0x0000, // 14890
+    0x0000, // 14891
+    0x0000, // 14892
+    0x0000, // 14893
+    0x0000, // 14894
+    0x0000, // 14895
+    0x0000, // 14896
+    0x0000, // 14897
+    0x0000, // 14898
+    0x0000, // 14899
+    0x0000, // 14900
+    0x0000, // 14901
+    0x0000, // 14902
+    0x0000, // 14903
+    0x0000, // 14904
+    0x0000, // 14905
+    0x0000, // 14906
+    0x0000, // 14907
+    0x0000, // 14908
+    0x0000, // 14909
+    0x0000, // 14910
+    0x0000, // 14911
+    0x0000, // 14912
+    0x0000, // 14913
+    0x0000, // 14914
+    0x0000, // 14915
+    0x5340, // 14916
+    0x53E3, // 14917
+    0x53E5, // 14918
+    0x548E, // 14919
+    0x5614, // 14920
+    0x5775, // 14921
+    0x57A2, // 14922
+    0x5BC7, // 14923
+    0x5D87, // 14924
+    0x5ED0, // 14925
+    0x61FC, // 14926
+    0x62D8, // 14927
+    0x6551, // 14928
+    0x67B8, // 14929
+    0x67E9, // 14930
+    0x69CB, // 14931
+    0x6B50, // 14932
+    0x6BC6, // 14933
+    0x6BEC, // 14934
+    0x6C42, // 14935
+    0x6E9D, // 14936
+    0x7078, // 14937
+    0x72D7, // 14938
+    0x7396, // 14939
+    0x7403, // 14940
+    0x77BF, // 14941
+    0x77E9, // 14942
+    0x7A76, // 14943
+    0x7D7F, // 14944
+    0x8009, // 14945
+    0x81FC, // 14946
+    0x8205, // 14947
+    0x820A, // 14948
+    0x82DF, // 14949
+    0x8862, // 14950
+    0x8B33, // 14951
+    0x8CFC, // 14952
+    0x8EC0, // 14953
+    0x9011, // 14954
+    0x90B1, // 14955
+    0x9264, // 14956
+    0x92B6, // 14957
+    0x99D2, // 14958
+    0x9A45, // 14959
+    0x9CE9, // 14960
+    0x9DD7, // 14961
+    0x9F9C, // 14962
+    0x570B, // 14963
+    0x5C40, // 14964
+    0x83CA, // 14965
+    0x97A0, // 14966
+    0x97AB, // 14967
+    0x9EB4, // 14968
+    0x541B, // 14969
+    0x7A98, // 14970
+    0x7FA4, // 14971
+    0x88D9, // 14972
+    0x8ECD, // 14973
+    0x90E1, // 14974
+    0x5800, // 14975
+    0x5C48, // 14976
+    0x6398, // 14977
+    0x7A9F, // 14978
+    0x5BAE, // 14979
+    0x5F13, // 14980
+    0x7A79, // 14981
+    0x7AAE, // 14982
+    0x828E, // 14983
+    0x8EAC, // 14984
+    0x5026, // 14985
+    0x5238, // 14986
+    0x52F8, // 14987
+    0x5377, // 14988
+    0x5708, // 14989
+    0x62F3, // 14990
+    0x6372, // 14991
+    0x6B0A, // 14992
+    0x6DC3, // 14993
+    0x7737, // 14994
+    0x53A5, // 14995
+    0x7357, // 14996
+    0x8568, // 14997
+    0x8E76, // 14998
+    0x95D5, // 14999
+    0x673A, // 15000
+    0x6AC3, // 15001
+    0x6F70, // 15002
+    0x8A6D, // 15003
+    0x8ECC, // 15004
+    0x994B, // 15005
+    0xF906, // 15006
+    0x6677, // 15007
+    0x6B78, // 15008
+    0x8CB4, // 15009
+    0x0000, // 15010
+    0x0000, // 15011
+    0x0000, // 15012
+    0x0000, // 15013
+    0x0000, // 15014
+    0x0000, // 15015
+    0x0000, // 15016
+    0x0000, // 15017
+    0x0000, // 15018
+    0x0000, // 15019
+    0x0000, // 15020
+    0x0000, // 15021
+    0x0000, // 15022
+    0x0000, // 15023
+    0x0000, // 15024
+    0x0000, // 15025
+    0x0000, // 15026
+    0x0000, // 15027
+    0x0000, // 15028
+    0x0000, // 15029
+    0x0000, // 15030
+    0x0000, // 15031
+    0x0000, // 15032
+    0x0000, // 15033
+    0x0000, // 15034
+    0x0000, // 15035
+    0x0000, // 15036
+    0x0000, // 15037
+    0x0000, // 15038
+    0x0000, // 15039
+    0x0000, // 15040
+    0x0000, // 15041
+    0x0000, // 15042
+    0x0000, // 15043
+    0x0000, // 15044
+    0x0000, // 15045
+    0x0000, // 15046
+    0x0000, // 15047
+    0x0000, // 15048
+    0x0000, // 15049
+    0x0000, // 15050
+    0x0000, // 15051
+    0x0000, // 15052
+    0x0000, // 15053
+    0x0000, // 15054
+    0x0000, // 15055
+    0x0000, // 15056
+    0x0000, // 15057
+    0x0000, // 15058
+    0x0000, // 15059
+    0x0000, // 15060
+    0x0000, // 15061
+    0x0000, // 15062
+    0x0000, // 15063
+    0x0000, // 15064
+    0x0000, // 15065
+    0x0000, // 15066
+    0x0000, // 15067
+    0x0000, // 15068
+    0x0000, // 15069
+    0x0000, // 15070
+    0x0000, // 15071
+    0x0000, // 15072
+    0x0000, // 15073
+    0x0000, // 15074
+    0x0000, // 15075
+    0x0000, // 15076
+    0x0000, // 15077
+    0x0000, // 15078
+    0x0000, // 15079
+    0x0000, // 15080
+    0x0000, // 15081
+    0x0000, // 15082
+    0x0000, // 15083
+    0x0000, // 15084
+    0x0000, // 15085
+    0x0000, // 15086
+    0x0000, // 15087
+    0x0000, // 15088
+    0x0000, // 15089
+    0x0000, // 15090
+    0x0000, // 15091
+    0x0000, // 15092
+    0x0000, // 15093
+    0x0000, // 15094
+    0x0000, // 15095
+    0x0000, // 15096
+    0x0000, // 15097
+    0x0000, // 15098
+    0x0000, // 15099
+    0x0000, // 15100
+    0x0000, // 15101
+    0x0000, // 15102
+    0x0000, // 15103
+    0x0000, // 15104
+    0x0000, // 15105
+    0x9B3C, // 15106
+    0xF907, // 15107
+    0x53EB, // 15108
+    0x572D, // 15109
+    0x594E, // 15110
+    0x63C6, // 15111
+    0x69FB, // 15112
+    0x73EA, // 15113
+    0x7845, // 15114
+    0x7ABA, // 15115
+    0x7AC5, // 15116
+    0x7CFE, // 15117
+    0x8475, // 15118
+    0x898F, // 15119
+    0x8D73, // 15120
+    0x9035, // 15121
+    0x95A8, // 15122
+    0x52FB, // 15123
+    0x5747, // 15124
+    0x7547, // 15125
+    0x7B60, // 15126
+    0x83CC, // 15127
+    0x921E, // 15128
+    0xF908, // 15129
+    0x6A58, // 15130
+    0x514B, // 15131
+    0x524B, // 15132
+    0x5287, // 15133
+    0x621F, // 15134
+    0x68D8, // 15135
+    0x6975, // 15136
+    0x9699, // 15137
+    0x50C5, // 15138
+    0x52A4, // 15139
+    0x52E4, // 15140
+    0x61C3, // 15141
+    0x65A4, // 15142
+    0x6839, // 15143
+    0x69FF, // 15144
+    0x747E, // 15145
+    0x7B4B, // 15146
+    0x82B9, // 15147
+    0x83EB, // 15148
+    0x89B2, // 15149
+    0x8B39, // 15150
+    0x8FD1, // 15151
+    0x9949, // 15152
+    0xF909, // 15153
+    0x4ECA, // 15154
+    0x5997, // 15155
+    0x64D2, // 15156
+    0x6611, // 15157
+    0x6A8E, // 15158
+    0x7434, // 15159
+    0x7981, // 15160
+    0x79BD, // 15161
+    0x82A9, // 15162
+    0x887E, // 15163
+    0x887F, // 15164
+    0x895F, // 15165
+    0xF90A, // 15166
+    0x9326, // 15167
+    0x4F0B, // 15168
+    0x53CA, // 15169
+    0x6025, // 15170
+    0x6271, // 15171
+    0x6C72, // 15172
+    0x7D1A, // 15173
+    0x7D66, // 15174
+    0x4E98, // 15175
+    0x5162, // 15176
+    0x77DC, // 15177
+    0x80AF, // 15178
+    0x4F01, // 15179
+    0x4F0E, // 15180
+    0x5176, // 15181
+    0x5180, // 15182
+    0x55DC, // 15183
+    0x5668, // 15184
+    0x573B, // 15185
+    0x57FA, // 15186
+    0x57FC, // 15187
+    0x5914, // 15188
+    0x5947, // 15189
+    0x5993, // 15190
+    0x5BC4, // 15191
+    0x5C90, // 15192
+    0x5D0E, // 15193
+    0x5DF1, // 15194
+    0x5E7E, // 15195
+    0x5FCC, // 15196
+    0x6280, // 15197
+    0x65D7, // 15198
+    0x65E3, // 15199
+    0x0000, // 15200
+    0x0000, // 15201
+    0x0000, // 15202
+    0x0000, // 15203
+    0x0000, // 15204
+    0x0000, // 15205
+    0x0000, // 15206
+    0x0000, // 15207
+    0x0000, // 15208
+    0x0000, // 15209
+    0x0000, // 15210
+    0x0000, // 15211
+    0x0000, // 15212
+    0x0000, // 15213
+    0x0000, // 15214
+    0x0000, // 15215
+    0x0000, // 15216
+    0x0000, // 15217
+    0x0000, // 15218
+    0x0000, // 15219
+    0x0000, // 15220
+    0x0000, // 15221
+    0x0000, // 15222
+    0x0000, // 15223
+    0x0000, // 15224
+    0x0000, // 15225
+    0x0000, // 15226
+    0x0000, // 15227
+    0x0000, // 15228
+    0x0000, // 15229
+    0x0000, // 15230
+    0x0000, // 15231
+    0x0000, // 15232
+    0x0000, // 15233
+    0x0000, // 15234
+    0x0000, // 15235
+    0x0000, // 15236
+    0x0000, // 15237
+    0x0000, // 15238
+    0x0000, // 15239
+    0x0000, // 15240
+    0x0000, // 15241
+    0x0000, // 15242
+    0x0000, // 15243
+    0x0000, // 15244
+    0x0000, // 15245
+    0x0000, // 15246
+    0x0000, // 15247
+    0x0000, // 15248
+    0x0000, // 15249
+    0x0000, // 15250
+    0x0000, // 15251
+    0x0000, // 15252
+    0x0000, // 15253
+    0x0000, // 15254
+    0x0000, // 15255
+    0x0000, // 15256
+    0x0000, // 15257
+    0x0000, // 15258
+    0x0000, // 15259
+    0x0000, // 15260
+    0x0000, // 15261
+    0x0000, // 15262
+    0x0000, // 15263
+    0x0000, // 15264
+    0x0000, // 15265
+    0x0000, // 15266
+    0x0000, // 15267
+    0x0000, // 15268
+    0x0000, // 15269
+    0x0000, // 15270
+    0x0000, // 15271
+    0x0000, // 15272
+    0x0000, // 15273
+    0x0000, // 15274
+    0x0000, // 15275
+    0x0000, // 15276
+    0x0000, // 15277
+    0x0000, // 15278
+    0x0000, // 15279
+    0x0000, // 15280
+    0x0000, // 15281
+    0x0000, // 15282
+    0x0000, // 15283
+    0x0000, // 15284
+    0x0000, // 15285
+    0x0000, // 15286
+    0x0000, // 15287
+    0x0000, // 15288
+    0x0000, // 15289
+    0x0000, // 15290
+    0x0000, // 15291
+    0x0000, // 15292
+    0x0000, // 15293
+    0x0000, // 15294
+    0x0000, // 15295
+    0x671E, // 15296
+    0x671F, // 15297
+    0x675E, // 15298
+    0x68CB, // 15299
+    0x68C4, // 15300
+    0x6A5F, // 15301
+    0x6B3A, // 15302
+    0x6C23, // 15303
+    0x6C7D, // 15304
+    0x6C82, // 15305
+    0x6DC7, // 15306
+    0x7398, // 15307
+    0x7426, // 15308
+    0x742A, // 15309
+    0x7482, // 15310
+    0x74A3, // 15311
+    0x7578, // 15312
+    0x757F, // 15313
+    0x7881, // 15314
+    0x78EF, // 15315
+    0x7941, // 15316
+    0x7947, // 15317
+    0x7948, // 15318
+    0x797A, // 15319
+    0x7B95, // 15320
+    0x7D00, // 15321
+    0x7DBA, // 15322
+    0x7F88, // 15323
+    0x8006, // 15324
+    0x802D, // 15325
+    0x808C, // 15326
+    0x8A18, // 15327
+    0x8B4F, // 15328
+    0x8C48, // 15329
+    0x8D77, // 15330
+    0x9321, // 15331
+    0x9324, // 15332
+    0x98E2, // 15333
+    0x9951, // 15334
+    0x9A0E, // 15335
+    0x9A0F, // 15336
+    0x9A65, // 15337
+    0x9E92, // 15338
+    0x7DCA, // 15339
+    0x4F76, // 15340
+    0x5409, // 15341
+    0x62EE, // 15342
+    0x6854, // 15343
+    0x91D1, // 15344
+    0x55AB, // 15345
+    0x513A, // 15346
+    0xF90B, // 15347
+    0xF90C, // 15348
+    0x5A1C, // 15349
+    0x61E6, // 15350
+    0xF90D, // 15351
+    0x62CF, // 15352
+    0x62FF, // 15353
+    0xF90E, // 15354
+    0xF90F, // 15355
+    0xF910, // 15356
+    0xF911, // 15357
+    0xF912, // 15358
+    0xF913, // 15359
+    0x90A3, // 15360
+    0xF914, // 15361
+    0xF915, // 15362
+    0xF916, // 15363
+    0xF917, // 15364
+    0xF918, // 15365
+    0x8AFE, // 15366
+    0xF919, // 15367
+    0xF91A, // 15368
+    0xF91B, // 15369
+    0xF91C, // 15370
+    0x6696, // 15371
+    0xF91D, // 15372
+    0x7156, // 15373
+    0xF91E, // 15374
+    0xF91F, // 15375
+    0x96E3, // 15376
+    0xF920, // 15377
+    0x634F, // 15378
+    0x637A, // 15379
+    0x5357, // 15380
+    0xF921, // 15381
+    0x678F, // 15382
+    0x6960, // 15383
+    0x6E73, // 15384
+    0xF922, // 15385
+    0x7537, // 15386
+    0xF923, // 15387
+    0xF924, // 15388
+    0xF925, // 15389
+    0x0000, // 15390
+    0x0000, // 15391
+    0x0000, // 15392
+    0x0000, // 15393
+    0x0000, // 15394
+    0x0000, // 15395
+    0x0000, // 15396
+    0x0000, // 15397
+    0x0000, // 15398
+    0x0000, // 15399
+    0x0000, // 15400
+    0x0000, // 15401
+    0x0000, // 15402
+    0x0000, // 15403
+    0x0000, // 15404
+    0x0000, // 15405
+    0x0000, // 15406
+    0x0000, // 15407
+    0x0000, // 15408
+    0x0000, // 15409
+    0x0000, // 15410
+    0x0000, // 15411
+    0x0000, // 15412
+    0x0000, // 15413
+    0x0000, // 15414
+    0x0000, // 15415
+    0x0000, // 15416
+    0x0000, // 15417
+    0x0000, // 15418
+    0x0000, // 15419
+    0x0000, // 15420
+    0x0000, // 15421
+    0x0000, // 15422
+    0x0000, // 15423
+    0x0000, // 15424
+    0x0000, // 15425
+    0x0000, // 15426
+    0x0000, // 15427
+    0x0000, // 15428
+    0x0000, // 15429
+    0x0000, // 15430
+    0x0000, // 15431
+    0x0000, // 15432
+    0x0000, // 15433
+    0x0000, // 15434
+    0x0000, // 15435
+    0x0000, // 15436
+    0x0000, // 15437
+    0x0000, // 15438
+    0x0000, // 15439
+    0x0000, // 15440
+    0x0000, // 15441
+    0x0000, // 15442
+    0x0000, // 15443
+    0x0000, // 15444
+    0x0000, // 15445
+    0x0000, // 15446
+    0x0000, // 15447
+    0x0000, // 15448
+    0x0000, // 15449
+    0x0000, // 15450
+    0x0000, // 15451
+    0x0000, // 15452
+    0x0000, // 15453
+    0x0000, // 15454
+    0x0000, // 15455
+    0x0000, // 15456
+    0x0000, // 15457
+    0x0000, // 15458
+    0x0000, // 15459
+    0x0000, // 15460
+    0x0000, // 15461
+    0x0000, // 15462
+    0x0000, // 15463
+    0x0000, // 15464
+    0x0000, // 15465
+    0x0000, // 15466
+    0x0000, // 15467
+    0x0000, // 15468
+    0x0000, // 15469
+    0x0000, // 15470
+    0x0000, // 15471
+    0x0000, // 15472
+    0x0000, // 15473
+    0x0000, // 15474
+    0x0000, // 15475
+    0x0000, // 15476
+    0x0000, // 15477
+    0x0000, // 15478
+    0x0000, // 15479
+    0x0000, // 15480
+    0x0000, // 15481
+    0x0000, // 15482
+    0x0000, // 15483
+    0x0000, // 15484
+    0x0000, // 15485
+    0x7D0D, // 15486
+    0xF926, // 15487
+    0xF927, // 15488
+    0x8872, // 15489
+    0x56CA, // 15490
+    0x5A18, // 15491
+    0xF928, // 15492
+    0xF929, // 15493
+    0xF92A, // 15494
+    0xF92B, // 15495
+    0xF92C, // 15496
+    0x4E43, // 15497
+    0xF92D, // 15498
+    0x5167, // 15499
+    0x5948, // 15500
+    0x67F0, // 15501
+    0x8010, // 15502
+    0xF92E, // 15503
+    0x5973, // 15504
+    0x5E74, // 15505
+    0x649A, // 15506
+    0x79CA, // 15507
+    0x5FF5, // 15508
+    0x606C, // 15509
+    0x62C8, // 15510
+    0x637B, // 15511
+    0x5BE7, // 15512
+    0x5BD7, // 15513
+    0x52AA, // 15514
+    0xF92F, // 15515
+    0x5974, // 15516
+    0x5F29, // 15517
+    0x6012, // 15518
+    0xF930, // 15519
+    0xF931, // 15520
+    0xF932, // 15521
+    0x7459, // 15522
+    0xF933, // 15523
+    0xF934, // 15524
+    0xF935, // 15525
+    0xF936, // 15526
+    0xF937, // 15527
+    0xF938, // 15528
+    0x99D1, // 15529
+    0xF939, // 15530
+    0xF93A, // 15531
+    0xF93B, // 15532
+    0xF93C, // 15533
+    0xF93D, // 15534
+    0xF93E, // 15535
+    0xF93F, // 15536
+    0xF940, // 15537
+    0xF941, // 15538
+    0xF942, // 15539
+    0xF943, // 15540
+    0x6FC3, // 15541
+    0xF944, // 15542
+    0xF945, // 15543
+    0x81BF, // 15544
+    0x8FB2, // 15545
+    0x60F1, // 15546
+    0xF946, // 15547
+    0xF947, // 15548
+    0x8166, // 15549
+    0xF948, // 15550
+    0xF949, // 15551
+    0x5C3F, // 15552
+    0xF94A, // 15553
+    0xF94B, // 15554
+    0xF94C, // 15555
+    0xF94D, // 15556
+    0xF94E, // 15557
+    0xF94F, // 15558
+    0xF950, // 15559
+    0xF951, // 15560
+    0x5AE9, // 15561
+    0x8A25, // 15562
+    0x677B, // 15563
+    0x7D10, // 15564
+    0xF952, // 15565
+    0xF953, // 15566
+    0xF954, // 15567
+    0xF955, // 15568
+    0xF956, // 15569
+    0xF957, // 15570
+    0x80FD, // 15571
+    0xF958, // 15572
+    0xF959, // 15573
+    0x5C3C, // 15574
+    0x6CE5, // 15575
+    0x533F, // 15576
+    0x6EBA, // 15577
+    0x591A, // 15578
+    0x8336, // 15579
+    0x0000, // 15580
+    0x0000, // 15581
+    0x0000, // 15582
+    0x0000, // 15583
+    0x0000, // 15584
+    0x0000, // 15585
+    0x0000, // 15586
+    0x0000, // 15587
+    0x0000, // 15588
+    0x0000, // 15589
+    0x0000, // 15590
+    0x0000, // 15591
+    0x0000, // 15592
+    0x0000, // 15593
+    0x0000, // 15594
+    0x0000, // 15595
+    0x0000, // 15596
+    0x0000, // 15597
+    0x0000, // 15598
+    0x0000, // 15599
+    0x0000, // 15600
+    0x0000, // 15601
+    0x0000, // 15602
+    0x0000, // 15603
+    0x0000, // 15604
+    0x0000, // 15605
+    0x0000, // 15606
+    0x0000, // 15607
+    0x0000, // 15608
+    0x0000, // 15609
+    0x0000, // 15610
+    0x0000, // 15611
+    0x0000, // 15612
+    0x0000, // 15613
+    0x0000, // 15614
+    0x0000, // 15615
+    0x0000, // 15616
+    0x0000, // 15617
+    0x0000, // 15618
+    0x0000, // 15619
+    0x0000, // 15620
+    0x0000, // 15621
+    0x0000, // 15622
+    0x0000, // 15623
+    0x0000, // 15624
+    0x0000, // 15625
+    0x0000, // 15626
+    0x0000, // 15627
+    0x0000, // 15628
+    0x0000, // 15629
+    0x0000, // 15630
+    0x0000, // 15631
+    0x0000, // 15632
+    0x0000, // 15633
+    0x0000, // 15634
+    0x0000, // 15635
+    0x0000, // 15636
+    0x0000, // 15637
+    0x0000, // 15638
+    0x0000, // 15639
+    0x0000, // 15640
+    0x0000, // 15641
+    0x0000, // 15642
+    0x0000, // 15643
+    0x0000, // 15644
+    0x0000, // 15645
+    0x0000, // 15646
+    0x0000, // 15647
+    0x0000, // 15648
+    0x0000, // 15649
+    0x0000, // 15650
+    0x0000, // 15651
+    0x0000, // 15652
+    0x0000, // 15653
+    0x0000, // 15654
+    0x0000, // 15655
+    0x0000, // 15656
+    0x0000, // 15657
+    0x0000, // 15658
+    0x0000, // 15659
+    0x0000, // 15660
+    0x0000, // 15661
+    0x0000, // 15662
+    0x0000, // 15663
+    0x0000, // 15664
+    0x0000, // 15665
+    0x0000, // 15666
+    0x0000, // 15667
+    0x0000, // 15668
+    0x0000, // 15669
+    0x0000, // 15670
+    0x0000, // 15671
+    0x0000, // 15672
+    0x0000, // 15673
+    0x0000, // 15674
+    0x0000, // 15675
+    0x4E39, // 15676
+    0x4EB6, // 15677
+    0x4F46, // 15678
+    0x55AE, // 15679
+    0x5718, // 15680
+    0x58C7, // 15681
+    0x5F56, // 15682
+    0x65B7, // 15683
+    0x65E6, // 15684
+    0x6A80, // 15685
+    0x6BB5, // 15686
+    0x6E4D, // 15687
+    0x77ED, // 15688
+    0x7AEF, // 15689
+    0x7C1E, // 15690
+    0x7DDE, // 15691
+    0x86CB, // 15692
+    0x8892, // 15693
+    0x9132, // 15694
+    0x935B, // 15695
+    0x64BB, // 15696
+    0x6FBE, // 15697
+    0x737A, // 15698
+    0x75B8, // 15699
+    0x9054, // 15700
+    0x5556, // 15701
+    0x574D, // 15702
+    0x61BA, // 15703
+    0x64D4, // 15704
+    0x66C7, // 15705
+    0x6DE1, // 15706
+    0x6E5B, // 15707
+    0x6F6D, // 15708
+    0x6FB9, // 15709
+    0x75F0, // 15710
+    0x8043, // 15711
+    0x81BD, // 15712
+    0x8541, // 15713
+    0x8983, // 15714
+    0x8AC7, // 15715
+    0x8B5A, // 15716
+    0x931F, // 15717
+    0x6C93, // 15718
+    0x7553, // 15719
+    0x7B54, // 15720
+    0x8E0F, // 15721
+    0x905D, // 15722
+    0x5510, // 15723
+    0x5802, // 15724
+    0x5858, // 15725
+    0x5E62, // 15726
+    0x6207, // 15727
+    0x649E, // 15728
+    0x68E0, // 15729
+    0x7576, // 15730
+    0x7CD6, // 15731
+    0x87B3, // 15732
+    0x9EE8, // 15733
+    0x4EE3, // 15734
+    0x5788, // 15735
+    0x576E, // 15736
+    0x5927, // 15737
+    0x5C0D, // 15738
+    0x5CB1, // 15739
+    0x5E36, // 15740
+    0x5F85, // 15741
+    0x6234, // 15742
+    0x64E1, // 15743
+    0x73B3, // 15744
+    0x81FA, // 15745
+    0x888B, // 15746
+    0x8CB8, // 15747
+    0x968A, // 15748
+    0x9EDB, // 15749
+    0x5B85, // 15750
+    0x5FB7, // 15751
+    0x60B3, // 15752
+    0x5012, // 15753
+    0x5200, // 15754
+    0x5230, // 15755
+    0x5716, // 15756
+    0x5835, // 15757
+    0x5857, // 15758
+    0x5C0E, // 15759
+    0x5C60, // 15760
+    0x5CF6, // 15761
+    0x5D8B, // 15762
+    0x5EA6, // 15763
+    0x5F92, // 15764
+    0x60BC, // 15765
+    0x6311, // 15766
+    0x6389, // 15767
+    0x6417, // 15768
+    0x6843, // 15769
+    0x0000, // 15770
+    0x0000, // 15771
+    0x0000, // 15772
+    0x0000, // 15773
+    0x0000, // 15774
+    0x0000, // 15775
+    0x0000, // 15776
+    0x0000, // 15777
+    0x0000, // 15778
+    0x0000, // 15779
+    0x0000, // 15780
+    0x0000, // 15781
+    0x0000, // 15782
+    0x0000, // 15783
+    0x0000, // 15784
+    0x0000, // 15785
+    0x0000, // 15786
+    0x0000, // 15787
+    0x0000, // 15788
+    0x0000, // 15789
+    0x0000, // 15790
+    0x0000, // 15791
+    0x0000, // 15792
+    0x0000, // 15793
+    0x0000, // 15794
+    0x0000, // 15795
+    0x0000, // 15796
+    0x0000, // 15797
+    0x0000, // 15798
+    0x0000, // 15799
+    0x0000, // 15800
+    0x0000, // 15801
+    0x0000, // 15802
+    0x0000, // 15803
+    0x0000, // 15804
+    0x0000, // 15805
+    0x0000, // 15806
+    0x0000, // 15807
+    0x0000, // 15808
+    0x0000, // 15809
+    0x0000, // 15810
+    0x0000, // 15811
+    0x0000, // 15812
+    0x0000, // 15813
+    0x0000, // 15814
+    0x0000, // 15815
+    0x0000, // 15816
+    0x0000, // 15817
+    0x0000, // 15818
+    0x0000, // 15819
+    0x0000, // 15820
+    0x0000, // 15821
+    0x0000, // 15822
+    0x0000, // 15823
+    0x0000, // 15824
+    0x0000, // 15825
+    0x0000, // 15826
+    0x0000, // 15827
+    0x0000, // 15828
+    0x0000, // 15829
+    0x0000, // 15830
+    0x0000, // 15831
+    0x0000, // 15832
+    0x0000, // 15833
+    0x0000, // 15834
+    0x0000, // 15835
+    0x0000, // 15836
+    0x0000, // 15837
+    0x0000, // 15838
+    0x0000, // 15839
+    0x0000, // 15840
+    0x0000, // 15841
+    0x0000, // 15842
+    0x0000, // 15843
+    0x0000, // 15844
+    0x0000, // 15845
+    0x0000, // 15846
+    0x0000, // 15847
+    0x0000, // 15848
+    0x0000, // 15849
+    0x0000, // 15850
+    0x0000, // 15851
+    0x0000, // 15852
+    0x0000, // 15853
+    0x0000, // 15854
+    0x0000, // 15855
+    0x0000, // 15856
+    0x0000, // 15857
+    0x0000, // 15858
+    0x0000, // 15859
+    0x0000, // 15860
+    0x0000, // 15861
+    0x0000, // 15862
+    0x0000, // 15863
+    0x0000, // 15864
+    0x0000, // 15865
+    0x68F9, // 15866
+    0x6AC2, // 15867
+    0x6DD8, // 15868
+    0x6E21, // 15869
+    0x6ED4, // 15870
+    0x6FE4, // 15871
+    0x71FE, // 15872
+    0x76DC, // 15873
+    0x7779, // 15874
+    0x79B1, // 15875
+    0x7A3B, // 15876
+    0x8404, // 15877
+    0x89A9, // 15878
+    0x8CED, // 15879
+    0x8DF3, // 15880
+    0x8E48, // 15881
+    0x9003, // 15882
+    0x9014, // 15883
+    0x9053, // 15884
+    0x90FD, // 15885
+    0x934D, // 15886
+    0x9676, // 15887
+    0x97DC, // 15888
+    0x6BD2, // 15889
+    0x7006, // 15890
+    0x7258, // 15891
+    0x72A2, // 15892
+    0x7368, // 15893
+    0x7763, // 15894
+    0x79BF, // 15895
+    0x7BE4, // 15896
+    0x7E9B, // 15897
+    0x8B80, // 15898
+    0x58A9, // 15899
+    0x60C7, // 15900
+    0x6566, // 15901
+    0x65FD, // 15902
+    0x66BE, // 15903
+    0x6C8C, // 15904
+    0x711E, // 15905
+    0x71C9, // 15906
+    0x8C5A, // 15907
+    0x9813, // 15908
+    0x4E6D, // 15909
+    0x7A81, // 15910
+    0x4EDD, // 15911
+    0x51AC, // 15912
+    0x51CD, // 15913
+    0x52D5, // 15914
+    0x540C, // 15915
+    0x61A7, // 15916
+    0x6771, // 15917
+    0x6850, // 15918
+    0x68DF, // 15919
+    0x6D1E, // 15920
+    0x6F7C, // 15921
+    0x75BC, // 15922
+    0x77B3, // 15923
+    0x7AE5, // 15924
+    0x80F4, // 15925
+    0x8463, // 15926
+    0x9285, // 15927
+    0x515C, // 15928
+    0x6597, // 15929
+    0x675C, // 15930
+    0x6793, // 15931
+    0x75D8, // 15932
+    0x7AC7, // 15933
+    0x8373, // 15934
+    0xF95A, // 15935
+    0x8C46, // 15936
+    0x9017, // 15937
+    0x982D, // 15938
+    0x5C6F, // 15939
+    0x81C0, // 15940
+    0x829A, // 15941
+    0x9041, // 15942
+    0x906F, // 15943
+    0x920D, // 15944
+    0x5F97, // 15945
+    0x5D9D, // 15946
+    0x6A59, // 15947
+    0x71C8, // 15948
+    0x767B, // 15949
+    0x7B49, // 15950
+    0x85E4, // 15951
+    0x8B04, // 15952
+    0x9127, // 15953
+    0x9A30, // 15954
+    0x5587, // 15955
+    0x61F6, // 15956
+    0xF95B, // 15957
+    0x7669, // 15958
+    0x7F85, // 15959
+    0x0000, // 15960
+    0x0000, // 15961
+    0x0000, // 15962
+    0x0000, // 15963
+    0x0000, // 15964
+    0x0000, // 15965
+    0x0000, // 15966
+    0x0000, // 15967
+    0x0000, // 15968
+    0x0000, // 15969
+    0x0000, // 15970
+    0x0000, // 15971
+    0x0000, // 15972
+    0x0000, // 15973
+    0x0000, // 15974
+    0x0000, // 15975
+    0x0000, // 15976
+    0x0000, // 15977
+    0x0000, // 15978
+    0x0000, // 15979
+    0x0000, // 15980
+    0x0000, // 15981
+    0x0000, // 15982
+    0x0000, // 15983
+    0x0000, // 15984
+    0x0000, // 15985
+    0x0000, // 15986
+    0x0000, // 15987
+    0x0000, // 15988
+    0x0000, // 15989
+    0x0000, // 15990
+    0x0000, // 15991
+    0x0000, // 15992
+    0x0000, // 15993
+    0x0000, // 15994
+    0x0000, // 15995
+    0x0000, // 15996
+    0x0000, // 15997
+    0x0000, // 15998
+    0x0000, // 15999
+    0x0000, // 16000
+    0x0000, // 16001
+    0x0000, // 16002
+    0x0000, // 16003
+    0x0000, // 16004
+    0x0000, // 16005
+    0x0000, // 16006
+    0x0000, // 16007
+    0x0000, // 16008
+    0x0000, // 16009
+    0x0000, // 16010
+    0x0000, // 16011
+    0x0000, // 16012
+    0x0000, // 16013
+    0x0000, // 16014
+    0x0000, // 16015
+    0x0000, // 16016
+    0x0000, // 16017
+    0x0000, // 16018
+    0x0000, // 16019
+    0x0000, // 16020
+    0x0000, // 16021
+    0x0000, // 16022
+    0x0000, // 16023
+    0x0000, // 16024
+    0x0000, // 16025
+    0x0000, // 16026
+    0x0000, // 16027
+    0x0000, // 16028
+    0x0000, // 16029
+    0x0000, // 16030
+    0x0000, // 16031
+    0x0000, // 16032
+    0x0000, // 16033
+    0x0000, // 16034
+    0x0000, // 16035
+    0x0000, // 16036
+    0x0000, // 16037
+    0x0000, // 16038
+    0x0000, // 16039
+    0x0000, // 16040
+    0x0000, // 16041
+    0x0000, // 16042
+    0x0000, // 16043
+    0x0000, // 16044
+    0x0000, // 16045
+    0x0000, // 16046
+    0x0000, // 16047
+    0x0000, // 16048
+    0x0000, // 16049
+    0x0000, // 16050
+    0x0000, // 16051
+    0x0000, // 16052
+    0x0000, // 16053
+    0x0000, // 16054
+    0x0000, // 16055
+    0x863F, // 16056
+    0x87BA, // 16057
+    0x88F8, // 16058
+    0x908F, // 16059
+    0xF95C, // 16060
+    0x6D1B, // 16061
+    0x70D9, // 16062
+    0x73DE, // 16063
+    0x7D61, // 16064
+    0x843D, // 16065
+    0xF95D, // 16066
+    0x916A, // 16067
+    0x99F1, // 16068
+    0xF95E, // 16069
+    0x4E82, // 16070
+    0x5375, // 16071
+    0x6B04, // 16072
+    0x6B12, // 16073
+    0x703E, // 16074
+    0x721B, // 16075
+    0x862D, // 16076
+    0x9E1E, // 16077
+    0x524C, // 16078
+    0x8FA3, // 16079
+    0x5D50, // 16080
+    0x64E5, // 16081
+    0x652C, // 16082
+    0x6B16, // 16083
+    0x6FEB, // 16084
+    0x7C43, // 16085
+    0x7E9C, // 16086
+    0x85CD, // 16087
+    0x8964, // 16088
+    0x89BD, // 16089
+    0x62C9, // 16090
+    0x81D8, // 16091
+    0x881F, // 16092
+    0x5ECA, // 16093
+    0x6717, // 16094
+    0x6D6A, // 16095
+    0x72FC, // 16096
+    0x7405, // 16097
+    0x746F, // 16098
+    0x8782, // 16099
+    0x90DE, // 16100
+    0x4F86, // 16101
+    0x5D0D, // 16102
+    0x5FA0, // 16103
+    0x840A, // 16104
+    0x51B7, // 16105
+    0x63A0, // 16106
+    0x7565, // 16107
+    0x4EAE, // 16108
+    0x5006, // 16109
+    0x5169, // 16110
+    0x51C9, // 16111
+    0x6881, // 16112
+    0x6A11, // 16113
+    0x7CAE, // 16114
+    0x7CB1, // 16115
+    0x7CE7, // 16116
+    0x826F, // 16117
+    0x8AD2, // 16118
+    0x8F1B, // 16119
+    0x91CF, // 16120
+    0x4FB6, // 16121
+    0x5137, // 16122
+    0x52F5, // 16123
+    0x5442, // 16124
+    0x5EEC, // 16125
+    0x616E, // 16126
+    0x623E, // 16127
+    0x65C5, // 16128
+    0x6ADA, // 16129
+    0x6FFE, // 16130
+    0x792A, // 16131
+    0x85DC, // 16132
+    0x8823, // 16133
+    0x95AD, // 16134
+    0x9A62, // 16135
+    0x9A6A, // 16136
+    0x9E97, // 16137
+    0x9ECE, // 16138
+    0x529B, // 16139
+    0x66C6, // 16140
+    0x6B77, // 16141
+    0x701D, // 16142
+    0x792B, // 16143
+    0x8F62, // 16144
+    0x9742, // 16145
+    0x6190, // 16146
+    0x6200, // 16147
+    0x6523, // 16148
+    0x6F23, // 16149
+    0x0000, // 16150
+    0x0000, // 16151
+    0x0000, // 16152
+    0x0000, // 16153
+    0x0000, // 16154
+    0x0000, // 16155
+    0x0000, // 16156
+    0x0000, // 16157
+    0x0000, // 16158
+    0x0000, // 16159
+    0x0000, // 16160
+    0x0000, // 16161
+    0x0000, // 16162
+    0x0000, // 16163
+    0x0000, // 16164
+    0x0000, // 16165
+    0x0000, // 16166
+    0x0000, // 16167
+    0x0000, // 16168
+    0x0000, // 16169
+    0x0000, // 16170
+    0x0000, // 16171
+    0x0000, // 16172
+    0x0000, // 16173
+    0x0000, // 16174
+    0x0000, // 16175
+    0x0000, // 16176
+    0x0000, // 16177
+    0x0000, // 16178
+    0x0000, // 16179
+    0x0000, // 16180
+    0x0000, // 16181
+    0x0000, // 16182
+    0x0000, // 16183
+    0x0000, // 16184
+    0x0000, // 16185
+    0x0000, // 16186
+    0x0000, // 16187
+    0x0000, // 16188
+    0x0000, // 16189
+    0x0000, // 16190
+    0x0000, // 16191
+    0x0000, // 16192
+    0x0000, // 16193
+    0x0000, // 16194
+    0x0000, // 16195
+    0x0000, // 16196
+    0x0000, // 16197
+    0x0000, // 16198
+    0x0000, // 16199
+    0x0000, // 16200
+    0x0000, // 16201
+    0x0000, // 16202
+    0x0000, // 16203
+    0x0000, // 16204
+    0x0000, // 16205
+    0x0000, // 16206
+    0x0000, // 16207
+    0x0000, // 16208
+    0x0000, // 16209
+    0x0000, // 16210
+    0x0000, // 16211
+    0x0000, // 16212
+    0x0000, // 16213
+    0x0000, // 16214
+    0x0000, // 16215
+    0x0000, // 16216
+    0x0000, // 16217
+    0x0000, // 16218
+    0x0000, // 16219
+    0x0000, // 16220
+    0x0000, // 16221
+    0x0000, // 16222
+    0x0000, // 16223
+    0x0000, // 16224
+    0x0000, // 16225
+    0x0000, // 16226
+    0x0000, // 16227
+    0x0000, // 16228
+    0x0000, // 16229
+    0x0000, // 16230
+    0x0000, // 16231
+    0x0000, // 16232
+    0x0000, // 16233
+    0x0000, // 16234
+    0x0000, // 16235
+    0x0000, // 16236
+    0x0000, // 16237
+    0x0000, // 16238
+    0x0000, // 16239
+    0x0000, // 16240
+    0x0000, // 16241
+    0x0000, // 16242
+    0x0000, // 16243
+    0x0000, // 16244
+    0x0000, // 16245
+    0x7149, // 16246
+    0x7489, // 16247
+    0x7DF4, // 16248
+    0x806F, // 16249
+    0x84EE, // 16250
+    0x8F26, // 16251
+    0x9023, // 16252
+    0x934A, // 16253
+    0x51BD, // 16254
+    0x5217, // 16255
+    0x52A3, // 16256
+    0x6D0C, // 16257
+    0x70C8, // 16258
+    0x88C2, // 16259
+    0x5EC9, // 16260
+    0x6582, // 16261
+    0x6BAE, // 16262
+    0x6FC2, // 16263
+    0x7C3E, // 16264
+    0x7375, // 16265
+    0x4EE4, // 16266
+    0x4F36, // 16267
+    0x56F9, // 16268
+    0xF95F, // 16269
+    0x5CBA, // 16270
+    0x5DBA, // 16271
+    0x601C, // 16272
+    0x73B2, // 16273
+    0x7B2D, // 16274
+    0x7F9A, // 16275
+    0x7FCE, // 16276
+    0x8046, // 16277
+    0x901E, // 16278
+    0x9234, // 16279
+    0x96F6, // 16280
+    0x9748, // 16281
+    0x9818, // 16282
+    0x9F61, // 16283
+    0x4F8B, // 16284
+    0x6FA7, // 16285
+    0x79AE, // 16286
+    0x91B4, // 16287
+    0x96B7, // 16288
+    0x52DE, // 16289
+    0xF960, // 16290
+    0x6488, // 16291
+    0x64C4, // 16292
+    0x6AD3, // 16293
+    0x6F5E, // 16294
+    0x7018, // 16295
+    0x7210, // 16296
+    0x76E7, // 16297
+    0x8001, // 16298
+    0x8606, // 16299
+    0x865C, // 16300
+    0x8DEF, // 16301
+    0x8F05, // 16302
+    0x9732, // 16303
+    0x9B6F, // 16304
+    0x9DFA, // 16305
+    0x9E75, // 16306
+    0x788C, // 16307
+    0x797F, // 16308
+    0x7DA0, // 16309
+    0x83C9, // 16310
+    0x9304, // 16311
+    0x9E7F, // 16312
+    0x9E93, // 16313
+    0x8AD6, // 16314
+    0x58DF, // 16315
+    0x5F04, // 16316
+    0x6727, // 16317
+    0x7027, // 16318
+    0x74CF, // 16319
+    0x7C60, // 16320
+    0x807E, // 16321
+    0x5121, // 16322
+    0x7028, // 16323
+    0x7262, // 16324
+    0x78CA, // 16325
+    0x8CC2, // 16326
+    0x8CDA, // 16327
+    0x8CF4, // 16328
+    0x96F7, // 16329
+    0x4E86, // 16330
+    0x50DA, // 16331
+    0x5BEE, // 16332
+    0x5ED6, // 16333
+    0x6599, // 16334
+    0x71CE, // 16335
+    0x7642, // 16336
+    0x77AD, // 16337
+    0x804A, // 16338
+    0x84FC, // 16339
+    0x0000, // 16340
+    0x0000, // 16341
+    0x0000, // 16342
+    0x0000, // 16343
+    0x0000, // 16344
+    0x0000, // 16345
+    0x0000, // 16346
+    0x0000, // 16347
+    0x0000, // 16348
+    0x0000, // 16349
+    0x0000, // 16350
+    0x0000, // 16351
+    0x0000, // 16352
+    0x0000, // 16353
+    0x0000, // 16354
+    0x0000, // 16355
+    0x0000, // 16356
+    0x0000, // 16357
+    0x0000, // 16358
+    0x0000, // 16359
+    0x0000, // 16360
+    0x0000, // 16361
+    0x0000, // 16362
+    0x0000, // 16363
+    0x0000, // 16364
+    0x0000, // 16365
+    0x0000, // 16366
+    0x0000, // 16367
+    0x0000, // 16368
+    0x0000, // 16369
+    0x0000, // 16370
+    0x0000, // 16371
+    0x0000, // 16372
+    0x0000, // 16373
+    0x0000, // 16374
+    0x0000, // 16375
+    0x0000, // 16376
+    0x0000, // 16377
+    0x0000, // 16378
+    0x0000, // 16379
+    0x0000, // 16380
+    0x0000, // 16381
+    0x0000, // 16382
+    0x0000, // 16383
+    0x0000, // 16384
+    0x0000, // 16385
+    0x0000, // 16386
+    0x0000, // 16387
+    0x0000, // 16388
+    0x0000, // 16389
+    0x0000, // 16390
+    0x0000, // 16391
+    0x0000, // 16392
+    0x0000, // 16393
+    0x0000, // 16394
+    0x0000, // 16395
+    0x0000, // 16396
+    0x0000, // 16397
+    0x0000, // 16398
+    0x0000, // 16399
+    0x0000, // 16400
+    0x0000, // 16401
+    0x0000, // 16402
+    0x0000, // 16403
+    0x0000, // 16404
+    0x0000, // 16405
+    0x0000, // 16406
+    0x0000, // 16407
+    0x0000, // 16408
+    0x0000, // 16409
+    0x0000, // 16410
+    0x0000, // 16411
+    0x0000, // 16412
+    0x0000, // 16413
+    0x0000, // 16414
+    0x0000, // 16415
+    0x0000, // 16416
+    0x0000, // 16417
+    0x0000, // 16418
+    0x0000, // 16419
+    0x0000, // 16420
+    0x0000, // 16421
+    0x0000, // 16422
+    0x0000, // 16423
+    0x0000, // 16424
+    0x0000, // 16425
+    0x0000, // 16426
+    0x0000, // 16427
+    0x0000, // 16428
+    0x0000, // 16429
+    0x0000, // 16430
+    0x0000, // 16431
+    0x0000, // 16432
+    0x0000, // 16433
+    0x0000, // 16434
+    0x0000, // 16435
+    0x907C, // 16436
+    0x9B27, // 16437
+    0x9F8D, // 16438
+    0x58D8, // 16439
+    0x5A41, // 16440
+    0x5C62, // 16441
+    0x6A13, // 16442
+    0x6DDA, // 16443
+    0x6F0F, // 16444
+    0x763B, // 16445
+    0x7D2F, // 16446
+    0x7E37, // 16447
+    0x851E, // 16448
+    0x8938, // 16449
+    0x93E4, // 16450
+    0x964B, // 16451
+    0x5289, // 16452
+    0x65D2, // 16453
+    0x67F3, // 16454
+    0x69B4, // 16455
+    0x6D41, // 16456
+    0x6E9C, // 16457
+    0x700F, // 16458
+    0x7409, // 16459
+    0x7460, // 16460
+    0x7559, // 16461
+    0x7624, // 16462
+    0x786B, // 16463
+    0x8B2C, // 16464
+    0x985E, // 16465
+    0x516D, // 16466
+    0x622E, // 16467
+    0x9678, // 16468
+    0x4F96, // 16469
+    0x502B, // 16470
+    0x5D19, // 16471
+    0x6DEA, // 16472
+    0x7DB8, // 16473
+    0x8F2A, // 16474
+    0x5F8B, // 16475
+    0x6144, // 16476
+    0x6817, // 16477
+    0xF961, // 16478
+    0x9686, // 16479
+    0x52D2, // 16480
+    0x808B, // 16481
+    0x51DC, // 16482
+    0x51CC, // 16483
+    0x695E, // 16484
+    0x7A1C, // 16485
+    0x7DBE, // 16486
+    0x83F1, // 16487
+    0x9675, // 16488
+    0x4FDA, // 16489
+    0x5229, // 16490
+    0x5398, // 16491
+    0x540F, // 16492
+    0x550E, // 16493
+    0x5C65, // 16494
+    0x60A7, // 16495
+    0x674E, // 16496
+    0x68A8, // 16497
+    0x6D6C, // 16498
+    0x7281, // 16499
+    0x72F8, // 16500
+    0x7406, // 16501
+    0x7483, // 16502
+    0xF962, // 16503
+    0x75E2, // 16504
+    0x7C6C, // 16505
+    0x7F79, // 16506
+    0x7FB8, // 16507
+    0x8389, // 16508
+    0x88CF, // 16509
+    0x88E1, // 16510
+    0x91CC, // 16511
+    0x91D0, // 16512
+    0x96E2, // 16513
+    0x9BC9, // 16514
+    0x541D, // 16515
+    0x6F7E, // 16516
+    0x71D0, // 16517
+    0x7498, // 16518
+    0x85FA, // 16519
+    0x8EAA, // 16520
+    0x96A3, // 16521
+    0x9C57, // 16522
+    0x9E9F, // 16523
+    0x6797, // 16524
+    0x6DCB, // 16525
+    0x7433, // 16526
+    0x81E8, // 16527
+    0x9716, // 16528
+    0x782C, // 16529
+    0x0000, // 16530
+    0x0000, // 16531
+    0x0000, // 16532
+    0x0000, // 16533
+    0x0000, // 16534
+    0x0000, // 16535
+    0x0000, // 16536
+    0x0000, // 16537
+    0x0000, // 16538
+    0x0000, // 16539
+    0x0000, // 16540
+    0x0000, // 16541
+    0x0000, // 16542
+    0x0000, // 16543
+    0x0000, // 16544
+    0x0000, // 16545
+    0x0000, // 16546
+    0x0000, // 16547
+    0x0000, // 16548
+    0x0000, // 16549
+    0x0000, // 16550
+    0x0000, // 16551
+    0x0000, // 16552
+    0x0000, // 16553
+    0x0000, // 16554
+    0x0000, // 16555
+    0x0000, // 16556
+    0x0000, // 16557
+    0x0000, // 16558
+    0x0000, // 16559
+    0x0000, // 16560
+    0x0000, // 16561
+    0x0000, // 16562
+    0x0000, // 16563
+    0x0000, // 16564
+    0x0000, // 16565
+    0x0000, // 16566
+    0x0000, // 16567
+    0x0000, // 16568
+    0x0000, // 16569
+    0x0000, // 16570
+    0x0000, // 16571
+    0x0000, // 16572
+    0x0000, // 16573
+    0x0000, // 16574
+    0x0000, // 16575
+    0x0000, // 16576
+    0x0000, // 16577
+    0x0000, // 16578
+    0x0000, // 16579
+    0x0000, // 16580
+    0x0000, // 16581
+    0x0000, // 16582
+    0x0000, // 16583
+    0x0000, // 16584
+    0x0000, // 16585
+    0x0000, // 16586
+    0x0000, // 16587
+    0x0000, // 16588
+    0x0000, // 16589
+    0x0000, // 16590
+    0x0000, // 16591
+    0x0000, // 16592
+    0x0000, // 16593
+    0x0000, // 16594
+    0x0000, // 16595
+    0x0000, // 16596
+    0x0000, // 16597
+    0x0000, // 16598
+    0x0000, // 16599
+    0x0000, // 16600
+    0x0000, // 16601
+    0x0000, // 16602
+    0x0000, // 16603
+    0x0000, // 16604
+    0x0000, // 16605
+    0x0000, // 16606
+    0x0000, // 16607
+    0x0000, // 16608
+    0x0000, // 16609
+    0x0000, // 16610
+    0x0000, // 16611
+    0x0000, // 16612
+    0x0000, // 16613
+    0x0000, // 16614
+    0x0000, // 16615
+    0x0000, // 16616
+    0x0000, // 16617
+    0x0000, // 16618
+    0x0000, // 16619
+    0x0000, // 16620
+    0x0000, // 16621
+    0x0000, // 16622
+    0x0000, // 16623
+    0x0000, // 16624
+    0x0000, // 16625
+    0x7ACB, // 16626
+    0x7B20, // 16627
+    0x7C92, // 16628
+    0x6469, // 16629
+    0x746A, // 16630
+    0x75F2, // 16631
+    0x78BC, // 16632
+    0x78E8, // 16633
+    0x99AC, // 16634
+    0x9B54, // 16635
+    0x9EBB, // 16636
+    0x5BDE, // 16637
+    0x5E55, // 16638
+    0x6F20, // 16639
+    0x819C, // 16640
+    0x83AB, // 16641
+    0x9088, // 16642
+    0x4E07, // 16643
+    0x534D, // 16644
+    0x5A29, // 16645
+    0x5DD2, // 16646
+    0x5F4E, // 16647
+    0x6162, // 16648
+    0x633D, // 16649
+    0x6669, // 16650
+    0x66FC, // 16651
+    0x6EFF, // 16652
+    0x6F2B, // 16653
+    0x7063, // 16654
+    0x779E, // 16655
+    0x842C, // 16656
+    0x8513, // 16657
+    0x883B, // 16658
+    0x8F13, // 16659
+    0x9945, // 16660
+    0x9C3B, // 16661
+    0x551C, // 16662
+    0x62B9, // 16663
+    0x672B, // 16664
+    0x6CAB, // 16665
+    0x8309, // 16666
+    0x896A, // 16667
+    0x977A, // 16668
+    0x4EA1, // 16669
+    0x5984, // 16670
+    0x5FD8, // 16671
+    0x5FD9, // 16672
+    0x671B, // 16673
+    0x7DB2, // 16674
+    0x7F54, // 16675
+    0x8292, // 16676
+    0x832B, // 16677
+    0x83BD, // 16678
+    0x8F1E, // 16679
+    0x9099, // 16680
+    0x57CB, // 16681
+    0x59B9, // 16682
+    0x5A92, // 16683
+    0x5BD0, // 16684
+    0x6627, // 16685
+    0x679A, // 16686
+    0x6885, // 16687
+    0x6BCF, // 16688
+    0x7164, // 16689
+    0x7F75, // 16690
+    0x8CB7, // 16691
+    0x8CE3, // 16692
+    0x9081, // 16693
+    0x9B45, // 16694
+    0x8108, // 16695
+    0x8C8A, // 16696
+    0x964C, // 16697
+    0x9A40, // 16698
+    0x9EA5, // 16699
+    0x5B5F, // 16700
+    0x6C13, // 16701
+    0x731B, // 16702
+    0x76F2, // 16703
+    0x76DF, // 16704
+    0x840C, // 16705
+    0x51AA, // 16706
+    0x8993, // 16707
+    0x514D, // 16708
+    0x5195, // 16709
+    0x52C9, // 16710
+    0x68C9, // 16711
+    0x6C94, // 16712
+    0x7704, // 16713
+    0x7720, // 16714
+    0x7DBF, // 16715
+    0x7DEC, // 16716
+    0x9762, // 16717
+    0x9EB5, // 16718
+    0x6EC5, // 16719
+    0x0000, // 16720
+    0x0000, // 16721
+    0x0000, // 16722
+    0x0000, // 16723
+    0x0000, // 16724
+    0x0000, // 16725
+    0x0000, // 16726
+    0x0000, // 16727
+    0x0000, // 16728
+    0x0000, // 16729
+    0x0000, // 16730
+    0x0000, // 16731
+    0x0000, // 16732
+    0x0000, // 16733
+    0x0000, // 16734
+    0x0000, // 16735
+    0x0000, // 16736
+    0x0000, // 16737
+    0x0000, // 16738
+    0x0000, // 16739
+    0x0000, // 16740
+    0x0000, // 16741
+    0x0000, // 16742
+    0x0000, // 16743
+    0x0000, // 16744
+    0x0000, // 16745
+    0x0000, // 16746
+    0x0000, // 16747
+    0x0000, // 16748
+    0x0000, // 16749
+    0x0000, // 16750
+    0x0000, // 16751
+    0x0000, // 16752
+    0x0000, // 16753
+    0x0000, // 16754
+    0x0000, // 16755
+    0x0000, // 16756
+    0x0000, // 16757
+    0x0000, // 16758
+    0x0000, // 16759
+    0x0000, // 16760
+    0x0000, // 16761
+    0x0000, // 16762
+    0x0000, // 16763
+    0x0000, // 16764
+    0x0000, // 16765
+    0x0000, // 16766
+    0x0000, // 16767
+    0x0000, // 16768
+    0x0000, // 16769
+    0x0000, // 16770
+    0x0000, // 16771
+    0x0000, // 16772
+    0x0000, // 16773
+    0x0000, // 16774
+    0x0000, // 16775
+    0x0000, // 16776
+    0x0000, // 16777
+    0x0000, // 16778
+    0x0000, // 16779
+    0x0000, // 16780
+    0x0000, // 16781
+    0x0000, // 16782
+    0x0000, // 16783
+    0x0000, // 16784
+    0x0000, // 16785
+    0x0000, // 16786
+    0x0000, // 16787
+    0x0000, // 16788
+    0x0000, // 16789
+    0x0000, // 16790
+    0x0000, // 16791
+    0x0000, // 16792
+    0x0000, // 16793
+    0x0000, // 16794
+    0x0000, // 16795
+    0x0000, // 16796
+    0x0000, // 16797
+    0x0000, // 16798
+    0x0000, // 16799
+    0x0000, // 16800
+    0x0000, // 16801
+    0x0000, // 16802
+    0x0000, // 16803
+    0x0000, // 16804
+    0x0000, // 16805
+    0x0000, // 16806
+    0x0000, // 16807
+    0x0000, // 16808
+    0x0000, // 16809
+    0x0000, // 16810
+    0x0000, // 16811
+    0x0000, // 16812
+    0x0000, // 16813
+    0x0000, // 16814
+    0x0000, // 16815
+    0x8511, // 16816
+    0x51A5, // 16817
+    0x540D, // 16818
+    0x547D, // 16819
+    0x660E, // 16820
+    0x669D, // 16821
+    0x6927, // 16822
+    0x6E9F, // 16823
+    0x76BF, // 16824
+    0x7791, // 16825
+    0x8317, // 16826
+    0x84C2, // 16827
+    0x879F, // 16828
+    0x9169, // 16829
+    0x9298, // 16830
+    0x9CF4, // 16831
+    0x8882, // 16832
+    0x4FAE, // 16833
+    0x5192, // 16834
+    0x52DF, // 16835
+    0x59C6, // 16836
+    0x5E3D, // 16837
+    0x6155, // 16838
+    0x6478, // 16839
+    0x6479, // 16840
+    0x66AE, // 16841
+    0x67D0, // 16842
+    0x6A21, // 16843
+    0x6BCD, // 16844
+    0x6BDB, // 16845
+    0x725F, // 16846
+    0x7261, // 16847
+    0x7441, // 16848
+    0x7738, // 16849
+    0x77DB, // 16850
+    0x8017, // 16851
+    0x82BC, // 16852
+    0x8305, // 16853
+    0x8B00, // 16854
+    0x8B28, // 16855
+    0x8C8C, // 16856
+    0x6728, // 16857
+    0x6C90, // 16858
+    0x7267, // 16859
+    0x76EE, // 16860
+    0x7766, // 16861
+    0x7A46, // 16862
+    0x9DA9, // 16863
+    0x6B7F, // 16864
+    0x6C92, // 16865
+    0x5922, // 16866
+    0x6726, // 16867
+    0x8499, // 16868
+    0x536F, // 16869
+    0x5893, // 16870
+    0x5999, // 16871
+    0x5EDF, // 16872
+    0x63CF, // 16873
+    0x6634, // 16874
+    0x6773, // 16875
+    0x6E3A, // 16876
+    0x732B, // 16877
+    0x7AD7, // 16878
+    0x82D7, // 16879
+    0x9328, // 16880
+    0x52D9, // 16881
+    0x5DEB, // 16882
+    0x61AE, // 16883
+    0x61CB, // 16884
+    0x620A, // 16885
+    0x62C7, // 16886
+    0x64AB, // 16887
+    0x65E0, // 16888
+    0x6959, // 16889
+    0x6B66, // 16890
+    0x6BCB, // 16891
+    0x7121, // 16892
+    0x73F7, // 16893
+    0x755D, // 16894
+    0x7E46, // 16895
+    0x821E, // 16896
+    0x8302, // 16897
+    0x856A, // 16898
+    0x8AA3, // 16899
+    0x8CBF, // 16900
+    0x9727, // 16901
+    0x9D61, // 16902
+    0x58A8, // 16903
+    0x9ED8, // 16904
+    0x5011, // 16905
+    0x520E, // 16906
+    0x543B, // 16907
+    0x554F, // 16908
+    0x6587, // 16909
+    0x0000, // 16910
+    0x0000, // 16911
+    0x0000, // 16912
+    0x0000, // 16913
+    0x0000, // 16914
+    0x0000, // 16915
+    0x0000, // 16916
+    0x0000, // 16917
+    0x0000, // 16918
+    0x0000, // 16919
+    0x0000, // 16920
+    0x0000, // 16921
+    0x0000, // 16922
+    0x0000, // 16923
+    0x0000, // 16924
+    0x0000, // 16925
+    0x0000, // 16926
+    0x0000, // 16927
+    0x0000, // 16928
+    0x0000, // 16929
+    0x0000, // 16930
+    0x0000, // 16931
+    0x0000, // 16932
+    0x0000, // 16933
+    0x0000, // 16934
+    0x0000, // 16935
+    0x0000, // 16936
+    0x0000, // 16937
+    0x0000, // 16938
+    0x0000, // 16939
+    0x0000, // 16940
+    0x0000, // 16941
+    0x0000, // 16942
+    0x0000, // 16943
+    0x0000, // 16944
+    0x0000, // 16945
+    0x0000, // 16946
+    0x0000, // 16947
+    0x0000, // 16948
+    0x0000, // 16949
+    0x0000, // 16950
+    0x0000, // 16951
+    0x0000, // 16952
+    0x0000, // 16953
+    0x0000, // 16954
+    0x0000, // 16955
+    0x0000, // 16956
+    0x0000, // 16957
+    0x0000, // 16958
+    0x0000, // 16959
+    0x0000, // 16960
+    0x0000, // 16961
+    0x0000, // 16962
+    0x0000, // 16963
+    0x0000, // 16964
+    0x0000, // 16965
+    0x0000, // 16966
+    0x0000, // 16967
+    0x0000, // 16968
+    0x0000, // 16969
+    0x0000, // 16970
+    0x0000, // 16971
+    0x0000, // 16972
+    0x0000, // 16973
+    0x0000, // 16974
+    0x0000, // 16975
+    0x0000, // 16976
+    0x0000, // 16977
+    0x0000, // 16978
+    0x0000, // 16979
+    0x0000, // 16980
+    0x0000, // 16981
+    0x0000, // 16982
+    0x0000, // 16983
+    0x0000, // 16984
+    0x0000, // 16985
+    0x0000, // 16986
+    0x0000, // 16987
+    0x0000, // 16988
+    0x0000, // 16989
+    0x0000, // 16990
+    0x0000, // 16991
+    0x0000, // 16992
+    0x0000, // 16993
+    0x0000, // 16994
+    0x0000, // 16995
+    0x0000, // 16996
+    0x0000, // 16997
+    0x0000, // 16998
+    0x0000, // 16999
+    0x0000, // 17000
+    0x0000, // 17001
+    0x0000, // 17002
+    0x0000, // 17003
+    0x0000, // 17004
+    0x0000, // 17005
+    0x6C76, // 17006
+    0x7D0A, // 17007
+    0x7D0B, // 17008
+    0x805E, // 17009
+    0x868A, // 17010
+    0x9580, // 17011
+    0x96EF, // 17012
+    0x52FF, // 17013
+    0x6C95, // 17014
+    0x7269, // 17015
+    0x5473, // 17016
+    0x5A9A, // 17017
+    0x5C3E, // 17018
+    0x5D4B, // 17019
+    0x5F4C, // 17020
+    0x5FAE, // 17021
+    0x672A, // 17022
+    0x68B6, // 17023
+    0x6963, // 17024
+    0x6E3C, // 17025
+    0x6E44, // 17026
+    0x7709, // 17027
+    0x7C73, // 17028
+    0x7F8E, // 17029
+    0x8587, // 17030
+    0x8B0E, // 17031
+    0x8FF7, // 17032
+    0x9761, // 17033
+    0x9EF4, // 17034
+    0x5CB7, // 17035
+    0x60B6, // 17036
+    0x610D, // 17037
+    0x61AB, // 17038
+    0x654F, // 17039
+    0x65FB, // 17040
+    0x65FC, // 17041
+    0x6C11, // 17042
+    0x6CEF, // 17043
+    0x739F, // 17044
+    0x73C9, // 17045
+    0x7DE1, // 17046
+    0x9594, // 17047
+    0x5BC6, // 17048
+    0x871C, // 17049
+    0x8B10, // 17050
+    0x525D, // 17051
+    0x535A, // 17052
+    0x62CD, // 17053
+    0x640F, // 17054
+    0x64B2, // 17055
+    0x6734, // 17056
+    0x6A38, // 17057
+    0x6CCA, // 17058
+    0x73C0, // 17059
+    0x749E, // 17060
+    0x7B94, // 17061
+    0x7C95, // 17062
+    0x7E1B, // 17063
+    0x818A, // 17064
+    0x8236, // 17065
+    0x8584, // 17066
+    0x8FEB, // 17067
+    0x96F9, // 17068
+    0x99C1, // 17069
+    0x4F34, // 17070
+    0x534A, // 17071
+    0x53CD, // 17072
+    0x53DB, // 17073
+    0x62CC, // 17074
+    0x642C, // 17075
+    0x6500, // 17076
+    0x6591, // 17077
+    0x69C3, // 17078
+    0x6CEE, // 17079
+    0x6F58, // 17080
+    0x73ED, // 17081
+    0x7554, // 17082
+    0x7622, // 17083
+    0x76E4, // 17084
+    0x76FC, // 17085
+    0x78D0, // 17086
+    0x78FB, // 17087
+    0x792C, // 17088
+    0x7D46, // 17089
+    0x822C, // 17090
+    0x87E0, // 17091
+    0x8FD4, // 17092
+    0x9812, // 17093
+    0x98EF, // 17094
+    0x52C3, // 17095
+    0x62D4, // 17096
+    0x64A5, // 17097
+    0x6E24, // 17098
+    0x6F51, // 17099
+    0x0000, // 17100
+    0x0000, // 17101
+    0x0000, // 17102
+    0x0000, // 17103
+    0x0000, // 17104
+    0x0000, // 17105
+    0x0000, // 17106
+    0x0000, // 17107
+    0x0000, // 17108
+    0x0000, // 17109
+    0x0000, // 17110
+    0x0000, // 17111
+    0x0000, // 17112
+    0x0000, // 17113
+    0x0000, // 17114
+    0x0000, // 17115
+    0x0000, // 17116
+    0x0000, // 17117
+    0x0000, // 17118
+    0x0000, // 17119
+    0x0000, // 17120
+    0x0000, // 17121
+    0x0000, // 17122
+    0x0000, // 17123
+    0x0000, // 17124
+    0x0000, // 17125
+    0x0000, // 17126
+    0x0000, // 17127
+    0x0000, // 17128
+    0x0000, // 17129
+    0x0000, // 17130
+    0x0000, // 17131
+    0x0000, // 17132
+    0x0000, // 17133
+    0x0000, // 17134
+    0x0000, // 17135
+    0x0000, // 17136
+    0x0000, // 17137
+    0x0000, // 17138
+    0x0000, // 17139
+    0x0000, // 17140
+    0x0000, // 17141
+    0x0000, // 17142
+    0x0000, // 17143
+    0x0000, // 17144
+    0x0000, // 17145
+    0x0000, // 17146
+    0x0000, // 17147
+    0x0000, // 17148
+    0x0000, // 17149
+    0x0000, // 17150
+    0x0000, // 17151
+    0x0000, // 17152
+    0x0000, // 17153
+    0x0000, // 17154
+    0x0000, // 17155
+    0x0000, // 17156
+    0x0000, // 17157
+    0x0000, // 17158
+    0x0000, // 17159
+    0x0000, // 17160
+    0x0000, // 17161
+    0x0000, // 17162
+    0x0000, // 17163
+    0x0000, // 17164
+    0x0000, // 17165
+    0x0000, // 17166
+    0x0000, // 17167
+    0x0000, // 17168
+    0x0000, // 17169
+    0x0000, // 17170
+    0x0000, // 17171
+    0x0000, // 17172
+    0x0000, // 17173
+    0x0000, // 17174
+    0x0000, // 17175
+    0x0000, // 17176
+    0x0000, // 17177
+    0x0000, // 17178
+    0x0000, // 17179
+    0x0000, // 17180
+    0x0000, // 17181
+    0x0000, // 17182
+    0x0000, // 17183
+    0x0000, // 17184
+    0x0000, // 17185
+    0x0000, // 17186
+    0x0000, // 17187
+    0x0000, // 17188
+    0x0000, // 17189
+    0x0000, // 17190
+    0x0000, // 17191
+    0x0000, // 17192
+    0x0000, // 17193
+    0x0000, // 17194
+    0x0000, // 17195
+    0x767C, // 17196
+    0x8DCB, // 17197
+    0x91B1, // 17198
+    0x9262, // 17199
+    0x9AEE, // 17200
+    0x9B43, // 17201
+    0x5023, // 17202
+    0x508D, // 17203
+    0x574A, // 17204
+    0x59A8, // 17205
+    0x5C28, // 17206
+    0x5E47, // 17207
+    0x5F77, // 17208
+    0x623F, // 17209
+    0x653E, // 17210
+    0x65B9, // 17211
+    0x65C1, // 17212
+    0x6609, // 17213
+    0x678B, // 17214
+    0x699C, // 17215
+    0x6EC2, // 17216
+    0x78C5, // 17217
+    0x7D21, // 17218
+    0x80AA, // 17219
+    0x8180, // 17220
+    0x822B, // 17221
+    0x82B3, // 17222
+    0x84A1, // 17223
+    0x868C, // 17224
+    0x8A2A, // 17225
+    0x8B17, // 17226
+    0x90A6, // 17227
+    0x9632, // 17228
+    0x9F90, // 17229
+    0x500D, // 17230
+    0x4FF3, // 17231
+    0xF963, // 17232
+    0x57F9, // 17233
+    0x5F98, // 17234
+    0x62DC, // 17235
+    0x6392, // 17236
+    0x676F, // 17237
+    0x6E43, // 17238
+    0x7119, // 17239
+    0x76C3, // 17240
+    0x80CC, // 17241
+    0x80DA, // 17242
+    0x88F4, // 17243
+    0x88F5, // 17244
+    0x8919, // 17245
+    0x8CE0, // 17246
+    0x8F29, // 17247
+    0x914D, // 17248
+    0x966A, // 17249
+    0x4F2F, // 17250
+    0x4F70, // 17251
+    0x5E1B, // 17252
+    0x67CF, // 17253
+    0x6822, // 17254
+    0x767D, // 17255
+    0x767E, // 17256
+    0x9B44, // 17257
+    0x5E61, // 17258
+    0x6A0A, // 17259
+    0x7169, // 17260
+    0x71D4, // 17261
+    0x756A, // 17262
+    0xF964, // 17263
+    0x7E41, // 17264
+    0x8543, // 17265
+    0x85E9, // 17266
+    0x98DC, // 17267
+    0x4F10, // 17268
+    0x7B4F, // 17269
+    0x7F70, // 17270
+    0x95A5, // 17271
+    0x51E1, // 17272
+    0x5E06, // 17273
+    0x68B5, // 17274
+    0x6C3E, // 17275
+    0x6C4E, // 17276
+    0x6CDB, // 17277
+    0x72AF, // 17278
+    0x7BC4, // 17279
+    0x8303, // 17280
+    0x6CD5, // 17281
+    0x743A, // 17282
+    0x50FB, // 17283
+    0x5288, // 17284
+    0x58C1, // 17285
+    0x64D8, // 17286
+    0x6A97, // 17287
+    0x74A7, // 17288
+    0x7656, // 17289
+    0x0000, // 17290
+    0x0000, // 17291
+    0x0000, // 17292
+    0x0000, // 17293
+    0x0000, // 17294
+    0x0000, // 17295
+    0x0000, // 17296
+    0x0000, // 17297
+    0x0000, // 17298
+    0x0000, // 17299
+    0x0000, // 17300
+    0x0000, // 17301
+    0x0000, // 17302
+    0x0000, // 17303
+    0x0000, // 17304
+    0x0000, // 17305
+    0x0000, // 17306
+    0x0000, // 17307
+    0x0000, // 17308
+    0x0000, // 17309
+    0x0000, // 17310
+    0x0000, // 17311
+    0x0000, // 17312
+    0x0000, // 17313
+    0x0000, // 17314
+    0x0000, // 17315
+    0x0000, // 17316
+    0x0000, // 17317
+    0x0000, // 17318
+    0x0000, // 17319
+    0x0000, // 17320
+    0x0000, // 17321
+    0x0000, // 17322
+    0x0000, // 17323
+    0x0000, // 17324
+    0x0000, // 17325
+    0x0000, // 17326
+    0x0000, // 17327
+    0x0000, // 17328
+    0x0000, // 17329
+    0x0000, // 17330
+    0x0000, // 17331
+    0x0000, // 17332
+    0x0000, // 17333
+    0x0000, // 17334
+    0x0000, // 17335
+    0x0000, // 17336
+    0x0000, // 17337
+    0x0000, // 17338
+    0x0000, // 17339
+    0x0000, // 17340
+    0x0000, // 17341
+    0x0000, // 17342
+    0x0000, // 17343
+    0x0000, // 17344
+    0x0000, // 17345
+    0x0000, // 17346
+    0x0000, // 17347
+    0x0000, // 17348
+    0x0000, // 17349
+    0x0000, // 17350
+    0x0000, // 17351
+    0x0000, // 17352
+    0x0000, // 17353
+    0x0000, // 17354
+    0x0000, // 17355
+    0x0000, // 17356
+    0x0000, // 17357
+    0x0000, // 17358
+    0x0000, // 17359
+    0x0000, // 17360
+    0x0000, // 17361
+    0x0000, // 17362
+    0x0000, // 17363
+    0x0000, // 17364
+    0x0000, // 17365
+    0x0000, // 17366
+    0x0000, // 17367
+    0x0000, // 17368
+    0x0000, // 17369
+    0x0000, // 17370
+    0x0000, // 17371
+    0x0000, // 17372
+    0x0000, // 17373
+    0x0000, // 17374
+    0x0000, // 17375
+    0x0000, // 17376
+    0x0000, // 17377
+    0x0000, // 17378
+    0x0000, // 17379
+    0x0000, // 17380
+    0x0000, // 17381
+    0x0000, // 17382
+    0x0000, // 17383
+    0x0000, // 17384
+    0x0000, // 17385
+    0x78A7, // 17386
+    0x8617, // 17387
+    0x95E2, // 17388
+    0x9739, // 17389
+    0xF965, // 17390
+    0x535E, // 17391
+    0x5F01, // 17392
+    0x8B8A, // 17393
+    0x8FA8, // 17394
+    0x8FAF, // 17395
+    0x908A, // 17396
+    0x5225, // 17397
+    0x77A5, // 17398
+    0x9C49, // 17399
+    0x9F08, // 17400
+    0x4E19, // 17401
+    0x5002, // 17402
+    0x5175, // 17403
+    0x5C5B, // 17404
+    0x5E77, // 17405
+    0x661E, // 17406
+    0x663A, // 17407
+    0x67C4, // 17408
+    0x68C5, // 17409
+    0x70B3, // 17410
+    0x7501, // 17411
+    0x75C5, // 17412
+    0x79C9, // 17413
+    0x7ADD, // 17414
+    0x8F27, // 17415
+    0x9920, // 17416
+    0x9A08, // 17417
+    0x4FDD, // 17418
+    0x5821, // 17419
+    0x5831, // 17420
+    0x5BF6, // 17421
+    0x666E, // 17422
+    0x6B65, // 17423
+    0x6D11, // 17424
+    0x6E7A, // 17425
+    0x6F7D, // 17426
+    0x73E4, // 17427
+    0x752B, // 17428
+    0x83E9, // 17429
+    0x88DC, // 17430
+    0x8913, // 17431
+    0x8B5C, // 17432
+    0x8F14, // 17433
+    0x4F0F, // 17434
+    0x50D5, // 17435
+    0x5310, // 17436
+    0x535C, // 17437
+    0x5B93, // 17438
+    0x5FA9, // 17439
+    0x670D, // 17440
+    0x798F, // 17441
+    0x8179, // 17442
+    0x832F, // 17443
+    0x8514, // 17444
+    0x8907, // 17445
+    0x8986, // 17446
+    0x8F39, // 17447
+    0x8F3B, // 17448
+    0x99A5, // 17449
+    0x9C12, // 17450
+    0x672C, // 17451
+    0x4E76, // 17452
+    0x4FF8, // 17453
+    0x5949, // 17454
+    0x5C01, // 17455
+    0x5CEF, // 17456
+    0x5CF0, // 17457
+    0x6367, // 17458
+    0x68D2, // 17459
+    0x70FD, // 17460
+    0x71A2, // 17461
+    0x742B, // 17462
+    0x7E2B, // 17463
+    0x84EC, // 17464
+    0x8702, // 17465
+    0x9022, // 17466
+    0x92D2, // 17467
+    0x9CF3, // 17468
+    0x4E0D, // 17469
+    0x4ED8, // 17470
+    0x4FEF, // 17471
+    0x5085, // 17472
+    0x5256, // 17473
+    0x526F, // 17474
+    0x5426, // 17475
+    0x5490, // 17476
+    0x57E0, // 17477
+    0x592B, // 17478
+    0x5A66, // 17479
+    0x0000, // 17480
+    0x0000, // 17481
+    0x0000, // 17482
+    0x0000, // 17483
+    0x0000, // 17484
+    0x0000, // 17485
+    0x0000, // 17486
+    0x0000, // 17487
+    0x0000, // 17488
+    0x0000, // 17489
+    0x0000, // 17490
+    0x0000, // 17491
+    0x0000, // 17492
+    0x0000, // 17493
+    0x0000, // 17494
+    0x0000, // 17495
+    0x0000, // 17496
+    0x0000, // 17497
+    0x0000, // 17498
+    0x0000, // 17499
+    0x0000, // 17500
+    0x0000, // 17501
+    0x0000, // 17502
+    0x0000, // 17503
+    0x0000, // 17504
+    0x0000, // 17505
+    0x0000, // 17506
+    0x0000, // 17507
+    0x0000, // 17508
+    0x0000, // 17509
+    0x0000, // 17510
+    0x0000, // 17511
+    0x0000, // 17512
+    0x0000, // 17513
+    0x0000, // 17514
+    0x0000, // 17515
+    0x0000, // 17516
+    0x0000, // 17517
+    0x0000, // 17518
+    0x0000, // 17519
+    0x0000, // 17520
+    0x0000, // 17521
+    0x0000, // 17522
+    0x0000, // 17523
+    0x0000, // 17524
+    0x0000, // 17525
+    0x0000, // 17526
+    0x0000, // 17527
+    0x0000, // 17528
+    0x0000, // 17529
+    0x0000, // 17530
+    0x0000, // 17531
+    0x0000, // 17532
+    0x0000, // 17533
+    0x0000, // 17534
+    0x0000, // 17535
+    0x0000, // 17536
+    0x0000, // 17537
+    0x0000, // 17538
+    0x0000, // 17539
+    0x0000, // 17540
+    0x0000, // 17541
+    0x0000, // 17542
+    0x0000, // 17543
+    0x0000, // 17544
+    0x0000, // 17545
+    0x0000, // 17546
+    0x0000, // 17547
+    0x0000, // 17548
+    0x0000, // 17549
+    0x0000, // 17550
+    0x0000, // 17551
+    0x0000, // 17552
+    0x0000, // 17553
+    0x0000, // 17554
+    0x0000, // 17555
+    0x0000, // 17556
+    0x0000, // 17557
+    0x0000, // 17558
+    0x0000, // 17559
+    0x0000, // 17560
+    0x0000, // 17561
+    0x0000, // 17562
+    0x0000, // 17563
+    0x0000, // 17564
+    0x0000, // 17565
+    0x0000, // 17566
+    0x0000, // 17567
+    0x0000, // 17568
+    0x0000, // 17569
+    0x0000, // 17570
+    0x0000, // 17571
+    0x0000, // 17572
+    0x0000, // 17573
+    0x0000, // 17574
+    0x0000, // 17575
+    0x5B5A, // 17576
+    0x5B75, // 17577
+    0x5BCC, // 17578
+    0x5E9C, // 17579
+    0xF966, // 17580
+    0x6276, // 17581
+    0x6577, // 17582
+    0x65A7, // 17583
+    0x6D6E, // 17584
+    0x6EA5, // 17585
+    0x7236, // 17586
+    0x7B26, // 17587
+    0x7C3F, // 17588
+    0x7F36, // 17589
+    0x8150, // 17590
+    0x8151, // 17591
+    0x819A, // 17592
+    0x8240, // 17593
+    0x8299, // 17594
+    0x83A9, // 17595
+    0x8A03, // 17596
+    0x8CA0, // 17597
+    0x8CE6, // 17598
+    0x8CFB, // 17599
+    0x8D74, // 17600
+    0x8DBA, // 17601
+    0x90E8, // 17602
+    0x91DC, // 17603
+    0x961C, // 17604
+    0x9644, // 17605
+    0x99D9, // 17606
+    0x9CE7, // 17607
+    0x5317, // 17608
+    0x5206, // 17609
+    0x5429, // 17610
+    0x5674, // 17611
+    0x58B3, // 17612
+    0x5954, // 17613
+    0x596E, // 17614
+    0x5FFF, // 17615
+    0x61A4, // 17616
+    0x626E, // 17617
+    0x6610, // 17618
+    0x6C7E, // 17619
+    0x711A, // 17620
+    0x76C6, // 17621
+    0x7C89, // 17622
+    0x7CDE, // 17623
+    0x7D1B, // 17624
+    0x82AC, // 17625
+    0x8CC1, // 17626
+    0x96F0, // 17627
+    0xF967, // 17628
+    0x4F5B, // 17629
+    0x5F17, // 17630
+    0x5F7F, // 17631
+    0x62C2, // 17632
+    0x5D29, // 17633
+    0x670B, // 17634
+    0x68DA, // 17635
+    0x787C, // 17636
+    0x7E43, // 17637
+    0x9D6C, // 17638
+    0x4E15, // 17639
+    0x5099, // 17640
+    0x5315, // 17641
+    0x532A, // 17642
+    0x5351, // 17643
+    0x5983, // 17644
+    0x5A62, // 17645
+    0x5E87, // 17646
+    0x60B2, // 17647
+    0x618A, // 17648
+    0x6249, // 17649
+    0x6279, // 17650
+    0x6590, // 17651
+    0x6787, // 17652
+    0x69A7, // 17653
+    0x6BD4, // 17654
+    0x6BD6, // 17655
+    0x6BD7, // 17656
+    0x6BD8, // 17657
+    0x6CB8, // 17658
+    0xF968, // 17659
+    0x7435, // 17660
+    0x75FA, // 17661
+    0x7812, // 17662
+    0x7891, // 17663
+    0x79D5, // 17664
+    0x79D8, // 17665
+    0x7C83, // 17666
+    0x7DCB, // 17667
+    0x7FE1, // 17668
+    0x80A5, // 17669
+    0x0000, // 17670
+    0x0000, // 17671
+    0x0000, // 17672
+    0x0000, // 17673
+    0x0000, // 17674
+    0x0000, // 17675
+    0x0000, // 17676
+    0x0000, // 17677
+    0x0000, // 17678
+    0x0000, // 17679
+    0x0000, // 17680
+    0x0000, // 17681
+    0x0000, // 17682
+    0x0000, // 17683
+    0x0000, // 17684
+    0x0000, // 17685
+    0x0000, // 17686
+    0x0000, // 17687
+    0x0000, // 17688
+    0x0000, // 17689
+    0x0000, // 17690
+    0x0000, // 17691
+    0x0000, // 17692
+    0x0000, // 17693
+    0x0000, // 17694
+    0x0000, // 17695
+    0x0000, // 17696
+    0x0000, // 17697
+    0x0000, // 17698
+    0x0000, // 17699
+    0x0000, // 17700
+    0x0000, // 17701
+    0x0000, // 17702
+    0x0000, // 17703
+    0x0000, // 17704
+    0x0000, // 17705
+    0x0000, // 17706
+    0x0000, // 17707
+    0x0000, // 17708
+    0x0000, // 17709
+    0x0000, // 17710
+    0x0000, // 17711
+    0x0000, // 17712
+    0x0000, // 17713
+    0x0000, // 17714
+    0x0000, // 17715
+    0x0000, // 17716
+    0x0000, // 17717
+    0x0000, // 17718
+    0x0000, // 17719
+    0x0000, // 17720
+    0x0000, // 17721
+    0x0000, // 17722
+    0x0000, // 17723
+    0x0000, // 17724
+    0x0000, // 17725
+    0x0000, // 17726
+    0x0000, // 17727
+    0x0000, // 17728
+    0x0000, // 17729
+    0x0000, // 17730
+    0x0000, // 17731
+    0x0000, // 17732
+    0x0000, // 17733
+    0x0000, // 17734
+    0x0000, // 17735
+    0x0000, // 17736
+    0x0000, // 17737
+    0x0000, // 17738
+    0x0000, // 17739
+    0x0000, // 17740
+    0x0000, // 17741
+    0x0000, // 17742
+    0x0000, // 17743
+    0x0000, // 17744
+    0x0000, // 17745
+    0x0000, // 17746
+    0x0000, // 17747
+    0x0000, // 17748
+    0x0000, // 17749
+    0x0000, // 17750
+    0x0000, // 17751
+    0x0000, // 17752
+    0x0000, // 17753
+    0x0000, // 17754
+    0x0000, // 17755
+    0x0000, // 17756
+    0x0000, // 17757
+    0x0000, // 17758
+    0x0000, // 17759
+    0x0000, // 17760
+    0x0000, // 17761
+    0x0000, // 17762
+    0x0000, // 17763
+    0x0000, // 17764
+    0x0000, // 17765
+    0x813E, // 17766
+    0x81C2, // 17767
+    0x83F2, // 17768
+    0x871A, // 17769
+    0x88E8, // 17770
+    0x8AB9, // 17771
+    0x8B6C, // 17772
+    0x8CBB, // 17773
+    0x9119, // 17774
+    0x975E, // 17775
+    0x98DB, // 17776
+    0x9F3B, // 17777
+    0x56AC, // 17778
+    0x5B2A, // 17779
+    0x5F6C, // 17780
+    0x658C, // 17781
+    0x6AB3, // 17782
+    0x6BAF, // 17783
+    0x6D5C, // 17784
+    0x6FF1, // 17785
+    0x7015, // 17786
+    0x725D, // 17787
+    0x73AD, // 17788
+    0x8CA7, // 17789
+    0x8CD3, // 17790
+    0x983B, // 17791
+    0x6191, // 17792
+    0x6C37, // 17793
+    0x8058, // 17794
+    0x9A01, // 17795
+    0x4E4D, // 17796
+    0x4E8B, // 17797
+    0x4E9B, // 17798
+    0x4ED5, // 17799
+    0x4F3A, // 17800
+    0x4F3C, // 17801
+    0x4F7F, // 17802
+    0x4FDF, // 17803
+    0x50FF, // 17804
+    0x53F2, // 17805
+    0x53F8, // 17806
+    0x5506, // 17807
+    0x55E3, // 17808
+    0x56DB, // 17809
+    0x58EB, // 17810
+    0x5962, // 17811
+    0x5A11, // 17812
+    0x5BEB, // 17813
+    0x5BFA, // 17814
+    0x5C04, // 17815
+    0x5DF3, // 17816
+    0x5E2B, // 17817
+    0x5F99, // 17818
+    0x601D, // 17819
+    0x6368, // 17820
+    0x659C, // 17821
+    0x65AF, // 17822
+    0x67F6, // 17823
+    0x67FB, // 17824
+    0x68AD, // 17825
+    0x6B7B, // 17826
+    0x6C99, // 17827
+    0x6CD7, // 17828
+    0x6E23, // 17829
+    0x7009, // 17830
+    0x7345, // 17831
+    0x7802, // 17832
+    0x793E, // 17833
+    0x7940, // 17834
+    0x7960, // 17835
+    0x79C1, // 17836
+    0x7BE9, // 17837
+    0x7D17, // 17838
+    0x7D72, // 17839
+    0x8086, // 17840
+    0x820D, // 17841
+    0x838E, // 17842
+    0x84D1, // 17843
+    0x86C7, // 17844
+    0x88DF, // 17845
+    0x8A50, // 17846
+    0x8A5E, // 17847
+    0x8B1D, // 17848
+    0x8CDC, // 17849
+    0x8D66, // 17850
+    0x8FAD, // 17851
+    0x90AA, // 17852
+    0x98FC, // 17853
+    0x99DF, // 17854
+    0x9E9D, // 17855
+    0x524A, // 17856
+    0xF969, // 17857
+    0x6714, // 17858
+    0xF96A, // 17859
+    0x0000, // 17860
+    0x0000, // 17861
+    0x0000, // 17862
+    0x0000, // 17863
+    0x0000, // 17864
+    0x0000, // 17865
+    0x0000, // 17866
+    0x0000, // 17867
+    0x0000, // 17868
+    0x0000, // 17869
+    0x0000, // 17870
+    0x0000, // 17871
+    0x0000, // 17872
+    0x0000, // 17873
+    0x0000, // 17874
+    0x0000, // 17875
+    0x0000, // 17876
+    0x0000, // 17877
+    0x0000, // 17878
+    0x0000, // 17879
+    0x0000, // 17880
+    0x0000, // 17881
+    0x0000, // 17882
+    0x0000, // 17883
+    0x0000, // 17884
+    0x0000, // 17885
+    0x0000, // 17886
+    0x0000, // 17887
+    0x0000, // 17888
+    0x0000, // 17889
+    0x0000, // 17890
+    0x0000, // 17891
+    0x0000, // 17892
+    0x0000, // 17893
+    0x0000, // 17894
+    0x0000, // 17895
+    0x0000, // 17896
+    0x0000, // 17897
+    0x0000, // 17898
+    0x0000, // 17899
+    0x0000, // 17900
+    0x0000, // 17901
+    0x0000, // 17902
+    0x0000, // 17903
+    0x0000, // 17904
+    0x0000, // 17905
+    0x0000, // 17906
+    0x0000, // 17907
+    0x0000, // 17908
+    0x0000, // 17909
+    0x0000, // 17910
+    0x0000, // 17911
+    0x0000, // 17912
+    0x0000, // 17913
+    0x0000, // 17914
+    0x0000, // 17915
+    0x0000, // 17916
+    0x0000, // 17917
+    0x0000, // 17918
+    0x0000, // 17919
+    0x0000, // 17920
+    0x0000, // 17921
+    0x0000, // 17922
+    0x0000, // 17923
+    0x0000, // 17924
+    0x0000, // 17925
+    0x0000, // 17926
+    0x0000, // 17927
+    0x0000, // 17928
+    0x0000, // 17929
+    0x0000, // 17930
+    0x0000, // 17931
+    0x0000, // 17932
+    0x0000, // 17933
+    0x0000, // 17934
+    0x0000, // 17935
+    0x0000, // 17936
+    0x0000, // 17937
+    0x0000, // 17938
+    0x0000, // 17939
+    0x0000, // 17940
+    0x0000, // 17941
+    0x0000, // 17942
+    0x0000, // 17943
+    0x0000, // 17944
+    0x0000, // 17945
+    0x0000, // 17946
+    0x0000, // 17947
+    0x0000, // 17948
+    0x0000, // 17949
+    0x0000, // 17950
+    0x0000, // 17951
+    0x0000, // 17952
+    0x0000, // 17953
+    0x0000, // 17954
+    0x0000, // 17955
+    0x5098, // 17956
+    0x522A, // 17957
+    0x5C71, // 17958
+    0x6563, // 17959
+    0x6C55, // 17960
+    0x73CA, // 17961
+    0x7523, // 17962
+    0x759D, // 17963
+    0x7B97, // 17964
+    0x849C, // 17965
+    0x9178, // 17966
+    0x9730, // 17967
+    0x4E77, // 17968
+    0x6492, // 17969
+    0x6BBA, // 17970
+    0x715E, // 17971
+    0x85A9, // 17972
+    0x4E09, // 17973
+    0xF96B, // 17974
+    0x6749, // 17975
+    0x68EE, // 17976
+    0x6E17, // 17977
+    0x829F, // 17978
+    0x8518, // 17979
+    0x886B, // 17980
+    0x63F7, // 17981
+    0x6F81, // 17982
+    0x9212, // 17983
+    0x98AF, // 17984
+    0x4E0A, // 17985
+    0x50B7, // 17986
+    0x50CF, // 17987
+    0x511F, // 17988
+    0x5546, // 17989
+    0x55AA, // 17990
+    0x5617, // 17991
+    0x5B40, // 17992
+    0x5C19, // 17993
+    0x5CE0, // 17994
+    0x5E38, // 17995
+    0x5E8A, // 17996
+    0x5EA0, // 17997
+    0x5EC2, // 17998
+    0x60F3, // 17999
+    0x6851, // 18000
+    0x6A61, // 18001
+    0x6E58, // 18002
+    0x723D, // 18003
+    0x7240, // 18004
+    0x72C0, // 18005
+    0x76F8, // 18006
+    0x7965, // 18007
+    0x7BB1, // 18008
+    0x7FD4, // 18009
+    0x88F3, // 18010
+    0x89F4, // 18011
+    0x8A73, // 18012
+    0x8C61, // 18013
+    0x8CDE, // 18014
+    0x971C, // 18015
+    0x585E, // 18016
+    0x74BD, // 18017
+    0x8CFD, // 18018
+    0x55C7, // 18019
+    0xF96C, // 18020
+    0x7A61, // 18021
+    0x7D22, // 18022
+    0x8272, // 18023
+    0x7272, // 18024
+    0x751F, // 18025
+    0x7525, // 18026
+    0xF96D, // 18027
+    0x7B19, // 18028
+    0x5885, // 18029
+    0x58FB, // 18030
+    0x5DBC, // 18031
+    0x5E8F, // 18032
+    0x5EB6, // 18033
+    0x5F90, // 18034
+    0x6055, // 18035
+    0x6292, // 18036
+    0x637F, // 18037
+    0x654D, // 18038
+    0x6691, // 18039
+    0x66D9, // 18040
+    0x66F8, // 18041
+    0x6816, // 18042
+    0x68F2, // 18043
+    0x7280, // 18044
+    0x745E, // 18045
+    0x7B6E, // 18046
+    0x7D6E, // 18047
+    0x7DD6, // 18048
+    0x7F72, // 18049
+    0x0000, // 18050
+    0x0000, // 18051
+    0x0000, // 18052
+    0x0000, // 18053
+    0x0000, // 18054
+    0x0000, // 18055
+    0x0000, // 18056
+    0x0000, // 18057
+    0x0000, // 18058
+    0x0000, // 18059
+    0x0000, // 18060
+    0x0000, // 18061
+    0x0000, // 18062
+    0x0000, // 18063
+    0x0000, // 18064
+    0x0000, // 18065
+    0x0000, // 18066
+    0x0000, // 18067
+    0x0000, // 18068
+    0x0000, // 18069
+    0x0000, // 18070
+    0x0000, // 18071
+    0x0000, // 18072
+    0x0000, // 18073
+    0x0000, // 18074
+    0x0000, // 18075
+    0x0000, // 18076
+    0x0000, // 18077
+    0x0000, // 18078
+    0x0000, // 18079
+    0x0000, // 18080
+    0x0000, // 18081
+    0x0000, // 18082
+    0x0000, // 18083
+    0x0000, // 18084
+    0x0000, // 18085
+    0x0000, // 18086
+    0x0000, // 18087
+    0x0000, // 18088
+    0x0000, // 18089
+    0x0000, // 18090
+    0x0000, // 18091
+    0x0000, // 18092
+    0x0000, // 18093
+    0x0000, // 18094
+    0x0000, // 18095
+    0x0000, // 18096
+    0x0000, // 18097
+    0x0000, // 18098
+    0x0000, // 18099
+    0x0000, // 18100
+    0x0000, // 18101
+    0x0000, // 18102
+    0x0000, // 18103
+    0x0000, // 18104
+    0x0000, // 18105
+    0x0000, // 18106
+    0x0000, // 18107
+    0x0000, // 18108
+    0x0000, // 18109
+    0x0000, // 18110
+    0x0000, // 18111
+    0x0000, // 18112
+    0x0000, // 18113
+    0x0000, // 18114
+    0x0000, // 18115
+    0x0000, // 18116
+    0x0000, // 18117
+    0x0000, // 18118
+    0x0000, // 18119
+    0x0000, // 18120
+    0x0000, // 18121
+    0x0000, // 18122
+    0x0000, // 18123
+    0x0000, // 18124
+    0x0000, // 18125
+    0x0000, // 18126
+    0x0000, // 18127
+    0x0000, // 18128
+    0x0000, // 18129
+    0x0000, // 18130
+    0x0000, // 18131
+    0x0000, // 18132
+    0x0000, // 18133
+    0x0000, // 18134
+    0x0000, // 18135
+    0x0000, // 18136
+    0x0000, // 18137
+    0x0000, // 18138
+    0x0000, // 18139
+    0x0000, // 18140
+    0x0000, // 18141
+    0x0000, // 18142
+    0x0000, // 18143
+    0x0000, // 18144
+    0x0000, // 18145
+    0x80E5, // 18146
+    0x8212, // 18147
+    0x85AF, // 18148
+    0x897F, // 18149
+    0x8A93, // 18150
+    0x901D, // 18151
+    0x92E4, // 18152
+    0x9ECD, // 18153
+    0x9F20, // 18154
+    0x5915, // 18155
+    0x596D, // 18156
+    0x5E2D, // 18157
+    0x60DC, // 18158
+    0x6614, // 18159
+    0x6673, // 18160
+    0x6790, // 18161
+    0x6C50, // 18162
+    0x6DC5, // 18163
+    0x6F5F, // 18164
+    0x77F3, // 18165
+    0x78A9, // 18166
+    0x84C6, // 18167
+    0x91CB, // 18168
+    0x932B, // 18169
+    0x4ED9, // 18170
+    0x50CA, // 18171
+    0x5148, // 18172
+    0x5584, // 18173
+    0x5B0B, // 18174
+    0x5BA3, // 18175
+    0x6247, // 18176
+    0x657E, // 18177
+    0x65CB, // 18178
+    0x6E32, // 18179
+    0x717D, // 18180
+    0x7401, // 18181
+    0x7444, // 18182
+    0x7487, // 18183
+    0x74BF, // 18184
+    0x766C, // 18185
+    0x79AA, // 18186
+    0x7DDA, // 18187
+    0x7E55, // 18188
+    0x7FA8, // 18189
+    0x817A, // 18190
+    0x81B3, // 18191
+    0x8239, // 18192
+    0x861A, // 18193
+    0x87EC, // 18194
+    0x8A75, // 18195
+    0x8DE3, // 18196
+    0x9078, // 18197
+    0x9291, // 18198
+    0x9425, // 18199
+    0x994D, // 18200
+    0x9BAE, // 18201
+    0x5368, // 18202
+    0x5C51, // 18203
+    0x6954, // 18204
+    0x6CC4, // 18205
+    0x6D29, // 18206
+    0x6E2B, // 18207
+    0x820C, // 18208
+    0x859B, // 18209
+    0x893B, // 18210
+    0x8A2D, // 18211
+    0x8AAA, // 18212
+    0x96EA, // 18213
+    0x9F67, // 18214
+    0x5261, // 18215
+    0x66B9, // 18216
+    0x6BB2, // 18217
+    0x7E96, // 18218
+    0x87FE, // 18219
+    0x8D0D, // 18220
+    0x9583, // 18221
+    0x965D, // 18222
+    0x651D, // 18223
+    0x6D89, // 18224
+    0x71EE, // 18225
+    0xF96E, // 18226
+    0x57CE, // 18227
+    0x59D3, // 18228
+    0x5BAC, // 18229
+    0x6027, // 18230
+    0x60FA, // 18231
+    0x6210, // 18232
+    0x661F, // 18233
+    0x665F, // 18234
+    0x7329, // 18235
+    0x73F9, // 18236
+    0x76DB, // 18237
+    0x7701, // 18238
+    0x7B6C, // 18239
+    0x0000, // 18240
+    0x0000, // 18241
+    0x0000, // 18242
+    0x0000, // 18243
+    0x0000, // 18244
+    0x0000, // 18245
+    0x0000, // 18246
+    0x0000, // 18247
+    0x0000, // 18248
+    0x0000, // 18249
+    0x0000, // 18250
+    0x0000, // 18251
+    0x0000, // 18252
+    0x0000, // 18253
+    0x0000, // 18254
+    0x0000, // 18255
+    0x0000, // 18256
+    0x0000, // 18257
+    0x0000, // 18258
+    0x0000, // 18259
+    0x0000, // 18260
+    0x0000, // 18261
+    0x0000, // 18262
+    0x0000, // 18263
+    0x0000, // 18264
+    0x0000, // 18265
+    0x0000, // 18266
+    0x0000, // 18267
+    0x0000, // 18268
+    0x0000, // 18269
+    0x0000, // 18270
+    0x0000, // 18271
+    0x0000, // 18272
+    0x0000, // 18273
+    0x0000, // 18274
+    0x0000, // 18275
+    0x0000, // 18276
+    0x0000, // 18277
+    0x0000, // 18278
+    0x0000, // 18279
+    0x0000, // 18280
+    0x0000, // 18281
+    0x0000, // 18282
+    0x0000, // 18283
+    0x0000, // 18284
+    0x0000, // 18285
+    0x0000, // 18286
+    0x0000, // 18287
+    0x0000, // 18288
+    0x0000, // 18289
+    0x0000, // 18290
+    0x0000, // 18291
+    0x0000, // 18292
+    0x0000, // 18293
+    0x0000, // 18294
+    0x0000, // 18295
+    0x0000, // 18296
+    0x0000, // 18297
+    0x0000, // 18298
+    0x0000, // 18299
+    0x0000, // 18300
+    0x0000, // 18301
+    0x0000, // 18302
+    0x0000, // 18303
+    0x0000, // 18304
+    0x0000, // 18305
+    0x0000, // 18306
+    0x0000, // 18307
+    0x0000, // 18308
+    0x0000, // 18309
+    0x0000, // 18310
+    0x0000, // 18311
+    0x0000, // 18312
+    0x0000, // 18313
+    0x0000, // 18314
+    0x0000, // 18315
+    0x0000, // 18316
+    0x0000, // 18317
+    0x0000, // 18318
+    0x0000, // 18319
+    0x0000, // 18320
+    0x0000, // 18321
+    0x0000, // 18322
+    0x0000, // 18323
+    0x0000, // 18324
+    0x0000, // 18325
+    0x0000, // 18326
+    0x0000, // 18327
+    0x0000, // 18328
+    0x0000, // 18329
+    0x0000, // 18330
+    0x0000, // 18331
+    0x0000, // 18332
+    0x0000, // 18333
+    0x0000, // 18334
+    0x0000, // 18335
+    0x8056, // 18336
+    0x8072, // 18337
+    0x8165, // 18338
+    0x8AA0, // 18339
+    0x9192, // 18340
+    0x4E16, // 18341
+    0x52E2, // 18342
+    0x6B72, // 18343
+    0x6D17, // 18344
+    0x7A05, // 18345
+    0x7B39, // 18346
+    0x7D30, // 18347
+    0xF96F, // 18348
+    0x8CB0, // 18349
+    0x53EC, // 18350
+    0x562F, // 18351
+    0x5851, // 18352
+    0x5BB5, // 18353
+    0x5C0F, // 18354
+    0x5C11, // 18355
+    0x5DE2, // 18356
+    0x6240, // 18357
+    0x6383, // 18358
+    0x6414, // 18359
+    0x662D, // 18360
+    0x68B3, // 18361
+    0x6CBC, // 18362
+    0x6D88, // 18363
+    0x6EAF, // 18364
+    0x701F, // 18365
+    0x70A4, // 18366
+    0x71D2, // 18367
+    0x7526, // 18368
+    0x758F, // 18369
+    0x758E, // 18370
+    0x7619, // 18371
+    0x7B11, // 18372
+    0x7BE0, // 18373
+    0x7C2B, // 18374
+    0x7D20, // 18375
+    0x7D39, // 18376
+    0x852C, // 18377
+    0x856D, // 18378
+    0x8607, // 18379
+    0x8A34, // 18380
+    0x900D, // 18381
+    0x9061, // 18382
+    0x90B5, // 18383
+    0x92B7, // 18384
+    0x97F6, // 18385
+    0x9A37, // 18386
+    0x4FD7, // 18387
+    0x5C6C, // 18388
+    0x675F, // 18389
+    0x6D91, // 18390
+    0x7C9F, // 18391
+    0x7E8C, // 18392
+    0x8B16, // 18393
+    0x8D16, // 18394
+    0x901F, // 18395
+    0x5B6B, // 18396
+    0x5DFD, // 18397
+    0x640D, // 18398
+    0x84C0, // 18399
+    0x905C, // 18400
+    0x98E1, // 18401
+    0x7387, // 18402
+    0x5B8B, // 18403
+    0x609A, // 18404
+    0x677E, // 18405
+    0x6DDE, // 18406
+    0x8A1F, // 18407
+    0x8AA6, // 18408
+    0x9001, // 18409
+    0x980C, // 18410
+    0x5237, // 18411
+    0xF970, // 18412
+    0x7051, // 18413
+    0x788E, // 18414
+    0x9396, // 18415
+    0x8870, // 18416
+    0x91D7, // 18417
+    0x4FEE, // 18418
+    0x53D7, // 18419
+    0x55FD, // 18420
+    0x56DA, // 18421
+    0x5782, // 18422
+    0x58FD, // 18423
+    0x5AC2, // 18424
+    0x5B88, // 18425
+    0x5CAB, // 18426
+    0x5CC0, // 18427
+    0x5E25, // 18428
+    0x6101, // 18429
+    0x0000, // 18430
+    0x0000, // 18431
+    0x0000, // 18432
+    0x0000, // 18433
+    0x0000, // 18434
+    0x0000, // 18435
+    0x0000, // 18436
+    0x0000, // 18437
+    0x0000, // 18438
+    0x0000, // 18439
+    0x0000, // 18440
+    0x0000, // 18441
+    0x0000, // 18442
+    0x0000, // 18443
+    0x0000, // 18444
+    0x0000, // 18445
+    0x0000, // 18446
+    0x0000, // 18447
+    0x0000, // 18448
+    0x0000, // 18449
+    0x0000, // 18450
+    0x0000, // 18451
+    0x0000, // 18452
+    0x0000, // 18453
+    0x0000, // 18454
+    0x0000, // 18455
+    0x0000, // 18456
+    0x0000, // 18457
+    0x0000, // 18458
+    0x0000, // 18459
+    0x0000, // 18460
+    0x0000, // 18461
+    0x0000, // 18462
+    0x0000, // 18463
+    0x0000, // 18464
+    0x0000, // 18465
+    0x0000, // 18466
+    0x0000, // 18467
+    0x0000, // 18468
+    0x0000, // 18469
+    0x0000, // 18470
+    0x0000, // 18471
+    0x0000, // 18472
+    0x0000, // 18473
+    0x0000, // 18474
+    0x0000, // 18475
+    0x0000, // 18476
+    0x0000, // 18477
+    0x0000, // 18478
+    0x0000, // 18479
+    0x0000, // 18480
+    0x0000, // 18481
+    0x0000, // 18482
+    0x0000, // 18483
+    0x0000, // 18484
+    0x0000, // 18485
+    0x0000, // 18486
+    0x0000, // 18487
+    0x0000, // 18488
+    0x0000, // 18489
+    0x0000, // 18490
+    0x0000, // 18491
+    0x0000, // 18492
+    0x0000, // 18493
+    0x0000, // 18494
+    0x0000, // 18495
+    0x0000, // 18496
+    0x0000, // 18497
+    0x0000, // 18498
+    0x0000, // 18499
+    0x0000, // 18500
+    0x0000, // 18501
+    0x0000, // 18502
+    0x0000, // 18503
+    0x0000, // 18504
+    0x0000, // 18505
+    0x0000, // 18506
+    0x0000, // 18507
+    0x0000, // 18508
+    0x0000, // 18509
+    0x0000, // 18510
+    0x0000, // 18511
+    0x0000, // 18512
+    0x0000, // 18513
+    0x0000, // 18514
+    0x0000, // 18515
+    0x0000, // 18516
+    0x0000, // 18517
+    0x0000, // 18518
+    0x0000, // 18519
+    0x0000, // 18520
+    0x0000, // 18521
+    0x0000, // 18522
+    0x0000, // 18523
+    0x0000, // 18524
+    0x0000, // 18525
+    0x620D, // 18526
+    0x624B, // 18527
+    0x6388, // 18528
+    0x641C, // 18529
+    0x6536, // 18530
+    0x6578, // 18531
+    0x6A39, // 18532
+    0x6B8A, // 18533
+    0x6C34, // 18534
+    0x6D19, // 18535
+    0x6F31, // 18536
+    0x71E7, // 18537
+    0x72E9, // 18538
+    0x7378, // 18539
+    0x7407, // 18540
+    0x74B2, // 18541
+    0x7626, // 18542
+    0x7761, // 18543
+    0x79C0, // 18544
+    0x7A57, // 18545
+    0x7AEA, // 18546
+    0x7CB9, // 18547
+    0x7D8F, // 18548
+    0x7DAC, // 18549
+    0x7E61, // 18550
+    0x7F9E, // 18551
+    0x8129, // 18552
+    0x8331, // 18553
+    0x8490, // 18554
+    0x84DA, // 18555
+    0x85EA, // 18556
+    0x8896, // 18557
+    0x8AB0, // 18558
+    0x8B90, // 18559
+    0x8F38, // 18560
+    0x9042, // 18561
+    0x9083, // 18562
+    0x916C, // 18563
+    0x9296, // 18564
+    0x92B9, // 18565
+    0x968B, // 18566
+    0x96A7, // 18567
+    0x96A8, // 18568
+    0x96D6, // 18569
+    0x9700, // 18570
+    0x9808, // 18571
+    0x9996, // 18572
+    0x9AD3, // 18573
+    0x9B1A, // 18574
+    0x53D4, // 18575
+    0x587E, // 18576
+    0x5919, // 18577
+    0x5B70, // 18578
+    0x5BBF, // 18579
+    0x6DD1, // 18580
+    0x6F5A, // 18581
+    0x719F, // 18582
+    0x7421, // 18583
+    0x74B9, // 18584
+    0x8085, // 18585
+    0x83FD, // 18586
+    0x5DE1, // 18587
+    0x5F87, // 18588
+    0x5FAA, // 18589
+    0x6042, // 18590
+    0x65EC, // 18591
+    0x6812, // 18592
+    0x696F, // 18593
+    0x6A53, // 18594
+    0x6B89, // 18595
+    0x6D35, // 18596
+    0x6DF3, // 18597
+    0x73E3, // 18598
+    0x76FE, // 18599
+    0x77AC, // 18600
+    0x7B4D, // 18601
+    0x7D14, // 18602
+    0x8123, // 18603
+    0x821C, // 18604
+    0x8340, // 18605
+    0x84F4, // 18606
+    0x8563, // 18607
+    0x8A62, // 18608
+    0x8AC4, // 18609
+    0x9187, // 18610
+    0x931E, // 18611
+    0x9806, // 18612
+    0x99B4, // 18613
+    0x620C, // 18614
+    0x8853, // 18615
+    0x8FF0, // 18616
+    0x9265, // 18617
+    0x5D07, // 18618
+    0x5D27, // 18619
+    0x0000, // 18620
+    0x0000, // 18621
+    0x0000, // 18622
+    0x0000, // 18623
+    0x0000, // 18624
+    0x0000, // 18625
+    0x0000, // 18626
+    0x0000, // 18627
+    0x0000, // 18628
+    0x0000, // 18629
+    0x0000, // 18630
+    0x0000, // 18631
+    0x0000, // 18632
+    0x0000, // 18633
+    0x0000, // 18634
+    0x0000, // 18635
+    0x0000, // 18636
+    0x0000, // 18637
+    0x0000, // 18638
+    0x0000, // 18639
+    0x0000, // 18640
+    0x0000, // 18641
+    0x0000, // 18642
+    0x0000, // 18643
+    0x0000, // 18644
+    0x0000, // 18645
+    0x0000, // 18646
+    0x0000, // 18647
+    0x0000, // 18648
+    0x0000, // 18649
+    0x0000, // 18650
+    0x0000, // 18651
+    0x0000, // 18652
+    0x0000, // 18653
+    0x0000, // 18654
+    0x0000, // 18655
+    0x0000, // 18656
+    0x0000, // 18657
+    0x0000, // 18658
+    0x0000, // 18659
+    0x0000, // 18660
+    0x0000, // 18661
+    0x0000, // 18662
+    0x0000, // 18663
+    0x0000, // 18664
+    0x0000, // 18665
+    0x0000, // 18666
+    0x0000, // 18667
+    0x0000, // 18668
+    0x0000, // 18669
+    0x0000, // 18670
+    0x0000, // 18671
+    0x0000, // 18672
+    0x0000, // 18673
+    0x0000, // 18674
+    0x0000, // 18675
+    0x0000, // 18676
+    0x0000, // 18677
+    0x0000, // 18678
+    0x0000, // 18679
+    0x0000, // 18680
+    0x0000, // 18681
+    0x0000, // 18682
+    0x0000, // 18683
+    0x0000, // 18684
+    0x0000, // 18685
+    0x0000, // 18686
+    0x0000, // 18687
+    0x0000, // 18688
+    0x0000, // 18689
+    0x0000, // 18690
+    0x0000, // 18691
+    0x0000, // 18692
+    0x0000, // 18693
+    0x0000, // 18694
+    0x0000, // 18695
+    0x0000, // 18696
+    0x0000, // 18697
+    0x0000, // 18698
+    0x0000, // 18699
+    0x0000, // 18700
+    0x0000, // 18701
+    0x0000, // 18702
+    0x0000, // 18703
+    0x0000, // 18704
+    0x0000, // 18705
+    0x0000, // 18706
+    0x0000, // 18707
+    0x0000, // 18708
+    0x0000, // 18709
+    0x0000, // 18710
+    0x0000, // 18711
+    0x0000, // 18712
+    0x0000, // 18713
+    0x0000, // 18714
+    0x0000, // 18715
+    0x5D69, // 18716
+    0x745F, // 18717
+    0x819D, // 18718
+    0x8768, // 18719
+    0x6FD5, // 18720
+    0x62FE, // 18721
+    0x7FD2, // 18722
+    0x8936, // 18723
+    0x8972, // 18724
+    0x4E1E, // 18725
+    0x4E58, // 18726
+    0x50E7, // 18727
+    0x52DD, // 18728
+    0x5347, // 18729
+    0x627F, // 18730
+    0x6607, // 18731
+    0x7E69, // 18732
+    0x8805, // 18733
+    0x965E, // 18734
+    0x4F8D, // 18735
+    0x5319, // 18736
+    0x5636, // 18737
+    0x59CB, // 18738
+    0x5AA4, // 18739
+    0x5C38, // 18740
+    0x5C4E, // 18741
+    0x5C4D, // 18742
+    0x5E02, // 18743
+    0x5F11, // 18744
+    0x6043, // 18745
+    0x65BD, // 18746
+    0x662F, // 18747
+    0x6642, // 18748
+    0x67BE, // 18749
+    0x67F4, // 18750
+    0x731C, // 18751
+    0x77E2, // 18752
+    0x793A, // 18753
+    0x7FC5, // 18754
+    0x8494, // 18755
+    0x84CD, // 18756
+    0x8996, // 18757
+    0x8A66, // 18758
+    0x8A69, // 18759
+    0x8AE1, // 18760
+    0x8C55, // 18761
+    0x8C7A, // 18762
+    0x57F4, // 18763
+    0x5BD4, // 18764
+    0x5F0F, // 18765
+    0x606F, // 18766
+    0x62ED, // 18767
+    0x690D, // 18768
+    0x6B96, // 18769
+    0x6E5C, // 18770
+    0x7184, // 18771
+    0x7BD2, // 18772
+    0x8755, // 18773
+    0x8B58, // 18774
+    0x8EFE, // 18775
+    0x98DF, // 18776
+    0x98FE, // 18777
+    0x4F38, // 18778
+    0x4F81, // 18779
+    0x4FE1, // 18780
+    0x547B, // 18781
+    0x5A20, // 18782
+    0x5BB8, // 18783
+    0x613C, // 18784
+    0x65B0, // 18785
+    0x6668, // 18786
+    0x71FC, // 18787
+    0x7533, // 18788
+    0x795E, // 18789
+    0x7D33, // 18790
+    0x814E, // 18791
+    0x81E3, // 18792
+    0x8398, // 18793
+    0x85AA, // 18794
+    0x85CE, // 18795
+    0x8703, // 18796
+    0x8A0A, // 18797
+    0x8EAB, // 18798
+    0x8F9B, // 18799
+    0xF971, // 18800
+    0x8FC5, // 18801
+    0x5931, // 18802
+    0x5BA4, // 18803
+    0x5BE6, // 18804
+    0x6089, // 18805
+    0x5BE9, // 18806
+    0x5C0B, // 18807
+    0x5FC3, // 18808
+    0x6C81, // 18809
+    0x0000, // 18810
+    0x0000, // 18811
+    0x0000, // 18812
+    0x0000, // 18813
+    0x0000, // 18814
+    0x0000, // 18815
+    0x0000, // 18816
+    0x0000, // 18817
+    0x0000, // 18818
+    0x0000, // 18819
+    0x0000, // 18820
+    0x0000, // 18821
+    0x0000, // 18822
+    0x0000, // 18823
+    0x0000, // 18824
+    0x0000, // 18825
+    0x0000, // 18826
+    0x0000, // 18827
+    0x0000, // 18828
+    0x0000, // 18829
+    0x0000, // 18830
+    0x0000, // 18831
+    0x0000, // 18832
+    0x0000, // 18833
+    0x0000, // 18834
+    0x0000, // 18835
+    0x0000, // 18836
+    0x0000, // 18837
+    0x0000, // 18838
+    0x0000, // 18839
+    0x0000, // 18840
+    0x0000, // 18841
+    0x0000, // 18842
+    0x0000, // 18843
+    0x0000, // 18844
+    0x0000, // 18845
+    0x0000, // 18846
+    0x0000, // 18847
+    0x0000, // 18848
+    0x0000, // 18849
+    0x0000, // 18850
+    0x0000, // 18851
+    0x0000, // 18852
+    0x0000, // 18853
+    0x0000, // 18854
+    0x0000, // 18855
+    0x0000, // 18856
+    0x0000, // 18857
+    0x0000, // 18858
+    0x0000, // 18859
+    0x0000, // 18860
+    0x0000, // 18861
+    0x0000, // 18862
+    0x0000, // 18863
+    0x0000, // 18864
+    0x0000, // 18865
+    0x0000, // 18866
+    0x0000, // 18867
+    0x0000, // 18868
+    0x0000, // 18869
+    0x0000, // 18870
+    0x0000, // 18871
+    0x0000, // 18872
+    0x0000, // 18873
+    0x0000, // 18874
+    0x0000, // 18875
+    0x0000, // 18876
+    0x0000, // 18877
+    0x0000, // 18878
+    0x0000, // 18879
+    0x0000, // 18880
+    0x0000, // 18881
+    0x0000, // 18882
+    0x0000, // 18883
+    0x0000, // 18884
+    0x0000, // 18885
+    0x0000, // 18886
+    0x0000, // 18887
+    0x0000, // 18888
+    0x0000, // 18889
+    0x0000, // 18890
+    0x0000, // 18891
+    0x0000, // 18892
+    0x0000, // 18893
+    0x0000, // 18894
+    0x0000, // 18895
+    0x0000, // 18896
+    0x0000, // 18897
+    0x0000, // 18898
+    0x0000, // 18899
+    0x0000, // 18900
+    0x0000, // 18901
+    0x0000, // 18902
+    0x0000, // 18903
+    0x0000, // 18904
+    0x0000, // 18905
+    0xF972, // 18906
+    0x6DF1, // 18907
+    0x700B, // 18908
+    0x751A, // 18909
+    0x82AF, // 18910
+    0x8AF6, // 18911
+    0x4EC0, // 18912
+    0x5341, // 18913
+    0xF973, // 18914
+    0x96D9, // 18915
+    0x6C0F, // 18916
+    0x4E9E, // 18917
+    0x4FC4, // 18918
+    0x5152, // 18919
+    0x555E, // 18920
+    0x5A25, // 18921
+    0x5CE8, // 18922
+    0x6211, // 18923
+    0x7259, // 18924
+    0x82BD, // 18925
+    0x83AA, // 18926
+    0x86FE, // 18927
+    0x8859, // 18928
+    0x8A1D, // 18929
+    0x963F, // 18930
+    0x96C5, // 18931
+    0x9913, // 18932
+    0x9D09, // 18933
+    0x9D5D, // 18934
+    0x580A, // 18935
+    0x5CB3, // 18936
+    0x5DBD, // 18937
+    0x5E44, // 18938
+    0x60E1, // 18939
+    0x6115, // 18940
+    0x63E1, // 18941
+    0x6A02, // 18942
+    0x6E25, // 18943
+    0x9102, // 18944
+    0x9354, // 18945
+    0x984E, // 18946
+    0x9C10, // 18947
+    0x9F77, // 18948
+    0x5B89, // 18949
+    0x5CB8, // 18950
+    0x6309, // 18951
+    0x664F, // 18952
+    0x6848, // 18953
+    0x773C, // 18954
+    0x96C1, // 18955
+    0x978D, // 18956
+    0x9854, // 18957
+    0x9B9F, // 18958
+    0x65A1, // 18959
+    0x8B01, // 18960
+    0x8ECB, // 18961
+    0x95BC, // 18962
+    0x5535, // 18963
+    0x5CA9, // 18964
+    0x5DD6, // 18965
+    0x5EB5, // 18966
+    0x6697, // 18967
+    0x764C, // 18968
+    0x83F4, // 18969
+    0x95C7, // 18970
+    0x58D3, // 18971
+    0x62BC, // 18972
+    0x72CE, // 18973
+    0x9D28, // 18974
+    0x4EF0, // 18975
+    0x592E, // 18976
+    0x600F, // 18977
+    0x663B, // 18978
+    0x6B83, // 18979
+    0x79E7, // 18980
+    0x9D26, // 18981
+    0x5393, // 18982
+    0x54C0, // 18983
+    0x57C3, // 18984
+    0x5D16, // 18985
+    0x611B, // 18986
+    0x66D6, // 18987
+    0x6DAF, // 18988
+    0x788D, // 18989
+    0x827E, // 18990
+    0x9698, // 18991
+    0x9744, // 18992
+    0x5384, // 18993
+    0x627C, // 18994
+    0x6396, // 18995
+    0x6DB2, // 18996
+    0x7E0A, // 18997
+    0x814B, // 18998
+    0x984D, // 18999
+    0x0000, // 19000
+    0x0000, // 19001
+    0x0000, // 19002
+    0x0000, // 19003
+    0x0000, // 19004
+    0x0000, // 19005
+    0x0000, // 19006
+    0x0000, // 19007
+    0x0000, // 19008
+    0x0000, // 19009
+    0x0000, // 19010
+    0x0000, // 19011
+    0x0000, // 19012
+    0x0000, // 19013
+    0x0000, // 19014
+    0x0000, // 19015
+    0x0000, // 19016
+    0x0000, // 19017
+    0x0000, // 19018
+    0x0000, // 19019
+    0x0000, // 19020
+    0x0000, // 19021
+    0x0000, // 19022
+    0x0000, // 19023
+    0x0000, // 19024
+    0x0000, // 19025
+    0x0000, // 19026
+    0x0000, // 19027
+    0x0000, // 19028
+    0x0000, // 19029
+    0x0000, // 19030
+    0x0000, // 19031
+    0x0000, // 19032
+    0x0000, // 19033
+    0x0000, // 19034
+    0x0000, // 19035
+    0x0000, // 19036
+    0x0000, // 19037
+    0x0000, // 19038
+    0x0000, // 19039
+    0x0000, // 19040
+    0x0000, // 19041
+    0x0000, // 19042
+    0x0000, // 19043
+    0x0000, // 19044
+    0x0000, // 19045
+    0x0000, // 19046
+    0x0000, // 19047
+    0x0000, // 19048
+    0x0000, // 19049
+    0x0000, // 19050
+    0x0000, // 19051
+    0x0000, // 19052
+    0x0000, // 19053
+    0x0000, // 19054
+    0x0000, // 19055
+    0x0000, // 19056
+    0x0000, // 19057
+    0x0000, // 19058
+    0x0000, // 19059
+    0x0000, // 19060
+    0x0000, // 19061
+    0x0000, // 19062
+    0x0000, // 19063
+    0x0000, // 19064
+    0x0000, // 19065
+    0x0000, // 19066
+    0x0000, // 19067
+    0x0000, // 19068
+    0x0000, // 19069
+    0x0000, // 19070
+    0x0000, // 19071
+    0x0000, // 19072
+    0x0000, // 19073
+    0x0000, // 19074
+    0x0000, // 19075
+    0x0000, // 19076
+    0x0000, // 19077
+    0x0000, // 19078
+    0x0000, // 19079
+    0x0000, // 19080
+    0x0000, // 19081
+    0x0000, // 19082
+    0x0000, // 19083
+    0x0000, // 19084
+    0x0000, // 19085
+    0x0000, // 19086
+    0x0000, // 19087
+    0x0000, // 19088
+    0x0000, // 19089
+    0x0000, // 19090
+    0x0000, // 19091
+    0x0000, // 19092
+    0x0000, // 19093
+    0x0000, // 19094
+    0x0000, // 19095
+    0x6AFB, // 19096
+    0x7F4C, // 19097
+    0x9DAF, // 19098
+    0x9E1A, // 19099
+    0x4E5F, // 19100
+    0x503B, // 19101
+    0x51B6, // 19102
+    0x591C, // 19103
+    0x60F9, // 19104
+    0x63F6, // 19105
+    0x6930, // 19106
+    0x723A, // 19107
+    0x8036, // 19108
+    0xF974, // 19109
+    0x91CE, // 19110
+    0x5F31, // 19111
+    0xF975, // 19112
+    0xF976, // 19113
+    0x7D04, // 19114
+    0x82E5, // 19115
+    0x846F, // 19116
+    0x84BB, // 19117
+    0x85E5, // 19118
+    0x8E8D, // 19119
+    0xF977, // 19120
+    0x4F6F, // 19121
+    0xF978, // 19122
+    0xF979, // 19123
+    0x58E4, // 19124
+    0x5B43, // 19125
+    0x6059, // 19126
+    0x63DA, // 19127
+    0x6518, // 19128
+    0x656D, // 19129
+    0x6698, // 19130
+    0xF97A, // 19131
+    0x694A, // 19132
+    0x6A23, // 19133
+    0x6D0B, // 19134
+    0x7001, // 19135
+    0x716C, // 19136
+    0x75D2, // 19137
+    0x760D, // 19138
+    0x79B3, // 19139
+    0x7A70, // 19140
+    0xF97B, // 19141
+    0x7F8A, // 19142
+    0xF97C, // 19143
+    0x8944, // 19144
+    0xF97D, // 19145
+    0x8B93, // 19146
+    0x91C0, // 19147
+    0x967D, // 19148
+    0xF97E, // 19149
+    0x990A, // 19150
+    0x5704, // 19151
+    0x5FA1, // 19152
+    0x65BC, // 19153
+    0x6F01, // 19154
+    0x7600, // 19155
+    0x79A6, // 19156
+    0x8A9E, // 19157
+    0x99AD, // 19158
+    0x9B5A, // 19159
+    0x9F6C, // 19160
+    0x5104, // 19161
+    0x61B6, // 19162
+    0x6291, // 19163
+    0x6A8D, // 19164
+    0x81C6, // 19165
+    0x5043, // 19166
+    0x5830, // 19167
+    0x5F66, // 19168
+    0x7109, // 19169
+    0x8A00, // 19170
+    0x8AFA, // 19171
+    0x5B7C, // 19172
+    0x8616, // 19173
+    0x4FFA, // 19174
+    0x513C, // 19175
+    0x56B4, // 19176
+    0x5944, // 19177
+    0x63A9, // 19178
+    0x6DF9, // 19179
+    0x5DAA, // 19180
+    0x696D, // 19181
+    0x5186, // 19182
+    0x4E88, // 19183
+    0x4F59, // 19184
+    0xF97F, // 19185
+    0xF980, // 19186
+    0xF981, // 19187
+    0x5982, // 19188
+    0xF982, // 19189
+    0x0000, // 19190
+    0x0000, // 19191
+    0x0000, // 19192
+    0x0000, // 19193
+    0x0000, // 19194
+    0x0000, // 19195
+    0x0000, // 19196
+    0x0000, // 19197
+    0x0000, // 19198
+    0x0000, // 19199
+    0x0000, // 19200
+    0x0000, // 19201
+    0x0000, // 19202
+    0x0000, // 19203
+    0x0000, // 19204
+    0x0000, // 19205
+    0x0000, // 19206
+    0x0000, // 19207
+    0x0000, // 19208
+    0x0000, // 19209
+    0x0000, // 19210
+    0x0000, // 19211
+    0x0000, // 19212
+    0x0000, // 19213
+    0x0000, // 19214
+    0x0000, // 19215
+    0x0000, // 19216
+    0x0000, // 19217
+    0x0000, // 19218
+    0x0000, // 19219
+    0x0000, // 19220
+    0x0000, // 19221
+    0x0000, // 19222
+    0x0000, // 19223
+    0x0000, // 19224
+    0x0000, // 19225
+    0x0000, // 19226
+    0x0000, // 19227
+    0x0000, // 19228
+    0x0000, // 19229
+    0x0000, // 19230
+    0x0000, // 19231
+    0x0000, // 19232
+    0x0000, // 19233
+    0x0000, // 19234
+    0x0000, // 19235
+    0x0000, // 19236
+    0x0000, // 19237
+    0x0000, // 19238
+    0x0000, // 19239
+    0x0000, // 19240
+    0x0000, // 19241
+    0x0000, // 19242
+    0x0000, // 19243
+    0x0000, // 19244
+    0x0000, // 19245
+    0x0000, // 19246
+    0x0000, // 19247
+    0x0000, // 19248
+    0x0000, // 19249
+    0x0000, // 19250
+    0x0000, // 19251
+    0x0000, // 19252
+    0x0000, // 19253
+    0x0000, // 19254
+    0x0000, // 19255
+    0x0000, // 19256
+    0x0000, // 19257
+    0x0000, // 19258
+    0x0000, // 19259
+    0x0000, // 19260
+    0x0000, // 19261
+    0x0000, // 19262
+    0x0000, // 19263
+    0x0000, // 19264
+    0x0000, // 19265
+    0x0000, // 19266
+    0x0000, // 19267
+    0x0000, // 19268
+    0x0000, // 19269
+    0x0000, // 19270
+    0x0000, // 19271
+    0x0000, // 19272
+    0x0000, // 19273
+    0x0000, // 19274
+    0x0000, // 19275
+    0x0000, // 19276
+    0x0000, // 19277
+    0x0000, // 19278
+    0x0000, // 19279
+    0x0000, // 19280
+    0x0000, // 19281
+    0x0000, // 19282
+    0x0000, // 19283
+    0x0000, // 19284
+    0x0000, // 19285
+    0xF983, // 19286
+    0x6B5F, // 19287
+    0x6C5D, // 19288
+    0xF984, // 19289
+    0x74B5, // 19290
+    0x7916, // 19291
+    0xF985, // 19292
+    0x8207, // 19293
+    0x8245, // 19294
+    0x8339, // 19295
+    0x8F3F, // 19296
+    0x8F5D, // 19297
+    0xF986, // 19298
+    0x9918, // 19299
+    0xF987, // 19300
+    0xF988, // 19301
+    0xF989, // 19302
+    0x4EA6, // 19303
+    0xF98A, // 19304
+    0x57DF, // 19305
+    0x5F79, // 19306
+    0x6613, // 19307
+    0xF98B, // 19308
+    0xF98C, // 19309
+    0x75AB, // 19310
+    0x7E79, // 19311
+    0x8B6F, // 19312
+    0xF98D, // 19313
+    0x9006, // 19314
+    0x9A5B, // 19315
+    0x56A5, // 19316
+    0x5827, // 19317
+    0x59F8, // 19318
+    0x5A1F, // 19319
+    0x5BB4, // 19320
+    0xF98E, // 19321
+    0x5EF6, // 19322
+    0xF98F, // 19323
+    0xF990, // 19324
+    0x6350, // 19325
+    0x633B, // 19326
+    0xF991, // 19327
+    0x693D, // 19328
+    0x6C87, // 19329
+    0x6CBF, // 19330
+    0x6D8E, // 19331
+    0x6D93, // 19332
+    0x6DF5, // 19333
+    0x6F14, // 19334
+    0xF992, // 19335
+    0x70DF, // 19336
+    0x7136, // 19337
+    0x7159, // 19338
+    0xF993, // 19339
+    0x71C3, // 19340
+    0x71D5, // 19341
+    0xF994, // 19342
+    0x784F, // 19343
+    0x786F, // 19344
+    0xF995, // 19345
+    0x7B75, // 19346
+    0x7DE3, // 19347
+    0xF996, // 19348
+    0x7E2F, // 19349
+    0xF997, // 19350
+    0x884D, // 19351
+    0x8EDF, // 19352
+    0xF998, // 19353
+    0xF999, // 19354
+    0xF99A, // 19355
+    0x925B, // 19356
+    0xF99B, // 19357
+    0x9CF6, // 19358
+    0xF99C, // 19359
+    0xF99D, // 19360
+    0xF99E, // 19361
+    0x6085, // 19362
+    0x6D85, // 19363
+    0xF99F, // 19364
+    0x71B1, // 19365
+    0xF9A0, // 19366
+    0xF9A1, // 19367
+    0x95B1, // 19368
+    0x53AD, // 19369
+    0xF9A2, // 19370
+    0xF9A3, // 19371
+    0xF9A4, // 19372
+    0x67D3, // 19373
+    0xF9A5, // 19374
+    0x708E, // 19375
+    0x7130, // 19376
+    0x7430, // 19377
+    0x8276, // 19378
+    0x82D2, // 19379
+    0x0000, // 19380
+    0x0000, // 19381
+    0x0000, // 19382
+    0x0000, // 19383
+    0x0000, // 19384
+    0x0000, // 19385
+    0x0000, // 19386
+    0x0000, // 19387
+    0x0000, // 19388
+    0x0000, // 19389
+    0x0000, // 19390
+    0x0000, // 19391
+    0x0000, // 19392
+    0x0000, // 19393
+    0x0000, // 19394
+    0x0000, // 19395
+    0x0000, // 19396
+    0x0000, // 19397
+    0x0000, // 19398
+    0x0000, // 19399
+    0x0000, // 19400
+    0x0000, // 19401
+    0x0000, // 19402
+    0x0000, // 19403
+    0x0000, // 19404
+    0x0000, // 19405
+    0x0000, // 19406
+    0x0000, // 19407
+    0x0000, // 19408
+    0x0000, // 19409
+    0x0000, // 19410
+    0x0000, // 19411
+    0x0000, // 19412
+    0x0000, // 19413
+    0x0000, // 19414
+    0x0000, // 19415
+    0x0000, // 19416
+    0x0000, // 19417
+    0x0000, // 19418
+    0x0000, // 19419
+    0x0000, // 19420
+    0x0000, // 19421
+    0x0000, // 19422
+    0x0000, // 19423
+    0x0000, // 19424
+    0x0000, // 19425
+    0x0000, // 19426
+    0x0000, // 19427
+    0x0000, // 19428
+    0x0000, // 19429
+    0x0000, // 19430
+    0x0000, // 19431
+    0x0000, // 19432
+    0x0000, // 19433
+    0x0000, // 19434
+    0x0000, // 19435
+    0x0000, // 19436
+    0x0000, // 19437
+    0x0000, // 19438
+    0x0000, // 19439
+    0x0000, // 19440
+    0x0000, // 19441
+    0x0000, // 19442
+    0x0000, // 19443
+    0x0000, // 19444
+    0x0000, // 19445
+    0x0000, // 19446
+    0x0000, // 19447
+    0x0000, // 19448
+    0x0000, // 19449
+    0x0000, // 19450
+    0x0000, // 19451
+    0x0000, // 19452
+    0x0000, // 19453
+    0x0000, // 19454
+    0x0000, // 19455
+    0x0000, // 19456
+    0x0000, // 19457
+    0x0000, // 19458
+    0x0000, // 19459
+    0x0000, // 19460
+    0x0000, // 19461
+    0x0000, // 19462
+    0x0000, // 19463
+    0x0000, // 19464
+    0x0000, // 19465
+    0x0000, // 19466
+    0x0000, // 19467
+    0x0000, // 19468
+    0x0000, // 19469
+    0x0000, // 19470
+    0x0000, // 19471
+    0x0000, // 19472
+    0x0000, // 19473
+    0x0000, // 19474
+    0x0000, // 19475
+    0xF9A6, // 19476
+    0x95BB, // 19477
+    0x9AE5, // 19478
+    0x9E7D, // 19479
+    0x66C4, // 19480
+    0xF9A7, // 19481
+    0x71C1, // 19482
+    0x8449, // 19483
+    0xF9A8, // 19484
+    0xF9A9, // 19485
+    0x584B, // 19486
+    0xF9AA, // 19487
+    0xF9AB, // 19488
+    0x5DB8, // 19489
+    0x5F71, // 19490
+    0xF9AC, // 19491
+    0x6620, // 19492
+    0x668E, // 19493
+    0x6979, // 19494
+    0x69AE, // 19495
+    0x6C38, // 19496
+    0x6CF3, // 19497
+    0x6E36, // 19498
+    0x6F41, // 19499
+    0x6FDA, // 19500
+    0x701B, // 19501
+    0x702F, // 19502
+    0x7150, // 19503
+    0x71DF, // 19504
+    0x7370, // 19505
+    0xF9AD, // 19506
+    0x745B, // 19507
+    0xF9AE, // 19508
+    0x74D4, // 19509
+    0x76C8, // 19510
+    0x7A4E, // 19511
+    0x7E93, // 19512
+    0xF9AF, // 19513
+    0xF9B0, // 19514
+    0x82F1, // 19515
+    0x8A60, // 19516
+    0x8FCE, // 19517
+    0xF9B1, // 19518
+    0x9348, // 19519
+    0xF9B2, // 19520
+    0x9719, // 19521
+    0xF9B3, // 19522
+    0xF9B4, // 19523
+    0x4E42, // 19524
+    0x502A, // 19525
+    0xF9B5, // 19526
+    0x5208, // 19527
+    0x53E1, // 19528
+    0x66F3, // 19529
+    0x6C6D, // 19530
+    0x6FCA, // 19531
+    0x730A, // 19532
+    0x777F, // 19533
+    0x7A62, // 19534
+    0x82AE, // 19535
+    0x85DD, // 19536
+    0x8602, // 19537
+    0xF9B6, // 19538
+    0x88D4, // 19539
+    0x8A63, // 19540
+    0x8B7D, // 19541
+    0x8C6B, // 19542
+    0xF9B7, // 19543
+    0x92B3, // 19544
+    0xF9B8, // 19545
+    0x9713, // 19546
+    0x9810, // 19547
+    0x4E94, // 19548
+    0x4F0D, // 19549
+    0x4FC9, // 19550
+    0x50B2, // 19551
+    0x5348, // 19552
+    0x543E, // 19553
+    0x5433, // 19554
+    0x55DA, // 19555
+    0x5862, // 19556
+    0x58BA, // 19557
+    0x5967, // 19558
+    0x5A1B, // 19559
+    0x5BE4, // 19560
+    0x609F, // 19561
+    0xF9B9, // 19562
+    0x61CA, // 19563
+    0x6556, // 19564
+    0x65FF, // 19565
+    0x6664, // 19566
+    0x68A7, // 19567
+    0x6C5A, // 19568
+    0x6FB3, // 19569
+    0x0000, // 19570
+    0x0000, // 19571
+    0x0000, // 19572
+    0x0000, // 19573
+    0x0000, // 19574
+    0x0000, // 19575
+    0x0000, // 19576
+    0x0000, // 19577
+    0x0000, // 19578
+    0x0000, // 19579
+    0x0000, // 19580
+    0x0000, // 19581
+    0x0000, // 19582
+    0x0000, // 19583
+    0x0000, // 19584
+    0x0000, // 19585
+    0x0000, // 19586
+    0x0000, // 19587
+    0x0000, // 19588
+    0x0000, // 19589
+    0x0000, // 19590
+    0x0000, // 19591
+    0x0000, // 19592
+    0x0000, // 19593
+    0x0000, // 19594
+    0x0000, // 19595
+    0x0000, // 19596
+    0x0000, // 19597
+    0x0000, // 19598
+    0x0000, // 19599
+    0x0000, // 19600
+    0x0000, // 19601
+    0x0000, // 19602
+    0x0000, // 19603
+    0x0000, // 19604
+    0x0000, // 19605
+    0x0000, // 19606
+    0x0000, // 19607
+    0x0000, // 19608
+    0x0000, // 19609
+    0x0000, // 19610
+    0x0000, // 19611
+    0x0000, // 19612
+    0x0000, // 19613
+    0x0000, // 19614
+    0x0000, // 19615
+    0x0000, // 19616
+    0x0000, // 19617
+    0x0000, // 19618
+    0x0000, // 19619
+    0x0000, // 19620
+    0x0000, // 19621
+    0x0000, // 19622
+    0x0000, // 19623
+    0x0000, // 19624
+    0x0000, // 19625
+    0x0000, // 19626
+    0x0000, // 19627
+    0x0000, // 19628
+    0x0000, // 19629
+    0x0000, // 19630
+    0x0000, // 19631
+    0x0000, // 19632
+    0x0000, // 19633
+    0x0000, // 19634
+    0x0000, // 19635
+    0x0000, // 19636
+    0x0000, // 19637
+    0x0000, // 19638
+    0x0000, // 19639
+    0x0000, // 19640
+    0x0000, // 19641
+    0x0000, // 19642
+    0x0000, // 19643
+    0x0000, // 19644
+    0x0000, // 19645
+    0x0000, // 19646
+    0x0000, // 19647
+    0x0000, // 19648
+    0x0000, // 19649
+    0x0000, // 19650
+    0x0000, // 19651
+    0x0000, // 19652
+    0x0000, // 19653
+    0x0000, // 19654
+    0x0000, // 19655
+    0x0000, // 19656
+    0x0000, // 19657
+    0x0000, // 19658
+    0x0000, // 19659
+    0x0000, // 19660
+    0x0000, // 19661
+    0x0000, // 19662
+    0x0000, // 19663
+    0x0000, // 19664
+    0x0000, // 19665
+    0x70CF, // 19666
+    0x71AC, // 19667
+    0x7352, // 19668
+    0x7B7D, // 19669
+    0x8708, // 19670
+    0x8AA4, // 19671
+    0x9C32, // 19672
+    0x9F07, // 19673
+    0x5C4B, // 19674
+    0x6C83, // 19675
+    0x7344, // 19676
+    0x7389, // 19677
+    0x923A, // 19678
+    0x6EAB, // 19679
+    0x7465, // 19680
+    0x761F, // 19681
+    0x7A69, // 19682
+    0x7E15, // 19683
+    0x860A, // 19684
+    0x5140, // 19685
+    0x58C5, // 19686
+    0x64C1, // 19687
+    0x74EE, // 19688
+    0x7515, // 19689
+    0x7670, // 19690
+    0x7FC1, // 19691
+    0x9095, // 19692
+    0x96CD, // 19693
+    0x9954, // 19694
+    0x6E26, // 19695
+    0x74E6, // 19696
+    0x7AA9, // 19697
+    0x7AAA, // 19698
+    0x81E5, // 19699
+    0x86D9, // 19700
+    0x8778, // 19701
+    0x8A1B, // 19702
+    0x5A49, // 19703
+    0x5B8C, // 19704
+    0x5B9B, // 19705
+    0x68A1, // 19706
+    0x6900, // 19707
+    0x6D63, // 19708
+    0x73A9, // 19709
+    0x7413, // 19710
+    0x742C, // 19711
+    0x7897, // 19712
+    0x7DE9, // 19713
+    0x7FEB, // 19714
+    0x8118, // 19715
+    0x8155, // 19716
+    0x839E, // 19717
+    0x8C4C, // 19718
+    0x962E, // 19719
+    0x9811, // 19720
+    0x66F0, // 19721
+    0x5F80, // 19722
+    0x65FA, // 19723
+    0x6789, // 19724
+    0x6C6A, // 19725
+    0x738B, // 19726
+    0x502D, // 19727
+    0x5A03, // 19728
+    0x6B6A, // 19729
+    0x77EE, // 19730
+    0x5916, // 19731
+    0x5D6C, // 19732
+    0x5DCD, // 19733
+    0x7325, // 19734
+    0x754F, // 19735
+    0xF9BA, // 19736
+    0xF9BB, // 19737
+    0x50E5, // 19738
+    0x51F9, // 19739
+    0x582F, // 19740
+    0x592D, // 19741
+    0x5996, // 19742
+    0x59DA, // 19743
+    0x5BE5, // 19744
+    0xF9BC, // 19745
+    0xF9BD, // 19746
+    0x5DA2, // 19747
+    0x62D7, // 19748
+    0x6416, // 19749
+    0x6493, // 19750
+    0x64FE, // 19751
+    0xF9BE, // 19752
+    0x66DC, // 19753
+    0xF9BF, // 19754
+    0x6A48, // 19755
+    0xF9C0, // 19756
+    0x71FF, // 19757
+    0x7464, // 19758
+    0xF9C1, // 19759
+    0x0000, // 19760
+    0x0000, // 19761
+    0x0000, // 19762
+    0x0000, // 19763
+    0x0000, // 19764
+    0x0000, // 19765
+    0x0000, // 19766
+    0x0000, // 19767
+    0x0000, // 19768
+    0x0000, // 19769
+    0x0000, // 19770
+    0x0000, // 19771
+    0x0000, // 19772
+    0x0000, // 19773
+    0x0000, // 19774
+    0x0000, // 19775
+    0x0000, // 19776
+    0x0000, // 19777
+    0x0000, // 19778
+    0x0000, // 19779
+    0x0000, // 19780
+    0x0000, // 19781
+    0x0000, // 19782
+    0x0000, // 19783
+    0x0000, // 19784
+    0x0000, // 19785
+    0x0000, // 19786
+    0x0000, // 19787
+    0x0000, // 19788
+    0x0000, // 19789
+    0x0000, // 19790
+    0x0000, // 19791
+    0x0000, // 19792
+    0x0000, // 19793
+    0x0000, // 19794
+    0x0000, // 19795
+    0x0000, // 19796
+    0x0000, // 19797
+    0x0000, // 19798
+    0x0000, // 19799
+    0x0000, // 19800
+    0x0000, // 19801
+    0x0000, // 19802
+    0x0000, // 19803
+    0x0000, // 19804
+    0x0000, // 19805
+    0x0000, // 19806
+    0x0000, // 19807
+    0x0000, // 19808
+    0x0000, // 19809
+    0x0000, // 19810
+    0x0000, // 19811
+    0x0000, // 19812
+    0x0000, // 19813
+    0x0000, // 19814
+    0x0000, // 19815
+    0x0000, // 19816
+    0x0000, // 19817
+    0x0000, // 19818
+    0x0000, // 19819
+    0x0000, // 19820
+    0x0000, // 19821
+    0x0000, // 19822
+    0x0000, // 19823
+    0x0000, // 19824
+    0x0000, // 19825
+    0x0000, // 19826
+    0x0000, // 19827
+    0x0000, // 19828
+    0x0000, // 19829
+    0x0000, // 19830
+    0x0000, // 19831
+    0x0000, // 19832
+    0x0000, // 19833
+    0x0000, // 19834
+    0x0000, // 19835
+    0x0000, // 19836
+    0x0000, // 19837
+    0x0000, // 19838
+    0x0000, // 19839
+    0x0000, // 19840
+    0x0000, // 19841
+    0x0000, // 19842
+    0x0000, // 19843
+    0x0000, // 19844
+    0x0000, // 19845
+    0x0000, // 19846
+    0x0000, // 19847
+    0x0000, // 19848
+    0x0000, // 19849
+    0x0000, // 19850
+    0x0000, // 19851
+    0x0000, // 19852
+    0x0000, // 19853
+    0x0000, // 19854
+    0x0000, // 19855
+    0x7A88, // 19856
+    0x7AAF, // 19857
+    0x7E47, // 19858
+    0x7E5E, // 19859
+    0x8000, // 19860
+    0x8170, // 19861
+    0xF9C2, // 19862
+    0x87EF, // 19863
+    0x8981, // 19864
+    0x8B20, // 19865
+    0x9059, // 19866
+    0xF9C3, // 19867
+    0x9080, // 19868
+    0x9952, // 19869
+    0x617E, // 19870
+    0x6B32, // 19871
+    0x6D74, // 19872
+    0x7E1F, // 19873
+    0x8925, // 19874
+    0x8FB1, // 19875
+    0x4FD1, // 19876
+    0x50AD, // 19877
+    0x5197, // 19878
+    0x52C7, // 19879
+    0x57C7, // 19880
+    0x5889, // 19881
+    0x5BB9, // 19882
+    0x5EB8, // 19883
+    0x6142, // 19884
+    0x6995, // 19885
+    0x6D8C, // 19886
+    0x6E67, // 19887
+    0x6EB6, // 19888
+    0x7194, // 19889
+    0x7462, // 19890
+    0x7528, // 19891
+    0x752C, // 19892
+    0x8073, // 19893
+    0x8338, // 19894
+    0x84C9, // 19895
+    0x8E0A, // 19896
+    0x9394, // 19897
+    0x93DE, // 19898
+    0xF9C4, // 19899
+    0x4E8E, // 19900
+    0x4F51, // 19901
+    0x5076, // 19902
+    0x512A, // 19903
+    0x53C8, // 19904
+    0x53CB, // 19905
+    0x53F3, // 19906
+    0x5B87, // 19907
+    0x5BD3, // 19908
+    0x5C24, // 19909
+    0x611A, // 19910
+    0x6182, // 19911
+    0x65F4, // 19912
+    0x725B, // 19913
+    0x7397, // 19914
+    0x7440, // 19915
+    0x76C2, // 19916
+    0x7950, // 19917
+    0x7991, // 19918
+    0x79B9, // 19919
+    0x7D06, // 19920
+    0x7FBD, // 19921
+    0x828B, // 19922
+    0x85D5, // 19923
+    0x865E, // 19924
+    0x8FC2, // 19925
+    0x9047, // 19926
+    0x90F5, // 19927
+    0x91EA, // 19928
+    0x9685, // 19929
+    0x96E8, // 19930
+    0x96E9, // 19931
+    0x52D6, // 19932
+    0x5F67, // 19933
+    0x65ED, // 19934
+    0x6631, // 19935
+    0x682F, // 19936
+    0x715C, // 19937
+    0x7A36, // 19938
+    0x90C1, // 19939
+    0x980A, // 19940
+    0x4E91, // 19941
+    0xF9C5, // 19942
+    0x6A52, // 19943
+    0x6B9E, // 19944
+    0x6F90, // 19945
+    0x7189, // 19946
+    0x8018, // 19947
+    0x82B8, // 19948
+    0x8553, // 19949
+    0x0000, // 19950
+    0x0000, // 19951
+    0x0000, // 19952
+    0x0000, // 19953
+    0x0000, // 19954
+    0x0000, // 19955
+    0x0000, // 19956
+    0x0000, // 19957
+    0x0000, // 19958
+    0x0000, // 19959
+    0x0000, // 19960
+    0x0000, // 19961
+    0x0000, // 19962
+    0x0000, // 19963
+    0x0000, // 19964
+    0x0000, // 19965
+    0x0000, // 19966
+    0x0000, // 19967
+    0x0000, // 19968
+    0x0000, // 19969
+    0x0000, // 19970
+    0x0000, // 19971
+    0x0000, // 19972
+    0x0000, // 19973
+    0x0000, // 19974
+    0x0000, // 19975
+    0x0000, // 19976
+    0x0000, // 19977
+    0x0000, // 19978
+    0x0000, // 19979
+    0x0000, // 19980
+    0x0000, // 19981
+    0x0000, // 19982
+    0x0000, // 19983
+    0x0000, // 19984
+    0x0000, // 19985
+    0x0000, // 19986
+    0x0000, // 19987
+    0x0000, // 19988
+    0x0000, // 19989
+    0x0000, // 19990
+    0x0000, // 19991
+    0x0000, // 19992
+    0x0000, // 19993
+    0x0000, // 19994
+    0x0000, // 19995
+    0x0000, // 19996
+    0x0000, // 19997
+    0x0000, // 19998
+    0x0000, // 19999
+    0x0000, // 20000
+    0x0000, // 20001
+    0x0000, // 20002
+    0x0000, // 20003
+    0x0000, // 20004
+    0x0000, // 20005
+    0x0000, // 20006
+    0x0000, // 20007
+    0x0000, // 20008
+    0x0000, // 20009
+    0x0000, // 20010
+    0x0000, // 20011
+    0x0000, // 20012
+    0x0000, // 20013
+    0x0000, // 20014
+    0x0000, // 20015
+    0x0000, // 20016
+    0x0000, // 20017
+    0x0000, // 20018
+    0x0000, // 20019
+    0x0000, // 20020
+    0x0000, // 20021
+    0x0000, // 20022
+    0x0000, // 20023
+    0x0000, // 20024
+    0x0000, // 20025
+    0x0000, // 20026
+    0x0000, // 20027
+    0x0000, // 20028
+    0x0000, // 20029
+    0x0000, // 20030
+    0x0000, // 20031
+    0x0000, // 20032
+    0x0000, // 20033
+    0x0000, // 20034
+    0x0000, // 20035
+    0x0000, // 20036
+    0x0000, // 20037
+    0x0000, // 20038
+    0x0000, // 20039
+    0x0000, // 20040
+    0x0000, // 20041
+    0x0000, // 20042
+    0x0000, // 20043
+    0x0000, // 20044
+    0x0000, // 20045
+    0x904B, // 20046
+    0x9695, // 20047
+    0x96F2, // 20048
+    0x97FB, // 20049
+    0x851A, // 20050
+    0x9B31, // 20051
+    0x4E90, // 20052
+    0x718A, // 20053
+    0x96C4, // 20054
+    0x5143, // 20055
+    0x539F, // 20056
+    0x54E1, // 20057
+    0x5713, // 20058
+    0x5712, // 20059
+    0x57A3, // 20060
+    0x5A9B, // 20061
+    0x5AC4, // 20062
+    0x5BC3, // 20063
+    0x6028, // 20064
+    0x613F, // 20065
+    0x63F4, // 20066
+    0x6C85, // 20067
+    0x6D39, // 20068
+    0x6E72, // 20069
+    0x6E90, // 20070
+    0x7230, // 20071
+    0x733F, // 20072
+    0x7457, // 20073
+    0x82D1, // 20074
+    0x8881, // 20075
+    0x8F45, // 20076
+    0x9060, // 20077
+    0xF9C6, // 20078
+    0x9662, // 20079
+    0x9858, // 20080
+    0x9D1B, // 20081
+    0x6708, // 20082
+    0x8D8A, // 20083
+    0x925E, // 20084
+    0x4F4D, // 20085
+    0x5049, // 20086
+    0x50DE, // 20087
+    0x5371, // 20088
+    0x570D, // 20089
+    0x59D4, // 20090
+    0x5A01, // 20091
+    0x5C09, // 20092
+    0x6170, // 20093
+    0x6690, // 20094
+    0x6E2D, // 20095
+    0x7232, // 20096
+    0x744B, // 20097
+    0x7DEF, // 20098
+    0x80C3, // 20099
+    0x840E, // 20100
+    0x8466, // 20101
+    0x853F, // 20102
+    0x875F, // 20103
+    0x885B, // 20104
+    0x8918, // 20105
+    0x8B02, // 20106
+    0x9055, // 20107
+    0x97CB, // 20108
+    0x9B4F, // 20109
+    0x4E73, // 20110
+    0x4F91, // 20111
+    0x5112, // 20112
+    0x516A, // 20113
+    0xF9C7, // 20114
+    0x552F, // 20115
+    0x55A9, // 20116
+    0x5B7A, // 20117
+    0x5BA5, // 20118
+    0x5E7C, // 20119
+    0x5E7D, // 20120
+    0x5EBE, // 20121
+    0x60A0, // 20122
+    0x60DF, // 20123
+    0x6108, // 20124
+    0x6109, // 20125
+    0x63C4, // 20126
+    0x6538, // 20127
+    0x6709, // 20128
+    0xF9C8, // 20129
+    0x67D4, // 20130
+    0x67DA, // 20131
+    0xF9C9, // 20132
+    0x6961, // 20133
+    0x6962, // 20134
+    0x6CB9, // 20135
+    0x6D27, // 20136
+    0xF9CA, // 20137
+    0x6E38, // 20138
+    0xF9CB, // 20139
+    0x0000, // 20140
+    0x0000, // 20141
+    0x0000, // 20142
+    0x0000, // 20143
+    0x0000, // 20144
+    0x0000, // 20145
+    0x0000, // 20146
+    0x0000, // 20147
+    0x0000, // 20148
+    0x0000, // 20149
+    0x0000, // 20150
+    0x0000, // 20151
+    0x0000, // 20152
+    0x0000, // 20153
+    0x0000, // 20154
+    0x0000, // 20155
+    0x0000, // 20156
+    0x0000, // 20157
+    0x0000, // 20158
+    0x0000, // 20159
+    0x0000, // 20160
+    0x0000, // 20161
+    0x0000, // 20162
+    0x0000, // 20163
+    0x0000, // 20164
+    0x0000, // 20165
+    0x0000, // 20166
+    0x0000, // 20167
+    0x0000, // 20168
+    0x0000, // 20169
+    0x0000, // 20170
+    0x0000, // 20171
+    0x0000, // 20172
+    0x0000, // 20173
+    0x0000, // 20174
+    0x0000, // 20175
+    0x0000, // 20176
+    0x0000, // 20177
+    0x0000, // 20178
+    0x0000, // 20179
+    0x0000, // 20180
+    0x0000, // 20181
+    0x0000, // 20182
+    0x0000, // 20183
+    0x0000, // 20184
+    0x0000, // 20185
+    0x0000, // 20186
+    0x0000, // 20187
+    0x0000, // 20188
+    0x0000, // 20189
+    0x0000, // 20190
+    0x0000, // 20191
+    0x0000, // 20192
+    0x0000, // 20193
+    0x0000, // 20194
+    0x0000, // 20195
+    0x0000, // 20196
+    0x0000, // 20197
+    0x0000, // 20198
+    0x0000, // 20199
+    0x0000, // 20200
+    0x0000, // 20201
+    0x0000, // 20202
+    0x0000, // 20203
+    0x0000, // 20204
+    0x0000, // 20205
+    0x0000, // 20206
+    0x0000, // 20207
+    0x0000, // 20208
+    0x0000, // 20209
+    0x0000, // 20210
+    0x0000, // 20211
+    0x0000, // 20212
+    0x0000, // 20213
+    0x0000, // 20214
+    0x0000, // 20215
+    0x0000, // 20216
+    0x0000, // 20217
+    0x0000, // 20218
+    0x0000, // 20219
+    0x0000, // 20220
+    0x0000, // 20221
+    0x0000, // 20222
+    0x0000, // 20223
+    0x0000, // 20224
+    0x0000, // 20225
+    0x0000, // 20226
+    0x0000, // 20227
+    0x0000, // 20228
+    0x0000, // 20229
+    0x0000, // 20230
+    0x0000, // 20231
+    0x0000, // 20232
+    0x0000, // 20233
+    0x0000, // 20234
+    0x0000, // 20235
+    0x6FE1, // 20236
+    0x7336, // 20237
+    0x7337, // 20238
+    0xF9CC, // 20239
+    0x745C, // 20240
+    0x7531, // 20241
+    0xF9CD, // 20242
+    0x7652, // 20243
+    0xF9CE, // 20244
+    0xF9CF, // 20245
+    0x7DAD, // 20246
+    0x81FE, // 20247
+    0x8438, // 20248
+    0x88D5, // 20249
+    0x8A98, // 20250
+    0x8ADB, // 20251
+    0x8AED, // 20252
+    0x8E30, // 20253
+    0x8E42, // 20254
+    0x904A, // 20255
+    0x903E, // 20256
+    0x907A, // 20257
+    0x9149, // 20258
+    0x91C9, // 20259
+    0x936E, // 20260
+    0xF9D0, // 20261
+    0xF9D1, // 20262
+    0x5809, // 20263
+    0xF9D2, // 20264
+    0x6BD3, // 20265
+    0x8089, // 20266
+    0x80B2, // 20267
+    0xF9D3, // 20268
+    0xF9D4, // 20269
+    0x5141, // 20270
+    0x596B, // 20271
+    0x5C39, // 20272
+    0xF9D5, // 20273
+    0xF9D6, // 20274
+    0x6F64, // 20275
+    0x73A7, // 20276
+    0x80E4, // 20277
+    0x8D07, // 20278
+    0xF9D7, // 20279
+    0x9217, // 20280
+    0x958F, // 20281
+    0xF9D8, // 20282
+    0xF9D9, // 20283
+    0xF9DA, // 20284
+    0xF9DB, // 20285
+    0x807F, // 20286
+    0x620E, // 20287
+    0x701C, // 20288
+    0x7D68, // 20289
+    0x878D, // 20290
+    0xF9DC, // 20291
+    0x57A0, // 20292
+    0x6069, // 20293
+    0x6147, // 20294
+    0x6BB7, // 20295
+    0x8ABE, // 20296
+    0x9280, // 20297
+    0x96B1, // 20298
+    0x4E59, // 20299
+    0x541F, // 20300
+    0x6DEB, // 20301
+    0x852D, // 20302
+    0x9670, // 20303
+    0x97F3, // 20304
+    0x98EE, // 20305
+    0x63D6, // 20306
+    0x6CE3, // 20307
+    0x9091, // 20308
+    0x51DD, // 20309
+    0x61C9, // 20310
+    0x81BA, // 20311
+    0x9DF9, // 20312
+    0x4F9D, // 20313
+    0x501A, // 20314
+    0x5100, // 20315
+    0x5B9C, // 20316
+    0x610F, // 20317
+    0x61FF, // 20318
+    0x64EC, // 20319
+    0x6905, // 20320
+    0x6BC5, // 20321
+    0x7591, // 20322
+    0x77E3, // 20323
+    0x7FA9, // 20324
+    0x8264, // 20325
+    0x858F, // 20326
+    0x87FB, // 20327
+    0x8863, // 20328
+    0x8ABC, // 20329
+    0x0000, // 20330
+    0x0000, // 20331
+    0x0000, // 20332
+    0x0000, // 20333
+    0x0000, // 20334
+    0x0000, // 20335
+    0x0000, // 20336
+    0x0000, // 20337
+    0x0000, // 20338
+    0x0000, // 20339
+    0x0000, // 20340
+    0x0000, // 20341
+    0x0000, // 20342
+    0x0000, // 20343
+    0x0000, // 20344
+    0x0000, // 20345
+    0x0000, // 20346
+    0x0000, // 20347
+    0x0000, // 20348
+    0x0000, // 20349
+    0x0000, // 20350
+    0x0000, // 20351
+    0x0000, // 20352
+    0x0000, // 20353
+    0x0000, // 20354
+    0x0000, // 20355
+    0x0000, // 20356
+    0x0000, // 20357
+    0x0000, // 20358
+    0x0000, // 20359
+    0x0000, // 20360
+    0x0000, // 20361
+    0x0000, // 20362
+    0x0000, // 20363
+    0x0000, // 20364
+    0x0000, // 20365
+    0x0000, // 20366
+    0x0000, // 20367
+    0x0000, // 20368
+    0x0000, // 20369
+    0x0000, // 20370
+    0x0000, // 20371
+    0x0000, // 20372
+    0x0000, // 20373
+    0x0000, // 20374
+    0x0000, // 20375
+    0x0000, // 20376
+    0x0000, // 20377
+    0x0000, // 20378
+    0x0000, // 20379
+    0x0000, // 20380
+    0x0000, // 20381
+    0x0000, // 20382
+    0x0000, // 20383
+    0x0000, // 20384
+    0x0000, // 20385
+    0x0000, // 20386
+    0x0000, // 20387
+    0x0000, // 20388
+    0x0000, // 20389
+    0x0000, // 20390
+    0x0000, // 20391
+    0x0000, // 20392
+    0x0000, // 20393
+    0x0000, // 20394
+    0x0000, // 20395
+    0x0000, // 20396
+    0x0000, // 20397
+    0x0000, // 20398
+    0x0000, // 20399
+    0x0000, // 20400
+    0x0000, // 20401
+    0x0000, // 20402
+    0x0000, // 20403
+    0x0000, // 20404
+    0x0000, // 20405
+    0x0000, // 20406
+    0x0000, // 20407
+    0x0000, // 20408
+    0x0000, // 20409
+    0x0000, // 20410
+    0x0000, // 20411
+    0x0000, // 20412
+    0x0000, // 20413
+    0x0000, // 20414
+    0x0000, // 20415
+    0x0000, // 20416
+    0x0000, // 20417
+    0x0000, // 20418
+    0x0000, // 20419
+    0x0000, // 20420
+    0x0000, // 20421
+    0x0000, // 20422
+    0x0000, // 20423
+    0x0000, // 20424
+    0x0000, // 20425
+    0x8B70, // 20426
+    0x91AB, // 20427
+    0x4E8C, // 20428
+    0x4EE5, // 20429
+    0x4F0A, // 20430
+    0xF9DD, // 20431
+    0xF9DE, // 20432
+    0x5937, // 20433
+    0x59E8, // 20434
+    0xF9DF, // 20435
+    0x5DF2, // 20436
+    0x5F1B, // 20437
+    0x5F5B, // 20438
+    0x6021, // 20439
+    0xF9E0, // 20440
+    0xF9E1, // 20441
+    0xF9E2, // 20442
+    0xF9E3, // 20443
+    0x723E, // 20444
+    0x73E5, // 20445
+    0xF9E4, // 20446
+    0x7570, // 20447
+    0x75CD, // 20448
+    0xF9E5, // 20449
+    0x79FB, // 20450
+    0xF9E6, // 20451
+    0x800C, // 20452
+    0x8033, // 20453
+    0x8084, // 20454
+    0x82E1, // 20455
+    0x8351, // 20456
+    0xF9E7, // 20457
+    0xF9E8, // 20458
+    0x8CBD, // 20459
+    0x8CB3, // 20460
+    0x9087, // 20461
+    0xF9E9, // 20462
+    0xF9EA, // 20463
+    0x98F4, // 20464
+    0x990C, // 20465
+    0xF9EB, // 20466
+    0xF9EC, // 20467
+    0x7037, // 20468
+    0x76CA, // 20469
+    0x7FCA, // 20470
+    0x7FCC, // 20471
+    0x7FFC, // 20472
+    0x8B1A, // 20473
+    0x4EBA, // 20474
+    0x4EC1, // 20475
+    0x5203, // 20476
+    0x5370, // 20477
+    0xF9ED, // 20478
+    0x54BD, // 20479
+    0x56E0, // 20480
+    0x59FB, // 20481
+    0x5BC5, // 20482
+    0x5F15, // 20483
+    0x5FCD, // 20484
+    0x6E6E, // 20485
+    0xF9EE, // 20486
+    0xF9EF, // 20487
+    0x7D6A, // 20488
+    0x8335, // 20489
+    0xF9F0, // 20490
+    0x8693, // 20491
+    0x8A8D, // 20492
+    0xF9F1, // 20493
+    0x976D, // 20494
+    0x9777, // 20495
+    0xF9F2, // 20496
+    0xF9F3, // 20497
+    0x4E00, // 20498
+    0x4F5A, // 20499
+    0x4F7E, // 20500
+    0x58F9, // 20501
+    0x65E5, // 20502
+    0x6EA2, // 20503
+    0x9038, // 20504
+    0x93B0, // 20505
+    0x99B9, // 20506
+    0x4EFB, // 20507
+    0x58EC, // 20508
+    0x598A, // 20509
+    0x59D9, // 20510
+    0x6041, // 20511
+    0xF9F4, // 20512
+    0xF9F5, // 20513
+    0x7A14, // 20514
+    0xF9F6, // 20515
+    0x834F, // 20516
+    0x8CC3, // 20517
+    0x5165, // 20518
+    0x5344, // 20519
+    0x0000, // 20520
+    0x0000, // 20521
+    0x0000, // 20522
+    0x0000, // 20523
+    0x0000, // 20524
+    0x0000, // 20525
+    0x0000, // 20526
+    0x0000, // 20527
+    0x0000, // 20528
+    0x0000, // 20529
+    0x0000, // 20530
+    0x0000, // 20531
+    0x0000, // 20532
+    0x0000, // 20533
+    0x0000, // 20534
+    0x0000, // 20535
+    0x0000, // 20536
+    0x0000, // 20537
+    0x0000, // 20538
+    0x0000, // 20539
+    0x0000, // 20540
+    0x0000, // 20541
+    0x0000, // 20542
+    0x0000, // 20543
+    0x0000, // 20544
+    0x0000, // 20545
+    0x0000, // 20546
+    0x0000, // 20547
+    0x0000, // 20548
+    0x0000, // 20549
+    0x0000, // 20550
+    0x0000, // 20551
+    0x0000, // 20552
+    0x0000, // 20553
+    0x0000, // 20554
+    0x0000, // 20555
+    0x0000, // 20556
+    0x0000, // 20557
+    0x0000, // 20558
+    0x0000, // 20559
+    0x0000, // 20560
+    0x0000, // 20561
+    0x0000, // 20562
+    0x0000, // 20563
+    0x0000, // 20564
+    0x0000, // 20565
+    0x0000, // 20566
+    0x0000, // 20567
+    0x0000, // 20568
+    0x0000, // 20569
+    0x0000, // 20570
+    0x0000, // 20571
+    0x0000, // 20572
+    0x0000, // 20573
+    0x0000, // 20574
+    0x0000, // 20575
+    0x0000, // 20576
+    0x0000, // 20577
+    0x0000, // 20578
+    0x0000, // 20579
+    0x0000, // 20580
+    0x0000, // 20581
+    0x0000, // 20582
+    0x0000, // 20583
+    0x0000, // 20584
+    0x0000, // 20585
+    0x0000, // 20586
+    0x0000, // 20587
+    0x0000, // 20588
+    0x0000, // 20589
+    0x0000, // 20590
+    0x0000, // 20591
+    0x0000, // 20592
+    0x0000, // 20593
+    0x0000, // 20594
+    0x0000, // 20595
+    0x0000, // 20596
+    0x0000, // 20597
+    0x0000, // 20598
+    0x0000, // 20599
+    0x0000, // 20600
+    0x0000, // 20601
+    0x0000, // 20602
+    0x0000, // 20603
+    0x0000, // 20604
+    0x0000, // 20605
+    0x0000, // 20606
+    0x0000, // 20607
+    0x0000, // 20608
+    0x0000, // 20609
+    0x0000, // 20610
+    0x0000, // 20611
+    0x0000, // 20612
+    0x0000, // 20613
+    0x0000, // 20614
+    0x0000, // 20615
+    0xF9F7, // 20616
+    0xF9F8, // 20617
+    0xF9F9, // 20618
+    0x4ECD, // 20619
+    0x5269, // 20620
+    0x5B55, // 20621
+    0x82BF, // 20622
+    0x4ED4, // 20623
+    0x523A, // 20624
+    0x54A8, // 20625
+    0x59C9, // 20626
+    0x59FF, // 20627
+    0x5B50, // 20628
+    0x5B57, // 20629
+    0x5B5C, // 20630
+    0x6063, // 20631
+    0x6148, // 20632
+    0x6ECB, // 20633
+    0x7099, // 20634
+    0x716E, // 20635
+    0x7386, // 20636
+    0x74F7, // 20637
+    0x75B5, // 20638
+    0x78C1, // 20639
+    0x7D2B, // 20640
+    0x8005, // 20641
+    0x81EA, // 20642
+    0x8328, // 20643
+    0x8517, // 20644
+    0x85C9, // 20645
+    0x8AEE, // 20646
+    0x8CC7, // 20647
+    0x96CC, // 20648
+    0x4F5C, // 20649
+    0x52FA, // 20650
+    0x56BC, // 20651
+    0x65AB, // 20652
+    0x6628, // 20653
+    0x707C, // 20654
+    0x70B8, // 20655
+    0x7235, // 20656
+    0x7DBD, // 20657
+    0x828D, // 20658
+    0x914C, // 20659
+    0x96C0, // 20660
+    0x9D72, // 20661
+    0x5B71, // 20662
+    0x68E7, // 20663
+    0x6B98, // 20664
+    0x6F7A, // 20665
+    0x76DE, // 20666
+    0x5C91, // 20667
+    0x66AB, // 20668
+    0x6F5B, // 20669
+    0x7BB4, // 20670
+    0x7C2A, // 20671
+    0x8836, // 20672
+    0x96DC, // 20673
+    0x4E08, // 20674
+    0x4ED7, // 20675
+    0x5320, // 20676
+    0x5834, // 20677
+    0x58BB, // 20678
+    0x58EF, // 20679
+    0x596C, // 20680
+    0x5C07, // 20681
+    0x5E33, // 20682
+    0x5E84, // 20683
+    0x5F35, // 20684
+    0x638C, // 20685
+    0x66B2, // 20686
+    0x6756, // 20687
+    0x6A1F, // 20688
+    0x6AA3, // 20689
+    0x6B0C, // 20690
+    0x6F3F, // 20691
+    0x7246, // 20692
+    0xF9FA, // 20693
+    0x7350, // 20694
+    0x748B, // 20695
+    0x7AE0, // 20696
+    0x7CA7, // 20697
+    0x8178, // 20698
+    0x81DF, // 20699
+    0x81E7, // 20700
+    0x838A, // 20701
+    0x846C, // 20702
+    0x8523, // 20703
+    0x8594, // 20704
+    0x85CF, // 20705
+    0x88DD, // 20706
+    0x8D13, // 20707
+    0x91AC, // 20708
+    0x9577, // 20709
+    0x0000, // 20710
+    0x0000, // 20711
+    0x0000, // 20712
+    0x0000, // 20713
+    0x0000, // 20714
+    0x0000, // 20715
+    0x0000, // 20716
+    0x0000, // 20717
+    0x0000, // 20718
+    0x0000, // 20719
+    0x0000, // 20720
+    0x0000, // 20721
+    0x0000, // 20722
+    0x0000, // 20723
+    0x0000, // 20724
+    0x0000, // 20725
+    0x0000, // 20726
+    0x0000, // 20727
+    0x0000, // 20728
+    0x0000, // 20729
+    0x0000, // 20730
+    0x0000, // 20731
+    0x0000, // 20732
+    0x0000, // 20733
+    0x0000, // 20734
+    0x0000, // 20735
+    0x0000, // 20736
+    0x0000, // 20737
+    0x0000, // 20738
+    0x0000, // 20739
+    0x0000, // 20740
+    0x0000, // 20741
+    0x0000, // 20742
+    0x0000, // 20743
+    0x0000, // 20744
+    0x0000, // 20745
+    0x0000, // 20746
+    0x0000, // 20747
+    0x0000, // 20748
+    0x0000, // 20749
+    0x0000, // 20750
+    0x0000, // 20751
+    0x0000, // 20752
+    0x0000, // 20753
+    0x0000, // 20754
+    0x0000, // 20755
+    0x0000, // 20756
+    0x0000, // 20757
+    0x0000, // 20758
+    0x0000, // 20759
+    0x0000, // 20760
+    0x0000, // 20761
+    0x0000, // 20762
+    0x0000, // 20763
+    0x0000, // 20764
+    0x0000, // 20765
+    0x0000, // 20766
+    0x0000, // 20767
+    0x0000, // 20768
+    0x0000, // 20769
+    0x0000, // 20770
+    0x0000, // 20771
+    0x0000, // 20772
+    0x0000, // 20773
+    0x0000, // 20774
+    0x0000, // 20775
+    0x0000, // 20776
+    0x0000, // 20777
+    0x0000, // 20778
+    0x0000, // 20779
+    0x0000, // 20780
+    0x0000, // 20781
+    0x0000, // 20782
+    0x0000, // 20783
+    0x0000, // 20784
+    0x0000, // 20785
+    0x0000, // 20786
+    0x0000, // 20787
+    0x0000, // 20788
+    0x0000, // 20789
+    0x0000, // 20790
+    0x0000, // 20791
+    0x0000, // 20792
+    0x0000, // 20793
+    0x0000, // 20794
+    0x0000, // 20795
+    0x0000, // 20796
+    0x0000, // 20797
+    0x0000, // 20798
+    0x0000, // 20799
+    0x0000, // 20800
+    0x0000, // 20801
+    0x0000, // 20802
+    0x0000, // 20803
+    0x0000, // 20804
+    0x0000, // 20805
+    0x969C, // 20806
+    0x518D, // 20807
+    0x54C9, // 20808
+    0x5728, // 20809
+    0x5BB0, // 20810
+    0x624D, // 20811
+    0x6750, // 20812
+    0x683D, // 20813
+    0x6893, // 20814
+    0x6E3D, // 20815
+    0x6ED3, // 20816
+    0x707D, // 20817
+    0x7E21, // 20818
+    0x88C1, // 20819
+    0x8CA1, // 20820
+    0x8F09, // 20821
+    0x9F4B, // 20822
+    0x9F4E, // 20823
+    0x722D, // 20824
+    0x7B8F, // 20825
+    0x8ACD, // 20826
+    0x931A, // 20827
+    0x4F47, // 20828
+    0x4F4E, // 20829
+    0x5132, // 20830
+    0x5480, // 20831
+    0x59D0, // 20832
+    0x5E95, // 20833
+    0x62B5, // 20834
+    0x6775, // 20835
+    0x696E, // 20836
+    0x6A17, // 20837
+    0x6CAE, // 20838
+    0x6E1A, // 20839
+    0x72D9, // 20840
+    0x732A, // 20841
+    0x75BD, // 20842
+    0x7BB8, // 20843
+    0x7D35, // 20844
+    0x82E7, // 20845
+    0x83F9, // 20846
+    0x8457, // 20847
+    0x85F7, // 20848
+    0x8A5B, // 20849
+    0x8CAF, // 20850
+    0x8E87, // 20851
+    0x9019, // 20852
+    0x90B8, // 20853
+    0x96CE, // 20854
+    0x9F5F, // 20855
+    0x52E3, // 20856
+    0x540A, // 20857
+    0x5AE1, // 20858
+    0x5BC2, // 20859
+    0x6458, // 20860
+    0x6575, // 20861
+    0x6EF4, // 20862
+    0x72C4, // 20863
+    0xF9FB, // 20864
+    0x7684, // 20865
+    0x7A4D, // 20866
+    0x7B1B, // 20867
+    0x7C4D, // 20868
+    0x7E3E, // 20869
+    0x7FDF, // 20870
+    0x837B, // 20871
+    0x8B2B, // 20872
+    0x8CCA, // 20873
+    0x8D64, // 20874
+    0x8DE1, // 20875
+    0x8E5F, // 20876
+    0x8FEA, // 20877
+    0x8FF9, // 20878
+    0x9069, // 20879
+    0x93D1, // 20880
+    0x4F43, // 20881
+    0x4F7A, // 20882
+    0x50B3, // 20883
+    0x5168, // 20884
+    0x5178, // 20885
+    0x524D, // 20886
+    0x526A, // 20887
+    0x5861, // 20888
+    0x587C, // 20889
+    0x5960, // 20890
+    0x5C08, // 20891
+    0x5C55, // 20892
+    0x5EDB, // 20893
+    0x609B, // 20894
+    0x6230, // 20895
+    0x6813, // 20896
+    0x6BBF, // 20897
+    0x6C08, // 20898
+    0x6FB1, // 20899
+    0x0000, // 20900
+    0x0000, // 20901
+    0x0000, // 20902
+    0x0000, // 20903
+    0x0000, // 20904
+    0x0000, // 20905
+    0x0000, // 20906
+    0x0000, // 20907
+    0x0000, // 20908
+    0x0000, // 20909
+    0x0000, // 20910
+    0x0000, // 20911
+    0x0000, // 20912
+    0x0000, // 20913
+    0x0000, // 20914
+    0x0000, // 20915
+    0x0000, // 20916
+    0x0000, // 20917
+    0x0000, // 20918
+    0x0000, // 20919
+    0x0000, // 20920
+    0x0000, // 20921
+    0x0000, // 20922
+    0x0000, // 20923
+    0x0000, // 20924
+    0x0000, // 20925
+    0x0000, // 20926
+    0x0000, // 20927
+    0x0000, // 20928
+    0x0000, // 20929
+    0x0000, // 20930
+    0x0000, // 20931
+    0x0000, // 20932
+    0x0000, // 20933
+    0x0000, // 20934
+    0x0000, // 20935
+    0x0000, // 20936
+    0x0000, // 20937
+    0x0000, // 20938
+    0x0000, // 20939
+    0x0000, // 20940
+    0x0000, // 20941
+    0x0000, // 20942
+    0x0000, // 20943
+    0x0000, // 20944
+    0x0000, // 20945
+    0x0000, // 20946
+    0x0000, // 20947
+    0x0000, // 20948
+    0x0000, // 20949
+    0x0000, // 20950
+    0x0000, // 20951
+    0x0000, // 20952
+    0x0000, // 20953
+    0x0000, // 20954
+    0x0000, // 20955
+    0x0000, // 20956
+    0x0000, // 20957
+    0x0000, // 20958
+    0x0000, // 20959
+    0x0000, // 20960
+    0x0000, // 20961
+    0x0000, // 20962
+    0x0000, // 20963
+    0x0000, // 20964
+    0x0000, // 20965
+    0x0000, // 20966
+    0x0000, // 20967
+    0x0000, // 20968
+    0x0000, // 20969
+    0x0000, // 20970
+    0x0000, // 20971
+    0x0000, // 20972
+    0x0000, // 20973
+    0x0000, // 20974
+    0x0000, // 20975
+    0x0000, // 20976
+    0x0000, // 20977
+    0x0000, // 20978
+    0x0000, // 20979
+    0x0000, // 20980
+    0x0000, // 20981
+    0x0000, // 20982
+    0x0000, // 20983
+    0x0000, // 20984
+    0x0000, // 20985
+    0x0000, // 20986
+    0x0000, // 20987
+    0x0000, // 20988
+    0x0000, // 20989
+    0x0000, // 20990
+    0x0000, // 20991
+    0x0000, // 20992
+    0x0000, // 20993
+    0x0000, // 20994
+    0x0000, // 20995
+    0x714E, // 20996
+    0x7420, // 20997
+    0x7530, // 20998
+    0x7538, // 20999
+    0x7551, // 21000
+    0x7672, // 21001
+    0x7B4C, // 21002
+    0x7B8B, // 21003
+    0x7BAD, // 21004
+    0x7BC6, // 21005
+    0x7E8F, // 21006
+    0x8A6E, // 21007
+    0x8F3E, // 21008
+    0x8F49, // 21009
+    0x923F, // 21010
+    0x9293, // 21011
+    0x9322, // 21012
+    0x942B, // 21013
+    0x96FB, // 21014
+    0x985A, // 21015
+    0x986B, // 21016
+    0x991E, // 21017
+    0x5207, // 21018
+    0x622A, // 21019
+    0x6298, // 21020
+    0x6D59, // 21021
+    0x7664, // 21022
+    0x7ACA, // 21023
+    0x7BC0, // 21024
+    0x7D76, // 21025
+    0x5360, // 21026
+    0x5CBE, // 21027
+    0x5E97, // 21028
+    0x6F38, // 21029
+    0x70B9, // 21030
+    0x7C98, // 21031
+    0x9711, // 21032
+    0x9B8E, // 21033
+    0x9EDE, // 21034
+    0x63A5, // 21035
+    0x647A, // 21036
+    0x8776, // 21037
+    0x4E01, // 21038
+    0x4E95, // 21039
+    0x4EAD, // 21040
+    0x505C, // 21041
+    0x5075, // 21042
+    0x5448, // 21043
+    0x59C3, // 21044
+    0x5B9A, // 21045
+    0x5E40, // 21046
+    0x5EAD, // 21047
+    0x5EF7, // 21048
+    0x5F81, // 21049
+    0x60C5, // 21050
+    0x633A, // 21051
+    0x653F, // 21052
+    0x6574, // 21053
+    0x65CC, // 21054
+    0x6676, // 21055
+    0x6678, // 21056
+    0x67FE, // 21057
+    0x6968, // 21058
+    0x6A89, // 21059
+    0x6B63, // 21060
+    0x6C40, // 21061
+    0x6DC0, // 21062
+    0x6DE8, // 21063
+    0x6E1F, // 21064
+    0x6E5E, // 21065
+    0x701E, // 21066
+    0x70A1, // 21067
+    0x738E, // 21068
+    0x73FD, // 21069
+    0x753A, // 21070
+    0x775B, // 21071
+    0x7887, // 21072
+    0x798E, // 21073
+    0x7A0B, // 21074
+    0x7A7D, // 21075
+    0x7CBE, // 21076
+    0x7D8E, // 21077
+    0x8247, // 21078
+    0x8A02, // 21079
+    0x8AEA, // 21080
+    0x8C9E, // 21081
+    0x912D, // 21082
+    0x914A, // 21083
+    0x91D8, // 21084
+    0x9266, // 21085
+    0x92CC, // 21086
+    0x9320, // 21087
+    0x9706, // 21088
+    0x9756, // 21089
+    0x0000, // 21090
+    0x0000, // 21091
+    0x0000, // 21092
+    0x0000, // 21093
+    0x0000, // 21094
+    0x0000, // 21095
+    0x0000, // 21096
+    0x0000, // 21097
+    0x0000, // 21098
+    0x0000, // 21099
+    0x0000, // 21100
+    0x0000, // 21101
+    0x0000, // 21102
+    0x0000, // 21103
+    0x0000, // 21104
+    0x0000, // 21105
+    0x0000, // 21106
+    0x0000, // 21107
+    0x0000, // 21108
+    0x0000, // 21109
+    0x0000, // 21110
+    0x0000, // 21111
+    0x0000, // 21112
+    0x0000, // 21113
+    0x0000, // 21114
+    0x0000, // 21115
+    0x0000, // 21116
+    0x0000, // 21117
+    0x0000, // 21118
+    0x0000, // 21119
+    0x0000, // 21120
+    0x0000, // 21121
+    0x0000, // 21122
+    0x0000, // 21123
+    0x0000, // 21124
+    0x0000, // 21125
+    0x0000, // 21126
+    0x0000, // 21127
+    0x0000, // 21128
+    0x0000, // 21129
+    0x0000, // 21130
+    0x0000, // 21131
+    0x0000, // 21132
+    0x0000, // 21133
+    0x0000, // 21134
+    0x0000, // 21135
+    0x0000, // 21136
+    0x0000, // 21137
+    0x0000, // 21138
+    0x0000, // 21139
+    0x0000, // 21140
+    0x0000, // 21141
+    0x0000, // 21142
+    0x0000, // 21143
+    0x0000, // 21144
+    0x0000, // 21145
+    0x0000, // 21146
+    0x0000, // 21147
+    0x0000, // 21148
+    0x0000, // 21149
+    0x0000, // 21150
+    0x0000, // 21151
+    0x0000, // 21152
+    0x0000, // 21153
+    0x0000, // 21154
+    0x0000, // 21155
+    0x0000, // 21156
+    0x0000, // 21157
+    0x0000, // 21158
+    0x0000, // 21159
+    0x0000, // 21160
+    0x0000, // 21161
+    0x0000, // 21162
+    0x0000, // 21163
+    0x0000, // 21164
+    0x0000, // 21165
+    0x0000, // 21166
+    0x0000, // 21167
+    0x0000, // 21168
+    0x0000, // 21169
+    0x0000, // 21170
+    0x0000, // 21171
+    0x0000, // 21172
+    0x0000, // 21173
+    0x0000, // 21174
+    0x0000, // 21175
+    0x0000, // 21176
+    0x0000, // 21177
+    0x0000, // 21178
+    0x0000, // 21179
+    0x0000, // 21180
+    0x0000, // 21181
+    0x0000, // 21182
+    0x0000, // 21183
+    0x0000, // 21184
+    0x0000, // 21185
+    0x975C, // 21186
+    0x9802, // 21187
+    0x9F0E, // 21188
+    0x5236, // 21189
+    0x5291, // 21190
+    0x557C, // 21191
+    0x5824, // 21192
+    0x5E1D, // 21193
+    0x5F1F, // 21194
+    0x608C, // 21195
+    0x63D0, // 21196
+    0x68AF, // 21197
+    0x6FDF, // 21198
+    0x796D, // 21199
+    0x7B2C, // 21200
+    0x81CD, // 21201
+    0x85BA, // 21202
+    0x88FD, // 21203
+    0x8AF8, // 21204
+    0x8E44, // 21205
+    0x918D, // 21206
+    0x9664, // 21207
+    0x969B, // 21208
+    0x973D, // 21209
+    0x984C, // 21210
+    0x9F4A, // 21211
+    0x4FCE, // 21212
+    0x5146, // 21213
+    0x51CB, // 21214
+    0x52A9, // 21215
+    0x5632, // 21216
+    0x5F14, // 21217
+    0x5F6B, // 21218
+    0x63AA, // 21219
+    0x64CD, // 21220
+    0x65E9, // 21221
+    0x6641, // 21222
+    0x66FA, // 21223
+    0x66F9, // 21224
+    0x671D, // 21225
+    0x689D, // 21226
+    0x68D7, // 21227
+    0x69FD, // 21228
+    0x6F15, // 21229
+    0x6F6E, // 21230
+    0x7167, // 21231
+    0x71E5, // 21232
+    0x722A, // 21233
+    0x74AA, // 21234
+    0x773A, // 21235
+    0x7956, // 21236
+    0x795A, // 21237
+    0x79DF, // 21238
+    0x7A20, // 21239
+    0x7A95, // 21240
+    0x7C97, // 21241
+    0x7CDF, // 21242
+    0x7D44, // 21243
+    0x7E70, // 21244
+    0x8087, // 21245
+    0x85FB, // 21246
+    0x86A4, // 21247
+    0x8A54, // 21248
+    0x8ABF, // 21249
+    0x8D99, // 21250
+    0x8E81, // 21251
+    0x9020, // 21252
+    0x906D, // 21253
+    0x91E3, // 21254
+    0x963B, // 21255
+    0x96D5, // 21256
+    0x9CE5, // 21257
+    0x65CF, // 21258
+    0x7C07, // 21259
+    0x8DB3, // 21260
+    0x93C3, // 21261
+    0x5B58, // 21262
+    0x5C0A, // 21263
+    0x5352, // 21264
+    0x62D9, // 21265
+    0x731D, // 21266
+    0x5027, // 21267
+    0x5B97, // 21268
+    0x5F9E, // 21269
+    0x60B0, // 21270
+    0x616B, // 21271
+    0x68D5, // 21272
+    0x6DD9, // 21273
+    0x742E, // 21274
+    0x7A2E, // 21275
+    0x7D42, // 21276
+    0x7D9C, // 21277
+    0x7E31, // 21278
+    0x816B, // 21279
+    0x0000, // 21280
+    0x0000, // 21281
+    0x0000, // 21282
+    0x0000, // 21283
+    0x0000, // 21284
+    0x0000, // 21285
+    0x0000, // 21286
+    0x0000, // 21287
+    0x0000, // 21288
+    0x0000, // 21289
+    0x0000, // 21290
+    0x0000, // 21291
+    0x0000, // 21292
+    0x0000, // 21293
+    0x0000, // 21294
+    0x0000, // 21295
+    0x0000, // 21296
+    0x0000, // 21297
+    0x0000, // 21298
+    0x0000, // 21299
+    0x0000, // 21300
+    0x0000, // 21301
+    0x0000, // 21302
+    0x0000, // 21303
+    0x0000, // 21304
+    0x0000, // 21305
+    0x0000, // 21306
+    0x0000, // 21307
+    0x0000, // 21308
+    0x0000, // 21309
+    0x0000, // 21310
+    0x0000, // 21311
+    0x0000, // 21312
+    0x0000, // 21313
+    0x0000, // 21314
+    0x0000, // 21315
+    0x0000, // 21316
+    0x0000, // 21317
+    0x0000, // 21318
+    0x0000, // 21319
+    0x0000, // 21320
+    0x0000, // 21321
+    0x0000, // 21322
+    0x0000, // 21323
+    0x0000, // 21324
+    0x0000, // 21325
+    0x0000, // 21326
+    0x0000, // 21327
+    0x0000, // 21328
+    0x0000, // 21329
+    0x0000, // 21330
+    0x0000, // 21331
+    0x0000, // 21332
+    0x0000, // 21333
+    0x0000, // 21334
+    0x0000, // 21335
+    0x0000, // 21336
+    0x0000, // 21337
+    0x0000, // 21338
+    0x0000, // 21339
+    0x0000, // 21340
+    0x0000, // 21341
+    0x0000, // 21342
+    0x0000, // 21343
+    0x0000, // 21344
+    0x0000, // 21345
+    0x0000, // 21346
+    0x0000, // 21347
+    0x0000, // 21348
+    0x0000, // 21349
+    0x0000, // 21350
+    0x0000, // 21351
+    0x0000, // 21352
+    0x0000, // 21353
+    0x0000, // 21354
+    0x0000, // 21355
+    0x0000, // 21356
+    0x0000, // 21357
+    0x0000, // 21358
+    0x0000, // 21359
+    0x0000, // 21360
+    0x0000, // 21361
+    0x0000, // 21362
+    0x0000, // 21363
+    0x0000, // 21364
+    0x0000, // 21365
+    0x0000, // 21366
+    0x0000, // 21367
+    0x0000, // 21368
+    0x0000, // 21369
+    0x0000, // 21370
+    0x0000, // 21371
+    0x0000, // 21372
+    0x0000, // 21373
+    0x0000, // 21374
+    0x0000, // 21375
+    0x8E2A, // 21376
+    0x8E35, // 21377
+    0x937E, // 21378
+    0x9418, // 21379
+    0x4F50, // 21380
+    0x5750, // 21381
+    0x5DE6, // 21382
+    0x5EA7, // 21383
+    0x632B, // 21384
+    0x7F6A, // 21385
+    0x4E3B, // 21386
+    0x4F4F, // 21387
+    0x4F8F, // 21388
+    0x505A, // 21389
+    0x59DD, // 21390
+    0x80C4, // 21391
+    0x546A, // 21392
+    0x5468, // 21393
+    0x55FE, // 21394
+    0x594F, // 21395
+    0x5B99, // 21396
+    0x5DDE, // 21397
+    0x5EDA, // 21398
+    0x665D, // 21399
+    0x6731, // 21400
+    0x67F1, // 21401
+    0x682A, // 21402
+    0x6CE8, // 21403
+    0x6D32, // 21404
+    0x6E4A, // 21405
+    0x6F8D, // 21406
+    0x70B7, // 21407
+    0x73E0, // 21408
+    0x7587, // 21409
+    0x7C4C, // 21410
+    0x7D02, // 21411
+    0x7D2C, // 21412
+    0x7DA2, // 21413
+    0x821F, // 21414
+    0x86DB, // 21415
+    0x8A3B, // 21416
+    0x8A85, // 21417
+    0x8D70, // 21418
+    0x8E8A, // 21419
+    0x8F33, // 21420
+    0x9031, // 21421
+    0x914E, // 21422
+    0x9152, // 21423
+    0x9444, // 21424
+    0x99D0, // 21425
+    0x7AF9, // 21426
+    0x7CA5, // 21427
+    0x4FCA, // 21428
+    0x5101, // 21429
+    0x51C6, // 21430
+    0x57C8, // 21431
+    0x5BEF, // 21432
+    0x5CFB, // 21433
+    0x6659, // 21434
+    0x6A3D, // 21435
+    0x6D5A, // 21436
+    0x6E96, // 21437
+    0x6FEC, // 21438
+    0x710C, // 21439
+    0x756F, // 21440
+    0x7AE3, // 21441
+    0x8822, // 21442
+    0x9021, // 21443
+    0x9075, // 21444
+    0x96CB, // 21445
+    0x99FF, // 21446
+    0x8301, // 21447
+    0x4E2D, // 21448
+    0x4EF2, // 21449
+    0x8846, // 21450
+    0x91CD, // 21451
+    0x537D, // 21452
+    0x6ADB, // 21453
+    0x696B, // 21454
+    0x6C41, // 21455
+    0x847A, // 21456
+    0x589E, // 21457
+    0x618E, // 21458
+    0x66FE, // 21459
+    0x62EF, // 21460
+    0x70DD, // 21461
+    0x7511, // 21462
+    0x75C7, // 21463
+    0x7E52, // 21464
+    0x84B8, // 21465
+    0x8B49, // 21466
+    0x8D08, // 21467
+    0x4E4B, // 21468
+    0x53EA, // 21469
+    0x0000, // 21470
+    0x0000, // 21471
+    0x0000, // 21472
+    0x0000, // 21473
+    0x0000, // 21474
+    0x0000, // 21475
+    0x0000, // 21476
+    0x0000, // 21477
+    0x0000, // 21478
+    0x0000, // 21479
+    0x0000, // 21480
+    0x0000, // 21481
+    0x0000, // 21482
+    0x0000, // 21483
+    0x0000, // 21484
+    0x0000, // 21485
+    0x0000, // 21486
+    0x0000, // 21487
+    0x0000, // 21488
+    0x0000, // 21489
+    0x0000, // 21490
+    0x0000, // 21491
+    0x0000, // 21492
+    0x0000, // 21493
+    0x0000, // 21494
+    0x0000, // 21495
+    0x0000, // 21496
+    0x0000, // 21497
+    0x0000, // 21498
+    0x0000, // 21499
+    0x0000, // 21500
+    0x0000, // 21501
+    0x0000, // 21502
+    0x0000, // 21503
+    0x0000, // 21504
+    0x0000, // 21505
+    0x0000, // 21506
+    0x0000, // 21507
+    0x0000, // 21508
+    0x0000, // 21509
+    0x0000, // 21510
+    0x0000, // 21511
+    0x0000, // 21512
+    0x0000, // 21513
+    0x0000, // 21514
+    0x0000, // 21515
+    0x0000, // 21516
+    0x0000, // 21517
+    0x0000, // 21518
+    0x0000, // 21519
+    0x0000, // 21520
+    0x0000, // 21521
+    0x0000, // 21522
+    0x0000, // 21523
+    0x0000, // 21524
+    0x0000, // 21525
+    0x0000, // 21526
+    0x0000, // 21527
+    0x0000, // 21528
+    0x0000, // 21529
+    0x0000, // 21530
+    0x0000, // 21531
+    0x0000, // 21532
+    0x0000, // 21533
+    0x0000, // 21534
+    0x0000, // 21535
+    0x0000, // 21536
+    0x0000, // 21537
+    0x0000, // 21538
+    0x0000, // 21539
+    0x0000, // 21540
+    0x0000, // 21541
+    0x0000, // 21542
+    0x0000, // 21543
+    0x0000, // 21544
+    0x0000, // 21545
+    0x0000, // 21546
+    0x0000, // 21547
+    0x0000, // 21548
+    0x0000, // 21549
+    0x0000, // 21550
+    0x0000, // 21551
+    0x0000, // 21552
+    0x0000, // 21553
+    0x0000, // 21554
+    0x0000, // 21555
+    0x0000, // 21556
+    0x0000, // 21557
+    0x0000, // 21558
+    0x0000, // 21559
+    0x0000, // 21560
+    0x0000, // 21561
+    0x0000, // 21562
+    0x0000, // 21563
+    0x0000, // 21564
+    0x0000, // 21565
+    0x54AB, // 21566
+    0x5730, // 21567
+    0x5740, // 21568
+    0x5FD7, // 21569
+    0x6301, // 21570
+    0x6307, // 21571
+    0x646F, // 21572
+    0x652F, // 21573
+    0x65E8, // 21574
+    0x667A, // 21575
+    0x679D, // 21576
+    0x67B3, // 21577
+    0x6B62, // 21578
+    0x6C60, // 21579
+    0x6C9A, // 21580
+    0x6F2C, // 21581
+    0x77E5, // 21582
+    0x7825, // 21583
+    0x7949, // 21584
+    0x7957, // 21585
+    0x7D19, // 21586
+    0x80A2, // 21587
+    0x8102, // 21588
+    0x81F3, // 21589
+    0x829D, // 21590
+    0x82B7, // 21591
+    0x8718, // 21592
+    0x8A8C, // 21593
+    0xF9FC, // 21594
+    0x8D04, // 21595
+    0x8DBE, // 21596
+    0x9072, // 21597
+    0x76F4, // 21598
+    0x7A19, // 21599
+    0x7A37, // 21600
+    0x7E54, // 21601
+    0x8077, // 21602
+    0x5507, // 21603
+    0x55D4, // 21604
+    0x5875, // 21605
+    0x632F, // 21606
+    0x6422, // 21607
+    0x6649, // 21608
+    0x664B, // 21609
+    0x686D, // 21610
+    0x699B, // 21611
+    0x6B84, // 21612
+    0x6D25, // 21613
+    0x6EB1, // 21614
+    0x73CD, // 21615
+    0x7468, // 21616
+    0x74A1, // 21617
+    0x755B, // 21618
+    0x75B9, // 21619
+    0x76E1, // 21620
+    0x771E, // 21621
+    0x778B, // 21622
+    0x79E6, // 21623
+    0x7E09, // 21624
+    0x7E1D, // 21625
+    0x81FB, // 21626
+    0x852F, // 21627
+    0x8897, // 21628
+    0x8A3A, // 21629
+    0x8CD1, // 21630
+    0x8EEB, // 21631
+    0x8FB0, // 21632
+    0x9032, // 21633
+    0x93AD, // 21634
+    0x9663, // 21635
+    0x9673, // 21636
+    0x9707, // 21637
+    0x4F84, // 21638
+    0x53F1, // 21639
+    0x59EA, // 21640
+    0x5AC9, // 21641
+    0x5E19, // 21642
+    0x684E, // 21643
+    0x74C6, // 21644
+    0x75BE, // 21645
+    0x79E9, // 21646
+    0x7A92, // 21647
+    0x81A3, // 21648
+    0x86ED, // 21649
+    0x8CEA, // 21650
+    0x8DCC, // 21651
+    0x8FED, // 21652
+    0x659F, // 21653
+    0x6715, // 21654
+    0xF9FD, // 21655
+    0x57F7, // 21656
+    0x6F57, // 21657
+    0x7DDD, // 21658
+    0x8F2F, // 21659
+    0x0000, // 21660
+    0x0000, // 21661
+    0x0000, // 21662
+    0x0000, // 21663
+    0x0000, // 21664
+    0x0000, // 21665
+    0x0000, // 21666
+    0x0000, // 21667
+    0x0000, // 21668
+    0x0000, // 21669
+    0x0000, // 21670
+    0x0000, // 21671
+    0x0000, // 21672
+    0x0000, // 21673
+    0x0000, // 21674
+    0x0000, // 21675
+    0x0000, // 21676
+    0x0000, // 21677
+    0x0000, // 21678
+    0x0000, // 21679
+    0x0000, // 21680
+    0x0000, // 21681
+    0x0000, // 21682
+    0x0000, // 21683
+    0x0000, // 21684
+    0x0000, // 21685
+    0x0000, // 21686
+    0x0000, // 21687
+    0x0000, // 21688
+    0x0000, // 21689
+    0x0000, // 21690
+    0x0000, // 21691
+    0x0000, // 21692
+    0x0000, // 21693
+    0x0000, // 21694
+    0x0000, // 21695
+    0x0000, // 21696
+    0x0000, // 21697
+    0x0000, // 21698
+    0x0000, // 21699
+    0x0000, // 21700
+    0x0000, // 21701
+    0x0000, // 21702
+    0x0000, // 21703
+    0x0000, // 21704
+    0x0000, // 21705
+    0x0000, // 21706
+    0x0000, // 21707
+    0x0000, // 21708
+    0x0000, // 21709
+    0x0000, // 21710
+    0x0000, // 21711
+    0x0000, // 21712
+    0x0000, // 21713
+    0x0000, // 21714
+    0x0000, // 21715
+    0x0000, // 21716
+    0x0000, // 21717
+    0x0000, // 21718
+    0x0000, // 21719
+    0x0000, // 21720
+    0x0000, // 21721
+    0x0000, // 21722
+    0x0000, // 21723
+    0x0000, // 21724
+    0x0000, // 21725
+    0x0000, // 21726
+    0x0000, // 21727
+    0x0000, // 21728
+    0x0000, // 21729
+    0x0000, // 21730
+    0x0000, // 21731
+    0x0000, // 21732
+    0x0000, // 21733
+    0x0000, // 21734
+    0x0000, // 21735
+    0x0000, // 21736
+    0x0000, // 21737
+    0x0000, // 21738
+    0x0000, // 21739
+    0x0000, // 21740
+    0x0000, // 21741
+    0x0000, // 21742
+    0x0000, // 21743
+    0x0000, // 21744
+    0x0000, // 21745
+    0x0000, // 21746
+    0x0000, // 21747
+    0x0000, // 21748
+    0x0000, // 21749
+    0x0000, // 21750
+    0x0000, // 21751
+    0x0000, // 21752
+    0x0000, // 21753
+    0x0000, // 21754
+    0x0000, // 21755
+    0x93F6, // 21756
+    0x96C6, // 21757
+    0x5FB5, // 21758
+    0x61F2, // 21759
+    0x6F84, // 21760
+    0x4E14, // 21761
+    0x4F98, // 21762
+    0x501F, // 21763
+    0x53C9, // 21764
+    0x55DF, // 21765
+    0x5D6F, // 21766
+    0x5DEE, // 21767
+    0x6B21, // 21768
+    0x6B64, // 21769
+    0x78CB, // 21770
+    0x7B9A, // 21771
+    0xF9FE, // 21772
+    0x8E49, // 21773
+    0x8ECA, // 21774
+    0x906E, // 21775
+    0x6349, // 21776
+    0x643E, // 21777
+    0x7740, // 21778
+    0x7A84, // 21779
+    0x932F, // 21780
+    0x947F, // 21781
+    0x9F6A, // 21782
+    0x64B0, // 21783
+    0x6FAF, // 21784
+    0x71E6, // 21785
+    0x74A8, // 21786
+    0x74DA, // 21787
+    0x7AC4, // 21788
+    0x7C12, // 21789
+    0x7E82, // 21790
+    0x7CB2, // 21791
+    0x7E98, // 21792
+    0x8B9A, // 21793
+    0x8D0A, // 21794
+    0x947D, // 21795
+    0x9910, // 21796
+    0x994C, // 21797
+    0x5239, // 21798
+    0x5BDF, // 21799
+    0x64E6, // 21800
+    0x672D, // 21801
+    0x7D2E, // 21802
+    0x50ED, // 21803
+    0x53C3, // 21804
+    0x5879, // 21805
+    0x6158, // 21806
+    0x6159, // 21807
+    0x61FA, // 21808
+    0x65AC, // 21809
+    0x7AD9, // 21810
+    0x8B92, // 21811
+    0x8B96, // 21812
+    0x5009, // 21813
+    0x5021, // 21814
+    0x5275, // 21815
+    0x5531, // 21816
+    0x5A3C, // 21817
+    0x5EE0, // 21818
+    0x5F70, // 21819
+    0x6134, // 21820
+    0x655E, // 21821
+    0x660C, // 21822
+    0x6636, // 21823
+    0x66A2, // 21824
+    0x69CD, // 21825
+    0x6EC4, // 21826
+    0x6F32, // 21827
+    0x7316, // 21828
+    0x7621, // 21829
+    0x7A93, // 21830
+    0x8139, // 21831
+    0x8259, // 21832
+    0x83D6, // 21833
+    0x84BC, // 21834
+    0x50B5, // 21835
+    0x57F0, // 21836
+    0x5BC0, // 21837
+    0x5BE8, // 21838
+    0x5F69, // 21839
+    0x63A1, // 21840
+    0x7826, // 21841
+    0x7DB5, // 21842
+    0x83DC, // 21843
+    0x8521, // 21844
+    0x91C7, // 21845
+    0x91F5, // 21846
+    0x518A, // 21847
+    0x67F5, // 21848
+    0x7B56, // 21849
+    0x0000, // 21850
+    0x0000, // 21851
+    0x0000, // 21852
+    0x0000, // 21853
+    0x0000, // 21854
+    0x0000, // 21855
+    0x0000, // 21856
+    0x0000, // 21857
+    0x0000, // 21858
+    0x0000, // 21859
+    0x0000, // 21860
+    0x0000, // 21861
+    0x0000, // 21862
+    0x0000, // 21863
+    0x0000, // 21864
+    0x0000, // 21865
+    0x0000, // 21866
+    0x0000, // 21867
+    0x0000, // 21868
+    0x0000, // 21869
+    0x0000, // 21870
+    0x0000, // 21871
+    0x0000, // 21872
+    0x0000, // 21873
+    0x0000, // 21874
+    0x0000, // 21875
+    0x0000, // 21876
+    0x0000, // 21877
+    0x0000, // 21878
+    0x0000, // 21879
+    0x0000, // 21880
+    0x0000, // 21881
+    0x0000, // 21882
+    0x0000, // 21883
+    0x0000, // 21884
+    0x0000, // 21885
+    0x0000, // 21886
+    0x0000, // 21887
+    0x0000, // 21888
+    0x0000, // 21889
+    0x0000, // 21890
+    0x0000, // 21891
+    0x0000, // 21892
+    0x0000, // 21893
+    0x0000, // 21894
+    0x0000, // 21895
+    0x0000, // 21896
+    0x0000, // 21897
+    0x0000, // 21898
+    0x0000, // 21899
+    0x0000, // 21900
+    0x0000, // 21901
+    0x0000, // 21902
+    0x0000, // 21903
+    0x0000, // 21904
+    0x0000, // 21905
+    0x0000, // 21906
+    0x0000, // 21907
+    0x0000, // 21908
+    0x0000, // 21909
+    0x0000, // 21910
+    0x0000, // 21911
+    0x0000, // 21912
+    0x0000, // 21913
+    0x0000, // 21914
+    0x0000, // 21915
+    0x0000, // 21916
+    0x0000, // 21917
+    0x0000, // 21918
+    0x0000, // 21919
+    0x0000, // 21920
+    0x0000, // 21921
+    0x0000, // 21922
+    0x0000, // 21923
+    0x0000, // 21924
+    0x0000, // 21925
+    0x0000, // 21926
+    0x0000, // 21927
+    0x0000, // 21928
+    0x0000, // 21929
+    0x0000, // 21930
+    0x0000, // 21931
+    0x0000, // 21932
+    0x0000, // 21933
+    0x0000, // 21934
+    0x0000, // 21935
+    0x0000, // 21936
+    0x0000, // 21937
+    0x0000, // 21938
+    0x0000, // 21939
+    0x0000, // 21940
+    0x0000, // 21941
+    0x0000, // 21942
+    0x0000, // 21943
+    0x0000, // 21944
+    0x0000, // 21945
+    0x8CAC, // 21946
+    0x51C4, // 21947
+    0x59BB, // 21948
+    0x60BD, // 21949
+    0x8655, // 21950
+    0x501C, // 21951
+    0xF9FF, // 21952
+    0x5254, // 21953
+    0x5C3A, // 21954
+    0x617D, // 21955
+    0x621A, // 21956
+    0x62D3, // 21957
+    0x64F2, // 21958
+    0x65A5, // 21959
+    0x6ECC, // 21960
+    0x7620, // 21961
+    0x810A, // 21962
+    0x8E60, // 21963
+    0x965F, // 21964
+    0x96BB, // 21965
+    0x4EDF, // 21966
+    0x5343, // 21967
+    0x5598, // 21968
+    0x5929, // 21969
+    0x5DDD, // 21970
+    0x64C5, // 21971
+    0x6CC9, // 21972
+    0x6DFA, // 21973
+    0x7394, // 21974
+    0x7A7F, // 21975
+    0x821B, // 21976
+    0x85A6, // 21977
+    0x8CE4, // 21978
+    0x8E10, // 21979
+    0x9077, // 21980
+    0x91E7, // 21981
+    0x95E1, // 21982
+    0x9621, // 21983
+    0x97C6, // 21984
+    0x51F8, // 21985
+    0x54F2, // 21986
+    0x5586, // 21987
+    0x5FB9, // 21988
+    0x64A4, // 21989
+    0x6F88, // 21990
+    0x7DB4, // 21991
+    0x8F1F, // 21992
+    0x8F4D, // 21993
+    0x9435, // 21994
+    0x50C9, // 21995
+    0x5C16, // 21996
+    0x6CBE, // 21997
+    0x6DFB, // 21998
+    0x751B, // 21999
+    0x77BB, // 22000
+    0x7C3D, // 22001
+    0x7C64, // 22002
+    0x8A79, // 22003
+    0x8AC2, // 22004
+    0x581E, // 22005
+    0x59BE, // 22006
+    0x5E16, // 22007
+    0x6377, // 22008
+    0x7252, // 22009
+    0x758A, // 22010
+    0x776B, // 22011
+    0x8ADC, // 22012
+    0x8CBC, // 22013
+    0x8F12, // 22014
+    0x5EF3, // 22015
+    0x6674, // 22016
+    0x6DF8, // 22017
+    0x807D, // 22018
+    0x83C1, // 22019
+    0x8ACB, // 22020
+    0x9751, // 22021
+    0x9BD6, // 22022
+    0xFA00, // 22023
+    0x5243, // 22024
+    0x66FF, // 22025
+    0x6D95, // 22026
+    0x6EEF, // 22027
+    0x7DE0, // 22028
+    0x8AE6, // 22029
+    0x902E, // 22030
+    0x905E, // 22031
+    0x9AD4, // 22032
+    0x521D, // 22033
+    0x527F, // 22034
+    0x54E8, // 22035
+    0x6194, // 22036
+    0x6284, // 22037
+    0x62DB, // 22038
+    0x68A2, // 22039
+    0x0000, // 22040
+    0x0000, // 22041
+    0x0000, // 22042
+    0x0000, // 22043
+    0x0000, // 22044
+    0x0000, // 22045
+    0x0000, // 22046
+    0x0000, // 22047
+    0x0000, // 22048
+    0x0000, // 22049
+    0x0000, // 22050
+    0x0000, // 22051
+    0x0000, // 22052
+    0x0000, // 22053
+    0x0000, // 22054
+    0x0000, // 22055
+    0x0000, // 22056
+    0x0000, // 22057
+    0x0000, // 22058
+    0x0000, // 22059
+    0x0000, // 22060
+    0x0000, // 22061
+    0x0000, // 22062
+    0x0000, // 22063
+    0x0000, // 22064
+    0x0000, // 22065
+    0x0000, // 22066
+    0x0000, // 22067
+    0x0000, // 22068
+    0x0000, // 22069
+    0x0000, // 22070
+    0x0000, // 22071
+    0x0000, // 22072
+    0x0000, // 22073
+    0x0000, // 22074
+    0x0000, // 22075
+    0x0000, // 22076
+    0x0000, // 22077
+    0x0000, // 22078
+    0x0000, // 22079
+    0x0000, // 22080
+    0x0000, // 22081
+    0x0000, // 22082
+    0x0000, // 22083
+    0x0000, // 22084
+    0x0000, // 22085
+    0x0000, // 22086
+    0x0000, // 22087
+    0x0000, // 22088
+    0x0000, // 22089
+    0x0000, // 22090
+    0x0000, // 22091
+    0x0000, // 22092
+    0x0000, // 22093
+    0x0000, // 22094
+    0x0000, // 22095
+    0x0000, // 22096
+    0x0000, // 22097
+    0x0000, // 22098
+    0x0000, // 22099
+    0x0000, // 22100
+    0x0000, // 22101
+    0x0000, // 22102
+    0x0000, // 22103
+    0x0000, // 22104
+    0x0000, // 22105
+    0x0000, // 22106
+    0x0000, // 22107
+    0x0000, // 22108
+    0x0000, // 22109
+    0x0000, // 22110
+    0x0000, // 22111
+    0x0000, // 22112
+    0x0000, // 22113
+    0x0000, // 22114
+    0x0000, // 22115
+    0x0000, // 22116
+    0x0000, // 22117
+    0x0000, // 22118
+    0x0000, // 22119
+    0x0000, // 22120
+    0x0000, // 22121
+    0x0000, // 22122
+    0x0000, // 22123
+    0x0000, // 22124
+    0x0000, // 22125
+    0x0000, // 22126
+    0x0000, // 22127
+    0x0000, // 22128
+    0x0000, // 22129
+    0x0000, // 22130
+    0x0000, // 22131
+    0x0000, // 22132
+    0x0000, // 22133
+    0x0000, // 22134
+    0x0000, // 22135
+    0x6912, // 22136
+    0x695A, // 22137
+    0x6A35, // 22138
+    0x7092, // 22139
+    0x7126, // 22140
+    0x785D, // 22141
+    0x7901, // 22142
+    0x790E, // 22143
+    0x79D2, // 22144
+    0x7A0D, // 22145
+    0x8096, // 22146
+    0x8278, // 22147
+    0x82D5, // 22148
+    0x8349, // 22149
+    0x8549, // 22150
+    0x8C82, // 22151
+    0x8D85, // 22152
+    0x9162, // 22153
+    0x918B, // 22154
+    0x91AE, // 22155
+    0x4FC3, // 22156
+    0x56D1, // 22157
+    0x71ED, // 22158
+    0x77D7, // 22159
+    0x8700, // 22160
+    0x89F8, // 22161
+    0x5BF8, // 22162
+    0x5FD6, // 22163
+    0x6751, // 22164
+    0x90A8, // 22165
+    0x53E2, // 22166
+    0x585A, // 22167
+    0x5BF5, // 22168
+    0x60A4, // 22169
+    0x6181, // 22170
+    0x6460, // 22171
+    0x7E3D, // 22172
+    0x8070, // 22173
+    0x8525, // 22174
+    0x9283, // 22175
+    0x64AE, // 22176
+    0x50AC, // 22177
+    0x5D14, // 22178
+    0x6700, // 22179
+    0x589C, // 22180
+    0x62BD, // 22181
+    0x63A8, // 22182
+    0x690E, // 22183
+    0x6978, // 22184
+    0x6A1E, // 22185
+    0x6E6B, // 22186
+    0x76BA, // 22187
+    0x79CB, // 22188
+    0x82BB, // 22189
+    0x8429, // 22190
+    0x8ACF, // 22191
+    0x8DA8, // 22192
+    0x8FFD, // 22193
+    0x9112, // 22194
+    0x914B, // 22195
+    0x919C, // 22196
+    0x9310, // 22197
+    0x9318, // 22198
+    0x939A, // 22199
+    0x96DB, // 22200
+    0x9A36, // 22201
+    0x9C0D, // 22202
+    0x4E11, // 22203
+    0x755C, // 22204
+    0x795D, // 22205
+    0x7AFA, // 22206
+    0x7B51, // 22207
+    0x7BC9, // 22208
+    0x7E2E, // 22209
+    0x84C4, // 22210
+    0x8E59, // 22211
+    0x8E74, // 22212
+    0x8EF8, // 22213
+    0x9010, // 22214
+    0x6625, // 22215
+    0x693F, // 22216
+    0x7443, // 22217
+    0x51FA, // 22218
+    0x672E, // 22219
+    0x9EDC, // 22220
+    0x5145, // 22221
+    0x5FE0, // 22222
+    0x6C96, // 22223
+    0x87F2, // 22224
+    0x885D, // 22225
+    0x8877, // 22226
+    0x60B4, // 22227
+    0x81B5, // 22228
+    0x8403, // 22229
+    0x0000, // 22230
+    0x0000, // 22231
+    0x0000, // 22232
+    0x0000, // 22233
+    0x0000, // 22234
+    0x0000, // 22235
+    0x0000, // 22236
+    0x0000, // 22237
+    0x0000, // 22238
+    0x0000, // 22239
+    0x0000, // 22240
+    0x0000, // 22241
+    0x0000, // 22242
+    0x0000, // 22243
+    0x0000, // 22244
+    0x0000, // 22245
+    0x0000, // 22246
+    0x0000, // 22247
+    0x0000, // 22248
+    0x0000, // 22249
+    0x0000, // 22250
+    0x0000, // 22251
+    0x0000, // 22252
+    0x0000, // 22253
+    0x0000, // 22254
+    0x0000, // 22255
+    0x0000, // 22256
+    0x0000, // 22257
+    0x0000, // 22258
+    0x0000, // 22259
+    0x0000, // 22260
+    0x0000, // 22261
+    0x0000, // 22262
+    0x0000, // 22263
+    0x0000, // 22264
+    0x0000, // 22265
+    0x0000, // 22266
+    0x0000, // 22267
+    0x0000, // 22268
+    0x0000, // 22269
+    0x0000, // 22270
+    0x0000, // 22271
+    0x0000, // 22272
+    0x0000, // 22273
+    0x0000, // 22274
+    0x0000, // 22275
+    0x0000, // 22276
+    0x0000, // 22277
+    0x0000, // 22278
+    0x0000, // 22279
+    0x0000, // 22280
+    0x0000, // 22281
+    0x0000, // 22282
+    0x0000, // 22283
+    0x0000, // 22284
+    0x0000, // 22285
+    0x0000, // 22286
+    0x0000, // 22287
+    0x0000, // 22288
+    0x0000, // 22289
+    0x0000, // 22290
+    0x0000, // 22291
+    0x0000, // 22292
+    0x0000, // 22293
+    0x0000, // 22294
+    0x0000, // 22295
+    0x0000, // 22296
+    0x0000, // 22297
+    0x0000, // 22298
+    0x0000, // 22299
+    0x0000, // 22300
+    0x0000, // 22301
+    0x0000, // 22302
+    0x0000, // 22303
+    0x0000, // 22304
+    0x0000, // 22305
+    0x0000, // 22306
+    0x0000, // 22307
+    0x0000, // 22308
+    0x0000, // 22309
+    0x0000, // 22310
+    0x0000, // 22311
+    0x0000, // 22312
+    0x0000, // 22313
+    0x0000, // 22314
+    0x0000, // 22315
+    0x0000, // 22316
+    0x0000, // 22317
+    0x0000, // 22318
+    0x0000, // 22319
+    0x0000, // 22320
+    0x0000, // 22321
+    0x0000, // 22322
+    0x0000, // 22323
+    0x0000, // 22324
+    0x0000, // 22325
+    0x8D05, // 22326
+    0x53D6, // 22327
+    0x5439, // 22328
+    0x5634, // 22329
+    0x5A36, // 22330
+    0x5C31, // 22331
+    0x708A, // 22332
+    0x7FE0, // 22333
+    0x805A, // 22334
+    0x8106, // 22335
+    0x81ED, // 22336
+    0x8DA3, // 22337
+    0x9189, // 22338
+    0x9A5F, // 22339
+    0x9DF2, // 22340
+    0x5074, // 22341
+    0x4EC4, // 22342
+    0x53A0, // 22343
+    0x60FB, // 22344
+    0x6E2C, // 22345
+    0x5C64, // 22346
+    0x4F88, // 22347
+    0x5024, // 22348
+    0x55E4, // 22349
+    0x5CD9, // 22350
+    0x5E5F, // 22351
+    0x6065, // 22352
+    0x6894, // 22353
+    0x6CBB, // 22354
+    0x6DC4, // 22355
+    0x71BE, // 22356
+    0x75D4, // 22357
+    0x75F4, // 22358
+    0x7661, // 22359
+    0x7A1A, // 22360
+    0x7A49, // 22361
+    0x7DC7, // 22362
+    0x7DFB, // 22363
+    0x7F6E, // 22364
+    0x81F4, // 22365
+    0x86A9, // 22366
+    0x8F1C, // 22367
+    0x96C9, // 22368
+    0x99B3, // 22369
+    0x9F52, // 22370
+    0x5247, // 22371
+    0x52C5, // 22372
+    0x98ED, // 22373
+    0x89AA, // 22374
+    0x4E03, // 22375
+    0x67D2, // 22376
+    0x6F06, // 22377
+    0x4FB5, // 22378
+    0x5BE2, // 22379
+    0x6795, // 22380
+    0x6C88, // 22381
+    0x6D78, // 22382
+    0x741B, // 22383
+    0x7827, // 22384
+    0x91DD, // 22385
+    0x937C, // 22386
+    0x87C4, // 22387
+    0x79E4, // 22388
+    0x7A31, // 22389
+    0x5FEB, // 22390
+    0x4ED6, // 22391
+    0x54A4, // 22392
+    0x553E, // 22393
+    0x58AE, // 22394
+    0x59A5, // 22395
+    0x60F0, // 22396
+    0x6253, // 22397
+    0x62D6, // 22398
+    0x6736, // 22399
+    0x6955, // 22400
+    0x8235, // 22401
+    0x9640, // 22402
+    0x99B1, // 22403
+    0x99DD, // 22404
+    0x502C, // 22405
+    0x5353, // 22406
+    0x5544, // 22407
+    0x577C, // 22408
+    0xFA01, // 22409
+    0x6258, // 22410
+    0xFA02, // 22411
+    0x64E2, // 22412
+    0x666B, // 22413
+    0x67DD, // 22414
+    0x6FC1, // 22415
+    0x6FEF, // 22416
+    0x7422, // 22417
+    0x7438, // 22418
+    0x8A17, // 22419
+    0x0000, // 22420
+    0x0000, // 22421
+    0x0000, // 22422
+    0x0000, // 22423
+    0x0000, // 22424
+    0x0000, // 22425
+    0x0000, // 22426
+    0x0000, // 22427
+    0x0000, // 22428
+    0x0000, // 22429
+    0x0000, // 22430
+    0x0000, // 22431
+    0x0000, // 22432
+    0x0000, // 22433
+    0x0000, // 22434
+    0x0000, // 22435
+    0x0000, // 22436
+    0x0000, // 22437
+    0x0000, // 22438
+    0x0000, // 22439
+    0x0000, // 22440
+    0x0000, // 22441
+    0x0000, // 22442
+    0x0000, // 22443
+    0x0000, // 22444
+    0x0000, // 22445
+    0x0000, // 22446
+    0x0000, // 22447
+    0x0000, // 22448
+    0x0000, // 22449
+    0x0000, // 22450
+    0x0000, // 22451
+    0x0000, // 22452
+    0x0000, // 22453
+    0x0000, // 22454
+    0x0000, // 22455
+    0x0000, // 22456
+    0x0000, // 22457
+    0x0000, // 22458
+    0x0000, // 22459
+    0x0000, // 22460
+    0x0000, // 22461
+    0x0000, // 22462
+    0x0000, // 22463
+    0x0000, // 22464
+    0x0000, // 22465
+    0x0000, // 22466
+    0x0000, // 22467
+    0x0000, // 22468
+    0x0000, // 22469
+    0x0000, // 22470
+    0x0000, // 22471
+    0x0000, // 22472
+    0x0000, // 22473
+    0x0000, // 22474
+    0x0000, // 22475
+    0x0000, // 22476
+    0x0000, // 22477
+    0x0000, // 22478
+    0x0000, // 22479
+    0x0000, // 22480
+    0x0000, // 22481
+    0x0000, // 22482
+    0x0000, // 22483
+    0x0000, // 22484
+    0x0000, // 22485
+    0x0000, // 22486
+    0x0000, // 22487
+    0x0000, // 22488
+    0x0000, // 22489
+    0x0000, // 22490
+    0x0000, // 22491
+    0x0000, // 22492
+    0x0000, // 22493
+    0x0000, // 22494
+    0x0000, // 22495
+    0x0000, // 22496
+    0x0000, // 22497
+    0x0000, // 22498
+    0x0000, // 22499
+    0x0000, // 22500
+    0x0000, // 22501
+    0x0000, // 22502
+    0x0000, // 22503
+    0x0000, // 22504
+    0x0000, // 22505
+    0x0000, // 22506
+    0x0000, // 22507
+    0x0000, // 22508
+    0x0000, // 22509
+    0x0000, // 22510
+    0x0000, // 22511
+    0x0000, // 22512
+    0x0000, // 22513
+    0x0000, // 22514
+    0x0000, // 22515
+    0x9438, // 22516
+    0x5451, // 22517
+    0x5606, // 22518
+    0x5766, // 22519
+    0x5F48, // 22520
+    0x619A, // 22521
+    0x6B4E, // 22522
+    0x7058, // 22523
+    0x70AD, // 22524
+    0x7DBB, // 22525
+    0x8A95, // 22526
+    0x596A, // 22527
+    0x812B, // 22528
+    0x63A2, // 22529
+    0x7708, // 22530
+    0x803D, // 22531
+    0x8CAA, // 22532
+    0x5854, // 22533
+    0x642D, // 22534
+    0x69BB, // 22535
+    0x5B95, // 22536
+    0x5E11, // 22537
+    0x6E6F, // 22538
+    0xFA03, // 22539
+    0x8569, // 22540
+    0x514C, // 22541
+    0x53F0, // 22542
+    0x592A, // 22543
+    0x6020, // 22544
+    0x614B, // 22545
+    0x6B86, // 22546
+    0x6C70, // 22547
+    0x6CF0, // 22548
+    0x7B1E, // 22549
+    0x80CE, // 22550
+    0x82D4, // 22551
+    0x8DC6, // 22552
+    0x90B0, // 22553
+    0x98B1, // 22554
+    0xFA04, // 22555
+    0x64C7, // 22556
+    0x6FA4, // 22557
+    0x6491, // 22558
+    0x6504, // 22559
+    0x514E, // 22560
+    0x5410, // 22561
+    0x571F, // 22562
+    0x8A0E, // 22563
+    0x615F, // 22564
+    0x6876, // 22565
+    0xFA05, // 22566
+    0x75DB, // 22567
+    0x7B52, // 22568
+    0x7D71, // 22569
+    0x901A, // 22570
+    0x5806, // 22571
+    0x69CC, // 22572
+    0x817F, // 22573
+    0x892A, // 22574
+    0x9000, // 22575
+    0x9839, // 22576
+    0x5078, // 22577
+    0x5957, // 22578
+    0x59AC, // 22579
+    0x6295, // 22580
+    0x900F, // 22581
+    0x9B2A, // 22582
+    0x615D, // 22583
+    0x7279, // 22584
+    0x95D6, // 22585
+    0x5761, // 22586
+    0x5A46, // 22587
+    0x5DF4, // 22588
+    0x628A, // 22589
+    0x64AD, // 22590
+    0x64FA, // 22591
+    0x6777, // 22592
+    0x6CE2, // 22593
+    0x6D3E, // 22594
+    0x722C, // 22595
+    0x7436, // 22596
+    0x7834, // 22597
+    0x7F77, // 22598
+    0x82AD, // 22599
+    0x8DDB, // 22600
+    0x9817, // 22601
+    0x5224, // 22602
+    0x5742, // 22603
+    0x677F, // 22604
+    0x7248, // 22605
+    0x74E3, // 22606
+    0x8CA9, // 22607
+    0x8FA6, // 22608
+    0x9211, // 22609
+    0x0000, // 22610
+    0x0000, // 22611
+    0x0000, // 22612
+    0x0000, // 22613
+    0x0000, // 22614
+    0x0000, // 22615
+    0x0000, // 22616
+    0x0000, // 22617
+    0x0000, // 22618
+    0x0000, // 22619
+    0x0000, // 22620
+    0x0000, // 22621
+    0x0000, // 22622
+    0x0000, // 22623
+    0x0000, // 22624
+    0x0000, // 22625
+    0x0000, // 22626
+    0x0000, // 22627
+    0x0000, // 22628
+    0x0000, // 22629
+    0x0000, // 22630
+    0x0000, // 22631
+    0x0000, // 22632
+    0x0000, // 22633
+    0x0000, // 22634
+    0x0000, // 22635
+    0x0000, // 22636
+    0x0000, // 22637
+    0x0000, // 22638
+    0x0000, // 22639
+    0x0000, // 22640
+    0x0000, // 22641
+    0x0000, // 22642
+    0x0000, // 22643
+    0x0000, // 22644
+    0x0000, // 22645
+    0x0000, // 22646
+    0x0000, // 22647
+    0x0000, // 22648
+    0x0000, // 22649
+    0x0000, // 22650
+    0x0000, // 22651
+    0x0000, // 22652
+    0x0000, // 22653
+    0x0000, // 22654
+    0x0000, // 22655
+    0x0000, // 22656
+    0x0000, // 22657
+    0x0000, // 22658
+    0x0000, // 22659
+    0x0000, // 22660
+    0x0000, // 22661
+    0x0000, // 22662
+    0x0000, // 22663
+    0x0000, // 22664
+    0x0000, // 22665
+    0x0000, // 22666
+    0x0000, // 22667
+    0x0000, // 22668
+    0x0000, // 22669
+    0x0000, // 22670
+    0x0000, // 22671
+    0x0000, // 22672
+    0x0000, // 22673
+    0x0000, // 22674
+    0x0000, // 22675
+    0x0000, // 22676
+    0x0000, // 22677
+    0x0000, // 22678
+    0x0000, // 22679
+    0x0000, // 22680
+    0x0000, // 22681
+    0x0000, // 22682
+    0x0000, // 22683
+    0x0000, // 22684
+    0x0000, // 22685
+    0x0000, // 22686
+    0x0000, // 22687
+    0x0000, // 22688
+    0x0000, // 22689
+    0x0000, // 22690
+    0x0000, // 22691
+    0x0000, // 22692
+    0x0000, // 22693
+    0x0000, // 22694
+    0x0000, // 22695
+    0x0000, // 22696
+    0x0000, // 22697
+    0x0000, // 22698
+    0x0000, // 22699
+    0x0000, // 22700
+    0x0000, // 22701
+    0x0000, // 22702
+    0x0000, // 22703
+    0x0000, // 22704
+    0x0000, // 22705
+    0x962A, // 22706
+    0x516B, // 22707
+    0x53ED, // 22708
+    0x634C, // 22709
+    0x4F69, // 22710
+    0x5504, // 22711
+    0x6096, // 22712
+    0x6557, // 22713
+    0x6C9B, // 22714
+    0x6D7F, // 22715
+    0x724C, // 22716
+    0x72FD, // 22717
+    0x7A17, // 22718
+    0x8987, // 22719
+    0x8C9D, // 22720
+    0x5F6D, // 22721
+    0x6F8E, // 22722
+    0x70F9, // 22723
+    0x81A8, // 22724
+    0x610E, // 22725
+    0x4FBF, // 22726
+    0x504F, // 22727
+    0x6241, // 22728
+    0x7247, // 22729
+    0x7BC7, // 22730
+    0x7DE8, // 22731
+    0x7FE9, // 22732
+    0x904D, // 22733
+    0x97AD, // 22734
+    0x9A19, // 22735
+    0x8CB6, // 22736
+    0x576A, // 22737
+    0x5E73, // 22738
+    0x67B0, // 22739
+    0x840D, // 22740
+    0x8A55, // 22741
+    0x5420, // 22742
+    0x5B16, // 22743
+    0x5E63, // 22744
+    0x5EE2, // 22745
+    0x5F0A, // 22746
+    0x6583, // 22747
+    0x80BA, // 22748
+    0x853D, // 22749
+    0x9589, // 22750
+    0x965B, // 22751
+    0x4F48, // 22752
+    0x5305, // 22753
+    0x530D, // 22754
+    0x530F, // 22755
+    0x5486, // 22756
+    0x54FA, // 22757
+    0x5703, // 22758
+    0x5E03, // 22759
+    0x6016, // 22760
+    0x629B, // 22761
+    0x62B1, // 22762
+    0x6355, // 22763
+    0xFA06, // 22764
+    0x6CE1, // 22765
+    0x6D66, // 22766
+    0x75B1, // 22767
+    0x7832, // 22768
+    0x80DE, // 22769
+    0x812F, // 22770
+    0x82DE, // 22771
+    0x8461, // 22772
+    0x84B2, // 22773
+    0x888D, // 22774
+    0x8912, // 22775
+    0x900B, // 22776
+    0x92EA, // 22777
+    0x98FD, // 22778
+    0x9B91, // 22779
+    0x5E45, // 22780
+    0x66B4, // 22781
+    0x66DD, // 22782
+    0x7011, // 22783
+    0x7206, // 22784
+    0xFA07, // 22785
+    0x4FF5, // 22786
+    0x527D, // 22787
+    0x5F6A, // 22788
+    0x6153, // 22789
+    0x6753, // 22790
+    0x6A19, // 22791
+    0x6F02, // 22792
+    0x74E2, // 22793
+    0x7968, // 22794
+    0x8868, // 22795
+    0x8C79, // 22796
+    0x98C7, // 22797
+    0x98C4, // 22798
+    0x9A43, // 22799
+    0x0000, // 22800
+    0x0000, // 22801
+    0x0000, // 22802
+    0x0000, // 22803
+    0x0000, // 22804
+    0x0000, // 22805
+    0x0000, // 22806
+    0x0000, // 22807
+    0x0000, // 22808
+    0x0000, // 22809
+    0x0000, // 22810
+    0x0000, // 22811
+    0x0000, // 22812
+    0x0000, // 22813
+    0x0000, // 22814
+    0x0000, // 22815
+    0x0000, // 22816
+    0x0000, // 22817
+    0x0000, // 22818
+    0x0000, // 22819
+    0x0000, // 22820
+    0x0000, // 22821
+    0x0000, // 22822
+    0x0000, // 22823
+    0x0000, // 22824
+    0x0000, // 22825
+    0x0000, // 22826
+    0x0000, // 22827
+    0x0000, // 22828
+    0x0000, // 22829
+    0x0000, // 22830
+    0x0000, // 22831
+    0x0000, // 22832
+    0x0000, // 22833
+    0x0000, // 22834
+    0x0000, // 22835
+    0x0000, // 22836
+    0x0000, // 22837
+    0x0000, // 22838
+    0x0000, // 22839
+    0x0000, // 22840
+    0x0000, // 22841
+    0x0000, // 22842
+    0x0000, // 22843
+    0x0000, // 22844
+    0x0000, // 22845
+    0x0000, // 22846
+    0x0000, // 22847
+    0x0000, // 22848
+    0x0000, // 22849
+    0x0000, // 22850
+    0x0000, // 22851
+    0x0000, // 22852
+    0x0000, // 22853
+    0x0000, // 22854
+    0x0000, // 22855
+    0x0000, // 22856
+    0x0000, // 22857
+    0x0000, // 22858
+    0x0000, // 22859
+    0x0000, // 22860
+    0x0000, // 22861
+    0x0000, // 22862
+    0x0000, // 22863
+    0x0000, // 22864
+    0x0000, // 22865
+    0x0000, // 22866
+    0x0000, // 22867
+    0x0000, // 22868
+    0x0000, // 22869
+    0x0000, // 22870
+    0x0000, // 22871
+    0x0000, // 22872
+    0x0000, // 22873
+    0x0000, // 22874
+    0x0000, // 22875
+    0x0000, // 22876
+    0x0000, // 22877
+    0x0000, // 22878
+    0x0000, // 22879
+    0x0000, // 22880
+    0x0000, // 22881
+    0x0000, // 22882
+    0x0000, // 22883
+    0x0000, // 22884
+    0x0000, // 22885
+    0x0000, // 22886
+    0x0000, // 22887
+    0x0000, // 22888
+    0x0000, // 22889
+    0x0000, // 22890
+    0x0000, // 22891
+    0x0000, // 22892
+    0x0000, // 22893
+    0x0000, // 22894
+    0x0000, // 22895
+    0x54C1, // 22896
+    0x7A1F, // 22897
+    0x6953, // 22898
+    0x8AF7, // 22899
+    0x8C4A, // 22900
+    0x98A8, // 22901
+    0x99AE, // 22902
+    0x5F7C, // 22903
+    0x62AB, // 22904
+    0x75B2, // 22905
+    0x76AE, // 22906
+    0x88AB, // 22907
+    0x907F, // 22908
+    0x9642, // 22909
+    0x5339, // 22910
+    0x5F3C, // 22911
+    0x5FC5, // 22912
+    0x6CCC, // 22913
+    0x73CC, // 22914
+    0x7562, // 22915
+    0x758B, // 22916
+    0x7B46, // 22917
+    0x82FE, // 22918
+    0x999D, // 22919
+    0x4E4F, // 22920
+    0x903C, // 22921
+    0x4E0B, // 22922
+    0x4F55, // 22923
+    0x53A6, // 22924
+    0x590F, // 22925
+    0x5EC8, // 22926
+    0x6630, // 22927
+    0x6CB3, // 22928
+    0x7455, // 22929
+    0x8377, // 22930
+    0x8766, // 22931
+    0x8CC0, // 22932
+    0x9050, // 22933
+    0x971E, // 22934
+    0x9C15, // 22935
+    0x58D1, // 22936
+    0x5B78, // 22937
+    0x8650, // 22938
+    0x8B14, // 22939
+    0x9DB4, // 22940
+    0x5BD2, // 22941
+    0x6068, // 22942
+    0x608D, // 22943
+    0x65F1, // 22944
+    0x6C57, // 22945
+    0x6F22, // 22946
+    0x6FA3, // 22947
+    0x701A, // 22948
+    0x7F55, // 22949
+    0x7FF0, // 22950
+    0x9591, // 22951
+    0x9592, // 22952
+    0x9650, // 22953
+    0x97D3, // 22954
+    0x5272, // 22955
+    0x8F44, // 22956
+    0x51FD, // 22957
+    0x542B, // 22958
+    0x54B8, // 22959
+    0x5563, // 22960
+    0x558A, // 22961
+    0x6ABB, // 22962
+    0x6DB5, // 22963
+    0x7DD8, // 22964
+    0x8266, // 22965
+    0x929C, // 22966
+    0x9677, // 22967
+    0x9E79, // 22968
+    0x5408, // 22969
+    0x54C8, // 22970
+    0x76D2, // 22971
+    0x86E4, // 22972
+    0x95A4, // 22973
+    0x95D4, // 22974
+    0x965C, // 22975
+    0x4EA2, // 22976
+    0x4F09, // 22977
+    0x59EE, // 22978
+    0x5AE6, // 22979
+    0x5DF7, // 22980
+    0x6052, // 22981
+    0x6297, // 22982
+    0x676D, // 22983
+    0x6841, // 22984
+    0x6C86, // 22985
+    0x6E2F, // 22986
+    0x7F38, // 22987
+    0x809B, // 22988
+    0x822A, // 22989
+    0x0000, // 22990
+    0x0000, // 22991
+    0x0000, // 22992
+    0x0000, // 22993
+    0x0000, // 22994
+    0x0000, // 22995
+    0x0000, // 22996
+    0x0000, // 22997
+    0x0000, // 22998
+    0x0000, // 22999
+    0x0000, // 23000
+    0x0000, // 23001
+    0x0000, // 23002
+    0x0000, // 23003
+    0x0000, // 23004
+    0x0000, // 23005
+    0x0000, // 23006
+    0x0000, // 23007
+    0x0000, // 23008
+    0x0000, // 23009
+    0x0000, // 23010
+    0x0000, // 23011
+    0x0000, // 23012
+    0x0000, // 23013
+    0x0000, // 23014
+    0x0000, // 23015
+    0x0000, // 23016
+    0x0000, // 23017
+    0x0000, // 23018
+    0x0000, // 23019
+    0x0000, // 23020
+    0x0000, // 23021
+    0x0000, // 23022
+    0x0000, // 23023
+    0x0000, // 23024
+    0x0000, // 23025
+    0x0000, // 23026
+    0x0000, // 23027
+    0x0000, // 23028
+    0x0000, // 23029
+    0x0000, // 23030
+    0x0000, // 23031
+    0x0000, // 23032
+    0x0000, // 23033
+    0x0000, // 23034
+    0x0000, // 23035
+    0x0000, // 23036
+    0x0000, // 23037
+    0x0000, // 23038
+    0x0000, // 23039
+    0x0000, // 23040
+    0x0000, // 23041
+    0x0000, // 23042
+    0x0000, // 23043
+    0x0000, // 23044
+    0x0000, // 23045
+    0x0000, // 23046
+    0x0000, // 23047
+    0x0000, // 23048
+    0x0000, // 23049
+    0x0000, // 23050
+    0x0000, // 23051
+    0x0000, // 23052
+    0x0000, // 23053
+    0x0000, // 23054
+    0x0000, // 23055
+    0x0000, // 23056
+    0x0000, // 23057
+    0x0000, // 23058
+    0x0000, // 23059
+    0x0000, // 23060
+    0x0000, // 23061
+    0x0000, // 23062
+    0x0000, // 23063
+    0x0000, // 23064
+    0x0000, // 23065
+    0x0000, // 23066
+    0x0000, // 23067
+    0x0000, // 23068
+    0x0000, // 23069
+    0x0000, // 23070
+    0x0000, // 23071
+    0x0000, // 23072
+    0x0000, // 23073
+    0x0000, // 23074
+    0x0000, // 23075
+    0x0000, // 23076
+    0x0000, // 23077
+    0x0000, // 23078
+    0x0000, // 23079
+    0x0000, // 23080
+    0x0000, // 23081
+    0x0000, // 23082
+    0x0000, // 23083
+    0x0000, // 23084
+    0x0000, // 23085
+    0xFA08, // 23086
+    0xFA09, // 23087
+    0x9805, // 23088
+    0x4EA5, // 23089
+    0x5055, // 23090
+    0x54B3, // 23091
+    0x5793, // 23092
+    0x595A, // 23093
+    0x5B69, // 23094
+    0x5BB3, // 23095
+    0x61C8, // 23096
+    0x6977, // 23097
+    0x6D77, // 23098
+    0x7023, // 23099
+    0x87F9, // 23100
+    0x89E3, // 23101
+    0x8A72, // 23102
+    0x8AE7, // 23103
+    0x9082, // 23104
+    0x99ED, // 23105
+    0x9AB8, // 23106
+    0x52BE, // 23107
+    0x6838, // 23108
+    0x5016, // 23109
+    0x5E78, // 23110
+    0x674F, // 23111
+    0x8347, // 23112
+    0x884C, // 23113
+    0x4EAB, // 23114
+    0x5411, // 23115
+    0x56AE, // 23116
+    0x73E6, // 23117
+    0x9115, // 23118
+    0x97FF, // 23119
+    0x9909, // 23120
+    0x9957, // 23121
+    0x9999, // 23122
+    0x5653, // 23123
+    0x589F, // 23124
+    0x865B, // 23125
+    0x8A31, // 23126
+    0x61B2, // 23127
+    0x6AF6, // 23128
+    0x737B, // 23129
+    0x8ED2, // 23130
+    0x6B47, // 23131
+    0x96AA, // 23132
+    0x9A57, // 23133
+    0x5955, // 23134
+    0x7200, // 23135
+    0x8D6B, // 23136
+    0x9769, // 23137
+    0x4FD4, // 23138
+    0x5CF4, // 23139
+    0x5F26, // 23140
+    0x61F8, // 23141
+    0x665B, // 23142
+    0x6CEB, // 23143
+    0x70AB, // 23144
+    0x7384, // 23145
+    0x73B9, // 23146
+    0x73FE, // 23147
+    0x7729, // 23148
+    0x774D, // 23149
+    0x7D43, // 23150
+    0x7D62, // 23151
+    0x7E23, // 23152
+    0x8237, // 23153
+    0x8852, // 23154
+    0xFA0A, // 23155
+    0x8CE2, // 23156
+    0x9249, // 23157
+    0x986F, // 23158
+    0x5B51, // 23159
+    0x7A74, // 23160
+    0x8840, // 23161
+    0x9801, // 23162
+    0x5ACC, // 23163
+    0x4FE0, // 23164
+    0x5354, // 23165
+    0x593E, // 23166
+    0x5CFD, // 23167
+    0x633E, // 23168
+    0x6D79, // 23169
+    0x72F9, // 23170
+    0x8105, // 23171
+    0x8107, // 23172
+    0x83A2, // 23173
+    0x92CF, // 23174
+    0x9830, // 23175
+    0x4EA8, // 23176
+    0x5144, // 23177
+    0x5211, // 23178
+    0x578B, // 23179
+    0x0000, // 23180
+    0x0000, // 23181
+    0x0000, // 23182
+    0x0000, // 23183
+    0x0000, // 23184
+    0x0000, // 23185
+    0x0000, // 23186
+    0x0000, // 23187
+    0x0000, // 23188
+    0x0000, // 23189
+    0x0000, // 23190
+    0x0000, // 23191
+    0x0000, // 23192
+    0x0000, // 23193
+    0x0000, // 23194
+    0x0000, // 23195
+    0x0000, // 23196
+    0x0000, // 23197
+    0x0000, // 23198
+    0x0000, // 23199
+    0x0000, // 23200
+    0x0000, // 23201
+    0x0000, // 23202
+    0x0000, // 23203
+    0x0000, // 23204
+    0x0000, // 23205
+    0x0000, // 23206
+    0x0000, // 23207
+    0x0000, // 23208
+    0x0000, // 23209
+    0x0000, // 23210
+    0x0000, // 23211
+    0x0000, // 23212
+    0x0000, // 23213
+    0x0000, // 23214
+    0x0000, // 23215
+    0x0000, // 23216
+    0x0000, // 23217
+    0x0000, // 23218
+    0x0000, // 23219
+    0x0000, // 23220
+    0x0000, // 23221
+    0x0000, // 23222
+    0x0000, // 23223
+    0x0000, // 23224
+    0x0000, // 23225
+    0x0000, // 23226
+    0x0000, // 23227
+    0x0000, // 23228
+    0x0000, // 23229
+    0x0000, // 23230
+    0x0000, // 23231
+    0x0000, // 23232
+    0x0000, // 23233
+    0x0000, // 23234
+    0x0000, // 23235
+    0x0000, // 23236
+    0x0000, // 23237
+    0x0000, // 23238
+    0x0000, // 23239
+    0x0000, // 23240
+    0x0000, // 23241
+    0x0000, // 23242
+    0x0000, // 23243
+    0x0000, // 23244
+    0x0000, // 23245
+    0x0000, // 23246
+    0x0000, // 23247
+    0x0000, // 23248
+    0x0000, // 23249
+    0x0000, // 23250
+    0x0000, // 23251
+    0x0000, // 23252
+    0x0000, // 23253
+    0x0000, // 23254
+    0x0000, // 23255
+    0x0000, // 23256
+    0x0000, // 23257
+    0x0000, // 23258
+    0x0000, // 23259
+    0x0000, // 23260
+    0x0000, // 23261
+    0x0000, // 23262
+    0x0000, // 23263
+    0x0000, // 23264
+    0x0000, // 23265
+    0x0000, // 23266
+    0x0000, // 23267
+    0x0000, // 23268
+    0x0000, // 23269
+    0x0000, // 23270
+    0x0000, // 23271
+    0x0000, // 23272
+    0x0000, // 23273
+    0x0000, // 23274
+    0x0000, // 23275
+    0x5F62, // 23276
+    0x6CC2, // 23277
+    0x6ECE, // 23278
+    0x7005, // 23279
+    0x7050, // 23280
+    0x70AF, // 23281
+    0x7192, // 23282
+    0x73E9, // 23283
+    0x7469, // 23284
+    0x834A, // 23285
+    0x87A2, // 23286
+    0x8861, // 23287
+    0x9008, // 23288
+    0x90A2, // 23289
+    0x93A3, // 23290
+    0x99A8, // 23291
+    0x516E, // 23292
+    0x5F57, // 23293
+    0x60E0, // 23294
+    0x6167, // 23295
+    0x66B3, // 23296
+    0x8559, // 23297
+    0x8E4A, // 23298
+    0x91AF, // 23299
+    0x978B, // 23300
+    0x4E4E, // 23301
+    0x4E92, // 23302
+    0x547C, // 23303
+    0x58D5, // 23304
+    0x58FA, // 23305
+    0x597D, // 23306
+    0x5CB5, // 23307
+    0x5F27, // 23308
+    0x6236, // 23309
+    0x6248, // 23310
+    0x660A, // 23311
+    0x6667, // 23312
+    0x6BEB, // 23313
+    0x6D69, // 23314
+    0x6DCF, // 23315
+    0x6E56, // 23316
+    0x6EF8, // 23317
+    0x6F94, // 23318
+    0x6FE0, // 23319
+    0x6FE9, // 23320
+    0x705D, // 23321
+    0x72D0, // 23322
+    0x7425, // 23323
+    0x745A, // 23324
+    0x74E0, // 23325
+    0x7693, // 23326
+    0x795C, // 23327
+    0x7CCA, // 23328
+    0x7E1E, // 23329
+    0x80E1, // 23330
+    0x82A6, // 23331
+    0x846B, // 23332
+    0x84BF, // 23333
+    0x864E, // 23334
+    0x865F, // 23335
+    0x8774, // 23336
+    0x8B77, // 23337
+    0x8C6A, // 23338
+    0x93AC, // 23339
+    0x9800, // 23340
+    0x9865, // 23341
+    0x60D1, // 23342
+    0x6216, // 23343
+    0x9177, // 23344
+    0x5A5A, // 23345
+    0x660F, // 23346
+    0x6DF7, // 23347
+    0x6E3E, // 23348
+    0x743F, // 23349
+    0x9B42, // 23350
+    0x5FFD, // 23351
+    0x60DA, // 23352
+    0x7B0F, // 23353
+    0x54C4, // 23354
+    0x5F18, // 23355
+    0x6C5E, // 23356
+    0x6CD3, // 23357
+    0x6D2A, // 23358
+    0x70D8, // 23359
+    0x7D05, // 23360
+    0x8679, // 23361
+    0x8A0C, // 23362
+    0x9D3B, // 23363
+    0x5316, // 23364
+    0x548C, // 23365
+    0x5B05, // 23366
+    0x6A3A, // 23367
+    0x706B, // 23368
+    0x7575, // 23369
+    0x0000, // 23370
+    0x0000, // 23371
+    0x0000, // 23372
+    0x0000, // 23373
+    0x0000, // 23374
+    0x0000, // 23375
+    0x0000, // 23376
+    0x0000, // 23377
+    0x0000, // 23378
+    0x0000, // 23379
+    0x0000, // 23380
+    0x0000, // 23381
+    0x0000, // 23382
+    0x0000, // 23383
+    0x0000, // 23384
+    0x0000, // 23385
+    0x0000, // 23386
+    0x0000, // 23387
+    0x0000, // 23388
+    0x0000, // 23389
+    0x0000, // 23390
+    0x0000, // 23391
+    0x0000, // 23392
+    0x0000, // 23393
+    0x0000, // 23394
+    0x0000, // 23395
+    0x0000, // 23396
+    0x0000, // 23397
+    0x0000, // 23398
+    0x0000, // 23399
+    0x0000, // 23400
+    0x0000, // 23401
+    0x0000, // 23402
+    0x0000, // 23403
+    0x0000, // 23404
+    0x0000, // 23405
+    0x0000, // 23406
+    0x0000, // 23407
+    0x0000, // 23408
+    0x0000, // 23409
+    0x0000, // 23410
+    0x0000, // 23411
+    0x0000, // 23412
+    0x0000, // 23413
+    0x0000, // 23414
+    0x0000, // 23415
+    0x0000, // 23416
+    0x0000, // 23417
+    0x0000, // 23418
+    0x0000, // 23419
+    0x0000, // 23420
+    0x0000, // 23421
+    0x0000, // 23422
+    0x0000, // 23423
+    0x0000, // 23424
+    0x0000, // 23425
+    0x0000, // 23426
+    0x0000, // 23427
+    0x0000, // 23428
+    0x0000, // 23429
+    0x0000, // 23430
+    0x0000, // 23431
+    0x0000, // 23432
+    0x0000, // 23433
+    0x0000, // 23434
+    0x0000, // 23435
+    0x0000, // 23436
+    0x0000, // 23437
+    0x0000, // 23438
+    0x0000, // 23439
+    0x0000, // 23440
+    0x0000, // 23441
+    0x0000, // 23442
+    0x0000, // 23443
+    0x0000, // 23444
+    0x0000, // 23445
+    0x0000, // 23446
+    0x0000, // 23447
+    0x0000, // 23448
+    0x0000, // 23449
+    0x0000, // 23450
+    0x0000, // 23451
+    0x0000, // 23452
+    0x0000, // 23453
+    0x0000, // 23454
+    0x0000, // 23455
+    0x0000, // 23456
+    0x0000, // 23457
+    0x0000, // 23458
+    0x0000, // 23459
+    0x0000, // 23460
+    0x0000, // 23461
+    0x0000, // 23462
+    0x0000, // 23463
+    0x0000, // 23464
+    0x0000, // 23465
+    0x798D, // 23466
+    0x79BE, // 23467
+    0x82B1, // 23468
+    0x83EF, // 23469
+    0x8A71, // 23470
+    0x8B41, // 23471
+    0x8CA8, // 23472
+    0x9774, // 23473
+    0xFA0B, // 23474
+    0x64F4, // 23475
+    0x652B, // 23476
+    0x78BA, // 23477
+    0x78BB, // 23478
+    0x7A6B, // 23479
+    0x4E38, // 23480
+    0x559A, // 23481
+    0x5950, // 23482
+    0x5BA6, // 23483
+    0x5E7B, // 23484
+    0x60A3, // 23485
+    0x63DB, // 23486
+    0x6B61, // 23487
+    0x6665, // 23488
+    0x6853, // 23489
+    0x6E19, // 23490
+    0x7165, // 23491
+    0x74B0, // 23492
+    0x7D08, // 23493
+    0x9084, // 23494
+    0x9A69, // 23495
+    0x9C25, // 23496
+    0x6D3B, // 23497
+    0x6ED1, // 23498
+    0x733E, // 23499
+    0x8C41, // 23500
+    0x95CA, // 23501
+    0x51F0, // 23502
+    0x5E4C, // 23503
+    0x5FA8, // 23504
+    0x604D, // 23505
+    0x60F6, // 23506
+    0x6130, // 23507
+    0x614C, // 23508
+    0x6643, // 23509
+    0x6644, // 23510
+    0x69A5, // 23511
+    0x6CC1, // 23512
+    0x6E5F, // 23513
+    0x6EC9, // 23514
+    0x6F62, // 23515
+    0x714C, // 23516
+    0x749C, // 23517
+    0x7687, // 23518
+    0x7BC1, // 23519
+    0x7C27, // 23520
+    0x8352, // 23521
+    0x8757, // 23522
+    0x9051, // 23523
+    0x968D, // 23524
+    0x9EC3, // 23525
+    0x532F, // 23526
+    0x56DE, // 23527
+    0x5EFB, // 23528
+    0x5F8A, // 23529
+    0x6062, // 23530
+    0x6094, // 23531
+    0x61F7, // 23532
+    0x6666, // 23533
+    0x6703, // 23534
+    0x6A9C, // 23535
+    0x6DEE, // 23536
+    0x6FAE, // 23537
+    0x7070, // 23538
+    0x736A, // 23539
+    0x7E6A, // 23540
+    0x81BE, // 23541
+    0x8334, // 23542
+    0x86D4, // 23543
+    0x8AA8, // 23544
+    0x8CC4, // 23545
+    0x5283, // 23546
+    0x7372, // 23547
+    0x5B96, // 23548
+    0x6A6B, // 23549
+    0x9404, // 23550
+    0x54EE, // 23551
+    0x5686, // 23552
+    0x5B5D, // 23553
+    0x6548, // 23554
+    0x6585, // 23555
+    0x66C9, // 23556
+    0x689F, // 23557
+    0x6D8D, // 23558
+    0x6DC6, // 23559
+    0x0000, // 23560
+    0x0000, // 23561
+    0x0000, // 23562
+    0x0000, // 23563
+    0x0000, // 23564
+    0x0000, // 23565
+    0x0000, // 23566
+    0x0000, // 23567
+    0x0000, // 23568
+    0x0000, // 23569
+    0x0000, // 23570
+    0x0000, // 23571
+    0x0000, // 23572
+    0x0000, // 23573
+    0x0000, // 23574
+    0x0000, // 23575
+    0x0000, // 23576
+    0x0000, // 23577
+    0x0000, // 23578
+    0x0000, // 23579
+    0x0000, // 23580
+    0x0000, // 23581
+    0x0000, // 23582
+    0x0000, // 23583
+    0x0000, // 23584
+    0x0000, // 23585
+    0x0000, // 23586
+    0x0000, // 23587
+    0x0000, // 23588
+    0x0000, // 23589
+    0x0000, // 23590
+    0x0000, // 23591
+    0x0000, // 23592
+    0x0000, // 23593
+    0x0000, // 23594
+    0x0000, // 23595
+    0x0000, // 23596
+    0x0000, // 23597
+    0x0000, // 23598
+    0x0000, // 23599
+    0x0000, // 23600
+    0x0000, // 23601
+    0x0000, // 23602
+    0x0000, // 23603
+    0x0000, // 23604
+    0x0000, // 23605
+    0x0000, // 23606
+    0x0000, // 23607
+    0x0000, // 23608
+    0x0000, // 23609
+    0x0000, // 23610
+    0x0000, // 23611
+    0x0000, // 23612
+    0x0000, // 23613
+    0x0000, // 23614
+    0x0000, // 23615
+    0x0000, // 23616
+    0x0000, // 23617
+    0x0000, // 23618
+    0x0000, // 23619
+    0x0000, // 23620
+    0x0000, // 23621
+    0x0000, // 23622
+    0x0000, // 23623
+    0x0000, // 23624
+    0x0000, // 23625
+    0x0000, // 23626
+    0x0000, // 23627
+    0x0000, // 23628
+    0x0000, // 23629
+    0x0000, // 23630
+    0x0000, // 23631
+    0x0000, // 23632
+    0x0000, // 23633
+    0x0000, // 23634
+    0x0000, // 23635
+    0x0000, // 23636
+    0x0000, // 23637
+    0x0000, // 23638
+    0x0000, // 23639
+    0x0000, // 23640
+    0x0000, // 23641
+    0x0000, // 23642
+    0x0000, // 23643
+    0x0000, // 23644
+    0x0000, // 23645
+    0x0000, // 23646
+    0x0000, // 23647
+    0x0000, // 23648
+    0x0000, // 23649
+    0x0000, // 23650
+    0x0000, // 23651
+    0x0000, // 23652
+    0x0000, // 23653
+    0x0000, // 23654
+    0x0000, // 23655
+    0x723B, // 23656
+    0x80B4, // 23657
+    0x9175, // 23658
+    0x9A4D, // 23659
+    0x4FAF, // 23660
+    0x5019, // 23661
+    0x539A, // 23662
+    0x540E, // 23663
+    0x543C, // 23664
+    0x5589, // 23665
+    0x55C5, // 23666
+    0x5E3F, // 23667
+    0x5F8C, // 23668
+    0x673D, // 23669
+    0x7166, // 23670
+    0x73DD, // 23671
+    0x9005, // 23672
+    0x52DB, // 23673
+    0x52F3, // 23674
+    0x5864, // 23675
+    0x58CE, // 23676
+    0x7104, // 23677
+    0x718F, // 23678
+    0x71FB, // 23679
+    0x85B0, // 23680
+    0x8A13, // 23681
+    0x6688, // 23682
+    0x85A8, // 23683
+    0x55A7, // 23684
+    0x6684, // 23685
+    0x714A, // 23686
+    0x8431, // 23687
+    0x5349, // 23688
+    0x5599, // 23689
+    0x6BC1, // 23690
+    0x5F59, // 23691
+    0x5FBD, // 23692
+    0x63EE, // 23693
+    0x6689, // 23694
+    0x7147, // 23695
+    0x8AF1, // 23696
+    0x8F1D, // 23697
+    0x9EBE, // 23698
+    0x4F11, // 23699
+    0x643A, // 23700
+    0x70CB, // 23701
+    0x7566, // 23702
+    0x8667, // 23703
+    0x6064, // 23704
+    0x8B4E, // 23705
+    0x9DF8, // 23706
+    0x5147, // 23707
+    0x51F6, // 23708
+    0x5308, // 23709
+    0x6D36, // 23710
+    0x80F8, // 23711
+    0x9ED1, // 23712
+    0x6615, // 23713
+    0x6B23, // 23714
+    0x7098, // 23715
+    0x75D5, // 23716
+    0x5403, // 23717
+    0x5C79, // 23718
+    0x7D07, // 23719
+    0x8A16, // 23720
+    0x6B20, // 23721
+    0x6B3D, // 23722
+    0x6B46, // 23723
+    0x5438, // 23724
+    0x6070, // 23725
+    0x6D3D, // 23726
+    0x7FD5, // 23727
+    0x8208, // 23728
+    0x50D6, // 23729
+    0x51DE, // 23730
+    0x559C, // 23731
+    0x566B, // 23732
+    0x56CD, // 23733
+    0x59EC, // 23734
+    0x5B09, // 23735
+    0x5E0C, // 23736
+    0x6199, // 23737
+    0x6198, // 23738
+    0x6231, // 23739
+    0x665E, // 23740
+    0x66E6, // 23741
+    0x7199, // 23742
+    0x71B9, // 23743
+    0x71BA, // 23744
+    0x72A7, // 23745
+    0x79A7, // 23746
+    0x7A00, // 23747
+    0x7FB2, // 23748
+    0x8A70, // 23749
+];
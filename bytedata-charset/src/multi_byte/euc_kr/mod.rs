@@ -0,0 +1,387 @@
+mod cp_euc_kr;
+mod euc_kr_cp;
+
+/// EUC-KR encoding. A legacy encoding for Korean characters.
+///
+/// This follows the WHATWG definition of EUC-KR, which is the Unified Hangul Code extension of KS X 1001 also known as windows-949.
+/// Besides the KS X 1001 double-byte area it contains every modern Hangul syllable.
+///
+/// Use [`UTF-8`] or [`UTF-16`] instead if possible.
+///
+/// [`UTF-8`]: crate::Utf8Encoding
+/// [`UTF-16`]: crate::Utf16Encoding
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+#[non_exhaustive]
+#[repr(transparent)]
+#[cfg_attr(docsrs, doc(cfg(feature = "euc-kr")))]
+pub struct EucKrEncoding;
+
+/// EUC-KR encoding.
+#[cfg_attr(docsrs, doc(cfg(feature = "euc-kr")))]
+pub static EUC_KR: EucKrEncoding = EucKrEncoding::new();
+
+impl EucKrEncoding {
+    /// Create a new EUC-KR encoding instance.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self
+    }
+
+    /// Decode an EUC-KR byte sequence.
+    #[inline]
+    #[must_use]
+    pub const fn decode_const(&self, bytes: &[u8]) -> crate::DecodeResult {
+        if bytes.is_empty() {
+            return crate::DecodeResult::Empty;
+        }
+        let byt = bytes[0];
+        if byt < 0x80 {
+            let mut i = 1;
+            while i < bytes.len() {
+                if bytes[i] & 0b1000_0000 == 0b1000_0000 {
+                    break;
+                }
+                i += 1;
+            }
+            return crate::DecodeResult::Utf8(i as u64);
+        }
+        decode_const_inner(bytes)
+    }
+
+    /// Encode an EUC-KR character sequence.
+    #[inline]
+    #[must_use]
+    pub const fn encode_const(&self, chars: &str) -> crate::EncodeResult {
+        let bytes = chars.as_bytes();
+        if bytes.is_empty() {
+            return crate::EncodeResult::Empty;
+        }
+        let byt = bytes[0];
+        if byt < 0x80 {
+            let mut i = 1;
+            while i < bytes.len() {
+                if bytes[i] & 0b1000_0000 == 0b1000_0000 {
+                    break;
+                }
+                i += 1;
+            }
+            return crate::EncodeResult::Utf8(i as u64);
+        }
+        encode_const_inner(bytes)
+    }
+
+    /// Detect if the given bytes are EUC-KR encoded.
+    ///
+    /// Apart from validating the byte structure, this requires longer texts to mostly consist of Hangul syllables, with only a few of them outside of the KS X 1001 area.
+    /// Japanese texts in `Shift_JIS` or EUC-JP are often structurally valid EUC-KR but decode to Hangul jamo and rare syllables.
+    #[inline]
+    #[must_use]
+    pub const fn detect_const(bytes: &[u8]) -> crate::detect::DetectionResult {
+        if bytes.is_empty() {
+            return crate::detect::DetectionResult::Incomplete;
+        }
+        detect_const_inner(bytes)
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "euc-kr")))]
+impl crate::Charset for EucKrEncoding {
+    const CHARSET_NAME: &'static str = "euc-kr";
+
+    #[inline]
+    fn size_hint(&self) -> (u16, u16) {
+        (1, 2)
+    }
+
+    #[inline]
+    fn charset_alias(&self) -> &[&'static str] {
+        &[
+            // IANA
+            Self::CHARSET_NAME,
+            "cseuckr",
+            "csksc56011987",
+            "iso-ir-149",
+            "korean",
+            "ks_c_5601-1987",
+            "ks_c_5601-1989",
+            "ksc5601",
+            "ksc_5601",
+            // code pages
+            "windows-949",
+            "cp949",
+            // other
+            "uhc",
+        ]
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "euc-kr")))]
+impl crate::detect::CharsetDetector for EucKrEncoding {
+    #[inline]
+    fn detect(&self, bytes: &[u8]) -> crate::detect::DetectionResult {
+        Self::detect_const(bytes)
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "euc-kr")))]
+impl crate::CharsetDecoding for EucKrEncoding {
+    #[inline]
+    fn decode(&self, bytes: &[u8]) -> crate::DecodeResult {
+        Self::decode_const(self, bytes)
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "euc-kr")))]
+impl crate::CharsetEncoding for EucKrEncoding {
+    #[inline]
+    fn encode(&self, chars: &str) -> crate::EncodeResult {
+        Self::encode_const(self, chars)
+    }
+}
+
+/// Detects EUC-KR by validating the byte structure and the share of Hangul syllables.
+#[expect(clippy::missing_asserts_for_indexing)]
+const fn detect_const_inner(mut bytes: &[u8]) -> crate::detect::DetectionResult {
+    const MIN_LEN: usize = 4;
+    /// The number of non-ASCII characters after which the share of Hangul is checked.
+    const MIN_CHARS: usize = 8;
+    let mut offset = 0;
+    let mut chars = 0;
+    let mut hangul = 0;
+    let mut extended = 0;
+    loop {
+        if bytes.is_empty() {
+            break;
+        }
+        let b0 = bytes[0];
+        if b0 < 0x80 {
+            bytes = bytedata::const_or_bytes(bytedata::const_slice(bytes, 1..bytes.len()), b"");
+            offset += 1;
+            continue;
+        }
+        match decode_const_inner(bytes) {
+            crate::DecodeResult::Char(ch, len) => {
+                let ch = ch as u32;
+                if ch >= 0xAC00 && ch <= 0xD7A3 {
+                    hangul += 1;
+                }
+                // the Unified Hangul Code extension is outside of the KS X 1001 area `0xA1A1..=0xFEFE`
+                if b0 < 0xA1 || bytes[1] < 0xA1 {
+                    extended += 1;
+                }
+                chars += 1;
+                let len = len as usize;
+                offset += len;
+                bytes =
+                    bytedata::const_or_bytes(bytedata::const_slice(bytes, len..bytes.len()), b"");
+            }
+            crate::DecodeResult::InvalidChar(_, _) | crate::DecodeResult::CharPair(_, _, _) => {
+                return crate::detect::DetectionResult::Irrelevant;
+            }
+            crate::DecodeResult::Utf8(_)
+            | crate::DecodeResult::Empty
+            | crate::DecodeResult::Incomplete => break,
+        }
+    }
+    // modern Korean texts are mostly written in Hangul, and the extension syllables are rarely used
+    if chars >= MIN_CHARS && (hangul * 2 < chars || extended * 4 > chars) {
+        return crate::detect::DetectionResult::Irrelevant;
+    }
+    if offset < MIN_LEN {
+        return crate::detect::DetectionResult::Incomplete;
+    }
+    crate::detect::DetectionResult::Tentative
+}
+
+/// Decodes an EUC-KR byte sequence. This should not be called directly without first validating the fist byte isn't ASCII.
+#[inline]
+#[expect(clippy::missing_asserts_for_indexing)]
+const fn decode_const_inner(bytes: &[u8]) -> crate::DecodeResult {
+    let lead = bytes[0];
+    if lead < 0x81 || lead == 0xFF {
+        return crate::DecodeResult::InvalidChar(lead as u32, 1);
+    }
+    if bytes.len() < 2 {
+        return crate::DecodeResult::Incomplete;
+    }
+    let trail = bytes[1];
+    if trail >= 0x41 && trail <= 0xFE {
+        let ptr = (lead as usize - 0x81) * 190 + trail as usize - 0x41; // 0 - 23_749
+        if ptr < euc_kr_cp::EUC_KR_PTR_TO_CP.len() {
+            let cp = euc_kr_cp::EUC_KR_PTR_TO_CP[ptr];
+            if cp != 0 {
+                let Some(ch) = char::from_u32(cp as u32) else {
+                    unreachable!();
+                };
+                return crate::DecodeResult::Char(ch, 2);
+            }
+        }
+    }
+    if trail < 0x80 {
+        // an ASCII trail byte is not consumed by an invalid sequence
+        return crate::DecodeResult::InvalidChar(lead as u32, 1);
+    }
+    crate::DecodeResult::InvalidChar(((lead as u32) << 8_i32) | trail as u32, 2)
+}
+
+#[inline]
+const fn encode_const_inner(mut bytes: &[u8]) -> crate::EncodeResult {
+    let mut chunk = [0_u8; 14];
+    let mut chunk_len = 0_usize;
+    let mut consumed = 0_usize;
+    loop {
+        let (ch, ch_len) = bytedata::const_utf8_char_next(bytes);
+        if ch_len == 0 || ch < 0x80 {
+            // ASCII is left for the next call to return as a UTF-8 compatible run
+            break;
+        }
+        let Some(ptr) = encode_ptr(ch) else {
+            if chunk_len != 0 {
+                break;
+            }
+            let Some(ch) = char::from_u32(ch) else {
+                unreachable!();
+            };
+            #[expect(clippy::cast_possible_truncation)]
+            return crate::EncodeResult::InvalidChar(ch, ch_len as u16);
+        };
+        #[expect(clippy::cast_possible_truncation, clippy::integer_division)]
+        {
+            chunk[chunk_len] = (ptr / 190 + 0x81) as u8;
+            chunk[chunk_len + 1] = (ptr % 190 + 0x41) as u8;
+        };
+        chunk_len += 2;
+        consumed += ch_len as usize;
+        if chunk_len > 10 {
+            break;
+        }
+        bytes = bytedata::const_or_bytes(
+            bytedata::const_slice(bytes, (ch_len as usize)..bytes.len()),
+            b"",
+        );
+    }
+    if chunk_len == 0 {
+        return crate::EncodeResult::Empty;
+    }
+    #[expect(clippy::cast_possible_truncation)]
+    crate::EncodeResult::Chunk(
+        bytedata::ByteChunk::from_slice(bytedata::const_or_bytes(
+            bytedata::const_slice(&chunk, 0..chunk_len),
+            b"",
+        )),
+        consumed as u16,
+    )
+}
+
+/// Find the EUC-KR pointer used to encode a code point.
+const fn encode_ptr(cp: u32) -> Option<u16> {
+    if cp > 0xFFFF {
+        return None;
+    }
+    #[expect(clippy::cast_possible_truncation)]
+    let cp = cp as u16;
+    let arr = &cp_euc_kr::EUC_KR_CP_TO_PTR;
+    let mut base = 0;
+    let mut lim = arr.len();
+    while base < lim {
+        #[expect(clippy::integer_division)]
+        let mid = base + (lim - base) / 2;
+        let (mid_cp, mid_ptr) = arr[mid];
+        if mid_cp < cp {
+            base = mid + 1;
+        } else if mid_cp > cp {
+            lim = mid;
+        } else {
+            return Some(mid_ptr);
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_euc_kr_decode() {
+        assert_eq!(
+            EUC_KR.decode_const(b"ab\xB0\xA1"),
+            crate::DecodeResult::Utf8(2)
+        );
+        assert_eq!(
+            EUC_KR.decode_const(b"\xB0\xA1"),
+            crate::DecodeResult::Char('\u{AC00}', 2)
+        );
+        // Unified Hangul Code extension
+        assert_eq!(
+            EUC_KR.decode_const(b"\x81\x41"),
+            crate::DecodeResult::Char('\u{AC02}', 2)
+        );
+        assert_eq!(
+            EUC_KR.decode_const(b"\xB0"),
+            crate::DecodeResult::Incomplete
+        );
+        assert_eq!(
+            EUC_KR.decode_const(b"\x81\x5B"),
+            crate::DecodeResult::InvalidChar(0x81, 1)
+        );
+        assert_eq!(
+            EUC_KR.decode_const(b"\xC9\xA1"),
+            crate::DecodeResult::InvalidChar(0xC9A1, 2)
+        );
+        assert_eq!(
+            EUC_KR.decode_const(b"\x80"),
+            crate::DecodeResult::InvalidChar(0x80, 1)
+        );
+    }
+
+    #[test]
+    fn test_euc_kr_encode() {
+        assert_eq!(
+            EUC_KR.encode_const("\u{D55C}\u{AD6D}\u{C5B4}a"),
+            crate::EncodeResult::Chunk(
+                bytedata::ByteChunk::from_slice(b"\xC7\xD1\xB1\xB9\xBE\xEE"),
+                9
+            )
+        );
+        assert_eq!(
+            EUC_KR.encode_const("\u{AC02}"),
+            crate::EncodeResult::Chunk(bytedata::ByteChunk::from_slice(b"\x81\x41"), 3)
+        );
+        assert_eq!(
+            EUC_KR.encode_const("\u{20AC}"),
+            crate::EncodeResult::Chunk(bytedata::ByteChunk::from_slice(b"\xA2\xE6"), 3)
+        );
+        assert_eq!(
+            EUC_KR.encode_const("\u{4E02}"),
+            crate::EncodeResult::InvalidChar('\u{4E02}', 3)
+        );
+    }
+
+    #[test]
+    fn test_euc_kr_detect() {
+        use crate::detect::DetectionResult;
+        // "대한민국 대한민국" in EUC-KR
+        assert_eq!(
+            EucKrEncoding::detect_const(
+                b"\xB4\xEB\xC7\xD1\xB9\xCE\xB1\xB9 \xB4\xEB\xC7\xD1\xB9\xCE\xB1\xB9"
+            ),
+            DetectionResult::Tentative
+        );
+        // "にほんごのぶんしょうです" in EUC-JP
+        assert_eq!(
+            EucKrEncoding::detect_const(
+                b"\xA4\xCB\xA4\xDB\xA4\xF3\xA4\xB4\xA4\xCE\xA4\xD6\xA4\xF3\xA4\xB7\xA4\xE7\xA4\xA6\xA4\xC7\xA4\xB9"
+            ),
+            DetectionResult::Irrelevant
+        );
+        // "にほんごのぶんしょう" in Shift_JIS
+        assert_eq!(
+            EucKrEncoding::detect_const(
+                b"\x82\xC9\x82\xD9\x82\xF1\x82\xB2\x82\xCC\x82\xD4\x82\xF1\x82\xB5\x82\xE5\x82\xA4"
+            ),
+            DetectionResult::Irrelevant
+        );
+    }
+}
@@ -0,0 +1,24 @@
+/// The two-byte codes that GB18030-2005 maps to the private use area, while GB18030-2022 maps them to standard code points.
+///
+/// Each entry is the pointer of the two-byte code, the GB18030-2022 code point and the GB18030-2005 private use code point.
+/// In GB18030-2005 the standard code points are instead encoded as four-byte sequences, which are shared by both revisions.
+pub(super) static GB18030_2005_PUA: [(u16, u16, u16); 18] = [
+    (7182, 0xFE10, 0xE78D),
+    (7183, 0xFE12, 0xE78E),
+    (7184, 0xFE11, 0xE78F),
+    (7185, 0xFE13, 0xE790),
+    (7186, 0xFE14, 0xE791),
+    (7187, 0xFE15, 0xE792),
+    (7188, 0xFE16, 0xE793),
+    (7201, 0xFE17, 0xE794),
+    (7202, 0xFE18, 0xE795),
+    (7208, 0xFE19, 0xE796),
+    (23775, 0x9FB4, 0xE81E),
+    (23783, 0x9FB5, 0xE826),
+    (23788, 0x9FB6, 0xE82B),
+    (23789, 0x9FB7, 0xE82C),
+    (23795, 0x9FB8, 0xE832),
+    (23812, 0x9FB9, 0xE843),
+    (23829, 0x9FBA, 0xE854),
+    (23845, 0x9FBB, 0xE864),
+];
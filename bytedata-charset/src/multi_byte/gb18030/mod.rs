@@ -1,4 +1,5 @@
 mod cp_gb18030;
+mod gb18030_2005;
 mod gb18030_cp;
 mod gb18030_cp_ranges;
 
@@ -7,6 +8,9 @@ mod gb18030_cp_ranges;
 /// This is a charset that is a superset of GBK (simplified Chinese) but should be able to encode all Unicode characters using the modern extensions.
 /// If possible, use [`UTF-8`] or [`UTF-16`] instead.
 ///
+/// The default instance uses the mappings of GB18030-2022, as does the WHATWG Encoding Standard.
+/// GB18030-2005 maps 18 of the two-byte codes to the private use area instead, use [`Gb18030Encoding::new_2005`] or [`GB18030_2005`] for data using those mappings.
+///
 /// [`UTF-8`]: crate::Utf8Encoding
 /// [`UTF-16`]: crate::Utf16Encoding
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
@@ -14,18 +18,46 @@ mod gb18030_cp_ranges;
 #[cfg_attr(docsrs, doc(cfg(feature = "gb18030")))]
 pub struct Gb18030Encoding {
     pub(super) gbk: bool,
+    pub(super) v2005: bool,
 }
 
-/// An encoding for GB18030.
+/// An encoding for GB18030, using the GB18030-2022 mappings.
 #[cfg_attr(docsrs, doc(cfg(feature = "gb18030")))]
 pub static GB18030: Gb18030Encoding = Gb18030Encoding::new();
 
+/// An encoding for GB18030, using the GB18030-2005 mappings.
+#[cfg_attr(docsrs, doc(cfg(feature = "gb18030")))]
+pub static GB18030_2005: Gb18030Encoding = Gb18030Encoding::new_2005();
+
 impl Gb18030Encoding {
-    /// Create a new GB18030 encoding instance.
+    /// Create a new GB18030 encoding instance using the GB18030-2022 mappings.
     #[inline]
     #[must_use]
     pub const fn new() -> Self {
-        Self { gbk: false }
+        Self {
+            gbk: false,
+            v2005: false,
+        }
+    }
+
+    /// Create a new GB18030 encoding instance using the GB18030-2005 mappings.
+    ///
+    /// This decodes 18 of the two-byte codes, such as `A6D9` and `FE59`, to the private use area,
+    /// and encodes the corresponding standard code points as four-byte sequences.
+    #[inline]
+    #[must_use]
+    pub const fn new_2005() -> Self {
+        Self {
+            gbk: false,
+            v2005: true,
+        }
+    }
+
+    /// Returns `true` if this instance uses the GB18030-2005 mappings instead of the GB18030-2022 mappings.
+    #[inline]
+    #[must_use]
+    pub const fn is_2005(&self) -> bool {
+        self.v2005
     }
 
     /// Decode a GB18030 byte sequence.
@@ -46,7 +78,7 @@ impl Gb18030Encoding {
             }
             return crate::DecodeResult::Utf8(i as u64);
         }
-        decode_const_inner(bytes)
+        decode_const_inner(bytes, self.v2005)
     }
 
     /// Encode a GB18030 character sequence.
@@ -68,7 +100,7 @@ impl Gb18030Encoding {
             }
             return crate::EncodeResult::Utf8(i as u64);
         }
-        encode_const_inner(bytes, self.gbk)
+        encode_const_inner(bytes, self.gbk, self.v2005)
     }
 
     /// Detect if the given bytes are GB18030 encoded.
@@ -91,25 +123,36 @@ impl Gb18030Encoding {
 
 #[cfg_attr(docsrs, doc(cfg(feature = "gb18030")))]
 impl crate::Charset for Gb18030Encoding {
-    const CHARSET_NAME: &'static str = "utf-8";
+    const CHARSET_NAME: &'static str = "gb18030";
 
     #[inline]
     fn size_hint(&self) -> (u16, u16) {
-        (1, 2)
+        (1, 4)
+    }
+
+    #[inline]
+    fn charset_name(&self) -> &'static str {
+        if self.v2005 {
+            "gb18030-2005"
+        } else {
+            Self::CHARSET_NAME
+        }
     }
 
     #[inline]
     fn charset_alias(&self) -> &[&'static str] {
+        if self.v2005 {
+            return &["gb18030-2005"];
+        }
         &[
             // IANA
             Self::CHARSET_NAME,
-            "csutf8",
-            // extra
-            "utf8",
-            "unicode-1-1-utf-8",
-            "unicode11utf8",
-            "unicode20utf8",
-            "x-unicode20utf8",
+            "csgb18030",
+            // code pages
+            "cp54936",
+            "windows-54936",
+            // other
+            "gb18030-2022",
         ]
     }
 }
@@ -155,9 +198,10 @@ const fn detect_const_inner(gbk: bool, mut bytes: &[u8]) -> crate::detect::Detec
             offset += 1;
             continue;
         }
-        let res = decode_const_inner(bytes);
+        // the mapping revisions only differ in which characters are decoded, not in which sequences are valid
+        let res = decode_const_inner(bytes, false);
         match res {
-            crate::DecodeResult::Char(_, len) => {
+            crate::DecodeResult::Char(_, len) | crate::DecodeResult::CharPair(_, _, len) => {
                 let len = len as usize;
                 offset += len;
                 bytes =
@@ -186,7 +230,7 @@ const fn detect_const_inner(gbk: bool, mut bytes: &[u8]) -> crate::detect::Detec
 /// Decodes a GB18030 byte sequence. This should not be called directly without first validating the fist byte isn't ASCII.
 #[inline]
 #[expect(clippy::missing_asserts_for_indexing)]
-const fn decode_const_inner(bytes: &[u8]) -> crate::DecodeResult {
+const fn decode_const_inner(bytes: &[u8], v2005: bool) -> crate::DecodeResult {
     let first = bytes[0];
     if first <= 0x80 {
         return crate::DecodeResult::Char('\u{20AC}', 1);
@@ -202,6 +246,11 @@ const fn decode_const_inner(bytes: &[u8]) -> crate::DecodeResult {
         let offset = if second < 0x7F { 0x40 } else { 0x41 };
         if second >= 0x40 && second < 0xFE && second != 0x7F {
             let ptr = (first as usize - 0x81) * 190 + second as usize - offset; // 0 - 23_939
+            if v2005 {
+                if let Some(ch) = find_2005_pua(ptr) {
+                    return crate::DecodeResult::Char(ch, 2);
+                }
+            }
             let x = gb18030_cp::GB18030_PTR_TO_CP[ptr];
             if let Some(x) = char::from_u32(x as u32) {
                 return crate::DecodeResult::Char(x, 2);
@@ -263,7 +312,7 @@ const fn decode_const_inner(bytes: &[u8]) -> crate::DecodeResult {
 
 #[inline]
 #[expect(clippy::too_many_lines)]
-const fn encode_const_inner(mut bytes: &[u8], gbk: bool) -> crate::EncodeResult {
+const fn encode_const_inner(mut bytes: &[u8], gbk: bool, v2005: bool) -> crate::EncodeResult {
     let mut chunk = [0_u8; 14];
     let mut chunk_len = 0_usize;
     let mut ascii = 0_usize;
@@ -404,7 +453,7 @@ const fn encode_const_inner(mut bytes: &[u8], gbk: bool) -> crate::EncodeResult
             let ch = ch as u16;
             if let Some(&(cp, pos)) = b_find_before(ch, &cp_gb18030::GB18030_CP_TO_PTR) {
                 #[expect(clippy::cast_possible_truncation, clippy::integer_division)]
-                if cp == ch {
+                if cp == ch && !(v2005 && is_2022_two_byte(ch)) {
                     chunk[chunk_len] = ((pos / 190) + 0x81) as u8;
                     chunk_len += 1;
                     let trail = (pos % 190) as u8;
@@ -444,7 +493,7 @@ const fn encode_const_inner(mut bytes: &[u8], gbk: bool) -> crate::EncodeResult
             return crate::EncodeResult::InvalidChar(ch, ch_len as u16);
         }
 
-        let (offset, cp_offset) = if ch > 0x10000 {
+        let (offset, cp_offset) = if ch >= 0x10000 {
             (189_000, 0x10000)
         } else if ch > 0xFFE6 {
             (39_394, 0xFFE6)
@@ -479,6 +528,10 @@ const fn encode_const_inner(mut bytes: &[u8], gbk: bool) -> crate::EncodeResult
             chunk_len += 4;
         };
         consumed += ch_len as usize;
+        bytes = bytedata::const_or_bytes(
+            bytedata::const_slice(bytes, (ch_len as usize)..bytes.len()),
+            b"",
+        );
         if chunk_len > 10 {
             #[expect(clippy::cast_possible_truncation)]
             return crate::EncodeResult::Chunk(
@@ -492,6 +545,31 @@ const fn encode_const_inner(mut bytes: &[u8], gbk: bool) -> crate::EncodeResult
     }
 }
 
+/// Find the GB18030-2005 private use character of a two-byte pointer, if it differs from GB18030-2022.
+const fn find_2005_pua(ptr: usize) -> Option<char> {
+    let mut i = 0;
+    while i < gb18030_2005::GB18030_2005_PUA.len() {
+        let (pua_ptr, _, pua) = gb18030_2005::GB18030_2005_PUA[i];
+        if pua_ptr as usize == ptr {
+            return char::from_u32(pua as u32);
+        }
+        i += 1;
+    }
+    None
+}
+
+/// Returns `true` if the code point has a two-byte code in GB18030-2022 but a four-byte code in GB18030-2005.
+const fn is_2022_two_byte(cp: u16) -> bool {
+    let mut i = 0;
+    while i < gb18030_2005::GB18030_2005_PUA.len() {
+        if gb18030_2005::GB18030_2005_PUA[i].1 == cp {
+            return true;
+        }
+        i += 1;
+    }
+    false
+}
+
 const fn b_find_before(key: u16, arr: &[(u16, u16)]) -> Option<&(u16, u16)> {
     let size = arr.len();
     if size == 0 || key < arr[0].0 {
@@ -535,3 +613,105 @@ const fn b_find_before_val(val: u16, arr: &[(u16, u16)]) -> Option<&(u16, u16)>
     }
     Some(&arr[base - 1])
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gb18030_2005() {
+        assert_eq!(
+            GB18030.decode_const(b"\xA6\xD9"),
+            crate::DecodeResult::Char('\u{FE10}', 2)
+        );
+        assert_eq!(
+            GB18030_2005.decode_const(b"\xA6\xD9"),
+            crate::DecodeResult::Char('\u{E78D}', 2)
+        );
+        assert_eq!(
+            GB18030_2005.decode_const(b"\xFE\xA0"),
+            crate::DecodeResult::Char('\u{E864}', 2)
+        );
+        // the four-byte sequence and unchanged two-byte codes decode the same in both revisions
+        for charset in [GB18030, GB18030_2005] {
+            assert_eq!(
+                charset.decode_const(b"\x84\x31\x82\x36"),
+                crate::DecodeResult::Char('\u{FE10}', 4)
+            );
+            assert_eq!(
+                charset.decode_const(b"\xA6\xE0"),
+                crate::DecodeResult::Char('\u{FE35}', 2)
+            );
+        }
+
+        let encoded = |charset: Gb18030Encoding, chars: &str| match charset.encode_const(chars) {
+            crate::EncodeResult::Chunk(chunk, _) => chunk.as_slice().to_vec(),
+            crate::EncodeResult::Utf8(_)
+            | crate::EncodeResult::InvalidChar(..)
+            | crate::EncodeResult::Incomplete
+            | crate::EncodeResult::Empty => Vec::new(),
+        };
+        assert_eq!(encoded(GB18030, "\u{FE10}"), b"\xA6\xD9");
+        assert_eq!(encoded(GB18030_2005, "\u{FE10}"), b"\x84\x31\x82\x36");
+        assert_eq!(encoded(GB18030_2005, "\u{9FB4}"), b"\x82\x35\x90\x37");
+        assert_eq!(encoded(GB18030, "\u{E78D}"), b"\xA6\xD9");
+        assert_eq!(encoded(GB18030_2005, "\u{E78D}"), b"\xA6\xD9");
+        assert_eq!(encoded(GB18030_2005, "\u{FE35}"), b"\xA6\xE0");
+
+        assert_eq!(crate::Charset::charset_name(&GB18030), "gb18030");
+        assert_eq!(crate::Charset::charset_name(&GB18030_2005), "gb18030-2005");
+        assert!(GB18030_2005.is_2005());
+    }
+
+    /// The bytes and the consumed length of an encoded chunk.
+    fn encoded_chunk(res: crate::EncodeResult) -> Option<(bytedata::ByteChunk, u16)> {
+        match res {
+            crate::EncodeResult::Chunk(chunk, len) => Some((chunk, len)),
+            crate::EncodeResult::Utf8(_)
+            | crate::EncodeResult::InvalidChar(..)
+            | crate::EncodeResult::Incomplete
+            | crate::EncodeResult::Empty => None,
+        }
+    }
+
+    #[test]
+    fn test_four_byte_round_trip() {
+        let cases: [(char, &[u8]); 6] = [
+            ('\u{80}', b"\x81\x30\x81\x30"),
+            ('\u{81}', b"\x81\x30\x81\x31"),
+            ('\u{FFFF}', b"\x84\x31\xA4\x39"),
+            ('\u{10000}', b"\x90\x30\x81\x30"),
+            ('\u{1F600}', b"\x94\x39\xFC\x36"),
+            ('\u{10FFFF}', b"\xE3\x32\x9A\x35"),
+        ];
+        for charset in [GB18030, GB18030_2005] {
+            for (ch, bytes) in cases {
+                let mut utf8 = [0_u8; 4];
+                let encoded = encoded_chunk(charset.encode_const(ch.encode_utf8(&mut utf8)));
+                assert_eq!(
+                    encoded
+                        .as_ref()
+                        .map(|encoded| (encoded.0.as_slice(), usize::from(encoded.1))),
+                    Some((bytes, ch.len_utf8())),
+                    "{ch:?}"
+                );
+                assert_eq!(
+                    charset.decode_const(bytes),
+                    crate::DecodeResult::Char(ch, 4)
+                );
+            }
+
+            // consecutive four-byte characters are encoded one after the other
+            let encoded = encoded_chunk(charset.encode_const("\u{80}\u{81}\u{1F600}"));
+            assert_eq!(
+                encoded
+                    .as_ref()
+                    .map(|encoded| (encoded.0.as_slice(), usize::from(encoded.1))),
+                Some((
+                    b"\x81\x30\x81\x30\x81\x30\x81\x31\x94\x39\xFC\x36".as_slice(),
+                    8
+                ))
+            );
+        }
+    }
+}
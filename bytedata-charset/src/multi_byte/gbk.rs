@@ -21,7 +21,10 @@ impl GbkEncoding {
     #[inline]
     #[must_use]
     pub const fn new() -> Self {
-        Self(super::Gb18030Encoding { gbk: true })
+        Self(super::Gb18030Encoding {
+            gbk: true,
+            v2005: false,
+        })
     }
 
     /// Decode a GBK byte sequence.
@@ -0,0 +1,680 @@
+/// ISO-2022-JP encoding. A legacy 7-bit encoding for Japanese characters, mostly used for email.
+///
+/// This follows the WHATWG definition of ISO-2022-JP, which switches between ASCII, JIS X 0201 Roman, JIS X 0201 katakana and JIS X 0208 using escape sequences.
+/// The encoder never switches to katakana, half-width katakana are encoded as their full-width JIS X 0208 counterparts.
+///
+/// As the meaning of a byte depends on the escape sequences before it, this charset only implements the stateful [`CharsetStatefulDecoding`] and [`CharsetStatefulEncoding`] traits.
+/// The encoder returns to ASCII when the stream is finished using [`CharsetStatefulEncoding::encode_finish`].
+///
+/// Use [`UTF-8`] or [`UTF-16`] instead if possible.
+///
+/// [`CharsetStatefulDecoding`]: crate::CharsetStatefulDecoding
+/// [`CharsetStatefulEncoding`]: crate::CharsetStatefulEncoding
+/// [`CharsetStatefulEncoding::encode_finish`]: crate::CharsetStatefulEncoding::encode_finish
+/// [`UTF-8`]: crate::Utf8Encoding
+/// [`UTF-16`]: crate::Utf16Encoding
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+#[non_exhaustive]
+#[repr(transparent)]
+#[cfg_attr(docsrs, doc(cfg(feature = "iso-2022-jp")))]
+pub struct Iso2022JpEncoding;
+
+/// ISO-2022-JP encoding.
+#[cfg_attr(docsrs, doc(cfg(feature = "iso-2022-jp")))]
+pub static ISO_2022_JP: Iso2022JpEncoding = Iso2022JpEncoding::new();
+
+/// The escape byte starting every escape sequence.
+const ESC: u8 = 0x1B;
+
+/// The bits of the state holding the active mode.
+const MODE_MASK: u32 = 0b11;
+/// ASCII mode, `ESC ( B`.
+const MODE_ASCII: u32 = 0;
+/// JIS X 0201 Roman mode, `ESC ( J`.
+const MODE_ROMAN: u32 = 1;
+/// JIS X 0201 katakana mode, `ESC ( I`. Only used when decoding.
+const MODE_KATAKANA: u32 = 2;
+/// JIS X 0208 mode, `ESC $ @` or `ESC $ B`.
+const MODE_JIS0208: u32 = 3;
+/// Set when the last thing decoded was an escape sequence, as two escape sequences in a row are an error.
+const OUTPUT_FLAG: u32 = 0b100;
+
+/// The full-width JIS X 0208 characters used to encode the half-width katakana `U+FF61..=U+FF9F`, from the WHATWG index iso-2022-jp-katakana.
+const KATAKANA: [u16; 63] = [
+    0x3002, 0x300C, 0x300D, 0x3001, 0x30FB, 0x30F2, 0x30A1, 0x30A3, 0x30A5, 0x30A7, 0x30A9, 0x30E3,
+    0x30E5, 0x30E7, 0x30C3, 0x30FC, 0x30A2, 0x30A4, 0x30A6, 0x30A8, 0x30AA, 0x30AB, 0x30AD, 0x30AF,
+    0x30B1, 0x30B3, 0x30B5, 0x30B7, 0x30B9, 0x30BB, 0x30BD, 0x30BF, 0x30C1, 0x30C4, 0x30C6, 0x30C8,
+    0x30CA, 0x30CB, 0x30CC, 0x30CD, 0x30CE, 0x30CF, 0x30D2, 0x30D5, 0x30D8, 0x30DB, 0x30DE, 0x30DF,
+    0x30E0, 0x30E1, 0x30E2, 0x30E4, 0x30E6, 0x30E8, 0x30E9, 0x30EA, 0x30EB, 0x30EC, 0x30ED, 0x30EF,
+    0x30F3, 0x309B, 0x309C,
+];
+
+impl Iso2022JpEncoding {
+    /// Create a new ISO-2022-JP encoding instance.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self
+    }
+
+    /// Decode an ISO-2022-JP byte sequence in the given state. Returns the result and the state after it is consumed.
+    #[inline]
+    #[must_use]
+    pub const fn decode_const(
+        &self,
+        state: crate::CharsetState,
+        bytes: &[u8],
+    ) -> (crate::DecodeResult, crate::CharsetState) {
+        if bytes.is_empty() {
+            return (crate::DecodeResult::Empty, state);
+        }
+        decode_const_inner(state, bytes, false)
+    }
+
+    /// Decode the last bytes of an ISO-2022-JP stream in the given state. Returns the result and the state after it is consumed.
+    ///
+    /// Truncated sequences are reported as invalid and escape sequences at the end of the stream are ignored.
+    #[inline]
+    #[must_use]
+    pub const fn decode_finish_const(
+        &self,
+        state: crate::CharsetState,
+        bytes: &[u8],
+    ) -> (crate::DecodeResult, crate::CharsetState) {
+        if bytes.is_empty() {
+            return (crate::DecodeResult::Empty, crate::CharsetState::new());
+        }
+        decode_const_inner(state, bytes, true)
+    }
+
+    /// Encode an ISO-2022-JP character sequence in the given state. Returns the result and the state after it is consumed.
+    #[inline]
+    #[must_use]
+    pub const fn encode_const(
+        &self,
+        state: crate::CharsetState,
+        chars: &str,
+    ) -> (crate::EncodeResult, crate::CharsetState) {
+        let bytes = chars.as_bytes();
+        if bytes.is_empty() {
+            return (crate::EncodeResult::Empty, state);
+        }
+        let mode = state.as_u32() & MODE_MASK;
+        if mode == MODE_ASCII || mode == MODE_ROMAN {
+            let mut i = 0;
+            while i < bytes.len() && is_ascii_compatible(mode, bytes[i]) {
+                i += 1;
+            }
+            if i != 0 {
+                return (crate::EncodeResult::Utf8(i as u64), state);
+            }
+        }
+        encode_const_inner(mode, bytes)
+    }
+
+    /// Finish an ISO-2022-JP stream by returning to ASCII. Returns the escape sequence to write, if any, and the initial state.
+    #[inline]
+    #[must_use]
+    pub const fn encode_finish_const(
+        &self,
+        state: crate::CharsetState,
+    ) -> (crate::EncodeResult, crate::CharsetState) {
+        if state.as_u32() & MODE_MASK == MODE_ASCII {
+            return (crate::EncodeResult::Empty, crate::CharsetState::new());
+        }
+        (
+            crate::EncodeResult::Chunk(bytedata::ByteChunk::from_slice(b"\x1B(B"), 0),
+            crate::CharsetState::new(),
+        )
+    }
+
+    /// Detect if the given bytes are ISO-2022-JP encoded.
+    ///
+    /// Only 7-bit bytes are allowed and at least one escape sequence to a Japanese character set needs to be present, as the text would otherwise be plain ASCII.
+    #[inline]
+    #[must_use]
+    pub const fn detect_const(bytes: &[u8]) -> crate::detect::DetectionResult {
+        if bytes.is_empty() {
+            return crate::detect::DetectionResult::Incomplete;
+        }
+        detect_const_inner(bytes)
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "iso-2022-jp")))]
+impl crate::Charset for Iso2022JpEncoding {
+    const CHARSET_NAME: &'static str = "iso-2022-jp";
+
+    #[inline]
+    fn size_hint(&self) -> (u16, u16) {
+        // an escape sequence is only consumed together with what follows it, which may be another escape sequence
+        (1, 6)
+    }
+
+    #[inline]
+    fn charset_alias(&self) -> &[&'static str] {
+        &[
+            // IANA
+            Self::CHARSET_NAME,
+            "csiso2022jp",
+        ]
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "iso-2022-jp")))]
+impl crate::detect::CharsetDetector for Iso2022JpEncoding {
+    #[inline]
+    fn detect(&self, bytes: &[u8]) -> crate::detect::DetectionResult {
+        Self::detect_const(bytes)
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "iso-2022-jp")))]
+impl crate::CharsetStatefulDecoding for Iso2022JpEncoding {
+    #[inline]
+    fn decode_stateful(
+        &self,
+        state: &mut crate::CharsetState,
+        bytes: &[u8],
+    ) -> crate::DecodeResult {
+        let (res, next) = self.decode_const(*state, bytes);
+        *state = next;
+        res
+    }
+
+    #[inline]
+    fn decode_finish(&self, state: &mut crate::CharsetState, bytes: &[u8]) -> crate::DecodeResult {
+        let (res, next) = self.decode_finish_const(*state, bytes);
+        *state = next;
+        res
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "iso-2022-jp")))]
+impl crate::CharsetStatefulEncoding for Iso2022JpEncoding {
+    #[inline]
+    fn encode_stateful(&self, state: &mut crate::CharsetState, chars: &str) -> crate::EncodeResult {
+        let (res, next) = self.encode_const(*state, chars);
+        *state = next;
+        res
+    }
+
+    #[inline]
+    fn encode_finish(&self, state: &mut crate::CharsetState) -> crate::EncodeResult {
+        let (res, next) = self.encode_finish_const(*state);
+        *state = next;
+        res
+    }
+}
+
+/// Returns `true` if the byte is passed through as is in the given mode, which is only possible in ASCII and Roman mode.
+const fn is_ascii_compatible(mode: u32, byte: u8) -> bool {
+    if byte >= 0x80 || byte == 0x0E || byte == 0x0F || byte == ESC {
+        return false;
+    }
+    mode == MODE_ASCII || mode == MODE_ROMAN && byte != 0x5C && byte != 0x7E
+}
+
+/// The mode selected by the escape sequence at the start of `bytes`.
+///
+/// Returns `Err(true)` if more bytes are needed and `Err(false)` if the bytes are not a valid escape sequence.
+#[expect(clippy::missing_asserts_for_indexing)]
+const fn escape_mode(bytes: &[u8]) -> Result<u32, bool> {
+    if bytes.len() < 2 {
+        return Err(true);
+    }
+    let lead = bytes[1];
+    if lead != b'$' && lead != b'(' {
+        return Err(false);
+    }
+    if bytes.len() < 3 {
+        return Err(true);
+    }
+    match (lead, bytes[2]) {
+        (b'(', b'B') => Ok(MODE_ASCII),
+        (b'(', b'J') => Ok(MODE_ROMAN),
+        (b'(', b'I') => Ok(MODE_KATAKANA),
+        (b'$', b'@' | b'B') => Ok(MODE_JIS0208),
+        _ => Err(false),
+    }
+}
+
+/// Decodes the next character, consuming any escape sequences in front of it.
+///
+/// If `last` is set the bytes are the end of the stream and truncated sequences are invalid instead of incomplete.
+#[expect(clippy::too_many_lines)]
+const fn decode_const_inner(
+    state: crate::CharsetState,
+    bytes: &[u8],
+    last: bool,
+) -> (crate::DecodeResult, crate::CharsetState) {
+    let mut mode = state.as_u32() & MODE_MASK;
+    let mut flag = state.as_u32() & OUTPUT_FLAG != 0;
+    let mut pos = 0_usize;
+    loop {
+        if pos >= bytes.len() {
+            // only escape sequences were consumed
+            if last {
+                return (crate::DecodeResult::Empty, crate::CharsetState::new());
+            }
+            return (crate::DecodeResult::Incomplete, state);
+        }
+        let byte = bytes[pos];
+        if byte != ESC {
+            break;
+        }
+        match escape_mode(bytedata::const_or_bytes(
+            bytedata::const_slice(bytes, pos..bytes.len()),
+            b"",
+        )) {
+            Ok(next) => {
+                mode = next;
+                pos += 3;
+                if flag {
+                    // an escape sequence directly following another one is an error
+                    #[expect(clippy::cast_possible_truncation)]
+                    return (
+                        crate::DecodeResult::InvalidChar(
+                            ((ESC as u32) << 16_i32)
+                                | ((bytes[pos - 2] as u32) << 8_i32)
+                                | bytes[pos - 1] as u32,
+                            pos as u32,
+                        ),
+                        crate::CharsetState::from_u32(mode | OUTPUT_FLAG),
+                    );
+                }
+                flag = true;
+            }
+            Err(true) if !last => return (crate::DecodeResult::Incomplete, state),
+            Err(_) => {
+                // only the escape byte is consumed, the rest is decoded in the current mode
+                #[expect(clippy::cast_possible_truncation)]
+                return (
+                    crate::DecodeResult::InvalidChar(ESC as u32, pos as u32 + 1),
+                    crate::CharsetState::from_u32(mode),
+                );
+            }
+        }
+    }
+    // anything but an escape sequence clears the output flag
+    let next = crate::CharsetState::from_u32(mode);
+    let byte = bytes[pos];
+    #[expect(clippy::cast_possible_truncation)]
+    let consumed = pos as u32 + 1;
+    if mode == MODE_ASCII || mode == MODE_ROMAN {
+        if byte >= 0x80 || byte == 0x0E || byte == 0x0F {
+            return (
+                crate::DecodeResult::InvalidChar(byte as u32, consumed),
+                next,
+            );
+        }
+        if mode == MODE_ROMAN && byte == 0x5C {
+            return (crate::DecodeResult::Char('\u{A5}', consumed), next);
+        }
+        if mode == MODE_ROMAN && byte == 0x7E {
+            return (crate::DecodeResult::Char('\u{203E}', consumed), next);
+        }
+        if pos != 0 {
+            // the escape sequence is not UTF-8 compatible
+            return (crate::DecodeResult::Char(byte as char, consumed), next);
+        }
+        let mut i = 1;
+        while i < bytes.len() && is_ascii_compatible(mode, bytes[i]) {
+            i += 1;
+        }
+        return (crate::DecodeResult::Utf8(i as u64), next);
+    }
+    if mode == MODE_KATAKANA {
+        if byte >= 0x21 && byte <= 0x5F {
+            let Some(ch) = char::from_u32(0xFF61 - 0x21 + byte as u32) else {
+                unreachable!();
+            };
+            return (crate::DecodeResult::Char(ch, consumed), next);
+        }
+        return (
+            crate::DecodeResult::InvalidChar(byte as u32, consumed),
+            next,
+        );
+    }
+    // JIS X 0208
+    if byte < 0x21 || byte > 0x7E {
+        return (
+            crate::DecodeResult::InvalidChar(byte as u32, consumed),
+            next,
+        );
+    }
+    if pos + 1 >= bytes.len() {
+        if last {
+            return (
+                crate::DecodeResult::InvalidChar(byte as u32, consumed),
+                next,
+            );
+        }
+        return (crate::DecodeResult::Incomplete, state);
+    }
+    let trail = bytes[pos + 1];
+    if trail == ESC {
+        // the lead byte is invalid, the escape sequence is decoded by the next call
+        return (
+            crate::DecodeResult::InvalidChar(byte as u32, consumed),
+            next,
+        );
+    }
+    if trail >= 0x21 && trail <= 0x7E {
+        let ptr = (byte as usize - 0x21) * 94 + trail as usize - 0x21;
+        if let Some(ch) = super::jis0208::decode_ptr(ptr) {
+            return (crate::DecodeResult::Char(ch, consumed + 1), next);
+        }
+    }
+    (
+        crate::DecodeResult::InvalidChar(((byte as u32) << 8_i32) | trail as u32, consumed + 1),
+        next,
+    )
+}
+
+/// Detects ISO-2022-JP by validating the escape sequences and the characters within them.
+const fn detect_const_inner(bytes: &[u8]) -> crate::detect::DetectionResult {
+    let mut state = crate::CharsetState::new();
+    let mut offset = 0;
+    let mut japanese = false;
+    while offset < bytes.len() {
+        let rest = bytedata::const_or_bytes(bytedata::const_slice(bytes, offset..bytes.len()), b"");
+        let (res, next) = decode_const_inner(state, rest, false);
+        match res {
+            crate::DecodeResult::Char(_, len) => {
+                offset += len as usize;
+            }
+            crate::DecodeResult::Utf8(len) => {
+                #[expect(clippy::cast_possible_truncation)]
+                let len = len as usize;
+                offset += len;
+            }
+            crate::DecodeResult::InvalidChar(_, _) | crate::DecodeResult::CharPair(_, _, _) => {
+                return crate::detect::DetectionResult::Irrelevant;
+            }
+            crate::DecodeResult::Incomplete | crate::DecodeResult::Empty => break,
+        }
+        let mode = next.as_u32() & MODE_MASK;
+        if mode == MODE_KATAKANA || mode == MODE_JIS0208 {
+            japanese = true;
+        }
+        state = next;
+    }
+    if !japanese {
+        return crate::detect::DetectionResult::Incomplete;
+    }
+    crate::detect::DetectionResult::Tentative
+}
+
+/// Encodes characters that are not ASCII compatible in the given mode, switching modes as needed.
+#[inline]
+#[expect(clippy::too_many_lines)]
+const fn encode_const_inner(
+    mut mode: u32,
+    mut bytes: &[u8],
+) -> (crate::EncodeResult, crate::CharsetState) {
+    let mut chunk = [0_u8; 14];
+    let mut chunk_len = 0_usize;
+    let mut consumed = 0_usize;
+    loop {
+        let (ch, ch_len) = bytedata::const_utf8_char_next(bytes);
+        if ch_len == 0 {
+            break;
+        }
+        if ch < 0x80 {
+            #[expect(clippy::cast_possible_truncation)]
+            let byte = ch as u8;
+            let control = byte == 0x0E || byte == 0x0F || byte == ESC;
+            if control && mode != MODE_JIS0208 {
+                if chunk_len != 0 {
+                    break;
+                }
+                // the control characters of ISO-2022 can never be encoded
+                return (
+                    crate::EncodeResult::InvalidChar('\u{FFFD}', 1),
+                    crate::CharsetState::from_u32(mode),
+                );
+            }
+            if is_ascii_compatible(mode, byte) {
+                // left for the next call to return as a UTF-8 compatible run
+                break;
+            }
+            if mode != MODE_ASCII {
+                chunk[chunk_len] = ESC;
+                chunk[chunk_len + 1] = b'(';
+                chunk[chunk_len + 2] = b'B';
+                chunk_len += 3;
+                mode = MODE_ASCII;
+            }
+            // the control characters are reported by the next call
+            if control {
+                break;
+            }
+            chunk[chunk_len] = byte;
+            chunk_len += 1;
+        } else if ch == 0xA5 || ch == 0x203E {
+            if mode != MODE_ROMAN {
+                chunk[chunk_len] = ESC;
+                chunk[chunk_len + 1] = b'(';
+                chunk[chunk_len + 2] = b'J';
+                chunk_len += 3;
+                mode = MODE_ROMAN;
+            }
+            chunk[chunk_len] = if ch == 0xA5 { 0x5C } else { 0x7E };
+            chunk_len += 1;
+        } else {
+            let cp = if ch == 0x2212 {
+                0xFF0D
+            } else if ch >= 0xFF61 && ch <= 0xFF9F {
+                KATAKANA[(ch - 0xFF61) as usize] as u32
+            } else {
+                ch
+            };
+            let ptr = match super::jis0208::encode_ptr(cp) {
+                Some(ptr) if ptr < 94 * 94 => ptr,
+                _ => {
+                    if chunk_len != 0 {
+                        break;
+                    }
+                    if mode == MODE_JIS0208 {
+                        // return to ASCII before the error is reported by the next call
+                        return (
+                            crate::EncodeResult::Chunk(
+                                bytedata::ByteChunk::from_slice(b"\x1B(B"),
+                                0,
+                            ),
+                            crate::CharsetState::from_u32(MODE_ASCII),
+                        );
+                    }
+                    let Some(ch) = char::from_u32(ch) else {
+                        unreachable!();
+                    };
+                    #[expect(clippy::cast_possible_truncation)]
+                    return (
+                        crate::EncodeResult::InvalidChar(ch, ch_len as u16),
+                        crate::CharsetState::from_u32(mode),
+                    );
+                }
+            };
+            if mode != MODE_JIS0208 {
+                chunk[chunk_len] = ESC;
+                chunk[chunk_len + 1] = b'$';
+                chunk[chunk_len + 2] = b'B';
+                chunk_len += 3;
+                mode = MODE_JIS0208;
+            }
+            #[expect(clippy::cast_possible_truncation, clippy::integer_division)]
+            {
+                chunk[chunk_len] = (ptr / 94 + 0x21) as u8;
+                chunk[chunk_len + 1] = (ptr % 94 + 0x21) as u8;
+            };
+            chunk_len += 2;
+        }
+        consumed += ch_len as usize;
+        // an escape sequence and a character take at most 5 bytes
+        if chunk_len > 9 {
+            break;
+        }
+        bytes = bytedata::const_or_bytes(
+            bytedata::const_slice(bytes, (ch_len as usize)..bytes.len()),
+            b"",
+        );
+    }
+    if chunk_len == 0 {
+        return (
+            crate::EncodeResult::Empty,
+            crate::CharsetState::from_u32(mode),
+        );
+    }
+    #[expect(clippy::cast_possible_truncation)]
+    (
+        crate::EncodeResult::Chunk(
+            bytedata::ByteChunk::from_slice(bytedata::const_or_bytes(
+                bytedata::const_slice(&chunk, 0..chunk_len),
+                b"",
+            )),
+            consumed as u16,
+        ),
+        crate::CharsetState::from_u32(mode),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CharsetState, CharsetStatefulDecoding as _, CharsetStatefulEncoding as _};
+
+    #[expect(clippy::cast_possible_truncation)]
+    fn decode_all(bytes: &[u8]) -> String {
+        let mut state = CharsetState::new();
+        let mut bytes = bytes;
+        let mut out = String::new();
+        loop {
+            let len = match ISO_2022_JP.decode_finish(&mut state, bytes) {
+                crate::DecodeResult::Char(ch, len) => {
+                    out.push(ch);
+                    len as usize
+                }
+                crate::DecodeResult::InvalidChar(_, len) => {
+                    out.push('\u{FFFD}');
+                    len as usize
+                }
+                crate::DecodeResult::Utf8(len) => {
+                    let len = len as usize;
+                    out.push_str(core::str::from_utf8(&bytes[..len]).unwrap_or_default());
+                    len
+                }
+                crate::DecodeResult::CharPair(..)
+                | crate::DecodeResult::Incomplete
+                | crate::DecodeResult::Empty => break,
+            };
+            bytes = &bytes[len..];
+        }
+        assert!(state.is_initial());
+        out
+    }
+
+    #[expect(clippy::cast_possible_truncation)]
+    fn encode_all(chars: &str) -> Option<Vec<u8>> {
+        let mut state = CharsetState::new();
+        let mut chars = chars;
+        let mut out = Vec::new();
+        loop {
+            let len = match ISO_2022_JP.encode_stateful(&mut state, chars) {
+                crate::EncodeResult::Chunk(chunk, len) => {
+                    out.extend_from_slice(chunk.as_slice());
+                    len as usize
+                }
+                crate::EncodeResult::Utf8(len) => {
+                    let len = len as usize;
+                    out.extend_from_slice(&chars.as_bytes()[..len]);
+                    len
+                }
+                crate::EncodeResult::Empty => break,
+                crate::EncodeResult::InvalidChar(..) | crate::EncodeResult::Incomplete => {
+                    return None
+                }
+            };
+            chars = chars.get(len..).unwrap_or_default();
+        }
+        if let crate::EncodeResult::Chunk(chunk, _) = ISO_2022_JP.encode_finish(&mut state) {
+            out.extend_from_slice(chunk.as_slice());
+        }
+        assert!(state.is_initial());
+        Some(out)
+    }
+
+    #[test]
+    fn test_iso_2022_jp_decode() {
+        assert_eq!(decode_all(b"ab\x1B$B$\"$$\x1B(Bcd"), "ab\u{3042}\u{3044}cd");
+        assert_eq!(decode_all(b"\x1B(J\\~\x1B(B\\~"), "\u{A5}\u{203E}\\~");
+        assert_eq!(decode_all(b"\x1B(I1\x1B(B"), "\u{FF71}");
+        // escape sequences at the end of the stream are ignored
+        assert_eq!(decode_all(b"a\x1B(B"), "a");
+        // two escape sequences in a row are an error
+        assert_eq!(decode_all(b"\x1B(J\x1B(Ba"), "\u{FFFD}a");
+        // only the escape byte of an invalid escape sequence is consumed
+        assert_eq!(decode_all(b"\x1B(Xa"), "\u{FFFD}(Xa");
+        assert_eq!(decode_all(b"\x1B$"), "\u{FFFD}$");
+        assert_eq!(decode_all(b"\x1B$B0"), "\u{FFFD}");
+        assert_eq!(decode_all(b"\x0E\x80"), "\u{FFFD}\u{FFFD}");
+        assert_eq!(
+            ISO_2022_JP.decode_const(CharsetState::new(), b"\x1B$B0"),
+            (crate::DecodeResult::Incomplete, CharsetState::new())
+        );
+    }
+
+    #[test]
+    fn test_iso_2022_jp_encode() {
+        assert_eq!(
+            encode_all("ab\u{3042}\u{3044}cd").as_deref(),
+            Some(&b"ab\x1B$B$\"$$\x1B(Bcd"[..])
+        );
+        assert_eq!(
+            encode_all("\u{65E5}\u{FF71}").as_deref(),
+            Some(&b"\x1B$BF|%\"\x1B(B"[..])
+        );
+        assert_eq!(
+            encode_all("\u{A5}a\\").as_deref(),
+            Some(&b"\x1B(J\\a\x1B(B\\"[..])
+        );
+        assert_eq!(encode_all("\u{E9}"), None);
+        assert_eq!(encode_all("\u{1B}"), None);
+        // the encoder returns to ASCII before reporting an unmappable character
+        assert_eq!(
+            ISO_2022_JP.encode_const(CharsetState::from_u32(MODE_JIS0208), "\u{E9}"),
+            (
+                crate::EncodeResult::Chunk(bytedata::ByteChunk::from_slice(b"\x1B(B"), 0),
+                CharsetState::new()
+            )
+        );
+    }
+
+    #[test]
+    fn test_iso_2022_jp_stream() {
+        let chunks: [&[u8]; 5] = [b"a\x1B", b"$", b"B$\"$", b"$\x1B(", b"Bz"];
+        let mut stream = crate::DecodeStream::new(ISO_2022_JP, chunks.into_iter());
+        let mut out = String::new();
+        while let Some(Ok(chunk)) = stream.iter_next() {
+            out.push_str(chunk.as_str());
+        }
+        assert_eq!(out, "a\u{3042}\u{3044}z");
+    }
+
+    #[test]
+    fn test_iso_2022_jp_detect() {
+        use crate::detect::DetectionResult;
+        assert_eq!(
+            Iso2022JpEncoding::detect_const(b"ab\x1B$B$\"$$\x1B(Bcd"),
+            DetectionResult::Tentative
+        );
+        assert_eq!(
+            Iso2022JpEncoding::detect_const(b"plain ascii"),
+            DetectionResult::Incomplete
+        );
+        assert_eq!(
+            Iso2022JpEncoding::detect_const(b"\x1B$B\xA4\xA2"),
+            DetectionResult::Irrelevant
+        );
+    }
+}
@@ -46,6 +46,24 @@ impl JavaModifiedUtf8Encoding {
             }
         }
 
+        #[cfg(all(any(target_arch = "x86_64", target_arch = "x86"), feature = "sse2"))]
+        if maxlen >= 16 && crate::ascii7::sse2_detected() {
+            // SAFETY: The pointer is valid and the length is correct, and sse2 has been checked.
+            let res = unsafe { super::super::ascii7::ascii7_decode_sse2(bytes_ptr, maxlen, 0) };
+            if !matches!(res, crate::DecodeResult::Empty) {
+                return res;
+            }
+        }
+
+        #[cfg(feature = "swar")]
+        if maxlen >= core::mem::size_of::<usize>() {
+            // SAFETY: The pointer is valid and the length is correct.
+            let res = unsafe { super::super::ascii7::ascii7_decode_swar(bytes_ptr, maxlen, 0) };
+            if !matches!(res, crate::DecodeResult::Empty) {
+                return res;
+            }
+        }
+
         // SAFETY: The pointer is valid and the length is correct.
         unsafe { decode_const_inner(bytes_ptr, maxlen) }
     }
@@ -73,6 +91,11 @@ impl JavaModifiedUtf8Encoding {
                     chars.push(ch);
                     consumed += con as usize;
                 }
+                crate::DecodeResult::CharPair(ch0, ch1, con) => {
+                    chars.push(ch0);
+                    chars.push(ch1);
+                    consumed += con as usize;
+                }
                 crate::DecodeResult::Utf8(utf8) => {
                     #[expect(clippy::cast_possible_truncation)]
                     let utf8 = utf8 as usize;
@@ -138,6 +161,11 @@ impl JavaModifiedUtf8Encoding {
                     chars.push(ch);
                     consumed += con as usize;
                 }
+                crate::DecodeResult::CharPair(ch0, ch1, con) => {
+                    chars.push(ch0);
+                    chars.push(ch1);
+                    consumed += con as usize;
+                }
                 crate::DecodeResult::Utf8(utf8) => {
                     #[expect(clippy::cast_possible_truncation)]
                     let utf8 = utf8 as usize;
@@ -228,6 +256,10 @@ impl JavaModifiedUtf8Encoding {
                 crate::DecodeResult::InvalidChar(ch, con) => {
                     return crate::result::ExhaustiveDecodeResult::InvalidChar(ch, con)
                 }
+                crate::DecodeResult::CharPair(ch0, _, con) => {
+                    // a pair of characters is not guaranteed to fit in place of the bytes it was decoded from
+                    return crate::result::ExhaustiveDecodeResult::InvalidChar(ch0 as u32, con);
+                }
             }
         }
         #[expect(clippy::cast_possible_truncation)]
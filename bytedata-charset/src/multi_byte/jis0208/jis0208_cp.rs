@@ -0,0 +1,11110 @@
+/// Based on <https://encoding.spec.whatwg.org/index-jis0208.txt>.
+///
+/// - Identifier: cbaa91f3deb7d0841faf5c33041fc15a285da0e87e64ab802c4bf04b7c4da861
+/// - Date: 2014-12-19
+pub(super) static JIS0208_PTR_TO_CP: [u16; 11104] = [
+    0x3000, // 0
+    0x3001, // 1
+    0x3002, // 2
+    0xFF0C, // 3
+    0xFF0E, // 4
+    0x30FB, // 5
+    0xFF1A, // 6
+    0xFF1B, // 7
+    0xFF1F, // 8
+    0xFF01, // 9
+    0x309B, // 10
+    0x309C, // 11
+    0x00B4, // 12
+    0xFF40, // 13
+    0x00A8, // 14
+    0xFF3E, // 15
+    0xFFE3, // 16
+    0xFF3F, // 17
+    0x30FD, // 18
+    0x30FE, // 19
+    0x309D, // 20
+    0x309E, // 21
+    0x3003, // 22
+    0x4EDD, // 23
+    0x3005, // 24
+    0x3006, // 25
+    0x3007, // 26
+    0x30FC, // 27
+    0x2015, // 28
+    0x2010, // 29
+    0xFF0F, // 30
+    0xFF3C, // 31
+    0xFF5E, // 32
+    0x2225, // 33
+    0xFF5C, // 34
+    0x2026, // 35
+    0x2025, // 36
+    0x2018, // 37
+    0x2019, // 38
+    0x201C, // 39
+    0x201D, // 40
+    0xFF08, // 41
+    0xFF09, // 42
+    0x3014, // 43
+    0x3015, // 44
+    0xFF3B, // 45
+    0xFF3D, // 46
+    0xFF5B, // 47
+    0xFF5D, // 48
+    0x3008, // 49
+    0x3009, // 50
+    0x300A, // 51
+    0x300B, // 52
+    0x300C, // 53
+    0x300D, // 54
+    0x300E, // 55
+    0x300F, // 56
+    0x3010, // 57
+    0x3011, // 58
+    0xFF0B, // 59
+    0xFF0D, // 60
+    0x00B1, // 61
+    0x00D7, // 62
+    0x00F7, // 63
+    0xFF1D, // 64
+    0x2260, // 65
+    0xFF1C, // 66
+    0xFF1E, // 67
+    0x2266, // 68
+    0x2267, // 69
+    0x221E, // 70
+    0x2234, // 71
+    0x2642, // 72
+    0x2640, // 73
+    0x00B0, // 74
+    0x2032, // 75
+    0x2033, // 76
+    0x2103, // 77
+    0xFFE5, // 78
+    0xFF04, // 79
+    0xFFE0, // 80
+    0xFFE1, // 81
+    0xFF05, // 82
+    0xFF03, // 83
+    0xFF06, // 84
+    0xFF0A, // 85
+    0xFF20, // 86
+    0x00A7, // 87
+    0x2606, // 88
+    0x2605, // 89
+    0x25CB, // 90
+    0x25CF, // 91
+    0x25CE, // 92
+    0x25C7, // 93
+    0x25C6, // 94
+    0x25A1, // 95
+    0x25A0, // 96
+    0x25B3, // 97
+    0x25B2, // 98
+    0x25BD, // 99
+    0x25BC, // 100
+    0x203B, // 101
+    0x3012, // 102
+    0x2192, // 103
+    0x2190, // 104
+    0x2191, // 105
+    0x2193, // 106
+    0x3013, // 107
+    0x0000, // 108
+    0x0000, // 109
+    0x0000, // 110
+    0x0000, // 111
+    0x0000, // 112
+    0x0000, // 113
+    0x0000, // 114
+    0x0000, // 115
+    0x0000, // 116
+    0x0000, // 117
+    0x0000, // 118
+    0x2208, // 119
+    0x220B, // 120
+    0x2286, // 121
+    0x2287, // 122
+    0x2282, // 123
+    0x2283, // 124
+    0x222A, // 125
+    0x2229, // 126
+    0x0000, // 127
+    0x0000, // 128
+    0x0000, // 129
+    0x0000, // 130
+    0x0000, // 131
+    0x0000, // 132
+    0x0000, // 133
+    0x0000, // 134
+    0x2227, // 135
+    0x2228, // 136
+    0xFFE2, // 137
+    0x21D2, // 138
+    0x21D4, // 139
+    0x2200, // 140
+    0x2203, // 141
+    0x0000, // 142
+    0x0000, // 143
+    0x0000, // 144
+    0x0000, // 145
+    0x0000, // 146
+    0x0000, // 147
+    0x0000, // 148
+    0x0000, // 149
+    0x0000, // 150
+    0x0000, // 151
+    0x0000, // 152
+    0x2220, // 153
+    0x22A5, // 154
+    0x2312, // 155
+    0x2202, // 156
+    0x2207, // 157
+    0x2261, // 158
+    0x2252, // 159
+    0x226A, // 160
+    0x226B, // 161
+    0x221A, // 162
+    0x223D, // 163
+    0x221D, // 164
+    0x2235, // 165
+    0x222B, // 166
+    0x222C, // 167
+    0x0000, // 168
+    0x0000, // 169
+    0x0000, // 170
+    0x0000, // 171
+    0x0000, // 172
+    0x0000, // 173
+    0x0000, // 174
+    0x212B, // 175
+    0x2030, // 176
+    0x266F, // 177
+    0x266D, // 178
+    0x266A, // 179
+    0x2020, // 180
+    0x2021, // 181
+    0x00B6, // 182
+    0x0000, // 183
+    0x0000, // 184
+    0x0000, // 185
+    0x0000, // 186
+    0x25EF, // 187
+    0x0000, // 188
+    0x0000, // 189
+    0x0000, // 190
+    0x0000, // 191
+    0x0000, // 192
+    0x0000, // 193
+    0x0000, // 194
+    0x0000, // 195
+    0x0000, // 196
+    0x0000, // 197
+    0x0000, // 198
+    0x0000, // 199
+    0x0000, // 200
+    0x0000, // 201
+    0x0000, // 202
+    0xFF10, // 203
+    0xFF11, // 204
+    0xFF12, // 205
+    0xFF13, // 206
+    0xFF14, // 207
+    0xFF15, // 208
+    0xFF16, // 209
+    0xFF17, // 210
+    0xFF18, // 211
+    0xFF19, // 212
+    0x0000, // 213
+    0x0000, // 214
+    0x0000, // 215
+    0x0000, // 216
+    0x0000, // 217
+    0x0000, // 218
+    0x0000, // 219
+    0xFF21, // 220
+    0xFF22, // 221
+    0xFF23, // 222
+    0xFF24, // 223
+    0xFF25, // 224
+    0xFF26, // 225
+    0xFF27, // 226
+    0xFF28, // 227
+    0xFF29, // 228
+    0xFF2A, // 229
+    0xFF2B, // 230
+    0xFF2C, // 231
+    0xFF2D, // 232
+    0xFF2E, // 233
+    0xFF2F, // 234
+    0xFF30, // 235
+    0xFF31, // 236
+    0xFF32, // 237
+    0xFF33, // 238
+    0xFF34, // 239
+    0xFF35, // 240
+    0xFF36, // 241
+    0xFF37, // 242
+    0xFF38, // 243
+    0xFF39, // 244
+    0xFF3A, // 245
+    0x0000, // 246
+    0x0000, // 247
+    0x0000, // 248
+    0x0000, // 249
+    0x0000, // 250
+    0x0000, // 251
+    0xFF41, // 252
+    0xFF42, // 253
+    0xFF43, // 254
+    0xFF44, // 255
+    0xFF45, // 256
+    0xFF46, // 257
+    0xFF47, // 258
+    0xFF48, // 259
+    0xFF49, // 260
+    0xFF4A, // 261
+    0xFF4B, // 262
+    0xFF4C, // 263
+    0xFF4D, // 264
+    0xFF4E, // 265
+    0xFF4F, // 266
+    0xFF50, // 267
+    0xFF51, // 268
+    0xFF52, // 269
+    0xFF53, // 270
+    0xFF54, // 271
+    0xFF55, // 272
+    0xFF56, // 273
+    0xFF57, // 274
+    0xFF58, // 275
+    0xFF59, // 276
+    0xFF5A, // 277
+    0x0000, // 278
+    0x0000, // 279
+    0x0000, // 280
+    0x0000, // 281
+    0x3041, // 282
+    0x3042, // 283
+    0x3043, // 284
+    0x3044, // 285
+    0x3045, // 286
+    0x3046, // 287
+    0x3047, // 288
+    0x3048, // 289
+    0x3049, // 290
+    0x304A, // 291
+    0x304B, // 292
+    0x304C, // 293
+    0x304D, // 294
+    0x304E, // 295
+    0x304F, // 296
+    0x3050, // 297
+    0x3051, // 298
+    0x3052, // 299
+    0x3053, // 300
+    0x3054, // 301
+    0x3055, // 302
+    0x3056, // 303
+    0x3057, // 304
+    0x3058, // 305
+    0x3059, // 306
+    0x305A, // 307
+    0x305B, // 308
+    0x305C, // 309
+    0x305D, // 310
+    0x305E, // 311
+    0x305F, // 312
+    0x3060, // 313
+    0x3061, // 314
+    0x3062, // 315
+    0x3063, // 316
+    0x3064, // 317
+    0x3065, // 318
+    0x3066, // 319
+    0x3067, // 320
+    0x3068, // 321
+    0x3069, // 322
+    0x306A, // 323
+    0x306B, // 324
+    0x306C, // 325
+    0x306D, // 326
+    0x306E, // 327
+    0x306F, // 328
+    0x3070, // 329
+    0x3071, // 330
+    0x3072, // 331
+    0x3073, // 332
+    0x3074, // 333
+    0x3075, // 334
+    0x3076, // 335
+    0x3077, // 336
+    0x3078, // 337
+    0x3079, // 338
+    0x307A, // 339
+    0x307B, // 340
+    0x307C, // 341
+    0x307D, // 342
+    0x307E, // 343
+    0x307F, // 344
+    0x3080, // 345
+    0x3081, // 346
+    0x3082, // 347
+    0x3083, // 348
+    0x3084, // 349
+    0x3085, // 350
+    0x3086, // 351
+    0x3087, // 352
+    0x3088, // 353
+    0x3089, // 354
+    0x308A, // 355
+    0x308B, // 356
+    0x308C, // 357
+    0x308D, // 358
+    0x308E, // 359
+    0x308F, // 360
+    0x3090, // 361
+    0x3091, // 362
+    0x3092, // 363
+    0x3093, // 364
+    0x0000, // 365
+    0x0000, // 366
+    0x0000, // 367
+    0x0000, // 368
+    0x0000, // 369
+    0x0000, // 370
+    0x0000, // 371
+    0x0000, // 372
+    0x0000, // 373
+    0x0000, // 374
+    0x0000, // 375
+    0x30A1, // 376
+    0x30A2, // 377
+    0x30A3, // 378
+    0x30A4, // 379
+    0x30A5, // 380
+    0x30A6, // 381
+    0x30A7, // 382
+    0x30A8, // 383
+    0x30A9, // 384
+    0x30AA, // 385
+    0x30AB, // 386
+    0x30AC, // 387
+    0x30AD, // 388
+    0x30AE, // 389
+    0x30AF, // 390
+    0x30B0, // 391
+    0x30B1, // 392
+    0x30B2, // 393
+    0x30B3, // 394
+    0x30B4, // 395
+    0x30B5, // 396
+    0x30B6, // 397
+    0x30B7, // 398
+    0x30B8, // 399
+    0x30B9, // 400
+    0x30BA, // 401
+    0x30BB, // 402
+    0x30BC, // 403
+    0x30BD, // 404
+    0x30BE, // 405
+    0x30BF, // 406
+    0x30C0, // 407
+    0x30C1, // 408
+    0x30C2, // 409
+    0x30C3, // 410
+    0x30C4, // 411
+    0x30C5, // 412
+    0x30C6, // 413
+    0x30C7, // 414
+    0x30C8, // 415
+    0x30C9, // 416
+    0x30CA, // 417
+    0x30CB, // 418
+    0x30CC, // 419
+    0x30CD, // 420
+    0x30CE, // 421
+    0x30CF, // 422
+    0x30D0, // 423
+    0x30D1, // 424
+    0x30D2, // 425
+    0x30D3, // 426
+    0x30D4, // 427
+    0x30D5, // 428
+    0x30D6, // 429
+    0x30D7, // 430
+    0x30D8, // 431
+    0x30D9, // 432
+    0x30DA, // 433
+    0x30DB, // 434
+    0x30DC, // 435
+    0x30DD, // 436
+    0x30DE, // 437
+    0x30DF, // 438
+    0x30E0, // 439
+    0x30E1, // 440
+    0x30E2, // 441
+    0x30E3, // 442
+    0x30E4, // 443
+    0x30E5, // 444
+    0x30E6, // 445
+    0x30E7, // 446
+    0x30E8, // 447
+    0x30E9, // 448
+    0x30EA, // 449
+    0x30EB, // 450
+    0x30EC, // 451
+    0x30ED, // 452
+    0x30EE, // 453
+    0x30EF, // 454
+    0x30F0, // 455
+    0x30F1, // 456
+    0x30F2, // 457
+    0x30F3, // 458
+    0x30F4, // 459
+    0x30F5, // 460
+    0x30F6, // 461
+    0x0000, // 462
+    0x0000, // 463
+    0x0000, // 464
+    0x0000, // 465
+    0x0000, // 466
+    0x0000, // 467
+    0x0000, // 468
+    0x0000, // 469
+    0x0391, // 470
+    0x0392, // 471
+    0x0393, // 472
+    0x0394, // 473
+    0x0395, // 474
+    0x0396, // 475
+    0x0397, // 476
+    0x0398, // 477
+    0x0399, // 478
+    0x039A, // 479
+    0x039B, // 480
+    0x039C, // 481
+    0x039D, // 482
+    0x039E, // 483
+    0x039F, // 484
+    0x03A0, // 485
+    0x03A1, // 486
+    0x03A3, // 487
+    0x03A4, // 488
+    0x03A5, // 489
+    0x03A6, // 490
+    0x03A7, // 491
+    0x03A8, // 492
+    0x03A9, // 493
+    0x0000, // 494
+    0x0000, // 495
+    0x0000, // 496
+    0x0000, // 497
+    0x0000, // 498
+    0x0000, // 499
+    0x0000, // 500
+    0x0000, // 501
+    0x03B1, // 502
+    0x03B2, // 503
+    0x03B3, // 504
+    0x03B4, // 505
+    0x03B5, // 506
+    0x03B6, // 507
+    0x03B7, // 508
+    0x03B8, // 509
+    0x03B9, // 510
+    0x03BA, // 511
+    0x03BB, // 512
+    0x03BC, // 513
+    0x03BD, // 514
+    0x03BE, // 515
+    0x03BF, // 516
+    0x03C0, // 517
+    0x03C1, // 518
+    0x03C3, // 519
+    0x03C4, // 520
+    0x03C5, // 521
+    0x03C6, // 522
+    0x03C7, // 523
+    0x03C8, // 524
+    0x03C9, // 525
+    0x0000, // 526
+    0x0000, // 527
+    0x0000, // 528
+    0x0000, // 529
+    0x0000, // 530
+    0x0000, // 531
+    0x0000, // 532
+    0x0000, // 533
+    0x0000, // 534
+    0x0000, // 535
+    0x0000, // 536
+    0x0000, // 537
+    0x0000, // 538
+    0x0000, // 539
+    0x0000, // 540
+    0x0000, // 541
+    0x0000, // 542
+    0x0000, // 543
+    0x0000, // 544
+    0x0000, // 545
+    0x0000, // 546
+    0x0000, // 547
+    0x0000, // 548
+    0x0000, // 549
+    0x0000, // 550
+    0x0000, // 551
+    0x0000, // 552
+    0x0000, // 553
+    0x0000, // 554
+    0x0000, // 555
+    0x0000, // 556
+    0x0000, // 557
+    0x0000, // 558
+    0x0000, // 559
+    0x0000, // 560
+    0x0000, // 561
+    0x0000, // 562
+    0x0000, // 563
+    0x0410, // 564
+    0x0411, // 565
+    0x0412, // 566
+    0x0413, // 567
+    0x0414, // 568
+    0x0415, // 569
+    0x0401, // 570
+    0x0416, // 571
+    0x0417, // 572
+    0x0418, // 573
+    0x0419, // 574
+    0x041A, // 575
+    0x041B, // 576
+    0x041C, // 577
+    0x041D, // 578
+    0x041E, // 579
+    0x041F, // 580
+    0x0420, // 581
+    0x0421, // 582
+    0x0422, // 583
+    0x0423, // 584
+    0x0424, // 585
+    0x0425, // 586
+    0x0426, // 587
+    0x0427, // 588
+    0x0428, // 589
+    0x0429, // 590
+    0x042A, // 591
+    0x042B, // 592
+    0x042C, // 593
+    0x042D, // 594
+    0x042E, // 595
+    0x042F, // 596
+    0x0000, // 597
+    0x0000, // 598
+    0x0000, // 599
+    0x0000, // 600
+    0x0000, // 601
+    0x0000, // 602
+    0x0000, // 603
+    0x0000, // 604
+    0x0000, // 605
+    0x0000, // 606
+    0x0000, // 607
+    0x0000, // 608
+    0x0000, // 609
+    0x0000, // 610
+    0x0000, // 611
+    0x0430, // 612
+    0x0431, // 613
+    0x0432, // 614
+    0x0433, // 615
+    0x0434, // 616
+    0x0435, // 617
+    0x0451, // 618
+    0x0436, // 619
+    0x0437, // 620
+    0x0438, // 621
+    0x0439, // 622
+    0x043A, // 623
+    0x043B, // 624
+    0x043C, // 625
+    0x043D, // 626
+    0x043E, // 627
+    0x043F, // 628
+    0x0440, // 629
+    0x0441, // 630
+    0x0442, // 631
+    0x0443, // 632
+    0x0444, // 633
+    0x0445, // 634
+    0x0446, // 635
+    0x0447, // 636
+    0x0448, // 637
+    0x0449, // 638
+    0x044A, // 639
+    0x044B, // 640
+    0x044C, // 641
+    0x044D, // 642
+    0x044E, // 643
+    0x044F, // 644
+    0x0000, // 645
+    0x0000, // 646
+    0x0000, // 647
+    0x0000, // 648
+    0x0000, // 649
+    0x0000, // 650
+    0x0000, // 651
+    0x0000, // 652
+    0x0000, // 653
+    0x0000, // 654
+    0x0000, // 655
+    0x0000, // 656
+    0x0000, // 657
+    0x2500, // 658
+    0x2502, // 659
+    0x250C, // 660
+    0x2510, // 661
+    0x2518, // 662
+    0x2514, // 663
+    0x251C, // 664
+    0x252C, // 665
+    0x2524, // 666
+    0x2534, // 667
+    0x253C, // 668
+    0x2501, // 669
+    0x2503, // 670
+    0x250F, // 671
+    0x2513, // 672
+    0x251B, // 673
+    0x2517, // 674
+    0x2523, // 675
+    0x2533, // 676
+    0x252B, // 677
+    0x253B, // 678
+    0x254B, // 679
+    0x2520, // 680
+    0x252F, // 681
+    0x2528, // 682
+    0x2537, // 683
+    0x253F, // 684
+    0x251D, // 685
+    0x2530, // 686
+    0x2525, // 687
+    0x2538, // 688
+    0x2542, // 689
+    0x0000, // 690
+    0x0000, // 691
+    0x0000, // 692
+    0x0000, // 693
+    0x0000, // 694
+    0x0000, // 695
+    0x0000, // 696
+    0x0000, // 697
+    0x0000, // 698
+    0x0000, // 699
+    0x0000, // 700
+    0x0000, // 701
+    0x0000, // 702
+    0x0000, // 703
+    0x0000, // 704
+    0x0000, // 705
+    0x0000, // 706
+    0x0000, // 707
+    0x0000, // 708
+    0x0000, // 709
+    0x0000, // 710
+    0x0000, // 711
+    0x0000, // 712
+    0x0000, // 713
+    0x0000, // 714
+    0x0000, // 715
+    0x0000, // 716
+    0x0000, // 717
+    0x0000, // 718
+    0x0000, // 719
+    0x0000, // 720
+    0x0000, // 721
+    0x0000, // 722
+    0x0000, // 723
+    0x0000, // 724
+    0x0000, // 725
+    0x0000, // 726
+    0x0000, // 727
+    0x0000, // 728
+    0x0000, // 729
+    0x0000, // 730
+    0x0000, // 731
+    0x0000, // 732
+    0x0000, // 733
+    0x0000, // 734
+    0x0000, // 735
+    0x0000, // 736
+    0x0000, // 737
+    0x0000, // 738
+    0x0000, // 739
+    0x0000, // 740
+    0x0000, // 741
+    0x0000, // 742
+    0x0000, // 743
+    0x0000, // 744
+    0x0000, // 745
+    0x0000, // 746
+    0x0000, // 747
+    0x0000, // 748
+    0x0000, // 749
+    0x0000, // 750
+    0x0000, // 751
+    0x0000, // 752
+    0x0000, // 753
+    0x0000, // 754
+    0x0000, // 755
+    0x0000, // 756
+    0x0000, // 757
+    0x0000, // 758
+    0x0000, // 759
+    0x0000, // 760
+    0x0000, // 761
+    0x0000, // 762
+    0x0000, // 763
+    0x0000, // 764
+    0x0000, // 765
+    0x0000, // 766
+    0x0000, // 767
+    0x0000, // 768
+    0x0000, // 769
+    0x0000, // 770
+    0x0000, // 771
+    0x0000, // 772
+    0x0000, // 773
+    0x0000, // 774
+    0x0000, // 775
+    0x0000, // 776
+    0x0000, // 777
+    0x0000, // 778
+    0x0000, // 779
+    0x0000, // 780
+    0x0000, // 781
+    0x0000, // 782
+    0x0000, // 783
+    0x0000, // 784
+    0x0000, // 785
+    0x0000, // 786
+    0x0000, // 787
+    0x0000, // 788
+    0x0000, // 789
+    0x0000, // 790
+    0x0000, // 791
+    0x0000, // 792
+    0x0000, // 793
+    0x0000, // 794
+    0x0000, // 795
+    0x0000, // 796
+    0x0000, // 797
+    0x0000, // 798
+    0x0000, // 799
+    0x0000, // 800
+    0x0000, // 801
+    0x0000, // 802
+    0x0000, // 803
+    0x0000, // 804
+    0x0000, // 805
+    0x0000, // 806
+    0x0000, // 807
+    0x0000, // 808
+    0x0000, // 809
+    0x0000, // 810
+    0x0000, // 811
+    0x0000, // 812
+    0x0000, // 813
+    0x0000, // 814
+    0x0000, // 815
+    0x0000, // 816
+    0x0000, // 817
+    0x0000, // 818
+    0x0000, // 819
+    0x0000, // 820
+    0x0000, // 821
+    0x0000, // 822
+    0x0000, // 823
+    0x0000, // 824
+    0x0000, // 825
+    0x0000, // 826
+    0x0000, // 827
+    0x0000, // 828
+    0x0000, // 829
+    0x0000, // 830
+    0x0000, // 831
+    0x0000, // 832
+    0x0000, // 833
+    0x0000, // 834
+    0x0000, // 835
+    0x0000, // 836
+    0x0000, // 837
+    0x0000, // 838
+    0x0000, // 839
+    0x0000, // 840
+    0x0000, // 841
+    0x0000, // 842
+    0x0000, // 843
+    0x0000, // 844
+    0x0000, // 845
+    0x0000, // 846
+    0x0000, // 847
+    0x0000, // 848
+    0x0000, // 849
+    0x0000, // 850
+    0x0000, // 851
+    0x0000, // 852
+    0x0000, // 853
+    0x0000, // 854
+    0x0000, // 855
+    0x0000, // 856
+    0x0000, // 857
+    0x0000, // 858
+    0x0000, // 859
+    0x0000, // 860
+    0x0000, // 861
+    0x0000, // 862
+    0x0000, // 863
+    0x0000, // 864
+    0x0000, // 865
+    0x0000, // 866
+    0x0000, // 867
+    0x0000, // 868
+    0x0000, // 869
+    0x0000, // 870
+    0x0000, // 871
+    0x0000, // 872
+    0x0000, // 873
+    0x0000, // 874
+    0x0000, // 875
+    0x0000, // 876
+    0x0000, // 877
+    0x0000, // 878
+    0x0000, // 879
+    0x0000, // 880
+    0x0000, // 881
+    0x0000, // 882
+    0x0000, // 883
+    0x0000, // 884
+    0x0000, // 885
+    0x0000, // 886
+    0x0000, // 887
+    0x0000, // 888
+    0x0000, // 889
+    0x0000, // 890
+    0x0000, // 891
+    0x0000, // 892
+    0x0000, // 893
+    0x0000, // 894
+    0x0000, // 895
+    0x0000, // 896
+    0x0000, // 897
+    0x0000, // 898
+    0x0000, // 899
+    0x0000, // 900
+    0x0000, // 901
+    0x0000, // 902
+    0x0000, // 903
+    0x0000, // 904
+    0x0000, // 905
+    0x0000, // 906
+    0x0000, // 907
+    0x0000, // 908
+    0x0000, // 909
+    0x0000, // 910
+    0x0000, // 911
+    0x0000, // 912
+    0x0000, // 913
+    0x0000, // 914
+    0x0000, // 915
+    0x0000, // 916
+    0x0000, // 917
+    0x0000, // 918
+    0x0000, // 919
+    0x0000, // 920
+    0x0000, // 921
+    0x0000, // 922
+    0x0000, // 923
+    0x0000, // 924
+    0x0000, // 925
+    0x0000, // 926
+    0x0000, // 927
+    0x0000, // 928
+    0x0000, // 929
+    0x0000, // 930
+    0x0000, // 931
+    0x0000, // 932
+    0x0000, // 933
+    0x0000, // 934
+    0x0000, // 935
+    0x0000, // 936
+    0x0000, // 937
+    0x0000, // 938
+    0x0000, // 939
+    0x0000, // 940
+    0x0000, // 941
+    0x0000, // 942
+    0x0000, // 943
+    0x0000, // 944
+    0x0000, // 945
+    0x0000, // 946
+    0x0000, // 947
+    0x0000, // 948
+    0x0000, // 949
+    0x0000, // 950
+    0x0000, // 951
+    0x0000, // 952
+    0x0000, // 953
+    0x0000, // 954
+    0x0000, // 955
+    0x0000, // 956
+    0x0000, // 957
+    0x0000, // 958
+    0x0000, // 959
+    0x0000, // 960
+    0x0000, // 961
+    0x0000, // 962
+    0x0000, // 963
+    0x0000, // 964
+    0x0000, // 965
+    0x0000, // 966
+    0x0000, // 967
+    0x0000, // 968
+    0x0000, // 969
+    0x0000, // 970
+    0x0000, // 971
+    0x0000, // 972
+    0x0000, // 973
+    0x0000, // 974
+    0x0000, // 975
+    0x0000, // 976
+    0x0000, // 977
+    0x0000, // 978
+    0x0000, // 979
+    0x0000, // 980
+    0x0000, // 981
+    0x0000, // 982
+    0x0000, // 983
+    0x0000, // 984
+    0x0000, // 985
+    0x0000, // 986
+    0x0000, // 987
+    0x0000, // 988
+    0x0000, // 989
+    0x0000, // 990
+    0x0000, // 991
+    0x0000, // 992
+    0x0000, // 993
+    0x0000, // 994
+    0x0000, // 995
+    0x0000, // 996
+    0x0000, // 997
+    0x0000, // 998
+    0x0000, // 999
+    0x0000, // 1000
+    0x0000, // 1001
+    0x0000, // 1002
+    0x0000, // 1003
+    0x0000, // 1004
+    0x0000, // 1005
+    0x0000, // 1006
+    0x0000, // 1007
+    0x0000, // 1008
+    0x0000, // 1009
+    0x0000, // 1010
+    0x0000, // 1011
+    0x0000, // 1012
+    0x0000, // 1013
+    0x0000, // 1014
+    0x0000, // 1015
+    0x0000, // 1016
+    0x0000, // 1017
+    0x0000, // 1018
+    0x0000, // 1019
+    0x0000, // 1020
+    0x0000, // 1021
+    0x0000, // 1022
+    0x0000, // 1023
+    0x0000, // 1024
+    0x0000, // 1025
+    0x0000, // 1026
+    0x0000, // 1027
+    0x0000, // 1028
+    0x0000, // 1029
+    0x0000, // 1030
+    0x0000, // 1031
+    0x0000, // 1032
+    0x0000, // 1033
+    0x0000, // 1034
+    0x0000, // 1035
+    0x0000, // 1036
+    0x0000, // 1037
+    0x0000, // 1038
+    0x0000, // 1039
+    0x0000, // 1040
+    0x0000, // 1041
+    0x0000, // 1042
+    0x0000, // 1043
+    0x0000, // 1044
+    0x0000, // 1045
+    0x0000, // 1046
+    0x0000, // 1047
+    0x0000, // 1048
+    0x0000, // 1049
+    0x0000, // 1050
+    0x0000, // 1051
+    0x0000, // 1052
+    0x0000, // 1053
+    0x0000, // 1054
+    0x0000, // 1055
+    0x0000, // 1056
+    0x0000, // 1057
+    0x0000, // 1058
+    0x0000, // 1059
+    0x0000, // 1060
+    0x0000, // 1061
+    0x0000, // 1062
+    0x0000, // 1063
+    0x0000, // 1064
+    0x0000, // 1065
+    0x0000, // 1066
+    0x0000, // 1067
+    0x0000, // 1068
+    0x0000, // 1069
+    0x0000, // 1070
+    0x0000, // 1071
+    0x0000, // 1072
+    0x0000, // 1073
+    0x0000, // 1074
+    0x0000, // 1075
+    0x0000, // 1076
+    0x0000, // 1077
+    0x0000, // 1078
+    0x0000, // 1079
+    0x0000, // 1080
+    0x0000, // 1081
+    0x0000, // 1082
+    0x0000, // 1083
+    0x0000, // 1084
+    0x0000, // 1085
+    0x0000, // 1086
+    0x0000, // 1087
+    0x0000, // 1088
+    0x0000, // 1089
+    0x0000, // 1090
+    0x0000, // 1091
+    0x0000, // 1092
+    0x0000, // 1093
+    0x0000, // 1094
+    0x0000, // 1095
+    0x0000, // 1096
+    0x0000, // 1097
+    0x0000, // 1098
+    0x0000, // 1099
+    0x0000, // 1100
+    0x0000, // 1101
+    0x0000, // 1102
+    0x0000, // 1103
+    0x0000, // 1104
+    0x0000, // 1105
+    0x0000, // 1106
+    0x0000, // 1107
+    0x0000, // 1108
+    0x0000, // 1109
+    0x0000, // 1110
+    0x0000, // 1111
+    0x0000, // 1112
+    0x0000, // 1113
+    0x0000, // 1114
+    0x0000, // 1115
+    0x0000, // 1116
+    0x0000, // 1117
+    0x0000, // 1118
+    0x0000, // 1119
+    0x0000, // 1120
+    0x0000, // 1121
+    0x0000, // 1122
+    0x0000, // 1123
+    0x0000, // 1124
+    0x0000, // 1125
+    0x0000, // 1126
+    0x0000, // 1127
+    0x2460, // 1128
+    0x2461, // 1129
+    0x2462, // 1130
+    0x2463, // 1131
+    0x2464, // 1132
+    0x2465, // 1133
+    0x2466, // 1134
+    0x2467, // 1135
+    0x2468, // 1136
+    0x2469, // 1137
+    0x246A, // 1138
+    0x246B, // 1139
+    0x246C, // 1140
+    0x246D, // 1141
+    0x246E, // 1142
+    0x246F, // 1143
+    0x2470, // 1144
+    0x2471, // 1145
+    0x2472, // 1146
+    0x2473, // 1147
+    0x2160, // 1148
+    0x2161, // 1149
+    0x2162, // 1150
+    0x2163, // 1151
+    0x2164, // 1152
+    0x2165, // 1153
+    0x2166, // 1154
+    0x2167, // 1155
+    0x2168, // 1156
+    0x2169, // 1157
+    0x0000, // 1158
+    0x3349, // 1159
+    0x3314, // 1160
+    0x3322, // 1161
+    0x334D, // 1162
+    0x3318, // 1163
+    0x3327, // 1164
+    0x3303, // 1165
+    0x3336, // 1166
+    0x3351, // 1167
+    0x3357, // 1168
+    0x330D, // 1169
+    0x3326, // 1170
+    0x3323, // 1171
+    0x332B, // 1172
+    0x334A, // 1173
+    0x333B, // 1174
+    0x339C, // 1175
+    0x339D, // 1176
+    0x339E, // 1177
+    0x338E, // 1178
+    0x338F, // 1179
+    0x33C4, // 1180
+    0x33A1, // 1181
+    0x0000, // 1182
+    0x0000, // 1183
+    0x0000, // 1184
+    0x0000, // 1185
+    0x0000, // 1186
+    0x0000, // 1187
+    0x0000, // 1188
+    0x0000, // 1189
+    0x337B, // 1190
+    0x301D, // 1191
+    0x301F, // 1192
+    0x2116, // 1193
+    0x33CD, // 1194
+    0x2121, // 1195
+    0x32A4, // 1196
+    0x32A5, // 1197
+    0x32A6, // 1198
+    0x32A7, // 1199
+    0x32A8, // 1200
+    0x3231, // 1201
+    0x3232, // 1202
+    0x3239, // 1203
+    0x337E, // 1204
+    0x337D, // 1205
+    0x337C, // 1206
+    0x2252, // 1207
+    0x2261, // 1208
+    0x222B, // 1209
+    0x222E, // 1210
+    0x2211, // 1211
+    0x221A, // 1212
+    0x22A5, // 1213
+    0x2220, // 1214
+    0x221F, // 1215
+    0x22BF, // 1216
+    0x2235, // 1217
+    0x2229, // 1218
+    0x222A, // 1219
+    0x0000, // 1220
+    0x0000, // 1221
+    0x0000, // 1222
+    0x0000, // 1223
+    0x0000, // 1224
+    0x0000, // 1225
+    0x0000, // 1226
+    0x0000, // 1227
+    0x0000, // 1228
+    0x0000, // 1229
+    0x0000, // 1230
+    0x0000, // 1231
+    0x0000, // 1232
+    0x0000, // 1233
+    0x0000, // 1234
+    0x0000, // 1235
+    0x0000, // 1236
+    0x0000, // 1237
+    0x0000, // 1238
+    0x0000, // 1239
+    0x0000, // 1240
+    0x0000, // 1241
+    0x0000, // 1242
+    0x0000, // 1243
+    0x0000, // 1244
+    0x0000, // 1245
+    0x0000, // 1246
+    0x0000, // 1247
+    0x0000, // 1248
+    0x0000, // 1249
+    0x0000, // 1250
+    0x0000, // 1251
+    0x0000, // 1252
+    0x0000, // 1253
+    0x0000, // 1254
+    0x0000, // 1255
+    0x0000, // 1256
+    0x0000, // 1257
+    0x0000, // 1258
+    0x0000, // 1259
+    0x0000, // 1260
+    0x0000, // 1261
+    0x0000, // 1262
+    0x0000, // 1263
+    0x0000, // 1264
+    0x0000, // 1265
+    0x0000, // 1266
+    0x0000, // 1267
+    0x0000, // 1268
+    0x0000, // 1269
+    0x0000, // 1270
+    0x0000, // 1271
+    0x0000, // 1272
+    0x0000, // 1273
+    0x0000, // 1274
+    0x0000, // 1275
+    0x0000, // 1276
+    0x0000, // 1277
+    0x0000, // 1278
+    0x0000, // 1279
+    0x0000, // 1280
+    0x0000, // 1281
+    0x0000, // 1282
+    0x0000, // 1283
+    0x0000, // 1284
+    0x0000, // 1285
+    0x0000, // 1286
+    0x0000, // 1287
+    0x0000, // 1288
+    0x0000, // 1289
+    0x0000, // 1290
+    0x0000, // 1291
+    0x0000, // 1292
+    0x0000, // 1293
+    0x0000, // 1294
+    0x0000, // 1295
+    0x0000, // 1296
+    0x0000, // 1297
+    0x0000, // 1298
+    0x0000, // 1299
+    0x0000, // 1300
+    0x0000, // 1301
+    0x0000, // 1302
+    0x0000, // 1303
+    0x0000, // 1304
+    0x0000, // 1305
+    0x0000, // 1306
+    0x0000, // 1307
+    0x0000, // 1308
+    0x0000, // 1309
+    0x0000, // 1310
+    0x0000, // 1311
+    0x0000, // 1312
+    0x0000, // 1313
+    0x0000, // 1314
+    0x0000, // 1315
+    0x0000, // 1316
+    0x0000, // 1317
+    0x0000, // 1318
+    0x0000, // 1319
+    0x0000, // 1320
+    0x0000, // 1321
+    0x0000, // 1322
+    0x0000, // 1323
+    0x0000, // 1324
+    0x0000, // 1325
+    0x0000, // 1326
+    0x0000, // 1327
+    0x0000, // 1328
+    0x0000, // 1329
+    0x0000, // 1330
+    0x0000, // 1331
+    0x0000, // 1332
+    0x0000, // 1333
+    0x0000, // 1334
+    0x0000, // 1335
+    0x0000, // 1336
+    0x0000, // 1337
+    0x0000, // 1338
+    0x0000, // 1339
+    0x0000, // 1340
+    0x0000, // 1341
+    0x0000, // 1342
+    0x0000, // 1343
+    0x0000, // 1344
+    0x0000, // 1345
+    0x0000, // 1346
+    0x0000, // 1347
+    0x0000, // 1348
+    0x0000, // 1349
+    0x0000, // 1350
+    0x0000, // 1351
+    0x0000, // 1352
+    0x0000, // 1353
+    0x0000, // 1354
+    0x0000, // 1355
+    0x0000, // 1356
+    0x0000, // 1357
+    0x0000, // 1358
+    0x0000, // 1359
+    0x0000, // 1360
+    0x0000, // 1361
+    0x0000, // 1362
+    0x0000, // 1363
+    0x0000, // 1364
+    0x0000, // 1365
+    0x0000, // 1366
+    0x0000, // 1367
+    0x0000, // 1368
+    0x0000, // 1369
+    0x0000, // 1370
+    0x0000, // 1371
+    0x0000, // 1372
+    0x0000, // 1373
+    0x0000, // 1374
+    0x0000, // 1375
+    0x0000, // 1376
+    0x0000, // 1377
+    0x0000, // 1378
+    0x0000, // 1379
+    0x0000, // 1380
+    0x0000, // 1381
+    0x0000, // 1382
+    0x0000, // 1383
+    0x0000, // 1384
+    0x0000, // 1385
+    0x0000, // 1386
+    0x0000, // 1387
+    0x0000, // 1388
+    0x0000, // 1389
+    0x0000, // 1390
+    0x0000, // 1391
+    0x0000, // 1392
+    0x0000, // 1393
+    0x0000, // 1394
+    0x0000, // 1395
+    0x0000, // 1396
+    0x0000, // 1397
+    0x0000, // 1398
+    0x0000, // 1399
+    0x0000, // 1400
+    0x0000, // 1401
+    0x0000, // 1402
+    0x0000, // 1403
+    0x0000, // 1404
+    0x0000, // 1405
+    0x0000, // 1406
+    0x0000, // 1407
+    0x0000, // 1408
+    0x0000, // 1409
+    0x4E9C, // 1410
+    0x5516, // 1411
+    0x5A03, // 1412
+    0x963F, // 1413
+    0x54C0, // 1414
+    0x611B, // 1415
+    0x6328, // 1416
+    0x59F6, // 1417
+    0x9022, // 1418
+    0x8475, // 1419
+    0x831C, // 1420
+    0x7A50, // 1421
+    0x60AA, // 1422
+    0x63E1, // 1423
+    0x6E25, // 1424
+    0x65ED, // 1425
+    0x8466, // 1426
+    0x82A6, // 1427
+    0x9BF5, // 1428
+    0x6893, // 1429
+    0x5727, // 1430
+    0x65A1, // 1431
+    0x6271, // 1432
+    0x5B9B, // 1433
+    0x59D0, // 1434
+    0x867B, // 1435
+    0x98F4, // 1436
+    0x7D62, // 1437
+    0x7DBE, // 1438
+    0x9B8E, // 1439
+    0x6216, // 1440
+    0x7C9F, // 1441
+    0x88B7, // 1442
+    0x5B89, // 1443
+    0x5EB5, // 1444
+    0x6309, // 1445
+    0x6697, // 1446
+    0x6848, // 1447
+    0x95C7, // 1448
+    0x978D, // 1449
+    0x674F, // 1450
+    0x4EE5, // 1451
+    0x4F0A, // 1452
+    0x4F4D, // 1453
+    0x4F9D, // 1454
+    0x5049, // 1455
+    0x56F2, // 1456
+    0x5937, // 1457
+    0x59D4, // 1458
+    0x5A01, // 1459
+    0x5C09, // 1460
+    0x60DF, // 1461
+    0x610F, // 1462
+    0x6170, // 1463
+    0x6613, // 1464
+    0x6905, // 1465
+    0x70BA, // 1466
+    0x754F, // 1467
+    0x7570, // 1468
+    0x79FB, // 1469
+    0x7DAD, // 1470
+    0x7DEF, // 1471
+    0x80C3, // 1472
+    0x840E, // 1473
+    0x8863, // 1474
+    0x8B02, // 1475
+    0x9055, // 1476
+    0x907A, // 1477
+    0x533B, // 1478
+    0x4E95, // 1479
+    0x4EA5, // 1480
+    0x57DF, // 1481
+    0x80B2, // 1482
+    0x90C1, // 1483
+    0x78EF, // 1484
+    0x4E00, // 1485
+    0x58F1, // 1486
+    0x6EA2, // 1487
+    0x9038, // 1488
+    0x7A32, // 1489
+    0x8328, // 1490
+    0x828B, // 1491
+    0x9C2F, // 1492
+    0x5141, // 1493
+    0x5370, // 1494
+    0x54BD, // 1495
+    0x54E1, // 1496
+    0x56E0, // 1497
+    0x59FB, // 1498
+    0x5F15, // 1499
+    0x98F2, // 1500
+    0x6DEB, // 1501
+    0x80E4, // 1502
+    0x852D, // 1503
+    0x9662, // 1504
+    0x9670, // 1505
+    0x96A0, // 1506
+    0x97FB, // 1507
+    0x540B, // 1508
+    0x53F3, // 1509
+    0x5B87, // 1510
+    0x70CF, // 1511
+    0x7FBD, // 1512
+    0x8FC2, // 1513
+    0x96E8, // 1514
+    0x536F, // 1515
+    0x9D5C, // 1516
+    0x7ABA, // 1517
+    0x4E11, // 1518
+    0x7893, // 1519
+    0x81FC, // 1520
+    0x6E26, // 1521
+    0x5618, // 1522
+    0x5504, // 1523
+    0x6B1D, // 1524
+    0x851A, // 1525
+    0x9C3B, // 1526
+    0x59E5, // 1527
+    0x53A9, // 1528
+    0x6D66, // 1529
+    0x74DC, // 1530
+    0x958F, // 1531
+    0x5642, // 1532
+    0x4E91, // 1533
+    0x904B, // 1534
+    0x96F2, // 1535
+    0x834F, // 1536
+    0x990C, // 1537
+    0x53E1, // 1538
+    0x55B6, // 1539
+    0x5B30, // 1540
+    0x5F71, // 1541
+    0x6620, // 1542
+    0x66F3, // 1543
+    0x6804, // 1544
+    0x6C38, // 1545
+    0x6CF3, // 1546
+    0x6D29, // 1547
+    0x745B, // 1548
+    0x76C8, // 1549
+    0x7A4E, // 1550
+    0x9834, // 1551
+    0x82F1, // 1552
+    0x885B, // 1553
+    0x8A60, // 1554
+    0x92ED, // 1555
+    0x6DB2, // 1556
+    0x75AB, // 1557
+    0x76CA, // 1558
+    0x99C5, // 1559
+    0x60A6, // 1560
+    0x8B01, // 1561
+    0x8D8A, // 1562
+    0x95B2, // 1563
+    0x698E, // 1564
+    0x53AD, // 1565
+    0x5186, // 1566
+    0x5712, // 1567
+    0x5830, // 1568
+    0x5944, // 1569
+    0x5BB4, // 1570
+    0x5EF6, // 1571
+    0x6028, // 1572
+    0x63A9, // 1573
+    0x63F4, // 1574
+    0x6CBF, // 1575
+    0x6F14, // 1576
+    0x708E, // 1577
+    0x7114, // 1578
+    0x7159, // 1579
+    0x71D5, // 1580
+    0x733F, // 1581
+    0x7E01, // 1582
+    0x8276, // 1583
+    0x82D1, // 1584
+    0x8597, // 1585
+    0x9060, // 1586
+    0x925B, // 1587
+    0x9D1B, // 1588
+    0x5869, // 1589
+    0x65BC, // 1590
+    0x6C5A, // 1591
+    0x7525, // 1592
+    0x51F9, // 1593
+    0x592E, // 1594
+    0x5965, // 1595
+    0x5F80, // 1596
+    0x5FDC, // 1597
+    0x62BC, // 1598
+    0x65FA, // 1599
+    0x6A2A, // 1600
+    0x6B27, // 1601
+    0x6BB4, // 1602
+    0x738B, // 1603
+    0x7FC1, // 1604
+    0x8956, // 1605
+    0x9D2C, // 1606
+    0x9D0E, // 1607
+    0x9EC4, // 1608
+    0x5CA1, // 1609
+    0x6C96, // 1610
+    0x837B, // 1611
+    0x5104, // 1612
+    0x5C4B, // 1613
+    0x61B6, // 1614
+    0x81C6, // 1615
+    0x6876, // 1616
+    0x7261, // 1617
+    0x4E59, // 1618
+    0x4FFA, // 1619
+    0x5378, // 1620
+    0x6069, // 1621
+    0x6E29, // 1622
+    0x7A4F, // 1623
+    0x97F3, // 1624
+    0x4E0B, // 1625
+    0x5316, // 1626
+    0x4EEE, // 1627
+    0x4F55, // 1628
+    0x4F3D, // 1629
+    0x4FA1, // 1630
+    0x4F73, // 1631
+    0x52A0, // 1632
+    0x53EF, // 1633
+    0x5609, // 1634
+    0x590F, // 1635
+    0x5AC1, // 1636
+    0x5BB6, // 1637
+    0x5BE1, // 1638
+    0x79D1, // 1639
+    0x6687, // 1640
+    0x679C, // 1641
+    0x67B6, // 1642
+    0x6B4C, // 1643
+    0x6CB3, // 1644
+    0x706B, // 1645
+    0x73C2, // 1646
+    0x798D, // 1647
+    0x79BE, // 1648
+    0x7A3C, // 1649
+    0x7B87, // 1650
+    0x82B1, // 1651
+    0x82DB, // 1652
+    0x8304, // 1653
+    0x8377, // 1654
+    0x83EF, // 1655
+    0x83D3, // 1656
+    0x8766, // 1657
+    0x8AB2, // 1658
+    0x5629, // 1659
+    0x8CA8, // 1660
+    0x8FE6, // 1661
+    0x904E, // 1662
+    0x971E, // 1663
+    0x868A, // 1664
+    0x4FC4, // 1665
+    0x5CE8, // 1666
+    0x6211, // 1667
+    0x7259, // 1668
+    0x753B, // 1669
+    0x81E5, // 1670
+    0x82BD, // 1671
+    0x86FE, // 1672
+    0x8CC0, // 1673
+    0x96C5, // 1674
+    0x9913, // 1675
+    0x99D5, // 1676
+    0x4ECB, // 1677
+    0x4F1A, // 1678
+    0x89E3, // 1679
+    0x56DE, // 1680
+    0x584A, // 1681
+    0x58CA, // 1682
+    0x5EFB, // 1683
+    0x5FEB, // 1684
+    0x602A, // 1685
+    0x6094, // 1686
+    0x6062, // 1687
+    0x61D0, // 1688
+    0x6212, // 1689
+    0x62D0, // 1690
+    0x6539, // 1691
+    0x9B41, // 1692
+    0x6666, // 1693
+    0x68B0, // 1694
+    0x6D77, // 1695
+    0x7070, // 1696
+    0x754C, // 1697
+    0x7686, // 1698
+    0x7D75, // 1699
+    0x82A5, // 1700
+    0x87F9, // 1701
+    0x958B, // 1702
+    0x968E, // 1703
+    0x8C9D, // 1704
+    0x51F1, // 1705
+    0x52BE, // 1706
+    0x5916, // 1707
+    0x54B3, // 1708
+    0x5BB3, // 1709
+    0x5D16, // 1710
+    0x6168, // 1711
+    0x6982, // 1712
+    0x6DAF, // 1713
+    0x788D, // 1714
+    0x84CB, // 1715
+    0x8857, // 1716
+    0x8A72, // 1717
+    0x93A7, // 1718
+    0x9AB8, // 1719
+    0x6D6C, // 1720
+    0x99A8, // 1721
+    0x86D9, // 1722
+    0x57A3, // 1723
+    0x67FF, // 1724
+    0x86CE, // 1725
+    0x920E, // 1726
+    0x5283, // 1727
+    0x5687, // 1728
+    0x5404, // 1729
+    0x5ED3, // 1730
+    0x62E1, // 1731
+    0x64B9, // 1732
+    0x683C, // 1733
+    0x6838, // 1734
+    0x6BBB, // 1735
+    0x7372, // 1736
+    0x78BA, // 1737
+    0x7A6B, // 1738
+    0x899A, // 1739
+    0x89D2, // 1740
+    0x8D6B, // 1741
+    0x8F03, // 1742
+    0x90ED, // 1743
+    0x95A3, // 1744
+    0x9694, // 1745
+    0x9769, // 1746
+    0x5B66, // 1747
+    0x5CB3, // 1748
+    0x697D, // 1749
+    0x984D, // 1750
+    0x984E, // 1751
+    0x639B, // 1752
+    0x7B20, // 1753
+    0x6A2B, // 1754
+    0x6A7F, // 1755
+    0x68B6, // 1756
+    0x9C0D, // 1757
+    0x6F5F, // 1758
+    0x5272, // 1759
+    0x559D, // 1760
+    0x6070, // 1761
+    0x62EC, // 1762
+    0x6D3B, // 1763
+    0x6E07, // 1764
+    0x6ED1, // 1765
+    0x845B, // 1766
+    0x8910, // 1767
+    0x8F44, // 1768
+    0x4E14, // 1769
+    0x9C39, // 1770
+    0x53F6, // 1771
+    0x691B, // 1772
+    0x6A3A, // 1773
+    0x9784, // 1774
+    0x682A, // 1775
+    0x515C, // 1776
+    0x7AC3, // 1777
+    0x84B2, // 1778
+    0x91DC, // 1779
+    0x938C, // 1780
+    0x565B, // 1781
+    0x9D28, // 1782
+    0x6822, // 1783
+    0x8305, // 1784
+    0x8431, // 1785
+    0x7CA5, // 1786
+    0x5208, // 1787
+    0x82C5, // 1788
+    0x74E6, // 1789
+    0x4E7E, // 1790
+    0x4F83, // 1791
+    0x51A0, // 1792
+    0x5BD2, // 1793
+    0x520A, // 1794
+    0x52D8, // 1795
+    0x52E7, // 1796
+    0x5DFB, // 1797
+    0x559A, // 1798
+    0x582A, // 1799
+    0x59E6, // 1800
+    0x5B8C, // 1801
+    0x5B98, // 1802
+    0x5BDB, // 1803
+    0x5E72, // 1804
+    0x5E79, // 1805
+    0x60A3, // 1806
+    0x611F, // 1807
+    0x6163, // 1808
+    0x61BE, // 1809
+    0x63DB, // 1810
+    0x6562, // 1811
+    0x67D1, // 1812
+    0x6853, // 1813
+    0x68FA, // 1814
+    0x6B3E, // 1815
+    0x6B53, // 1816
+    0x6C57, // 1817
+    0x6F22, // 1818
+    0x6F97, // 1819
+    0x6F45, // 1820
+    0x74B0, // 1821
+    0x7518, // 1822
+    0x76E3, // 1823
+    0x770B, // 1824
+    0x7AFF, // 1825
+    0x7BA1, // 1826
+    0x7C21, // 1827
+    0x7DE9, // 1828
+    0x7F36, // 1829
+    0x7FF0, // 1830
+    0x809D, // 1831
+    0x8266, // 1832
+    0x839E, // 1833
+    0x89B3, // 1834
+    0x8ACC, // 1835
+    0x8CAB, // 1836
+    0x9084, // 1837
+    0x9451, // 1838
+    0x9593, // 1839
+    0x9591, // 1840
+    0x95A2, // 1841
+    0x9665, // 1842
+    0x97D3, // 1843
+    0x9928, // 1844
+    0x8218, // 1845
+    0x4E38, // 1846
+    0x542B, // 1847
+    0x5CB8, // 1848
+    0x5DCC, // 1849
+    0x73A9, // 1850
+    0x764C, // 1851
+    0x773C, // 1852
+    0x5CA9, // 1853
+    0x7FEB, // 1854
+    0x8D0B, // 1855
+    0x96C1, // 1856
+    0x9811, // 1857
+    0x9854, // 1858
+    0x9858, // 1859
+    0x4F01, // 1860
+    0x4F0E, // 1861
+    0x5371, // 1862
+    0x559C, // 1863
+    0x5668, // 1864
+    0x57FA, // 1865
+    0x5947, // 1866
+    0x5B09, // 1867
+    0x5BC4, // 1868
+    0x5C90, // 1869
+    0x5E0C, // 1870
+    0x5E7E, // 1871
+    0x5FCC, // 1872
+    0x63EE, // 1873
+    0x673A, // 1874
+    0x65D7, // 1875
+    0x65E2, // 1876
+    0x671F, // 1877
+    0x68CB, // 1878
+    0x68C4, // 1879
+    0x6A5F, // 1880
+    0x5E30, // 1881
+    0x6BC5, // 1882
+    0x6C17, // 1883
+    0x6C7D, // 1884
+    0x757F, // 1885
+    0x7948, // 1886
+    0x5B63, // 1887
+    0x7A00, // 1888
+    0x7D00, // 1889
+    0x5FBD, // 1890
+    0x898F, // 1891
+    0x8A18, // 1892
+    0x8CB4, // 1893
+    0x8D77, // 1894
+    0x8ECC, // 1895
+    0x8F1D, // 1896
+    0x98E2, // 1897
+    0x9A0E, // 1898
+    0x9B3C, // 1899
+    0x4E80, // 1900
+    0x507D, // 1901
+    0x5100, // 1902
+    0x5993, // 1903
+    0x5B9C, // 1904
+    0x622F, // 1905
+    0x6280, // 1906
+    0x64EC, // 1907
+    0x6B3A, // 1908
+    0x72A0, // 1909
+    0x7591, // 1910
+    0x7947, // 1911
+    0x7FA9, // 1912
+    0x87FB, // 1913
+    0x8ABC, // 1914
+    0x8B70, // 1915
+    0x63AC, // 1916
+    0x83CA, // 1917
+    0x97A0, // 1918
+    0x5409, // 1919
+    0x5403, // 1920
+    0x55AB, // 1921
+    0x6854, // 1922
+    0x6A58, // 1923
+    0x8A70, // 1924
+    0x7827, // 1925
+    0x6775, // 1926
+    0x9ECD, // 1927
+    0x5374, // 1928
+    0x5BA2, // 1929
+    0x811A, // 1930
+    0x8650, // 1931
+    0x9006, // 1932
+    0x4E18, // 1933
+    0x4E45, // 1934
+    0x4EC7, // 1935
+    0x4F11, // 1936
+    0x53CA, // 1937
+    0x5438, // 1938
+    0x5BAE, // 1939
+    0x5F13, // 1940
+    0x6025, // 1941
+    0x6551, // 1942
+    0x673D, // 1943
+    0x6C42, // 1944
+    0x6C72, // 1945
+    0x6CE3, // 1946
+    0x7078, // 1947
+    0x7403, // 1948
+    0x7A76, // 1949
+    0x7AAE, // 1950
+    0x7B08, // 1951
+    0x7D1A, // 1952
+    0x7CFE, // 1953
+    0x7D66, // 1954
+    0x65E7, // 1955
+    0x725B, // 1956
+    0x53BB, // 1957
+    0x5C45, // 1958
+    0x5DE8, // 1959
+    0x62D2, // 1960
+    0x62E0, // 1961
+    0x6319, // 1962
+    0x6E20, // 1963
+    0x865A, // 1964
+    0x8A31, // 1965
+    0x8DDD, // 1966
+    0x92F8, // 1967
+    0x6F01, // 1968
+    0x79A6, // 1969
+    0x9B5A, // 1970
+    0x4EA8, // 1971
+    0x4EAB, // 1972
+    0x4EAC, // 1973
+    0x4F9B, // 1974
+    0x4FA0, // 1975
+    0x50D1, // 1976
+    0x5147, // 1977
+    0x7AF6, // 1978
+    0x5171, // 1979
+    0x51F6, // 1980
+    0x5354, // 1981
+    0x5321, // 1982
+    0x537F, // 1983
+    0x53EB, // 1984
+    0x55AC, // 1985
+    0x5883, // 1986
+    0x5CE1, // 1987
+    0x5F37, // 1988
+    0x5F4A, // 1989
+    0x602F, // 1990
+    0x6050, // 1991
+    0x606D, // 1992
+    0x631F, // 1993
+    0x6559, // 1994
+    0x6A4B, // 1995
+    0x6CC1, // 1996
+    0x72C2, // 1997
+    0x72ED, // 1998
+    0x77EF, // 1999
+    0x80F8, // 2000
+    0x8105, // 2001
+    0x8208, // 2002
+    0x854E, // 2003
+    0x90F7, // 2004
+    0x93E1, // 2005
+    0x97FF, // 2006
+    0x9957, // 2007
+    0x9A5A, // 2008
+    0x4EF0, // 2009
+    0x51DD, // 2010
+    0x5C2D, // 2011
+    0x6681, // 2012
+    0x696D, // 2013
+    0x5C40, // 2014
+    0x66F2, // 2015
+    0x6975, // 2016
+    0x7389, // 2017
+    0x6850, // 2018
+    0x7C81, // 2019
+    0x50C5, // 2020
+    0x52E4, // 2021
+    0x5747, // 2022
+    0x5DFE, // 2023
+    0x9326, // 2024
+    0x65A4, // 2025
+    0x6B23, // 2026
+    0x6B3D, // 2027
+    0x7434, // 2028
+    0x7981, // 2029
+    0x79BD, // 2030
+    0x7B4B, // 2031
+    0x7DCA, // 2032
+    0x82B9, // 2033
+    0x83CC, // 2034
+    0x887F, // 2035
+    0x895F, // 2036
+    0x8B39, // 2037
+    0x8FD1, // 2038
+    0x91D1, // 2039
+    0x541F, // 2040
+    0x9280, // 2041
+    0x4E5D, // 2042
+    0x5036, // 2043
+    0x53E5, // 2044
+    0x533A, // 2045
+    0x72D7, // 2046
+    0x7396, // 2047
+    0x77E9, // 2048
+    0x82E6, // 2049
+    0x8EAF, // 2050
+    0x99C6, // 2051
+    0x99C8, // 2052
+    0x99D2, // 2053
+    0x5177, // 2054
+    0x611A, // 2055
+    0x865E, // 2056
+    0x55B0, // 2057
+    0x7A7A, // 2058
+    0x5076, // 2059
+    0x5BD3, // 2060
+    0x9047, // 2061
+    0x9685, // 2062
+    0x4E32, // 2063
+    0x6ADB, // 2064
+    0x91E7, // 2065
+    0x5C51, // 2066
+    0x5C48, // 2067
+    0x6398, // 2068
+    0x7A9F, // 2069
+    0x6C93, // 2070
+    0x9774, // 2071
+    0x8F61, // 2072
+    0x7AAA, // 2073
+    0x718A, // 2074
+    0x9688, // 2075
+    0x7C82, // 2076
+    0x6817, // 2077
+    0x7E70, // 2078
+    0x6851, // 2079
+    0x936C, // 2080
+    0x52F2, // 2081
+    0x541B, // 2082
+    0x85AB, // 2083
+    0x8A13, // 2084
+    0x7FA4, // 2085
+    0x8ECD, // 2086
+    0x90E1, // 2087
+    0x5366, // 2088
+    0x8888, // 2089
+    0x7941, // 2090
+    0x4FC2, // 2091
+    0x50BE, // 2092
+    0x5211, // 2093
+    0x5144, // 2094
+    0x5553, // 2095
+    0x572D, // 2096
+    0x73EA, // 2097
+    0x578B, // 2098
+    0x5951, // 2099
+    0x5F62, // 2100
+    0x5F84, // 2101
+    0x6075, // 2102
+    0x6176, // 2103
+    0x6167, // 2104
+    0x61A9, // 2105
+    0x63B2, // 2106
+    0x643A, // 2107
+    0x656C, // 2108
+    0x666F, // 2109
+    0x6842, // 2110
+    0x6E13, // 2111
+    0x7566, // 2112
+    0x7A3D, // 2113
+    0x7CFB, // 2114
+    0x7D4C, // 2115
+    0x7D99, // 2116
+    0x7E4B, // 2117
+    0x7F6B, // 2118
+    0x830E, // 2119
+    0x834A, // 2120
+    0x86CD, // 2121
+    0x8A08, // 2122
+    0x8A63, // 2123
+    0x8B66, // 2124
+    0x8EFD, // 2125
+    0x981A, // 2126
+    0x9D8F, // 2127
+    0x82B8, // 2128
+    0x8FCE, // 2129
+    0x9BE8, // 2130
+    0x5287, // 2131
+    0x621F, // 2132
+    0x6483, // 2133
+    0x6FC0, // 2134
+    0x9699, // 2135
+    0x6841, // 2136
+    0x5091, // 2137
+    0x6B20, // 2138
+    0x6C7A, // 2139
+    0x6F54, // 2140
+    0x7A74, // 2141
+    0x7D50, // 2142
+    0x8840, // 2143
+    0x8A23, // 2144
+    0x6708, // 2145
+    0x4EF6, // 2146
+    0x5039, // 2147
+    0x5026, // 2148
+    0x5065, // 2149
+    0x517C, // 2150
+    0x5238, // 2151
+    0x5263, // 2152
+    0x55A7, // 2153
+    0x570F, // 2154
+    0x5805, // 2155
+    0x5ACC, // 2156
+    0x5EFA, // 2157
+    0x61B2, // 2158
+    0x61F8, // 2159
+    0x62F3, // 2160
+    0x6372, // 2161
+    0x691C, // 2162
+    0x6A29, // 2163
+    0x727D, // 2164
+    0x72AC, // 2165
+    0x732E, // 2166
+    0x7814, // 2167
+    0x786F, // 2168
+    0x7D79, // 2169
+    0x770C, // 2170
+    0x80A9, // 2171
+    0x898B, // 2172
+    0x8B19, // 2173
+    0x8CE2, // 2174
+    0x8ED2, // 2175
+    0x9063, // 2176
+    0x9375, // 2177
+    0x967A, // 2178
+    0x9855, // 2179
+    0x9A13, // 2180
+    0x9E78, // 2181
+    0x5143, // 2182
+    0x539F, // 2183
+    0x53B3, // 2184
+    0x5E7B, // 2185
+    0x5F26, // 2186
+    0x6E1B, // 2187
+    0x6E90, // 2188
+    0x7384, // 2189
+    0x73FE, // 2190
+    0x7D43, // 2191
+    0x8237, // 2192
+    0x8A00, // 2193
+    0x8AFA, // 2194
+    0x9650, // 2195
+    0x4E4E, // 2196
+    0x500B, // 2197
+    0x53E4, // 2198
+    0x547C, // 2199
+    0x56FA, // 2200
+    0x59D1, // 2201
+    0x5B64, // 2202
+    0x5DF1, // 2203
+    0x5EAB, // 2204
+    0x5F27, // 2205
+    0x6238, // 2206
+    0x6545, // 2207
+    0x67AF, // 2208
+    0x6E56, // 2209
+    0x72D0, // 2210
+    0x7CCA, // 2211
+    0x88B4, // 2212
+    0x80A1, // 2213
+    0x80E1, // 2214
+    0x83F0, // 2215
+    0x864E, // 2216
+    0x8A87, // 2217
+    0x8DE8, // 2218
+    0x9237, // 2219
+    0x96C7, // 2220
+    0x9867, // 2221
+    0x9F13, // 2222
+    0x4E94, // 2223
+    0x4E92, // 2224
+    0x4F0D, // 2225
+    0x5348, // 2226
+    0x5449, // 2227
+    0x543E, // 2228
+    0x5A2F, // 2229
+    0x5F8C, // 2230
+    0x5FA1, // 2231
+    0x609F, // 2232
+    0x68A7, // 2233
+    0x6A8E, // 2234
+    0x745A, // 2235
+    0x7881, // 2236
+    0x8A9E, // 2237
+    0x8AA4, // 2238
+    0x8B77, // 2239
+    0x9190, // 2240
+    0x4E5E, // 2241
+    0x9BC9, // 2242
+    0x4EA4, // 2243
+    0x4F7C, // 2244
+    0x4FAF, // 2245
+    0x5019, // 2246
+    0x5016, // 2247
+    0x5149, // 2248
+    0x516C, // 2249
+    0x529F, // 2250
+    0x52B9, // 2251
+    0x52FE, // 2252
+    0x539A, // 2253
+    0x53E3, // 2254
+    0x5411, // 2255
+    0x540E, // 2256
+    0x5589, // 2257
+    0x5751, // 2258
+    0x57A2, // 2259
+    0x597D, // 2260
+    0x5B54, // 2261
+    0x5B5D, // 2262
+    0x5B8F, // 2263
+    0x5DE5, // 2264
+    0x5DE7, // 2265
+    0x5DF7, // 2266
+    0x5E78, // 2267
+    0x5E83, // 2268
+    0x5E9A, // 2269
+    0x5EB7, // 2270
+    0x5F18, // 2271
+    0x6052, // 2272
+    0x614C, // 2273
+    0x6297, // 2274
+    0x62D8, // 2275
+    0x63A7, // 2276
+    0x653B, // 2277
+    0x6602, // 2278
+    0x6643, // 2279
+    0x66F4, // 2280
+    0x676D, // 2281
+    0x6821, // 2282
+    0x6897, // 2283
+    0x69CB, // 2284
+    0x6C5F, // 2285
+    0x6D2A, // 2286
+    0x6D69, // 2287
+    0x6E2F, // 2288
+    0x6E9D, // 2289
+    0x7532, // 2290
+    0x7687, // 2291
+    0x786C, // 2292
+    0x7A3F, // 2293
+    0x7CE0, // 2294
+    0x7D05, // 2295
+    0x7D18, // 2296
+    0x7D5E, // 2297
+    0x7DB1, // 2298
+    0x8015, // 2299
+    0x8003, // 2300
+    0x80AF, // 2301
+    0x80B1, // 2302
+    0x8154, // 2303
+    0x818F, // 2304
+    0x822A, // 2305
+    0x8352, // 2306
+    0x884C, // 2307
+    0x8861, // 2308
+    0x8B1B, // 2309
+    0x8CA2, // 2310
+    0x8CFC, // 2311
+    0x90CA, // 2312
+    0x9175, // 2313
+    0x9271, // 2314
+    0x783F, // 2315
+    0x92FC, // 2316
+    0x95A4, // 2317
+    0x964D, // 2318
+    0x9805, // 2319
+    0x9999, // 2320
+    0x9AD8, // 2321
+    0x9D3B, // 2322
+    0x525B, // 2323
+    0x52AB, // 2324
+    0x53F7, // 2325
+    0x5408, // 2326
+    0x58D5, // 2327
+    0x62F7, // 2328
+    0x6FE0, // 2329
+    0x8C6A, // 2330
+    0x8F5F, // 2331
+    0x9EB9, // 2332
+    0x514B, // 2333
+    0x523B, // 2334
+    0x544A, // 2335
+    0x56FD, // 2336
+    0x7A40, // 2337
+    0x9177, // 2338
+    0x9D60, // 2339
+    0x9ED2, // 2340
+    0x7344, // 2341
+    0x6F09, // 2342
+    0x8170, // 2343
+    0x7511, // 2344
+    0x5FFD, // 2345
+    0x60DA, // 2346
+    0x9AA8, // 2347
+    0x72DB, // 2348
+    0x8FBC, // 2349
+    0x6B64, // 2350
+    0x9803, // 2351
+    0x4ECA, // 2352
+    0x56F0, // 2353
+    0x5764, // 2354
+    0x58BE, // 2355
+    0x5A5A, // 2356
+    0x6068, // 2357
+    0x61C7, // 2358
+    0x660F, // 2359
+    0x6606, // 2360
+    0x6839, // 2361
+    0x68B1, // 2362
+    0x6DF7, // 2363
+    0x75D5, // 2364
+    0x7D3A, // 2365
+    0x826E, // 2366
+    0x9B42, // 2367
+    0x4E9B, // 2368
+    0x4F50, // 2369
+    0x53C9, // 2370
+    0x5506, // 2371
+    0x5D6F, // 2372
+    0x5DE6, // 2373
+    0x5DEE, // 2374
+    0x67FB, // 2375
+    0x6C99, // 2376
+    0x7473, // 2377
+    0x7802, // 2378
+    0x8A50, // 2379
+    0x9396, // 2380
+    0x88DF, // 2381
+    0x5750, // 2382
+    0x5EA7, // 2383
+    0x632B, // 2384
+    0x50B5, // 2385
+    0x50AC, // 2386
+    0x518D, // 2387
+    0x6700, // 2388
+    0x54C9, // 2389
+    0x585E, // 2390
+    0x59BB, // 2391
+    0x5BB0, // 2392
+    0x5F69, // 2393
+    0x624D, // 2394
+    0x63A1, // 2395
+    0x683D, // 2396
+    0x6B73, // 2397
+    0x6E08, // 2398
+    0x707D, // 2399
+    0x91C7, // 2400
+    0x7280, // 2401
+    0x7815, // 2402
+    0x7826, // 2403
+    0x796D, // 2404
+    0x658E, // 2405
+    0x7D30, // 2406
+    0x83DC, // 2407
+    0x88C1, // 2408
+    0x8F09, // 2409
+    0x969B, // 2410
+    0x5264, // 2411
+    0x5728, // 2412
+    0x6750, // 2413
+    0x7F6A, // 2414
+    0x8CA1, // 2415
+    0x51B4, // 2416
+    0x5742, // 2417
+    0x962A, // 2418
+    0x583A, // 2419
+    0x698A, // 2420
+    0x80B4, // 2421
+    0x54B2, // 2422
+    0x5D0E, // 2423
+    0x57FC, // 2424
+    0x7895, // 2425
+    0x9DFA, // 2426
+    0x4F5C, // 2427
+    0x524A, // 2428
+    0x548B, // 2429
+    0x643E, // 2430
+    0x6628, // 2431
+    0x6714, // 2432
+    0x67F5, // 2433
+    0x7A84, // 2434
+    0x7B56, // 2435
+    0x7D22, // 2436
+    0x932F, // 2437
+    0x685C, // 2438
+    0x9BAD, // 2439
+    0x7B39, // 2440
+    0x5319, // 2441
+    0x518A, // 2442
+    0x5237, // 2443
+    0x5BDF, // 2444
+    0x62F6, // 2445
+    0x64AE, // 2446
+    0x64E6, // 2447
+    0x672D, // 2448
+    0x6BBA, // 2449
+    0x85A9, // 2450
+    0x96D1, // 2451
+    0x7690, // 2452
+    0x9BD6, // 2453
+    0x634C, // 2454
+    0x9306, // 2455
+    0x9BAB, // 2456
+    0x76BF, // 2457
+    0x6652, // 2458
+    0x4E09, // 2459
+    0x5098, // 2460
+    0x53C2, // 2461
+    0x5C71, // 2462
+    0x60E8, // 2463
+    0x6492, // 2464
+    0x6563, // 2465
+    0x685F, // 2466
+    0x71E6, // 2467
+    0x73CA, // 2468
+    0x7523, // 2469
+    0x7B97, // 2470
+    0x7E82, // 2471
+    0x8695, // 2472
+    0x8B83, // 2473
+    0x8CDB, // 2474
+    0x9178, // 2475
+    0x9910, // 2476
+    0x65AC, // 2477
+    0x66AB, // 2478
+    0x6B8B, // 2479
+    0x4ED5, // 2480
+    0x4ED4, // 2481
+    0x4F3A, // 2482
+    0x4F7F, // 2483
+    0x523A, // 2484
+    0x53F8, // 2485
+    0x53F2, // 2486
+    0x55E3, // 2487
+    0x56DB, // 2488
+    0x58EB, // 2489
+    0x59CB, // 2490
+    0x59C9, // 2491
+    0x59FF, // 2492
+    0x5B50, // 2493
+    0x5C4D, // 2494
+    0x5E02, // 2495
+    0x5E2B, // 2496
+    0x5FD7, // 2497
+    0x601D, // 2498
+    0x6307, // 2499
+    0x652F, // 2500
+    0x5B5C, // 2501
+    0x65AF, // 2502
+    0x65BD, // 2503
+    0x65E8, // 2504
+    0x679D, // 2505
+    0x6B62, // 2506
+    0x6B7B, // 2507
+    0x6C0F, // 2508
+    0x7345, // 2509
+    0x7949, // 2510
+    0x79C1, // 2511
+    0x7CF8, // 2512
+    0x7D19, // 2513
+    0x7D2B, // 2514
+    0x80A2, // 2515
+    0x8102, // 2516
+    0x81F3, // 2517
+    0x8996, // 2518
+    0x8A5E, // 2519
+    0x8A69, // 2520
+    0x8A66, // 2521
+    0x8A8C, // 2522
+    0x8AEE, // 2523
+    0x8CC7, // 2524
+    0x8CDC, // 2525
+    0x96CC, // 2526
+    0x98FC, // 2527
+    0x6B6F, // 2528
+    0x4E8B, // 2529
+    0x4F3C, // 2530
+    0x4F8D, // 2531
+    0x5150, // 2532
+    0x5B57, // 2533
+    0x5BFA, // 2534
+    0x6148, // 2535
+    0x6301, // 2536
+    0x6642, // 2537
+    0x6B21, // 2538
+    0x6ECB, // 2539
+    0x6CBB, // 2540
+    0x723E, // 2541
+    0x74BD, // 2542
+    0x75D4, // 2543
+    0x78C1, // 2544
+    0x793A, // 2545
+    0x800C, // 2546
+    0x8033, // 2547
+    0x81EA, // 2548
+    0x8494, // 2549
+    0x8F9E, // 2550
+    0x6C50, // 2551
+    0x9E7F, // 2552
+    0x5F0F, // 2553
+    0x8B58, // 2554
+    0x9D2B, // 2555
+    0x7AFA, // 2556
+    0x8EF8, // 2557
+    0x5B8D, // 2558
+    0x96EB, // 2559
+    0x4E03, // 2560
+    0x53F1, // 2561
+    0x57F7, // 2562
+    0x5931, // 2563
+    0x5AC9, // 2564
+    0x5BA4, // 2565
+    0x6089, // 2566
+    0x6E7F, // 2567
+    0x6F06, // 2568
+    0x75BE, // 2569
+    0x8CEA, // 2570
+    0x5B9F, // 2571
+    0x8500, // 2572
+    0x7BE0, // 2573
+    0x5072, // 2574
+    0x67F4, // 2575
+    0x829D, // 2576
+    0x5C61, // 2577
+    0x854A, // 2578
+    0x7E1E, // 2579
+    0x820E, // 2580
+    0x5199, // 2581
+    0x5C04, // 2582
+    0x6368, // 2583
+    0x8D66, // 2584
+    0x659C, // 2585
+    0x716E, // 2586
+    0x793E, // 2587
+    0x7D17, // 2588
+    0x8005, // 2589
+    0x8B1D, // 2590
+    0x8ECA, // 2591
+    0x906E, // 2592
+    0x86C7, // 2593
+    0x90AA, // 2594
+    0x501F, // 2595
+    0x52FA, // 2596
+    0x5C3A, // 2597
+    0x6753, // 2598
+    0x707C, // 2599
+    0x7235, // 2600
+    0x914C, // 2601
+    0x91C8, // 2602
+    0x932B, // 2603
+    0x82E5, // 2604
+    0x5BC2, // 2605
+    0x5F31, // 2606
+    0x60F9, // 2607
+    0x4E3B, // 2608
+    0x53D6, // 2609
+    0x5B88, // 2610
+    0x624B, // 2611
+    0x6731, // 2612
+    0x6B8A, // 2613
+    0x72E9, // 2614
+    0x73E0, // 2615
+    0x7A2E, // 2616
+    0x816B, // 2617
+    0x8DA3, // 2618
+    0x9152, // 2619
+    0x9996, // 2620
+    0x5112, // 2621
+    0x53D7, // 2622
+    0x546A, // 2623
+    0x5BFF, // 2624
+    0x6388, // 2625
+    0x6A39, // 2626
+    0x7DAC, // 2627
+    0x9700, // 2628
+    0x56DA, // 2629
+    0x53CE, // 2630
+    0x5468, // 2631
+    0x5B97, // 2632
+    0x5C31, // 2633
+    0x5DDE, // 2634
+    0x4FEE, // 2635
+    0x6101, // 2636
+    0x62FE, // 2637
+    0x6D32, // 2638
+    0x79C0, // 2639
+    0x79CB, // 2640
+    0x7D42, // 2641
+    0x7E4D, // 2642
+    0x7FD2, // 2643
+    0x81ED, // 2644
+    0x821F, // 2645
+    0x8490, // 2646
+    0x8846, // 2647
+    0x8972, // 2648
+    0x8B90, // 2649
+    0x8E74, // 2650
+    0x8F2F, // 2651
+    0x9031, // 2652
+    0x914B, // 2653
+    0x916C, // 2654
+    0x96C6, // 2655
+    0x919C, // 2656
+    0x4EC0, // 2657
+    0x4F4F, // 2658
+    0x5145, // 2659
+    0x5341, // 2660
+    0x5F93, // 2661
+    0x620E, // 2662
+    0x67D4, // 2663
+    0x6C41, // 2664
+    0x6E0B, // 2665
+    0x7363, // 2666
+    0x7E26, // 2667
+    0x91CD, // 2668
+    0x9283, // 2669
+    0x53D4, // 2670
+    0x5919, // 2671
+    0x5BBF, // 2672
+    0x6DD1, // 2673
+    0x795D, // 2674
+    0x7E2E, // 2675
+    0x7C9B, // 2676
+    0x587E, // 2677
+    0x719F, // 2678
+    0x51FA, // 2679
+    0x8853, // 2680
+    0x8FF0, // 2681
+    0x4FCA, // 2682
+    0x5CFB, // 2683
+    0x6625, // 2684
+    0x77AC, // 2685
+    0x7AE3, // 2686
+    0x821C, // 2687
+    0x99FF, // 2688
+    0x51C6, // 2689
+    0x5FAA, // 2690
+    0x65EC, // 2691
+    0x696F, // 2692
+    0x6B89, // 2693
+    0x6DF3, // 2694
+    0x6E96, // 2695
+    0x6F64, // 2696
+    0x76FE, // 2697
+    0x7D14, // 2698
+    0x5DE1, // 2699
+    0x9075, // 2700
+    0x9187, // 2701
+    0x9806, // 2702
+    0x51E6, // 2703
+    0x521D, // 2704
+    0x6240, // 2705
+    0x6691, // 2706
+    0x66D9, // 2707
+    0x6E1A, // 2708
+    0x5EB6, // 2709
+    0x7DD2, // 2710
+    0x7F72, // 2711
+    0x66F8, // 2712
+    0x85AF, // 2713
+    0x85F7, // 2714
+    0x8AF8, // 2715
+    0x52A9, // 2716
+    0x53D9, // 2717
+    0x5973, // 2718
+    0x5E8F, // 2719
+    0x5F90, // 2720
+    0x6055, // 2721
+    0x92E4, // 2722
+    0x9664, // 2723
+    0x50B7, // 2724
+    0x511F, // 2725
+    0x52DD, // 2726
+    0x5320, // 2727
+    0x5347, // 2728
+    0x53EC, // 2729
+    0x54E8, // 2730
+    0x5546, // 2731
+    0x5531, // 2732
+    0x5617, // 2733
+    0x5968, // 2734
+    0x59BE, // 2735
+    0x5A3C, // 2736
+    0x5BB5, // 2737
+    0x5C06, // 2738
+    0x5C0F, // 2739
+    0x5C11, // 2740
+    0x5C1A, // 2741
+    0x5E84, // 2742
+    0x5E8A, // 2743
+    0x5EE0, // 2744
+    0x5F70, // 2745
+    0x627F, // 2746
+    0x6284, // 2747
+    0x62DB, // 2748
+    0x638C, // 2749
+    0x6377, // 2750
+    0x6607, // 2751
+    0x660C, // 2752
+    0x662D, // 2753
+    0x6676, // 2754
+    0x677E, // 2755
+    0x68A2, // 2756
+    0x6A1F, // 2757
+    0x6A35, // 2758
+    0x6CBC, // 2759
+    0x6D88, // 2760
+    0x6E09, // 2761
+    0x6E58, // 2762
+    0x713C, // 2763
+    0x7126, // 2764
+    0x7167, // 2765
+    0x75C7, // 2766
+    0x7701, // 2767
+    0x785D, // 2768
+    0x7901, // 2769
+    0x7965, // 2770
+    0x79F0, // 2771
+    0x7AE0, // 2772
+    0x7B11, // 2773
+    0x7CA7, // 2774
+    0x7D39, // 2775
+    0x8096, // 2776
+    0x83D6, // 2777
+    0x848B, // 2778
+    0x8549, // 2779
+    0x885D, // 2780
+    0x88F3, // 2781
+    0x8A1F, // 2782
+    0x8A3C, // 2783
+    0x8A54, // 2784
+    0x8A73, // 2785
+    0x8C61, // 2786
+    0x8CDE, // 2787
+    0x91A4, // 2788
+    0x9266, // 2789
+    0x937E, // 2790
+    0x9418, // 2791
+    0x969C, // 2792
+    0x9798, // 2793
+    0x4E0A, // 2794
+    0x4E08, // 2795
+    0x4E1E, // 2796
+    0x4E57, // 2797
+    0x5197, // 2798
+    0x5270, // 2799
+    0x57CE, // 2800
+    0x5834, // 2801
+    0x58CC, // 2802
+    0x5B22, // 2803
+    0x5E38, // 2804
+    0x60C5, // 2805
+    0x64FE, // 2806
+    0x6761, // 2807
+    0x6756, // 2808
+    0x6D44, // 2809
+    0x72B6, // 2810
+    0x7573, // 2811
+    0x7A63, // 2812
+    0x84B8, // 2813
+    0x8B72, // 2814
+    0x91B8, // 2815
+    0x9320, // 2816
+    0x5631, // 2817
+    0x57F4, // 2818
+    0x98FE, // 2819
+    0x62ED, // 2820
+    0x690D, // 2821
+    0x6B96, // 2822
+    0x71ED, // 2823
+    0x7E54, // 2824
+    0x8077, // 2825
+    0x8272, // 2826
+    0x89E6, // 2827
+    0x98DF, // 2828
+    0x8755, // 2829
+    0x8FB1, // 2830
+    0x5C3B, // 2831
+    0x4F38, // 2832
+    0x4FE1, // 2833
+    0x4FB5, // 2834
+    0x5507, // 2835
+    0x5A20, // 2836
+    0x5BDD, // 2837
+    0x5BE9, // 2838
+    0x5FC3, // 2839
+    0x614E, // 2840
+    0x632F, // 2841
+    0x65B0, // 2842
+    0x664B, // 2843
+    0x68EE, // 2844
+    0x699B, // 2845
+    0x6D78, // 2846
+    0x6DF1, // 2847
+    0x7533, // 2848
+    0x75B9, // 2849
+    0x771F, // 2850
+    0x795E, // 2851
+    0x79E6, // 2852
+    0x7D33, // 2853
+    0x81E3, // 2854
+    0x82AF, // 2855
+    0x85AA, // 2856
+    0x89AA, // 2857
+    0x8A3A, // 2858
+    0x8EAB, // 2859
+    0x8F9B, // 2860
+    0x9032, // 2861
+    0x91DD, // 2862
+    0x9707, // 2863
+    0x4EBA, // 2864
+    0x4EC1, // 2865
+    0x5203, // 2866
+    0x5875, // 2867
+    0x58EC, // 2868
+    0x5C0B, // 2869
+    0x751A, // 2870
+    0x5C3D, // 2871
+    0x814E, // 2872
+    0x8A0A, // 2873
+    0x8FC5, // 2874
+    0x9663, // 2875
+    0x976D, // 2876
+    0x7B25, // 2877
+    0x8ACF, // 2878
+    0x9808, // 2879
+    0x9162, // 2880
+    0x56F3, // 2881
+    0x53A8, // 2882
+    0x9017, // 2883
+    0x5439, // 2884
+    0x5782, // 2885
+    0x5E25, // 2886
+    0x63A8, // 2887
+    0x6C34, // 2888
+    0x708A, // 2889
+    0x7761, // 2890
+    0x7C8B, // 2891
+    0x7FE0, // 2892
+    0x8870, // 2893
+    0x9042, // 2894
+    0x9154, // 2895
+    0x9310, // 2896
+    0x9318, // 2897
+    0x968F, // 2898
+    0x745E, // 2899
+    0x9AC4, // 2900
+    0x5D07, // 2901
+    0x5D69, // 2902
+    0x6570, // 2903
+    0x67A2, // 2904
+    0x8DA8, // 2905
+    0x96DB, // 2906
+    0x636E, // 2907
+    0x6749, // 2908
+    0x6919, // 2909
+    0x83C5, // 2910
+    0x9817, // 2911
+    0x96C0, // 2912
+    0x88FE, // 2913
+    0x6F84, // 2914
+    0x647A, // 2915
+    0x5BF8, // 2916
+    0x4E16, // 2917
+    0x702C, // 2918
+    0x755D, // 2919
+    0x662F, // 2920
+    0x51C4, // 2921
+    0x5236, // 2922
+    0x52E2, // 2923
+    0x59D3, // 2924
+    0x5F81, // 2925
+    0x6027, // 2926
+    0x6210, // 2927
+    0x653F, // 2928
+    0x6574, // 2929
+    0x661F, // 2930
+    0x6674, // 2931
+    0x68F2, // 2932
+    0x6816, // 2933
+    0x6B63, // 2934
+    0x6E05, // 2935
+    0x7272, // 2936
+    0x751F, // 2937
+    0x76DB, // 2938
+    0x7CBE, // 2939
+    0x8056, // 2940
+    0x58F0, // 2941
+    0x88FD, // 2942
+    0x897F, // 2943
+    0x8AA0, // 2944
+    0x8A93, // 2945
+    0x8ACB, // 2946
+    0x901D, // 2947
+    0x9192, // 2948
+    0x9752, // 2949
+    0x9759, // 2950
+    0x6589, // 2951
+    0x7A0E, // 2952
+    0x8106, // 2953
+    0x96BB, // 2954
+    0x5E2D, // 2955
+    0x60DC, // 2956
+    0x621A, // 2957
+    0x65A5, // 2958
+    0x6614, // 2959
+    0x6790, // 2960
+    0x77F3, // 2961
+    0x7A4D, // 2962
+    0x7C4D, // 2963
+    0x7E3E, // 2964
+    0x810A, // 2965
+    0x8CAC, // 2966
+    0x8D64, // 2967
+    0x8DE1, // 2968
+    0x8E5F, // 2969
+    0x78A9, // 2970
+    0x5207, // 2971
+    0x62D9, // 2972
+    0x63A5, // 2973
+    0x6442, // 2974
+    0x6298, // 2975
+    0x8A2D, // 2976
+    0x7A83, // 2977
+    0x7BC0, // 2978
+    0x8AAC, // 2979
+    0x96EA, // 2980
+    0x7D76, // 2981
+    0x820C, // 2982
+    0x8749, // 2983
+    0x4ED9, // 2984
+    0x5148, // 2985
+    0x5343, // 2986
+    0x5360, // 2987
+    0x5BA3, // 2988
+    0x5C02, // 2989
+    0x5C16, // 2990
+    0x5DDD, // 2991
+    0x6226, // 2992
+    0x6247, // 2993
+    0x64B0, // 2994
+    0x6813, // 2995
+    0x6834, // 2996
+    0x6CC9, // 2997
+    0x6D45, // 2998
+    0x6D17, // 2999
+    0x67D3, // 3000
+    0x6F5C, // 3001
+    0x714E, // 3002
+    0x717D, // 3003
+    0x65CB, // 3004
+    0x7A7F, // 3005
+    0x7BAD, // 3006
+    0x7DDA, // 3007
+    0x7E4A, // 3008
+    0x7FA8, // 3009
+    0x817A, // 3010
+    0x821B, // 3011
+    0x8239, // 3012
+    0x85A6, // 3013
+    0x8A6E, // 3014
+    0x8CCE, // 3015
+    0x8DF5, // 3016
+    0x9078, // 3017
+    0x9077, // 3018
+    0x92AD, // 3019
+    0x9291, // 3020
+    0x9583, // 3021
+    0x9BAE, // 3022
+    0x524D, // 3023
+    0x5584, // 3024
+    0x6F38, // 3025
+    0x7136, // 3026
+    0x5168, // 3027
+    0x7985, // 3028
+    0x7E55, // 3029
+    0x81B3, // 3030
+    0x7CCE, // 3031
+    0x564C, // 3032
+    0x5851, // 3033
+    0x5CA8, // 3034
+    0x63AA, // 3035
+    0x66FE, // 3036
+    0x66FD, // 3037
+    0x695A, // 3038
+    0x72D9, // 3039
+    0x758F, // 3040
+    0x758E, // 3041
+    0x790E, // 3042
+    0x7956, // 3043
+    0x79DF, // 3044
+    0x7C97, // 3045
+    0x7D20, // 3046
+    0x7D44, // 3047
+    0x8607, // 3048
+    0x8A34, // 3049
+    0x963B, // 3050
+    0x9061, // 3051
+    0x9F20, // 3052
+    0x50E7, // 3053
+    0x5275, // 3054
+    0x53CC, // 3055
+    0x53E2, // 3056
+    0x5009, // 3057
+    0x55AA, // 3058
+    0x58EE, // 3059
+    0x594F, // 3060
+    0x723D, // 3061
+    0x5B8B, // 3062
+    0x5C64, // 3063
+    0x531D, // 3064
+    0x60E3, // 3065
+    0x60F3, // 3066
+    0x635C, // 3067
+    0x6383, // 3068
+    0x633F, // 3069
+    0x63BB, // 3070
+    0x64CD, // 3071
+    0x65E9, // 3072
+    0x66F9, // 3073
+    0x5DE3, // 3074
+    0x69CD, // 3075
+    0x69FD, // 3076
+    0x6F15, // 3077
+    0x71E5, // 3078
+    0x4E89, // 3079
+    0x75E9, // 3080
+    0x76F8, // 3081
+    0x7A93, // 3082
+    0x7CDF, // 3083
+    0x7DCF, // 3084
+    0x7D9C, // 3085
+    0x8061, // 3086
+    0x8349, // 3087
+    0x8358, // 3088
+    0x846C, // 3089
+    0x84BC, // 3090
+    0x85FB, // 3091
+    0x88C5, // 3092
+    0x8D70, // 3093
+    0x9001, // 3094
+    0x906D, // 3095
+    0x9397, // 3096
+    0x971C, // 3097
+    0x9A12, // 3098
+    0x50CF, // 3099
+    0x5897, // 3100
+    0x618E, // 3101
+    0x81D3, // 3102
+    0x8535, // 3103
+    0x8D08, // 3104
+    0x9020, // 3105
+    0x4FC3, // 3106
+    0x5074, // 3107
+    0x5247, // 3108
+    0x5373, // 3109
+    0x606F, // 3110
+    0x6349, // 3111
+    0x675F, // 3112
+    0x6E2C, // 3113
+    0x8DB3, // 3114
+    0x901F, // 3115
+    0x4FD7, // 3116
+    0x5C5E, // 3117
+    0x8CCA, // 3118
+    0x65CF, // 3119
+    0x7D9A, // 3120
+    0x5352, // 3121
+    0x8896, // 3122
+    0x5176, // 3123
+    0x63C3, // 3124
+    0x5B58, // 3125
+    0x5B6B, // 3126
+    0x5C0A, // 3127
+    0x640D, // 3128
+    0x6751, // 3129
+    0x905C, // 3130
+    0x4ED6, // 3131
+    0x591A, // 3132
+    0x592A, // 3133
+    0x6C70, // 3134
+    0x8A51, // 3135
+    0x553E, // 3136
+    0x5815, // 3137
+    0x59A5, // 3138
+    0x60F0, // 3139
+    0x6253, // 3140
+    0x67C1, // 3141
+    0x8235, // 3142
+    0x6955, // 3143
+    0x9640, // 3144
+    0x99C4, // 3145
+    0x9A28, // 3146
+    0x4F53, // 3147
+    0x5806, // 3148
+    0x5BFE, // 3149
+    0x8010, // 3150
+    0x5CB1, // 3151
+    0x5E2F, // 3152
+    0x5F85, // 3153
+    0x6020, // 3154
+    0x614B, // 3155
+    0x6234, // 3156
+    0x66FF, // 3157
+    0x6CF0, // 3158
+    0x6EDE, // 3159
+    0x80CE, // 3160
+    0x817F, // 3161
+    0x82D4, // 3162
+    0x888B, // 3163
+    0x8CB8, // 3164
+    0x9000, // 3165
+    0x902E, // 3166
+    0x968A, // 3167
+    0x9EDB, // 3168
+    0x9BDB, // 3169
+    0x4EE3, // 3170
+    0x53F0, // 3171
+    0x5927, // 3172
+    0x7B2C, // 3173
+    0x918D, // 3174
+    0x984C, // 3175
+    0x9DF9, // 3176
+    0x6EDD, // 3177
+    0x7027, // 3178
+    0x5353, // 3179
+    0x5544, // 3180
+    0x5B85, // 3181
+    0x6258, // 3182
+    0x629E, // 3183
+    0x62D3, // 3184
+    0x6CA2, // 3185
+    0x6FEF, // 3186
+    0x7422, // 3187
+    0x8A17, // 3188
+    0x9438, // 3189
+    0x6FC1, // 3190
+    0x8AFE, // 3191
+    0x8338, // 3192
+    0x51E7, // 3193
+    0x86F8, // 3194
+    0x53EA, // 3195
+    0x53E9, // 3196
+    0x4F46, // 3197
+    0x9054, // 3198
+    0x8FB0, // 3199
+    0x596A, // 3200
+    0x8131, // 3201
+    0x5DFD, // 3202
+    0x7AEA, // 3203
+    0x8FBF, // 3204
+    0x68DA, // 3205
+    0x8C37, // 3206
+    0x72F8, // 3207
+    0x9C48, // 3208
+    0x6A3D, // 3209
+    0x8AB0, // 3210
+    0x4E39, // 3211
+    0x5358, // 3212
+    0x5606, // 3213
+    0x5766, // 3214
+    0x62C5, // 3215
+    0x63A2, // 3216
+    0x65E6, // 3217
+    0x6B4E, // 3218
+    0x6DE1, // 3219
+    0x6E5B, // 3220
+    0x70AD, // 3221
+    0x77ED, // 3222
+    0x7AEF, // 3223
+    0x7BAA, // 3224
+    0x7DBB, // 3225
+    0x803D, // 3226
+    0x80C6, // 3227
+    0x86CB, // 3228
+    0x8A95, // 3229
+    0x935B, // 3230
+    0x56E3, // 3231
+    0x58C7, // 3232
+    0x5F3E, // 3233
+    0x65AD, // 3234
+    0x6696, // 3235
+    0x6A80, // 3236
+    0x6BB5, // 3237
+    0x7537, // 3238
+    0x8AC7, // 3239
+    0x5024, // 3240
+    0x77E5, // 3241
+    0x5730, // 3242
+    0x5F1B, // 3243
+    0x6065, // 3244
+    0x667A, // 3245
+    0x6C60, // 3246
+    0x75F4, // 3247
+    0x7A1A, // 3248
+    0x7F6E, // 3249
+    0x81F4, // 3250
+    0x8718, // 3251
+    0x9045, // 3252
+    0x99B3, // 3253
+    0x7BC9, // 3254
+    0x755C, // 3255
+    0x7AF9, // 3256
+    0x7B51, // 3257
+    0x84C4, // 3258
+    0x9010, // 3259
+    0x79E9, // 3260
+    0x7A92, // 3261
+    0x8336, // 3262
+    0x5AE1, // 3263
+    0x7740, // 3264
+    0x4E2D, // 3265
+    0x4EF2, // 3266
+    0x5B99, // 3267
+    0x5FE0, // 3268
+    0x62BD, // 3269
+    0x663C, // 3270
+    0x67F1, // 3271
+    0x6CE8, // 3272
+    0x866B, // 3273
+    0x8877, // 3274
+    0x8A3B, // 3275
+    0x914E, // 3276
+    0x92F3, // 3277
+    0x99D0, // 3278
+    0x6A17, // 3279
+    0x7026, // 3280
+    0x732A, // 3281
+    0x82E7, // 3282
+    0x8457, // 3283
+    0x8CAF, // 3284
+    0x4E01, // 3285
+    0x5146, // 3286
+    0x51CB, // 3287
+    0x558B, // 3288
+    0x5BF5, // 3289
+    0x5E16, // 3290
+    0x5E33, // 3291
+    0x5E81, // 3292
+    0x5F14, // 3293
+    0x5F35, // 3294
+    0x5F6B, // 3295
+    0x5FB4, // 3296
+    0x61F2, // 3297
+    0x6311, // 3298
+    0x66A2, // 3299
+    0x671D, // 3300
+    0x6F6E, // 3301
+    0x7252, // 3302
+    0x753A, // 3303
+    0x773A, // 3304
+    0x8074, // 3305
+    0x8139, // 3306
+    0x8178, // 3307
+    0x8776, // 3308
+    0x8ABF, // 3309
+    0x8ADC, // 3310
+    0x8D85, // 3311
+    0x8DF3, // 3312
+    0x929A, // 3313
+    0x9577, // 3314
+    0x9802, // 3315
+    0x9CE5, // 3316
+    0x52C5, // 3317
+    0x6357, // 3318
+    0x76F4, // 3319
+    0x6715, // 3320
+    0x6C88, // 3321
+    0x73CD, // 3322
+    0x8CC3, // 3323
+    0x93AE, // 3324
+    0x9673, // 3325
+    0x6D25, // 3326
+    0x589C, // 3327
+    0x690E, // 3328
+    0x69CC, // 3329
+    0x8FFD, // 3330
+    0x939A, // 3331
+    0x75DB, // 3332
+    0x901A, // 3333
+    0x585A, // 3334
+    0x6802, // 3335
+    0x63B4, // 3336
+    0x69FB, // 3337
+    0x4F43, // 3338
+    0x6F2C, // 3339
+    0x67D8, // 3340
+    0x8FBB, // 3341
+    0x8526, // 3342
+    0x7DB4, // 3343
+    0x9354, // 3344
+    0x693F, // 3345
+    0x6F70, // 3346
+    0x576A, // 3347
+    0x58F7, // 3348
+    0x5B2C, // 3349
+    0x7D2C, // 3350
+    0x722A, // 3351
+    0x540A, // 3352
+    0x91E3, // 3353
+    0x9DB4, // 3354
+    0x4EAD, // 3355
+    0x4F4E, // 3356
+    0x505C, // 3357
+    0x5075, // 3358
+    0x5243, // 3359
+    0x8C9E, // 3360
+    0x5448, // 3361
+    0x5824, // 3362
+    0x5B9A, // 3363
+    0x5E1D, // 3364
+    0x5E95, // 3365
+    0x5EAD, // 3366
+    0x5EF7, // 3367
+    0x5F1F, // 3368
+    0x608C, // 3369
+    0x62B5, // 3370
+    0x633A, // 3371
+    0x63D0, // 3372
+    0x68AF, // 3373
+    0x6C40, // 3374
+    0x7887, // 3375
+    0x798E, // 3376
+    0x7A0B, // 3377
+    0x7DE0, // 3378
+    0x8247, // 3379
+    0x8A02, // 3380
+    0x8AE6, // 3381
+    0x8E44, // 3382
+    0x9013, // 3383
+    0x90B8, // 3384
+    0x912D, // 3385
+    0x91D8, // 3386
+    0x9F0E, // 3387
+    0x6CE5, // 3388
+    0x6458, // 3389
+    0x64E2, // 3390
+    0x6575, // 3391
+    0x6EF4, // 3392
+    0x7684, // 3393
+    0x7B1B, // 3394
+    0x9069, // 3395
+    0x93D1, // 3396
+    0x6EBA, // 3397
+    0x54F2, // 3398
+    0x5FB9, // 3399
+    0x64A4, // 3400
+    0x8F4D, // 3401
+    0x8FED, // 3402
+    0x9244, // 3403
+    0x5178, // 3404
+    0x586B, // 3405
+    0x5929, // 3406
+    0x5C55, // 3407
+    0x5E97, // 3408
+    0x6DFB, // 3409
+    0x7E8F, // 3410
+    0x751C, // 3411
+    0x8CBC, // 3412
+    0x8EE2, // 3413
+    0x985B, // 3414
+    0x70B9, // 3415
+    0x4F1D, // 3416
+    0x6BBF, // 3417
+    0x6FB1, // 3418
+    0x7530, // 3419
+    0x96FB, // 3420
+    0x514E, // 3421
+    0x5410, // 3422
+    0x5835, // 3423
+    0x5857, // 3424
+    0x59AC, // 3425
+    0x5C60, // 3426
+    0x5F92, // 3427
+    0x6597, // 3428
+    0x675C, // 3429
+    0x6E21, // 3430
+    0x767B, // 3431
+    0x83DF, // 3432
+    0x8CED, // 3433
+    0x9014, // 3434
+    0x90FD, // 3435
+    0x934D, // 3436
+    0x7825, // 3437
+    0x783A, // 3438
+    0x52AA, // 3439
+    0x5EA6, // 3440
+    0x571F, // 3441
+    0x5974, // 3442
+    0x6012, // 3443
+    0x5012, // 3444
+    0x515A, // 3445
+    0x51AC, // 3446
+    0x51CD, // 3447
+    0x5200, // 3448
+    0x5510, // 3449
+    0x5854, // 3450
+    0x5858, // 3451
+    0x5957, // 3452
+    0x5B95, // 3453
+    0x5CF6, // 3454
+    0x5D8B, // 3455
+    0x60BC, // 3456
+    0x6295, // 3457
+    0x642D, // 3458
+    0x6771, // 3459
+    0x6843, // 3460
+    0x68BC, // 3461
+    0x68DF, // 3462
+    0x76D7, // 3463
+    0x6DD8, // 3464
+    0x6E6F, // 3465
+    0x6D9B, // 3466
+    0x706F, // 3467
+    0x71C8, // 3468
+    0x5F53, // 3469
+    0x75D8, // 3470
+    0x7977, // 3471
+    0x7B49, // 3472
+    0x7B54, // 3473
+    0x7B52, // 3474
+    0x7CD6, // 3475
+    0x7D71, // 3476
+    0x5230, // 3477
+    0x8463, // 3478
+    0x8569, // 3479
+    0x85E4, // 3480
+    0x8A0E, // 3481
+    0x8B04, // 3482
+    0x8C46, // 3483
+    0x8E0F, // 3484
+    0x9003, // 3485
+    0x900F, // 3486
+    0x9419, // 3487
+    0x9676, // 3488
+    0x982D, // 3489
+    0x9A30, // 3490
+    0x95D8, // 3491
+    0x50CD, // 3492
+    0x52D5, // 3493
+    0x540C, // 3494
+    0x5802, // 3495
+    0x5C0E, // 3496
+    0x61A7, // 3497
+    0x649E, // 3498
+    0x6D1E, // 3499
+    0x77B3, // 3500
+    0x7AE5, // 3501
+    0x80F4, // 3502
+    0x8404, // 3503
+    0x9053, // 3504
+    0x9285, // 3505
+    0x5CE0, // 3506
+    0x9D07, // 3507
+    0x533F, // 3508
+    0x5F97, // 3509
+    0x5FB3, // 3510
+    0x6D9C, // 3511
+    0x7279, // 3512
+    0x7763, // 3513
+    0x79BF, // 3514
+    0x7BE4, // 3515
+    0x6BD2, // 3516
+    0x72EC, // 3517
+    0x8AAD, // 3518
+    0x6803, // 3519
+    0x6A61, // 3520
+    0x51F8, // 3521
+    0x7A81, // 3522
+    0x6934, // 3523
+    0x5C4A, // 3524
+    0x9CF6, // 3525
+    0x82EB, // 3526
+    0x5BC5, // 3527
+    0x9149, // 3528
+    0x701E, // 3529
+    0x5678, // 3530
+    0x5C6F, // 3531
+    0x60C7, // 3532
+    0x6566, // 3533
+    0x6C8C, // 3534
+    0x8C5A, // 3535
+    0x9041, // 3536
+    0x9813, // 3537
+    0x5451, // 3538
+    0x66C7, // 3539
+    0x920D, // 3540
+    0x5948, // 3541
+    0x90A3, // 3542
+    0x5185, // 3543
+    0x4E4D, // 3544
+    0x51EA, // 3545
+    0x8599, // 3546
+    0x8B0E, // 3547
+    0x7058, // 3548
+    0x637A, // 3549
+    0x934B, // 3550
+    0x6962, // 3551
+    0x99B4, // 3552
+    0x7E04, // 3553
+    0x7577, // 3554
+    0x5357, // 3555
+    0x6960, // 3556
+    0x8EDF, // 3557
+    0x96E3, // 3558
+    0x6C5D, // 3559
+    0x4E8C, // 3560
+    0x5C3C, // 3561
+    0x5F10, // 3562
+    0x8FE9, // 3563
+    0x5302, // 3564
+    0x8CD1, // 3565
+    0x8089, // 3566
+    0x8679, // 3567
+    0x5EFF, // 3568
+    0x65E5, // 3569
+    0x4E73, // 3570
+    0x5165, // 3571
+    0x5982, // 3572
+    0x5C3F, // 3573
+    0x97EE, // 3574
+    0x4EFB, // 3575
+    0x598A, // 3576
+    0x5FCD, // 3577
+    0x8A8D, // 3578
+    0x6FE1, // 3579
+    0x79B0, // 3580
+    0x7962, // 3581
+    0x5BE7, // 3582
+    0x8471, // 3583
+    0x732B, // 3584
+    0x71B1, // 3585
+    0x5E74, // 3586
+    0x5FF5, // 3587
+    0x637B, // 3588
+    0x649A, // 3589
+    0x71C3, // 3590
+    0x7C98, // 3591
+    0x4E43, // 3592
+    0x5EFC, // 3593
+    0x4E4B, // 3594
+    0x57DC, // 3595
+    0x56A2, // 3596
+    0x60A9, // 3597
+    0x6FC3, // 3598
+    0x7D0D, // 3599
+    0x80FD, // 3600
+    0x8133, // 3601
+    0x81BF, // 3602
+    0x8FB2, // 3603
+    0x8997, // 3604
+    0x86A4, // 3605
+    0x5DF4, // 3606
+    0x628A, // 3607
+    0x64AD, // 3608
+    0x8987, // 3609
+    0x6777, // 3610
+    0x6CE2, // 3611
+    0x6D3E, // 3612
+    0x7436, // 3613
+    0x7834, // 3614
+    0x5A46, // 3615
+    0x7F75, // 3616
+    0x82AD, // 3617
+    0x99AC, // 3618
+    0x4FF3, // 3619
+    0x5EC3, // 3620
+    0x62DD, // 3621
+    0x6392, // 3622
+    0x6557, // 3623
+    0x676F, // 3624
+    0x76C3, // 3625
+    0x724C, // 3626
+    0x80CC, // 3627
+    0x80BA, // 3628
+    0x8F29, // 3629
+    0x914D, // 3630
+    0x500D, // 3631
+    0x57F9, // 3632
+    0x5A92, // 3633
+    0x6885, // 3634
+    0x6973, // 3635
+    0x7164, // 3636
+    0x72FD, // 3637
+    0x8CB7, // 3638
+    0x58F2, // 3639
+    0x8CE0, // 3640
+    0x966A, // 3641
+    0x9019, // 3642
+    0x877F, // 3643
+    0x79E4, // 3644
+    0x77E7, // 3645
+    0x8429, // 3646
+    0x4F2F, // 3647
+    0x5265, // 3648
+    0x535A, // 3649
+    0x62CD, // 3650
+    0x67CF, // 3651
+    0x6CCA, // 3652
+    0x767D, // 3653
+    0x7B94, // 3654
+    0x7C95, // 3655
+    0x8236, // 3656
+    0x8584, // 3657
+    0x8FEB, // 3658
+    0x66DD, // 3659
+    0x6F20, // 3660
+    0x7206, // 3661
+    0x7E1B, // 3662
+    0x83AB, // 3663
+    0x99C1, // 3664
+    0x9EA6, // 3665
+    0x51FD, // 3666
+    0x7BB1, // 3667
+    0x7872, // 3668
+    0x7BB8, // 3669
+    0x8087, // 3670
+    0x7B48, // 3671
+    0x6AE8, // 3672
+    0x5E61, // 3673
+    0x808C, // 3674
+    0x7551, // 3675
+    0x7560, // 3676
+    0x516B, // 3677
+    0x9262, // 3678
+    0x6E8C, // 3679
+    0x767A, // 3680
+    0x9197, // 3681
+    0x9AEA, // 3682
+    0x4F10, // 3683
+    0x7F70, // 3684
+    0x629C, // 3685
+    0x7B4F, // 3686
+    0x95A5, // 3687
+    0x9CE9, // 3688
+    0x567A, // 3689
+    0x5859, // 3690
+    0x86E4, // 3691
+    0x96BC, // 3692
+    0x4F34, // 3693
+    0x5224, // 3694
+    0x534A, // 3695
+    0x53CD, // 3696
+    0x53DB, // 3697
+    0x5E06, // 3698
+    0x642C, // 3699
+    0x6591, // 3700
+    0x677F, // 3701
+    0x6C3E, // 3702
+    0x6C4E, // 3703
+    0x7248, // 3704
+    0x72AF, // 3705
+    0x73ED, // 3706
+    0x7554, // 3707
+    0x7E41, // 3708
+    0x822C, // 3709
+    0x85E9, // 3710
+    0x8CA9, // 3711
+    0x7BC4, // 3712
+    0x91C6, // 3713
+    0x7169, // 3714
+    0x9812, // 3715
+    0x98EF, // 3716
+    0x633D, // 3717
+    0x6669, // 3718
+    0x756A, // 3719
+    0x76E4, // 3720
+    0x78D0, // 3721
+    0x8543, // 3722
+    0x86EE, // 3723
+    0x532A, // 3724
+    0x5351, // 3725
+    0x5426, // 3726
+    0x5983, // 3727
+    0x5E87, // 3728
+    0x5F7C, // 3729
+    0x60B2, // 3730
+    0x6249, // 3731
+    0x6279, // 3732
+    0x62AB, // 3733
+    0x6590, // 3734
+    0x6BD4, // 3735
+    0x6CCC, // 3736
+    0x75B2, // 3737
+    0x76AE, // 3738
+    0x7891, // 3739
+    0x79D8, // 3740
+    0x7DCB, // 3741
+    0x7F77, // 3742
+    0x80A5, // 3743
+    0x88AB, // 3744
+    0x8AB9, // 3745
+    0x8CBB, // 3746
+    0x907F, // 3747
+    0x975E, // 3748
+    0x98DB, // 3749
+    0x6A0B, // 3750
+    0x7C38, // 3751
+    0x5099, // 3752
+    0x5C3E, // 3753
+    0x5FAE, // 3754
+    0x6787, // 3755
+    0x6BD8, // 3756
+    0x7435, // 3757
+    0x7709, // 3758
+    0x7F8E, // 3759
+    0x9F3B, // 3760
+    0x67CA, // 3761
+    0x7A17, // 3762
+    0x5339, // 3763
+    0x758B, // 3764
+    0x9AED, // 3765
+    0x5F66, // 3766
+    0x819D, // 3767
+    0x83F1, // 3768
+    0x8098, // 3769
+    0x5F3C, // 3770
+    0x5FC5, // 3771
+    0x7562, // 3772
+    0x7B46, // 3773
+    0x903C, // 3774
+    0x6867, // 3775
+    0x59EB, // 3776
+    0x5A9B, // 3777
+    0x7D10, // 3778
+    0x767E, // 3779
+    0x8B2C, // 3780
+    0x4FF5, // 3781
+    0x5F6A, // 3782
+    0x6A19, // 3783
+    0x6C37, // 3784
+    0x6F02, // 3785
+    0x74E2, // 3786
+    0x7968, // 3787
+    0x8868, // 3788
+    0x8A55, // 3789
+    0x8C79, // 3790
+    0x5EDF, // 3791
+    0x63CF, // 3792
+    0x75C5, // 3793
+    0x79D2, // 3794
+    0x82D7, // 3795
+    0x9328, // 3796
+    0x92F2, // 3797
+    0x849C, // 3798
+    0x86ED, // 3799
+    0x9C2D, // 3800
+    0x54C1, // 3801
+    0x5F6C, // 3802
+    0x658C, // 3803
+    0x6D5C, // 3804
+    0x7015, // 3805
+    0x8CA7, // 3806
+    0x8CD3, // 3807
+    0x983B, // 3808
+    0x654F, // 3809
+    0x74F6, // 3810
+    0x4E0D, // 3811
+    0x4ED8, // 3812
+    0x57E0, // 3813
+    0x592B, // 3814
+    0x5A66, // 3815
+    0x5BCC, // 3816
+    0x51A8, // 3817
+    0x5E03, // 3818
+    0x5E9C, // 3819
+    0x6016, // 3820
+    0x6276, // 3821
+    0x6577, // 3822
+    0x65A7, // 3823
+    0x666E, // 3824
+    0x6D6E, // 3825
+    0x7236, // 3826
+    0x7B26, // 3827
+    0x8150, // 3828
+    0x819A, // 3829
+    0x8299, // 3830
+    0x8B5C, // 3831
+    0x8CA0, // 3832
+    0x8CE6, // 3833
+    0x8D74, // 3834
+    0x961C, // 3835
+    0x9644, // 3836
+    0x4FAE, // 3837
+    0x64AB, // 3838
+    0x6B66, // 3839
+    0x821E, // 3840
+    0x8461, // 3841
+    0x856A, // 3842
+    0x90E8, // 3843
+    0x5C01, // 3844
+    0x6953, // 3845
+    0x98A8, // 3846
+    0x847A, // 3847
+    0x8557, // 3848
+    0x4F0F, // 3849
+    0x526F, // 3850
+    0x5FA9, // 3851
+    0x5E45, // 3852
+    0x670D, // 3853
+    0x798F, // 3854
+    0x8179, // 3855
+    0x8907, // 3856
+    0x8986, // 3857
+    0x6DF5, // 3858
+    0x5F17, // 3859
+    0x6255, // 3860
+    0x6CB8, // 3861
+    0x4ECF, // 3862
+    0x7269, // 3863
+    0x9B92, // 3864
+    0x5206, // 3865
+    0x543B, // 3866
+    0x5674, // 3867
+    0x58B3, // 3868
+    0x61A4, // 3869
+    0x626E, // 3870
+    0x711A, // 3871
+    0x596E, // 3872
+    0x7C89, // 3873
+    0x7CDE, // 3874
+    0x7D1B, // 3875
+    0x96F0, // 3876
+    0x6587, // 3877
+    0x805E, // 3878
+    0x4E19, // 3879
+    0x4F75, // 3880
+    0x5175, // 3881
+    0x5840, // 3882
+    0x5E63, // 3883
+    0x5E73, // 3884
+    0x5F0A, // 3885
+    0x67C4, // 3886
+    0x4E26, // 3887
+    0x853D, // 3888
+    0x9589, // 3889
+    0x965B, // 3890
+    0x7C73, // 3891
+    0x9801, // 3892
+    0x50FB, // 3893
+    0x58C1, // 3894
+    0x7656, // 3895
+    0x78A7, // 3896
+    0x5225, // 3897
+    0x77A5, // 3898
+    0x8511, // 3899
+    0x7B86, // 3900
+    0x504F, // 3901
+    0x5909, // 3902
+    0x7247, // 3903
+    0x7BC7, // 3904
+    0x7DE8, // 3905
+    0x8FBA, // 3906
+    0x8FD4, // 3907
+    0x904D, // 3908
+    0x4FBF, // 3909
+    0x52C9, // 3910
+    0x5A29, // 3911
+    0x5F01, // 3912
+    0x97AD, // 3913
+    0x4FDD, // 3914
+    0x8217, // 3915
+    0x92EA, // 3916
+    0x5703, // 3917
+    0x6355, // 3918
+    0x6B69, // 3919
+    0x752B, // 3920
+    0x88DC, // 3921
+    0x8F14, // 3922
+    0x7A42, // 3923
+    0x52DF, // 3924
+    0x5893, // 3925
+    0x6155, // 3926
+    0x620A, // 3927
+    0x66AE, // 3928
+    0x6BCD, // 3929
+    0x7C3F, // 3930
+    0x83E9, // 3931
+    0x5023, // 3932
+    0x4FF8, // 3933
+    0x5305, // 3934
+    0x5446, // 3935
+    0x5831, // 3936
+    0x5949, // 3937
+    0x5B9D, // 3938
+    0x5CF0, // 3939
+    0x5CEF, // 3940
+    0x5D29, // 3941
+    0x5E96, // 3942
+    0x62B1, // 3943
+    0x6367, // 3944
+    0x653E, // 3945
+    0x65B9, // 3946
+    0x670B, // 3947
+    0x6CD5, // 3948
+    0x6CE1, // 3949
+    0x70F9, // 3950
+    0x7832, // 3951
+    0x7E2B, // 3952
+    0x80DE, // 3953
+    0x82B3, // 3954
+    0x840C, // 3955
+    0x84EC, // 3956
+    0x8702, // 3957
+    0x8912, // 3958
+    0x8A2A, // 3959
+    0x8C4A, // 3960
+    0x90A6, // 3961
+    0x92D2, // 3962
+    0x98FD, // 3963
+    0x9CF3, // 3964
+    0x9D6C, // 3965
+    0x4E4F, // 3966
+    0x4EA1, // 3967
+    0x508D, // 3968
+    0x5256, // 3969
+    0x574A, // 3970
+    0x59A8, // 3971
+    0x5E3D, // 3972
+    0x5FD8, // 3973
+    0x5FD9, // 3974
+    0x623F, // 3975
+    0x66B4, // 3976
+    0x671B, // 3977
+    0x67D0, // 3978
+    0x68D2, // 3979
+    0x5192, // 3980
+    0x7D21, // 3981
+    0x80AA, // 3982
+    0x81A8, // 3983
+    0x8B00, // 3984
+    0x8C8C, // 3985
+    0x8CBF, // 3986
+    0x927E, // 3987
+    0x9632, // 3988
+    0x5420, // 3989
+    0x982C, // 3990
+    0x5317, // 3991
+    0x50D5, // 3992
+    0x535C, // 3993
+    0x58A8, // 3994
+    0x64B2, // 3995
+    0x6734, // 3996
+    0x7267, // 3997
+    0x7766, // 3998
+    0x7A46, // 3999
+    0x91E6, // 4000
+    0x52C3, // 4001
+    0x6CA1, // 4002
+    0x6B86, // 4003
+    0x5800, // 4004
+    0x5E4C, // 4005
+    0x5954, // 4006
+    0x672C, // 4007
+    0x7FFB, // 4008
+    0x51E1, // 4009
+    0x76C6, // 4010
+    0x6469, // 4011
+    0x78E8, // 4012
+    0x9B54, // 4013
+    0x9EBB, // 4014
+    0x57CB, // 4015
+    0x59B9, // 4016
+    0x6627, // 4017
+    0x679A, // 4018
+    0x6BCE, // 4019
+    0x54E9, // 4020
+    0x69D9, // 4021
+    0x5E55, // 4022
+    0x819C, // 4023
+    0x6795, // 4024
+    0x9BAA, // 4025
+    0x67FE, // 4026
+    0x9C52, // 4027
+    0x685D, // 4028
+    0x4EA6, // 4029
+    0x4FE3, // 4030
+    0x53C8, // 4031
+    0x62B9, // 4032
+    0x672B, // 4033
+    0x6CAB, // 4034
+    0x8FC4, // 4035
+    0x4FAD, // 4036
+    0x7E6D, // 4037
+    0x9EBF, // 4038
+    0x4E07, // 4039
+    0x6162, // 4040
+    0x6E80, // 4041
+    0x6F2B, // 4042
+    0x8513, // 4043
+    0x5473, // 4044
+    0x672A, // 4045
+    0x9B45, // 4046
+    0x5DF3, // 4047
+    0x7B95, // 4048
+    0x5CAC, // 4049
+    0x5BC6, // 4050
+    0x871C, // 4051
+    0x6E4A, // 4052
+    0x84D1, // 4053
+    0x7A14, // 4054
+    0x8108, // 4055
+    0x5999, // 4056
+    0x7C8D, // 4057
+    0x6C11, // 4058
+    0x7720, // 4059
+    0x52D9, // 4060
+    0x5922, // 4061
+    0x7121, // 4062
+    0x725F, // 4063
+    0x77DB, // 4064
+    0x9727, // 4065
+    0x9D61, // 4066
+    0x690B, // 4067
+    0x5A7F, // 4068
+    0x5A18, // 4069
+    0x51A5, // 4070
+    0x540D, // 4071
+    0x547D, // 4072
+    0x660E, // 4073
+    0x76DF, // 4074
+    0x8FF7, // 4075
+    0x9298, // 4076
+    0x9CF4, // 4077
+    0x59EA, // 4078
+    0x725D, // 4079
+    0x6EC5, // 4080
+    0x514D, // 4081
+    0x68C9, // 4082
+    0x7DBF, // 4083
+    0x7DEC, // 4084
+    0x9762, // 4085
+    0x9EBA, // 4086
+    0x6478, // 4087
+    0x6A21, // 4088
+    0x8302, // 4089
+    0x5984, // 4090
+    0x5B5F, // 4091
+    0x6BDB, // 4092
+    0x731B, // 4093
+    0x76F2, // 4094
+    0x7DB2, // 4095
+    0x8017, // 4096
+    0x8499, // 4097
+    0x5132, // 4098
+    0x6728, // 4099
+    0x9ED9, // 4100
+    0x76EE, // 4101
+    0x6762, // 4102
+    0x52FF, // 4103
+    0x9905, // 4104
+    0x5C24, // 4105
+    0x623B, // 4106
+    0x7C7E, // 4107
+    0x8CB0, // 4108
+    0x554F, // 4109
+    0x60B6, // 4110
+    0x7D0B, // 4111
+    0x9580, // 4112
+    0x5301, // 4113
+    0x4E5F, // 4114
+    0x51B6, // 4115
+    0x591C, // 4116
+    0x723A, // 4117
+    0x8036, // 4118
+    0x91CE, // 4119
+    0x5F25, // 4120
+    0x77E2, // 4121
+    0x5384, // 4122
+    0x5F79, // 4123
+    0x7D04, // 4124
+    0x85AC, // 4125
+    0x8A33, // 4126
+    0x8E8D, // 4127
+    0x9756, // 4128
+    0x67F3, // 4129
+    0x85AE, // 4130
+    0x9453, // 4131
+    0x6109, // 4132
+    0x6108, // 4133
+    0x6CB9, // 4134
+    0x7652, // 4135
+    0x8AED, // 4136
+    0x8F38, // 4137
+    0x552F, // 4138
+    0x4F51, // 4139
+    0x512A, // 4140
+    0x52C7, // 4141
+    0x53CB, // 4142
+    0x5BA5, // 4143
+    0x5E7D, // 4144
+    0x60A0, // 4145
+    0x6182, // 4146
+    0x63D6, // 4147
+    0x6709, // 4148
+    0x67DA, // 4149
+    0x6E67, // 4150
+    0x6D8C, // 4151
+    0x7336, // 4152
+    0x7337, // 4153
+    0x7531, // 4154
+    0x7950, // 4155
+    0x88D5, // 4156
+    0x8A98, // 4157
+    0x904A, // 4158
+    0x9091, // 4159
+    0x90F5, // 4160
+    0x96C4, // 4161
+    0x878D, // 4162
+    0x5915, // 4163
+    0x4E88, // 4164
+    0x4F59, // 4165
+    0x4E0E, // 4166
+    0x8A89, // 4167
+    0x8F3F, // 4168
+    0x9810, // 4169
+    0x50AD, // 4170
+    0x5E7C, // 4171
+    0x5996, // 4172
+    0x5BB9, // 4173
+    0x5EB8, // 4174
+    0x63DA, // 4175
+    0x63FA, // 4176
+    0x64C1, // 4177
+    0x66DC, // 4178
+    0x694A, // 4179
+    0x69D8, // 4180
+    0x6D0B, // 4181
+    0x6EB6, // 4182
+    0x7194, // 4183
+    0x7528, // 4184
+    0x7AAF, // 4185
+    0x7F8A, // 4186
+    0x8000, // 4187
+    0x8449, // 4188
+    0x84C9, // 4189
+    0x8981, // 4190
+    0x8B21, // 4191
+    0x8E0A, // 4192
+    0x9065, // 4193
+    0x967D, // 4194
+    0x990A, // 4195
+    0x617E, // 4196
+    0x6291, // 4197
+    0x6B32, // 4198
+    0x6C83, // 4199
+    0x6D74, // 4200
+    0x7FCC, // 4201
+    0x7FFC, // 4202
+    0x6DC0, // 4203
+    0x7F85, // 4204
+    0x87BA, // 4205
+    0x88F8, // 4206
+    0x6765, // 4207
+    0x83B1, // 4208
+    0x983C, // 4209
+    0x96F7, // 4210
+    0x6D1B, // 4211
+    0x7D61, // 4212
+    0x843D, // 4213
+    0x916A, // 4214
+    0x4E71, // 4215
+    0x5375, // 4216
+    0x5D50, // 4217
+    0x6B04, // 4218
+    0x6FEB, // 4219
+    0x85CD, // 4220
+    0x862D, // 4221
+    0x89A7, // 4222
+    0x5229, // 4223
+    0x540F, // 4224
+    0x5C65, // 4225
+    0x674E, // 4226
+    0x68A8, // 4227
+    0x7406, // 4228
+    0x7483, // 4229
+    0x75E2, // 4230
+    0x88CF, // 4231
+    0x88E1, // 4232
+    0x91CC, // 4233
+    0x96E2, // 4234
+    0x9678, // 4235
+    0x5F8B, // 4236
+    0x7387, // 4237
+    0x7ACB, // 4238
+    0x844E, // 4239
+    0x63A0, // 4240
+    0x7565, // 4241
+    0x5289, // 4242
+    0x6D41, // 4243
+    0x6E9C, // 4244
+    0x7409, // 4245
+    0x7559, // 4246
+    0x786B, // 4247
+    0x7C92, // 4248
+    0x9686, // 4249
+    0x7ADC, // 4250
+    0x9F8D, // 4251
+    0x4FB6, // 4252
+    0x616E, // 4253
+    0x65C5, // 4254
+    0x865C, // 4255
+    0x4E86, // 4256
+    0x4EAE, // 4257
+    0x50DA, // 4258
+    0x4E21, // 4259
+    0x51CC, // 4260
+    0x5BEE, // 4261
+    0x6599, // 4262
+    0x6881, // 4263
+    0x6DBC, // 4264
+    0x731F, // 4265
+    0x7642, // 4266
+    0x77AD, // 4267
+    0x7A1C, // 4268
+    0x7CE7, // 4269
+    0x826F, // 4270
+    0x8AD2, // 4271
+    0x907C, // 4272
+    0x91CF, // 4273
+    0x9675, // 4274
+    0x9818, // 4275
+    0x529B, // 4276
+    0x7DD1, // 4277
+    0x502B, // 4278
+    0x5398, // 4279
+    0x6797, // 4280
+    0x6DCB, // 4281
+    0x71D0, // 4282
+    0x7433, // 4283
+    0x81E8, // 4284
+    0x8F2A, // 4285
+    0x96A3, // 4286
+    0x9C57, // 4287
+    0x9E9F, // 4288
+    0x7460, // 4289
+    0x5841, // 4290
+    0x6D99, // 4291
+    0x7D2F, // 4292
+    0x985E, // 4293
+    0x4EE4, // 4294
+    0x4F36, // 4295
+    0x4F8B, // 4296
+    0x51B7, // 4297
+    0x52B1, // 4298
+    0x5DBA, // 4299
+    0x601C, // 4300
+    0x73B2, // 4301
+    0x793C, // 4302
+    0x82D3, // 4303
+    0x9234, // 4304
+    0x96B7, // 4305
+    0x96F6, // 4306
+    0x970A, // 4307
+    0x9E97, // 4308
+    0x9F62, // 4309
+    0x66A6, // 4310
+    0x6B74, // 4311
+    0x5217, // 4312
+    0x52A3, // 4313
+    0x70C8, // 4314
+    0x88C2, // 4315
+    0x5EC9, // 4316
+    0x604B, // 4317
+    0x6190, // 4318
+    0x6F23, // 4319
+    0x7149, // 4320
+    0x7C3E, // 4321
+    0x7DF4, // 4322
+    0x806F, // 4323
+    0x84EE, // 4324
+    0x9023, // 4325
+    0x932C, // 4326
+    0x5442, // 4327
+    0x9B6F, // 4328
+    0x6AD3, // 4329
+    0x7089, // 4330
+    0x8CC2, // 4331
+    0x8DEF, // 4332
+    0x9732, // 4333
+    0x52B4, // 4334
+    0x5A41, // 4335
+    0x5ECA, // 4336
+    0x5F04, // 4337
+    0x6717, // 4338
+    0x697C, // 4339
+    0x6994, // 4340
+    0x6D6A, // 4341
+    0x6F0F, // 4342
+    0x7262, // 4343
+    0x72FC, // 4344
+    0x7BED, // 4345
+    0x8001, // 4346
+    0x807E, // 4347
+    0x874B, // 4348
+    0x90CE, // 4349
+    0x516D, // 4350
+    0x9E93, // 4351
+    0x7984, // 4352
+    0x808B, // 4353
+    0x9332, // 4354
+    0x8AD6, // 4355
+    0x502D, // 4356
+    0x548C, // 4357
+    0x8A71, // 4358
+    0x6B6A, // 4359
+    0x8CC4, // 4360
+    0x8107, // 4361
+    0x60D1, // 4362
+    0x67A0, // 4363
+    0x9DF2, // 4364
+    0x4E99, // 4365
+    0x4E98, // 4366
+    0x9C10, // 4367
+    0x8A6B, // 4368
+    0x85C1, // 4369
+    0x8568, // 4370
+    0x6900, // 4371
+    0x6E7E, // 4372
+    0x7897, // 4373
+    0x8155, // 4374
+    0x0000, // 4375
+    0x0000, // 4376
+    0x0000, // 4377
+    0x0000, // 4378
+    0x0000, // 4379
+    0x0000, // 4380
+    0x0000, // 4381
+    0x0000, // 4382
+    0x0000, // 4383
+    0x0000, // 4384
+    0x0000, // 4385
+    0x0000, // 4386
+    0x0000, // 4387
+    0x0000, // 4388
+    0x0000, // 4389
+    0x0000, // 4390
+    0x0000, // 4391
+    0x0000, // 4392
+    0x0000, // 4393
+    0x0000, // 4394
+    0x0000, // 4395
+    0x0000, // 4396
+    0x0000, // 4397
+    0x0000, // 4398
+    0x0000, // 4399
+    0x0000, // 4400
+    0x0000, // 4401
+    0x0000, // 4402
+    0x0000, // 4403
+    0x0000, // 4404
+    0x0000, // 4405
+    0x0000, // 4406
+    0x0000, // 4407
+    0x0000, // 4408
+    0x0000, // 4409
+    0x0000, // 4410
+    0x0000, // 4411
+    0x0000, // 4412
+    0x0000, // 4413
+    0x0000, // 4414
+    0x0000, // 4415
+    0x0000, // 4416
+    0x0000, // 4417
+    0x5F0C, // 4418
+    0x4E10, // 4419
+    0x4E15, // 4420
+    0x4E2A, // 4421
+    0x4E31, // 4422
+    0x4E36, // 4423
+    0x4E3C, // 4424
+    0x4E3F, // 4425
+    0x4E42, // 4426
+    0x4E56, // 4427
+    0x4E58, // 4428
+    0x4E82, // 4429
+    0x4E85, // 4430
+    0x8C6B, // 4431
+    0x4E8A, // 4432
+    0x8212, // 4433
+    0x5F0D, // 4434
+    0x4E8E, // 4435
+    0x4E9E, // 4436
+    0x4E9F, // 4437
+    0x4EA0, // 4438
+    0x4EA2, // 4439
+    0x4EB0, // 4440
+    0x4EB3, // 4441
+    0x4EB6, // 4442
+    0x4ECE, // 4443
+    0x4ECD, // 4444
+    0x4EC4, // 4445
+    0x4EC6, // 4446
+    0x4EC2, // 4447
+    0x4ED7, // 4448
+    0x4EDE, // 4449
+    0x4EED, // 4450
+    0x4EDF, // 4451
+    0x4EF7, // 4452
+    0x4F09, // 4453
+    0x4F5A, // 4454
+    0x4F30, // 4455
+    0x4F5B, // 4456
+    0x4F5D, // 4457
+    0x4F57, // 4458
+    0x4F47, // 4459
+    0x4F76, // 4460
+    0x4F88, // 4461
+    0x4F8F, // 4462
+    0x4F98, // 4463
+    0x4F7B, // 4464
+    0x4F69, // 4465
+    0x4F70, // 4466
+    0x4F91, // 4467
+    0x4F6F, // 4468
+    0x4F86, // 4469
+    0x4F96, // 4470
+    0x5118, // 4471
+    0x4FD4, // 4472
+    0x4FDF, // 4473
+    0x4FCE, // 4474
+    0x4FD8, // 4475
+    0x4FDB, // 4476
+    0x4FD1, // 4477
+    0x4FDA, // 4478
+    0x4FD0, // 4479
+    0x4FE4, // 4480
+    0x4FE5, // 4481
+    0x501A, // 4482
+    0x5028, // 4483
+    0x5014, // 4484
+    0x502A, // 4485
+    0x5025, // 4486
+    0x5005, // 4487
+    0x4F1C, // 4488
+    0x4FF6, // 4489
+    0x5021, // 4490
+    0x5029, // 4491
+    0x502C, // 4492
+    0x4FFE, // 4493
+    0x4FEF, // 4494
+    0x5011, // 4495
+    0x5006, // 4496
+    0x5043, // 4497
+    0x5047, // 4498
+    0x6703, // 4499
+    0x5055, // 4500
+    0x5050, // 4501
+    0x5048, // 4502
+    0x505A, // 4503
+    0x5056, // 4504
+    0x506C, // 4505
+    0x5078, // 4506
+    0x5080, // 4507
+    0x509A, // 4508
+    0x5085, // 4509
+    0x50B4, // 4510
+    0x50B2, // 4511
+    0x50C9, // 4512
+    0x50CA, // 4513
+    0x50B3, // 4514
+    0x50C2, // 4515
+    0x50D6, // 4516
+    0x50DE, // 4517
+    0x50E5, // 4518
+    0x50ED, // 4519
+    0x50E3, // 4520
+    0x50EE, // 4521
+    0x50F9, // 4522
+    0x50F5, // 4523
+    0x5109, // 4524
+    0x5101, // 4525
+    0x5102, // 4526
+    0x5116, // 4527
+    0x5115, // 4528
+    0x5114, // 4529
+    0x511A, // 4530
+    0x5121, // 4531
+    0x513A, // 4532
+    0x5137, // 4533
+    0x513C, // 4534
+    0x513B, // 4535
+    0x513F, // 4536
+    0x5140, // 4537
+    0x5152, // 4538
+    0x514C, // 4539
+    0x5154, // 4540
+    0x5162, // 4541
+    0x7AF8, // 4542
+    0x5169, // 4543
+    0x516A, // 4544
+    0x516E, // 4545
+    0x5180, // 4546
+    0x5182, // 4547
+    0x56D8, // 4548
+    0x518C, // 4549
+    0x5189, // 4550
+    0x518F, // 4551
+    0x5191, // 4552
+    0x5193, // 4553
+    0x5195, // 4554
+    0x5196, // 4555
+    0x51A4, // 4556
+    0x51A6, // 4557
+    0x51A2, // 4558
+    0x51A9, // 4559
+    0x51AA, // 4560
+    0x51AB, // 4561
+    0x51B3, // 4562
+    0x51B1, // 4563
+    0x51B2, // 4564
+    0x51B0, // 4565
+    0x51B5, // 4566
+    0x51BD, // 4567
+    0x51C5, // 4568
+    0x51C9, // 4569
+    0x51DB, // 4570
+    0x51E0, // 4571
+    0x8655, // 4572
+    0x51E9, // 4573
+    0x51ED, // 4574
+    0x51F0, // 4575
+    0x51F5, // 4576
+    0x51FE, // 4577
+    0x5204, // 4578
+    0x520B, // 4579
+    0x5214, // 4580
+    0x520E, // 4581
+    0x5227, // 4582
+    0x522A, // 4583
+    0x522E, // 4584
+    0x5233, // 4585
+    0x5239, // 4586
+    0x524F, // 4587
+    0x5244, // 4588
+    0x524B, // 4589
+    0x524C, // 4590
+    0x525E, // 4591
+    0x5254, // 4592
+    0x526A, // 4593
+    0x5274, // 4594
+    0x5269, // 4595
+    0x5273, // 4596
+    0x527F, // 4597
+    0x527D, // 4598
+    0x528D, // 4599
+    0x5294, // 4600
+    0x5292, // 4601
+    0x5271, // 4602
+    0x5288, // 4603
+    0x5291, // 4604
+    0x8FA8, // 4605
+    0x8FA7, // 4606
+    0x52AC, // 4607
+    0x52AD, // 4608
+    0x52BC, // 4609
+    0x52B5, // 4610
+    0x52C1, // 4611
+    0x52CD, // 4612
+    0x52D7, // 4613
+    0x52DE, // 4614
+    0x52E3, // 4615
+    0x52E6, // 4616
+    0x98ED, // 4617
+    0x52E0, // 4618
+    0x52F3, // 4619
+    0x52F5, // 4620
+    0x52F8, // 4621
+    0x52F9, // 4622
+    0x5306, // 4623
+    0x5308, // 4624
+    0x7538, // 4625
+    0x530D, // 4626
+    0x5310, // 4627
+    0x530F, // 4628
+    0x5315, // 4629
+    0x531A, // 4630
+    0x5323, // 4631
+    0x532F, // 4632
+    0x5331, // 4633
+    0x5333, // 4634
+    0x5338, // 4635
+    0x5340, // 4636
+    0x5346, // 4637
+    0x5345, // 4638
+    0x4E17, // 4639
+    0x5349, // 4640
+    0x534D, // 4641
+    0x51D6, // 4642
+    0x535E, // 4643
+    0x5369, // 4644
+    0x536E, // 4645
+    0x5918, // 4646
+    0x537B, // 4647
+    0x5377, // 4648
+    0x5382, // 4649
+    0x5396, // 4650
+    0x53A0, // 4651
+    0x53A6, // 4652
+    0x53A5, // 4653
+    0x53AE, // 4654
+    0x53B0, // 4655
+    0x53B6, // 4656
+    0x53C3, // 4657
+    0x7C12, // 4658
+    0x96D9, // 4659
+    0x53DF, // 4660
+    0x66FC, // 4661
+    0x71EE, // 4662
+    0x53EE, // 4663
+    0x53E8, // 4664
+    0x53ED, // 4665
+    0x53FA, // 4666
+    0x5401, // 4667
+    0x543D, // 4668
+    0x5440, // 4669
+    0x542C, // 4670
+    0x542D, // 4671
+    0x543C, // 4672
+    0x542E, // 4673
+    0x5436, // 4674
+    0x5429, // 4675
+    0x541D, // 4676
+    0x544E, // 4677
+    0x548F, // 4678
+    0x5475, // 4679
+    0x548E, // 4680
+    0x545F, // 4681
+    0x5471, // 4682
+    0x5477, // 4683
+    0x5470, // 4684
+    0x5492, // 4685
+    0x547B, // 4686
+    0x5480, // 4687
+    0x5476, // 4688
+    0x5484, // 4689
+    0x5490, // 4690
+    0x5486, // 4691
+    0x54C7, // 4692
+    0x54A2, // 4693
+    0x54B8, // 4694
+    0x54A5, // 4695
+    0x54AC, // 4696
+    0x54C4, // 4697
+    0x54C8, // 4698
+    0x54A8, // 4699
+    0x54AB, // 4700
+    0x54C2, // 4701
+    0x54A4, // 4702
+    0x54BE, // 4703
+    0x54BC, // 4704
+    0x54D8, // 4705
+    0x54E5, // 4706
+    0x54E6, // 4707
+    0x550F, // 4708
+    0x5514, // 4709
+    0x54FD, // 4710
+    0x54EE, // 4711
+    0x54ED, // 4712
+    0x54FA, // 4713
+    0x54E2, // 4714
+    0x5539, // 4715
+    0x5540, // 4716
+    0x5563, // 4717
+    0x554C, // 4718
+    0x552E, // 4719
+    0x555C, // 4720
+    0x5545, // 4721
+    0x5556, // 4722
+    0x5557, // 4723
+    0x5538, // 4724
+    0x5533, // 4725
+    0x555D, // 4726
+    0x5599, // 4727
+    0x5580, // 4728
+    0x54AF, // 4729
+    0x558A, // 4730
+    0x559F, // 4731
+    0x557B, // 4732
+    0x557E, // 4733
+    0x5598, // 4734
+    0x559E, // 4735
+    0x55AE, // 4736
+    0x557C, // 4737
+    0x5583, // 4738
+    0x55A9, // 4739
+    0x5587, // 4740
+    0x55A8, // 4741
+    0x55DA, // 4742
+    0x55C5, // 4743
+    0x55DF, // 4744
+    0x55C4, // 4745
+    0x55DC, // 4746
+    0x55E4, // 4747
+    0x55D4, // 4748
+    0x5614, // 4749
+    0x55F7, // 4750
+    0x5616, // 4751
+    0x55FE, // 4752
+    0x55FD, // 4753
+    0x561B, // 4754
+    0x55F9, // 4755
+    0x564E, // 4756
+    0x5650, // 4757
+    0x71DF, // 4758
+    0x5634, // 4759
+    0x5636, // 4760
+    0x5632, // 4761
+    0x5638, // 4762
+    0x566B, // 4763
+    0x5664, // 4764
+    0x562F, // 4765
+    0x566C, // 4766
+    0x566A, // 4767
+    0x5686, // 4768
+    0x5680, // 4769
+    0x568A, // 4770
+    0x56A0, // 4771
+    0x5694, // 4772
+    0x568F, // 4773
+    0x56A5, // 4774
+    0x56AE, // 4775
+    0x56B6, // 4776
+    0x56B4, // 4777
+    0x56C2, // 4778
+    0x56BC, // 4779
+    0x56C1, // 4780
+    0x56C3, // 4781
+    0x56C0, // 4782
+    0x56C8, // 4783
+    0x56CE, // 4784
+    0x56D1, // 4785
+    0x56D3, // 4786
+    0x56D7, // 4787
+    0x56EE, // 4788
+    0x56F9, // 4789
+    0x5700, // 4790
+    0x56FF, // 4791
+    0x5704, // 4792
+    0x5709, // 4793
+    0x5708, // 4794
+    0x570B, // 4795
+    0x570D, // 4796
+    0x5713, // 4797
+    0x5718, // 4798
+    0x5716, // 4799
+    0x55C7, // 4800
+    0x571C, // 4801
+    0x5726, // 4802
+    0x5737, // 4803
+    0x5738, // 4804
+    0x574E, // 4805
+    0x573B, // 4806
+    0x5740, // 4807
+    0x574F, // 4808
+    0x5769, // 4809
+    0x57C0, // 4810
+    0x5788, // 4811
+    0x5761, // 4812
+    0x577F, // 4813
+    0x5789, // 4814
+    0x5793, // 4815
+    0x57A0, // 4816
+    0x57B3, // 4817
+    0x57A4, // 4818
+    0x57AA, // 4819
+    0x57B0, // 4820
+    0x57C3, // 4821
+    0x57C6, // 4822
+    0x57D4, // 4823
+    0x57D2, // 4824
+    0x57D3, // 4825
+    0x580A, // 4826
+    0x57D6, // 4827
+    0x57E3, // 4828
+    0x580B, // 4829
+    0x5819, // 4830
+    0x581D, // 4831
+    0x5872, // 4832
+    0x5821, // 4833
+    0x5862, // 4834
+    0x584B, // 4835
+    0x5870, // 4836
+    0x6BC0, // 4837
+    0x5852, // 4838
+    0x583D, // 4839
+    0x5879, // 4840
+    0x5885, // 4841
+    0x58B9, // 4842
+    0x589F, // 4843
+    0x58AB, // 4844
+    0x58BA, // 4845
+    0x58DE, // 4846
+    0x58BB, // 4847
+    0x58B8, // 4848
+    0x58AE, // 4849
+    0x58C5, // 4850
+    0x58D3, // 4851
+    0x58D1, // 4852
+    0x58D7, // 4853
+    0x58D9, // 4854
+    0x58D8, // 4855
+    0x58E5, // 4856
+    0x58DC, // 4857
+    0x58E4, // 4858
+    0x58DF, // 4859
+    0x58EF, // 4860
+    0x58FA, // 4861
+    0x58F9, // 4862
+    0x58FB, // 4863
+    0x58FC, // 4864
+    0x58FD, // 4865
+    0x5902, // 4866
+    0x590A, // 4867
+    0x5910, // 4868
+    0x591B, // 4869
+    0x68A6, // 4870
+    0x5925, // 4871
+    0x592C, // 4872
+    0x592D, // 4873
+    0x5932, // 4874
+    0x5938, // 4875
+    0x593E, // 4876
+    0x7AD2, // 4877
+    0x5955, // 4878
+    0x5950, // 4879
+    0x594E, // 4880
+    0x595A, // 4881
+    0x5958, // 4882
+    0x5962, // 4883
+    0x5960, // 4884
+    0x5967, // 4885
+    0x596C, // 4886
+    0x5969, // 4887
+    0x5978, // 4888
+    0x5981, // 4889
+    0x599D, // 4890
+    0x4F5E, // 4891
+    0x4FAB, // 4892
+    0x59A3, // 4893
+    0x59B2, // 4894
+    0x59C6, // 4895
+    0x59E8, // 4896
+    0x59DC, // 4897
+    0x598D, // 4898
+    0x59D9, // 4899
+    0x59DA, // 4900
+    0x5A25, // 4901
+    0x5A1F, // 4902
+    0x5A11, // 4903
+    0x5A1C, // 4904
+    0x5A09, // 4905
+    0x5A1A, // 4906
+    0x5A40, // 4907
+    0x5A6C, // 4908
+    0x5A49, // 4909
+    0x5A35, // 4910
+    0x5A36, // 4911
+    0x5A62, // 4912
+    0x5A6A, // 4913
+    0x5A9A, // 4914
+    0x5ABC, // 4915
+    0x5ABE, // 4916
+    0x5ACB, // 4917
+    0x5AC2, // 4918
+    0x5ABD, // 4919
+    0x5AE3, // 4920
+    0x5AD7, // 4921
+    0x5AE6, // 4922
+    0x5AE9, // 4923
+    0x5AD6, // 4924
+    0x5AFA, // 4925
+    0x5AFB, // 4926
+    0x5B0C, // 4927
+    0x5B0B, // 4928
+    0x5B16, // 4929
+    0x5B32, // 4930
+    0x5AD0, // 4931
+    0x5B2A, // 4932
+    0x5B36, // 4933
+    0x5B3E, // 4934
+    0x5B43, // 4935
+    0x5B45, // 4936
+    0x5B40, // 4937
+    0x5B51, // 4938
+    0x5B55, // 4939
+    0x5B5A, // 4940
+    0x5B5B, // 4941
+    0x5B65, // 4942
+    0x5B69, // 4943
+    0x5B70, // 4944
+    0x5B73, // 4945
+    0x5B75, // 4946
+    0x5B78, // 4947
+    0x6588, // 4948
+    0x5B7A, // 4949
+    0x5B80, // 4950
+    0x5B83, // 4951
+    0x5BA6, // 4952
+    0x5BB8, // 4953
+    0x5BC3, // 4954
+    0x5BC7, // 4955
+    0x5BC9, // 4956
+    0x5BD4, // 4957
+    0x5BD0, // 4958
+    0x5BE4, // 4959
+    0x5BE6, // 4960
+    0x5BE2, // 4961
+    0x5BDE, // 4962
+    0x5BE5, // 4963
+    0x5BEB, // 4964
+    0x5BF0, // 4965
+    0x5BF6, // 4966
+    0x5BF3, // 4967
+    0x5C05, // 4968
+    0x5C07, // 4969
+    0x5C08, // 4970
+    0x5C0D, // 4971
+    0x5C13, // 4972
+    0x5C20, // 4973
+    0x5C22, // 4974
+    0x5C28, // 4975
+    0x5C38, // 4976
+    0x5C39, // 4977
+    0x5C41, // 4978
+    0x5C46, // 4979
+    0x5C4E, // 4980
+    0x5C53, // 4981
+    0x5C50, // 4982
+    0x5C4F, // 4983
+    0x5B71, // 4984
+    0x5C6C, // 4985
+    0x5C6E, // 4986
+    0x4E62, // 4987
+    0x5C76, // 4988
+    0x5C79, // 4989
+    0x5C8C, // 4990
+    0x5C91, // 4991
+    0x5C94, // 4992
+    0x599B, // 4993
+    0x5CAB, // 4994
+    0x5CBB, // 4995
+    0x5CB6, // 4996
+    0x5CBC, // 4997
+    0x5CB7, // 4998
+    0x5CC5, // 4999
+    0x5CBE, // 5000
+    0x5CC7, // 5001
+    0x5CD9, // 5002
+    0x5CE9, // 5003
+    0x5CFD, // 5004
+    0x5CFA, // 5005
+    0x5CED, // 5006
+    0x5D8C, // 5007
+    0x5CEA, // 5008
+    0x5D0B, // 5009
+    0x5D15, // 5010
+    0x5D17, // 5011
+    0x5D5C, // 5012
+    0x5D1F, // 5013
+    0x5D1B, // 5014
+    0x5D11, // 5015
+    0x5D14, // 5016
+    0x5D22, // 5017
+    0x5D1A, // 5018
+    0x5D19, // 5019
+    0x5D18, // 5020
+    0x5D4C, // 5021
+    0x5D52, // 5022
+    0x5D4E, // 5023
+    0x5D4B, // 5024
+    0x5D6C, // 5025
+    0x5D73, // 5026
+    0x5D76, // 5027
+    0x5D87, // 5028
+    0x5D84, // 5029
+    0x5D82, // 5030
+    0x5DA2, // 5031
+    0x5D9D, // 5032
+    0x5DAC, // 5033
+    0x5DAE, // 5034
+    0x5DBD, // 5035
+    0x5D90, // 5036
+    0x5DB7, // 5037
+    0x5DBC, // 5038
+    0x5DC9, // 5039
+    0x5DCD, // 5040
+    0x5DD3, // 5041
+    0x5DD2, // 5042
+    0x5DD6, // 5043
+    0x5DDB, // 5044
+    0x5DEB, // 5045
+    0x5DF2, // 5046
+    0x5DF5, // 5047
+    0x5E0B, // 5048
+    0x5E1A, // 5049
+    0x5E19, // 5050
+    0x5E11, // 5051
+    0x5E1B, // 5052
+    0x5E36, // 5053
+    0x5E37, // 5054
+    0x5E44, // 5055
+    0x5E43, // 5056
+    0x5E40, // 5057
+    0x5E4E, // 5058
+    0x5E57, // 5059
+    0x5E54, // 5060
+    0x5E5F, // 5061
+    0x5E62, // 5062
+    0x5E64, // 5063
+    0x5E47, // 5064
+    0x5E75, // 5065
+    0x5E76, // 5066
+    0x5E7A, // 5067
+    0x9EBC, // 5068
+    0x5E7F, // 5069
+    0x5EA0, // 5070
+    0x5EC1, // 5071
+    0x5EC2, // 5072
+    0x5EC8, // 5073
+    0x5ED0, // 5074
+    0x5ECF, // 5075
+    0x5ED6, // 5076
+    0x5EE3, // 5077
+    0x5EDD, // 5078
+    0x5EDA, // 5079
+    0x5EDB, // 5080
+    0x5EE2, // 5081
+    0x5EE1, // 5082
+    0x5EE8, // 5083
+    0x5EE9, // 5084
+    0x5EEC, // 5085
+    0x5EF1, // 5086
+    0x5EF3, // 5087
+    0x5EF0, // 5088
+    0x5EF4, // 5089
+    0x5EF8, // 5090
+    0x5EFE, // 5091
+    0x5F03, // 5092
+    0x5F09, // 5093
+    0x5F5D, // 5094
+    0x5F5C, // 5095
+    0x5F0B, // 5096
+    0x5F11, // 5097
+    0x5F16, // 5098
+    0x5F29, // 5099
+    0x5F2D, // 5100
+    0x5F38, // 5101
+    0x5F41, // 5102
+    0x5F48, // 5103
+    0x5F4C, // 5104
+    0x5F4E, // 5105
+    0x5F2F, // 5106
+    0x5F51, // 5107
+    0x5F56, // 5108
+    0x5F57, // 5109
+    0x5F59, // 5110
+    0x5F61, // 5111
+    0x5F6D, // 5112
+    0x5F73, // 5113
+    0x5F77, // 5114
+    0x5F83, // 5115
+    0x5F82, // 5116
+    0x5F7F, // 5117
+    0x5F8A, // 5118
+    0x5F88, // 5119
+    0x5F91, // 5120
+    0x5F87, // 5121
+    0x5F9E, // 5122
+    0x5F99, // 5123
+    0x5F98, // 5124
+    0x5FA0, // 5125
+    0x5FA8, // 5126
+    0x5FAD, // 5127
+    0x5FBC, // 5128
+    0x5FD6, // 5129
+    0x5FFB, // 5130
+    0x5FE4, // 5131
+    0x5FF8, // 5132
+    0x5FF1, // 5133
+    0x5FDD, // 5134
+    0x60B3, // 5135
+    0x5FFF, // 5136
+    0x6021, // 5137
+    0x6060, // 5138
+    0x6019, // 5139
+    0x6010, // 5140
+    0x6029, // 5141
+    0x600E, // 5142
+    0x6031, // 5143
+    0x601B, // 5144
+    0x6015, // 5145
+    0x602B, // 5146
+    0x6026, // 5147
+    0x600F, // 5148
+    0x603A, // 5149
+    0x605A, // 5150
+    0x6041, // 5151
+    0x606A, // 5152
+    0x6077, // 5153
+    0x605F, // 5154
+    0x604A, // 5155
+    0x6046, // 5156
+    0x604D, // 5157
+    0x6063, // 5158
+    0x6043, // 5159
+    0x6064, // 5160
+    0x6042, // 5161
+    0x606C, // 5162
+    0x606B, // 5163
+    0x6059, // 5164
+    0x6081, // 5165
+    0x608D, // 5166
+    0x60E7, // 5167
+    0x6083, // 5168
+    0x609A, // 5169
+    0x6084, // 5170
+    0x609B, // 5171
+    0x6096, // 5172
+    0x6097, // 5173
+    0x6092, // 5174
+    0x60A7, // 5175
+    0x608B, // 5176
+    0x60E1, // 5177
+    0x60B8, // 5178
+    0x60E0, // 5179
+    0x60D3, // 5180
+    0x60B4, // 5181
+    0x5FF0, // 5182
+    0x60BD, // 5183
+    0x60C6, // 5184
+    0x60B5, // 5185
+    0x60D8, // 5186
+    0x614D, // 5187
+    0x6115, // 5188
+    0x6106, // 5189
+    0x60F6, // 5190
+    0x60F7, // 5191
+    0x6100, // 5192
+    0x60F4, // 5193
+    0x60FA, // 5194
+    0x6103, // 5195
+    0x6121, // 5196
+    0x60FB, // 5197
+    0x60F1, // 5198
+    0x610D, // 5199
+    0x610E, // 5200
+    0x6147, // 5201
+    0x613E, // 5202
+    0x6128, // 5203
+    0x6127, // 5204
+    0x614A, // 5205
+    0x613F, // 5206
+    0x613C, // 5207
+    0x612C, // 5208
+    0x6134, // 5209
+    0x613D, // 5210
+    0x6142, // 5211
+    0x6144, // 5212
+    0x6173, // 5213
+    0x6177, // 5214
+    0x6158, // 5215
+    0x6159, // 5216
+    0x615A, // 5217
+    0x616B, // 5218
+    0x6174, // 5219
+    0x616F, // 5220
+    0x6165, // 5221
+    0x6171, // 5222
+    0x615F, // 5223
+    0x615D, // 5224
+    0x6153, // 5225
+    0x6175, // 5226
+    0x6199, // 5227
+    0x6196, // 5228
+    0x6187, // 5229
+    0x61AC, // 5230
+    0x6194, // 5231
+    0x619A, // 5232
+    0x618A, // 5233
+    0x6191, // 5234
+    0x61AB, // 5235
+    0x61AE, // 5236
+    0x61CC, // 5237
+    0x61CA, // 5238
+    0x61C9, // 5239
+    0x61F7, // 5240
+    0x61C8, // 5241
+    0x61C3, // 5242
+    0x61C6, // 5243
+    0x61BA, // 5244
+    0x61CB, // 5245
+    0x7F79, // 5246
+    0x61CD, // 5247
+    0x61E6, // 5248
+    0x61E3, // 5249
+    0x61F6, // 5250
+    0x61FA, // 5251
+    0x61F4, // 5252
+    0x61FF, // 5253
+    0x61FD, // 5254
+    0x61FC, // 5255
+    0x61FE, // 5256
+    0x6200, // 5257
+    0x6208, // 5258
+    0x6209, // 5259
+    0x620D, // 5260
+    0x620C, // 5261
+    0x6214, // 5262
+    0x621B, // 5263
+    0x621E, // 5264
+    0x6221, // 5265
+    0x622A, // 5266
+    0x622E, // 5267
+    0x6230, // 5268
+    0x6232, // 5269
+    0x6233, // 5270
+    0x6241, // 5271
+    0x624E, // 5272
+    0x625E, // 5273
+    0x6263, // 5274
+    0x625B, // 5275
+    0x6260, // 5276
+    0x6268, // 5277
+    0x627C, // 5278
+    0x6282, // 5279
+    0x6289, // 5280
+    0x627E, // 5281
+    0x6292, // 5282
+    0x6293, // 5283
+    0x6296, // 5284
+    0x62D4, // 5285
+    0x6283, // 5286
+    0x6294, // 5287
+    0x62D7, // 5288
+    0x62D1, // 5289
+    0x62BB, // 5290
+    0x62CF, // 5291
+    0x62FF, // 5292
+    0x62C6, // 5293
+    0x64D4, // 5294
+    0x62C8, // 5295
+    0x62DC, // 5296
+    0x62CC, // 5297
+    0x62CA, // 5298
+    0x62C2, // 5299
+    0x62C7, // 5300
+    0x629B, // 5301
+    0x62C9, // 5302
+    0x630C, // 5303
+    0x62EE, // 5304
+    0x62F1, // 5305
+    0x6327, // 5306
+    0x6302, // 5307
+    0x6308, // 5308
+    0x62EF, // 5309
+    0x62F5, // 5310
+    0x6350, // 5311
+    0x633E, // 5312
+    0x634D, // 5313
+    0x641C, // 5314
+    0x634F, // 5315
+    0x6396, // 5316
+    0x638E, // 5317
+    0x6380, // 5318
+    0x63AB, // 5319
+    0x6376, // 5320
+    0x63A3, // 5321
+    0x638F, // 5322
+    0x6389, // 5323
+    0x639F, // 5324
+    0x63B5, // 5325
+    0x636B, // 5326
+    0x6369, // 5327
+    0x63BE, // 5328
+    0x63E9, // 5329
+    0x63C0, // 5330
+    0x63C6, // 5331
+    0x63E3, // 5332
+    0x63C9, // 5333
+    0x63D2, // 5334
+    0x63F6, // 5335
+    0x63C4, // 5336
+    0x6416, // 5337
+    0x6434, // 5338
+    0x6406, // 5339
+    0x6413, // 5340
+    0x6426, // 5341
+    0x6436, // 5342
+    0x651D, // 5343
+    0x6417, // 5344
+    0x6428, // 5345
+    0x640F, // 5346
+    0x6467, // 5347
+    0x646F, // 5348
+    0x6476, // 5349
+    0x644E, // 5350
+    0x652A, // 5351
+    0x6495, // 5352
+    0x6493, // 5353
+    0x64A5, // 5354
+    0x64A9, // 5355
+    0x6488, // 5356
+    0x64BC, // 5357
+    0x64DA, // 5358
+    0x64D2, // 5359
+    0x64C5, // 5360
+    0x64C7, // 5361
+    0x64BB, // 5362
+    0x64D8, // 5363
+    0x64C2, // 5364
+    0x64F1, // 5365
+    0x64E7, // 5366
+    0x8209, // 5367
+    0x64E0, // 5368
+    0x64E1, // 5369
+    0x62AC, // 5370
+    0x64E3, // 5371
+    0x64EF, // 5372
+    0x652C, // 5373
+    0x64F6, // 5374
+    0x64F4, // 5375
+    0x64F2, // 5376
+    0x64FA, // 5377
+    0x6500, // 5378
+    0x64FD, // 5379
+    0x6518, // 5380
+    0x651C, // 5381
+    0x6505, // 5382
+    0x6524, // 5383
+    0x6523, // 5384
+    0x652B, // 5385
+    0x6534, // 5386
+    0x6535, // 5387
+    0x6537, // 5388
+    0x6536, // 5389
+    0x6538, // 5390
+    0x754B, // 5391
+    0x6548, // 5392
+    0x6556, // 5393
+    0x6555, // 5394
+    0x654D, // 5395
+    0x6558, // 5396
+    0x655E, // 5397
+    0x655D, // 5398
+    0x6572, // 5399
+    0x6578, // 5400
+    0x6582, // 5401
+    0x6583, // 5402
+    0x8B8A, // 5403
+    0x659B, // 5404
+    0x659F, // 5405
+    0x65AB, // 5406
+    0x65B7, // 5407
+    0x65C3, // 5408
+    0x65C6, // 5409
+    0x65C1, // 5410
+    0x65C4, // 5411
+    0x65CC, // 5412
+    0x65D2, // 5413
+    0x65DB, // 5414
+    0x65D9, // 5415
+    0x65E0, // 5416
+    0x65E1, // 5417
+    0x65F1, // 5418
+    0x6772, // 5419
+    0x660A, // 5420
+    0x6603, // 5421
+    0x65FB, // 5422
+    0x6773, // 5423
+    0x6635, // 5424
+    0x6636, // 5425
+    0x6634, // 5426
+    0x661C, // 5427
+    0x664F, // 5428
+    0x6644, // 5429
+    0x6649, // 5430
+    0x6641, // 5431
+    0x665E, // 5432
+    0x665D, // 5433
+    0x6664, // 5434
+    0x6667, // 5435
+    0x6668, // 5436
+    0x665F, // 5437
+    0x6662, // 5438
+    0x6670, // 5439
+    0x6683, // 5440
+    0x6688, // 5441
+    0x668E, // 5442
+    0x6689, // 5443
+    0x6684, // 5444
+    0x6698, // 5445
+    0x669D, // 5446
+    0x66C1, // 5447
+    0x66B9, // 5448
+    0x66C9, // 5449
+    0x66BE, // 5450
+    0x66BC, // 5451
+    0x66C4, // 5452
+    0x66B8, // 5453
+    0x66D6, // 5454
+    0x66DA, // 5455
+    0x66E0, // 5456
+    0x663F, // 5457
+    0x66E6, // 5458
+    0x66E9, // 5459
+    0x66F0, // 5460
+    0x66F5, // 5461
+    0x66F7, // 5462
+    0x670F, // 5463
+    0x6716, // 5464
+    0x671E, // 5465
+    0x6726, // 5466
+    0x6727, // 5467
+    0x9738, // 5468
+    0x672E, // 5469
+    0x673F, // 5470
+    0x6736, // 5471
+    0x6741, // 5472
+    0x6738, // 5473
+    0x6737, // 5474
+    0x6746, // 5475
+    0x675E, // 5476
+    0x6760, // 5477
+    0x6759, // 5478
+    0x6763, // 5479
+    0x6764, // 5480
+    0x6789, // 5481
+    0x6770, // 5482
+    0x67A9, // 5483
+    0x677C, // 5484
+    0x676A, // 5485
+    0x678C, // 5486
+    0x678B, // 5487
+    0x67A6, // 5488
+    0x67A1, // 5489
+    0x6785, // 5490
+    0x67B7, // 5491
+    0x67EF, // 5492
+    0x67B4, // 5493
+    0x67EC, // 5494
+    0x67B3, // 5495
+    0x67E9, // 5496
+    0x67B8, // 5497
+    0x67E4, // 5498
+    0x67DE, // 5499
+    0x67DD, // 5500
+    0x67E2, // 5501
+    0x67EE, // 5502
+    0x67B9, // 5503
+    0x67CE, // 5504
+    0x67C6, // 5505
+    0x67E7, // 5506
+    0x6A9C, // 5507
+    0x681E, // 5508
+    0x6846, // 5509
+    0x6829, // 5510
+    0x6840, // 5511
+    0x684D, // 5512
+    0x6832, // 5513
+    0x684E, // 5514
+    0x68B3, // 5515
+    0x682B, // 5516
+    0x6859, // 5517
+    0x6863, // 5518
+    0x6877, // 5519
+    0x687F, // 5520
+    0x689F, // 5521
+    0x688F, // 5522
+    0x68AD, // 5523
+    0x6894, // 5524
+    0x689D, // 5525
+    0x689B, // 5526
+    0x6883, // 5527
+    0x6AAE, // 5528
+    0x68B9, // 5529
+    0x6874, // 5530
+    0x68B5, // 5531
+    0x68A0, // 5532
+    0x68BA, // 5533
+    0x690F, // 5534
+    0x688D, // 5535
+    0x687E, // 5536
+    0x6901, // 5537
+    0x68CA, // 5538
+    0x6908, // 5539
+    0x68D8, // 5540
+    0x6922, // 5541
+    0x6926, // 5542
+    0x68E1, // 5543
+    0x690C, // 5544
+    0x68CD, // 5545
+    0x68D4, // 5546
+    0x68E7, // 5547
+    0x68D5, // 5548
+    0x6936, // 5549
+    0x6912, // 5550
+    0x6904, // 5551
+    0x68D7, // 5552
+    0x68E3, // 5553
+    0x6925, // 5554
+    0x68F9, // 5555
+    0x68E0, // 5556
+    0x68EF, // 5557
+    0x6928, // 5558
+    0x692A, // 5559
+    0x691A, // 5560
+    0x6923, // 5561
+    0x6921, // 5562
+    0x68C6, // 5563
+    0x6979, // 5564
+    0x6977, // 5565
+    0x695C, // 5566
+    0x6978, // 5567
+    0x696B, // 5568
+    0x6954, // 5569
+    0x697E, // 5570
+    0x696E, // 5571
+    0x6939, // 5572
+    0x6974, // 5573
+    0x693D, // 5574
+    0x6959, // 5575
+    0x6930, // 5576
+    0x6961, // 5577
+    0x695E, // 5578
+    0x695D, // 5579
+    0x6981, // 5580
+    0x696A, // 5581
+    0x69B2, // 5582
+    0x69AE, // 5583
+    0x69D0, // 5584
+    0x69BF, // 5585
+    0x69C1, // 5586
+    0x69D3, // 5587
+    0x69BE, // 5588
+    0x69CE, // 5589
+    0x5BE8, // 5590
+    0x69CA, // 5591
+    0x69DD, // 5592
+    0x69BB, // 5593
+    0x69C3, // 5594
+    0x69A7, // 5595
+    0x6A2E, // 5596
+    0x6991, // 5597
+    0x69A0, // 5598
+    0x699C, // 5599
+    0x6995, // 5600
+    0x69B4, // 5601
+    0x69DE, // 5602
+    0x69E8, // 5603
+    0x6A02, // 5604
+    0x6A1B, // 5605
+    0x69FF, // 5606
+    0x6B0A, // 5607
+    0x69F9, // 5608
+    0x69F2, // 5609
+    0x69E7, // 5610
+    0x6A05, // 5611
+    0x69B1, // 5612
+    0x6A1E, // 5613
+    0x69ED, // 5614
+    0x6A14, // 5615
+    0x69EB, // 5616
+    0x6A0A, // 5617
+    0x6A12, // 5618
+    0x6AC1, // 5619
+    0x6A23, // 5620
+    0x6A13, // 5621
+    0x6A44, // 5622
+    0x6A0C, // 5623
+    0x6A72, // 5624
+    0x6A36, // 5625
+    0x6A78, // 5626
+    0x6A47, // 5627
+    0x6A62, // 5628
+    0x6A59, // 5629
+    0x6A66, // 5630
+    0x6A48, // 5631
+    0x6A38, // 5632
+    0x6A22, // 5633
+    0x6A90, // 5634
+    0x6A8D, // 5635
+    0x6AA0, // 5636
+    0x6A84, // 5637
+    0x6AA2, // 5638
+    0x6AA3, // 5639
+    0x6A97, // 5640
+    0x8617, // 5641
+    0x6ABB, // 5642
+    0x6AC3, // 5643
+    0x6AC2, // 5644
+    0x6AB8, // 5645
+    0x6AB3, // 5646
+    0x6AAC, // 5647
+    0x6ADE, // 5648
+    0x6AD1, // 5649
+    0x6ADF, // 5650
+    0x6AAA, // 5651
+    0x6ADA, // 5652
+    0x6AEA, // 5653
+    0x6AFB, // 5654
+    0x6B05, // 5655
+    0x8616, // 5656
+    0x6AFA, // 5657
+    0x6B12, // 5658
+    0x6B16, // 5659
+    0x9B31, // 5660
+    0x6B1F, // 5661
+    0x6B38, // 5662
+    0x6B37, // 5663
+    0x76DC, // 5664
+    0x6B39, // 5665
+    0x98EE, // 5666
+    0x6B47, // 5667
+    0x6B43, // 5668
+    0x6B49, // 5669
+    0x6B50, // 5670
+    0x6B59, // 5671
+    0x6B54, // 5672
+    0x6B5B, // 5673
+    0x6B5F, // 5674
+    0x6B61, // 5675
+    0x6B78, // 5676
+    0x6B79, // 5677
+    0x6B7F, // 5678
+    0x6B80, // 5679
+    0x6B84, // 5680
+    0x6B83, // 5681
+    0x6B8D, // 5682
+    0x6B98, // 5683
+    0x6B95, // 5684
+    0x6B9E, // 5685
+    0x6BA4, // 5686
+    0x6BAA, // 5687
+    0x6BAB, // 5688
+    0x6BAF, // 5689
+    0x6BB2, // 5690
+    0x6BB1, // 5691
+    0x6BB3, // 5692
+    0x6BB7, // 5693
+    0x6BBC, // 5694
+    0x6BC6, // 5695
+    0x6BCB, // 5696
+    0x6BD3, // 5697
+    0x6BDF, // 5698
+    0x6BEC, // 5699
+    0x6BEB, // 5700
+    0x6BF3, // 5701
+    0x6BEF, // 5702
+    0x9EBE, // 5703
+    0x6C08, // 5704
+    0x6C13, // 5705
+    0x6C14, // 5706
+    0x6C1B, // 5707
+    0x6C24, // 5708
+    0x6C23, // 5709
+    0x6C5E, // 5710
+    0x6C55, // 5711
+    0x6C62, // 5712
+    0x6C6A, // 5713
+    0x6C82, // 5714
+    0x6C8D, // 5715
+    0x6C9A, // 5716
+    0x6C81, // 5717
+    0x6C9B, // 5718
+    0x6C7E, // 5719
+    0x6C68, // 5720
+    0x6C73, // 5721
+    0x6C92, // 5722
+    0x6C90, // 5723
+    0x6CC4, // 5724
+    0x6CF1, // 5725
+    0x6CD3, // 5726
+    0x6CBD, // 5727
+    0x6CD7, // 5728
+    0x6CC5, // 5729
+    0x6CDD, // 5730
+    0x6CAE, // 5731
+    0x6CB1, // 5732
+    0x6CBE, // 5733
+    0x6CBA, // 5734
+    0x6CDB, // 5735
+    0x6CEF, // 5736
+    0x6CD9, // 5737
+    0x6CEA, // 5738
+    0x6D1F, // 5739
+    0x884D, // 5740
+    0x6D36, // 5741
+    0x6D2B, // 5742
+    0x6D3D, // 5743
+    0x6D38, // 5744
+    0x6D19, // 5745
+    0x6D35, // 5746
+    0x6D33, // 5747
+    0x6D12, // 5748
+    0x6D0C, // 5749
+    0x6D63, // 5750
+    0x6D93, // 5751
+    0x6D64, // 5752
+    0x6D5A, // 5753
+    0x6D79, // 5754
+    0x6D59, // 5755
+    0x6D8E, // 5756
+    0x6D95, // 5757
+    0x6FE4, // 5758
+    0x6D85, // 5759
+    0x6DF9, // 5760
+    0x6E15, // 5761
+    0x6E0A, // 5762
+    0x6DB5, // 5763
+    0x6DC7, // 5764
+    0x6DE6, // 5765
+    0x6DB8, // 5766
+    0x6DC6, // 5767
+    0x6DEC, // 5768
+    0x6DDE, // 5769
+    0x6DCC, // 5770
+    0x6DE8, // 5771
+    0x6DD2, // 5772
+    0x6DC5, // 5773
+    0x6DFA, // 5774
+    0x6DD9, // 5775
+    0x6DE4, // 5776
+    0x6DD5, // 5777
+    0x6DEA, // 5778
+    0x6DEE, // 5779
+    0x6E2D, // 5780
+    0x6E6E, // 5781
+    0x6E2E, // 5782
+    0x6E19, // 5783
+    0x6E72, // 5784
+    0x6E5F, // 5785
+    0x6E3E, // 5786
+    0x6E23, // 5787
+    0x6E6B, // 5788
+    0x6E2B, // 5789
+    0x6E76, // 5790
+    0x6E4D, // 5791
+    0x6E1F, // 5792
+    0x6E43, // 5793
+    0x6E3A, // 5794
+    0x6E4E, // 5795
+    0x6E24, // 5796
+    0x6EFF, // 5797
+    0x6E1D, // 5798
+    0x6E38, // 5799
+    0x6E82, // 5800
+    0x6EAA, // 5801
+    0x6E98, // 5802
+    0x6EC9, // 5803
+    0x6EB7, // 5804
+    0x6ED3, // 5805
+    0x6EBD, // 5806
+    0x6EAF, // 5807
+    0x6EC4, // 5808
+    0x6EB2, // 5809
+    0x6ED4, // 5810
+    0x6ED5, // 5811
+    0x6E8F, // 5812
+    0x6EA5, // 5813
+    0x6EC2, // 5814
+    0x6E9F, // 5815
+    0x6F41, // 5816
+    0x6F11, // 5817
+    0x704C, // 5818
+    0x6EEC, // 5819
+    0x6EF8, // 5820
+    0x6EFE, // 5821
+    0x6F3F, // 5822
+    0x6EF2, // 5823
+    0x6F31, // 5824
+    0x6EEF, // 5825
+    0x6F32, // 5826
+    0x6ECC, // 5827
+    0x6F3E, // 5828
+    0x6F13, // 5829
+    0x6EF7, // 5830
+    0x6F86, // 5831
+    0x6F7A, // 5832
+    0x6F78, // 5833
+    0x6F81, // 5834
+    0x6F80, // 5835
+    0x6F6F, // 5836
+    0x6F5B, // 5837
+    0x6FF3, // 5838
+    0x6F6D, // 5839
+    0x6F82, // 5840
+    0x6F7C, // 5841
+    0x6F58, // 5842
+    0x6F8E, // 5843
+    0x6F91, // 5844
+    0x6FC2, // 5845
+    0x6F66, // 5846
+    0x6FB3, // 5847
+    0x6FA3, // 5848
+    0x6FA1, // 5849
+    0x6FA4, // 5850
+    0x6FB9, // 5851
+    0x6FC6, // 5852
+    0x6FAA, // 5853
+    0x6FDF, // 5854
+    0x6FD5, // 5855
+    0x6FEC, // 5856
+    0x6FD4, // 5857
+    0x6FD8, // 5858
+    0x6FF1, // 5859
+    0x6FEE, // 5860
+    0x6FDB, // 5861
+    0x7009, // 5862
+    0x700B, // 5863
+    0x6FFA, // 5864
+    0x7011, // 5865
+    0x7001, // 5866
+    0x700F, // 5867
+    0x6FFE, // 5868
+    0x701B, // 5869
+    0x701A, // 5870
+    0x6F74, // 5871
+    0x701D, // 5872
+    0x7018, // 5873
+    0x701F, // 5874
+    0x7030, // 5875
+    0x703E, // 5876
+    0x7032, // 5877
+    0x7051, // 5878
+    0x7063, // 5879
+    0x7099, // 5880
+    0x7092, // 5881
+    0x70AF, // 5882
+    0x70F1, // 5883
+    0x70AC, // 5884
+    0x70B8, // 5885
+    0x70B3, // 5886
+    0x70AE, // 5887
+    0x70DF, // 5888
+    0x70CB, // 5889
+    0x70DD, // 5890
+    0x70D9, // 5891
+    0x7109, // 5892
+    0x70FD, // 5893
+    0x711C, // 5894
+    0x7119, // 5895
+    0x7165, // 5896
+    0x7155, // 5897
+    0x7188, // 5898
+    0x7166, // 5899
+    0x7162, // 5900
+    0x714C, // 5901
+    0x7156, // 5902
+    0x716C, // 5903
+    0x718F, // 5904
+    0x71FB, // 5905
+    0x7184, // 5906
+    0x7195, // 5907
+    0x71A8, // 5908
+    0x71AC, // 5909
+    0x71D7, // 5910
+    0x71B9, // 5911
+    0x71BE, // 5912
+    0x71D2, // 5913
+    0x71C9, // 5914
+    0x71D4, // 5915
+    0x71CE, // 5916
+    0x71E0, // 5917
+    0x71EC, // 5918
+    0x71E7, // 5919
+    0x71F5, // 5920
+    0x71FC, // 5921
+    0x71F9, // 5922
+    0x71FF, // 5923
+    0x720D, // 5924
+    0x7210, // 5925
+    0x721B, // 5926
+    0x7228, // 5927
+    0x722D, // 5928
+    0x722C, // 5929
+    0x7230, // 5930
+    0x7232, // 5931
+    0x723B, // 5932
+    0x723C, // 5933
+    0x723F, // 5934
+    0x7240, // 5935
+    0x7246, // 5936
+    0x724B, // 5937
+    0x7258, // 5938
+    0x7274, // 5939
+    0x727E, // 5940
+    0x7282, // 5941
+    0x7281, // 5942
+    0x7287, // 5943
+    0x7292, // 5944
+    0x7296, // 5945
+    0x72A2, // 5946
+    0x72A7, // 5947
+    0x72B9, // 5948
+    0x72B2, // 5949
+    0x72C3, // 5950
+    0x72C6, // 5951
+    0x72C4, // 5952
+    0x72CE, // 5953
+    0x72D2, // 5954
+    0x72E2, // 5955
+    0x72E0, // 5956
+    0x72E1, // 5957
+    0x72F9, // 5958
+    0x72F7, // 5959
+    0x500F, // 5960
+    0x7317, // 5961
+    0x730A, // 5962
+    0x731C, // 5963
+    0x7316, // 5964
+    0x731D, // 5965
+    0x7334, // 5966
+    0x732F, // 5967
+    0x7329, // 5968
+    0x7325, // 5969
+    0x733E, // 5970
+    0x734E, // 5971
+    0x734F, // 5972
+    0x9ED8, // 5973
+    0x7357, // 5974
+    0x736A, // 5975
+    0x7368, // 5976
+    0x7370, // 5977
+    0x7378, // 5978
+    0x7375, // 5979
+    0x737B, // 5980
+    0x737A, // 5981
+    0x73C8, // 5982
+    0x73B3, // 5983
+    0x73CE, // 5984
+    0x73BB, // 5985
+    0x73C0, // 5986
+    0x73E5, // 5987
+    0x73EE, // 5988
+    0x73DE, // 5989
+    0x74A2, // 5990
+    0x7405, // 5991
+    0x746F, // 5992
+    0x7425, // 5993
+    0x73F8, // 5994
+    0x7432, // 5995
+    0x743A, // 5996
+    0x7455, // 5997
+    0x743F, // 5998
+    0x745F, // 5999
+    0x7459, // 6000
+    0x7441, // 6001
+    0x745C, // 6002
+    0x7469, // 6003
+    0x7470, // 6004
+    0x7463, // 6005
+    0x746A, // 6006
+    0x7476, // 6007
+    0x747E, // 6008
+    0x748B, // 6009
+    0x749E, // 6010
+    0x74A7, // 6011
+    0x74CA, // 6012
+    0x74CF, // 6013
+    0x74D4, // 6014
+    0x73F1, // 6015
+    0x74E0, // 6016
+    0x74E3, // 6017
+    0x74E7, // 6018
+    0x74E9, // 6019
+    0x74EE, // 6020
+    0x74F2, // 6021
+    0x74F0, // 6022
+    0x74F1, // 6023
+    0x74F8, // 6024
+    0x74F7, // 6025
+    0x7504, // 6026
+    0x7503, // 6027
+    0x7505, // 6028
+    0x750C, // 6029
+    0x750E, // 6030
+    0x750D, // 6031
+    0x7515, // 6032
+    0x7513, // 6033
+    0x751E, // 6034
+    0x7526, // 6035
+    0x752C, // 6036
+    0x753C, // 6037
+    0x7544, // 6038
+    0x754D, // 6039
+    0x754A, // 6040
+    0x7549, // 6041
+    0x755B, // 6042
+    0x7546, // 6043
+    0x755A, // 6044
+    0x7569, // 6045
+    0x7564, // 6046
+    0x7567, // 6047
+    0x756B, // 6048
+    0x756D, // 6049
+    0x7578, // 6050
+    0x7576, // 6051
+    0x7586, // 6052
+    0x7587, // 6053
+    0x7574, // 6054
+    0x758A, // 6055
+    0x7589, // 6056
+    0x7582, // 6057
+    0x7594, // 6058
+    0x759A, // 6059
+    0x759D, // 6060
+    0x75A5, // 6061
+    0x75A3, // 6062
+    0x75C2, // 6063
+    0x75B3, // 6064
+    0x75C3, // 6065
+    0x75B5, // 6066
+    0x75BD, // 6067
+    0x75B8, // 6068
+    0x75BC, // 6069
+    0x75B1, // 6070
+    0x75CD, // 6071
+    0x75CA, // 6072
+    0x75D2, // 6073
+    0x75D9, // 6074
+    0x75E3, // 6075
+    0x75DE, // 6076
+    0x75FE, // 6077
+    0x75FF, // 6078
+    0x75FC, // 6079
+    0x7601, // 6080
+    0x75F0, // 6081
+    0x75FA, // 6082
+    0x75F2, // 6083
+    0x75F3, // 6084
+    0x760B, // 6085
+    0x760D, // 6086
+    0x7609, // 6087
+    0x761F, // 6088
+    0x7627, // 6089
+    0x7620, // 6090
+    0x7621, // 6091
+    0x7622, // 6092
+    0x7624, // 6093
+    0x7634, // 6094
+    0x7630, // 6095
+    0x763B, // 6096
+    0x7647, // 6097
+    0x7648, // 6098
+    0x7646, // 6099
+    0x765C, // 6100
+    0x7658, // 6101
+    0x7661, // 6102
+    0x7662, // 6103
+    0x7668, // 6104
+    0x7669, // 6105
+    0x766A, // 6106
+    0x7667, // 6107
+    0x766C, // 6108
+    0x7670, // 6109
+    0x7672, // 6110
+    0x7676, // 6111
+    0x7678, // 6112
+    0x767C, // 6113
+    0x7680, // 6114
+    0x7683, // 6115
+    0x7688, // 6116
+    0x768B, // 6117
+    0x768E, // 6118
+    0x7696, // 6119
+    0x7693, // 6120
+    0x7699, // 6121
+    0x769A, // 6122
+    0x76B0, // 6123
+    0x76B4, // 6124
+    0x76B8, // 6125
+    0x76B9, // 6126
+    0x76BA, // 6127
+    0x76C2, // 6128
+    0x76CD, // 6129
+    0x76D6, // 6130
+    0x76D2, // 6131
+    0x76DE, // 6132
+    0x76E1, // 6133
+    0x76E5, // 6134
+    0x76E7, // 6135
+    0x76EA, // 6136
+    0x862F, // 6137
+    0x76FB, // 6138
+    0x7708, // 6139
+    0x7707, // 6140
+    0x7704, // 6141
+    0x7729, // 6142
+    0x7724, // 6143
+    0x771E, // 6144
+    0x7725, // 6145
+    0x7726, // 6146
+    0x771B, // 6147
+    0x7737, // 6148
+    0x7738, // 6149
+    0x7747, // 6150
+    0x775A, // 6151
+    0x7768, // 6152
+    0x776B, // 6153
+    0x775B, // 6154
+    0x7765, // 6155
+    0x777F, // 6156
+    0x777E, // 6157
+    0x7779, // 6158
+    0x778E, // 6159
+    0x778B, // 6160
+    0x7791, // 6161
+    0x77A0, // 6162
+    0x779E, // 6163
+    0x77B0, // 6164
+    0x77B6, // 6165
+    0x77B9, // 6166
+    0x77BF, // 6167
+    0x77BC, // 6168
+    0x77BD, // 6169
+    0x77BB, // 6170
+    0x77C7, // 6171
+    0x77CD, // 6172
+    0x77D7, // 6173
+    0x77DA, // 6174
+    0x77DC, // 6175
+    0x77E3, // 6176
+    0x77EE, // 6177
+    0x77FC, // 6178
+    0x780C, // 6179
+    0x7812, // 6180
+    0x7926, // 6181
+    0x7820, // 6182
+    0x792A, // 6183
+    0x7845, // 6184
+    0x788E, // 6185
+    0x7874, // 6186
+    0x7886, // 6187
+    0x787C, // 6188
+    0x789A, // 6189
+    0x788C, // 6190
+    0x78A3, // 6191
+    0x78B5, // 6192
+    0x78AA, // 6193
+    0x78AF, // 6194
+    0x78D1, // 6195
+    0x78C6, // 6196
+    0x78CB, // 6197
+    0x78D4, // 6198
+    0x78BE, // 6199
+    0x78BC, // 6200
+    0x78C5, // 6201
+    0x78CA, // 6202
+    0x78EC, // 6203
+    0x78E7, // 6204
+    0x78DA, // 6205
+    0x78FD, // 6206
+    0x78F4, // 6207
+    0x7907, // 6208
+    0x7912, // 6209
+    0x7911, // 6210
+    0x7919, // 6211
+    0x792C, // 6212
+    0x792B, // 6213
+    0x7940, // 6214
+    0x7960, // 6215
+    0x7957, // 6216
+    0x795F, // 6217
+    0x795A, // 6218
+    0x7955, // 6219
+    0x7953, // 6220
+    0x797A, // 6221
+    0x797F, // 6222
+    0x798A, // 6223
+    0x799D, // 6224
+    0x79A7, // 6225
+    0x9F4B, // 6226
+    0x79AA, // 6227
+    0x79AE, // 6228
+    0x79B3, // 6229
+    0x79B9, // 6230
+    0x79BA, // 6231
+    0x79C9, // 6232
+    0x79D5, // 6233
+    0x79E7, // 6234
+    0x79EC, // 6235
+    0x79E1, // 6236
+    0x79E3, // 6237
+    0x7A08, // 6238
+    0x7A0D, // 6239
+    0x7A18, // 6240
+    0x7A19, // 6241
+    0x7A20, // 6242
+    0x7A1F, // 6243
+    0x7980, // 6244
+    0x7A31, // 6245
+    0x7A3B, // 6246
+    0x7A3E, // 6247
+    0x7A37, // 6248
+    0x7A43, // 6249
+    0x7A57, // 6250
+    0x7A49, // 6251
+    0x7A61, // 6252
+    0x7A62, // 6253
+    0x7A69, // 6254
+    0x9F9D, // 6255
+    0x7A70, // 6256
+    0x7A79, // 6257
+    0x7A7D, // 6258
+    0x7A88, // 6259
+    0x7A97, // 6260
+    0x7A95, // 6261
+    0x7A98, // 6262
+    0x7A96, // 6263
+    0x7AA9, // 6264
+    0x7AC8, // 6265
+    0x7AB0, // 6266
+    0x7AB6, // 6267
+    0x7AC5, // 6268
+    0x7AC4, // 6269
+    0x7ABF, // 6270
+    0x9083, // 6271
+    0x7AC7, // 6272
+    0x7ACA, // 6273
+    0x7ACD, // 6274
+    0x7ACF, // 6275
+    0x7AD5, // 6276
+    0x7AD3, // 6277
+    0x7AD9, // 6278
+    0x7ADA, // 6279
+    0x7ADD, // 6280
+    0x7AE1, // 6281
+    0x7AE2, // 6282
+    0x7AE6, // 6283
+    0x7AED, // 6284
+    0x7AF0, // 6285
+    0x7B02, // 6286
+    0x7B0F, // 6287
+    0x7B0A, // 6288
+    0x7B06, // 6289
+    0x7B33, // 6290
+    0x7B18, // 6291
+    0x7B19, // 6292
+    0x7B1E, // 6293
+    0x7B35, // 6294
+    0x7B28, // 6295
+    0x7B36, // 6296
+    0x7B50, // 6297
+    0x7B7A, // 6298
+    0x7B04, // 6299
+    0x7B4D, // 6300
+    0x7B0B, // 6301
+    0x7B4C, // 6302
+    0x7B45, // 6303
+    0x7B75, // 6304
+    0x7B65, // 6305
+    0x7B74, // 6306
+    0x7B67, // 6307
+    0x7B70, // 6308
+    0x7B71, // 6309
+    0x7B6C, // 6310
+    0x7B6E, // 6311
+    0x7B9D, // 6312
+    0x7B98, // 6313
+    0x7B9F, // 6314
+    0x7B8D, // 6315
+    0x7B9C, // 6316
+    0x7B9A, // 6317
+    0x7B8B, // 6318
+    0x7B92, // 6319
+    0x7B8F, // 6320
+    0x7B5D, // 6321
+    0x7B99, // 6322
+    0x7BCB, // 6323
+    0x7BC1, // 6324
+    0x7BCC, // 6325
+    0x7BCF, // 6326
+    0x7BB4, // 6327
+    0x7BC6, // 6328
+    0x7BDD, // 6329
+    0x7BE9, // 6330
+    0x7C11, // 6331
+    0x7C14, // 6332
+    0x7BE6, // 6333
+    0x7BE5, // 6334
+    0x7C60, // 6335
+    0x7C00, // 6336
+    0x7C07, // 6337
+    0x7C13, // 6338
+    0x7BF3, // 6339
+    0x7BF7, // 6340
+    0x7C17, // 6341
+    0x7C0D, // 6342
+    0x7BF6, // 6343
+    0x7C23, // 6344
+    0x7C27, // 6345
+    0x7C2A, // 6346
+    0x7C1F, // 6347
+    0x7C37, // 6348
+    0x7C2B, // 6349
+    0x7C3D, // 6350
+    0x7C4C, // 6351
+    0x7C43, // 6352
+    0x7C54, // 6353
+    0x7C4F, // 6354
+    0x7C40, // 6355
+    0x7C50, // 6356
+    0x7C58, // 6357
+    0x7C5F, // 6358
+    0x7C64, // 6359
+    0x7C56, // 6360
+    0x7C65, // 6361
+    0x7C6C, // 6362
+    0x7C75, // 6363
+    0x7C83, // 6364
+    0x7C90, // 6365
+    0x7CA4, // 6366
+    0x7CAD, // 6367
+    0x7CA2, // 6368
+    0x7CAB, // 6369
+    0x7CA1, // 6370
+    0x7CA8, // 6371
+    0x7CB3, // 6372
+    0x7CB2, // 6373
+    0x7CB1, // 6374
+    0x7CAE, // 6375
+    0x7CB9, // 6376
+    0x7CBD, // 6377
+    0x7CC0, // 6378
+    0x7CC5, // 6379
+    0x7CC2, // 6380
+    0x7CD8, // 6381
+    0x7CD2, // 6382
+    0x7CDC, // 6383
+    0x7CE2, // 6384
+    0x9B3B, // 6385
+    0x7CEF, // 6386
+    0x7CF2, // 6387
+    0x7CF4, // 6388
+    0x7CF6, // 6389
+    0x7CFA, // 6390
+    0x7D06, // 6391
+    0x7D02, // 6392
+    0x7D1C, // 6393
+    0x7D15, // 6394
+    0x7D0A, // 6395
+    0x7D45, // 6396
+    0x7D4B, // 6397
+    0x7D2E, // 6398
+    0x7D32, // 6399
+    0x7D3F, // 6400
+    0x7D35, // 6401
+    0x7D46, // 6402
+    0x7D73, // 6403
+    0x7D56, // 6404
+    0x7D4E, // 6405
+    0x7D72, // 6406
+    0x7D68, // 6407
+    0x7D6E, // 6408
+    0x7D4F, // 6409
+    0x7D63, // 6410
+    0x7D93, // 6411
+    0x7D89, // 6412
+    0x7D5B, // 6413
+    0x7D8F, // 6414
+    0x7D7D, // 6415
+    0x7D9B, // 6416
+    0x7DBA, // 6417
+    0x7DAE, // 6418
+    0x7DA3, // 6419
+    0x7DB5, // 6420
+    0x7DC7, // 6421
+    0x7DBD, // 6422
+    0x7DAB, // 6423
+    0x7E3D, // 6424
+    0x7DA2, // 6425
+    0x7DAF, // 6426
+    0x7DDC, // 6427
+    0x7DB8, // 6428
+    0x7D9F, // 6429
+    0x7DB0, // 6430
+    0x7DD8, // 6431
+    0x7DDD, // 6432
+    0x7DE4, // 6433
+    0x7DDE, // 6434
+    0x7DFB, // 6435
+    0x7DF2, // 6436
+    0x7DE1, // 6437
+    0x7E05, // 6438
+    0x7E0A, // 6439
+    0x7E23, // 6440
+    0x7E21, // 6441
+    0x7E12, // 6442
+    0x7E31, // 6443
+    0x7E1F, // 6444
+    0x7E09, // 6445
+    0x7E0B, // 6446
+    0x7E22, // 6447
+    0x7E46, // 6448
+    0x7E66, // 6449
+    0x7E3B, // 6450
+    0x7E35, // 6451
+    0x7E39, // 6452
+    0x7E43, // 6453
+    0x7E37, // 6454
+    0x7E32, // 6455
+    0x7E3A, // 6456
+    0x7E67, // 6457
+    0x7E5D, // 6458
+    0x7E56, // 6459
+    0x7E5E, // 6460
+    0x7E59, // 6461
+    0x7E5A, // 6462
+    0x7E79, // 6463
+    0x7E6A, // 6464
+    0x7E69, // 6465
+    0x7E7C, // 6466
+    0x7E7B, // 6467
+    0x7E83, // 6468
+    0x7DD5, // 6469
+    0x7E7D, // 6470
+    0x8FAE, // 6471
+    0x7E7F, // 6472
+    0x7E88, // 6473
+    0x7E89, // 6474
+    0x7E8C, // 6475
+    0x7E92, // 6476
+    0x7E90, // 6477
+    0x7E93, // 6478
+    0x7E94, // 6479
+    0x7E96, // 6480
+    0x7E8E, // 6481
+    0x7E9B, // 6482
+    0x7E9C, // 6483
+    0x7F38, // 6484
+    0x7F3A, // 6485
+    0x7F45, // 6486
+    0x7F4C, // 6487
+    0x7F4D, // 6488
+    0x7F4E, // 6489
+    0x7F50, // 6490
+    0x7F51, // 6491
+    0x7F55, // 6492
+    0x7F54, // 6493
+    0x7F58, // 6494
+    0x7F5F, // 6495
+    0x7F60, // 6496
+    0x7F68, // 6497
+    0x7F69, // 6498
+    0x7F67, // 6499
+    0x7F78, // 6500
+    0x7F82, // 6501
+    0x7F86, // 6502
+    0x7F83, // 6503
+    0x7F88, // 6504
+    0x7F87, // 6505
+    0x7F8C, // 6506
+    0x7F94, // 6507
+    0x7F9E, // 6508
+    0x7F9D, // 6509
+    0x7F9A, // 6510
+    0x7FA3, // 6511
+    0x7FAF, // 6512
+    0x7FB2, // 6513
+    0x7FB9, // 6514
+    0x7FAE, // 6515
+    0x7FB6, // 6516
+    0x7FB8, // 6517
+    0x8B71, // 6518
+    0x7FC5, // 6519
+    0x7FC6, // 6520
+    0x7FCA, // 6521
+    0x7FD5, // 6522
+    0x7FD4, // 6523
+    0x7FE1, // 6524
+    0x7FE6, // 6525
+    0x7FE9, // 6526
+    0x7FF3, // 6527
+    0x7FF9, // 6528
+    0x98DC, // 6529
+    0x8006, // 6530
+    0x8004, // 6531
+    0x800B, // 6532
+    0x8012, // 6533
+    0x8018, // 6534
+    0x8019, // 6535
+    0x801C, // 6536
+    0x8021, // 6537
+    0x8028, // 6538
+    0x803F, // 6539
+    0x803B, // 6540
+    0x804A, // 6541
+    0x8046, // 6542
+    0x8052, // 6543
+    0x8058, // 6544
+    0x805A, // 6545
+    0x805F, // 6546
+    0x8062, // 6547
+    0x8068, // 6548
+    0x8073, // 6549
+    0x8072, // 6550
+    0x8070, // 6551
+    0x8076, // 6552
+    0x8079, // 6553
+    0x807D, // 6554
+    0x807F, // 6555
+    0x8084, // 6556
+    0x8086, // 6557
+    0x8085, // 6558
+    0x809B, // 6559
+    0x8093, // 6560
+    0x809A, // 6561
+    0x80AD, // 6562
+    0x5190, // 6563
+    0x80AC, // 6564
+    0x80DB, // 6565
+    0x80E5, // 6566
+    0x80D9, // 6567
+    0x80DD, // 6568
+    0x80C4, // 6569
+    0x80DA, // 6570
+    0x80D6, // 6571
+    0x8109, // 6572
+    0x80EF, // 6573
+    0x80F1, // 6574
+    0x811B, // 6575
+    0x8129, // 6576
+    0x8123, // 6577
+    0x812F, // 6578
+    0x814B, // 6579
+    0x968B, // 6580
+    0x8146, // 6581
+    0x813E, // 6582
+    0x8153, // 6583
+    0x8151, // 6584
+    0x80FC, // 6585
+    0x8171, // 6586
+    0x816E, // 6587
+    0x8165, // 6588
+    0x8166, // 6589
+    0x8174, // 6590
+    0x8183, // 6591
+    0x8188, // 6592
+    0x818A, // 6593
+    0x8180, // 6594
+    0x8182, // 6595
+    0x81A0, // 6596
+    0x8195, // 6597
+    0x81A4, // 6598
+    0x81A3, // 6599
+    0x815F, // 6600
+    0x8193, // 6601
+    0x81A9, // 6602
+    0x81B0, // 6603
+    0x81B5, // 6604
+    0x81BE, // 6605
+    0x81B8, // 6606
+    0x81BD, // 6607
+    0x81C0, // 6608
+    0x81C2, // 6609
+    0x81BA, // 6610
+    0x81C9, // 6611
+    0x81CD, // 6612
+    0x81D1, // 6613
+    0x81D9, // 6614
+    0x81D8, // 6615
+    0x81C8, // 6616
+    0x81DA, // 6617
+    0x81DF, // 6618
+    0x81E0, // 6619
+    0x81E7, // 6620
+    0x81FA, // 6621
+    0x81FB, // 6622
+    0x81FE, // 6623
+    0x8201, // 6624
+    0x8202, // 6625
+    0x8205, // 6626
+    0x8207, // 6627
+    0x820A, // 6628
+    0x820D, // 6629
+    0x8210, // 6630
+    0x8216, // 6631
+    0x8229, // 6632
+    0x822B, // 6633
+    0x8238, // 6634
+    0x8233, // 6635
+    0x8240, // 6636
+    0x8259, // 6637
+    0x8258, // 6638
+    0x825D, // 6639
+    0x825A, // 6640
+    0x825F, // 6641
+    0x8264, // 6642
+    0x8262, // 6643
+    0x8268, // 6644
+    0x826A, // 6645
+    0x826B, // 6646
+    0x822E, // 6647
+    0x8271, // 6648
+    0x8277, // 6649
+    0x8278, // 6650
+    0x827E, // 6651
+    0x828D, // 6652
+    0x8292, // 6653
+    0x82AB, // 6654
+    0x829F, // 6655
+    0x82BB, // 6656
+    0x82AC, // 6657
+    0x82E1, // 6658
+    0x82E3, // 6659
+    0x82DF, // 6660
+    0x82D2, // 6661
+    0x82F4, // 6662
+    0x82F3, // 6663
+    0x82FA, // 6664
+    0x8393, // 6665
+    0x8303, // 6666
+    0x82FB, // 6667
+    0x82F9, // 6668
+    0x82DE, // 6669
+    0x8306, // 6670
+    0x82DC, // 6671
+    0x8309, // 6672
+    0x82D9, // 6673
+    0x8335, // 6674
+    0x8334, // 6675
+    0x8316, // 6676
+    0x8332, // 6677
+    0x8331, // 6678
+    0x8340, // 6679
+    0x8339, // 6680
+    0x8350, // 6681
+    0x8345, // 6682
+    0x832F, // 6683
+    0x832B, // 6684
+    0x8317, // 6685
+    0x8318, // 6686
+    0x8385, // 6687
+    0x839A, // 6688
+    0x83AA, // 6689
+    0x839F, // 6690
+    0x83A2, // 6691
+    0x8396, // 6692
+    0x8323, // 6693
+    0x838E, // 6694
+    0x8387, // 6695
+    0x838A, // 6696
+    0x837C, // 6697
+    0x83B5, // 6698
+    0x8373, // 6699
+    0x8375, // 6700
+    0x83A0, // 6701
+    0x8389, // 6702
+    0x83A8, // 6703
+    0x83F4, // 6704
+    0x8413, // 6705
+    0x83EB, // 6706
+    0x83CE, // 6707
+    0x83FD, // 6708
+    0x8403, // 6709
+    0x83D8, // 6710
+    0x840B, // 6711
+    0x83C1, // 6712
+    0x83F7, // 6713
+    0x8407, // 6714
+    0x83E0, // 6715
+    0x83F2, // 6716
+    0x840D, // 6717
+    0x8422, // 6718
+    0x8420, // 6719
+    0x83BD, // 6720
+    0x8438, // 6721
+    0x8506, // 6722
+    0x83FB, // 6723
+    0x846D, // 6724
+    0x842A, // 6725
+    0x843C, // 6726
+    0x855A, // 6727
+    0x8484, // 6728
+    0x8477, // 6729
+    0x846B, // 6730
+    0x84AD, // 6731
+    0x846E, // 6732
+    0x8482, // 6733
+    0x8469, // 6734
+    0x8446, // 6735
+    0x842C, // 6736
+    0x846F, // 6737
+    0x8479, // 6738
+    0x8435, // 6739
+    0x84CA, // 6740
+    0x8462, // 6741
+    0x84B9, // 6742
+    0x84BF, // 6743
+    0x849F, // 6744
+    0x84D9, // 6745
+    0x84CD, // 6746
+    0x84BB, // 6747
+    0x84DA, // 6748
+    0x84D0, // 6749
+    0x84C1, // 6750
+    0x84C6, // 6751
+    0x84D6, // 6752
+    0x84A1, // 6753
+    0x8521, // 6754
+    0x84FF, // 6755
+    0x84F4, // 6756
+    0x8517, // 6757
+    0x8518, // 6758
+    0x852C, // 6759
+    0x851F, // 6760
+    0x8515, // 6761
+    0x8514, // 6762
+    0x84FC, // 6763
+    0x8540, // 6764
+    0x8563, // 6765
+    0x8558, // 6766
+    0x8548, // 6767
+    0x8541, // 6768
+    0x8602, // 6769
+    0x854B, // 6770
+    0x8555, // 6771
+    0x8580, // 6772
+    0x85A4, // 6773
+    0x8588, // 6774
+    0x8591, // 6775
+    0x858A, // 6776
+    0x85A8, // 6777
+    0x856D, // 6778
+    0x8594, // 6779
+    0x859B, // 6780
+    0x85EA, // 6781
+    0x8587, // 6782
+    0x859C, // 6783
+    0x8577, // 6784
+    0x857E, // 6785
+    0x8590, // 6786
+    0x85C9, // 6787
+    0x85BA, // 6788
+    0x85CF, // 6789
+    0x85B9, // 6790
+    0x85D0, // 6791
+    0x85D5, // 6792
+    0x85DD, // 6793
+    0x85E5, // 6794
+    0x85DC, // 6795
+    0x85F9, // 6796
+    0x860A, // 6797
+    0x8613, // 6798
+    0x860B, // 6799
+    0x85FE, // 6800
+    0x85FA, // 6801
+    0x8606, // 6802
+    0x8622, // 6803
+    0x861A, // 6804
+    0x8630, // 6805
+    0x863F, // 6806
+    0x864D, // 6807
+    0x4E55, // 6808
+    0x8654, // 6809
+    0x865F, // 6810
+    0x8667, // 6811
+    0x8671, // 6812
+    0x8693, // 6813
+    0x86A3, // 6814
+    0x86A9, // 6815
+    0x86AA, // 6816
+    0x868B, // 6817
+    0x868C, // 6818
+    0x86B6, // 6819
+    0x86AF, // 6820
+    0x86C4, // 6821
+    0x86C6, // 6822
+    0x86B0, // 6823
+    0x86C9, // 6824
+    0x8823, // 6825
+    0x86AB, // 6826
+    0x86D4, // 6827
+    0x86DE, // 6828
+    0x86E9, // 6829
+    0x86EC, // 6830
+    0x86DF, // 6831
+    0x86DB, // 6832
+    0x86EF, // 6833
+    0x8712, // 6834
+    0x8706, // 6835
+    0x8708, // 6836
+    0x8700, // 6837
+    0x8703, // 6838
+    0x86FB, // 6839
+    0x8711, // 6840
+    0x8709, // 6841
+    0x870D, // 6842
+    0x86F9, // 6843
+    0x870A, // 6844
+    0x8734, // 6845
+    0x873F, // 6846
+    0x8737, // 6847
+    0x873B, // 6848
+    0x8725, // 6849
+    0x8729, // 6850
+    0x871A, // 6851
+    0x8760, // 6852
+    0x875F, // 6853
+    0x8778, // 6854
+    0x874C, // 6855
+    0x874E, // 6856
+    0x8774, // 6857
+    0x8757, // 6858
+    0x8768, // 6859
+    0x876E, // 6860
+    0x8759, // 6861
+    0x8753, // 6862
+    0x8763, // 6863
+    0x876A, // 6864
+    0x8805, // 6865
+    0x87A2, // 6866
+    0x879F, // 6867
+    0x8782, // 6868
+    0x87AF, // 6869
+    0x87CB, // 6870
+    0x87BD, // 6871
+    0x87C0, // 6872
+    0x87D0, // 6873
+    0x96D6, // 6874
+    0x87AB, // 6875
+    0x87C4, // 6876
+    0x87B3, // 6877
+    0x87C7, // 6878
+    0x87C6, // 6879
+    0x87BB, // 6880
+    0x87EF, // 6881
+    0x87F2, // 6882
+    0x87E0, // 6883
+    0x880F, // 6884
+    0x880D, // 6885
+    0x87FE, // 6886
+    0x87F6, // 6887
+    0x87F7, // 6888
+    0x880E, // 6889
+    0x87D2, // 6890
+    0x8811, // 6891
+    0x8816, // 6892
+    0x8815, // 6893
+    0x8822, // 6894
+    0x8821, // 6895
+    0x8831, // 6896
+    0x8836, // 6897
+    0x8839, // 6898
+    0x8827, // 6899
+    0x883B, // 6900
+    0x8844, // 6901
+    0x8842, // 6902
+    0x8852, // 6903
+    0x8859, // 6904
+    0x885E, // 6905
+    0x8862, // 6906
+    0x886B, // 6907
+    0x8881, // 6908
+    0x887E, // 6909
+    0x889E, // 6910
+    0x8875, // 6911
+    0x887D, // 6912
+    0x88B5, // 6913
+    0x8872, // 6914
+    0x8882, // 6915
+    0x8897, // 6916
+    0x8892, // 6917
+    0x88AE, // 6918
+    0x8899, // 6919
+    0x88A2, // 6920
+    0x888D, // 6921
+    0x88A4, // 6922
+    0x88B0, // 6923
+    0x88BF, // 6924
+    0x88B1, // 6925
+    0x88C3, // 6926
+    0x88C4, // 6927
+    0x88D4, // 6928
+    0x88D8, // 6929
+    0x88D9, // 6930
+    0x88DD, // 6931
+    0x88F9, // 6932
+    0x8902, // 6933
+    0x88FC, // 6934
+    0x88F4, // 6935
+    0x88E8, // 6936
+    0x88F2, // 6937
+    0x8904, // 6938
+    0x890C, // 6939
+    0x890A, // 6940
+    0x8913, // 6941
+    0x8943, // 6942
+    0x891E, // 6943
+    0x8925, // 6944
+    0x892A, // 6945
+    0x892B, // 6946
+    0x8941, // 6947
+    0x8944, // 6948
+    0x893B, // 6949
+    0x8936, // 6950
+    0x8938, // 6951
+    0x894C, // 6952
+    0x891D, // 6953
+    0x8960, // 6954
+    0x895E, // 6955
+    0x8966, // 6956
+    0x8964, // 6957
+    0x896D, // 6958
+    0x896A, // 6959
+    0x896F, // 6960
+    0x8974, // 6961
+    0x8977, // 6962
+    0x897E, // 6963
+    0x8983, // 6964
+    0x8988, // 6965
+    0x898A, // 6966
+    0x8993, // 6967
+    0x8998, // 6968
+    0x89A1, // 6969
+    0x89A9, // 6970
+    0x89A6, // 6971
+    0x89AC, // 6972
+    0x89AF, // 6973
+    0x89B2, // 6974
+    0x89BA, // 6975
+    0x89BD, // 6976
+    0x89BF, // 6977
+    0x89C0, // 6978
+    0x89DA, // 6979
+    0x89DC, // 6980
+    0x89DD, // 6981
+    0x89E7, // 6982
+    0x89F4, // 6983
+    0x89F8, // 6984
+    0x8A03, // 6985
+    0x8A16, // 6986
+    0x8A10, // 6987
+    0x8A0C, // 6988
+    0x8A1B, // 6989
+    0x8A1D, // 6990
+    0x8A25, // 6991
+    0x8A36, // 6992
+    0x8A41, // 6993
+    0x8A5B, // 6994
+    0x8A52, // 6995
+    0x8A46, // 6996
+    0x8A48, // 6997
+    0x8A7C, // 6998
+    0x8A6D, // 6999
+    0x8A6C, // 7000
+    0x8A62, // 7001
+    0x8A85, // 7002
+    0x8A82, // 7003
+    0x8A84, // 7004
+    0x8AA8, // 7005
+    0x8AA1, // 7006
+    0x8A91, // 7007
+    0x8AA5, // 7008
+    0x8AA6, // 7009
+    0x8A9A, // 7010
+    0x8AA3, // 7011
+    0x8AC4, // 7012
+    0x8ACD, // 7013
+    0x8AC2, // 7014
+    0x8ADA, // 7015
+    0x8AEB, // 7016
+    0x8AF3, // 7017
+    0x8AE7, // 7018
+    0x8AE4, // 7019
+    0x8AF1, // 7020
+    0x8B14, // 7021
+    0x8AE0, // 7022
+    0x8AE2, // 7023
+    0x8AF7, // 7024
+    0x8ADE, // 7025
+    0x8ADB, // 7026
+    0x8B0C, // 7027
+    0x8B07, // 7028
+    0x8B1A, // 7029
+    0x8AE1, // 7030
+    0x8B16, // 7031
+    0x8B10, // 7032
+    0x8B17, // 7033
+    0x8B20, // 7034
+    0x8B33, // 7035
+    0x97AB, // 7036
+    0x8B26, // 7037
+    0x8B2B, // 7038
+    0x8B3E, // 7039
+    0x8B28, // 7040
+    0x8B41, // 7041
+    0x8B4C, // 7042
+    0x8B4F, // 7043
+    0x8B4E, // 7044
+    0x8B49, // 7045
+    0x8B56, // 7046
+    0x8B5B, // 7047
+    0x8B5A, // 7048
+    0x8B6B, // 7049
+    0x8B5F, // 7050
+    0x8B6C, // 7051
+    0x8B6F, // 7052
+    0x8B74, // 7053
+    0x8B7D, // 7054
+    0x8B80, // 7055
+    0x8B8C, // 7056
+    0x8B8E, // 7057
+    0x8B92, // 7058
+    0x8B93, // 7059
+    0x8B96, // 7060
+    0x8B99, // 7061
+    0x8B9A, // 7062
+    0x8C3A, // 7063
+    0x8C41, // 7064
+    0x8C3F, // 7065
+    0x8C48, // 7066
+    0x8C4C, // 7067
+    0x8C4E, // 7068
+    0x8C50, // 7069
+    0x8C55, // 7070
+    0x8C62, // 7071
+    0x8C6C, // 7072
+    0x8C78, // 7073
+    0x8C7A, // 7074
+    0x8C82, // 7075
+    0x8C89, // 7076
+    0x8C85, // 7077
+    0x8C8A, // 7078
+    0x8C8D, // 7079
+    0x8C8E, // 7080
+    0x8C94, // 7081
+    0x8C7C, // 7082
+    0x8C98, // 7083
+    0x621D, // 7084
+    0x8CAD, // 7085
+    0x8CAA, // 7086
+    0x8CBD, // 7087
+    0x8CB2, // 7088
+    0x8CB3, // 7089
+    0x8CAE, // 7090
+    0x8CB6, // 7091
+    0x8CC8, // 7092
+    0x8CC1, // 7093
+    0x8CE4, // 7094
+    0x8CE3, // 7095
+    0x8CDA, // 7096
+    0x8CFD, // 7097
+    0x8CFA, // 7098
+    0x8CFB, // 7099
+    0x8D04, // 7100
+    0x8D05, // 7101
+    0x8D0A, // 7102
+    0x8D07, // 7103
+    0x8D0F, // 7104
+    0x8D0D, // 7105
+    0x8D10, // 7106
+    0x9F4E, // 7107
+    0x8D13, // 7108
+    0x8CCD, // 7109
+    0x8D14, // 7110
+    0x8D16, // 7111
+    0x8D67, // 7112
+    0x8D6D, // 7113
+    0x8D71, // 7114
+    0x8D73, // 7115
+    0x8D81, // 7116
+    0x8D99, // 7117
+    0x8DC2, // 7118
+    0x8DBE, // 7119
+    0x8DBA, // 7120
+    0x8DCF, // 7121
+    0x8DDA, // 7122
+    0x8DD6, // 7123
+    0x8DCC, // 7124
+    0x8DDB, // 7125
+    0x8DCB, // 7126
+    0x8DEA, // 7127
+    0x8DEB, // 7128
+    0x8DDF, // 7129
+    0x8DE3, // 7130
+    0x8DFC, // 7131
+    0x8E08, // 7132
+    0x8E09, // 7133
+    0x8DFF, // 7134
+    0x8E1D, // 7135
+    0x8E1E, // 7136
+    0x8E10, // 7137
+    0x8E1F, // 7138
+    0x8E42, // 7139
+    0x8E35, // 7140
+    0x8E30, // 7141
+    0x8E34, // 7142
+    0x8E4A, // 7143
+    0x8E47, // 7144
+    0x8E49, // 7145
+    0x8E4C, // 7146
+    0x8E50, // 7147
+    0x8E48, // 7148
+    0x8E59, // 7149
+    0x8E64, // 7150
+    0x8E60, // 7151
+    0x8E2A, // 7152
+    0x8E63, // 7153
+    0x8E55, // 7154
+    0x8E76, // 7155
+    0x8E72, // 7156
+    0x8E7C, // 7157
+    0x8E81, // 7158
+    0x8E87, // 7159
+    0x8E85, // 7160
+    0x8E84, // 7161
+    0x8E8B, // 7162
+    0x8E8A, // 7163
+    0x8E93, // 7164
+    0x8E91, // 7165
+    0x8E94, // 7166
+    0x8E99, // 7167
+    0x8EAA, // 7168
+    0x8EA1, // 7169
+    0x8EAC, // 7170
+    0x8EB0, // 7171
+    0x8EC6, // 7172
+    0x8EB1, // 7173
+    0x8EBE, // 7174
+    0x8EC5, // 7175
+    0x8EC8, // 7176
+    0x8ECB, // 7177
+    0x8EDB, // 7178
+    0x8EE3, // 7179
+    0x8EFC, // 7180
+    0x8EFB, // 7181
+    0x8EEB, // 7182
+    0x8EFE, // 7183
+    0x8F0A, // 7184
+    0x8F05, // 7185
+    0x8F15, // 7186
+    0x8F12, // 7187
+    0x8F19, // 7188
+    0x8F13, // 7189
+    0x8F1C, // 7190
+    0x8F1F, // 7191
+    0x8F1B, // 7192
+    0x8F0C, // 7193
+    0x8F26, // 7194
+    0x8F33, // 7195
+    0x8F3B, // 7196
+    0x8F39, // 7197
+    0x8F45, // 7198
+    0x8F42, // 7199
+    0x8F3E, // 7200
+    0x8F4C, // 7201
+    0x8F49, // 7202
+    0x8F46, // 7203
+    0x8F4E, // 7204
+    0x8F57, // 7205
+    0x8F5C, // 7206
+    0x8F62, // 7207
+    0x8F63, // 7208
+    0x8F64, // 7209
+    0x8F9C, // 7210
+    0x8F9F, // 7211
+    0x8FA3, // 7212
+    0x8FAD, // 7213
+    0x8FAF, // 7214
+    0x8FB7, // 7215
+    0x8FDA, // 7216
+    0x8FE5, // 7217
+    0x8FE2, // 7218
+    0x8FEA, // 7219
+    0x8FEF, // 7220
+    0x9087, // 7221
+    0x8FF4, // 7222
+    0x9005, // 7223
+    0x8FF9, // 7224
+    0x8FFA, // 7225
+    0x9011, // 7226
+    0x9015, // 7227
+    0x9021, // 7228
+    0x900D, // 7229
+    0x901E, // 7230
+    0x9016, // 7231
+    0x900B, // 7232
+    0x9027, // 7233
+    0x9036, // 7234
+    0x9035, // 7235
+    0x9039, // 7236
+    0x8FF8, // 7237
+    0x904F, // 7238
+    0x9050, // 7239
+    0x9051, // 7240
+    0x9052, // 7241
+    0x900E, // 7242
+    0x9049, // 7243
+    0x903E, // 7244
+    0x9056, // 7245
+    0x9058, // 7246
+    0x905E, // 7247
+    0x9068, // 7248
+    0x906F, // 7249
+    0x9076, // 7250
+    0x96A8, // 7251
+    0x9072, // 7252
+    0x9082, // 7253
+    0x907D, // 7254
+    0x9081, // 7255
+    0x9080, // 7256
+    0x908A, // 7257
+    0x9089, // 7258
+    0x908F, // 7259
+    0x90A8, // 7260
+    0x90AF, // 7261
+    0x90B1, // 7262
+    0x90B5, // 7263
+    0x90E2, // 7264
+    0x90E4, // 7265
+    0x6248, // 7266
+    0x90DB, // 7267
+    0x9102, // 7268
+    0x9112, // 7269
+    0x9119, // 7270
+    0x9132, // 7271
+    0x9130, // 7272
+    0x914A, // 7273
+    0x9156, // 7274
+    0x9158, // 7275
+    0x9163, // 7276
+    0x9165, // 7277
+    0x9169, // 7278
+    0x9173, // 7279
+    0x9172, // 7280
+    0x918B, // 7281
+    0x9189, // 7282
+    0x9182, // 7283
+    0x91A2, // 7284
+    0x91AB, // 7285
+    0x91AF, // 7286
+    0x91AA, // 7287
+    0x91B5, // 7288
+    0x91B4, // 7289
+    0x91BA, // 7290
+    0x91C0, // 7291
+    0x91C1, // 7292
+    0x91C9, // 7293
+    0x91CB, // 7294
+    0x91D0, // 7295
+    0x91D6, // 7296
+    0x91DF, // 7297
+    0x91E1, // 7298
+    0x91DB, // 7299
+    0x91FC, // 7300
+    0x91F5, // 7301
+    0x91F6, // 7302
+    0x921E, // 7303
+    0x91FF, // 7304
+    0x9214, // 7305
+    0x922C, // 7306
+    0x9215, // 7307
+    0x9211, // 7308
+    0x925E, // 7309
+    0x9257, // 7310
+    0x9245, // 7311
+    0x9249, // 7312
+    0x9264, // 7313
+    0x9248, // 7314
+    0x9295, // 7315
+    0x923F, // 7316
+    0x924B, // 7317
+    0x9250, // 7318
+    0x929C, // 7319
+    0x9296, // 7320
+    0x9293, // 7321
+    0x929B, // 7322
+    0x925A, // 7323
+    0x92CF, // 7324
+    0x92B9, // 7325
+    0x92B7, // 7326
+    0x92E9, // 7327
+    0x930F, // 7328
+    0x92FA, // 7329
+    0x9344, // 7330
+    0x932E, // 7331
+    0x9319, // 7332
+    0x9322, // 7333
+    0x931A, // 7334
+    0x9323, // 7335
+    0x933A, // 7336
+    0x9335, // 7337
+    0x933B, // 7338
+    0x935C, // 7339
+    0x9360, // 7340
+    0x937C, // 7341
+    0x936E, // 7342
+    0x9356, // 7343
+    0x93B0, // 7344
+    0x93AC, // 7345
+    0x93AD, // 7346
+    0x9394, // 7347
+    0x93B9, // 7348
+    0x93D6, // 7349
+    0x93D7, // 7350
+    0x93E8, // 7351
+    0x93E5, // 7352
+    0x93D8, // 7353
+    0x93C3, // 7354
+    0x93DD, // 7355
+    0x93D0, // 7356
+    0x93C8, // 7357
+    0x93E4, // 7358
+    0x941A, // 7359
+    0x9414, // 7360
+    0x9413, // 7361
+    0x9403, // 7362
+    0x9407, // 7363
+    0x9410, // 7364
+    0x9436, // 7365
+    0x942B, // 7366
+    0x9435, // 7367
+    0x9421, // 7368
+    0x943A, // 7369
+    0x9441, // 7370
+    0x9452, // 7371
+    0x9444, // 7372
+    0x945B, // 7373
+    0x9460, // 7374
+    0x9462, // 7375
+    0x945E, // 7376
+    0x946A, // 7377
+    0x9229, // 7378
+    0x9470, // 7379
+    0x9475, // 7380
+    0x9477, // 7381
+    0x947D, // 7382
+    0x945A, // 7383
+    0x947C, // 7384
+    0x947E, // 7385
+    0x9481, // 7386
+    0x947F, // 7387
+    0x9582, // 7388
+    0x9587, // 7389
+    0x958A, // 7390
+    0x9594, // 7391
+    0x9596, // 7392
+    0x9598, // 7393
+    0x9599, // 7394
+    0x95A0, // 7395
+    0x95A8, // 7396
+    0x95A7, // 7397
+    0x95AD, // 7398
+    0x95BC, // 7399
+    0x95BB, // 7400
+    0x95B9, // 7401
+    0x95BE, // 7402
+    0x95CA, // 7403
+    0x6FF6, // 7404
+    0x95C3, // 7405
+    0x95CD, // 7406
+    0x95CC, // 7407
+    0x95D5, // 7408
+    0x95D4, // 7409
+    0x95D6, // 7410
+    0x95DC, // 7411
+    0x95E1, // 7412
+    0x95E5, // 7413
+    0x95E2, // 7414
+    0x9621, // 7415
+    0x9628, // 7416
+    0x962E, // 7417
+    0x962F, // 7418
+    0x9642, // 7419
+    0x964C, // 7420
+    0x964F, // 7421
+    0x964B, // 7422
+    0x9677, // 7423
+    0x965C, // 7424
+    0x965E, // 7425
+    0x965D, // 7426
+    0x965F, // 7427
+    0x9666, // 7428
+    0x9672, // 7429
+    0x966C, // 7430
+    0x968D, // 7431
+    0x9698, // 7432
+    0x9695, // 7433
+    0x9697, // 7434
+    0x96AA, // 7435
+    0x96A7, // 7436
+    0x96B1, // 7437
+    0x96B2, // 7438
+    0x96B0, // 7439
+    0x96B4, // 7440
+    0x96B6, // 7441
+    0x96B8, // 7442
+    0x96B9, // 7443
+    0x96CE, // 7444
+    0x96CB, // 7445
+    0x96C9, // 7446
+    0x96CD, // 7447
+    0x894D, // 7448
+    0x96DC, // 7449
+    0x970D, // 7450
+    0x96D5, // 7451
+    0x96F9, // 7452
+    0x9704, // 7453
+    0x9706, // 7454
+    0x9708, // 7455
+    0x9713, // 7456
+    0x970E, // 7457
+    0x9711, // 7458
+    0x970F, // 7459
+    0x9716, // 7460
+    0x9719, // 7461
+    0x9724, // 7462
+    0x972A, // 7463
+    0x9730, // 7464
+    0x9739, // 7465
+    0x973D, // 7466
+    0x973E, // 7467
+    0x9744, // 7468
+    0x9746, // 7469
+    0x9748, // 7470
+    0x9742, // 7471
+    0x9749, // 7472
+    0x975C, // 7473
+    0x9760, // 7474
+    0x9764, // 7475
+    0x9766, // 7476
+    0x9768, // 7477
+    0x52D2, // 7478
+    0x976B, // 7479
+    0x9771, // 7480
+    0x9779, // 7481
+    0x9785, // 7482
+    0x977C, // 7483
+    0x9781, // 7484
+    0x977A, // 7485
+    0x9786, // 7486
+    0x978B, // 7487
+    0x978F, // 7488
+    0x9790, // 7489
+    0x979C, // 7490
+    0x97A8, // 7491
+    0x97A6, // 7492
+    0x97A3, // 7493
+    0x97B3, // 7494
+    0x97B4, // 7495
+    0x97C3, // 7496
+    0x97C6, // 7497
+    0x97C8, // 7498
+    0x97CB, // 7499
+    0x97DC, // 7500
+    0x97ED, // 7501
+    0x9F4F, // 7502
+    0x97F2, // 7503
+    0x7ADF, // 7504
+    0x97F6, // 7505
+    0x97F5, // 7506
+    0x980F, // 7507
+    0x980C, // 7508
+    0x9838, // 7509
+    0x9824, // 7510
+    0x9821, // 7511
+    0x9837, // 7512
+    0x983D, // 7513
+    0x9846, // 7514
+    0x984F, // 7515
+    0x984B, // 7516
+    0x986B, // 7517
+    0x986F, // 7518
+    0x9870, // 7519
+    0x9871, // 7520
+    0x9874, // 7521
+    0x9873, // 7522
+    0x98AA, // 7523
+    0x98AF, // 7524
+    0x98B1, // 7525
+    0x98B6, // 7526
+    0x98C4, // 7527
+    0x98C3, // 7528
+    0x98C6, // 7529
+    0x98E9, // 7530
+    0x98EB, // 7531
+    0x9903, // 7532
+    0x9909, // 7533
+    0x9912, // 7534
+    0x9914, // 7535
+    0x9918, // 7536
+    0x9921, // 7537
+    0x991D, // 7538
+    0x991E, // 7539
+    0x9924, // 7540
+    0x9920, // 7541
+    0x992C, // 7542
+    0x992E, // 7543
+    0x993D, // 7544
+    0x993E, // 7545
+    0x9942, // 7546
+    0x9949, // 7547
+    0x9945, // 7548
+    0x9950, // 7549
+    0x994B, // 7550
+    0x9951, // 7551
+    0x9952, // 7552
+    0x994C, // 7553
+    0x9955, // 7554
+    0x9997, // 7555
+    0x9998, // 7556
+    0x99A5, // 7557
+    0x99AD, // 7558
+    0x99AE, // 7559
+    0x99BC, // 7560
+    0x99DF, // 7561
+    0x99DB, // 7562
+    0x99DD, // 7563
+    0x99D8, // 7564
+    0x99D1, // 7565
+    0x99ED, // 7566
+    0x99EE, // 7567
+    0x99F1, // 7568
+    0x99F2, // 7569
+    0x99FB, // 7570
+    0x99F8, // 7571
+    0x9A01, // 7572
+    0x9A0F, // 7573
+    0x9A05, // 7574
+    0x99E2, // 7575
+    0x9A19, // 7576
+    0x9A2B, // 7577
+    0x9A37, // 7578
+    0x9A45, // 7579
+    0x9A42, // 7580
+    0x9A40, // 7581
+    0x9A43, // 7582
+    0x9A3E, // 7583
+    0x9A55, // 7584
+    0x9A4D, // 7585
+    0x9A5B, // 7586
+    0x9A57, // 7587
+    0x9A5F, // 7588
+    0x9A62, // 7589
+    0x9A65, // 7590
+    0x9A64, // 7591
+    0x9A69, // 7592
+    0x9A6B, // 7593
+    0x9A6A, // 7594
+    0x9AAD, // 7595
+    0x9AB0, // 7596
+    0x9ABC, // 7597
+    0x9AC0, // 7598
+    0x9ACF, // 7599
+    0x9AD1, // 7600
+    0x9AD3, // 7601
+    0x9AD4, // 7602
+    0x9ADE, // 7603
+    0x9ADF, // 7604
+    0x9AE2, // 7605
+    0x9AE3, // 7606
+    0x9AE6, // 7607
+    0x9AEF, // 7608
+    0x9AEB, // 7609
+    0x9AEE, // 7610
+    0x9AF4, // 7611
+    0x9AF1, // 7612
+    0x9AF7, // 7613
+    0x9AFB, // 7614
+    0x9B06, // 7615
+    0x9B18, // 7616
+    0x9B1A, // 7617
+    0x9B1F, // 7618
+    0x9B22, // 7619
+    0x9B23, // 7620
+    0x9B25, // 7621
+    0x9B27, // 7622
+    0x9B28, // 7623
+    0x9B29, // 7624
+    0x9B2A, // 7625
+    0x9B2E, // 7626
+    0x9B2F, // 7627
+    0x9B32, // 7628
+    0x9B44, // 7629
+    0x9B43, // 7630
+    0x9B4F, // 7631
+    0x9B4D, // 7632
+    0x9B4E, // 7633
+    0x9B51, // 7634
+    0x9B58, // 7635
+    0x9B74, // 7636
+    0x9B93, // 7637
+    0x9B83, // 7638
+    0x9B91, // 7639
+    0x9B96, // 7640
+    0x9B97, // 7641
+    0x9B9F, // 7642
+    0x9BA0, // 7643
+    0x9BA8, // 7644
+    0x9BB4, // 7645
+    0x9BC0, // 7646
+    0x9BCA, // 7647
+    0x9BB9, // 7648
+    0x9BC6, // 7649
+    0x9BCF, // 7650
+    0x9BD1, // 7651
+    0x9BD2, // 7652
+    0x9BE3, // 7653
+    0x9BE2, // 7654
+    0x9BE4, // 7655
+    0x9BD4, // 7656
+    0x9BE1, // 7657
+    0x9C3A, // 7658
+    0x9BF2, // 7659
+    0x9BF1, // 7660
+    0x9BF0, // 7661
+    0x9C15, // 7662
+    0x9C14, // 7663
+    0x9C09, // 7664
+    0x9C13, // 7665
+    0x9C0C, // 7666
+    0x9C06, // 7667
+    0x9C08, // 7668
+    0x9C12, // 7669
+    0x9C0A, // 7670
+    0x9C04, // 7671
+    0x9C2E, // 7672
+    0x9C1B, // 7673
+    0x9C25, // 7674
+    0x9C24, // 7675
+    0x9C21, // 7676
+    0x9C30, // 7677
+    0x9C47, // 7678
+    0x9C32, // 7679
+    0x9C46, // 7680
+    0x9C3E, // 7681
+    0x9C5A, // 7682
+    0x9C60, // 7683
+    0x9C67, // 7684
+    0x9C76, // 7685
+    0x9C78, // 7686
+    0x9CE7, // 7687
+    0x9CEC, // 7688
+    0x9CF0, // 7689
+    0x9D09, // 7690
+    0x9D08, // 7691
+    0x9CEB, // 7692
+    0x9D03, // 7693
+    0x9D06, // 7694
+    0x9D2A, // 7695
+    0x9D26, // 7696
+    0x9DAF, // 7697
+    0x9D23, // 7698
+    0x9D1F, // 7699
+    0x9D44, // 7700
+    0x9D15, // 7701
+    0x9D12, // 7702
+    0x9D41, // 7703
+    0x9D3F, // 7704
+    0x9D3E, // 7705
+    0x9D46, // 7706
+    0x9D48, // 7707
+    0x9D5D, // 7708
+    0x9D5E, // 7709
+    0x9D64, // 7710
+    0x9D51, // 7711
+    0x9D50, // 7712
+    0x9D59, // 7713
+    0x9D72, // 7714
+    0x9D89, // 7715
+    0x9D87, // 7716
+    0x9DAB, // 7717
+    0x9D6F, // 7718
+    0x9D7A, // 7719
+    0x9D9A, // 7720
+    0x9DA4, // 7721
+    0x9DA9, // 7722
+    0x9DB2, // 7723
+    0x9DC4, // 7724
+    0x9DC1, // 7725
+    0x9DBB, // 7726
+    0x9DB8, // 7727
+    0x9DBA, // 7728
+    0x9DC6, // 7729
+    0x9DCF, // 7730
+    0x9DC2, // 7731
+    0x9DD9, // 7732
+    0x9DD3, // 7733
+    0x9DF8, // 7734
+    0x9DE6, // 7735
+    0x9DED, // 7736
+    0x9DEF, // 7737
+    0x9DFD, // 7738
+    0x9E1A, // 7739
+    0x9E1B, // 7740
+    0x9E1E, // 7741
+    0x9E75, // 7742
+    0x9E79, // 7743
+    0x9E7D, // 7744
+    0x9E81, // 7745
+    0x9E88, // 7746
+    0x9E8B, // 7747
+    0x9E8C, // 7748
+    0x9E92, // 7749
+    0x9E95, // 7750
+    0x9E91, // 7751
+    0x9E9D, // 7752
+    0x9EA5, // 7753
+    0x9EA9, // 7754
+    0x9EB8, // 7755
+    0x9EAA, // 7756
+    0x9EAD, // 7757
+    0x9761, // 7758
+    0x9ECC, // 7759
+    0x9ECE, // 7760
+    0x9ECF, // 7761
+    0x9ED0, // 7762
+    0x9ED4, // 7763
+    0x9EDC, // 7764
+    0x9EDE, // 7765
+    0x9EDD, // 7766
+    0x9EE0, // 7767
+    0x9EE5, // 7768
+    0x9EE8, // 7769
+    0x9EEF, // 7770
+    0x9EF4, // 7771
+    0x9EF6, // 7772
+    0x9EF7, // 7773
+    0x9EF9, // 7774
+    0x9EFB, // 7775
+    0x9EFC, // 7776
+    0x9EFD, // 7777
+    0x9F07, // 7778
+    0x9F08, // 7779
+    0x76B7, // 7780
+    0x9F15, // 7781
+    0x9F21, // 7782
+    0x9F2C, // 7783
+    0x9F3E, // 7784
+    0x9F4A, // 7785
+    0x9F52, // 7786
+    0x9F54, // 7787
+    0x9F63, // 7788
+    0x9F5F, // 7789
+    0x9F60, // 7790
+    0x9F61, // 7791
+    0x9F66, // 7792
+    0x9F67, // 7793
+    0x9F6C, // 7794
+    0x9F6A, // 7795
+    0x9F77, // 7796
+    0x9F72, // 7797
+    0x9F76, // 7798
+    0x9F95, // 7799
+    0x9F9C, // 7800
+    0x9FA0, // 7801
+    0x582F, // 7802
+    0x69C7, // 7803
+    0x9059, // 7804
+    0x7464, // 7805
+    0x51DC, // 7806
+    0x7199, // 7807
+    0x0000, // 7808
+    0x0000, // 7809
+    0x0000, // 7810
+    0x0000, // 7811
+    0x0000, // 7812
+    0x0000, // 7813
+    0x0000, // 7814
+    0x0000, // 7815
+    0x0000, // 7816
+    0x0000, // 7817
+    0x0000, // 7818
+    0x0000, // 7819
+    0x0000, // 7820
+    0x0000, // 7821
+    0x0000, // 7822
+    0x0000, // 7823
+    0x0000, // 7824
+    0x0000, // 7825
+    0x0000, // 7826
+    0x0000, // 7827
+    0x0000, // 7828
+    0x0000, // 7829
+    0x0000, // 7830
+    0x0000, // 7831
+    0x0000, // 7832
+    0x0000, // 7833
+    0x0000, // 7834
+    0x0000, // 7835
+    0x0000, // 7836
+    0x0000, // 7837
+    0x0000, // 7838
+    0x0000, // 7839
+    0x0000, // 7840
+    0x0000, // 7841
+    0x0000, // 7842
+    0x0000, // 7843
+    0x0000, // 7844
+    0x0000, // 7845
+    0x0000, // 7846
+    0x0000, // 7847
+    0x0000, // 7848
+    0x0000, // 7849
+    0x0000, // 7850
+    0x0000, // 7851
+    0x0000, // 7852
+    0x0000, // 7853
+    0x0000, // 7854
+    0x0000, // 7855
+    0x0000, // 7856
+    0x0000, // 7857
+    0x0000, // 7858
+    0x0000, // 7859
+    0x0000, // 7860
+    0x0000, // 7861
+    0x0000, // 7862
+    0x0000, // 7863
+    0x0000, // 7864
+    0x0000, // 7865
+    0x0000, // 7866
+    0x0000, // 7867
+    0x0000, // 7868
+    0x0000, // 7869
+    0x0000, // 7870
+    0x0000, // 7871
+    0x0000, // 7872
+    0x0000, // 7873
+    0x0000, // 7874
+    0x0000, // 7875
+    0x0000, // 7876
+    0x0000, // 7877
+    0x0000, // 7878
+    0x0000, // 7879
+    0x0000, // 7880
+    0x0000, // 7881
+    0x0000, // 7882
+    0x0000, // 7883
+    0x0000, // 7884
+    0x0000, // 7885
+    0x0000, // 7886
+    0x0000, // 7887
+    0x0000, // 7888
+    0x0000, // 7889
+    0x0000, // 7890
+    0x0000, // 7891
+    0x0000, // 7892
+    0x0000, // 7893
+    0x0000, // 7894
+    0x0000, // 7895
+    0x0000, // 7896
+    0x0000, // 7897
+    0x0000, // 7898
+    0x0000, // 7899
+    0x0000, // 7900
+    0x0000, // 7901
+    0x0000, // 7902
+    0x0000, // 7903
+    0x0000, // 7904
+    0x0000, // 7905
+    0x0000, // 7906
+    0x0000, // 7907
+    0x0000, // 7908
+    0x0000, // 7909
+    0x0000, // 7910
+    0x0000, // 7911
+    0x0000, // 7912
+    0x0000, // 7913
+    0x0000, // 7914
+    0x0000, // 7915
+    0x0000, // 7916
+    0x0000, // 7917
+    0x0000, // 7918
+    0x0000, // 7919
+    0x0000, // 7920
+    0x0000, // 7921
+    0x0000, // 7922
+    0x0000, // 7923
+    0x0000, // 7924
+    0x0000, // 7925
+    0x0000, // 7926
+    0x0000, // 7927
+    0x0000, // 7928
+    0x0000, // 7929
+    0x0000, // 7930
+    0x0000, // 7931
+    0x0000, // 7932
+    0x0000, // 7933
+    0x0000, // 7934
+    0x0000, // 7935
+    0x0000, // 7936
+    0x0000, // 7937
+    0x0000, // 7938
+    0x0000, // 7939
+    0x0000, // 7940
+    0x0000, // 7941
+    0x0000, // 7942
+    0x0000, // 7943
+    0x0000, // 7944
+    0x0000, // 7945
+    0x0000, // 7946
+    0x0000, // 7947
+    0x0000, // 7948
+    0x0000, // 7949
+    0x0000, // 7950
+    0x0000, // 7951
+    0x0000, // 7952
+    0x0000, // 7953
+    0x0000, // 7954
+    0x0000, // 7955
+    0x0000, // 7956
+    0x0000, // 7957
+    0x0000, // 7958
+    0x0000, // 7959
+    0x0000, // 7960
+    0x0000, // 7961
+    0x0000, // 7962
+    0x0000, // 7963
+    0x0000, // 7964
+    0x0000, // 7965
+    0x0000, // 7966
+    0x0000, // 7967
+    0x0000, // 7968
+    0x0000, // 7969
+    0x0000, // 7970
+    0x0000, // 7971
+    0x0000, // 7972
+    0x0000, // 7973
+    0x0000, // 7974
+    0x0000, // 7975
+    0x0000, // 7976
+    0x0000, // 7977
+    0x0000, // 7978
+    0x0000, // 7979
+    0x0000, // 7980
+    0x0000, // 7981
+    0x0000, // 7982
+    0x0000, // 7983
+    0x0000, // 7984
+    0x0000, // 7985
+    0x0000, // 7986
+    0x0000, // 7987
+    0x0000, // 7988
+    0x0000, // 7989
+    0x0000, // 7990
+    0x0000, // 7991
+    0x0000, // 7992
+    0x0000, // 7993
+    0x0000, // 7994
+    0x0000, // 7995
+    0x0000, // 7996
+    0x0000, // 7997
+    0x0000, // 7998
+    0x0000, // 7999
+    0x0000, // 8000
+    0x0000, // 8001
+    0x0000, // 8002
+    0x0000, // 8003
+    0x0000, // 8004
+    0x0000, // 8005
+    0x0000, // 8006
+    0x0000, // 8007
+    0x0000, // 8008
+    0x0000, // 8009
+    0x0000, // 8010
+    0x0000, // 8011
+    0x0000, // 8012
+    0x0000, // 8013
+    0x0000, // 8014
+    0x0000, // 8015
+    0x0000, // 8016
+    0x0000, // 8017
+    0x0000, // 8018
+    0x0000, // 8019
+    0x0000, // 8020
+    0x0000, // 8021
+    0x0000, // 8022
+    0x0000, // 8023
+    0x0000, // 8024
+    0x0000, // 8025
+    0x0000, // 8026
+    0x0000, // 8027
+    0x0000, // 8028
+    0x0000, // 8029
+    0x0000, // 8030
+    0x0000, // 8031
+    0x0000, // 8032
+    0x0000, // 8033
+    0x0000, // 8034
+    0x0000, // 8035
+    0x0000, // 8036
+    0x0000, // 8037
+    0x0000, // 8038
+    0x0000, // 8039
+    0x0000, // 8040
+    0x0000, // 8041
+    0x0000, // 8042
+    0x0000, // 8043
+    0x0000, // 8044
+    0x0000, // 8045
+    0x0000, // 8046
+    0x0000, // 8047
+    0x0000, // 8048
+    0x0000, // 8049
+    0x0000, // 8050
+    0x0000, // 8051
+    0x0000, // 8052
+    0x0000, // 8053
+    0x0000, // 8054
+    0x0000, // 8055
+    0x0000, // 8056
+    0x0000, // 8057
+    0x0000, // 8058
+    0x0000, // 8059
+    0x0000, // 8060
+    0x0000, // 8061
+    0x0000, // 8062
+    0x0000, // 8063
+    0x0000, // 8064
+    0x0000, // 8065
+    0x0000, // 8066
+    0x0000, // 8067
+    0x0000, // 8068
+    0x0000, // 8069
+    0x0000, // 8070
+    0x0000, // 8071
+    0x0000, // 8072
+    0x0000, // 8073
+    0x0000, // 8074
+    0x0000, // 8075
+    0x0000, // 8076
+    0x0000, // 8077
+    0x0000, // 8078
+    0x0000, // 8079
+    0x0000, // 8080
+    0x0000, // 8081
+    0x0000, // 8082
+    0x0000, // 8083
+    0x0000, // 8084
+    0x0000, // 8085
+    0x0000, // 8086
+    0x0000, // 8087
+    0x0000, // 8088
+    0x0000, // 8089
+    0x0000, // 8090
+    0x0000, // 8091
+    0x0000, // 8092
+    0x0000, // 8093
+    0x0000, // 8094
+    0x0000, // 8095
+    0x0000, // 8096
+    0x0000, // 8097
+    0x0000, // 8098
+    0x0000, // 8099
+    0x0000, // 8100
+    0x0000, // 8101
+    0x0000, // 8102
+    0x0000, // 8103
+    0x0000, // 8104
+    0x0000, // 8105
+    0x0000, // 8106
+    0x0000, // 8107
+    0x0000, // 8108
+    0x0000, // 8109
+    0x0000, // 8110
+    0x0000, // 8111
+    0x0000, // 8112
+    0x0000, // 8113
+    0x0000, // 8114
+    0x0000, // 8115
+    0x0000, // 8116
+    0x0000, // 8117
+    0x0000, // 8118
+    0x0000, // 8119
+    0x0000, // 8120
+    0x0000, // 8121
+    0x0000, // 8122
+    0x0000, // 8123
+    0x0000, // 8124
+    0x0000, // 8125
+    0x0000, // 8126
+    0x0000, // 8127
+    0x0000, // 8128
+    0x0000, // 8129
+    0x0000, // 8130
+    0x0000, // 8131
+    0x0000, // 8132
+    0x0000, // 8133
+    0x0000, // 8134
+    0x0000, // 8135
+    0x0000, // 8136
+    0x0000, // 8137
+    0x0000, // 8138
+    0x0000, // 8139
+    0x0000, // 8140
+    0x0000, // 8141
+    0x0000, // 8142
+    0x0000, // 8143
+    0x0000, // 8144
+    0x0000, // 8145
+    0x0000, // 8146
+    0x0000, // 8147
+    0x0000, // 8148
+    0x0000, // 8149
+    0x0000, // 8150
+    0x0000, // 8151
+    0x0000, // 8152
+    0x0000, // 8153
+    0x0000, // 8154
+    0x0000, // 8155
+    0x0000, // 8156
+    0x0000, // 8157
+    0x0000, // 8158
+    0x0000, // 8159
+    0x0000, // 8160
+    0x0000, // 8161
+    0x0000, // 8162
+    0x0000, // 8163
+    0x0000, // 8164
+    0x0000, // 8165
+    0x0000, // 8166
+    0x0000, // 8167
+    0x0000, // 8168
+    0x0000, // 8169
+    0x0000, // 8170
+    0x0000, // 8171
+    0x0000, // 8172
+    0x0000, // 8173
+    0x0000, // 8174
+    0x0000, // 8175
+    0x0000, // 8176
+    0x0000, // 8177
+    0x0000, // 8178
+    0x0000, // 8179
+    0x0000, // 8180
+    0x0000, // 8181
+    0x0000, // 8182
+    0x0000, // 8183
+    0x0000, // 8184
+    0x0000, // 8185
+    0x0000, // 8186
+    0x0000, // 8187
+    0x0000, // 8188
+    0x0000, // 8189
+    0x0000, // 8190
+    0x0000, // 8191
+    0x0000, // 8192
+    0x0000, // 8193
+    0x0000, // 8194
+    0x0000, // 8195
+    0x0000, // 8196
+    0x0000, // 8197
+    0x0000, // 8198
+    0x0000, // 8199
+    0x0000, // 8200
+    0x0000, // 8201
+    0x0000, // 8202
+    0x0000, // 8203
+    0x0000, // 8204
+    0x0000, // 8205
+    0x0000, // 8206
+    0x0000, // 8207
+    0x0000, // 8208
+    0x0000, // 8209
+    0x0000, // 8210
+    0x0000, // 8211
+    0x0000, // 8212
+    0x0000, // 8213
+    0x0000, // 8214
+    0x0000, // 8215
+    0x0000, // 8216
+    0x0000, // 8217
+    0x0000, // 8218
+    0x0000, // 8219
+    0x0000, // 8220
+    0x0000, // 8221
+    0x0000, // 8222
+    0x0000, // 8223
+    0x0000, // 8224
+    0x0000, // 8225
+    0x0000, // 8226
+    0x0000, // 8227
+    0x0000, // 8228
+    0x0000, // 8229
+    0x0000, // 8230
+    0x0000, // 8231
+    0x0000, // 8232
+    0x0000, // 8233
+    0x0000, // 8234
+    0x0000, // 8235
+    0x0000, // 8236
+    0x0000, // 8237
+    0x0000, // 8238
+    0x0000, // 8239
+    0x0000, // 8240
+    0x0000, // 8241
+    0x0000, // 8242
+    0x0000, // 8243
+    0x0000, // 8244
+    0x0000, // 8245
+    0x0000, // 8246
+    0x0000, // 8247
+    0x0000, // 8248
+    0x0000, // 8249
+    0x0000, // 8250
+    0x0000, // 8251
+    0x0000, // 8252
+    0x0000, // 8253
+    0x0000, // 8254
+    0x0000, // 8255
+    0x0000, // 8256
+    0x0000, // 8257
+    0x0000, // 8258
+    0x0000, // 8259
+    0x0000, // 8260
+    0x0000, // 8261
+    0x0000, // 8262
+    0x0000, // 8263
+    0x0000, // 8264
+    0x0000, // 8265
+    0x0000, // 8266
+    0x0000, // 8267
+    0x0000, // 8268
+    0x0000, // 8269
+    0x0000, // 8270
+    0x0000, // 8271
+    0x7E8A, // 8272
+    0x891C, // 8273
+    0x9348, // 8274
+    0x9288, // 8275
+    0x84DC, // 8276
+    0x4FC9, // 8277
+    0x70BB, // 8278
+    0x6631, // 8279
+    0x68C8, // 8280
+    0x92F9, // 8281
+    0x66FB, // 8282
+    0x5F45, // 8283
+    0x4E28, // 8284
+    0x4EE1, // 8285
+    0x4EFC, // 8286
+    0x4F00, // 8287
+    0x4F03, // 8288
+    0x4F39, // 8289
+    0x4F56, // 8290
+    0x4F92, // 8291
+    0x4F8A, // 8292
+    0x4F9A, // 8293
+    0x4F94, // 8294
+    0x4FCD, // 8295
+    0x5040, // 8296
+    0x5022, // 8297
+    0x4FFF, // 8298
+    0x501E, // 8299
+    0x5046, // 8300
+    0x5070, // 8301
+    0x5042, // 8302
+    0x5094, // 8303
+    0x50F4, // 8304
+    0x50D8, // 8305
+    0x514A, // 8306
+    0x5164, // 8307
+    0x519D, // 8308
+    0x51BE, // 8309
+    0x51EC, // 8310
+    0x5215, // 8311
+    0x529C, // 8312
+    0x52A6, // 8313
+    0x52C0, // 8314
+    0x52DB, // 8315
+    0x5300, // 8316
+    0x5307, // 8317
+    0x5324, // 8318
+    0x5372, // 8319
+    0x5393, // 8320
+    0x53B2, // 8321
+    0x53DD, // 8322
+    0xFA0E, // 8323
+    0x549C, // 8324
+    0x548A, // 8325
+    0x54A9, // 8326
+    0x54FF, // 8327
+    0x5586, // 8328
+    0x5759, // 8329
+    0x5765, // 8330
+    0x57AC, // 8331
+    0x57C8, // 8332
+    0x57C7, // 8333
+    0xFA0F, // 8334
+    0xFA10, // 8335
+    0x589E, // 8336
+    0x58B2, // 8337
+    0x590B, // 8338
+    0x5953, // 8339
+    0x595B, // 8340
+    0x595D, // 8341
+    0x5963, // 8342
+    0x59A4, // 8343
+    0x59BA, // 8344
+    0x5B56, // 8345
+    0x5BC0, // 8346
+    0x752F, // 8347
+    0x5BD8, // 8348
+    0x5BEC, // 8349
+    0x5C1E, // 8350
+    0x5CA6, // 8351
+    0x5CBA, // 8352
+    0x5CF5, // 8353
+    0x5D27, // 8354
+    0x5D53, // 8355
+    0xFA11, // 8356
+    0x5D42, // 8357
+    0x5D6D, // 8358
+    0x5DB8, // 8359
+    0x5DB9, // 8360
+    0x5DD0, // 8361
+    0x5F21, // 8362
+    0x5F34, // 8363
+    0x5F67, // 8364
+    0x5FB7, // 8365
+    0x5FDE, // 8366
+    0x605D, // 8367
+    0x6085, // 8368
+    0x608A, // 8369
+    0x60DE, // 8370
+    0x60D5, // 8371
+    0x6120, // 8372
+    0x60F2, // 8373
+    0x6111, // 8374
+    0x6137, // 8375
+    0x6130, // 8376
+    0x6198, // 8377
+    0x6213, // 8378
+    0x62A6, // 8379
+    0x63F5, // 8380
+    0x6460, // 8381
+    0x649D, // 8382
+    0x64CE, // 8383
+    0x654E, // 8384
+    0x6600, // 8385
+    0x6615, // 8386
+    0x663B, // 8387
+    0x6609, // 8388
+    0x662E, // 8389
+    0x661E, // 8390
+    0x6624, // 8391
+    0x6665, // 8392
+    0x6657, // 8393
+    0x6659, // 8394
+    0xFA12, // 8395
+    0x6673, // 8396
+    0x6699, // 8397
+    0x66A0, // 8398
+    0x66B2, // 8399
+    0x66BF, // 8400
+    0x66FA, // 8401
+    0x670E, // 8402
+    0xF929, // 8403
+    0x6766, // 8404
+    0x67BB, // 8405
+    0x6852, // 8406
+    0x67C0, // 8407
+    0x6801, // 8408
+    0x6844, // 8409
+    0x68CF, // 8410
+    0xFA13, // 8411
+    0x6968, // 8412
+    0xFA14, // 8413
+    0x6998, // 8414
+    0x69E2, // 8415
+    0x6A30, // 8416
+    0x6A6B, // 8417
+    0x6A46, // 8418
+    0x6A73, // 8419
+    0x6A7E, // 8420
+    0x6AE2, // 8421
+    0x6AE4, // 8422
+    0x6BD6, // 8423
+    0x6C3F, // 8424
+    0x6C5C, // 8425
+    0x6C86, // 8426
+    0x6C6F, // 8427
+    0x6CDA, // 8428
+    0x6D04, // 8429
+    0x6D87, // 8430
+    0x6D6F, // 8431
+    0x6D96, // 8432
+    0x6DAC, // 8433
+    0x6DCF, // 8434
+    0x6DF8, // 8435
+    0x6DF2, // 8436
+    0x6DFC, // 8437
+    0x6E39, // 8438
+    0x6E5C, // 8439
+    0x6E27, // 8440
+    0x6E3C, // 8441
+    0x6EBF, // 8442
+    0x6F88, // 8443
+    0x6FB5, // 8444
+    0x6FF5, // 8445
+    0x7005, // 8446
+    0x7007, // 8447
+    0x7028, // 8448
+    0x7085, // 8449
+    0x70AB, // 8450
+    0x710F, // 8451
+    0x7104, // 8452
+    0x715C, // 8453
+    0x7146, // 8454
+    0x7147, // 8455
+    0xFA15, // 8456
+    0x71C1, // 8457
+    0x71FE, // 8458
+    0x72B1, // 8459
+    0x72BE, // 8460
+    0x7324, // 8461
+    0xFA16, // 8462
+    0x7377, // 8463
+    0x73BD, // 8464
+    0x73C9, // 8465
+    0x73D6, // 8466
+    0x73E3, // 8467
+    0x73D2, // 8468
+    0x7407, // 8469
+    0x73F5, // 8470
+    0x7426, // 8471
+    0x742A, // 8472
+    0x7429, // 8473
+    0x742E, // 8474
+    0x7462, // 8475
+    0x7489, // 8476
+    0x749F, // 8477
+    0x7501, // 8478
+    0x756F, // 8479
+    0x7682, // 8480
+    0x769C, // 8481
+    0x769E, // 8482
+    0x769B, // 8483
+    0x76A6, // 8484
+    0xFA17, // 8485
+    0x7746, // 8486
+    0x52AF, // 8487
+    0x7821, // 8488
+    0x784E, // 8489
+    0x7864, // 8490
+    0x787A, // 8491
+    0x7930, // 8492
+    0xFA18, // 8493
+    0xFA19, // 8494
+    0xFA1A, // 8495
+    0x7994, // 8496
+    0xFA1B, // 8497
+    0x799B, // 8498
+    0x7AD1, // 8499
+    0x7AE7, // 8500
+    0xFA1C, // 8501
+    0x7AEB, // 8502
+    0x7B9E, // 8503
+    0xFA1D, // 8504
+    0x7D48, // 8505
+    0x7D5C, // 8506
+    0x7DB7, // 8507
+    0x7DA0, // 8508
+    0x7DD6, // 8509
+    0x7E52, // 8510
+    0x7F47, // 8511
+    0x7FA1, // 8512
+    0xFA1E, // 8513
+    0x8301, // 8514
+    0x8362, // 8515
+    0x837F, // 8516
+    0x83C7, // 8517
+    0x83F6, // 8518
+    0x8448, // 8519
+    0x84B4, // 8520
+    0x8553, // 8521
+    0x8559, // 8522
+    0x856B, // 8523
+    0xFA1F, // 8524
+    0x85B0, // 8525
+    0xFA20, // 8526
+    0xFA21, // 8527
+    0x8807, // 8528
+    0x88F5, // 8529
+    0x8A12, // 8530
+    0x8A37, // 8531
+    0x8A79, // 8532
+    0x8AA7, // 8533
+    0x8ABE, // 8534
+    0x8ADF, // 8535
+    0xFA22, // 8536
+    0x8AF6, // 8537
+    0x8B53, // 8538
+    0x8B7F, // 8539
+    0x8CF0, // 8540
+    0x8CF4, // 8541
+    0x8D12, // 8542
+    0x8D76, // 8543
+    0xFA23, // 8544
+    0x8ECF, // 8545
+    0xFA24, // 8546
+    0xFA25, // 8547
+    0x9067, // 8548
+    0x90DE, // 8549
+    0xFA26, // 8550
+    0x9115, // 8551
+    0x9127, // 8552
+    0x91DA, // 8553
+    0x91D7, // 8554
+    0x91DE, // 8555
+    0x91ED, // 8556
+    0x91EE, // 8557
+    0x91E4, // 8558
+    0x91E5, // 8559
+    0x9206, // 8560
+    0x9210, // 8561
+    0x920A, // 8562
+    0x923A, // 8563
+    0x9240, // 8564
+    0x923C, // 8565
+    0x924E, // 8566
+    0x9259, // 8567
+    0x9251, // 8568
+    0x9239, // 8569
+    0x9267, // 8570
+    0x92A7, // 8571
+    0x9277, // 8572
+    0x9278, // 8573
+    0x92E7, // 8574
+    0x92D7, // 8575
+    0x92D9, // 8576
+    0x92D0, // 8577
+    0xFA27, // 8578
+    0x92D5, // 8579
+    0x92E0, // 8580
+    0x92D3, // 8581
+    0x9325, // 8582
+    0x9321, // 8583
+    0x92FB, // 8584
+    0xFA28, // 8585
+    0x931E, // 8586
+    0x92FF, // 8587
+    0x931D, // 8588
+    0x9302, // 8589
+    0x9370, // 8590
+    0x9357, // 8591
+    0x93A4, // 8592
+    0x93C6, // 8593
+    0x93DE, // 8594
+    0x93F8, // 8595
+    0x9431, // 8596
+    0x9445, // 8597
+    0x9448, // 8598
+    0x9592, // 8599
+    0xF9DC, // 8600
+    0xFA29, // 8601
+    0x969D, // 8602
+    0x96AF, // 8603
+    0x9733, // 8604
+    0x973B, // 8605
+    0x9743, // 8606
+    0x974D, // 8607
+    0x974F, // 8608
+    0x9751, // 8609
+    0x9755, // 8610
+    0x9857, // 8611
+    0x9865, // 8612
+    0xFA2A, // 8613
+    0xFA2B, // 8614
+    0x9927, // 8615
+    0xFA2C, // 8616
+    0x999E, // 8617
+    0x9A4E, // 8618
+    0x9AD9, // 8619
+    0x9ADC, // 8620
+    0x9B75, // 8621
+    0x9B72, // 8622
+    0x9B8F, // 8623
+    0x9BB1, // 8624
+    0x9BBB, // 8625
+    0x9C00, // 8626
+    0x9D70, // 8627
+    0x9D6B, // 8628
+    0xFA2D, // 8629
+    0x9E19, // 8630
+    0x9ED1, // 8631
+    0x0000, // 8632
+    0x0000, // 8633
+    0x2170, // 8634
+    0x2171, // 8635
+    0x2172, // 8636
+    0x2173, // 8637
+    0x2174, // 8638
+    0x2175, // 8639
+    0x2176, // 8640
+    0x2177, // 8641
+    0x2178, // 8642
+    0x2179, // 8643
+    0xFFE2, // 8644
+    0xFFE4, // 8645
+    0xFF07, // 8646
+    0xFF02, // 8647
+    0x0000, // 8648
+    0x0000, // 8649
+    0x0000, // 8650
+    0x0000, // 8651
+    0x0000, // 8652
+    0x0000, // 8653
+    0x0000, // 8654
+    0x0000, // 8655
+    0x0000, // 8656
+    0x0000, // 8657
+    0x0000, // 8658
+    0x0000, // 8659
+    0x0000, // 8660
+    0x0000, // 8661
+    0x0000, // 8662
+    0x0000, // 8663
+    0x0000, // 8664
+    0x0000, // 8665
+    0x0000, // 8666
+    0x0000, // 8667
+    0x0000, // 8668
+    0x0000, // 8669
+    0x0000, // 8670
+    0x0000, // 8671
+    0x0000, // 8672
+    0x0000, // 8673
+    0x0000, // 8674
+    0x0000, // 8675
+    0x0000, // 8676
+    0x0000, // 8677
+    0x0000, // 8678
+    0x0000, // 8679
+    0x0000, // 8680
+    0x0000, // 8681
+    0x0000, // 8682
+    0x0000, // 8683
+    0x0000, // 8684
+    0x0000, // 8685
+    0x0000, // 8686
+    0x0000, // 8687
+    0x0000, // 8688
+    0x0000, // 8689
+    0x0000, // 8690
+    0x0000, // 8691
+    0x0000, // 8692
+    0x0000, // 8693
+    0x0000, // 8694
+    0x0000, // 8695
+    0x0000, // 8696
+    0x0000, // 8697
+    0x0000, // 8698
+    0x0000, // 8699
+    0x0000, // 8700
+    0x0000, // 8701
+    0x0000, // 8702
+    0x0000, // 8703
+    0x0000, // 8704
+    0x0000, // 8705
+    0x0000, // 8706
+    0x0000, // 8707
+    0x0000, // 8708
+    0x0000, // 8709
+    0x0000, // 8710
+    0x0000, // 8711
+    0x0000, // 8712
+    0x0000, // 8713
+    0x0000, // 8714
+    0x0000, // 8715
+    0x0000, // 8716
+    0x0000, // 8717
+    0x0000, // 8718
+    0x0000, // 8719
+    0x0000, // 8720
+    0x0000, // 8721
+    0x0000, // 8722
+    0x0000, // 8723
+    0x0000, // 8724
+    0x0000, // 8725
+    0x0000, // 8726
+    0x0000, // 8727
+    0x0000, // 8728
+    0x0000, // 8729
+    0x0000, // 8730
+    0x0000, // 8731
+    0x0000, // 8732
+    0x0000, // 8733
+    0x0000, // 8734
+    0x0000, // 8735
+    0x0000, // 8736
+    0x0000, // 8737
+    0x0000, // 8738
+    0x0000, // 8739
+    0x0000, // 8740
+    0x0000, // 8741
+    0x0000, // 8742
+    0x0000, // 8743
+    0x0000, // 8744
+    0x0000, // 8745
+    0x0000, // 8746
+    0x0000, // 8747
+    0x0000, // 8748
+    0x0000, // 8749
+    0x0000, // 8750
+    0x0000, // 8751
+    0x0000, // 8752
+    0x0000, // 8753
+    0x0000, // 8754
+    0x0000, // 8755
+    0x0000, // 8756
+    0x0000, // 8757
+    0x0000, // 8758
+    0x0000, // 8759
+    0x0000, // 8760
+    0x0000, // 8761
+    0x0000, // 8762
+    0x0000, // 8763
+    0x0000, // 8764
+    0x0000, // 8765
+    0x0000, // 8766
+    0x0000, // 8767
+    0x0000, // 8768
+    0x0000, // 8769
+    0x0000, // 8770
+    0x0000, // 8771
+    0x0000, // 8772
+    0x0000, // 8773
+    0x0000, // 8774
+    0x0000, // 8775
+    0x0000, // 8776
+    0x0000, // 8777
+    0x0000, // 8778
+    0x0000, // 8779
+    0x0000, // 8780
+    0x0000, // 8781
+    0x0000, // 8782
+    0x0000, // 8783
+    0x0000, // 8784
+    0x0000, // 8785
+    0x0000, // 8786
+    0x0000, // 8787
+    0x0000, // 8788
+    0x0000, // 8789
+    0x0000, // 8790
+    0x0000, // 8791
+    0x0000, // 8792
+    0x0000, // 8793
+    0x0000, // 8794
+    0x0000, // 8795
+    0x0000, // 8796
+    0x0000, // 8797
+    0x0000, // 8798
+    0x0000, // 8799
+    0x0000, // 8800
+    0x0000, // 8801
+    0x0000, // 8802
+    0x0000, // 8803
+    0x0000, // 8804
+    0x0000, // 8805
+    0x0000, // 8806
+    0x0000, // 8807
+    0x0000, // 8808
+    0x0000, // 8809
+    0x0000, // 8810
+    0x0000, // 8811
+    0x0000, // 8812
+    0x0000, // 8813
+    0x0000, // 8814
+    0x0000, // 8815
+    0x0000, // 8816
+    0x0000, // 8817
+    0x0000, // 8818
+    0x0000, // 8819
+    0x0000, // 8820
+    0x0000, // 8821
+    0x0000, // 8822
+    0x0000, // 8823
+    0x0000, // 8824
+    0x0000, // 8825
+    0x0000, // 8826
+    0x0000, // 8827
+    0x0000, // 8828
+    0x0000, // 8829
+    0x0000, // 8830
+    0x0000, // 8831
+    0x0000, // 8832
+    0x0000, // 8833
+    0x0000, // 8834
+    0x0000, // 8835
+    0x0000, // 8836
+    0x0000, // 8837
+    0x0000, // 8838
+    0x0000, // 8839
+    0x0000, // 8840
+    0x0000, // 8841
+    0x0000, // 8842
+    0x0000, // 8843
+    0x0000, // 8844
+    0x0000, // 8845
+    0x0000, // 8846
+    0x0000, // 8847
+    0x0000, // 8848
+    0x0000, // 8849
+    0x0000, // 8850
+    0x0000, // 8851
+    0x0000, // 8852
+    0x0000, // 8853
+    0x0000, // 8854
+    0x0000, // 8855
+    0x0000, // 8856
+    0x0000, // 8857
+    0x0000, // 8858
+    0x0000, // 8859
+    0x0000, // 8860
+    0x0000, // 8861
+    0x0000, // 8862
+    0x0000, // 8863
+    0x0000, // 8864
+    0x0000, // 8865
+    0x0000, // 8866
+    0x0000, // 8867
+    0x0000, // 8868
+    0x0000, // 8869
+    0x0000, // 8870
+    0x0000, // 8871
+    0x0000, // 8872
+    0x0000, // 8873
+    0x0000, // 8874
+    0x0000, // 8875
+    0x0000, // 8876
+    0x0000, // 8877
+    0x0000, // 8878
+    0x0000, // 8879
+    0x0000, // 8880
+    0x0000, // 8881
+    0x0000, // 8882
+    0x0000, // 8883
+    0x0000, // 8884
+    0x0000, // 8885
+    0x0000, // 8886
+    0x0000, // 8887
+    0x0000, // 8888
+    0x0000, // 8889
+    0x0000, // 8890
+    0x0000, // 8891
+    0x0000, // 8892
+    0x0000, // 8893
+    0x0000, // 8894
+    0x0000, // 8895
+    0x0000, // 8896
+    0x0000, // 8897
+    0x0000, // 8898
+    0x0000, // 8899
+    0x0000, // 8900
+    0x0000, // 8901
+    0x0000, // 8902
+    0x0000, // 8903
+    0x0000, // 8904
+    0x0000, // 8905
+    0x0000, // 8906
+    0x0000, // 8907
+    0x0000, // 8908
+    0x0000, // 8909
+    0x0000, // 8910
+    0x0000, // 8911
+    0x0000, // 8912
+    0x0000, // 8913
+    0x0000, // 8914
+    0x0000, // 8915
+    0x0000, // 8916
+    0x0000, // 8917
+    0x0000, // 8918
+    0x0000, // 8919
+    0x0000, // 8920
+    0x0000, // 8921
+    0x0000, // 8922
+    0x0000, // 8923
+    0x0000, // 8924
+    0x0000, // 8925
+    0x0000, // 8926
+    0x0000, // 8927
+    0x0000, // 8928
+    0x0000, // 8929
+    0x0000, // 8930
+    0x0000, // 8931
+    0x0000, // 8932
+    0x0000, // 8933
+    0x0000, // 8934
+    0x0000, // 8935
+    0x0000, // 8936
+    0x0000, // 8937
+    0x0000, // 8938
+    0x0000, // 8939
+    0x0000, // 8940
+    0x0000, // 8941
+    0x0000, // 8942
+    0x0000, // 8943
+    0x0000, // 8944
+    0x0000, // 8945
+    0x0000, // 8946
+    0x0000, // 8947
+    0x0000, // 8948
+    0x0000, // 8949
+    0x0000, // 8950
+    0x0000, // 8951
+    0x0000, // 8952
+    0x0000, // 8953
+    0x0000, // 8954
+    0x0000, // 8955
+    0x0000, // 8956
+    0x0000, // 8957
+    0x0000, // 8958
+    0x0000, // 8959
+    0x0000, // 8960
+    0x0000, // 8961
+    0x0000, // 8962
+    0x0000, // 8963
+    0x0000, // 8964
+    0x0000, // 8965
+    0x0000, // 8966
+    0x0000, // 8967
+    0x0000, // 8968
+    0x0000, // 8969
+    0x0000, // 8970
+    0x0000, // 8971
+    0x0000, // 8972
+    0x0000, // 8973
+    0x0000, // 8974
+    0x0000, // 8975
+    0x0000, // 8976
+    0x0000, // 8977
+    0x0000, // 8978
+    0x0000, // 8979
+    0x0000, // 8980
+    0x0000, // 8981
+    0x0000, // 8982
+    0x0000, // 8983
+    0x0000, // 8984
+    0x0000, // 8985
+    0x0000, // 8986
+    0x0000, // 8987
+    0x0000, // 8988
+    0x0000, // 8989
+    0x0000, // 8990
+    0x0000, // 8991
+    0x0000, // 8992
+    0x0000, // 8993
+    0x0000, // 8994
+    0x0000, // 8995
+    0x0000, // 8996
+    0x0000, // 8997
+    0x0000, // 8998
+    0x0000, // 8999
+    0x0000, // 9000
+    0x0000, // 9001
+    0x0000, // 9002
+    0x0000, // 9003
+    0x0000, // 9004
+    0x0000, // 9005
+    0x0000, // 9006
+    0x0000, // 9007
+    0x0000, // 9008
+    0x0000, // 9009
+    0x0000, // 9010
+    0x0000, // 9011
+    0x0000, // 9012
+    0x0000, // 9013
+    0x0000, // 9014
+    0x0000, // 9015
+    0x0000, // 9016
+    0x0000, // 9017
+    0x0000, // 9018
+    0x0000, // 9019
+    0x0000, // 9020
+    0x0000, // 9021
+    0x0000, // 9022
+    0x0000, // 9023
+    0x0000, // 9024
+    0x0000, // 9025
+    0x0000, // 9026
+    0x0000, // 9027
+    0x0000, // 9028
+    0x0000, // 9029
+    0x0000, // 9030
+    0x0000, // 9031
+    0x0000, // 9032
+    0x0000, // 9033
+    0x0000, // 9034
+    0x0000, // 9035
+    0x0000, // 9036
+    0x0000, // 9037
+    0x0000, // 9038
+    0x0000, // 9039
+    0x0000, // 9040
+    0x0000, // 9041
+    0x0000, // 9042
+    0x0000, // 9043
+    0x0000, // 9044
+    0x0000, // 9045
+    0x0000, // 9046
+    0x0000, // 9047
+    0x0000, // 9048
+    0x0000, // 9049
+    0x0000, // 9050
+    0x0000, // 9051
+    0x0000, // 9052
+    0x0000, // 9053
+    0x0000, // 9054
+    0x0000, // 9055
+    0x0000, // 9056
+    0x0000, // 9057
+    0x0000, // 9058
+    0x0000, // 9059
+    0x0000, // 9060
+    0x0000, // 9061
+    0x0000, // 9062
+    0x0000, // 9063
+    0x0000, // 9064
+    0x0000, // 9065
+    0x0000, // 9066
+    0x0000, // 9067
+    0x0000, // 9068
+    0x0000, // 9069
+    0x0000, // 9070
+    0x0000, // 9071
+    0x0000, // 9072
+    0x0000, // 9073
+    0x0000, // 9074
+    0x0000, // 9075
+    0x0000, // 9076
+    0x0000, // 9077
+    0x0000, // 9078
+    0x0000, // 9079
+    0x0000, // 9080
+    0x0000, // 9081
+    0x0000, // 9082
+    0x0000, // 9083
+    0x0000, // 9084
+    0x0000, // 9085
+    0x0000, // 9086
+    0x0000, // 9087
+    0x0000, // 9088
+    0x0000, // 9089
+    0x0000, // 9090
+    0x0000, // 9091
+    0x0000, // 9092
+    0x0000, // 9093
+    0x0000, // 9094
+    0x0000, // 9095
+    0x0000, // 9096
+    0x0000, // 9097
+    0x0000, // 9098
+    0x0000, // 9099
+    0x0000, // 9100
+    0x0000, // 9101
+    0x0000, // 9102
+    0x0000, // 9103
+    0x0000, // 9104
+    0x0000, // 9105
+    0x0000, // 9106
+    0x0000, // 9107
+    0x0000, // 9108
+    0x0000, // 9109
+    0x0000, // 9110
+    0x0000, // 9111
+    0x0000, // 9112
+    0x0000, // 9113
+    0x0000, // 9114
+    0x0000, // 9115
+    0x0000, // 9116
+    0x0000, // 9117
+    0x0000, // 9118
+    0x0000, // 9119
+    0x0000, // 9120
+    0x0000, // 9121
+    0x0000, // 9122
+    0x0000, // 9123
+    0x0000, // 9124
+    0x0000, // 9125
+    0x0000, // 9126
+    0x0000, // 9127
+    0x0000, // 9128
+    0x0000, // 9129
+    0x0000, // 9130
+    0x0000, // 9131
+    0x0000, // 9132
+    0x0000, // 9133
+    0x0000, // 9134
+    0x0000, // 9135
+    0x0000, // 9136
+    0x0000, // 9137
+    0x0000, // 9138
+    0x0000, // 9139
+    0x0000, // 9140
+    0x0000, // 9141
+    0x0000, // 9142
+    0x0000, // 9143
+    0x0000, // 9144
+    0x0000, // 9145
+    0x0000, // 9146
+    0x0000, // 9147
+    0x0000, // 9148
+    0x0000, // 9149
+    0x0000, // 9150
+    0x0000, // 9151
+    0x0000, // 9152
+    0x0000, // 9153
+    0x0000, // 9154
+    0x0000, // 9155
+    0x0000, // 9156
+    0x0000, // 9157
+    0x0000, // 9158
+    0x0000, // 9159
+    0x0000, // 9160
+    0x0000, // 9161
+    0x0000, // 9162
+    0x0000, // 9163
+    0x0000, // 9164
+    0x0000, // 9165
+    0x0000, // 9166
+    0x0000, // 9167
+    0x0000, // 9168
+    0x0000, // 9169
+    0x0000, // 9170
+    0x0000, // 9171
+    0x0000, // 9172
+    0x0000, // 9173
+    0x0000, // 9174
+    0x0000, // 9175
+    0x0000, // 9176
+    0x0000, // 9177
+    0x0000, // 9178
+    0x0000, // 9179
+    0x0000, // 9180
+    0x0000, // 9181
+    0x0000, // 9182
+    0x0000, // 9183
+    0x0000, // 9184
+    0x0000, // 9185
+    0x0000, // 9186
+    0x0000, // 9187
+    0x0000, // 9188
+    0x0000, // 9189
+    0x0000, // 9190
+    0x0000, // 9191
+    0x0000, // 9192
+    0x0000, // 9193
+    0x0000, // 9194
+    0x0000, // 9195
+    0x0000, // 9196
+    0x0000, // 9197
+    0x0000, // 9198
+    0x0000, // 9199
+    0x0000, // 9200
+    0x0000, // 9201
+    0x0000, // 9202
+    0x0000, // 9203
+    0x0000, // 9204
+    0x0000, // 9205
+    0x0000, // 9206
+    0x0000, // 9207
+    0x0000, // 9208
+    0x0000, // 9209
+    0x0000, // 9210
+    0x0000, // 9211
+    0x0000, // 9212
+    0x0000, // 9213
+    0x0000, // 9214
+    0x0000, // 9215
+    0x0000, // 9216
+    0x0000, // 9217
+    0x0000, // 9218
+    0x0000, // 9219
+    0x0000, // 9220
+    0x0000, // 9221
+    0x0000, // 9222
+    0x0000, // 9223
+    0x0000, // 9224
+    0x0000, // 9225
+    0x0000, // 9226
+    0x0000, // 9227
+    0x0000, // 9228
+    0x0000, // 9229
+    0x0000, // 9230
+    0x0000, // 9231
+    0x0000, // 9232
+    0x0000, // 9233
+    0x0000, // 9234
+    0x0000, // 9235
+    0x0000, // 9236
+    0x0000, // 9237
+    0x0000, // 9238
+    0x0000, // 9239
+    0x0000, // 9240
+    0x0000, // 9241
+    0x0000, // 9242
+    0x0000, // 9243
+    0x0000, // 9244
+    0x0000, // 9245
+    0x0000, // 9246
+    0x0000, // 9247
+    0x0000, // 9248
+    0x0000, // 9249
+    0x0000, // 9250
+    0x0000, // 9251
+    0x0000, // 9252
+    0x0000, // 9253
+    0x0000, // 9254
+    0x0000, // 9255
+    0x0000, // 9256
+    0x0000, // 9257
+    0x0000, // 9258
+    0x0000, // 9259
+    0x0000, // 9260
+    0x0000, // 9261
+    0x0000, // 9262
+    0x0000, // 9263
+    0x0000, // 9264
+    0x0000, // 9265
+    0x0000, // 9266
+    0x0000, // 9267
+    0x0000, // 9268
+    0x0000, // 9269
+    0x0000, // 9270
+    0x0000, // 9271
+    0x0000, // 9272
+    0x0000, // 9273
+    0x0000, // 9274
+    0x0000, // 9275
+    0x0000, // 9276
+    0x0000, // 9277
+    0x0000, // 9278
+    0x0000, // 9279
+    0x0000, // 9280
+    0x0000, // 9281
+    0x0000, // 9282
+    0x0000, // 9283
+    0x0000, // 9284
+    0x0000, // 9285
+    0x0000, // 9286
+    0x0000, // 9287
+    0x0000, // 9288
+    0x0000, // 9289
+    0x0000, // 9290
+    0x0000, // 9291
+    0x0000, // 9292
+    0x0000, // 9293
+    0x0000, // 9294
+    0x0000, // 9295
+    0x0000, // 9296
+    0x0000, // 9297
+    0x0000, // 9298
+    0x0000, // 9299
+    0x0000, // 9300
+    0x0000, // 9301
+    0x0000, // 9302
+    0x0000, // 9303
+    0x0000, // 9304
+    0x0000, // 9305
+    0x0000, // 9306
+    0x0000, // 9307
+    0x0000, // 9308
+    0x0000, // 9309
+    0x0000, // 9310
+    0x0000, // 9311
+    0x0000, // 9312
+    0x0000, // 9313
+    0x0000, // 9314
+    0x0000, // 9315
+    0x0000, // 9316
+    0x0000, // 9317
+    0x0000, // 9318
+    0x0000, // 9319
+    0x0000, // 9320
+    0x0000, // 9321
+    0x0000, // 9322
+    0x0000, // 9323
+    0x0000, // 9324
+    0x0000, // 9325
+    0x0000, // 9326
+    0x0000, // 9327
+    0x0000, // 9328
+    0x0000, // 9329
+    0x0000, // 9330
+    0x0000, // 9331
+    0x0000, // 9332
+    0x0000, // 9333
+    0x0000, // 9334
+    0x0000, // 9335
+    0x0000, // 9336
+    0x0000, // 9337
+    0x0000, // 9338
+    0x0000, // 9339
+    0x0000, // 9340
+    0x0000, // 9341
+    0x0000, // 9342
+    0x0000, // 9343
+    0x0000, // 9344
+    0x0000, // 9345
+    0x0000, // 9346
+    0x0000, // 9347
+    0x0000, // 9348
+    0x0000, // 9349
+    0x0000, // 9350
+    0x0000, // 9351
+    0x0000, // 9352
+    0x0000, // 9353
+    0x0000, // 9354
+    0x0000, // 9355
+    0x0000, // 9356
+    0x0000, // 9357
+    0x0000, // 9358
+    0x0000, // 9359
+    0x0000, // 9360
+    0x0000, // 9361
+    0x0000, // 9362
+    0x0000, // 9363
+    0x0000, // 9364
+    0x0000, // 9365
+    0x0000, // 9366
+    0x0000, // 9367
+    0x0000, // 9368
+    0x0000, // 9369
+    0x0000, // 9370
+    0x0000, // 9371
+    0x0000, // 9372
+    0x0000, // 9373
+    0x0000, // 9374
+    0x0000, // 9375
+    0x0000, // 9376
+    0x0000, // 9377
+    0x0000, // 9378
+    0x0000, // 9379
+    0x0000, // 9380
+    0x0000, // 9381
+    0x0000, // 9382
+    0x0000, // 9383
+    0x0000, // 9384
+    0x0000, // 9385
+    0x0000, // 9386
+    0x0000, // 9387
+    0x0000, // 9388
+    0x0000, // 9389
+    0x0000, // 9390
+    0x0000, // 9391
+    0x0000, // 9392
+    0x0000, // 9393
+    0x0000, // 9394
+    0x0000, // 9395
+    0x0000, // 9396
+    0x0000, // 9397
+    0x0000, // 9398
+    0x0000, // 9399
+    0x0000, // 9400
+    0x0000, // 9401
+    0x0000, // 9402
+    0x0000, // 9403
+    0x0000, // 9404
+    0x0000, // 9405
+    0x0000, // 9406
+    0x0000, // 9407
+    0x0000, // 9408
+    0x0000, // 9409
+    0x0000, // 9410
+    0x0000, // 9411
+    0x0000, // 9412
+    0x0000, // 9413
+    0x0000, // 9414
+    0x0000, // 9415
+    0x0000, // 9416
+    0x0000, // 9417
+    0x0000, // 9418
+    0x0000, // 9419
+    0x0000, // 9420
+    0x0000, // 9421
+    0x0000, // 9422
+    0x0000, // 9423
+    0x0000, // 9424
+    0x0000, // 9425
+    0x0000, // 9426
+    0x0000, // 9427
+    0x0000, // 9428
+    0x0000, // 9429
+    0x0000, // 9430
+    0x0000, // 9431
+    0x0000, // 9432
+    0x0000, // 9433
+    0x0000, // 9434
+    0x0000, // 9435
+    0x0000, // 9436
+    0x0000, // 9437
+    0x0000, // 9438
+    0x0000, // 9439
+    0x0000, // 9440
+    0x0000, // 9441
+    0x0000, // 9442
+    0x0000, // 9443
+    0x0000, // 9444
+    0x0000, // 9445
+    0x0000, // 9446
+    0x0000, // 9447
+    0x0000, // 9448
+    0x0000, // 9449
+    0x0000, // 9450
+    0x0000, // 9451
+    0x0000, // 9452
+    0x0000, // 9453
+    0x0000, // 9454
+    0x0000, // 9455
+    0x0000, // 9456
+    0x0000, // 9457
+    0x0000, // 9458
+    0x0000, // 9459
+    0x0000, // 9460
+    0x0000, // 9461
+    0x0000, // 9462
+    0x0000, // 9463
+    0x0000, // 9464
+    0x0000, // 9465
+    0x0000, // 9466
+    0x0000, // 9467
+    0x0000, // 9468
+    0x0000, // 9469
+    0x0000, // 9470
+    0x0000, // 9471
+    0x0000, // 9472
+    0x0000, // 9473
+    0x0000, // 9474
+    0x0000, // 9475
+    0x0000, // 9476
+    0x0000, // 9477
+    0x0000, // 9478
+    0x0000, // 9479
+    0x0000, // 9480
+    0x0000, // 9481
+    0x0000, // 9482
+    0x0000, // 9483
+    0x0000, // 9484
+    0x0000, // 9485
+    0x0000, // 9486
+    0x0000, // 9487
+    0x0000, // 9488
+    0x0000, // 9489
+    0x0000, // 9490
+    0x0000, // 9491
+    0x0000, // 9492
+    0x0000, // 9493
+    0x0000, // 9494
+    0x0000, // 9495
+    0x0000, // 9496
+    0x0000, // 9497
+    0x0000, // 9498
+    0x0000, // 9499
+    0x0000, // 9500
+    0x0000, // 9501
+    0x0000, // 9502
+    0x0000, // 9503
+    0x0000, // 9504
+    0x0000, // 9505
+    0x0000, // 9506
+    0x0000, // 9507
+    0x0000, // 9508
+    0x0000, // 9509
+    0x0000, // 9510
+    0x0000, // 9511
+    0x0000, // 9512
+    0x0000, // 9513
+    0x0000, // 9514
+    0x0000, // 9515
+    0x0000, // 9516
+    0x0000, // 9517
+    0x0000, // 9518
+    0x0000, // 9519
+    0x0000, // 9520
+    0x0000, // 9521
+    0x0000, // 9522
+    0x0000, // 9523
+    0x0000, // 9524
+    0x0000, // 9525
+    0x0000, // 9526
+    0x0000, // 9527
+    0x0000, // 9528
+    0x0000, // 9529
+    0x0000, // 9530
+    0x0000, // 9531
+    0x0000, // 9532
+    0x0000, // 9533
+    0x0000, // 9534
+    0x0000, // 9535
+    0x0000, // 9536
+    0x0000, // 9537
+    0x0000, // 9538
+    0x0000, // 9539
+    0x0000, // 9540
+    0x0000, // 9541
+    0x0000, // 9542
+    0x0000, // 9543
+    0x0000, // 9544
+    0x0000, // 9545
+    0x0000, // 9546
+    0x0000, // 9547
+    0x0000, // 9548
+    0x0000, // 9549
+    0x0000, // 9550
+    0x0000, // 9551
+    0x0000, // 9552
+    0x0000, // 9553
+    0x0000, // 9554
+    0x0000, // 9555
+    0x0000, // 9556
+    0x0000, // 9557
+    0x0000, // 9558
+    0x0000, // 9559
+    0x0000, // 9560
+    0x0000, // 9561
+    0x0000, // 9562
+    0x0000, // 9563
+    0x0000, // 9564
+    0x0000, // 9565
+    0x0000, // 9566
+    0x0000, // 9567
+    0x0000, // 9568
+    0x0000, // 9569
+    0x0000, // 9570
+    0x0000, // 9571
+    0x0000, // 9572
+    0x0000, // 9573
+    0x0000, // 9574
+    0x0000, // 9575
+    0x0000, // 9576
+    0x0000, // 9577
+    0x0000, // 9578
+    0x0000, // 9579
+    0x0000, // 9580
+    0x0000, // 9581
+    0x0000, // 9582
+    0x0000, // 9583
+    0x0000, // 9584
+    0x0000, // 9585
+    0x0000, // 9586
+    0x0000, // 9587
+    0x0000, // 9588
+    0x0000, // 9589
+    0x0000, // 9590
+    0x0000, // 9591
+    0x0000, // 9592
+    0x0000, // 9593
+    0x0000, // 9594
+    0x0000, // 9595
+    0x0000, // 9596
+    0x0000, // 9597
+    0x0000, // 9598
+    0x0000, // 9599
+    0x0000, // 9600
+    0x0000, // 9601
+    0x0000, // 9602
+    0x0000, // 9603
+    0x0000, // 9604
+    0x0000, // 9605
+    0x0000, // 9606
+    0x0000, // 9607
+    0x0000, // 9608
+    0x0000, // 9609
+    0x0000, // 9610
+    0x0000, // 9611
+    0x0000, // 9612
+    0x0000, // 9613
+    0x0000, // 9614
+    0x0000, // 9615
+    0x0000, // 9616
+    0x0000, // 9617
+    0x0000, // 9618
+    0x0000, // 9619
+    0x0000, // 9620
+    0x0000, // 9621
+    0x0000, // 9622
+    0x0000, // 9623
+    0x0000, // 9624
+    0x0000, // 9625
+    0x0000, // 9626
+    0x0000, // 9627
+    0x0000, // 9628
+    0x0000, // 9629
+    0x0000, // 9630
+    0x0000, // 9631
+    0x0000, // 9632
+    0x0000, // 9633
+    0x0000, // 9634
+    0x0000, // 9635
+    0x0000, // 9636
+    0x0000, // 9637
+    0x0000, // 9638
+    0x0000, // 9639
+    0x0000, // 9640
+    0x0000, // 9641
+    0x0000, // 9642
+    0x0000, // 9643
+    0x0000, // 9644
+    0x0000, // 9645
+    0x0000, // 9646
+    0x0000, // 9647
+    0x0000, // 9648
+    0x0000, // 9649
+    0x0000, // 9650
+    0x0000, // 9651
+    0x0000, // 9652
+    0x0000, // 9653
+    0x0000, // 9654
+    0x0000, // 9655
+    0x0000, // 9656
+    0x0000, // 9657
+    0x0000, // 9658
+    0x0000, // 9659
+    0x0000, // 9660
+    0x0000, // 9661
+    0x0000, // 9662
+    0x0000, // 9663
+    0x0000, // 9664
+    0x0000, // 9665
+    0x0000, // 9666
+    0x0000, // 9667
+    0x0000, // 9668
+    0x0000, // 9669
+    0x0000, // 9670
+    0x0000, // 9671
+    0x0000, // 9672
+    0x0000, // 9673
+    0x0000, // 9674
+    0x0000, // 9675
+    0x0000, // 9676
+    0x0000, // 9677
+    0x0000, // 9678
+    0x0000, // 9679
+    0x0000, // 9680
+    0x0000, // 9681
+    0x0000, // 9682
+    0x0000, // 9683
+    0x0000, // 9684
+    0x0000, // 9685
+    0x0000, // 9686
+    0x0000, // 9687
+    0x0000, // 9688
+    0x0000, // 9689
+    0x0000, // 9690
+    0x0000, // 9691
+    0x0000, // 9692
+    0x0000, // 9693
+    0x0000, // 9694
+    0x0000, // 9695
+    0x0000, // 9696
+    0x0000, // 9697
+    0x0000, // 9698
+    0x0000, // 9699
+    0x0000, // 9700
+    0x0000, // 9701
+    0x0000, // 9702
+    0x0000, // 9703
+    0x0000, // 9704
+    0x0000, // 9705
+    0x0000, // 9706
+    0x0000, // 9707
+    0x0000, // 9708
+    0x0000, // 9709
+    0x0000, // 9710
+    0x0000, // 9711
+    0x0000, // 9712
+    0x0000, // 9713
+    0x0000, // 9714
+    0x0000, // 9715
+    0x0000, // 9716
+    0x0000, // 9717
+    0x0000, // 9718
+    0x0000, // 9719
+    0x0000, // 9720
+    0x0000, // 9721
+    0x0000, // 9722
+    0x0000, // 9723
+    0x0000, // 9724
+    0x0000, // 9725
+    0x0000, // 9726
+    0x0000, // 9727
+    0x0000, // 9728
+    0x0000, // 9729
+    0x0000, // 9730
+    0x0000, // 9731
+    0x0000, // 9732
+    0x0000, // 9733
+    0x0000, // 9734
+    0x0000, // 9735
+    0x0000, // 9736
+    0x0000, // 9737
+    0x0000, // 9738
+    0x0000, // 9739
+    0x0000, // 9740
+    0x0000, // 9741
+    0x0000, // 9742
+    0x0000, // 9743
+    0x0000, // 9744
+    0x0000, // 9745
+    0x0000, // 9746
+    0x0000, // 9747
+    0x0000, // 9748
+    0x0000, // 9749
+    0x0000, // 9750
+    0x0000, // 9751
+    0x0000, // 9752
+    0x0000, // 9753
+    0x0000, // 9754
+    0x0000, // 9755
+    0x0000, // 9756
+    0x0000, // 9757
+    0x0000, // 9758
+    0x0000, // 9759
+    0x0000, // 9760
+    0x0000, // 9761
+    0x0000, // 9762
+    0x0000, // 9763
+    0x0000, // 9764
+    0x0000, // 9765
+    0x0000, // 9766
+    0x0000, // 9767
+    0x0000, // 9768
+    0x0000, // 9769
+    0x0000, // 9770
+    0x0000, // 9771
+    0x0000, // 9772
+    0x0000, // 9773
+    0x0000, // 9774
+    0x0000, // 9775
+    0x0000, // 9776
+    0x0000, // 9777
+    0x0000, // 9778
+    0x0000, // 9779
+    0x0000, // 9780
+    0x0000, // 9781
+    0x0000, // 9782
+    0x0000, // 9783
+    0x0000, // 9784
+    0x0000, // 9785
+    0x0000, // 9786
+    0x0000, // 9787
+    0x0000, // 9788
+    0x0000, // 9789
+    0x0000, // 9790
+    0x0000, // 9791
+    0x0000, // 9792
+    0x0000, // 9793
+    0x0000, // 9794
+    0x0000, // 9795
+    0x0000, // 9796
+    0x0000, // 9797
+    0x0000, // 9798
+    0x0000, // 9799
+    0x0000, // 9800
+    0x0000, // 9801
+    0x0000, // 9802
+    0x0000, // 9803
+    0x0000, // 9804
+    0x0000, // 9805
+    0x0000, // 9806
+    0x0000, // 9807
+    0x0000, // 9808
+    0x0000, // 9809
+    0x0000, // 9810
+    0x0000, // 9811
+    0x0000, // 9812
+    0x0000, // 9813
+    0x0000, // 9814
+    0x0000, // 9815
+    0x0000, // 9816
+    0x0000, // 9817
+    0x0000, // 9818
+    0x0000, // 9819
+    0x0000, // 9820
+    0x0000, // 9821
+    0x0000, // 9822
+    0x0000, // 9823
+    0x0000, // 9824
+    0x0000, // 9825
+    0x0000, // 9826
+    0x0000, // 9827
+    0x0000, // 9828
+    0x0000, // 9829
+    0x0000, // 9830
+    0x0000, // 9831
+    0x0000, // 9832
+    0x0000, // 9833
+    0x0000, // 9834
+    0x0000, // 9835
+    0x0000, // 9836
+    0x0000, // 9837
+    0x0000, // 9838
+    0x0000, // 9839
+    0x0000, // 9840
+    0x0000, // 9841
+    0x0000, // 9842
+    0x0000, // 9843
+    0x0000, // 9844
+    0x0000, // 9845
+    0x0000, // 9846
+    0x0000, // 9847
+    0x0000, // 9848
+    0x0000, // 9849
+    0x0000, // 9850
+    0x0000, // 9851
+    0x0000, // 9852
+    0x0000, // 9853
+    0x0000, // 9854
+    0x0000, // 9855
+    0x0000, // 9856
+    0x0000, // 9857
+    0x0000, // 9858
+    0x0000, // 9859
+    0x0000, // 9860
+    0x0000, // 9861
+    0x0000, // 9862
+    0x0000, // 9863
+    0x0000, // 9864
+    0x0000, // 9865
+    0x0000, // 9866
+    0x0000, // 9867
+    0x0000, // 9868
+    0x0000, // 9869
+    0x0000, // 9870
+    0x0000, // 9871
+    0x0000, // 9872
+    0x0000, // 9873
+    0x0000, // 9874
+    0x0000, // 9875
+    0x0000, // 9876
+    0x0000, // 9877
+    0x0000, // 9878
+    0x0000, // 9879
+    0x0000, // 9880
+    0x0000, // 9881
+    0x0000, // 9882
+    0x0000, // 9883
+    0x0000, // 9884
+    0x0000, // 9885
+    0x0000, // 9886
+    0x0000, // 9887
+    0x0000, // 9888
+    0x0000, // 9889
+    0x0000, // 9890
+    0x0000, // 9891
+    0x0000, // 9892
+    0x0000, // 9893
+    0x0000, // 9894
+    0x0000, // 9895
+    0x0000, // 9896
+    0x0000, // 9897
+    0x0000, // 9898
+    0x0000, // 9899
+    0x0000, // 9900
+    0x0000, // 9901
+    0x0000, // 9902
+    0x0000, // 9903
+    0x0000, // 9904
+    0x0000, // 9905
+    0x0000, // 9906
+    0x0000, // 9907
+    0x0000, // 9908
+    0x0000, // 9909
+    0x0000, // 9910
+    0x0000, // 9911
+    0x0000, // 9912
+    0x0000, // 9913
+    0x0000, // 9914
+    0x0000, // 9915
+    0x0000, // 9916
+    0x0000, // 9917
+    0x0000, // 9918
+    0x0000, // 9919
+    0x0000, // 9920
+    0x0000, // 9921
+    0x0000, // 9922
+    0x0000, // 9923
+    0x0000, // 9924
+    0x0000, // 9925
+    0x0000, // 9926
+    0x0000, // 9927
+    0x0000, // 9928
+    0x0000, // 9929
+    0x0000, // 9930
+    0x0000, // 9931
+    0x0000, // 9932
+    0x0000, // 9933
+    0x0000, // 9934
+    0x0000, // 9935
+    0x0000, // 9936
+    0x0000, // 9937
+    0x0000, // 9938
+    0x0000, // 9939
+    0x0000, // 9940
+    0x0000, // 9941
+    0x0000, // 9942
+    0x0000, // 9943
+    0x0000, // 9944
+    0x0000, // 9945
+    0x0000, // 9946
+    0x0000, // 9947
+    0x0000, // 9948
+    0x0000, // 9949
+    0x0000, // 9950
+    0x0000, // 9951
+    0x0000, // 9952
+    0x0000, // 9953
+    0x0000, // 9954
+    0x0000, // 9955
+    0x0000, // 9956
+    0x0000, // 9957
+    0x0000, // 9958
+    0x0000, // 9959
+    0x0000, // 9960
+    0x0000, // 9961
+    0x0000, // 9962
+    0x0000, // 9963
+    0x0000, // 9964
+    0x0000, // 9965
+    0x0000, // 9966
+    0x0000, // 9967
+    0x0000, // 9968
+    0x0000, // 9969
+    0x0000, // 9970
+    0x0000, // 9971
+    0x0000, // 9972
+    0x0000, // 9973
+    0x0000, // 9974
+    0x0000, // 9975
+    0x0000, // 9976
+    0x0000, // 9977
+    0x0000, // 9978
+    0x0000, // 9979
+    0x0000, // 9980
+    0x0000, // 9981
+    0x0000, // 9982
+    0x0000, // 9983
+    0x0000, // 9984
+    0x0000, // 9985
+    0x0000, // 9986
+    0x0000, // 9987
+    0x0000, // 9988
+    0x0000, // 9989
+    0x0000, // 9990
+    0x0000, // 9991
+    0x0000, // 9992
+    0x0000, // 9993
+    0x0000, // 9994
+    0x0000, // 9995
+    0x0000, // 9996
+    0x0000, // 9997
+    0x0000, // 9998
+    0x0000, // 9999
+    0x0000, // 10000
+    0x0000, // 10001
+    0x0000, // 10002
+    0x0000, // 10003
+    0x0000, // 10004
+    0x0000, // 10005
+    0x0000, // 10006
+    0x0000, // 10007
+    0x0000, // 10008
+    0x0000, // 10009
+    0x0000, // 10010
+    0x0000, // 10011
+    0x0000, // 10012
+    0x0000, // 10013
+    0x0000, // 10014
+    0x0000, // 10015
+    0x0000, // 10016
+    0x0000, // 10017
+    0x0000, // 10018
+    0x0000, // 10019
+    0x0000, // 10020
+    0x0000, // 10021
+    0x0000, // 10022
+    0x0000, // 10023
+    0x0000, // 10024
+    0x0000, // 10025
+    0x0000, // 10026
+    0x0000, // 10027
+    0x0000, // 10028
+    0x0000, // 10029
+    0x0000, // 10030
+    0x0000, // 10031
+    0x0000, // 10032
+    0x0000, // 10033
+    0x0000, // 10034
+    0x0000, // 10035
+    0x0000, // 10036
+    0x0000, // 10037
+    0x0000, // 10038
+    0x0000, // 10039
+    0x0000, // 10040
+    0x0000, // 10041
+    0x0000, // 10042
+    0x0000, // 10043
+    0x0000, // 10044
+    0x0000, // 10045
+    0x0000, // 10046
+    0x0000, // 10047
+    0x0000, // 10048
+    0x0000, // 10049
+    0x0000, // 10050
+    0x0000, // 10051
+    0x0000, // 10052
+    0x0000, // 10053
+    0x0000, // 10054
+    0x0000, // 10055
+    0x0000, // 10056
+    0x0000, // 10057
+    0x0000, // 10058
+    0x0000, // 10059
+    0x0000, // 10060
+    0x0000, // 10061
+    0x0000, // 10062
+    0x0000, // 10063
+    0x0000, // 10064
+    0x0000, // 10065
+    0x0000, // 10066
+    0x0000, // 10067
+    0x0000, // 10068
+    0x0000, // 10069
+    0x0000, // 10070
+    0x0000, // 10071
+    0x0000, // 10072
+    0x0000, // 10073
+    0x0000, // 10074
+    0x0000, // 10075
+    0x0000, // 10076
+    0x0000, // 10077
+    0x0000, // 10078
+    0x0000, // 10079
+    0x0000, // 10080
+    0x0000, // 10081
+    0x0000, // 10082
+    0x0000, // 10083
+    0x0000, // 10084
+    0x0000, // 10085
+    0x0000, // 10086
+    0x0000, // 10087
+    0x0000, // 10088
+    0x0000, // 10089
+    0x0000, // 10090
+    0x0000, // 10091
+    0x0000, // 10092
+    0x0000, // 10093
+    0x0000, // 10094
+    0x0000, // 10095
+    0x0000, // 10096
+    0x0000, // 10097
+    0x0000, // 10098
+    0x0000, // 10099
+    0x0000, // 10100
+    0x0000, // 10101
+    0x0000, // 10102
+    0x0000, // 10103
+    0x0000, // 10104
+    0x0000, // 10105
+    0x0000, // 10106
+    0x0000, // 10107
+    0x0000, // 10108
+    0x0000, // 10109
+    0x0000, // 10110
+    0x0000, // 10111
+    0x0000, // 10112
+    0x0000, // 10113
+    0x0000, // 10114
+    0x0000, // 10115
+    0x0000, // 10116
+    0x0000, // 10117
+    0x0000, // 10118
+    0x0000, // 10119
+    0x0000, // 10120
+    0x0000, // 10121
+    0x0000, // 10122
+    0x0000, // 10123
+    0x0000, // 10124
+    0x0000, // 10125
+    0x0000, // 10126
+    0x0000, // 10127
+    0x0000, // 10128
+    0x0000, // 10129
+    0x0000, // 10130
+    0x0000, // 10131
+    0x0000, // 10132
+    0x0000, // 10133
+    0x0000, // 10134
+    0x0000, // 10135
+    0x0000, // 10136
+    0x0000, // 10137
+    0x0000, // 10138
+    0x0000, // 10139
+    0x0000, // 10140
+    0x0000, // 10141
+    0x0000, // 10142
+    0x0000, // 10143
+    0x0000, // 10144
+    0x0000, // 10145
+    0x0000, // 10146
+    0x0000, // 10147
+    0x0000, // 10148
+    0x0000, // 10149
+    0x0000, // 10150
+    0x0000, // 10151
+    0x0000, // 10152
+    0x0000, // 10153
+    0x0000, // 10154
+    0x0000, // 10155
+    0x0000, // 10156
+    0x0000, // 10157
+    0x0000, // 10158
+    0x0000, // 10159
+    0x0000, // 10160
+    0x0000, // 10161
+    0x0000, // 10162
+    0x0000, // 10163
+    0x0000, // 10164
+    0x0000, // 10165
+    0x0000, // 10166
+    0x0000, // 10167
+    0x0000, // 10168
+    0x0000, // 10169
+    0x0000, // 10170
+    0x0000, // 10171
+    0x0000, // 10172
+    0x0000, // 10173
+    0x0000, // 10174
+    0x0000, // 10175
+    0x0000, // 10176
+    0x0000, // 10177
+    0x0000, // 10178
+    0x0000, // 10179
+    0x0000, // 10180
+    0x0000, // 10181
+    0x0000, // 10182
+    0x0000, // 10183
+    0x0000, // 10184
+    0x0000, // 10185
+    0x0000, // 10186
+    0x0000, // 10187
+    0x0000, // 10188
+    0x0000, // 10189
+    0x0000, // 10190
+    0x0000, // 10191
+    0x0000, // 10192
+    0x0000, // 10193
+    0x0000, // 10194
+    0x0000, // 10195
+    0x0000, // 10196
+    0x0000, // 10197
+    0x0000, // 10198
+    0x0000, // 10199
+    0x0000, // 10200
+    0x0000, // 10201
+    0x0000, // 10202
+    0x0000, // 10203
+    0x0000, // 10204
+    0x0000, // 10205
+    0x0000, // 10206
+    0x0000, // 10207
+    0x0000, // 10208
+    0x0000, // 10209
+    0x0000, // 10210
+    0x0000, // 10211
+    0x0000, // 10212
+    0x0000, // 10213
+    0x0000, // 10214
+    0x0000, // 10215
+    0x0000, // 10216
+    0x0000, // 10217
+    0x0000, // 10218
+    0x0000, // 10219
+    0x0000, // 10220
+    0x0000, // 10221
+    0x0000, // 10222
+    0x0000, // 10223
+    0x0000, // 10224
+    0x0000, // 10225
+    0x0000, // 10226
+    0x0000, // 10227
+    0x0000, // 10228
+    0x0000, // 10229
+    0x0000, // 10230
+    0x0000, // 10231
+    0x0000, // 10232
+    0x0000, // 10233
+    0x0000, // 10234
+    0x0000, // 10235
+    0x0000, // 10236
+    0x0000, // 10237
+    0x0000, // 10238
+    0x0000, // 10239
+    0x0000, // 10240
+    0x0000, // 10241
+    0x0000, // 10242
+    0x0000, // 10243
+    0x0000, // 10244
+    0x0000, // 10245
+    0x0000, // 10246
+    0x0000, // 10247
+    0x0000, // 10248
+    0x0000, // 10249
+    0x0000, // 10250
+    0x0000, // 10251
+    0x0000, // 10252
+    0x0000, // 10253
+    0x0000, // 10254
+    0x0000, // 10255
+    0x0000, // 10256
+    0x0000, // 10257
+    0x0000, // 10258
+    0x0000, // 10259
+    0x0000, // 10260
+    0x0000, // 10261
+    0x0000, // 10262
+    0x0000, // 10263
+    0x0000, // 10264
+    0x0000, // 10265
+    0x0000, // 10266
+    0x0000, // 10267
+    0x0000, // 10268
+    0x0000, // 10269
+    0x0000, // 10270
+    0x0000, // 10271
+    0x0000, // 10272
+    0x0000, // 10273
+    0x0000, // 10274
+    0x0000, // 10275
+    0x0000, // 10276
+    0x0000, // 10277
+    0x0000, // 10278
+    0x0000, // 10279
+    0x0000, // 10280
+    0x0000, // 10281
+    0x0000, // 10282
+    0x0000, // 10283
+    0x0000, // 10284
+    0x0000, // 10285
+    0x0000, // 10286
+    0x0000, // 10287
+    0x0000, // 10288
+    0x0000, // 10289
+    0x0000, // 10290
+    0x0000, // 10291
+    0x0000, // 10292
+    0x0000, // 10293
+    0x0000, // 10294
+    0x0000, // 10295
+    0x0000, // 10296
+    0x0000, // 10297
+    0x0000, // 10298
+    0x0000, // 10299
+    0x0000, // 10300
+    0x0000, // 10301
+    0x0000, // 10302
+    0x0000, // 10303
+    0x0000, // 10304
+    0x0000, // 10305
+    0x0000, // 10306
+    0x0000, // 10307
+    0x0000, // 10308
+    0x0000, // 10309
+    0x0000, // 10310
+    0x0000, // 10311
+    0x0000, // 10312
+    0x0000, // 10313
+    0x0000, // 10314
+    0x0000, // 10315
+    0x0000, // 10316
+    0x0000, // 10317
+    0x0000, // 10318
+    0x0000, // 10319
+    0x0000, // 10320
+    0x0000, // 10321
+    0x0000, // 10322
+    0x0000, // 10323
+    0x0000, // 10324
+    0x0000, // 10325
+    0x0000, // 10326
+    0x0000, // 10327
+    0x0000, // 10328
+    0x0000, // 10329
+    0x0000, // 10330
+    0x0000, // 10331
+    0x0000, // 10332
+    0x0000, // 10333
+    0x0000, // 10334
+    0x0000, // 10335
+    0x0000, // 10336
+    0x0000, // 10337
+    0x0000, // 10338
+    0x0000, // 10339
+    0x0000, // 10340
+    0x0000, // 10341
+    0x0000, // 10342
+    0x0000, // 10343
+    0x0000, // 10344
+    0x0000, // 10345
+    0x0000, // 10346
+    0x0000, // 10347
+    0x0000, // 10348
+    0x0000, // 10349
+    0x0000, // 10350
+    0x0000, // 10351
+    0x0000, // 10352
+    0x0000, // 10353
+    0x0000, // 10354
+    0x0000, // 10355
+    0x0000, // 10356
+    0x0000, // 10357
+    0x0000, // 10358
+    0x0000, // 10359
+    0x0000, // 10360
+    0x0000, // 10361
+    0x0000, // 10362
+    0x0000, // 10363
+    0x0000, // 10364
+    0x0000, // 10365
+    0x0000, // 10366
+    0x0000, // 10367
+    0x0000, // 10368
+    0x0000, // 10369
+    0x0000, // 10370
+    0x0000, // 10371
+    0x0000, // 10372
+    0x0000, // 10373
+    0x0000, // 10374
+    0x0000, // 10375
+    0x0000, // 10376
+    0x0000, // 10377
+    0x0000, // 10378
+    0x0000, // 10379
+    0x0000, // 10380
+    0x0000, // 10381
+    0x0000, // 10382
+    0x0000, // 10383
+    0x0000, // 10384
+    0x0000, // 10385
+    0x0000, // 10386
+    0x0000, // 10387
+    0x0000, // 10388
+    0x0000, // 10389
+    0x0000, // 10390
+    0x0000, // 10391
+    0x0000, // 10392
+    0x0000, // 10393
+    0x0000, // 10394
+    0x0000, // 10395
+    0x0000, // 10396
+    0x0000, // 10397
+    0x0000, // 10398
+    0x0000, // 10399
+    0x0000, // 10400
+    0x0000, // 10401
+    0x0000, // 10402
+    0x0000, // 10403
+    0x0000, // 10404
+    0x0000, // 10405
+    0x0000, // 10406
+    0x0000, // 10407
+    0x0000, // 10408
+    0x0000, // 10409
+    0x0000, // 10410
+    0x0000, // 10411
+    0x0000, // 10412
+    0x0000, // 10413
+    0x0000, // 10414
+    0x0000, // 10415
+    0x0000, // 10416
+    0x0000, // 10417
+    0x0000, // 10418
+    0x0000, // 10419
+    0x0000, // 10420
+    0x0000, // 10421
+    0x0000, // 10422
+    0x0000, // 10423
+    0x0000, // 10424
+    0x0000, // 10425
+    0x0000, // 10426
+    0x0000, // 10427
+    0x0000, // 10428
+    0x0000, // 10429
+    0x0000, // 10430
+    0x0000, // 10431
+    0x0000, // 10432
+    0x0000, // 10433
+    0x0000, // 10434
+    0x0000, // 10435
+    0x0000, // 10436
+    0x0000, // 10437
+    0x0000, // 10438
+    0x0000, // 10439
+    0x0000, // 10440
+    0x0000, // 10441
+    0x0000, // 10442
+    0x0000, // 10443
+    0x0000, // 10444
+    0x0000, // 10445
+    0x0000, // 10446
+    0x0000, // 10447
+    0x0000, // 10448
+    0x0000, // 10449
+    0x0000, // 10450
+    0x0000, // 10451
+    0x0000, // 10452
+    0x0000, // 10453
+    0x0000, // 10454
+    0x0000, // 10455
+    0x0000, // 10456
+    0x0000, // 10457
+    0x0000, // 10458
+    0x0000, // 10459
+    0x0000, // 10460
+    0x0000, // 10461
+    0x0000, // 10462
+    0x0000, // 10463
+    0x0000, // 10464
+    0x0000, // 10465
+    0x0000, // 10466
+    0x0000, // 10467
+    0x0000, // 10468
+    0x0000, // 10469
+    0x0000, // 10470
+    0x0000, // 10471
+    0x0000, // 10472
+    0x0000, // 10473
+    0x0000, // 10474
+    0x0000, // 10475
+    0x0000, // 10476
+    0x0000, // 10477
+    0x0000, // 10478
+    0x0000, // 10479
+    0x0000, // 10480
+    0x0000, // 10481
+    0x0000, // 10482
+    0x0000, // 10483
+    0x0000, // 10484
+    0x0000, // 10485
+    0x0000, // 10486
+    0x0000, // 10487
+    0x0000, // 10488
+    0x0000, // 10489
+    0x0000, // 10490
+    0x0000, // 10491
+    0x0000, // 10492
+    0x0000, // 10493
+    0x0000, // 10494
+    0x0000, // 10495
+    0x0000, // 10496
+    0x0000, // 10497
+    0x0000, // 10498
+    0x0000, // 10499
+    0x0000, // 10500
+    0x0000, // 10501
+    0x0000, // 10502
+    0x0000, // 10503
+    0x0000, // 10504
+    0x0000, // 10505
+    0x0000, // 10506
+    0x0000, // 10507
+    0x0000, // 10508
+    0x0000, // 10509
+    0x0000, // 10510
+    0x0000, // 10511
+    0x0000, // 10512
+    0x0000, // 10513
+    0x0000, // 10514
+    0x0000, // 10515
+    0x0000, // 10516
+    0x0000, // 10517
+    0x0000, // 10518
+    0x0000, // 10519
+    0x0000, // 10520
+    0x0000, // 10521
+    0x0000, // 10522
+    0x0000, // 10523
+    0x0000, // 10524
+    0x0000, // 10525
+    0x0000, // 10526
+    0x0000, // 10527
+    0x0000, // 10528
+    0x0000, // 10529
+    0x0000, // 10530
+    0x0000, // 10531
+    0x0000, // 10532
+    0x0000, // 10533
+    0x0000, // 10534
+    0x0000, // 10535
+    0x0000, // 10536
+    0x0000, // 10537
+    0x0000, // 10538
+    0x0000, // 10539
+    0x0000, // 10540
+    0x0000, // 10541
+    0x0000, // 10542
+    0x0000, // 10543
+    0x0000, // 10544
+    0x0000, // 10545
+    0x0000, // 10546
+    0x0000, // 10547
+    0x0000, // 10548
+    0x0000, // 10549
+    0x0000, // 10550
+    0x0000, // 10551
+    0x0000, // 10552
+    0x0000, // 10553
+    0x0000, // 10554
+    0x0000, // 10555
+    0x0000, // 10556
+    0x0000, // 10557
+    0x0000, // 10558
+    0x0000, // 10559
+    0x0000, // 10560
+    0x0000, // 10561
+    0x0000, // 10562
+    0x0000, // 10563
+    0x0000, // 10564
+    0x0000, // 10565
+    0x0000, // 10566
+    0x0000, // 10567
+    0x0000, // 10568
+    0x0000, // 10569
+    0x0000, // 10570
+    0x0000, // 10571
+    0x0000, // 10572
+    0x0000, // 10573
+    0x0000, // 10574
+    0x0000, // 10575
+    0x0000, // 10576
+    0x0000, // 10577
+    0x0000, // 10578
+    0x0000, // 10579
+    0x0000, // 10580
+    0x0000, // 10581
+    0x0000, // 10582
+    0x0000, // 10583
+    0x0000, // 10584
+    0x0000, // 10585
+    0x0000, // 10586
+    0x0000, // 10587
+    0x0000, // 10588
+    0x0000, // 10589
+    0x0000, // 10590
+    0x0000, // 10591
+    0x0000, // 10592
+    0x0000, // 10593
+    0x0000, // 10594
+    0x0000, // 10595
+    0x0000, // 10596
+    0x0000, // 10597
+    0x0000, // 10598
+    0x0000, // 10599
+    0x0000, // 10600
+    0x0000, // 10601
+    0x0000, // 10602
+    0x0000, // 10603
+    0x0000, // 10604
+    0x0000, // 10605
+    0x0000, // 10606
+    0x0000, // 10607
+    0x0000, // 10608
+    0x0000, // 10609
+    0x0000, // 10610
+    0x0000, // 10611
+    0x0000, // 10612
+    0x0000, // 10613
+    0x0000, // 10614
+    0x0000, // 10615
+    0x0000, // 10616
+    0x0000, // 10617
+    0x0000, // 10618
+    0x0000, // 10619
+    0x0000, // 10620
+    0x0000, // 10621
+    0x0000, // 10622
+    0x0000, // 10623
+    0x0000, // 10624
+    0x0000, // 10625
+    0x0000, // 10626
+    0x0000, // 10627
+    0x0000, // 10628
+    0x0000, // 10629
+    0x0000, // 10630
+    0x0000, // 10631
+    0x0000, // 10632
+    0x0000, // 10633
+    0x0000, // 10634
+    0x0000, // 10635
+    0x0000, // 10636
+    0x0000, // 10637
+    0x0000, // 10638
+    0x0000, // 10639
+    0x0000, // 10640
+    0x0000, // 10641
+    0x0000, // 10642
+    0x0000, // 10643
+    0x0000, // 10644
+    0x0000, // 10645
+    0x0000, // 10646
+    0x0000, // 10647
+    0x0000, // 10648
+    0x0000, // 10649
+    0x0000, // 10650
+    0x0000, // 10651
+    0x0000, // 10652
+    0x0000, // 10653
+    0x0000, // 10654
+    0x0000, // 10655
+    0x0000, // 10656
+    0x0000, // 10657
+    0x0000, // 10658
+    0x0000, // 10659
+    0x0000, // 10660
+    0x0000, // 10661
+    0x0000, // 10662
+    0x0000, // 10663
+    0x0000, // 10664
+    0x0000, // 10665
+    0x0000, // 10666
+    0x0000, // 10667
+    0x0000, // 10668
+    0x0000, // 10669
+    0x0000, // 10670
+    0x0000, // 10671
+    0x0000, // 10672
+    0x0000, // 10673
+    0x0000, // 10674
+    0x0000, // 10675
+    0x0000, // 10676
+    0x0000, // 10677
+    0x0000, // 10678
+    0x0000, // 10679
+    0x0000, // 10680
+    0x0000, // 10681
+    0x0000, // 10682
+    0x0000, // 10683
+    0x0000, // 10684
+    0x0000, // 10685
+    0x0000, // 10686
+    0x0000, // 10687
+    0x0000, // 10688
+    0x0000, // 10689
+    0x0000, // 10690
+    0x0000, // 10691
+    0x0000, // 10692
+    0x0000, // 10693
+    0x0000, // 10694
+    0x0000, // 10695
+    0x0000, // 10696
+    0x0000, // 10697
+    0x0000, // 10698
+    0x0000, // 10699
+    0x0000, // 10700
+    0x0000, // 10701
+    0x0000, // 10702
+    0x0000, // 10703
+    0x0000, // 10704
+    0x0000, // 10705
+    0x0000, // 10706
+    0x0000, // 10707
+    0x0000, // 10708
+    0x0000, // 10709
+    0x0000, // 10710
+    0x0000, // 10711
+    0x0000, // 10712
+    0x0000, // 10713
+    0x0000, // 10714
+    0x0000, // 10715
+    0x2170, // 10716
+    0x2171, // 10717
+    0x2172, // 10718
+    0x2173, // 10719
+    0x2174, // 10720
+    0x2175, // 10721
+    0x2176, // 10722
+    0x2177, // 10723
+    0x2178, // 10724
+    0x2179, // 10725
+    0x2160, // 10726
+    0x2161, // 10727
+    0x2162, // 10728
+    0x2163, // 10729
+    0x2164, // 10730
+    0x2165, // 10731
+    0x2166, // 10732
+    0x2167, // 10733
+    0x2168, // 10734
+    0x2169, // 10735
+    0xFFE2, // 10736
+    0xFFE4, // 10737
+    0xFF07, // 10738
+    0xFF02, // 10739
+    0x3231, // 10740
+    0x2116, // 10741
+    0x2121, // 10742
+    0x2235, // 10743
+    0x7E8A, // 10744
+    0x891C, // 10745
+    0x9348, // 10746
+    0x9288, // 10747
+    0x84DC, // 10748
+    0x4FC9, // 10749
+    0x70BB, // 10750
+    0x6631, // 10751
+    0x68C8, // 10752
+    0x92F9, // 10753
+    0x66FB, // 10754
+    0x5F45, // 10755
+    0x4E28, // 10756
+    0x4EE1, // 10757
+    0x4EFC, // 10758
+    0x4F00, // 10759
+    0x4F03, // 10760
+    0x4F39, // 10761
+    0x4F56, // 10762
+    0x4F92, // 10763
+    0x4F8A, // 10764
+    0x4F9A, // 10765
+    0x4F94, // 10766
+    0x4FCD, // 10767
+    0x5040, // 10768
+    0x5022, // 10769
+    0x4FFF, // 10770
+    0x501E, // 10771
+    0x5046, // 10772
+    0x5070, // 10773
+    0x5042, // 10774
+    0x5094, // 10775
+    0x50F4, // 10776
+    0x50D8, // 10777
+    0x514A, // 10778
+    0x5164, // 10779
+    0x519D, // 10780
+    0x51BE, // 10781
+    0x51EC, // 10782
+    0x5215, // 10783
+    0x529C, // 10784
+    0x52A6, // 10785
+    0x52C0, // 10786
+    0x52DB, // 10787
+    0x5300, // 10788
+    0x5307, // 10789
+    0x5324, // 10790
+    0x5372, // 10791
+    0x5393, // 10792
+    0x53B2, // 10793
+    0x53DD, // 10794
+    0xFA0E, // 10795
+    0x549C, // 10796
+    0x548A, // 10797
+    0x54A9, // 10798
+    0x54FF, // 10799
+    0x5586, // 10800
+    0x5759, // 10801
+    0x5765, // 10802
+    0x57AC, // 10803
+    0x57C8, // 10804
+    0x57C7, // 10805
+    0xFA0F, // 10806
+    0xFA10, // 10807
+    0x589E, // 10808
+    0x58B2, // 10809
+    0x590B, // 10810
+    0x5953, // 10811
+    0x595B, // 10812
+    0x595D, // 10813
+    0x5963, // 10814
+    0x59A4, // 10815
+    0x59BA, // 10816
+    0x5B56, // 10817
+    0x5BC0, // 10818
+    0x752F, // 10819
+    0x5BD8, // 10820
+    0x5BEC, // 10821
+    0x5C1E, // 10822
+    0x5CA6, // 10823
+    0x5CBA, // 10824
+    0x5CF5, // 10825
+    0x5D27, // 10826
+    0x5D53, // 10827
+    0xFA11, // 10828
+    0x5D42, // 10829
+    0x5D6D, // 10830
+    0x5DB8, // 10831
+    0x5DB9, // 10832
+    0x5DD0, // 10833
+    0x5F21, // 10834
+    0x5F34, // 10835
+    0x5F67, // 10836
+    0x5FB7, // 10837
+    0x5FDE, // 10838
+    0x605D, // 10839
+    0x6085, // 10840
+    0x608A, // 10841
+    0x60DE, // 10842
+    0x60D5, // 10843
+    0x6120, // 10844
+    0x60F2, // 10845
+    0x6111, // 10846
+    0x6137, // 10847
+    0x6130, // 10848
+    0x6198, // 10849
+    0x6213, // 10850
+    0x62A6, // 10851
+    0x63F5, // 10852
+    0x6460, // 10853
+    0x649D, // 10854
+    0x64CE, // 10855
+    0x654E, // 10856
+    0x6600, // 10857
+    0x6615, // 10858
+    0x663B, // 10859
+    0x6609, // 10860
+    0x662E, // 10861
+    0x661E, // 10862
+    0x6624, // 10863
+    0x6665, // 10864
+    0x6657, // 10865
+    0x6659, // 10866
+    0xFA12, // 10867
+    0x6673, // 10868
+    0x6699, // 10869
+    0x66A0, // 10870
+    0x66B2, // 10871
+    0x66BF, // 10872
+    0x66FA, // 10873
+    0x670E, // 10874
+    0xF929, // 10875
+    0x6766, // 10876
+    0x67BB, // 10877
+    0x6852, // 10878
+    0x67C0, // 10879
+    0x6801, // 10880
+    0x6844, // 10881
+    0x68CF, // 10882
+    0xFA13, // 10883
+    0x6968, // 10884
+    0xFA14, // 10885
+    0x6998, // 10886
+    0x69E2, // 10887
+    0x6A30, // 10888
+    0x6A6B, // 10889
+    0x6A46, // 10890
+    0x6A73, // 10891
+    0x6A7E, // 10892
+    0x6AE2, // 10893
+    0x6AE4, // 10894
+    0x6BD6, // 10895
+    0x6C3F, // 10896
+    0x6C5C, // 10897
+    0x6C86, // 10898
+    0x6C6F, // 10899
+    0x6CDA, // 10900
+    0x6D04, // 10901
+    0x6D87, // 10902
+    0x6D6F, // 10903
+    0x6D96, // 10904
+    0x6DAC, // 10905
+    0x6DCF, // 10906
+    0x6DF8, // 10907
+    0x6DF2, // 10908
+    0x6DFC, // 10909
+    0x6E39, // 10910
+    0x6E5C, // 10911
+    0x6E27, // 10912
+    0x6E3C, // 10913
+    0x6EBF, // 10914
+    0x6F88, // 10915
+    0x6FB5, // 10916
+    0x6FF5, // 10917
+    0x7005, // 10918
+    0x7007, // 10919
+    0x7028, // 10920
+    0x7085, // 10921
+    0x70AB, // 10922
+    0x710F, // 10923
+    0x7104, // 10924
+    0x715C, // 10925
+    0x7146, // 10926
+    0x7147, // 10927
+    0xFA15, // 10928
+    0x71C1, // 10929
+    0x71FE, // 10930
+    0x72B1, // 10931
+    0x72BE, // 10932
+    0x7324, // 10933
+    0xFA16, // 10934
+    0x7377, // 10935
+    0x73BD, // 10936
+    0x73C9, // 10937
+    0x73D6, // 10938
+    0x73E3, // 10939
+    0x73D2, // 10940
+    0x7407, // 10941
+    0x73F5, // 10942
+    0x7426, // 10943
+    0x742A, // 10944
+    0x7429, // 10945
+    0x742E, // 10946
+    0x7462, // 10947
+    0x7489, // 10948
+    0x749F, // 10949
+    0x7501, // 10950
+    0x756F, // 10951
+    0x7682, // 10952
+    0x769C, // 10953
+    0x769E, // 10954
+    0x769B, // 10955
+    0x76A6, // 10956
+    0xFA17, // 10957
+    0x7746, // 10958
+    0x52AF, // 10959
+    0x7821, // 10960
+    0x784E, // 10961
+    0x7864, // 10962
+    0x787A, // 10963
+    0x7930, // 10964
+    0xFA18, // 10965
+    0xFA19, // 10966
+    0xFA1A, // 10967
+    0x7994, // 10968
+    0xFA1B, // 10969
+    0x799B, // 10970
+    0x7AD1, // 10971
+    0x7AE7, // 10972
+    0xFA1C, // 10973
+    0x7AEB, // 10974
+    0x7B9E, // 10975
+    0xFA1D, // 10976
+    0x7D48, // 10977
+    0x7D5C, // 10978
+    0x7DB7, // 10979
+    0x7DA0, // 10980
+    0x7DD6, // 10981
+    0x7E52, // 10982
+    0x7F47, // 10983
+    0x7FA1, // 10984
+    0xFA1E, // 10985
+    0x8301, // 10986
+    0x8362, // 10987
+    0x837F, // 10988
+    0x83C7, // 10989
+    0x83F6, // 10990
+    0x8448, // 10991
+    0x84B4, // 10992
+    0x8553, // 10993
+    0x8559, // 10994
+    0x856B, // 10995
+    0xFA1F, // 10996
+    0x85B0, // 10997
+    0xFA20, // 10998
+    0xFA21, // 10999
+    0x8807, // 11000
+    0x88F5, // 11001
+    0x8A12, // 11002
+    0x8A37, // 11003
+    0x8A79, // 11004
+    0x8AA7, // 11005
+    0x8ABE, // 11006
+    0x8ADF, // 11007
+    0xFA22, // 11008
+    0x8AF6, // 11009
+    0x8B53, // 11010
+    0x8B7F, // 11011
+    0x8CF0, // 11012
+    0x8CF4, // 11013
+    0x8D12, // 11014
+    0x8D76, // 11015
+    0xFA23, // 11016
+    0x8ECF, // 11017
+    0xFA24, // 11018
+    0xFA25, // 11019
+    0x9067, // 11020
+    0x90DE, // 11021
+    0xFA26, // 11022
+    0x9115, // 11023
+    0x9127, // 11024
+    0x91DA, // 11025
+    0x91D7, // 11026
+    0x91DE, // 11027
+    0x91ED, // 11028
+    0x91EE, // 11029
+    0x91E4, // 11030
+    0x91E5, // 11031
+    0x9206, // 11032
+    0x9210, // 11033
+    0x920A, // 11034
+    0x923A, // 11035
+    0x9240, // 11036
+    0x923C, // 11037
+    0x924E, // 11038
+    0x9259, // 11039
+    0x9251, // 11040
+    0x9239, // 11041
+    0x9267, // 11042
+    0x92A7, // 11043
+    0x9277, // 11044
+    0x9278, // 11045
+    0x92E7, // 11046
+    0x92D7, // 11047
+    0x92D9, // 11048
+    0x92D0, // 11049
+    0xFA27, // 11050
+    0x92D5, // 11051
+    0x92E0, // 11052
+    0x92D3, // 11053
+    0x9325, // 11054
+    0x9321, // 11055
+    0x92FB, // 11056
+    0xFA28, // 11057
+    0x931E, // 11058
+    0x92FF, // 11059
+    0x931D, // 11060
+    0x9302, // 11061
+    0x9370, // 11062
+    0x9357, // 11063
+    0x93A4, // 11064
+    0x93C6, // 11065
+    0x93DE, // 11066
+    0x93F8, // 11067
+    0x9431, // 11068
+    0x9445, // 11069
+    0x9448, // 11070
+    0x9592, // 11071
+    0xF9DC, // 11072
+    0xFA29, // 11073
+    0x969D, // 11074
+    0x96AF, // 11075
+    0x9733, // 11076
+    0x973B, // 11077
+    0x9743, // 11078
+    0x974D, // 11079
+    0x974F, // 11080
+    0x9751, // 11081
+    0x9755, // 11082
+    0x9857, // 11083
+    0x9865, // 11084
+    0xFA2A, // 11085
+    0xFA2B, // 11086
+    0x9927, // 11087
+    0xFA2C, // 11088
+    0x999E, // 11089
+    0x9A4E, // 11090
+    0x9AD9, // 11091
+    0x9ADC, // 11092
+    0x9B75, // 11093
+    0x9B72, // 11094
+    0x9B8F, // 11095
+    0x9BB1, // 11096
+    0x9BBB, // 11097
+    0x9C00, // 11098
+    0x9D70, // 11099
+    0x9D6B, // 11100
+    0xFA2D, // 11101
+    0x9E19, // 11102
+    0x9ED1, // 11103
+];
@@ -0,0 +1,59 @@
+mod cp_jis0208;
+mod jis0208_cp;
+
+/// The code point of a pointer in the JIS X 0208 index.
+#[inline]
+pub(super) const fn decode_ptr(ptr: usize) -> Option<char> {
+    if ptr >= jis0208_cp::JIS0208_PTR_TO_CP.len() {
+        return None;
+    }
+    let cp = jis0208_cp::JIS0208_PTR_TO_CP[ptr];
+    if cp == 0 {
+        return None;
+    }
+    char::from_u32(cp as u32)
+}
+
+/// The first pointer of a code point in the JIS X 0208 index.
+#[cfg(any(feature = "euc-jp", feature = "iso-2022-jp"))]
+#[inline]
+pub(super) const fn encode_ptr(cp: u32) -> Option<u16> {
+    if cp > 0xFFFF {
+        return None;
+    }
+    #[expect(clippy::cast_possible_truncation)]
+    b_find(cp as u16, &cp_jis0208::JIS0208_CP_TO_PTR)
+}
+
+/// The pointer of a code point in the JIS X 0208 index as used by `Shift_JIS`, which excludes the pointers `8272..=8835`.
+#[cfg(feature = "shift_jis")]
+#[inline]
+pub(super) const fn encode_ptr_shift_jis(cp: u32) -> Option<u16> {
+    if cp > 0xFFFF {
+        return None;
+    }
+    #[expect(clippy::cast_possible_truncation)]
+    let cp = cp as u16;
+    match b_find(cp, &cp_jis0208::JIS0208_CP_TO_PTR_SHIFT_JIS) {
+        Some(ptr) => Some(ptr),
+        None => b_find(cp, &cp_jis0208::JIS0208_CP_TO_PTR),
+    }
+}
+
+const fn b_find(key: u16, arr: &[(u16, u16)]) -> Option<u16> {
+    let mut base = 0;
+    let mut lim = arr.len();
+    while base < lim {
+        #[expect(clippy::integer_division)]
+        let mid = base + (lim - base) / 2;
+        let (mid_key, mid_val) = arr[mid];
+        if mid_key < key {
+            base = mid + 1;
+        } else if mid_key > key {
+            lim = mid;
+        } else {
+            return Some(mid_val);
+        }
+    }
+    None
+}
@@ -0,0 +1,7109 @@
+/// Based on <https://encoding.spec.whatwg.org/index-jis0212.txt>, starting at pointer 108.
+///
+/// - Identifier: 83bf90dd1c591a4355730d8c4567efc499d74da7490531019ef22a879991cfb7
+/// - Date: 2014-12-19
+pub(super) static JIS0212_PTR_TO_CP: [u16; 7103] = [
+    0x02D8, // 108
+    0x02C7, // 109
+    0x00B8, // 110
+    0x02D9, // 111
+    0x02DD, // 112
+    0x00AF, // 113
+    0x02DB, // 114
+    0x02DA, // 115
+    0xFF5E, // 116
+    0x0384, // 117
+    0x0385, // 118
+    0x0000, // 119
+    0x0000, // 120
+    0x0000, // 121
+    0x0000, // 122
+    0x0000, // 123
+    0x0000, // 124
+    0x0000, // 125
+    0x0000, // 126
+    0x00A1, // 127
+    0x00A6, // 128
+    0x00BF, // 129
+    0x0000, // 130
+    0x0000, // 131
+    0x0000, // 132
+    0x0000, // 133
+    0x0000, // 134
+    0x0000, // 135
+    0x0000, // 136
+    0x0000, // 137
+    0x0000, // 138
+    0x0000, // 139
+    0x0000, // 140
+    0x0000, // 141
+    0x0000, // 142
+    0x0000, // 143
+    0x0000, // 144
+    0x0000, // 145
+    0x0000, // 146
+    0x0000, // 147
+    0x0000, // 148
+    0x0000, // 149
+    0x0000, // 150
+    0x0000, // 151
+    0x0000, // 152
+    0x0000, // 153
+    0x0000, // 154
+    0x0000, // 155
+    0x0000, // 156
+    0x0000, // 157
+    0x0000, // 158
+    0x0000, // 159
+    0x0000, // 160
+    0x0000, // 161
+    0x0000, // 162
+    0x0000, // 163
+    0x0000, // 164
+    0x0000, // 165
+    0x0000, // 166
+    0x0000, // 167
+    0x00BA, // 168
+    0x00AA, // 169
+    0x00A9, // 170
+    0x00AE, // 171
+    0x2122, // 172
+    0x00A4, // 173
+    0x2116, // 174
+    0x0000, // 175
+    0x0000, // 176
+    0x0000, // 177
+    0x0000, // 178
+    0x0000, // 179
+    0x0000, // 180
+    0x0000, // 181
+    0x0000, // 182
+    0x0000, // 183
+    0x0000, // 184
+    0x0000, // 185
+    0x0000, // 186
+    0x0000, // 187
+    0x0000, // 188
+    0x0000, // 189
+    0x0000, // 190
+    0x0000, // 191
+    0x0000, // 192
+    0x0000, // 193
+    0x0000, // 194
+    0x0000, // 195
+    0x0000, // 196
+    0x0000, // 197
+    0x0000, // 198
+    0x0000, // 199
+    0x0000, // 200
+    0x0000, // 201
+    0x0000, // 202
+    0x0000, // 203
+    0x0000, // 204
+    0x0000, // 205
+    0x0000, // 206
+    0x0000, // 207
+    0x0000, // 208
+    0x0000, // 209
+    0x0000, // 210
+    0x0000, // 211
+    0x0000, // 212
+    0x0000, // 213
+    0x0000, // 214
+    0x0000, // 215
+    0x0000, // 216
+    0x0000, // 217
+    0x0000, // 218
+    0x0000, // 219
+    0x0000, // 220
+    0x0000, // 221
+    0x0000, // 222
+    0x0000, // 223
+    0x0000, // 224
+    0x0000, // 225
+    0x0000, // 226
+    0x0000, // 227
+    0x0000, // 228
+    0x0000, // 229
+    0x0000, // 230
+    0x0000, // 231
+    0x0000, // 232
+    0x0000, // 233
+    0x0000, // 234
+    0x0000, // 235
+    0x0000, // 236
+    0x0000, // 237
+    0x0000, // 238
+    0x0000, // 239
+    0x0000, // 240
+    0x0000, // 241
+    0x0000, // 242
+    0x0000, // 243
+    0x0000, // 244
+    0x0000, // 245
+    0x0000, // 246
+    0x0000, // 247
+    0x0000, // 248
+    0x0000, // 249
+    0x0000, // 250
+    0x0000, // 251
+    0x0000, // 252
+    0x0000, // 253
+    0x0000, // 254
+    0x0000, // 255
+    0x0000, // 256
+    0x0000, // 257
+    0x0000, // 258
+    0x0000, // 259
+    0x0000, // 260
+    0x0000, // 261
+    0x0000, // 262
+    0x0000, // 263
+    0x0000, // 264
+    0x0000, // 265
+    0x0000, // 266
+    0x0000, // 267
+    0x0000, // 268
+    0x0000, // 269
+    0x0000, // 270
+    0x0000, // 271
+    0x0000, // 272
+    0x0000, // 273
+    0x0000, // 274
+    0x0000, // 275
+    0x0000, // 276
+    0x0000, // 277
+    0x0000, // 278
+    0x0000, // 279
+    0x0000, // 280
+    0x0000, // 281
+    0x0000, // 282
+    0x0000, // 283
+    0x0000, // 284
+    0x0000, // 285
+    0x0000, // 286
+    0x0000, // 287
+    0x0000, // 288
+    0x0000, // 289
+    0x0000, // 290
+    0x0000, // 291
+    0x0000, // 292
+    0x0000, // 293
+    0x0000, // 294
+    0x0000, // 295
+    0x0000, // 296
+    0x0000, // 297
+    0x0000, // 298
+    0x0000, // 299
+    0x0000, // 300
+    0x0000, // 301
+    0x0000, // 302
+    0x0000, // 303
+    0x0000, // 304
+    0x0000, // 305
+    0x0000, // 306
+    0x0000, // 307
+    0x0000, // 308
+    0x0000, // 309
+    0x0000, // 310
+    0x0000, // 311
+    0x0000, // 312
+    0x0000, // 313
+    0x0000, // 314
+    0x0000, // 315
+    0x0000, // 316
+    0x0000, // 317
+    0x0000, // 318
+    0x0000, // 319
+    0x0000, // 320
+    0x0000, // 321
+    0x0000, // 322
+    0x0000, // 323
+    0x0000, // 324
+    0x0000, // 325
+    0x0000, // 326
+    0x0000, // 327
+    0x0000, // 328
+    0x0000, // 329
+    0x0000, // 330
+    0x0000, // 331
+    0x0000, // 332
+    0x0000, // 333
+    0x0000, // 334
+    0x0000, // 335
+    0x0000, // 336
+    0x0000, // 337
+    0x0000, // 338
+    0x0000, // 339
+    0x0000, // 340
+    0x0000, // 341
+    0x0000, // 342
+    0x0000, // 343
+    0x0000, // 344
+    0x0000, // 345
+    0x0000, // 346
+    0x0000, // 347
+    0x0000, // 348
+    0x0000, // 349
+    0x0000, // 350
+    0x0000, // 351
+    0x0000, // 352
+    0x0000, // 353
+    0x0000, // 354
+    0x0000, // 355
+    0x0000, // 356
+    0x0000, // 357
+    0x0000, // 358
+    0x0000, // 359
+    0x0000, // 360
+    0x0000, // 361
+    0x0000, // 362
+    0x0000, // 363
+    0x0000, // 364
+    0x0000, // 365
+    0x0000, // 366
+    0x0000, // 367
+    0x0000, // 368
+    0x0000, // 369
+    0x0000, // 370
+    0x0000, // 371
+    0x0000, // 372
+    0x0000, // 373
+    0x0000, // 374
+    0x0000, // 375
+    0x0000, // 376
+    0x0000, // 377
+    0x0000, // 378
+    0x0000, // 379
+    0x0000, // 380
+    0x0000, // 381
+    0x0000, // 382
+    0x0000, // 383
+    0x0000, // 384
+    0x0000, // 385
+    0x0000, // 386
+    0x0000, // 387
+    0x0000, // 388
+    0x0000, // 389
+    0x0000, // 390
+    0x0000, // 391
+    0x0000, // 392
+    0x0000, // 393
+    0x0000, // 394
+    0x0000, // 395
+    0x0000, // 396
+    0x0000, // 397
+    0x0000, // 398
+    0x0000, // 399
+    0x0000, // 400
+    0x0000, // 401
+    0x0000, // 402
+    0x0000, // 403
+    0x0000, // 404
+    0x0000, // 405
+    0x0000, // 406
+    0x0000, // 407
+    0x0000, // 408
+    0x0000, // 409
+    0x0000, // 410
+    0x0000, // 411
+    0x0000, // 412
+    0x0000, // 413
+    0x0000, // 414
+    0x0000, // 415
+    0x0000, // 416
+    0x0000, // 417
+    0x0000, // 418
+    0x0000, // 419
+    0x0000, // 420
+    0x0000, // 421
+    0x0000, // 422
+    0x0000, // 423
+    0x0000, // 424
+    0x0000, // 425
+    0x0000, // 426
+    0x0000, // 427
+    0x0000, // 428
+    0x0000, // 429
+    0x0000, // 430
+    0x0000, // 431
+    0x0000, // 432
+    0x0000, // 433
+    0x0000, // 434
+    0x0000, // 435
+    0x0000, // 436
+    0x0000, // 437
+    0x0000, // 438
+    0x0000, // 439
+    0x0000, // 440
+    0x0000, // 441
+    0x0000, // 442
+    0x0000, // 443
+    0x0000, // 444
+    0x0000, // 445
+    0x0000, // 446
+    0x0000, // 447
+    0x0000, // 448
+    0x0000, // 449
+    0x0000, // 450
+    0x0000, // 451
+    0x0000, // 452
+    0x0000, // 453
+    0x0000, // 454
+    0x0000, // 455
+    0x0000, // 456
+    0x0000, // 457
+    0x0000, // 458
+    0x0000, // 459
+    0x0000, // 460
+    0x0000, // 461
+    0x0000, // 462
+    0x0000, // 463
+    0x0000, // 464
+    0x0000, // 465
+    0x0000, // 466
+    0x0000, // 467
+    0x0000, // 468
+    0x0000, // 469
+    0x0000, // 470
+    0x0000, // 471
+    0x0000, // 472
+    0x0000, // 473
+    0x0000, // 474
+    0x0000, // 475
+    0x0000, // 476
+    0x0000, // 477
+    0x0000, // 478
+    0x0000, // 479
+    0x0000, // 480
+    0x0000, // 481
+    0x0000, // 482
+    0x0000, // 483
+    0x0000, // 484
+    0x0000, // 485
+    0x0000, // 486
+    0x0000, // 487
+    0x0000, // 488
+    0x0000, // 489
+    0x0000, // 490
+    0x0000, // 491
+    0x0000, // 492
+    0x0000, // 493
+    0x0000, // 494
+    0x0000, // 495
+    0x0000, // 496
+    0x0000, // 497
+    0x0000, // 498
+    0x0000, // 499
+    0x0000, // 500
+    0x0000, // 501
+    0x0000, // 502
+    0x0000, // 503
+    0x0000, // 504
+    0x0000, // 505
+    0x0000, // 506
+    0x0000, // 507
+    0x0000, // 508
+    0x0000, // 509
+    0x0000, // 510
+    0x0000, // 511
+    0x0000, // 512
+    0x0000, // 513
+    0x0000, // 514
+    0x0000, // 515
+    0x0000, // 516
+    0x0000, // 517
+    0x0000, // 518
+    0x0000, // 519
+    0x0000, // 520
+    0x0000, // 521
+    0x0000, // 522
+    0x0000, // 523
+    0x0000, // 524
+    0x0000, // 525
+    0x0000, // 526
+    0x0000, // 527
+    0x0000, // 528
+    0x0000, // 529
+    0x0000, // 530
+    0x0000, // 531
+    0x0000, // 532
+    0x0000, // 533
+    0x0386, // 534
+    0x0388, // 535
+    0x0389, // 536
+    0x038A, // 537
+    0x03AA, // 538
+    0x0000, // 539
+    0x038C, // 540
+    0x0000, // 541
+    0x038E, // 542
+    0x03AB, // 543
+    0x0000, // 544
+    0x038F, // 545
+    0x0000, // 546
+    0x0000, // 547
+    0x0000, // 548
+    0x0000, // 549
+    0x03AC, // 550
+    0x03AD, // 551
+    0x03AE, // 552
+    0x03AF, // 553
+    0x03CA, // 554
+    0x0390, // 555
+    0x03CC, // 556
+    0x03C2, // 557
+    0x03CD, // 558
+    0x03CB, // 559
+    0x03B0, // 560
+    0x03CE, // 561
+    0x0000, // 562
+    0x0000, // 563
+    0x0000, // 564
+    0x0000, // 565
+    0x0000, // 566
+    0x0000, // 567
+    0x0000, // 568
+    0x0000, // 569
+    0x0000, // 570
+    0x0000, // 571
+    0x0000, // 572
+    0x0000, // 573
+    0x0000, // 574
+    0x0000, // 575
+    0x0000, // 576
+    0x0000, // 577
+    0x0000, // 578
+    0x0000, // 579
+    0x0000, // 580
+    0x0000, // 581
+    0x0000, // 582
+    0x0000, // 583
+    0x0000, // 584
+    0x0000, // 585
+    0x0000, // 586
+    0x0000, // 587
+    0x0000, // 588
+    0x0000, // 589
+    0x0000, // 590
+    0x0000, // 591
+    0x0000, // 592
+    0x0000, // 593
+    0x0000, // 594
+    0x0000, // 595
+    0x0000, // 596
+    0x0402, // 597
+    0x0403, // 598
+    0x0404, // 599
+    0x0405, // 600
+    0x0406, // 601
+    0x0407, // 602
+    0x0408, // 603
+    0x0409, // 604
+    0x040A, // 605
+    0x040B, // 606
+    0x040C, // 607
+    0x040E, // 608
+    0x040F, // 609
+    0x0000, // 610
+    0x0000, // 611
+    0x0000, // 612
+    0x0000, // 613
+    0x0000, // 614
+    0x0000, // 615
+    0x0000, // 616
+    0x0000, // 617
+    0x0000, // 618
+    0x0000, // 619
+    0x0000, // 620
+    0x0000, // 621
+    0x0000, // 622
+    0x0000, // 623
+    0x0000, // 624
+    0x0000, // 625
+    0x0000, // 626
+    0x0000, // 627
+    0x0000, // 628
+    0x0000, // 629
+    0x0000, // 630
+    0x0000, // 631
+    0x0000, // 632
+    0x0000, // 633
+    0x0000, // 634
+    0x0000, // 635
+    0x0000, // 636
+    0x0000, // 637
+    0x0000, // 638
+    0x0000, // 639
+    0x0000, // 640
+    0x0000, // 641
+    0x0000, // 642
+    0x0000, // 643
+    0x0000, // 644
+    0x0452, // 645
+    0x0453, // 646
+    0x0454, // 647
+    0x0455, // 648
+    0x0456, // 649
+    0x0457, // 650
+    0x0458, // 651
+    0x0459, // 652
+    0x045A, // 653
+    0x045B, // 654
+    0x045C, // 655
+    0x045E, // 656
+    0x045F, // 657
+    0x0000, // 658
+    0x0000, // 659
+    0x0000, // 660
+    0x0000, // 661
+    0x0000, // 662
+    0x0000, // 663
+    0x0000, // 664
+    0x0000, // 665
+    0x0000, // 666
+    0x0000, // 667
+    0x0000, // 668
+    0x0000, // 669
+    0x0000, // 670
+    0x0000, // 671
+    0x0000, // 672
+    0x0000, // 673
+    0x0000, // 674
+    0x0000, // 675
+    0x0000, // 676
+    0x0000, // 677
+    0x0000, // 678
+    0x0000, // 679
+    0x0000, // 680
+    0x0000, // 681
+    0x0000, // 682
+    0x0000, // 683
+    0x0000, // 684
+    0x0000, // 685
+    0x0000, // 686
+    0x0000, // 687
+    0x0000, // 688
+    0x0000, // 689
+    0x0000, // 690
+    0x0000, // 691
+    0x0000, // 692
+    0x0000, // 693
+    0x0000, // 694
+    0x0000, // 695
+    0x0000, // 696
+    0x0000, // 697
+    0x0000, // 698
+    0x0000, // 699
+    0x0000, // 700
+    0x0000, // 701
+    0x0000, // 702
+    0x0000, // 703
+    0x0000, // 704
+    0x0000, // 705
+    0x0000, // 706
+    0x0000, // 707
+    0x0000, // 708
+    0x0000, // 709
+    0x0000, // 710
+    0x0000, // 711
+    0x0000, // 712
+    0x0000, // 713
+    0x0000, // 714
+    0x0000, // 715
+    0x0000, // 716
+    0x0000, // 717
+    0x0000, // 718
+    0x0000, // 719
+    0x0000, // 720
+    0x0000, // 721
+    0x0000, // 722
+    0x0000, // 723
+    0x0000, // 724
+    0x0000, // 725
+    0x0000, // 726
+    0x0000, // 727
+    0x0000, // 728
+    0x0000, // 729
+    0x0000, // 730
+    0x0000, // 731
+    0x0000, // 732
+    0x0000, // 733
+    0x0000, // 734
+    0x0000, // 735
+    0x0000, // 736
+    0x0000, // 737
+    0x0000, // 738
+    0x0000, // 739
+    0x0000, // 740
+    0x0000, // 741
+    0x0000, // 742
+    0x0000, // 743
+    0x0000, // 744
+    0x0000, // 745
+    0x0000, // 746
+    0x0000, // 747
+    0x0000, // 748
+    0x0000, // 749
+    0x0000, // 750
+    0x0000, // 751
+    0x00C6, // 752
+    0x0110, // 753
+    0x0000, // 754
+    0x0126, // 755
+    0x0000, // 756
+    0x0132, // 757
+    0x0000, // 758
+    0x0141, // 759
+    0x013F, // 760
+    0x0000, // 761
+    0x014A, // 762
+    0x00D8, // 763
+    0x0152, // 764
+    0x0000, // 765
+    0x0166, // 766
+    0x00DE, // 767
+    0x0000, // 768
+    0x0000, // 769
+    0x0000, // 770
+    0x0000, // 771
+    0x0000, // 772
+    0x0000, // 773
+    0x0000, // 774
+    0x0000, // 775
+    0x0000, // 776
+    0x0000, // 777
+    0x0000, // 778
+    0x0000, // 779
+    0x0000, // 780
+    0x0000, // 781
+    0x0000, // 782
+    0x0000, // 783
+    0x00E6, // 784
+    0x0111, // 785
+    0x00F0, // 786
+    0x0127, // 787
+    0x0131, // 788
+    0x0133, // 789
+    0x0138, // 790
+    0x0142, // 791
+    0x0140, // 792
+    0x0149, // 793
+    0x014B, // 794
+    0x00F8, // 795
+    0x0153, // 796
+    0x00DF, // 797
+    0x0167, // 798
+    0x00FE, // 799
+    0x0000, // 800
+    0x0000, // 801
+    0x0000, // 802
+    0x0000, // 803
+    0x0000, // 804
+    0x0000, // 805
+    0x0000, // 806
+    0x0000, // 807
+    0x0000, // 808
+    0x0000, // 809
+    0x0000, // 810
+    0x0000, // 811
+    0x0000, // 812
+    0x0000, // 813
+    0x0000, // 814
+    0x0000, // 815
+    0x0000, // 816
+    0x0000, // 817
+    0x0000, // 818
+    0x0000, // 819
+    0x0000, // 820
+    0x0000, // 821
+    0x0000, // 822
+    0x0000, // 823
+    0x0000, // 824
+    0x0000, // 825
+    0x0000, // 826
+    0x0000, // 827
+    0x0000, // 828
+    0x0000, // 829
+    0x0000, // 830
+    0x0000, // 831
+    0x0000, // 832
+    0x0000, // 833
+    0x0000, // 834
+    0x0000, // 835
+    0x0000, // 836
+    0x0000, // 837
+    0x0000, // 838
+    0x0000, // 839
+    0x0000, // 840
+    0x0000, // 841
+    0x0000, // 842
+    0x0000, // 843
+    0x0000, // 844
+    0x0000, // 845
+    0x00C1, // 846
+    0x00C0, // 847
+    0x00C4, // 848
+    0x00C2, // 849
+    0x0102, // 850
+    0x01CD, // 851
+    0x0100, // 852
+    0x0104, // 853
+    0x00C5, // 854
+    0x00C3, // 855
+    0x0106, // 856
+    0x0108, // 857
+    0x010C, // 858
+    0x00C7, // 859
+    0x010A, // 860
+    0x010E, // 861
+    0x00C9, // 862
+    0x00C8, // 863
+    0x00CB, // 864
+    0x00CA, // 865
+    0x011A, // 866
+    0x0116, // 867
+    0x0112, // 868
+    0x0118, // 869
+    0x0000, // 870
+    0x011C, // 871
+    0x011E, // 872
+    0x0122, // 873
+    0x0120, // 874
+    0x0124, // 875
+    0x00CD, // 876
+    0x00CC, // 877
+    0x00CF, // 878
+    0x00CE, // 879
+    0x01CF, // 880
+    0x0130, // 881
+    0x012A, // 882
+    0x012E, // 883
+    0x0128, // 884
+    0x0134, // 885
+    0x0136, // 886
+    0x0139, // 887
+    0x013D, // 888
+    0x013B, // 889
+    0x0143, // 890
+    0x0147, // 891
+    0x0145, // 892
+    0x00D1, // 893
+    0x00D3, // 894
+    0x00D2, // 895
+    0x00D6, // 896
+    0x00D4, // 897
+    0x01D1, // 898
+    0x0150, // 899
+    0x014C, // 900
+    0x00D5, // 901
+    0x0154, // 902
+    0x0158, // 903
+    0x0156, // 904
+    0x015A, // 905
+    0x015C, // 906
+    0x0160, // 907
+    0x015E, // 908
+    0x0164, // 909
+    0x0162, // 910
+    0x00DA, // 911
+    0x00D9, // 912
+    0x00DC, // 913
+    0x00DB, // 914
+    0x016C, // 915
+    0x01D3, // 916
+    0x0170, // 917
+    0x016A, // 918
+    0x0172, // 919
+    0x016E, // 920
+    0x0168, // 921
+    0x01D7, // 922
+    0x01DB, // 923
+    0x01D9, // 924
+    0x01D5, // 925
+    0x0174, // 926
+    0x00DD, // 927
+    0x0178, // 928
+    0x0176, // 929
+    0x0179, // 930
+    0x017D, // 931
+    0x017B, // 932
+    0x0000, // 933
+    0x0000, // 934
+    0x0000, // 935
+    0x0000, // 936
+    0x0000, // 937
+    0x0000, // 938
+    0x0000, // 939
+    0x00E1, // 940
+    0x00E0, // 941
+    0x00E4, // 942
+    0x00E2, // 943
+    0x0103, // 944
+    0x01CE, // 945
+    0x0101, // 946
+    0x0105, // 947
+    0x00E5, // 948
+    0x00E3, // 949
+    0x0107, // 950
+    0x0109, // 951
+    0x010D, // 952
+    0x00E7, // 953
+    0x010B, // 954
+    0x010F, // 955
+    0x00E9, // 956
+    0x00E8, // 957
+    0x00EB, // 958
+    0x00EA, // 959
+    0x011B, // 960
+    0x0117, // 961
+    0x0113, // 962
+    0x0119, // 963
+    0x01F5, // 964
+    0x011D, // 965
+    0x011F, // 966
+    0x0000, // 967
+    0x0121, // 968
+    0x0125, // 969
+    0x00ED, // 970
+    0x00EC, // 971
+    0x00EF, // 972
+    0x00EE, // 973
+    0x01D0, // 974
+    0x0000, // 975
+    0x012B, // 976
+    0x012F, // 977
+    0x0129, // 978
+    0x0135, // 979
+    0x0137, // 980
+    0x013A, // 981
+    0x013E, // 982
+    0x013C, // 983
+    0x0144, // 984
+    0x0148, // 985
+    0x0146, // 986
+    0x00F1, // 987
+    0x00F3, // 988
+    0x00F2, // 989
+    0x00F6, // 990
+    0x00F4, // 991
+    0x01D2, // 992
+    0x0151, // 993
+    0x014D, // 994
+    0x00F5, // 995
+    0x0155, // 996
+    0x0159, // 997
+    0x0157, // 998
+    0x015B, // 999
+    0x015D, // 1000
+    0x0161, // 1001
+    0x015F, // 1002
+    0x0165, // 1003
+    0x0163, // 1004
+    0x00FA, // 1005
+    0x00F9, // 1006
+    0x00FC, // 1007
+    0x00FB, // 1008
+    0x016D, // 1009
+    0x01D4, // 1010
+    0x0171, // 1011
+    0x016B, // 1012
+    0x0173, // 1013
+    0x016F, // 1014
+    0x0169, // 1015
+    0x01D8, // 1016
+    0x01DC, // 1017
+    0x01DA, // 1018
+    0x01D6, // 1019
+    0x0175, // 1020
+    0x00FD, // 1021
+    0x00FF, // 1022
+    0x0177, // 1023
+    0x017A, // 1024
+    0x017E, // 1025
+    0x017C, // 1026
+    0x0000, // 1027
+    0x0000, // 1028
+    0x0000, // 1029
+    0x0000, // 1030
+    0x0000, // 1031
+    0x0000, // 1032
+    0x0000, // 1033
+    0x0000, // 1034
+    0x0000, // 1035
+    0x0000, // 1036
+    0x0000, // 1037
+    0x0000, // 1038
+    0x0000, // 1039
+    0x0000, // 1040
+    0x0000, // 1041
+    0x0000, // 1042
+    0x0000, // 1043
+    0x0000, // 1044
+    0x0000, // 1045
+    0x0000, // 1046
+    0x0000, // 1047
+    0x0000, // 1048
+    0x0000, // 1049
+    0x0000, // 1050
+    0x0000, // 1051
+    0x0000, // 1052
+    0x0000, // 1053
+    0x0000, // 1054
+    0x0000, // 1055
+    0x0000, // 1056
+    0x0000, // 1057
+    0x0000, // 1058
+    0x0000, // 1059
+    0x0000, // 1060
+    0x0000, // 1061
+    0x0000, // 1062
+    0x0000, // 1063
+    0x0000, // 1064
+    0x0000, // 1065
+    0x0000, // 1066
+    0x0000, // 1067
+    0x0000, // 1068
+    0x0000, // 1069
+    0x0000, // 1070
+    0x0000, // 1071
+    0x0000, // 1072
+    0x0000, // 1073
+    0x0000, // 1074
+    0x0000, // 1075
+    0x0000, // 1076
+    0x0000, // 1077
+    0x0000, // 1078
+    0x0000, // 1079
+    0x0000, // 1080
+    0x0000, // 1081
+    0x0000, // 1082
+    0x0000, // 1083
+    0x0000, // 1084
+    0x0000, // 1085
+    0x0000, // 1086
+    0x0000, // 1087
+    0x0000, // 1088
+    0x0000, // 1089
+    0x0000, // 1090
+    0x0000, // 1091
+    0x0000, // 1092
+    0x0000, // 1093
+    0x0000, // 1094
+    0x0000, // 1095
+    0x0000, // 1096
+    0x0000, // 1097
+    0x0000, // 1098
+    0x0000, // 1099
+    0x0000, // 1100
+    0x0000, // 1101
+    0x0000, // 1102
+    0x0000, // 1103
+    0x0000, // 1104
+    0x0000, // 1105
+    0x0000, // 1106
+    0x0000, // 1107
+    0x0000, // 1108
+    0x0000, // 1109
+    0x0000, // 1110
+    0x0000, // 1111
+    0x0000, // 1112
+    0x0000, // 1113
+    0x0000, // 1114
+    0x0000, // 1115
+    0x0000, // 1116
+    0x0000, // 1117
+    0x0000, // 1118
+    0x0000, // 1119
+    0x0000, // 1120
+    0x0000, // 1121
+    0x0000, // 1122
+    0x0000, // 1123
+    0x0000, // 1124
+    0x0000, // 1125
+    0x0000, // 1126
+    0x0000, // 1127
+    0x0000, // 1128
+    0x0000, // 1129
+    0x0000, // 1130
+    0x0000, // 1131
+    0x0000, // 1132
+    0x0000, // 1133
+    0x0000, // 1134
+    0x0000, // 1135
+    0x0000, // 1136
+    0x0000, // 1137
+    0x0000, // 1138
+    0x0000, // 1139
+    0x0000, // 1140
+    0x0000, // 1141
+    0x0000, // 1142
+    0x0000, // 1143
+    0x0000, // 1144
+    0x0000, // 1145
+    0x0000, // 1146
+    0x0000, // 1147
+    0x0000, // 1148
+    0x0000, // 1149
+    0x0000, // 1150
+    0x0000, // 1151
+    0x0000, // 1152
+    0x0000, // 1153
+    0x0000, // 1154
+    0x0000, // 1155
+    0x0000, // 1156
+    0x0000, // 1157
+    0x0000, // 1158
+    0x0000, // 1159
+    0x0000, // 1160
+    0x0000, // 1161
+    0x0000, // 1162
+    0x0000, // 1163
+    0x0000, // 1164
+    0x0000, // 1165
+    0x0000, // 1166
+    0x0000, // 1167
+    0x0000, // 1168
+    0x0000, // 1169
+    0x0000, // 1170
+    0x0000, // 1171
+    0x0000, // 1172
+    0x0000, // 1173
+    0x0000, // 1174
+    0x0000, // 1175
+    0x0000, // 1176
+    0x0000, // 1177
+    0x0000, // 1178
+    0x0000, // 1179
+    0x0000, // 1180
+    0x0000, // 1181
+    0x0000, // 1182
+    0x0000, // 1183
+    0x0000, // 1184
+    0x0000, // 1185
+    0x0000, // 1186
+    0x0000, // 1187
+    0x0000, // 1188
+    0x0000, // 1189
+    0x0000, // 1190
+    0x0000, // 1191
+    0x0000, // 1192
+    0x0000, // 1193
+    0x0000, // 1194
+    0x0000, // 1195
+    0x0000, // 1196
+    0x0000, // 1197
+    0x0000, // 1198
+    0x0000, // 1199
+    0x0000, // 1200
+    0x0000, // 1201
+    0x0000, // 1202
+    0x0000, // 1203
+    0x0000, // 1204
+    0x0000, // 1205
+    0x0000, // 1206
+    0x0000, // 1207
+    0x0000, // 1208
+    0x0000, // 1209
+    0x0000, // 1210
+    0x0000, // 1211
+    0x0000, // 1212
+    0x0000, // 1213
+    0x0000, // 1214
+    0x0000, // 1215
+    0x0000, // 1216
+    0x0000, // 1217
+    0x0000, // 1218
+    0x0000, // 1219
+    0x0000, // 1220
+    0x0000, // 1221
+    0x0000, // 1222
+    0x0000, // 1223
+    0x0000, // 1224
+    0x0000, // 1225
+    0x0000, // 1226
+    0x0000, // 1227
+    0x0000, // 1228
+    0x0000, // 1229
+    0x0000, // 1230
+    0x0000, // 1231
+    0x0000, // 1232
+    0x0000, // 1233
+    0x0000, // 1234
+    0x0000, // 1235
+    0x0000, // 1236
+    0x0000, // 1237
+    0x0000, // 1238
+    0x0000, // 1239
+    0x0000, // 1240
+    0x0000, // 1241
+    0x0000, // 1242
+    0x0000, // 1243
+    0x0000, // 1244
+    0x0000, // 1245
+    0x0000, // 1246
+    0x0000, // 1247
+    0x0000, // 1248
+    0x0000, // 1249
+    0x0000, // 1250
+    0x0000, // 1251
+    0x0000, // 1252
+    0x0000, // 1253
+    0x0000, // 1254
+    0x0000, // 1255
+    0x0000, // 1256
+    0x0000, // 1257
+    0x0000, // 1258
+    0x0000, // 1259
+    0x0000, // 1260
+    0x0000, // 1261
+    0x0000, // 1262
+    0x0000, // 1263
+    0x0000, // 1264
+    0x0000, // 1265
+    0x0000, // 1266
+    0x0000, // 1267
+    0x0000, // 1268
+    0x0000, // 1269
+    0x0000, // 1270
+    0x0000, // 1271
+    0x0000, // 1272
+    0x0000, // 1273
+    0x0000, // 1274
+    0x0000, // 1275
+    0x0000, // 1276
+    0x0000, // 1277
+    0x0000, // 1278
+    0x0000, // 1279
+    0x0000, // 1280
+    0x0000, // 1281
+    0x0000, // 1282
+    0x0000, // 1283
+    0x0000, // 1284
+    0x0000, // 1285
+    0x0000, // 1286
+    0x0000, // 1287
+    0x0000, // 1288
+    0x0000, // 1289
+    0x0000, // 1290
+    0x0000, // 1291
+    0x0000, // 1292
+    0x0000, // 1293
+    0x0000, // 1294
+    0x0000, // 1295
+    0x0000, // 1296
+    0x0000, // 1297
+    0x0000, // 1298
+    0x0000, // 1299
+    0x0000, // 1300
+    0x0000, // 1301
+    0x0000, // 1302
+    0x0000, // 1303
+    0x0000, // 1304
+    0x0000, // 1305
+    0x0000, // 1306
+    0x0000, // 1307
+    0x0000, // 1308
+    0x0000, // 1309
+    0x0000, // 1310
+    0x0000, // 1311
+    0x0000, // 1312
+    0x0000, // 1313
+    0x0000, // 1314
+    0x0000, // 1315
+    0x0000, // 1316
+    0x0000, // 1317
+    0x0000, // 1318
+    0x0000, // 1319
+    0x0000, // 1320
+    0x0000, // 1321
+    0x0000, // 1322
+    0x0000, // 1323
+    0x0000, // 1324
+    0x0000, // 1325
+    0x0000, // 1326
+    0x0000, // 1327
+    0x0000, // 1328
+    0x0000, // 1329
+    0x0000, // 1330
+    0x0000, // 1331
+    0x0000, // 1332
+    0x0000, // 1333
+    0x0000, // 1334
+    0x0000, // 1335
+    0x0000, // 1336
+    0x0000, // 1337
+    0x0000, // 1338
+    0x0000, // 1339
+    0x0000, // 1340
+    0x0000, // 1341
+    0x0000, // 1342
+    0x0000, // 1343
+    0x0000, // 1344
+    0x0000, // 1345
+    0x0000, // 1346
+    0x0000, // 1347
+    0x0000, // 1348
+    0x0000, // 1349
+    0x0000, // 1350
+    0x0000, // 1351
+    0x0000, // 1352
+    0x0000, // 1353
+    0x0000, // 1354
+    0x0000, // 1355
+    0x0000, // 1356
+    0x0000, // 1357
+    0x0000, // 1358
+    0x0000, // 1359
+    0x0000, // 1360
+    0x0000, // 1361
+    0x0000, // 1362
+    0x0000, // 1363
+    0x0000, // 1364
+    0x0000, // 1365
+    0x0000, // 1366
+    0x0000, // 1367
+    0x0000, // 1368
+    0x0000, // 1369
+    0x0000, // 1370
+    0x0000, // 1371
+    0x0000, // 1372
+    0x0000, // 1373
+    0x0000, // 1374
+    0x0000, // 1375
+    0x0000, // 1376
+    0x0000, // 1377
+    0x0000, // 1378
+    0x0000, // 1379
+    0x0000, // 1380
+    0x0000, // 1381
+    0x0000, // 1382
+    0x0000, // 1383
+    0x0000, // 1384
+    0x0000, // 1385
+    0x0000, // 1386
+    0x0000, // 1387
+    0x0000, // 1388
+    0x0000, // 1389
+    0x0000, // 1390
+    0x0000, // 1391
+    0x0000, // 1392
+    0x0000, // 1393
+    0x0000, // 1394
+    0x0000, // 1395
+    0x0000, // 1396
+    0x0000, // 1397
+    0x0000, // 1398
+    0x0000, // 1399
+    0x0000, // 1400
+    0x0000, // 1401
+    0x0000, // 1402
+    0x0000, // 1403
+    0x0000, // 1404
+    0x0000, // 1405
+    0x0000, // 1406
+    0x0000, // 1407
+    0x0000, // 1408
+    0x0000, // 1409
+    0x4E02, // 1410
+    0x4E04, // 1411
+    0x4E05, // 1412
+    0x4E0C, // 1413
+    0x4E12, // 1414
+    0x4E1F, // 1415
+    0x4E23, // 1416
+    0x4E24, // 1417
+    0x4E28, // 1418
+    0x4E2B, // 1419
+    0x4E2E, // 1420
+    0x4E2F, // 1421
+    0x4E30, // 1422
+    0x4E35, // 1423
+    0x4E40, // 1424
+    0x4E41, // 1425
+    0x4E44, // 1426
+    0x4E47, // 1427
+    0x4E51, // 1428
+    0x4E5A, // 1429
+    0x4E5C, // 1430
+    0x4E63, // 1431
+    0x4E68, // 1432
+    0x4E69, // 1433
+    0x4E74, // 1434
+    0x4E75, // 1435
+    0x4E79, // 1436
+    0x4E7F, // 1437
+    0x4E8D, // 1438
+    0x4E96, // 1439
+    0x4E97, // 1440
+    0x4E9D, // 1441
+    0x4EAF, // 1442
+    0x4EB9, // 1443
+    0x4EC3, // 1444
+    0x4ED0, // 1445
+    0x4EDA, // 1446
+    0x4EDB, // 1447
+    0x4EE0, // 1448
+    0x4EE1, // 1449
+    0x4EE2, // 1450
+    0x4EE8, // 1451
+    0x4EEF, // 1452
+    0x4EF1, // 1453
+    0x4EF3, // 1454
+    0x4EF5, // 1455
+    0x4EFD, // 1456
+    0x4EFE, // 1457
+    0x4EFF, // 1458
+    0x4F00, // 1459
+    0x4F02, // 1460
+    0x4F03, // 1461
+    0x4F08, // 1462
+    0x4F0B, // 1463
+    0x4F0C, // 1464
+    0x4F12, // 1465
+    0x4F15, // 1466
+    0x4F16, // 1467
+    0x4F17, // 1468
+    0x4F19, // 1469
+    0x4F2E, // 1470
+    0x4F31, // 1471
+    0x4F60, // 1472
+    0x4F33, // 1473
+    0x4F35, // 1474
+    0x4F37, // 1475
+    0x4F39, // 1476
+    0x4F3B, // 1477
+    0x4F3E, // 1478
+    0x4F40, // 1479
+    0x4F42, // 1480
+    0x4F48, // 1481
+    0x4F49, // 1482
+    0x4F4B, // 1483
+    0x4F4C, // 1484
+    0x4F52, // 1485
+    0x4F54, // 1486
+    0x4F56, // 1487
+    0x4F58, // 1488
+    0x4F5F, // 1489
+    0x4F63, // 1490
+    0x4F6A, // 1491
+    0x4F6C, // 1492
+    0x4F6E, // 1493
+    0x4F71, // 1494
+    0x4F77, // 1495
+    0x4F78, // 1496
+    0x4F79, // 1497
+    0x4F7A, // 1498
+    0x4F7D, // 1499
+    0x4F7E, // 1500
+    0x4F81, // 1501
+    0x4F82, // 1502
+    0x4F84, // 1503
+    0x4F85, // 1504
+    0x4F89, // 1505
+    0x4F8A, // 1506
+    0x4F8C, // 1507
+    0x4F8E, // 1508
+    0x4F90, // 1509
+    0x4F92, // 1510
+    0x4F93, // 1511
+    0x4F94, // 1512
+    0x4F97, // 1513
+    0x4F99, // 1514
+    0x4F9A, // 1515
+    0x4F9E, // 1516
+    0x4F9F, // 1517
+    0x4FB2, // 1518
+    0x4FB7, // 1519
+    0x4FB9, // 1520
+    0x4FBB, // 1521
+    0x4FBC, // 1522
+    0x4FBD, // 1523
+    0x4FBE, // 1524
+    0x4FC0, // 1525
+    0x4FC1, // 1526
+    0x4FC5, // 1527
+    0x4FC6, // 1528
+    0x4FC8, // 1529
+    0x4FC9, // 1530
+    0x4FCB, // 1531
+    0x4FCC, // 1532
+    0x4FCD, // 1533
+    0x4FCF, // 1534
+    0x4FD2, // 1535
+    0x4FDC, // 1536
+    0x4FE0, // 1537
+    0x4FE2, // 1538
+    0x4FF0, // 1539
+    0x4FF2, // 1540
+    0x4FFC, // 1541
+    0x4FFD, // 1542
+    0x4FFF, // 1543
+    0x5000, // 1544
+    0x5001, // 1545
+    0x5004, // 1546
+    0x5007, // 1547
+    0x500A, // 1548
+    0x500C, // 1549
+    0x500E, // 1550
+    0x5010, // 1551
+    0x5013, // 1552
+    0x5017, // 1553
+    0x5018, // 1554
+    0x501B, // 1555
+    0x501C, // 1556
+    0x501D, // 1557
+    0x501E, // 1558
+    0x5022, // 1559
+    0x5027, // 1560
+    0x502E, // 1561
+    0x5030, // 1562
+    0x5032, // 1563
+    0x5033, // 1564
+    0x5035, // 1565
+    0x5040, // 1566
+    0x5041, // 1567
+    0x5042, // 1568
+    0x5045, // 1569
+    0x5046, // 1570
+    0x504A, // 1571
+    0x504C, // 1572
+    0x504E, // 1573
+    0x5051, // 1574
+    0x5052, // 1575
+    0x5053, // 1576
+    0x5057, // 1577
+    0x5059, // 1578
+    0x505F, // 1579
+    0x5060, // 1580
+    0x5062, // 1581
+    0x5063, // 1582
+    0x5066, // 1583
+    0x5067, // 1584
+    0x506A, // 1585
+    0x506D, // 1586
+    0x5070, // 1587
+    0x5071, // 1588
+    0x503B, // 1589
+    0x5081, // 1590
+    0x5083, // 1591
+    0x5084, // 1592
+    0x5086, // 1593
+    0x508A, // 1594
+    0x508E, // 1595
+    0x508F, // 1596
+    0x5090, // 1597
+    0x5092, // 1598
+    0x5093, // 1599
+    0x5094, // 1600
+    0x5096, // 1601
+    0x509B, // 1602
+    0x509C, // 1603
+    0x509E, // 1604
+    0x509F, // 1605
+    0x50A0, // 1606
+    0x50A1, // 1607
+    0x50A2, // 1608
+    0x50AA, // 1609
+    0x50AF, // 1610
+    0x50B0, // 1611
+    0x50B9, // 1612
+    0x50BA, // 1613
+    0x50BD, // 1614
+    0x50C0, // 1615
+    0x50C3, // 1616
+    0x50C4, // 1617
+    0x50C7, // 1618
+    0x50CC, // 1619
+    0x50CE, // 1620
+    0x50D0, // 1621
+    0x50D3, // 1622
+    0x50D4, // 1623
+    0x50D8, // 1624
+    0x50DC, // 1625
+    0x50DD, // 1626
+    0x50DF, // 1627
+    0x50E2, // 1628
+    0x50E4, // 1629
+    0x50E6, // 1630
+    0x50E8, // 1631
+    0x50E9, // 1632
+    0x50EF, // 1633
+    0x50F1, // 1634
+    0x50F6, // 1635
+    0x50FA, // 1636
+    0x50FE, // 1637
+    0x5103, // 1638
+    0x5106, // 1639
+    0x5107, // 1640
+    0x5108, // 1641
+    0x510B, // 1642
+    0x510C, // 1643
+    0x510D, // 1644
+    0x510E, // 1645
+    0x50F2, // 1646
+    0x5110, // 1647
+    0x5117, // 1648
+    0x5119, // 1649
+    0x511B, // 1650
+    0x511C, // 1651
+    0x511D, // 1652
+    0x511E, // 1653
+    0x5123, // 1654
+    0x5127, // 1655
+    0x5128, // 1656
+    0x512C, // 1657
+    0x512D, // 1658
+    0x512F, // 1659
+    0x5131, // 1660
+    0x5133, // 1661
+    0x5134, // 1662
+    0x5135, // 1663
+    0x5138, // 1664
+    0x5139, // 1665
+    0x5142, // 1666
+    0x514A, // 1667
+    0x514F, // 1668
+    0x5153, // 1669
+    0x5155, // 1670
+    0x5157, // 1671
+    0x5158, // 1672
+    0x515F, // 1673
+    0x5164, // 1674
+    0x5166, // 1675
+    0x517E, // 1676
+    0x5183, // 1677
+    0x5184, // 1678
+    0x518B, // 1679
+    0x518E, // 1680
+    0x5198, // 1681
+    0x519D, // 1682
+    0x51A1, // 1683
+    0x51A3, // 1684
+    0x51AD, // 1685
+    0x51B8, // 1686
+    0x51BA, // 1687
+    0x51BC, // 1688
+    0x51BE, // 1689
+    0x51BF, // 1690
+    0x51C2, // 1691
+    0x51C8, // 1692
+    0x51CF, // 1693
+    0x51D1, // 1694
+    0x51D2, // 1695
+    0x51D3, // 1696
+    0x51D5, // 1697
+    0x51D8, // 1698
+    0x51DE, // 1699
+    0x51E2, // 1700
+    0x51E5, // 1701
+    0x51EE, // 1702
+    0x51F2, // 1703
+    0x51F3, // 1704
+    0x51F4, // 1705
+    0x51F7, // 1706
+    0x5201, // 1707
+    0x5202, // 1708
+    0x5205, // 1709
+    0x5212, // 1710
+    0x5213, // 1711
+    0x5215, // 1712
+    0x5216, // 1713
+    0x5218, // 1714
+    0x5222, // 1715
+    0x5228, // 1716
+    0x5231, // 1717
+    0x5232, // 1718
+    0x5235, // 1719
+    0x523C, // 1720
+    0x5245, // 1721
+    0x5249, // 1722
+    0x5255, // 1723
+    0x5257, // 1724
+    0x5258, // 1725
+    0x525A, // 1726
+    0x525C, // 1727
+    0x525F, // 1728
+    0x5260, // 1729
+    0x5261, // 1730
+    0x5266, // 1731
+    0x526E, // 1732
+    0x5277, // 1733
+    0x5278, // 1734
+    0x5279, // 1735
+    0x5280, // 1736
+    0x5282, // 1737
+    0x5285, // 1738
+    0x528A, // 1739
+    0x528C, // 1740
+    0x5293, // 1741
+    0x5295, // 1742
+    0x5296, // 1743
+    0x5297, // 1744
+    0x5298, // 1745
+    0x529A, // 1746
+    0x529C, // 1747
+    0x52A4, // 1748
+    0x52A5, // 1749
+    0x52A6, // 1750
+    0x52A7, // 1751
+    0x52AF, // 1752
+    0x52B0, // 1753
+    0x52B6, // 1754
+    0x52B7, // 1755
+    0x52B8, // 1756
+    0x52BA, // 1757
+    0x52BB, // 1758
+    0x52BD, // 1759
+    0x52C0, // 1760
+    0x52C4, // 1761
+    0x52C6, // 1762
+    0x52C8, // 1763
+    0x52CC, // 1764
+    0x52CF, // 1765
+    0x52D1, // 1766
+    0x52D4, // 1767
+    0x52D6, // 1768
+    0x52DB, // 1769
+    0x52DC, // 1770
+    0x52E1, // 1771
+    0x52E5, // 1772
+    0x52E8, // 1773
+    0x52E9, // 1774
+    0x52EA, // 1775
+    0x52EC, // 1776
+    0x52F0, // 1777
+    0x52F1, // 1778
+    0x52F4, // 1779
+    0x52F6, // 1780
+    0x52F7, // 1781
+    0x5300, // 1782
+    0x5303, // 1783
+    0x530A, // 1784
+    0x530B, // 1785
+    0x530C, // 1786
+    0x5311, // 1787
+    0x5313, // 1788
+    0x5318, // 1789
+    0x531B, // 1790
+    0x531C, // 1791
+    0x531E, // 1792
+    0x531F, // 1793
+    0x5325, // 1794
+    0x5327, // 1795
+    0x5328, // 1796
+    0x5329, // 1797
+    0x532B, // 1798
+    0x532C, // 1799
+    0x532D, // 1800
+    0x5330, // 1801
+    0x5332, // 1802
+    0x5335, // 1803
+    0x533C, // 1804
+    0x533D, // 1805
+    0x533E, // 1806
+    0x5342, // 1807
+    0x534C, // 1808
+    0x534B, // 1809
+    0x5359, // 1810
+    0x535B, // 1811
+    0x5361, // 1812
+    0x5363, // 1813
+    0x5365, // 1814
+    0x536C, // 1815
+    0x536D, // 1816
+    0x5372, // 1817
+    0x5379, // 1818
+    0x537E, // 1819
+    0x5383, // 1820
+    0x5387, // 1821
+    0x5388, // 1822
+    0x538E, // 1823
+    0x5393, // 1824
+    0x5394, // 1825
+    0x5399, // 1826
+    0x539D, // 1827
+    0x53A1, // 1828
+    0x53A4, // 1829
+    0x53AA, // 1830
+    0x53AB, // 1831
+    0x53AF, // 1832
+    0x53B2, // 1833
+    0x53B4, // 1834
+    0x53B5, // 1835
+    0x53B7, // 1836
+    0x53B8, // 1837
+    0x53BA, // 1838
+    0x53BD, // 1839
+    0x53C0, // 1840
+    0x53C5, // 1841
+    0x53CF, // 1842
+    0x53D2, // 1843
+    0x53D3, // 1844
+    0x53D5, // 1845
+    0x53DA, // 1846
+    0x53DD, // 1847
+    0x53DE, // 1848
+    0x53E0, // 1849
+    0x53E6, // 1850
+    0x53E7, // 1851
+    0x53F5, // 1852
+    0x5402, // 1853
+    0x5413, // 1854
+    0x541A, // 1855
+    0x5421, // 1856
+    0x5427, // 1857
+    0x5428, // 1858
+    0x542A, // 1859
+    0x542F, // 1860
+    0x5431, // 1861
+    0x5434, // 1862
+    0x5435, // 1863
+    0x5443, // 1864
+    0x5444, // 1865
+    0x5447, // 1866
+    0x544D, // 1867
+    0x544F, // 1868
+    0x545E, // 1869
+    0x5462, // 1870
+    0x5464, // 1871
+    0x5466, // 1872
+    0x5467, // 1873
+    0x5469, // 1874
+    0x546B, // 1875
+    0x546D, // 1876
+    0x546E, // 1877
+    0x5474, // 1878
+    0x547F, // 1879
+    0x5481, // 1880
+    0x5483, // 1881
+    0x5485, // 1882
+    0x5488, // 1883
+    0x5489, // 1884
+    0x548D, // 1885
+    0x5491, // 1886
+    0x5495, // 1887
+    0x5496, // 1888
+    0x549C, // 1889
+    0x549F, // 1890
+    0x54A1, // 1891
+    0x54A6, // 1892
+    0x54A7, // 1893
+    0x54A9, // 1894
+    0x54AA, // 1895
+    0x54AD, // 1896
+    0x54AE, // 1897
+    0x54B1, // 1898
+    0x54B7, // 1899
+    0x54B9, // 1900
+    0x54BA, // 1901
+    0x54BB, // 1902
+    0x54BF, // 1903
+    0x54C6, // 1904
+    0x54CA, // 1905
+    0x54CD, // 1906
+    0x54CE, // 1907
+    0x54E0, // 1908
+    0x54EA, // 1909
+    0x54EC, // 1910
+    0x54EF, // 1911
+    0x54F6, // 1912
+    0x54FC, // 1913
+    0x54FE, // 1914
+    0x54FF, // 1915
+    0x5500, // 1916
+    0x5501, // 1917
+    0x5505, // 1918
+    0x5508, // 1919
+    0x5509, // 1920
+    0x550C, // 1921
+    0x550D, // 1922
+    0x550E, // 1923
+    0x5515, // 1924
+    0x552A, // 1925
+    0x552B, // 1926
+    0x5532, // 1927
+    0x5535, // 1928
+    0x5536, // 1929
+    0x553B, // 1930
+    0x553C, // 1931
+    0x553D, // 1932
+    0x5541, // 1933
+    0x5547, // 1934
+    0x5549, // 1935
+    0x554A, // 1936
+    0x554D, // 1937
+    0x5550, // 1938
+    0x5551, // 1939
+    0x5558, // 1940
+    0x555A, // 1941
+    0x555B, // 1942
+    0x555E, // 1943
+    0x5560, // 1944
+    0x5561, // 1945
+    0x5564, // 1946
+    0x5566, // 1947
+    0x557F, // 1948
+    0x5581, // 1949
+    0x5582, // 1950
+    0x5586, // 1951
+    0x5588, // 1952
+    0x558E, // 1953
+    0x558F, // 1954
+    0x5591, // 1955
+    0x5592, // 1956
+    0x5593, // 1957
+    0x5594, // 1958
+    0x5597, // 1959
+    0x55A3, // 1960
+    0x55A4, // 1961
+    0x55AD, // 1962
+    0x55B2, // 1963
+    0x55BF, // 1964
+    0x55C1, // 1965
+    0x55C3, // 1966
+    0x55C6, // 1967
+    0x55C9, // 1968
+    0x55CB, // 1969
+    0x55CC, // 1970
+    0x55CE, // 1971
+    0x55D1, // 1972
+    0x55D2, // 1973
+    0x55D3, // 1974
+    0x55D7, // 1975
+    0x55D8, // 1976
+    0x55DB, // 1977
+    0x55DE, // 1978
+    0x55E2, // 1979
+    0x55E9, // 1980
+    0x55F6, // 1981
+    0x55FF, // 1982
+    0x5605, // 1983
+    0x5608, // 1984
+    0x560A, // 1985
+    0x560D, // 1986
+    0x560E, // 1987
+    0x560F, // 1988
+    0x5610, // 1989
+    0x5611, // 1990
+    0x5612, // 1991
+    0x5619, // 1992
+    0x562C, // 1993
+    0x5630, // 1994
+    0x5633, // 1995
+    0x5635, // 1996
+    0x5637, // 1997
+    0x5639, // 1998
+    0x563B, // 1999
+    0x563C, // 2000
+    0x563D, // 2001
+    0x563F, // 2002
+    0x5640, // 2003
+    0x5641, // 2004
+    0x5643, // 2005
+    0x5644, // 2006
+    0x5646, // 2007
+    0x5649, // 2008
+    0x564B, // 2009
+    0x564D, // 2010
+    0x564F, // 2011
+    0x5654, // 2012
+    0x565E, // 2013
+    0x5660, // 2014
+    0x5661, // 2015
+    0x5662, // 2016
+    0x5663, // 2017
+    0x5666, // 2018
+    0x5669, // 2019
+    0x566D, // 2020
+    0x566F, // 2021
+    0x5671, // 2022
+    0x5672, // 2023
+    0x5675, // 2024
+    0x5684, // 2025
+    0x5685, // 2026
+    0x5688, // 2027
+    0x568B, // 2028
+    0x568C, // 2029
+    0x5695, // 2030
+    0x5699, // 2031
+    0x569A, // 2032
+    0x569D, // 2033
+    0x569E, // 2034
+    0x569F, // 2035
+    0x56A6, // 2036
+    0x56A7, // 2037
+    0x56A8, // 2038
+    0x56A9, // 2039
+    0x56AB, // 2040
+    0x56AC, // 2041
+    0x56AD, // 2042
+    0x56B1, // 2043
+    0x56B3, // 2044
+    0x56B7, // 2045
+    0x56BE, // 2046
+    0x56C5, // 2047
+    0x56C9, // 2048
+    0x56CA, // 2049
+    0x56CB, // 2050
+    0x56CF, // 2051
+    0x56D0, // 2052
+    0x56CC, // 2053
+    0x56CD, // 2054
+    0x56D9, // 2055
+    0x56DC, // 2056
+    0x56DD, // 2057
+    0x56DF, // 2058
+    0x56E1, // 2059
+    0x56E4, // 2060
+    0x56E5, // 2061
+    0x56E6, // 2062
+    0x56E7, // 2063
+    0x56E8, // 2064
+    0x56F1, // 2065
+    0x56EB, // 2066
+    0x56ED, // 2067
+    0x56F6, // 2068
+    0x56F7, // 2069
+    0x5701, // 2070
+    0x5702, // 2071
+    0x5707, // 2072
+    0x570A, // 2073
+    0x570C, // 2074
+    0x5711, // 2075
+    0x5715, // 2076
+    0x571A, // 2077
+    0x571B, // 2078
+    0x571D, // 2079
+    0x5720, // 2080
+    0x5722, // 2081
+    0x5723, // 2082
+    0x5724, // 2083
+    0x5725, // 2084
+    0x5729, // 2085
+    0x572A, // 2086
+    0x572C, // 2087
+    0x572E, // 2088
+    0x572F, // 2089
+    0x5733, // 2090
+    0x5734, // 2091
+    0x573D, // 2092
+    0x573E, // 2093
+    0x573F, // 2094
+    0x5745, // 2095
+    0x5746, // 2096
+    0x574C, // 2097
+    0x574D, // 2098
+    0x5752, // 2099
+    0x5762, // 2100
+    0x5765, // 2101
+    0x5767, // 2102
+    0x5768, // 2103
+    0x576B, // 2104
+    0x576D, // 2105
+    0x576E, // 2106
+    0x576F, // 2107
+    0x5770, // 2108
+    0x5771, // 2109
+    0x5773, // 2110
+    0x5774, // 2111
+    0x5775, // 2112
+    0x5777, // 2113
+    0x5779, // 2114
+    0x577A, // 2115
+    0x577B, // 2116
+    0x577C, // 2117
+    0x577E, // 2118
+    0x5781, // 2119
+    0x5783, // 2120
+    0x578C, // 2121
+    0x5794, // 2122
+    0x5797, // 2123
+    0x5799, // 2124
+    0x579A, // 2125
+    0x579C, // 2126
+    0x579D, // 2127
+    0x579E, // 2128
+    0x579F, // 2129
+    0x57A1, // 2130
+    0x5795, // 2131
+    0x57A7, // 2132
+    0x57A8, // 2133
+    0x57A9, // 2134
+    0x57AC, // 2135
+    0x57B8, // 2136
+    0x57BD, // 2137
+    0x57C7, // 2138
+    0x57C8, // 2139
+    0x57CC, // 2140
+    0x57CF, // 2141
+    0x57D5, // 2142
+    0x57DD, // 2143
+    0x57DE, // 2144
+    0x57E4, // 2145
+    0x57E6, // 2146
+    0x57E7, // 2147
+    0x57E9, // 2148
+    0x57ED, // 2149
+    0x57F0, // 2150
+    0x57F5, // 2151
+    0x57F6, // 2152
+    0x57F8, // 2153
+    0x57FD, // 2154
+    0x57FE, // 2155
+    0x57FF, // 2156
+    0x5803, // 2157
+    0x5804, // 2158
+    0x5808, // 2159
+    0x5809, // 2160
+    0x57E1, // 2161
+    0x580C, // 2162
+    0x580D, // 2163
+    0x581B, // 2164
+    0x581E, // 2165
+    0x581F, // 2166
+    0x5820, // 2167
+    0x5826, // 2168
+    0x5827, // 2169
+    0x582D, // 2170
+    0x5832, // 2171
+    0x5839, // 2172
+    0x583F, // 2173
+    0x5849, // 2174
+    0x584C, // 2175
+    0x584D, // 2176
+    0x584F, // 2177
+    0x5850, // 2178
+    0x5855, // 2179
+    0x585F, // 2180
+    0x5861, // 2181
+    0x5864, // 2182
+    0x5867, // 2183
+    0x5868, // 2184
+    0x5878, // 2185
+    0x587C, // 2186
+    0x587F, // 2187
+    0x5880, // 2188
+    0x5881, // 2189
+    0x5887, // 2190
+    0x5888, // 2191
+    0x5889, // 2192
+    0x588A, // 2193
+    0x588C, // 2194
+    0x588D, // 2195
+    0x588F, // 2196
+    0x5890, // 2197
+    0x5894, // 2198
+    0x5896, // 2199
+    0x589D, // 2200
+    0x58A0, // 2201
+    0x58A1, // 2202
+    0x58A2, // 2203
+    0x58A6, // 2204
+    0x58A9, // 2205
+    0x58B1, // 2206
+    0x58B2, // 2207
+    0x58C4, // 2208
+    0x58BC, // 2209
+    0x58C2, // 2210
+    0x58C8, // 2211
+    0x58CD, // 2212
+    0x58CE, // 2213
+    0x58D0, // 2214
+    0x58D2, // 2215
+    0x58D4, // 2216
+    0x58D6, // 2217
+    0x58DA, // 2218
+    0x58DD, // 2219
+    0x58E1, // 2220
+    0x58E2, // 2221
+    0x58E9, // 2222
+    0x58F3, // 2223
+    0x5905, // 2224
+    0x5906, // 2225
+    0x590B, // 2226
+    0x590C, // 2227
+    0x5912, // 2228
+    0x5913, // 2229
+    0x5914, // 2230
+    0x8641, // 2231
+    0x591D, // 2232
+    0x5921, // 2233
+    0x5923, // 2234
+    0x5924, // 2235
+    0x5928, // 2236
+    0x592F, // 2237
+    0x5930, // 2238
+    0x5933, // 2239
+    0x5935, // 2240
+    0x5936, // 2241
+    0x593F, // 2242
+    0x5943, // 2243
+    0x5946, // 2244
+    0x5952, // 2245
+    0x5953, // 2246
+    0x5959, // 2247
+    0x595B, // 2248
+    0x595D, // 2249
+    0x595E, // 2250
+    0x595F, // 2251
+    0x5961, // 2252
+    0x5963, // 2253
+    0x596B, // 2254
+    0x596D, // 2255
+    0x596F, // 2256
+    0x5972, // 2257
+    0x5975, // 2258
+    0x5976, // 2259
+    0x5979, // 2260
+    0x597B, // 2261
+    0x597C, // 2262
+    0x598B, // 2263
+    0x598C, // 2264
+    0x598E, // 2265
+    0x5992, // 2266
+    0x5995, // 2267
+    0x5997, // 2268
+    0x599F, // 2269
+    0x59A4, // 2270
+    0x59A7, // 2271
+    0x59AD, // 2272
+    0x59AE, // 2273
+    0x59AF, // 2274
+    0x59B0, // 2275
+    0x59B3, // 2276
+    0x59B7, // 2277
+    0x59BA, // 2278
+    0x59BC, // 2279
+    0x59C1, // 2280
+    0x59C3, // 2281
+    0x59C4, // 2282
+    0x59C8, // 2283
+    0x59CA, // 2284
+    0x59CD, // 2285
+    0x59D2, // 2286
+    0x59DD, // 2287
+    0x59DE, // 2288
+    0x59DF, // 2289
+    0x59E3, // 2290
+    0x59E4, // 2291
+    0x59E7, // 2292
+    0x59EE, // 2293
+    0x59EF, // 2294
+    0x59F1, // 2295
+    0x59F2, // 2296
+    0x59F4, // 2297
+    0x59F7, // 2298
+    0x5A00, // 2299
+    0x5A04, // 2300
+    0x5A0C, // 2301
+    0x5A0D, // 2302
+    0x5A0E, // 2303
+    0x5A12, // 2304
+    0x5A13, // 2305
+    0x5A1E, // 2306
+    0x5A23, // 2307
+    0x5A24, // 2308
+    0x5A27, // 2309
+    0x5A28, // 2310
+    0x5A2A, // 2311
+    0x5A2D, // 2312
+    0x5A30, // 2313
+    0x5A44, // 2314
+    0x5A45, // 2315
+    0x5A47, // 2316
+    0x5A48, // 2317
+    0x5A4C, // 2318
+    0x5A50, // 2319
+    0x5A55, // 2320
+    0x5A5E, // 2321
+    0x5A63, // 2322
+    0x5A65, // 2323
+    0x5A67, // 2324
+    0x5A6D, // 2325
+    0x5A77, // 2326
+    0x5A7A, // 2327
+    0x5A7B, // 2328
+    0x5A7E, // 2329
+    0x5A8B, // 2330
+    0x5A90, // 2331
+    0x5A93, // 2332
+    0x5A96, // 2333
+    0x5A99, // 2334
+    0x5A9C, // 2335
+    0x5A9E, // 2336
+    0x5A9F, // 2337
+    0x5AA0, // 2338
+    0x5AA2, // 2339
+    0x5AA7, // 2340
+    0x5AAC, // 2341
+    0x5AB1, // 2342
+    0x5AB2, // 2343
+    0x5AB3, // 2344
+    0x5AB5, // 2345
+    0x5AB8, // 2346
+    0x5ABA, // 2347
+    0x5ABB, // 2348
+    0x5ABF, // 2349
+    0x5AC4, // 2350
+    0x5AC6, // 2351
+    0x5AC8, // 2352
+    0x5ACF, // 2353
+    0x5ADA, // 2354
+    0x5ADC, // 2355
+    0x5AE0, // 2356
+    0x5AE5, // 2357
+    0x5AEA, // 2358
+    0x5AEE, // 2359
+    0x5AF5, // 2360
+    0x5AF6, // 2361
+    0x5AFD, // 2362
+    0x5B00, // 2363
+    0x5B01, // 2364
+    0x5B08, // 2365
+    0x5B17, // 2366
+    0x5B34, // 2367
+    0x5B19, // 2368
+    0x5B1B, // 2369
+    0x5B1D, // 2370
+    0x5B21, // 2371
+    0x5B25, // 2372
+    0x5B2D, // 2373
+    0x5B38, // 2374
+    0x5B41, // 2375
+    0x5B4B, // 2376
+    0x5B4C, // 2377
+    0x5B52, // 2378
+    0x5B56, // 2379
+    0x5B5E, // 2380
+    0x5B68, // 2381
+    0x5B6E, // 2382
+    0x5B6F, // 2383
+    0x5B7C, // 2384
+    0x5B7D, // 2385
+    0x5B7E, // 2386
+    0x5B7F, // 2387
+    0x5B81, // 2388
+    0x5B84, // 2389
+    0x5B86, // 2390
+    0x5B8A, // 2391
+    0x5B8E, // 2392
+    0x5B90, // 2393
+    0x5B91, // 2394
+    0x5B93, // 2395
+    0x5B94, // 2396
+    0x5B96, // 2397
+    0x5BA8, // 2398
+    0x5BA9, // 2399
+    0x5BAC, // 2400
+    0x5BAD, // 2401
+    0x5BAF, // 2402
+    0x5BB1, // 2403
+    0x5BB2, // 2404
+    0x5BB7, // 2405
+    0x5BBA, // 2406
+    0x5BBC, // 2407
+    0x5BC0, // 2408
+    0x5BC1, // 2409
+    0x5BCD, // 2410
+    0x5BCF, // 2411
+    0x5BD6, // 2412
+    0x5BD7, // 2413
+    0x5BD8, // 2414
+    0x5BD9, // 2415
+    0x5BDA, // 2416
+    0x5BE0, // 2417
+    0x5BEF, // 2418
+    0x5BF1, // 2419
+    0x5BF4, // 2420
+    0x5BFD, // 2421
+    0x5C0C, // 2422
+    0x5C17, // 2423
+    0x5C1E, // 2424
+    0x5C1F, // 2425
+    0x5C23, // 2426
+    0x5C26, // 2427
+    0x5C29, // 2428
+    0x5C2B, // 2429
+    0x5C2C, // 2430
+    0x5C2E, // 2431
+    0x5C30, // 2432
+    0x5C32, // 2433
+    0x5C35, // 2434
+    0x5C36, // 2435
+    0x5C59, // 2436
+    0x5C5A, // 2437
+    0x5C5C, // 2438
+    0x5C62, // 2439
+    0x5C63, // 2440
+    0x5C67, // 2441
+    0x5C68, // 2442
+    0x5C69, // 2443
+    0x5C6D, // 2444
+    0x5C70, // 2445
+    0x5C74, // 2446
+    0x5C75, // 2447
+    0x5C7A, // 2448
+    0x5C7B, // 2449
+    0x5C7C, // 2450
+    0x5C7D, // 2451
+    0x5C87, // 2452
+    0x5C88, // 2453
+    0x5C8A, // 2454
+    0x5C8F, // 2455
+    0x5C92, // 2456
+    0x5C9D, // 2457
+    0x5C9F, // 2458
+    0x5CA0, // 2459
+    0x5CA2, // 2460
+    0x5CA3, // 2461
+    0x5CA6, // 2462
+    0x5CAA, // 2463
+    0x5CB2, // 2464
+    0x5CB4, // 2465
+    0x5CB5, // 2466
+    0x5CBA, // 2467
+    0x5CC9, // 2468
+    0x5CCB, // 2469
+    0x5CD2, // 2470
+    0x5CDD, // 2471
+    0x5CD7, // 2472
+    0x5CEE, // 2473
+    0x5CF1, // 2474
+    0x5CF2, // 2475
+    0x5CF4, // 2476
+    0x5D01, // 2477
+    0x5D06, // 2478
+    0x5D0D, // 2479
+    0x5D12, // 2480
+    0x5D2B, // 2481
+    0x5D23, // 2482
+    0x5D24, // 2483
+    0x5D26, // 2484
+    0x5D27, // 2485
+    0x5D31, // 2486
+    0x5D34, // 2487
+    0x5D39, // 2488
+    0x5D3D, // 2489
+    0x5D3F, // 2490
+    0x5D42, // 2491
+    0x5D43, // 2492
+    0x5D46, // 2493
+    0x5D48, // 2494
+    0x5D55, // 2495
+    0x5D51, // 2496
+    0x5D59, // 2497
+    0x5D4A, // 2498
+    0x5D5F, // 2499
+    0x5D60, // 2500
+    0x5D61, // 2501
+    0x5D62, // 2502
+    0x5D64, // 2503
+    0x5D6A, // 2504
+    0x5D6D, // 2505
+    0x5D70, // 2506
+    0x5D79, // 2507
+    0x5D7A, // 2508
+    0x5D7E, // 2509
+    0x5D7F, // 2510
+    0x5D81, // 2511
+    0x5D83, // 2512
+    0x5D88, // 2513
+    0x5D8A, // 2514
+    0x5D92, // 2515
+    0x5D93, // 2516
+    0x5D94, // 2517
+    0x5D95, // 2518
+    0x5D99, // 2519
+    0x5D9B, // 2520
+    0x5D9F, // 2521
+    0x5DA0, // 2522
+    0x5DA7, // 2523
+    0x5DAB, // 2524
+    0x5DB0, // 2525
+    0x5DB4, // 2526
+    0x5DB8, // 2527
+    0x5DB9, // 2528
+    0x5DC3, // 2529
+    0x5DC7, // 2530
+    0x5DCB, // 2531
+    0x5DD0, // 2532
+    0x5DCE, // 2533
+    0x5DD8, // 2534
+    0x5DD9, // 2535
+    0x5DE0, // 2536
+    0x5DE4, // 2537
+    0x5DE9, // 2538
+    0x5DF8, // 2539
+    0x5DF9, // 2540
+    0x5E00, // 2541
+    0x5E07, // 2542
+    0x5E0D, // 2543
+    0x5E12, // 2544
+    0x5E14, // 2545
+    0x5E15, // 2546
+    0x5E18, // 2547
+    0x5E1F, // 2548
+    0x5E20, // 2549
+    0x5E2E, // 2550
+    0x5E28, // 2551
+    0x5E32, // 2552
+    0x5E35, // 2553
+    0x5E3E, // 2554
+    0x5E4B, // 2555
+    0x5E50, // 2556
+    0x5E49, // 2557
+    0x5E51, // 2558
+    0x5E56, // 2559
+    0x5E58, // 2560
+    0x5E5B, // 2561
+    0x5E5C, // 2562
+    0x5E5E, // 2563
+    0x5E68, // 2564
+    0x5E6A, // 2565
+    0x5E6B, // 2566
+    0x5E6C, // 2567
+    0x5E6D, // 2568
+    0x5E6E, // 2569
+    0x5E70, // 2570
+    0x5E80, // 2571
+    0x5E8B, // 2572
+    0x5E8E, // 2573
+    0x5EA2, // 2574
+    0x5EA4, // 2575
+    0x5EA5, // 2576
+    0x5EA8, // 2577
+    0x5EAA, // 2578
+    0x5EAC, // 2579
+    0x5EB1, // 2580
+    0x5EB3, // 2581
+    0x5EBD, // 2582
+    0x5EBE, // 2583
+    0x5EBF, // 2584
+    0x5EC6, // 2585
+    0x5ECC, // 2586
+    0x5ECB, // 2587
+    0x5ECE, // 2588
+    0x5ED1, // 2589
+    0x5ED2, // 2590
+    0x5ED4, // 2591
+    0x5ED5, // 2592
+    0x5EDC, // 2593
+    0x5EDE, // 2594
+    0x5EE5, // 2595
+    0x5EEB, // 2596
+    0x5F02, // 2597
+    0x5F06, // 2598
+    0x5F07, // 2599
+    0x5F08, // 2600
+    0x5F0E, // 2601
+    0x5F19, // 2602
+    0x5F1C, // 2603
+    0x5F1D, // 2604
+    0x5F21, // 2605
+    0x5F22, // 2606
+    0x5F23, // 2607
+    0x5F24, // 2608
+    0x5F28, // 2609
+    0x5F2B, // 2610
+    0x5F2C, // 2611
+    0x5F2E, // 2612
+    0x5F30, // 2613
+    0x5F34, // 2614
+    0x5F36, // 2615
+    0x5F3B, // 2616
+    0x5F3D, // 2617
+    0x5F3F, // 2618
+    0x5F40, // 2619
+    0x5F44, // 2620
+    0x5F45, // 2621
+    0x5F47, // 2622
+    0x5F4D, // 2623
+    0x5F50, // 2624
+    0x5F54, // 2625
+    0x5F58, // 2626
+    0x5F5B, // 2627
+    0x5F60, // 2628
+    0x5F63, // 2629
+    0x5F64, // 2630
+    0x5F67, // 2631
+    0x5F6F, // 2632
+    0x5F72, // 2633
+    0x5F74, // 2634
+    0x5F75, // 2635
+    0x5F78, // 2636
+    0x5F7A, // 2637
+    0x5F7D, // 2638
+    0x5F7E, // 2639
+    0x5F89, // 2640
+    0x5F8D, // 2641
+    0x5F8F, // 2642
+    0x5F96, // 2643
+    0x5F9C, // 2644
+    0x5F9D, // 2645
+    0x5FA2, // 2646
+    0x5FA7, // 2647
+    0x5FAB, // 2648
+    0x5FA4, // 2649
+    0x5FAC, // 2650
+    0x5FAF, // 2651
+    0x5FB0, // 2652
+    0x5FB1, // 2653
+    0x5FB8, // 2654
+    0x5FC4, // 2655
+    0x5FC7, // 2656
+    0x5FC8, // 2657
+    0x5FC9, // 2658
+    0x5FCB, // 2659
+    0x5FD0, // 2660
+    0x5FD1, // 2661
+    0x5FD2, // 2662
+    0x5FD3, // 2663
+    0x5FD4, // 2664
+    0x5FDE, // 2665
+    0x5FE1, // 2666
+    0x5FE2, // 2667
+    0x5FE8, // 2668
+    0x5FE9, // 2669
+    0x5FEA, // 2670
+    0x5FEC, // 2671
+    0x5FED, // 2672
+    0x5FEE, // 2673
+    0x5FEF, // 2674
+    0x5FF2, // 2675
+    0x5FF3, // 2676
+    0x5FF6, // 2677
+    0x5FFA, // 2678
+    0x5FFC, // 2679
+    0x6007, // 2680
+    0x600A, // 2681
+    0x600D, // 2682
+    0x6013, // 2683
+    0x6014, // 2684
+    0x6017, // 2685
+    0x6018, // 2686
+    0x601A, // 2687
+    0x601F, // 2688
+    0x6024, // 2689
+    0x602D, // 2690
+    0x6033, // 2691
+    0x6035, // 2692
+    0x6040, // 2693
+    0x6047, // 2694
+    0x6048, // 2695
+    0x6049, // 2696
+    0x604C, // 2697
+    0x6051, // 2698
+    0x6054, // 2699
+    0x6056, // 2700
+    0x6057, // 2701
+    0x605D, // 2702
+    0x6061, // 2703
+    0x6067, // 2704
+    0x6071, // 2705
+    0x607E, // 2706
+    0x607F, // 2707
+    0x6082, // 2708
+    0x6086, // 2709
+    0x6088, // 2710
+    0x608A, // 2711
+    0x608E, // 2712
+    0x6091, // 2713
+    0x6093, // 2714
+    0x6095, // 2715
+    0x6098, // 2716
+    0x609D, // 2717
+    0x609E, // 2718
+    0x60A2, // 2719
+    0x60A4, // 2720
+    0x60A5, // 2721
+    0x60A8, // 2722
+    0x60B0, // 2723
+    0x60B1, // 2724
+    0x60B7, // 2725
+    0x60BB, // 2726
+    0x60BE, // 2727
+    0x60C2, // 2728
+    0x60C4, // 2729
+    0x60C8, // 2730
+    0x60C9, // 2731
+    0x60CA, // 2732
+    0x60CB, // 2733
+    0x60CE, // 2734
+    0x60CF, // 2735
+    0x60D4, // 2736
+    0x60D5, // 2737
+    0x60D9, // 2738
+    0x60DB, // 2739
+    0x60DD, // 2740
+    0x60DE, // 2741
+    0x60E2, // 2742
+    0x60E5, // 2743
+    0x60F2, // 2744
+    0x60F5, // 2745
+    0x60F8, // 2746
+    0x60FC, // 2747
+    0x60FD, // 2748
+    0x6102, // 2749
+    0x6107, // 2750
+    0x610A, // 2751
+    0x610C, // 2752
+    0x6110, // 2753
+    0x6111, // 2754
+    0x6112, // 2755
+    0x6113, // 2756
+    0x6114, // 2757
+    0x6116, // 2758
+    0x6117, // 2759
+    0x6119, // 2760
+    0x611C, // 2761
+    0x611E, // 2762
+    0x6122, // 2763
+    0x612A, // 2764
+    0x612B, // 2765
+    0x6130, // 2766
+    0x6131, // 2767
+    0x6135, // 2768
+    0x6136, // 2769
+    0x6137, // 2770
+    0x6139, // 2771
+    0x6141, // 2772
+    0x6145, // 2773
+    0x6146, // 2774
+    0x6149, // 2775
+    0x615E, // 2776
+    0x6160, // 2777
+    0x616C, // 2778
+    0x6172, // 2779
+    0x6178, // 2780
+    0x617B, // 2781
+    0x617C, // 2782
+    0x617F, // 2783
+    0x6180, // 2784
+    0x6181, // 2785
+    0x6183, // 2786
+    0x6184, // 2787
+    0x618B, // 2788
+    0x618D, // 2789
+    0x6192, // 2790
+    0x6193, // 2791
+    0x6197, // 2792
+    0x6198, // 2793
+    0x619C, // 2794
+    0x619D, // 2795
+    0x619F, // 2796
+    0x61A0, // 2797
+    0x61A5, // 2798
+    0x61A8, // 2799
+    0x61AA, // 2800
+    0x61AD, // 2801
+    0x61B8, // 2802
+    0x61B9, // 2803
+    0x61BC, // 2804
+    0x61C0, // 2805
+    0x61C1, // 2806
+    0x61C2, // 2807
+    0x61CE, // 2808
+    0x61CF, // 2809
+    0x61D5, // 2810
+    0x61DC, // 2811
+    0x61DD, // 2812
+    0x61DE, // 2813
+    0x61DF, // 2814
+    0x61E1, // 2815
+    0x61E2, // 2816
+    0x61E7, // 2817
+    0x61E9, // 2818
+    0x61E5, // 2819
+    0x61EC, // 2820
+    0x61ED, // 2821
+    0x61EF, // 2822
+    0x6201, // 2823
+    0x6203, // 2824
+    0x6204, // 2825
+    0x6207, // 2826
+    0x6213, // 2827
+    0x6215, // 2828
+    0x621C, // 2829
+    0x6220, // 2830
+    0x6222, // 2831
+    0x6223, // 2832
+    0x6227, // 2833
+    0x6229, // 2834
+    0x622B, // 2835
+    0x6239, // 2836
+    0x623D, // 2837
+    0x6242, // 2838
+    0x6243, // 2839
+    0x6244, // 2840
+    0x6246, // 2841
+    0x624C, // 2842
+    0x6250, // 2843
+    0x6251, // 2844
+    0x6252, // 2845
+    0x6254, // 2846
+    0x6256, // 2847
+    0x625A, // 2848
+    0x625C, // 2849
+    0x6264, // 2850
+    0x626D, // 2851
+    0x626F, // 2852
+    0x6273, // 2853
+    0x627A, // 2854
+    0x627D, // 2855
+    0x628D, // 2856
+    0x628E, // 2857
+    0x628F, // 2858
+    0x6290, // 2859
+    0x62A6, // 2860
+    0x62A8, // 2861
+    0x62B3, // 2862
+    0x62B6, // 2863
+    0x62B7, // 2864
+    0x62BA, // 2865
+    0x62BE, // 2866
+    0x62BF, // 2867
+    0x62C4, // 2868
+    0x62CE, // 2869
+    0x62D5, // 2870
+    0x62D6, // 2871
+    0x62DA, // 2872
+    0x62EA, // 2873
+    0x62F2, // 2874
+    0x62F4, // 2875
+    0x62FC, // 2876
+    0x62FD, // 2877
+    0x6303, // 2878
+    0x6304, // 2879
+    0x630A, // 2880
+    0x630B, // 2881
+    0x630D, // 2882
+    0x6310, // 2883
+    0x6313, // 2884
+    0x6316, // 2885
+    0x6318, // 2886
+    0x6329, // 2887
+    0x632A, // 2888
+    0x632D, // 2889
+    0x6335, // 2890
+    0x6336, // 2891
+    0x6339, // 2892
+    0x633C, // 2893
+    0x6341, // 2894
+    0x6342, // 2895
+    0x6343, // 2896
+    0x6344, // 2897
+    0x6346, // 2898
+    0x634A, // 2899
+    0x634B, // 2900
+    0x634E, // 2901
+    0x6352, // 2902
+    0x6353, // 2903
+    0x6354, // 2904
+    0x6358, // 2905
+    0x635B, // 2906
+    0x6365, // 2907
+    0x6366, // 2908
+    0x636C, // 2909
+    0x636D, // 2910
+    0x6371, // 2911
+    0x6374, // 2912
+    0x6375, // 2913
+    0x6378, // 2914
+    0x637C, // 2915
+    0x637D, // 2916
+    0x637F, // 2917
+    0x6382, // 2918
+    0x6384, // 2919
+    0x6387, // 2920
+    0x638A, // 2921
+    0x6390, // 2922
+    0x6394, // 2923
+    0x6395, // 2924
+    0x6399, // 2925
+    0x639A, // 2926
+    0x639E, // 2927
+    0x63A4, // 2928
+    0x63A6, // 2929
+    0x63AD, // 2930
+    0x63AE, // 2931
+    0x63AF, // 2932
+    0x63BD, // 2933
+    0x63C1, // 2934
+    0x63C5, // 2935
+    0x63C8, // 2936
+    0x63CE, // 2937
+    0x63D1, // 2938
+    0x63D3, // 2939
+    0x63D4, // 2940
+    0x63D5, // 2941
+    0x63DC, // 2942
+    0x63E0, // 2943
+    0x63E5, // 2944
+    0x63EA, // 2945
+    0x63EC, // 2946
+    0x63F2, // 2947
+    0x63F3, // 2948
+    0x63F5, // 2949
+    0x63F8, // 2950
+    0x63F9, // 2951
+    0x6409, // 2952
+    0x640A, // 2953
+    0x6410, // 2954
+    0x6412, // 2955
+    0x6414, // 2956
+    0x6418, // 2957
+    0x641E, // 2958
+    0x6420, // 2959
+    0x6422, // 2960
+    0x6424, // 2961
+    0x6425, // 2962
+    0x6429, // 2963
+    0x642A, // 2964
+    0x642F, // 2965
+    0x6430, // 2966
+    0x6435, // 2967
+    0x643D, // 2968
+    0x643F, // 2969
+    0x644B, // 2970
+    0x644F, // 2971
+    0x6451, // 2972
+    0x6452, // 2973
+    0x6453, // 2974
+    0x6454, // 2975
+    0x645A, // 2976
+    0x645B, // 2977
+    0x645C, // 2978
+    0x645D, // 2979
+    0x645F, // 2980
+    0x6460, // 2981
+    0x6461, // 2982
+    0x6463, // 2983
+    0x646D, // 2984
+    0x6473, // 2985
+    0x6474, // 2986
+    0x647B, // 2987
+    0x647D, // 2988
+    0x6485, // 2989
+    0x6487, // 2990
+    0x648F, // 2991
+    0x6490, // 2992
+    0x6491, // 2993
+    0x6498, // 2994
+    0x6499, // 2995
+    0x649B, // 2996
+    0x649D, // 2997
+    0x649F, // 2998
+    0x64A1, // 2999
+    0x64A3, // 3000
+    0x64A6, // 3001
+    0x64A8, // 3002
+    0x64AC, // 3003
+    0x64B3, // 3004
+    0x64BD, // 3005
+    0x64BE, // 3006
+    0x64BF, // 3007
+    0x64C4, // 3008
+    0x64C9, // 3009
+    0x64CA, // 3010
+    0x64CB, // 3011
+    0x64CC, // 3012
+    0x64CE, // 3013
+    0x64D0, // 3014
+    0x64D1, // 3015
+    0x64D5, // 3016
+    0x64D7, // 3017
+    0x64E4, // 3018
+    0x64E5, // 3019
+    0x64E9, // 3020
+    0x64EA, // 3021
+    0x64ED, // 3022
+    0x64F0, // 3023
+    0x64F5, // 3024
+    0x64F7, // 3025
+    0x64FB, // 3026
+    0x64FF, // 3027
+    0x6501, // 3028
+    0x6504, // 3029
+    0x6508, // 3030
+    0x6509, // 3031
+    0x650A, // 3032
+    0x650F, // 3033
+    0x6513, // 3034
+    0x6514, // 3035
+    0x6516, // 3036
+    0x6519, // 3037
+    0x651B, // 3038
+    0x651E, // 3039
+    0x651F, // 3040
+    0x6522, // 3041
+    0x6526, // 3042
+    0x6529, // 3043
+    0x652E, // 3044
+    0x6531, // 3045
+    0x653A, // 3046
+    0x653C, // 3047
+    0x653D, // 3048
+    0x6543, // 3049
+    0x6547, // 3050
+    0x6549, // 3051
+    0x6550, // 3052
+    0x6552, // 3053
+    0x6554, // 3054
+    0x655F, // 3055
+    0x6560, // 3056
+    0x6567, // 3057
+    0x656B, // 3058
+    0x657A, // 3059
+    0x657D, // 3060
+    0x6581, // 3061
+    0x6585, // 3062
+    0x658A, // 3063
+    0x6592, // 3064
+    0x6595, // 3065
+    0x6598, // 3066
+    0x659D, // 3067
+    0x65A0, // 3068
+    0x65A3, // 3069
+    0x65A6, // 3070
+    0x65AE, // 3071
+    0x65B2, // 3072
+    0x65B3, // 3073
+    0x65B4, // 3074
+    0x65BF, // 3075
+    0x65C2, // 3076
+    0x65C8, // 3077
+    0x65C9, // 3078
+    0x65CE, // 3079
+    0x65D0, // 3080
+    0x65D4, // 3081
+    0x65D6, // 3082
+    0x65D8, // 3083
+    0x65DF, // 3084
+    0x65F0, // 3085
+    0x65F2, // 3086
+    0x65F4, // 3087
+    0x65F5, // 3088
+    0x65F9, // 3089
+    0x65FE, // 3090
+    0x65FF, // 3091
+    0x6600, // 3092
+    0x6604, // 3093
+    0x6608, // 3094
+    0x6609, // 3095
+    0x660D, // 3096
+    0x6611, // 3097
+    0x6612, // 3098
+    0x6615, // 3099
+    0x6616, // 3100
+    0x661D, // 3101
+    0x661E, // 3102
+    0x6621, // 3103
+    0x6622, // 3104
+    0x6623, // 3105
+    0x6624, // 3106
+    0x6626, // 3107
+    0x6629, // 3108
+    0x662A, // 3109
+    0x662B, // 3110
+    0x662C, // 3111
+    0x662E, // 3112
+    0x6630, // 3113
+    0x6631, // 3114
+    0x6633, // 3115
+    0x6639, // 3116
+    0x6637, // 3117
+    0x6640, // 3118
+    0x6645, // 3119
+    0x6646, // 3120
+    0x664A, // 3121
+    0x664C, // 3122
+    0x6651, // 3123
+    0x664E, // 3124
+    0x6657, // 3125
+    0x6658, // 3126
+    0x6659, // 3127
+    0x665B, // 3128
+    0x665C, // 3129
+    0x6660, // 3130
+    0x6661, // 3131
+    0x66FB, // 3132
+    0x666A, // 3133
+    0x666B, // 3134
+    0x666C, // 3135
+    0x667E, // 3136
+    0x6673, // 3137
+    0x6675, // 3138
+    0x667F, // 3139
+    0x6677, // 3140
+    0x6678, // 3141
+    0x6679, // 3142
+    0x667B, // 3143
+    0x6680, // 3144
+    0x667C, // 3145
+    0x668B, // 3146
+    0x668C, // 3147
+    0x668D, // 3148
+    0x6690, // 3149
+    0x6692, // 3150
+    0x6699, // 3151
+    0x669A, // 3152
+    0x669B, // 3153
+    0x669C, // 3154
+    0x669F, // 3155
+    0x66A0, // 3156
+    0x66A4, // 3157
+    0x66AD, // 3158
+    0x66B1, // 3159
+    0x66B2, // 3160
+    0x66B5, // 3161
+    0x66BB, // 3162
+    0x66BF, // 3163
+    0x66C0, // 3164
+    0x66C2, // 3165
+    0x66C3, // 3166
+    0x66C8, // 3167
+    0x66CC, // 3168
+    0x66CE, // 3169
+    0x66CF, // 3170
+    0x66D4, // 3171
+    0x66DB, // 3172
+    0x66DF, // 3173
+    0x66E8, // 3174
+    0x66EB, // 3175
+    0x66EC, // 3176
+    0x66EE, // 3177
+    0x66FA, // 3178
+    0x6705, // 3179
+    0x6707, // 3180
+    0x670E, // 3181
+    0x6713, // 3182
+    0x6719, // 3183
+    0x671C, // 3184
+    0x6720, // 3185
+    0x6722, // 3186
+    0x6733, // 3187
+    0x673E, // 3188
+    0x6745, // 3189
+    0x6747, // 3190
+    0x6748, // 3191
+    0x674C, // 3192
+    0x6754, // 3193
+    0x6755, // 3194
+    0x675D, // 3195
+    0x6766, // 3196
+    0x676C, // 3197
+    0x676E, // 3198
+    0x6774, // 3199
+    0x6776, // 3200
+    0x677B, // 3201
+    0x6781, // 3202
+    0x6784, // 3203
+    0x678E, // 3204
+    0x678F, // 3205
+    0x6791, // 3206
+    0x6793, // 3207
+    0x6796, // 3208
+    0x6798, // 3209
+    0x6799, // 3210
+    0x679B, // 3211
+    0x67B0, // 3212
+    0x67B1, // 3213
+    0x67B2, // 3214
+    0x67B5, // 3215
+    0x67BB, // 3216
+    0x67BC, // 3217
+    0x67BD, // 3218
+    0x67F9, // 3219
+    0x67C0, // 3220
+    0x67C2, // 3221
+    0x67C3, // 3222
+    0x67C5, // 3223
+    0x67C8, // 3224
+    0x67C9, // 3225
+    0x67D2, // 3226
+    0x67D7, // 3227
+    0x67D9, // 3228
+    0x67DC, // 3229
+    0x67E1, // 3230
+    0x67E6, // 3231
+    0x67F0, // 3232
+    0x67F2, // 3233
+    0x67F6, // 3234
+    0x67F7, // 3235
+    0x6852, // 3236
+    0x6814, // 3237
+    0x6819, // 3238
+    0x681D, // 3239
+    0x681F, // 3240
+    0x6828, // 3241
+    0x6827, // 3242
+    0x682C, // 3243
+    0x682D, // 3244
+    0x682F, // 3245
+    0x6830, // 3246
+    0x6831, // 3247
+    0x6833, // 3248
+    0x683B, // 3249
+    0x683F, // 3250
+    0x6844, // 3251
+    0x6845, // 3252
+    0x684A, // 3253
+    0x684C, // 3254
+    0x6855, // 3255
+    0x6857, // 3256
+    0x6858, // 3257
+    0x685B, // 3258
+    0x686B, // 3259
+    0x686E, // 3260
+    0x686F, // 3261
+    0x6870, // 3262
+    0x6871, // 3263
+    0x6872, // 3264
+    0x6875, // 3265
+    0x6879, // 3266
+    0x687A, // 3267
+    0x687B, // 3268
+    0x687C, // 3269
+    0x6882, // 3270
+    0x6884, // 3271
+    0x6886, // 3272
+    0x6888, // 3273
+    0x6896, // 3274
+    0x6898, // 3275
+    0x689A, // 3276
+    0x689C, // 3277
+    0x68A1, // 3278
+    0x68A3, // 3279
+    0x68A5, // 3280
+    0x68A9, // 3281
+    0x68AA, // 3282
+    0x68AE, // 3283
+    0x68B2, // 3284
+    0x68BB, // 3285
+    0x68C5, // 3286
+    0x68C8, // 3287
+    0x68CC, // 3288
+    0x68CF, // 3289
+    0x68D0, // 3290
+    0x68D1, // 3291
+    0x68D3, // 3292
+    0x68D6, // 3293
+    0x68D9, // 3294
+    0x68DC, // 3295
+    0x68DD, // 3296
+    0x68E5, // 3297
+    0x68E8, // 3298
+    0x68EA, // 3299
+    0x68EB, // 3300
+    0x68EC, // 3301
+    0x68ED, // 3302
+    0x68F0, // 3303
+    0x68F1, // 3304
+    0x68F5, // 3305
+    0x68F6, // 3306
+    0x68FB, // 3307
+    0x68FC, // 3308
+    0x68FD, // 3309
+    0x6906, // 3310
+    0x6909, // 3311
+    0x690A, // 3312
+    0x6910, // 3313
+    0x6911, // 3314
+    0x6913, // 3315
+    0x6916, // 3316
+    0x6917, // 3317
+    0x6931, // 3318
+    0x6933, // 3319
+    0x6935, // 3320
+    0x6938, // 3321
+    0x693B, // 3322
+    0x6942, // 3323
+    0x6945, // 3324
+    0x6949, // 3325
+    0x694E, // 3326
+    0x6957, // 3327
+    0x695B, // 3328
+    0x6963, // 3329
+    0x6964, // 3330
+    0x6965, // 3331
+    0x6966, // 3332
+    0x6968, // 3333
+    0x6969, // 3334
+    0x696C, // 3335
+    0x6970, // 3336
+    0x6971, // 3337
+    0x6972, // 3338
+    0x697A, // 3339
+    0x697B, // 3340
+    0x697F, // 3341
+    0x6980, // 3342
+    0x698D, // 3343
+    0x6992, // 3344
+    0x6996, // 3345
+    0x6998, // 3346
+    0x69A1, // 3347
+    0x69A5, // 3348
+    0x69A6, // 3349
+    0x69A8, // 3350
+    0x69AB, // 3351
+    0x69AD, // 3352
+    0x69AF, // 3353
+    0x69B7, // 3354
+    0x69B8, // 3355
+    0x69BA, // 3356
+    0x69BC, // 3357
+    0x69C5, // 3358
+    0x69C8, // 3359
+    0x69D1, // 3360
+    0x69D6, // 3361
+    0x69D7, // 3362
+    0x69E2, // 3363
+    0x69E5, // 3364
+    0x69EE, // 3365
+    0x69EF, // 3366
+    0x69F1, // 3367
+    0x69F3, // 3368
+    0x69F5, // 3369
+    0x69FE, // 3370
+    0x6A00, // 3371
+    0x6A01, // 3372
+    0x6A03, // 3373
+    0x6A0F, // 3374
+    0x6A11, // 3375
+    0x6A15, // 3376
+    0x6A1A, // 3377
+    0x6A1D, // 3378
+    0x6A20, // 3379
+    0x6A24, // 3380
+    0x6A28, // 3381
+    0x6A30, // 3382
+    0x6A32, // 3383
+    0x6A34, // 3384
+    0x6A37, // 3385
+    0x6A3B, // 3386
+    0x6A3E, // 3387
+    0x6A3F, // 3388
+    0x6A45, // 3389
+    0x6A46, // 3390
+    0x6A49, // 3391
+    0x6A4A, // 3392
+    0x6A4E, // 3393
+    0x6A50, // 3394
+    0x6A51, // 3395
+    0x6A52, // 3396
+    0x6A55, // 3397
+    0x6A56, // 3398
+    0x6A5B, // 3399
+    0x6A64, // 3400
+    0x6A67, // 3401
+    0x6A6A, // 3402
+    0x6A71, // 3403
+    0x6A73, // 3404
+    0x6A7E, // 3405
+    0x6A81, // 3406
+    0x6A83, // 3407
+    0x6A86, // 3408
+    0x6A87, // 3409
+    0x6A89, // 3410
+    0x6A8B, // 3411
+    0x6A91, // 3412
+    0x6A9B, // 3413
+    0x6A9D, // 3414
+    0x6A9E, // 3415
+    0x6A9F, // 3416
+    0x6AA5, // 3417
+    0x6AAB, // 3418
+    0x6AAF, // 3419
+    0x6AB0, // 3420
+    0x6AB1, // 3421
+    0x6AB4, // 3422
+    0x6ABD, // 3423
+    0x6ABE, // 3424
+    0x6ABF, // 3425
+    0x6AC6, // 3426
+    0x6AC9, // 3427
+    0x6AC8, // 3428
+    0x6ACC, // 3429
+    0x6AD0, // 3430
+    0x6AD4, // 3431
+    0x6AD5, // 3432
+    0x6AD6, // 3433
+    0x6ADC, // 3434
+    0x6ADD, // 3435
+    0x6AE4, // 3436
+    0x6AE7, // 3437
+    0x6AEC, // 3438
+    0x6AF0, // 3439
+    0x6AF1, // 3440
+    0x6AF2, // 3441
+    0x6AFC, // 3442
+    0x6AFD, // 3443
+    0x6B02, // 3444
+    0x6B03, // 3445
+    0x6B06, // 3446
+    0x6B07, // 3447
+    0x6B09, // 3448
+    0x6B0F, // 3449
+    0x6B10, // 3450
+    0x6B11, // 3451
+    0x6B17, // 3452
+    0x6B1B, // 3453
+    0x6B1E, // 3454
+    0x6B24, // 3455
+    0x6B28, // 3456
+    0x6B2B, // 3457
+    0x6B2C, // 3458
+    0x6B2F, // 3459
+    0x6B35, // 3460
+    0x6B36, // 3461
+    0x6B3B, // 3462
+    0x6B3F, // 3463
+    0x6B46, // 3464
+    0x6B4A, // 3465
+    0x6B4D, // 3466
+    0x6B52, // 3467
+    0x6B56, // 3468
+    0x6B58, // 3469
+    0x6B5D, // 3470
+    0x6B60, // 3471
+    0x6B67, // 3472
+    0x6B6B, // 3473
+    0x6B6E, // 3474
+    0x6B70, // 3475
+    0x6B75, // 3476
+    0x6B7D, // 3477
+    0x6B7E, // 3478
+    0x6B82, // 3479
+    0x6B85, // 3480
+    0x6B97, // 3481
+    0x6B9B, // 3482
+    0x6B9F, // 3483
+    0x6BA0, // 3484
+    0x6BA2, // 3485
+    0x6BA3, // 3486
+    0x6BA8, // 3487
+    0x6BA9, // 3488
+    0x6BAC, // 3489
+    0x6BAD, // 3490
+    0x6BAE, // 3491
+    0x6BB0, // 3492
+    0x6BB8, // 3493
+    0x6BB9, // 3494
+    0x6BBD, // 3495
+    0x6BBE, // 3496
+    0x6BC3, // 3497
+    0x6BC4, // 3498
+    0x6BC9, // 3499
+    0x6BCC, // 3500
+    0x6BD6, // 3501
+    0x6BDA, // 3502
+    0x6BE1, // 3503
+    0x6BE3, // 3504
+    0x6BE6, // 3505
+    0x6BE7, // 3506
+    0x6BEE, // 3507
+    0x6BF1, // 3508
+    0x6BF7, // 3509
+    0x6BF9, // 3510
+    0x6BFF, // 3511
+    0x6C02, // 3512
+    0x6C04, // 3513
+    0x6C05, // 3514
+    0x6C09, // 3515
+    0x6C0D, // 3516
+    0x6C0E, // 3517
+    0x6C10, // 3518
+    0x6C12, // 3519
+    0x6C19, // 3520
+    0x6C1F, // 3521
+    0x6C26, // 3522
+    0x6C27, // 3523
+    0x6C28, // 3524
+    0x6C2C, // 3525
+    0x6C2E, // 3526
+    0x6C33, // 3527
+    0x6C35, // 3528
+    0x6C36, // 3529
+    0x6C3A, // 3530
+    0x6C3B, // 3531
+    0x6C3F, // 3532
+    0x6C4A, // 3533
+    0x6C4B, // 3534
+    0x6C4D, // 3535
+    0x6C4F, // 3536
+    0x6C52, // 3537
+    0x6C54, // 3538
+    0x6C59, // 3539
+    0x6C5B, // 3540
+    0x6C5C, // 3541
+    0x6C6B, // 3542
+    0x6C6D, // 3543
+    0x6C6F, // 3544
+    0x6C74, // 3545
+    0x6C76, // 3546
+    0x6C78, // 3547
+    0x6C79, // 3548
+    0x6C7B, // 3549
+    0x6C85, // 3550
+    0x6C86, // 3551
+    0x6C87, // 3552
+    0x6C89, // 3553
+    0x6C94, // 3554
+    0x6C95, // 3555
+    0x6C97, // 3556
+    0x6C98, // 3557
+    0x6C9C, // 3558
+    0x6C9F, // 3559
+    0x6CB0, // 3560
+    0x6CB2, // 3561
+    0x6CB4, // 3562
+    0x6CC2, // 3563
+    0x6CC6, // 3564
+    0x6CCD, // 3565
+    0x6CCF, // 3566
+    0x6CD0, // 3567
+    0x6CD1, // 3568
+    0x6CD2, // 3569
+    0x6CD4, // 3570
+    0x6CD6, // 3571
+    0x6CDA, // 3572
+    0x6CDC, // 3573
+    0x6CE0, // 3574
+    0x6CE7, // 3575
+    0x6CE9, // 3576
+    0x6CEB, // 3577
+    0x6CEC, // 3578
+    0x6CEE, // 3579
+    0x6CF2, // 3580
+    0x6CF4, // 3581
+    0x6D04, // 3582
+    0x6D07, // 3583
+    0x6D0A, // 3584
+    0x6D0E, // 3585
+    0x6D0F, // 3586
+    0x6D11, // 3587
+    0x6D13, // 3588
+    0x6D1A, // 3589
+    0x6D26, // 3590
+    0x6D27, // 3591
+    0x6D28, // 3592
+    0x6C67, // 3593
+    0x6D2E, // 3594
+    0x6D2F, // 3595
+    0x6D31, // 3596
+    0x6D39, // 3597
+    0x6D3C, // 3598
+    0x6D3F, // 3599
+    0x6D57, // 3600
+    0x6D5E, // 3601
+    0x6D5F, // 3602
+    0x6D61, // 3603
+    0x6D65, // 3604
+    0x6D67, // 3605
+    0x6D6F, // 3606
+    0x6D70, // 3607
+    0x6D7C, // 3608
+    0x6D82, // 3609
+    0x6D87, // 3610
+    0x6D91, // 3611
+    0x6D92, // 3612
+    0x6D94, // 3613
+    0x6D96, // 3614
+    0x6D97, // 3615
+    0x6D98, // 3616
+    0x6DAA, // 3617
+    0x6DAC, // 3618
+    0x6DB4, // 3619
+    0x6DB7, // 3620
+    0x6DB9, // 3621
+    0x6DBD, // 3622
+    0x6DBF, // 3623
+    0x6DC4, // 3624
+    0x6DC8, // 3625
+    0x6DCA, // 3626
+    0x6DCE, // 3627
+    0x6DCF, // 3628
+    0x6DD6, // 3629
+    0x6DDB, // 3630
+    0x6DDD, // 3631
+    0x6DDF, // 3632
+    0x6DE0, // 3633
+    0x6DE2, // 3634
+    0x6DE5, // 3635
+    0x6DE9, // 3636
+    0x6DEF, // 3637
+    0x6DF0, // 3638
+    0x6DF4, // 3639
+    0x6DF6, // 3640
+    0x6DFC, // 3641
+    0x6E00, // 3642
+    0x6E04, // 3643
+    0x6E1E, // 3644
+    0x6E22, // 3645
+    0x6E27, // 3646
+    0x6E32, // 3647
+    0x6E36, // 3648
+    0x6E39, // 3649
+    0x6E3B, // 3650
+    0x6E3C, // 3651
+    0x6E44, // 3652
+    0x6E45, // 3653
+    0x6E48, // 3654
+    0x6E49, // 3655
+    0x6E4B, // 3656
+    0x6E4F, // 3657
+    0x6E51, // 3658
+    0x6E52, // 3659
+    0x6E53, // 3660
+    0x6E54, // 3661
+    0x6E57, // 3662
+    0x6E5C, // 3663
+    0x6E5D, // 3664
+    0x6E5E, // 3665
+    0x6E62, // 3666
+    0x6E63, // 3667
+    0x6E68, // 3668
+    0x6E73, // 3669
+    0x6E7B, // 3670
+    0x6E7D, // 3671
+    0x6E8D, // 3672
+    0x6E93, // 3673
+    0x6E99, // 3674
+    0x6EA0, // 3675
+    0x6EA7, // 3676
+    0x6EAD, // 3677
+    0x6EAE, // 3678
+    0x6EB1, // 3679
+    0x6EB3, // 3680
+    0x6EBB, // 3681
+    0x6EBF, // 3682
+    0x6EC0, // 3683
+    0x6EC1, // 3684
+    0x6EC3, // 3685
+    0x6EC7, // 3686
+    0x6EC8, // 3687
+    0x6ECA, // 3688
+    0x6ECD, // 3689
+    0x6ECE, // 3690
+    0x6ECF, // 3691
+    0x6EEB, // 3692
+    0x6EED, // 3693
+    0x6EEE, // 3694
+    0x6EF9, // 3695
+    0x6EFB, // 3696
+    0x6EFD, // 3697
+    0x6F04, // 3698
+    0x6F08, // 3699
+    0x6F0A, // 3700
+    0x6F0C, // 3701
+    0x6F0D, // 3702
+    0x6F16, // 3703
+    0x6F18, // 3704
+    0x6F1A, // 3705
+    0x6F1B, // 3706
+    0x6F26, // 3707
+    0x6F29, // 3708
+    0x6F2A, // 3709
+    0x6F2F, // 3710
+    0x6F30, // 3711
+    0x6F33, // 3712
+    0x6F36, // 3713
+    0x6F3B, // 3714
+    0x6F3C, // 3715
+    0x6F2D, // 3716
+    0x6F4F, // 3717
+    0x6F51, // 3718
+    0x6F52, // 3719
+    0x6F53, // 3720
+    0x6F57, // 3721
+    0x6F59, // 3722
+    0x6F5A, // 3723
+    0x6F5D, // 3724
+    0x6F5E, // 3725
+    0x6F61, // 3726
+    0x6F62, // 3727
+    0x6F68, // 3728
+    0x6F6C, // 3729
+    0x6F7D, // 3730
+    0x6F7E, // 3731
+    0x6F83, // 3732
+    0x6F87, // 3733
+    0x6F88, // 3734
+    0x6F8B, // 3735
+    0x6F8C, // 3736
+    0x6F8D, // 3737
+    0x6F90, // 3738
+    0x6F92, // 3739
+    0x6F93, // 3740
+    0x6F94, // 3741
+    0x6F96, // 3742
+    0x6F9A, // 3743
+    0x6F9F, // 3744
+    0x6FA0, // 3745
+    0x6FA5, // 3746
+    0x6FA6, // 3747
+    0x6FA7, // 3748
+    0x6FA8, // 3749
+    0x6FAE, // 3750
+    0x6FAF, // 3751
+    0x6FB0, // 3752
+    0x6FB5, // 3753
+    0x6FB6, // 3754
+    0x6FBC, // 3755
+    0x6FC5, // 3756
+    0x6FC7, // 3757
+    0x6FC8, // 3758
+    0x6FCA, // 3759
+    0x6FDA, // 3760
+    0x6FDE, // 3761
+    0x6FE8, // 3762
+    0x6FE9, // 3763
+    0x6FF0, // 3764
+    0x6FF5, // 3765
+    0x6FF9, // 3766
+    0x6FFC, // 3767
+    0x6FFD, // 3768
+    0x7000, // 3769
+    0x7005, // 3770
+    0x7006, // 3771
+    0x7007, // 3772
+    0x700D, // 3773
+    0x7017, // 3774
+    0x7020, // 3775
+    0x7023, // 3776
+    0x702F, // 3777
+    0x7034, // 3778
+    0x7037, // 3779
+    0x7039, // 3780
+    0x703C, // 3781
+    0x7043, // 3782
+    0x7044, // 3783
+    0x7048, // 3784
+    0x7049, // 3785
+    0x704A, // 3786
+    0x704B, // 3787
+    0x7054, // 3788
+    0x7055, // 3789
+    0x705D, // 3790
+    0x705E, // 3791
+    0x704E, // 3792
+    0x7064, // 3793
+    0x7065, // 3794
+    0x706C, // 3795
+    0x706E, // 3796
+    0x7075, // 3797
+    0x7076, // 3798
+    0x707E, // 3799
+    0x7081, // 3800
+    0x7085, // 3801
+    0x7086, // 3802
+    0x7094, // 3803
+    0x7095, // 3804
+    0x7096, // 3805
+    0x7097, // 3806
+    0x7098, // 3807
+    0x709B, // 3808
+    0x70A4, // 3809
+    0x70AB, // 3810
+    0x70B0, // 3811
+    0x70B1, // 3812
+    0x70B4, // 3813
+    0x70B7, // 3814
+    0x70CA, // 3815
+    0x70D1, // 3816
+    0x70D3, // 3817
+    0x70D4, // 3818
+    0x70D5, // 3819
+    0x70D6, // 3820
+    0x70D8, // 3821
+    0x70DC, // 3822
+    0x70E4, // 3823
+    0x70FA, // 3824
+    0x7103, // 3825
+    0x7104, // 3826
+    0x7105, // 3827
+    0x7106, // 3828
+    0x7107, // 3829
+    0x710B, // 3830
+    0x710C, // 3831
+    0x710F, // 3832
+    0x711E, // 3833
+    0x7120, // 3834
+    0x712B, // 3835
+    0x712D, // 3836
+    0x712F, // 3837
+    0x7130, // 3838
+    0x7131, // 3839
+    0x7138, // 3840
+    0x7141, // 3841
+    0x7145, // 3842
+    0x7146, // 3843
+    0x7147, // 3844
+    0x714A, // 3845
+    0x714B, // 3846
+    0x7150, // 3847
+    0x7152, // 3848
+    0x7157, // 3849
+    0x715A, // 3850
+    0x715C, // 3851
+    0x715E, // 3852
+    0x7160, // 3853
+    0x7168, // 3854
+    0x7179, // 3855
+    0x7180, // 3856
+    0x7185, // 3857
+    0x7187, // 3858
+    0x718C, // 3859
+    0x7192, // 3860
+    0x719A, // 3861
+    0x719B, // 3862
+    0x71A0, // 3863
+    0x71A2, // 3864
+    0x71AF, // 3865
+    0x71B0, // 3866
+    0x71B2, // 3867
+    0x71B3, // 3868
+    0x71BA, // 3869
+    0x71BF, // 3870
+    0x71C0, // 3871
+    0x71C1, // 3872
+    0x71C4, // 3873
+    0x71CB, // 3874
+    0x71CC, // 3875
+    0x71D3, // 3876
+    0x71D6, // 3877
+    0x71D9, // 3878
+    0x71DA, // 3879
+    0x71DC, // 3880
+    0x71F8, // 3881
+    0x71FE, // 3882
+    0x7200, // 3883
+    0x7207, // 3884
+    0x7208, // 3885
+    0x7209, // 3886
+    0x7213, // 3887
+    0x7217, // 3888
+    0x721A, // 3889
+    0x721D, // 3890
+    0x721F, // 3891
+    0x7224, // 3892
+    0x722B, // 3893
+    0x722F, // 3894
+    0x7234, // 3895
+    0x7238, // 3896
+    0x7239, // 3897
+    0x7241, // 3898
+    0x7242, // 3899
+    0x7243, // 3900
+    0x7245, // 3901
+    0x724E, // 3902
+    0x724F, // 3903
+    0x7250, // 3904
+    0x7253, // 3905
+    0x7255, // 3906
+    0x7256, // 3907
+    0x725A, // 3908
+    0x725C, // 3909
+    0x725E, // 3910
+    0x7260, // 3911
+    0x7263, // 3912
+    0x7268, // 3913
+    0x726B, // 3914
+    0x726E, // 3915
+    0x726F, // 3916
+    0x7271, // 3917
+    0x7277, // 3918
+    0x7278, // 3919
+    0x727B, // 3920
+    0x727C, // 3921
+    0x727F, // 3922
+    0x7284, // 3923
+    0x7289, // 3924
+    0x728D, // 3925
+    0x728E, // 3926
+    0x7293, // 3927
+    0x729B, // 3928
+    0x72A8, // 3929
+    0x72AD, // 3930
+    0x72AE, // 3931
+    0x72B1, // 3932
+    0x72B4, // 3933
+    0x72BE, // 3934
+    0x72C1, // 3935
+    0x72C7, // 3936
+    0x72C9, // 3937
+    0x72CC, // 3938
+    0x72D5, // 3939
+    0x72D6, // 3940
+    0x72D8, // 3941
+    0x72DF, // 3942
+    0x72E5, // 3943
+    0x72F3, // 3944
+    0x72F4, // 3945
+    0x72FA, // 3946
+    0x72FB, // 3947
+    0x72FE, // 3948
+    0x7302, // 3949
+    0x7304, // 3950
+    0x7305, // 3951
+    0x7307, // 3952
+    0x730B, // 3953
+    0x730D, // 3954
+    0x7312, // 3955
+    0x7313, // 3956
+    0x7318, // 3957
+    0x7319, // 3958
+    0x731E, // 3959
+    0x7322, // 3960
+    0x7324, // 3961
+    0x7327, // 3962
+    0x7328, // 3963
+    0x732C, // 3964
+    0x7331, // 3965
+    0x7332, // 3966
+    0x7335, // 3967
+    0x733A, // 3968
+    0x733B, // 3969
+    0x733D, // 3970
+    0x7343, // 3971
+    0x734D, // 3972
+    0x7350, // 3973
+    0x7352, // 3974
+    0x7356, // 3975
+    0x7358, // 3976
+    0x735D, // 3977
+    0x735E, // 3978
+    0x735F, // 3979
+    0x7360, // 3980
+    0x7366, // 3981
+    0x7367, // 3982
+    0x7369, // 3983
+    0x736B, // 3984
+    0x736C, // 3985
+    0x736E, // 3986
+    0x736F, // 3987
+    0x7371, // 3988
+    0x7377, // 3989
+    0x7379, // 3990
+    0x737C, // 3991
+    0x7380, // 3992
+    0x7381, // 3993
+    0x7383, // 3994
+    0x7385, // 3995
+    0x7386, // 3996
+    0x738E, // 3997
+    0x7390, // 3998
+    0x7393, // 3999
+    0x7395, // 4000
+    0x7397, // 4001
+    0x7398, // 4002
+    0x739C, // 4003
+    0x739E, // 4004
+    0x739F, // 4005
+    0x73A0, // 4006
+    0x73A2, // 4007
+    0x73A5, // 4008
+    0x73A6, // 4009
+    0x73AA, // 4010
+    0x73AB, // 4011
+    0x73AD, // 4012
+    0x73B5, // 4013
+    0x73B7, // 4014
+    0x73B9, // 4015
+    0x73BC, // 4016
+    0x73BD, // 4017
+    0x73BF, // 4018
+    0x73C5, // 4019
+    0x73C6, // 4020
+    0x73C9, // 4021
+    0x73CB, // 4022
+    0x73CC, // 4023
+    0x73CF, // 4024
+    0x73D2, // 4025
+    0x73D3, // 4026
+    0x73D6, // 4027
+    0x73D9, // 4028
+    0x73DD, // 4029
+    0x73E1, // 4030
+    0x73E3, // 4031
+    0x73E6, // 4032
+    0x73E7, // 4033
+    0x73E9, // 4034
+    0x73F4, // 4035
+    0x73F5, // 4036
+    0x73F7, // 4037
+    0x73F9, // 4038
+    0x73FA, // 4039
+    0x73FB, // 4040
+    0x73FD, // 4041
+    0x73FF, // 4042
+    0x7400, // 4043
+    0x7401, // 4044
+    0x7404, // 4045
+    0x7407, // 4046
+    0x740A, // 4047
+    0x7411, // 4048
+    0x741A, // 4049
+    0x741B, // 4050
+    0x7424, // 4051
+    0x7426, // 4052
+    0x7428, // 4053
+    0x7429, // 4054
+    0x742A, // 4055
+    0x742B, // 4056
+    0x742C, // 4057
+    0x742D, // 4058
+    0x742E, // 4059
+    0x742F, // 4060
+    0x7430, // 4061
+    0x7431, // 4062
+    0x7439, // 4063
+    0x7440, // 4064
+    0x7443, // 4065
+    0x7444, // 4066
+    0x7446, // 4067
+    0x7447, // 4068
+    0x744B, // 4069
+    0x744D, // 4070
+    0x7451, // 4071
+    0x7452, // 4072
+    0x7457, // 4073
+    0x745D, // 4074
+    0x7462, // 4075
+    0x7466, // 4076
+    0x7467, // 4077
+    0x7468, // 4078
+    0x746B, // 4079
+    0x746D, // 4080
+    0x746E, // 4081
+    0x7471, // 4082
+    0x7472, // 4083
+    0x7480, // 4084
+    0x7481, // 4085
+    0x7485, // 4086
+    0x7486, // 4087
+    0x7487, // 4088
+    0x7489, // 4089
+    0x748F, // 4090
+    0x7490, // 4091
+    0x7491, // 4092
+    0x7492, // 4093
+    0x7498, // 4094
+    0x7499, // 4095
+    0x749A, // 4096
+    0x749C, // 4097
+    0x749F, // 4098
+    0x74A0, // 4099
+    0x74A1, // 4100
+    0x74A3, // 4101
+    0x74A6, // 4102
+    0x74A8, // 4103
+    0x74A9, // 4104
+    0x74AA, // 4105
+    0x74AB, // 4106
+    0x74AE, // 4107
+    0x74AF, // 4108
+    0x74B1, // 4109
+    0x74B2, // 4110
+    0x74B5, // 4111
+    0x74B9, // 4112
+    0x74BB, // 4113
+    0x74BF, // 4114
+    0x74C8, // 4115
+    0x74C9, // 4116
+    0x74CC, // 4117
+    0x74D0, // 4118
+    0x74D3, // 4119
+    0x74D8, // 4120
+    0x74DA, // 4121
+    0x74DB, // 4122
+    0x74DE, // 4123
+    0x74DF, // 4124
+    0x74E4, // 4125
+    0x74E8, // 4126
+    0x74EA, // 4127
+    0x74EB, // 4128
+    0x74EF, // 4129
+    0x74F4, // 4130
+    0x74FA, // 4131
+    0x74FB, // 4132
+    0x74FC, // 4133
+    0x74FF, // 4134
+    0x7506, // 4135
+    0x7512, // 4136
+    0x7516, // 4137
+    0x7517, // 4138
+    0x7520, // 4139
+    0x7521, // 4140
+    0x7524, // 4141
+    0x7527, // 4142
+    0x7529, // 4143
+    0x752A, // 4144
+    0x752F, // 4145
+    0x7536, // 4146
+    0x7539, // 4147
+    0x753D, // 4148
+    0x753E, // 4149
+    0x753F, // 4150
+    0x7540, // 4151
+    0x7543, // 4152
+    0x7547, // 4153
+    0x7548, // 4154
+    0x754E, // 4155
+    0x7550, // 4156
+    0x7552, // 4157
+    0x7557, // 4158
+    0x755E, // 4159
+    0x755F, // 4160
+    0x7561, // 4161
+    0x756F, // 4162
+    0x7571, // 4163
+    0x7579, // 4164
+    0x757A, // 4165
+    0x757B, // 4166
+    0x757C, // 4167
+    0x757D, // 4168
+    0x757E, // 4169
+    0x7581, // 4170
+    0x7585, // 4171
+    0x7590, // 4172
+    0x7592, // 4173
+    0x7593, // 4174
+    0x7595, // 4175
+    0x7599, // 4176
+    0x759C, // 4177
+    0x75A2, // 4178
+    0x75A4, // 4179
+    0x75B4, // 4180
+    0x75BA, // 4181
+    0x75BF, // 4182
+    0x75C0, // 4183
+    0x75C1, // 4184
+    0x75C4, // 4185
+    0x75C6, // 4186
+    0x75CC, // 4187
+    0x75CE, // 4188
+    0x75CF, // 4189
+    0x75D7, // 4190
+    0x75DC, // 4191
+    0x75DF, // 4192
+    0x75E0, // 4193
+    0x75E1, // 4194
+    0x75E4, // 4195
+    0x75E7, // 4196
+    0x75EC, // 4197
+    0x75EE, // 4198
+    0x75EF, // 4199
+    0x75F1, // 4200
+    0x75F9, // 4201
+    0x7600, // 4202
+    0x7602, // 4203
+    0x7603, // 4204
+    0x7604, // 4205
+    0x7607, // 4206
+    0x7608, // 4207
+    0x760A, // 4208
+    0x760C, // 4209
+    0x760F, // 4210
+    0x7612, // 4211
+    0x7613, // 4212
+    0x7615, // 4213
+    0x7616, // 4214
+    0x7619, // 4215
+    0x761B, // 4216
+    0x761C, // 4217
+    0x761D, // 4218
+    0x761E, // 4219
+    0x7623, // 4220
+    0x7625, // 4221
+    0x7626, // 4222
+    0x7629, // 4223
+    0x762D, // 4224
+    0x7632, // 4225
+    0x7633, // 4226
+    0x7635, // 4227
+    0x7638, // 4228
+    0x7639, // 4229
+    0x763A, // 4230
+    0x763C, // 4231
+    0x764A, // 4232
+    0x7640, // 4233
+    0x7641, // 4234
+    0x7643, // 4235
+    0x7644, // 4236
+    0x7645, // 4237
+    0x7649, // 4238
+    0x764B, // 4239
+    0x7655, // 4240
+    0x7659, // 4241
+    0x765F, // 4242
+    0x7664, // 4243
+    0x7665, // 4244
+    0x766D, // 4245
+    0x766E, // 4246
+    0x766F, // 4247
+    0x7671, // 4248
+    0x7674, // 4249
+    0x7681, // 4250
+    0x7685, // 4251
+    0x768C, // 4252
+    0x768D, // 4253
+    0x7695, // 4254
+    0x769B, // 4255
+    0x769C, // 4256
+    0x769D, // 4257
+    0x769F, // 4258
+    0x76A0, // 4259
+    0x76A2, // 4260
+    0x76A3, // 4261
+    0x76A4, // 4262
+    0x76A5, // 4263
+    0x76A6, // 4264
+    0x76A7, // 4265
+    0x76A8, // 4266
+    0x76AA, // 4267
+    0x76AD, // 4268
+    0x76BD, // 4269
+    0x76C1, // 4270
+    0x76C5, // 4271
+    0x76C9, // 4272
+    0x76CB, // 4273
+    0x76CC, // 4274
+    0x76CE, // 4275
+    0x76D4, // 4276
+    0x76D9, // 4277
+    0x76E0, // 4278
+    0x76E6, // 4279
+    0x76E8, // 4280
+    0x76EC, // 4281
+    0x76F0, // 4282
+    0x76F1, // 4283
+    0x76F6, // 4284
+    0x76F9, // 4285
+    0x76FC, // 4286
+    0x7700, // 4287
+    0x7706, // 4288
+    0x770A, // 4289
+    0x770E, // 4290
+    0x7712, // 4291
+    0x7714, // 4292
+    0x7715, // 4293
+    0x7717, // 4294
+    0x7719, // 4295
+    0x771A, // 4296
+    0x771C, // 4297
+    0x7722, // 4298
+    0x7728, // 4299
+    0x772D, // 4300
+    0x772E, // 4301
+    0x772F, // 4302
+    0x7734, // 4303
+    0x7735, // 4304
+    0x7736, // 4305
+    0x7739, // 4306
+    0x773D, // 4307
+    0x773E, // 4308
+    0x7742, // 4309
+    0x7745, // 4310
+    0x7746, // 4311
+    0x774A, // 4312
+    0x774D, // 4313
+    0x774E, // 4314
+    0x774F, // 4315
+    0x7752, // 4316
+    0x7756, // 4317
+    0x7757, // 4318
+    0x775C, // 4319
+    0x775E, // 4320
+    0x775F, // 4321
+    0x7760, // 4322
+    0x7762, // 4323
+    0x7764, // 4324
+    0x7767, // 4325
+    0x776A, // 4326
+    0x776C, // 4327
+    0x7770, // 4328
+    0x7772, // 4329
+    0x7773, // 4330
+    0x7774, // 4331
+    0x777A, // 4332
+    0x777D, // 4333
+    0x7780, // 4334
+    0x7784, // 4335
+    0x778C, // 4336
+    0x778D, // 4337
+    0x7794, // 4338
+    0x7795, // 4339
+    0x7796, // 4340
+    0x779A, // 4341
+    0x779F, // 4342
+    0x77A2, // 4343
+    0x77A7, // 4344
+    0x77AA, // 4345
+    0x77AE, // 4346
+    0x77AF, // 4347
+    0x77B1, // 4348
+    0x77B5, // 4349
+    0x77BE, // 4350
+    0x77C3, // 4351
+    0x77C9, // 4352
+    0x77D1, // 4353
+    0x77D2, // 4354
+    0x77D5, // 4355
+    0x77D9, // 4356
+    0x77DE, // 4357
+    0x77DF, // 4358
+    0x77E0, // 4359
+    0x77E4, // 4360
+    0x77E6, // 4361
+    0x77EA, // 4362
+    0x77EC, // 4363
+    0x77F0, // 4364
+    0x77F1, // 4365
+    0x77F4, // 4366
+    0x77F8, // 4367
+    0x77FB, // 4368
+    0x7805, // 4369
+    0x7806, // 4370
+    0x7809, // 4371
+    0x780D, // 4372
+    0x780E, // 4373
+    0x7811, // 4374
+    0x781D, // 4375
+    0x7821, // 4376
+    0x7822, // 4377
+    0x7823, // 4378
+    0x782D, // 4379
+    0x782E, // 4380
+    0x7830, // 4381
+    0x7835, // 4382
+    0x7837, // 4383
+    0x7843, // 4384
+    0x7844, // 4385
+    0x7847, // 4386
+    0x7848, // 4387
+    0x784C, // 4388
+    0x784E, // 4389
+    0x7852, // 4390
+    0x785C, // 4391
+    0x785E, // 4392
+    0x7860, // 4393
+    0x7861, // 4394
+    0x7863, // 4395
+    0x7864, // 4396
+    0x7868, // 4397
+    0x786A, // 4398
+    0x786E, // 4399
+    0x787A, // 4400
+    0x787E, // 4401
+    0x788A, // 4402
+    0x788F, // 4403
+    0x7894, // 4404
+    0x7898, // 4405
+    0x78A1, // 4406
+    0x789D, // 4407
+    0x789E, // 4408
+    0x789F, // 4409
+    0x78A4, // 4410
+    0x78A8, // 4411
+    0x78AC, // 4412
+    0x78AD, // 4413
+    0x78B0, // 4414
+    0x78B1, // 4415
+    0x78B2, // 4416
+    0x78B3, // 4417
+    0x78BB, // 4418
+    0x78BD, // 4419
+    0x78BF, // 4420
+    0x78C7, // 4421
+    0x78C8, // 4422
+    0x78C9, // 4423
+    0x78CC, // 4424
+    0x78CE, // 4425
+    0x78D2, // 4426
+    0x78D3, // 4427
+    0x78D5, // 4428
+    0x78D6, // 4429
+    0x78E4, // 4430
+    0x78DB, // 4431
+    0x78DF, // 4432
+    0x78E0, // 4433
+    0x78E1, // 4434
+    0x78E6, // 4435
+    0x78EA, // 4436
+    0x78F2, // 4437
+    0x78F3, // 4438
+    0x7900, // 4439
+    0x78F6, // 4440
+    0x78F7, // 4441
+    0x78FA, // 4442
+    0x78FB, // 4443
+    0x78FF, // 4444
+    0x7906, // 4445
+    0x790C, // 4446
+    0x7910, // 4447
+    0x791A, // 4448
+    0x791C, // 4449
+    0x791E, // 4450
+    0x791F, // 4451
+    0x7920, // 4452
+    0x7925, // 4453
+    0x7927, // 4454
+    0x7929, // 4455
+    0x792D, // 4456
+    0x7931, // 4457
+    0x7934, // 4458
+    0x7935, // 4459
+    0x793B, // 4460
+    0x793D, // 4461
+    0x793F, // 4462
+    0x7944, // 4463
+    0x7945, // 4464
+    0x7946, // 4465
+    0x794A, // 4466
+    0x794B, // 4467
+    0x794F, // 4468
+    0x7951, // 4469
+    0x7954, // 4470
+    0x7958, // 4471
+    0x795B, // 4472
+    0x795C, // 4473
+    0x7967, // 4474
+    0x7969, // 4475
+    0x796B, // 4476
+    0x7972, // 4477
+    0x7979, // 4478
+    0x797B, // 4479
+    0x797C, // 4480
+    0x797E, // 4481
+    0x798B, // 4482
+    0x798C, // 4483
+    0x7991, // 4484
+    0x7993, // 4485
+    0x7994, // 4486
+    0x7995, // 4487
+    0x7996, // 4488
+    0x7998, // 4489
+    0x799B, // 4490
+    0x799C, // 4491
+    0x79A1, // 4492
+    0x79A8, // 4493
+    0x79A9, // 4494
+    0x79AB, // 4495
+    0x79AF, // 4496
+    0x79B1, // 4497
+    0x79B4, // 4498
+    0x79B8, // 4499
+    0x79BB, // 4500
+    0x79C2, // 4501
+    0x79C4, // 4502
+    0x79C7, // 4503
+    0x79C8, // 4504
+    0x79CA, // 4505
+    0x79CF, // 4506
+    0x79D4, // 4507
+    0x79D6, // 4508
+    0x79DA, // 4509
+    0x79DD, // 4510
+    0x79DE, // 4511
+    0x79E0, // 4512
+    0x79E2, // 4513
+    0x79E5, // 4514
+    0x79EA, // 4515
+    0x79EB, // 4516
+    0x79ED, // 4517
+    0x79F1, // 4518
+    0x79F8, // 4519
+    0x79FC, // 4520
+    0x7A02, // 4521
+    0x7A03, // 4522
+    0x7A07, // 4523
+    0x7A09, // 4524
+    0x7A0A, // 4525
+    0x7A0C, // 4526
+    0x7A11, // 4527
+    0x7A15, // 4528
+    0x7A1B, // 4529
+    0x7A1E, // 4530
+    0x7A21, // 4531
+    0x7A27, // 4532
+    0x7A2B, // 4533
+    0x7A2D, // 4534
+    0x7A2F, // 4535
+    0x7A30, // 4536
+    0x7A34, // 4537
+    0x7A35, // 4538
+    0x7A38, // 4539
+    0x7A39, // 4540
+    0x7A3A, // 4541
+    0x7A44, // 4542
+    0x7A45, // 4543
+    0x7A47, // 4544
+    0x7A48, // 4545
+    0x7A4C, // 4546
+    0x7A55, // 4547
+    0x7A56, // 4548
+    0x7A59, // 4549
+    0x7A5C, // 4550
+    0x7A5D, // 4551
+    0x7A5F, // 4552
+    0x7A60, // 4553
+    0x7A65, // 4554
+    0x7A67, // 4555
+    0x7A6A, // 4556
+    0x7A6D, // 4557
+    0x7A75, // 4558
+    0x7A78, // 4559
+    0x7A7E, // 4560
+    0x7A80, // 4561
+    0x7A82, // 4562
+    0x7A85, // 4563
+    0x7A86, // 4564
+    0x7A8A, // 4565
+    0x7A8B, // 4566
+    0x7A90, // 4567
+    0x7A91, // 4568
+    0x7A94, // 4569
+    0x7A9E, // 4570
+    0x7AA0, // 4571
+    0x7AA3, // 4572
+    0x7AAC, // 4573
+    0x7AB3, // 4574
+    0x7AB5, // 4575
+    0x7AB9, // 4576
+    0x7ABB, // 4577
+    0x7ABC, // 4578
+    0x7AC6, // 4579
+    0x7AC9, // 4580
+    0x7ACC, // 4581
+    0x7ACE, // 4582
+    0x7AD1, // 4583
+    0x7ADB, // 4584
+    0x7AE8, // 4585
+    0x7AE9, // 4586
+    0x7AEB, // 4587
+    0x7AEC, // 4588
+    0x7AF1, // 4589
+    0x7AF4, // 4590
+    0x7AFB, // 4591
+    0x7AFD, // 4592
+    0x7AFE, // 4593
+    0x7B07, // 4594
+    0x7B14, // 4595
+    0x7B1F, // 4596
+    0x7B23, // 4597
+    0x7B27, // 4598
+    0x7B29, // 4599
+    0x7B2A, // 4600
+    0x7B2B, // 4601
+    0x7B2D, // 4602
+    0x7B2E, // 4603
+    0x7B2F, // 4604
+    0x7B30, // 4605
+    0x7B31, // 4606
+    0x7B34, // 4607
+    0x7B3D, // 4608
+    0x7B3F, // 4609
+    0x7B40, // 4610
+    0x7B41, // 4611
+    0x7B47, // 4612
+    0x7B4E, // 4613
+    0x7B55, // 4614
+    0x7B60, // 4615
+    0x7B64, // 4616
+    0x7B66, // 4617
+    0x7B69, // 4618
+    0x7B6A, // 4619
+    0x7B6D, // 4620
+    0x7B6F, // 4621
+    0x7B72, // 4622
+    0x7B73, // 4623
+    0x7B77, // 4624
+    0x7B84, // 4625
+    0x7B89, // 4626
+    0x7B8E, // 4627
+    0x7B90, // 4628
+    0x7B91, // 4629
+    0x7B96, // 4630
+    0x7B9B, // 4631
+    0x7B9E, // 4632
+    0x7BA0, // 4633
+    0x7BA5, // 4634
+    0x7BAC, // 4635
+    0x7BAF, // 4636
+    0x7BB0, // 4637
+    0x7BB2, // 4638
+    0x7BB5, // 4639
+    0x7BB6, // 4640
+    0x7BBA, // 4641
+    0x7BBB, // 4642
+    0x7BBC, // 4643
+    0x7BBD, // 4644
+    0x7BC2, // 4645
+    0x7BC5, // 4646
+    0x7BC8, // 4647
+    0x7BCA, // 4648
+    0x7BD4, // 4649
+    0x7BD6, // 4650
+    0x7BD7, // 4651
+    0x7BD9, // 4652
+    0x7BDA, // 4653
+    0x7BDB, // 4654
+    0x7BE8, // 4655
+    0x7BEA, // 4656
+    0x7BF2, // 4657
+    0x7BF4, // 4658
+    0x7BF5, // 4659
+    0x7BF8, // 4660
+    0x7BF9, // 4661
+    0x7BFA, // 4662
+    0x7BFC, // 4663
+    0x7BFE, // 4664
+    0x7C01, // 4665
+    0x7C02, // 4666
+    0x7C03, // 4667
+    0x7C04, // 4668
+    0x7C06, // 4669
+    0x7C09, // 4670
+    0x7C0B, // 4671
+    0x7C0C, // 4672
+    0x7C0E, // 4673
+    0x7C0F, // 4674
+    0x7C19, // 4675
+    0x7C1B, // 4676
+    0x7C20, // 4677
+    0x7C25, // 4678
+    0x7C26, // 4679
+    0x7C28, // 4680
+    0x7C2C, // 4681
+    0x7C31, // 4682
+    0x7C33, // 4683
+    0x7C34, // 4684
+    0x7C36, // 4685
+    0x7C39, // 4686
+    0x7C3A, // 4687
+    0x7C46, // 4688
+    0x7C4A, // 4689
+    0x7C55, // 4690
+    0x7C51, // 4691
+    0x7C52, // 4692
+    0x7C53, // 4693
+    0x7C59, // 4694
+    0x7C5A, // 4695
+    0x7C5B, // 4696
+    0x7C5C, // 4697
+    0x7C5D, // 4698
+    0x7C5E, // 4699
+    0x7C61, // 4700
+    0x7C63, // 4701
+    0x7C67, // 4702
+    0x7C69, // 4703
+    0x7C6D, // 4704
+    0x7C6E, // 4705
+    0x7C70, // 4706
+    0x7C72, // 4707
+    0x7C79, // 4708
+    0x7C7C, // 4709
+    0x7C7D, // 4710
+    0x7C86, // 4711
+    0x7C87, // 4712
+    0x7C8F, // 4713
+    0x7C94, // 4714
+    0x7C9E, // 4715
+    0x7CA0, // 4716
+    0x7CA6, // 4717
+    0x7CB0, // 4718
+    0x7CB6, // 4719
+    0x7CB7, // 4720
+    0x7CBA, // 4721
+    0x7CBB, // 4722
+    0x7CBC, // 4723
+    0x7CBF, // 4724
+    0x7CC4, // 4725
+    0x7CC7, // 4726
+    0x7CC8, // 4727
+    0x7CC9, // 4728
+    0x7CCD, // 4729
+    0x7CCF, // 4730
+    0x7CD3, // 4731
+    0x7CD4, // 4732
+    0x7CD5, // 4733
+    0x7CD7, // 4734
+    0x7CD9, // 4735
+    0x7CDA, // 4736
+    0x7CDD, // 4737
+    0x7CE6, // 4738
+    0x7CE9, // 4739
+    0x7CEB, // 4740
+    0x7CF5, // 4741
+    0x7D03, // 4742
+    0x7D07, // 4743
+    0x7D08, // 4744
+    0x7D09, // 4745
+    0x7D0F, // 4746
+    0x7D11, // 4747
+    0x7D12, // 4748
+    0x7D13, // 4749
+    0x7D16, // 4750
+    0x7D1D, // 4751
+    0x7D1E, // 4752
+    0x7D23, // 4753
+    0x7D26, // 4754
+    0x7D2A, // 4755
+    0x7D2D, // 4756
+    0x7D31, // 4757
+    0x7D3C, // 4758
+    0x7D3D, // 4759
+    0x7D3E, // 4760
+    0x7D40, // 4761
+    0x7D41, // 4762
+    0x7D47, // 4763
+    0x7D48, // 4764
+    0x7D4D, // 4765
+    0x7D51, // 4766
+    0x7D53, // 4767
+    0x7D57, // 4768
+    0x7D59, // 4769
+    0x7D5A, // 4770
+    0x7D5C, // 4771
+    0x7D5D, // 4772
+    0x7D65, // 4773
+    0x7D67, // 4774
+    0x7D6A, // 4775
+    0x7D70, // 4776
+    0x7D78, // 4777
+    0x7D7A, // 4778
+    0x7D7B, // 4779
+    0x7D7F, // 4780
+    0x7D81, // 4781
+    0x7D82, // 4782
+    0x7D83, // 4783
+    0x7D85, // 4784
+    0x7D86, // 4785
+    0x7D88, // 4786
+    0x7D8B, // 4787
+    0x7D8C, // 4788
+    0x7D8D, // 4789
+    0x7D91, // 4790
+    0x7D96, // 4791
+    0x7D97, // 4792
+    0x7D9D, // 4793
+    0x7D9E, // 4794
+    0x7DA6, // 4795
+    0x7DA7, // 4796
+    0x7DAA, // 4797
+    0x7DB3, // 4798
+    0x7DB6, // 4799
+    0x7DB7, // 4800
+    0x7DB9, // 4801
+    0x7DC2, // 4802
+    0x7DC3, // 4803
+    0x7DC4, // 4804
+    0x7DC5, // 4805
+    0x7DC6, // 4806
+    0x7DCC, // 4807
+    0x7DCD, // 4808
+    0x7DCE, // 4809
+    0x7DD7, // 4810
+    0x7DD9, // 4811
+    0x7E00, // 4812
+    0x7DE2, // 4813
+    0x7DE5, // 4814
+    0x7DE6, // 4815
+    0x7DEA, // 4816
+    0x7DEB, // 4817
+    0x7DED, // 4818
+    0x7DF1, // 4819
+    0x7DF5, // 4820
+    0x7DF6, // 4821
+    0x7DF9, // 4822
+    0x7DFA, // 4823
+    0x7E08, // 4824
+    0x7E10, // 4825
+    0x7E11, // 4826
+    0x7E15, // 4827
+    0x7E17, // 4828
+    0x7E1C, // 4829
+    0x7E1D, // 4830
+    0x7E20, // 4831
+    0x7E27, // 4832
+    0x7E28, // 4833
+    0x7E2C, // 4834
+    0x7E2D, // 4835
+    0x7E2F, // 4836
+    0x7E33, // 4837
+    0x7E36, // 4838
+    0x7E3F, // 4839
+    0x7E44, // 4840
+    0x7E45, // 4841
+    0x7E47, // 4842
+    0x7E4E, // 4843
+    0x7E50, // 4844
+    0x7E52, // 4845
+    0x7E58, // 4846
+    0x7E5F, // 4847
+    0x7E61, // 4848
+    0x7E62, // 4849
+    0x7E65, // 4850
+    0x7E6B, // 4851
+    0x7E6E, // 4852
+    0x7E6F, // 4853
+    0x7E73, // 4854
+    0x7E78, // 4855
+    0x7E7E, // 4856
+    0x7E81, // 4857
+    0x7E86, // 4858
+    0x7E87, // 4859
+    0x7E8A, // 4860
+    0x7E8D, // 4861
+    0x7E91, // 4862
+    0x7E95, // 4863
+    0x7E98, // 4864
+    0x7E9A, // 4865
+    0x7E9D, // 4866
+    0x7E9E, // 4867
+    0x7F3C, // 4868
+    0x7F3B, // 4869
+    0x7F3D, // 4870
+    0x7F3E, // 4871
+    0x7F3F, // 4872
+    0x7F43, // 4873
+    0x7F44, // 4874
+    0x7F47, // 4875
+    0x7F4F, // 4876
+    0x7F52, // 4877
+    0x7F53, // 4878
+    0x7F5B, // 4879
+    0x7F5C, // 4880
+    0x7F5D, // 4881
+    0x7F61, // 4882
+    0x7F63, // 4883
+    0x7F64, // 4884
+    0x7F65, // 4885
+    0x7F66, // 4886
+    0x7F6D, // 4887
+    0x7F71, // 4888
+    0x7F7D, // 4889
+    0x7F7E, // 4890
+    0x7F7F, // 4891
+    0x7F80, // 4892
+    0x7F8B, // 4893
+    0x7F8D, // 4894
+    0x7F8F, // 4895
+    0x7F90, // 4896
+    0x7F91, // 4897
+    0x7F96, // 4898
+    0x7F97, // 4899
+    0x7F9C, // 4900
+    0x7FA1, // 4901
+    0x7FA2, // 4902
+    0x7FA6, // 4903
+    0x7FAA, // 4904
+    0x7FAD, // 4905
+    0x7FB4, // 4906
+    0x7FBC, // 4907
+    0x7FBF, // 4908
+    0x7FC0, // 4909
+    0x7FC3, // 4910
+    0x7FC8, // 4911
+    0x7FCE, // 4912
+    0x7FCF, // 4913
+    0x7FDB, // 4914
+    0x7FDF, // 4915
+    0x7FE3, // 4916
+    0x7FE5, // 4917
+    0x7FE8, // 4918
+    0x7FEC, // 4919
+    0x7FEE, // 4920
+    0x7FEF, // 4921
+    0x7FF2, // 4922
+    0x7FFA, // 4923
+    0x7FFD, // 4924
+    0x7FFE, // 4925
+    0x7FFF, // 4926
+    0x8007, // 4927
+    0x8008, // 4928
+    0x800A, // 4929
+    0x800D, // 4930
+    0x800E, // 4931
+    0x800F, // 4932
+    0x8011, // 4933
+    0x8013, // 4934
+    0x8014, // 4935
+    0x8016, // 4936
+    0x801D, // 4937
+    0x801E, // 4938
+    0x801F, // 4939
+    0x8020, // 4940
+    0x8024, // 4941
+    0x8026, // 4942
+    0x802C, // 4943
+    0x802E, // 4944
+    0x8030, // 4945
+    0x8034, // 4946
+    0x8035, // 4947
+    0x8037, // 4948
+    0x8039, // 4949
+    0x803A, // 4950
+    0x803C, // 4951
+    0x803E, // 4952
+    0x8040, // 4953
+    0x8044, // 4954
+    0x8060, // 4955
+    0x8064, // 4956
+    0x8066, // 4957
+    0x806D, // 4958
+    0x8071, // 4959
+    0x8075, // 4960
+    0x8081, // 4961
+    0x8088, // 4962
+    0x808E, // 4963
+    0x809C, // 4964
+    0x809E, // 4965
+    0x80A6, // 4966
+    0x80A7, // 4967
+    0x80AB, // 4968
+    0x80B8, // 4969
+    0x80B9, // 4970
+    0x80C8, // 4971
+    0x80CD, // 4972
+    0x80CF, // 4973
+    0x80D2, // 4974
+    0x80D4, // 4975
+    0x80D5, // 4976
+    0x80D7, // 4977
+    0x80D8, // 4978
+    0x80E0, // 4979
+    0x80ED, // 4980
+    0x80EE, // 4981
+    0x80F0, // 4982
+    0x80F2, // 4983
+    0x80F3, // 4984
+    0x80F6, // 4985
+    0x80F9, // 4986
+    0x80FA, // 4987
+    0x80FE, // 4988
+    0x8103, // 4989
+    0x810B, // 4990
+    0x8116, // 4991
+    0x8117, // 4992
+    0x8118, // 4993
+    0x811C, // 4994
+    0x811E, // 4995
+    0x8120, // 4996
+    0x8124, // 4997
+    0x8127, // 4998
+    0x812C, // 4999
+    0x8130, // 5000
+    0x8135, // 5001
+    0x813A, // 5002
+    0x813C, // 5003
+    0x8145, // 5004
+    0x8147, // 5005
+    0x814A, // 5006
+    0x814C, // 5007
+    0x8152, // 5008
+    0x8157, // 5009
+    0x8160, // 5010
+    0x8161, // 5011
+    0x8167, // 5012
+    0x8168, // 5013
+    0x8169, // 5014
+    0x816D, // 5015
+    0x816F, // 5016
+    0x8177, // 5017
+    0x8181, // 5018
+    0x8190, // 5019
+    0x8184, // 5020
+    0x8185, // 5021
+    0x8186, // 5022
+    0x818B, // 5023
+    0x818E, // 5024
+    0x8196, // 5025
+    0x8198, // 5026
+    0x819B, // 5027
+    0x819E, // 5028
+    0x81A2, // 5029
+    0x81AE, // 5030
+    0x81B2, // 5031
+    0x81B4, // 5032
+    0x81BB, // 5033
+    0x81CB, // 5034
+    0x81C3, // 5035
+    0x81C5, // 5036
+    0x81CA, // 5037
+    0x81CE, // 5038
+    0x81CF, // 5039
+    0x81D5, // 5040
+    0x81D7, // 5041
+    0x81DB, // 5042
+    0x81DD, // 5043
+    0x81DE, // 5044
+    0x81E1, // 5045
+    0x81E4, // 5046
+    0x81EB, // 5047
+    0x81EC, // 5048
+    0x81F0, // 5049
+    0x81F1, // 5050
+    0x81F2, // 5051
+    0x81F5, // 5052
+    0x81F6, // 5053
+    0x81F8, // 5054
+    0x81F9, // 5055
+    0x81FD, // 5056
+    0x81FF, // 5057
+    0x8200, // 5058
+    0x8203, // 5059
+    0x820F, // 5060
+    0x8213, // 5061
+    0x8214, // 5062
+    0x8219, // 5063
+    0x821A, // 5064
+    0x821D, // 5065
+    0x8221, // 5066
+    0x8222, // 5067
+    0x8228, // 5068
+    0x8232, // 5069
+    0x8234, // 5070
+    0x823A, // 5071
+    0x8243, // 5072
+    0x8244, // 5073
+    0x8245, // 5074
+    0x8246, // 5075
+    0x824B, // 5076
+    0x824E, // 5077
+    0x824F, // 5078
+    0x8251, // 5079
+    0x8256, // 5080
+    0x825C, // 5081
+    0x8260, // 5082
+    0x8263, // 5083
+    0x8267, // 5084
+    0x826D, // 5085
+    0x8274, // 5086
+    0x827B, // 5087
+    0x827D, // 5088
+    0x827F, // 5089
+    0x8280, // 5090
+    0x8281, // 5091
+    0x8283, // 5092
+    0x8284, // 5093
+    0x8287, // 5094
+    0x8289, // 5095
+    0x828A, // 5096
+    0x828E, // 5097
+    0x8291, // 5098
+    0x8294, // 5099
+    0x8296, // 5100
+    0x8298, // 5101
+    0x829A, // 5102
+    0x829B, // 5103
+    0x82A0, // 5104
+    0x82A1, // 5105
+    0x82A3, // 5106
+    0x82A4, // 5107
+    0x82A7, // 5108
+    0x82A8, // 5109
+    0x82A9, // 5110
+    0x82AA, // 5111
+    0x82AE, // 5112
+    0x82B0, // 5113
+    0x82B2, // 5114
+    0x82B4, // 5115
+    0x82B7, // 5116
+    0x82BA, // 5117
+    0x82BC, // 5118
+    0x82BE, // 5119
+    0x82BF, // 5120
+    0x82C6, // 5121
+    0x82D0, // 5122
+    0x82D5, // 5123
+    0x82DA, // 5124
+    0x82E0, // 5125
+    0x82E2, // 5126
+    0x82E4, // 5127
+    0x82E8, // 5128
+    0x82EA, // 5129
+    0x82ED, // 5130
+    0x82EF, // 5131
+    0x82F6, // 5132
+    0x82F7, // 5133
+    0x82FD, // 5134
+    0x82FE, // 5135
+    0x8300, // 5136
+    0x8301, // 5137
+    0x8307, // 5138
+    0x8308, // 5139
+    0x830A, // 5140
+    0x830B, // 5141
+    0x8354, // 5142
+    0x831B, // 5143
+    0x831D, // 5144
+    0x831E, // 5145
+    0x831F, // 5146
+    0x8321, // 5147
+    0x8322, // 5148
+    0x832C, // 5149
+    0x832D, // 5150
+    0x832E, // 5151
+    0x8330, // 5152
+    0x8333, // 5153
+    0x8337, // 5154
+    0x833A, // 5155
+    0x833C, // 5156
+    0x833D, // 5157
+    0x8342, // 5158
+    0x8343, // 5159
+    0x8344, // 5160
+    0x8347, // 5161
+    0x834D, // 5162
+    0x834E, // 5163
+    0x8351, // 5164
+    0x8355, // 5165
+    0x8356, // 5166
+    0x8357, // 5167
+    0x8370, // 5168
+    0x8378, // 5169
+    0x837D, // 5170
+    0x837F, // 5171
+    0x8380, // 5172
+    0x8382, // 5173
+    0x8384, // 5174
+    0x8386, // 5175
+    0x838D, // 5176
+    0x8392, // 5177
+    0x8394, // 5178
+    0x8395, // 5179
+    0x8398, // 5180
+    0x8399, // 5181
+    0x839B, // 5182
+    0x839C, // 5183
+    0x839D, // 5184
+    0x83A6, // 5185
+    0x83A7, // 5186
+    0x83A9, // 5187
+    0x83AC, // 5188
+    0x83BE, // 5189
+    0x83BF, // 5190
+    0x83C0, // 5191
+    0x83C7, // 5192
+    0x83C9, // 5193
+    0x83CF, // 5194
+    0x83D0, // 5195
+    0x83D1, // 5196
+    0x83D4, // 5197
+    0x83DD, // 5198
+    0x8353, // 5199
+    0x83E8, // 5200
+    0x83EA, // 5201
+    0x83F6, // 5202
+    0x83F8, // 5203
+    0x83F9, // 5204
+    0x83FC, // 5205
+    0x8401, // 5206
+    0x8406, // 5207
+    0x840A, // 5208
+    0x840F, // 5209
+    0x8411, // 5210
+    0x8415, // 5211
+    0x8419, // 5212
+    0x83AD, // 5213
+    0x842F, // 5214
+    0x8439, // 5215
+    0x8445, // 5216
+    0x8447, // 5217
+    0x8448, // 5218
+    0x844A, // 5219
+    0x844D, // 5220
+    0x844F, // 5221
+    0x8451, // 5222
+    0x8452, // 5223
+    0x8456, // 5224
+    0x8458, // 5225
+    0x8459, // 5226
+    0x845A, // 5227
+    0x845C, // 5228
+    0x8460, // 5229
+    0x8464, // 5230
+    0x8465, // 5231
+    0x8467, // 5232
+    0x846A, // 5233
+    0x8470, // 5234
+    0x8473, // 5235
+    0x8474, // 5236
+    0x8476, // 5237
+    0x8478, // 5238
+    0x847C, // 5239
+    0x847D, // 5240
+    0x8481, // 5241
+    0x8485, // 5242
+    0x8492, // 5243
+    0x8493, // 5244
+    0x8495, // 5245
+    0x849E, // 5246
+    0x84A6, // 5247
+    0x84A8, // 5248
+    0x84A9, // 5249
+    0x84AA, // 5250
+    0x84AF, // 5251
+    0x84B1, // 5252
+    0x84B4, // 5253
+    0x84BA, // 5254
+    0x84BD, // 5255
+    0x84BE, // 5256
+    0x84C0, // 5257
+    0x84C2, // 5258
+    0x84C7, // 5259
+    0x84C8, // 5260
+    0x84CC, // 5261
+    0x84CF, // 5262
+    0x84D3, // 5263
+    0x84DC, // 5264
+    0x84E7, // 5265
+    0x84EA, // 5266
+    0x84EF, // 5267
+    0x84F0, // 5268
+    0x84F1, // 5269
+    0x84F2, // 5270
+    0x84F7, // 5271
+    0x8532, // 5272
+    0x84FA, // 5273
+    0x84FB, // 5274
+    0x84FD, // 5275
+    0x8502, // 5276
+    0x8503, // 5277
+    0x8507, // 5278
+    0x850C, // 5279
+    0x850E, // 5280
+    0x8510, // 5281
+    0x851C, // 5282
+    0x851E, // 5283
+    0x8522, // 5284
+    0x8523, // 5285
+    0x8524, // 5286
+    0x8525, // 5287
+    0x8527, // 5288
+    0x852A, // 5289
+    0x852B, // 5290
+    0x852F, // 5291
+    0x8533, // 5292
+    0x8534, // 5293
+    0x8536, // 5294
+    0x853F, // 5295
+    0x8546, // 5296
+    0x854F, // 5297
+    0x8550, // 5298
+    0x8551, // 5299
+    0x8552, // 5300
+    0x8553, // 5301
+    0x8556, // 5302
+    0x8559, // 5303
+    0x855C, // 5304
+    0x855D, // 5305
+    0x855E, // 5306
+    0x855F, // 5307
+    0x8560, // 5308
+    0x8561, // 5309
+    0x8562, // 5310
+    0x8564, // 5311
+    0x856B, // 5312
+    0x856F, // 5313
+    0x8579, // 5314
+    0x857A, // 5315
+    0x857B, // 5316
+    0x857D, // 5317
+    0x857F, // 5318
+    0x8581, // 5319
+    0x8585, // 5320
+    0x8586, // 5321
+    0x8589, // 5322
+    0x858B, // 5323
+    0x858C, // 5324
+    0x858F, // 5325
+    0x8593, // 5326
+    0x8598, // 5327
+    0x859D, // 5328
+    0x859F, // 5329
+    0x85A0, // 5330
+    0x85A2, // 5331
+    0x85A5, // 5332
+    0x85A7, // 5333
+    0x85B4, // 5334
+    0x85B6, // 5335
+    0x85B7, // 5336
+    0x85B8, // 5337
+    0x85BC, // 5338
+    0x85BD, // 5339
+    0x85BE, // 5340
+    0x85BF, // 5341
+    0x85C2, // 5342
+    0x85C7, // 5343
+    0x85CA, // 5344
+    0x85CB, // 5345
+    0x85CE, // 5346
+    0x85AD, // 5347
+    0x85D8, // 5348
+    0x85DA, // 5349
+    0x85DF, // 5350
+    0x85E0, // 5351
+    0x85E6, // 5352
+    0x85E8, // 5353
+    0x85ED, // 5354
+    0x85F3, // 5355
+    0x85F6, // 5356
+    0x85FC, // 5357
+    0x85FF, // 5358
+    0x8600, // 5359
+    0x8604, // 5360
+    0x8605, // 5361
+    0x860D, // 5362
+    0x860E, // 5363
+    0x8610, // 5364
+    0x8611, // 5365
+    0x8612, // 5366
+    0x8618, // 5367
+    0x8619, // 5368
+    0x861B, // 5369
+    0x861E, // 5370
+    0x8621, // 5371
+    0x8627, // 5372
+    0x8629, // 5373
+    0x8636, // 5374
+    0x8638, // 5375
+    0x863A, // 5376
+    0x863C, // 5377
+    0x863D, // 5378
+    0x8640, // 5379
+    0x8642, // 5380
+    0x8646, // 5381
+    0x8652, // 5382
+    0x8653, // 5383
+    0x8656, // 5384
+    0x8657, // 5385
+    0x8658, // 5386
+    0x8659, // 5387
+    0x865D, // 5388
+    0x8660, // 5389
+    0x8661, // 5390
+    0x8662, // 5391
+    0x8663, // 5392
+    0x8664, // 5393
+    0x8669, // 5394
+    0x866C, // 5395
+    0x866F, // 5396
+    0x8675, // 5397
+    0x8676, // 5398
+    0x8677, // 5399
+    0x867A, // 5400
+    0x868D, // 5401
+    0x8691, // 5402
+    0x8696, // 5403
+    0x8698, // 5404
+    0x869A, // 5405
+    0x869C, // 5406
+    0x86A1, // 5407
+    0x86A6, // 5408
+    0x86A7, // 5409
+    0x86A8, // 5410
+    0x86AD, // 5411
+    0x86B1, // 5412
+    0x86B3, // 5413
+    0x86B4, // 5414
+    0x86B5, // 5415
+    0x86B7, // 5416
+    0x86B8, // 5417
+    0x86B9, // 5418
+    0x86BF, // 5419
+    0x86C0, // 5420
+    0x86C1, // 5421
+    0x86C3, // 5422
+    0x86C5, // 5423
+    0x86D1, // 5424
+    0x86D2, // 5425
+    0x86D5, // 5426
+    0x86D7, // 5427
+    0x86DA, // 5428
+    0x86DC, // 5429
+    0x86E0, // 5430
+    0x86E3, // 5431
+    0x86E5, // 5432
+    0x86E7, // 5433
+    0x8688, // 5434
+    0x86FA, // 5435
+    0x86FC, // 5436
+    0x86FD, // 5437
+    0x8704, // 5438
+    0x8705, // 5439
+    0x8707, // 5440
+    0x870B, // 5441
+    0x870E, // 5442
+    0x870F, // 5443
+    0x8710, // 5444
+    0x8713, // 5445
+    0x8714, // 5446
+    0x8719, // 5447
+    0x871E, // 5448
+    0x871F, // 5449
+    0x8721, // 5450
+    0x8723, // 5451
+    0x8728, // 5452
+    0x872E, // 5453
+    0x872F, // 5454
+    0x8731, // 5455
+    0x8732, // 5456
+    0x8739, // 5457
+    0x873A, // 5458
+    0x873C, // 5459
+    0x873D, // 5460
+    0x873E, // 5461
+    0x8740, // 5462
+    0x8743, // 5463
+    0x8745, // 5464
+    0x874D, // 5465
+    0x8758, // 5466
+    0x875D, // 5467
+    0x8761, // 5468
+    0x8764, // 5469
+    0x8765, // 5470
+    0x876F, // 5471
+    0x8771, // 5472
+    0x8772, // 5473
+    0x877B, // 5474
+    0x8783, // 5475
+    0x8784, // 5476
+    0x8785, // 5477
+    0x8786, // 5478
+    0x8787, // 5479
+    0x8788, // 5480
+    0x8789, // 5481
+    0x878B, // 5482
+    0x878C, // 5483
+    0x8790, // 5484
+    0x8793, // 5485
+    0x8795, // 5486
+    0x8797, // 5487
+    0x8798, // 5488
+    0x8799, // 5489
+    0x879E, // 5490
+    0x87A0, // 5491
+    0x87A3, // 5492
+    0x87A7, // 5493
+    0x87AC, // 5494
+    0x87AD, // 5495
+    0x87AE, // 5496
+    0x87B1, // 5497
+    0x87B5, // 5498
+    0x87BE, // 5499
+    0x87BF, // 5500
+    0x87C1, // 5501
+    0x87C8, // 5502
+    0x87C9, // 5503
+    0x87CA, // 5504
+    0x87CE, // 5505
+    0x87D5, // 5506
+    0x87D6, // 5507
+    0x87D9, // 5508
+    0x87DA, // 5509
+    0x87DC, // 5510
+    0x87DF, // 5511
+    0x87E2, // 5512
+    0x87E3, // 5513
+    0x87E4, // 5514
+    0x87EA, // 5515
+    0x87EB, // 5516
+    0x87ED, // 5517
+    0x87F1, // 5518
+    0x87F3, // 5519
+    0x87F8, // 5520
+    0x87FA, // 5521
+    0x87FF, // 5522
+    0x8801, // 5523
+    0x8803, // 5524
+    0x8806, // 5525
+    0x8809, // 5526
+    0x880A, // 5527
+    0x880B, // 5528
+    0x8810, // 5529
+    0x8819, // 5530
+    0x8812, // 5531
+    0x8813, // 5532
+    0x8814, // 5533
+    0x8818, // 5534
+    0x881A, // 5535
+    0x881B, // 5536
+    0x881C, // 5537
+    0x881E, // 5538
+    0x881F, // 5539
+    0x8828, // 5540
+    0x882D, // 5541
+    0x882E, // 5542
+    0x8830, // 5543
+    0x8832, // 5544
+    0x8835, // 5545
+    0x883A, // 5546
+    0x883C, // 5547
+    0x8841, // 5548
+    0x8843, // 5549
+    0x8845, // 5550
+    0x8848, // 5551
+    0x8849, // 5552
+    0x884A, // 5553
+    0x884B, // 5554
+    0x884E, // 5555
+    0x8851, // 5556
+    0x8855, // 5557
+    0x8856, // 5558
+    0x8858, // 5559
+    0x885A, // 5560
+    0x885C, // 5561
+    0x885F, // 5562
+    0x8860, // 5563
+    0x8864, // 5564
+    0x8869, // 5565
+    0x8871, // 5566
+    0x8879, // 5567
+    0x887B, // 5568
+    0x8880, // 5569
+    0x8898, // 5570
+    0x889A, // 5571
+    0x889B, // 5572
+    0x889C, // 5573
+    0x889F, // 5574
+    0x88A0, // 5575
+    0x88A8, // 5576
+    0x88AA, // 5577
+    0x88BA, // 5578
+    0x88BD, // 5579
+    0x88BE, // 5580
+    0x88C0, // 5581
+    0x88CA, // 5582
+    0x88CB, // 5583
+    0x88CC, // 5584
+    0x88CD, // 5585
+    0x88CE, // 5586
+    0x88D1, // 5587
+    0x88D2, // 5588
+    0x88D3, // 5589
+    0x88DB, // 5590
+    0x88DE, // 5591
+    0x88E7, // 5592
+    0x88EF, // 5593
+    0x88F0, // 5594
+    0x88F1, // 5595
+    0x88F5, // 5596
+    0x88F7, // 5597
+    0x8901, // 5598
+    0x8906, // 5599
+    0x890D, // 5600
+    0x890E, // 5601
+    0x890F, // 5602
+    0x8915, // 5603
+    0x8916, // 5604
+    0x8918, // 5605
+    0x8919, // 5606
+    0x891A, // 5607
+    0x891C, // 5608
+    0x8920, // 5609
+    0x8926, // 5610
+    0x8927, // 5611
+    0x8928, // 5612
+    0x8930, // 5613
+    0x8931, // 5614
+    0x8932, // 5615
+    0x8935, // 5616
+    0x8939, // 5617
+    0x893A, // 5618
+    0x893E, // 5619
+    0x8940, // 5620
+    0x8942, // 5621
+    0x8945, // 5622
+    0x8946, // 5623
+    0x8949, // 5624
+    0x894F, // 5625
+    0x8952, // 5626
+    0x8957, // 5627
+    0x895A, // 5628
+    0x895B, // 5629
+    0x895C, // 5630
+    0x8961, // 5631
+    0x8962, // 5632
+    0x8963, // 5633
+    0x896B, // 5634
+    0x896E, // 5635
+    0x8970, // 5636
+    0x8973, // 5637
+    0x8975, // 5638
+    0x897A, // 5639
+    0x897B, // 5640
+    0x897C, // 5641
+    0x897D, // 5642
+    0x8989, // 5643
+    0x898D, // 5644
+    0x8990, // 5645
+    0x8994, // 5646
+    0x8995, // 5647
+    0x899B, // 5648
+    0x899C, // 5649
+    0x899F, // 5650
+    0x89A0, // 5651
+    0x89A5, // 5652
+    0x89B0, // 5653
+    0x89B4, // 5654
+    0x89B5, // 5655
+    0x89B6, // 5656
+    0x89B7, // 5657
+    0x89BC, // 5658
+    0x89D4, // 5659
+    0x89D5, // 5660
+    0x89D6, // 5661
+    0x89D7, // 5662
+    0x89D8, // 5663
+    0x89E5, // 5664
+    0x89E9, // 5665
+    0x89EB, // 5666
+    0x89ED, // 5667
+    0x89F1, // 5668
+    0x89F3, // 5669
+    0x89F6, // 5670
+    0x89F9, // 5671
+    0x89FD, // 5672
+    0x89FF, // 5673
+    0x8A04, // 5674
+    0x8A05, // 5675
+    0x8A07, // 5676
+    0x8A0F, // 5677
+    0x8A11, // 5678
+    0x8A12, // 5679
+    0x8A14, // 5680
+    0x8A15, // 5681
+    0x8A1E, // 5682
+    0x8A20, // 5683
+    0x8A22, // 5684
+    0x8A24, // 5685
+    0x8A26, // 5686
+    0x8A2B, // 5687
+    0x8A2C, // 5688
+    0x8A2F, // 5689
+    0x8A35, // 5690
+    0x8A37, // 5691
+    0x8A3D, // 5692
+    0x8A3E, // 5693
+    0x8A40, // 5694
+    0x8A43, // 5695
+    0x8A45, // 5696
+    0x8A47, // 5697
+    0x8A49, // 5698
+    0x8A4D, // 5699
+    0x8A4E, // 5700
+    0x8A53, // 5701
+    0x8A56, // 5702
+    0x8A57, // 5703
+    0x8A58, // 5704
+    0x8A5C, // 5705
+    0x8A5D, // 5706
+    0x8A61, // 5707
+    0x8A65, // 5708
+    0x8A67, // 5709
+    0x8A75, // 5710
+    0x8A76, // 5711
+    0x8A77, // 5712
+    0x8A79, // 5713
+    0x8A7A, // 5714
+    0x8A7B, // 5715
+    0x8A7E, // 5716
+    0x8A7F, // 5717
+    0x8A80, // 5718
+    0x8A83, // 5719
+    0x8A86, // 5720
+    0x8A8B, // 5721
+    0x8A8F, // 5722
+    0x8A90, // 5723
+    0x8A92, // 5724
+    0x8A96, // 5725
+    0x8A97, // 5726
+    0x8A99, // 5727
+    0x8A9F, // 5728
+    0x8AA7, // 5729
+    0x8AA9, // 5730
+    0x8AAE, // 5731
+    0x8AAF, // 5732
+    0x8AB3, // 5733
+    0x8AB6, // 5734
+    0x8AB7, // 5735
+    0x8ABB, // 5736
+    0x8ABE, // 5737
+    0x8AC3, // 5738
+    0x8AC6, // 5739
+    0x8AC8, // 5740
+    0x8AC9, // 5741
+    0x8ACA, // 5742
+    0x8AD1, // 5743
+    0x8AD3, // 5744
+    0x8AD4, // 5745
+    0x8AD5, // 5746
+    0x8AD7, // 5747
+    0x8ADD, // 5748
+    0x8ADF, // 5749
+    0x8AEC, // 5750
+    0x8AF0, // 5751
+    0x8AF4, // 5752
+    0x8AF5, // 5753
+    0x8AF6, // 5754
+    0x8AFC, // 5755
+    0x8AFF, // 5756
+    0x8B05, // 5757
+    0x8B06, // 5758
+    0x8B0B, // 5759
+    0x8B11, // 5760
+    0x8B1C, // 5761
+    0x8B1E, // 5762
+    0x8B1F, // 5763
+    0x8B0A, // 5764
+    0x8B2D, // 5765
+    0x8B30, // 5766
+    0x8B37, // 5767
+    0x8B3C, // 5768
+    0x8B42, // 5769
+    0x8B43, // 5770
+    0x8B44, // 5771
+    0x8B45, // 5772
+    0x8B46, // 5773
+    0x8B48, // 5774
+    0x8B52, // 5775
+    0x8B53, // 5776
+    0x8B54, // 5777
+    0x8B59, // 5778
+    0x8B4D, // 5779
+    0x8B5E, // 5780
+    0x8B63, // 5781
+    0x8B6D, // 5782
+    0x8B76, // 5783
+    0x8B78, // 5784
+    0x8B79, // 5785
+    0x8B7C, // 5786
+    0x8B7E, // 5787
+    0x8B81, // 5788
+    0x8B84, // 5789
+    0x8B85, // 5790
+    0x8B8B, // 5791
+    0x8B8D, // 5792
+    0x8B8F, // 5793
+    0x8B94, // 5794
+    0x8B95, // 5795
+    0x8B9C, // 5796
+    0x8B9E, // 5797
+    0x8B9F, // 5798
+    0x8C38, // 5799
+    0x8C39, // 5800
+    0x8C3D, // 5801
+    0x8C3E, // 5802
+    0x8C45, // 5803
+    0x8C47, // 5804
+    0x8C49, // 5805
+    0x8C4B, // 5806
+    0x8C4F, // 5807
+    0x8C51, // 5808
+    0x8C53, // 5809
+    0x8C54, // 5810
+    0x8C57, // 5811
+    0x8C58, // 5812
+    0x8C5B, // 5813
+    0x8C5D, // 5814
+    0x8C59, // 5815
+    0x8C63, // 5816
+    0x8C64, // 5817
+    0x8C66, // 5818
+    0x8C68, // 5819
+    0x8C69, // 5820
+    0x8C6D, // 5821
+    0x8C73, // 5822
+    0x8C75, // 5823
+    0x8C76, // 5824
+    0x8C7B, // 5825
+    0x8C7E, // 5826
+    0x8C86, // 5827
+    0x8C87, // 5828
+    0x8C8B, // 5829
+    0x8C90, // 5830
+    0x8C92, // 5831
+    0x8C93, // 5832
+    0x8C99, // 5833
+    0x8C9B, // 5834
+    0x8C9C, // 5835
+    0x8CA4, // 5836
+    0x8CB9, // 5837
+    0x8CBA, // 5838
+    0x8CC5, // 5839
+    0x8CC6, // 5840
+    0x8CC9, // 5841
+    0x8CCB, // 5842
+    0x8CCF, // 5843
+    0x8CD6, // 5844
+    0x8CD5, // 5845
+    0x8CD9, // 5846
+    0x8CDD, // 5847
+    0x8CE1, // 5848
+    0x8CE8, // 5849
+    0x8CEC, // 5850
+    0x8CEF, // 5851
+    0x8CF0, // 5852
+    0x8CF2, // 5853
+    0x8CF5, // 5854
+    0x8CF7, // 5855
+    0x8CF8, // 5856
+    0x8CFE, // 5857
+    0x8CFF, // 5858
+    0x8D01, // 5859
+    0x8D03, // 5860
+    0x8D09, // 5861
+    0x8D12, // 5862
+    0x8D17, // 5863
+    0x8D1B, // 5864
+    0x8D65, // 5865
+    0x8D69, // 5866
+    0x8D6C, // 5867
+    0x8D6E, // 5868
+    0x8D7F, // 5869
+    0x8D82, // 5870
+    0x8D84, // 5871
+    0x8D88, // 5872
+    0x8D8D, // 5873
+    0x8D90, // 5874
+    0x8D91, // 5875
+    0x8D95, // 5876
+    0x8D9E, // 5877
+    0x8D9F, // 5878
+    0x8DA0, // 5879
+    0x8DA6, // 5880
+    0x8DAB, // 5881
+    0x8DAC, // 5882
+    0x8DAF, // 5883
+    0x8DB2, // 5884
+    0x8DB5, // 5885
+    0x8DB7, // 5886
+    0x8DB9, // 5887
+    0x8DBB, // 5888
+    0x8DC0, // 5889
+    0x8DC5, // 5890
+    0x8DC6, // 5891
+    0x8DC7, // 5892
+    0x8DC8, // 5893
+    0x8DCA, // 5894
+    0x8DCE, // 5895
+    0x8DD1, // 5896
+    0x8DD4, // 5897
+    0x8DD5, // 5898
+    0x8DD7, // 5899
+    0x8DD9, // 5900
+    0x8DE4, // 5901
+    0x8DE5, // 5902
+    0x8DE7, // 5903
+    0x8DEC, // 5904
+    0x8DF0, // 5905
+    0x8DBC, // 5906
+    0x8DF1, // 5907
+    0x8DF2, // 5908
+    0x8DF4, // 5909
+    0x8DFD, // 5910
+    0x8E01, // 5911
+    0x8E04, // 5912
+    0x8E05, // 5913
+    0x8E06, // 5914
+    0x8E0B, // 5915
+    0x8E11, // 5916
+    0x8E14, // 5917
+    0x8E16, // 5918
+    0x8E20, // 5919
+    0x8E21, // 5920
+    0x8E22, // 5921
+    0x8E23, // 5922
+    0x8E26, // 5923
+    0x8E27, // 5924
+    0x8E31, // 5925
+    0x8E33, // 5926
+    0x8E36, // 5927
+    0x8E37, // 5928
+    0x8E38, // 5929
+    0x8E39, // 5930
+    0x8E3D, // 5931
+    0x8E40, // 5932
+    0x8E41, // 5933
+    0x8E4B, // 5934
+    0x8E4D, // 5935
+    0x8E4E, // 5936
+    0x8E4F, // 5937
+    0x8E54, // 5938
+    0x8E5B, // 5939
+    0x8E5C, // 5940
+    0x8E5D, // 5941
+    0x8E5E, // 5942
+    0x8E61, // 5943
+    0x8E62, // 5944
+    0x8E69, // 5945
+    0x8E6C, // 5946
+    0x8E6D, // 5947
+    0x8E6F, // 5948
+    0x8E70, // 5949
+    0x8E71, // 5950
+    0x8E79, // 5951
+    0x8E7A, // 5952
+    0x8E7B, // 5953
+    0x8E82, // 5954
+    0x8E83, // 5955
+    0x8E89, // 5956
+    0x8E90, // 5957
+    0x8E92, // 5958
+    0x8E95, // 5959
+    0x8E9A, // 5960
+    0x8E9B, // 5961
+    0x8E9D, // 5962
+    0x8E9E, // 5963
+    0x8EA2, // 5964
+    0x8EA7, // 5965
+    0x8EA9, // 5966
+    0x8EAD, // 5967
+    0x8EAE, // 5968
+    0x8EB3, // 5969
+    0x8EB5, // 5970
+    0x8EBA, // 5971
+    0x8EBB, // 5972
+    0x8EC0, // 5973
+    0x8EC1, // 5974
+    0x8EC3, // 5975
+    0x8EC4, // 5976
+    0x8EC7, // 5977
+    0x8ECF, // 5978
+    0x8ED1, // 5979
+    0x8ED4, // 5980
+    0x8EDC, // 5981
+    0x8EE8, // 5982
+    0x8EEE, // 5983
+    0x8EF0, // 5984
+    0x8EF1, // 5985
+    0x8EF7, // 5986
+    0x8EF9, // 5987
+    0x8EFA, // 5988
+    0x8EED, // 5989
+    0x8F00, // 5990
+    0x8F02, // 5991
+    0x8F07, // 5992
+    0x8F08, // 5993
+    0x8F0F, // 5994
+    0x8F10, // 5995
+    0x8F16, // 5996
+    0x8F17, // 5997
+    0x8F18, // 5998
+    0x8F1E, // 5999
+    0x8F20, // 6000
+    0x8F21, // 6001
+    0x8F23, // 6002
+    0x8F25, // 6003
+    0x8F27, // 6004
+    0x8F28, // 6005
+    0x8F2C, // 6006
+    0x8F2D, // 6007
+    0x8F2E, // 6008
+    0x8F34, // 6009
+    0x8F35, // 6010
+    0x8F36, // 6011
+    0x8F37, // 6012
+    0x8F3A, // 6013
+    0x8F40, // 6014
+    0x8F41, // 6015
+    0x8F43, // 6016
+    0x8F47, // 6017
+    0x8F4F, // 6018
+    0x8F51, // 6019
+    0x8F52, // 6020
+    0x8F53, // 6021
+    0x8F54, // 6022
+    0x8F55, // 6023
+    0x8F58, // 6024
+    0x8F5D, // 6025
+    0x8F5E, // 6026
+    0x8F65, // 6027
+    0x8F9D, // 6028
+    0x8FA0, // 6029
+    0x8FA1, // 6030
+    0x8FA4, // 6031
+    0x8FA5, // 6032
+    0x8FA6, // 6033
+    0x8FB5, // 6034
+    0x8FB6, // 6035
+    0x8FB8, // 6036
+    0x8FBE, // 6037
+    0x8FC0, // 6038
+    0x8FC1, // 6039
+    0x8FC6, // 6040
+    0x8FCA, // 6041
+    0x8FCB, // 6042
+    0x8FCD, // 6043
+    0x8FD0, // 6044
+    0x8FD2, // 6045
+    0x8FD3, // 6046
+    0x8FD5, // 6047
+    0x8FE0, // 6048
+    0x8FE3, // 6049
+    0x8FE4, // 6050
+    0x8FE8, // 6051
+    0x8FEE, // 6052
+    0x8FF1, // 6053
+    0x8FF5, // 6054
+    0x8FF6, // 6055
+    0x8FFB, // 6056
+    0x8FFE, // 6057
+    0x9002, // 6058
+    0x9004, // 6059
+    0x9008, // 6060
+    0x900C, // 6061
+    0x9018, // 6062
+    0x901B, // 6063
+    0x9028, // 6064
+    0x9029, // 6065
+    0x902F, // 6066
+    0x902A, // 6067
+    0x902C, // 6068
+    0x902D, // 6069
+    0x9033, // 6070
+    0x9034, // 6071
+    0x9037, // 6072
+    0x903F, // 6073
+    0x9043, // 6074
+    0x9044, // 6075
+    0x904C, // 6076
+    0x905B, // 6077
+    0x905D, // 6078
+    0x9062, // 6079
+    0x9066, // 6080
+    0x9067, // 6081
+    0x906C, // 6082
+    0x9070, // 6083
+    0x9074, // 6084
+    0x9079, // 6085
+    0x9085, // 6086
+    0x9088, // 6087
+    0x908B, // 6088
+    0x908C, // 6089
+    0x908E, // 6090
+    0x9090, // 6091
+    0x9095, // 6092
+    0x9097, // 6093
+    0x9098, // 6094
+    0x9099, // 6095
+    0x909B, // 6096
+    0x90A0, // 6097
+    0x90A1, // 6098
+    0x90A2, // 6099
+    0x90A5, // 6100
+    0x90B0, // 6101
+    0x90B2, // 6102
+    0x90B3, // 6103
+    0x90B4, // 6104
+    0x90B6, // 6105
+    0x90BD, // 6106
+    0x90CC, // 6107
+    0x90BE, // 6108
+    0x90C3, // 6109
+    0x90C4, // 6110
+    0x90C5, // 6111
+    0x90C7, // 6112
+    0x90C8, // 6113
+    0x90D5, // 6114
+    0x90D7, // 6115
+    0x90D8, // 6116
+    0x90D9, // 6117
+    0x90DC, // 6118
+    0x90DD, // 6119
+    0x90DF, // 6120
+    0x90E5, // 6121
+    0x90D2, // 6122
+    0x90F6, // 6123
+    0x90EB, // 6124
+    0x90EF, // 6125
+    0x90F0, // 6126
+    0x90F4, // 6127
+    0x90FE, // 6128
+    0x90FF, // 6129
+    0x9100, // 6130
+    0x9104, // 6131
+    0x9105, // 6132
+    0x9106, // 6133
+    0x9108, // 6134
+    0x910D, // 6135
+    0x9110, // 6136
+    0x9114, // 6137
+    0x9116, // 6138
+    0x9117, // 6139
+    0x9118, // 6140
+    0x911A, // 6141
+    0x911C, // 6142
+    0x911E, // 6143
+    0x9120, // 6144
+    0x9125, // 6145
+    0x9122, // 6146
+    0x9123, // 6147
+    0x9127, // 6148
+    0x9129, // 6149
+    0x912E, // 6150
+    0x912F, // 6151
+    0x9131, // 6152
+    0x9134, // 6153
+    0x9136, // 6154
+    0x9137, // 6155
+    0x9139, // 6156
+    0x913A, // 6157
+    0x913C, // 6158
+    0x913D, // 6159
+    0x9143, // 6160
+    0x9147, // 6161
+    0x9148, // 6162
+    0x914F, // 6163
+    0x9153, // 6164
+    0x9157, // 6165
+    0x9159, // 6166
+    0x915A, // 6167
+    0x915B, // 6168
+    0x9161, // 6169
+    0x9164, // 6170
+    0x9167, // 6171
+    0x916D, // 6172
+    0x9174, // 6173
+    0x9179, // 6174
+    0x917A, // 6175
+    0x917B, // 6176
+    0x9181, // 6177
+    0x9183, // 6178
+    0x9185, // 6179
+    0x9186, // 6180
+    0x918A, // 6181
+    0x918E, // 6182
+    0x9191, // 6183
+    0x9193, // 6184
+    0x9194, // 6185
+    0x9195, // 6186
+    0x9198, // 6187
+    0x919E, // 6188
+    0x91A1, // 6189
+    0x91A6, // 6190
+    0x91A8, // 6191
+    0x91AC, // 6192
+    0x91AD, // 6193
+    0x91AE, // 6194
+    0x91B0, // 6195
+    0x91B1, // 6196
+    0x91B2, // 6197
+    0x91B3, // 6198
+    0x91B6, // 6199
+    0x91BB, // 6200
+    0x91BC, // 6201
+    0x91BD, // 6202
+    0x91BF, // 6203
+    0x91C2, // 6204
+    0x91C3, // 6205
+    0x91C5, // 6206
+    0x91D3, // 6207
+    0x91D4, // 6208
+    0x91D7, // 6209
+    0x91D9, // 6210
+    0x91DA, // 6211
+    0x91DE, // 6212
+    0x91E4, // 6213
+    0x91E5, // 6214
+    0x91E9, // 6215
+    0x91EA, // 6216
+    0x91EC, // 6217
+    0x91ED, // 6218
+    0x91EE, // 6219
+    0x91EF, // 6220
+    0x91F0, // 6221
+    0x91F1, // 6222
+    0x91F7, // 6223
+    0x91F9, // 6224
+    0x91FB, // 6225
+    0x91FD, // 6226
+    0x9200, // 6227
+    0x9201, // 6228
+    0x9204, // 6229
+    0x9205, // 6230
+    0x9206, // 6231
+    0x9207, // 6232
+    0x9209, // 6233
+    0x920A, // 6234
+    0x920C, // 6235
+    0x9210, // 6236
+    0x9212, // 6237
+    0x9213, // 6238
+    0x9216, // 6239
+    0x9218, // 6240
+    0x921C, // 6241
+    0x921D, // 6242
+    0x9223, // 6243
+    0x9224, // 6244
+    0x9225, // 6245
+    0x9226, // 6246
+    0x9228, // 6247
+    0x922E, // 6248
+    0x922F, // 6249
+    0x9230, // 6250
+    0x9233, // 6251
+    0x9235, // 6252
+    0x9236, // 6253
+    0x9238, // 6254
+    0x9239, // 6255
+    0x923A, // 6256
+    0x923C, // 6257
+    0x923E, // 6258
+    0x9240, // 6259
+    0x9242, // 6260
+    0x9243, // 6261
+    0x9246, // 6262
+    0x9247, // 6263
+    0x924A, // 6264
+    0x924D, // 6265
+    0x924E, // 6266
+    0x924F, // 6267
+    0x9251, // 6268
+    0x9258, // 6269
+    0x9259, // 6270
+    0x925C, // 6271
+    0x925D, // 6272
+    0x9260, // 6273
+    0x9261, // 6274
+    0x9265, // 6275
+    0x9267, // 6276
+    0x9268, // 6277
+    0x9269, // 6278
+    0x926E, // 6279
+    0x926F, // 6280
+    0x9270, // 6281
+    0x9275, // 6282
+    0x9276, // 6283
+    0x9277, // 6284
+    0x9278, // 6285
+    0x9279, // 6286
+    0x927B, // 6287
+    0x927C, // 6288
+    0x927D, // 6289
+    0x927F, // 6290
+    0x9288, // 6291
+    0x9289, // 6292
+    0x928A, // 6293
+    0x928D, // 6294
+    0x928E, // 6295
+    0x9292, // 6296
+    0x9297, // 6297
+    0x9299, // 6298
+    0x929F, // 6299
+    0x92A0, // 6300
+    0x92A4, // 6301
+    0x92A5, // 6302
+    0x92A7, // 6303
+    0x92A8, // 6304
+    0x92AB, // 6305
+    0x92AF, // 6306
+    0x92B2, // 6307
+    0x92B6, // 6308
+    0x92B8, // 6309
+    0x92BA, // 6310
+    0x92BB, // 6311
+    0x92BC, // 6312
+    0x92BD, // 6313
+    0x92BF, // 6314
+    0x92C0, // 6315
+    0x92C1, // 6316
+    0x92C2, // 6317
+    0x92C3, // 6318
+    0x92C5, // 6319
+    0x92C6, // 6320
+    0x92C7, // 6321
+    0x92C8, // 6322
+    0x92CB, // 6323
+    0x92CC, // 6324
+    0x92CD, // 6325
+    0x92CE, // 6326
+    0x92D0, // 6327
+    0x92D3, // 6328
+    0x92D5, // 6329
+    0x92D7, // 6330
+    0x92D8, // 6331
+    0x92D9, // 6332
+    0x92DC, // 6333
+    0x92DD, // 6334
+    0x92DF, // 6335
+    0x92E0, // 6336
+    0x92E1, // 6337
+    0x92E3, // 6338
+    0x92E5, // 6339
+    0x92E7, // 6340
+    0x92E8, // 6341
+    0x92EC, // 6342
+    0x92EE, // 6343
+    0x92F0, // 6344
+    0x92F9, // 6345
+    0x92FB, // 6346
+    0x92FF, // 6347
+    0x9300, // 6348
+    0x9302, // 6349
+    0x9308, // 6350
+    0x930D, // 6351
+    0x9311, // 6352
+    0x9314, // 6353
+    0x9315, // 6354
+    0x931C, // 6355
+    0x931D, // 6356
+    0x931E, // 6357
+    0x931F, // 6358
+    0x9321, // 6359
+    0x9324, // 6360
+    0x9325, // 6361
+    0x9327, // 6362
+    0x9329, // 6363
+    0x932A, // 6364
+    0x9333, // 6365
+    0x9334, // 6366
+    0x9336, // 6367
+    0x9337, // 6368
+    0x9347, // 6369
+    0x9348, // 6370
+    0x9349, // 6371
+    0x9350, // 6372
+    0x9351, // 6373
+    0x9352, // 6374
+    0x9355, // 6375
+    0x9357, // 6376
+    0x9358, // 6377
+    0x935A, // 6378
+    0x935E, // 6379
+    0x9364, // 6380
+    0x9365, // 6381
+    0x9367, // 6382
+    0x9369, // 6383
+    0x936A, // 6384
+    0x936D, // 6385
+    0x936F, // 6386
+    0x9370, // 6387
+    0x9371, // 6388
+    0x9373, // 6389
+    0x9374, // 6390
+    0x9376, // 6391
+    0x937A, // 6392
+    0x937D, // 6393
+    0x937F, // 6394
+    0x9380, // 6395
+    0x9381, // 6396
+    0x9382, // 6397
+    0x9388, // 6398
+    0x938A, // 6399
+    0x938B, // 6400
+    0x938D, // 6401
+    0x938F, // 6402
+    0x9392, // 6403
+    0x9395, // 6404
+    0x9398, // 6405
+    0x939B, // 6406
+    0x939E, // 6407
+    0x93A1, // 6408
+    0x93A3, // 6409
+    0x93A4, // 6410
+    0x93A6, // 6411
+    0x93A8, // 6412
+    0x93AB, // 6413
+    0x93B4, // 6414
+    0x93B5, // 6415
+    0x93B6, // 6416
+    0x93BA, // 6417
+    0x93A9, // 6418
+    0x93C1, // 6419
+    0x93C4, // 6420
+    0x93C5, // 6421
+    0x93C6, // 6422
+    0x93C7, // 6423
+    0x93C9, // 6424
+    0x93CA, // 6425
+    0x93CB, // 6426
+    0x93CC, // 6427
+    0x93CD, // 6428
+    0x93D3, // 6429
+    0x93D9, // 6430
+    0x93DC, // 6431
+    0x93DE, // 6432
+    0x93DF, // 6433
+    0x93E2, // 6434
+    0x93E6, // 6435
+    0x93E7, // 6436
+    0x93F9, // 6437
+    0x93F7, // 6438
+    0x93F8, // 6439
+    0x93FA, // 6440
+    0x93FB, // 6441
+    0x93FD, // 6442
+    0x9401, // 6443
+    0x9402, // 6444
+    0x9404, // 6445
+    0x9408, // 6446
+    0x9409, // 6447
+    0x940D, // 6448
+    0x940E, // 6449
+    0x940F, // 6450
+    0x9415, // 6451
+    0x9416, // 6452
+    0x9417, // 6453
+    0x941F, // 6454
+    0x942E, // 6455
+    0x942F, // 6456
+    0x9431, // 6457
+    0x9432, // 6458
+    0x9433, // 6459
+    0x9434, // 6460
+    0x943B, // 6461
+    0x943F, // 6462
+    0x943D, // 6463
+    0x9443, // 6464
+    0x9445, // 6465
+    0x9448, // 6466
+    0x944A, // 6467
+    0x944C, // 6468
+    0x9455, // 6469
+    0x9459, // 6470
+    0x945C, // 6471
+    0x945F, // 6472
+    0x9461, // 6473
+    0x9463, // 6474
+    0x9468, // 6475
+    0x946B, // 6476
+    0x946D, // 6477
+    0x946E, // 6478
+    0x946F, // 6479
+    0x9471, // 6480
+    0x9472, // 6481
+    0x9484, // 6482
+    0x9483, // 6483
+    0x9578, // 6484
+    0x9579, // 6485
+    0x957E, // 6486
+    0x9584, // 6487
+    0x9588, // 6488
+    0x958C, // 6489
+    0x958D, // 6490
+    0x958E, // 6491
+    0x959D, // 6492
+    0x959E, // 6493
+    0x959F, // 6494
+    0x95A1, // 6495
+    0x95A6, // 6496
+    0x95A9, // 6497
+    0x95AB, // 6498
+    0x95AC, // 6499
+    0x95B4, // 6500
+    0x95B6, // 6501
+    0x95BA, // 6502
+    0x95BD, // 6503
+    0x95BF, // 6504
+    0x95C6, // 6505
+    0x95C8, // 6506
+    0x95C9, // 6507
+    0x95CB, // 6508
+    0x95D0, // 6509
+    0x95D1, // 6510
+    0x95D2, // 6511
+    0x95D3, // 6512
+    0x95D9, // 6513
+    0x95DA, // 6514
+    0x95DD, // 6515
+    0x95DE, // 6516
+    0x95DF, // 6517
+    0x95E0, // 6518
+    0x95E4, // 6519
+    0x95E6, // 6520
+    0x961D, // 6521
+    0x961E, // 6522
+    0x9622, // 6523
+    0x9624, // 6524
+    0x9625, // 6525
+    0x9626, // 6526
+    0x962C, // 6527
+    0x9631, // 6528
+    0x9633, // 6529
+    0x9637, // 6530
+    0x9638, // 6531
+    0x9639, // 6532
+    0x963A, // 6533
+    0x963C, // 6534
+    0x963D, // 6535
+    0x9641, // 6536
+    0x9652, // 6537
+    0x9654, // 6538
+    0x9656, // 6539
+    0x9657, // 6540
+    0x9658, // 6541
+    0x9661, // 6542
+    0x966E, // 6543
+    0x9674, // 6544
+    0x967B, // 6545
+    0x967C, // 6546
+    0x967E, // 6547
+    0x967F, // 6548
+    0x9681, // 6549
+    0x9682, // 6550
+    0x9683, // 6551
+    0x9684, // 6552
+    0x9689, // 6553
+    0x9691, // 6554
+    0x9696, // 6555
+    0x969A, // 6556
+    0x969D, // 6557
+    0x969F, // 6558
+    0x96A4, // 6559
+    0x96A5, // 6560
+    0x96A6, // 6561
+    0x96A9, // 6562
+    0x96AE, // 6563
+    0x96AF, // 6564
+    0x96B3, // 6565
+    0x96BA, // 6566
+    0x96CA, // 6567
+    0x96D2, // 6568
+    0x5DB2, // 6569
+    0x96D8, // 6570
+    0x96DA, // 6571
+    0x96DD, // 6572
+    0x96DE, // 6573
+    0x96DF, // 6574
+    0x96E9, // 6575
+    0x96EF, // 6576
+    0x96F1, // 6577
+    0x96FA, // 6578
+    0x9702, // 6579
+    0x9703, // 6580
+    0x9705, // 6581
+    0x9709, // 6582
+    0x971A, // 6583
+    0x971B, // 6584
+    0x971D, // 6585
+    0x9721, // 6586
+    0x9722, // 6587
+    0x9723, // 6588
+    0x9728, // 6589
+    0x9731, // 6590
+    0x9733, // 6591
+    0x9741, // 6592
+    0x9743, // 6593
+    0x974A, // 6594
+    0x974E, // 6595
+    0x974F, // 6596
+    0x9755, // 6597
+    0x9757, // 6598
+    0x9758, // 6599
+    0x975A, // 6600
+    0x975B, // 6601
+    0x9763, // 6602
+    0x9767, // 6603
+    0x976A, // 6604
+    0x976E, // 6605
+    0x9773, // 6606
+    0x9776, // 6607
+    0x9777, // 6608
+    0x9778, // 6609
+    0x977B, // 6610
+    0x977D, // 6611
+    0x977F, // 6612
+    0x9780, // 6613
+    0x9789, // 6614
+    0x9795, // 6615
+    0x9796, // 6616
+    0x9797, // 6617
+    0x9799, // 6618
+    0x979A, // 6619
+    0x979E, // 6620
+    0x979F, // 6621
+    0x97A2, // 6622
+    0x97AC, // 6623
+    0x97AE, // 6624
+    0x97B1, // 6625
+    0x97B2, // 6626
+    0x97B5, // 6627
+    0x97B6, // 6628
+    0x97B8, // 6629
+    0x97B9, // 6630
+    0x97BA, // 6631
+    0x97BC, // 6632
+    0x97BE, // 6633
+    0x97BF, // 6634
+    0x97C1, // 6635
+    0x97C4, // 6636
+    0x97C5, // 6637
+    0x97C7, // 6638
+    0x97C9, // 6639
+    0x97CA, // 6640
+    0x97CC, // 6641
+    0x97CD, // 6642
+    0x97CE, // 6643
+    0x97D0, // 6644
+    0x97D1, // 6645
+    0x97D4, // 6646
+    0x97D7, // 6647
+    0x97D8, // 6648
+    0x97D9, // 6649
+    0x97DD, // 6650
+    0x97DE, // 6651
+    0x97E0, // 6652
+    0x97DB, // 6653
+    0x97E1, // 6654
+    0x97E4, // 6655
+    0x97EF, // 6656
+    0x97F1, // 6657
+    0x97F4, // 6658
+    0x97F7, // 6659
+    0x97F8, // 6660
+    0x97FA, // 6661
+    0x9807, // 6662
+    0x980A, // 6663
+    0x9819, // 6664
+    0x980D, // 6665
+    0x980E, // 6666
+    0x9814, // 6667
+    0x9816, // 6668
+    0x981C, // 6669
+    0x981E, // 6670
+    0x9820, // 6671
+    0x9823, // 6672
+    0x9826, // 6673
+    0x982B, // 6674
+    0x982E, // 6675
+    0x982F, // 6676
+    0x9830, // 6677
+    0x9832, // 6678
+    0x9833, // 6679
+    0x9835, // 6680
+    0x9825, // 6681
+    0x983E, // 6682
+    0x9844, // 6683
+    0x9847, // 6684
+    0x984A, // 6685
+    0x9851, // 6686
+    0x9852, // 6687
+    0x9853, // 6688
+    0x9856, // 6689
+    0x9857, // 6690
+    0x9859, // 6691
+    0x985A, // 6692
+    0x9862, // 6693
+    0x9863, // 6694
+    0x9865, // 6695
+    0x9866, // 6696
+    0x986A, // 6697
+    0x986C, // 6698
+    0x98AB, // 6699
+    0x98AD, // 6700
+    0x98AE, // 6701
+    0x98B0, // 6702
+    0x98B4, // 6703
+    0x98B7, // 6704
+    0x98B8, // 6705
+    0x98BA, // 6706
+    0x98BB, // 6707
+    0x98BF, // 6708
+    0x98C2, // 6709
+    0x98C5, // 6710
+    0x98C8, // 6711
+    0x98CC, // 6712
+    0x98E1, // 6713
+    0x98E3, // 6714
+    0x98E5, // 6715
+    0x98E6, // 6716
+    0x98E7, // 6717
+    0x98EA, // 6718
+    0x98F3, // 6719
+    0x98F6, // 6720
+    0x9902, // 6721
+    0x9907, // 6722
+    0x9908, // 6723
+    0x9911, // 6724
+    0x9915, // 6725
+    0x9916, // 6726
+    0x9917, // 6727
+    0x991A, // 6728
+    0x991B, // 6729
+    0x991C, // 6730
+    0x991F, // 6731
+    0x9922, // 6732
+    0x9926, // 6733
+    0x9927, // 6734
+    0x992B, // 6735
+    0x9931, // 6736
+    0x9932, // 6737
+    0x9933, // 6738
+    0x9934, // 6739
+    0x9935, // 6740
+    0x9939, // 6741
+    0x993A, // 6742
+    0x993B, // 6743
+    0x993C, // 6744
+    0x9940, // 6745
+    0x9941, // 6746
+    0x9946, // 6747
+    0x9947, // 6748
+    0x9948, // 6749
+    0x994D, // 6750
+    0x994E, // 6751
+    0x9954, // 6752
+    0x9958, // 6753
+    0x9959, // 6754
+    0x995B, // 6755
+    0x995C, // 6756
+    0x995E, // 6757
+    0x995F, // 6758
+    0x9960, // 6759
+    0x999B, // 6760
+    0x999D, // 6761
+    0x999F, // 6762
+    0x99A6, // 6763
+    0x99B0, // 6764
+    0x99B1, // 6765
+    0x99B2, // 6766
+    0x99B5, // 6767
+    0x99B9, // 6768
+    0x99BA, // 6769
+    0x99BD, // 6770
+    0x99BF, // 6771
+    0x99C3, // 6772
+    0x99C9, // 6773
+    0x99D3, // 6774
+    0x99D4, // 6775
+    0x99D9, // 6776
+    0x99DA, // 6777
+    0x99DC, // 6778
+    0x99DE, // 6779
+    0x99E7, // 6780
+    0x99EA, // 6781
+    0x99EB, // 6782
+    0x99EC, // 6783
+    0x99F0, // 6784
+    0x99F4, // 6785
+    0x99F5, // 6786
+    0x99F9, // 6787
+    0x99FD, // 6788
+    0x99FE, // 6789
+    0x9A02, // 6790
+    0x9A03, // 6791
+    0x9A04, // 6792
+    0x9A0B, // 6793
+    0x9A0C, // 6794
+    0x9A10, // 6795
+    0x9A11, // 6796
+    0x9A16, // 6797
+    0x9A1E, // 6798
+    0x9A20, // 6799
+    0x9A22, // 6800
+    0x9A23, // 6801
+    0x9A24, // 6802
+    0x9A27, // 6803
+    0x9A2D, // 6804
+    0x9A2E, // 6805
+    0x9A33, // 6806
+    0x9A35, // 6807
+    0x9A36, // 6808
+    0x9A38, // 6809
+    0x9A47, // 6810
+    0x9A41, // 6811
+    0x9A44, // 6812
+    0x9A4A, // 6813
+    0x9A4B, // 6814
+    0x9A4C, // 6815
+    0x9A4E, // 6816
+    0x9A51, // 6817
+    0x9A54, // 6818
+    0x9A56, // 6819
+    0x9A5D, // 6820
+    0x9AAA, // 6821
+    0x9AAC, // 6822
+    0x9AAE, // 6823
+    0x9AAF, // 6824
+    0x9AB2, // 6825
+    0x9AB4, // 6826
+    0x9AB5, // 6827
+    0x9AB6, // 6828
+    0x9AB9, // 6829
+    0x9ABB, // 6830
+    0x9ABE, // 6831
+    0x9ABF, // 6832
+    0x9AC1, // 6833
+    0x9AC3, // 6834
+    0x9AC6, // 6835
+    0x9AC8, // 6836
+    0x9ACE, // 6837
+    0x9AD0, // 6838
+    0x9AD2, // 6839
+    0x9AD5, // 6840
+    0x9AD6, // 6841
+    0x9AD7, // 6842
+    0x9ADB, // 6843
+    0x9ADC, // 6844
+    0x9AE0, // 6845
+    0x9AE4, // 6846
+    0x9AE5, // 6847
+    0x9AE7, // 6848
+    0x9AE9, // 6849
+    0x9AEC, // 6850
+    0x9AF2, // 6851
+    0x9AF3, // 6852
+    0x9AF5, // 6853
+    0x9AF9, // 6854
+    0x9AFA, // 6855
+    0x9AFD, // 6856
+    0x9AFF, // 6857
+    0x9B00, // 6858
+    0x9B01, // 6859
+    0x9B02, // 6860
+    0x9B03, // 6861
+    0x9B04, // 6862
+    0x9B05, // 6863
+    0x9B08, // 6864
+    0x9B09, // 6865
+    0x9B0B, // 6866
+    0x9B0C, // 6867
+    0x9B0D, // 6868
+    0x9B0E, // 6869
+    0x9B10, // 6870
+    0x9B12, // 6871
+    0x9B16, // 6872
+    0x9B19, // 6873
+    0x9B1B, // 6874
+    0x9B1C, // 6875
+    0x9B20, // 6876
+    0x9B26, // 6877
+    0x9B2B, // 6878
+    0x9B2D, // 6879
+    0x9B33, // 6880
+    0x9B34, // 6881
+    0x9B35, // 6882
+    0x9B37, // 6883
+    0x9B39, // 6884
+    0x9B3A, // 6885
+    0x9B3D, // 6886
+    0x9B48, // 6887
+    0x9B4B, // 6888
+    0x9B4C, // 6889
+    0x9B55, // 6890
+    0x9B56, // 6891
+    0x9B57, // 6892
+    0x9B5B, // 6893
+    0x9B5E, // 6894
+    0x9B61, // 6895
+    0x9B63, // 6896
+    0x9B65, // 6897
+    0x9B66, // 6898
+    0x9B68, // 6899
+    0x9B6A, // 6900
+    0x9B6B, // 6901
+    0x9B6C, // 6902
+    0x9B6D, // 6903
+    0x9B6E, // 6904
+    0x9B73, // 6905
+    0x9B75, // 6906
+    0x9B77, // 6907
+    0x9B78, // 6908
+    0x9B79, // 6909
+    0x9B7F, // 6910
+    0x9B80, // 6911
+    0x9B84, // 6912
+    0x9B85, // 6913
+    0x9B86, // 6914
+    0x9B87, // 6915
+    0x9B89, // 6916
+    0x9B8A, // 6917
+    0x9B8B, // 6918
+    0x9B8D, // 6919
+    0x9B8F, // 6920
+    0x9B90, // 6921
+    0x9B94, // 6922
+    0x9B9A, // 6923
+    0x9B9D, // 6924
+    0x9B9E, // 6925
+    0x9BA6, // 6926
+    0x9BA7, // 6927
+    0x9BA9, // 6928
+    0x9BAC, // 6929
+    0x9BB0, // 6930
+    0x9BB1, // 6931
+    0x9BB2, // 6932
+    0x9BB7, // 6933
+    0x9BB8, // 6934
+    0x9BBB, // 6935
+    0x9BBC, // 6936
+    0x9BBE, // 6937
+    0x9BBF, // 6938
+    0x9BC1, // 6939
+    0x9BC7, // 6940
+    0x9BC8, // 6941
+    0x9BCE, // 6942
+    0x9BD0, // 6943
+    0x9BD7, // 6944
+    0x9BD8, // 6945
+    0x9BDD, // 6946
+    0x9BDF, // 6947
+    0x9BE5, // 6948
+    0x9BE7, // 6949
+    0x9BEA, // 6950
+    0x9BEB, // 6951
+    0x9BEF, // 6952
+    0x9BF3, // 6953
+    0x9BF7, // 6954
+    0x9BF8, // 6955
+    0x9BF9, // 6956
+    0x9BFA, // 6957
+    0x9BFD, // 6958
+    0x9BFF, // 6959
+    0x9C00, // 6960
+    0x9C02, // 6961
+    0x9C0B, // 6962
+    0x9C0F, // 6963
+    0x9C11, // 6964
+    0x9C16, // 6965
+    0x9C18, // 6966
+    0x9C19, // 6967
+    0x9C1A, // 6968
+    0x9C1C, // 6969
+    0x9C1E, // 6970
+    0x9C22, // 6971
+    0x9C23, // 6972
+    0x9C26, // 6973
+    0x9C27, // 6974
+    0x9C28, // 6975
+    0x9C29, // 6976
+    0x9C2A, // 6977
+    0x9C31, // 6978
+    0x9C35, // 6979
+    0x9C36, // 6980
+    0x9C37, // 6981
+    0x9C3D, // 6982
+    0x9C41, // 6983
+    0x9C43, // 6984
+    0x9C44, // 6985
+    0x9C45, // 6986
+    0x9C49, // 6987
+    0x9C4A, // 6988
+    0x9C4E, // 6989
+    0x9C4F, // 6990
+    0x9C50, // 6991
+    0x9C53, // 6992
+    0x9C54, // 6993
+    0x9C56, // 6994
+    0x9C58, // 6995
+    0x9C5B, // 6996
+    0x9C5D, // 6997
+    0x9C5E, // 6998
+    0x9C5F, // 6999
+    0x9C63, // 7000
+    0x9C69, // 7001
+    0x9C6A, // 7002
+    0x9C5C, // 7003
+    0x9C6B, // 7004
+    0x9C68, // 7005
+    0x9C6E, // 7006
+    0x9C70, // 7007
+    0x9C72, // 7008
+    0x9C75, // 7009
+    0x9C77, // 7010
+    0x9C7B, // 7011
+    0x9CE6, // 7012
+    0x9CF2, // 7013
+    0x9CF7, // 7014
+    0x9CF9, // 7015
+    0x9D0B, // 7016
+    0x9D02, // 7017
+    0x9D11, // 7018
+    0x9D17, // 7019
+    0x9D18, // 7020
+    0x9D1C, // 7021
+    0x9D1D, // 7022
+    0x9D1E, // 7023
+    0x9D2F, // 7024
+    0x9D30, // 7025
+    0x9D32, // 7026
+    0x9D33, // 7027
+    0x9D34, // 7028
+    0x9D3A, // 7029
+    0x9D3C, // 7030
+    0x9D45, // 7031
+    0x9D3D, // 7032
+    0x9D42, // 7033
+    0x9D43, // 7034
+    0x9D47, // 7035
+    0x9D4A, // 7036
+    0x9D53, // 7037
+    0x9D54, // 7038
+    0x9D5F, // 7039
+    0x9D63, // 7040
+    0x9D62, // 7041
+    0x9D65, // 7042
+    0x9D69, // 7043
+    0x9D6A, // 7044
+    0x9D6B, // 7045
+    0x9D70, // 7046
+    0x9D76, // 7047
+    0x9D77, // 7048
+    0x9D7B, // 7049
+    0x9D7C, // 7050
+    0x9D7E, // 7051
+    0x9D83, // 7052
+    0x9D84, // 7053
+    0x9D86, // 7054
+    0x9D8A, // 7055
+    0x9D8D, // 7056
+    0x9D8E, // 7057
+    0x9D92, // 7058
+    0x9D93, // 7059
+    0x9D95, // 7060
+    0x9D96, // 7061
+    0x9D97, // 7062
+    0x9D98, // 7063
+    0x9DA1, // 7064
+    0x9DAA, // 7065
+    0x9DAC, // 7066
+    0x9DAE, // 7067
+    0x9DB1, // 7068
+    0x9DB5, // 7069
+    0x9DB9, // 7070
+    0x9DBC, // 7071
+    0x9DBF, // 7072
+    0x9DC3, // 7073
+    0x9DC7, // 7074
+    0x9DC9, // 7075
+    0x9DCA, // 7076
+    0x9DD4, // 7077
+    0x9DD5, // 7078
+    0x9DD6, // 7079
+    0x9DD7, // 7080
+    0x9DDA, // 7081
+    0x9DDE, // 7082
+    0x9DDF, // 7083
+    0x9DE0, // 7084
+    0x9DE5, // 7085
+    0x9DE7, // 7086
+    0x9DE9, // 7087
+    0x9DEB, // 7088
+    0x9DEE, // 7089
+    0x9DF0, // 7090
+    0x9DF3, // 7091
+    0x9DF4, // 7092
+    0x9DFE, // 7093
+    0x9E0A, // 7094
+    0x9E02, // 7095
+    0x9E07, // 7096
+    0x9E0E, // 7097
+    0x9E10, // 7098
+    0x9E11, // 7099
+    0x9E12, // 7100
+    0x9E15, // 7101
+    0x9E16, // 7102
+    0x9E19, // 7103
+    0x9E1C, // 7104
+    0x9E1D, // 7105
+    0x9E7A, // 7106
+    0x9E7B, // 7107
+    0x9E7C, // 7108
+    0x9E80, // 7109
+    0x9E82, // 7110
+    0x9E83, // 7111
+    0x9E84, // 7112
+    0x9E85, // 7113
+    0x9E87, // 7114
+    0x9E8E, // 7115
+    0x9E8F, // 7116
+    0x9E96, // 7117
+    0x9E98, // 7118
+    0x9E9B, // 7119
+    0x9E9E, // 7120
+    0x9EA4, // 7121
+    0x9EA8, // 7122
+    0x9EAC, // 7123
+    0x9EAE, // 7124
+    0x9EAF, // 7125
+    0x9EB0, // 7126
+    0x9EB3, // 7127
+    0x9EB4, // 7128
+    0x9EB5, // 7129
+    0x9EC6, // 7130
+    0x9EC8, // 7131
+    0x9ECB, // 7132
+    0x9ED5, // 7133
+    0x9EDF, // 7134
+    0x9EE4, // 7135
+    0x9EE7, // 7136
+    0x9EEC, // 7137
+    0x9EED, // 7138
+    0x9EEE, // 7139
+    0x9EF0, // 7140
+    0x9EF1, // 7141
+    0x9EF2, // 7142
+    0x9EF5, // 7143
+    0x9EF8, // 7144
+    0x9EFF, // 7145
+    0x9F02, // 7146
+    0x9F03, // 7147
+    0x9F09, // 7148
+    0x9F0F, // 7149
+    0x9F10, // 7150
+    0x9F11, // 7151
+    0x9F12, // 7152
+    0x9F14, // 7153
+    0x9F16, // 7154
+    0x9F17, // 7155
+    0x9F19, // 7156
+    0x9F1A, // 7157
+    0x9F1B, // 7158
+    0x9F1F, // 7159
+    0x9F22, // 7160
+    0x9F26, // 7161
+    0x9F2A, // 7162
+    0x9F2B, // 7163
+    0x9F2F, // 7164
+    0x9F31, // 7165
+    0x9F32, // 7166
+    0x9F34, // 7167
+    0x9F37, // 7168
+    0x9F39, // 7169
+    0x9F3A, // 7170
+    0x9F3C, // 7171
+    0x9F3D, // 7172
+    0x9F3F, // 7173
+    0x9F41, // 7174
+    0x9F43, // 7175
+    0x9F44, // 7176
+    0x9F45, // 7177
+    0x9F46, // 7178
+    0x9F47, // 7179
+    0x9F53, // 7180
+    0x9F55, // 7181
+    0x9F56, // 7182
+    0x9F57, // 7183
+    0x9F58, // 7184
+    0x9F5A, // 7185
+    0x9F5D, // 7186
+    0x9F5E, // 7187
+    0x9F68, // 7188
+    0x9F69, // 7189
+    0x9F6D, // 7190
+    0x9F6E, // 7191
+    0x9F6F, // 7192
+    0x9F70, // 7193
+    0x9F71, // 7194
+    0x9F73, // 7195
+    0x9F75, // 7196
+    0x9F7A, // 7197
+    0x9F7D, // 7198
+    0x9F8F, // 7199
+    0x9F90, // 7200
+    0x9F91, // 7201
+    0x9F92, // 7202
+    0x9F94, // 7203
+    0x9F96, // 7204
+    0x9F97, // 7205
+    0x9F9E, // 7206
+    0x9FA1, // 7207
+    0x9FA2, // 7208
+    0x9FA3, // 7209
+    0x9FA5, // 7210
+];
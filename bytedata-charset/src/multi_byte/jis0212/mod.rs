@@ -0,0 +1,17 @@
+mod jis0212_cp;
+
+/// The first pointer in the JIS X 0212 index table.
+const PTR_OFFSET: usize = 108;
+
+/// The code point of a pointer in the JIS X 0212 index.
+#[inline]
+pub(super) const fn decode_ptr(ptr: usize) -> Option<char> {
+    if ptr < PTR_OFFSET || ptr - PTR_OFFSET >= jis0212_cp::JIS0212_PTR_TO_CP.len() {
+        return None;
+    }
+    let cp = jis0212_cp::JIS0212_PTR_TO_CP[ptr - PTR_OFFSET];
+    if cp == 0 {
+        return None;
+    }
+    char::from_u32(cp as u32)
+}
@@ -13,6 +13,31 @@ mod big5;
 #[cfg(feature = "big5")]
 pub use big5::*;
 
+#[cfg(any(feature = "shift_jis", feature = "euc-jp", feature = "iso-2022-jp"))]
+mod jis0208;
+#[cfg(feature = "euc-jp")]
+mod jis0212;
+
+#[cfg(feature = "shift_jis")]
+mod shift_jis;
+#[cfg(feature = "shift_jis")]
+pub use shift_jis::*;
+
+#[cfg(feature = "euc-jp")]
+mod euc_jp;
+#[cfg(feature = "euc-jp")]
+pub use euc_jp::*;
+
+#[cfg(feature = "iso-2022-jp")]
+mod iso_2022_jp;
+#[cfg(feature = "iso-2022-jp")]
+pub use iso_2022_jp::*;
+
+#[cfg(feature = "euc-kr")]
+mod euc_kr;
+#[cfg(feature = "euc-kr")]
+pub use euc_kr::*;
+
 #[cfg(feature = "java")]
 mod java_utf;
 #[cfg(feature = "java")]
@@ -0,0 +1,377 @@
+/// `Shift_JIS` encoding. A legacy encoding for Japanese characters.
+///
+/// This follows the WHATWG definition of `Shift_JIS`, which is the Windows-31J variant including the NEC and IBM extensions.
+///
+/// Use [`UTF-8`] or [`UTF-16`] instead if possible.
+///
+/// [`UTF-8`]: crate::Utf8Encoding
+/// [`UTF-16`]: crate::Utf16Encoding
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+#[non_exhaustive]
+#[repr(transparent)]
+#[cfg_attr(docsrs, doc(cfg(feature = "shift_jis")))]
+pub struct ShiftJisEncoding;
+
+/// `Shift_JIS` encoding.
+#[cfg_attr(docsrs, doc(cfg(feature = "shift_jis")))]
+pub static SHIFT_JIS: ShiftJisEncoding = ShiftJisEncoding::new();
+
+impl ShiftJisEncoding {
+    /// Create a new `Shift_JIS` encoding instance.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self
+    }
+
+    /// Decode a `Shift_JIS` byte sequence.
+    #[inline]
+    #[must_use]
+    pub const fn decode_const(&self, bytes: &[u8]) -> crate::DecodeResult {
+        if bytes.is_empty() {
+            return crate::DecodeResult::Empty;
+        }
+        let byt = bytes[0];
+        if byt < 0x80 {
+            let mut i = 1;
+            while i < bytes.len() {
+                if bytes[i] & 0b1000_0000 == 0b1000_0000 {
+                    break;
+                }
+                i += 1;
+            }
+            return crate::DecodeResult::Utf8(i as u64);
+        }
+        decode_const_inner(bytes)
+    }
+
+    /// Encode a `Shift_JIS` character sequence.
+    #[inline]
+    #[must_use]
+    pub const fn encode_const(&self, chars: &str) -> crate::EncodeResult {
+        let bytes = chars.as_bytes();
+        if bytes.is_empty() {
+            return crate::EncodeResult::Empty;
+        }
+        let byt = bytes[0];
+        if byt < 0x80 {
+            let mut i = 1;
+            while i < bytes.len() {
+                if bytes[i] & 0b1000_0000 == 0b1000_0000 {
+                    break;
+                }
+                i += 1;
+            }
+            return crate::EncodeResult::Utf8(i as u64);
+        }
+        encode_const_inner(bytes)
+    }
+
+    /// Detect if the given bytes are `Shift_JIS` encoded.
+    #[inline]
+    #[must_use]
+    pub const fn detect_const(bytes: &[u8]) -> crate::detect::DetectionResult {
+        if bytes.is_empty() {
+            return crate::detect::DetectionResult::Incomplete;
+        }
+        detect_const_inner(bytes)
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "shift_jis")))]
+impl crate::Charset for ShiftJisEncoding {
+    const CHARSET_NAME: &'static str = "shift_jis";
+
+    #[inline]
+    fn size_hint(&self) -> (u16, u16) {
+        (1, 2)
+    }
+
+    #[inline]
+    fn charset_alias(&self) -> &[&'static str] {
+        &[
+            // IANA
+            Self::CHARSET_NAME,
+            "csshiftjis",
+            "ms_kanji",
+            "windows-31j",
+            // code pages
+            "cp932",
+            "ms932",
+            "windows-932",
+            // other
+            "shift-jis",
+            "sjis",
+            "x-sjis",
+        ]
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "shift_jis")))]
+impl crate::detect::CharsetDetector for ShiftJisEncoding {
+    #[inline]
+    fn detect(&self, bytes: &[u8]) -> crate::detect::DetectionResult {
+        Self::detect_const(bytes)
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "shift_jis")))]
+impl crate::CharsetDecoding for ShiftJisEncoding {
+    #[inline]
+    fn decode(&self, bytes: &[u8]) -> crate::DecodeResult {
+        self.decode_const(bytes)
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "shift_jis")))]
+impl crate::CharsetEncoding for ShiftJisEncoding {
+    #[inline]
+    fn encode(&self, chars: &str) -> crate::EncodeResult {
+        self.encode_const(chars)
+    }
+}
+
+/// Detects `Shift_JIS` by validating the byte structure and that every double-byte sequence is mapped in the index.
+const fn detect_const_inner(mut bytes: &[u8]) -> crate::detect::DetectionResult {
+    const MIN_LEN: usize = 4;
+    let mut offset = 0;
+    loop {
+        if bytes.is_empty() {
+            if offset < MIN_LEN {
+                return crate::detect::DetectionResult::Incomplete;
+            }
+            return crate::detect::DetectionResult::Tentative;
+        }
+        let b0 = bytes[0];
+        if b0 < 0x80 {
+            bytes = bytedata::const_or_bytes(bytedata::const_slice(bytes, 1..bytes.len()), b"");
+            offset += 1;
+            continue;
+        }
+        match decode_const_inner(bytes) {
+            crate::DecodeResult::Char(ch, len) => {
+                // private use characters and C1 controls are not expected in text
+                if ch as u32 == 0x80 || (ch as u32 >= 0xE000 && ch as u32 <= 0xF8FF) {
+                    return crate::detect::DetectionResult::Irrelevant;
+                }
+                let len = len as usize;
+                offset += len;
+                bytes =
+                    bytedata::const_or_bytes(bytedata::const_slice(bytes, len..bytes.len()), b"");
+            }
+            crate::DecodeResult::InvalidChar(_, _) | crate::DecodeResult::CharPair(_, _, _) => {
+                return crate::detect::DetectionResult::Irrelevant;
+            }
+            crate::DecodeResult::Utf8(_)
+            | crate::DecodeResult::Empty
+            | crate::DecodeResult::Incomplete => {
+                if offset < MIN_LEN {
+                    return crate::detect::DetectionResult::Incomplete;
+                }
+                return crate::detect::DetectionResult::Tentative;
+            }
+        }
+    }
+}
+
+/// Decodes a `Shift_JIS` byte sequence. This should not be called directly without first validating the fist byte isn't ASCII.
+#[inline]
+#[expect(clippy::missing_asserts_for_indexing)]
+const fn decode_const_inner(bytes: &[u8]) -> crate::DecodeResult {
+    let lead = bytes[0];
+    if lead == 0x80 {
+        return crate::DecodeResult::Char('\u{0080}', 1);
+    }
+    if lead >= 0xA1 && lead <= 0xDF {
+        // half-width katakana
+        let Some(ch) = char::from_u32(0xFF61 - 0xA1 + lead as u32) else {
+            unreachable!();
+        };
+        return crate::DecodeResult::Char(ch, 1);
+    }
+    if !((lead >= 0x81 && lead <= 0x9F) || (lead >= 0xE0 && lead <= 0xFC)) {
+        return crate::DecodeResult::InvalidChar(lead as u32, 1);
+    }
+    if bytes.len() < 2 {
+        return crate::DecodeResult::Incomplete;
+    }
+    let trail = bytes[1];
+    if (trail >= 0x40 && trail <= 0x7E) || (trail >= 0x80 && trail <= 0xFC) {
+        let lead_offset = if lead < 0xA0 { 0x81 } else { 0xC1 };
+        let offset = if trail < 0x7F { 0x40 } else { 0x41 };
+        let ptr = (lead as usize - lead_offset) * 188 + trail as usize - offset; // 0 - 11_279
+        if ptr >= 8836 && ptr <= 10715 {
+            // user-defined characters are mapped to the private use area
+            #[expect(clippy::cast_possible_truncation)]
+            let Some(ch) = char::from_u32(0xE000 - 8836 + ptr as u32) else {
+                unreachable!();
+            };
+            return crate::DecodeResult::Char(ch, 2);
+        }
+        if let Some(ch) = super::jis0208::decode_ptr(ptr) {
+            return crate::DecodeResult::Char(ch, 2);
+        }
+    }
+    if trail < 0x80 {
+        // an ASCII trail byte is not consumed by an invalid sequence
+        return crate::DecodeResult::InvalidChar(lead as u32, 1);
+    }
+    crate::DecodeResult::InvalidChar(((lead as u32) << 8_i32) | trail as u32, 2)
+}
+
+#[inline]
+const fn encode_const_inner(mut bytes: &[u8]) -> crate::EncodeResult {
+    let mut chunk = [0_u8; 14];
+    let mut chunk_len = 0_usize;
+    let mut consumed = 0_usize;
+    loop {
+        let (ch, ch_len) = bytedata::const_utf8_char_next(bytes);
+        if ch_len == 0 || ch < 0x80 {
+            // ASCII is left for the next call to return as a UTF-8 compatible run
+            break;
+        }
+        #[expect(clippy::cast_possible_truncation)]
+        let single = match ch {
+            0x80 => 0x80,
+            0xA5 => 0x5C,
+            0x203E => 0x7E,
+            0xFF61..=0xFF9F => (ch - 0xFF61 + 0xA1) as u8,
+            _ => 0,
+        };
+        if single != 0 {
+            chunk[chunk_len] = single;
+            chunk_len += 1;
+        } else {
+            let cp = if ch == 0x2212 { 0xFF0D } else { ch };
+            let Some(ptr) = super::jis0208::encode_ptr_shift_jis(cp) else {
+                if chunk_len != 0 {
+                    break;
+                }
+                let Some(ch) = char::from_u32(ch) else {
+                    unreachable!();
+                };
+                #[expect(clippy::cast_possible_truncation)]
+                return crate::EncodeResult::InvalidChar(ch, ch_len as u16);
+            };
+            #[expect(clippy::integer_division)]
+            let lead = ptr / 188;
+            let lead_offset = if lead < 0x1F { 0x81 } else { 0xC1 };
+            let trail = ptr % 188;
+            let offset = if trail < 0x3F { 0x40 } else { 0x41 };
+            #[expect(clippy::cast_possible_truncation)]
+            {
+                chunk[chunk_len] = (lead + lead_offset) as u8;
+                chunk[chunk_len + 1] = (trail + offset) as u8;
+            };
+            chunk_len += 2;
+        }
+        consumed += ch_len as usize;
+        if chunk_len > 10 {
+            break;
+        }
+        bytes = bytedata::const_or_bytes(
+            bytedata::const_slice(bytes, (ch_len as usize)..bytes.len()),
+            b"",
+        );
+    }
+    if chunk_len == 0 {
+        return crate::EncodeResult::Empty;
+    }
+    #[expect(clippy::cast_possible_truncation)]
+    crate::EncodeResult::Chunk(
+        bytedata::ByteChunk::from_slice(bytedata::const_or_bytes(
+            bytedata::const_slice(&chunk, 0..chunk_len),
+            b"",
+        )),
+        consumed as u16,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shift_jis_decode() {
+        assert_eq!(
+            SHIFT_JIS.decode_const(b"a\\\x82\xA0"),
+            crate::DecodeResult::Utf8(2)
+        );
+        assert_eq!(
+            SHIFT_JIS.decode_const(b"\x82\xA0"),
+            crate::DecodeResult::Char('\u{3042}', 2)
+        );
+        assert_eq!(
+            SHIFT_JIS.decode_const(b"\x93\xFA"),
+            crate::DecodeResult::Char('\u{65E5}', 2)
+        );
+        assert_eq!(
+            SHIFT_JIS.decode_const(b"\xB1"),
+            crate::DecodeResult::Char('\u{FF71}', 1)
+        );
+        assert_eq!(
+            SHIFT_JIS.decode_const(b"\xED\x40"),
+            crate::DecodeResult::Char('\u{7E8A}', 2)
+        );
+        assert_eq!(
+            SHIFT_JIS.decode_const(b"\xF0\x40"),
+            crate::DecodeResult::Char('\u{E000}', 2)
+        );
+        assert_eq!(
+            SHIFT_JIS.decode_const(b"\x82"),
+            crate::DecodeResult::Incomplete
+        );
+        assert_eq!(
+            SHIFT_JIS.decode_const(b"\xA0"),
+            crate::DecodeResult::InvalidChar(0xA0, 1)
+        );
+        assert_eq!(
+            SHIFT_JIS.decode_const(b"\x82\x20"),
+            crate::DecodeResult::InvalidChar(0x82, 1)
+        );
+        assert_eq!(
+            SHIFT_JIS.decode_const(b"\x85\x80"),
+            crate::DecodeResult::InvalidChar(0x8580, 2)
+        );
+    }
+
+    #[test]
+    fn test_shift_jis_encode() {
+        assert_eq!(
+            SHIFT_JIS.encode_const("\u{65E5}\u{672C}\u{8A9E}a"),
+            crate::EncodeResult::Chunk(
+                bytedata::ByteChunk::from_slice(b"\x93\xFA\x96\x7B\x8C\xEA"),
+                9
+            )
+        );
+        assert_eq!(
+            SHIFT_JIS.encode_const("\u{A5}\u{203E}\u{FF71}\u{2212}"),
+            crate::EncodeResult::Chunk(
+                bytedata::ByteChunk::from_slice(b"\x5C\x7E\xB1\x81\x7C"),
+                11
+            )
+        );
+        // the NEC selected IBM extensions are encoded using the IBM extensions
+        assert_eq!(
+            SHIFT_JIS.encode_const("\u{7E8A}"),
+            crate::EncodeResult::Chunk(bytedata::ByteChunk::from_slice(b"\xFA\x5C"), 3)
+        );
+        assert_eq!(
+            SHIFT_JIS.encode_const("\u{E000}"),
+            crate::EncodeResult::InvalidChar('\u{E000}', 3)
+        );
+    }
+
+    #[test]
+    fn test_shift_jis_detect() {
+        use crate::detect::DetectionResult;
+        assert_eq!(
+            ShiftJisEncoding::detect_const(b"\x93\xFA\x96\x7B\x8C\xEA"),
+            DetectionResult::Tentative
+        );
+        assert_eq!(
+            ShiftJisEncoding::detect_const(b"\x93\xFA\x96\x7B\xFD"),
+            DetectionResult::Irrelevant
+        );
+    }
+}
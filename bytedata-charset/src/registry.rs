@@ -0,0 +1,512 @@
+//! ## Charset registry
+//!
+//! Resolves charset labels, as found in HTTP headers, MIME parts and XML declarations, to the charsets enabled through cargo features.
+//! See the [`CharsetRegistry`] struct for more information.
+
+/// A registry of all charsets enabled through cargo features.
+///
+/// Labels are resolved according to the [WHATWG Encoding Standard]: leading and trailing ASCII whitespace is ignored and labels are matched ASCII case-insensitive.
+/// The registry is ordered so that the WHATWG encodings are searched first, which means a label such as `"latin1"` resolves to windows-1252 just as in a browser.
+/// If the charset preferred by the WHATWG standard is disabled by cargo features the label falls back to any other enabled charset using it, e.g. ISO-8859-1.
+///
+/// The labels of each charset are taken from [`Charset::charset_alias`].
+///
+/// [WHATWG Encoding Standard]: https://encoding.spec.whatwg.org/#concept-encoding-get
+/// [`Charset::charset_alias`]: crate::Charset::charset_alias
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub struct CharsetRegistry;
+
+impl CharsetRegistry {
+    /// All enabled charsets in the order they are searched when resolving a label.
+    const CHARSETS: &'static [&'static dyn crate::CharsetCodec] = &[
+        // WHATWG encodings
+        &crate::Utf8Encoding::new(),
+        #[cfg(feature = "ibm866")]
+        &crate::single_byte::Ibm866::new(),
+        #[cfg(feature = "iso-8859-2")]
+        &crate::iso_8859::Iso8859_2::new(),
+        #[cfg(feature = "iso-8859-3")]
+        &crate::iso_8859::Iso8859_3::new(),
+        #[cfg(feature = "iso-8859-4")]
+        &crate::iso_8859::Iso8859_4::new(),
+        #[cfg(feature = "iso-8859-5")]
+        &crate::iso_8859::Iso8859_5::new(),
+        #[cfg(feature = "iso-8859-6")]
+        &crate::iso_8859::Iso8859_6::new(),
+        #[cfg(feature = "iso-8859-7")]
+        &crate::iso_8859::Iso8859_7::new(),
+        #[cfg(feature = "iso-8859-8")]
+        &crate::iso_8859::Iso8859_8::new(),
+        #[cfg(feature = "iso-8859-8-i")]
+        &crate::iso_8859::Iso8859_8I::new(),
+        #[cfg(feature = "iso-8859-10")]
+        &crate::iso_8859::Iso8859_10::new(),
+        #[cfg(feature = "iso-8859-13")]
+        &crate::iso_8859::Iso8859_13::new(),
+        #[cfg(feature = "iso-8859-14")]
+        &crate::iso_8859::Iso8859_14::new(),
+        #[cfg(feature = "iso-8859-15")]
+        &crate::iso_8859::Iso8859_15::new(),
+        #[cfg(feature = "iso-8859-16")]
+        &crate::iso_8859::Iso8859_16::new(),
+        #[cfg(feature = "koi8-r")]
+        &crate::single_byte::Koi8R::new(),
+        #[cfg(feature = "koi8-u")]
+        &crate::single_byte::Koi8U::new(),
+        #[cfg(feature = "macintosh")]
+        &crate::single_byte::Macintosh::new(),
+        #[cfg(feature = "windows-874")]
+        &crate::windows::Windows874::new(),
+        #[cfg(feature = "windows-1250")]
+        &crate::windows::Windows1250::new(),
+        #[cfg(feature = "windows-1251")]
+        &crate::windows::Windows1251::new(),
+        #[cfg(feature = "windows-1252")]
+        &crate::windows::Windows1252::new(),
+        #[cfg(feature = "windows-1253")]
+        &crate::windows::Windows1253::new(),
+        #[cfg(feature = "windows-1254")]
+        &crate::windows::Windows1254::new(),
+        #[cfg(feature = "windows-1255")]
+        &crate::windows::Windows1255::new(),
+        #[cfg(feature = "windows-1256")]
+        &crate::windows::Windows1256::new(),
+        #[cfg(feature = "windows-1257")]
+        &crate::windows::Windows1257::new(),
+        #[cfg(feature = "windows-1258")]
+        &crate::windows::Windows1258::new(),
+        #[cfg(feature = "x-mac-cyrillic")]
+        &crate::single_byte::XMacCyrillic::new(),
+        #[cfg(feature = "gbk")]
+        &crate::multi_byte::GbkEncoding::new(),
+        #[cfg(feature = "gb18030")]
+        &crate::multi_byte::Gb18030Encoding::new(),
+        #[cfg(feature = "big5")]
+        &crate::multi_byte::Big5Encoding::new(),
+        #[cfg(feature = "euc-jp")]
+        &crate::multi_byte::EucJpEncoding::new(),
+        #[cfg(feature = "iso-2022-jp")]
+        &crate::multi_byte::Iso2022JpEncoding::new(),
+        #[cfg(feature = "shift_jis")]
+        &crate::multi_byte::ShiftJisEncoding::new(),
+        #[cfg(feature = "euc-kr")]
+        &crate::multi_byte::EucKrEncoding::new(),
+        #[cfg(feature = "replacement")]
+        &crate::ReplacementEncoding::new(),
+        &crate::Utf16Encoding::UTF16_BE,
+        &crate::Utf16Encoding::UTF16_LE,
+        #[cfg(feature = "x-user-defined")]
+        &crate::single_byte::XUserDefined::new(),
+        // other encodings
+        &crate::Ascii7Encoding::new(),
+        #[cfg(feature = "iso-8859-1")]
+        &crate::iso_8859::Iso8859_1::new(),
+        #[cfg(feature = "iso-8859-9")]
+        &crate::iso_8859::Iso8859_9::new(),
+        #[cfg(feature = "iso-8859-11")]
+        &crate::iso_8859::Iso8859_11::new(),
+        #[cfg(feature = "utf-32")]
+        &crate::Utf32Encoding::UTF32_BE,
+        #[cfg(feature = "utf-32")]
+        &crate::Utf32Encoding::UTF32_LE,
+        #[cfg(feature = "java")]
+        &crate::multi_byte::JavaModifiedUtf8Encoding::new(),
+        #[cfg(feature = "gb18030")]
+        &crate::multi_byte::Gb18030Encoding::new_2005(),
+        #[cfg(feature = "ibm437")]
+        &crate::single_byte::Ibm437::new(),
+        #[cfg(feature = "ibm437")]
+        &crate::single_byte::Ibm437::new_graphic(),
+        #[cfg(feature = "ibm737")]
+        &crate::single_byte::Ibm737::new(),
+        #[cfg(feature = "ibm850")]
+        &crate::single_byte::Ibm850::new(),
+        #[cfg(feature = "ibm852")]
+        &crate::single_byte::Ibm852::new(),
+        #[cfg(feature = "ibm855")]
+        &crate::single_byte::Ibm855::new(),
+        #[cfg(feature = "ibm857")]
+        &crate::single_byte::Ibm857::new(),
+        #[cfg(feature = "ibm860")]
+        &crate::single_byte::Ibm860::new(),
+        #[cfg(feature = "ibm861")]
+        &crate::single_byte::Ibm861::new(),
+        #[cfg(feature = "ibm862")]
+        &crate::single_byte::Ibm862::new(),
+        #[cfg(feature = "ibm863")]
+        &crate::single_byte::Ibm863::new(),
+        #[cfg(feature = "ibm864")]
+        &crate::single_byte::Ibm864::new(),
+        #[cfg(feature = "ibm865")]
+        &crate::single_byte::Ibm865::new(),
+        #[cfg(feature = "ibm869")]
+        &crate::single_byte::Ibm869::new(),
+        #[cfg(feature = "x-mac-ce")]
+        &crate::single_byte::XMacCe::new(),
+        #[cfg(feature = "x-mac-greek")]
+        &crate::single_byte::XMacGreek::new(),
+        #[cfg(feature = "x-mac-turkish")]
+        &crate::single_byte::XMacTurkish::new(),
+        #[cfg(feature = "x-mac-icelandic")]
+        &crate::single_byte::XMacIcelandic::new(),
+        #[cfg(feature = "x-mac-croatian")]
+        &crate::single_byte::XMacCroatian::new(),
+        #[cfg(feature = "x-mac-romanian")]
+        &crate::single_byte::XMacRomanian::new(),
+        #[cfg(feature = "x-mac-hebrew")]
+        &crate::single_byte::XMacHebrew::new(),
+        #[cfg(feature = "x-mac-arabic")]
+        &crate::single_byte::XMacArabic::new(),
+        #[cfg(feature = "ibm037")]
+        &crate::ebcdic::Ibm037::new(),
+        #[cfg(feature = "ibm037")]
+        &crate::ebcdic::Ibm037::new_swap_lf_nl(),
+        #[cfg(feature = "ibm273")]
+        &crate::ebcdic::Ibm273::new(),
+        #[cfg(feature = "ibm273")]
+        &crate::ebcdic::Ibm273::new_swap_lf_nl(),
+        #[cfg(feature = "ibm500")]
+        &crate::ebcdic::Ibm500::new(),
+        #[cfg(feature = "ibm500")]
+        &crate::ebcdic::Ibm500::new_swap_lf_nl(),
+        #[cfg(feature = "ibm1047")]
+        &crate::ebcdic::Ibm1047::new(),
+        #[cfg(feature = "ibm1047")]
+        &crate::ebcdic::Ibm1047::new_swap_lf_nl(),
+    ];
+
+    /// Create a new charset registry instance.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self
+    }
+
+    /// All charsets available in the registry, in the order they are searched when resolving a label.
+    #[inline]
+    #[must_use]
+    pub const fn charsets() -> &'static [&'static dyn crate::CharsetCodec] {
+        Self::CHARSETS
+    }
+
+    /// Find the charset that uses the given label.
+    ///
+    /// Returns `None` if no enabled charset is known by the label.
+    #[must_use]
+    #[expect(clippy::missing_inline_in_public_items)]
+    pub fn lookup(label: &str) -> Option<&'static dyn crate::CharsetCodec> {
+        let label = trim_label(label);
+        if label.is_empty() {
+            return None;
+        }
+        Self::CHARSETS.iter().copied().find(|charset| {
+            charset
+                .charset_alias()
+                .iter()
+                .any(|alias| alias.eq_ignore_ascii_case(label))
+        })
+    }
+
+    /// Find the canonical name of the charset that uses the given label.
+    ///
+    /// The name is not necessarily the label itself, even when the label is the canonical name of another charset.
+    /// See [`CharsetRegistry::canonical_name`] for the names that resolve to a WHATWG encoding instead.
+    #[inline]
+    #[must_use]
+    pub fn lookup_name(label: &str) -> Option<&'static str> {
+        Self::lookup(label).map(Self::canonical_name)
+    }
+
+    /// The canonical name of a charset.
+    ///
+    /// This is the first of the labels returned by [`CharsetRef::charset_alias`], which is the preferred WHATWG or IANA name of the charsets in this crate.
+    /// Falls back to [`CharsetRef::charset_name`] if the charset has no labels.
+    ///
+    /// Looking up the canonical name returns the same charset, except for the charsets whose name the WHATWG standard uses as a label of a superset.
+    /// When that superset is enabled, `"us-ascii"` and `"iso-8859-1"` resolve to windows-1252, `"iso-8859-9"` to windows-1254 and `"iso-8859-11"` to windows-874,
+    /// so those charsets can only be selected by using them directly.
+    ///
+    /// [`CharsetRef::charset_alias`]: crate::CharsetRef::charset_alias
+    /// [`CharsetRef::charset_name`]: crate::CharsetRef::charset_name
+    #[inline]
+    #[must_use]
+    pub fn canonical_name<C: crate::CharsetRef + ?Sized>(charset: &C) -> &str {
+        charset
+            .charset_alias()
+            .first()
+            .copied()
+            .unwrap_or_else(|| charset.charset_name())
+    }
+}
+
+/// A registry of charsets added at runtime under custom labels, such as an [`OwnedSingleByteEncoding`] created from a configuration file.
+///
+/// Labels are resolved in the same way as by [`CharsetRegistry`], with the registered charsets searched before the charsets enabled through cargo features.
+/// A registered charset is found both by the label it was registered with and by the labels returned by [`Charset::charset_alias`].
+/// If several registered charsets use the same label, the one registered last is used.
+///
+/// [`OwnedSingleByteEncoding`]: crate::single_byte::OwnedSingleByteEncoding
+/// [`Charset::charset_alias`]: crate::Charset::charset_alias
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[derive(Clone, Default)]
+pub struct CustomCharsetRegistry {
+    charsets: alloc::vec::Vec<(
+        alloc::string::String,
+        alloc::sync::Arc<dyn crate::CharsetCodec + Send + Sync>,
+    )>,
+}
+
+#[cfg(feature = "alloc")]
+impl CustomCharsetRegistry {
+    /// Create a new registry without any custom charsets.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            charsets: alloc::vec::Vec::new(),
+        }
+    }
+
+    /// Register a charset under the given label.
+    ///
+    /// Leading and trailing ASCII whitespace is removed from the label.
+    #[inline]
+    pub fn register(
+        &mut self,
+        label: &str,
+        charset: alloc::sync::Arc<dyn crate::CharsetCodec + Send + Sync>,
+    ) {
+        self.charsets.push((trim_label(label).into(), charset));
+    }
+
+    /// Find the charset that uses the given label, searching the registered charsets before the [`CharsetRegistry`].
+    ///
+    /// Returns `None` if no registered or enabled charset is known by the label.
+    #[must_use]
+    #[expect(clippy::missing_inline_in_public_items)]
+    pub fn lookup(&self, label: &str) -> Option<&dyn crate::CharsetCodec> {
+        let trimmed = trim_label(label);
+        if trimmed.is_empty() {
+            return None;
+        }
+        let custom = self
+            .charsets
+            .iter()
+            .rev()
+            .find(|entry| {
+                entry.0.eq_ignore_ascii_case(trimmed)
+                    || entry
+                        .1
+                        .charset_alias()
+                        .iter()
+                        .any(|alias| alias.eq_ignore_ascii_case(trimmed))
+            })
+            .map(|entry| &*entry.1 as &dyn crate::CharsetCodec);
+        custom.or_else(|| CharsetRegistry::lookup(trimmed))
+    }
+
+    /// Find the canonical name of the charset that uses the given label.
+    #[inline]
+    #[must_use]
+    pub fn lookup_name(&self, label: &str) -> Option<&str> {
+        self.lookup(label).map(CharsetRegistry::canonical_name)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl core::fmt::Debug for CustomCharsetRegistry {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_map()
+            .entries(
+                self.charsets
+                    .iter()
+                    .map(|entry| (&entry.0, entry.1.charset_name())),
+            )
+            .finish()
+    }
+}
+
+/// Remove leading and trailing ASCII whitespace from a label.
+#[inline]
+fn trim_label(label: &str) -> &str {
+    label.trim_matches(|ch: char| matches!(ch, '\t' | '\n' | '\x0C' | '\r' | ' '))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lookup() {
+        let utf8 = CharsetRegistry::lookup(" UTF8\t").map(CharsetRegistry::canonical_name);
+        assert_eq!(utf8, Some("utf-8"));
+        assert_eq!(CharsetRegistry::lookup_name("unicode"), Some("utf-16le"));
+        assert_eq!(CharsetRegistry::lookup_name("utf-16be"), Some("utf-16be"));
+        assert_eq!(CharsetRegistry::lookup_name(""), None);
+        assert_eq!(CharsetRegistry::lookup_name("\n"), None);
+        assert_eq!(CharsetRegistry::lookup_name("utf-9"), None);
+        #[cfg(feature = "windows-1252")]
+        {
+            assert_eq!(CharsetRegistry::lookup_name("Latin1"), Some("windows-1252"));
+            assert_eq!(
+                CharsetRegistry::lookup_name("us-ascii"),
+                Some("windows-1252")
+            );
+            assert_eq!(CharsetRegistry::lookup_name("CP1252"), Some("windows-1252"));
+        };
+        #[cfg(feature = "windows-1254")]
+        assert_eq!(CharsetRegistry::lookup_name("latin5"), Some("windows-1254"));
+        #[cfg(feature = "koi8-u")]
+        assert_eq!(CharsetRegistry::lookup_name("koi8-ru"), Some("koi8-u"));
+        #[cfg(feature = "koi8-r")]
+        assert_eq!(CharsetRegistry::lookup_name("koi8"), Some("koi8-r"));
+        #[cfg(feature = "gbk")]
+        assert_eq!(CharsetRegistry::lookup_name("gb2312"), Some("gbk"));
+        #[cfg(feature = "gb18030")]
+        {
+            assert_eq!(CharsetRegistry::lookup_name("GB18030"), Some("gb18030"));
+            assert_eq!(
+                CharsetRegistry::lookup_name("gb18030-2022"),
+                Some("gb18030")
+            );
+            assert_eq!(
+                CharsetRegistry::lookup_name("GB18030-2005"),
+                Some("gb18030-2005")
+            );
+        };
+        #[cfg(feature = "iso-2022-jp")]
+        assert_eq!(
+            CharsetRegistry::lookup_name("csISO2022JP"),
+            Some("iso-2022-jp")
+        );
+        #[cfg(feature = "euc-kr")]
+        assert_eq!(CharsetRegistry::lookup_name("windows-949"), Some("euc-kr"));
+        #[cfg(feature = "iso-8859-8-i")]
+        assert_eq!(
+            CharsetRegistry::lookup_name("logical"),
+            Some("iso-8859-8-i")
+        );
+        #[cfg(feature = "ibm437")]
+        assert_eq!(CharsetRegistry::lookup_name("cp437"), Some("ibm437"));
+        #[cfg(feature = "ibm850")]
+        assert_eq!(CharsetRegistry::lookup_name("850"), Some("ibm850"));
+        #[cfg(feature = "x-mac-ce")]
+        assert_eq!(
+            CharsetRegistry::lookup_name("x-MacCentralEurope"),
+            Some("x-mac-ce")
+        );
+        #[cfg(feature = "ibm037")]
+        assert_eq!(CharsetRegistry::lookup_name("CP037"), Some("ibm037"));
+        #[cfg(feature = "ibm1047")]
+        {
+            assert_eq!(CharsetRegistry::lookup_name("ibm-1047"), Some("ibm1047"));
+            assert_eq!(
+                CharsetRegistry::lookup_name("IBM1047-swaplfnl"),
+                Some("ibm1047-swaplfnl")
+            );
+        };
+        #[cfg(feature = "replacement")]
+        assert_eq!(
+            CharsetRegistry::lookup_name("csISO2022KR"),
+            Some("replacement")
+        );
+    }
+
+    #[test]
+    fn test_canonical_round_trip() {
+        const SUPERSETS: &[(&str, &str)] = &[
+            ("us-ascii", "windows-1252"),
+            ("iso-8859-1", "windows-1252"),
+            ("iso-8859-9", "windows-1254"),
+            ("iso-8859-11", "windows-874"),
+        ];
+        for charset in CharsetRegistry::charsets() {
+            let name = CharsetRegistry::canonical_name(*charset);
+            let found = CharsetRegistry::lookup_name(name);
+            if found != Some(name) {
+                assert!(
+                    SUPERSETS
+                        .iter()
+                        .any(|entry| entry.0 == name && Some(entry.1) == found),
+                    "canonical name {name:?} resolves to {found:?}"
+                );
+            }
+        }
+        #[cfg(feature = "windows-1252")]
+        assert_eq!(
+            CharsetRegistry::lookup_name(CharsetRegistry::canonical_name(&crate::ASCII7)),
+            Some("windows-1252")
+        );
+        #[cfg(not(feature = "windows-1252"))]
+        assert_eq!(
+            CharsetRegistry::lookup_name(CharsetRegistry::canonical_name(&crate::ASCII7)),
+            Some("us-ascii")
+        );
+    }
+
+    #[test]
+    fn test_code_page_labels() {
+        #[cfg(all(feature = "iso-8859-2", feature = "windows-1252"))]
+        {
+            assert_eq!(CharsetRegistry::lookup_name("cp819"), Some("windows-1252"));
+            assert_eq!(CharsetRegistry::lookup_name("ibm912"), Some("iso-8859-2"));
+        };
+        #[cfg(feature = "iso-8859-8")]
+        {
+            assert_eq!(CharsetRegistry::lookup_name("visual"), Some("iso-8859-8"));
+            assert_eq!(
+                CharsetRegistry::lookup_name("csISO88598E"),
+                Some("iso-8859-8")
+            );
+        };
+        #[cfg(feature = "iso-8859-7")]
+        assert_eq!(
+            CharsetRegistry::lookup_name("sun_eu_greek"),
+            Some("iso-8859-7")
+        );
+        #[cfg(all(feature = "iso-8859-9", feature = "iso-8859-10"))]
+        {
+            assert_eq!(CharsetRegistry::lookup_name("cp920"), Some("iso-8859-9"));
+            assert_eq!(CharsetRegistry::lookup_name("cp28599"), Some("iso-8859-9"));
+            assert_eq!(CharsetRegistry::lookup_name("ibm919"), Some("iso-8859-10"));
+            assert_eq!(
+                CharsetRegistry::lookup_name("windows-28600"),
+                Some("iso-8859-10")
+            );
+        };
+    }
+
+    #[cfg(all(feature = "alloc", feature = "single-byte"))]
+    #[test]
+    fn test_custom_registry() {
+        let mut registry = CustomCharsetRegistry::new();
+        let charset =
+            crate::single_byte::OwnedSingleByteEncoding::from_pairs("x-acme", [(0x80, '\u{E9}')]);
+        registry.register(" ACME-1 ", alloc::sync::Arc::new(charset));
+        assert_eq!(registry.lookup_name("acme-1"), Some("x-acme"));
+        assert_eq!(registry.lookup_name("X-ACME"), Some("x-acme"));
+        assert_eq!(registry.lookup_name("utf8"), Some("utf-8"));
+        assert_eq!(registry.lookup_name("acme-2"), None);
+
+        registry.register("utf8", alloc::sync::Arc::new(crate::Ascii7Encoding::new()));
+        assert_eq!(registry.lookup_name("utf8"), Some("us-ascii"));
+    }
+
+    #[test]
+    fn test_unique_names() {
+        let charsets = CharsetRegistry::charsets();
+        for (idx, charset) in charsets.iter().enumerate() {
+            let name = CharsetRegistry::canonical_name(*charset);
+            assert!(
+                CharsetRegistry::lookup(name).is_some(),
+                "canonical name {name:?} must resolve"
+            );
+            for other in &charsets[idx + 1..] {
+                assert_ne!(name, CharsetRegistry::canonical_name(*other));
+            }
+        }
+    }
+}
@@ -0,0 +1,177 @@
+/// The WHATWG replacement encoding.
+///
+/// This encoding is used for labels of charsets that are known to be misused for cross-site scripting attacks, such as ISO-2022-KR and HZ-GB-2312.
+/// Decoding any non-empty input results in a single invalid character, which is a U+FFFD replacement character when decoding with a [`DecodeStream`], and the rest of the input is discarded.
+/// Encoding is not supported, every character is reported as invalid.
+///
+/// [`DecodeStream`]: crate::DecodeStream
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+#[non_exhaustive]
+#[repr(transparent)]
+#[cfg_attr(docsrs, doc(cfg(feature = "replacement")))]
+pub struct ReplacementEncoding;
+
+/// The WHATWG replacement encoding.
+#[cfg_attr(docsrs, doc(cfg(feature = "replacement")))]
+pub static REPLACEMENT: ReplacementEncoding = ReplacementEncoding::new();
+
+/// The state flag set once the invalid character has been returned.
+const ERROR_RETURNED: u32 = 1;
+
+impl ReplacementEncoding {
+    /// Create a new replacement encoding instance.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self
+    }
+
+    /// Decode the given bytes in the given state, returning the result and the next state.
+    ///
+    /// The first non-empty input of a stream is consumed as a single invalid character, all following bytes are discarded by returning [`DecodeResult::Empty`].
+    ///
+    /// [`DecodeResult::Empty`]: crate::DecodeResult::Empty
+    #[inline]
+    #[must_use]
+    pub const fn decode_const(
+        &self,
+        state: crate::CharsetState,
+        bytes: &[u8],
+    ) -> (crate::DecodeResult, crate::CharsetState) {
+        if bytes.is_empty() || state.as_u32() & ERROR_RETURNED != 0 {
+            return (crate::DecodeResult::Empty, state);
+        }
+        let len = if bytes.len() > u32::MAX as usize {
+            u32::MAX
+        } else {
+            #[expect(clippy::cast_possible_truncation)]
+            let len = bytes.len() as u32;
+            len
+        };
+        (
+            crate::DecodeResult::InvalidChar(bytes[0] as u32, len),
+            crate::CharsetState::from_u32(state.as_u32() | ERROR_RETURNED),
+        )
+    }
+
+    /// Encode the given characters, which always fails as the replacement encoding has no encoder.
+    #[inline]
+    #[must_use]
+    pub const fn encode_const(&self, chars: &str) -> crate::EncodeResult {
+        let (ch, ch_len) = bytedata::const_utf8_char_next(chars.as_bytes());
+        if ch_len == 0 {
+            return crate::EncodeResult::Empty;
+        }
+        let Some(ch) = char::from_u32(ch) else {
+            unreachable!();
+        };
+        #[expect(clippy::cast_possible_truncation)]
+        crate::EncodeResult::InvalidChar(ch, ch_len as u16)
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "replacement")))]
+impl crate::Charset for ReplacementEncoding {
+    const CHARSET_NAME: &'static str = "replacement";
+
+    #[inline]
+    fn size_hint(&self) -> (u16, u16) {
+        (1, 1)
+    }
+
+    #[inline]
+    fn charset_alias(&self) -> &[&'static str] {
+        &[
+            // other
+            Self::CHARSET_NAME,
+            "csiso2022kr",
+            "hz-gb-2312",
+            "iso-2022-cn",
+            "iso-2022-cn-ext",
+            "iso-2022-kr",
+        ]
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "replacement")))]
+impl crate::CharsetStatefulDecoding for ReplacementEncoding {
+    #[inline]
+    fn decode_stateful(
+        &self,
+        state: &mut crate::CharsetState,
+        bytes: &[u8],
+    ) -> crate::DecodeResult {
+        let (res, next) = self.decode_const(*state, bytes);
+        *state = next;
+        res
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "replacement")))]
+impl crate::CharsetStatefulEncoding for ReplacementEncoding {
+    #[inline]
+    fn encode_stateful(
+        &self,
+        _state: &mut crate::CharsetState,
+        chars: &str,
+    ) -> crate::EncodeResult {
+        self.encode_const(chars)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CharsetStatefulDecoding;
+
+    #[test]
+    fn test_replacement_decode() {
+        let mut state = crate::CharsetState::new();
+        assert_eq!(
+            REPLACEMENT.decode_stateful(&mut state, b""),
+            crate::DecodeResult::Empty
+        );
+        assert!(state.is_initial());
+        assert_eq!(
+            REPLACEMENT.decode_stateful(&mut state, b"\x1B$)Cabc"),
+            crate::DecodeResult::InvalidChar(0x1B, 7)
+        );
+        assert_eq!(
+            REPLACEMENT.decode_stateful(&mut state, b"def"),
+            crate::DecodeResult::Empty
+        );
+        assert_eq!(
+            REPLACEMENT.decode_finish(&mut state, b"ghi"),
+            crate::DecodeResult::Empty
+        );
+        assert!(state.is_initial());
+    }
+
+    #[test]
+    fn test_replacement_stream() {
+        let chunks: [&[u8]; 3] = [b"abc", b"\xFF", b"def"];
+        let mut stream = crate::DecodeStream::new(REPLACEMENT, chunks.into_iter());
+        let mut out = String::new();
+        while let Some(Ok(chunk)) = stream.iter_next() {
+            out.push_str(chunk.as_str());
+        }
+        assert_eq!(out, "\u{FFFD}");
+
+        let empty: [&[u8]; 1] = [b""];
+        let mut empty_stream = crate::DecodeStream::new(REPLACEMENT, empty.into_iter());
+        assert!(empty_stream.iter_next().is_none());
+    }
+
+    #[test]
+    fn test_replacement_encode() {
+        assert_eq!(REPLACEMENT.encode_const(""), crate::EncodeResult::Empty);
+        assert_eq!(
+            REPLACEMENT.encode_const("a"),
+            crate::EncodeResult::InvalidChar('a', 1)
+        );
+        assert_eq!(
+            REPLACEMENT.encode_const("\u{FFFD}b"),
+            crate::EncodeResult::InvalidChar('\u{FFFD}', 3)
+        );
+    }
+}
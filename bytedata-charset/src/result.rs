@@ -1,9 +1,13 @@
 /// The result of a charset decoding operation.
+///
+/// New variants may be added in minor releases, so matches outside of this crate need a wildcard arm.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[expect(clippy::exhaustive_enums)]
+#[non_exhaustive]
 pub enum DecodeResult {
     /// The next number of bytes is a valid character. The character and the number of bytes consumed are returned.
     Char(char, u32),
+    /// The next number of bytes is a valid sequence of two characters, such as a base character followed by a combining mark. The characters and the number of bytes consumed are returned.
+    CharPair(char, char, u32),
     /// The next number of bytes is not a valid character. The invalid character data and the number of bytes consumed is returned.
     InvalidChar(u32, u32),
     /// The returned number of bytes are all valid utf-8 compatible characters.
@@ -0,0 +1,99 @@
+//! A const reverse index from characters to the bytes of a single-byte charset.
+
+/// A reverse index from the characters of a single-byte charset table to their byte values.
+///
+/// The index is built in a `const` context from the forward table and holds the characters sorted by code point,
+/// so that a lookup is a binary search of at most `log2(N)` steps instead of a scan of the whole table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[expect(clippy::redundant_pub_crate)]
+pub(crate) struct ReverseIndex<const N: usize> {
+    /// The code points of the indexed characters, sorted in ascending order.
+    chars: [u32; N],
+    /// The byte value of the character at the same position in `chars`.
+    bytes: [u8; N],
+    /// The number of indexed characters.
+    len: usize,
+}
+
+impl<const N: usize> ReverseIndex<N> {
+    /// Build the index for a forward table where the character at position `i` is encoded as the byte `offset + i`.
+    ///
+    /// Entries of `'\0'` are treated as undefined and skipped, unless `keep_nul` is set and the entry is at position `0`.
+    /// If a character occurs more than once, the first byte value is kept.
+    pub(crate) const fn new(table: &[char; N], offset: u8, keep_nul: bool) -> Self {
+        let mut chars = [0_u32; N];
+        let mut bytes = [0_u8; N];
+        let mut len = 0;
+        let mut i = 0;
+        while i < N {
+            let ch = table[i] as u32;
+            if ch == 0 && !(keep_nul && i == 0) {
+                i += 1;
+                continue;
+            }
+            // insertion sort, as the table is small and this is evaluated at compile time
+            let mut pos = len;
+            while pos != 0 && chars[pos - 1] > ch {
+                pos -= 1;
+            }
+            if pos != 0 && chars[pos - 1] == ch {
+                i += 1;
+                continue;
+            }
+            let mut j = len;
+            while j > pos {
+                chars[j] = chars[j - 1];
+                bytes[j] = bytes[j - 1];
+                j -= 1;
+            }
+            chars[pos] = ch;
+            #[expect(clippy::cast_possible_truncation)]
+            let byte = offset.wrapping_add(i as u8);
+            bytes[pos] = byte;
+            len += 1;
+            i += 1;
+        }
+        Self { chars, bytes, len }
+    }
+
+    /// Find the byte value of a character, returning `None` if the character is not in the table.
+    #[inline]
+    pub(crate) const fn get(&self, ch: u32) -> Option<u8> {
+        let mut low = 0;
+        let mut high = self.len;
+        while low < high {
+            let mid = low + ((high - low) >> 1_u32);
+            let key = self.chars[mid];
+            if key == ch {
+                return Some(self.bytes[mid]);
+            }
+            if key < ch {
+                low = mid + 1;
+            } else {
+                high = mid;
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reverse_index() {
+        const TABLE: [char; 6] = ['\u{20AC}', '\0', 'b', '\u{E9}', 'b', 'a'];
+        const INDEX: ReverseIndex<6> = ReverseIndex::new(&TABLE, 0x80, false);
+        assert_eq!(INDEX.get(0x20AC), Some(0x80));
+        assert_eq!(INDEX.get(u32::from('a')), Some(0x85));
+        assert_eq!(INDEX.get(u32::from('b')), Some(0x82));
+        assert_eq!(INDEX.get(0xE9), Some(0x83));
+        assert_eq!(INDEX.get(0), None);
+        assert_eq!(INDEX.get(u32::from('c')), None);
+
+        let with_nul = ReverseIndex::new(&['\0', 'a', '\0'], 0, true);
+        assert_eq!(with_nul.get(0), Some(0));
+        assert_eq!(with_nul.get(u32::from('a')), Some(1));
+    }
+}
@@ -2,11 +2,81 @@
 mod single_byte;
 pub use single_byte::*;
 
+#[cfg(feature = "alloc")]
+mod owned;
+#[cfg(feature = "alloc")]
+pub use owned::*;
+
+#[cfg(feature = "ibm437")]
+mod ibm437;
+#[cfg(feature = "ibm437")]
+pub use ibm437::*;
+
+#[cfg(feature = "ibm737")]
+mod ibm737;
+#[cfg(feature = "ibm737")]
+pub use ibm737::*;
+
+#[cfg(feature = "ibm850")]
+mod ibm850;
+#[cfg(feature = "ibm850")]
+pub use ibm850::*;
+
+#[cfg(feature = "ibm852")]
+mod ibm852;
+#[cfg(feature = "ibm852")]
+pub use ibm852::*;
+
+#[cfg(feature = "ibm855")]
+mod ibm855;
+#[cfg(feature = "ibm855")]
+pub use ibm855::*;
+
+#[cfg(feature = "ibm857")]
+mod ibm857;
+#[cfg(feature = "ibm857")]
+pub use ibm857::*;
+
+#[cfg(feature = "ibm860")]
+mod ibm860;
+#[cfg(feature = "ibm860")]
+pub use ibm860::*;
+
+#[cfg(feature = "ibm861")]
+mod ibm861;
+#[cfg(feature = "ibm861")]
+pub use ibm861::*;
+
+#[cfg(feature = "ibm862")]
+mod ibm862;
+#[cfg(feature = "ibm862")]
+pub use ibm862::*;
+
+#[cfg(feature = "ibm863")]
+mod ibm863;
+#[cfg(feature = "ibm863")]
+pub use ibm863::*;
+
+#[cfg(feature = "ibm864")]
+mod ibm864;
+#[cfg(feature = "ibm864")]
+pub use ibm864::*;
+
+#[cfg(feature = "ibm865")]
+mod ibm865;
+#[cfg(feature = "ibm865")]
+pub use ibm865::*;
+
 #[cfg(feature = "ibm866")]
 mod ibm866;
 #[cfg(feature = "ibm866")]
 pub use ibm866::*;
 
+#[cfg(feature = "ibm869")]
+mod ibm869;
+#[cfg(feature = "ibm869")]
+pub use ibm869::*;
+
 #[cfg(feature = "koi8-r")]
 mod koi8_r;
 #[cfg(feature = "koi8-r")]
@@ -27,7 +97,567 @@ mod x_mac_cyrillic;
 #[cfg(feature = "x-mac-cyrillic")]
 pub use x_mac_cyrillic::*;
 
+#[cfg(feature = "x-mac-ce")]
+mod x_mac_ce;
+#[cfg(feature = "x-mac-ce")]
+pub use x_mac_ce::*;
+
+#[cfg(feature = "x-mac-greek")]
+mod x_mac_greek;
+#[cfg(feature = "x-mac-greek")]
+pub use x_mac_greek::*;
+
+#[cfg(feature = "x-mac-turkish")]
+mod x_mac_turkish;
+#[cfg(feature = "x-mac-turkish")]
+pub use x_mac_turkish::*;
+
+#[cfg(feature = "x-mac-icelandic")]
+mod x_mac_icelandic;
+#[cfg(feature = "x-mac-icelandic")]
+pub use x_mac_icelandic::*;
+
+#[cfg(feature = "x-mac-croatian")]
+mod x_mac_croatian;
+#[cfg(feature = "x-mac-croatian")]
+pub use x_mac_croatian::*;
+
+#[cfg(feature = "x-mac-romanian")]
+mod x_mac_romanian;
+#[cfg(feature = "x-mac-romanian")]
+pub use x_mac_romanian::*;
+
+#[cfg(feature = "x-mac-hebrew")]
+mod x_mac_hebrew;
+#[cfg(feature = "x-mac-hebrew")]
+pub use x_mac_hebrew::*;
+
+#[cfg(feature = "x-mac-arabic")]
+mod x_mac_arabic;
+#[cfg(feature = "x-mac-arabic")]
+pub use x_mac_arabic::*;
+
 #[cfg(feature = "x-user-defined")]
 mod x_user_defined;
 #[cfg(feature = "x-user-defined")]
 pub use x_user_defined::*;
+
+#[cfg(test)]
+mod tests {
+    #[cfg(any(
+        feature = "ibm437",
+        feature = "ibm737",
+        feature = "ibm850",
+        feature = "ibm852",
+        feature = "ibm855",
+        feature = "ibm857",
+        feature = "ibm860",
+        feature = "ibm861",
+        feature = "ibm862",
+        feature = "ibm863",
+        feature = "ibm864",
+        feature = "ibm865",
+        feature = "ibm869"
+    ))]
+    /// Decode every byte of the high half and check that the character encodes back to the same byte.
+    fn assert_high_half_round_trip(charset: &super::SingleByteEncoding, unassigned: &[u8]) {
+        for byte in 0x80..=u8::MAX {
+            let mut buf = [0_u8; 4];
+            let res = charset.decode(&[byte]);
+            if let crate::DecodeResult::Char(ch, 1) = res {
+                let chars = ch.encode_utf8(&mut buf);
+                assert!(
+                    matches!(
+                        charset.encode(chars),
+                        crate::EncodeResult::Chunk(chunk, len) if chunk.as_slice() == [byte] && len as usize == chars.len()
+                    ),
+                    "byte {byte:#04X} decoded to {ch:?} which does not encode back"
+                );
+            } else {
+                assert!(
+                    unassigned.contains(&byte)
+                        && matches!(res, crate::DecodeResult::InvalidChar(_, 1)),
+                    "byte {byte:#04X} decoded to {res:?}"
+                );
+            }
+        }
+    }
+
+    #[cfg(feature = "ibm437")]
+    #[test]
+    fn test_ibm437() {
+        use super::{IBM437, IBM437_GRAPHIC};
+
+        assert_high_half_round_trip(IBM437.single_byte(), &[]);
+        assert_high_half_round_trip(IBM437_GRAPHIC.single_byte(), &[]);
+        assert_eq!(
+            IBM437.decode(b"\x80"),
+            crate::DecodeResult::Char('\u{C7}', 1)
+        );
+        assert_eq!(
+            IBM437.decode(b"\xE1"),
+            crate::DecodeResult::Char('\u{DF}', 1)
+        );
+        assert_eq!(
+            IBM437.decode(b"\xFF"),
+            crate::DecodeResult::Char('\u{A0}', 1)
+        );
+    }
+
+    #[cfg(feature = "ibm437")]
+    #[test]
+    fn test_ibm437_graphic() {
+        use super::{IBM437, IBM437_GRAPHIC};
+
+        let graphic = "\u{263A}\u{263B}\u{2665}\u{2666}\u{2663}\u{2660}\u{2022}\u{25D8}\u{25CB}\u{25D9}\u{2642}\u{2640}\u{266A}\u{266B}\u{263C}\u{25BA}\u{25C4}\u{2195}\u{203C}\u{00B6}\u{00A7}\u{25AC}\u{21A8}\u{2191}\u{2193}\u{2192}\u{2190}\u{221F}\u{2194}\u{25B2}\u{25BC}";
+        let bytes = (0x01..=0x1F).chain(core::iter::once(0x7F));
+        for (byte, ch) in bytes.zip(graphic.chars().chain(core::iter::once('\u{2302}'))) {
+            let mut buf = [0_u8; 4];
+            // the plain variant keeps the control characters of ASCII
+            assert_eq!(IBM437.decode(&[byte]), crate::DecodeResult::Utf8(1));
+            assert!(matches!(
+                IBM437.encode(ch.encode_utf8(&mut buf)),
+                crate::EncodeResult::InvalidChar(invalid, _) if invalid == ch
+            ));
+            assert_eq!(
+                IBM437_GRAPHIC.decode(&[byte]),
+                crate::DecodeResult::Char(ch, 1)
+            );
+            assert!(matches!(
+                IBM437_GRAPHIC.encode(ch.encode_utf8(&mut buf)),
+                crate::EncodeResult::Chunk(chunk, _) if chunk.as_slice() == [byte]
+            ));
+        }
+        // NUL and the printable ASCII characters are the same in both variants
+        assert_eq!(IBM437_GRAPHIC.decode(b"\0"), crate::DecodeResult::Utf8(1));
+        assert_eq!(IBM437_GRAPHIC.decode(b"A~"), crate::DecodeResult::Utf8(2));
+        assert_eq!(
+            crate::Charset::charset_name(&IBM437_GRAPHIC),
+            "ibm437-graphic"
+        );
+    }
+
+    #[cfg(feature = "ibm737")]
+    #[test]
+    fn test_ibm737() {
+        use super::IBM737;
+
+        assert_high_half_round_trip(IBM737.single_byte(), &[]);
+        assert_eq!(
+            IBM737.decode(b"\x80"),
+            crate::DecodeResult::Char('\u{391}', 1)
+        );
+        assert_eq!(
+            IBM737.decode(b"\xE0"),
+            crate::DecodeResult::Char('\u{3C9}', 1)
+        );
+    }
+
+    #[cfg(feature = "ibm850")]
+    #[test]
+    fn test_ibm850() {
+        use super::IBM850;
+
+        assert_high_half_round_trip(IBM850.single_byte(), &[]);
+        assert_eq!(
+            IBM850.decode(b"\x9B"),
+            crate::DecodeResult::Char('\u{F8}', 1)
+        );
+        assert_eq!(
+            IBM850.decode(b"\xD5"),
+            crate::DecodeResult::Char('\u{131}', 1)
+        );
+    }
+
+    #[cfg(feature = "ibm852")]
+    #[test]
+    fn test_ibm852() {
+        use super::IBM852;
+
+        assert_high_half_round_trip(IBM852.single_byte(), &[]);
+        assert_eq!(
+            IBM852.decode(b"\xA5"),
+            crate::DecodeResult::Char('\u{105}', 1)
+        );
+        assert_eq!(
+            IBM852.decode(b"\xE0"),
+            crate::DecodeResult::Char('\u{D3}', 1)
+        );
+    }
+
+    #[cfg(feature = "ibm855")]
+    #[test]
+    fn test_ibm855() {
+        use super::IBM855;
+
+        assert_high_half_round_trip(IBM855.single_byte(), &[]);
+        assert_eq!(
+            IBM855.decode(b"\x80"),
+            crate::DecodeResult::Char('\u{452}', 1)
+        );
+        assert_eq!(
+            IBM855.decode(b"\x81"),
+            crate::DecodeResult::Char('\u{402}', 1)
+        );
+    }
+
+    #[cfg(feature = "ibm857")]
+    #[test]
+    fn test_ibm857() {
+        use super::IBM857;
+
+        assert_high_half_round_trip(IBM857.single_byte(), b"\xD5\xE7\xF2");
+        assert_eq!(
+            IBM857.decode(b"\x8D"),
+            crate::DecodeResult::Char('\u{131}', 1)
+        );
+        assert_eq!(
+            IBM857.decode(b"\x98"),
+            crate::DecodeResult::Char('\u{130}', 1)
+        );
+    }
+
+    #[cfg(feature = "ibm860")]
+    #[test]
+    fn test_ibm860() {
+        use super::IBM860;
+
+        assert_high_half_round_trip(IBM860.single_byte(), &[]);
+        assert_eq!(
+            IBM860.decode(b"\x84"),
+            crate::DecodeResult::Char('\u{E3}', 1)
+        );
+        assert_eq!(
+            IBM860.decode(b"\x86"),
+            crate::DecodeResult::Char('\u{C1}', 1)
+        );
+    }
+
+    #[cfg(feature = "ibm861")]
+    #[test]
+    fn test_ibm861() {
+        use super::IBM861;
+
+        assert_high_half_round_trip(IBM861.single_byte(), &[]);
+        assert_eq!(
+            IBM861.decode(b"\x8B"),
+            crate::DecodeResult::Char('\u{D0}', 1)
+        );
+        assert_eq!(
+            IBM861.decode(b"\x8C"),
+            crate::DecodeResult::Char('\u{F0}', 1)
+        );
+    }
+
+    #[cfg(feature = "ibm862")]
+    #[test]
+    fn test_ibm862() {
+        use super::IBM862;
+
+        assert_high_half_round_trip(IBM862.single_byte(), &[]);
+        assert_eq!(
+            IBM862.decode(b"\x80"),
+            crate::DecodeResult::Char('\u{5D0}', 1)
+        );
+        assert_eq!(
+            IBM862.decode(b"\x9A"),
+            crate::DecodeResult::Char('\u{5EA}', 1)
+        );
+    }
+
+    #[cfg(feature = "ibm863")]
+    #[test]
+    fn test_ibm863() {
+        use super::IBM863;
+
+        assert_high_half_round_trip(IBM863.single_byte(), &[]);
+        assert_eq!(
+            IBM863.decode(b"\x84"),
+            crate::DecodeResult::Char('\u{C2}', 1)
+        );
+        assert_eq!(
+            IBM863.decode(b"\x86"),
+            crate::DecodeResult::Char('\u{B6}', 1)
+        );
+    }
+
+    #[cfg(feature = "ibm864")]
+    #[test]
+    fn test_ibm864() {
+        use super::IBM864;
+
+        assert_high_half_round_trip(IBM864.single_byte(), b"\x9B\x9C\x9F\xA6\xA7\xFF");
+        assert_eq!(
+            IBM864.decode(b"\x80"),
+            crate::DecodeResult::Char('\u{B0}', 1)
+        );
+        // the percent sign is replaced by the Arabic percent sign
+        assert_eq!(IBM864.decode(b"%"), crate::DecodeResult::Char('\u{66A}', 1));
+        assert!(matches!(
+            IBM864.encode("\u{66A}"),
+            crate::EncodeResult::Chunk(chunk, 2) if chunk.as_slice() == b"%"
+        ));
+    }
+
+    #[cfg(feature = "ibm865")]
+    #[test]
+    fn test_ibm865() {
+        use super::IBM865;
+
+        assert_high_half_round_trip(IBM865.single_byte(), &[]);
+        assert_eq!(
+            IBM865.decode(b"\x9B"),
+            crate::DecodeResult::Char('\u{F8}', 1)
+        );
+        assert_eq!(
+            IBM865.decode(b"\x9D"),
+            crate::DecodeResult::Char('\u{D8}', 1)
+        );
+        assert_eq!(
+            IBM865.decode(b"\xAF"),
+            crate::DecodeResult::Char('\u{A4}', 1)
+        );
+    }
+
+    #[cfg(feature = "ibm869")]
+    #[test]
+    fn test_ibm869() {
+        use super::IBM869;
+
+        assert_high_half_round_trip(
+            IBM869.single_byte(),
+            b"\x80\x81\x82\x83\x84\x85\x87\x93\x94",
+        );
+        assert_eq!(
+            IBM869.decode(b"\x86"),
+            crate::DecodeResult::Char('\u{386}', 1)
+        );
+    }
+
+    #[cfg(any(
+        feature = "x-mac-ce",
+        feature = "x-mac-greek",
+        feature = "x-mac-turkish",
+        feature = "x-mac-icelandic",
+        feature = "x-mac-croatian",
+        feature = "x-mac-romanian",
+        feature = "x-mac-hebrew",
+        feature = "x-mac-arabic"
+    ))]
+    /// Decode every byte of the high half and check that the character encodes back to the same byte.
+    ///
+    /// Returns the number of right-to-left variants of ASCII characters, which encode to the ASCII byte instead.
+    /// The `duplicates` are bytes that decode to the same character as an earlier byte, and encode to that byte.
+    fn assert_mac_round_trip(
+        charset: &crate::ascii7_compat::AsciiCompatible,
+        duplicates: &[(u8, u8)],
+    ) -> usize {
+        let mut ascii_variants = 0;
+        for byte in 0x80..=u8::MAX {
+            let mut buf = [0_u8; 4];
+            let crate::DecodeResult::Char(ch, 1) = charset.decode(&[byte]) else {
+                unreachable!("byte {byte:#04X} did not decode to a character");
+            };
+            let res = charset.encode(ch.encode_utf8(&mut buf));
+            if ch.is_ascii() {
+                ascii_variants += 1;
+                assert_eq!(res, crate::EncodeResult::Utf8(1), "byte {byte:#04X}");
+                continue;
+            }
+            let expected = duplicates
+                .iter()
+                .find(|dup| dup.0 == byte)
+                .map_or(byte, |dup| dup.1);
+            let crate::EncodeResult::Chunk(chunk, len) = res else {
+                unreachable!("byte {byte:#04X} decoded to {ch:?} which does not encode back");
+            };
+            assert_eq!(
+                (chunk.as_slice(), usize::from(len)),
+                ([expected].as_slice(), ch.len_utf8()),
+                "byte {byte:#04X} decoded to {ch:?}"
+            );
+        }
+        ascii_variants
+    }
+
+    #[cfg(feature = "x-mac-ce")]
+    #[test]
+    fn test_x_mac_ce() {
+        use super::X_MAC_CE;
+
+        assert_eq!(assert_mac_round_trip(X_MAC_CE.ascii_compat(), &[]), 0);
+        assert_eq!(
+            X_MAC_CE.decode(b"\x81"),
+            crate::DecodeResult::Char('\u{100}', 1)
+        );
+        assert_eq!(
+            X_MAC_CE.decode(b"\x84"),
+            crate::DecodeResult::Char('\u{104}', 1)
+        );
+    }
+
+    #[cfg(feature = "x-mac-greek")]
+    #[test]
+    fn test_x_mac_greek() {
+        use super::X_MAC_GREEK;
+
+        assert_eq!(assert_mac_round_trip(X_MAC_GREEK.ascii_compat(), &[]), 0);
+        assert_eq!(
+            X_MAC_GREEK.decode(b"\xA1"),
+            crate::DecodeResult::Char('\u{393}', 1)
+        );
+        assert_eq!(
+            X_MAC_GREEK.decode(b"\xE1"),
+            crate::DecodeResult::Char('\u{3B1}', 1)
+        );
+    }
+
+    #[cfg(feature = "x-mac-turkish")]
+    #[test]
+    fn test_x_mac_turkish() {
+        use super::X_MAC_TURKISH;
+
+        assert_eq!(assert_mac_round_trip(X_MAC_TURKISH.ascii_compat(), &[]), 0);
+        assert_eq!(
+            X_MAC_TURKISH.decode(b"\xDA"),
+            crate::DecodeResult::Char('\u{11E}', 1)
+        );
+        assert_eq!(
+            X_MAC_TURKISH.decode(b"\xDD"),
+            crate::DecodeResult::Char('\u{131}', 1)
+        );
+    }
+
+    #[cfg(feature = "x-mac-icelandic")]
+    #[test]
+    fn test_x_mac_icelandic() {
+        use super::X_MAC_ICELANDIC;
+
+        assert_eq!(
+            assert_mac_round_trip(X_MAC_ICELANDIC.ascii_compat(), &[]),
+            0
+        );
+        assert_eq!(
+            X_MAC_ICELANDIC.decode(b"\xA0"),
+            crate::DecodeResult::Char('\u{DD}', 1)
+        );
+        assert_eq!(
+            X_MAC_ICELANDIC.decode(b"\xDF"),
+            crate::DecodeResult::Char('\u{FE}', 1)
+        );
+    }
+
+    #[cfg(feature = "x-mac-croatian")]
+    #[test]
+    fn test_x_mac_croatian() {
+        use super::X_MAC_CROATIAN;
+
+        assert_eq!(assert_mac_round_trip(X_MAC_CROATIAN.ascii_compat(), &[]), 0);
+        assert_eq!(
+            X_MAC_CROATIAN.decode(b"\xA9"),
+            crate::DecodeResult::Char('\u{160}', 1)
+        );
+        assert_eq!(
+            X_MAC_CROATIAN.decode(b"\xE6"),
+            crate::DecodeResult::Char('\u{107}', 1)
+        );
+    }
+
+    #[cfg(feature = "x-mac-romanian")]
+    #[test]
+    fn test_x_mac_romanian() {
+        use super::X_MAC_ROMANIAN;
+
+        assert_eq!(assert_mac_round_trip(X_MAC_ROMANIAN.ascii_compat(), &[]), 0);
+        assert_eq!(
+            X_MAC_ROMANIAN.decode(b"\xAF"),
+            crate::DecodeResult::Char('\u{218}', 1)
+        );
+        assert_eq!(
+            X_MAC_ROMANIAN.decode(b"\xDE"),
+            crate::DecodeResult::Char('\u{21A}', 1)
+        );
+    }
+
+    #[cfg(feature = "x-mac-hebrew")]
+    #[test]
+    fn test_x_mac_hebrew() {
+        use super::X_MAC_HEBREW;
+
+        // qamats qatan is mapped to qamats, so it encodes to the byte of qamats
+        let variants = assert_mac_round_trip(X_MAC_HEBREW.ascii_compat(), &[(0xDE, 0xCB)]);
+        // the right-to-left space, digits and punctuation at 0xA0..=0xBF except the sheqel sign, and 0xFB..=0xFF
+        assert_eq!(variants, 36);
+        assert_eq!(
+            X_MAC_HEBREW.decode(b"\xE0"),
+            crate::DecodeResult::Char('\u{5D0}', 1)
+        );
+        assert_eq!(
+            X_MAC_HEBREW.decode(b"\xFA"),
+            crate::DecodeResult::Char('\u{5EA}', 1)
+        );
+        assert_eq!(
+            X_MAC_HEBREW.decode(b"\xA6"),
+            crate::DecodeResult::Char('\u{20AA}', 1)
+        );
+        assert_eq!(
+            X_MAC_HEBREW.decode(b"\x81"),
+            crate::DecodeResult::Char('\u{FB1F}', 1)
+        );
+
+        // the right-to-left variants decode without directional marks and encode as ASCII
+        assert_eq!(
+            X_MAC_HEBREW.decode(b"\xA0"),
+            crate::DecodeResult::Char(' ', 1)
+        );
+        assert_eq!(
+            X_MAC_HEBREW.decode(b"\xB0"),
+            crate::DecodeResult::Char('0', 1)
+        );
+        assert_eq!(X_MAC_HEBREW.encode(" 0"), crate::EncodeResult::Utf8(2));
+        // the parentheses are mirrored in right-to-left text
+        assert_eq!(
+            X_MAC_HEBREW.decode(b"\xA8"),
+            crate::DecodeResult::Char(')', 1)
+        );
+        assert_eq!(
+            X_MAC_HEBREW.decode(b"\xA9"),
+            crate::DecodeResult::Char('(', 1)
+        );
+        // sequences of a character and private use characters decode to the first character only
+        assert_eq!(
+            X_MAC_HEBREW.decode(b"\xC0"),
+            crate::DecodeResult::Char('\u{F86A}', 1)
+        );
+    }
+
+    #[cfg(feature = "x-mac-arabic")]
+    #[test]
+    fn test_x_mac_arabic() {
+        use super::X_MAC_ARABIC;
+
+        // the right-to-left space, punctuation and brackets
+        assert_eq!(assert_mac_round_trip(X_MAC_ARABIC.ascii_compat(), &[]), 26);
+        assert_eq!(
+            X_MAC_ARABIC.decode(b"\xC7"),
+            crate::DecodeResult::Char('\u{627}', 1)
+        );
+        assert_eq!(
+            X_MAC_ARABIC.decode(b"\xAC"),
+            crate::DecodeResult::Char('\u{60C}', 1)
+        );
+        assert_eq!(
+            X_MAC_ARABIC.decode(b"\xB0"),
+            crate::DecodeResult::Char('\u{660}', 1)
+        );
+        assert_eq!(
+            X_MAC_ARABIC.decode(b"\xE0"),
+            crate::DecodeResult::Char('\u{640}', 1)
+        );
+        assert_eq!(
+            X_MAC_ARABIC.decode(b"\xA0"),
+            crate::DecodeResult::Char(' ', 1)
+        );
+        assert_eq!(X_MAC_ARABIC.encode(" "), crate::EncodeResult::Utf8(1));
+    }
+}
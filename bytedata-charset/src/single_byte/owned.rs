@@ -0,0 +1,380 @@
+use alloc::borrow::Cow;
+use alloc::sync::Arc;
+
+use crate::reverse_index::ReverseIndex;
+use bytedata_charset_core::parse_mapping_line;
+
+/// The name and tables of an [`OwnedSingleByteEncoding`], shared between its clones.
+#[derive(Debug, PartialEq, Eq)]
+struct Tables {
+    name: Cow<'static, str>,
+    chars: [char; 256],
+    /// Whether the first 128 characters are ASCII, allowing the ASCII fast paths to be used.
+    ascii: bool,
+    index: ReverseIndex<256>,
+}
+
+/// A single-byte charset encoder with a table created at runtime.
+///
+/// This works like [`SingleByteEncoding`], but owns its table instead of requiring a `&'static [char; 256]`,
+/// which allows charsets that are only known at runtime, e.g. from a configuration file, to be used without leaking memory.
+/// The table and its reverse index are reference counted, so cloning the encoder is cheap.
+///
+/// Just as for [`SingleByteEncoding`], a `'\0'` in the table marks a byte that is not part of the charset, except for at position `0`.
+/// To make the charset available when resolving labels it can be registered in a [`CustomCharsetRegistry`].
+///
+/// The name may be a `&'static str` or a `String`, and is reported by [`CharsetRef::charset_name`] and in a [`DecodeError`].
+/// As [`CharsetRef::charset_alias`] only returns `&'static str`, a charset with an owned name has no aliases,
+/// and is only found by the label it is registered with.
+///
+/// [`CharsetRef::charset_name`]: crate::CharsetRef::charset_name
+/// [`CharsetRef::charset_alias`]: crate::CharsetRef::charset_alias
+/// [`DecodeError`]: crate::DecodeError
+/// [`SingleByteEncoding`]: super::SingleByteEncoding
+/// [`CustomCharsetRegistry`]: crate::CustomCharsetRegistry
+#[cfg_attr(docsrs, doc(cfg(all(feature = "single-byte", feature = "alloc"))))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OwnedSingleByteEncoding {
+    tables: Arc<Tables>,
+}
+
+impl OwnedSingleByteEncoding {
+    /// Create a new single-byte charset encoding instance from a table of all 256 bytes.
+    #[inline]
+    #[must_use]
+    pub fn new<N: Into<Cow<'static, str>>>(name: N, chars: [char; 256]) -> Self {
+        Self {
+            tables: Arc::new(Tables {
+                name: name.into(),
+                ascii: super::single_byte::is_ascii_table(&chars),
+                index: ReverseIndex::new(&chars, 0, true),
+                chars,
+            }),
+        }
+    }
+
+    /// Create a new single-byte charset encoding instance from a list of byte and character pairs.
+    ///
+    /// Bytes that are not in the list are not part of the charset.
+    /// If none of the bytes below `0x80` are in the list, they are mapped to ASCII.
+    /// If a byte occurs more than once, the last character is used.
+    #[must_use]
+    #[expect(clippy::missing_inline_in_public_items)]
+    pub fn from_pairs<N: Into<Cow<'static, str>>, I: IntoIterator<Item = (u8, char)>>(
+        name: N,
+        pairs: I,
+    ) -> Self {
+        let mut chars = ['\0'; 256];
+        let mut has_ascii = false;
+        for (byte, ch) in pairs {
+            has_ascii |= byte < 0x80;
+            chars[byte as usize] = ch;
+        }
+        if !has_ascii {
+            fill_ascii(&mut chars);
+        }
+        Self::new(name, chars)
+    }
+
+    /// Create a new single-byte charset encoding instance from the contents of a mapping file.
+    ///
+    /// The mapping may be in the unicode.org `MAPPINGS` format, with a hexadecimal byte and character per line (`0xNN<TAB>0xUUUU`),
+    /// or in the format of the WHATWG `index-*.txt` files, with the decimal offset from `0x80` and the character per line (`pointer<TAB>0xUUUU`).
+    /// Comments start with `#`, and a line with only a byte marks the byte as not part of the charset.
+    /// If none of the bytes below `0x80` are in the mapping, they are mapped to ASCII.
+    ///
+    /// Directional hints such as `<RL>+` in Apple mapping files are ignored, and bytes mapped to a sequence of characters use the first character.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error with the line number if a line can not be parsed, maps a byte twice, or maps a byte other than `0x00` to U+0000.
+    #[expect(clippy::missing_inline_in_public_items)]
+    pub fn from_mapping<N: Into<Cow<'static, str>>>(
+        name: N,
+        mapping: &str,
+    ) -> Result<Self, MappingError> {
+        let mut chars = ['\0'; 256];
+        let mut defined = [false; 256];
+        for (line, content) in mapping.lines().enumerate() {
+            let err = |reason| MappingError {
+                line: line + 1,
+                reason,
+            };
+            let Some((byte, ch)) = parse_mapping_line(content).map_err(err)? else {
+                continue;
+            };
+            let Some(ch) = ch else {
+                // the byte is explicitly undefined
+                continue;
+            };
+            if core::mem::replace(&mut defined[byte as usize], true) {
+                return Err(err("duplicate mapping for byte"));
+            }
+            chars[byte as usize] = ch;
+        }
+        if !defined[..0x80].contains(&true) {
+            fill_ascii(&mut chars);
+        }
+        Ok(Self::new(name, chars))
+    }
+
+    /// The name of the charset.
+    #[inline]
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.tables.name
+    }
+
+    /// The table of characters for each byte.
+    #[inline]
+    #[must_use]
+    pub fn chars(&self) -> &[char; 256] {
+        &self.tables.chars
+    }
+
+    /// Returns `true` if the first 128 characters of the charset are the ASCII characters.
+    #[inline]
+    #[must_use]
+    pub fn is_ascii_compatible(&self) -> bool {
+        self.tables.ascii
+    }
+
+    /// Decode characters from the given bytes.
+    #[inline]
+    #[must_use]
+    pub fn decode(&self, bytes: &[u8]) -> crate::DecodeResult {
+        super::single_byte::decode_table(&self.tables.chars, self.tables.ascii, bytes)
+    }
+
+    /// Encode characters from the given bytes.
+    #[inline]
+    #[must_use]
+    pub fn encode(&self, chars: &str) -> crate::EncodeResult {
+        super::single_byte::encode_table(
+            &self.tables.chars,
+            self.tables.ascii,
+            &self.tables.index,
+            chars,
+        )
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(all(feature = "single-byte", feature = "alloc"))))]
+impl crate::Charset for OwnedSingleByteEncoding {
+    const CHARSET_NAME: &'static str = "single-byte";
+
+    #[inline]
+    fn charset_name(&self) -> &str {
+        &self.tables.name
+    }
+
+    #[inline]
+    // `Cow` has no method returning a reference to the borrowed value, which is needed for the slice
+    #[expect(clippy::ref_patterns)]
+    fn charset_alias(&self) -> &[&'static str] {
+        match self.tables.name {
+            Cow::Borrowed(ref name) => core::slice::from_ref(name),
+            Cow::Owned(_) => &[],
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (u16, u16) {
+        (1, 1)
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(all(feature = "single-byte", feature = "alloc"))))]
+impl crate::CharsetDecoding for OwnedSingleByteEncoding {
+    #[inline]
+    fn decode(&self, bytes: &[u8]) -> crate::DecodeResult {
+        Self::decode(self, bytes)
+    }
+
+    #[inline]
+    fn decode_all<'a>(
+        &self,
+        bytes: bytedata::ByteData<'a>,
+        invalid: crate::DecodeInvalid,
+    ) -> Result<bytedata::StringData<'a>, crate::DecodeError> {
+        crate::bulk::decode_all_single_byte(
+            &self.tables.name,
+            bytes,
+            invalid,
+            self.tables.ascii,
+            |byte| self.tables.chars[byte as usize],
+        )
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(all(feature = "single-byte", feature = "alloc"))))]
+impl crate::CharsetEncoding for OwnedSingleByteEncoding {
+    #[inline]
+    fn encode(&self, chars: &str) -> crate::EncodeResult {
+        Self::encode(self, chars)
+    }
+
+    #[inline]
+    fn encode_all<'a>(
+        &self,
+        chars: &'a str,
+        invalid: crate::EncodeInvalid,
+    ) -> Result<bytedata::ByteData<'a>, &'static str> {
+        crate::bulk::encode_all_ascii(self, self.tables.ascii, chars, invalid)
+    }
+
+    #[inline]
+    fn encode_to_vec(
+        &self,
+        chars: &str,
+        invalid: crate::EncodeInvalid,
+        out: &mut alloc::vec::Vec<u8>,
+    ) -> Result<(), &'static str> {
+        crate::bulk::encode_to_vec_ascii(self, self.tables.ascii, chars, invalid, out)
+    }
+}
+
+/// An error returned when a charset mapping can not be parsed.
+#[cfg_attr(docsrs, doc(cfg(all(feature = "single-byte", feature = "alloc"))))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MappingError {
+    line: usize,
+    reason: &'static str,
+}
+
+impl MappingError {
+    /// The line of the mapping that failed to parse, starting at `1`.
+    #[inline]
+    #[must_use]
+    pub const fn line(&self) -> usize {
+        self.line
+    }
+
+    /// A description of what is wrong with the line.
+    #[inline]
+    #[must_use]
+    pub const fn reason(&self) -> &'static str {
+        self.reason
+    }
+}
+
+impl core::fmt::Display for MappingError {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "invalid charset mapping at line {}: {}",
+            self.line, self.reason
+        )
+    }
+}
+
+impl core::error::Error for MappingError {}
+
+/// Map the bytes below `0x80` to ASCII.
+fn fill_ascii(chars: &mut [char; 256]) {
+    for (byte, ch) in (0_u8..0x80).zip(chars.iter_mut()) {
+        *ch = char::from(byte);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_mapping() {
+        let mapping =
+            "# test\n0x00\t0x0000\n0x41\t0x0042\t# B\n0x80\t0x20AC\n0x81\n0xA0\t<RL>+0x0020\n";
+        let charset = OwnedSingleByteEncoding::from_mapping("x-test", mapping);
+        let Ok(charset) = charset else {
+            unreachable!("the mapping is valid: {charset:?}");
+        };
+        assert!(!charset.is_ascii_compatible());
+        assert!(matches!(
+            charset.decode(b"\x41"),
+            crate::DecodeResult::Char('B', 1)
+        ));
+        assert!(matches!(
+            charset.decode(b"\x81"),
+            crate::DecodeResult::InvalidChar(0x81, 1)
+        ));
+        assert!(matches!(
+            charset.encode("\u{20AC}"),
+            crate::EncodeResult::Chunk(chunk, 3) if chunk.as_slice() == b"\x80"
+        ));
+        assert!(matches!(
+            charset.encode("A"),
+            crate::EncodeResult::InvalidChar('A', 1)
+        ));
+
+        let whatwg =
+            OwnedSingleByteEncoding::from_mapping("x-test", "     0\t0x0402\n   127\t0x044F\n");
+        let Ok(whatwg) = whatwg else {
+            unreachable!("the mapping is valid: {whatwg:?}");
+        };
+        assert!(whatwg.is_ascii_compatible());
+        assert_eq!(whatwg.chars()[0x80], '\u{402}');
+        assert_eq!(whatwg.chars()[0xFF], '\u{44F}');
+        assert_eq!(crate::CharsetRef::charset_name(&whatwg), "x-test");
+
+        let dup = OwnedSingleByteEncoding::from_mapping("x-test", "0x41\t0x0041\n\n0x41\t0x0042\n");
+        assert_eq!(
+            dup.map(|_| ()),
+            Err(MappingError {
+                line: 3,
+                reason: "duplicate mapping for byte"
+            })
+        );
+        let wide = OwnedSingleByteEncoding::from_mapping("x-test", "0x8140\t0x3000\n");
+        assert_eq!(wide.map_err(|err| err.line()).map(|_| ()), Err(1));
+    }
+
+    #[test]
+    fn test_from_pairs() {
+        let charset = OwnedSingleByteEncoding::from_pairs("x-test", [(0x80, '\u{E9}')]);
+        assert!(charset.is_ascii_compatible());
+        assert!(matches!(
+            charset.decode(b"a\x80"),
+            crate::DecodeResult::Utf8(1)
+        ));
+        assert!(matches!(
+            charset.decode(b"\x80"),
+            crate::DecodeResult::Char('\u{E9}', 1)
+        ));
+        let cloned = charset.clone();
+        assert_eq!(cloned, charset);
+    }
+
+    #[test]
+    fn test_owned_name() {
+        let name = alloc::format!("x-{}", "runtime");
+        let charset = OwnedSingleByteEncoding::from_pairs(name, [(0x80, '\u{E9}')]);
+        assert_eq!(charset.name(), "x-runtime");
+        assert_eq!(crate::CharsetRef::charset_name(&charset), "x-runtime");
+        assert!(crate::CharsetRef::charset_alias(&charset).is_empty());
+
+        let named = OwnedSingleByteEncoding::from_pairs("x-static", [(0x80, '\u{E9}')]);
+        assert_eq!(named.name(), "x-static");
+        assert_eq!(crate::CharsetRef::charset_alias(&named), ["x-static"]);
+
+        let mut registry = crate::CustomCharsetRegistry::new();
+        registry.register(
+            "x-runtime",
+            Arc::new(OwnedSingleByteEncoding::from_pairs(
+                alloc::string::String::from("x-runtime"),
+                [(0x80, '\u{E9}')],
+            )),
+        );
+        assert_eq!(registry.lookup_name("X-Runtime"), Some("x-runtime"));
+
+        let Err(err) = crate::CharsetDecoding::decode_all(
+            &charset,
+            b"a\x81".into(),
+            crate::DecodeInvalid::Error,
+        ) else {
+            unreachable!("0x81 is not part of the charset");
+        };
+        assert_eq!(err.charset_name(), "x-runtime");
+    }
+}
@@ -19,6 +19,9 @@
 pub struct SingleByteEncoding {
     chars: &'static [char; 256],
     name: &'static str,
+    /// Whether the first 128 characters are ASCII, allowing the ASCII fast paths to be used.
+    ascii: bool,
+    index: crate::reverse_index::ReverseIndex<256>,
 }
 
 impl SingleByteEncoding {
@@ -26,72 +29,47 @@ impl SingleByteEncoding {
     #[inline]
     #[must_use]
     pub const fn new(name: &'static str, chars: &'static [char; 256]) -> Self {
-        Self { chars, name }
+        Self {
+            chars,
+            name,
+            ascii: is_ascii_table(chars),
+            index: crate::reverse_index::ReverseIndex::new(chars, 0, true),
+        }
+    }
+
+    /// Returns `true` if the first 128 characters of the charset are the ASCII characters.
+    #[inline]
+    #[must_use]
+    pub const fn is_ascii_compatible(&self) -> bool {
+        self.ascii
     }
 
     /// Decode characters from the given bytes.
     #[inline]
     #[must_use]
     pub const fn decode_const(&self, bytes: &[u8]) -> crate::DecodeResult {
-        if bytes.is_empty() {
-            return crate::DecodeResult::Empty;
-        }
-
-        // some sprinkled characters, such as digits and space, may be in ASCII position.
-        let mut i = 0;
-        while i < bytes.len() {
-            let bval = bytes[i] as u32;
-            if bval < 128 && bval == self.chars[bval as usize] as u32 {
-                i += 1;
-                continue;
-            }
-            break;
-        }
-        if i != 0 {
-            return crate::DecodeResult::Utf8(i as u64);
-        }
-        // SAFETY: The pointer is valid and there is at least 1 byte available.
-        let byte = unsafe { bytes.as_ptr().read() };
-        decode_const_inner(self.chars, byte)
+        decode_table_const(self.chars, bytes)
     }
 
     /// Decode characters from the given bytes.
     #[inline]
     #[must_use]
-    #[expect(clippy::missing_const_for_fn)]
     pub fn decode(&self, bytes: &[u8]) -> crate::DecodeResult {
-        self.decode_const(bytes)
+        decode_table(self.chars, self.ascii, bytes)
     }
 
     /// Encode characters from the given bytes.
     #[inline]
     #[must_use]
     pub const fn encode_const(&self, chars: &str) -> crate::EncodeResult {
-        if chars.is_empty() {
-            return crate::EncodeResult::Empty;
-        }
-        let bytes = chars.as_bytes();
-        let mut i = 0;
-        while i < bytes.len() {
-            let bval = bytes[i] as u32;
-            if bval < 128 && bval == self.chars[bval as usize] as u32 {
-                i += 1;
-                continue;
-            }
-            break;
-        }
-        if i != 0 {
-            return crate::EncodeResult::Utf8(i as u64);
-        }
-        encode_const_inner(self.chars, chars)
+        encode_table_const(self.chars, &self.index, chars)
     }
 
     /// Encode characters from the given bytes.
     #[inline]
     #[must_use]
-    #[expect(clippy::missing_const_for_fn)]
     pub fn encode(&self, chars: &str) -> crate::EncodeResult {
-        self.encode_const(chars)
+        encode_table(self.chars, self.ascii, &self.index, chars)
     }
 }
 
@@ -103,6 +81,11 @@ impl crate::Charset for SingleByteEncoding {
         self.name
     }
 
+    #[inline]
+    fn charset_alias(&self) -> &[&'static str] {
+        core::slice::from_ref(&self.name)
+    }
+
     #[inline]
     fn size_hint(&self) -> (u16, u16) {
         (1, 1)
@@ -113,7 +96,19 @@ impl crate::Charset for SingleByteEncoding {
 impl crate::CharsetDecoding for SingleByteEncoding {
     #[inline]
     fn decode(&self, bytes: &[u8]) -> crate::DecodeResult {
-        Self::decode_const(self, bytes)
+        Self::decode(self, bytes)
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn decode_all<'a>(
+        &self,
+        bytes: bytedata::ByteData<'a>,
+        invalid: crate::DecodeInvalid,
+    ) -> Result<bytedata::StringData<'a>, crate::DecodeError> {
+        crate::bulk::decode_all_single_byte(self.name, bytes, invalid, self.ascii, |byte| {
+            self.chars[byte as usize]
+        })
     }
 }
 
@@ -121,10 +116,169 @@ impl crate::CharsetDecoding for SingleByteEncoding {
 impl crate::CharsetEncoding for SingleByteEncoding {
     #[inline]
     fn encode(&self, chars: &str) -> crate::EncodeResult {
-        Self::encode_const(self, chars)
+        Self::encode(self, chars)
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn encode_all<'a>(
+        &self,
+        chars: &'a str,
+        invalid: crate::EncodeInvalid,
+    ) -> Result<bytedata::ByteData<'a>, &'static str> {
+        crate::bulk::encode_all_ascii(self, self.ascii, chars, invalid)
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn encode_to_vec(
+        &self,
+        chars: &str,
+        invalid: crate::EncodeInvalid,
+        out: &mut alloc::vec::Vec<u8>,
+    ) -> Result<(), &'static str> {
+        crate::bulk::encode_to_vec_ascii(self, self.ascii, chars, invalid, out)
     }
 }
 
+/// Returns `true` if the first 128 characters of the table are the ASCII characters.
+pub(super) const fn is_ascii_table(chars: &[char; 256]) -> bool {
+    let mut i = 0;
+    while i < 128 {
+        if chars[i] as usize != i {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Decode characters from the given bytes using the table of a single-byte charset.
+pub(super) const fn decode_table_const(chars: &[char; 256], bytes: &[u8]) -> crate::DecodeResult {
+    if bytes.is_empty() {
+        return crate::DecodeResult::Empty;
+    }
+
+    // some sprinkled characters, such as digits and space, may be in ASCII position.
+    let mut i = 0;
+    while i < bytes.len() {
+        let bval = bytes[i] as u32;
+        if bval < 128 && bval == chars[bval as usize] as u32 {
+            i += 1;
+            continue;
+        }
+        break;
+    }
+    if i != 0 {
+        return crate::DecodeResult::Utf8(i as u64);
+    }
+    // SAFETY: The pointer is valid and there is at least 1 byte available.
+    let byte = unsafe { bytes.as_ptr().read() };
+    decode_const_inner(chars, byte)
+}
+
+/// Decode characters from the given bytes using the table of a single-byte charset, using the ASCII fast paths if `ascii` is set.
+#[inline]
+#[cfg_attr(
+    not(all(any(target_arch = "x86_64", target_arch = "x86"), feature = "sse2")),
+    expect(clippy::missing_const_for_fn)
+)]
+#[cfg_attr(
+    not(any(
+        all(any(target_arch = "x86_64", target_arch = "x86"), feature = "sse2"),
+        feature = "swar"
+    )),
+    expect(unused_variables)
+)]
+pub(super) fn decode_table(chars: &[char; 256], ascii: bool, bytes: &[u8]) -> crate::DecodeResult {
+    #[cfg(all(any(target_arch = "x86_64", target_arch = "x86"), feature = "sse2"))]
+    if ascii && bytes.len() >= 16 && crate::ascii7::sse2_detected() {
+        // SAFETY: The pointer is valid and the length is correct, and sse2 has been checked.
+        let res = unsafe { crate::ascii7::ascii7_decode_sse2(bytes.as_ptr(), bytes.len(), 0) };
+        if !matches!(res, crate::DecodeResult::Empty) {
+            return res;
+        }
+    }
+
+    #[cfg(feature = "swar")]
+    if ascii && bytes.len() >= core::mem::size_of::<usize>() {
+        // SAFETY: The pointer is valid and the length is correct.
+        let res = unsafe { crate::ascii7::ascii7_decode_swar(bytes.as_ptr(), bytes.len(), 0) };
+        if !matches!(res, crate::DecodeResult::Empty) {
+            return res;
+        }
+    }
+
+    decode_table_const(chars, bytes)
+}
+
+/// Encode characters from the given string using the table and reverse index of a single-byte charset.
+pub(super) const fn encode_table_const(
+    table: &[char; 256],
+    index: &crate::reverse_index::ReverseIndex<256>,
+    chars: &str,
+) -> crate::EncodeResult {
+    if chars.is_empty() {
+        return crate::EncodeResult::Empty;
+    }
+    let bytes = chars.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        let bval = bytes[i] as u32;
+        if bval < 128 && bval == table[bval as usize] as u32 {
+            i += 1;
+            continue;
+        }
+        break;
+    }
+    if i != 0 {
+        return crate::EncodeResult::Utf8(i as u64);
+    }
+    encode_const_inner(index, chars)
+}
+
+/// Encode characters from the given string using the table and reverse index of a single-byte charset, using the ASCII fast paths if `ascii` is set.
+#[inline]
+#[cfg_attr(
+    not(all(any(target_arch = "x86_64", target_arch = "x86"), feature = "sse2")),
+    expect(clippy::missing_const_for_fn)
+)]
+#[cfg_attr(
+    not(any(
+        all(any(target_arch = "x86_64", target_arch = "x86"), feature = "sse2"),
+        feature = "swar"
+    )),
+    expect(unused_variables)
+)]
+pub(super) fn encode_table(
+    table: &[char; 256],
+    ascii: bool,
+    index: &crate::reverse_index::ReverseIndex<256>,
+    chars: &str,
+) -> crate::EncodeResult {
+    #[cfg(all(any(target_arch = "x86_64", target_arch = "x86"), feature = "sse2"))]
+    if ascii && chars.len() >= 16 && crate::ascii7::sse2_detected() {
+        // SAFETY: The pointer is valid and the length is correct, and sse2 has been checked.
+        let res =
+            unsafe { crate::ascii7::ascii7_encode_sse2(chars.as_bytes().as_ptr(), chars.len(), 0) };
+        if !matches!(res, crate::EncodeResult::Empty) {
+            return res;
+        }
+    }
+
+    #[cfg(feature = "swar")]
+    if ascii && chars.len() >= core::mem::size_of::<usize>() {
+        // SAFETY: The pointer is valid and the length is correct.
+        let res =
+            unsafe { crate::ascii7::ascii7_encode_swar(chars.as_bytes().as_ptr(), chars.len(), 0) };
+        if !matches!(res, crate::EncodeResult::Empty) {
+            return res;
+        }
+    }
+
+    encode_table_const(table, index, chars)
+}
+
 #[inline]
 const fn decode_const_inner(chars: &[char; 256], byte: u8) -> crate::DecodeResult {
     let by = byte as usize;
@@ -139,20 +293,17 @@ const fn decode_const_inner(chars: &[char; 256], byte: u8) -> crate::DecodeResul
     }
 }
 
-const fn encode_const_inner(chars: &'static [char; 256], data: &str) -> crate::EncodeResult {
-    /// Find a character in the extended charset. Returns `0` if not found or if the value was actually `0`.
+const fn encode_const_inner(
+    index: &crate::reverse_index::ReverseIndex<256>,
+    data: &str,
+) -> crate::EncodeResult {
+    /// Find a character in the charset. Returns `0` if not found or if the value was actually `0`.
     #[inline]
-    const fn find_char(chars: &'static [char; 256], ch: u32) -> u8 {
-        let mut i = 0;
-        while i < 256 {
-            if chars[i] as u32 != ch {
-                i += 1;
-                continue;
-            }
-            #[expect(clippy::cast_possible_truncation)]
-            return i as u8;
+    const fn find_char(index: &crate::reverse_index::ReverseIndex<256>, ch: u32) -> u8 {
+        match index.get(ch) {
+            Some(byte) => byte,
+            None => 0,
         }
-        0
     }
     let mut buf = [0_u8; 14];
     let mut consumed = 0;
@@ -166,7 +317,7 @@ const fn encode_const_inner(chars: &'static [char; 256], data: &str) -> crate::E
             }
             return crate::EncodeResult::Incomplete;
         }
-        let found = find_char(chars, cp);
+        let found = find_char(index, cp);
         if found == 0 && cp != 0 {
             if consumed != 0 {
                 break;
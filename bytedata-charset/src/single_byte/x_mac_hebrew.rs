@@ -0,0 +1,291 @@
+use crate::ascii7_compat::AsciiCompatible;
+
+/// The x-mac-hebrew charset.
+const X_MAC_HEBREW_CHARSET: [char; 128] = {
+    let mut charset = ['\0'; 128];
+
+    charset[0x80 & 0x7F] = '\u{00C4}';
+    charset[0x81 & 0x7F] = '\u{FB1F}';
+    charset[0x82 & 0x7F] = '\u{00C7}';
+    charset[0x83 & 0x7F] = '\u{00C9}';
+    charset[0x84 & 0x7F] = '\u{00D1}';
+    charset[0x85 & 0x7F] = '\u{00D6}';
+    charset[0x86 & 0x7F] = '\u{00DC}';
+    charset[0x87 & 0x7F] = '\u{00E1}';
+    charset[0x88 & 0x7F] = '\u{00E0}';
+    charset[0x89 & 0x7F] = '\u{00E2}';
+    charset[0x8a & 0x7F] = '\u{00E4}';
+    charset[0x8b & 0x7F] = '\u{00E3}';
+    charset[0x8c & 0x7F] = '\u{00E5}';
+    charset[0x8d & 0x7F] = '\u{00E7}';
+    charset[0x8e & 0x7F] = '\u{00E9}';
+    charset[0x8f & 0x7F] = '\u{00E8}';
+
+    charset[0x90 & 0x7F] = '\u{00EA}';
+    charset[0x91 & 0x7F] = '\u{00EB}';
+    charset[0x92 & 0x7F] = '\u{00ED}';
+    charset[0x93 & 0x7F] = '\u{00EC}';
+    charset[0x94 & 0x7F] = '\u{00EE}';
+    charset[0x95 & 0x7F] = '\u{00EF}';
+    charset[0x96 & 0x7F] = '\u{00F1}';
+    charset[0x97 & 0x7F] = '\u{00F3}';
+    charset[0x98 & 0x7F] = '\u{00F2}';
+    charset[0x99 & 0x7F] = '\u{00F4}';
+    charset[0x9a & 0x7F] = '\u{00F6}';
+    charset[0x9b & 0x7F] = '\u{00F5}';
+    charset[0x9c & 0x7F] = '\u{00FA}';
+    charset[0x9d & 0x7F] = '\u{00F9}';
+    charset[0x9e & 0x7F] = '\u{00FB}';
+    charset[0x9f & 0x7F] = '\u{00FC}';
+
+    charset[0xa0 & 0x7F] = '\u{0020}';
+    charset[0xa1 & 0x7F] = '\u{0021}';
+    charset[0xa2 & 0x7F] = '\u{0022}';
+    charset[0xa3 & 0x7F] = '\u{0023}';
+    charset[0xa4 & 0x7F] = '\u{0024}';
+    charset[0xa5 & 0x7F] = '\u{0025}';
+    charset[0xa6 & 0x7F] = '\u{20AA}';
+    charset[0xa7 & 0x7F] = '\u{0027}';
+    charset[0xa8 & 0x7F] = '\u{0029}';
+    charset[0xa9 & 0x7F] = '\u{0028}';
+    charset[0xaa & 0x7F] = '\u{002A}';
+    charset[0xab & 0x7F] = '\u{002B}';
+    charset[0xac & 0x7F] = '\u{002C}';
+    charset[0xad & 0x7F] = '\u{002D}';
+    charset[0xae & 0x7F] = '\u{002E}';
+    charset[0xaf & 0x7F] = '\u{002F}';
+
+    charset[0xb0 & 0x7F] = '\u{0030}';
+    charset[0xb1 & 0x7F] = '\u{0031}';
+    charset[0xb2 & 0x7F] = '\u{0032}';
+    charset[0xb3 & 0x7F] = '\u{0033}';
+    charset[0xb4 & 0x7F] = '\u{0034}';
+    charset[0xb5 & 0x7F] = '\u{0035}';
+    charset[0xb6 & 0x7F] = '\u{0036}';
+    charset[0xb7 & 0x7F] = '\u{0037}';
+    charset[0xb8 & 0x7F] = '\u{0038}';
+    charset[0xb9 & 0x7F] = '\u{0039}';
+    charset[0xba & 0x7F] = '\u{003A}';
+    charset[0xbb & 0x7F] = '\u{003B}';
+    charset[0xbc & 0x7F] = '\u{003C}';
+    charset[0xbd & 0x7F] = '\u{003D}';
+    charset[0xbe & 0x7F] = '\u{003E}';
+    charset[0xbf & 0x7F] = '\u{003F}';
+
+    charset[0xc0 & 0x7F] = '\u{F86A}';
+    charset[0xc1 & 0x7F] = '\u{201E}';
+    charset[0xc2 & 0x7F] = '\u{F89B}';
+    charset[0xc3 & 0x7F] = '\u{F89C}';
+    charset[0xc4 & 0x7F] = '\u{F89D}';
+    charset[0xc5 & 0x7F] = '\u{F89E}';
+    charset[0xc6 & 0x7F] = '\u{05BC}';
+    charset[0xc7 & 0x7F] = '\u{FB4B}';
+    charset[0xc8 & 0x7F] = '\u{FB35}';
+    charset[0xc9 & 0x7F] = '\u{2026}';
+    charset[0xca & 0x7F] = '\u{00A0}';
+    charset[0xcb & 0x7F] = '\u{05B8}';
+    charset[0xcc & 0x7F] = '\u{05B7}';
+    charset[0xcd & 0x7F] = '\u{05B5}';
+    charset[0xce & 0x7F] = '\u{05B6}';
+    charset[0xcf & 0x7F] = '\u{05B4}';
+
+    charset[0xd0 & 0x7F] = '\u{2013}';
+    charset[0xd1 & 0x7F] = '\u{2014}';
+    charset[0xd2 & 0x7F] = '\u{201C}';
+    charset[0xd3 & 0x7F] = '\u{201D}';
+    charset[0xd4 & 0x7F] = '\u{2018}';
+    charset[0xd5 & 0x7F] = '\u{2019}';
+    charset[0xd6 & 0x7F] = '\u{FB2A}';
+    charset[0xd7 & 0x7F] = '\u{FB2B}';
+    charset[0xd8 & 0x7F] = '\u{05BF}';
+    charset[0xd9 & 0x7F] = '\u{05B0}';
+    charset[0xda & 0x7F] = '\u{05B2}';
+    charset[0xdb & 0x7F] = '\u{05B1}';
+    charset[0xdc & 0x7F] = '\u{05BB}';
+    charset[0xdd & 0x7F] = '\u{05B9}';
+    charset[0xde & 0x7F] = '\u{05B8}';
+    charset[0xdf & 0x7F] = '\u{05B3}';
+
+    charset[0xe0 & 0x7F] = '\u{05D0}';
+    charset[0xe1 & 0x7F] = '\u{05D1}';
+    charset[0xe2 & 0x7F] = '\u{05D2}';
+    charset[0xe3 & 0x7F] = '\u{05D3}';
+    charset[0xe4 & 0x7F] = '\u{05D4}';
+    charset[0xe5 & 0x7F] = '\u{05D5}';
+    charset[0xe6 & 0x7F] = '\u{05D6}';
+    charset[0xe7 & 0x7F] = '\u{05D7}';
+    charset[0xe8 & 0x7F] = '\u{05D8}';
+    charset[0xe9 & 0x7F] = '\u{05D9}';
+    charset[0xea & 0x7F] = '\u{05DA}';
+    charset[0xeb & 0x7F] = '\u{05DB}';
+    charset[0xec & 0x7F] = '\u{05DC}';
+    charset[0xed & 0x7F] = '\u{05DD}';
+    charset[0xee & 0x7F] = '\u{05DE}';
+    charset[0xef & 0x7F] = '\u{05DF}';
+
+    charset[0xf0 & 0x7F] = '\u{05E0}';
+    charset[0xf1 & 0x7F] = '\u{05E1}';
+    charset[0xf2 & 0x7F] = '\u{05E2}';
+    charset[0xf3 & 0x7F] = '\u{05E3}';
+    charset[0xf4 & 0x7F] = '\u{05E4}';
+    charset[0xf5 & 0x7F] = '\u{05E5}';
+    charset[0xf6 & 0x7F] = '\u{05E6}';
+    charset[0xf7 & 0x7F] = '\u{05E7}';
+    charset[0xf8 & 0x7F] = '\u{05E8}';
+    charset[0xf9 & 0x7F] = '\u{05E9}';
+    charset[0xfa & 0x7F] = '\u{05EA}';
+    charset[0xfb & 0x7F] = '\u{007D}';
+    charset[0xfc & 0x7F] = '\u{005D}';
+    charset[0xfd & 0x7F] = '\u{007B}';
+    charset[0xfe & 0x7F] = '\u{005B}';
+    charset[0xff & 0x7F] = '\u{007C}';
+
+    charset
+};
+
+/// An encoding for x-mac-hebrew.
+#[cfg_attr(docsrs, doc(cfg(feature = "x-mac-hebrew")))]
+pub static X_MAC_HEBREW: XMacHebrew = XMacHebrew::new();
+
+/// A mapper from bytes over 128 to the corresponding unicode character.
+const ENCODER: AsciiCompatible = AsciiCompatible::new(
+    <XMacHebrew as crate::Charset>::CHARSET_NAME,
+    &X_MAC_HEBREW_CHARSET,
+);
+
+/// An encoding for x-mac-hebrew (Mac Hebrew).
+/// If possible use [`UTF-8`] instead.
+///
+/// The classic Mac OS used separate bytes for the left-to-right and right-to-left variants of the ASCII space, digits and punctuation.
+/// Apple maps the right-to-left variants to the same character wrapped in directional formatting characters,
+/// but this encoding maps every byte to a single character, so the right-to-left variants decode to the plain character without any directional marks.
+/// As a consequence these characters are always encoded as the ASCII bytes and decoding followed by encoding does not round-trip them.
+/// A few bytes that Apple maps to sequences including private use characters decode to their first character only.
+///
+/// [`UTF-8`]: crate::Utf8Encoding
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[expect(clippy::exhaustive_structs)]
+#[cfg_attr(docsrs, doc(cfg(feature = "x-mac-hebrew")))]
+pub struct XMacHebrew;
+
+impl XMacHebrew {
+    /// Create a new x-mac-hebrew encoding instance.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self
+    }
+
+    /// Encode characters from the given bytes.
+    #[must_use]
+    #[inline]
+    pub const fn encode_const(&self, chars: &str) -> crate::EncodeResult {
+        ENCODER.encode_const(chars)
+    }
+
+    /// Encode characters from the given bytes.
+    #[must_use]
+    #[inline]
+    pub fn encode(&self, chars: &str) -> crate::EncodeResult {
+        ENCODER.encode(chars)
+    }
+
+    /// Decode characters from the given bytes.
+    #[must_use]
+    #[inline]
+    pub const fn decode_const(&self, bytes: &[u8]) -> crate::DecodeResult {
+        ENCODER.decode_const(bytes)
+    }
+
+    /// Decode characters from the given bytes.
+    #[must_use]
+    #[inline]
+    pub fn decode(&self, bytes: &[u8]) -> crate::DecodeResult {
+        ENCODER.decode(bytes)
+    }
+
+    /// Get the generic ASCII-compatible charset encoder for this charset.
+    #[must_use]
+    #[inline]
+    pub const fn ascii_compat(&self) -> &'static AsciiCompatible {
+        &ENCODER
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "x-mac-hebrew")))]
+impl core::default::Default for XMacHebrew {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "x-mac-hebrew")))]
+impl crate::Charset for XMacHebrew {
+    const CHARSET_NAME: &'static str = "x-mac-hebrew";
+
+    #[inline]
+    fn size_hint(&self) -> (u16, u16) {
+        (1, 1)
+    }
+
+    #[inline]
+    fn charset_alias(&self) -> &[&'static str] {
+        &[
+            // Apple
+            Self::CHARSET_NAME,
+            // other
+            "x-machebrew",
+            "machebrew",
+            "cp10005",
+            "windows-10005",
+        ]
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "x-mac-hebrew")))]
+impl crate::CharsetDecoding for XMacHebrew {
+    #[inline]
+    fn decode(&self, bytes: &[u8]) -> crate::DecodeResult {
+        Self::decode(self, bytes)
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn decode_all<'a>(
+        &self,
+        bytes: bytedata::ByteData<'a>,
+        invalid: crate::DecodeInvalid,
+    ) -> Result<bytedata::StringData<'a>, crate::DecodeError> {
+        crate::CharsetDecoding::decode_all(&ENCODER, bytes, invalid)
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "x-mac-hebrew")))]
+impl crate::CharsetEncoding for XMacHebrew {
+    #[inline]
+    fn encode(&self, chars: &str) -> crate::EncodeResult {
+        Self::encode(self, chars)
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn encode_all<'a>(
+        &self,
+        chars: &'a str,
+        invalid: crate::EncodeInvalid,
+    ) -> Result<bytedata::ByteData<'a>, &'static str> {
+        crate::CharsetEncoding::encode_all(&ENCODER, chars, invalid)
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn encode_to_vec(
+        &self,
+        chars: &str,
+        invalid: crate::EncodeInvalid,
+        out: &mut alloc::vec::Vec<u8>,
+    ) -> Result<(), &'static str> {
+        crate::CharsetEncoding::encode_to_vec(&ENCODER, chars, invalid, out)
+    }
+}
@@ -98,18 +98,8 @@ impl crate::Charset for XUserDefined {
     #[inline]
     fn charset_alias(&self) -> &[&'static str] {
         &[
-            // IANA
+            // WHATWG
             Self::CHARSET_NAME,
-            "cskoi8r",
-            // code pages
-            "cp20866",
-            "windows-20866",
-            "cp878",
-            "ibm878",
-            // other
-            "koi",
-            "koi8",
-            "koi8_r",
         ]
     }
 }
@@ -120,6 +110,16 @@ impl crate::CharsetDecoding for XUserDefined {
     fn decode(&self, bytes: &[u8]) -> crate::DecodeResult {
         Self::decode(self, bytes)
     }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn decode_all<'a>(
+        &self,
+        bytes: bytedata::ByteData<'a>,
+        invalid: crate::DecodeInvalid,
+    ) -> Result<bytedata::StringData<'a>, crate::DecodeError> {
+        crate::CharsetDecoding::decode_all(&ENCODER, bytes, invalid)
+    }
 }
 
 #[cfg_attr(docsrs, doc(cfg(feature = "x-user-defined")))]
@@ -128,4 +128,25 @@ impl crate::CharsetEncoding for XUserDefined {
     fn encode(&self, chars: &str) -> crate::EncodeResult {
         Self::encode(self, chars)
     }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn encode_all<'a>(
+        &self,
+        chars: &'a str,
+        invalid: crate::EncodeInvalid,
+    ) -> Result<bytedata::ByteData<'a>, &'static str> {
+        crate::CharsetEncoding::encode_all(&ENCODER, chars, invalid)
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn encode_to_vec(
+        &self,
+        chars: &str,
+        invalid: crate::EncodeInvalid,
+        out: &mut alloc::vec::Vec<u8>,
+    ) -> Result<(), &'static str> {
+        crate::CharsetEncoding::encode_to_vec(&ENCODER, chars, invalid, out)
+    }
 }
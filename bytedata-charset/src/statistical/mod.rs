@@ -0,0 +1,634 @@
+//! ## Statistical charset detection
+//!
+//! Distinguishes single-byte charsets that are structurally equal, such as windows-1251, KOI8-R and ISO-8859-5, by how likely the decoded text is in a language.
+//! See the [`StatisticalDetector`] struct for more information.
+
+mod models;
+
+use crate::detect::{Confidence, DetectionResult};
+
+/// The average bigram score below which a charset is irrelevant, as the decoded text is unlikely to be in any language.
+///
+/// Short sentences score much lower than the corpus the models were built from, as a few rare bigrams weigh heavily,
+/// so this is set below the scores of correctly decoded sentences of eight to twenty non-ASCII bigrams.
+const IRRELEVANT_BELOW: u8 = 60;
+
+/// The number of scored bigrams of the best scored charset needed for any charset to be irrelevant because of a low score.
+const MIN_EVIDENCE: u32 = 8;
+
+/// The number of scored bigrams needed for a charset to be certain.
+const CERTAIN_EVIDENCE: u32 = 16;
+
+/// The difference in average bigram score to the next charset needed for a charset to be certain.
+const CERTAIN_MARGIN: u8 = 16;
+
+/// The number of charsets enabled through cargo features, which is an upper bound for the number of candidates.
+const MAX_CANDIDATES: usize = crate::CharsetRegistry::charsets().len();
+
+/// A script of the letters of a language model.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Script {
+    Latin,
+    Greek,
+    Cyrillic,
+    Hebrew,
+    Arabic,
+    Thai,
+}
+
+impl Script {
+    const ALL: [Self; 6] = [
+        Self::Latin,
+        Self::Greek,
+        Self::Cyrillic,
+        Self::Hebrew,
+        Self::Arabic,
+        Self::Thai,
+    ];
+
+    /// The script of a letter.
+    const fn of(ch: char) -> Option<Self> {
+        match ch as u32 {
+            0x0000..=0x024F | 0x1E00..=0x1EFF => Some(Self::Latin),
+            0x0370..=0x03FF => Some(Self::Greek),
+            0x0400..=0x052F => Some(Self::Cyrillic),
+            0x0590..=0x05FF => Some(Self::Hebrew),
+            0x0600..=0x06FF | 0x0750..=0x077F | 0xFB50..=0xFDFF | 0xFE70..=0xFEFF => {
+                Some(Self::Arabic)
+            }
+            0x0E00..=0x0E7F => Some(Self::Thai),
+            _ => None,
+        }
+    }
+}
+
+/// A bigram model of the letters of a language.
+struct LanguageModel {
+    /// The language code of the model.
+    language: &'static str,
+    /// The script of the letters.
+    script: Script,
+    /// The lowercase letters of the language, sorted by code point.
+    alphabet: &'static [char],
+    /// The scores of each bigram of classes, where class `0` is a word boundary, `1..=alphabet.len()` are the letters of the alphabet and the last class is any other letter.
+    bigrams: &'static [u8],
+}
+
+impl LanguageModel {
+    /// The number of classes of the model.
+    const fn classes(&self) -> usize {
+        self.alphabet.len() + 2
+    }
+
+    /// The class of a character.
+    fn class(&self, ch: char) -> usize {
+        let lower = ch.to_lowercase().next().unwrap_or(ch);
+        match self.alphabet.binary_search(&lower) {
+            Ok(idx) => idx + 1,
+            Err(_) if ch.is_alphabetic() => self.classes() - 1,
+            Err(_) => 0,
+        }
+    }
+
+    /// Returns `true` if all letters of the language can be decoded by a charset.
+    fn is_supported_by(&self, decodable: &[char]) -> bool {
+        self.alphabet
+            .iter()
+            .all(|ch| ch.is_ascii() || decodable.binary_search(ch).is_ok())
+    }
+
+    /// Score decoded text, returning the sum of the bigram scores and the number of bigrams scored.
+    ///
+    /// Only bigrams with a non-ASCII character are scored, as the others decode the same in all ASCII compatible charsets.
+    /// Symbols that are not expected in text and a lowercase letter followed by an uppercase letter score `0`.
+    fn score<I: Iterator<Item = char>>(&self, chars: I) -> (u64, u32) {
+        let classes = self.classes();
+        let mut total = 0_u64;
+        let mut count = 0_u32;
+        let mut prev = ' ';
+        let mut prev_class = 0;
+        for ch in chars {
+            let class = self.class(ch);
+            if is_unexpected_symbol(ch) {
+                count += 1;
+            } else if (!ch.is_ascii() || !prev.is_ascii()) && (class != 0 || prev_class != 0) {
+                count += 1;
+                let case_change =
+                    prev_class != 0 && class != 0 && prev.is_lowercase() && ch.is_uppercase();
+                if !case_change {
+                    total += u64::from(
+                        self.bigrams
+                            .get(prev_class * classes + class)
+                            .copied()
+                            .unwrap_or_default(),
+                    );
+                }
+            } else {
+                // ASCII and word boundaries are not scored
+            }
+            prev = ch;
+            prev_class = class;
+        }
+        (total, count)
+    }
+}
+
+/// Returns `true` for non-ASCII symbols that are not expected in text, such as box drawing characters or C1 controls.
+fn is_unexpected_symbol(ch: char) -> bool {
+    if ch.is_ascii() || ch.is_alphabetic() {
+        return false;
+    }
+    !matches!(ch as u32,
+        0x00A0 | 0x00A1 | 0x00A2..=0x00A5 | 0x00A7 | 0x00A9 | 0x00AB | 0x00AE | 0x00B0 | 0x00B1 | 0x00B7 | 0x00BB | 0x00BF | 0x00D7 | 0x00F7
+        // general punctuation, currency symbols and letterlike symbols
+        | 0x2000..=0x206F | 0x20A0..=0x20CF | 0x2100..=0x214F
+    )
+}
+
+/// The statistical score of a charset for some data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CharsetScore {
+    score: u8,
+    evidence: u32,
+    language: Option<&'static str>,
+}
+
+impl CharsetScore {
+    /// The average score of the scored bigrams of the decoded text, where higher is more likely.
+    ///
+    /// Text in a language the detector has a model for usually scores between 110 and 160.
+    #[inline]
+    #[must_use]
+    pub const fn score(&self) -> u8 {
+        self.score
+    }
+
+    /// The number of bigrams that were scored.
+    ///
+    /// Only bigrams with a non-ASCII character are scored, so pure ASCII data has no evidence for any charset.
+    #[inline]
+    #[must_use]
+    pub const fn evidence(&self) -> u32 {
+        self.evidence
+    }
+
+    /// The code of the language the decoded text is most likely in, if any language model matched.
+    #[inline]
+    #[must_use]
+    pub const fn language(&self) -> Option<&'static str> {
+        self.language
+    }
+
+    /// The confidence of the charset, given the best score and evidence of all candidates and whether the best charset is certain.
+    ///
+    /// The evidence of the best charset decides whether a low score is meaningful,
+    /// as a wrong decoding often has fewer scored bigrams than the correct one and must not be kept for that.
+    const fn confidence(&self, best: (u8, u32), certain: bool) -> Confidence {
+        if self.evidence == 0 {
+            return Confidence::Tentative;
+        }
+        if best.1 >= MIN_EVIDENCE && self.score < IRRELEVANT_BELOW {
+            return Confidence::Irrelevant;
+        }
+        if certain && self.score == best.0 && self.evidence >= CERTAIN_EVIDENCE {
+            return Confidence::Certain;
+        }
+        if certain && self.score.saturating_add(CERTAIN_MARGIN) <= best.0 {
+            return Confidence::Irrelevant;
+        }
+        Confidence::Tentative
+    }
+}
+
+/// A detector that ranks the enabled single-byte charsets by how likely the decoded text is in one of the languages it has a model for.
+///
+/// The structural detectors can not tell apart single-byte charsets that use the same byte ranges, such as windows-1251, KOI8-R and ISO-8859-5 for Cyrillic or windows-1252 and ISO-8859-2 for Latin.
+/// This detector decodes the data with every candidate and scores the bigrams of the letters with the language models of the script most of the letters are in,
+/// similar to how [chardetng] and [uchardet] work.
+/// Models are compiled into the crate for most European languages, Greek, Hebrew, Arabic, Persian and Thai.
+///
+/// A charset is [`DetectionResult::Irrelevant`] if the data contains a byte it can not decode or a control character not expected in text, or if the decoded text scores low.
+/// The best charset is [`DetectionResult::Certain`] if there is enough non-ASCII text and no other charset with a different decoding scores close to it.
+/// Data without non-ASCII letters gives no evidence, which leaves all charsets that can decode it [`DetectionResult::Tentative`].
+///
+/// The candidates are the charsets in the [`CharsetRegistry`] that use one byte per character, except for x-user-defined and replacement.
+///
+/// [chardetng]: https://github.com/hsivonen/chardetng
+/// [uchardet]: https://www.freedesktop.org/wiki/Software/uchardet/
+/// [`CharsetRegistry`]: crate::CharsetRegistry
+#[cfg_attr(docsrs, doc(cfg(feature = "statistical-detect")))]
+#[derive(Debug, Clone, Copy, Default)]
+#[non_exhaustive]
+pub struct StatisticalDetector;
+
+impl StatisticalDetector {
+    /// Create a new statistical detector.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self
+    }
+
+    /// Score a charset for the given bytes.
+    ///
+    /// Returns `None` if the charset can not decode the bytes or they decode to a control character that is not expected in text.
+    /// This is meant for single-byte charsets, such as an [`OwnedSingleByteEncoding`] that is not in the [`CharsetRegistry`].
+    ///
+    /// [`OwnedSingleByteEncoding`]: crate::single_byte::OwnedSingleByteEncoding
+    /// [`CharsetRegistry`]: crate::CharsetRegistry
+    #[must_use]
+    #[expect(clippy::missing_inline_in_public_items)]
+    pub fn score(&self, charset: &dyn crate::CharsetCodec, bytes: &[u8]) -> Option<CharsetScore> {
+        let mut scripts = [0_u32; Script::ALL.len()];
+        let valid = for_each_char(charset, bytes, |ch| {
+            if !ch.is_ascii() && ch.is_alphabetic() {
+                if let Some(script) = Script::of(ch) {
+                    scripts[script as usize] += 1;
+                }
+            }
+        });
+        if !valid {
+            return None;
+        }
+        let mut script = None;
+        let mut most = 0;
+        for (candidate, count) in Script::ALL.iter().zip(scripts) {
+            if count > most {
+                script = Some(*candidate);
+                most = count;
+            }
+        }
+        let Some(script) = script else {
+            return Some(CharsetScore {
+                score: 0,
+                evidence: 0,
+                language: None,
+            });
+        };
+
+        // the characters of each byte, sorted to look up which letters the charset supports
+        let mut decodable = ['\0'; 256];
+        for (byte, ch) in (0..=u8::MAX).zip(decodable.iter_mut()) {
+            if let crate::DecodeResult::Char(decoded, 1) =
+                charset.decode_stateful(&mut crate::CharsetState::new(), &[byte])
+            {
+                *ch = decoded;
+            }
+        }
+        decodable.sort_unstable();
+
+        let mut best = CharsetScore {
+            score: 0,
+            evidence: 0,
+            language: None,
+        };
+        for model in models::MODELS {
+            if model.script != script || !model.is_supported_by(&decodable) {
+                continue;
+            }
+            let mut chars = CharBuffer::new(charset, bytes);
+            let (total, count) = model.score(core::iter::from_fn(|| chars.next()));
+            if count == 0 {
+                continue;
+            }
+            #[expect(clippy::integer_division)]
+            let average = u8::try_from(total / u64::from(count)).unwrap_or(u8::MAX);
+            if best.language.is_none() || average > best.score {
+                best = CharsetScore {
+                    score: average,
+                    evidence: count,
+                    language: Some(model.language),
+                };
+            }
+        }
+        Some(best)
+    }
+
+    /// Select the most likely single-byte charset for the given bytes, together with its detection result.
+    #[must_use]
+    #[expect(clippy::missing_inline_in_public_items)]
+    pub fn select_charset(
+        &self,
+        bytes: &[u8],
+    ) -> (&'static dyn crate::CharsetCodec, DetectionResult) {
+        let mut best: Option<(&'static dyn crate::CharsetCodec, DetectionResult, u8)> = None;
+        self.for_each_result(bytes, |charset, result, score| {
+            let rank = (result_rank(result), u8::MAX - score);
+            if best.is_none_or(|entry| rank < (result_rank(entry.1), u8::MAX - entry.2)) {
+                best = Some((charset, result, score));
+            }
+        });
+        best.map_or((&crate::UTF8, DetectionResult::Irrelevant), |entry| {
+            (entry.0, entry.1)
+        })
+    }
+
+    /// Rank all single-byte charsets for the given bytes, starting with the most likely one.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    #[must_use]
+    #[expect(clippy::missing_inline_in_public_items)]
+    pub fn rank(
+        &self,
+        bytes: &[u8],
+    ) -> alloc::vec::Vec<(&'static dyn crate::CharsetCodec, DetectionResult)> {
+        let mut ranked = alloc::vec::Vec::new();
+        self.for_each_result(bytes, |charset, result, score| {
+            ranked.push(((result_rank(result), u8::MAX - score), charset, result));
+        });
+        ranked.sort_by_key(|entry| entry.0);
+        ranked.into_iter().map(|entry| (entry.1, entry.2)).collect()
+    }
+
+    /// Detect the bytes with every single-byte charset, calling `visit` with each charset, its result and its score.
+    pub(crate) fn for_each_result<F>(self, bytes: &[u8], mut visit: F)
+    where
+        F: FnMut(&'static dyn crate::CharsetCodec, DetectionResult, u8),
+    {
+        let mut scores: [Option<(&'static dyn crate::CharsetCodec, Option<CharsetScore>)>;
+            MAX_CANDIDATES] = [None; MAX_CANDIDATES];
+        let candidates = crate::CharsetRegistry::charsets()
+            .iter()
+            .copied()
+            .filter(|charset| crate::web_detect::is_single_byte_candidate(*charset));
+        for (charset, slot) in candidates.zip(scores.iter_mut()) {
+            *slot = Some((charset, self.score(charset, bytes)));
+        }
+
+        let mut best = (0, 0);
+        for score in scores.iter().flatten().filter_map(|entry| entry.1) {
+            if score.evidence != 0 {
+                best = best.max((score.score, score.evidence));
+            }
+        }
+        let mut runner_up = 0;
+        for score in scores.iter().flatten().filter_map(|entry| entry.1) {
+            if score.evidence != 0 && score.score < best.0 {
+                runner_up = runner_up.max(score.score);
+            }
+        }
+        let certain = best.1 >= CERTAIN_EVIDENCE && best.0 - runner_up >= CERTAIN_MARGIN;
+
+        for entry in scores.iter().flatten() {
+            let result = match entry.1 {
+                _ if bytes.is_empty() => DetectionResult::Incomplete,
+                Some(score) => DetectionResult::from_confidence(score.confidence(best, certain)),
+                None => DetectionResult::Irrelevant,
+            };
+            visit(entry.0, result, entry.1.map_or(0, |score| score.score));
+        }
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "statistical-detect")))]
+impl crate::detect::CharsetDetector for StatisticalDetector {
+    #[inline]
+    fn detect(&self, bytes: &[u8]) -> DetectionResult {
+        self.select_charset(bytes).1
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "statistical-detect")))]
+impl crate::detect::CharsetSelector for StatisticalDetector {
+    type Charset = &'static dyn crate::CharsetCodec;
+
+    #[inline]
+    fn select(&self, bytes: &[u8]) -> (Self::Charset, DetectionResult) {
+        self.select_charset(bytes)
+    }
+}
+
+/// The rank of a detection result, where lower is more likely.
+const fn result_rank(result: DetectionResult) -> u8 {
+    match result {
+        DetectionResult::Certain => 0,
+        DetectionResult::Tentative => 1,
+        DetectionResult::Incomplete => 2,
+        DetectionResult::Irrelevant => 3,
+    }
+}
+
+/// Decode the bytes with a charset, calling `visit` with each character.
+///
+/// Returns `false` if a byte sequence is invalid or decodes to a control character that is not expected in text.
+fn for_each_char<F: FnMut(char)>(
+    charset: &dyn crate::CharsetCodec,
+    bytes: &[u8],
+    mut visit: F,
+) -> bool {
+    let mut chars = CharBuffer::new(charset, bytes);
+    while let Some(ch) = chars.next() {
+        if crate::web_detect::is_unexpected_control(ch) {
+            return false;
+        }
+        visit(ch);
+    }
+    !chars.invalid
+}
+
+/// Decodes bytes one character at a time.
+struct CharBuffer<'a> {
+    charset: &'a dyn crate::CharsetCodec,
+    state: crate::CharsetState,
+    rest: &'a [u8],
+    /// Whether the charset decodes ASCII bytes as ASCII.
+    ascii_compatible: bool,
+    /// The rest of a run of ASCII characters.
+    pending: core::str::Chars<'a>,
+    /// The second character of a pair.
+    second: Option<char>,
+    invalid: bool,
+}
+
+impl<'a> CharBuffer<'a> {
+    fn new(charset: &'a dyn crate::CharsetCodec, bytes: &'a [u8]) -> Self {
+        let ascii_compatible = matches!(
+            charset.decode_stateful(&mut crate::CharsetState::new(), b"A"),
+            crate::DecodeResult::Utf8(1) | crate::DecodeResult::Char('A', 1)
+        );
+        Self {
+            charset,
+            state: crate::CharsetState::new(),
+            rest: bytes,
+            ascii_compatible,
+            pending: "".chars(),
+            second: None,
+            invalid: false,
+        }
+    }
+
+    fn next(&mut self) -> Option<char> {
+        if let Some(ch) = self.pending.next().or_else(|| self.second.take()) {
+            return Some(ch);
+        }
+        if self.rest.is_empty() || self.invalid {
+            return None;
+        }
+        let (ch, len) = match self.charset.decode_stateful(&mut self.state, self.rest) {
+            crate::DecodeResult::Utf8(len) => {
+                #[expect(clippy::cast_possible_truncation)]
+                let len = len as usize;
+                let (text, rest) = self.rest.split_at_checked(len).unwrap_or((self.rest, &[]));
+                self.rest = rest;
+                self.pending = core::str::from_utf8(text).unwrap_or_default().chars();
+                return self.pending.next();
+            }
+            // bytes outside ASCII that decode to ASCII, such as the right-to-left punctuation of Mac OS Hebrew and Arabic, are not expected in text
+            crate::DecodeResult::Char(ch, len)
+                if self.ascii_compatible
+                    && ch.is_ascii()
+                    && self.rest.first().is_some_and(|byte| !byte.is_ascii()) =>
+            {
+                (char::REPLACEMENT_CHARACTER, len)
+            }
+            crate::DecodeResult::Char(ch, len) => (ch, len),
+            crate::DecodeResult::CharPair(ch, second, len) => {
+                self.second = Some(second);
+                (ch, len)
+            }
+            crate::DecodeResult::InvalidChar(_, _) => {
+                self.invalid = true;
+                return None;
+            }
+            crate::DecodeResult::Empty | crate::DecodeResult::Incomplete => return None,
+        };
+        self.rest = self.rest.get(len as usize..).unwrap_or_default();
+        Some(ch)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_models() {
+        for model in models::MODELS {
+            assert_eq!(
+                model.bigrams.len(),
+                model.classes() * model.classes(),
+                "bigrams of {}",
+                model.language
+            );
+            assert!(
+                model.alphabet.is_sorted_by(|prev, next| prev < next),
+                "alphabet of {} is sorted",
+                model.language
+            );
+            assert!(
+                model
+                    .alphabet
+                    .iter()
+                    .all(|ch| ch.is_ascii() || Script::of(*ch) == Some(model.script)),
+                "alphabet of {} is in its script",
+                model.language
+            );
+        }
+    }
+
+    #[cfg(all(
+        feature = "windows-1251",
+        feature = "koi8-r",
+        feature = "iso-8859-5",
+        feature = "ibm866"
+    ))]
+    #[test]
+    fn test_cyrillic() {
+        // "Съешь же ещё этих мягких французских булок, да выпей чаю." in each charset
+        let texts: [(&str, &[u8]); 4] = [
+            ("windows-1251", b"\xD1\xFA\xE5\xF8\xFC \xE6\xE5 \xE5\xF9\xB8 \xFD\xF2\xE8\xF5 \xEC\xFF\xE3\xEA\xE8\xF5 \xF4\xF0\xE0\xED\xF6\xF3\xE7\xF1\xEA\xE8\xF5 \xE1\xF3\xEB\xEE\xEA, \xE4\xE0 \xE2\xFB\xEF\xE5\xE9 \xF7\xE0\xFE."),
+            ("koi8-r", b"\xF3\xDF\xC5\xDB\xD8 \xD6\xC5 \xC5\xDD\xA3 \xDC\xD4\xC9\xC8 \xCD\xD1\xC7\xCB\xC9\xC8 \xC6\xD2\xC1\xCE\xC3\xD5\xDA\xD3\xCB\xC9\xC8 \xC2\xD5\xCC\xCF\xCB, \xC4\xC1 \xD7\xD9\xD0\xC5\xCA \xDE\xC1\xC0."),
+            ("iso-8859-5", b"\xC1\xEA\xD5\xE8\xEC \xD6\xD5 \xD5\xE9\xF1 \xED\xE2\xD8\xE5 \xDC\xEF\xD3\xDA\xD8\xE5 \xE4\xE0\xD0\xDD\xE6\xE3\xD7\xE1\xDA\xD8\xE5 \xD1\xE3\xDB\xDE\xDA, \xD4\xD0 \xD2\xEB\xDF\xD5\xD9 \xE7\xD0\xEE."),
+            ("ibm866", b"\x91\xEA\xA5\xE8\xEC \xA6\xA5 \xA5\xE9\xF1 \xED\xE2\xA8\xE5 \xAC\xEF\xA3\xAA\xA8\xE5 \xE4\xE0\xA0\xAD\xE6\xE3\xA7\xE1\xAA\xA8\xE5 \xA1\xE3\xAB\xAE\xAA, \xA4\xA0 \xA2\xEB\xAF\xA5\xA9 \xE7\xA0\xEE."),
+        ];
+        let detector = StatisticalDetector::new();
+        for (name, bytes) in texts {
+            let (charset, result) = detector.select_charset(bytes);
+            assert_eq!(
+                (crate::CharsetRegistry::canonical_name(charset), result),
+                (name, DetectionResult::Certain)
+            );
+            assert_eq!(
+                detector
+                    .score(charset, bytes)
+                    .and_then(|score| score.language()),
+                Some("ru")
+            );
+        }
+    }
+
+    #[cfg(all(feature = "windows-1252", feature = "iso-8859-2"))]
+    #[test]
+    fn test_latin() {
+        let detector = StatisticalDetector::new();
+        // "Zażółć gęślą jaźń, pchnąć w tę łódź jeża" in ISO-8859-2
+        let polish = b"Za\xBF\xF3\xB3\xE6 g\xEA\xB6l\xB1 ja\xBC\xF1, pchn\xB1\xE6 w t\xEA \xB3\xF3d\xBC je\xBFa";
+        let (charset, result) = detector.select_charset(polish);
+        assert_eq!(
+            crate::CharsetRegistry::canonical_name(charset),
+            "iso-8859-2"
+        );
+        assert!(!result.is_irrelevant());
+
+        let windows = crate::CharsetRegistry::lookup("windows-1252");
+        let Some(windows) = windows else {
+            unreachable!("windows-1252 is enabled");
+        };
+        // "Größere Übungen für Äpfel" in windows-1252
+        let german = b"Gr\xF6\xDFere \xDCbungen f\xFCr \xC4pfel";
+        let german = detector.score(windows, german);
+        assert_eq!(german.and_then(|score| score.language()), Some("de"));
+        assert!(german.is_some_and(|score| score.score() >= IRRELEVANT_BELOW));
+
+        let ascii = detector.score(windows, b"plain ascii");
+        assert_eq!(ascii.map(|score| score.evidence()), Some(0));
+    }
+
+    #[cfg(all(
+        feature = "windows-1252",
+        feature = "macintosh",
+        feature = "iso-8859-2"
+    ))]
+    #[test]
+    fn test_short_text() {
+        let detector = StatisticalDetector::new();
+        let result_of = |bytes: &[u8], name: &str| {
+            let mut found = None;
+            detector.for_each_result(bytes, |charset, result, _| {
+                if crate::CharsetRegistry::canonical_name(charset) == name {
+                    found = Some(result);
+                }
+            });
+            found
+        };
+
+        // "Ceci est un texte français très simple à lire, n'est-ce pas ? Où est la forêt ?" in windows-1252
+        let french = b"Ceci est un texte fran\xE7ais tr\xE8s simple \xE0 lire, n'est-ce pas ? O\xF9 est la for\xEAt ?";
+        let (charset, result) = detector.select_charset(french);
+        assert_eq!(
+            detector
+                .score(charset, french)
+                .and_then(|score| score.language()),
+            Some("fr")
+        );
+        assert!(!result.is_irrelevant());
+        assert_eq!(
+            result_of(french, "windows-1252"),
+            Some(DetectionResult::Tentative)
+        );
+        // the macintosh decoding has fewer scored bigrams, which must not keep it from being irrelevant
+        assert_eq!(
+            result_of(french, "macintosh"),
+            Some(DetectionResult::Irrelevant)
+        );
+
+        // "Zażółć gęślą jaźń" in ISO-8859-2
+        let polish = b"Za\xBF\xF3\xB3\xE6 g\xEA\xB6l\xB1 ja\xBC\xF1";
+        let (polish_charset, polish_result) = detector.select_charset(polish);
+        assert_eq!(
+            crate::CharsetRegistry::canonical_name(polish_charset),
+            "iso-8859-2"
+        );
+        assert_eq!(polish_result, DetectionResult::Tentative);
+    }
+}
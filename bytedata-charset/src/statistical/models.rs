@@ -0,0 +1,2677 @@
+//! Language models of the statistical detector.
+//!
+//! Each model lists the lowercase letters of the language and scores every bigram of a word boundary, a letter or any other letter.
+//! The scores are `255 + 12 * log2(p)` for the probability `p` of the bigram, so that each step of 12 halves the probability, with `0` for bigrams that were never seen.
+//! Generated by `tools/gen_language_models.rs` from the gettext message catalogs and translated manual pages of a Linux distribution, do not edit.
+
+use super::{LanguageModel, Script};
+
+pub(super) static MODELS: &[LanguageModel] = &[
+    LanguageModel {
+        language: "ru",
+        script: Script::Cyrillic,
+        alphabet: &[
+            '\u{0430}', '\u{0431}', '\u{0432}', '\u{0433}', '\u{0434}', '\u{0435}', '\u{0436}',
+            '\u{0437}', '\u{0438}', '\u{0439}', '\u{043A}', '\u{043B}', '\u{043C}', '\u{043D}',
+            '\u{043E}', '\u{043F}', '\u{0440}', '\u{0441}', '\u{0442}', '\u{0443}', '\u{0444}',
+            '\u{0445}', '\u{0446}', '\u{0447}', '\u{0448}', '\u{0449}', '\u{044A}', '\u{044B}',
+            '\u{044C}', '\u{044D}', '\u{044E}', '\u{044F}', '\u{0451}',
+        ],
+        bigrams: &[
+            0, 150, 156, 175, 135, 168, 132, 110, 160, 171, 75, 167, 134, 152, 178, 170, 181, 160,
+            179, 158, 161, 157, 117, 121, 141, 121, 68, 0, 70, 0, 141, 88, 126, 41, 0, 178, 82,
+            142, 153, 131, 142, 146, 131, 157, 93, 157, 148, 161, 149, 171, 69, 145, 155, 146, 169,
+            109, 100, 121, 137, 142, 112, 112, 0, 0, 0, 36, 125, 147, 104, 0, 108, 137, 56, 47, 27,
+            84, 129, 15, 54, 123, 0, 133, 143, 55, 119, 142, 39, 139, 97, 41, 135, 27, 103, 106,
+            34, 15, 118, 120, 138, 39, 36, 15, 84, 61, 0, 165, 165, 0, 109, 46, 83, 159, 0, 63,
+            145, 0, 135, 143, 110, 138, 159, 100, 129, 126, 115, 131, 0, 107, 0, 3, 88, 85, 0, 154,
+            78, 15, 15, 96, 69, 0, 124, 126, 60, 74, 94, 104, 119, 0, 0, 128, 0, 47, 112, 97, 117,
+            153, 27, 138, 71, 47, 128, 15, 49, 15, 57, 0, 0, 0, 34, 0, 34, 27, 15, 27, 0, 136, 165,
+            34, 114, 79, 124, 162, 108, 77, 145, 0, 115, 152, 87, 135, 154, 113, 127, 119, 77, 135,
+            34, 83, 75, 76, 82, 0, 0, 115, 64, 39, 56, 86, 95, 0, 182, 100, 116, 144, 138, 154,
+            123, 136, 130, 119, 140, 152, 159, 156, 178, 127, 130, 172, 159, 168, 90, 92, 104, 120,
+            121, 118, 128, 0, 0, 0, 54, 80, 84, 82, 0, 80, 123, 84, 15, 15, 127, 148, 0, 0, 146, 0,
+            97, 22, 54, 140, 73, 42, 15, 68, 3, 97, 0, 0, 0, 0, 34, 0, 0, 0, 36, 22, 0, 0, 71, 0,
+            134, 162, 92, 129, 42, 137, 107, 48, 53, 122, 0, 103, 95, 142, 140, 148, 15, 108, 84,
+            15, 130, 0, 0, 60, 49, 0, 0, 3, 126, 44, 0, 3, 121, 15, 0, 176, 124, 139, 144, 128,
+            133, 160, 94, 148, 146, 147, 145, 145, 161, 155, 114, 131, 148, 163, 162, 61, 129, 122,
+            133, 138, 121, 90, 0, 0, 0, 0, 133, 159, 58, 0, 170, 36, 42, 57, 46, 111, 77, 0, 46,
+            57, 0, 101, 151, 79, 99, 92, 41, 65, 128, 131, 22, 39, 15, 59, 76, 68, 0, 0, 39, 0, 0,
+            39, 53, 0, 0, 150, 170, 66, 88, 30, 39, 135, 89, 51, 156, 0, 72, 145, 60, 110, 165, 44,
+            136, 138, 138, 137, 27, 65, 133, 15, 61, 0, 0, 34, 41, 85, 46, 15, 3, 0, 147, 154, 113,
+            54, 87, 63, 159, 127, 27, 163, 0, 112, 103, 66, 132, 163, 48, 0, 98, 72, 129, 58, 34,
+            0, 117, 3, 3, 0, 120, 165, 36, 141, 157, 106, 0, 157, 153, 102, 130, 41, 46, 168, 0, 0,
+            139, 0, 83, 84, 123, 119, 154, 117, 22, 91, 22, 123, 27, 34, 34, 22, 39, 0, 0, 136, 39,
+            46, 30, 135, 87, 0, 149, 168, 46, 85, 112, 143, 176, 36, 100, 175, 0, 122, 55, 46, 157,
+            172, 34, 41, 136, 149, 133, 122, 82, 99, 94, 69, 0, 0, 164, 110, 3, 92, 129, 96, 0,
+            173, 75, 157, 172, 154, 161, 145, 147, 145, 120, 149, 155, 170, 157, 150, 121, 151,
+            158, 161, 157, 78, 83, 107, 119, 137, 138, 91, 0, 0, 0, 74, 42, 115, 30, 0, 125, 153,
+            22, 30, 0, 3, 151, 0, 15, 144, 0, 92, 116, 27, 95, 172, 112, 168, 87, 93, 142, 15, 41,
+            93, 22, 41, 0, 0, 108, 75, 15, 56, 90, 0, 0, 146, 174, 81, 128, 120, 96, 170, 135, 61,
+            156, 0, 113, 86, 132, 137, 168, 89, 115, 130, 131, 146, 90, 119, 67, 68, 116, 41, 0,
+            139, 84, 36, 51, 116, 75, 0, 149, 136, 110, 114, 79, 94, 145, 97, 0, 153, 0, 156, 151,
+            122, 112, 156, 153, 110, 137, 175, 133, 57, 109, 90, 101, 108, 0, 39, 122, 145, 22, 58,
+            153, 72, 0, 165, 165, 77, 146, 27, 78, 161, 0, 73, 160, 0, 137, 102, 98, 142, 166, 95,
+            160, 154, 71, 133, 73, 60, 0, 100, 15, 3, 0, 130, 169, 48, 58, 83, 66, 0, 147, 99, 114,
+            97, 114, 150, 140, 124, 113, 69, 109, 138, 125, 136, 126, 66, 135, 131, 146, 138, 34,
+            102, 74, 51, 125, 94, 127, 0, 0, 0, 78, 135, 64, 0, 0, 71, 151, 0, 0, 41, 0, 109, 0, 0,
+            133, 22, 15, 104, 49, 46, 133, 3, 109, 56, 85, 118, 90, 15, 0, 0, 0, 0, 0, 30, 54, 0,
+            0, 3, 0, 0, 151, 100, 36, 87, 0, 15, 103, 0, 0, 118, 0, 3, 41, 58, 85, 134, 0, 110, 75,
+            49, 75, 39, 36, 0, 0, 15, 0, 0, 15, 22, 58, 0, 15, 0, 0, 113, 120, 27, 91, 34, 15, 133,
+            0, 22, 151, 0, 93, 15, 0, 3, 93, 78, 0, 51, 15, 94, 0, 27, 30, 0, 0, 0, 0, 112, 0, 0,
+            15, 0, 0, 0, 114, 141, 0, 15, 0, 15, 153, 48, 0, 145, 0, 114, 78, 34, 132, 89, 22, 36,
+            54, 128, 80, 0, 27, 0, 34, 66, 0, 0, 0, 75, 0, 0, 0, 109, 0, 99, 120, 3, 68, 0, 0, 131,
+            0, 0, 145, 0, 116, 76, 39, 106, 92, 15, 86, 78, 80, 76, 0, 0, 0, 15, 0, 0, 0, 15, 63,
+            0, 0, 27, 84, 0, 51, 115, 0, 0, 0, 0, 144, 0, 0, 132, 0, 0, 0, 0, 39, 0, 0, 0, 0, 0,
+            80, 0, 0, 0, 0, 0, 0, 0, 0, 88, 0, 0, 0, 95, 0, 0, 0, 0, 0, 0, 0, 118, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 76, 61, 0, 157, 0, 107,
+            136, 76, 106, 142, 61, 102, 0, 153, 115, 124, 128, 61, 0, 122, 118, 96, 136, 0, 0, 144,
+            22, 104, 101, 34, 0, 0, 0, 15, 0, 47, 0, 0, 174, 0, 61, 54, 58, 55, 80, 0, 148, 42, 0,
+            125, 0, 76, 144, 66, 42, 3, 125, 113, 0, 46, 0, 82, 44, 112, 22, 0, 0, 0, 0, 106, 90,
+            44, 0, 64, 3, 22, 49, 56, 42, 0, 0, 34, 15, 57, 102, 119, 70, 80, 0, 56, 73, 77, 131,
+            27, 75, 34, 15, 0, 88, 0, 0, 0, 0, 0, 0, 0, 0, 0, 144, 15, 80, 0, 27, 65, 0, 80, 44,
+            27, 27, 64, 34, 44, 69, 15, 34, 77, 64, 124, 0, 15, 0, 15, 139, 0, 131, 0, 0, 0, 22,
+            69, 0, 0, 0, 179, 15, 51, 117, 52, 105, 129, 47, 117, 0, 60, 56, 69, 109, 117, 30, 60,
+            93, 82, 130, 39, 15, 99, 89, 68, 3, 91, 0, 0, 0, 39, 108, 103, 0, 0, 97, 0, 0, 39, 22,
+            47, 0, 44, 44, 0, 22, 41, 69, 84, 126, 0, 0, 87, 58, 115, 0, 0, 59, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+    },
+    LanguageModel {
+        language: "uk",
+        script: Script::Cyrillic,
+        alphabet: &[
+            '\u{0430}', '\u{0431}', '\u{0432}', '\u{0433}', '\u{0434}', '\u{0435}', '\u{0436}',
+            '\u{0437}', '\u{0438}', '\u{0439}', '\u{043A}', '\u{043B}', '\u{043C}', '\u{043D}',
+            '\u{043E}', '\u{043F}', '\u{0440}', '\u{0441}', '\u{0442}', '\u{0443}', '\u{0444}',
+            '\u{0445}', '\u{0446}', '\u{0447}', '\u{0448}', '\u{0449}', '\u{044C}', '\u{044E}',
+            '\u{044F}', '\u{0454}', '\u{0456}', '\u{0457}', '\u{0491}',
+        ],
+        bigrams: &[
+            0, 159, 162, 180, 139, 173, 128, 116, 173, 55, 111, 168, 144, 165, 177, 160, 183, 164,
+            171, 161, 156, 156, 123, 141, 146, 129, 136, 0, 93, 154, 141, 157, 101, 63, 0, 182,
+            103, 149, 150, 138, 144, 76, 126, 154, 0, 158, 148, 164, 158, 179, 97, 147, 159, 150,
+            167, 115, 99, 133, 133, 154, 121, 86, 1, 116, 104, 143, 48, 107, 59, 0, 134, 146, 59,
+            77, 54, 41, 136, 9, 53, 118, 29, 106, 144, 117, 108, 145, 36, 134, 102, 76, 151, 9, 86,
+            55, 83, 9, 0, 60, 21, 21, 9, 137, 9, 0, 0, 160, 170, 87, 109, 90, 143, 153, 117, 84,
+            170, 9, 138, 133, 99, 146, 157, 119, 91, 134, 115, 142, 0, 111, 72, 86, 72, 1, 29, 52,
+            41, 21, 161, 0, 0, 0, 129, 142, 91, 95, 84, 72, 115, 9, 9, 98, 33, 73, 112, 98, 118,
+            157, 41, 134, 69, 88, 133, 33, 67, 9, 29, 48, 0, 51, 21, 33, 0, 128, 0, 0, 0, 144, 161,
+            111, 110, 80, 84, 153, 135, 77, 138, 0, 139, 149, 94, 147, 162, 123, 125, 114, 128,
+            134, 44, 85, 73, 51, 87, 0, 104, 63, 70, 36, 147, 0, 1, 0, 173, 106, 113, 137, 129,
+            145, 66, 138, 134, 0, 127, 152, 136, 145, 174, 116, 129, 168, 148, 146, 82, 103, 99,
+            126, 106, 110, 71, 0, 75, 98, 81, 66, 71, 76, 0, 117, 121, 82, 41, 9, 84, 148, 45, 0,
+            130, 0, 92, 119, 29, 135, 104, 41, 29, 33, 70, 119, 0, 33, 17, 94, 29, 0, 9, 0, 51, 9,
+            103, 0, 0, 0, 149, 166, 118, 139, 99, 129, 104, 0, 48, 115, 17, 101, 86, 140, 152, 129,
+            126, 102, 102, 89, 129, 57, 21, 29, 63, 102, 0, 98, 67, 71, 9, 123, 0, 0, 0, 179, 48,
+            117, 148, 111, 124, 21, 102, 133, 0, 157, 156, 145, 161, 143, 39, 141, 132, 164, 155,
+            36, 127, 152, 126, 126, 124, 116, 0, 33, 99, 48, 0, 43, 0, 0, 162, 44, 85, 91, 56, 108,
+            29, 53, 48, 37, 0, 93, 148, 108, 117, 118, 63, 77, 128, 132, 21, 43, 9, 56, 41, 72, 0,
+            0, 25, 71, 9, 17, 29, 0, 0, 148, 172, 65, 102, 43, 41, 128, 0, 44, 150, 41, 78, 141,
+            59, 122, 171, 75, 138, 134, 139, 150, 31, 78, 128, 43, 72, 132, 72, 48, 33, 0, 151, 0,
+            0, 0, 143, 157, 71, 59, 88, 69, 151, 53, 21, 151, 0, 135, 96, 71, 60, 158, 54, 25, 69,
+            84, 136, 55, 63, 39, 37, 17, 0, 157, 134, 154, 53, 156, 0, 0, 0, 160, 163, 120, 129,
+            53, 64, 157, 9, 48, 154, 0, 124, 113, 72, 113, 156, 117, 63, 95, 76, 142, 43, 44, 47,
+            90, 37, 0, 45, 42, 64, 9, 157, 0, 0, 0, 145, 175, 71, 78, 137, 146, 172, 41, 100, 165,
+            9, 131, 67, 68, 172, 172, 51, 48, 137, 150, 146, 107, 82, 114, 86, 115, 0, 137, 118,
+            170, 84, 160, 0, 63, 0, 179, 100, 156, 172, 157, 152, 88, 146, 152, 0, 101, 154, 152,
+            164, 154, 81, 145, 169, 158, 148, 87, 90, 105, 125, 140, 117, 73, 0, 133, 87, 88, 93,
+            132, 21, 0, 126, 155, 33, 59, 0, 17, 156, 0, 9, 144, 0, 101, 114, 39, 105, 170, 83,
+            162, 85, 103, 140, 21, 78, 100, 97, 49, 0, 41, 68, 41, 0, 152, 0, 0, 0, 147, 167, 90,
+            121, 121, 104, 167, 65, 67, 168, 21, 112, 86, 130, 129, 169, 87, 97, 130, 132, 146, 83,
+            116, 93, 56, 124, 9, 77, 101, 135, 9, 153, 0, 9, 0, 141, 138, 55, 89, 49, 33, 140, 0,
+            9, 148, 0, 142, 138, 85, 136, 139, 140, 55, 94, 176, 145, 57, 113, 91, 0, 84, 0, 150,
+            55, 152, 21, 142, 0, 0, 0, 143, 168, 56, 130, 36, 21, 152, 82, 31, 175, 9, 125, 102,
+            81, 140, 161, 39, 159, 104, 97, 150, 69, 73, 68, 60, 58, 0, 155, 94, 99, 48, 149, 0, 0,
+            0, 174, 122, 115, 160, 109, 135, 90, 101, 104, 0, 98, 131, 134, 135, 135, 78, 134, 132,
+            135, 144, 72, 104, 93, 56, 117, 94, 103, 0, 112, 76, 135, 37, 93, 0, 0, 79, 149, 0, 37,
+            44, 9, 104, 0, 0, 21, 21, 9, 87, 61, 43, 130, 0, 108, 42, 81, 116, 53, 0, 0, 9, 0, 0,
+            37, 9, 17, 0, 136, 0, 0, 0, 156, 118, 9, 72, 33, 37, 107, 0, 0, 94, 0, 33, 55, 79, 96,
+            123, 9, 90, 62, 72, 106, 17, 57, 0, 9, 21, 0, 48, 21, 0, 9, 136, 0, 0, 0, 77, 92, 21,
+            0, 33, 0, 138, 0, 59, 114, 0, 0, 21, 0, 33, 57, 25, 0, 36, 80, 81, 0, 21, 21, 17, 0, 0,
+            130, 112, 117, 0, 152, 0, 0, 0, 117, 147, 0, 9, 0, 9, 150, 77, 0, 145, 0, 106, 69, 21,
+            139, 105, 17, 37, 65, 48, 108, 0, 55, 52, 50, 0, 0, 0, 0, 9, 0, 126, 0, 0, 0, 105, 116,
+            9, 100, 9, 0, 136, 0, 0, 134, 0, 103, 92, 56, 112, 109, 21, 82, 58, 116, 119, 9, 9, 9,
+            37, 9, 0, 0, 0, 37, 0, 110, 0, 0, 0, 36, 95, 0, 0, 0, 0, 121, 0, 0, 84, 0, 0, 0, 0, 0,
+            146, 0, 0, 0, 1, 95, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 70, 0, 0, 0, 156, 1, 69, 61, 60, 63,
+            0, 0, 43, 0, 69, 155, 21, 72, 141, 133, 54, 1, 139, 121, 0, 44, 41, 56, 45, 110, 25, 0,
+            71, 106, 90, 0, 70, 0, 0, 144, 36, 53, 123, 61, 68, 9, 91, 37, 0, 52, 71, 51, 72, 74,
+            17, 61, 83, 64, 128, 0, 9, 21, 29, 126, 9, 0, 0, 91, 9, 113, 0, 33, 0, 0, 180, 37, 61,
+            112, 90, 136, 49, 25, 108, 0, 69, 148, 84, 127, 114, 72, 71, 89, 79, 129, 64, 21, 102,
+            89, 52, 29, 0, 1, 75, 0, 94, 0, 21, 0, 0, 155, 0, 21, 79, 61, 106, 0, 29, 21, 0, 29,
+            108, 66, 114, 98, 0, 33, 88, 78, 130, 21, 25, 37, 43, 9, 9, 0, 0, 90, 21, 0, 0, 90, 0,
+            0, 172, 127, 132, 161, 122, 163, 70, 109, 136, 0, 139, 145, 152, 134, 155, 113, 100,
+            141, 150, 133, 84, 81, 107, 102, 127, 126, 95, 0, 118, 134, 113, 56, 137, 33, 0, 149,
+            9, 43, 69, 43, 60, 21, 9, 55, 0, 36, 63, 70, 61, 84, 29, 33, 69, 62, 76, 0, 9, 91, 9,
+            31, 9, 0, 0, 0, 0, 9, 0, 76, 0, 0, 72, 59, 17, 17, 9, 21, 45, 0, 0, 31, 0, 0, 9, 0, 0,
+            25, 0, 43, 9, 41, 59, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0, 45, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+    },
+    LanguageModel {
+        language: "be",
+        script: Script::Cyrillic,
+        alphabet: &[
+            '\u{0430}', '\u{0431}', '\u{0432}', '\u{0433}', '\u{0434}', '\u{0435}', '\u{0436}',
+            '\u{0437}', '\u{0439}', '\u{043A}', '\u{043B}', '\u{043C}', '\u{043D}', '\u{043E}',
+            '\u{043F}', '\u{0440}', '\u{0441}', '\u{0442}', '\u{0443}', '\u{0444}', '\u{0445}',
+            '\u{0446}', '\u{0447}', '\u{0448}', '\u{044B}', '\u{044C}', '\u{044D}', '\u{044E}',
+            '\u{044F}', '\u{0451}', '\u{0456}', '\u{045E}',
+        ],
+        bigrams: &[
+            0, 175, 165, 169, 157, 169, 128, 120, 169, 108, 176, 155, 170, 175, 128, 180, 164, 174,
+            162, 155, 155, 142, 142, 143, 144, 78, 40, 144, 110, 142, 109, 154, 152, 0, 191, 128,
+            159, 165, 162, 170, 156, 112, 155, 165, 163, 174, 168, 182, 100, 152, 176, 167, 159,
+            117, 123, 143, 158, 149, 137, 40, 40, 89, 120, 170, 128, 128, 163, 0, 133, 160, 79, 88,
+            52, 0, 140, 52, 59, 0, 40, 149, 95, 118, 138, 64, 135, 107, 0, 148, 40, 91, 40, 71, 59,
+            123, 71, 112, 64, 108, 0, 139, 0, 0, 98, 174, 0, 0, 52, 40, 150, 0, 0, 0, 52, 52, 0,
+            52, 145, 0, 84, 52, 0, 123, 40, 0, 52, 64, 0, 169, 52, 119, 52, 131, 40, 151, 0, 0,
+            137, 165, 89, 115, 52, 96, 131, 0, 52, 64, 83, 122, 91, 115, 136, 64, 143, 102, 88,
+            141, 52, 64, 52, 134, 52, 86, 0, 126, 52, 52, 76, 136, 0, 0, 149, 168, 79, 110, 71, 91,
+            64, 147, 151, 0, 137, 147, 116, 149, 141, 105, 136, 133, 115, 136, 76, 95, 0, 110, 90,
+            139, 52, 143, 0, 52, 0, 59, 0, 0, 173, 115, 102, 127, 116, 134, 71, 115, 122, 133, 132,
+            140, 144, 161, 92, 108, 163, 138, 128, 79, 83, 97, 143, 104, 106, 0, 0, 52, 40, 105,
+            71, 85, 118, 0, 121, 142, 93, 71, 52, 76, 52, 64, 52, 0, 79, 64, 52, 120, 112, 79, 64,
+            95, 40, 119, 0, 52, 0, 40, 71, 136, 0, 126, 0, 0, 0, 52, 0, 0, 139, 162, 110, 137, 92,
+            98, 142, 0, 0, 0, 111, 115, 115, 146, 121, 67, 114, 119, 52, 115, 0, 0, 52, 52, 84,
+            100, 134, 89, 71, 116, 71, 144, 0, 0, 154, 71, 96, 93, 84, 116, 64, 0, 83, 0, 107, 145,
+            109, 131, 71, 64, 107, 145, 115, 64, 71, 52, 103, 78, 95, 52, 0, 0, 0, 71, 0, 79, 0, 0,
+            152, 186, 59, 111, 71, 71, 136, 0, 64, 0, 59, 138, 88, 125, 153, 81, 152, 138, 127,
+            150, 67, 105, 99, 64, 102, 91, 64, 104, 91, 52, 0, 168, 0, 0, 145, 169, 95, 85, 105,
+            105, 155, 78, 71, 0, 134, 73, 88, 91, 148, 83, 52, 105, 106, 143, 79, 76, 76, 71, 0,
+            124, 165, 108, 134, 155, 104, 167, 0, 0, 149, 173, 134, 110, 73, 91, 154, 52, 64, 0,
+            92, 122, 59, 119, 151, 123, 86, 120, 81, 133, 64, 71, 64, 64, 76, 141, 0, 123, 64, 135,
+            73, 148, 0, 0, 164, 181, 95, 96, 153, 154, 170, 81, 108, 0, 131, 93, 86, 146, 148, 79,
+            64, 155, 155, 141, 102, 96, 131, 114, 117, 162, 155, 120, 117, 155, 128, 164, 0, 0,
+            142, 101, 131, 154, 123, 149, 104, 115, 102, 124, 131, 150, 133, 151, 0, 127, 150, 145,
+            122, 59, 95, 84, 96, 128, 108, 0, 0, 76, 64, 84, 52, 76, 147, 0, 123, 174, 59, 40, 0,
+            0, 146, 0, 0, 0, 119, 118, 0, 101, 135, 0, 159, 109, 90, 148, 52, 95, 109, 71, 79, 116,
+            0, 102, 71, 103, 0, 144, 0, 0, 157, 184, 118, 115, 128, 131, 52, 102, 88, 0, 128, 117,
+            127, 128, 148, 101, 83, 142, 137, 153, 91, 127, 103, 94, 113, 171, 64, 163, 0, 0, 0,
+            71, 0, 0, 152, 159, 73, 116, 64, 52, 144, 0, 52, 0, 174, 136, 91, 132, 125, 148, 92,
+            73, 168, 143, 85, 123, 143, 0, 40, 125, 133, 119, 81, 139, 71, 151, 0, 0, 154, 174, 59,
+            133, 71, 0, 59, 0, 0, 52, 127, 117, 79, 130, 145, 71, 158, 106, 76, 146, 71, 106, 59,
+            83, 64, 152, 0, 155, 0, 0, 0, 64, 40, 0, 165, 139, 144, 119, 130, 128, 129, 120, 132,
+            105, 138, 139, 137, 139, 93, 137, 147, 141, 133, 64, 104, 108, 117, 126, 118, 0, 0,
+            121, 116, 81, 0, 110, 86, 0, 103, 151, 0, 52, 78, 0, 115, 0, 0, 0, 52, 101, 0, 64, 103,
+            0, 124, 64, 107, 110, 0, 52, 0, 52, 0, 52, 52, 85, 0, 40, 0, 136, 0, 0, 138, 145, 59,
+            78, 0, 71, 115, 0, 0, 0, 0, 81, 84, 115, 141, 0, 98, 91, 84, 120, 0, 0, 0, 52, 52, 79,
+            52, 94, 52, 40, 52, 129, 0, 0, 108, 149, 64, 52, 71, 52, 122, 0, 89, 0, 137, 52, 0, 64,
+            109, 59, 0, 71, 0, 108, 0, 0, 142, 0, 0, 148, 165, 129, 92, 117, 83, 150, 0, 0, 110,
+            156, 64, 84, 52, 52, 0, 97, 0, 0, 119, 40, 64, 141, 100, 0, 71, 64, 52, 108, 0, 52, 73,
+            0, 0, 152, 0, 142, 0, 0, 0, 59, 0, 0, 125, 135, 0, 90, 52, 0, 0, 0, 0, 0, 116, 97, 100,
+            98, 105, 64, 108, 100, 120, 109, 52, 52, 90, 129, 52, 141, 0, 122, 0, 0, 0, 0, 0, 0,
+            175, 98, 131, 127, 116, 124, 97, 83, 130, 122, 141, 141, 152, 149, 52, 118, 140, 141,
+            135, 90, 128, 130, 143, 127, 109, 40, 0, 0, 91, 158, 81, 112, 119, 0, 170, 0, 105, 105,
+            96, 110, 111, 0, 81, 52, 124, 101, 127, 159, 40, 106, 0, 128, 109, 0, 81, 76, 126, 83,
+            112, 0, 0, 0, 103, 121, 101, 79, 40, 0, 145, 96, 116, 114, 124, 123, 64, 110, 105, 130,
+            134, 143, 135, 157, 90, 99, 144, 148, 139, 71, 98, 94, 111, 122, 116, 0, 0, 0, 64, 86,
+            0, 71, 95, 0, 136, 59, 90, 71, 67, 73, 40, 0, 76, 52, 96, 52, 76, 102, 0, 71, 100, 115,
+            103, 0, 52, 0, 113, 122, 52, 0, 0, 64, 71, 0, 0, 52, 0, 0, 185, 71, 86, 134, 120, 126,
+            117, 102, 109, 92, 129, 127, 121, 138, 84, 119, 132, 117, 110, 0, 76, 103, 123, 90,
+            107, 0, 0, 64, 78, 118, 0, 52, 128, 0, 102, 0, 64, 127, 85, 71, 0, 52, 64, 67, 81, 67,
+            71, 134, 0, 0, 104, 95, 76, 0, 0, 52, 52, 40, 0, 0, 0, 0, 0, 0, 0, 0, 92, 0, 178, 84,
+            124, 132, 122, 136, 110, 116, 118, 127, 158, 143, 133, 161, 94, 116, 143, 152, 131, 79,
+            100, 109, 148, 130, 119, 0, 0, 52, 84, 157, 112, 105, 105, 0, 155, 94, 67, 130, 93,
+            142, 71, 106, 113, 52, 120, 127, 64, 146, 52, 91, 110, 135, 132, 0, 52, 52, 101, 76,
+            84, 0, 0, 71, 0, 0, 0, 71, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+    },
+    LanguageModel {
+        language: "bg",
+        script: Script::Cyrillic,
+        alphabet: &[
+            '\u{0430}', '\u{0431}', '\u{0432}', '\u{0433}', '\u{0434}', '\u{0435}', '\u{0436}',
+            '\u{0437}', '\u{0438}', '\u{0439}', '\u{043A}', '\u{043B}', '\u{043C}', '\u{043D}',
+            '\u{043E}', '\u{043F}', '\u{0440}', '\u{0441}', '\u{0442}', '\u{0443}', '\u{0444}',
+            '\u{0445}', '\u{0446}', '\u{0447}', '\u{0448}', '\u{0449}', '\u{044A}', '\u{044C}',
+            '\u{044E}', '\u{044F}',
+        ],
+        bigrams: &[
+            0, 156, 157, 170, 149, 173, 163, 93, 173, 177, 73, 168, 139, 160, 189, 171, 183, 159,
+            183, 158, 145, 159, 124, 129, 140, 128, 111, 63, 0, 95, 102, 0, 201, 78, 138, 163, 128,
+            155, 102, 127, 159, 92, 158, 153, 157, 147, 180, 79, 140, 156, 141, 173, 101, 108, 109,
+            137, 132, 110, 133, 0, 0, 0, 108, 0, 82, 139, 0, 124, 30, 30, 145, 0, 65, 136, 0, 66,
+            137, 56, 112, 131, 0, 144, 108, 60, 128, 0, 111, 0, 42, 0, 119, 126, 0, 0, 84, 0, 155,
+            176, 18, 18, 96, 83, 162, 65, 78, 160, 0, 127, 115, 116, 129, 149, 96, 135, 127, 116,
+            112, 0, 120, 0, 52, 0, 0, 148, 0, 0, 129, 0, 109, 136, 30, 99, 37, 46, 100, 0, 18, 120,
+            0, 42, 129, 63, 120, 135, 30, 150, 58, 30, 131, 0, 0, 0, 0, 0, 0, 79, 0, 0, 0, 0, 148,
+            174, 95, 127, 73, 120, 162, 98, 49, 152, 0, 79, 75, 98, 139, 151, 98, 136, 116, 64,
+            128, 52, 94, 0, 69, 71, 30, 143, 0, 0, 112, 0, 194, 89, 127, 128, 126, 161, 68, 144,
+            142, 114, 124, 154, 156, 148, 178, 123, 146, 156, 155, 165, 129, 100, 111, 119, 130,
+            146, 97, 49, 0, 30, 88, 0, 88, 136, 0, 87, 0, 142, 151, 0, 0, 127, 0, 100, 56, 0, 111,
+            72, 0, 0, 0, 65, 78, 0, 0, 0, 0, 0, 0, 65, 0, 0, 0, 0, 139, 174, 121, 154, 104, 137,
+            112, 66, 18, 142, 0, 125, 110, 127, 139, 136, 140, 119, 102, 127, 106, 0, 127, 46, 115,
+            98, 0, 82, 0, 54, 74, 0, 183, 122, 116, 144, 127, 136, 153, 98, 167, 129, 114, 152,
+            153, 156, 155, 118, 128, 155, 154, 163, 56, 125, 83, 131, 144, 126, 115, 18, 0, 0, 165,
+            0, 129, 0, 54, 80, 52, 70, 86, 0, 30, 0, 0, 114, 152, 73, 131, 70, 42, 46, 132, 132, 0,
+            0, 0, 69, 18, 49, 0, 0, 0, 0, 0, 0, 138, 171, 42, 132, 0, 37, 132, 0, 18, 153, 0, 0,
+            146, 65, 96, 164, 37, 138, 137, 144, 131, 0, 63, 109, 0, 30, 0, 137, 0, 80, 0, 0, 153,
+            151, 92, 96, 99, 70, 159, 112, 134, 164, 0, 110, 18, 75, 152, 153, 18, 18, 116, 115,
+            127, 54, 52, 52, 37, 60, 0, 121, 30, 134, 137, 0, 140, 162, 103, 104, 0, 18, 167, 0, 0,
+            138, 0, 93, 64, 30, 121, 156, 108, 89, 103, 52, 104, 0, 52, 18, 0, 0, 0, 106, 0, 0,
+            117, 0, 163, 187, 49, 83, 112, 146, 183, 18, 93, 175, 0, 112, 109, 46, 127, 169, 18,
+            52, 139, 152, 120, 117, 82, 77, 73, 77, 42, 94, 37, 100, 132, 0, 180, 93, 156, 160,
+            139, 159, 114, 149, 140, 122, 146, 143, 154, 150, 151, 66, 151, 158, 156, 167, 78, 93,
+            68, 114, 140, 89, 104, 30, 0, 0, 128, 0, 111, 147, 0, 77, 0, 0, 139, 0, 0, 143, 0, 110,
+            110, 18, 90, 169, 0, 175, 117, 104, 127, 0, 0, 139, 0, 42, 18, 141, 0, 81, 95, 0, 142,
+            176, 87, 131, 126, 116, 176, 132, 108, 164, 0, 109, 97, 136, 127, 162, 92, 18, 136,
+            138, 129, 85, 121, 91, 86, 116, 0, 132, 58, 0, 132, 0, 156, 146, 69, 137, 56, 0, 161,
+            0, 30, 152, 0, 159, 144, 107, 123, 128, 143, 119, 46, 174, 113, 67, 92, 52, 78, 0, 0,
+            155, 0, 0, 114, 0, 174, 174, 86, 151, 92, 110, 172, 37, 42, 161, 0, 124, 99, 108, 139,
+            174, 95, 153, 117, 116, 119, 60, 82, 0, 81, 0, 0, 137, 0, 58, 111, 0, 122, 105, 121,
+            126, 115, 108, 101, 89, 89, 67, 42, 133, 128, 135, 119, 54, 131, 128, 143, 119, 0, 107,
+            42, 64, 118, 76, 110, 37, 0, 0, 0, 0, 71, 152, 0, 0, 54, 37, 110, 0, 0, 132, 0, 0, 103,
+            52, 18, 136, 0, 108, 67, 103, 102, 0, 0, 0, 0, 0, 0, 56, 0, 0, 0, 0, 113, 107, 0, 106,
+            0, 0, 100, 0, 0, 119, 0, 0, 18, 58, 87, 142, 0, 112, 69, 74, 94, 0, 30, 0, 0, 0, 0, 72,
+            0, 0, 0, 0, 99, 125, 30, 107, 0, 0, 126, 0, 0, 156, 0, 71, 0, 0, 30, 30, 30, 0, 0, 0,
+            49, 0, 0, 0, 0, 0, 0, 73, 0, 30, 93, 0, 105, 144, 0, 124, 0, 0, 148, 0, 30, 139, 0,
+            118, 83, 30, 139, 93, 0, 104, 0, 30, 91, 0, 0, 0, 18, 18, 0, 72, 0, 0, 0, 0, 101, 117,
+            0, 108, 0, 0, 121, 0, 0, 130, 0, 137, 18, 0, 131, 78, 0, 99, 0, 0, 54, 0, 0, 0, 69, 0,
+            0, 60, 0, 0, 0, 0, 116, 127, 0, 0, 0, 0, 136, 0, 0, 124, 0, 0, 0, 0, 91, 123, 0, 18, 0,
+            58, 65, 0, 0, 0, 0, 0, 30, 0, 0, 0, 0, 0, 18, 0, 86, 131, 84, 133, 60, 99, 137, 0, 30,
+            122, 140, 129, 107, 119, 119, 154, 128, 146, 0, 30, 75, 79, 100, 30, 127, 0, 0, 46, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 65, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 85, 18, 0, 0, 63, 80, 0, 89, 46, 0, 0, 64, 42, 54, 54, 0, 0, 85, 30, 100,
+            0, 30, 0, 30, 133, 0, 0, 0, 0, 0, 0, 0, 161, 0, 117, 139, 80, 76, 63, 49, 84, 0, 42,
+            110, 108, 122, 140, 0, 75, 99, 107, 150, 0, 0, 83, 0, 64, 0, 103, 0, 0, 0, 30, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0,
+        ],
+    },
+    LanguageModel {
+        language: "sr",
+        script: Script::Cyrillic,
+        alphabet: &[
+            '\u{0430}', '\u{0431}', '\u{0432}', '\u{0433}', '\u{0434}', '\u{0435}', '\u{0436}',
+            '\u{0437}', '\u{0438}', '\u{043A}', '\u{043B}', '\u{043C}', '\u{043D}', '\u{043E}',
+            '\u{043F}', '\u{0440}', '\u{0441}', '\u{0442}', '\u{0443}', '\u{0444}', '\u{0445}',
+            '\u{0446}', '\u{0447}', '\u{0448}', '\u{0452}', '\u{0458}', '\u{0459}', '\u{045A}',
+            '\u{045B}', '\u{045F}',
+        ],
+        bigrams: &[
+            0, 160, 162, 161, 151, 177, 135, 102, 165, 174, 168, 147, 164, 182, 174, 185, 158, 179,
+            156, 171, 135, 129, 125, 135, 137, 81, 160, 80, 104, 113, 92, 0, 199, 56, 137, 168,
+            133, 154, 68, 119, 161, 107, 163, 152, 158, 168, 135, 143, 162, 146, 167, 118, 106,
+            120, 133, 147, 131, 124, 160, 105, 162, 115, 75, 0, 117, 149, 0, 58, 16, 44, 143, 0,
+            65, 149, 44, 137, 71, 113, 143, 44, 145, 80, 35, 129, 64, 28, 28, 44, 28, 0, 115, 89,
+            28, 0, 0, 0, 136, 171, 47, 28, 74, 74, 163, 28, 44, 154, 111, 110, 63, 144, 154, 50,
+            152, 91, 16, 121, 28, 16, 78, 47, 78, 28, 75, 139, 40, 0, 0, 0, 146, 137, 62, 100, 50,
+            87, 115, 0, 62, 126, 58, 131, 106, 115, 135, 74, 151, 80, 83, 149, 47, 0, 50, 0, 0, 0,
+            58, 0, 59, 0, 0, 0, 146, 174, 128, 124, 111, 59, 160, 40, 65, 151, 58, 94, 97, 148,
+            155, 76, 152, 113, 77, 135, 69, 28, 47, 28, 63, 28, 77, 84, 117, 16, 28, 0, 193, 85,
+            132, 141, 135, 162, 35, 123, 146, 135, 164, 154, 161, 169, 135, 141, 154, 147, 149,
+            115, 117, 100, 121, 113, 151, 131, 119, 141, 145, 135, 47, 0, 79, 139, 40, 0, 40, 88,
+            133, 0, 0, 136, 0, 28, 0, 100, 44, 0, 28, 0, 16, 97, 0, 0, 0, 28, 0, 50, 44, 0, 66, 0,
+            0, 0, 141, 167, 120, 137, 105, 125, 123, 0, 0, 151, 28, 132, 123, 149, 135, 16, 115, 0,
+            0, 135, 0, 28, 0, 0, 0, 0, 62, 0, 0, 0, 28, 0, 184, 89, 129, 158, 120, 128, 65, 81,
+            158, 47, 160, 147, 162, 161, 120, 119, 148, 171, 155, 78, 114, 135, 141, 148, 143, 89,
+            165, 95, 101, 106, 74, 0, 151, 174, 50, 111, 50, 50, 154, 0, 0, 156, 0, 130, 74, 81,
+            169, 61, 144, 137, 135, 151, 35, 35, 120, 0, 78, 0, 66, 135, 50, 0, 0, 0, 137, 163, 87,
+            76, 86, 90, 148, 56, 67, 166, 81, 74, 81, 124, 150, 70, 44, 113, 116, 126, 92, 54, 59,
+            54, 56, 0, 0, 0, 16, 0, 0, 0, 168, 163, 137, 58, 47, 59, 166, 28, 40, 134, 77, 87, 52,
+            113, 159, 116, 91, 109, 74, 118, 64, 47, 56, 35, 64, 0, 64, 86, 35, 59, 0, 0, 158, 179,
+            65, 76, 128, 138, 173, 28, 90, 174, 119, 63, 81, 47, 169, 90, 50, 140, 145, 147, 97,
+            68, 107, 94, 88, 0, 16, 106, 35, 0, 72, 0, 174, 96, 146, 162, 158, 168, 64, 130, 150,
+            97, 148, 146, 155, 151, 63, 149, 167, 164, 160, 99, 108, 101, 122, 135, 130, 104, 154,
+            130, 92, 102, 58, 0, 119, 144, 28, 69, 66, 54, 142, 0, 16, 151, 90, 105, 71, 107, 174,
+            70, 176, 121, 106, 140, 35, 67, 135, 50, 108, 0, 73, 88, 0, 16, 0, 0, 145, 178, 93,
+            125, 126, 124, 179, 139, 96, 168, 101, 97, 116, 129, 161, 90, 0, 138, 123, 145, 77,
+            118, 91, 89, 128, 89, 86, 54, 94, 58, 66, 0, 143, 164, 69, 133, 58, 76, 153, 0, 40,
+            150, 161, 144, 125, 142, 128, 157, 115, 81, 175, 145, 64, 50, 106, 28, 40, 0, 78, 0, 0,
+            0, 0, 0, 152, 175, 28, 138, 52, 61, 169, 0, 16, 162, 126, 98, 88, 131, 165, 114, 152,
+            115, 92, 140, 81, 95, 70, 0, 28, 0, 77, 93, 16, 0, 0, 0, 176, 110, 128, 123, 132, 120,
+            93, 121, 115, 89, 141, 138, 146, 142, 93, 147, 136, 143, 141, 58, 92, 70, 87, 140, 117,
+            61, 149, 52, 86, 128, 54, 0, 110, 107, 0, 16, 50, 79, 98, 0, 0, 131, 0, 80, 47, 0, 120,
+            47, 108, 63, 99, 113, 80, 28, 50, 40, 16, 0, 52, 28, 0, 0, 0, 0, 138, 113, 0, 94, 0,
+            50, 105, 0, 16, 121, 0, 28, 58, 50, 114, 35, 82, 63, 112, 89, 44, 16, 16, 28, 28, 0,
+            40, 0, 0, 0, 16, 0, 116, 128, 28, 58, 28, 77, 136, 0, 0, 155, 78, 56, 40, 40, 80, 69,
+            109, 81, 44, 112, 44, 16, 62, 0, 0, 0, 52, 0, 0, 0, 0, 0, 119, 135, 0, 83, 0, 28, 143,
+            0, 0, 151, 134, 96, 28, 130, 67, 0, 40, 0, 0, 124, 0, 0, 52, 0, 0, 0, 63, 16, 28, 28,
+            0, 0, 112, 140, 0, 82, 28, 0, 136, 28, 0, 131, 144, 78, 56, 109, 82, 74, 54, 40, 146,
+            80, 40, 0, 56, 73, 0, 0, 0, 47, 108, 124, 0, 0, 0, 115, 0, 16, 0, 0, 128, 0, 0, 104, 0,
+            0, 0, 0, 97, 0, 0, 0, 0, 120, 0, 0, 0, 0, 28, 0, 28, 0, 0, 0, 0, 0, 148, 156, 77, 108,
+            65, 75, 175, 0, 35, 132, 106, 90, 96, 109, 108, 77, 94, 119, 123, 145, 35, 0, 81, 71,
+            58, 0, 16, 0, 90, 0, 61, 0, 83, 142, 47, 0, 0, 0, 138, 0, 0, 121, 128, 0, 0, 102, 44,
+            28, 0, 92, 0, 136, 0, 0, 79, 35, 47, 0, 0, 0, 28, 0, 0, 0, 73, 154, 0, 0, 16, 0, 158,
+            0, 0, 117, 35, 0, 16, 0, 93, 0, 0, 67, 0, 120, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 28, 0,
+            105, 123, 0, 0, 0, 0, 142, 0, 0, 127, 28, 0, 0, 97, 73, 0, 0, 40, 0, 106, 0, 0, 0, 0,
+            0, 0, 0, 28, 0, 0, 0, 0, 61, 90, 28, 0, 0, 28, 70, 0, 0, 90, 0, 0, 0, 0, 72, 28, 0, 0,
+            0, 62, 0, 0, 0, 0, 47, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+    },
+    LanguageModel {
+        language: "mk",
+        script: Script::Cyrillic,
+        alphabet: &[
+            '\u{0430}', '\u{0431}', '\u{0432}', '\u{0433}', '\u{0434}', '\u{0435}', '\u{0436}',
+            '\u{0437}', '\u{0438}', '\u{043A}', '\u{043B}', '\u{043C}', '\u{043D}', '\u{043E}',
+            '\u{043F}', '\u{0440}', '\u{0441}', '\u{0442}', '\u{0443}', '\u{0444}', '\u{0445}',
+            '\u{0446}', '\u{0447}', '\u{0448}', '\u{0453}', '\u{0455}', '\u{0458}', '\u{0459}',
+            '\u{045A}', '\u{045C}', '\u{045F}',
+        ],
+        bigrams: &[
+            0, 153, 162, 174, 162, 184, 163, 0, 177, 170, 168, 141, 169, 192, 167, 181, 145, 178,
+            154, 152, 146, 118, 130, 123, 135, 106, 0, 151, 0, 0, 84, 0, 0, 207, 127, 139, 160,
+            139, 135, 106, 113, 120, 0, 142, 161, 171, 166, 72, 155, 149, 134, 189, 84, 110, 0,
+            140, 145, 118, 96, 0, 151, 0, 161, 0, 84, 0, 84, 140, 0, 0, 0, 0, 147, 0, 0, 148, 0,
+            100, 0, 91, 149, 0, 140, 0, 0, 130, 0, 0, 0, 0, 0, 0, 0, 116, 0, 0, 0, 0, 0, 152, 172,
+            0, 72, 72, 91, 148, 0, 0, 145, 96, 112, 0, 127, 170, 96, 151, 0, 112, 126, 0, 0, 0,
+            146, 0, 0, 0, 130, 0, 0, 0, 0, 0, 112, 126, 0, 0, 0, 0, 72, 0, 0, 131, 0, 137, 0, 96,
+            152, 0, 154, 0, 0, 112, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 150, 181, 108, 122, 96,
+            140, 160, 0, 0, 141, 0, 96, 112, 151, 154, 112, 151, 0, 0, 121, 96, 0, 72, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 191, 150, 129, 147, 129, 154, 72, 122, 125, 137, 172, 152, 164, 171,
+            125, 138, 151, 144, 165, 84, 115, 96, 110, 133, 153, 116, 0, 91, 0, 120, 118, 0, 0, 0,
+            157, 72, 0, 0, 0, 138, 0, 0, 135, 0, 0, 0, 84, 0, 0, 0, 0, 0, 125, 0, 0, 0, 0, 0, 0, 0,
+            72, 0, 0, 0, 0, 0, 118, 176, 131, 134, 108, 0, 106, 0, 0, 123, 0, 106, 96, 143, 113, 0,
+            91, 0, 0, 84, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 180, 91, 125, 130, 108, 154, 96,
+            72, 148, 131, 167, 140, 160, 158, 139, 134, 164, 155, 165, 124, 96, 0, 118, 125, 132,
+            0, 0, 160, 0, 119, 0, 0, 0, 119, 182, 0, 124, 0, 0, 125, 0, 0, 137, 0, 137, 0, 96, 165,
+            0, 141, 135, 142, 142, 0, 0, 91, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 120, 152, 0, 0, 72, 0,
+            159, 72, 0, 174, 103, 0, 84, 147, 153, 0, 0, 0, 84, 135, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 171, 165, 120, 0, 0, 0, 169, 0, 0, 139, 108, 0, 0, 84, 169, 126, 0, 72, 0, 91,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 155, 189, 0, 115, 103, 116, 181, 0, 96, 165,
+            118, 72, 0, 0, 164, 0, 0, 115, 150, 140, 106, 72, 115, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            183, 84, 142, 155, 133, 169, 116, 157, 140, 133, 136, 155, 144, 150, 0, 141, 170, 154,
+            180, 0, 0, 0, 151, 153, 119, 72, 72, 149, 0, 0, 0, 0, 0, 127, 151, 0, 0, 0, 0, 155, 0,
+            0, 142, 125, 125, 0, 116, 169, 0, 170, 112, 84, 72, 0, 0, 115, 112, 72, 0, 0, 0, 0, 0,
+            0, 0, 0, 139, 177, 0, 103, 103, 96, 174, 147, 110, 169, 106, 72, 146, 120, 160, 84, 0,
+            125, 129, 133, 72, 0, 100, 84, 131, 0, 0, 0, 0, 0, 0, 0, 0, 128, 119, 0, 108, 0, 84,
+            152, 0, 0, 148, 134, 162, 103, 121, 154, 151, 91, 0, 170, 103, 112, 0, 0, 91, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 176, 184, 0, 141, 0, 0, 177, 0, 0, 157, 100, 108, 0, 126, 181, 72,
+            154, 121, 0, 145, 0, 72, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 122, 91, 116, 166, 110, 91,
+            0, 0, 91, 84, 120, 132, 137, 100, 0, 121, 121, 150, 126, 0, 0, 0, 72, 130, 72, 0, 0, 0,
+            0, 0, 0, 0, 0, 72, 110, 0, 0, 0, 0, 115, 0, 0, 119, 0, 72, 0, 0, 146, 0, 96, 0, 0, 84,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 110, 0, 0, 0, 0, 91, 0, 0, 0, 0, 0, 0, 84,
+            100, 0, 72, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 84, 121, 0, 0, 0, 0, 133,
+            0, 0, 162, 0, 0, 0, 0, 72, 0, 84, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 130,
+            121, 0, 0, 0, 0, 144, 0, 0, 159, 122, 84, 0, 133, 125, 0, 0, 0, 0, 127, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 121, 130, 0, 0, 0, 0, 126, 0, 0, 136, 147, 0, 0, 121, 72, 0, 0,
+            0, 125, 116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 100, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 124, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 72, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 140,
+            171, 0, 108, 0, 131, 137, 0, 72, 0, 0, 91, 0, 110, 112, 72, 0, 91, 118, 103, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 72, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 125, 0, 0, 0, 0, 162, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 120, 0, 0,
+            72, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 84, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0,
+        ],
+    },
+    LanguageModel {
+        language: "el",
+        script: Script::Greek,
+        alphabet: &[
+            '\u{0390}', '\u{03AC}', '\u{03AD}', '\u{03AE}', '\u{03AF}', '\u{03B0}', '\u{03B1}',
+            '\u{03B2}', '\u{03B3}', '\u{03B4}', '\u{03B5}', '\u{03B6}', '\u{03B7}', '\u{03B8}',
+            '\u{03B9}', '\u{03BA}', '\u{03BB}', '\u{03BC}', '\u{03BD}', '\u{03BE}', '\u{03BF}',
+            '\u{03C0}', '\u{03C1}', '\u{03C2}', '\u{03C3}', '\u{03C4}', '\u{03C5}', '\u{03C6}',
+            '\u{03C7}', '\u{03C8}', '\u{03C9}', '\u{03CA}', '\u{03CB}', '\u{03CC}', '\u{03CD}',
+            '\u{03CE}',
+        ],
+        bigrams: &[
+            0, 0, 133, 155, 145, 100, 0, 183, 144, 162, 175, 179, 113, 154, 140, 127, 170, 145,
+            173, 157, 105, 157, 177, 130, 22, 175, 182, 146, 129, 155, 88, 113, 0, 0, 143, 87, 94,
+            0, 22, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 22, 0, 41, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 156, 0, 0, 0, 0, 0, 0, 46, 93, 130, 124, 63, 116, 73,
+            124, 87, 125, 145, 122, 144, 101, 89, 97, 136, 109, 139, 134, 0, 115, 101, 85, 0, 0, 0,
+            0, 0, 0, 0, 83, 0, 0, 0, 0, 22, 0, 122, 75, 142, 92, 69, 100, 0, 119, 81, 125, 135,
+            107, 160, 118, 109, 128, 126, 142, 135, 139, 22, 92, 138, 79, 81, 0, 0, 0, 0, 0, 0,
+            170, 0, 0, 0, 0, 0, 0, 0, 0, 87, 99, 0, 0, 0, 115, 0, 132, 106, 133, 114, 88, 0, 80,
+            128, 149, 143, 137, 0, 80, 98, 107, 0, 0, 0, 0, 0, 0, 0, 147, 0, 0, 0, 0, 0, 0, 162,
+            90, 106, 127, 120, 129, 129, 99, 0, 105, 111, 118, 155, 69, 150, 116, 112, 109, 144,
+            133, 0, 75, 104, 66, 124, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 186, 50, 50, 0,
+            56, 131, 0, 53, 128, 146, 141, 84, 117, 0, 134, 166, 155, 156, 153, 171, 114, 89, 160,
+            166, 155, 154, 170, 137, 149, 123, 60, 0, 109, 22, 60, 97, 0, 0, 62, 0, 130, 95, 82,
+            100, 0, 128, 22, 46, 56, 113, 0, 101, 0, 118, 0, 116, 0, 0, 0, 133, 0, 125, 0, 0, 0, 0,
+            0, 0, 0, 63, 0, 0, 96, 60, 69, 0, 69, 0, 106, 126, 142, 134, 0, 139, 0, 132, 34, 132,
+            0, 107, 0, 153, 143, 131, 125, 134, 80, 140, 0, 153, 0, 0, 0, 77, 0, 122, 0, 116, 0, 0,
+            101, 86, 100, 0, 77, 0, 86, 116, 96, 130, 0, 128, 0, 0, 0, 164, 0, 139, 0, 161, 0, 0,
+            0, 0, 0, 154, 0, 121, 0, 0, 0, 146, 0, 0, 0, 102, 0, 0, 99, 114, 97, 0, 168, 0, 110, 0,
+            0, 170, 0, 79, 94, 141, 139, 0, 82, 0, 120, 170, 142, 143, 132, 169, 134, 112, 155,
+            160, 142, 150, 159, 137, 122, 113, 87, 121, 73, 62, 65, 127, 58, 0, 60, 0, 85, 50, 108,
+            89, 0, 109, 0, 0, 0, 132, 0, 89, 0, 112, 0, 22, 63, 34, 0, 114, 0, 0, 0, 0, 0, 50, 0,
+            0, 0, 60, 0, 0, 102, 0, 76, 0, 180, 0, 0, 0, 0, 0, 0, 22, 0, 117, 101, 0, 0, 34, 122,
+            0, 138, 113, 154, 149, 78, 0, 78, 136, 163, 154, 138, 0, 86, 92, 89, 0, 0, 0, 0, 22, 0,
+            0, 46, 0, 89, 128, 122, 84, 0, 135, 0, 0, 0, 142, 0, 136, 0, 89, 0, 0, 135, 77, 0, 134,
+            0, 95, 0, 0, 0, 125, 0, 0, 0, 97, 0, 0, 105, 111, 88, 0, 174, 0, 135, 108, 115, 22, 0,
+            166, 113, 125, 135, 137, 101, 123, 128, 34, 172, 141, 149, 138, 93, 152, 112, 135, 131,
+            165, 137, 0, 93, 119, 93, 105, 0, 0, 118, 0, 122, 0, 130, 0, 146, 136, 143, 117, 0,
+            165, 22, 0, 114, 153, 0, 121, 22, 127, 108, 139, 72, 65, 0, 155, 74, 143, 58, 58, 148,
+            133, 94, 84, 0, 118, 0, 0, 153, 105, 118, 0, 109, 0, 138, 132, 145, 131, 0, 147, 92,
+            100, 69, 153, 0, 142, 71, 143, 46, 147, 130, 53, 0, 157, 58, 0, 0, 34, 95, 116, 92, 0,
+            0, 114, 0, 0, 124, 112, 133, 0, 97, 0, 132, 161, 141, 136, 0, 168, 122, 22, 0, 163, 0,
+            149, 22, 147, 0, 46, 139, 113, 0, 154, 146, 41, 0, 34, 0, 71, 127, 34, 75, 83, 0, 0,
+            140, 0, 101, 0, 181, 0, 137, 133, 129, 129, 0, 175, 0, 113, 135, 150, 73, 142, 114,
+            144, 81, 74, 58, 80, 0, 163, 0, 0, 46, 118, 155, 111, 22, 53, 0, 147, 0, 0, 135, 94,
+            105, 0, 56, 0, 82, 90, 56, 60, 0, 112, 0, 0, 0, 122, 0, 118, 0, 110, 0, 0, 0, 0, 0,
+            111, 0, 0, 0, 0, 101, 92, 0, 0, 0, 87, 0, 0, 106, 97, 22, 0, 179, 0, 46, 89, 110, 137,
+            0, 97, 122, 150, 132, 127, 60, 123, 133, 153, 136, 153, 156, 155, 53, 98, 152, 155,
+            160, 148, 136, 177, 116, 119, 60, 46, 68, 22, 81, 152, 34, 0, 94, 0, 134, 123, 106,
+            132, 0, 156, 0, 46, 0, 154, 0, 123, 0, 154, 0, 143, 22, 68, 0, 171, 66, 157, 0, 0, 119,
+            41, 0, 41, 0, 119, 0, 0, 145, 53, 90, 0, 107, 0, 145, 141, 135, 146, 0, 165, 104, 147,
+            88, 150, 53, 141, 102, 164, 105, 84, 131, 98, 82, 165, 34, 99, 0, 87, 127, 124, 111,
+            152, 0, 132, 0, 0, 137, 103, 127, 0, 188, 0, 0, 0, 0, 0, 0, 0, 22, 0, 0, 22, 0, 0, 0,
+            0, 0, 0, 22, 0, 0, 0, 0, 0, 22, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 103, 0, 104, 83,
+            103, 137, 0, 141, 106, 0, 96, 159, 0, 172, 110, 145, 127, 95, 149, 73, 0, 129, 111, 74,
+            0, 130, 175, 151, 134, 118, 0, 123, 0, 0, 115, 128, 62, 0, 105, 0, 144, 132, 156, 136,
+            0, 173, 77, 41, 0, 162, 108, 170, 50, 162, 41, 104, 100, 69, 0, 181, 0, 148, 46, 101,
+            78, 136, 69, 22, 0, 142, 0, 0, 141, 127, 109, 0, 170, 0, 99, 79, 89, 46, 0, 115, 70,
+            125, 96, 82, 66, 78, 122, 93, 105, 115, 139, 156, 91, 75, 150, 148, 128, 142, 141, 0,
+            91, 128, 85, 0, 34, 0, 85, 0, 53, 0, 34, 0, 138, 109, 134, 95, 0, 141, 0, 65, 0, 106,
+            0, 105, 95, 113, 22, 70, 0, 0, 0, 136, 0, 107, 0, 34, 81, 81, 0, 0, 0, 108, 0, 0, 103,
+            83, 88, 0, 93, 0, 94, 100, 117, 123, 0, 125, 0, 0, 0, 159, 0, 96, 89, 125, 0, 0, 0, 97,
+            0, 136, 0, 148, 0, 0, 84, 71, 0, 53, 0, 121, 0, 0, 105, 72, 120, 0, 0, 0, 34, 0, 46, 0,
+            0, 53, 0, 0, 0, 108, 0, 123, 0, 66, 0, 0, 0, 0, 0, 87, 0, 0, 0, 0, 53, 22, 0, 0, 0, 60,
+            0, 0, 22, 0, 34, 0, 125, 0, 0, 0, 41, 0, 0, 22, 22, 112, 117, 34, 0, 0, 91, 0, 46, 86,
+            119, 158, 0, 0, 84, 130, 124, 142, 123, 0, 34, 77, 0, 0, 0, 0, 0, 0, 0, 0, 89, 0, 34,
+            0, 0, 0, 0, 53, 63, 0, 0, 0, 34, 0, 0, 0, 96, 63, 0, 62, 0, 0, 0, 34, 34, 22, 70, 0,
+            34, 0, 0, 0, 0, 0, 53, 0, 0, 0, 41, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 22,
+            0, 34, 0, 0, 22, 0, 0, 0, 0, 0, 0, 50, 0, 0, 0, 0, 0, 0, 0, 0, 161, 0, 0, 0, 0, 0, 0,
+            68, 83, 123, 121, 53, 65, 70, 97, 60, 106, 123, 136, 146, 0, 50, 113, 131, 140, 122,
+            137, 0, 69, 114, 82, 63, 0, 0, 0, 0, 0, 0, 146, 0, 0, 0, 0, 0, 0, 72, 63, 88, 46, 87,
+            0, 58, 117, 22, 68, 105, 117, 138, 74, 109, 126, 127, 95, 115, 108, 0, 80, 60, 89, 50,
+            0, 0, 0, 0, 0, 0, 107, 0, 0, 0, 0, 0, 0, 0, 22, 46, 97, 0, 0, 0, 93, 34, 34, 46, 113,
+            144, 0, 34, 68, 122, 91, 132, 105, 0, 22, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0,
+        ],
+    },
+    LanguageModel {
+        language: "he",
+        script: Script::Hebrew,
+        alphabet: &[
+            '\u{05D0}', '\u{05D1}', '\u{05D2}', '\u{05D3}', '\u{05D4}', '\u{05D5}', '\u{05D6}',
+            '\u{05D7}', '\u{05D8}', '\u{05D9}', '\u{05DA}', '\u{05DB}', '\u{05DC}', '\u{05DD}',
+            '\u{05DE}', '\u{05DF}', '\u{05E0}', '\u{05E1}', '\u{05E2}', '\u{05E3}', '\u{05E4}',
+            '\u{05E5}', '\u{05E6}', '\u{05E7}', '\u{05E8}', '\u{05E9}', '\u{05EA}',
+        ],
+        bigrams: &[
+            0, 167, 158, 144, 139, 171, 140, 127, 138, 138, 146, 0, 139, 163, 0, 170, 71, 152, 150,
+            145, 0, 149, 0, 133, 154, 139, 164, 148, 0, 145, 68, 112, 114, 104, 127, 149, 109, 113,
+            117, 151, 64, 59, 137, 106, 123, 121, 138, 119, 64, 64, 121, 52, 76, 118, 139, 104,
+            134, 0, 137, 115, 74, 88, 106, 121, 141, 90, 114, 107, 147, 0, 71, 144, 0, 106, 74,
+            122, 104, 118, 0, 80, 130, 110, 110, 136, 105, 117, 0, 144, 110, 90, 71, 109, 107, 139,
+            59, 0, 87, 135, 0, 0, 127, 91, 86, 68, 102, 83, 80, 0, 64, 0, 0, 40, 132, 74, 94, 0,
+            141, 111, 109, 91, 87, 122, 137, 68, 88, 0, 143, 0, 108, 112, 82, 109, 76, 94, 71, 103,
+            78, 114, 0, 71, 92, 130, 111, 101, 0, 177, 128, 113, 112, 116, 105, 133, 98, 117, 88,
+            121, 0, 93, 108, 59, 135, 40, 111, 118, 110, 0, 118, 0, 119, 119, 144, 119, 126, 0,
+            149, 139, 152, 135, 140, 109, 140, 121, 126, 120, 138, 103, 110, 144, 120, 135, 139,
+            156, 134, 108, 99, 127, 102, 112, 130, 154, 122, 155, 0, 121, 104, 89, 76, 40, 108,
+            106, 0, 82, 0, 126, 0, 68, 59, 64, 109, 40, 94, 64, 0, 52, 0, 0, 0, 74, 112, 0, 59, 0,
+            129, 83, 128, 76, 122, 101, 121, 83, 52, 0, 124, 0, 0, 118, 78, 96, 78, 68, 96, 0, 0,
+            0, 74, 80, 83, 115, 102, 105, 0, 131, 114, 104, 87, 0, 120, 140, 64, 90, 78, 141, 0, 0,
+            112, 0, 100, 108, 115, 83, 104, 0, 109, 0, 0, 94, 118, 74, 74, 0, 159, 134, 127, 116,
+            133, 145, 148, 110, 114, 133, 147, 93, 112, 141, 153, 140, 134, 148, 133, 101, 89, 125,
+            89, 111, 150, 140, 128, 170, 0, 139, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 78, 94, 91, 0, 104, 100, 130, 78, 82, 64, 122, 0, 0,
+            102, 68, 83, 96, 119, 86, 59, 71, 82, 0, 0, 40, 108, 114, 122, 0, 163, 146, 114, 107,
+            114, 142, 139, 82, 104, 128, 158, 95, 108, 111, 83, 130, 68, 122, 97, 94, 64, 112, 52,
+            98, 119, 94, 115, 118, 0, 161, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 87, 134, 118, 82, 120, 138, 152, 99, 117, 108, 146, 127,
+            113, 128, 0, 114, 108, 133, 136, 133, 0, 113, 0, 113, 139, 129, 127, 133, 0, 160, 0, 0,
+            0, 0, 0, 40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 94, 122,
+            103, 143, 136, 140, 138, 106, 74, 130, 161, 71, 114, 110, 76, 108, 99, 89, 114, 96, 0,
+            97, 0, 71, 111, 96, 87, 137, 0, 131, 112, 87, 83, 90, 118, 134, 0, 64, 126, 140, 64,
+            80, 118, 52, 134, 100, 114, 100, 64, 96, 124, 0, 74, 104, 117, 0, 109, 0, 133, 64, 108,
+            68, 110, 99, 110, 85, 0, 106, 124, 0, 59, 121, 123, 98, 64, 90, 40, 0, 0, 64, 40, 91,
+            76, 130, 80, 105, 0, 123, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 94, 101, 80, 96, 92, 112, 151, 0, 82, 87, 136, 52, 64, 104, 0,
+            0, 102, 113, 119, 114, 0, 68, 40, 64, 107, 137, 107, 128, 0, 136, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 119, 97, 114, 102, 68,
+            100, 118, 0, 52, 40, 129, 0, 0, 88, 40, 89, 0, 59, 0, 85, 40, 109, 64, 0, 40, 117, 0,
+            74, 0, 136, 115, 120, 52, 99, 142, 152, 88, 40, 110, 141, 0, 0, 120, 59, 93, 90, 122,
+            109, 0, 89, 88, 0, 100, 52, 124, 131, 95, 0, 152, 125, 131, 118, 120, 134, 150, 97,
+            110, 123, 154, 108, 122, 98, 40, 129, 71, 106, 105, 87, 59, 141, 76, 102, 118, 85, 124,
+            122, 0, 137, 105, 112, 118, 82, 113, 131, 40, 85, 82, 143, 40, 64, 156, 112, 129, 83,
+            119, 40, 71, 0, 106, 0, 52, 103, 114, 40, 117, 0, 180, 108, 108, 71, 0, 100, 134, 68,
+            117, 64, 141, 64, 108, 86, 0, 135, 116, 101, 85, 80, 40, 97, 0, 85, 127, 112, 59, 78,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0,
+        ],
+    },
+    LanguageModel {
+        language: "ar",
+        script: Script::Arabic,
+        alphabet: &[
+            '\u{0621}', '\u{0622}', '\u{0623}', '\u{0624}', '\u{0625}', '\u{0626}', '\u{0627}',
+            '\u{0628}', '\u{0629}', '\u{062A}', '\u{062B}', '\u{062C}', '\u{062D}', '\u{062E}',
+            '\u{062F}', '\u{0630}', '\u{0631}', '\u{0632}', '\u{0633}', '\u{0634}', '\u{0635}',
+            '\u{0636}', '\u{0637}', '\u{0638}', '\u{0639}', '\u{063A}', '\u{0641}', '\u{0642}',
+            '\u{0643}', '\u{0644}', '\u{0645}', '\u{0646}', '\u{0647}', '\u{0648}', '\u{0649}',
+            '\u{064A}',
+        ],
+        bigrams: &[
+            0, 0, 117, 164, 0, 155, 0, 201, 165, 0, 170, 97, 163, 143, 148, 148, 123, 144, 118,
+            155, 139, 162, 114, 125, 67, 158, 155, 164, 152, 156, 168, 184, 148, 134, 151, 0, 154,
+            0, 141, 0, 0, 0, 0, 0, 0, 67, 0, 118, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 55, 0, 0, 0, 0, 0, 0, 67, 0, 0, 0, 0, 0, 0, 0, 100, 0, 0, 0, 0, 0, 89,
+            0, 0, 55, 0, 67, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 91, 0, 74, 0, 0, 0, 97, 0, 137, 0, 0, 0,
+            0, 0, 0, 0, 111, 0, 74, 118, 79, 100, 115, 102, 97, 141, 83, 126, 0, 79, 89, 55, 86,
+            110, 86, 115, 107, 106, 134, 127, 127, 0, 140, 0, 128, 0, 0, 0, 0, 0, 0, 0, 0, 55, 0,
+            0, 0, 74, 0, 0, 55, 0, 0, 0, 0, 0, 89, 0, 0, 0, 0, 0, 0, 0, 97, 0, 67, 0, 0, 67, 0, 0,
+            67, 0, 0, 0, 0, 0, 0, 0, 0, 0, 101, 0, 67, 91, 86, 91, 55, 102, 94, 89, 100, 127, 89,
+            95, 110, 94, 55, 110, 79, 98, 0, 83, 134, 102, 138, 0, 0, 0, 129, 0, 94, 0, 0, 0, 0, 0,
+            0, 67, 0, 100, 55, 0, 55, 67, 0, 67, 0, 100, 0, 0, 0, 86, 55, 89, 0, 0, 0, 67, 83, 0,
+            102, 120, 101, 0, 0, 55, 118, 0, 174, 143, 0, 0, 67, 0, 140, 67, 147, 101, 169, 111,
+            128, 145, 130, 145, 97, 161, 125, 150, 123, 131, 103, 124, 0, 129, 115, 137, 129, 148,
+            202, 157, 170, 107, 135, 74, 148, 0, 152, 0, 0, 74, 0, 67, 0, 153, 91, 125, 127, 86,
+            86, 109, 83, 137, 0, 149, 55, 94, 101, 67, 0, 115, 0, 135, 55, 67, 115, 110, 133, 113,
+            127, 110, 147, 0, 161, 0, 198, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 169, 0, 0, 86, 0, 0, 0, 157, 131,
+            131, 103, 103, 103, 148, 132, 108, 67, 148, 67, 120, 129, 130, 95, 115, 86, 146, 126,
+            123, 118, 101, 111, 129, 150, 121, 156, 86, 158, 0, 127, 0, 0, 0, 0, 0, 0, 126, 114,
+            95, 0, 0, 0, 0, 0, 0, 0, 103, 0, 0, 0, 0, 0, 0, 0, 55, 0, 0, 67, 0, 94, 79, 122, 0,
+            112, 0, 114, 0, 136, 0, 0, 0, 0, 0, 0, 134, 117, 105, 55, 0, 55, 67, 0, 137, 83, 125,
+            135, 97, 0, 0, 0, 0, 0, 113, 91, 0, 0, 67, 140, 156, 122, 118, 126, 0, 135, 0, 156, 0,
+            0, 0, 0, 0, 0, 138, 91, 135, 122, 95, 101, 0, 0, 143, 111, 138, 126, 105, 0, 110, 98,
+            67, 0, 0, 0, 103, 91, 74, 118, 122, 55, 67, 117, 0, 126, 0, 103, 0, 0, 0, 0, 0, 0, 138,
+            86, 89, 126, 0, 0, 0, 0, 130, 67, 121, 98, 83, 0, 113, 86, 144, 0, 0, 0, 89, 0, 0, 121,
+            83, 0, 0, 86, 55, 117, 0, 164, 83, 0, 74, 0, 0, 86, 148, 55, 147, 74, 97, 0, 0, 115,
+            125, 0, 127, 79, 67, 79, 0, 0, 0, 0, 125, 79, 120, 86, 55, 122, 135, 107, 83, 155, 86,
+            162, 0, 114, 0, 0, 0, 0, 0, 0, 130, 0, 79, 67, 0, 79, 0, 0, 0, 0, 125, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 101, 0, 74, 86, 0, 67, 103, 74, 0, 110, 0, 177, 0, 0, 95, 55, 0, 79, 158,
+            144, 159, 129, 55, 131, 74, 83, 130, 0, 86, 86, 133, 130, 120, 126, 94, 0, 95, 111,
+            131, 132, 140, 106, 135, 136, 102, 160, 101, 170, 0, 133, 79, 0, 0, 0, 0, 83, 130, 94,
+            100, 0, 0, 55, 0, 0, 86, 0, 132, 0, 79, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 94, 130, 98, 67,
+            116, 0, 144, 0, 146, 0, 0, 0, 55, 0, 0, 152, 129, 116, 161, 0, 105, 95, 106, 67, 0,
+            134, 0, 0, 0, 0, 0, 120, 0, 86, 83, 106, 74, 121, 141, 141, 122, 89, 139, 0, 153, 0,
+            121, 0, 0, 0, 0, 0, 83, 134, 83, 101, 109, 0, 74, 55, 94, 67, 0, 118, 0, 0, 0, 0, 0,
+            67, 0, 89, 105, 121, 91, 110, 138, 102, 0, 97, 113, 0, 142, 0, 138, 0, 0, 0, 0, 0, 0,
+            134, 0, 101, 0, 0, 0, 121, 0, 128, 0, 133, 0, 0, 0, 86, 0, 79, 0, 0, 55, 135, 74, 0,
+            130, 97, 101, 0, 157, 74, 134, 0, 132, 0, 0, 0, 0, 0, 0, 120, 102, 67, 0, 0, 0, 0, 0,
+            0, 0, 91, 0, 0, 0, 0, 0, 0, 0, 74, 126, 83, 0, 0, 55, 67, 0, 55, 86, 0, 114, 0, 145, 0,
+            0, 133, 0, 0, 67, 138, 128, 123, 0, 0, 0, 89, 0, 0, 0, 117, 0, 79, 0, 0, 0, 97, 0, 103,
+            0, 55, 97, 0, 115, 0, 86, 79, 110, 91, 134, 0, 102, 0, 0, 0, 0, 0, 0, 110, 0, 67, 0, 0,
+            0, 0, 0, 0, 0, 118, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 55, 67, 0, 95, 0, 0, 67, 0,
+            150, 0, 0, 0, 0, 0, 0, 138, 112, 131, 113, 113, 74, 0, 0, 135, 138, 141, 0, 79, 74, 0,
+            94, 106, 67, 0, 0, 74, 0, 67, 149, 133, 137, 0, 120, 0, 127, 0, 127, 0, 0, 0, 0, 0, 0,
+            145, 83, 120, 79, 0, 0, 0, 0, 0, 67, 126, 79, 79, 79, 0, 55, 111, 0, 0, 0, 0, 0, 0,
+            110, 67, 86, 0, 141, 0, 155, 0, 164, 0, 0, 55, 0, 55, 55, 153, 79, 125, 143, 0, 0, 113,
+            0, 74, 103, 148, 55, 106, 137, 83, 100, 0, 106, 106, 95, 0, 122, 86, 136, 79, 113, 101,
+            125, 55, 160, 0, 145, 0, 0, 0, 0, 0, 0, 144, 118, 120, 117, 0, 0, 55, 0, 125, 0, 138,
+            0, 83, 55, 102, 0, 125, 0, 118, 0, 128, 74, 0, 119, 106, 91, 74, 125, 74, 138, 0, 148,
+            0, 0, 0, 0, 0, 0, 148, 127, 127, 135, 89, 0, 55, 0, 67, 0, 144, 55, 127, 83, 0, 0, 0,
+            0, 74, 0, 67, 86, 0, 129, 115, 138, 0, 146, 0, 149, 0, 172, 67, 98, 157, 0, 148, 55,
+            168, 158, 141, 156, 130, 141, 142, 136, 144, 106, 142, 106, 146, 127, 141, 103, 127, 0,
+            146, 136, 161, 143, 153, 152, 175, 149, 136, 159, 145, 169, 0, 165, 0, 0, 0, 108, 0, 0,
+            161, 131, 152, 144, 105, 142, 137, 116, 130, 67, 137, 115, 157, 116, 129, 120, 117,
+            115, 144, 110, 148, 126, 137, 159, 132, 144, 155, 150, 74, 160, 0, 169, 0, 0, 0, 0, 0,
+            0, 156, 100, 128, 143, 0, 134, 67, 91, 160, 0, 107, 114, 140, 118, 127, 0, 106, 108,
+            74, 135, 120, 124, 125, 108, 121, 0, 119, 139, 74, 166, 0, 136, 0, 0, 0, 0, 0, 0, 139,
+            67, 91, 83, 0, 0, 0, 0, 91, 117, 113, 55, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 91, 117,
+            122, 0, 157, 101, 118, 0, 160, 83, 0, 0, 0, 67, 67, 153, 142, 55, 144, 86, 132, 132,
+            67, 134, 79, 171, 127, 146, 120, 122, 91, 123, 67, 126, 119, 148, 133, 128, 158, 143,
+            159, 86, 79, 131, 154, 0, 156, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 173, 97, 0, 0, 74, 0, 102, 165,
+            131, 183, 147, 114, 134, 145, 95, 146, 101, 163, 140, 150, 115, 113, 97, 129, 55, 119,
+            129, 139, 136, 147, 154, 153, 159, 111, 147, 55, 124, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+    },
+    LanguageModel {
+        language: "fa",
+        script: Script::Arabic,
+        alphabet: &[
+            '\u{0621}', '\u{0622}', '\u{0623}', '\u{0624}', '\u{0626}', '\u{0627}', '\u{0628}',
+            '\u{062A}', '\u{062B}', '\u{062C}', '\u{062D}', '\u{062E}', '\u{062F}', '\u{0630}',
+            '\u{0631}', '\u{0632}', '\u{0633}', '\u{0634}', '\u{0635}', '\u{0636}', '\u{0637}',
+            '\u{0638}', '\u{0639}', '\u{063A}', '\u{0641}', '\u{0642}', '\u{0644}', '\u{0645}',
+            '\u{0646}', '\u{0647}', '\u{0648}', '\u{064A}', '\u{067E}', '\u{0686}', '\u{0698}',
+            '\u{06A9}', '\u{06AF}',
+        ],
+        bigrams: &[
+            0, 0, 153, 0, 0, 85, 184, 182, 175, 107, 169, 149, 164, 175, 111, 166, 146, 165, 170,
+            138, 80, 117, 80, 153, 130, 159, 150, 146, 178, 184, 163, 158, 166, 177, 144, 111, 173,
+            159, 0, 99, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 85, 0, 0, 0, 0, 0, 0, 92, 73, 0, 0, 0, 73, 108,
+            97, 113, 89, 92, 0, 0, 0, 0, 0, 0, 89, 109, 0, 122, 101, 120, 0, 97, 113, 73, 97, 61,
+            85, 89, 0, 73, 0, 0, 0, 0, 0, 0, 0, 0, 80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 85, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 61, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 61, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 99, 0, 0, 85, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 92, 0, 95, 109,
+            132, 116, 0, 0, 0, 0, 0, 0, 175, 61, 0, 0, 0, 130, 0, 147, 153, 0, 121, 109, 143, 164,
+            0, 175, 160, 166, 144, 119, 106, 116, 61, 134, 73, 151, 128, 167, 171, 184, 147, 138,
+            188, 127, 85, 92, 136, 122, 0, 151, 0, 0, 0, 0, 0, 170, 61, 114, 0, 85, 92, 109, 133,
+            0, 167, 115, 126, 73, 0, 0, 101, 0, 117, 0, 61, 89, 134, 73, 122, 149, 145, 151, 0, 0,
+            0, 119, 95, 0, 177, 0, 0, 92, 0, 0, 158, 148, 73, 0, 111, 122, 134, 92, 0, 145, 61,
+            113, 92, 149, 0, 73, 129, 122, 125, 136, 97, 103, 128, 150, 140, 161, 159, 0, 0, 0,
+            119, 97, 0, 73, 0, 0, 0, 0, 0, 97, 80, 0, 0, 0, 0, 0, 0, 0, 106, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 73, 95, 85, 0, 0, 0, 80, 0, 0, 0, 0, 0, 0, 124, 0, 0, 0, 0, 0, 142, 0, 103, 0,
+            0, 0, 0, 125, 0, 131, 138, 103, 73, 0, 0, 0, 0, 103, 0, 73, 0, 0, 159, 118, 106, 131,
+            131, 85, 0, 0, 0, 0, 0, 120, 0, 0, 0, 0, 0, 142, 0, 108, 0, 80, 0, 0, 136, 104, 99, 0,
+            92, 0, 0, 0, 0, 0, 0, 0, 0, 0, 112, 0, 61, 121, 0, 108, 0, 0, 0, 0, 0, 0, 97, 0, 0, 0,
+            0, 0, 140, 0, 140, 0, 0, 0, 0, 95, 0, 116, 0, 0, 97, 133, 0, 149, 0, 0, 0, 85, 0, 127,
+            61, 0, 124, 144, 127, 0, 0, 0, 0, 0, 0, 180, 0, 0, 80, 0, 0, 169, 61, 0, 0, 0, 0, 99,
+            110, 0, 164, 0, 135, 129, 0, 0, 0, 0, 0, 0, 80, 95, 106, 124, 153, 173, 150, 159, 73,
+            0, 0, 106, 103, 0, 0, 0, 0, 0, 0, 0, 117, 0, 0, 0, 0, 0, 111, 0, 0, 118, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 104, 0, 0, 0, 0, 0, 0, 116, 0, 0, 0, 0, 0, 0, 183, 0, 0, 0, 0, 97, 172,
+            134, 135, 0, 129, 113, 116, 164, 0, 89, 128, 145, 117, 110, 126, 0, 0, 85, 0, 130, 123,
+            111, 133, 148, 147, 170, 175, 61, 121, 85, 131, 131, 0, 157, 0, 0, 0, 0, 80, 144, 134,
+            61, 0, 0, 0, 0, 99, 0, 111, 0, 85, 89, 0, 0, 0, 0, 0, 0, 0, 0, 0, 127, 127, 118, 114,
+            150, 0, 0, 0, 85, 108, 0, 146, 0, 0, 0, 0, 0, 149, 130, 175, 0, 0, 0, 103, 80, 0, 135,
+            61, 61, 0, 0, 0, 124, 0, 89, 85, 73, 0, 131, 85, 131, 136, 144, 155, 108, 0, 0, 116, 0,
+            0, 149, 0, 0, 0, 0, 61, 152, 99, 150, 0, 0, 0, 129, 154, 0, 123, 0, 73, 0, 0, 0, 0, 0,
+            80, 85, 73, 85, 85, 132, 133, 128, 145, 136, 0, 0, 0, 146, 92, 0, 136, 0, 0, 0, 0, 0,
+            118, 80, 0, 0, 0, 101, 0, 110, 0, 125, 0, 0, 0, 0, 0, 0, 0, 0, 0, 134, 0, 120, 0, 73,
+            111, 148, 125, 0, 0, 0, 0, 0, 0, 127, 0, 0, 0, 0, 0, 117, 0, 0, 0, 0, 0, 0, 0, 0, 85,
+            0, 0, 0, 0, 0, 0, 0, 89, 0, 0, 0, 0, 0, 0, 0, 61, 97, 0, 0, 0, 0, 0, 0, 128, 0, 0, 0,
+            0, 0, 147, 89, 0, 0, 0, 80, 0, 0, 0, 120, 0, 0, 0, 0, 0, 0, 0, 97, 0, 0, 92, 108, 61,
+            0, 115, 111, 110, 0, 0, 0, 0, 0, 0, 73, 0, 0, 0, 0, 0, 116, 0, 61, 0, 0, 0, 0, 0, 0,
+            120, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 131, 0, 116, 0, 0, 0, 0, 0, 0, 141, 0,
+            0, 0, 0, 0, 128, 113, 142, 85, 0, 0, 0, 130, 0, 132, 0, 0, 73, 0, 89, 61, 0, 0, 0, 73,
+            61, 129, 125, 128, 97, 92, 122, 0, 0, 0, 85, 0, 0, 0, 0, 0, 0, 0, 0, 124, 0, 0, 0, 0,
+            0, 0, 85, 0, 73, 80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 80, 0, 85, 0, 104, 136, 0, 0, 0, 0,
+            0, 0, 133, 0, 0, 0, 0, 0, 144, 61, 141, 0, 0, 121, 0, 111, 0, 148, 85, 97, 103, 61,
+            104, 0, 131, 123, 85, 0, 116, 108, 0, 108, 119, 109, 135, 0, 0, 0, 0, 61, 0, 126, 0, 0,
+            0, 0, 0, 141, 116, 106, 0, 0, 0, 0, 139, 0, 111, 97, 89, 120, 113, 0, 127, 0, 112, 0,
+            95, 0, 128, 92, 0, 101, 107, 132, 0, 0, 0, 0, 0, 0, 162, 0, 0, 0, 0, 92, 155, 132, 129,
+            61, 85, 0, 0, 110, 0, 0, 107, 116, 61, 0, 0, 73, 61, 80, 107, 106, 113, 80, 129, 136,
+            132, 146, 163, 85, 0, 85, 114, 101, 0, 163, 0, 0, 0, 61, 61, 165, 134, 141, 95, 122,
+            119, 85, 115, 0, 129, 107, 124, 130, 104, 85, 97, 0, 146, 95, 80, 142, 119, 97, 141,
+            164, 155, 164, 80, 0, 0, 123, 73, 0, 181, 0, 0, 0, 0, 73, 170, 121, 149, 0, 119, 61, 0,
+            176, 0, 107, 123, 140, 147, 116, 0, 95, 118, 0, 85, 0, 132, 101, 159, 111, 153, 159,
+            172, 92, 97, 0, 123, 159, 0, 188, 0, 0, 0, 0, 0, 160, 0, 99, 0, 0, 0, 0, 101, 0, 122,
+            0, 99, 80, 0, 0, 0, 0, 0, 0, 61, 0, 117, 114, 141, 106, 160, 143, 0, 0, 0, 0, 61, 0,
+            154, 0, 107, 0, 0, 127, 167, 133, 133, 0, 135, 0, 85, 157, 0, 171, 118, 139, 138, 107,
+            97, 0, 0, 128, 0, 89, 121, 147, 136, 171, 126, 116, 171, 113, 97, 104, 138, 120, 0,
+            202, 97, 0, 0, 0, 0, 173, 142, 149, 0, 135, 104, 111, 154, 0, 168, 125, 155, 148, 123,
+            0, 85, 0, 85, 89, 124, 120, 148, 136, 165, 139, 142, 171, 107, 119, 97, 157, 122, 0,
+            120, 0, 0, 0, 0, 0, 150, 0, 85, 0, 0, 0, 73, 0, 116, 159, 0, 114, 135, 0, 0, 0, 0, 0,
+            0, 0, 0, 103, 0, 118, 106, 133, 153, 0, 0, 0, 0, 0, 0, 120, 0, 0, 0, 0, 85, 116, 0, 0,
+            0, 0, 0, 0, 0, 0, 95, 0, 116, 73, 0, 0, 0, 0, 0, 0, 0, 0, 0, 97, 111, 85, 123, 119,
+            104, 85, 0, 108, 0, 0, 85, 0, 0, 0, 0, 0, 107, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 101, 97, 103, 0, 0, 0, 0, 92, 0, 155, 0, 0, 0, 0, 0, 157,
+            73, 119, 106, 0, 0, 0, 113, 0, 156, 97, 142, 116, 0, 0, 0, 0, 0, 0, 0, 0, 138, 121,
+            144, 137, 139, 145, 85, 85, 0, 0, 0, 0, 142, 0, 0, 0, 0, 0, 151, 92, 0, 0, 89, 0, 0, 0,
+            126, 142, 125, 95, 117, 0, 0, 0, 0, 0, 0, 61, 0, 118, 89, 101, 85, 146, 140, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+    },
+    LanguageModel {
+        language: "th",
+        script: Script::Thai,
+        alphabet: &[
+            '\u{0E01}', '\u{0E02}', '\u{0E03}', '\u{0E04}', '\u{0E05}', '\u{0E06}', '\u{0E07}',
+            '\u{0E08}', '\u{0E09}', '\u{0E0A}', '\u{0E0B}', '\u{0E0C}', '\u{0E0D}', '\u{0E0E}',
+            '\u{0E0F}', '\u{0E10}', '\u{0E11}', '\u{0E12}', '\u{0E13}', '\u{0E14}', '\u{0E15}',
+            '\u{0E16}', '\u{0E17}', '\u{0E18}', '\u{0E19}', '\u{0E1A}', '\u{0E1B}', '\u{0E1C}',
+            '\u{0E1D}', '\u{0E1E}', '\u{0E1F}', '\u{0E20}', '\u{0E21}', '\u{0E22}', '\u{0E23}',
+            '\u{0E24}', '\u{0E25}', '\u{0E26}', '\u{0E27}', '\u{0E28}', '\u{0E29}', '\u{0E2A}',
+            '\u{0E2B}', '\u{0E2C}', '\u{0E2D}', '\u{0E2E}', '\u{0E2F}', '\u{0E30}', '\u{0E31}',
+            '\u{0E32}', '\u{0E33}', '\u{0E34}', '\u{0E35}', '\u{0E36}', '\u{0E37}', '\u{0E38}',
+            '\u{0E39}', '\u{0E3A}', '\u{0E40}', '\u{0E41}', '\u{0E42}', '\u{0E43}', '\u{0E44}',
+            '\u{0E45}', '\u{0E46}', '\u{0E47}', '\u{0E48}', '\u{0E49}', '\u{0E4A}', '\u{0E4B}',
+            '\u{0E4C}', '\u{0E4D}', '\u{0E4E}',
+        ],
+        bigrams: &[
+            0, 156, 142, 0, 153, 0, 0, 82, 145, 87, 140, 149, 0, 88, 0, 0, 0, 0, 0, 0, 136, 150,
+            123, 142, 80, 141, 141, 140, 107, 106, 142, 121, 145, 152, 130, 144, 0, 141, 0, 124,
+            114, 0, 158, 144, 0, 161, 126, 44, 0, 0, 44, 0, 0, 0, 0, 0, 0, 0, 0, 173, 161, 151,
+            135, 167, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 159, 118, 130, 0, 112, 0, 56, 96, 153, 44,
+            109, 120, 0, 0, 63, 84, 44, 0, 0, 87, 100, 142, 88, 135, 72, 102, 88, 103, 123, 44, 78,
+            72, 78, 106, 90, 151, 121, 145, 0, 129, 82, 109, 128, 102, 0, 124, 44, 0, 100, 146,
+            169, 140, 148, 131, 63, 0, 107, 117, 0, 158, 123, 112, 104, 114, 0, 63, 120, 127, 119,
+            68, 0, 120, 0, 0, 0, 97, 56, 63, 0, 0, 0, 0, 0, 63, 56, 44, 0, 0, 0, 0, 0, 68, 0, 0,
+            128, 56, 125, 0, 56, 0, 114, 0, 0, 56, 0, 0, 0, 0, 84, 103, 126, 0, 63, 0, 97, 0, 0,
+            85, 56, 0, 157, 0, 0, 0, 114, 111, 0, 72, 128, 118, 0, 63, 0, 0, 44, 44, 63, 68, 63, 0,
+            0, 44, 85, 156, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            121, 119, 56, 0, 104, 0, 0, 97, 0, 0, 99, 63, 0, 0, 0, 0, 68, 0, 0, 56, 0, 109, 0, 44,
+            68, 108, 87, 0, 0, 0, 72, 44, 0, 105, 88, 143, 44, 122, 0, 148, 0, 0, 97, 0, 0, 130, 0,
+            0, 80, 125, 121, 130, 116, 114, 0, 118, 131, 102, 0, 111, 63, 107, 87, 72, 0, 0, 0,
+            143, 104, 0, 0, 100, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 63, 0, 0, 0, 0, 0, 0, 0, 165, 152, 132, 0, 134, 0, 0, 91, 119, 63,
+            109, 96, 0, 0, 0, 0, 63, 0, 0, 0, 102, 131, 107, 123, 0, 105, 103, 104, 88, 63, 112,
+            63, 94, 124, 97, 140, 0, 110, 0, 108, 56, 0, 130, 128, 0, 132, 63, 0, 94, 0, 126, 0,
+            85, 63, 0, 75, 56, 0, 0, 146, 146, 125, 128, 134, 0, 104, 0, 56, 0, 0, 0, 0, 0, 0, 0,
+            145, 116, 84, 0, 75, 0, 0, 105, 109, 0, 78, 75, 0, 0, 0, 0, 0, 0, 0, 0, 93, 107, 56,
+            124, 0, 112, 114, 44, 75, 0, 44, 0, 0, 93, 44, 102, 0, 78, 0, 93, 0, 0, 117, 78, 0,
+            121, 0, 0, 142, 136, 140, 130, 122, 127, 85, 44, 117, 91, 0, 108, 93, 80, 94, 102, 0,
+            0, 87, 56, 104, 0, 0, 68, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 114, 0, 0, 0, 82, 0, 0, 0, 56, 0, 0, 78, 0, 75, 0, 0, 0, 0,
+            0, 0, 0, 0, 63, 0, 44, 0, 68, 100, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 114, 82, 82, 0, 72, 0, 0, 0, 56, 0, 75, 56, 0, 0, 0, 0, 0, 0, 0, 0, 44, 98,
+            0, 44, 56, 129, 56, 80, 0, 0, 0, 56, 0, 63, 75, 97, 0, 100, 0, 103, 0, 0, 84, 0, 0,
+            112, 56, 0, 82, 112, 121, 0, 124, 127, 0, 147, 108, 80, 0, 103, 44, 100, 44, 85, 0, 0,
+            85, 134, 149, 0, 0, 101, 0, 0, 0, 119, 92, 0, 0, 84, 0, 0, 84, 0, 0, 0, 56, 0, 0, 0, 0,
+            0, 0, 0, 0, 78, 100, 0, 44, 0, 122, 75, 84, 0, 0, 0, 68, 0, 107, 68, 84, 0, 96, 0, 84,
+            0, 0, 109, 0, 0, 130, 0, 0, 84, 124, 142, 0, 130, 141, 116, 0, 101, 120, 0, 114, 0,
+            106, 0, 0, 0, 0, 110, 80, 114, 0, 0, 107, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 56, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 97, 56, 63, 0, 0, 0, 0, 0, 87, 0, 0, 0, 0, 97, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 68, 0, 0, 0, 0, 0, 0, 0, 0, 0, 72, 0, 0, 0, 0, 44, 117, 0, 0, 0, 0, 0,
+            0, 109, 0, 0, 92, 0, 0, 0, 0, 0, 44, 85, 68, 0, 0, 0, 0, 0, 96, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 72, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 56, 0, 0, 44, 0, 0, 0, 0,
+            0, 44, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 44, 44, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 44, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            56, 0, 0, 44, 44, 0, 0, 0, 0, 0, 0, 80, 0, 85, 63, 0, 0, 0, 0, 0, 0, 0, 0, 63, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 88, 90, 0, 0, 90, 0, 0, 0, 56, 0, 68, 84, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 68, 0, 56, 0, 75, 92, 110, 0, 56, 75, 75, 0, 92, 56, 68, 0, 80, 0, 68, 0, 0,
+            96, 68, 0, 112, 56, 0, 0, 0, 116, 0, 0, 82, 0, 0, 0, 0, 0, 109, 90, 100, 0, 87, 0, 0,
+            0, 0, 0, 0, 0, 56, 0, 0, 0, 56, 56, 0, 0, 56, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 56, 0, 75, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 56, 0, 0, 0, 0, 44, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 56, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 44, 0, 0, 0,
+            0, 0, 0, 44, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 135, 68, 0, 0, 78, 0, 0, 0, 72, 0,
+            0, 0, 0, 0, 0, 0, 0, 87, 0, 72, 0, 87, 0, 56, 0, 0, 68, 0, 0, 0, 44, 0, 63, 75, 44,
+            141, 0, 0, 0, 0, 0, 0, 104, 68, 0, 78, 0, 0, 129, 0, 120, 0, 44, 101, 0, 0, 56, 0, 0,
+            75, 44, 0, 0, 72, 0, 0, 0, 0, 0, 0, 0, 113, 0, 0, 0, 150, 117, 141, 0, 97, 0, 0, 130,
+            111, 0, 82, 95, 0, 0, 0, 0, 0, 0, 0, 0, 108, 136, 114, 122, 0, 120, 96, 97, 56, 0, 136,
+            80, 105, 102, 126, 123, 0, 118, 0, 113, 0, 0, 112, 109, 0, 130, 0, 0, 87, 129, 139, 95,
+            127, 135, 63, 63, 84, 123, 0, 143, 128, 96, 121, 118, 0, 82, 0, 63, 153, 0, 0, 129, 0,
+            0, 0, 135, 129, 120, 0, 68, 0, 0, 92, 0, 0, 84, 97, 0, 0, 0, 0, 0, 0, 0, 0, 68, 111,
+            63, 110, 0, 113, 56, 90, 44, 0, 0, 72, 0, 102, 116, 144, 0, 96, 0, 94, 44, 0, 106, 75,
+            0, 128, 0, 0, 132, 157, 141, 103, 149, 120, 0, 88, 123, 122, 0, 127, 97, 113, 97, 100,
+            0, 0, 102, 142, 151, 63, 0, 147, 0, 0, 0, 112, 92, 80, 0, 85, 0, 44, 0, 93, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 85, 109, 80, 75, 0, 63, 97, 95, 0, 0, 44, 44, 0, 56, 72, 87, 0,
+            108, 0, 82, 0, 0, 107, 72, 0, 133, 0, 0, 44, 85, 135, 0, 90, 44, 104, 78, 56, 136, 0,
+            127, 99, 63, 97, 0, 0, 0, 0, 72, 103, 0, 0, 63, 0, 0, 0, 100, 72, 80, 0, 82, 0, 0, 56,
+            56, 0, 56, 56, 0, 0, 0, 0, 0, 0, 0, 0, 75, 63, 44, 0, 98, 118, 82, 0, 0, 0, 0, 0, 0,
+            97, 102, 130, 44, 78, 0, 78, 108, 0, 56, 0, 0, 133, 0, 0, 90, 136, 127, 131, 126, 162,
+            102, 56, 100, 90, 0, 84, 80, 56, 68, 63, 0, 0, 99, 106, 112, 0, 0, 114, 0, 0, 0, 100,
+            44, 72, 0, 0, 0, 0, 0, 44, 0, 44, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 63, 0, 72, 0, 0, 0, 44,
+            0, 0, 0, 0, 0, 63, 68, 94, 0, 0, 0, 0, 0, 0, 44, 44, 0, 85, 0, 0, 0, 63, 143, 0, 116,
+            115, 0, 0, 56, 0, 0, 75, 56, 0, 0, 56, 0, 0, 0, 0, 0, 0, 0, 110, 0, 0, 0, 170, 132,
+            134, 0, 130, 0, 0, 44, 118, 91, 123, 121, 0, 56, 0, 0, 82, 0, 0, 0, 149, 150, 91, 139,
+            109, 107, 117, 106, 84, 0, 105, 72, 72, 121, 130, 126, 0, 114, 0, 131, 87, 0, 131, 131,
+            0, 141, 90, 0, 122, 125, 148, 97, 141, 151, 108, 129, 109, 105, 0, 144, 142, 131, 114,
+            134, 0, 56, 56, 125, 126, 0, 0, 123, 0, 0, 0, 147, 132, 118, 0, 126, 0, 0, 92, 110,
+            102, 100, 88, 0, 0, 0, 0, 0, 0, 0, 0, 95, 115, 82, 111, 56, 124, 142, 104, 97, 44, 75,
+            68, 44, 82, 85, 151, 0, 120, 0, 106, 0, 0, 119, 88, 0, 132, 56, 0, 75, 140, 139, 0,
+            132, 134, 68, 63, 130, 126, 0, 136, 134, 112, 103, 113, 0, 44, 85, 93, 44, 0, 0, 56, 0,
+            0, 0, 122, 118, 82, 0, 0, 0, 0, 63, 56, 0, 78, 75, 0, 56, 0, 85, 56, 0, 0, 0, 75, 113,
+            0, 72, 0, 111, 0, 97, 0, 0, 56, 0, 125, 56, 113, 149, 0, 138, 0, 56, 0, 0, 80, 56, 0,
+            114, 0, 0, 80, 132, 129, 0, 134, 120, 0, 56, 115, 95, 0, 103, 123, 88, 68, 105, 0, 0,
+            141, 72, 98, 0, 0, 99, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 56, 0, 0, 0, 0, 0, 0, 0, 0, 56, 0, 0, 112, 0, 0, 0, 0, 123, 0, 0,
+            0, 0, 0, 0, 56, 0, 0, 139, 0, 0, 0, 44, 112, 0, 0, 0, 0, 0, 0, 0, 0, 0, 111, 44, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 110, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 72, 44, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 44, 80, 0, 0, 0, 0, 0, 0, 119, 152, 75, 0,
+            78, 0, 0, 68, 88, 0, 101, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 96, 56, 80, 94, 93, 131, 0, 44,
+            0, 68, 56, 0, 94, 108, 128, 78, 138, 0, 56, 0, 0, 44, 68, 0, 117, 0, 0, 68, 115, 121,
+            0, 135, 92, 0, 127, 84, 98, 0, 87, 68, 0, 68, 68, 0, 0, 0, 0, 0, 0, 0, 121, 0, 0, 0,
+            100, 85, 0, 0, 0, 0, 0, 0, 0, 0, 0, 82, 0, 0, 0, 0, 0, 0, 0, 0, 68, 84, 0, 44, 0, 0, 0,
+            68, 0, 0, 44, 78, 0, 44, 56, 114, 0, 115, 0, 56, 0, 0, 84, 0, 0, 113, 44, 0, 0, 85,
+            100, 0, 113, 85, 0, 63, 84, 87, 0, 108, 80, 87, 56, 44, 0, 0, 0, 0, 157, 0, 0, 68, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 68, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 56, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 93, 155, 0, 0, 0, 0,
+            0, 0, 88, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 157, 119, 123, 0, 123, 0,
+            0, 0, 121, 44, 111, 97, 0, 0, 0, 0, 63, 44, 0, 93, 124, 126, 88, 122, 82, 135, 118,
+            114, 94, 0, 127, 44, 111, 100, 93, 129, 0, 122, 0, 107, 0, 56, 128, 106, 0, 136, 72, 0,
+            111, 130, 165, 0, 129, 153, 56, 144, 95, 148, 0, 136, 120, 115, 117, 111, 0, 0, 85,
+            172, 96, 0, 0, 56, 0, 0, 0, 160, 140, 109, 0, 125, 0, 0, 125, 80, 56, 120, 56, 0, 0, 0,
+            0, 0, 0, 0, 56, 110, 104, 80, 112, 56, 144, 111, 100, 56, 0, 44, 56, 75, 117, 91, 121,
+            0, 109, 0, 122, 56, 0, 111, 112, 0, 125, 0, 0, 111, 131, 142, 0, 115, 87, 63, 104, 121,
+            141, 0, 121, 115, 112, 118, 113, 0, 0, 0, 133, 101, 0, 0, 126, 0, 0, 0, 136, 139, 87,
+            0, 96, 0, 0, 112, 112, 0, 84, 82, 0, 0, 0, 0, 102, 0, 0, 145, 111, 124, 152, 128, 56,
+            121, 127, 110, 0, 0, 82, 0, 91, 135, 110, 135, 0, 105, 0, 126, 72, 87, 127, 118, 0,
+            144, 56, 0, 153, 163, 162, 56, 148, 156, 78, 143, 141, 145, 0, 138, 126, 105, 106, 105,
+            0, 0, 132, 104, 129, 0, 0, 168, 0, 0, 0, 44, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 78, 0, 0, 0, 44, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 56, 120,
+            0, 44, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 143, 112, 123, 0, 91, 0, 0, 126, 99, 0, 80, 99, 0, 56, 0, 56, 0, 0, 0, 0,
+            122, 111, 0, 97, 0, 129, 129, 84, 102, 0, 63, 63, 95, 100, 107, 105, 0, 126, 0, 118, 0,
+            0, 125, 68, 0, 130, 68, 44, 142, 142, 159, 102, 144, 145, 56, 140, 122, 137, 0, 133,
+            113, 106, 72, 104, 0, 0, 117, 130, 135, 0, 0, 118, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 145, 123, 112, 0, 97, 0, 0, 114, 118, 0, 78, 75, 0, 0, 0, 0, 0,
+            0, 0, 84, 93, 118, 0, 85, 68, 143, 119, 0, 56, 0, 68, 56, 44, 110, 140, 124, 0, 123, 0,
+            68, 56, 0, 111, 72, 0, 126, 0, 0, 97, 137, 155, 0, 129, 129, 0, 56, 68, 56, 0, 141,
+            105, 100, 80, 98, 0, 0, 72, 135, 128, 0, 0, 84, 0, 0, 0, 115, 0, 0, 0, 0, 0, 0, 0, 63,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 80, 0, 63, 0, 56, 92, 0, 0, 0, 0, 0, 0, 68, 0, 100,
+            0, 0, 0, 0, 0, 85, 110, 0, 0, 0, 0, 0, 0, 63, 56, 0, 63, 56, 0, 0, 44, 97, 0, 68, 0, 0,
+            0, 44, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 103, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 75,
+            0, 63, 0, 0, 90, 0, 44, 0, 88, 0, 44, 0, 0, 0, 0, 0, 0, 56, 56, 75, 98, 0, 111, 0, 0,
+            0, 0, 0, 56, 0, 44, 56, 0, 0, 0, 146, 0, 44, 0, 0, 0, 0, 0, 0, 0, 80, 75, 0, 63, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 140, 116, 94, 0, 102, 0, 0, 93, 44, 75, 68, 103, 0, 0, 0, 0,
+            0, 0, 0, 0, 130, 129, 132, 80, 78, 100, 72, 88, 85, 0, 0, 68, 80, 136, 97, 130, 0, 116,
+            0, 118, 44, 0, 99, 112, 0, 122, 0, 0, 125, 134, 161, 147, 125, 130, 0, 82, 120, 113, 0,
+            129, 97, 120, 44, 92, 0, 0, 0, 133, 72, 0, 0, 128, 0, 0, 0, 56, 72, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 96, 0, 0, 0, 0, 0, 0, 0, 104, 0, 0, 0, 150, 0, 0, 0, 0, 95, 0, 88, 145,
+            93, 150, 0, 142, 0, 115, 0, 0, 68, 0, 0, 0, 0, 0, 56, 128, 136, 0, 56, 56, 0, 0, 72,
+            56, 0, 0, 0, 0, 0, 0, 0, 0, 56, 75, 130, 0, 0, 121, 0, 0, 0, 72, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 56, 0, 0, 0, 0, 0, 0, 72, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 68, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 143, 154, 108, 0, 133, 0, 0, 171, 114, 0, 100, 109, 0,
+            84, 0, 44, 0, 0, 0, 0, 128, 122, 88, 110, 90, 152, 136, 108, 132, 72, 90, 115, 0, 152,
+            149, 160, 0, 137, 0, 103, 56, 0, 135, 108, 0, 142, 87, 0, 112, 148, 150, 97, 141, 129,
+            87, 102, 118, 115, 0, 134, 135, 117, 108, 129, 0, 44, 109, 130, 103, 0, 0, 0, 0, 0, 0,
+            75, 68, 0, 0, 0, 0, 0, 0, 56, 0, 72, 75, 0, 0, 0, 0, 0, 0, 0, 0, 0, 87, 0, 0, 0, 63,
+            44, 0, 0, 0, 0, 56, 0, 94, 56, 82, 0, 68, 0, 84, 0, 0, 68, 0, 0, 93, 0, 0, 0, 106, 121,
+            0, 110, 102, 0, 0, 75, 68, 0, 90, 0, 80, 0, 0, 0, 0, 0, 82, 0, 0, 0, 0, 0, 0, 0, 44, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 44, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 136, 132, 111, 0, 95, 0, 0, 92, 100, 68,
+            111, 78, 0, 0, 0, 0, 44, 0, 0, 0, 116, 116, 103, 118, 0, 109, 134, 105, 63, 0, 103, 82,
+            80, 124, 94, 95, 0, 116, 0, 130, 0, 0, 106, 120, 0, 115, 80, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 138, 118, 109, 106, 118, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 56, 143, 0, 0, 96,
+            0, 0, 153, 97, 0, 110, 75, 0, 127, 0, 0, 145, 0, 56, 82, 145, 134, 0, 82, 56, 158, 159,
+            56, 0, 0, 109, 98, 0, 123, 110, 0, 0, 130, 0, 149, 75, 0, 135, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 135, 150, 87, 0, 0, 0, 0, 0, 167, 150,
+            115, 0, 130, 0, 0, 158, 129, 0, 130, 121, 0, 68, 0, 0, 85, 56, 0, 137, 149, 141, 91,
+            124, 145, 162, 125, 124, 56, 56, 131, 90, 63, 169, 158, 179, 0, 138, 0, 137, 85, 147,
+            125, 124, 78, 120, 110, 0, 130, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 149, 121, 127, 111, 113,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 113, 103, 56, 0, 100, 0, 0, 114, 56, 0, 0, 63, 0, 0,
+            0, 0, 0, 0, 0, 0, 100, 94, 84, 84, 0, 121, 91, 85, 0, 0, 84, 0, 0, 56, 68, 96, 0, 124,
+            0, 44, 0, 0, 121, 146, 0, 72, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 135, 112, 44, 100,
+            109, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 128, 147, 75, 0, 87, 0, 0, 136, 104, 44, 114, 96,
+            0, 68, 0, 0, 0, 56, 0, 0, 162, 129, 56, 115, 111, 150, 125, 121, 0, 0, 90, 99, 78, 137,
+            114, 131, 0, 131, 0, 135, 56, 68, 133, 95, 82, 72, 56, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 136, 104, 121, 80, 80, 0, 0, 0, 129, 107, 0, 0, 95, 0, 0, 0, 157, 135, 111, 0, 114,
+            0, 0, 56, 84, 0, 104, 106, 0, 0, 0, 0, 82, 0, 0, 0, 115, 108, 63, 109, 56, 133, 118,
+            114, 56, 0, 82, 91, 0, 110, 168, 129, 0, 115, 0, 99, 0, 0, 118, 80, 0, 127, 68, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 137, 119, 125, 109, 93, 0, 0, 0, 164, 134, 0, 44, 0, 0,
+            0, 0, 0, 104, 0, 0, 0, 0, 0, 111, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 56, 0, 0, 0, 0,
+            78, 63, 0, 0, 0, 0, 0, 0, 84, 0, 0, 0, 56, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 125, 118, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 56, 0, 0, 0, 0, 114, 72, 0, 0, 0, 0, 0, 0,
+            63, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 161, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 158, 120, 0, 0, 0, 0, 0, 0, 107, 114, 75, 0, 124, 0, 0, 91, 56, 0,
+            99, 98, 0, 103, 0, 0, 0, 0, 0, 128, 130, 104, 0, 92, 63, 106, 97, 87, 0, 0, 78, 0, 0,
+            120, 104, 121, 0, 108, 0, 82, 0, 0, 100, 72, 0, 63, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 112, 91, 88, 56, 75, 0, 0, 0, 142, 75, 0, 0, 56, 0, 0, 0, 130, 148, 56, 0, 78, 0, 0,
+            111, 82, 44, 85, 97, 0, 0, 0, 80, 0, 0, 0, 0, 105, 103, 0, 88, 0, 121, 110, 136, 44, 0,
+            44, 63, 0, 97, 56, 129, 0, 148, 0, 100, 0, 0, 87, 75, 0, 80, 72, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 124, 93, 104, 75, 82, 0, 0, 0, 141, 126, 0, 68, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 99, 166, 141, 0, 136, 0, 0, 94, 130, 105, 140,
+            152, 56, 56, 0, 0, 0, 0, 0, 0, 141, 143, 56, 137, 80, 145, 145, 155, 72, 80, 137, 107,
+            56, 146, 121, 152, 0, 154, 0, 144, 114, 0, 127, 134, 0, 144, 112, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 88, 133, 44, 0, 124, 0,
+            0, 0, 115, 0, 85, 104, 0, 0, 0, 0, 0, 0, 0, 0, 100, 130, 100, 124, 56, 114, 139, 117,
+            97, 0, 153, 158, 0, 115, 104, 94, 0, 150, 0, 106, 0, 0, 130, 116, 0, 129, 97, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 72, 140, 63,
+            0, 127, 0, 0, 56, 82, 0, 68, 120, 0, 0, 0, 0, 0, 56, 0, 0, 137, 129, 68, 97, 0, 125,
+            136, 128, 0, 0, 112, 105, 68, 119, 103, 128, 0, 118, 0, 100, 0, 0, 84, 118, 0, 131,
+            101, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 68, 0, 44, 0, 0, 0, 0, 0, 93, 0, 152, 0, 0, 0, 0, 0, 0, 0, 0, 0, 98, 121, 0, 0, 0,
+            147, 63, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 44, 0, 0, 56, 141, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 56, 93, 102, 0,
+            87, 0, 0, 0, 68, 0, 56, 96, 0, 0, 0, 0, 0, 0, 0, 0, 153, 117, 75, 115, 0, 99, 113, 138,
+            0, 0, 84, 91, 0, 171, 0, 96, 0, 98, 0, 124, 0, 0, 0, 87, 0, 124, 85, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 112, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 56,
+            119, 0, 0, 56, 0, 0, 75, 129, 0, 0, 68, 0, 0, 0, 0, 0, 0, 0, 0, 63, 106, 0, 0, 0, 142,
+            109, 0, 0, 0, 0, 0, 0, 101, 0, 0, 0, 56, 0, 44, 0, 0, 0, 0, 0, 128, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 63, 0, 0, 44, 87, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 143, 119, 106,
+            0, 118, 0, 0, 148, 123, 0, 96, 63, 0, 0, 0, 0, 0, 0, 0, 0, 95, 128, 125, 117, 0, 145,
+            97, 100, 78, 56, 124, 63, 44, 145, 131, 134, 0, 102, 0, 142, 44, 0, 155, 97, 0, 165,
+            56, 0, 0, 0, 164, 94, 0, 0, 0, 0, 0, 0, 0, 136, 120, 80, 136, 141, 0, 44, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 154, 115, 113, 0, 104, 0, 0, 151, 122, 56, 82, 63, 0, 0, 0, 0, 0, 0, 0,
+            0, 101, 118, 92, 97, 0, 143, 72, 102, 78, 0, 75, 0, 0, 158, 75, 109, 0, 84, 0, 140, 63,
+            0, 103, 98, 0, 164, 0, 0, 0, 0, 152, 109, 0, 0, 0, 0, 0, 0, 0, 124, 117, 84, 115, 126,
+            0, 44, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 87, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 63, 0, 68, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 68, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 44, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 165, 133, 110, 0, 90, 0, 0, 0,
+            109, 0, 87, 107, 0, 44, 0, 0, 0, 0, 0, 0, 120, 122, 56, 120, 56, 124, 106, 101, 97, 0,
+            75, 107, 0, 107, 97, 114, 0, 118, 0, 78, 44, 0, 122, 92, 0, 101, 56, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 143, 128, 124, 99, 114, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+    },
+    LanguageModel {
+        language: "de",
+        script: Script::Latin,
+        alphabet: &[
+            'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q',
+            'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z', '\u{00DF}', '\u{00E4}', '\u{00F6}',
+            '\u{00FC}',
+        ],
+        bigrams: &[
+            0, 178, 169, 153, 185, 173, 166, 163, 152, 168, 126, 166, 157, 165, 169, 157, 162, 120,
+            154, 178, 159, 164, 167, 169, 127, 113, 160, 1, 112, 102, 125, 0, 157, 99, 154, 145,
+            143, 91, 113, 141, 134, 130, 99, 146, 166, 152, 173, 98, 132, 91, 164, 157, 169, 163,
+            110, 110, 113, 119, 103, 60, 0, 0, 0, 0, 137, 145, 93, 85, 69, 173, 80, 106, 104, 143,
+            110, 63, 138, 89, 91, 128, 59, 0, 125, 116, 122, 134, 57, 86, 28, 113, 88, 0, 93, 0,
+            52, 0, 134, 129, 52, 93, 82, 123, 69, 77, 183, 109, 74, 140, 109, 79, 50, 140, 92, 68,
+            111, 84, 118, 107, 40, 41, 51, 74, 0, 0, 0, 0, 0, 0, 167, 166, 94, 73, 106, 183, 87,
+            91, 89, 167, 87, 72, 112, 101, 103, 131, 119, 22, 124, 121, 99, 141, 77, 95, 50, 82,
+            60, 0, 65, 60, 71, 0, 190, 135, 148, 139, 143, 127, 143, 146, 150, 182, 69, 140, 165,
+            151, 193, 106, 131, 93, 193, 173, 162, 136, 112, 122, 136, 97, 122, 93, 88, 83, 1, 0,
+            149, 140, 63, 64, 71, 155, 130, 108, 49, 143, 53, 47, 119, 48, 104, 145, 60, 13, 127,
+            113, 124, 129, 0, 65, 48, 72, 90, 0, 87, 53, 146, 0, 160, 144, 108, 67, 78, 175, 65,
+            108, 100, 141, 43, 104, 129, 87, 125, 118, 89, 30, 147, 134, 136, 137, 78, 80, 46, 71,
+            83, 0, 81, 44, 127, 0, 160, 151, 94, 60, 86, 166, 84, 85, 66, 144, 22, 82, 154, 104,
+            136, 131, 65, 25, 148, 117, 159, 122, 66, 96, 0, 75, 78, 0, 116, 99, 73, 0, 157, 136,
+            141, 169, 135, 178, 131, 158, 113, 96, 81, 127, 150, 151, 180, 158, 125, 78, 149, 168,
+            165, 90, 131, 83, 113, 84, 117, 63, 18, 0, 6, 0, 96, 113, 48, 48, 43, 124, 32, 6, 42,
+            97, 43, 40, 1, 18, 39, 98, 56, 0, 30, 69, 6, 95, 30, 37, 0, 34, 0, 0, 34, 34, 61, 0,
+            141, 151, 79, 61, 79, 154, 61, 122, 99, 126, 32, 78, 116, 77, 99, 153, 91, 6, 120, 112,
+            145, 125, 69, 92, 0, 72, 102, 0, 18, 115, 87, 0, 159, 153, 116, 106, 135, 168, 107,
+            129, 77, 160, 70, 87, 160, 90, 113, 140, 114, 56, 69, 145, 155, 135, 89, 91, 43, 106,
+            115, 0, 113, 113, 119, 0, 161, 157, 129, 56, 96, 160, 74, 102, 52, 154, 28, 76, 103,
+            141, 85, 136, 140, 28, 80, 110, 112, 131, 64, 92, 43, 68, 68, 0, 97, 108, 97, 0, 194,
+            155, 125, 123, 171, 168, 139, 170, 109, 164, 89, 138, 113, 102, 157, 141, 94, 52, 84,
+            152, 165, 148, 110, 113, 60, 103, 140, 0, 94, 104, 101, 0, 147, 103, 132, 130, 146, 76,
+            117, 130, 112, 104, 79, 124, 146, 149, 170, 116, 143, 63, 162, 131, 135, 129, 114, 119,
+            89, 80, 117, 91, 0, 0, 0, 0, 141, 153, 76, 91, 91, 145, 119, 103, 105, 131, 0, 117,
+            123, 72, 70, 134, 122, 45, 153, 110, 144, 125, 67, 82, 48, 85, 6, 0, 65, 0, 28, 0, 98,
+            67, 1, 6, 25, 43, 0, 18, 0, 63, 1, 0, 87, 0, 1, 48, 22, 32, 25, 67, 64, 124, 0, 59, 0,
+            0, 0, 0, 0, 0, 0, 0, 181, 158, 137, 136, 163, 172, 129, 138, 129, 157, 69, 130, 126,
+            139, 142, 150, 111, 50, 125, 154, 162, 147, 121, 140, 32, 114, 137, 0, 116, 120, 123,
+            0, 179, 139, 103, 164, 115, 167, 114, 131, 132, 162, 28, 122, 113, 103, 104, 141, 146,
+            90, 110, 157, 173, 135, 112, 124, 40, 132, 109, 0, 93, 49, 103, 0, 187, 157, 92, 118,
+            104, 183, 118, 105, 144, 167, 63, 88, 123, 92, 104, 140, 109, 46, 145, 143, 138, 141,
+            88, 114, 82, 127, 150, 0, 106, 63, 118, 0, 149, 126, 127, 133, 104, 138, 148, 131, 80,
+            123, 75, 108, 132, 150, 171, 84, 132, 55, 154, 158, 146, 84, 75, 90, 87, 80, 93, 103,
+            0, 0, 13, 0, 119, 128, 50, 63, 53, 161, 39, 39, 13, 134, 6, 13, 58, 58, 71, 151, 57, 0,
+            59, 81, 52, 67, 48, 28, 18, 41, 1, 0, 37, 25, 0, 0, 121, 143, 45, 71, 87, 164, 42, 60,
+            91, 151, 13, 43, 69, 45, 87, 126, 57, 0, 88, 80, 36, 125, 1, 44, 44, 62, 1, 0, 111, 94,
+            90, 0, 129, 93, 58, 77, 61, 95, 74, 6, 46, 120, 1, 49, 48, 66, 18, 62, 98, 6, 52, 52,
+            120, 50, 32, 44, 74, 85, 120, 0, 0, 0, 0, 0, 135, 118, 52, 61, 52, 94, 25, 63, 18, 89,
+            48, 64, 73, 113, 106, 97, 124, 0, 80, 124, 109, 92, 46, 40, 18, 73, 58, 0, 0, 0, 0, 0,
+            132, 126, 61, 68, 71, 161, 58, 70, 82, 132, 18, 58, 98, 111, 52, 99, 48, 22, 56, 71,
+            135, 154, 48, 119, 0, 61, 89, 0, 69, 82, 41, 0, 88, 18, 51, 0, 0, 126, 0, 25, 6, 80, 0,
+            36, 62, 0, 6, 0, 6, 0, 37, 39, 63, 1, 0, 0, 0, 0, 32, 0, 0, 0, 0, 0, 13, 0, 22, 89, 79,
+            6, 69, 95, 111, 76, 0, 0, 108, 42, 130, 0, 18, 47, 118, 83, 111, 88, 6, 0, 0, 0, 51,
+            83, 42, 0, 0, 0, 34, 0, 6, 94, 39, 6, 104, 108, 85, 34, 0, 0, 45, 75, 115, 0, 69, 0,
+            106, 115, 99, 0, 0, 25, 0, 18, 0, 117, 0, 6, 0, 0, 69, 6, 126, 113, 103, 48, 107, 112,
+            118, 25, 0, 40, 129, 78, 91, 13, 85, 0, 140, 123, 116, 0, 0, 0, 0, 0, 18, 1, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0,
+        ],
+    },
+    LanguageModel {
+        language: "fr",
+        script: Script::Latin,
+        alphabet: &[
+            'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q',
+            'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z', '\u{00E0}', '\u{00E2}', '\u{00E7}',
+            '\u{00E8}', '\u{00E9}', '\u{00EA}', '\u{00EB}', '\u{00EE}', '\u{00EF}', '\u{00F4}',
+            '\u{00F9}', '\u{00FB}', '\u{00FC}',
+        ],
+        bigrams: &[
+            0, 178, 156, 178, 191, 175, 166, 150, 141, 168, 128, 135, 185, 167, 169, 162, 181, 146,
+            164, 179, 169, 167, 158, 133, 130, 118, 118, 141, 46, 38, 30, 145, 129, 0, 81, 4, 0, 0,
+            0, 4, 0, 172, 95, 143, 152, 139, 84, 131, 147, 108, 156, 115, 120, 164, 145, 173, 97,
+            142, 138, 166, 160, 166, 157, 143, 107, 115, 121, 95, 0, 28, 76, 0, 42, 0, 26, 108, 82,
+            16, 0, 0, 0, 0, 132, 139, 66, 84, 62, 128, 74, 62, 65, 137, 111, 55, 153, 69, 60, 137,
+            49, 0, 130, 108, 102, 129, 42, 71, 44, 100, 70, 0, 20, 0, 53, 82, 35, 23, 1, 0, 11, 0,
+            0, 16, 0, 150, 150, 52, 122, 81, 160, 73, 69, 168, 141, 71, 121, 130, 81, 49, 169, 95,
+            72, 139, 100, 157, 138, 47, 38, 53, 74, 0, 11, 0, 0, 92, 108, 42, 0, 4, 0, 87, 4, 0, 0,
+            0, 166, 153, 74, 66, 105, 183, 73, 76, 81, 152, 89, 64, 88, 85, 76, 145, 116, 1, 130,
+            111, 73, 151, 71, 80, 64, 93, 58, 0, 16, 0, 86, 149, 23, 23, 34, 0, 28, 0, 49, 16, 0,
+            202, 134, 122, 157, 131, 111, 128, 125, 95, 113, 79, 91, 151, 156, 176, 87, 132, 113,
+            179, 184, 161, 161, 118, 99, 148, 91, 129, 0, 0, 74, 0, 4, 0, 0, 0, 38, 0, 0, 0, 0, 0,
+            138, 137, 26, 65, 82, 121, 141, 55, 39, 165, 23, 40, 115, 54, 56, 146, 83, 11, 117,
+            109, 92, 112, 16, 30, 40, 74, 0, 0, 0, 0, 56, 115, 0, 0, 0, 0, 0, 0, 23, 4, 0, 142,
+            134, 88, 71, 77, 152, 39, 95, 103, 137, 37, 71, 117, 97, 142, 118, 90, 23, 136, 109,
+            87, 140, 72, 74, 44, 65, 78, 0, 1, 0, 77, 117, 11, 0, 0, 0, 0, 0, 11, 42, 0, 134, 150,
+            40, 61, 70, 156, 52, 46, 64, 155, 11, 55, 79, 95, 69, 132, 50, 16, 111, 75, 100, 117,
+            38, 61, 0, 78, 4, 23, 40, 0, 93, 110, 16, 16, 34, 0, 83, 0, 0, 4, 0, 156, 139, 146,
+            161, 147, 164, 147, 148, 78, 83, 72, 100, 161, 151, 171, 170, 129, 143, 156, 168, 162,
+            81, 141, 80, 118, 84, 105, 4, 23, 11, 107, 116, 34, 4, 0, 0, 4, 0, 0, 0, 0, 97, 107,
+            49, 50, 42, 120, 30, 4, 40, 95, 41, 28, 11, 35, 39, 126, 55, 0, 40, 67, 4, 105, 23, 32,
+            0, 20, 1, 96, 0, 0, 0, 23, 0, 0, 0, 0, 0, 0, 0, 0, 0, 125, 126, 62, 60, 46, 121, 53,
+            115, 98, 110, 0, 58, 64, 62, 76, 112, 83, 4, 81, 91, 74, 106, 32, 84, 4, 71, 1, 0, 32,
+            0, 4, 71, 0, 4, 23, 0, 0, 0, 0, 16, 0, 166, 169, 77, 94, 114, 182, 88, 94, 61, 167, 64,
+            69, 150, 76, 70, 149, 107, 80, 71, 123, 131, 139, 72, 74, 60, 117, 110, 61, 52, 0, 105,
+            127, 4, 0, 0, 0, 16, 0, 0, 4, 0, 146, 162, 139, 78, 87, 164, 75, 59, 51, 149, 32, 54,
+            95, 144, 88, 148, 155, 34, 80, 111, 84, 125, 72, 67, 50, 65, 35, 0, 0, 0, 103, 132,
+            101, 0, 1, 0, 23, 0, 1, 28, 0, 181, 150, 77, 151, 158, 166, 134, 147, 79, 150, 86, 100,
+            102, 84, 148, 156, 86, 104, 101, 163, 175, 139, 132, 68, 61, 103, 78, 0, 16, 85, 47,
+            137, 94, 0, 4, 0, 38, 0, 0, 26, 0, 149, 98, 129, 143, 141, 80, 118, 128, 85, 150, 78,
+            103, 141, 161, 182, 115, 148, 89, 164, 142, 140, 170, 111, 117, 77, 109, 69, 0, 0, 0,
+            1, 35, 0, 11, 61, 52, 0, 79, 54, 0, 0, 143, 167, 75, 102, 90, 156, 59, 97, 116, 129, 0,
+            116, 146, 73, 64, 162, 138, 48, 158, 119, 146, 137, 62, 81, 42, 85, 0, 0, 4, 0, 50,
+            130, 67, 0, 0, 0, 85, 0, 0, 16, 0, 100, 99, 1, 16, 26, 88, 0, 16, 41, 85, 1, 0, 85, 0,
+            16, 82, 20, 30, 32, 63, 63, 165, 1, 58, 0, 30, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 182, 162, 106, 138, 130, 179, 99, 134, 70, 159, 58, 96, 102, 142, 130, 157, 104,
+            98, 144, 154, 151, 130, 121, 77, 50, 113, 56, 4, 55, 70, 109, 154, 91, 23, 30, 0, 102,
+            0, 0, 0, 0, 193, 152, 77, 125, 80, 166, 85, 65, 128, 162, 16, 95, 104, 90, 89, 151,
+            139, 114, 99, 159, 167, 152, 77, 103, 45, 135, 63, 0, 4, 0, 62, 137, 0, 0, 4, 0, 23, 0,
+            58, 4, 0, 184, 160, 65, 115, 91, 173, 80, 84, 146, 177, 56, 76, 110, 88, 71, 151, 101,
+            4, 163, 144, 143, 139, 56, 71, 80, 129, 70, 28, 82, 0, 124, 140, 101, 4, 35, 0, 72, 0,
+            0, 16, 0, 165, 130, 119, 134, 111, 163, 105, 110, 80, 148, 95, 97, 143, 137, 161, 79,
+            141, 69, 172, 153, 164, 81, 136, 76, 129, 83, 76, 0, 0, 0, 39, 117, 92, 53, 0, 38, 4,
+            0, 0, 0, 0, 116, 150, 46, 62, 48, 161, 54, 40, 16, 137, 4, 16, 59, 70, 55, 134, 58, 0,
+            117, 65, 39, 87, 37, 37, 40, 45, 1, 0, 4, 0, 56, 122, 1, 0, 0, 0, 1, 0, 0, 0, 0, 116,
+            126, 41, 72, 85, 105, 40, 57, 100, 117, 11, 49, 61, 47, 86, 108, 60, 0, 83, 79, 40, 80,
+            1, 38, 52, 58, 4, 0, 16, 0, 32, 46, 0, 0, 0, 0, 0, 0, 0, 1, 0, 143, 104, 53, 96, 61,
+            119, 63, 30, 39, 126, 4, 38, 46, 66, 16, 63, 117, 34, 53, 50, 123, 49, 30, 46, 76, 69,
+            117, 0, 16, 0, 4, 112, 0, 0, 0, 0, 0, 0, 0, 0, 0, 137, 120, 48, 69, 40, 109, 26, 53,
+            20, 90, 20, 43, 86, 121, 109, 96, 126, 0, 78, 126, 85, 91, 37, 35, 16, 71, 58, 0, 20,
+            0, 16, 81, 32, 0, 0, 0, 0, 0, 0, 0, 0, 138, 108, 52, 66, 55, 105, 32, 53, 76, 97, 16,
+            26, 65, 107, 42, 94, 16, 23, 46, 64, 39, 65, 30, 32, 4, 41, 70, 0, 0, 0, 16, 87, 0, 0,
+            0, 0, 0, 4, 0, 0, 0, 143, 0, 0, 0, 1, 0, 0, 0, 4, 0, 0, 0, 0, 0, 4, 4, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 23, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 32, 16, 0, 86, 0, 0, 0,
+            49, 1, 4, 1, 20, 41, 32, 30, 0, 0, 0, 28, 16, 40, 4, 0, 4, 0, 23, 0, 0, 4, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 90, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 79, 0, 0, 0, 0,
+            0, 75, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 44, 0, 16, 62, 68, 0,
+            0, 84, 0, 0, 0, 35, 91, 113, 63, 23, 0, 106, 121, 111, 110, 4, 51, 16, 0, 0, 26, 0, 0,
+            0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 151, 110, 104, 147, 118, 151, 132, 118, 20, 78, 97,
+            49, 119, 127, 110, 95, 133, 93, 144, 135, 135, 72, 103, 30, 11, 16, 28, 0, 0, 0, 0, 89,
+            0, 0, 0, 4, 0, 0, 0, 0, 0, 28, 0, 0, 67, 0, 0, 0, 0, 0, 0, 0, 0, 11, 102, 41, 0, 0, 0,
+            0, 0, 137, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 57, 0, 4, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 28, 0, 4, 0, 0, 0, 32, 30, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 60, 0, 0, 45, 0, 0, 0, 0, 0, 0, 0, 0, 81, 1, 106, 0, 0, 0, 0,
+            0, 79, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 62, 34, 30, 26, 26,
+            34, 0, 30, 0, 0, 0, 16, 23, 34, 41, 0, 0, 52, 23, 16, 49, 0, 4, 32, 0, 0, 35, 0, 0, 0,
+            0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 32, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 102, 46, 80, 4,
+            0, 0, 4, 0, 103, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 79, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 16, 0, 0, 0, 48, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 11, 4, 0, 0, 0, 0, 59,
+            0, 56, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 16, 4, 4, 0, 0, 28,
+            0, 4, 4, 28, 0, 23, 0, 16, 32, 0, 16, 0, 11, 1, 4, 0, 0, 0, 0, 0, 16, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+    },
+    LanguageModel {
+        language: "es",
+        script: Script::Latin,
+        alphabet: &[
+            'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q',
+            'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z', '\u{00E1}', '\u{00E9}', '\u{00ED}',
+            '\u{00F1}', '\u{00F3}', '\u{00FA}', '\u{00FC}',
+        ],
+        bigrams: &[
+            0, 176, 154, 179, 189, 188, 165, 147, 149, 164, 114, 116, 176, 163, 175, 161, 179, 144,
+            167, 181, 165, 167, 156, 127, 126, 140, 104, 108, 79, 115, 0, 102, 102, 0, 0, 192, 69,
+            152, 165, 171, 85, 104, 132, 93, 112, 127, 113, 171, 154, 163, 73, 136, 120, 180, 165,
+            153, 129, 134, 86, 103, 129, 121, 0, 41, 82, 122, 0, 78, 0, 0, 122, 143, 55, 89, 63,
+            139, 83, 55, 52, 149, 122, 61, 151, 95, 64, 136, 73, 0, 146, 106, 114, 126, 48, 27, 50,
+            113, 54, 57, 58, 68, 0, 96, 101, 0, 0, 132, 168, 55, 145, 88, 155, 67, 48, 157, 174,
+            41, 124, 138, 66, 62, 173, 91, 34, 142, 90, 154, 145, 39, 41, 3, 58, 0, 73, 74, 106, 0,
+            118, 15, 0, 0, 150, 165, 85, 65, 95, 189, 78, 88, 70, 160, 71, 61, 93, 108, 78, 175,
+            95, 43, 108, 102, 80, 127, 90, 80, 65, 78, 34, 54, 80, 99, 0, 74, 27, 0, 0, 195, 148,
+            136, 166, 154, 127, 138, 146, 75, 104, 128, 78, 174, 150, 182, 119, 136, 120, 179, 182,
+            154, 118, 132, 91, 147, 93, 115, 0, 0, 73, 112, 46, 0, 0, 0, 127, 138, 15, 60, 90, 130,
+            110, 58, 34, 160, 3, 3, 117, 61, 52, 137, 90, 0, 118, 100, 101, 136, 22, 3, 41, 65, 0,
+            41, 0, 86, 0, 37, 0, 0, 0, 133, 135, 76, 74, 76, 137, 53, 84, 89, 143, 34, 49, 108,
+            108, 122, 134, 94, 15, 136, 104, 84, 145, 31, 53, 22, 43, 58, 37, 43, 41, 0, 61, 99,
+            54, 0, 131, 144, 43, 66, 76, 148, 51, 15, 75, 143, 22, 41, 53, 71, 53, 123, 45, 0, 69,
+            69, 96, 107, 43, 49, 0, 53, 3, 31, 83, 39, 0, 3, 63, 0, 0, 141, 154, 140, 168, 163,
+            153, 142, 144, 60, 77, 109, 81, 143, 148, 171, 162, 140, 108, 151, 157, 156, 80, 150,
+            54, 102, 60, 138, 74, 90, 0, 22, 166, 0, 0, 0, 91, 117, 52, 22, 3, 138, 0, 0, 3, 76, 0,
+            31, 0, 31, 22, 128, 54, 0, 22, 75, 0, 111, 22, 0, 0, 0, 3, 0, 15, 0, 0, 45, 0, 0, 0,
+            124, 104, 75, 49, 51, 118, 58, 90, 69, 97, 0, 31, 46, 58, 59, 92, 88, 0, 58, 96, 84,
+            90, 15, 60, 0, 46, 3, 15, 0, 0, 0, 0, 0, 0, 0, 178, 175, 77, 101, 113, 161, 95, 103,
+            39, 164, 0, 58, 144, 112, 62, 163, 102, 85, 77, 115, 138, 135, 107, 55, 60, 96, 64, 87,
+            82, 128, 0, 118, 84, 0, 0, 130, 161, 150, 78, 79, 158, 70, 58, 54, 157, 15, 50, 91,
+            117, 117, 150, 149, 15, 71, 96, 81, 128, 64, 41, 54, 53, 0, 127, 103, 85, 15, 102, 95,
+            0, 0, 184, 163, 74, 154, 158, 162, 134, 138, 64, 150, 93, 95, 116, 100, 87, 174, 82,
+            67, 79, 145, 172, 132, 133, 60, 54, 86, 103, 91, 90, 74, 0, 91, 124, 3, 0, 195, 95,
+            140, 145, 140, 72, 113, 124, 76, 116, 87, 95, 148, 158, 172, 112, 150, 111, 173, 170,
+            139, 115, 111, 108, 88, 75, 69, 27, 31, 22, 0, 0, 0, 0, 0, 134, 164, 62, 136, 90, 156,
+            53, 101, 85, 132, 3, 91, 143, 61, 45, 160, 97, 55, 154, 112, 124, 156, 64, 63, 51, 92,
+            0, 97, 50, 75, 0, 68, 105, 15, 0, 99, 37, 0, 3, 3, 27, 0, 27, 0, 22, 0, 0, 96, 0, 41,
+            34, 27, 15, 37, 27, 31, 156, 3, 68, 0, 27, 0, 0, 0, 0, 0, 0, 0, 0, 0, 179, 176, 106,
+            145, 137, 178, 97, 133, 58, 162, 71, 88, 113, 146, 125, 167, 107, 103, 145, 139, 146,
+            138, 122, 76, 61, 101, 88, 130, 73, 108, 0, 113, 55, 0, 0, 186, 152, 95, 143, 106, 173,
+            85, 63, 126, 162, 0, 92, 112, 109, 92, 153, 149, 115, 90, 115, 170, 145, 87, 82, 51,
+            102, 45, 49, 41, 118, 0, 113, 31, 0, 0, 155, 172, 68, 106, 92, 172, 82, 88, 117, 165,
+            0, 80, 98, 101, 73, 168, 98, 15, 164, 114, 104, 141, 37, 91, 66, 104, 52, 136, 91, 86,
+            0, 102, 60, 0, 0, 133, 144, 132, 131, 138, 167, 97, 112, 41, 138, 102, 75, 138, 139,
+            163, 97, 132, 37, 145, 149, 143, 89, 89, 37, 84, 100, 83, 71, 103, 82, 27, 3, 0, 0, 0,
+            112, 149, 52, 59, 41, 150, 62, 22, 3, 140, 0, 0, 45, 70, 43, 147, 48, 0, 71, 54, 45,
+            94, 31, 43, 49, 31, 0, 136, 75, 91, 0, 41, 0, 0, 0, 109, 119, 22, 49, 71, 96, 31, 55,
+            82, 106, 31, 31, 52, 37, 77, 97, 51, 0, 84, 83, 0, 41, 0, 31, 57, 49, 3, 37, 15, 0, 0,
+            0, 0, 0, 0, 133, 102, 55, 102, 56, 90, 59, 39, 46, 130, 0, 43, 50, 78, 27, 51, 122, 15,
+            62, 54, 126, 55, 43, 34, 79, 83, 56, 0, 0, 0, 0, 0, 0, 0, 0, 147, 116, 49, 64, 37, 101,
+            0, 50, 0, 73, 0, 39, 61, 86, 88, 97, 94, 0, 60, 91, 110, 97, 34, 31, 27, 31, 53, 34,
+            37, 0, 0, 41, 84, 0, 0, 118, 143, 49, 86, 15, 101, 0, 50, 31, 81, 0, 0, 54, 49, 15, 97,
+            0, 89, 39, 39, 3, 64, 0, 50, 0, 45, 27, 62, 0, 0, 0, 81, 0, 0, 0, 134, 0, 52, 104, 34,
+            0, 82, 100, 15, 39, 0, 0, 137, 112, 122, 0, 63, 79, 109, 117, 99, 74, 27, 0, 100, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 99, 48, 48, 43, 31, 0, 34, 15, 0, 27, 0, 0, 61, 22, 97, 0, 50,
+            3, 95, 115, 100, 0, 34, 0, 68, 0, 27, 0, 0, 0, 0, 0, 0, 0, 0, 97, 119, 43, 83, 87, 48,
+            86, 75, 0, 0, 15, 0, 49, 122, 132, 95, 22, 27, 53, 93, 90, 0, 22, 0, 71, 0, 72, 0, 0,
+            0, 0, 0, 0, 0, 0, 3, 115, 0, 0, 0, 31, 0, 0, 0, 0, 0, 0, 0, 0, 0, 120, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 3, 0, 3, 0, 15, 0, 0, 0, 132, 0, 15, 22, 120, 0, 27, 76, 0, 0, 0, 0,
+            118, 75, 166, 3, 43, 0, 102, 67, 39, 0, 3, 0, 46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 84, 73,
+            104, 71, 3, 39, 76, 0, 0, 0, 0, 0, 105, 120, 111, 0, 3, 0, 48, 109, 64, 0, 0, 0, 0, 0,
+            22, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 50, 0, 0, 0, 0, 0, 15, 0, 0, 0, 15, 0, 0, 0,
+            3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+    },
+    LanguageModel {
+        language: "it",
+        script: Script::Latin,
+        alphabet: &[
+            'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q',
+            'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z', '\u{00E0}', '\u{00E8}', '\u{00E9}',
+            '\u{00EC}', '\u{00ED}', '\u{00F2}', '\u{00F3}', '\u{00F9}', '\u{00FA}',
+        ],
+        bigrams: &[
+            0, 176, 155, 179, 186, 168, 166, 154, 145, 180, 116, 141, 172, 166, 174, 161, 176, 143,
+            166, 181, 167, 166, 161, 137, 118, 120, 121, 21, 148, 33, 0, 0, 0, 14, 0, 0, 0, 190,
+            104, 146, 148, 140, 93, 112, 149, 116, 137, 98, 124, 173, 156, 170, 104, 144, 116, 171,
+            154, 174, 134, 139, 113, 103, 126, 153, 0, 0, 0, 0, 26, 0, 0, 0, 0, 0, 121, 145, 121,
+            69, 40, 139, 63, 61, 74, 156, 81, 62, 134, 75, 68, 134, 52, 0, 124, 90, 70, 132, 52,
+            79, 38, 112, 56, 0, 14, 63, 48, 14, 0, 14, 0, 26, 0, 137, 166, 36, 144, 87, 152, 58,
+            53, 163, 154, 30, 130, 124, 78, 63, 174, 77, 78, 141, 85, 132, 137, 33, 48, 2, 67, 0,
+            33, 26, 0, 14, 38, 14, 44, 14, 14, 0, 156, 157, 90, 67, 105, 173, 71, 95, 89, 176, 84,
+            64, 94, 73, 81, 153, 92, 0, 105, 102, 79, 131, 79, 73, 59, 81, 68, 38, 14, 50, 42, 26,
+            0, 33, 2, 26, 0, 197, 140, 126, 155, 145, 113, 136, 150, 94, 135, 74, 101, 171, 148,
+            173, 114, 131, 103, 180, 172, 158, 92, 136, 106, 121, 100, 129, 0, 0, 0, 0, 0, 0, 33,
+            0, 0, 0, 128, 133, 21, 56, 80, 129, 125, 54, 2, 165, 26, 49, 111, 54, 59, 141, 60, 2,
+            122, 104, 100, 125, 0, 40, 2, 74, 0, 0, 14, 38, 0, 0, 0, 0, 0, 0, 0, 145, 144, 96, 64,
+            84, 147, 48, 144, 126, 153, 40, 77, 133, 93, 133, 138, 92, 26, 138, 105, 93, 146, 82,
+            83, 42, 67, 68, 0, 0, 14, 0, 0, 0, 0, 0, 26, 0, 139, 144, 48, 38, 63, 159, 57, 49, 68,
+            149, 2, 58, 80, 88, 71, 128, 66, 26, 99, 78, 102, 124, 42, 68, 0, 65, 14, 33, 0, 99, 0,
+            38, 14, 14, 0, 33, 0, 187, 158, 149, 165, 148, 146, 146, 145, 79, 87, 83, 108, 173,
+            163, 177, 173, 141, 79, 149, 160, 164, 139, 143, 89, 112, 92, 147, 99, 48, 56, 0, 0,
+            73, 48, 108, 0, 0, 84, 111, 54, 14, 46, 100, 0, 14, 44, 101, 33, 42, 21, 26, 44, 98,
+            64, 0, 38, 75, 0, 95, 38, 42, 0, 40, 0, 0, 0, 14, 0, 0, 0, 14, 0, 14, 0, 134, 133, 74,
+            60, 54, 124, 64, 95, 107, 116, 14, 65, 74, 71, 71, 120, 97, 2, 93, 101, 85, 117, 38,
+            93, 14, 69, 0, 14, 0, 56, 0, 26, 0, 0, 0, 38, 0, 176, 169, 104, 111, 113, 175, 94, 97,
+            69, 169, 71, 76, 167, 108, 75, 161, 111, 36, 65, 113, 140, 134, 97, 64, 46, 111, 71,
+            21, 0, 62, 30, 36, 26, 33, 0, 0, 0, 140, 165, 137, 67, 80, 168, 65, 60, 42, 150, 33,
+            56, 90, 133, 83, 152, 157, 21, 86, 93, 82, 131, 52, 76, 55, 69, 42, 0, 26, 55, 14, 26,
+            0, 26, 0, 0, 0, 178, 164, 81, 143, 159, 174, 134, 154, 86, 160, 95, 113, 105, 81, 132,
+            173, 111, 77, 70, 142, 172, 143, 122, 67, 49, 106, 138, 26, 14, 85, 14, 58, 0, 21, 74,
+            0, 0, 190, 105, 123, 146, 144, 85, 121, 138, 87, 117, 77, 114, 156, 160, 185, 114, 148,
+            77, 172, 159, 146, 138, 140, 121, 93, 87, 98, 0, 60, 26, 0, 0, 0, 0, 0, 0, 0, 137, 157,
+            66, 91, 93, 162, 42, 103, 100, 141, 0, 93, 134, 73, 73, 165, 140, 55, 155, 105, 128,
+            144, 65, 86, 26, 92, 130, 26, 0, 56, 14, 14, 0, 26, 0, 42, 0, 101, 91, 2, 26, 14, 80,
+            0, 26, 30, 85, 2, 0, 95, 0, 0, 85, 26, 38, 30, 76, 71, 145, 0, 67, 0, 0, 2, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 162, 169, 98, 136, 135, 180, 95, 134, 73, 174, 65, 102, 115, 142,
+            134, 163, 103, 40, 145, 142, 144, 133, 126, 81, 36, 132, 98, 102, 33, 68, 0, 68, 30,
+            40, 26, 45, 0, 154, 155, 85, 153, 60, 169, 91, 69, 136, 168, 14, 104, 111, 91, 86, 159,
+            147, 98, 95, 165, 171, 152, 94, 118, 48, 109, 64, 0, 26, 58, 77, 26, 0, 14, 0, 0, 0,
+            162, 175, 69, 117, 94, 173, 75, 87, 141, 171, 67, 84, 109, 98, 84, 175, 117, 14, 159,
+            114, 160, 143, 46, 88, 76, 110, 74, 124, 14, 63, 0, 58, 0, 26, 38, 26, 0, 142, 144,
+            121, 113, 119, 144, 105, 122, 73, 141, 83, 106, 143, 138, 165, 134, 141, 57, 150, 153,
+            156, 86, 76, 85, 80, 88, 116, 0, 0, 57, 14, 50, 117, 0, 0, 0, 0, 114, 153, 44, 66, 53,
+            158, 30, 44, 26, 152, 14, 2, 62, 68, 59, 137, 49, 0, 101, 65, 38, 110, 110, 36, 2, 53,
+            2, 0, 0, 26, 0, 14, 0, 0, 0, 0, 0, 121, 134, 51, 74, 91, 110, 38, 65, 96, 121, 21, 44,
+            72, 53, 86, 119, 57, 0, 93, 82, 46, 90, 2, 45, 30, 66, 21, 14, 42, 45, 0, 0, 0, 0, 0,
+            0, 0, 127, 87, 62, 80, 65, 94, 53, 14, 42, 102, 0, 46, 49, 73, 0, 60, 94, 14, 45, 44,
+            107, 58, 42, 51, 75, 86, 56, 0, 0, 0, 0, 26, 0, 14, 0, 0, 0, 146, 125, 61, 66, 55, 97,
+            21, 62, 26, 97, 21, 55, 73, 87, 92, 104, 103, 0, 75, 104, 105, 99, 48, 40, 26, 60, 48,
+            0, 14, 42, 0, 14, 0, 33, 0, 33, 0, 103, 146, 49, 33, 26, 118, 14, 63, 84, 165, 26, 30,
+            55, 58, 52, 113, 26, 14, 38, 45, 45, 77, 14, 42, 0, 49, 140, 0, 26, 26, 0, 0, 0, 0, 14,
+            14, 0, 131, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 26, 14, 0, 0, 0, 14, 0, 0, 0, 0, 0,
+            33, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 148, 0, 0, 14, 0, 0, 0, 26, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 38, 42, 0, 0, 26, 26, 0, 0, 14, 0, 14, 0, 2, 0, 0, 0, 0, 0, 0, 110, 26, 0, 38,
+            48, 0, 26, 14, 0, 0, 14, 42, 56, 46, 67, 33, 0, 26, 56, 56, 54, 26, 33, 0, 14, 26, 0,
+            0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 81, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 48, 0, 0, 0,
+            14, 0, 14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 79, 58, 0, 0, 0, 14, 0, 14,
+            0, 0, 0, 14, 0, 14, 60, 38, 0, 0, 0, 38, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 119, 14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 0, 14, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 57, 26, 0, 26, 14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 69,
+            2, 0, 0, 21, 0, 14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 111, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            26, 0, 0, 63, 38, 26, 14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 26, 33, 0, 0, 0, 26, 0, 0, 0, 0,
+            0, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+    },
+    LanguageModel {
+        language: "pt",
+        script: Script::Latin,
+        alphabet: &[
+            'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q',
+            'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z', '\u{00E0}', '\u{00E1}', '\u{00E2}',
+            '\u{00E3}', '\u{00E7}', '\u{00E9}', '\u{00EA}', '\u{00ED}', '\u{00F3}', '\u{00F4}',
+            '\u{00F5}', '\u{00FA}',
+        ],
+        bigrams: &[
+            0, 183, 155, 178, 189, 180, 172, 150, 139, 169, 126, 116, 160, 167, 173, 179, 181, 151,
+            165, 179, 165, 166, 159, 121, 128, 99, 111, 100, 111, 58, 7, 7, 137, 0, 106, 19, 0, 19,
+            104, 0, 192, 69, 139, 156, 174, 83, 103, 136, 70, 144, 98, 110, 170, 158, 164, 135,
+            150, 104, 179, 169, 155, 129, 138, 86, 101, 100, 135, 0, 0, 0, 7, 150, 34, 0, 115, 0,
+            0, 19, 19, 0, 139, 138, 73, 86, 75, 127, 76, 59, 53, 142, 114, 40, 130, 101, 53, 133,
+            66, 0, 133, 117, 112, 132, 38, 34, 0, 110, 73, 0, 57, 34, 19, 0, 88, 0, 68, 94, 0, 0,
+            48, 0, 139, 168, 63, 100, 90, 149, 79, 55, 160, 156, 43, 124, 130, 69, 51, 176, 86, 77,
+            141, 90, 150, 138, 58, 48, 62, 75, 7, 0, 75, 19, 0, 122, 63, 90, 77, 99, 0, 0, 0, 0,
+            148, 170, 66, 73, 108, 186, 85, 93, 64, 157, 68, 64, 89, 88, 75, 178, 130, 57, 116,
+            107, 75, 129, 81, 89, 63, 77, 40, 0, 84, 34, 43, 0, 19, 99, 73, 67, 31, 0, 0, 0, 192,
+            134, 134, 164, 142, 107, 147, 139, 61, 160, 119, 73, 159, 167, 173, 98, 142, 117, 177,
+            180, 149, 116, 137, 90, 149, 90, 115, 0, 7, 0, 0, 120, 0, 0, 0, 48, 0, 0, 95, 0, 130,
+            143, 31, 76, 89, 133, 116, 56, 31, 168, 7, 7, 117, 63, 55, 153, 82, 7, 112, 99, 102,
+            118, 0, 7, 31, 77, 0, 0, 34, 34, 0, 0, 0, 0, 55, 34, 0, 0, 0, 0, 144, 135, 69, 78, 71,
+            146, 45, 94, 82, 139, 26, 40, 104, 88, 126, 124, 95, 7, 136, 112, 85, 146, 38, 48, 7,
+            46, 87, 0, 58, 38, 31, 0, 56, 52, 70, 45, 0, 0, 0, 0, 133, 151, 7, 53, 76, 158, 34, 34,
+            70, 117, 7, 45, 82, 69, 73, 145, 53, 0, 81, 74, 106, 121, 45, 40, 0, 57, 7, 0, 86, 19,
+            19, 0, 34, 0, 50, 0, 0, 0, 65, 0, 144, 158, 131, 168, 160, 124, 143, 147, 61, 76, 73,
+            70, 151, 154, 175, 157, 134, 115, 165, 162, 157, 79, 144, 48, 124, 45, 145, 0, 103, 7,
+            81, 124, 40, 0, 31, 34, 0, 43, 69, 0, 88, 125, 55, 60, 19, 116, 40, 0, 7, 71, 0, 43, 7,
+            0, 19, 97, 53, 0, 19, 26, 0, 117, 31, 0, 0, 7, 7, 0, 99, 0, 31, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 127, 109, 68, 57, 57, 119, 50, 130, 72, 95, 0, 26, 40, 55, 62, 77, 81, 19, 43, 101,
+            87, 88, 19, 69, 0, 38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 161, 154, 73, 90, 118,
+            151, 97, 109, 142, 167, 0, 61, 124, 117, 64, 157, 103, 105, 60, 115, 142, 128, 113, 75,
+            58, 95, 66, 0, 70, 67, 34, 38, 75, 94, 86, 73, 31, 0, 46, 0, 174, 168, 139, 62, 86,
+            167, 58, 43, 52, 146, 0, 61, 87, 100, 77, 157, 155, 26, 57, 93, 79, 123, 62, 62, 43,
+            67, 0, 0, 111, 46, 81, 0, 98, 62, 88, 119, 0, 0, 88, 0, 154, 159, 74, 151, 158, 149,
+            140, 132, 149, 150, 94, 110, 99, 76, 77, 160, 87, 92, 76, 146, 173, 132, 134, 53, 51,
+            87, 63, 0, 107, 87, 147, 117, 86, 84, 109, 75, 19, 0, 117, 0, 199, 100, 138, 147, 148,
+            65, 113, 124, 58, 128, 74, 98, 146, 165, 168, 119, 144, 91, 173, 173, 150, 149, 134,
+            112, 99, 38, 48, 0, 31, 0, 0, 69, 19, 38, 34, 7, 0, 0, 0, 0, 141, 171, 48, 103, 93,
+            157, 57, 95, 75, 134, 0, 130, 137, 64, 71, 168, 98, 34, 159, 113, 133, 117, 66, 45, 31,
+            76, 0, 0, 91, 7, 19, 126, 38, 0, 31, 96, 62, 72, 105, 0, 101, 31, 19, 19, 40, 7, 0, 0,
+            7, 19, 7, 0, 40, 0, 31, 31, 26, 26, 34, 38, 58, 161, 19, 72, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 182, 176, 96, 130, 127, 175, 103, 133, 38, 167, 26, 86, 106, 146,
+            129, 168, 99, 125, 145, 140, 146, 129, 124, 76, 45, 106, 50, 0, 123, 82, 113, 89, 85,
+            98, 86, 91, 0, 71, 40, 0, 189, 153, 85, 144, 116, 170, 94, 76, 124, 152, 0, 95, 102,
+            116, 68, 150, 149, 108, 103, 155, 170, 144, 107, 80, 48, 113, 40, 0, 102, 38, 134, 0,
+            70, 0, 136, 105, 0, 97, 0, 0, 160, 172, 64, 115, 97, 177, 84, 74, 120, 164, 7, 82, 99,
+            95, 69, 165, 112, 26, 160, 113, 113, 143, 64, 83, 74, 109, 56, 0, 121, 77, 106, 0, 108,
+            90, 95, 117, 0, 74, 62, 0, 152, 147, 121, 101, 116, 153, 103, 114, 34, 147, 76, 70,
+            136, 160, 141, 78, 139, 46, 149, 148, 149, 38, 74, 34, 97, 31, 99, 0, 7, 31, 31, 101,
+            52, 90, 92, 0, 0, 0, 0, 0, 118, 151, 43, 65, 50, 164, 46, 26, 19, 141, 0, 7, 7, 76, 48,
+            142, 51, 0, 104, 61, 38, 40, 31, 26, 40, 0, 7, 0, 129, 7, 46, 0, 76, 65, 90, 0, 19, 0,
+            0, 0, 112, 111, 19, 48, 70, 93, 48, 58, 75, 108, 7, 52, 74, 45, 85, 95, 55, 0, 74, 77,
+            40, 19, 7, 46, 53, 34, 7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 135, 114, 67, 105, 53,
+            126, 73, 31, 34, 124, 0, 48, 40, 69, 19, 110, 121, 0, 60, 48, 124, 62, 38, 7, 75, 93,
+            72, 0, 19, 0, 31, 0, 0, 0, 7, 0, 0, 0, 0, 0, 132, 77, 55, 48, 26, 79, 31, 26, 0, 57, 7,
+            34, 68, 106, 78, 62, 99, 0, 74, 98, 109, 34, 7, 46, 19, 38, 34, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 136, 142, 45, 0, 26, 125, 0, 34, 40, 116, 0, 7, 48, 63, 26, 85, 0, 0,
+            50, 57, 31, 60, 0, 31, 0, 50, 43, 0, 69, 53, 43, 0, 19, 46, 19, 0, 0, 31, 0, 0, 98, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 19, 0, 58, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 130, 0, 60, 98, 43, 0, 79, 93, 0, 7, 0, 0, 129, 19, 0, 0,
+            45, 84, 130, 92, 89, 79, 112, 0, 94, 0, 7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 99, 93, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 86, 0, 0, 0, 0, 51, 0, 0, 0, 7, 0, 0, 0, 0, 0, 168, 19,
+            0, 0, 19, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 19, 124, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 120, 0, 0, 0, 26, 0, 0, 0, 0, 0, 0, 0, 0, 60, 0, 155, 0,
+            0, 0, 0, 0, 0, 131, 0, 0, 138, 0, 7, 50, 59, 0, 0, 77, 0, 43, 0, 0, 68, 110, 68, 0, 40,
+            19, 93, 85, 92, 7, 48, 0, 19, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 94, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 84, 113, 0, 0, 0, 0, 110, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 51, 45, 58, 105, 119, 0, 77, 88, 0, 0, 0, 0, 63, 112, 105,
+            48, 50, 0, 82, 80, 85, 7, 136, 0, 0, 0, 7, 0, 0, 0, 0, 55, 0, 0, 0, 0, 0, 0, 0, 0, 105,
+            0, 7, 0, 107, 0, 0, 72, 0, 34, 0, 0, 96, 40, 84, 0, 86, 0, 127, 99, 64, 0, 34, 0, 72,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 52, 0, 0, 0, 0, 0, 0, 0, 0,
+            31, 43, 0, 0, 0, 43, 7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 19,
+            0, 0, 0, 0, 134, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 105, 61, 95, 0, 0, 19, 0, 0, 0, 0, 104, 115, 94,
+            0, 31, 0, 34, 80, 69, 0, 31, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+    },
+    LanguageModel {
+        language: "nl",
+        script: Script::Latin,
+        alphabet: &[
+            'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q',
+            'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z', '\u{00E1}', '\u{00E8}', '\u{00E9}',
+            '\u{00EB}', '\u{00EF}', '\u{00F3}', '\u{00F6}', '\u{00FC}',
+        ],
+        bigrams: &[
+            0, 174, 173, 158, 182, 172, 147, 172, 168, 172, 121, 158, 155, 168, 167, 175, 165, 110,
+            154, 172, 169, 155, 178, 166, 114, 107, 155, 58, 0, 94, 0, 0, 20, 51, 0, 0, 159, 174,
+            137, 149, 143, 95, 136, 142, 115, 134, 97, 155, 169, 153, 184, 98, 147, 97, 170, 144,
+            166, 132, 119, 105, 109, 117, 104, 0, 0, 20, 42, 81, 0, 0, 0, 0, 128, 146, 109, 82, 70,
+            168, 51, 63, 75, 151, 95, 32, 134, 78, 66, 140, 75, 0, 153, 105, 79, 135, 44, 67, 0,
+            109, 75, 48, 20, 68, 39, 0, 39, 0, 32, 0, 132, 139, 62, 105, 94, 139, 78, 58, 167, 131,
+            48, 122, 105, 64, 60, 154, 82, 80, 121, 89, 143, 117, 48, 46, 64, 79, 0, 54, 27, 0, 0,
+            0, 42, 0, 0, 0, 172, 154, 84, 77, 113, 186, 87, 102, 95, 163, 82, 87, 100, 99, 100,
+            150, 133, 27, 130, 140, 142, 133, 90, 101, 51, 82, 91, 20, 32, 51, 20, 0, 0, 0, 20, 0,
+            191, 131, 156, 148, 144, 175, 140, 158, 126, 148, 74, 154, 175, 147, 194, 111, 145, 92,
+            186, 170, 178, 140, 155, 124, 131, 99, 138, 0, 0, 36, 86, 100, 48, 0, 8, 0, 150, 118,
+            106, 76, 120, 117, 116, 104, 101, 140, 69, 87, 106, 72, 67, 141, 61, 32, 114, 115, 127,
+            110, 46, 92, 8, 74, 50, 0, 32, 20, 0, 0, 0, 0, 0, 0, 163, 137, 87, 79, 121, 183, 44,
+            118, 108, 139, 46, 81, 97, 83, 118, 124, 97, 32, 143, 132, 116, 137, 81, 70, 42, 80,
+            90, 39, 0, 0, 0, 0, 32, 51, 48, 0, 143, 148, 44, 44, 74, 168, 39, 44, 70, 144, 32, 57,
+            89, 78, 78, 142, 50, 39, 125, 75, 144, 130, 50, 62, 0, 74, 62, 32, 0, 64, 39, 0, 39,
+            32, 20, 0, 144, 142, 127, 142, 147, 176, 126, 156, 76, 88, 165, 149, 143, 132, 178,
+            133, 121, 75, 134, 164, 162, 91, 124, 71, 103, 85, 104, 20, 44, 51, 114, 0, 51, 0, 0,
+            0, 133, 121, 68, 62, 133, 119, 105, 104, 48, 102, 58, 137, 101, 50, 136, 103, 93, 0,
+            46, 119, 60, 101, 118, 75, 0, 36, 119, 32, 0, 20, 0, 0, 39, 54, 0, 0, 153, 149, 114,
+            78, 61, 168, 51, 131, 111, 126, 75, 145, 116, 82, 100, 142, 87, 20, 114, 130, 143, 123,
+            64, 94, 0, 63, 20, 39, 0, 64, 20, 0, 32, 32, 20, 0, 163, 155, 107, 77, 152, 168, 117,
+            125, 89, 162, 83, 124, 151, 89, 93, 144, 114, 36, 87, 148, 133, 136, 98, 83, 50, 100,
+            76, 67, 8, 69, 20, 0, 39, 20, 20, 0, 156, 163, 127, 56, 109, 164, 70, 108, 73, 140, 8,
+            68, 98, 137, 79, 144, 127, 36, 70, 114, 115, 115, 66, 81, 39, 72, 81, 54, 0, 61, 0, 0,
+            20, 0, 20, 0, 197, 159, 120, 134, 174, 155, 135, 166, 110, 160, 100, 134, 117, 115,
+            128, 142, 102, 46, 113, 154, 161, 132, 127, 96, 55, 100, 108, 52, 39, 54, 0, 0, 27, 20,
+            8, 0, 147, 117, 122, 135, 140, 161, 144, 139, 82, 103, 81, 129, 148, 156, 169, 165,
+            163, 69, 173, 132, 140, 151, 139, 119, 97, 74, 88, 32, 0, 20, 48, 27, 0, 0, 0, 0, 155,
+            157, 83, 88, 118, 150, 59, 127, 102, 131, 70, 131, 133, 104, 101, 133, 131, 32, 147,
+            118, 148, 127, 98, 81, 20, 74, 80, 50, 0, 51, 0, 0, 0, 0, 32, 0, 96, 82, 36, 32, 39,
+            51, 0, 0, 8, 59, 8, 0, 42, 20, 20, 52, 0, 42, 36, 80, 51, 114, 8, 72, 0, 0, 8, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 173, 159, 129, 135, 169, 172, 98, 140, 112, 158, 84, 136, 121, 136,
+            128, 160, 113, 46, 115, 154, 145, 151, 131, 134, 42, 105, 107, 63, 32, 69, 20, 8, 27,
+            48, 0, 0, 179, 136, 108, 155, 92, 154, 94, 90, 131, 151, 107, 112, 142, 101, 116, 136,
+            131, 58, 112, 140, 176, 123, 108, 92, 42, 134, 69, 51, 32, 54, 20, 0, 0, 20, 32, 0,
+            189, 170, 118, 113, 97, 178, 83, 122, 132, 166, 73, 91, 111, 103, 106, 155, 113, 20,
+            147, 143, 140, 138, 126, 122, 72, 124, 100, 63, 20, 63, 20, 0, 39, 32, 32, 0, 141, 116,
+            126, 110, 131, 118, 97, 123, 71, 163, 76, 127, 134, 137, 144, 77, 121, 51, 147, 138,
+            145, 115, 78, 135, 91, 75, 98, 32, 0, 64, 0, 32, 20, 0, 0, 0, 115, 166, 20, 76, 54,
+            171, 20, 58, 36, 136, 42, 27, 105, 50, 69, 161, 55, 0, 110, 83, 39, 93, 36, 27, 39, 48,
+            27, 44, 0, 59, 0, 0, 61, 20, 0, 0, 124, 151, 75, 52, 100, 150, 54, 58, 66, 142, 27, 50,
+            68, 70, 97, 154, 73, 0, 79, 95, 69, 61, 77, 58, 56, 57, 27, 0, 0, 32, 0, 0, 0, 0, 20,
+            0, 124, 93, 81, 69, 55, 90, 83, 36, 51, 107, 0, 8, 44, 64, 20, 64, 112, 0, 60, 51, 116,
+            32, 32, 0, 77, 92, 66, 0, 0, 0, 0, 0, 0, 0, 0, 0, 131, 114, 60, 72, 56, 94, 32, 68, 39,
+            83, 46, 65, 70, 114, 104, 95, 122, 0, 88, 125, 107, 80, 39, 39, 32, 44, 27, 0, 0, 0, 0,
+            0, 20, 0, 0, 0, 111, 129, 62, 39, 44, 146, 0, 54, 64, 146, 80, 46, 50, 62, 48, 135, 32,
+            0, 58, 60, 36, 113, 20, 87, 0, 48, 59, 44, 20, 39, 0, 0, 20, 0, 20, 0, 81, 0, 20, 51,
+            42, 0, 0, 20, 0, 0, 0, 0, 54, 0, 77, 0, 32, 0, 70, 48, 20, 0, 32, 0, 20, 0, 32, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 20, 0, 0, 0, 20, 0, 0, 0, 0, 0, 0, 32, 0, 0, 0, 44, 56,
+            0, 0, 39, 0, 0, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 91, 20, 20, 39, 51, 32, 0, 44, 0, 0,
+            32, 54, 60, 56, 98, 32, 27, 32, 66, 61, 52, 44, 32, 0, 0, 20, 0, 0, 0, 93, 0, 20, 0, 0,
+            0, 0, 108, 0, 0, 8, 0, 32, 27, 0, 0, 71, 0, 0, 62, 20, 75, 0, 0, 0, 92, 39, 44, 0, 32,
+            0, 39, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 54, 42, 0, 0, 20, 55, 0, 0, 0, 0, 0, 0, 20, 55,
+            99, 0, 0, 0, 36, 58, 50, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 32, 20, 20, 20,
+            32, 0, 0, 32, 0, 0, 0, 0, 32, 0, 74, 8, 20, 0, 64, 32, 39, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 61, 0, 0, 0, 0, 0, 32, 0, 20, 44, 0, 20, 0, 0, 0, 0, 48, 20, 39, 0, 20, 0, 58,
+            20, 32, 0, 39, 0, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 20, 0, 0, 0, 20, 0, 20, 20,
+            32, 0, 39, 0, 32, 27, 0, 0, 0, 48, 8, 20, 0, 0, 0, 0, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+    },
+    LanguageModel {
+        language: "sv",
+        script: Script::Latin,
+        alphabet: &[
+            'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q',
+            'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z', '\u{00E4}', '\u{00E5}', '\u{00E9}',
+            '\u{00F6}',
+        ],
+        bigrams: &[
+            0, 179, 165, 153, 171, 169, 179, 157, 157, 179, 129, 172, 163, 171, 165, 167, 168, 115,
+            165, 183, 172, 158, 165, 135, 129, 120, 118, 150, 118, 36, 131, 0, 185, 104, 143, 136,
+            161, 92, 111, 150, 120, 129, 99, 152, 166, 157, 181, 102, 141, 88, 177, 160, 174, 128,
+            153, 112, 115, 125, 102, 12, 12, 12, 46, 0, 128, 148, 107, 81, 67, 148, 80, 63, 72,
+            142, 118, 57, 138, 75, 74, 143, 64, 0, 123, 112, 85, 132, 49, 79, 43, 129, 63, 78, 86,
+            66, 95, 0, 140, 133, 57, 104, 81, 140, 78, 74, 156, 126, 51, 155, 104, 69, 55, 136,
+            104, 33, 114, 91, 122, 106, 51, 45, 55, 83, 12, 0, 0, 0, 0, 0, 172, 163, 102, 67, 125,
+            178, 94, 93, 91, 145, 101, 85, 113, 94, 125, 138, 125, 0, 139, 140, 97, 133, 107, 89,
+            64, 95, 75, 92, 93, 56, 89, 0, 182, 129, 125, 147, 156, 112, 140, 138, 128, 107, 106,
+            149, 166, 144, 181, 110, 130, 82, 186, 159, 171, 94, 115, 103, 141, 97, 83, 24, 1, 0,
+            38, 0, 144, 127, 60, 69, 86, 143, 124, 63, 41, 160, 94, 33, 141, 63, 58, 142, 88, 12,
+            139, 100, 127, 124, 1, 38, 46, 95, 0, 117, 105, 36, 162, 0, 166, 156, 99, 74, 114, 159,
+            82, 146, 117, 149, 72, 85, 119, 97, 136, 136, 98, 38, 146, 143, 135, 137, 63, 80, 51,
+            73, 76, 110, 114, 24, 104, 0, 152, 150, 45, 55, 75, 149, 60, 43, 74, 137, 100, 54, 92,
+            88, 82, 128, 51, 24, 91, 77, 98, 133, 49, 87, 0, 72, 1, 114, 121, 63, 113, 0, 161, 141,
+            129, 140, 150, 136, 132, 159, 91, 88, 84, 140, 169, 134, 184, 157, 123, 81, 130, 162,
+            153, 89, 151, 89, 118, 89, 103, 19, 0, 53, 1, 0, 121, 127, 61, 55, 80, 131, 48, 12, 43,
+            104, 38, 64, 91, 43, 72, 108, 61, 12, 44, 88, 64, 118, 33, 39, 0, 39, 1, 119, 0, 12,
+            82, 0, 152, 169, 80, 69, 65, 155, 78, 124, 105, 139, 82, 78, 126, 77, 134, 160, 97, 1,
+            143, 119, 156, 142, 120, 89, 12, 89, 1, 129, 62, 55, 117, 0, 171, 165, 108, 84, 134,
+            166, 114, 110, 97, 160, 129, 130, 172, 97, 128, 152, 116, 31, 99, 137, 150, 143, 103,
+            77, 61, 132, 72, 141, 125, 63, 111, 0, 162, 163, 137, 85, 106, 165, 102, 85, 75, 146,
+            55, 89, 118, 150, 144, 137, 136, 36, 98, 116, 120, 124, 104, 70, 55, 80, 43, 108, 127,
+            54, 100, 0, 187, 165, 102, 124, 173, 157, 137, 172, 107, 159, 102, 139, 120, 95, 145,
+            145, 101, 49, 109, 160, 173, 135, 147, 71, 51, 134, 86, 120, 107, 58, 77, 0, 150, 108,
+            132, 151, 138, 106, 118, 149, 90, 109, 85, 140, 150, 166, 166, 121, 142, 70, 166, 136,
+            144, 132, 121, 113, 85, 85, 76, 55, 31, 24, 38, 0, 148, 156, 68, 100, 111, 146, 80,
+            108, 98, 131, 0, 126, 133, 91, 111, 142, 146, 55, 148, 127, 123, 129, 67, 75, 39, 90,
+            0, 79, 140, 53, 19, 0, 99, 74, 0, 1, 27, 41, 0, 19, 0, 70, 0, 0, 92, 0, 19, 51, 24, 31,
+            38, 54, 61, 115, 1, 65, 0, 12, 0, 0, 0, 0, 0, 0, 187, 175, 121, 115, 149, 169, 118,
+            130, 109, 163, 112, 137, 129, 141, 149, 154, 106, 45, 127, 150, 151, 142, 137, 81, 57,
+            130, 60, 137, 135, 73, 106, 0, 175, 156, 104, 119, 101, 161, 123, 99, 133, 152, 89,
+            161, 147, 111, 127, 153, 139, 95, 112, 151, 175, 131, 118, 101, 53, 139, 78, 128, 109,
+            57, 123, 0, 185, 175, 94, 123, 112, 180, 120, 107, 138, 174, 95, 105, 123, 108, 127,
+            150, 103, 12, 154, 145, 165, 141, 115, 73, 70, 140, 77, 132, 107, 66, 126, 0, 144, 128,
+            123, 106, 123, 125, 101, 113, 74, 123, 79, 123, 137, 144, 153, 80, 142, 55, 146, 138,
+            157, 83, 118, 85, 91, 87, 83, 12, 0, 55, 36, 0, 155, 154, 104, 69, 89, 157, 81, 84, 63,
+            150, 19, 78, 77, 86, 113, 102, 68, 0, 95, 130, 98, 108, 69, 41, 45, 90, 0, 155, 103,
+            31, 27, 0, 116, 132, 49, 65, 80, 118, 49, 61, 86, 115, 19, 51, 70, 39, 81, 108, 61, 0,
+            88, 76, 27, 82, 0, 41, 54, 65, 19, 0, 0, 43, 0, 0, 139, 100, 64, 80, 63, 116, 89, 38,
+            39, 113, 12, 70, 88, 73, 52, 67, 106, 12, 67, 72, 123, 63, 51, 52, 79, 79, 62, 0, 0, 0,
+            12, 0, 137, 127, 55, 139, 101, 98, 58, 114, 33, 94, 43, 77, 99, 130, 115, 103, 131, 0,
+            112, 126, 130, 99, 54, 45, 24, 52, 51, 0, 0, 43, 39, 0, 106, 114, 57, 24, 24, 104, 12,
+            61, 83, 100, 65, 31, 63, 60, 50, 97, 24, 24, 52, 57, 36, 73, 12, 36, 0, 50, 54, 0, 0,
+            24, 0, 0, 33, 0, 12, 87, 97, 0, 89, 130, 24, 12, 0, 106, 139, 123, 161, 0, 77, 0, 157,
+            124, 127, 0, 114, 0, 86, 0, 0, 24, 0, 0, 0, 0, 144, 33, 66, 0, 105, 89, 51, 117, 0, 24,
+            0, 107, 127, 0, 135, 12, 53, 0, 117, 124, 129, 0, 82, 0, 0, 0, 0, 0, 41, 0, 0, 0, 93,
+            31, 12, 36, 50, 36, 24, 12, 0, 1, 24, 48, 55, 46, 72, 24, 0, 31, 56, 56, 55, 39, 31, 0,
+            24, 24, 0, 0, 0, 12, 0, 0, 70, 83, 12, 0, 123, 33, 0, 96, 24, 31, 97, 126, 105, 96,
+            110, 0, 106, 0, 163, 111, 62, 1, 128, 0, 0, 24, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+    },
+    LanguageModel {
+        language: "da",
+        script: Script::Latin,
+        alphabet: &[
+            'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q',
+            'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z', '\u{00E5}', '\u{00E6}', '\u{00E9}',
+            '\u{00F8}',
+        ],
+        bigrams: &[
+            0, 177, 168, 150, 172, 175, 180, 159, 160, 177, 128, 171, 161, 169, 163, 166, 166, 113,
+            157, 182, 174, 166, 166, 128, 134, 114, 118, 113, 120, 99, 110, 0, 161, 100, 139, 130,
+            151, 101, 158, 147, 113, 125, 102, 146, 168, 149, 175, 101, 138, 83, 166, 147, 168,
+            129, 145, 105, 99, 121, 103, 0, 18, 0, 54, 0, 131, 142, 91, 67, 54, 149, 73, 61, 72,
+            140, 111, 66, 145, 80, 113, 134, 59, 0, 152, 102, 82, 132, 30, 66, 30, 120, 73, 78, 76,
+            54, 83, 0, 136, 133, 71, 106, 93, 142, 54, 42, 136, 126, 37, 118, 95, 96, 69, 132, 84,
+            42, 119, 92, 114, 99, 30, 42, 18, 70, 0, 0, 0, 58, 0, 0, 169, 150, 100, 79, 130, 183,
+            119, 121, 111, 157, 94, 115, 135, 99, 119, 144, 101, 0, 141, 149, 144, 137, 130, 86,
+            56, 99, 68, 75, 88, 69, 105, 0, 192, 134, 123, 130, 166, 110, 138, 148, 124, 105, 141,
+            151, 171, 153, 185, 115, 131, 76, 192, 168, 176, 101, 133, 107, 119, 99, 92, 0, 59, 0,
+            49, 0, 155, 128, 99, 74, 92, 145, 127, 78, 101, 163, 116, 96, 134, 78, 67, 164, 79, 0,
+            140, 130, 138, 124, 82, 37, 37, 98, 0, 100, 92, 0, 135, 0, 172, 140, 86, 66, 110, 170,
+            85, 118, 126, 146, 62, 74, 137, 107, 145, 130, 98, 0, 145, 145, 142, 135, 100, 69, 0,
+            137, 74, 103, 108, 64, 109, 0, 133, 146, 49, 52, 74, 149, 65, 30, 62, 128, 112, 62, 76,
+            81, 75, 137, 42, 42, 94, 78, 104, 117, 137, 79, 0, 80, 18, 111, 112, 58, 116, 0, 161,
+            135, 125, 133, 148, 139, 140, 163, 80, 80, 98, 165, 175, 143, 179, 149, 128, 81, 136,
+            163, 147, 89, 156, 71, 109, 83, 106, 0, 30, 74, 0, 0, 117, 119, 70, 46, 105, 147, 42,
+            30, 37, 96, 42, 65, 137, 30, 56, 107, 77, 0, 106, 103, 62, 111, 52, 0, 0, 42, 18, 0,
+            110, 30, 92, 0, 159, 162, 83, 70, 73, 173, 74, 85, 107, 141, 76, 165, 121, 84, 119,
+            158, 89, 0, 150, 144, 145, 145, 111, 71, 0, 109, 0, 61, 103, 76, 117, 0, 175, 156, 115,
+            77, 152, 173, 121, 143, 93, 166, 107, 119, 161, 102, 116, 150, 125, 30, 89, 154, 141,
+            137, 127, 65, 62, 129, 98, 113, 136, 86, 117, 0, 161, 161, 131, 73, 102, 168, 92, 84,
+            86, 143, 37, 91, 110, 150, 95, 138, 136, 46, 122, 111, 112, 126, 98, 64, 58, 70, 42,
+            118, 118, 68, 99, 0, 183, 154, 96, 119, 176, 166, 129, 170, 113, 155, 132, 138, 121,
+            92, 143, 141, 99, 37, 81, 156, 162, 138, 115, 65, 62, 124, 90, 120, 104, 66, 130, 0,
+            149, 98, 129, 127, 143, 119, 120, 158, 84, 99, 87, 141, 150, 163, 163, 110, 148, 54,
+            172, 131, 129, 134, 140, 111, 98, 74, 81, 0, 0, 30, 30, 0, 144, 147, 83, 98, 111, 152,
+            94, 99, 102, 132, 0, 91, 132, 80, 87, 138, 136, 30, 154, 120, 129, 133, 71, 64, 42, 83,
+            0, 139, 62, 54, 95, 0, 97, 88, 0, 18, 18, 37, 0, 0, 0, 54, 0, 0, 37, 0, 0, 49, 18, 42,
+            42, 52, 64, 106, 18, 81, 0, 0, 0, 0, 0, 0, 0, 0, 188, 159, 128, 119, 145, 180, 111,
+            134, 113, 166, 76, 143, 125, 139, 142, 154, 108, 42, 130, 154, 151, 157, 134, 86, 56,
+            132, 75, 111, 136, 95, 113, 0, 172, 145, 103, 121, 97, 167, 124, 90, 131, 153, 76, 170,
+            142, 113, 132, 152, 142, 54, 114, 143, 176, 130, 114, 101, 42, 138, 82, 124, 127, 62,
+            113, 0, 186, 168, 87, 113, 103, 178, 101, 84, 131, 175, 107, 107, 119, 102, 124, 149,
+            107, 18, 154, 128, 152, 136, 104, 93, 85, 137, 67, 85, 97, 92, 133, 0, 141, 119, 127,
+            106, 150, 134, 114, 155, 75, 121, 79, 132, 143, 142, 158, 78, 131, 54, 142, 140, 143,
+            65, 113, 62, 92, 76, 85, 18, 61, 66, 54, 0, 142, 149, 67, 80, 76, 169, 68, 58, 52, 160,
+            42, 49, 79, 73, 141, 123, 64, 0, 85, 102, 90, 76, 18, 18, 30, 62, 30, 76, 141, 91, 0,
+            0, 118, 124, 30, 52, 81, 110, 46, 68, 79, 113, 18, 52, 67, 59, 85, 100, 56, 0, 81, 88,
+            37, 30, 0, 37, 37, 64, 37, 0, 0, 42, 0, 0, 138, 83, 76, 74, 59, 87, 70, 46, 46, 90, 0,
+            59, 58, 79, 30, 72, 87, 0, 56, 52, 101, 71, 0, 0, 74, 95, 94, 0, 0, 30, 0, 0, 137, 114,
+            88, 62, 128, 102, 61, 100, 46, 79, 18, 126, 137, 121, 121, 102, 132, 0, 103, 128, 126,
+            82, 61, 37, 42, 56, 46, 0, 0, 18, 42, 0, 113, 111, 52, 76, 77, 107, 0, 62, 72, 104, 49,
+            42, 78, 88, 58, 98, 30, 0, 49, 62, 61, 82, 30, 61, 0, 46, 72, 0, 0, 30, 0, 0, 147, 0,
+            112, 0, 114, 100, 30, 78, 0, 0, 0, 72, 103, 65, 111, 0, 0, 0, 126, 112, 37, 0, 84, 0,
+            0, 0, 0, 49, 0, 0, 42, 0, 56, 18, 74, 76, 87, 30, 99, 97, 0, 66, 0, 106, 126, 80, 141,
+            0, 0, 0, 147, 128, 126, 30, 119, 0, 0, 0, 0, 0, 0, 0, 30, 0, 88, 0, 30, 30, 46, 54, 0,
+            42, 0, 0, 0, 58, 42, 52, 93, 42, 0, 49, 119, 54, 84, 30, 30, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            69, 0, 112, 0, 109, 96, 30, 135, 42, 0, 126, 72, 108, 98, 107, 30, 18, 0, 146, 103,
+            116, 0, 104, 0, 0, 0, 0, 30, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+    },
+    LanguageModel {
+        language: "nb",
+        script: Script::Latin,
+        alphabet: &[
+            'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q',
+            'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z', '\u{00E5}', '\u{00E6}', '\u{00E9}',
+            '\u{00F8}',
+        ],
+        bigrams: &[
+            0, 174, 170, 142, 167, 178, 182, 156, 162, 179, 129, 171, 164, 170, 164, 167, 168, 99,
+            155, 183, 173, 168, 169, 122, 122, 100, 103, 151, 0, 96, 115, 0, 160, 79, 134, 117,
+            141, 91, 113, 142, 86, 115, 58, 145, 171, 147, 170, 84, 144, 27, 171, 155, 166, 122,
+            164, 90, 86, 97, 51, 0, 0, 0, 39, 0, 125, 141, 110, 76, 67, 145, 39, 65, 58, 136, 101,
+            68, 146, 80, 46, 129, 51, 0, 155, 102, 58, 130, 27, 65, 39, 129, 70, 96, 51, 0, 83, 0,
+            132, 120, 78, 79, 100, 117, 65, 27, 121, 91, 0, 115, 96, 74, 51, 123, 81, 27, 115, 97,
+            111, 93, 27, 58, 27, 39, 0, 0, 0, 0, 0, 0, 165, 152, 93, 65, 124, 176, 103, 97, 70,
+            160, 79, 91, 128, 88, 102, 141, 95, 0, 142, 130, 119, 145, 121, 51, 70, 87, 51, 58, 88,
+            27, 81, 0, 193, 124, 116, 119, 160, 107, 136, 150, 124, 146, 75, 153, 173, 146, 186,
+            112, 134, 60, 193, 167, 177, 87, 135, 83, 115, 92, 68, 0, 46, 0, 39, 0, 133, 133, 0,
+            77, 93, 150, 126, 76, 27, 163, 121, 0, 128, 77, 51, 167, 55, 0, 139, 83, 128, 130, 0,
+            0, 0, 102, 0, 91, 67, 0, 141, 0, 175, 130, 79, 68, 114, 165, 91, 133, 115, 138, 133,
+            68, 122, 81, 150, 117, 103, 0, 147, 140, 139, 131, 88, 27, 0, 143, 79, 110, 0, 0, 0, 0,
+            121, 141, 27, 0, 60, 147, 55, 0, 88, 115, 109, 27, 27, 70, 46, 137, 46, 0, 81, 75, 106,
+            112, 140, 0, 0, 67, 27, 104, 0, 39, 115, 0, 160, 136, 119, 117, 151, 137, 140, 163, 65,
+            83, 74, 171, 176, 133, 178, 120, 123, 70, 133, 165, 145, 79, 150, 58, 109, 60, 95, 0,
+            0, 0, 39, 0, 100, 107, 70, 0, 58, 158, 0, 0, 0, 90, 27, 63, 0, 51, 39, 146, 75, 0, 0,
+            27, 0, 107, 27, 0, 0, 0, 0, 0, 0, 0, 127, 0, 160, 158, 82, 67, 60, 178, 74, 100, 86,
+            140, 137, 172, 147, 86, 121, 159, 93, 0, 151, 152, 145, 136, 99, 60, 0, 107, 0, 70, 0,
+            27, 96, 0, 176, 162, 114, 83, 157, 173, 120, 157, 90, 165, 103, 123, 168, 98, 114, 146,
+            115, 27, 80, 148, 142, 138, 114, 51, 27, 132, 58, 123, 95, 39, 109, 0, 160, 161, 123,
+            58, 96, 169, 97, 85, 74, 142, 0, 82, 106, 151, 96, 135, 130, 39, 119, 112, 115, 112,
+            98, 27, 0, 80, 0, 128, 88, 51, 99, 0, 182, 155, 99, 106, 168, 168, 127, 170, 117, 145,
+            135, 138, 126, 88, 160, 138, 93, 39, 88, 152, 165, 134, 115, 46, 51, 132, 68, 120, 95,
+            0, 138, 0, 141, 91, 132, 118, 140, 125, 117, 153, 70, 72, 55, 140, 148, 165, 161, 105,
+            154, 0, 174, 136, 128, 111, 134, 99, 97, 39, 27, 0, 0, 0, 0, 0, 144, 147, 78, 98, 108,
+            155, 119, 119, 110, 131, 0, 85, 129, 78, 116, 137, 159, 39, 151, 127, 130, 131, 72, 63,
+            39, 60, 0, 147, 0, 0, 99, 0, 88, 55, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 0, 0, 0, 39,
+            39, 39, 58, 93, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 191, 156, 123, 111, 149, 179, 115, 138,
+            114, 165, 39, 137, 118, 140, 138, 150, 106, 27, 130, 151, 164, 159, 125, 79, 27, 132,
+            39, 120, 27, 39, 120, 0, 171, 142, 96, 108, 97, 169, 123, 91, 114, 158, 145, 167, 148,
+            117, 122, 155, 140, 55, 104, 149, 175, 129, 129, 95, 51, 133, 27, 104, 0, 0, 117, 0,
+            186, 168, 89, 96, 119, 182, 115, 104, 114, 172, 113, 109, 119, 111, 119, 150, 110, 27,
+            154, 140, 167, 137, 120, 71, 75, 133, 27, 103, 27, 0, 135, 0, 145, 105, 130, 79, 110,
+            120, 118, 142, 63, 110, 58, 155, 140, 142, 152, 67, 131, 0, 139, 142, 160, 39, 97, 55,
+            99, 65, 0, 0, 0, 0, 65, 0, 159, 159, 110, 60, 60, 168, 60, 68, 90, 156, 39, 78, 75, 71,
+            143, 115, 46, 0, 87, 127, 105, 82, 91, 0, 46, 0, 0, 103, 119, 0, 27, 0, 110, 113, 39,
+            27, 82, 81, 0, 77, 70, 104, 27, 27, 39, 46, 70, 89, 27, 0, 68, 72, 39, 0, 0, 39, 46, 0,
+            27, 0, 0, 0, 0, 0, 131, 75, 86, 71, 51, 83, 55, 0, 63, 76, 0, 27, 46, 79, 39, 65, 92,
+            0, 51, 55, 95, 51, 0, 0, 80, 94, 0, 0, 0, 0, 0, 0, 132, 97, 65, 55, 112, 119, 70, 108,
+            46, 65, 0, 132, 144, 115, 119, 84, 130, 0, 117, 123, 138, 46, 83, 39, 27, 60, 0, 0, 0,
+            0, 39, 0, 95, 74, 0, 0, 0, 99, 0, 0, 0, 93, 0, 0, 27, 39, 0, 75, 0, 0, 0, 0, 39, 58, 0,
+            46, 0, 27, 27, 0, 0, 0, 0, 0, 163, 0, 0, 0, 116, 91, 46, 46, 0, 51, 0, 106, 110, 0,
+            107, 0, 112, 0, 127, 107, 111, 27, 85, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 130, 27, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 51, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 39, 0, 96, 0, 0, 0, 51, 0, 0, 39, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 67, 0, 0, 0, 102, 74, 27, 0, 0, 0, 0, 142, 119, 108, 107, 0, 93, 0, 151, 99, 117, 0,
+            112, 0, 0, 124, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+    },
+    LanguageModel {
+        language: "fi",
+        script: Script::Latin,
+        alphabet: &[
+            'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q',
+            'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z', '\u{00E4}', '\u{00E5}', '\u{00F6}',
+        ],
+        bigrams: &[
+            0, 168, 141, 137, 149, 173, 138, 134, 149, 147, 156, 178, 172, 167, 161, 175, 168, 106,
+            156, 175, 180, 146, 178, 124, 141, 146, 95, 121, 0, 0, 0, 189, 168, 123, 119, 130, 125,
+            90, 108, 123, 166, 133, 158, 170, 149, 168, 94, 135, 56, 164, 160, 165, 151, 152, 79,
+            91, 88, 77, 39, 0, 0, 0, 123, 106, 67, 75, 39, 104, 92, 62, 44, 133, 113, 32, 116, 85,
+            60, 138, 60, 0, 98, 105, 80, 117, 32, 0, 56, 83, 54, 0, 0, 0, 0, 138, 113, 58, 83, 93,
+            109, 77, 44, 118, 99, 58, 111, 95, 74, 67, 127, 97, 20, 111, 99, 118, 87, 39, 58, 44,
+            58, 0, 0, 0, 0, 0, 143, 142, 87, 77, 93, 156, 77, 68, 67, 151, 67, 77, 97, 75, 85, 161,
+            87, 0, 94, 99, 88, 113, 92, 99, 85, 116, 44, 96, 0, 39, 0, 174, 131, 106, 119, 158,
+            167, 110, 118, 130, 168, 119, 155, 171, 152, 183, 107, 139, 62, 166, 160, 176, 127,
+            132, 85, 118, 122, 67, 104, 0, 0, 0, 130, 90, 20, 74, 97, 88, 116, 51, 51, 124, 20, 20,
+            96, 60, 60, 116, 97, 0, 99, 82, 94, 111, 0, 32, 48, 69, 0, 32, 0, 0, 0, 130, 108, 77,
+            75, 88, 119, 51, 68, 85, 112, 20, 32, 102, 103, 118, 106, 100, 32, 103, 101, 93, 120,
+            20, 56, 0, 48, 66, 39, 0, 0, 0, 126, 141, 20, 80, 142, 157, 60, 32, 32, 126, 133, 137,
+            67, 117, 81, 110, 58, 0, 81, 67, 151, 119, 94, 56, 0, 116, 20, 102, 0, 0, 0, 183, 155,
+            109, 122, 137, 163, 109, 120, 129, 159, 138, 155, 157, 161, 182, 149, 132, 39, 164,
+            180, 179, 111, 150, 63, 108, 63, 95, 132, 0, 106, 0, 78, 158, 20, 39, 20, 149, 0, 0,
+            20, 114, 0, 0, 32, 48, 48, 153, 78, 0, 39, 60, 0, 105, 39, 0, 0, 0, 20, 147, 0, 48, 0,
+            127, 161, 69, 81, 48, 163, 44, 58, 63, 165, 0, 156, 54, 66, 56, 171, 80, 0, 107, 156,
+            131, 159, 75, 54, 0, 141, 20, 161, 0, 109, 0, 137, 165, 65, 62, 101, 169, 98, 84, 78,
+            176, 113, 141, 173, 141, 74, 158, 108, 0, 74, 113, 141, 159, 123, 63, 75, 125, 77, 145,
+            0, 122, 0, 132, 161, 139, 98, 85, 164, 85, 67, 65, 164, 32, 20, 98, 129, 90, 134, 128,
+            20, 76, 107, 90, 150, 77, 32, 65, 99, 0, 156, 0, 32, 0, 193, 153, 70, 104, 139, 169,
+            98, 124, 112, 161, 104, 141, 105, 110, 161, 145, 97, 39, 92, 139, 164, 144, 106, 82,
+            54, 122, 39, 146, 0, 104, 0, 166, 138, 117, 122, 148, 100, 112, 105, 157, 170, 135,
+            147, 165, 146, 174, 142, 148, 0, 149, 171, 160, 120, 129, 103, 62, 67, 20, 39, 0, 0, 0,
+            137, 152, 58, 109, 87, 142, 54, 82, 78, 152, 0, 82, 109, 82, 93, 139, 144, 51, 132,
+            121, 113, 146, 73, 63, 56, 117, 0, 149, 0, 66, 0, 91, 0, 0, 20, 20, 20, 0, 0, 0, 20, 0,
+            0, 39, 20, 44, 0, 20, 20, 48, 20, 63, 85, 20, 85, 0, 39, 0, 0, 0, 0, 0, 139, 154, 90,
+            110, 112, 152, 92, 126, 152, 164, 147, 150, 101, 124, 98, 151, 104, 0, 130, 132, 137,
+            120, 139, 63, 72, 118, 32, 131, 0, 80, 0, 164, 167, 82, 108, 89, 170, 74, 71, 118, 174,
+            66, 149, 119, 121, 95, 151, 126, 54, 109, 164, 183, 148, 116, 74, 51, 148, 48, 153, 0,
+            44, 0, 169, 182, 75, 96, 89, 178, 80, 77, 113, 177, 70, 128, 110, 100, 73, 174, 102, 0,
+            129, 149, 179, 178, 77, 63, 62, 159, 65, 170, 0, 144, 0, 160, 135, 104, 88, 130, 145,
+            90, 104, 111, 149, 121, 153, 152, 145, 158, 155, 113, 0, 141, 165, 157, 157, 141, 0,
+            79, 44, 51, 0, 0, 0, 0, 117, 173, 60, 71, 65, 147, 74, 39, 20, 166, 0, 20, 44, 83, 66,
+            152, 63, 0, 87, 58, 60, 134, 60, 54, 54, 111, 0, 147, 0, 0, 0, 111, 107, 39, 62, 70,
+            102, 20, 72, 66, 107, 0, 20, 68, 56, 62, 98, 60, 0, 87, 82, 0, 20, 0, 39, 39, 0, 20, 0,
+            0, 0, 0, 144, 69, 81, 79, 60, 80, 74, 48, 32, 84, 20, 0, 39, 81, 0, 48, 96, 0, 54, 67,
+            99, 58, 20, 32, 51, 51, 66, 0, 0, 0, 0, 146, 66, 56, 32, 117, 87, 0, 54, 140, 117, 108,
+            135, 133, 143, 135, 68, 134, 0, 106, 143, 160, 20, 111, 20, 44, 140, 44, 106, 0, 131,
+            0, 100, 58, 48, 0, 20, 99, 0, 32, 32, 84, 0, 0, 51, 65, 0, 65, 0, 0, 0, 32, 20, 0, 0,
+            32, 0, 51, 20, 0, 0, 0, 0, 175, 77, 20, 0, 91, 113, 0, 0, 123, 146, 120, 121, 150, 134,
+            149, 133, 121, 0, 149, 147, 142, 20, 125, 0, 0, 157, 0, 162, 0, 32, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 128, 70,
+            0, 0, 54, 0, 0, 0, 87, 99, 89, 97, 100, 106, 126, 70, 83, 0, 79, 119, 126, 0, 48, 0, 0,
+            119, 0, 89, 0, 98, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+    },
+    LanguageModel {
+        language: "ca",
+        script: Script::Latin,
+        alphabet: &[
+            'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q',
+            'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z', '\u{00E0}', '\u{00E7}', '\u{00E8}',
+            '\u{00E9}', '\u{00ED}', '\u{00EF}', '\u{00F2}', '\u{00F3}', '\u{00FA}', '\u{00FC}',
+        ],
+        bigrams: &[
+            0, 183, 154, 178, 189, 188, 170, 151, 166, 167, 120, 118, 181, 166, 176, 162, 179, 148,
+            161, 180, 164, 166, 157, 116, 126, 101, 114, 112, 0, 95, 147, 115, 0, 48, 14, 118, 0,
+            0, 195, 73, 144, 161, 161, 79, 123, 124, 91, 133, 116, 110, 171, 158, 166, 74, 146,
+            142, 174, 144, 169, 141, 127, 97, 97, 94, 83, 0, 126, 50, 0, 38, 72, 0, 62, 0, 0, 0,
+            145, 146, 62, 79, 76, 114, 67, 61, 64, 138, 121, 42, 148, 108, 50, 127, 78, 0, 144,
+            109, 116, 131, 38, 45, 0, 100, 68, 72, 0, 62, 104, 45, 0, 99, 38, 64, 0, 0, 145, 171,
+            67, 135, 88, 142, 52, 33, 126, 170, 52, 119, 137, 74, 67, 169, 72, 26, 143, 110, 159,
+            138, 33, 52, 38, 52, 0, 90, 26, 92, 107, 81, 0, 91, 50, 0, 0, 0, 165, 162, 67, 64, 103,
+            186, 70, 72, 71, 157, 85, 70, 90, 106, 75, 143, 95, 50, 135, 102, 70, 135, 94, 75, 68,
+            67, 45, 79, 0, 105, 0, 85, 0, 73, 68, 57, 0, 0, 187, 136, 128, 163, 135, 118, 137, 152,
+            63, 148, 101, 83, 177, 154, 177, 105, 138, 120, 182, 185, 160, 143, 119, 93, 150, 85,
+            65, 74, 97, 0, 0, 0, 78, 50, 26, 14, 0, 0, 130, 134, 14, 69, 83, 144, 114, 68, 14, 165,
+            0, 26, 116, 55, 52, 146, 63, 0, 112, 101, 91, 125, 0, 0, 14, 72, 0, 54, 0, 48, 33, 87,
+            0, 26, 45, 0, 0, 0, 135, 132, 76, 64, 76, 146, 42, 81, 86, 148, 33, 55, 107, 88, 131,
+            116, 89, 14, 135, 98, 93, 157, 33, 45, 0, 50, 68, 62, 0, 69, 0, 14, 0, 48, 26, 48, 112,
+            0, 128, 162, 26, 38, 61, 130, 50, 14, 76, 130, 14, 38, 61, 65, 55, 125, 42, 0, 76, 67,
+            97, 89, 33, 45, 0, 55, 14, 63, 0, 14, 26, 14, 0, 14, 0, 0, 0, 0, 166, 157, 134, 161,
+            154, 137, 142, 144, 55, 76, 62, 86, 147, 151, 170, 150, 138, 124, 152, 158, 170, 137,
+            132, 60, 150, 58, 95, 103, 0, 26, 38, 0, 48, 38, 163, 0, 38, 0, 95, 125, 62, 14, 26,
+            122, 0, 0, 14, 82, 0, 45, 0, 0, 26, 96, 76, 0, 26, 38, 0, 115, 33, 0, 0, 0, 0, 69, 0,
+            0, 0, 0, 0, 0, 0, 72, 0, 0, 126, 109, 74, 62, 57, 106, 52, 90, 81, 103, 0, 33, 52, 61,
+            52, 95, 74, 14, 48, 89, 92, 89, 26, 66, 0, 50, 0, 0, 0, 0, 26, 0, 0, 0, 0, 38, 0, 0,
+            184, 172, 84, 95, 102, 166, 92, 104, 71, 164, 0, 62, 158, 113, 70, 150, 104, 0, 55,
+            154, 138, 125, 77, 57, 55, 91, 77, 91, 83, 98, 26, 131, 0, 80, 52, 14, 0, 0, 151, 161,
+            149, 64, 72, 164, 85, 33, 52, 151, 26, 57, 86, 110, 98, 147, 153, 14, 66, 111, 82, 115,
+            52, 38, 48, 54, 0, 118, 0, 117, 130, 114, 0, 121, 45, 95, 0, 0, 172, 164, 75, 152, 145,
+            151, 136, 138, 100, 153, 90, 88, 116, 86, 107, 174, 83, 85, 84, 154, 173, 124, 136, 50,
+            69, 130, 74, 88, 107, 106, 14, 72, 0, 82, 0, 118, 0, 0, 172, 86, 148, 144, 144, 76,
+            105, 145, 66, 108, 74, 93, 146, 163, 168, 103, 144, 103, 173, 153, 150, 128, 123, 106,
+            73, 50, 50, 57, 42, 45, 0, 0, 14, 0, 0, 26, 0, 0, 145, 155, 58, 136, 89, 166, 45, 86,
+            73, 128, 0, 92, 144, 77, 88, 161, 98, 42, 159, 118, 131, 136, 63, 50, 45, 74, 0, 97,
+            109, 64, 0, 92, 0, 86, 38, 113, 38, 0, 106, 52, 0, 14, 0, 14, 0, 0, 0, 33, 0, 0, 33, 0,
+            0, 0, 14, 0, 38, 14, 61, 163, 0, 78, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 93, 0, 181,
+            171, 115, 130, 138, 178, 105, 131, 60, 165, 14, 92, 107, 147, 126, 160, 114, 109, 148,
+            150, 146, 131, 121, 67, 114, 101, 48, 135, 91, 120, 109, 90, 0, 114, 86, 66, 0, 0, 194,
+            145, 101, 144, 81, 163, 86, 77, 122, 163, 0, 97, 95, 97, 65, 145, 148, 110, 83, 150,
+            171, 145, 91, 83, 58, 97, 14, 74, 0, 68, 0, 102, 0, 83, 108, 57, 0, 0, 184, 171, 57,
+            103, 98, 169, 83, 129, 111, 163, 95, 89, 97, 97, 78, 152, 99, 14, 164, 150, 104, 143,
+            48, 61, 152, 107, 138, 139, 0, 105, 125, 102, 0, 78, 97, 33, 0, 0, 156, 146, 124, 123,
+            124, 160, 104, 106, 26, 138, 92, 79, 137, 135, 163, 61, 134, 26, 150, 150, 156, 45, 70,
+            42, 105, 52, 65, 75, 0, 104, 57, 78, 118, 0, 14, 0, 0, 0, 110, 148, 42, 61, 50, 146,
+            38, 33, 14, 144, 0, 0, 14, 60, 45, 127, 38, 0, 38, 42, 14, 61, 33, 0, 33, 14, 0, 133,
+            0, 63, 79, 105, 0, 26, 14, 0, 0, 0, 108, 108, 42, 33, 69, 98, 42, 60, 58, 102, 33, 42,
+            61, 42, 70, 92, 45, 0, 70, 81, 0, 38, 0, 33, 42, 54, 14, 0, 0, 0, 0, 0, 0, 42, 0, 0, 0,
+            0, 151, 122, 73, 104, 54, 156, 61, 0, 73, 139, 0, 65, 38, 76, 14, 87, 120, 0, 67, 66,
+            123, 70, 14, 0, 70, 58, 63, 0, 0, 38, 14, 71, 0, 93, 33, 38, 0, 0, 129, 122, 57, 57,
+            33, 85, 14, 52, 0, 65, 0, 0, 54, 82, 76, 84, 99, 0, 58, 100, 100, 60, 26, 26, 38, 33,
+            0, 14, 0, 0, 38, 0, 0, 0, 0, 0, 0, 0, 95, 136, 61, 0, 14, 118, 0, 45, 45, 97, 0, 0, 42,
+            64, 0, 91, 0, 0, 50, 52, 14, 54, 0, 42, 0, 50, 38, 66, 0, 0, 26, 26, 0, 0, 0, 0, 0, 0,
+            142, 0, 61, 121, 48, 0, 88, 102, 50, 38, 0, 33, 135, 105, 114, 0, 75, 85, 112, 87, 107,
+            88, 60, 0, 99, 0, 50, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 112, 133, 0, 0, 26, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 94, 0, 0, 0, 0, 0, 52, 0, 0, 0, 0, 0, 42, 0, 0, 0, 0, 0, 57, 0, 0, 0,
+            0, 103, 0, 42, 62, 87, 0, 50, 71, 0, 61, 0, 0, 60, 14, 127, 0, 61, 26, 109, 125, 102,
+            26, 69, 0, 86, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 129, 0, 0, 0, 0, 0, 0, 0, 0, 26,
+            0, 0, 26, 57, 75, 0, 0, 14, 0, 155, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 122, 58, 54, 95, 82, 62, 80, 79, 0, 0, 0, 0, 71, 106, 135, 38, 38, 0, 57, 107, 94,
+            0, 58, 0, 89, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 45, 0, 75, 14, 0, 0, 0, 0,
+            0, 0, 14, 0, 69, 0, 0, 0, 0, 71, 116, 72, 0, 0, 33, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 116, 0, 14, 79, 103, 0, 42, 60, 0, 0, 0, 0, 103, 54, 99, 0, 91, 0, 116, 93, 48, 0,
+            0, 0, 33, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 164, 0, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0,
+            0, 0, 108, 0, 0, 0, 54, 82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 100,
+            0, 113, 0, 0, 0, 0, 26, 0, 26, 0, 0, 103, 112, 83, 0, 42, 0, 33, 115, 77, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 112, 0, 0, 0, 14, 0, 38, 38, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 90, 0, 26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0,
+        ],
+    },
+    LanguageModel {
+        language: "is",
+        script: Script::Latin,
+        alphabet: &[
+            'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q',
+            'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z', '\u{00E1}', '\u{00E6}', '\u{00E9}',
+            '\u{00ED}', '\u{00F0}', '\u{00F3}', '\u{00F6}', '\u{00FA}', '\u{00FD}', '\u{00FE}',
+        ],
+        bigrams: &[
+            0, 178, 179, 159, 161, 168, 162, 170, 162, 150, 142, 173, 168, 178, 165, 147, 160, 116,
+            147, 178, 177, 144, 158, 136, 118, 153, 135, 141, 82, 0, 143, 0, 135, 102, 127, 63,
+            137, 0, 196, 129, 155, 134, 149, 127, 140, 151, 142, 152, 127, 157, 171, 167, 187, 124,
+            155, 104, 179, 158, 160, 150, 137, 144, 90, 146, 124, 0, 0, 0, 125, 147, 101, 63, 0, 0,
+            63, 0, 125, 170, 82, 89, 0, 153, 0, 0, 111, 151, 78, 75, 121, 103, 104, 150, 0, 0, 139,
+            0, 0, 148, 84, 112, 0, 108, 0, 0, 94, 82, 143, 0, 111, 63, 123, 96, 0, 0, 125, 139, 0,
+            87, 75, 117, 70, 0, 156, 120, 0, 82, 78, 63, 63, 134, 0, 0, 101, 0, 84, 117, 0, 0, 0,
+            78, 0, 63, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 151, 162, 51, 0, 123, 145, 0, 111, 120, 166,
+            126, 84, 118, 78, 90, 146, 0, 0, 114, 130, 0, 141, 78, 84, 0, 106, 102, 90, 95, 82,
+            135, 0, 129, 84, 94, 82, 0, 0, 164, 137, 125, 123, 126, 122, 130, 142, 106, 147, 103,
+            152, 163, 146, 164, 115, 121, 84, 170, 163, 145, 111, 107, 116, 106, 148, 106, 0, 0, 0,
+            0, 127, 120, 51, 0, 0, 96, 0, 138, 139, 63, 51, 51, 123, 109, 99, 63, 138, 113, 87,
+            121, 0, 99, 136, 51, 0, 140, 109, 116, 124, 75, 75, 0, 124, 0, 78, 101, 0, 99, 84, 51,
+            90, 63, 0, 0, 0, 161, 167, 121, 0, 100, 147, 0, 133, 130, 149, 114, 111, 128, 97, 122,
+            142, 63, 0, 134, 125, 131, 164, 120, 116, 63, 111, 0, 95, 75, 0, 132, 96, 132, 113, 95,
+            0, 87, 0, 126, 163, 0, 63, 63, 146, 0, 63, 87, 151, 78, 90, 130, 114, 109, 144, 63, 63,
+            117, 63, 114, 147, 112, 87, 0, 51, 0, 114, 117, 102, 94, 0, 63, 90, 87, 0, 0, 0, 183,
+            155, 128, 135, 139, 121, 130, 130, 108, 96, 111, 144, 159, 142, 174, 116, 131, 101,
+            156, 150, 153, 101, 109, 112, 95, 120, 99, 0, 0, 82, 0, 168, 0, 0, 0, 0, 70, 0, 90,
+            159, 75, 0, 75, 111, 0, 0, 75, 131, 63, 84, 0, 63, 0, 111, 101, 0, 0, 0, 0, 116, 0, 0,
+            0, 63, 0, 121, 0, 0, 107, 0, 120, 118, 116, 0, 0, 0, 161, 182, 0, 63, 0, 142, 84, 0,
+            133, 159, 122, 149, 124, 106, 153, 151, 111, 0, 147, 120, 156, 149, 101, 119, 63, 111,
+            0, 92, 0, 0, 134, 0, 127, 90, 113, 99, 0, 0, 172, 171, 124, 63, 159, 161, 109, 116, 89,
+            158, 101, 115, 149, 105, 78, 140, 97, 0, 78, 138, 129, 152, 108, 63, 0, 123, 63, 124,
+            95, 94, 138, 0, 123, 70, 112, 152, 121, 0, 155, 171, 152, 63, 106, 151, 90, 70, 97,
+            160, 95, 70, 89, 112, 106, 146, 120, 0, 84, 114, 102, 133, 100, 90, 0, 145, 75, 165,
+            75, 63, 129, 0, 112, 119, 78, 51, 51, 0, 177, 167, 97, 107, 171, 159, 75, 178, 108,
+            158, 116, 133, 119, 153, 151, 153, 82, 87, 75, 162, 145, 146, 125, 87, 75, 131, 101,
+            96, 112, 82, 145, 0, 92, 51, 113, 129, 0, 0, 166, 115, 125, 113, 113, 96, 114, 139,
+            108, 118, 108, 139, 145, 141, 163, 122, 124, 87, 162, 133, 139, 123, 101, 106, 75, 101,
+            94, 63, 0, 0, 63, 51, 0, 0, 0, 0, 0, 0, 125, 156, 51, 70, 0, 136, 51, 51, 118, 139, 90,
+            0, 104, 104, 127, 129, 124, 0, 114, 113, 118, 138, 70, 82, 0, 82, 0, 87, 87, 75, 113,
+            0, 116, 51, 126, 0, 0, 0, 97, 95, 0, 63, 0, 78, 0, 0, 0, 82, 0, 0, 0, 0, 0, 0, 0, 0,
+            51, 0, 78, 124, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 178, 170, 127, 84, 125,
+            156, 108, 131, 116, 166, 99, 136, 127, 128, 136, 145, 92, 63, 132, 148, 136, 142, 84,
+            99, 0, 102, 90, 141, 114, 108, 154, 143, 127, 117, 139, 70, 0, 0, 152, 162, 99, 102,
+            84, 148, 0, 70, 145, 149, 115, 184, 136, 113, 134, 132, 113, 63, 127, 142, 165, 146,
+            131, 99, 0, 51, 0, 92, 97, 109, 145, 0, 116, 84, 118, 113, 0, 0, 171, 168, 75, 99, 0,
+            147, 99, 97, 132, 160, 116, 102, 116, 70, 124, 139, 84, 0, 136, 132, 138, 167, 87, 82,
+            75, 118, 70, 151, 118, 103, 142, 63, 126, 117, 124, 51, 0, 0, 163, 144, 119, 112, 127,
+            125, 105, 135, 94, 130, 97, 135, 149, 163, 167, 108, 125, 75, 170, 149, 140, 96, 102,
+            114, 82, 118, 101, 75, 0, 0, 90, 140, 0, 0, 0, 0, 106, 0, 90, 146, 75, 0, 70, 165, 0,
+            0, 0, 147, 0, 0, 63, 0, 102, 114, 0, 0, 92, 0, 0, 97, 0, 0, 0, 0, 0, 63, 132, 75, 127,
+            0, 0, 63, 0, 0, 0, 0, 107, 154, 78, 0, 0, 128, 0, 0, 51, 120, 63, 75, 82, 63, 75, 122,
+            0, 0, 75, 63, 0, 106, 0, 63, 0, 90, 0, 63, 0, 75, 0, 0, 0, 0, 0, 0, 0, 0, 102, 82, 99,
+            0, 0, 96, 0, 0, 75, 103, 0, 0, 75, 0, 75, 75, 95, 0, 0, 63, 100, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 87, 0, 0, 0, 0, 0, 0, 0, 126, 156, 70, 87, 94, 125, 125, 109, 0, 126, 135, 108,
+            110, 101, 145, 134, 104, 0, 130, 109, 124, 130, 51, 0, 0, 75, 63, 63, 0, 75, 63, 92, 0,
+            0, 0, 0, 0, 0, 101, 131, 0, 0, 0, 116, 63, 82, 111, 123, 0, 0, 51, 63, 75, 101, 0, 0,
+            0, 0, 63, 94, 0, 63, 0, 82, 75, 63, 0, 0, 0, 0, 0, 0, 75, 0, 0, 0, 146, 111, 82, 63,
+            84, 90, 87, 111, 75, 101, 0, 152, 166, 96, 122, 0, 63, 0, 119, 121, 108, 0, 0, 0, 63,
+            0, 0, 0, 0, 63, 75, 87, 0, 0, 0, 0, 94, 0, 94, 51, 0, 0, 0, 0, 51, 118, 0, 70, 90, 95,
+            95, 101, 124, 0, 0, 0, 118, 107, 103, 0, 89, 0, 0, 0, 0, 0, 0, 0, 0, 127, 0, 0, 0, 0,
+            0, 0, 121, 63, 0, 63, 0, 0, 75, 0, 0, 0, 0, 97, 82, 63, 63, 0, 0, 0, 113, 63, 103, 70,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 63, 0, 0, 147, 150, 123, 0, 97, 103, 70, 123, 0,
+            0, 0, 141, 125, 107, 146, 63, 89, 0, 124, 168, 123, 92, 94, 0, 75, 0, 0, 96, 0, 0, 0,
+            92, 102, 0, 75, 0, 63, 0, 170, 140, 94, 0, 51, 63, 97, 96, 118, 137, 70, 51, 111, 96,
+            70, 0, 0, 0, 100, 108, 0, 149, 153, 0, 0, 0, 0, 0, 0, 0, 0, 63, 0, 63, 0, 0, 51, 0,
+            143, 106, 84, 0, 82, 0, 51, 119, 0, 0, 0, 119, 133, 128, 132, 51, 111, 0, 116, 121,
+            112, 51, 122, 0, 0, 0, 0, 0, 0, 0, 75, 117, 0, 0, 0, 0, 103, 0, 90, 0, 0, 0, 0, 0, 101,
+            116, 0, 0, 0, 51, 126, 78, 128, 0, 101, 0, 101, 82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            109, 0, 75, 0, 0, 0, 0, 125, 127, 101, 0, 123, 90, 0, 115, 70, 89, 0, 97, 115, 114,
+            126, 0, 92, 0, 129, 120, 118, 0, 94, 0, 90, 0, 0, 0, 0, 0, 82, 0, 0, 75, 63, 0, 0, 0,
+            94, 63, 0, 0, 0, 0, 0, 0, 0, 51, 123, 0, 82, 75, 111, 0, 97, 0, 108, 123, 92, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 153, 0, 0, 63, 0, 0, 0, 104, 90, 106, 0, 0, 127, 0, 0, 0, 0, 103,
+            0, 0, 0, 0, 0, 0, 0, 63, 0, 0, 0, 78, 0, 0, 63, 0, 82, 63, 0, 96, 0, 0, 63, 87, 129, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+    },
+    LanguageModel {
+        language: "ga",
+        script: Script::Latin,
+        alphabet: &[
+            'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q',
+            'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z', '\u{00E1}', '\u{00E9}', '\u{00ED}',
+            '\u{00F3}', '\u{00FA}',
+        ],
+        bigrams: &[
+            0, 193, 164, 184, 172, 153, 172, 167, 150, 171, 121, 113, 177, 162, 180, 153, 155, 103,
+            169, 180, 176, 147, 118, 130, 127, 85, 84, 141, 140, 142, 132, 142, 0, 186, 73, 147,
+            174, 173, 76, 96, 165, 0, 185, 61, 102, 153, 164, 184, 149, 122, 0, 181, 165, 159, 100,
+            99, 88, 85, 78, 54, 0, 0, 154, 0, 0, 0, 127, 144, 73, 66, 42, 127, 96, 61, 170, 114,
+            61, 42, 132, 103, 42, 112, 96, 0, 135, 101, 54, 123, 54, 0, 0, 80, 73, 78, 61, 78, 0,
+            80, 0, 152, 157, 61, 82, 102, 146, 80, 54, 184, 130, 0, 109, 130, 107, 85, 168, 97, 0,
+            152, 124, 125, 140, 42, 73, 76, 0, 0, 133, 110, 87, 127, 110, 0, 177, 152, 66, 95, 89,
+            156, 96, 61, 167, 159, 61, 82, 139, 84, 87, 136, 90, 0, 115, 92, 130, 126, 84, 0, 89,
+            89, 0, 123, 126, 125, 96, 135, 0, 182, 183, 89, 117, 112, 103, 111, 113, 73, 163, 61,
+            73, 121, 106, 134, 156, 98, 54, 135, 125, 124, 61, 89, 80, 120, 76, 61, 150, 0, 0, 0,
+            0, 0, 136, 139, 0, 84, 99, 131, 118, 54, 150, 134, 0, 0, 92, 61, 54, 141, 73, 0, 119,
+            73, 106, 132, 0, 42, 42, 54, 0, 114, 146, 116, 92, 54, 0, 155, 155, 76, 134, 91, 127,
+            80, 73, 165, 129, 0, 0, 117, 99, 126, 142, 99, 0, 119, 84, 115, 147, 0, 42, 0, 0, 84,
+            117, 54, 42, 129, 42, 0, 183, 185, 145, 121, 119, 169, 136, 42, 103, 145, 0, 0, 135,
+            123, 147, 160, 99, 0, 157, 131, 156, 151, 42, 61, 0, 0, 42, 155, 146, 140, 127, 133, 0,
+            150, 150, 137, 141, 176, 103, 129, 153, 54, 76, 0, 66, 172, 160, 176, 161, 150, 42,
+            175, 170, 169, 66, 90, 54, 108, 0, 94, 0, 0, 54, 42, 143, 0, 73, 106, 42, 42, 42, 73,
+            0, 0, 0, 61, 0, 0, 0, 0, 54, 89, 101, 0, 42, 66, 0, 54, 54, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 122, 78, 0, 73, 66, 114, 42, 42, 42, 89, 0, 0, 0, 54, 0, 76, 88, 0, 42, 85, 70, 88,
+            0, 61, 0, 0, 0, 0, 0, 0, 0, 0, 0, 170, 168, 106, 94, 92, 171, 89, 70, 61, 144, 0, 0,
+            141, 87, 66, 143, 102, 0, 103, 106, 143, 138, 70, 42, 0, 96, 85, 141, 138, 152, 124,
+            123, 0, 155, 151, 132, 91, 61, 136, 84, 0, 183, 129, 54, 0, 127, 97, 118, 113, 134, 0,
+            96, 126, 90, 113, 54, 0, 54, 42, 0, 136, 119, 113, 125, 73, 0, 187, 172, 94, 133, 132,
+            162, 103, 133, 61, 145, 0, 103, 107, 147, 171, 126, 91, 0, 141, 133, 158, 131, 93, 0,
+            42, 66, 54, 145, 117, 164, 140, 119, 0, 157, 84, 131, 156, 135, 42, 118, 151, 42, 160,
+            42, 91, 143, 175, 167, 99, 125, 0, 164, 153, 129, 110, 73, 107, 66, 66, 42, 0, 0, 42,
+            0, 0, 0, 148, 141, 66, 94, 95, 145, 76, 89, 128, 114, 0, 73, 120, 99, 108, 125, 98, 42,
+            138, 107, 127, 99, 84, 73, 66, 70, 0, 108, 130, 98, 106, 0, 0, 87, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 61, 0, 0, 0, 42, 0, 42, 42, 73, 90, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 184,
+            167, 136, 130, 145, 160, 100, 137, 0, 159, 42, 78, 122, 138, 104, 154, 90, 0, 147, 127,
+            158, 139, 82, 73, 54, 96, 54, 146, 141, 145, 134, 144, 0, 177, 150, 54, 164, 66, 157,
+            100, 100, 147, 146, 0, 94, 136, 98, 96, 145, 144, 61, 108, 116, 158, 123, 90, 80, 66,
+            109, 42, 146, 112, 124, 94, 126, 0, 166, 170, 66, 96, 96, 165, 89, 80, 180, 139, 0, 82,
+            124, 96, 90, 132, 115, 0, 144, 119, 111, 124, 42, 70, 54, 101, 0, 157, 112, 125, 111,
+            129, 0, 133, 151, 92, 122, 106, 84, 94, 109, 54, 158, 42, 0, 117, 123, 136, 54, 104, 0,
+            140, 148, 133, 42, 0, 0, 84, 0, 0, 70, 0, 109, 54, 0, 0, 109, 100, 42, 42, 73, 119, 42,
+            61, 42, 96, 0, 0, 0, 70, 0, 61, 84, 0, 54, 66, 54, 54, 42, 0, 42, 0, 0, 0, 0, 42, 0, 0,
+            0, 109, 114, 66, 0, 89, 90, 42, 87, 78, 99, 0, 42, 42, 70, 70, 101, 54, 0, 84, 92, 0,
+            0, 0, 42, 0, 0, 42, 0, 0, 0, 0, 0, 0, 132, 73, 87, 89, 42, 80, 66, 0, 54, 73, 0, 0, 54,
+            90, 0, 0, 104, 0, 42, 70, 94, 70, 0, 0, 0, 66, 66, 0, 0, 0, 0, 0, 0, 122, 42, 0, 0, 0,
+            42, 0, 0, 0, 42, 0, 0, 54, 92, 93, 54, 94, 0, 54, 92, 80, 0, 42, 0, 42, 54, 0, 0, 0, 0,
+            0, 0, 0, 92, 42, 42, 0, 0, 93, 0, 0, 0, 100, 0, 0, 42, 54, 0, 66, 0, 0, 0, 0, 0, 0, 0,
+            54, 0, 0, 42, 0, 0, 0, 0, 0, 0, 165, 0, 119, 82, 123, 0, 0, 92, 0, 173, 0, 0, 138, 91,
+            149, 0, 0, 0, 126, 129, 125, 0, 0, 0, 0, 0, 0, 0, 0, 0, 42, 0, 0, 138, 155, 80, 0, 42,
+            0, 0, 0, 0, 166, 0, 0, 0, 42, 95, 0, 0, 0, 0, 0, 54, 0, 0, 0, 0, 0, 0, 42, 0, 42, 0, 0,
+            0, 169, 0, 76, 114, 101, 0, 91, 0, 0, 0, 0, 0, 137, 78, 140, 168, 0, 0, 118, 112, 132,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 42, 42, 0, 147, 0, 42, 84, 132, 0, 98, 120, 0, 145, 0, 0,
+            73, 96, 113, 0, 42, 0, 133, 108, 91, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 159, 0, 103,
+            106, 84, 0, 0, 0, 0, 140, 0, 0, 128, 54, 127, 0, 105, 0, 102, 144, 78, 0, 0, 0, 0, 0,
+            0, 42, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+    },
+    LanguageModel {
+        language: "et",
+        script: Script::Latin,
+        alphabet: &[
+            'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q',
+            'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z', '\u{00E4}', '\u{00F5}', '\u{00F6}',
+            '\u{00FC}', '\u{0161}', '\u{017E}',
+        ],
+        bigrams: &[
+            0, 173, 156, 141, 149, 169, 163, 144, 150, 156, 159, 184, 171, 168, 165, 168, 174, 102,
+            157, 181, 172, 146, 183, 122, 122, 101, 109, 115, 130, 0, 144, 107, 61, 0, 190, 167,
+            156, 110, 164, 127, 111, 142, 142, 169, 145, 156, 176, 164, 171, 124, 129, 0, 168, 172,
+            173, 138, 154, 75, 94, 102, 68, 0, 124, 0, 73, 101, 0, 0, 152, 160, 73, 68, 37, 139,
+            49, 0, 90, 142, 100, 49, 125, 85, 75, 139, 0, 0, 130, 93, 71, 130, 73, 65, 0, 83, 0, 0,
+            0, 0, 56, 0, 0, 0, 132, 120, 56, 71, 88, 114, 68, 37, 115, 86, 0, 113, 99, 37, 61, 126,
+            68, 0, 110, 86, 108, 96, 37, 65, 61, 0, 0, 0, 0, 0, 0, 0, 0, 0, 178, 170, 87, 37, 91,
+            160, 97, 56, 83, 162, 101, 97, 102, 138, 106, 132, 86, 0, 116, 115, 78, 148, 107, 65,
+            78, 80, 61, 0, 61, 0, 56, 0, 120, 0, 189, 155, 136, 120, 157, 168, 122, 148, 125, 162,
+            91, 156, 168, 158, 166, 110, 116, 61, 168, 166, 160, 97, 143, 68, 117, 87, 78, 61, 0,
+            0, 56, 61, 89, 0, 127, 158, 0, 78, 77, 105, 105, 73, 0, 138, 0, 68, 93, 56, 49, 129,
+            61, 0, 113, 78, 98, 103, 0, 37, 0, 73, 0, 68, 0, 68, 49, 0, 0, 0, 144, 166, 85, 56, 94,
+            148, 49, 71, 92, 150, 0, 99, 103, 92, 134, 120, 96, 0, 135, 96, 94, 151, 68, 37, 0, 0,
+            61, 0, 49, 0, 0, 0, 0, 0, 122, 137, 0, 37, 71, 150, 0, 0, 99, 142, 125, 90, 78, 87, 85,
+            137, 0, 0, 80, 78, 141, 122, 116, 0, 0, 73, 0, 83, 97, 0, 83, 0, 0, 0, 190, 151, 133,
+            117, 167, 129, 126, 161, 121, 165, 99, 168, 168, 166, 173, 135, 133, 80, 153, 177, 168,
+            121, 142, 65, 109, 56, 106, 0, 61, 0, 0, 81, 0, 0, 78, 167, 84, 0, 0, 138, 0, 0, 0, 98,
+            56, 49, 0, 65, 75, 119, 77, 0, 0, 49, 37, 148, 0, 0, 0, 0, 0, 136, 49, 0, 0, 0, 0, 0,
+            160, 168, 68, 61, 65, 157, 61, 68, 104, 159, 0, 136, 125, 90, 98, 161, 103, 0, 130,
+            161, 132, 162, 114, 37, 0, 49, 0, 135, 122, 49, 122, 61, 0, 0, 167, 161, 94, 73, 152,
+            172, 71, 131, 49, 175, 147, 129, 152, 111, 114, 154, 105, 0, 68, 126, 147, 149, 114,
+            77, 0, 89, 37, 116, 134, 68, 120, 0, 65, 0, 145, 168, 149, 61, 71, 165, 56, 0, 68, 176,
+            0, 98, 95, 128, 108, 134, 121, 37, 89, 104, 80, 141, 80, 0, 0, 77, 0, 143, 106, 56, 0,
+            61, 0, 0, 166, 157, 86, 112, 168, 172, 116, 151, 56, 165, 105, 129, 100, 99, 144, 138,
+            90, 0, 71, 122, 156, 153, 94, 56, 49, 87, 73, 128, 101, 37, 102, 61, 0, 0, 147, 98,
+            123, 111, 137, 131, 102, 143, 131, 128, 100, 143, 165, 143, 170, 163, 126, 0, 157, 137,
+            140, 118, 116, 101, 78, 37, 61, 0, 0, 0, 0, 0, 0, 0, 142, 150, 61, 91, 75, 148, 0, 87,
+            68, 148, 0, 73, 111, 90, 97, 149, 126, 0, 147, 119, 117, 138, 77, 37, 0, 37, 0, 128,
+            118, 78, 101, 0, 0, 0, 85, 61, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 37, 0,
+            68, 98, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 151, 163, 111, 115, 139, 161, 86, 147, 109,
+            173, 146, 127, 109, 135, 109, 149, 103, 0, 129, 133, 140, 145, 143, 73, 0, 102, 37, 95,
+            91, 68, 98, 61, 0, 0, 177, 162, 85, 96, 80, 179, 86, 71, 114, 170, 85, 138, 124, 106,
+            96, 139, 119, 56, 105, 144, 176, 165, 114, 83, 37, 97, 37, 104, 126, 61, 143, 0, 0, 0,
+            173, 182, 37, 90, 96, 172, 102, 75, 116, 166, 86, 120, 125, 141, 106, 145, 111, 0, 142,
+            146, 138, 172, 102, 37, 75, 98, 68, 128, 125, 119, 136, 128, 0, 0, 161, 131, 137, 77,
+            164, 120, 114, 135, 123, 147, 110, 128, 149, 151, 152, 85, 134, 0, 151, 170, 165, 157,
+            124, 0, 98, 61, 68, 0, 61, 0, 0, 87, 0, 0, 144, 171, 37, 0, 0, 149, 84, 37, 0, 162, 37,
+            37, 49, 71, 98, 132, 0, 0, 87, 99, 37, 123, 65, 0, 49, 0, 0, 154, 161, 56, 68, 0, 0, 0,
+            107, 115, 37, 0, 73, 77, 0, 84, 49, 96, 49, 0, 49, 37, 77, 78, 0, 0, 65, 56, 0, 0, 0,
+            0, 56, 0, 49, 0, 0, 0, 0, 0, 0, 0, 133, 71, 77, 75, 61, 83, 49, 0, 0, 86, 0, 0, 49, 65,
+            49, 73, 92, 0, 61, 37, 101, 0, 0, 0, 89, 71, 0, 0, 0, 0, 0, 0, 0, 0, 125, 85, 0, 0, 37,
+            61, 0, 0, 0, 65, 0, 56, 75, 78, 86, 71, 88, 0, 65, 96, 83, 65, 37, 49, 49, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 103, 81, 0, 0, 0, 108, 0, 49, 61, 90, 49, 0, 37, 0, 49, 75, 0, 0, 0, 56,
+            0, 68, 0, 61, 0, 0, 37, 0, 0, 0, 0, 0, 0, 0, 68, 0, 94, 0, 87, 116, 0, 84, 124, 143, 0,
+            49, 149, 65, 110, 75, 81, 0, 156, 129, 116, 0, 65, 0, 0, 0, 0, 143, 0, 49, 0, 0, 0, 0,
+            37, 0, 37, 0, 37, 94, 0, 68, 120, 154, 77, 68, 107, 90, 144, 37, 129, 0, 125, 89, 146,
+            115, 71, 0, 0, 0, 49, 49, 49, 0, 0, 0, 0, 0, 97, 0, 37, 0, 87, 0, 0, 0, 0, 49, 49, 75,
+            77, 0, 71, 0, 37, 0, 92, 0, 103, 68, 0, 0, 0, 0, 0, 0, 0, 122, 49, 0, 0, 0, 89, 61, 97,
+            0, 80, 56, 68, 80, 139, 0, 0, 121, 132, 139, 101, 0, 123, 0, 107, 130, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 131, 37, 0, 0, 61, 113, 0, 0, 0, 107, 0, 0, 0, 117, 0, 73, 0, 61, 0, 99,
+            0, 0, 0, 0, 65, 97, 81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 106, 0, 0, 0, 71, 0, 0, 0,
+            111, 0, 37, 0, 0, 0, 68, 61, 0, 0, 0, 0, 73, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0,
+        ],
+    },
+    LanguageModel {
+        language: "pl",
+        script: Script::Latin,
+        alphabet: &[
+            'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q',
+            'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z', '\u{00F3}', '\u{0105}', '\u{0107}',
+            '\u{0119}', '\u{0142}', '\u{0144}', '\u{015B}', '\u{017A}', '\u{017C}',
+        ],
+        bigrams: &[
+            0, 165, 165, 160, 172, 147, 147, 155, 141, 161, 161, 166, 158, 167, 178, 170, 185, 111,
+            159, 175, 167, 166, 134, 178, 114, 111, 173, 77, 59, 44, 57, 118, 0, 125, 105, 123, 0,
+            188, 95, 139, 158, 152, 87, 108, 141, 110, 131, 145, 159, 163, 154, 179, 96, 143, 94,
+            165, 154, 157, 127, 107, 155, 93, 111, 148, 0, 20, 142, 0, 145, 128, 104, 78, 128, 0,
+            140, 144, 74, 85, 53, 138, 27, 61, 61, 145, 79, 74, 134, 82, 96, 136, 51, 0, 138, 125,
+            61, 134, 27, 85, 20, 139, 63, 87, 78, 0, 112, 135, 0, 0, 0, 0, 0, 139, 140, 50, 95, 91,
+            152, 60, 63, 164, 159, 156, 132, 96, 73, 115, 137, 94, 44, 109, 87, 117, 118, 39, 50,
+            8, 134, 170, 79, 95, 20, 59, 20, 0, 0, 0, 0, 0, 156, 156, 85, 125, 105, 148, 78, 81,
+            80, 132, 76, 118, 134, 106, 149, 162, 124, 0, 134, 123, 92, 130, 84, 115, 67, 135, 148,
+            97, 99, 0, 74, 137, 0, 77, 107, 113, 0, 189, 124, 124, 149, 148, 105, 127, 154, 89,
+            103, 153, 153, 151, 151, 169, 117, 140, 79, 165, 162, 152, 98, 103, 135, 119, 95, 143,
+            39, 0, 97, 20, 117, 125, 136, 89, 135, 0, 130, 118, 20, 67, 77, 118, 109, 65, 8, 143,
+            68, 55, 109, 61, 71, 139, 56, 0, 117, 98, 98, 112, 0, 27, 36, 79, 0, 8, 0, 0, 36, 0, 0,
+            0, 0, 0, 0, 141, 143, 74, 64, 106, 129, 42, 89, 104, 144, 42, 73, 114, 83, 127, 156,
+            91, 36, 141, 102, 93, 142, 87, 96, 53, 71, 66, 113, 96, 0, 72, 120, 0, 0, 0, 20, 0,
+            157, 139, 32, 88, 73, 141, 59, 50, 74, 133, 42, 44, 72, 83, 90, 138, 32, 0, 105, 83,
+            103, 108, 50, 85, 0, 93, 8, 59, 0, 0, 58, 8, 0, 8, 0, 0, 0, 179, 167, 119, 148, 142,
+            187, 114, 135, 73, 121, 122, 163, 139, 136, 162, 151, 114, 71, 130, 158, 143, 128, 102,
+            136, 100, 66, 123, 86, 128, 128, 151, 98, 108, 91, 0, 108, 0, 152, 152, 64, 79, 93,
+            161, 39, 50, 42, 144, 58, 77, 55, 101, 118, 102, 80, 0, 73, 133, 108, 115, 36, 81, 0,
+            32, 20, 51, 138, 0, 129, 0, 0, 129, 0, 0, 0, 157, 163, 87, 130, 57, 116, 65, 93, 94,
+            167, 42, 76, 137, 77, 116, 165, 80, 8, 145, 135, 141, 150, 51, 99, 20, 53, 0, 131, 92,
+            0, 94, 122, 0, 59, 0, 92, 0, 144, 157, 107, 80, 104, 159, 99, 97, 63, 170, 82, 124,
+            126, 83, 144, 152, 113, 0, 63, 122, 118, 147, 74, 82, 54, 106, 76, 59, 95, 0, 95, 0, 0,
+            0, 0, 0, 0, 159, 159, 124, 93, 81, 150, 54, 48, 51, 161, 53, 97, 93, 105, 117, 158,
+            125, 27, 88, 102, 83, 137, 46, 51, 46, 125, 32, 98, 63, 0, 42, 48, 0, 27, 0, 0, 0, 155,
+            176, 83, 130, 145, 163, 133, 139, 83, 187, 85, 124, 100, 83, 128, 158, 101, 36, 80,
+            128, 153, 134, 82, 83, 57, 163, 85, 68, 126, 0, 98, 0, 0, 0, 0, 0, 0, 176, 101, 149,
+            146, 165, 80, 118, 145, 81, 99, 109, 150, 151, 152, 165, 108, 151, 54, 163, 152, 143,
+            128, 115, 171, 77, 71, 144, 0, 0, 0, 0, 129, 126, 145, 0, 150, 0, 141, 153, 51, 138,
+            88, 137, 50, 94, 91, 154, 0, 101, 155, 75, 112, 171, 107, 20, 167, 113, 124, 137, 68,
+            68, 32, 114, 0, 119, 42, 0, 87, 97, 0, 0, 0, 0, 0, 103, 55, 0, 36, 0, 42, 0, 0, 0, 55,
+            0, 0, 67, 0, 0, 39, 8, 20, 32, 76, 69, 106, 0, 73, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 151, 169, 101, 130, 129, 162, 98, 129, 78, 141, 70, 109, 110, 135, 123, 164, 102,
+            20, 111, 140, 144, 144, 89, 124, 32, 147, 165, 137, 72, 20, 109, 8, 0, 8, 0, 32, 0,
+            158, 143, 84, 127, 71, 149, 80, 73, 128, 151, 123, 161, 104, 88, 96, 138, 137, 75, 98,
+            119, 175, 147, 89, 104, 36, 142, 156, 116, 117, 0, 20, 128, 0, 0, 0, 0, 0, 168, 168,
+            63, 111, 90, 158, 83, 77, 136, 133, 67, 138, 128, 101, 129, 159, 101, 20, 152, 112,
+            109, 148, 44, 136, 75, 156, 70, 134, 107, 0, 128, 86, 0, 0, 0, 0, 0, 169, 127, 138,
+            141, 131, 112, 108, 131, 71, 115, 140, 127, 127, 145, 143, 82, 132, 44, 146, 150, 137,
+            72, 68, 121, 81, 72, 112, 20, 0, 61, 0, 111, 89, 67, 39, 145, 0, 110, 121, 36, 69, 48,
+            126, 27, 46, 8, 128, 20, 20, 56, 60, 60, 93, 53, 0, 59, 53, 32, 60, 8, 8, 32, 46, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 164, 170, 73, 110, 115, 153, 36, 83, 89, 165, 8, 114, 63,
+            48, 140, 146, 113, 0, 117, 137, 97, 113, 0, 55, 36, 166, 112, 108, 108, 0, 105, 123, 0,
+            36, 0, 0, 0, 125, 74, 53, 71, 69, 86, 57, 20, 46, 95, 0, 50, 44, 68, 20, 56, 91, 0, 39,
+            44, 97, 53, 27, 0, 77, 64, 60, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 175, 107, 131, 154, 109,
+            83, 125, 118, 20, 78, 138, 141, 125, 148, 127, 92, 138, 0, 117, 145, 146, 74, 27, 142,
+            32, 58, 91, 0, 0, 137, 0, 118, 78, 132, 0, 84, 0, 156, 167, 125, 119, 115, 165, 0, 112,
+            57, 147, 95, 113, 71, 136, 153, 147, 113, 0, 98, 97, 100, 128, 0, 147, 0, 164, 63, 78,
+            120, 0, 122, 108, 0, 0, 0, 0, 0, 32, 32, 105, 101, 110, 0, 0, 62, 0, 0, 86, 57, 100, 0,
+            66, 0, 20, 0, 126, 69, 103, 0, 0, 153, 0, 0, 8, 0, 0, 61, 0, 117, 0, 0, 81, 104, 0,
+            147, 0, 20, 145, 133, 0, 0, 100, 0, 0, 0, 36, 0, 0, 0, 0, 101, 0, 0, 69, 107, 0, 0, 57,
+            0, 0, 115, 0, 0, 109, 0, 56, 0, 32, 92, 79, 0, 162, 20, 0, 0, 20, 0, 0, 0, 20, 0, 0, 0,
+            0, 0, 0, 32, 0, 0, 0, 0, 0, 20, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 148, 0,
+            67, 132, 138, 0, 20, 90, 0, 0, 0, 108, 0, 0, 0, 0, 124, 0, 0, 63, 120, 0, 0, 0, 0, 0,
+            126, 0, 0, 84, 0, 60, 0, 103, 8, 27, 0, 127, 145, 42, 83, 74, 105, 8, 64, 0, 0, 0, 99,
+            0, 46, 119, 146, 39, 0, 0, 68, 70, 135, 0, 32, 0, 121, 0, 119, 140, 8, 127, 20, 0, 0,
+            0, 0, 0, 127, 0, 0, 124, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 134, 0, 8, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 92, 8, 8, 147, 0, 0, 0, 0, 0, 0, 0, 0, 140, 71,
+            108, 0, 58, 0, 113, 0, 0, 0, 0, 124, 0, 0, 0, 0, 0, 132, 0, 0, 0, 0, 0, 0, 0, 106, 58,
+            20, 0, 36, 0, 0, 20, 0, 20, 0, 0, 61, 0, 92, 51, 44, 0, 104, 20, 0, 0, 0, 87, 0, 0, 0,
+            0, 0, 89, 0, 0, 0, 0, 0, 0, 0, 124, 113, 20, 60, 106, 141, 0, 0, 0, 77, 0, 107, 107,
+            36, 147, 97, 0, 0, 39, 87, 0, 82, 0, 0, 0, 145, 0, 8, 112, 0, 44, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+    },
+    LanguageModel {
+        language: "cs",
+        script: Script::Latin,
+        alphabet: &[
+            'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q',
+            'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z', '\u{00E1}', '\u{00E9}', '\u{00ED}',
+            '\u{00F3}', '\u{00FA}', '\u{00FD}', '\u{010D}', '\u{010F}', '\u{011B}', '\u{0148}',
+            '\u{0159}', '\u{0161}', '\u{0165}', '\u{016F}', '\u{017E}',
+        ],
+        bigrams: &[
+            0, 169, 161, 160, 166, 141, 147, 138, 151, 153, 166, 167, 150, 162, 183, 167, 188, 105,
+            155, 184, 162, 158, 176, 127, 119, 101, 172, 69, 36, 64, 0, 130, 0, 146, 0, 0, 48, 139,
+            126, 0, 36, 129, 0, 179, 69, 141, 152, 156, 77, 101, 114, 134, 119, 136, 154, 160, 152,
+            158, 83, 141, 55, 157, 152, 169, 119, 151, 90, 114, 95, 154, 0, 0, 0, 0, 41, 0, 148,
+            76, 0, 89, 118, 111, 0, 0, 121, 0, 125, 150, 60, 79, 85, 131, 36, 61, 67, 122, 110, 72,
+            134, 79, 130, 161, 58, 0, 140, 128, 73, 141, 85, 58, 29, 143, 62, 121, 57, 117, 0, 0,
+            125, 50, 0, 124, 0, 62, 79, 0, 65, 0, 0, 140, 123, 69, 86, 93, 158, 61, 36, 166, 138,
+            0, 140, 111, 77, 97, 138, 79, 29, 113, 96, 120, 104, 36, 57, 17, 83, 36, 29, 48, 146,
+            0, 0, 0, 0, 0, 0, 17, 0, 0, 0, 93, 0, 0, 156, 153, 97, 98, 114, 154, 80, 100, 84, 139,
+            67, 136, 123, 100, 153, 155, 136, 0, 143, 128, 88, 135, 116, 77, 64, 127, 45, 137, 114,
+            125, 29, 0, 92, 69, 0, 125, 36, 95, 91, 0, 102, 93, 0, 187, 117, 146, 145, 154, 114,
+            124, 118, 106, 101, 135, 149, 165, 147, 174, 119, 153, 68, 163, 160, 152, 100, 142, 97,
+            140, 92, 149, 74, 0, 0, 41, 79, 0, 127, 50, 0, 98, 95, 110, 0, 0, 121, 0, 126, 106, 17,
+            79, 83, 105, 106, 68, 36, 141, 0, 36, 97, 66, 62, 137, 61, 41, 116, 86, 107, 121, 0,
+            17, 17, 74, 0, 48, 17, 0, 0, 0, 0, 0, 0, 0, 0, 17, 29, 0, 17, 0, 0, 132, 111, 79, 68,
+            85, 128, 58, 95, 94, 120, 36, 62, 106, 84, 128, 111, 101, 45, 129, 106, 98, 133, 72,
+            66, 55, 76, 72, 72, 41, 0, 53, 0, 0, 0, 0, 0, 0, 0, 78, 0, 41, 0, 0, 152, 137, 36, 90,
+            69, 137, 53, 17, 81, 127, 0, 45, 136, 70, 115, 160, 70, 0, 120, 76, 106, 128, 60, 58,
+            0, 144, 17, 114, 106, 45, 41, 53, 96, 17, 0, 0, 0, 36, 70, 0, 67, 62, 0, 164, 119, 120,
+            148, 141, 123, 130, 137, 110, 91, 123, 146, 138, 140, 165, 123, 131, 60, 120, 150, 160,
+            89, 145, 36, 112, 45, 127, 86, 29, 66, 0, 0, 0, 114, 0, 0, 48, 95, 122, 0, 0, 112, 0,
+            117, 137, 67, 17, 85, 166, 17, 36, 17, 134, 29, 66, 48, 130, 117, 109, 79, 0, 48, 129,
+            124, 82, 81, 29, 0, 0, 0, 83, 0, 137, 0, 17, 0, 17, 0, 0, 29, 0, 106, 0, 88, 17, 0,
+            155, 159, 78, 124, 116, 133, 55, 88, 78, 105, 0, 57, 145, 74, 125, 162, 95, 17, 134,
+            92, 143, 153, 105, 57, 0, 145, 0, 145, 143, 29, 121, 0, 124, 67, 0, 0, 0, 72, 66, 0,
+            120, 68, 0, 154, 158, 118, 85, 109, 161, 84, 95, 126, 162, 29, 127, 128, 84, 141, 164,
+            105, 0, 69, 119, 119, 138, 75, 58, 57, 119, 145, 138, 118, 143, 0, 41, 98, 64, 0, 0,
+            84, 0, 111, 0, 103, 72, 0, 161, 150, 118, 80, 73, 153, 57, 0, 57, 140, 29, 111, 94, 93,
+            111, 144, 128, 17, 95, 97, 101, 139, 41, 55, 58, 113, 0, 139, 131, 133, 90, 0, 104, 78,
+            0, 143, 0, 60, 82, 0, 117, 72, 0, 158, 175, 76, 125, 138, 174, 127, 128, 62, 145, 45,
+            129, 99, 81, 122, 166, 85, 45, 69, 137, 153, 143, 94, 41, 41, 138, 85, 159, 153, 175,
+            17, 29, 154, 123, 0, 142, 0, 0, 112, 0, 88, 67, 0, 179, 93, 153, 137, 163, 53, 113,
+            127, 130, 104, 135, 147, 155, 147, 156, 105, 139, 0, 168, 154, 150, 171, 175, 110, 99,
+            55, 146, 50, 0, 0, 0, 17, 0, 136, 0, 0, 80, 128, 108, 17, 0, 143, 0, 145, 145, 60, 110,
+            86, 138, 58, 111, 72, 144, 0, 101, 146, 76, 134, 175, 97, 64, 168, 126, 122, 138, 82,
+            67, 57, 97, 17, 102, 29, 142, 0, 0, 45, 17, 0, 110, 0, 165, 71, 0, 105, 0, 0, 93, 0, 0,
+            0, 41, 36, 0, 41, 0, 17, 0, 0, 101, 0, 0, 0, 45, 29, 36, 17, 55, 95, 0, 81, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 157, 163, 98, 126, 123, 158, 83, 128, 73,
+            143, 45, 106, 110, 135, 133, 172, 107, 17, 100, 124, 137, 152, 132, 71, 48, 132, 127,
+            148, 118, 77, 45, 0, 114, 113, 36, 0, 29, 0, 114, 0, 123, 101, 0, 156, 143, 94, 120,
+            91, 163, 75, 61, 128, 136, 48, 158, 151, 128, 120, 162, 142, 101, 92, 120, 176, 136,
+            114, 94, 57, 136, 36, 132, 69, 127, 0, 57, 50, 29, 0, 0, 0, 0, 41, 53, 94, 0, 0, 175,
+            165, 45, 104, 92, 167, 90, 92, 114, 162, 41, 126, 127, 106, 148, 160, 104, 17, 150,
+            114, 112, 157, 134, 91, 76, 143, 67, 123, 127, 137, 17, 0, 100, 0, 0, 134, 48, 127,
+            107, 0, 119, 0, 0, 175, 113, 156, 109, 138, 103, 96, 96, 117, 109, 151, 127, 137, 139,
+            137, 72, 150, 17, 139, 141, 144, 73, 116, 17, 90, 45, 123, 110, 0, 0, 0, 0, 0, 116, 72,
+            0, 69, 55, 124, 29, 0, 149, 0, 154, 162, 48, 98, 101, 158, 53, 36, 74, 136, 29, 110,
+            122, 50, 141, 152, 83, 0, 124, 126, 76, 127, 29, 41, 45, 155, 114, 156, 139, 133, 41,
+            0, 153, 62, 0, 136, 82, 115, 117, 0, 99, 81, 0, 115, 117, 29, 62, 87, 98, 45, 72, 58,
+            113, 17, 48, 69, 36, 79, 94, 36, 0, 78, 84, 17, 17, 0, 36, 45, 41, 17, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 53, 0, 0, 0, 0, 127, 81, 76, 74, 50, 105, 65, 0, 50, 126, 0, 58, 36,
+            72, 36, 96, 106, 17, 48, 57, 124, 85, 0, 0, 71, 99, 60, 0, 0, 36, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 36, 0, 0, 169, 82, 145, 99, 86, 69, 41, 69, 109, 66, 67, 117, 134, 112, 118,
+            82, 142, 0, 99, 127, 135, 74, 77, 41, 41, 29, 81, 0, 17, 0, 0, 17, 0, 97, 0, 0, 0, 69,
+            97, 0, 0, 124, 0, 143, 155, 105, 98, 133, 163, 0, 48, 93, 132, 95, 120, 95, 127, 150,
+            143, 131, 0, 100, 111, 98, 121, 123, 41, 0, 121, 29, 136, 60, 136, 58, 36, 29, 0, 0, 0,
+            17, 69, 99, 0, 97, 0, 0, 158, 0, 61, 110, 143, 0, 0, 29, 106, 0, 81, 97, 140, 128, 160,
+            0, 116, 0, 125, 128, 141, 0, 147, 0, 0, 0, 147, 0, 0, 41, 0, 0, 0, 73, 0, 0, 0, 134,
+            107, 0, 0, 94, 0, 163, 0, 0, 0, 94, 0, 29, 0, 135, 0, 0, 57, 102, 135, 128, 0, 17, 0,
+            83, 96, 86, 29, 55, 0, 0, 0, 88, 0, 0, 0, 0, 0, 0, 41, 0, 0, 0, 0, 48, 0, 0, 65, 0,
+            181, 0, 41, 141, 119, 0, 0, 0, 125, 0, 75, 141, 129, 135, 137, 0, 108, 0, 113, 145,
+            138, 0, 117, 0, 0, 0, 113, 0, 0, 0, 0, 0, 0, 134, 0, 0, 0, 111, 118, 79, 0, 97, 0, 0,
+            0, 0, 0, 124, 0, 0, 29, 0, 0, 0, 0, 0, 0, 73, 17, 0, 0, 0, 0, 53, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 17, 0, 0, 0, 0, 0, 0, 29, 50, 90, 0, 0, 0, 36, 0, 17, 48,
+            104, 17, 50, 0, 86, 0, 102, 100, 58, 0, 72, 0, 0, 0, 53, 0, 0, 0, 0, 0, 0, 103, 0, 0,
+            0, 0, 0, 0, 0, 29, 0, 161, 0, 94, 138, 72, 0, 0, 0, 17, 0, 66, 65, 36, 125, 29, 0, 90,
+            0, 113, 123, 124, 17, 82, 0, 0, 0, 83, 0, 0, 0, 0, 0, 0, 48, 0, 0, 0, 0, 97, 0, 0, 17,
+            0, 135, 126, 0, 75, 0, 149, 0, 0, 0, 123, 41, 121, 90, 0, 130, 98, 0, 0, 0, 36, 128,
+            105, 0, 29, 0, 0, 0, 117, 0, 140, 0, 0, 0, 0, 0, 0, 0, 0, 41, 0, 104, 0, 0, 85, 75, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 55, 36, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 139, 0, 0, 98, 109, 0, 0, 0, 102, 0, 104, 97, 124,
+            94, 145, 0, 78, 0, 117, 83, 121, 0, 29, 0, 0, 0, 116, 0, 0, 0, 0, 0, 0, 89, 72, 0, 29,
+            117, 86, 94, 0, 102, 0, 102, 68, 0, 17, 41, 0, 0, 0, 0, 0, 0, 72, 0, 0, 0, 89, 0, 0, 0,
+            45, 58, 96, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 125, 111,
+            17, 17, 0, 160, 0, 0, 0, 148, 0, 103, 0, 0, 84, 84, 0, 0, 0, 29, 48, 74, 0, 0, 0, 0, 0,
+            130, 0, 150, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 87, 0, 0, 115, 92, 0, 0, 0, 139, 0, 0, 0,
+            118, 0, 105, 94, 41, 92, 92, 96, 0, 48, 76, 144, 70, 81, 0, 0, 0, 0, 45, 0, 131, 0, 0,
+            0, 0, 0, 0, 0, 0, 72, 70, 0, 0, 0, 97, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 29, 0, 0, 0, 82,
+            0, 0, 0, 0, 41, 36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 148,
+            0, 81, 48, 0, 0, 0, 0, 53, 0, 50, 0, 88, 93, 0, 0, 0, 0, 61, 103, 29, 0, 101, 0, 0, 0,
+            67, 0, 0, 0, 0, 0, 0, 29, 0, 0, 29, 0, 0, 0, 0, 113, 0, 131, 122, 95, 83, 112, 140, 0,
+            0, 0, 147, 0, 121, 45, 0, 121, 74, 41, 0, 0, 82, 83, 80, 36, 0, 0, 0, 0, 124, 0, 127,
+            0, 0, 0, 0, 36, 0, 57, 0, 76, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+    },
+    LanguageModel {
+        language: "sk",
+        script: Script::Latin,
+        alphabet: &[
+            'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q',
+            'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z', '\u{00E1}', '\u{00E4}', '\u{00E9}',
+            '\u{00ED}', '\u{00F3}', '\u{00F4}', '\u{00FA}', '\u{00FD}', '\u{010D}', '\u{010F}',
+            '\u{013A}', '\u{013E}', '\u{0148}', '\u{0155}', '\u{0161}', '\u{0165}', '\u{017E}',
+        ],
+        bigrams: &[
+            0, 172, 159, 156, 165, 138, 147, 138, 154, 154, 162, 164, 142, 165, 184, 169, 187, 94,
+            160, 184, 163, 155, 177, 123, 130, 102, 174, 72, 0, 41, 29, 0, 0, 133, 0, 150, 86, 0,
+            102, 60, 0, 137, 70, 129, 0, 187, 69, 132, 146, 162, 60, 95, 108, 139, 120, 144, 160,
+            163, 154, 169, 81, 140, 65, 158, 155, 159, 119, 154, 76, 114, 88, 154, 29, 0, 0, 0, 0,
+            0, 73, 0, 149, 67, 0, 100, 96, 0, 105, 153, 109, 0, 123, 154, 70, 82, 69, 134, 73, 62,
+            0, 125, 111, 81, 126, 100, 132, 165, 48, 0, 147, 129, 73, 140, 62, 0, 0, 141, 73, 108,
+            0, 57, 86, 0, 0, 100, 53, 53, 0, 0, 41, 0, 0, 0, 0, 0, 0, 142, 131, 65, 73, 100, 145,
+            53, 41, 162, 159, 0, 136, 95, 70, 107, 141, 80, 29, 104, 86, 117, 111, 41, 60, 53, 86,
+            0, 0, 0, 81, 102, 0, 0, 57, 0, 0, 0, 0, 0, 0, 0, 0, 29, 0, 0, 151, 154, 91, 97, 111,
+            156, 83, 80, 81, 147, 73, 136, 123, 105, 152, 156, 136, 0, 143, 127, 93, 134, 121, 80,
+            81, 120, 126, 135, 0, 85, 93, 0, 100, 107, 88, 86, 0, 108, 98, 76, 0, 57, 0, 29, 0,
+            190, 118, 149, 139, 158, 119, 125, 121, 127, 97, 138, 145, 145, 141, 175, 124, 153, 57,
+            162, 158, 151, 95, 122, 84, 142, 89, 136, 72, 0, 0, 0, 0, 0, 81, 0, 123, 102, 0, 148,
+            113, 0, 106, 119, 120, 0, 129, 123, 0, 69, 90, 100, 111, 41, 0, 140, 0, 29, 100, 48,
+            53, 138, 69, 0, 105, 103, 115, 114, 0, 0, 29, 73, 0, 0, 0, 0, 60, 60, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 132, 93, 72, 65, 89, 125, 57, 87, 84, 118, 0, 0, 98, 92, 123, 105,
+            92, 0, 124, 104, 101, 125, 57, 53, 0, 57, 81, 57, 0, 41, 0, 79, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 151, 137, 41, 90, 62, 134, 60, 29, 29, 113, 0, 48, 130, 57, 112, 158,
+            79, 0, 119, 65, 117, 128, 57, 41, 0, 141, 29, 107, 0, 104, 117, 0, 29, 0, 114, 0, 0,
+            70, 115, 0, 48, 62, 0, 0, 0, 163, 166, 125, 144, 139, 176, 130, 128, 103, 118, 108,
+            142, 144, 140, 159, 123, 128, 41, 115, 149, 151, 127, 120, 41, 109, 0, 126, 98, 0, 53,
+            111, 41, 0, 0, 0, 125, 41, 0, 0, 0, 0, 113, 155, 99, 0, 139, 121, 65, 29, 111, 169, 0,
+            0, 48, 103, 0, 62, 29, 78, 106, 122, 88, 0, 29, 101, 120, 88, 80, 0, 0, 0, 0, 57, 0,
+            29, 92, 0, 0, 137, 0, 29, 0, 0, 0, 29, 0, 80, 29, 0, 0, 157, 158, 82, 134, 94, 132, 48,
+            87, 60, 100, 0, 29, 133, 81, 133, 165, 86, 0, 133, 94, 148, 152, 103, 60, 0, 147, 0,
+            144, 0, 122, 0, 117, 65, 109, 121, 53, 0, 0, 126, 0, 0, 29, 0, 72, 0, 151, 160, 84, 70,
+            90, 158, 95, 80, 98, 156, 0, 99, 125, 81, 139, 165, 104, 0, 65, 104, 113, 132, 76, 60,
+            29, 137, 80, 134, 0, 111, 131, 105, 0, 88, 97, 29, 0, 0, 0, 84, 0, 86, 0, 29, 0, 159,
+            147, 127, 81, 77, 159, 69, 0, 53, 149, 41, 115, 101, 91, 106, 154, 125, 0, 73, 85, 98,
+            138, 57, 41, 57, 124, 0, 145, 119, 106, 65, 70, 118, 53, 69, 29, 0, 0, 0, 0, 62, 0, 0,
+            53, 0, 144, 174, 72, 124, 136, 174, 130, 120, 48, 172, 41, 129, 97, 93, 113, 161, 82,
+            41, 67, 116, 152, 145, 95, 41, 53, 137, 77, 164, 0, 163, 144, 48, 70, 131, 163, 119, 0,
+            0, 0, 0, 0, 122, 0, 0, 0, 176, 81, 158, 140, 166, 53, 122, 126, 127, 74, 136, 155, 157,
+            156, 155, 100, 139, 0, 173, 157, 151, 153, 178, 102, 100, 53, 149, 57, 0, 0, 0, 0, 0,
+            29, 0, 142, 53, 29, 135, 81, 0, 109, 29, 152, 0, 146, 143, 62, 111, 85, 140, 41, 88,
+            65, 141, 0, 105, 148, 84, 136, 176, 96, 41, 178, 110, 115, 133, 80, 53, 53, 97, 0, 99,
+            96, 0, 138, 0, 93, 100, 67, 0, 0, 67, 0, 41, 0, 53, 0, 0, 0, 82, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 48, 0, 0, 0, 29, 29, 41, 29, 69, 86, 0, 48, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 158, 164, 114, 130, 120, 174, 84, 118, 96, 166, 29,
+            121, 106, 136, 137, 166, 109, 0, 90, 118, 130, 148, 131, 69, 41, 129, 130, 153, 0, 117,
+            141, 57, 70, 114, 122, 121, 0, 0, 0, 29, 0, 98, 29, 88, 0, 155, 162, 77, 118, 69, 148,
+            81, 69, 121, 135, 29, 151, 147, 123, 118, 141, 146, 57, 77, 114, 174, 133, 100, 82, 60,
+            140, 29, 124, 0, 53, 118, 0, 0, 158, 41, 57, 0, 0, 53, 29, 0, 0, 136, 0, 0, 159, 165,
+            48, 91, 93, 163, 87, 74, 109, 158, 0, 128, 130, 100, 149, 167, 113, 29, 155, 103, 113,
+            156, 139, 70, 72, 139, 29, 130, 0, 131, 124, 100, 57, 115, 123, 41, 53, 101, 0, 48, 0,
+            41, 29, 0, 0, 173, 110, 108, 99, 127, 112, 89, 103, 97, 101, 152, 132, 132, 136, 135,
+            62, 148, 29, 139, 140, 140, 48, 113, 0, 74, 29, 96, 113, 0, 0, 0, 0, 0, 0, 0, 90, 73,
+            0, 105, 29, 0, 108, 81, 150, 0, 166, 169, 41, 96, 88, 160, 29, 48, 76, 144, 0, 123,
+            120, 76, 141, 158, 99, 0, 121, 124, 80, 128, 41, 0, 0, 154, 114, 130, 110, 132, 99, 41,
+            84, 103, 149, 0, 41, 0, 73, 93, 0, 118, 0, 81, 0, 105, 107, 48, 48, 82, 89, 29, 80, 69,
+            105, 0, 29, 67, 53, 69, 89, 60, 0, 72, 84, 0, 0, 0, 70, 0, 0, 29, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 134, 76, 86, 81, 62, 102, 73, 0, 29, 126, 0, 70, 48,
+            87, 41, 84, 110, 0, 53, 57, 127, 85, 29, 0, 62, 94, 53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 168, 53, 141, 98, 80, 53, 29, 81, 131, 29, 53, 120, 81, 128,
+            121, 81, 140, 0, 99, 127, 132, 74, 101, 29, 48, 70, 94, 0, 0, 0, 0, 0, 0, 0, 0, 95, 0,
+            0, 0, 0, 0, 103, 126, 113, 0, 141, 154, 109, 102, 132, 133, 0, 0, 111, 142, 78, 116,
+            132, 126, 151, 161, 110, 0, 107, 108, 65, 118, 134, 41, 0, 115, 29, 137, 0, 0, 127, 65,
+            0, 41, 53, 0, 0, 0, 74, 29, 0, 88, 62, 0, 0, 161, 29, 29, 144, 112, 0, 0, 0, 90, 0,
+            120, 91, 140, 126, 126, 0, 108, 0, 138, 123, 144, 41, 147, 0, 0, 0, 151, 29, 0, 0, 0,
+            0, 0, 0, 0, 76, 0, 0, 0, 57, 0, 90, 29, 93, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 114, 0, 0, 0, 0, 0, 79, 0, 0, 0, 0, 0, 0, 0, 0, 105, 0, 0, 0, 0,
+            0, 0, 108, 0, 0, 166, 0, 0, 0, 93, 0, 41, 29, 138, 0, 0, 0, 0, 131, 96, 0, 0, 0, 109,
+            0, 62, 0, 0, 0, 0, 0, 29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 151,
+            29, 0, 115, 65, 0, 0, 0, 57, 0, 29, 143, 112, 123, 110, 0, 114, 0, 119, 143, 134, 0,
+            141, 0, 0, 0, 95, 0, 0, 0, 0, 0, 0, 0, 0, 80, 0, 0, 48, 41, 0, 104, 0, 82, 0, 0, 0, 41,
+            0, 121, 0, 0, 95, 0, 0, 0, 0, 0, 65, 99, 0, 76, 0, 88, 0, 41, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 60, 29, 29, 0, 0, 0, 0, 0, 0, 0,
+            88, 0, 29, 0, 57, 0, 57, 90, 73, 0, 99, 0, 0, 0, 67, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 41, 0, 117, 0, 146, 0, 154, 126, 120, 0, 0, 0, 79, 0, 0, 84, 108, 82, 84, 0,
+            96, 0, 121, 101, 121, 0, 93, 0, 0, 0, 53, 0, 0, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 0, 0,
+            101, 111, 53, 0, 166, 0, 116, 136, 0, 0, 0, 0, 0, 0, 0, 60, 97, 122, 53, 0, 99, 0, 115,
+            120, 74, 29, 79, 0, 0, 0, 86, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 101, 0, 65, 0,
+            127, 144, 0, 0, 0, 139, 0, 0, 0, 147, 0, 126, 94, 29, 131, 120, 0, 0, 0, 0, 95, 113, 0,
+            0, 0, 0, 0, 0, 0, 0, 141, 0, 0, 57, 0, 0, 0, 0, 0, 0, 0, 105, 0, 0, 0, 104, 93, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 60, 0, 0, 0, 0, 48, 29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 48, 0, 0, 0, 72, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 101, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 67, 0, 0, 0,
+            108, 0, 116, 138, 129, 0, 0, 0, 0, 0, 0, 0, 0, 130, 29, 79, 120, 117, 48, 0, 0, 100,
+            76, 91, 84, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 126, 0, 0, 0, 0, 0, 41, 0, 0, 0, 0, 0, 113,
+            88, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 105, 0, 0, 0, 0, 57, 101, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 62, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 48,
+            0, 0, 0, 0, 0, 114, 111, 0, 0, 0, 132, 0, 0, 72, 125, 0, 107, 86, 29, 91, 87, 106, 0,
+            0, 29, 136, 77, 41, 0, 0, 0, 0, 0, 0, 0, 124, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 80, 98, 0,
+            0, 171, 126, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 90, 0, 0, 0, 0, 29, 60, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 118, 118, 100, 0, 109, 133, 0,
+            0, 0, 150, 0, 127, 0, 29, 145, 67, 0, 0, 0, 82, 62, 91, 0, 0, 0, 0, 0, 41, 0, 0, 131,
+            0, 0, 0, 0, 0, 41, 0, 0, 48, 0, 57, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0,
+        ],
+    },
+    LanguageModel {
+        language: "sl",
+        script: Script::Latin,
+        alphabet: &[
+            'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q',
+            'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z', '\u{010D}', '\u{0161}', '\u{017E}',
+        ],
+        bigrams: &[
+            0, 163, 163, 148, 175, 144, 143, 153, 138, 173, 159, 168, 155, 168, 182, 170, 185, 106,
+            159, 179, 163, 161, 174, 124, 123, 103, 171, 142, 142, 120, 0, 197, 84, 147, 131, 149,
+            80, 106, 132, 135, 121, 160, 159, 164, 158, 176, 101, 151, 73, 164, 153, 168, 119, 167,
+            85, 83, 102, 157, 136, 123, 120, 0, 132, 147, 61, 59, 90, 143, 28, 66, 59, 154, 82, 55,
+            144, 96, 129, 143, 40, 0, 146, 122, 80, 134, 102, 40, 0, 82, 61, 78, 91, 0, 0, 139,
+            145, 64, 85, 96, 146, 61, 40, 127, 136, 40, 120, 97, 64, 79, 135, 78, 47, 111, 93, 110,
+            107, 67, 47, 28, 74, 0, 28, 0, 0, 0, 154, 167, 98, 69, 98, 158, 82, 96, 85, 149, 116,
+            111, 122, 128, 154, 160, 135, 0, 141, 127, 91, 115, 126, 69, 28, 67, 106, 81, 98, 112,
+            0, 190, 120, 135, 132, 165, 107, 107, 150, 108, 110, 146, 161, 161, 158, 176, 116, 143,
+            47, 158, 154, 158, 106, 161, 98, 114, 100, 151, 136, 133, 126, 0, 126, 110, 28, 76, 84,
+            105, 107, 73, 47, 128, 40, 28, 96, 59, 47, 120, 59, 0, 116, 80, 105, 105, 0, 40, 28,
+            52, 0, 0, 52, 0, 0, 136, 155, 81, 64, 93, 128, 61, 88, 99, 130, 47, 66, 132, 79, 123,
+            157, 79, 28, 141, 100, 105, 135, 100, 52, 0, 80, 71, 28, 78, 0, 0, 152, 127, 47, 0, 67,
+            131, 52, 40, 59, 134, 40, 117, 79, 73, 88, 137, 47, 0, 115, 76, 122, 103, 28, 52, 0,
+            66, 28, 52, 0, 0, 0, 188, 124, 123, 154, 134, 112, 114, 131, 144, 73, 148, 167, 161,
+            156, 172, 121, 141, 72, 155, 159, 159, 87, 147, 47, 105, 52, 162, 144, 143, 98, 0, 149,
+            166, 85, 28, 100, 175, 0, 55, 59, 143, 47, 100, 47, 99, 126, 136, 79, 0, 59, 126, 124,
+            141, 105, 0, 0, 0, 0, 40, 133, 0, 0, 157, 176, 84, 101, 76, 157, 52, 82, 74, 158, 89,
+            47, 146, 83, 130, 168, 95, 0, 139, 113, 113, 142, 102, 72, 0, 59, 0, 0, 79, 0, 0, 148,
+            159, 95, 104, 106, 157, 88, 107, 77, 167, 161, 122, 123, 96, 145, 165, 114, 0, 66, 114,
+            119, 131, 85, 47, 28, 92, 80, 96, 104, 103, 0, 155, 159, 135, 55, 59, 169, 69, 40, 69,
+            148, 77, 80, 107, 92, 123, 162, 115, 0, 110, 116, 76, 119, 67, 52, 55, 59, 0, 69, 103,
+            40, 0, 167, 186, 90, 123, 142, 171, 96, 136, 76, 179, 166, 119, 109, 90, 98, 172, 101,
+            47, 61, 137, 150, 133, 71, 67, 59, 86, 97, 117, 126, 28, 0, 181, 98, 151, 125, 167, 79,
+            115, 158, 100, 106, 128, 151, 158, 153, 157, 99, 146, 52, 166, 165, 164, 125, 166, 106,
+            79, 72, 134, 160, 125, 137, 0, 137, 153, 55, 104, 80, 142, 59, 72, 80, 156, 0, 128,
+            125, 72, 115, 175, 100, 40, 176, 104, 115, 135, 69, 52, 47, 76, 0, 67, 74, 0, 0, 89,
+            40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 47, 0, 0, 40, 28, 28, 40, 28, 69, 97, 0, 89, 0, 0, 0,
+            0, 0, 0, 0, 152, 178, 114, 111, 131, 177, 83, 132, 121, 168, 130, 120, 109, 120, 141,
+            158, 92, 0, 108, 147, 139, 136, 126, 78, 28, 102, 96, 90, 126, 101, 0, 156, 153, 91,
+            109, 59, 161, 71, 73, 129, 146, 55, 162, 149, 117, 138, 145, 148, 59, 121, 115, 177,
+            123, 106, 69, 52, 94, 83, 0, 28, 0, 0, 166, 165, 52, 97, 87, 174, 88, 71, 125, 170,
+            104, 127, 106, 98, 141, 169, 99, 0, 153, 114, 113, 133, 140, 64, 69, 108, 69, 0, 99, 0,
+            0, 152, 110, 134, 104, 117, 106, 86, 125, 72, 104, 132, 133, 125, 141, 135, 73, 150,
+            40, 137, 144, 135, 52, 97, 40, 91, 66, 97, 128, 116, 113, 0, 164, 162, 47, 83, 69, 167,
+            40, 90, 114, 160, 71, 117, 127, 87, 153, 152, 111, 0, 151, 139, 107, 95, 40, 28, 0, 64,
+            131, 64, 98, 40, 0, 106, 109, 40, 40, 61, 109, 28, 47, 83, 109, 28, 28, 77, 47, 79, 88,
+            40, 0, 72, 87, 0, 0, 0, 52, 55, 67, 28, 0, 0, 0, 0, 128, 74, 69, 67, 59, 79, 77, 0, 28,
+            81, 0, 69, 55, 81, 28, 71, 85, 0, 59, 61, 97, 61, 0, 0, 87, 72, 66, 0, 0, 0, 0, 129,
+            93, 78, 67, 52, 66, 0, 55, 40, 67, 0, 52, 67, 72, 84, 91, 94, 0, 76, 92, 74, 66, 52,
+            28, 52, 28, 47, 0, 0, 0, 0, 152, 168, 141, 40, 115, 136, 0, 113, 117, 137, 89, 97, 122,
+            115, 150, 130, 134, 0, 128, 55, 77, 108, 135, 52, 0, 47, 66, 94, 104, 47, 0, 131, 147,
+            47, 0, 52, 158, 0, 0, 0, 163, 113, 112, 102, 0, 139, 85, 47, 0, 112, 55, 76, 111, 59,
+            0, 0, 0, 0, 28, 0, 0, 0, 84, 115, 0, 0, 0, 130, 0, 0, 0, 139, 69, 138, 88, 67, 108, 85,
+            81, 0, 55, 0, 137, 78, 89, 0, 0, 0, 0, 156, 28, 0, 0, 73, 123, 69, 0, 40, 129, 0, 0, 0,
+            130, 88, 28, 83, 0, 139, 55, 52, 0, 0, 0, 0, 98, 0, 0, 0, 0, 0, 0, 0, 28, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+    },
+    LanguageModel {
+        language: "hr",
+        script: Script::Latin,
+        alphabet: &[
+            'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q',
+            'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z', '\u{0107}', '\u{010D}', '\u{0111}',
+            '\u{0161}', '\u{017E}',
+        ],
+        bigrams: &[
+            0, 163, 162, 148, 174, 143, 148, 152, 134, 177, 160, 169, 152, 165, 182, 173, 186, 108,
+            161, 184, 163, 172, 161, 126, 124, 100, 170, 113, 129, 0, 119, 94, 0, 197, 66, 143,
+            149, 155, 62, 104, 130, 107, 103, 154, 165, 160, 150, 176, 136, 145, 60, 163, 143, 172,
+            121, 164, 83, 85, 93, 157, 113, 143, 123, 112, 132, 0, 126, 147, 69, 77, 48, 136, 43,
+            66, 36, 150, 113, 24, 136, 92, 120, 136, 48, 0, 146, 90, 75, 129, 90, 0, 0, 84, 70, 0,
+            0, 0, 24, 0, 0, 135, 142, 64, 83, 95, 143, 58, 48, 122, 164, 93, 121, 99, 74, 52, 127,
+            88, 24, 122, 93, 116, 116, 48, 62, 48, 62, 0, 0, 0, 0, 0, 0, 0, 156, 171, 133, 88, 88,
+            151, 79, 108, 66, 153, 114, 85, 111, 107, 151, 152, 85, 0, 146, 111, 83, 131, 121, 55,
+            75, 76, 64, 0, 0, 0, 0, 66, 0, 195, 120, 136, 138, 163, 106, 128, 130, 105, 116, 81,
+            166, 155, 160, 174, 122, 136, 72, 158, 154, 155, 125, 146, 89, 118, 85, 139, 132, 121,
+            132, 139, 109, 0, 130, 102, 24, 78, 96, 109, 109, 66, 24, 143, 0, 24, 100, 70, 55, 136,
+            55, 0, 107, 93, 116, 114, 0, 24, 24, 72, 0, 0, 0, 0, 0, 0, 0, 150, 129, 81, 60, 93,
+            128, 55, 84, 91, 123, 43, 52, 128, 86, 128, 132, 86, 36, 149, 100, 100, 155, 64, 43,
+            43, 55, 69, 24, 0, 0, 0, 0, 0, 145, 114, 36, 43, 72, 126, 55, 24, 85, 124, 0, 24, 60,
+            64, 62, 122, 78, 0, 88, 70, 118, 92, 101, 43, 0, 72, 24, 0, 0, 0, 0, 0, 0, 188, 105,
+            119, 152, 133, 101, 131, 136, 138, 82, 178, 158, 154, 161, 165, 130, 140, 43, 159, 165,
+            162, 66, 151, 36, 108, 0, 157, 90, 145, 79, 140, 88, 0, 151, 169, 52, 24, 60, 188, 0,
+            24, 24, 144, 0, 96, 60, 89, 117, 113, 83, 0, 36, 121, 124, 153, 93, 0, 0, 0, 43, 0, 73,
+            0, 55, 0, 0, 152, 173, 89, 117, 60, 156, 58, 48, 64, 152, 0, 36, 140, 62, 93, 171, 84,
+            24, 140, 135, 141, 148, 112, 55, 0, 43, 0, 0, 24, 0, 0, 0, 0, 145, 159, 80, 79, 98,
+            146, 94, 79, 55, 169, 162, 48, 124, 77, 131, 156, 100, 0, 70, 110, 120, 126, 72, 69,
+            64, 93, 81, 0, 0, 0, 0, 43, 0, 160, 165, 123, 72, 75, 164, 64, 0, 55, 144, 136, 103,
+            112, 93, 107, 162, 132, 24, 110, 97, 87, 109, 62, 24, 52, 24, 0, 67, 0, 0, 0, 0, 0,
+            157, 180, 79, 122, 137, 173, 116, 132, 48, 177, 167, 124, 96, 91, 81, 169, 106, 43, 72,
+            134, 154, 150, 96, 36, 43, 71, 83, 0, 74, 0, 0, 0, 0, 174, 89, 143, 135, 164, 60, 119,
+            161, 87, 98, 157, 149, 147, 161, 158, 101, 155, 0, 170, 162, 162, 118, 161, 105, 104,
+            55, 148, 106, 134, 106, 121, 132, 0, 140, 145, 64, 140, 83, 141, 58, 78, 75, 153, 130,
+            128, 131, 82, 116, 175, 102, 36, 175, 123, 119, 136, 83, 62, 48, 81, 0, 79, 24, 0, 76,
+            0, 0, 95, 0, 0, 24, 0, 24, 0, 0, 0, 24, 0, 0, 43, 0, 0, 0, 24, 24, 43, 24, 66, 92, 0,
+            89, 0, 0, 0, 0, 0, 24, 0, 0, 0, 150, 177, 91, 110, 125, 178, 85, 130, 116, 174, 102,
+            100, 111, 134, 139, 164, 108, 0, 95, 137, 134, 141, 120, 64, 43, 98, 111, 0, 58, 48,
+            132, 133, 0, 161, 155, 83, 116, 77, 159, 76, 66, 124, 149, 92, 155, 146, 125, 140, 130,
+            156, 60, 100, 111, 176, 147, 136, 70, 48, 98, 36, 0, 0, 0, 0, 0, 0, 162, 173, 43, 98,
+            95, 169, 93, 86, 116, 173, 108, 131, 105, 96, 136, 170, 120, 0, 156, 119, 113, 142,
+            140, 67, 78, 117, 70, 0, 0, 0, 0, 0, 0, 176, 99, 115, 103, 119, 86, 107, 128, 75, 102,
+            130, 135, 140, 143, 143, 100, 146, 0, 137, 153, 144, 52, 116, 0, 91, 0, 125, 147, 139,
+            0, 117, 116, 0, 133, 168, 48, 80, 66, 159, 24, 36, 24, 155, 127, 112, 137, 60, 140,
+            152, 70, 0, 153, 85, 60, 122, 36, 24, 52, 24, 0, 0, 0, 0, 58, 0, 0, 110, 107, 43, 43,
+            73, 103, 24, 76, 62, 107, 0, 24, 74, 58, 64, 89, 36, 0, 75, 89, 0, 0, 0, 52, 52, 0, 24,
+            0, 0, 0, 0, 0, 0, 131, 70, 81, 78, 52, 81, 70, 0, 43, 89, 0, 66, 43, 85, 36, 64, 99, 0,
+            43, 55, 98, 24, 0, 0, 79, 102, 70, 0, 0, 0, 0, 0, 0, 130, 74, 67, 24, 24, 58, 0, 0, 0,
+            70, 60, 36, 70, 79, 76, 60, 108, 0, 62, 96, 83, 43, 24, 48, 36, 48, 0, 0, 0, 0, 0, 0,
+            0, 145, 170, 117, 0, 103, 121, 0, 99, 36, 145, 0, 0, 129, 121, 153, 134, 0, 0, 123, 43,
+            24, 117, 137, 36, 0, 36, 24, 0, 0, 0, 0, 0, 0, 108, 120, 0, 0, 0, 148, 0, 0, 0, 128, 0,
+            0, 0, 0, 106, 36, 0, 0, 0, 0, 0, 106, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 112, 131, 0, 0,
+            0, 138, 0, 0, 0, 150, 81, 133, 85, 0, 134, 0, 0, 0, 0, 0, 0, 114, 79, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 117, 0, 0, 0, 130, 0, 0, 0, 95, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 119, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 93, 125, 0, 62, 0, 136, 0, 0, 0, 134, 0, 135, 52, 0, 114,
+            48, 71, 0, 43, 0, 133, 69, 81, 0, 0, 0, 0, 77, 71, 0, 0, 0, 0, 78, 133, 43, 0, 91, 139,
+            0, 0, 0, 133, 55, 0, 0, 24, 98, 24, 0, 0, 36, 0, 0, 90, 0, 0, 0, 0, 0, 0, 0, 62, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0,
+        ],
+    },
+    LanguageModel {
+        language: "hu",
+        script: Script::Latin,
+        alphabet: &[
+            'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q',
+            'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z', '\u{00E1}', '\u{00E9}', '\u{00ED}',
+            '\u{00F3}', '\u{00F6}', '\u{00FA}', '\u{00FC}', '\u{0151}', '\u{0171}',
+        ],
+        bigrams: &[
+            0, 189, 165, 161, 152, 171, 170, 150, 169, 156, 148, 179, 164, 174, 171, 145, 159, 104,
+            151, 172, 168, 144, 163, 122, 121, 100, 140, 142, 158, 121, 95, 126, 125, 120, 75, 33,
+            0, 190, 101, 141, 126, 153, 85, 112, 154, 117, 142, 132, 162, 168, 149, 166, 96, 154,
+            76, 164, 161, 170, 126, 127, 97, 103, 110, 164, 86, 49, 40, 52, 0, 57, 66, 0, 0, 0,
+            140, 152, 135, 78, 33, 159, 45, 64, 81, 141, 100, 79, 133, 86, 76, 133, 45, 0, 120,
+            100, 72, 126, 110, 59, 0, 86, 63, 131, 97, 93, 110, 78, 96, 52, 108, 21, 0, 135, 130,
+            86, 91, 97, 132, 68, 45, 132, 139, 59, 118, 95, 75, 59, 131, 76, 52, 107, 165, 114, 97,
+            52, 59, 21, 57, 0, 66, 102, 125, 63, 0, 0, 0, 0, 0, 0, 149, 144, 95, 63, 100, 150, 78,
+            80, 104, 138, 115, 89, 104, 96, 111, 146, 104, 0, 102, 125, 92, 118, 121, 59, 70, 73,
+            106, 129, 130, 116, 113, 73, 57, 76, 125, 52, 0, 173, 120, 121, 124, 136, 104, 123,
+            169, 140, 112, 136, 160, 181, 163, 175, 110, 124, 70, 169, 163, 174, 96, 137, 98, 126,
+            95, 163, 131, 85, 105, 88, 0, 0, 21, 0, 0, 0, 127, 110, 21, 70, 76, 152, 109, 72, 45,
+            136, 40, 33, 95, 70, 45, 150, 55, 0, 121, 76, 113, 112, 0, 0, 21, 61, 0, 151, 101, 0,
+            89, 91, 33, 117, 68, 84, 0, 157, 149, 98, 64, 88, 150, 106, 121, 126, 131, 128, 98,
+            118, 86, 124, 142, 93, 0, 133, 112, 112, 132, 111, 59, 0, 164, 96, 126, 129, 82, 109,
+            104, 78, 61, 108, 81, 0, 130, 166, 33, 40, 64, 154, 52, 40, 102, 149, 33, 0, 75, 77,
+            70, 151, 40, 0, 84, 77, 108, 107, 21, 33, 0, 70, 21, 113, 93, 114, 71, 97, 91, 45, 0,
+            52, 0, 171, 141, 141, 129, 136, 122, 125, 144, 104, 86, 105, 153, 148, 138, 165, 122,
+            115, 70, 131, 156, 145, 91, 128, 57, 106, 77, 127, 115, 73, 119, 134, 0, 113, 64, 0, 0,
+            0, 119, 145, 71, 72, 107, 156, 45, 57, 21, 94, 73, 21, 152, 49, 75, 122, 76, 0, 102,
+            77, 126, 104, 70, 0, 0, 0, 97, 127, 100, 45, 69, 92, 70, 49, 0, 64, 0, 176, 161, 113,
+            89, 63, 161, 87, 86, 114, 156, 49, 131, 94, 91, 102, 152, 92, 0, 118, 123, 140, 143,
+            84, 77, 0, 63, 21, 112, 148, 123, 120, 153, 45, 119, 0, 45, 0, 171, 164, 117, 126, 132,
+            175, 112, 125, 139, 154, 130, 132, 158, 144, 127, 145, 103, 0, 112, 138, 159, 121, 142,
+            45, 61, 151, 115, 152, 148, 142, 148, 116, 72, 118, 135, 0, 0, 166, 159, 135, 76, 72,
+            172, 84, 0, 93, 147, 87, 119, 104, 109, 94, 142, 117, 0, 87, 95, 111, 125, 86, 61, 49,
+            52, 94, 148, 135, 95, 127, 100, 92, 57, 0, 115, 0, 173, 153, 98, 151, 152, 168, 118,
+            144, 90, 154, 100, 132, 119, 90, 126, 140, 83, 40, 67, 126, 161, 124, 91, 70, 63, 163,
+            99, 150, 141, 121, 86, 94, 89, 108, 108, 78, 0, 142, 91, 126, 119, 126, 70, 117, 145,
+            87, 91, 86, 156, 164, 150, 159, 101, 134, 52, 165, 153, 151, 121, 124, 107, 103, 75,
+            153, 61, 33, 33, 0, 0, 0, 0, 0, 0, 0, 143, 147, 70, 139, 89, 140, 86, 83, 87, 118, 90,
+            94, 112, 79, 104, 143, 120, 40, 139, 111, 117, 127, 96, 52, 45, 79, 33, 112, 133, 117,
+            21, 21, 77, 66, 40, 0, 0, 91, 59, 0, 21, 0, 21, 0, 0, 0, 45, 0, 0, 40, 21, 0, 0, 21,
+            40, 33, 21, 61, 97, 0, 85, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 159, 164, 120, 124,
+            131, 165, 101, 133, 118, 149, 114, 121, 132, 139, 131, 152, 95, 33, 130, 137, 160, 119,
+            139, 79, 33, 98, 127, 147, 131, 112, 122, 116, 83, 133, 117, 86, 0, 179, 162, 112, 110,
+            93, 156, 100, 84, 133, 150, 78, 121, 102, 120, 102, 162, 115, 57, 123, 151, 148, 128,
+            104, 89, 45, 95, 179, 147, 147, 143, 107, 84, 115, 93, 120, 78, 0, 182, 165, 114, 108,
+            81, 171, 108, 78, 135, 153, 123, 136, 133, 113, 117, 159, 109, 21, 143, 129, 160, 147,
+            134, 63, 76, 130, 67, 166, 158, 130, 149, 139, 120, 83, 136, 116, 0, 132, 115, 111, 95,
+            111, 110, 102, 122, 66, 110, 88, 111, 147, 149, 135, 77, 111, 33, 134, 146, 139, 63,
+            90, 33, 86, 70, 76, 103, 45, 52, 0, 0, 0, 0, 0, 0, 0, 138, 162, 57, 68, 63, 159, 63,
+            61, 78, 139, 81, 59, 57, 61, 77, 132, 57, 0, 67, 55, 127, 113, 72, 33, 21, 55, 0, 148,
+            147, 103, 76, 69, 21, 107, 114, 84, 0, 107, 114, 33, 0, 88, 105, 49, 77, 68, 107, 21,
+            40, 70, 40, 76, 87, 33, 0, 68, 88, 40, 45, 0, 40, 49, 52, 40, 0, 33, 0, 0, 0, 0, 33, 0,
+            0, 0, 128, 86, 80, 68, 57, 92, 74, 0, 59, 103, 0, 64, 49, 81, 40, 59, 95, 0, 52, 57,
+            110, 68, 0, 0, 76, 101, 67, 0, 33, 0, 0, 0, 0, 0, 0, 21, 0, 159, 133, 102, 71, 59, 156,
+            72, 0, 93, 126, 61, 90, 86, 99, 98, 138, 104, 0, 89, 118, 135, 94, 128, 40, 45, 40,
+            120, 113, 105, 103, 45, 72, 84, 85, 100, 120, 0, 170, 148, 102, 33, 111, 157, 68, 86,
+            116, 149, 59, 121, 123, 94, 147, 142, 76, 0, 94, 118, 151, 105, 108, 45, 0, 40, 121,
+            153, 146, 127, 131, 127, 101, 121, 140, 85, 0, 101, 88, 127, 122, 93, 0, 91, 137, 115,
+            71, 154, 119, 169, 139, 151, 0, 78, 0, 154, 170, 148, 101, 125, 0, 0, 0, 119, 0, 40,
+            104, 0, 0, 0, 0, 0, 0, 0, 108, 0, 100, 107, 109, 33, 0, 145, 112, 63, 87, 138, 143,
+            117, 152, 33, 143, 0, 160, 168, 138, 33, 135, 0, 0, 0, 112, 0, 57, 0, 0, 0, 0, 0, 0, 0,
+            0, 76, 52, 57, 94, 0, 0, 0, 88, 0, 0, 21, 49, 106, 125, 116, 0, 120, 0, 140, 76, 163,
+            0, 130, 0, 0, 0, 99, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 159, 92, 108, 68, 135, 61, 84, 85,
+            88, 110, 108, 134, 124, 72, 111, 40, 82, 0, 111, 114, 123, 21, 103, 0, 0, 0, 95, 21,
+            55, 45, 40, 0, 45, 0, 0, 0, 0, 33, 0, 109, 0, 93, 52, 0, 92, 0, 33, 0, 104, 131, 105,
+            132, 0, 49, 0, 136, 127, 118, 0, 134, 0, 0, 0, 141, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 120,
+            49, 57, 74, 45, 0, 21, 102, 0, 0, 121, 40, 118, 45, 61, 0, 61, 0, 96, 118, 110, 0, 33,
+            0, 0, 0, 96, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 88, 21, 55, 21, 49, 73, 0, 116, 33, 49, 33,
+            120, 148, 33, 78, 0, 33, 0, 111, 76, 99, 0, 49, 0, 0, 0, 107, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 151, 63, 105, 21, 104, 87, 100, 66, 89, 83, 95, 120, 122, 61, 99, 40, 76, 0, 112,
+            114, 116, 45, 105, 0, 0, 0, 107, 85, 59, 45, 21, 76, 0, 40, 0, 0, 0, 120, 21, 52, 33,
+            0, 59, 40, 0, 52, 59, 68, 120, 74, 59, 81, 0, 21, 0, 87, 83, 67, 0, 108, 0, 0, 0, 97,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+    },
+    LanguageModel {
+        language: "ro",
+        script: Script::Latin,
+        alphabet: &[
+            'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q',
+            'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z', '\u{00E2}', '\u{00EE}', '\u{0103}',
+            '\u{0219}', '\u{021B}',
+        ],
+        bigrams: &[
+            0, 181, 154, 181, 186, 171, 174, 147, 128, 166, 112, 124, 169, 169, 174, 163, 177, 102,
+            158, 182, 161, 164, 158, 120, 145, 97, 119, 43, 163, 60, 148, 100, 0, 186, 81, 144,
+            164, 138, 71, 136, 127, 92, 144, 126, 105, 167, 139, 159, 84, 139, 48, 181, 149, 177,
+            149, 124, 97, 111, 93, 149, 0, 0, 24, 127, 146, 0, 144, 131, 62, 100, 61, 122, 75, 56,
+            62, 152, 53, 36, 138, 76, 50, 133, 66, 0, 114, 98, 88, 140, 12, 50, 36, 71, 84, 0, 0,
+            103, 24, 103, 0, 145, 167, 48, 130, 87, 165, 71, 81, 149, 156, 90, 115, 119, 88, 52,
+            166, 97, 12, 145, 90, 155, 163, 93, 50, 12, 50, 0, 135, 57, 156, 50, 140, 0, 154, 155,
+            81, 72, 88, 181, 69, 96, 62, 161, 80, 80, 83, 84, 82, 136, 85, 0, 123, 90, 75, 139, 87,
+            99, 76, 76, 48, 81, 0, 128, 0, 0, 0, 200, 175, 129, 169, 134, 119, 132, 138, 82, 144,
+            100, 78, 163, 151, 169, 114, 148, 52, 176, 170, 152, 101, 133, 88, 150, 83, 140, 12,
+            75, 24, 144, 126, 0, 127, 131, 12, 57, 90, 137, 107, 76, 48, 172, 12, 12, 119, 43, 45,
+            151, 89, 0, 111, 72, 100, 125, 24, 0, 56, 50, 0, 96, 31, 119, 0, 0, 0, 127, 127, 72,
+            60, 79, 134, 43, 69, 104, 137, 40, 59, 107, 96, 115, 116, 89, 0, 134, 100, 90, 135, 45,
+            53, 12, 40, 86, 53, 0, 128, 0, 0, 0, 121, 119, 24, 73, 69, 143, 43, 24, 73, 140, 0, 48,
+            66, 64, 61, 103, 65, 0, 83, 68, 97, 93, 40, 43, 0, 53, 12, 56, 12, 72, 0, 0, 0, 182,
+            151, 142, 166, 144, 170, 148, 128, 64, 147, 85, 77, 167, 169, 175, 144, 141, 50, 152,
+            152, 164, 160, 145, 57, 116, 53, 150, 0, 24, 0, 156, 132, 0, 111, 116, 60, 24, 12, 93,
+            0, 24, 12, 84, 0, 36, 75, 0, 31, 100, 63, 0, 12, 24, 0, 116, 24, 0, 0, 0, 12, 0, 0, 31,
+            0, 0, 0, 126, 107, 77, 75, 55, 107, 50, 67, 83, 108, 0, 40, 53, 64, 43, 87, 82, 0, 52,
+            87, 88, 88, 24, 55, 0, 53, 0, 43, 0, 48, 0, 0, 0, 172, 158, 85, 104, 100, 169, 93, 93,
+            52, 171, 36, 68, 121, 74, 87, 161, 106, 0, 65, 116, 145, 162, 100, 57, 67, 83, 131, 67,
+            0, 137, 24, 91, 0, 138, 169, 139, 74, 86, 166, 76, 40, 53, 156, 12, 40, 106, 99, 121,
+            147, 160, 24, 52, 84, 90, 139, 45, 55, 48, 56, 24, 83, 12, 141, 24, 0, 0, 170, 151, 59,
+            146, 153, 168, 130, 136, 55, 162, 87, 99, 104, 84, 91, 143, 84, 31, 104, 146, 174, 167,
+            114, 50, 75, 81, 112, 50, 0, 141, 67, 134, 0, 153, 159, 132, 153, 143, 65, 110, 120,
+            59, 112, 59, 93, 148, 157, 158, 103, 150, 0, 170, 148, 142, 126, 94, 99, 97, 53, 115,
+            0, 0, 36, 80, 74, 0, 146, 148, 90, 99, 81, 163, 52, 81, 88, 134, 0, 81, 144, 73, 75,
+            156, 94, 43, 164, 116, 141, 147, 71, 48, 43, 56, 0, 97, 12, 129, 12, 140, 0, 90, 56, 0,
+            0, 0, 0, 0, 0, 0, 31, 0, 0, 31, 0, 0, 0, 0, 12, 40, 12, 48, 85, 12, 76, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 166, 162, 109, 132, 124, 187, 101, 129, 112, 174, 31, 78, 102, 147, 130,
+            154, 101, 40, 85, 141, 144, 167, 118, 55, 50, 97, 84, 94, 52, 145, 95, 101, 0, 156,
+            150, 73, 148, 87, 164, 107, 56, 120, 160, 0, 96, 101, 114, 81, 129, 146, 36, 86, 119,
+            175, 153, 80, 82, 48, 101, 52, 68, 31, 145, 0, 31, 0, 177, 169, 61, 90, 106, 183, 105,
+            80, 108, 167, 0, 87, 106, 97, 67, 154, 100, 12, 168, 105, 106, 157, 40, 84, 85, 103,
+            57, 115, 12, 165, 0, 0, 0, 176, 133, 125, 132, 110, 103, 112, 115, 31, 156, 59, 73,
+            174, 155, 173, 62, 138, 24, 157, 133, 162, 59, 89, 40, 113, 55, 102, 24, 0, 108, 94,
+            124, 0, 121, 153, 36, 12, 60, 153, 50, 36, 0, 136, 0, 0, 31, 80, 57, 120, 57, 0, 78,
+            64, 48, 71, 50, 24, 45, 24, 0, 75, 0, 119, 0, 0, 0, 107, 115, 24, 55, 68, 100, 31, 64,
+            53, 106, 12, 63, 65, 48, 65, 82, 60, 0, 67, 77, 0, 0, 0, 24, 36, 36, 0, 0, 0, 0, 0, 0,
+            0, 136, 102, 71, 103, 59, 130, 64, 31, 48, 126, 0, 53, 40, 71, 0, 36, 118, 0, 48, 55,
+            124, 112, 31, 12, 50, 93, 137, 24, 0, 83, 0, 0, 0, 123, 91, 61, 55, 12, 74, 0, 48, 0,
+            62, 0, 31, 70, 93, 80, 70, 88, 0, 45, 80, 65, 60, 12, 31, 36, 79, 67, 0, 0, 0, 0, 0, 0,
+            139, 148, 63, 84, 89, 134, 70, 81, 53, 134, 24, 24, 72, 127, 36, 108, 0, 24, 12, 83,
+            12, 115, 60, 36, 0, 40, 81, 79, 0, 151, 0, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 56, 0, 0,
+            79, 98, 135, 0, 0, 0, 105, 0, 124, 0, 0, 0, 0, 0, 43, 0, 0, 0, 40, 69, 0, 65, 0, 0, 0,
+            0, 0, 0, 0, 0, 64, 12, 0, 78, 102, 162, 0, 0, 0, 40, 0, 43, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            31, 0, 0, 185, 0, 12, 89, 70, 0, 0, 79, 0, 71, 0, 0, 84, 91, 63, 0, 60, 0, 149, 123,
+            130, 110, 0, 0, 0, 0, 72, 24, 0, 48, 105, 114, 0, 77, 121, 0, 61, 0, 124, 0, 0, 0, 165,
+            0, 0, 0, 36, 90, 82, 0, 0, 0, 0, 137, 115, 0, 0, 0, 0, 0, 0, 0, 59, 0, 0, 0, 93, 104,
+            0, 0, 0, 114, 0, 0, 0, 170, 0, 0, 0, 0, 12, 24, 0, 0, 0, 0, 0, 93, 0, 0, 0, 0, 0, 0, 0,
+            115, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+    },
+    LanguageModel {
+        language: "tr",
+        script: Script::Latin,
+        alphabet: &[
+            'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q',
+            'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z', '\u{00E2}', '\u{00E7}', '\u{00EE}',
+            '\u{00F6}', '\u{00FB}', '\u{00FC}', '\u{011F}', '\u{0131}', '\u{015F}',
+        ],
+        bigrams: &[
+            0, 176, 182, 144, 181, 163, 145, 172, 159, 177, 101, 174, 146, 149, 151, 163, 158, 109,
+            145, 179, 164, 152, 162, 122, 126, 171, 130, 0, 155, 0, 148, 0, 132, 0, 94, 131, 0,
+            179, 94, 148, 141, 160, 68, 122, 109, 136, 110, 78, 165, 170, 167, 179, 43, 145, 48,
+            181, 159, 160, 102, 112, 73, 91, 164, 152, 0, 135, 0, 0, 0, 81, 146, 24, 150, 0, 120,
+            157, 63, 74, 84, 149, 79, 54, 36, 170, 86, 62, 125, 86, 60, 142, 52, 0, 98, 90, 65,
+            151, 31, 12, 31, 80, 58, 0, 12, 0, 126, 0, 119, 43, 108, 0, 0, 133, 145, 54, 79, 89,
+            147, 59, 40, 123, 136, 50, 120, 102, 74, 58, 129, 81, 24, 110, 89, 117, 129, 40, 50,
+            36, 55, 0, 0, 0, 0, 0, 0, 101, 0, 139, 0, 0, 147, 163, 79, 67, 107, 174, 73, 69, 59,
+            164, 59, 68, 120, 67, 83, 159, 84, 0, 118, 89, 75, 142, 86, 78, 70, 80, 24, 50, 24, 0,
+            117, 0, 136, 12, 163, 0, 0, 181, 119, 122, 141, 148, 103, 124, 106, 79, 77, 74, 166,
+            163, 162, 177, 96, 119, 68, 181, 160, 161, 70, 135, 93, 120, 152, 120, 0, 156, 0, 40,
+            0, 0, 157, 0, 142, 0, 136, 136, 12, 71, 92, 114, 112, 54, 31, 131, 12, 55, 107, 70, 69,
+            122, 79, 0, 109, 101, 109, 78, 0, 31, 36, 71, 0, 0, 31, 0, 0, 0, 12, 0, 123, 0, 0, 129,
+            109, 67, 70, 76, 161, 50, 83, 84, 153, 31, 31, 93, 74, 108, 100, 97, 24, 130, 101, 93,
+            124, 85, 43, 12, 52, 64, 0, 24, 0, 143, 0, 137, 0, 110, 0, 0, 131, 151, 52, 71, 68,
+            141, 67, 12, 31, 130, 31, 43, 78, 74, 52, 107, 46, 0, 88, 82, 130, 78, 36, 43, 0, 57,
+            12, 73, 62, 0, 0, 0, 67, 0, 95, 12, 0, 183, 103, 120, 132, 135, 102, 135, 115, 113, 74,
+            64, 156, 173, 157, 180, 116, 138, 36, 175, 156, 149, 67, 118, 40, 106, 148, 160, 0,
+            161, 0, 0, 0, 0, 135, 0, 159, 0, 81, 78, 24, 12, 12, 94, 0, 0, 12, 84, 0, 24, 24, 40,
+            31, 71, 68, 0, 24, 52, 0, 70, 31, 0, 0, 0, 0, 0, 0, 0, 54, 0, 0, 0, 57, 0, 0, 173, 157,
+            74, 64, 71, 150, 58, 82, 54, 157, 0, 96, 159, 108, 59, 147, 82, 0, 110, 137, 148, 156,
+            59, 46, 0, 31, 60, 0, 96, 0, 93, 0, 115, 0, 137, 36, 0, 160, 184, 70, 87, 146, 181, 92,
+            133, 48, 170, 12, 112, 159, 152, 118, 136, 112, 0, 69, 112, 135, 150, 70, 63, 72, 101,
+            71, 72, 87, 50, 0, 0, 137, 12, 162, 0, 0, 160, 175, 125, 109, 123, 167, 61, 106, 43,
+            156, 24, 78, 151, 105, 79, 125, 111, 12, 89, 115, 85, 141, 57, 31, 54, 40, 110, 0, 0,
+            0, 0, 0, 125, 0, 147, 36, 0, 184, 159, 93, 143, 167, 164, 107, 129, 59, 163, 36, 115,
+            148, 147, 82, 132, 84, 31, 118, 124, 144, 151, 83, 36, 43, 106, 75, 0, 65, 0, 54, 0,
+            129, 0, 168, 58, 0, 135, 81, 103, 116, 132, 52, 108, 116, 46, 88, 70, 148, 163, 137,
+            155, 102, 124, 0, 161, 158, 126, 113, 93, 106, 72, 120, 99, 0, 57, 0, 0, 0, 0, 122, 0,
+            123, 0, 145, 150, 57, 101, 90, 129, 48, 96, 89, 127, 0, 96, 125, 96, 76, 128, 97, 52,
+            126, 115, 120, 104, 67, 58, 55, 100, 12, 0, 80, 0, 12, 0, 36, 0, 127, 0, 0, 99, 0, 0,
+            12, 0, 12, 0, 24, 0, 0, 0, 0, 84, 0, 36, 0, 31, 12, 36, 12, 40, 96, 0, 77, 0, 36, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 184, 165, 97, 116, 147, 163, 99, 131, 99, 171, 40, 129, 154,
+            148, 119, 137, 97, 24, 99, 148, 145, 150, 100, 60, 58, 101, 84, 0, 125, 0, 0, 0, 140,
+            0, 163, 121, 0, 152, 159, 77, 104, 73, 159, 59, 83, 122, 168, 0, 117, 111, 115, 123,
+            143, 108, 87, 96, 114, 159, 135, 88, 87, 46, 156, 31, 0, 84, 12, 110, 0, 137, 0, 164,
+            0, 0, 165, 167, 91, 105, 102, 165, 108, 88, 112, 164, 0, 124, 139, 130, 91, 129, 104,
+            12, 129, 105, 120, 145, 73, 61, 81, 109, 60, 0, 88, 12, 77, 0, 141, 0, 155, 0, 0, 163,
+            74, 113, 122, 108, 99, 89, 95, 70, 96, 24, 124, 162, 144, 157, 59, 121, 0, 152, 127,
+            144, 40, 88, 0, 84, 138, 128, 0, 102, 0, 0, 0, 0, 122, 0, 135, 0, 123, 129, 69, 105,
+            86, 160, 52, 24, 12, 135, 0, 0, 81, 70, 43, 85, 40, 0, 114, 72, 50, 113, 50, 31, 46,
+            84, 0, 12, 0, 0, 0, 0, 12, 0, 12, 70, 0, 112, 104, 31, 40, 84, 95, 31, 63, 58, 100, 0,
+            12, 60, 54, 70, 94, 57, 0, 82, 83, 12, 24, 0, 31, 43, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 135, 67, 73, 82, 48, 84, 64, 0, 31, 84, 0, 54, 31, 75, 24, 54, 92, 0, 52, 55, 100,
+            55, 12, 0, 71, 61, 58, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 135, 175, 73, 63, 113, 155, 97,
+            124, 24, 135, 0, 82, 125, 98, 127, 160, 96, 0, 123, 103, 115, 126, 43, 36, 36, 67, 48,
+            0, 0, 0, 112, 0, 130, 0, 146, 0, 0, 158, 140, 43, 123, 120, 140, 0, 121, 36, 142, 0,
+            48, 132, 121, 90, 95, 0, 0, 0, 92, 50, 119, 24, 12, 0, 93, 54, 0, 0, 0, 12, 0, 106, 0,
+            135, 0, 0, 72, 0, 0, 0, 12, 0, 0, 0, 50, 0, 0, 0, 73, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 134, 144, 105, 0, 12, 158, 0, 12, 0, 159, 0, 0,
+            104, 101, 55, 121, 0, 0, 68, 50, 81, 96, 0, 0, 0, 12, 0, 0, 67, 0, 107, 0, 105, 0, 141,
+            0, 0, 52, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 50, 0, 31, 0, 24, 0, 0, 105, 0, 0, 0, 93, 129, 71,
+            144, 0, 71, 0, 134, 127, 76, 0, 59, 0, 0, 96, 132, 0, 24, 0, 0, 0, 0, 96, 0, 58, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 141, 0, 0, 85, 64, 0, 12, 0, 12, 0, 0, 133, 124, 145, 143, 0, 76,
+            0, 146, 123, 120, 0, 105, 0, 0, 120, 136, 0, 103, 0, 0, 0, 0, 106, 0, 112, 0, 107, 113,
+            0, 48, 80, 140, 0, 0, 0, 156, 0, 0, 130, 103, 40, 48, 0, 0, 123, 24, 0, 123, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 89, 0, 139, 43, 0, 180, 12, 71, 133, 123, 0, 113, 12, 0, 0, 0,
+            148, 162, 146, 167, 0, 94, 0, 164, 138, 120, 0, 31, 0, 0, 142, 140, 0, 91, 0, 0, 0, 0,
+            130, 0, 151, 0, 148, 135, 12, 0, 75, 124, 0, 46, 0, 139, 0, 120, 153, 127, 40, 12, 31,
+            0, 0, 100, 152, 120, 110, 0, 0, 36, 61, 0, 54, 0, 40, 0, 92, 0, 136, 12, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0,
+        ],
+    },
+    LanguageModel {
+        language: "lt",
+        script: Script::Latin,
+        alphabet: &[
+            'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q',
+            'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z', '\u{0105}', '\u{010D}', '\u{0117}',
+            '\u{0119}', '\u{012F}', '\u{0161}', '\u{016B}', '\u{0173}', '\u{017E}',
+        ],
+        bigrams: &[
+            0, 175, 158, 134, 164, 151, 157, 155, 129, 166, 152, 174, 162, 157, 180, 141, 185, 112,
+            164, 177, 172, 147, 162, 123, 124, 143, 89, 49, 92, 0, 0, 158, 147, 37, 0, 140, 0, 179,
+            99, 136, 139, 150, 78, 104, 130, 65, 180, 119, 154, 168, 169, 169, 61, 155, 73, 170,
+            184, 169, 169, 165, 82, 82, 88, 106, 0, 107, 0, 0, 0, 152, 37, 0, 125, 0, 120, 145, 37,
+            61, 103, 133, 0, 70, 49, 126, 127, 0, 127, 103, 37, 137, 65, 0, 116, 81, 92, 140, 37,
+            0, 0, 80, 68, 85, 0, 116, 116, 0, 0, 136, 109, 0, 0, 122, 113, 68, 80, 92, 125, 37, 37,
+            131, 143, 0, 115, 90, 68, 65, 123, 81, 0, 109, 85, 100, 78, 37, 65, 61, 73, 0, 0, 0,
+            56, 0, 0, 0, 98, 0, 0, 0, 140, 157, 81, 70, 65, 146, 85, 86, 70, 163, 75, 98, 91, 78,
+            80, 160, 88, 0, 120, 78, 70, 139, 120, 0, 37, 149, 37, 95, 0, 133, 73, 100, 0, 49, 114,
+            137, 0, 166, 128, 118, 119, 133, 109, 106, 138, 56, 171, 112, 156, 159, 150, 166, 98,
+            152, 49, 166, 158, 160, 89, 102, 91, 106, 88, 109, 0, 124, 0, 0, 101, 140, 0, 0, 115,
+            0, 128, 149, 37, 68, 85, 107, 110, 65, 0, 132, 0, 37, 100, 0, 65, 136, 56, 0, 97, 70,
+            123, 109, 0, 0, 37, 56, 0, 49, 0, 0, 0, 0, 0, 0, 37, 0, 0, 132, 157, 88, 68, 101, 127,
+            61, 87, 89, 151, 37, 0, 118, 89, 114, 135, 86, 0, 150, 116, 146, 129, 68, 37, 0, 113,
+            99, 116, 49, 97, 49, 77, 0, 98, 106, 37, 0, 122, 111, 49, 37, 61, 122, 0, 37, 37, 97,
+            0, 0, 0, 65, 65, 116, 49, 0, 81, 61, 108, 80, 37, 0, 0, 108, 37, 0, 0, 0, 0, 0, 0, 0,
+            70, 0, 0, 185, 168, 130, 128, 153, 165, 133, 142, 37, 98, 153, 171, 160, 170, 182, 161,
+            144, 37, 157, 176, 157, 153, 105, 37, 108, 0, 133, 109, 128, 37, 0, 0, 162, 100, 151,
+            86, 0, 88, 156, 37, 37, 0, 153, 0, 0, 37, 142, 0, 82, 0, 0, 49, 144, 89, 0, 37, 0, 0,
+            151, 56, 0, 0, 0, 0, 120, 0, 0, 75, 110, 0, 93, 119, 0, 0, 138, 169, 92, 117, 107, 151,
+            56, 70, 77, 163, 0, 49, 160, 112, 85, 164, 91, 0, 134, 154, 157, 151, 98, 68, 0, 124,
+            0, 112, 61, 122, 37, 82, 136, 85, 120, 0, 0, 138, 171, 111, 70, 127, 154, 84, 114, 61,
+            172, 65, 101, 114, 78, 101, 150, 118, 0, 73, 105, 135, 139, 124, 37, 75, 137, 73, 115,
+            0, 149, 95, 118, 0, 78, 130, 56, 0, 137, 176, 128, 56, 68, 164, 75, 0, 61, 152, 37, 56,
+            100, 78, 89, 163, 130, 0, 65, 132, 101, 138, 49, 0, 61, 126, 0, 138, 0, 139, 97, 0, 0,
+            0, 128, 56, 0, 137, 163, 68, 112, 143, 170, 122, 159, 56, 167, 37, 155, 81, 99, 77,
+            147, 73, 56, 61, 118, 170, 157, 99, 37, 37, 130, 49, 112, 118, 146, 115, 121, 77, 37,
+            130, 37, 0, 183, 88, 131, 120, 155, 49, 112, 147, 56, 65, 154, 141, 138, 159, 148, 99,
+            128, 0, 157, 166, 155, 101, 102, 106, 108, 61, 89, 0, 97, 0, 0, 0, 86, 0, 0, 97, 0,
+            144, 180, 61, 94, 116, 154, 56, 81, 73, 154, 49, 92, 142, 90, 111, 141, 103, 37, 162,
+            112, 128, 124, 112, 49, 49, 92, 0, 86, 0, 111, 56, 70, 56, 0, 82, 49, 0, 94, 0, 0, 0,
+            0, 0, 0, 0, 0, 37, 0, 0, 56, 0, 0, 0, 0, 0, 37, 37, 81, 70, 0, 101, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 158, 178, 135, 113, 133, 157, 89, 120, 56, 173, 101, 127, 93, 136,
+            111, 163, 134, 0, 104, 139, 153, 139, 123, 65, 49, 126, 49, 105, 89, 131, 86, 106, 116,
+            110, 111, 91, 0, 197, 144, 91, 121, 120, 151, 68, 68, 113, 166, 0, 150, 145, 119, 115,
+            136, 147, 61, 124, 106, 171, 155, 115, 49, 56, 122, 0, 127, 78, 112, 77, 75, 37, 37,
+            111, 0, 0, 156, 179, 61, 94, 80, 163, 89, 95, 105, 184, 77, 115, 107, 124, 114, 164,
+            109, 0, 155, 125, 115, 160, 131, 65, 73, 156, 80, 131, 0, 140, 120, 117, 87, 114, 136,
+            68, 0, 156, 107, 112, 88, 150, 82, 113, 132, 0, 138, 130, 151, 136, 146, 152, 158, 117,
+            0, 159, 169, 149, 49, 123, 0, 70, 37, 92, 0, 102, 0, 0, 0, 107, 0, 0, 137, 0, 118, 156,
+            49, 37, 70, 160, 0, 56, 0, 163, 0, 0, 0, 70, 49, 132, 49, 0, 56, 49, 37, 114, 37, 0, 0,
+            148, 91, 88, 0, 107, 70, 0, 0, 37, 108, 0, 0, 100, 93, 56, 0, 68, 107, 37, 65, 37, 114,
+            0, 37, 81, 61, 70, 88, 49, 0, 75, 95, 0, 0, 0, 56, 49, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 125, 65, 98, 56, 61, 70, 78, 0, 49, 86, 0, 78, 37, 90, 0, 0, 94, 0, 37, 68, 89, 37,
+            0, 0, 68, 107, 49, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 131, 70, 131, 49, 122, 56, 0, 140, 0,
+            56, 122, 152, 90, 148, 112, 65, 119, 0, 142, 133, 156, 49, 129, 37, 49, 56, 92, 0, 0,
+            0, 0, 0, 95, 0, 0, 49, 0, 109, 91, 61, 0, 125, 111, 0, 49, 37, 117, 0, 0, 0, 65, 0,
+            108, 0, 0, 0, 0, 49, 100, 0, 49, 0, 49, 0, 0, 0, 90, 49, 0, 0, 0, 98, 0, 0, 160, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 105, 0, 89, 0, 0, 0, 0, 0, 119, 96, 0, 0, 49, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 68, 0, 49, 0, 0, 0, 0, 86, 0, 0, 0, 150, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 150, 0, 0, 56, 75, 0, 0, 73, 0, 0,
+            134, 96, 140, 118, 94, 0, 88, 0, 129, 153, 137, 0, 65, 0, 0, 0, 0, 0, 56, 0, 0, 0, 56,
+            0, 0, 102, 0, 135, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 122, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 37, 0, 0, 0, 0, 154, 0, 0, 0, 115, 56, 0, 95, 0, 0, 120,
+            112, 0, 68, 0, 0, 94, 0, 132, 103, 104, 0, 126, 0, 0, 0, 0, 0, 0, 49, 0, 0, 49, 0, 0,
+            56, 0, 129, 144, 37, 0, 107, 114, 0, 49, 0, 148, 113, 139, 101, 132, 89, 123, 105, 0,
+            123, 114, 138, 119, 127, 0, 0, 123, 0, 99, 111, 103, 0, 91, 77, 65, 116, 0, 0, 0, 0, 0,
+            0, 82, 0, 0, 37, 0, 0, 0, 106, 65, 65, 84, 0, 37, 0, 125, 117, 131, 0, 37, 0, 0, 0, 98,
+            0, 37, 0, 0, 0, 73, 0, 0, 49, 0, 172, 0, 0, 0, 0, 0, 0, 0, 0, 0, 90, 0, 0, 0, 0, 0, 0,
+            0, 0, 97, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 113, 110, 89, 0, 110, 113,
+            0, 0, 0, 145, 0, 98, 0, 90, 77, 101, 84, 0, 106, 73, 93, 91, 100, 0, 0, 137, 0, 0, 0,
+            56, 0, 56, 61, 0, 37, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+    },
+    LanguageModel {
+        language: "lv",
+        script: Script::Latin,
+        alphabet: &[
+            'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q',
+            'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z', '\u{0101}', '\u{010D}', '\u{0113}',
+            '\u{0123}', '\u{012B}', '\u{0137}', '\u{013C}', '\u{0146}', '\u{0161}', '\u{016B}',
+            '\u{017E}',
+        ],
+        bigrams: &[
+            0, 179, 156, 143, 169, 142, 153, 153, 129, 182, 152, 174, 170, 158, 181, 137, 183, 94,
+            162, 174, 169, 159, 172, 103, 127, 105, 132, 107, 65, 96, 79, 107, 58, 96, 46, 143, 0,
+            46, 0, 187, 58, 141, 117, 153, 46, 74, 127, 82, 177, 135, 160, 158, 159, 162, 46, 156,
+            0, 172, 178, 180, 164, 150, 70, 86, 79, 121, 112, 46, 0, 46, 70, 0, 133, 117, 132, 0,
+            96, 0, 112, 154, 0, 0, 0, 136, 0, 58, 0, 133, 114, 82, 122, 101, 0, 125, 46, 0, 111,
+            97, 46, 144, 118, 0, 0, 74, 0, 126, 0, 123, 0, 122, 0, 0, 0, 0, 134, 0, 0, 129, 123, 0,
+            58, 46, 138, 0, 0, 105, 153, 0, 111, 86, 0, 74, 128, 58, 0, 101, 65, 101, 82, 46, 0,
+            65, 0, 0, 104, 0, 106, 0, 106, 0, 0, 0, 0, 0, 0, 0, 145, 168, 89, 46, 65, 160, 70, 77,
+            0, 147, 82, 105, 74, 46, 107, 142, 79, 0, 134, 112, 65, 134, 0, 0, 0, 46, 148, 130, 0,
+            136, 0, 151, 0, 0, 86, 0, 70, 77, 0, 163, 125, 102, 136, 145, 126, 115, 133, 77, 160,
+            128, 165, 151, 153, 160, 87, 137, 0, 160, 173, 167, 77, 156, 87, 104, 77, 131, 65, 0,
+            0, 102, 58, 0, 129, 113, 132, 0, 128, 0, 130, 134, 0, 65, 84, 107, 114, 0, 0, 136, 0,
+            0, 98, 86, 0, 146, 0, 0, 98, 74, 98, 84, 0, 0, 0, 0, 0, 58, 0, 46, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 127, 157, 87, 70, 128, 113, 77, 90, 87, 123, 0, 0, 117, 86, 123, 119, 65, 0, 138,
+            138, 141, 142, 65, 0, 0, 0, 95, 122, 0, 0, 0, 0, 0, 0, 0, 118, 112, 0, 0, 109, 99, 46,
+            0, 0, 110, 0, 0, 0, 97, 0, 0, 0, 0, 65, 120, 0, 0, 58, 0, 110, 0, 0, 0, 0, 74, 0, 46,
+            0, 114, 0, 0, 0, 0, 0, 0, 0, 0, 0, 176, 118, 119, 132, 148, 185, 133, 136, 0, 65, 152,
+            161, 153, 143, 165, 126, 137, 46, 165, 165, 148, 0, 124, 0, 107, 0, 172, 74, 0, 0, 98,
+            0, 105, 89, 146, 103, 0, 77, 0, 96, 167, 0, 0, 46, 117, 0, 58, 0, 124, 0, 46, 46, 0, 0,
+            139, 96, 0, 0, 119, 46, 159, 79, 0, 0, 0, 46, 145, 0, 77, 0, 0, 0, 0, 0, 58, 108, 0, 0,
+            146, 167, 90, 102, 70, 107, 58, 0, 74, 116, 102, 58, 145, 112, 115, 161, 87, 0, 145,
+            164, 149, 159, 98, 46, 0, 0, 0, 152, 0, 0, 0, 0, 0, 129, 58, 143, 0, 0, 0, 127, 169,
+            65, 46, 132, 147, 93, 89, 0, 164, 65, 106, 121, 74, 129, 155, 112, 0, 65, 143, 127,
+            145, 120, 0, 0, 89, 58, 145, 0, 154, 0, 140, 0, 0, 77, 58, 115, 0, 0, 165, 168, 116, 0,
+            65, 152, 58, 0, 46, 148, 102, 82, 77, 118, 84, 135, 116, 0, 0, 159, 115, 146, 46, 0, 0,
+            46, 0, 150, 0, 150, 0, 107, 0, 0, 0, 70, 65, 0, 0, 146, 166, 0, 104, 146, 174, 123,
+            120, 58, 151, 65, 112, 79, 112, 117, 168, 103, 0, 65, 138, 160, 145, 87, 0, 58, 46, 46,
+            155, 0, 125, 0, 126, 0, 0, 0, 89, 0, 70, 0, 162, 74, 130, 119, 135, 89, 100, 154, 0,
+            96, 144, 145, 139, 138, 157, 91, 137, 0, 158, 155, 166, 99, 124, 103, 91, 0, 116, 0, 0,
+            0, 70, 0, 99, 84, 82, 136, 0, 0, 0, 138, 169, 46, 121, 93, 141, 0, 111, 58, 163, 77,
+            79, 142, 105, 131, 139, 93, 0, 152, 131, 117, 130, 86, 0, 0, 65, 84, 148, 0, 137, 0,
+            108, 0, 70, 0, 87, 0, 0, 0, 65, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46,
+            0, 87, 58, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 168, 168, 136, 102, 109,
+            159, 58, 114, 46, 162, 77, 129, 111, 141, 128, 148, 139, 0, 117, 155, 154, 145, 137, 0,
+            0, 79, 102, 161, 0, 139, 46, 146, 109, 0, 0, 102, 110, 0, 0, 198, 167, 58, 101, 84,
+            152, 79, 46, 122, 148, 82, 149, 153, 122, 108, 136, 136, 0, 108, 128, 176, 137, 116,
+            46, 0, 90, 74, 123, 0, 138, 0, 125, 0, 0, 0, 0, 95, 0, 0, 178, 173, 132, 104, 107, 155,
+            98, 97, 103, 172, 116, 130, 110, 126, 152, 166, 119, 0, 155, 166, 146, 165, 121, 58,
+            84, 101, 124, 159, 0, 153, 0, 157, 46, 115, 115, 90, 113, 0, 0, 180, 58, 100, 98, 120,
+            96, 114, 130, 0, 77, 96, 150, 139, 167, 149, 0, 120, 0, 154, 158, 139, 0, 93, 0, 58, 0,
+            149, 90, 46, 0, 0, 46, 0, 91, 0, 103, 0, 58, 0, 146, 166, 46, 0, 0, 153, 46, 46, 0,
+            163, 46, 0, 0, 0, 70, 119, 0, 0, 65, 98, 0, 105, 0, 0, 0, 0, 0, 145, 0, 153, 0, 102, 0,
+            0, 0, 0, 0, 0, 0, 104, 70, 46, 0, 0, 79, 0, 0, 0, 93, 0, 0, 46, 46, 70, 65, 0, 0, 58,
+            84, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 121, 65, 103, 70, 74, 77,
+            79, 0, 0, 87, 0, 87, 0, 82, 0, 0, 97, 0, 58, 58, 98, 0, 0, 0, 79, 87, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 119, 0, 65, 46, 0, 46, 0, 0, 0, 46, 0, 0, 70, 0, 58, 58, 103, 0,
+            0, 90, 65, 0, 0, 0, 58, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 149, 121, 74, 58,
+            143, 124, 0, 77, 0, 145, 46, 104, 116, 145, 70, 117, 111, 0, 102, 133, 118, 108, 147,
+            58, 0, 0, 70, 101, 0, 134, 0, 145, 46, 0, 103, 74, 46, 0, 0, 162, 84, 114, 134, 155, 0,
+            87, 90, 0, 84, 146, 136, 143, 145, 124, 0, 108, 0, 155, 161, 153, 74, 108, 0, 0, 0, 84,
+            0, 0, 0, 0, 0, 46, 77, 46, 121, 0, 65, 0, 0, 65, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 65, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 142, 0, 0,
+            121, 105, 0, 0, 145, 0, 0, 152, 86, 151, 139, 115, 0, 95, 0, 156, 119, 158, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 87, 0, 46, 102, 0, 127, 0, 0, 0, 0, 79, 0, 0, 0, 77, 0, 0, 0, 110, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 99, 0, 0, 0, 0, 0, 0, 0, 0, 0, 126,
+            0, 150, 114, 133, 0, 0, 142, 0, 0, 130, 127, 58, 144, 105, 0, 105, 0, 97, 114, 160, 0,
+            118, 0, 0, 0, 70, 0, 46, 0, 0, 0, 0, 0, 0, 115, 0, 0, 0, 0, 108, 0, 0, 0, 108, 0, 0, 0,
+            130, 0, 0, 0, 0, 0, 46, 0, 0, 0, 0, 0, 84, 0, 0, 0, 0, 0, 0, 0, 106, 0, 65, 0, 0, 0, 0,
+            0, 0, 0, 97, 140, 0, 0, 0, 0, 0, 0, 0, 101, 0, 46, 0, 0, 46, 105, 0, 0, 0, 0, 0, 123,
+            58, 0, 0, 0, 0, 95, 0, 70, 0, 0, 0, 0, 82, 102, 125, 0, 0, 46, 132, 0, 0, 0, 123, 0, 0,
+            0, 95, 58, 46, 0, 0, 0, 121, 0, 0, 0, 46, 0, 132, 0, 0, 0, 0, 0, 107, 0, 98, 0, 0, 77,
+            0, 0, 98, 0, 0, 0, 126, 156, 0, 0, 70, 90, 74, 58, 0, 133, 0, 65, 94, 94, 46, 121, 97,
+            0, 110, 100, 70, 121, 0, 0, 0, 0, 0, 112, 0, 113, 0, 125, 132, 0, 46, 0, 95, 0, 0, 79,
+            0, 0, 46, 123, 0, 0, 0, 0, 0, 0, 115, 86, 91, 98, 0, 0, 0, 111, 118, 139, 0, 58, 0, 0,
+            0, 58, 0, 0, 0, 0, 0, 0, 0, 0, 70, 0, 46, 0, 65, 103, 0, 0, 0, 58, 0, 0, 0, 70, 0, 0,
+            0, 0, 0, 98, 0, 0, 0, 58, 0, 97, 0, 0, 0, 0, 0, 84, 0, 0, 79, 118, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+    },
+];
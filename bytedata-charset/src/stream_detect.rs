@@ -0,0 +1,363 @@
+//! ## Incremental charset detection
+//!
+//! Detects the charset of a stream that arrives in chunks, and hands the buffered bytes over to a [`DecodeStream`] once the charset is chosen.
+//! See the [`StreamDetector`] struct for more information.
+//!
+//! [`DecodeStream`]: crate::DecodeStream
+
+use crate::detect::{CharsetSelector, DetectionResult};
+
+/// The default number of bytes a [`StreamDetector`] inspects before it commits to a charset.
+const DEFAULT_LIMIT: usize = 16 * 1024;
+
+/// A detector that refines its guess of the charset as the chunks of a stream arrive.
+///
+/// Every chunk that is pushed is kept in a [`ByteQueue`] without copying it, and the start of the stream is run through the wrapped [`CharsetSelector`] again
+/// whenever the inspected bytes have doubled since its last run, so that the total work stays linear in the number of inspected bytes however small the chunks are.
+/// The detector commits to the selected charset as soon as it is [`DetectionResult::Certain`], when the limit of inspected bytes is reached or when the stream ends,
+/// after which chunks are only buffered.
+/// The chosen charset is then handed to a [`DecodeStream`] together with the buffered bytes, so that the stream is decoded from its first byte without reading the source again.
+///
+/// The wrapped selector defaults to a [`WebDetector`], which ranks all charsets enabled through cargo features.
+///
+/// [`ByteQueue`]: bytedata::ByteQueue
+/// [`DecodeStream`]: crate::DecodeStream
+/// [`WebDetector`]: crate::WebDetector
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub struct StreamDetector<'a, D: CharsetSelector = crate::WebDetector> {
+    detector: D,
+    /// All bytes pushed so far, to be replayed when decoding.
+    buffered: bytedata::ByteQueue<'a>,
+    /// A contiguous copy of the start of the stream, which is what the selector inspects.
+    inspected: alloc::vec::Vec<u8>,
+    limit: usize,
+    selected: Option<(D::Charset, DetectionResult)>,
+    /// The number of inspected bytes the selector last ran on.
+    selected_len: usize,
+    committed: bool,
+}
+
+impl<D: CharsetSelector + core::fmt::Debug> core::fmt::Debug for StreamDetector<'_, D> {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("StreamDetector")
+            .field("detector", &self.detector)
+            .field("buffered", &self.buffered.len())
+            .field("limit", &self.limit)
+            .field(
+                "selected",
+                &self
+                    .selected
+                    .as_ref()
+                    .map(|entry| (crate::CharsetRef::charset_name(&entry.0), entry.1)),
+            )
+            .field("committed", &self.committed)
+            .finish()
+    }
+}
+
+impl<'a, D: CharsetSelector> StreamDetector<'a, D> {
+    /// Create a new stream detector using the given selector.
+    #[inline]
+    #[must_use]
+    pub const fn new(detector: D) -> Self {
+        Self {
+            detector,
+            buffered: bytedata::ByteQueue::new(),
+            inspected: alloc::vec::Vec::new(),
+            limit: DEFAULT_LIMIT,
+            selected: None,
+            selected_len: 0,
+            committed: false,
+        }
+    }
+
+    /// Set the number of bytes at the start of the stream that are inspected before committing to a charset, which is 16 KiB by default.
+    #[inline]
+    #[must_use]
+    pub const fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = limit;
+        self
+    }
+
+    /// Push the next chunk of the stream, returning the detection result of the selected charset.
+    ///
+    /// The selector only runs again once the inspected bytes have doubled or the limit is reached, until then the result of its last run is returned.
+    /// Once the detector has committed to a charset the chunk is only buffered.
+    #[expect(clippy::missing_inline_in_public_items)]
+    pub fn push<B: Into<bytedata::ByteData<'a>>>(&mut self, chunk: B) -> DetectionResult {
+        let chunk = chunk.into();
+        if !self.committed {
+            let take = self.limit.saturating_sub(self.inspected.len());
+            let bytes = chunk.as_slice();
+            self.inspected
+                .extend_from_slice(bytes.get(..take).unwrap_or(bytes));
+            if self.selected.is_none()
+                || self.inspected.len() >= self.selected_len.max(1).saturating_mul(2)
+                || self.inspected.len() >= self.limit
+            {
+                self.select();
+            }
+        }
+        self.buffered.push_back(chunk);
+        self.result()
+    }
+
+    /// Push chunks from the source until the detector commits to a charset or the source ends, returning the detection result of the selected charset.
+    ///
+    /// The source can then be passed to [`StreamDetector::into_decode_stream`] to decode the rest of the stream.
+    #[expect(clippy::missing_inline_in_public_items)]
+    pub fn read_from<S>(&mut self, source: &mut S) -> DetectionResult
+    where
+        S: Iterator,
+        S::Item: Into<bytedata::ByteData<'a>>,
+    {
+        while !self.committed {
+            let Some(chunk) = source.next() else {
+                return self.finish();
+            };
+            self.push(chunk);
+        }
+        self.result()
+    }
+
+    /// Mark the end of the stream, committing to the selected charset.
+    #[expect(clippy::missing_inline_in_public_items)]
+    pub fn finish(&mut self) -> DetectionResult {
+        if !self.committed && (self.selected.is_none() || self.selected_len < self.inspected.len())
+        {
+            self.select();
+        }
+        self.commit();
+        self.result()
+    }
+
+    /// The selected charset, if any chunk has been pushed.
+    #[inline]
+    #[must_use]
+    pub fn charset(&self) -> Option<&D::Charset> {
+        self.selected.as_ref().map(|entry| &entry.0)
+    }
+
+    /// The detection result of the selected charset, which is [`DetectionResult::Incomplete`] until a chunk has been pushed.
+    #[inline]
+    #[must_use]
+    pub const fn result(&self) -> DetectionResult {
+        match self.selected {
+            Some((_, result)) => result,
+            None => DetectionResult::Incomplete,
+        }
+    }
+
+    /// Returns `true` once the detector has committed to the selected charset and no longer inspects new chunks.
+    #[inline]
+    #[must_use]
+    pub const fn is_committed(&self) -> bool {
+        self.committed
+    }
+
+    /// The bytes pushed so far, which are replayed when decoding.
+    #[inline]
+    #[must_use]
+    pub const fn buffered(&self) -> &bytedata::ByteQueue<'a> {
+        &self.buffered
+    }
+
+    /// Create a [`DecodeStream`] using the selected charset, which decodes the buffered bytes before reading the rest of the stream from the source.
+    ///
+    /// If the detector has not committed yet the charset is selected for all bytes inspected so far.
+    /// A byte order mark of the selected charset is skipped, just as the [WHATWG decode] algorithm does.
+    ///
+    /// [`DecodeStream`]: crate::DecodeStream
+    /// [WHATWG decode]: https://encoding.spec.whatwg.org/#decode
+    #[expect(clippy::missing_inline_in_public_items)]
+    pub fn into_decode_stream<S>(self, source: S) -> crate::DecodeStream<'a, D::Charset, S>
+    where
+        D::Charset: crate::CharsetStatefulDecoding,
+    {
+        let up_to_date = self.committed || self.selected_len == self.inspected.len();
+        let (charset, _) = match self.selected {
+            Some(selected) if up_to_date => selected,
+            _ => self.detector.select(&self.inspected),
+        };
+        let mut buffered = self.buffered;
+        let start: alloc::vec::Vec<u8> = buffered.bytes().take(4).collect();
+        let bom = bom_len(&charset, &start);
+        if bom != 0 {
+            core::mem::drop(buffered.drain(..bom));
+        }
+        crate::DecodeStream::with_buffered(charset, source, buffered)
+    }
+
+    /// Run the selector on the inspected bytes, committing once the result is certain or the limit is reached.
+    fn select(&mut self) {
+        let (charset, result) = self.detector.select(&self.inspected);
+        self.selected = Some((charset, result));
+        self.selected_len = self.inspected.len();
+        if matches!(result, DetectionResult::Certain) || self.inspected.len() >= self.limit {
+            self.commit();
+        }
+    }
+
+    /// Stop inspecting new chunks.
+    fn commit(&mut self) {
+        self.committed = true;
+        self.inspected = alloc::vec::Vec::new();
+    }
+}
+
+/// The length of the byte order mark of the charset at the start of the bytes, or `0` if there is none.
+fn bom_len<C: crate::CharsetRef + ?Sized>(charset: &C, bytes: &[u8]) -> usize {
+    match (crate::CharsetRegistry::canonical_name(charset), bytes) {
+        ("utf-8", &[0xEF, 0xBB, 0xBF, ..]) => 3,
+        ("utf-16be", &[0xFE, 0xFF, ..]) | ("utf-16le", &[0xFF, 0xFE, ..]) => 2,
+        ("utf-32be", &[0x00, 0x00, 0xFE, 0xFF, ..])
+        | ("utf-32le", &[0xFF, 0xFE, 0x00, 0x00, ..]) => 4,
+        _ => 0,
+    }
+}
+
+impl Default for StreamDetector<'_> {
+    #[inline]
+    fn default() -> Self {
+        Self::new(crate::WebDetector::new())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::detect::CharsetDetector;
+
+    fn decode_all<'a, C: crate::CharsetStatefulDecoding, S>(
+        mut stream: crate::DecodeStream<'a, C, S>,
+    ) -> Result<alloc::string::String, crate::DecodeError>
+    where
+        S: Iterator,
+        S::Item: Into<bytedata::ByteData<'a>>,
+    {
+        let mut out = alloc::string::String::new();
+        while let Some(chunk) = stream.iter_next() {
+            out.push_str(chunk?.as_str());
+        }
+        Ok(out)
+    }
+
+    /// A selector that counts how often it runs and how many bytes it inspects in total.
+    struct CountingSelector<'c> {
+        runs: &'c core::cell::Cell<usize>,
+        inspected: &'c core::cell::Cell<usize>,
+    }
+
+    impl CharsetDetector for CountingSelector<'_> {
+        fn detect(&self, bytes: &[u8]) -> DetectionResult {
+            self.runs.set(self.runs.get() + 1);
+            self.inspected.set(self.inspected.get() + bytes.len());
+            DetectionResult::Tentative
+        }
+    }
+
+    impl CharsetSelector for CountingSelector<'_> {
+        type Charset = crate::Utf8Encoding;
+
+        fn select(&self, bytes: &[u8]) -> (Self::Charset, DetectionResult) {
+            (crate::Utf8Encoding::new(), self.detect(bytes))
+        }
+    }
+
+    #[test]
+    fn test_commit_on_bom() {
+        let mut source = [&b"\xEF\xBB\xBFh\xC3"[..], b"\xA9llo", b" world"].into_iter();
+        let mut detector = StreamDetector::default();
+        assert_eq!(detector.result(), DetectionResult::Incomplete);
+        assert_eq!(detector.read_from(&mut source), DetectionResult::Certain);
+        assert!(detector.is_committed());
+        assert_eq!(detector.buffered().len(), 5);
+        assert_eq!(
+            detector
+                .charset()
+                .map(|charset| crate::CharsetRegistry::canonical_name(*charset)),
+            Some("utf-8")
+        );
+        let out = decode_all(detector.into_decode_stream(source));
+        // the byte order mark is not part of the text
+        assert_eq!(out.as_deref(), Ok("h\u{E9}llo world"));
+
+        let mut utf16 = StreamDetector::default();
+        assert_eq!(utf16.push(&b"\xFF\xFEh\0"[..]), DetectionResult::Certain);
+        let text = decode_all(utf16.into_decode_stream(core::iter::once(&b"i\0"[..])));
+        assert_eq!(text.as_deref(), Ok("hi"));
+    }
+
+    #[test]
+    fn test_refine() {
+        let mut detector = StreamDetector::new(crate::Utf16Encoding::UTF16_LE).with_limit(8);
+        assert_eq!(detector.push(&b"\xFF"[..]), DetectionResult::Incomplete);
+        assert!(!detector.is_committed());
+        assert_eq!(detector.push(&b"\xFEa\x00"[..]), DetectionResult::Certain);
+        assert!(detector.is_committed());
+
+        let mut limited = StreamDetector::new(crate::Utf8Encoding::new()).with_limit(4);
+        limited.push(&b"abc"[..]);
+        assert!(!limited.is_committed());
+        limited.push(&b"def"[..]);
+        assert!(limited.is_committed());
+        assert_eq!(limited.buffered().len(), 6);
+    }
+
+    #[cfg(feature = "windows-1252")]
+    #[test]
+    fn test_replay() {
+        let chunks: [&[u8]; 3] = [b"caf", b"\xE9 au", b" lait"];
+        let mut detector = StreamDetector::new(crate::WebDetector::with_locale("fr"));
+        let mut source = chunks.into_iter();
+        assert_eq!(detector.read_from(&mut source), DetectionResult::Tentative);
+        assert!(detector.is_committed());
+        assert_eq!(source.next(), None);
+        let out = decode_all(detector.into_decode_stream(source));
+        assert_eq!(out.as_deref(), Ok("caf\u{E9} au lait"));
+    }
+
+    #[test]
+    fn test_small_chunks() {
+        let runs = core::cell::Cell::new(0_usize);
+        let inspected = core::cell::Cell::new(0_usize);
+        let counting = CountingSelector {
+            runs: &runs,
+            inspected: &inspected,
+        };
+        let mut detector = StreamDetector::new(counting).with_limit(4096);
+        for _ in 0..4000_usize {
+            assert_eq!(detector.push(&b"a"[..]), DetectionResult::Tentative);
+        }
+        assert!(!detector.is_committed());
+        // the selector runs whenever the inspected bytes double, at 1, 2, 4, ..., 2048 bytes
+        assert_eq!(runs.get(), 12);
+        assert_eq!(inspected.get(), 4095);
+
+        // the end of the stream runs it once more on all bytes
+        assert_eq!(detector.finish(), DetectionResult::Tentative);
+        assert_eq!(runs.get(), 13);
+        assert_eq!(inspected.get(), 4095 + 4000);
+        let out = decode_all(detector.into_decode_stream(core::iter::empty::<&[u8]>()));
+        assert_eq!(out.map(|text| text.len()), Ok(4000));
+    }
+
+    #[test]
+    fn test_empty_first_chunk() {
+        let runs = core::cell::Cell::new(0_usize);
+        let inspected = core::cell::Cell::new(0_usize);
+        let counting = CountingSelector {
+            runs: &runs,
+            inspected: &inspected,
+        };
+        let mut detector = StreamDetector::new(counting).with_limit(4096);
+        assert_eq!(detector.push(&b""[..]), DetectionResult::Tentative);
+        for _ in 0..4000_usize {
+            detector.push(&b"a"[..]);
+        }
+        // the selector runs on the empty chunk and then at 2, 4, ..., 2048 bytes
+        assert_eq!(runs.get(), 12);
+        assert_eq!(inspected.get(), 4094);
+    }
+}
@@ -3,7 +3,13 @@ pub trait CharsetRef {
     /// The name of the charset.
     ///
     /// This method can be overridden to provide a different name than the internal name dependant on the specifics of `self`.
-    fn charset_name(&self) -> &'static str;
+    fn charset_name(&self) -> &str;
+
+    /// The labels the charset is known by, starting with the preferred name.
+    #[inline]
+    fn charset_alias(&self) -> &[&'static str] {
+        &[]
+    }
 
     /// The minimum and maximum number of bytes that represent a character in the charset.
     fn size_hint(&self) -> (u16, u16);
@@ -11,10 +17,15 @@ pub trait CharsetRef {
 
 impl<'a> CharsetRef for &'a (dyn CharsetRef + 'a) {
     #[inline]
-    fn charset_name(&self) -> &'static str {
+    fn charset_name(&self) -> &str {
         (**self).charset_name()
     }
 
+    #[inline]
+    fn charset_alias(&self) -> &[&'static str] {
+        (**self).charset_alias()
+    }
+
     #[inline]
     fn size_hint(&self) -> (u16, u16) {
         (**self).size_hint()
@@ -34,13 +45,13 @@ pub trait Charset {
     /// This method can be overridden to provide a different name than the internal name dependant on the specifics of `self`.
     #[inline]
     #[must_use]
-    fn charset_name(&self) -> &'static str {
+    fn charset_name(&self) -> &str {
         Self::CHARSET_NAME
     }
 
-    /// The name of the charset.
+    /// The labels the charset is known by, starting with the preferred name.
     ///
-    /// This method can be overridden to provide a different name than the internal name dependant on the specifics of `self`.
+    /// This method can be overridden to provide a different set of labels than the internal name dependant on the specifics of `self`.
     #[inline]
     #[must_use]
     fn charset_alias(&self) -> &[&'static str] {
@@ -53,10 +64,15 @@ pub trait Charset {
 
 impl<T: Charset> CharsetRef for T {
     #[inline]
-    fn charset_name(&self) -> &'static str {
+    fn charset_name(&self) -> &str {
         <T as Charset>::charset_name(self)
     }
 
+    #[inline]
+    fn charset_alias(&self) -> &[&'static str] {
+        <T as Charset>::charset_alias(self)
+    }
+
     #[inline]
     fn size_hint(&self) -> (u16, u16) {
         <T as Charset>::size_hint(self)
@@ -67,10 +83,387 @@ impl<T: Charset> CharsetRef for T {
 pub trait CharsetDecoding: CharsetRef {
     /// Decode characters from the given bytes.
     fn decode(&self, bytes: &[u8]) -> crate::DecodeResult;
+
+    /// Decode all of the given bytes into a string.
+    ///
+    /// The input is returned without copying if all of it is UTF-8 compatible in this charset.
+    /// Invalid sequences, including a character cut off by the end of the input, are handled according to `invalid`.
+    /// Charsets may override this with a faster implementation than stepping through [`CharsetDecoding::decode`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`DecodeError`] for the first invalid sequence if `invalid` is [`DecodeInvalid::Error`].
+    ///
+    /// [`DecodeError`]: crate::DecodeError
+    /// [`DecodeInvalid::Error`]: crate::DecodeInvalid::Error
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    #[inline]
+    fn decode_all<'a>(
+        &self,
+        bytes: bytedata::ByteData<'a>,
+        invalid: crate::DecodeInvalid,
+    ) -> Result<bytedata::StringData<'a>, crate::DecodeError> {
+        crate::bulk::decode_all(self, bytes, invalid)
+    }
 }
 
 /// A charset that can encode characters to bytes.
 pub trait CharsetEncoding: CharsetRef {
     /// Encode characters to bytes.
     fn encode(&self, chars: &str) -> crate::EncodeResult;
+
+    /// Encode all of the given characters.
+    ///
+    /// The input is returned without copying if all of it is encoded as UTF-8 in this charset.
+    /// Characters that can not be encoded are handled according to `invalid`.
+    /// Charsets may override this with a faster implementation than stepping through [`CharsetEncoding::encode`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a character can not be encoded and `invalid` does not provide a replacement that can be encoded.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    #[inline]
+    fn encode_all<'a>(
+        &self,
+        chars: &'a str,
+        invalid: crate::EncodeInvalid,
+    ) -> Result<bytedata::ByteData<'a>, &'static str> {
+        crate::bulk::encode_all(self, chars, invalid)
+    }
+
+    /// Encode all of the given characters, appending the bytes to `out`.
+    ///
+    /// The vector is left unchanged if an error is returned.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a character can not be encoded and `invalid` does not provide a replacement that can be encoded.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    #[inline]
+    fn encode_to_vec(
+        &self,
+        chars: &str,
+        invalid: crate::EncodeInvalid,
+        out: &mut alloc::vec::Vec<u8>,
+    ) -> Result<(), &'static str> {
+        crate::bulk::encode_to_vec(self, chars, invalid, out)
+    }
+}
+
+/// The per-stream state of a [`CharsetStatefulDecoding`] or [`CharsetStatefulEncoding`] implementation.
+///
+/// Shift-state encodings such as ISO-2022-JP need to remember the active mode between calls.
+/// The meaning of the value is private to the charset using it, a zeroed state is always the initial state of the stream.
+/// Stateless charsets never touch the state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct CharsetState(u32);
+
+impl CharsetState {
+    /// The initial state of a stream.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self(0)
+    }
+
+    /// Create a state from a charset specific value.
+    #[inline]
+    #[must_use]
+    pub const fn from_u32(value: u32) -> Self {
+        Self(value)
+    }
+
+    /// The charset specific value of the state.
+    #[inline]
+    #[must_use]
+    pub const fn as_u32(self) -> u32 {
+        self.0
+    }
+
+    /// Returns `true` if this is the initial state of a stream.
+    #[inline]
+    #[must_use]
+    pub const fn is_initial(self) -> bool {
+        self.0 == 0
+    }
+}
+
+/// A charset that decodes bytes to characters while keeping track of a state between calls.
+///
+/// This is implemented for every [`CharsetDecoding`] type, which ignores the state.
+/// A state should only be kept if the returned result is consumed, callers that discard a result need to restore the state to what it was before the call.
+pub trait CharsetStatefulDecoding: CharsetRef {
+    /// Decode characters from the given bytes using and updating the state of the stream.
+    ///
+    /// A decoder may return [`DecodeResult::Empty`] for non-empty input to signal that all of the given bytes are consumed without producing any characters.
+    ///
+    /// [`DecodeResult::Empty`]: crate::DecodeResult::Empty
+    fn decode_stateful(&self, state: &mut CharsetState, bytes: &[u8]) -> crate::DecodeResult;
+
+    /// Decode characters from the given bytes which are the last bytes of the stream.
+    ///
+    /// This is called repeatedly at the end of input until [`DecodeResult::Empty`] is returned, which means that any bytes left are fully handled.
+    /// It never returns [`DecodeResult::Incomplete`], a truncated sequence is reported as [`DecodeResult::InvalidChar`] instead.
+    /// The state is reset to the initial state once the stream is finished.
+    ///
+    /// [`DecodeResult::Empty`]: crate::DecodeResult::Empty
+    /// [`DecodeResult::Incomplete`]: crate::DecodeResult::Incomplete
+    /// [`DecodeResult::InvalidChar`]: crate::DecodeResult::InvalidChar
+    #[inline]
+    fn decode_finish(&self, state: &mut CharsetState, bytes: &[u8]) -> crate::DecodeResult {
+        if bytes.is_empty() {
+            *state = CharsetState::new();
+            return crate::DecodeResult::Empty;
+        }
+        match self.decode_stateful(state, bytes) {
+            crate::DecodeResult::Empty => {
+                // the remaining bytes were discarded by the decoder
+                *state = CharsetState::new();
+                crate::DecodeResult::Empty
+            }
+            crate::DecodeResult::Incomplete => {
+                let data = bytes
+                    .iter()
+                    .take(4)
+                    .fold(0_u32, |acc, &byte| (acc << 8_u32) | u32::from(byte));
+                #[expect(clippy::cast_possible_truncation)]
+                crate::DecodeResult::InvalidChar(data, bytes.len() as u32)
+            }
+            res @ (crate::DecodeResult::Char(..)
+            | crate::DecodeResult::CharPair(..)
+            | crate::DecodeResult::InvalidChar(..)
+            | crate::DecodeResult::Utf8(_)) => res,
+        }
+    }
+
+    /// Decode all of the given bytes into a string, starting from and ending in the initial state.
+    ///
+    /// This is the stateful counterpart of [`CharsetDecoding::decode_all`], which it uses for stateless charsets.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`DecodeError`] for the first invalid sequence if `invalid` is [`DecodeInvalid::Error`].
+    ///
+    /// [`DecodeError`]: crate::DecodeError
+    /// [`DecodeInvalid::Error`]: crate::DecodeInvalid::Error
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    #[inline]
+    fn decode_all_stateful<'a>(
+        &self,
+        bytes: bytedata::ByteData<'a>,
+        invalid: crate::DecodeInvalid,
+    ) -> Result<bytedata::StringData<'a>, crate::DecodeError> {
+        crate::bulk::decode_all(self, bytes, invalid)
+    }
+}
+
+impl<T: CharsetDecoding + ?Sized> CharsetStatefulDecoding for T {
+    #[inline]
+    fn decode_stateful(&self, _state: &mut CharsetState, bytes: &[u8]) -> crate::DecodeResult {
+        self.decode(bytes)
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn decode_all_stateful<'a>(
+        &self,
+        bytes: bytedata::ByteData<'a>,
+        invalid: crate::DecodeInvalid,
+    ) -> Result<bytedata::StringData<'a>, crate::DecodeError> {
+        self.decode_all(bytes, invalid)
+    }
+}
+
+/// A charset that encodes characters to bytes while keeping track of a state between calls.
+///
+/// This is implemented for every [`CharsetEncoding`] type, which ignores the state.
+/// A state should only be kept if the returned result is consumed, callers that discard a result need to restore the state to what it was before the call.
+pub trait CharsetStatefulEncoding: CharsetRef {
+    /// Encode characters to bytes using and updating the state of the stream.
+    fn encode_stateful(&self, state: &mut CharsetState, chars: &str) -> crate::EncodeResult;
+
+    /// Finish the stream by returning the bytes needed to get back to the initial state.
+    ///
+    /// Returns [`EncodeResult::Empty`] if no more bytes need to be written, the state is then reset to the initial state.
+    ///
+    /// [`EncodeResult::Empty`]: crate::EncodeResult::Empty
+    #[inline]
+    fn encode_finish(&self, state: &mut CharsetState) -> crate::EncodeResult {
+        *state = CharsetState::new();
+        crate::EncodeResult::Empty
+    }
+
+    /// Encode the replacement of a character that can not be encoded, as returned by [`EncodeResult::InvalidChar`].
+    ///
+    /// The replacement text given by the [`EncodeInvalid`] mode is encoded using this charset and passed to `output` in chunks of at most [`EncodeReplacement::CAPACITY`] bytes.
+    /// Nothing is passed to `output` and the state is left unchanged if an error is returned.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the mode does not allow a replacement or if the replacement can not be encoded in this charset.
+    ///
+    /// [`EncodeResult::InvalidChar`]: crate::EncodeResult::InvalidChar
+    /// [`EncodeInvalid`]: crate::EncodeInvalid
+    /// [`EncodeReplacement::CAPACITY`]: crate::EncodeReplacement::CAPACITY
+    #[inline]
+    fn encode_invalid(
+        &self,
+        state: &mut CharsetState,
+        ch: char,
+        invalid: crate::EncodeInvalid,
+        output: &mut dyn FnMut(&[u8]),
+    ) -> Result<(), &'static str> {
+        let replacement = invalid.replacement(ch)?;
+        // make sure the whole replacement can be encoded before passing anything to the output
+        let mut emit = false;
+        loop {
+            let mut next = *state;
+            let mut chars = replacement.as_str();
+            while !chars.is_empty() {
+                let len = match self.encode_stateful(&mut next, chars) {
+                    crate::EncodeResult::Chunk(chunk, len) => {
+                        if emit {
+                            output(chunk.as_slice());
+                        }
+                        len as usize
+                    }
+                    crate::EncodeResult::Utf8(len) => {
+                        #[expect(clippy::cast_possible_truncation)]
+                        let len = len as usize;
+                        if emit {
+                            output(chars.as_bytes().get(..len).unwrap_or_default());
+                        }
+                        len
+                    }
+                    crate::EncodeResult::InvalidChar(..)
+                    | crate::EncodeResult::Incomplete
+                    | crate::EncodeResult::Empty => return Err("invalid character replacement"),
+                };
+                chars = chars.get(len..).unwrap_or_default();
+            }
+            if emit {
+                *state = next;
+                return Ok(());
+            }
+            emit = true;
+        }
+    }
+
+    /// Encode all of the given characters, starting from and returning to the initial state.
+    ///
+    /// This is the stateful counterpart of [`CharsetEncoding::encode_all`], which it uses for stateless charsets.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a character can not be encoded and `invalid` does not provide a replacement that can be encoded.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    #[inline]
+    fn encode_all_stateful<'a>(
+        &self,
+        chars: &'a str,
+        invalid: crate::EncodeInvalid,
+    ) -> Result<bytedata::ByteData<'a>, &'static str> {
+        crate::bulk::encode_all(self, chars, invalid)
+    }
+
+    /// Encode all of the given characters, starting from and returning to the initial state, appending the bytes to `out`.
+    ///
+    /// This is the stateful counterpart of [`CharsetEncoding::encode_to_vec`], which it uses for stateless charsets.
+    /// The vector is left unchanged if an error is returned.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a character can not be encoded and `invalid` does not provide a replacement that can be encoded.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    #[inline]
+    fn encode_to_vec_stateful(
+        &self,
+        chars: &str,
+        invalid: crate::EncodeInvalid,
+        out: &mut alloc::vec::Vec<u8>,
+    ) -> Result<(), &'static str> {
+        crate::bulk::encode_to_vec(self, chars, invalid, out)
+    }
+}
+
+impl<T: CharsetEncoding + ?Sized> CharsetStatefulEncoding for T {
+    #[inline]
+    fn encode_stateful(&self, _state: &mut CharsetState, chars: &str) -> crate::EncodeResult {
+        self.encode(chars)
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn encode_all_stateful<'a>(
+        &self,
+        chars: &'a str,
+        invalid: crate::EncodeInvalid,
+    ) -> Result<bytedata::ByteData<'a>, &'static str> {
+        self.encode_all(chars, invalid)
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn encode_to_vec_stateful(
+        &self,
+        chars: &str,
+        invalid: crate::EncodeInvalid,
+        out: &mut alloc::vec::Vec<u8>,
+    ) -> Result<(), &'static str> {
+        self.encode_to_vec(chars, invalid, out)
+    }
+}
+
+/// A charset that can both decode and encode characters.
+///
+/// This is implemented for every type that implements both [`CharsetStatefulDecoding`] and [`CharsetStatefulEncoding`] and allows for a single trait object to do both.
+/// As those are implemented for all stateless charsets, this covers every charset in the crate.
+pub trait CharsetCodec: CharsetStatefulDecoding + CharsetStatefulEncoding {}
+
+impl<T: CharsetStatefulDecoding + CharsetStatefulEncoding + ?Sized> CharsetCodec for T {}
+
+impl<'a> CharsetRef for &'a (dyn CharsetCodec + 'a) {
+    #[inline]
+    fn charset_name(&self) -> &str {
+        (**self).charset_name()
+    }
+
+    #[inline]
+    fn charset_alias(&self) -> &[&'static str] {
+        (**self).charset_alias()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (u16, u16) {
+        (**self).size_hint()
+    }
+}
+
+impl<'a> CharsetStatefulDecoding for &'a (dyn CharsetCodec + 'a) {
+    #[inline]
+    fn decode_stateful(&self, state: &mut CharsetState, bytes: &[u8]) -> crate::DecodeResult {
+        (**self).decode_stateful(state, bytes)
+    }
+
+    #[inline]
+    fn decode_finish(&self, state: &mut CharsetState, bytes: &[u8]) -> crate::DecodeResult {
+        (**self).decode_finish(state, bytes)
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn decode_all_stateful<'b>(
+        &self,
+        bytes: bytedata::ByteData<'b>,
+        invalid: crate::DecodeInvalid,
+    ) -> Result<bytedata::StringData<'b>, crate::DecodeError> {
+        (**self).decode_all_stateful(bytes, invalid)
+    }
 }
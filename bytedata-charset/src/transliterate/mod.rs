@@ -0,0 +1,69 @@
+mod table;
+
+use table::TRANSLITERATE;
+
+/// Get an ASCII approximation of a character, such as `e` for `é`, `ss` for `ß` and `EUR` for `€`.
+///
+/// The table is generated from the Unicode decompositions of the characters with combining marks removed,
+/// completed by approximations of common characters that have no decomposition such as typographic quotes and dashes.
+/// Returns `None` for ASCII characters and characters without an approximation.
+///
+/// See [`EncodeInvalid::Transliterate`] for using this when encoding to a charset.
+///
+/// [`EncodeInvalid::Transliterate`]: crate::EncodeInvalid::Transliterate
+#[inline]
+#[must_use]
+#[cfg_attr(docsrs, doc(cfg(feature = "transliterate")))]
+pub const fn transliterate(ch: char) -> Option<&'static str> {
+    let ch = ch as u32;
+    let mut low = 0;
+    let mut high = TRANSLITERATE.len();
+    while low < high {
+        let mid = low + ((high - low) >> 1_u32);
+        let (key, text) = TRANSLITERATE[mid];
+        let key = key as u32;
+        if key == ch {
+            return Some(text);
+        }
+        if key < ch {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_table_sorted() {
+        assert!(TRANSLITERATE
+            .iter()
+            .zip(TRANSLITERATE.iter().skip(1))
+            .all(|(first, second)| first.0 < second.0));
+        assert!(TRANSLITERATE
+            .iter()
+            .all(|&(ch, text)| !ch.is_ascii() && text.is_ascii() && !text.is_empty()));
+    }
+
+    #[test]
+    fn test_transliterate() {
+        assert_eq!(transliterate('a'), None);
+        assert_eq!(transliterate('\u{E9}'), Some("e"));
+        assert_eq!(transliterate('\u{C5}'), Some("A"));
+        assert_eq!(transliterate('\u{1EA0}'), Some("A"));
+        assert_eq!(transliterate('\u{DF}'), Some("ss"));
+        assert_eq!(transliterate('\u{1E2}'), Some("AE"));
+        assert_eq!(transliterate('\u{201C}'), Some("\""));
+        assert_eq!(transliterate('\u{2019}'), Some("'"));
+        assert_eq!(transliterate('\u{20AC}'), Some("EUR"));
+        assert_eq!(transliterate('\u{BD}'), Some("1/2"));
+        assert_eq!(transliterate('\u{FB01}'), Some("fi"));
+        assert_eq!(transliterate('\u{2026}'), Some("..."));
+        assert_eq!(transliterate('\u{A8}'), None);
+        assert_eq!(transliterate('\u{3042}'), None);
+    }
+}
@@ -0,0 +1,1221 @@
+// Generated by tools/gen_transliterate.rs from the Unicode NamesList.txt, do not edit.
+
+/// The transliterations sorted by character.
+pub(super) static TRANSLITERATE: [(char, &str); 1216] = [
+    ('\u{00A0}', " "),
+    ('\u{00A1}', "!"),
+    ('\u{00A2}', "c"),
+    ('\u{00A9}', "(C)"),
+    ('\u{00AA}', "a"),
+    ('\u{00AB}', "<<"),
+    ('\u{00AD}', "-"),
+    ('\u{00AE}', "(R)"),
+    ('\u{00B2}', "2"),
+    ('\u{00B3}', "3"),
+    ('\u{00B7}', "."),
+    ('\u{00B9}', "1"),
+    ('\u{00BA}', "o"),
+    ('\u{00BB}', ">>"),
+    ('\u{00BC}', "1/4"),
+    ('\u{00BD}', "1/2"),
+    ('\u{00BE}', "3/4"),
+    ('\u{00BF}', "?"),
+    ('\u{00C0}', "A"),
+    ('\u{00C1}', "A"),
+    ('\u{00C2}', "A"),
+    ('\u{00C3}', "A"),
+    ('\u{00C4}', "A"),
+    ('\u{00C5}', "A"),
+    ('\u{00C6}', "AE"),
+    ('\u{00C7}', "C"),
+    ('\u{00C8}', "E"),
+    ('\u{00C9}', "E"),
+    ('\u{00CA}', "E"),
+    ('\u{00CB}', "E"),
+    ('\u{00CC}', "I"),
+    ('\u{00CD}', "I"),
+    ('\u{00CE}', "I"),
+    ('\u{00CF}', "I"),
+    ('\u{00D0}', "D"),
+    ('\u{00D1}', "N"),
+    ('\u{00D2}', "O"),
+    ('\u{00D3}', "O"),
+    ('\u{00D4}', "O"),
+    ('\u{00D5}', "O"),
+    ('\u{00D6}', "O"),
+    ('\u{00D7}', "x"),
+    ('\u{00D8}', "O"),
+    ('\u{00D9}', "U"),
+    ('\u{00DA}', "U"),
+    ('\u{00DB}', "U"),
+    ('\u{00DC}', "U"),
+    ('\u{00DD}', "Y"),
+    ('\u{00DE}', "TH"),
+    ('\u{00DF}', "ss"),
+    ('\u{00E0}', "a"),
+    ('\u{00E1}', "a"),
+    ('\u{00E2}', "a"),
+    ('\u{00E3}', "a"),
+    ('\u{00E4}', "a"),
+    ('\u{00E5}', "a"),
+    ('\u{00E6}', "ae"),
+    ('\u{00E7}', "c"),
+    ('\u{00E8}', "e"),
+    ('\u{00E9}', "e"),
+    ('\u{00EA}', "e"),
+    ('\u{00EB}', "e"),
+    ('\u{00EC}', "i"),
+    ('\u{00ED}', "i"),
+    ('\u{00EE}', "i"),
+    ('\u{00EF}', "i"),
+    ('\u{00F0}', "d"),
+    ('\u{00F1}', "n"),
+    ('\u{00F2}', "o"),
+    ('\u{00F3}', "o"),
+    ('\u{00F4}', "o"),
+    ('\u{00F5}', "o"),
+    ('\u{00F6}', "o"),
+    ('\u{00F7}', "/"),
+    ('\u{00F8}', "o"),
+    ('\u{00F9}', "u"),
+    ('\u{00FA}', "u"),
+    ('\u{00FB}', "u"),
+    ('\u{00FC}', "u"),
+    ('\u{00FD}', "y"),
+    ('\u{00FE}', "th"),
+    ('\u{00FF}', "y"),
+    ('\u{0100}', "A"),
+    ('\u{0101}', "a"),
+    ('\u{0102}', "A"),
+    ('\u{0103}', "a"),
+    ('\u{0104}', "A"),
+    ('\u{0105}', "a"),
+    ('\u{0106}', "C"),
+    ('\u{0107}', "c"),
+    ('\u{0108}', "C"),
+    ('\u{0109}', "c"),
+    ('\u{010A}', "C"),
+    ('\u{010B}', "c"),
+    ('\u{010C}', "C"),
+    ('\u{010D}', "c"),
+    ('\u{010E}', "D"),
+    ('\u{010F}', "d"),
+    ('\u{0110}', "D"),
+    ('\u{0111}', "d"),
+    ('\u{0112}', "E"),
+    ('\u{0113}', "e"),
+    ('\u{0114}', "E"),
+    ('\u{0115}', "e"),
+    ('\u{0116}', "E"),
+    ('\u{0117}', "e"),
+    ('\u{0118}', "E"),
+    ('\u{0119}', "e"),
+    ('\u{011A}', "E"),
+    ('\u{011B}', "e"),
+    ('\u{011C}', "G"),
+    ('\u{011D}', "g"),
+    ('\u{011E}', "G"),
+    ('\u{011F}', "g"),
+    ('\u{0120}', "G"),
+    ('\u{0121}', "g"),
+    ('\u{0122}', "G"),
+    ('\u{0123}', "g"),
+    ('\u{0124}', "H"),
+    ('\u{0125}', "h"),
+    ('\u{0126}', "H"),
+    ('\u{0127}', "h"),
+    ('\u{0128}', "I"),
+    ('\u{0129}', "i"),
+    ('\u{012A}', "I"),
+    ('\u{012B}', "i"),
+    ('\u{012C}', "I"),
+    ('\u{012D}', "i"),
+    ('\u{012E}', "I"),
+    ('\u{012F}', "i"),
+    ('\u{0130}', "I"),
+    ('\u{0131}', "i"),
+    ('\u{0132}', "IJ"),
+    ('\u{0133}', "ij"),
+    ('\u{0134}', "J"),
+    ('\u{0135}', "j"),
+    ('\u{0136}', "K"),
+    ('\u{0137}', "k"),
+    ('\u{0139}', "L"),
+    ('\u{013A}', "l"),
+    ('\u{013B}', "L"),
+    ('\u{013C}', "l"),
+    ('\u{013D}', "L"),
+    ('\u{013E}', "l"),
+    ('\u{013F}', "L."),
+    ('\u{0140}', "l."),
+    ('\u{0141}', "L"),
+    ('\u{0142}', "l"),
+    ('\u{0143}', "N"),
+    ('\u{0144}', "n"),
+    ('\u{0145}', "N"),
+    ('\u{0146}', "n"),
+    ('\u{0147}', "N"),
+    ('\u{0148}', "n"),
+    ('\u{014C}', "O"),
+    ('\u{014D}', "o"),
+    ('\u{014E}', "O"),
+    ('\u{014F}', "o"),
+    ('\u{0150}', "O"),
+    ('\u{0151}', "o"),
+    ('\u{0152}', "OE"),
+    ('\u{0153}', "oe"),
+    ('\u{0154}', "R"),
+    ('\u{0155}', "r"),
+    ('\u{0156}', "R"),
+    ('\u{0157}', "r"),
+    ('\u{0158}', "R"),
+    ('\u{0159}', "r"),
+    ('\u{015A}', "S"),
+    ('\u{015B}', "s"),
+    ('\u{015C}', "S"),
+    ('\u{015D}', "s"),
+    ('\u{015E}', "S"),
+    ('\u{015F}', "s"),
+    ('\u{0160}', "S"),
+    ('\u{0161}', "s"),
+    ('\u{0162}', "T"),
+    ('\u{0163}', "t"),
+    ('\u{0164}', "T"),
+    ('\u{0165}', "t"),
+    ('\u{0166}', "T"),
+    ('\u{0167}', "t"),
+    ('\u{0168}', "U"),
+    ('\u{0169}', "u"),
+    ('\u{016A}', "U"),
+    ('\u{016B}', "u"),
+    ('\u{016C}', "U"),
+    ('\u{016D}', "u"),
+    ('\u{016E}', "U"),
+    ('\u{016F}', "u"),
+    ('\u{0170}', "U"),
+    ('\u{0171}', "u"),
+    ('\u{0172}', "U"),
+    ('\u{0173}', "u"),
+    ('\u{0174}', "W"),
+    ('\u{0175}', "w"),
+    ('\u{0176}', "Y"),
+    ('\u{0177}', "y"),
+    ('\u{0178}', "Y"),
+    ('\u{0179}', "Z"),
+    ('\u{017A}', "z"),
+    ('\u{017B}', "Z"),
+    ('\u{017C}', "z"),
+    ('\u{017D}', "Z"),
+    ('\u{017E}', "z"),
+    ('\u{0180}', "b"),
+    ('\u{0192}', "f"),
+    ('\u{01A0}', "O"),
+    ('\u{01A1}', "o"),
+    ('\u{01AF}', "U"),
+    ('\u{01B0}', "u"),
+    ('\u{01C4}', "DZ"),
+    ('\u{01C5}', "Dz"),
+    ('\u{01C6}', "dz"),
+    ('\u{01C7}', "LJ"),
+    ('\u{01C8}', "Lj"),
+    ('\u{01C9}', "lj"),
+    ('\u{01CA}', "NJ"),
+    ('\u{01CB}', "Nj"),
+    ('\u{01CC}', "nj"),
+    ('\u{01CD}', "A"),
+    ('\u{01CE}', "a"),
+    ('\u{01CF}', "I"),
+    ('\u{01D0}', "i"),
+    ('\u{01D1}', "O"),
+    ('\u{01D2}', "o"),
+    ('\u{01D3}', "U"),
+    ('\u{01D4}', "u"),
+    ('\u{01D5}', "U"),
+    ('\u{01D6}', "u"),
+    ('\u{01D7}', "U"),
+    ('\u{01D8}', "u"),
+    ('\u{01D9}', "U"),
+    ('\u{01DA}', "u"),
+    ('\u{01DB}', "U"),
+    ('\u{01DC}', "u"),
+    ('\u{01DE}', "A"),
+    ('\u{01DF}', "a"),
+    ('\u{01E0}', "A"),
+    ('\u{01E1}', "a"),
+    ('\u{01E2}', "AE"),
+    ('\u{01E3}', "ae"),
+    ('\u{01E6}', "G"),
+    ('\u{01E7}', "g"),
+    ('\u{01E8}', "K"),
+    ('\u{01E9}', "k"),
+    ('\u{01EA}', "O"),
+    ('\u{01EB}', "o"),
+    ('\u{01EC}', "O"),
+    ('\u{01ED}', "o"),
+    ('\u{01F0}', "j"),
+    ('\u{01F1}', "DZ"),
+    ('\u{01F2}', "Dz"),
+    ('\u{01F3}', "dz"),
+    ('\u{01F4}', "G"),
+    ('\u{01F5}', "g"),
+    ('\u{01F8}', "N"),
+    ('\u{01F9}', "n"),
+    ('\u{01FA}', "A"),
+    ('\u{01FB}', "a"),
+    ('\u{01FC}', "AE"),
+    ('\u{01FD}', "ae"),
+    ('\u{01FE}', "O"),
+    ('\u{01FF}', "o"),
+    ('\u{0200}', "A"),
+    ('\u{0201}', "a"),
+    ('\u{0202}', "A"),
+    ('\u{0203}', "a"),
+    ('\u{0204}', "E"),
+    ('\u{0205}', "e"),
+    ('\u{0206}', "E"),
+    ('\u{0207}', "e"),
+    ('\u{0208}', "I"),
+    ('\u{0209}', "i"),
+    ('\u{020A}', "I"),
+    ('\u{020B}', "i"),
+    ('\u{020C}', "O"),
+    ('\u{020D}', "o"),
+    ('\u{020E}', "O"),
+    ('\u{020F}', "o"),
+    ('\u{0210}', "R"),
+    ('\u{0211}', "r"),
+    ('\u{0212}', "R"),
+    ('\u{0213}', "r"),
+    ('\u{0214}', "U"),
+    ('\u{0215}', "u"),
+    ('\u{0216}', "U"),
+    ('\u{0217}', "u"),
+    ('\u{0218}', "S"),
+    ('\u{0219}', "s"),
+    ('\u{021A}', "T"),
+    ('\u{021B}', "t"),
+    ('\u{021E}', "H"),
+    ('\u{021F}', "h"),
+    ('\u{0226}', "A"),
+    ('\u{0227}', "a"),
+    ('\u{0228}', "E"),
+    ('\u{0229}', "e"),
+    ('\u{022A}', "O"),
+    ('\u{022B}', "o"),
+    ('\u{022C}', "O"),
+    ('\u{022D}', "o"),
+    ('\u{022E}', "O"),
+    ('\u{022F}', "o"),
+    ('\u{0230}', "O"),
+    ('\u{0231}', "o"),
+    ('\u{0232}', "Y"),
+    ('\u{0233}', "y"),
+    ('\u{0237}', "j"),
+    ('\u{02B0}', "h"),
+    ('\u{02B2}', "j"),
+    ('\u{02B3}', "r"),
+    ('\u{02B7}', "w"),
+    ('\u{02B8}', "y"),
+    ('\u{02E1}', "l"),
+    ('\u{02E2}', "s"),
+    ('\u{02E3}', "x"),
+    ('\u{1D2C}', "A"),
+    ('\u{1D2D}', "AE"),
+    ('\u{1D2E}', "B"),
+    ('\u{1D30}', "D"),
+    ('\u{1D31}', "E"),
+    ('\u{1D33}', "G"),
+    ('\u{1D34}', "H"),
+    ('\u{1D35}', "I"),
+    ('\u{1D36}', "J"),
+    ('\u{1D37}', "K"),
+    ('\u{1D38}', "L"),
+    ('\u{1D39}', "M"),
+    ('\u{1D3A}', "N"),
+    ('\u{1D3C}', "O"),
+    ('\u{1D3E}', "P"),
+    ('\u{1D3F}', "R"),
+    ('\u{1D40}', "T"),
+    ('\u{1D41}', "U"),
+    ('\u{1D42}', "W"),
+    ('\u{1D43}', "a"),
+    ('\u{1D47}', "b"),
+    ('\u{1D48}', "d"),
+    ('\u{1D49}', "e"),
+    ('\u{1D4D}', "g"),
+    ('\u{1D4F}', "k"),
+    ('\u{1D50}', "m"),
+    ('\u{1D52}', "o"),
+    ('\u{1D56}', "p"),
+    ('\u{1D57}', "t"),
+    ('\u{1D58}', "u"),
+    ('\u{1D5B}', "v"),
+    ('\u{1D62}', "i"),
+    ('\u{1D63}', "r"),
+    ('\u{1D64}', "u"),
+    ('\u{1D65}', "v"),
+    ('\u{1D9C}', "c"),
+    ('\u{1D9E}', "d"),
+    ('\u{1DA0}', "f"),
+    ('\u{1DBB}', "z"),
+    ('\u{1E00}', "A"),
+    ('\u{1E01}', "a"),
+    ('\u{1E02}', "B"),
+    ('\u{1E03}', "b"),
+    ('\u{1E04}', "B"),
+    ('\u{1E05}', "b"),
+    ('\u{1E06}', "B"),
+    ('\u{1E07}', "b"),
+    ('\u{1E08}', "C"),
+    ('\u{1E09}', "c"),
+    ('\u{1E0A}', "D"),
+    ('\u{1E0B}', "d"),
+    ('\u{1E0C}', "D"),
+    ('\u{1E0D}', "d"),
+    ('\u{1E0E}', "D"),
+    ('\u{1E0F}', "d"),
+    ('\u{1E10}', "D"),
+    ('\u{1E11}', "d"),
+    ('\u{1E12}', "D"),
+    ('\u{1E13}', "d"),
+    ('\u{1E14}', "E"),
+    ('\u{1E15}', "e"),
+    ('\u{1E16}', "E"),
+    ('\u{1E17}', "e"),
+    ('\u{1E18}', "E"),
+    ('\u{1E19}', "e"),
+    ('\u{1E1A}', "E"),
+    ('\u{1E1B}', "e"),
+    ('\u{1E1C}', "E"),
+    ('\u{1E1D}', "e"),
+    ('\u{1E1E}', "F"),
+    ('\u{1E1F}', "f"),
+    ('\u{1E20}', "G"),
+    ('\u{1E21}', "g"),
+    ('\u{1E22}', "H"),
+    ('\u{1E23}', "h"),
+    ('\u{1E24}', "H"),
+    ('\u{1E25}', "h"),
+    ('\u{1E26}', "H"),
+    ('\u{1E27}', "h"),
+    ('\u{1E28}', "H"),
+    ('\u{1E29}', "h"),
+    ('\u{1E2A}', "H"),
+    ('\u{1E2B}', "h"),
+    ('\u{1E2C}', "I"),
+    ('\u{1E2D}', "i"),
+    ('\u{1E2E}', "I"),
+    ('\u{1E2F}', "i"),
+    ('\u{1E30}', "K"),
+    ('\u{1E31}', "k"),
+    ('\u{1E32}', "K"),
+    ('\u{1E33}', "k"),
+    ('\u{1E34}', "K"),
+    ('\u{1E35}', "k"),
+    ('\u{1E36}', "L"),
+    ('\u{1E37}', "l"),
+    ('\u{1E38}', "L"),
+    ('\u{1E39}', "l"),
+    ('\u{1E3A}', "L"),
+    ('\u{1E3B}', "l"),
+    ('\u{1E3C}', "L"),
+    ('\u{1E3D}', "l"),
+    ('\u{1E3E}', "M"),
+    ('\u{1E3F}', "m"),
+    ('\u{1E40}', "M"),
+    ('\u{1E41}', "m"),
+    ('\u{1E42}', "M"),
+    ('\u{1E43}', "m"),
+    ('\u{1E44}', "N"),
+    ('\u{1E45}', "n"),
+    ('\u{1E46}', "N"),
+    ('\u{1E47}', "n"),
+    ('\u{1E48}', "N"),
+    ('\u{1E49}', "n"),
+    ('\u{1E4A}', "N"),
+    ('\u{1E4B}', "n"),
+    ('\u{1E4C}', "O"),
+    ('\u{1E4D}', "o"),
+    ('\u{1E4E}', "O"),
+    ('\u{1E4F}', "o"),
+    ('\u{1E50}', "O"),
+    ('\u{1E51}', "o"),
+    ('\u{1E52}', "O"),
+    ('\u{1E53}', "o"),
+    ('\u{1E54}', "P"),
+    ('\u{1E55}', "p"),
+    ('\u{1E56}', "P"),
+    ('\u{1E57}', "p"),
+    ('\u{1E58}', "R"),
+    ('\u{1E59}', "r"),
+    ('\u{1E5A}', "R"),
+    ('\u{1E5B}', "r"),
+    ('\u{1E5C}', "R"),
+    ('\u{1E5D}', "r"),
+    ('\u{1E5E}', "R"),
+    ('\u{1E5F}', "r"),
+    ('\u{1E60}', "S"),
+    ('\u{1E61}', "s"),
+    ('\u{1E62}', "S"),
+    ('\u{1E63}', "s"),
+    ('\u{1E64}', "S"),
+    ('\u{1E65}', "s"),
+    ('\u{1E66}', "S"),
+    ('\u{1E67}', "s"),
+    ('\u{1E68}', "S"),
+    ('\u{1E69}', "s"),
+    ('\u{1E6A}', "T"),
+    ('\u{1E6B}', "t"),
+    ('\u{1E6C}', "T"),
+    ('\u{1E6D}', "t"),
+    ('\u{1E6E}', "T"),
+    ('\u{1E6F}', "t"),
+    ('\u{1E70}', "T"),
+    ('\u{1E71}', "t"),
+    ('\u{1E72}', "U"),
+    ('\u{1E73}', "u"),
+    ('\u{1E74}', "U"),
+    ('\u{1E75}', "u"),
+    ('\u{1E76}', "U"),
+    ('\u{1E77}', "u"),
+    ('\u{1E78}', "U"),
+    ('\u{1E79}', "u"),
+    ('\u{1E7A}', "U"),
+    ('\u{1E7B}', "u"),
+    ('\u{1E7C}', "V"),
+    ('\u{1E7D}', "v"),
+    ('\u{1E7E}', "V"),
+    ('\u{1E7F}', "v"),
+    ('\u{1E80}', "W"),
+    ('\u{1E81}', "w"),
+    ('\u{1E82}', "W"),
+    ('\u{1E83}', "w"),
+    ('\u{1E84}', "W"),
+    ('\u{1E85}', "w"),
+    ('\u{1E86}', "W"),
+    ('\u{1E87}', "w"),
+    ('\u{1E88}', "W"),
+    ('\u{1E89}', "w"),
+    ('\u{1E8A}', "X"),
+    ('\u{1E8B}', "x"),
+    ('\u{1E8C}', "X"),
+    ('\u{1E8D}', "x"),
+    ('\u{1E8E}', "Y"),
+    ('\u{1E8F}', "y"),
+    ('\u{1E90}', "Z"),
+    ('\u{1E91}', "z"),
+    ('\u{1E92}', "Z"),
+    ('\u{1E93}', "z"),
+    ('\u{1E94}', "Z"),
+    ('\u{1E95}', "z"),
+    ('\u{1E96}', "h"),
+    ('\u{1E97}', "t"),
+    ('\u{1E98}', "w"),
+    ('\u{1E99}', "y"),
+    ('\u{1E9E}', "SS"),
+    ('\u{1EA0}', "A"),
+    ('\u{1EA1}', "a"),
+    ('\u{1EA2}', "A"),
+    ('\u{1EA3}', "a"),
+    ('\u{1EA4}', "A"),
+    ('\u{1EA5}', "a"),
+    ('\u{1EA6}', "A"),
+    ('\u{1EA7}', "a"),
+    ('\u{1EA8}', "A"),
+    ('\u{1EA9}', "a"),
+    ('\u{1EAA}', "A"),
+    ('\u{1EAB}', "a"),
+    ('\u{1EAC}', "A"),
+    ('\u{1EAD}', "a"),
+    ('\u{1EAE}', "A"),
+    ('\u{1EAF}', "a"),
+    ('\u{1EB0}', "A"),
+    ('\u{1EB1}', "a"),
+    ('\u{1EB2}', "A"),
+    ('\u{1EB3}', "a"),
+    ('\u{1EB4}', "A"),
+    ('\u{1EB5}', "a"),
+    ('\u{1EB6}', "A"),
+    ('\u{1EB7}', "a"),
+    ('\u{1EB8}', "E"),
+    ('\u{1EB9}', "e"),
+    ('\u{1EBA}', "E"),
+    ('\u{1EBB}', "e"),
+    ('\u{1EBC}', "E"),
+    ('\u{1EBD}', "e"),
+    ('\u{1EBE}', "E"),
+    ('\u{1EBF}', "e"),
+    ('\u{1EC0}', "E"),
+    ('\u{1EC1}', "e"),
+    ('\u{1EC2}', "E"),
+    ('\u{1EC3}', "e"),
+    ('\u{1EC4}', "E"),
+    ('\u{1EC5}', "e"),
+    ('\u{1EC6}', "E"),
+    ('\u{1EC7}', "e"),
+    ('\u{1EC8}', "I"),
+    ('\u{1EC9}', "i"),
+    ('\u{1ECA}', "I"),
+    ('\u{1ECB}', "i"),
+    ('\u{1ECC}', "O"),
+    ('\u{1ECD}', "o"),
+    ('\u{1ECE}', "O"),
+    ('\u{1ECF}', "o"),
+    ('\u{1ED0}', "O"),
+    ('\u{1ED1}', "o"),
+    ('\u{1ED2}', "O"),
+    ('\u{1ED3}', "o"),
+    ('\u{1ED4}', "O"),
+    ('\u{1ED5}', "o"),
+    ('\u{1ED6}', "O"),
+    ('\u{1ED7}', "o"),
+    ('\u{1ED8}', "O"),
+    ('\u{1ED9}', "o"),
+    ('\u{1EDA}', "O"),
+    ('\u{1EDB}', "o"),
+    ('\u{1EDC}', "O"),
+    ('\u{1EDD}', "o"),
+    ('\u{1EDE}', "O"),
+    ('\u{1EDF}', "o"),
+    ('\u{1EE0}', "O"),
+    ('\u{1EE1}', "o"),
+    ('\u{1EE2}', "O"),
+    ('\u{1EE3}', "o"),
+    ('\u{1EE4}', "U"),
+    ('\u{1EE5}', "u"),
+    ('\u{1EE6}', "U"),
+    ('\u{1EE7}', "u"),
+    ('\u{1EE8}', "U"),
+    ('\u{1EE9}', "u"),
+    ('\u{1EEA}', "U"),
+    ('\u{1EEB}', "u"),
+    ('\u{1EEC}', "U"),
+    ('\u{1EED}', "u"),
+    ('\u{1EEE}', "U"),
+    ('\u{1EEF}', "u"),
+    ('\u{1EF0}', "U"),
+    ('\u{1EF1}', "u"),
+    ('\u{1EF2}', "Y"),
+    ('\u{1EF3}', "y"),
+    ('\u{1EF4}', "Y"),
+    ('\u{1EF5}', "y"),
+    ('\u{1EF6}', "Y"),
+    ('\u{1EF7}', "y"),
+    ('\u{1EF8}', "Y"),
+    ('\u{1EF9}', "y"),
+    ('\u{2007}', " "),
+    ('\u{2010}', "-"),
+    ('\u{2011}', "-"),
+    ('\u{2012}', "-"),
+    ('\u{2013}', "-"),
+    ('\u{2014}', "-"),
+    ('\u{2015}', "-"),
+    ('\u{2018}', "'"),
+    ('\u{2019}', "'"),
+    ('\u{201A}', "'"),
+    ('\u{201B}', "'"),
+    ('\u{201C}', "\""),
+    ('\u{201D}', "\""),
+    ('\u{201E}', "\""),
+    ('\u{201F}', "\""),
+    ('\u{2022}', "*"),
+    ('\u{2025}', ".."),
+    ('\u{2026}', "..."),
+    ('\u{202F}', " "),
+    ('\u{2032}', "'"),
+    ('\u{2033}', "\""),
+    ('\u{2034}', "'''"),
+    ('\u{2039}', "<"),
+    ('\u{203A}', ">"),
+    ('\u{203C}', "!!"),
+    ('\u{2044}', "/"),
+    ('\u{2047}', "??"),
+    ('\u{2048}', "?!"),
+    ('\u{2049}', "!?"),
+    ('\u{2057}', "''''"),
+    ('\u{2070}', "0"),
+    ('\u{2071}', "i"),
+    ('\u{2074}', "4"),
+    ('\u{2075}', "5"),
+    ('\u{2076}', "6"),
+    ('\u{2077}', "7"),
+    ('\u{2078}', "8"),
+    ('\u{2079}', "9"),
+    ('\u{207A}', "+"),
+    ('\u{207B}', "-"),
+    ('\u{207C}', "="),
+    ('\u{207D}', "("),
+    ('\u{207E}', ")"),
+    ('\u{207F}', "n"),
+    ('\u{2080}', "0"),
+    ('\u{2081}', "1"),
+    ('\u{2082}', "2"),
+    ('\u{2083}', "3"),
+    ('\u{2084}', "4"),
+    ('\u{2085}', "5"),
+    ('\u{2086}', "6"),
+    ('\u{2087}', "7"),
+    ('\u{2088}', "8"),
+    ('\u{2089}', "9"),
+    ('\u{208A}', "+"),
+    ('\u{208B}', "-"),
+    ('\u{208C}', "="),
+    ('\u{208D}', "("),
+    ('\u{208E}', ")"),
+    ('\u{2090}', "a"),
+    ('\u{2091}', "e"),
+    ('\u{2092}', "o"),
+    ('\u{2093}', "x"),
+    ('\u{2095}', "h"),
+    ('\u{2096}', "k"),
+    ('\u{2097}', "l"),
+    ('\u{2098}', "m"),
+    ('\u{2099}', "n"),
+    ('\u{209A}', "p"),
+    ('\u{209B}', "s"),
+    ('\u{209C}', "t"),
+    ('\u{20A8}', "Rs"),
+    ('\u{20AC}', "EUR"),
+    ('\u{2100}', "a/c"),
+    ('\u{2101}', "a/s"),
+    ('\u{2105}', "c/o"),
+    ('\u{2106}', "c/u"),
+    ('\u{2116}', "No"),
+    ('\u{2120}', "SM"),
+    ('\u{2121}', "TEL"),
+    ('\u{2122}', "TM"),
+    ('\u{213B}', "FAX"),
+    ('\u{2150}', "1/7"),
+    ('\u{2151}', "1/9"),
+    ('\u{2152}', "1/10"),
+    ('\u{2153}', "1/3"),
+    ('\u{2154}', "2/3"),
+    ('\u{2155}', "1/5"),
+    ('\u{2156}', "2/5"),
+    ('\u{2157}', "3/5"),
+    ('\u{2158}', "4/5"),
+    ('\u{2159}', "1/6"),
+    ('\u{215A}', "5/6"),
+    ('\u{215B}', "1/8"),
+    ('\u{215C}', "3/8"),
+    ('\u{215D}', "5/8"),
+    ('\u{215E}', "7/8"),
+    ('\u{215F}', "1/"),
+    ('\u{2161}', "II"),
+    ('\u{2162}', "III"),
+    ('\u{2163}', "IV"),
+    ('\u{2165}', "VI"),
+    ('\u{2166}', "VII"),
+    ('\u{2167}', "VIII"),
+    ('\u{2168}', "IX"),
+    ('\u{216A}', "XI"),
+    ('\u{216B}', "XII"),
+    ('\u{2171}', "ii"),
+    ('\u{2172}', "iii"),
+    ('\u{2173}', "iv"),
+    ('\u{2175}', "vi"),
+    ('\u{2176}', "vii"),
+    ('\u{2177}', "viii"),
+    ('\u{2178}', "ix"),
+    ('\u{217A}', "xi"),
+    ('\u{217B}', "xii"),
+    ('\u{2189}', "0/3"),
+    ('\u{2212}', "-"),
+    ('\u{2260}', "="),
+    ('\u{226E}', "<"),
+    ('\u{226F}', ">"),
+    ('\u{2460}', "1"),
+    ('\u{2461}', "2"),
+    ('\u{2462}', "3"),
+    ('\u{2463}', "4"),
+    ('\u{2464}', "5"),
+    ('\u{2465}', "6"),
+    ('\u{2466}', "7"),
+    ('\u{2467}', "8"),
+    ('\u{2468}', "9"),
+    ('\u{2469}', "10"),
+    ('\u{246A}', "11"),
+    ('\u{246B}', "12"),
+    ('\u{246C}', "13"),
+    ('\u{246D}', "14"),
+    ('\u{246E}', "15"),
+    ('\u{246F}', "16"),
+    ('\u{2470}', "17"),
+    ('\u{2471}', "18"),
+    ('\u{2472}', "19"),
+    ('\u{2473}', "20"),
+    ('\u{2474}', "(1)"),
+    ('\u{2475}', "(2)"),
+    ('\u{2476}', "(3)"),
+    ('\u{2477}', "(4)"),
+    ('\u{2478}', "(5)"),
+    ('\u{2479}', "(6)"),
+    ('\u{247A}', "(7)"),
+    ('\u{247B}', "(8)"),
+    ('\u{247C}', "(9)"),
+    ('\u{247D}', "(10)"),
+    ('\u{247E}', "(11)"),
+    ('\u{247F}', "(12)"),
+    ('\u{2480}', "(13)"),
+    ('\u{2481}', "(14)"),
+    ('\u{2482}', "(15)"),
+    ('\u{2483}', "(16)"),
+    ('\u{2484}', "(17)"),
+    ('\u{2485}', "(18)"),
+    ('\u{2486}', "(19)"),
+    ('\u{2487}', "(20)"),
+    ('\u{2488}', "1."),
+    ('\u{2489}', "2."),
+    ('\u{248A}', "3."),
+    ('\u{248B}', "4."),
+    ('\u{248C}', "5."),
+    ('\u{248D}', "6."),
+    ('\u{248E}', "7."),
+    ('\u{248F}', "8."),
+    ('\u{2490}', "9."),
+    ('\u{2491}', "10."),
+    ('\u{2492}', "11."),
+    ('\u{2493}', "12."),
+    ('\u{2494}', "13."),
+    ('\u{2495}', "14."),
+    ('\u{2496}', "15."),
+    ('\u{2497}', "16."),
+    ('\u{2498}', "17."),
+    ('\u{2499}', "18."),
+    ('\u{249A}', "19."),
+    ('\u{249B}', "20."),
+    ('\u{249C}', "(a)"),
+    ('\u{249D}', "(b)"),
+    ('\u{249E}', "(c)"),
+    ('\u{249F}', "(d)"),
+    ('\u{24A0}', "(e)"),
+    ('\u{24A1}', "(f)"),
+    ('\u{24A2}', "(g)"),
+    ('\u{24A3}', "(h)"),
+    ('\u{24A4}', "(i)"),
+    ('\u{24A5}', "(j)"),
+    ('\u{24A6}', "(k)"),
+    ('\u{24A7}', "(l)"),
+    ('\u{24A8}', "(m)"),
+    ('\u{24A9}', "(n)"),
+    ('\u{24AA}', "(o)"),
+    ('\u{24AB}', "(p)"),
+    ('\u{24AC}', "(q)"),
+    ('\u{24AD}', "(r)"),
+    ('\u{24AE}', "(s)"),
+    ('\u{24AF}', "(t)"),
+    ('\u{24B0}', "(u)"),
+    ('\u{24B1}', "(v)"),
+    ('\u{24B2}', "(w)"),
+    ('\u{24B3}', "(x)"),
+    ('\u{24B4}', "(y)"),
+    ('\u{24B5}', "(z)"),
+    ('\u{24B6}', "A"),
+    ('\u{24B7}', "B"),
+    ('\u{24B8}', "C"),
+    ('\u{24B9}', "D"),
+    ('\u{24BA}', "E"),
+    ('\u{24BB}', "F"),
+    ('\u{24BC}', "G"),
+    ('\u{24BD}', "H"),
+    ('\u{24BE}', "I"),
+    ('\u{24BF}', "J"),
+    ('\u{24C0}', "K"),
+    ('\u{24C1}', "L"),
+    ('\u{24C2}', "M"),
+    ('\u{24C3}', "N"),
+    ('\u{24C4}', "O"),
+    ('\u{24C5}', "P"),
+    ('\u{24C6}', "Q"),
+    ('\u{24C7}', "R"),
+    ('\u{24C8}', "S"),
+    ('\u{24C9}', "T"),
+    ('\u{24CA}', "U"),
+    ('\u{24CB}', "V"),
+    ('\u{24CC}', "W"),
+    ('\u{24CD}', "X"),
+    ('\u{24CE}', "Y"),
+    ('\u{24CF}', "Z"),
+    ('\u{24D0}', "a"),
+    ('\u{24D1}', "b"),
+    ('\u{24D2}', "c"),
+    ('\u{24D3}', "d"),
+    ('\u{24D4}', "e"),
+    ('\u{24D5}', "f"),
+    ('\u{24D6}', "g"),
+    ('\u{24D7}', "h"),
+    ('\u{24D8}', "i"),
+    ('\u{24D9}', "j"),
+    ('\u{24DA}', "k"),
+    ('\u{24DB}', "l"),
+    ('\u{24DC}', "m"),
+    ('\u{24DD}', "n"),
+    ('\u{24DE}', "o"),
+    ('\u{24DF}', "p"),
+    ('\u{24E0}', "q"),
+    ('\u{24E1}', "r"),
+    ('\u{24E2}', "s"),
+    ('\u{24E3}', "t"),
+    ('\u{24E4}', "u"),
+    ('\u{24E5}', "v"),
+    ('\u{24E6}', "w"),
+    ('\u{24E7}', "x"),
+    ('\u{24E8}', "y"),
+    ('\u{24E9}', "z"),
+    ('\u{24EA}', "0"),
+    ('\u{2A74}', "::="),
+    ('\u{2A75}', "=="),
+    ('\u{2A76}', "==="),
+    ('\u{2C7C}', "j"),
+    ('\u{2C7D}', "V"),
+    ('\u{3000}', " "),
+    ('\u{3250}', "PTE"),
+    ('\u{3251}', "21"),
+    ('\u{3252}', "22"),
+    ('\u{3253}', "23"),
+    ('\u{3254}', "24"),
+    ('\u{3255}', "25"),
+    ('\u{3256}', "26"),
+    ('\u{3257}', "27"),
+    ('\u{3258}', "28"),
+    ('\u{3259}', "29"),
+    ('\u{325A}', "30"),
+    ('\u{325B}', "31"),
+    ('\u{325C}', "32"),
+    ('\u{325D}', "33"),
+    ('\u{325E}', "34"),
+    ('\u{325F}', "35"),
+    ('\u{32B1}', "36"),
+    ('\u{32B2}', "37"),
+    ('\u{32B3}', "38"),
+    ('\u{32B4}', "39"),
+    ('\u{32B5}', "40"),
+    ('\u{32B6}', "41"),
+    ('\u{32B7}', "42"),
+    ('\u{32B8}', "43"),
+    ('\u{32B9}', "44"),
+    ('\u{32BA}', "45"),
+    ('\u{32BB}', "46"),
+    ('\u{32BC}', "47"),
+    ('\u{32BD}', "48"),
+    ('\u{32BE}', "49"),
+    ('\u{32BF}', "50"),
+    ('\u{32CC}', "Hg"),
+    ('\u{32CD}', "erg"),
+    ('\u{32CE}', "eV"),
+    ('\u{32CF}', "LTD"),
+    ('\u{3371}', "hPa"),
+    ('\u{3372}', "da"),
+    ('\u{3373}', "AU"),
+    ('\u{3374}', "bar"),
+    ('\u{3375}', "oV"),
+    ('\u{3376}', "pc"),
+    ('\u{3377}', "dm"),
+    ('\u{3378}', "dm2"),
+    ('\u{3379}', "dm3"),
+    ('\u{337A}', "IU"),
+    ('\u{3380}', "pA"),
+    ('\u{3381}', "nA"),
+    ('\u{3383}', "mA"),
+    ('\u{3384}', "kA"),
+    ('\u{3385}', "KB"),
+    ('\u{3386}', "MB"),
+    ('\u{3387}', "GB"),
+    ('\u{3388}', "cal"),
+    ('\u{3389}', "kcal"),
+    ('\u{338A}', "pF"),
+    ('\u{338B}', "nF"),
+    ('\u{338E}', "mg"),
+    ('\u{338F}', "kg"),
+    ('\u{3390}', "Hz"),
+    ('\u{3391}', "kHz"),
+    ('\u{3392}', "MHz"),
+    ('\u{3393}', "GHz"),
+    ('\u{3394}', "THz"),
+    ('\u{3399}', "fm"),
+    ('\u{339A}', "nm"),
+    ('\u{339C}', "mm"),
+    ('\u{339D}', "cm"),
+    ('\u{339E}', "km"),
+    ('\u{339F}', "mm2"),
+    ('\u{33A0}', "cm2"),
+    ('\u{33A1}', "m2"),
+    ('\u{33A2}', "km2"),
+    ('\u{33A3}', "mm3"),
+    ('\u{33A4}', "cm3"),
+    ('\u{33A5}', "m3"),
+    ('\u{33A6}', "km3"),
+    ('\u{33A9}', "Pa"),
+    ('\u{33AA}', "kPa"),
+    ('\u{33AB}', "MPa"),
+    ('\u{33AC}', "GPa"),
+    ('\u{33AD}', "rad"),
+    ('\u{33B0}', "ps"),
+    ('\u{33B1}', "ns"),
+    ('\u{33B3}', "ms"),
+    ('\u{33B4}', "pV"),
+    ('\u{33B5}', "nV"),
+    ('\u{33B7}', "mV"),
+    ('\u{33B8}', "kV"),
+    ('\u{33B9}', "MV"),
+    ('\u{33BA}', "pW"),
+    ('\u{33BB}', "nW"),
+    ('\u{33BD}', "mW"),
+    ('\u{33BE}', "kW"),
+    ('\u{33BF}', "MW"),
+    ('\u{33C2}', "a.m."),
+    ('\u{33C3}', "Bq"),
+    ('\u{33C4}', "cc"),
+    ('\u{33C5}', "cd"),
+    ('\u{33C7}', "Co."),
+    ('\u{33C8}', "dB"),
+    ('\u{33C9}', "Gy"),
+    ('\u{33CA}', "ha"),
+    ('\u{33CB}', "HP"),
+    ('\u{33CC}', "in"),
+    ('\u{33CD}', "KK"),
+    ('\u{33CE}', "KM"),
+    ('\u{33CF}', "kt"),
+    ('\u{33D0}', "lm"),
+    ('\u{33D1}', "ln"),
+    ('\u{33D2}', "log"),
+    ('\u{33D3}', "lx"),
+    ('\u{33D4}', "mb"),
+    ('\u{33D5}', "mil"),
+    ('\u{33D6}', "mol"),
+    ('\u{33D7}', "PH"),
+    ('\u{33D8}', "p.m."),
+    ('\u{33D9}', "PPM"),
+    ('\u{33DA}', "PR"),
+    ('\u{33DB}', "sr"),
+    ('\u{33DC}', "Sv"),
+    ('\u{33DD}', "Wb"),
+    ('\u{33FF}', "gal"),
+    ('\u{A7F2}', "C"),
+    ('\u{A7F3}', "F"),
+    ('\u{A7F4}', "Q"),
+    ('\u{A7F8}', "H"),
+    ('\u{A7F9}', "oe"),
+    ('\u{FB00}', "ff"),
+    ('\u{FB01}', "fi"),
+    ('\u{FB02}', "fl"),
+    ('\u{FB03}', "ffi"),
+    ('\u{FB04}', "ffl"),
+    ('\u{FB06}', "st"),
+    ('\u{FE10}', ","),
+    ('\u{FE13}', ":"),
+    ('\u{FE14}', ";"),
+    ('\u{FE15}', "!"),
+    ('\u{FE16}', "?"),
+    ('\u{FE19}', "..."),
+    ('\u{FE30}', ".."),
+    ('\u{FE31}', "-"),
+    ('\u{FE32}', "-"),
+    ('\u{FE33}', "_"),
+    ('\u{FE34}', "_"),
+    ('\u{FE35}', "("),
+    ('\u{FE36}', ")"),
+    ('\u{FE37}', "{"),
+    ('\u{FE38}', "}"),
+    ('\u{FE47}', "["),
+    ('\u{FE48}', "]"),
+    ('\u{FE50}', ","),
+    ('\u{FE52}', "."),
+    ('\u{FE54}', ";"),
+    ('\u{FE55}', ":"),
+    ('\u{FE56}', "?"),
+    ('\u{FE57}', "!"),
+    ('\u{FE58}', "-"),
+    ('\u{FE59}', "("),
+    ('\u{FE5A}', ")"),
+    ('\u{FE5B}', "{"),
+    ('\u{FE5C}', "}"),
+    ('\u{FE5F}', "#"),
+    ('\u{FE60}', "&"),
+    ('\u{FE61}', "*"),
+    ('\u{FE62}', "+"),
+    ('\u{FE63}', "-"),
+    ('\u{FE64}', "<"),
+    ('\u{FE65}', ">"),
+    ('\u{FE66}', "="),
+    ('\u{FE68}', "\\"),
+    ('\u{FE69}', "$"),
+    ('\u{FE6A}', "%"),
+    ('\u{FE6B}', "@"),
+    ('\u{FF01}', "!"),
+    ('\u{FF02}', "\""),
+    ('\u{FF03}', "#"),
+    ('\u{FF04}', "$"),
+    ('\u{FF05}', "%"),
+    ('\u{FF06}', "&"),
+    ('\u{FF07}', "'"),
+    ('\u{FF08}', "("),
+    ('\u{FF09}', ")"),
+    ('\u{FF0A}', "*"),
+    ('\u{FF0B}', "+"),
+    ('\u{FF0C}', ","),
+    ('\u{FF0D}', "-"),
+    ('\u{FF0E}', "."),
+    ('\u{FF0F}', "/"),
+    ('\u{FF10}', "0"),
+    ('\u{FF11}', "1"),
+    ('\u{FF12}', "2"),
+    ('\u{FF13}', "3"),
+    ('\u{FF14}', "4"),
+    ('\u{FF15}', "5"),
+    ('\u{FF16}', "6"),
+    ('\u{FF17}', "7"),
+    ('\u{FF18}', "8"),
+    ('\u{FF19}', "9"),
+    ('\u{FF1A}', ":"),
+    ('\u{FF1B}', ";"),
+    ('\u{FF1C}', "<"),
+    ('\u{FF1D}', "="),
+    ('\u{FF1E}', ">"),
+    ('\u{FF1F}', "?"),
+    ('\u{FF20}', "@"),
+    ('\u{FF21}', "A"),
+    ('\u{FF22}', "B"),
+    ('\u{FF23}', "C"),
+    ('\u{FF24}', "D"),
+    ('\u{FF25}', "E"),
+    ('\u{FF26}', "F"),
+    ('\u{FF27}', "G"),
+    ('\u{FF28}', "H"),
+    ('\u{FF29}', "I"),
+    ('\u{FF2A}', "J"),
+    ('\u{FF2B}', "K"),
+    ('\u{FF2C}', "L"),
+    ('\u{FF2D}', "M"),
+    ('\u{FF2E}', "N"),
+    ('\u{FF2F}', "O"),
+    ('\u{FF30}', "P"),
+    ('\u{FF31}', "Q"),
+    ('\u{FF32}', "R"),
+    ('\u{FF33}', "S"),
+    ('\u{FF34}', "T"),
+    ('\u{FF35}', "U"),
+    ('\u{FF36}', "V"),
+    ('\u{FF37}', "W"),
+    ('\u{FF38}', "X"),
+    ('\u{FF39}', "Y"),
+    ('\u{FF3A}', "Z"),
+    ('\u{FF3B}', "["),
+    ('\u{FF3C}', "\\"),
+    ('\u{FF3D}', "]"),
+    ('\u{FF3E}', "^"),
+    ('\u{FF3F}', "_"),
+    ('\u{FF40}', "`"),
+    ('\u{FF41}', "a"),
+    ('\u{FF42}', "b"),
+    ('\u{FF43}', "c"),
+    ('\u{FF44}', "d"),
+    ('\u{FF45}', "e"),
+    ('\u{FF46}', "f"),
+    ('\u{FF47}', "g"),
+    ('\u{FF48}', "h"),
+    ('\u{FF49}', "i"),
+    ('\u{FF4A}', "j"),
+    ('\u{FF4B}', "k"),
+    ('\u{FF4C}', "l"),
+    ('\u{FF4D}', "m"),
+    ('\u{FF4E}', "n"),
+    ('\u{FF4F}', "o"),
+    ('\u{FF50}', "p"),
+    ('\u{FF51}', "q"),
+    ('\u{FF52}', "r"),
+    ('\u{FF53}', "s"),
+    ('\u{FF54}', "t"),
+    ('\u{FF55}', "u"),
+    ('\u{FF56}', "v"),
+    ('\u{FF57}', "w"),
+    ('\u{FF58}', "x"),
+    ('\u{FF59}', "y"),
+    ('\u{FF5A}', "z"),
+    ('\u{FF5B}', "{"),
+    ('\u{FF5C}', "|"),
+    ('\u{FF5D}', "}"),
+    ('\u{FF5E}', "~"),
+    ('\u{FFE0}', "c"),
+    ('\u{10783}', "ae"),
+    ('\u{10795}', "h"),
+    ('\u{107A2}', "o"),
+    ('\u{107A5}', "q"),
+    ('\u{1F100}', "0."),
+    ('\u{1F101}', "0,"),
+    ('\u{1F102}', "1,"),
+    ('\u{1F103}', "2,"),
+    ('\u{1F104}', "3,"),
+    ('\u{1F105}', "4,"),
+    ('\u{1F106}', "5,"),
+    ('\u{1F107}', "6,"),
+    ('\u{1F108}', "7,"),
+    ('\u{1F109}', "8,"),
+    ('\u{1F10A}', "9,"),
+    ('\u{1F110}', "(A)"),
+    ('\u{1F111}', "(B)"),
+    ('\u{1F112}', "(C)"),
+    ('\u{1F113}', "(D)"),
+    ('\u{1F114}', "(E)"),
+    ('\u{1F115}', "(F)"),
+    ('\u{1F116}', "(G)"),
+    ('\u{1F117}', "(H)"),
+    ('\u{1F118}', "(I)"),
+    ('\u{1F119}', "(J)"),
+    ('\u{1F11A}', "(K)"),
+    ('\u{1F11B}', "(L)"),
+    ('\u{1F11C}', "(M)"),
+    ('\u{1F11D}', "(N)"),
+    ('\u{1F11E}', "(O)"),
+    ('\u{1F11F}', "(P)"),
+    ('\u{1F120}', "(Q)"),
+    ('\u{1F121}', "(R)"),
+    ('\u{1F122}', "(S)"),
+    ('\u{1F123}', "(T)"),
+    ('\u{1F124}', "(U)"),
+    ('\u{1F125}', "(V)"),
+    ('\u{1F126}', "(W)"),
+    ('\u{1F127}', "(X)"),
+    ('\u{1F128}', "(Y)"),
+    ('\u{1F129}', "(Z)"),
+    ('\u{1F12B}', "C"),
+    ('\u{1F12C}', "R"),
+    ('\u{1F12D}', "CD"),
+    ('\u{1F12E}', "WZ"),
+    ('\u{1F130}', "A"),
+    ('\u{1F131}', "B"),
+    ('\u{1F132}', "C"),
+    ('\u{1F133}', "D"),
+    ('\u{1F134}', "E"),
+    ('\u{1F135}', "F"),
+    ('\u{1F136}', "G"),
+    ('\u{1F137}', "H"),
+    ('\u{1F138}', "I"),
+    ('\u{1F139}', "J"),
+    ('\u{1F13A}', "K"),
+    ('\u{1F13B}', "L"),
+    ('\u{1F13C}', "M"),
+    ('\u{1F13D}', "N"),
+    ('\u{1F13E}', "O"),
+    ('\u{1F13F}', "P"),
+    ('\u{1F140}', "Q"),
+    ('\u{1F141}', "R"),
+    ('\u{1F142}', "S"),
+    ('\u{1F143}', "T"),
+    ('\u{1F144}', "U"),
+    ('\u{1F145}', "V"),
+    ('\u{1F146}', "W"),
+    ('\u{1F147}', "X"),
+    ('\u{1F148}', "Y"),
+    ('\u{1F149}', "Z"),
+    ('\u{1F14A}', "HV"),
+    ('\u{1F14B}', "MV"),
+    ('\u{1F14C}', "SD"),
+    ('\u{1F14D}', "SS"),
+    ('\u{1F14E}', "PPV"),
+    ('\u{1F14F}', "WC"),
+    ('\u{1F16A}', "MC"),
+    ('\u{1F16B}', "MD"),
+    ('\u{1F16C}', "MR"),
+    ('\u{1F190}', "DJ"),
+];
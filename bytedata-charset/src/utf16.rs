@@ -106,6 +106,14 @@ impl Utf16Encoding {
                     // SAFETY: the length is returned from the decode function
                     seq = unsafe { seq.get_unchecked(len..) };
                 }
+                DecodeResult::CharPair(ch0, ch1, len) => {
+                    let len = len as usize >> 1_i32;
+                    chars.push(ch0);
+                    chars.push(ch1);
+                    consumed += len;
+                    // SAFETY: the length is returned from the decode function
+                    seq = unsafe { seq.get_unchecked(len..) };
+                }
                 _ if consumed != 0 => {
                     return crate::result::ExhaustiveDecodeResult::Decoded(consumed);
                 }
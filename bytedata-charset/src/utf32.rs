@@ -100,6 +100,14 @@ impl crate::Charset for Utf32Encoding {
     fn size_hint(&self) -> (u16, u16) {
         (4, 4)
     }
+
+    #[inline]
+    fn charset_alias(&self) -> &[&'static str] {
+        match self.0 {
+            CharsetEndian::Big => ["utf-32be", "utf-32", "csutf32be", "csutf32"].as_slice(),
+            CharsetEndian::Little => ["utf-32le", "csutf32le"].as_slice(),
+        }
+    }
 }
 
 #[cfg_attr(docsrs, doc(cfg(feature = "utf-32")))]
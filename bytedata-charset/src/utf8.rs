@@ -46,6 +46,24 @@ impl Utf8Encoding {
             }
         }
 
+        #[cfg(all(any(target_arch = "x86_64", target_arch = "x86"), feature = "sse2"))]
+        if maxlen >= 16 && crate::ascii7::sse2_detected() {
+            // SAFETY: The pointer is valid and the length is correct, and sse2 has been checked.
+            let res = unsafe { super::ascii7::ascii7_decode_sse2(bytes_ptr, maxlen, 0) };
+            if !matches!(res, crate::DecodeResult::Empty) {
+                return res;
+            }
+        }
+
+        #[cfg(feature = "swar")]
+        if maxlen >= core::mem::size_of::<usize>() {
+            // SAFETY: The pointer is valid and the length is correct.
+            let res = unsafe { super::ascii7::ascii7_decode_swar(bytes_ptr, maxlen, 0) };
+            if !matches!(res, crate::DecodeResult::Empty) {
+                return res;
+            }
+        }
+
         fallback(bytes)
     }
 
@@ -127,6 +145,20 @@ impl crate::CharsetDecoding for Utf8Encoding {
     fn decode(&self, bytes: &[u8]) -> crate::DecodeResult {
         Self::decode_const(self, bytes)
     }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn decode_all<'a>(
+        &self,
+        bytes: bytedata::ByteData<'a>,
+        invalid: crate::DecodeInvalid,
+    ) -> Result<bytedata::StringData<'a>, crate::DecodeError> {
+        if core::str::from_utf8(bytes.as_slice()).is_ok() {
+            // SAFETY: the input has been validated as utf-8
+            return Ok(unsafe { bytedata::StringData::from_bytedata_unchecked(bytes) });
+        }
+        crate::bulk::decode_all(self, bytes, invalid)
+    }
 }
 
 impl crate::CharsetEncoding for Utf8Encoding {
@@ -134,6 +166,28 @@ impl crate::CharsetEncoding for Utf8Encoding {
     fn encode(&self, chars: &str) -> crate::EncodeResult {
         Self::encode_const(self, chars)
     }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn encode_all<'a>(
+        &self,
+        chars: &'a str,
+        _invalid: crate::EncodeInvalid,
+    ) -> Result<bytedata::ByteData<'a>, &'static str> {
+        Ok(chars.as_bytes().into())
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn encode_to_vec(
+        &self,
+        chars: &str,
+        _invalid: crate::EncodeInvalid,
+        out: &mut alloc::vec::Vec<u8>,
+    ) -> Result<(), &'static str> {
+        out.extend_from_slice(chars.as_bytes());
+        Ok(())
+    }
 }
 
 #[expect(clippy::missing_asserts_for_indexing)]
@@ -154,7 +208,6 @@ const fn detect_const_inner(bytes: &[u8]) -> crate::detect::DetectionResult {
                 return crate::detect::DetectionResult::Certain;
             }
         }
-        return crate::detect::DetectionResult::Irrelevant;
     }
     let mut i = 0;
     while i < len {
@@ -165,21 +218,36 @@ const fn detect_const_inner(bytes: &[u8]) -> crate::detect::DetectionResult {
             i += 1;
             continue;
         }
-        if bytes[i] & 0b1110_0000 == 0b1100_0000 {
-            if i + 1 < len {
-                if bytes[i + 1] & 0b1100_0000 == 0b1000_0000 {
-                    i += 2;
-                    continue;
+        // the sequence length and the valid range of the second byte, which excludes overlong forms, surrogates, and values above U+10FFFF
+        let (width, second_min, second_max) = match bytes[i] {
+            0xC2..=0xDF => (2, 0x80, 0xBF),
+            0xE0 => (3, 0xA0, 0xBF),
+            0xE1..=0xEC | 0xEE..=0xEF => (3, 0x80, 0xBF),
+            0xED => (3, 0x80, 0x9F),
+            0xF0 => (4, 0x90, 0xBF),
+            0xF1..=0xF3 => (4, 0x80, 0xBF),
+            0xF4 => (4, 0x80, 0x8F),
+            _ => return crate::detect::DetectionResult::Irrelevant,
+        };
+        let mut j = 1;
+        while j < width {
+            if i + j >= len {
+                if len >= 1024 {
+                    return crate::detect::DetectionResult::Certain;
                 }
-                return crate::detect::DetectionResult::Irrelevant;
+                return crate::detect::DetectionResult::Tentative;
             }
-            if len >= 1024 {
-                return crate::detect::DetectionResult::Certain;
+            let (min, max) = if j == 1 {
+                (second_min, second_max)
+            } else {
+                (0x80, 0xBF)
+            };
+            if bytes[i + j] < min || bytes[i + j] > max {
+                return crate::detect::DetectionResult::Irrelevant;
             }
-            return crate::detect::DetectionResult::Tentative;
+            j += 1;
         }
-
-        return crate::detect::DetectionResult::Irrelevant;
+        i += width;
     }
     crate::detect::DetectionResult::Tentative
 }
@@ -202,3 +270,84 @@ const fn decode_const_inner(bytes: &[u8]) -> crate::DecodeResult {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::detect::DetectionResult;
+
+    #[test]
+    fn test_detect() {
+        assert_eq!(
+            Utf8Encoding::detect_const(b"\xEF\xBB\xBFhello"),
+            DetectionResult::Certain
+        );
+        assert_eq!(
+            Utf8Encoding::detect_const(b"\xEF\xBB"),
+            DetectionResult::Incomplete
+        );
+        assert_eq!(
+            Utf8Encoding::detect_const("Grüße".as_bytes()),
+            DetectionResult::Tentative
+        );
+        assert_eq!(
+            Utf8Encoding::detect_const("日本語のテキスト".as_bytes()),
+            DetectionResult::Tentative
+        );
+        assert_eq!(
+            Utf8Encoding::detect_const("\u{FF71}\u{1F600}".as_bytes()),
+            DetectionResult::Tentative
+        );
+        // a sequence cut off at the end of the input
+        assert_eq!(
+            Utf8Encoding::detect_const(&"日本".as_bytes()[..5]),
+            DetectionResult::Tentative
+        );
+
+        // overlong, surrogate, and out of range sequences
+        assert_eq!(
+            Utf8Encoding::detect_const(b"\xC0\xAF"),
+            DetectionResult::Irrelevant
+        );
+        assert_eq!(
+            Utf8Encoding::detect_const(b"\xE0\x80\xAF"),
+            DetectionResult::Irrelevant
+        );
+        assert_eq!(
+            Utf8Encoding::detect_const(b"\xED\xA0\x80"),
+            DetectionResult::Irrelevant
+        );
+        assert_eq!(
+            Utf8Encoding::detect_const(b"\xF4\x90\x80\x80"),
+            DetectionResult::Irrelevant
+        );
+        assert_eq!(
+            Utf8Encoding::detect_const(b"caf\xE9 au lait"),
+            DetectionResult::Irrelevant
+        );
+        assert_eq!(
+            Utf8Encoding::detect_const(b"a\0b"),
+            DetectionResult::Irrelevant
+        );
+    }
+
+    #[test]
+    fn test_detect_long() {
+        // "日" cut off after two bytes at the end of a kilobyte of input
+        let mut bytes = [b'a'; 1024];
+        bytes[1021] = 0xE6;
+        bytes[1022] = 0x97;
+        bytes[1023] = 0xA5;
+        assert_eq!(
+            Utf8Encoding::detect_const(&bytes),
+            DetectionResult::Tentative
+        );
+        assert_eq!(
+            Utf8Encoding::detect_const(&bytes[1..1023]),
+            DetectionResult::Tentative
+        );
+        bytes.copy_within(1021..1023, 1022);
+        bytes[1021] = b'a';
+        assert_eq!(Utf8Encoding::detect_const(&bytes), DetectionResult::Certain);
+    }
+}
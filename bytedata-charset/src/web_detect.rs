@@ -0,0 +1,710 @@
+//! ## Web charset detection
+//!
+//! Selects the most likely charset of a document among all charsets enabled through cargo features, similar to how browsers sniff the charset of a page.
+//! See the [`WebDetector`] struct for more information.
+
+use crate::detect::DetectionResult;
+
+/// A detector that selects the most likely charset among all enabled charsets.
+///
+/// The candidates are ranked in the order browsers use when sniffing the charset of a document without a declared charset:
+///
+/// 1. A byte order mark for UTF-16 or UTF-32, which makes the charset [`DetectionResult::Certain`].
+/// 2. UTF-8, if the data starts with a byte order mark or contains valid multi-byte sequences.
+/// 3. The default charset, usually taken from the locale of the user with [`WebDetector::with_locale`], if the data contains non-ASCII characters.
+///    The multi-byte charsets follow, if the data is valid in their structure and contains at least two consecutive multi-byte characters (or escape sequences for ISO-2022-JP).
+///    They are checked in the order EUC-KR, EUC-JP, `Shift_JIS`, Big5 and GB18030, as the later ones accept more of the byte sequences used by the earlier ones.
+/// 4. The single-byte charsets in the order of the [`CharsetRegistry`], if no byte decodes to an invalid character or to a control character not expected in text.
+///    The default charset is the first of them for pure ASCII data, just as a browser would use it.
+///    Without a default charset, `windows-1252` is the first of them for data with non-ASCII characters, as it is the default of browsers for all locales without one of their own.
+///    Multi-byte charsets that only decode isolated multi-byte characters, as is common for accented letters in Latin text, follow the single-byte charsets with the same detection result.
+///
+/// A charset is only compared by its detection result to the other charsets of the same step, so a [`DetectionResult::Certain`] single-byte charset never outranks a [`DetectionResult::Tentative`] multi-byte charset of step 3.
+/// Charsets that are [`DetectionResult::Incomplete`] or [`DetectionResult::Irrelevant`] are ranked after all others, in the same order.
+/// UTF-16 and UTF-32 without a byte order mark are not candidates, as browsers never detect them.
+///
+/// Labels declared in the document or by the transport layer are not considered, those should be resolved with the [`CharsetRegistry`] before detection is used.
+///
+/// [`CharsetRegistry`]: crate::CharsetRegistry
+#[derive(Clone, Copy, Default)]
+pub struct WebDetector {
+    default: Option<&'static dyn crate::CharsetCodec>,
+}
+
+/// How a charset is ranked compared to other charsets with the same detection result.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    /// UTF-8, which is preferred if the data contains non-ASCII characters.
+    Utf8,
+    /// A multi-byte charset, which is preferred over single-byte charsets if the data contains non-ASCII characters.
+    #[cfg_attr(
+        not(any(
+            feature = "euc-kr",
+            feature = "euc-jp",
+            feature = "shift_jis",
+            feature = "big5",
+            feature = "gb18030"
+        )),
+        expect(dead_code)
+    )]
+    MultiByte,
+    /// A 7-bit charset using escape sequences, which only is tentative if escape sequences are present.
+    #[cfg_attr(not(feature = "iso-2022-jp"), expect(dead_code))]
+    Escaped,
+    /// A charset detected by decoding, which is the case for all single-byte charsets.
+    Decoded,
+    /// UTF-16 and UTF-32, which are only candidates with a byte order mark.
+    Unicode,
+}
+
+/// Whether a charset is preferred over other charsets with the same detection result.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Preference {
+    /// The default charset of the detector.
+    Default,
+    /// `windows-1252` for a detector without a default charset, which is only preferred among the single-byte charsets.
+    Fallback,
+    /// Any other charset.
+    None,
+}
+
+type DetectFn = fn(&[u8]) -> DetectionResult;
+
+/// The charsets with a detector of their own, in the order they are ranked if the results are equal.
+const DETECTORS: &[(&dyn crate::CharsetCodec, Kind, DetectFn)] = &[
+    (
+        &crate::Utf8Encoding::new(),
+        Kind::Utf8,
+        crate::Utf8Encoding::detect_const,
+    ),
+    #[cfg(feature = "iso-2022-jp")]
+    (
+        &crate::multi_byte::Iso2022JpEncoding::new(),
+        Kind::Escaped,
+        crate::multi_byte::Iso2022JpEncoding::detect_const,
+    ),
+    #[cfg(feature = "euc-kr")]
+    (
+        &crate::multi_byte::EucKrEncoding::new(),
+        Kind::MultiByte,
+        crate::multi_byte::EucKrEncoding::detect_const,
+    ),
+    #[cfg(feature = "euc-jp")]
+    (
+        &crate::multi_byte::EucJpEncoding::new(),
+        Kind::MultiByte,
+        crate::multi_byte::EucJpEncoding::detect_const,
+    ),
+    #[cfg(feature = "shift_jis")]
+    (
+        &crate::multi_byte::ShiftJisEncoding::new(),
+        Kind::MultiByte,
+        crate::multi_byte::ShiftJisEncoding::detect_const,
+    ),
+    #[cfg(feature = "big5")]
+    (
+        &crate::multi_byte::Big5Encoding::new(),
+        Kind::MultiByte,
+        crate::multi_byte::Big5Encoding::detect_const,
+    ),
+    #[cfg(feature = "gb18030")]
+    (
+        &crate::multi_byte::Gb18030Encoding::new(),
+        Kind::MultiByte,
+        crate::multi_byte::Gb18030Encoding::detect_const,
+    ),
+    // the UTF-32LE byte order mark starts with the one for UTF-16LE
+    #[cfg(feature = "utf-32")]
+    (&crate::Utf32Encoding::UTF32_BE, Kind::Unicode, |bytes| {
+        crate::Utf32Encoding::UTF32_BE.detect_const(bytes)
+    }),
+    #[cfg(feature = "utf-32")]
+    (&crate::Utf32Encoding::UTF32_LE, Kind::Unicode, |bytes| {
+        crate::Utf32Encoding::UTF32_LE.detect_const(bytes)
+    }),
+    (&crate::Utf16Encoding::UTF16_BE, Kind::Unicode, |bytes| {
+        crate::Utf16Encoding::UTF16_BE.detect_const(bytes)
+    }),
+    (&crate::Utf16Encoding::UTF16_LE, Kind::Unicode, |bytes| {
+        crate::Utf16Encoding::UTF16_LE.detect_const(bytes)
+    }),
+];
+
+/// Single-byte charsets that are never detected, as they do not represent text.
+const NOT_DETECTED: &[&str] = &["replacement", "x-user-defined"];
+
+/// The default charsets of the locales listed by the [HTML standard](https://html.spec.whatwg.org/multipage/parsing.html#determining-the-character-encoding), except for Chinese.
+const LOCALE_DEFAULTS: &[(&str, &str)] = &[
+    ("ar", "windows-1256"),
+    ("az", "windows-1254"),
+    ("ba", "windows-1251"),
+    ("be", "windows-1251"),
+    ("bg", "windows-1251"),
+    ("cs", "windows-1250"),
+    ("el", "iso-8859-7"),
+    ("et", "windows-1257"),
+    ("fa", "windows-1256"),
+    ("he", "windows-1255"),
+    ("hr", "windows-1250"),
+    ("hu", "iso-8859-2"),
+    ("ja", "shift_jis"),
+    ("kk", "windows-1251"),
+    ("ko", "euc-kr"),
+    ("ku", "windows-1254"),
+    ("ky", "windows-1251"),
+    ("lt", "windows-1257"),
+    ("lv", "windows-1257"),
+    ("mk", "windows-1251"),
+    ("pl", "iso-8859-2"),
+    ("ru", "windows-1251"),
+    ("sah", "windows-1251"),
+    ("sk", "windows-1250"),
+    ("sl", "iso-8859-2"),
+    ("sr", "windows-1251"),
+    ("tg", "windows-1251"),
+    ("th", "windows-874"),
+    ("tr", "windows-1254"),
+    ("tt", "windows-1251"),
+    ("uk", "windows-1251"),
+    ("vi", "windows-1258"),
+];
+
+impl WebDetector {
+    /// Create a new detector without a default charset.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { default: None }
+    }
+
+    /// Create a new detector that prefers the given charset over other charsets with the same detection result.
+    #[inline]
+    #[must_use]
+    pub const fn with_default(charset: &'static dyn crate::CharsetCodec) -> Self {
+        Self {
+            default: Some(charset),
+        }
+    }
+
+    /// Create a new detector that prefers the charset browsers default to for the given locale, such as `"ja-JP"`.
+    ///
+    /// If that charset is not enabled through cargo features, the detector has no default charset.
+    /// See [`WebDetector::locale_default_name`] for the charset used for each locale.
+    #[must_use]
+    #[expect(clippy::missing_inline_in_public_items)]
+    pub fn with_locale(locale: &str) -> Self {
+        Self {
+            default: crate::CharsetRegistry::lookup(Self::locale_default_name(locale)),
+        }
+    }
+
+    /// The charset that is preferred over other charsets with the same detection result.
+    #[inline]
+    #[must_use]
+    pub const fn default_charset(&self) -> Option<&'static dyn crate::CharsetCodec> {
+        self.default
+    }
+
+    /// The name of the charset browsers default to for documents in the given locale.
+    ///
+    /// The locale is a language tag such as `"pl"`, `"zh-TW"` or `"zh_Hant_HK"`, of which only the language and, for Chinese, the script or region is used.
+    /// The table is the one suggested by the [HTML standard], where `windows-1252` is used for all locales not listed.
+    ///
+    /// [HTML standard]: https://html.spec.whatwg.org/multipage/parsing.html#determining-the-character-encoding
+    #[must_use]
+    #[expect(clippy::missing_inline_in_public_items)]
+    pub fn locale_default_name(locale: &str) -> &'static str {
+        let mut subtags = locale.trim().split(['-', '_']);
+        let language = subtags.next().unwrap_or_default();
+        if language.eq_ignore_ascii_case("zh") {
+            let traditional = subtags.any(|subtag| {
+                ["hant", "tw", "hk", "mo"]
+                    .iter()
+                    .any(|tag| tag.eq_ignore_ascii_case(subtag))
+            });
+            return if traditional { "big5" } else { "gb18030" };
+        }
+        LOCALE_DEFAULTS
+            .iter()
+            .find(|entry| entry.0.eq_ignore_ascii_case(language))
+            .map_or("windows-1252", |entry| entry.1)
+    }
+
+    /// Select the most likely charset for the given bytes, together with the detection result of that charset.
+    #[must_use]
+    #[expect(clippy::missing_inline_in_public_items)]
+    pub fn select_charset(
+        &self,
+        bytes: &[u8],
+    ) -> (&'static dyn crate::CharsetCodec, DetectionResult) {
+        let mut best: (
+            &'static dyn crate::CharsetCodec,
+            DetectionResult,
+            (u8, u8, u8, u8, u8),
+        ) = (
+            &crate::UTF8,
+            DetectionResult::Irrelevant,
+            (u8::MAX, u8::MAX, u8::MAX, u8::MAX, u8::MAX),
+        );
+        self.for_each_candidate(bytes, |charset, result, key| {
+            if key < best.2 {
+                best = (charset, result, key);
+            }
+        });
+        (best.0, best.1)
+    }
+
+    /// Rank all candidate charsets for the given bytes, starting with the most likely one.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    #[must_use]
+    #[expect(clippy::missing_inline_in_public_items)]
+    pub fn rank(
+        &self,
+        bytes: &[u8],
+    ) -> alloc::vec::Vec<(&'static dyn crate::CharsetCodec, DetectionResult)> {
+        let mut ranked = alloc::vec::Vec::new();
+        self.for_each_candidate(bytes, |charset, result, key| {
+            ranked.push((key, charset, result));
+        });
+        ranked.sort_by_key(|entry| entry.0);
+        ranked.into_iter().map(|entry| (entry.1, entry.2)).collect()
+    }
+
+    /// Detect the bytes with every candidate charset, calling `visit` with each charset, its result and the key it is ranked by.
+    fn for_each_candidate<F>(&self, bytes: &[u8], mut visit: F)
+    where
+        F: FnMut(&'static dyn crate::CharsetCodec, DetectionResult, (u8, u8, u8, u8, u8)),
+    {
+        let non_ascii = !bytes.is_ascii();
+        let mut default_seen = self.default.is_none();
+        let mut candidate = |charset: &'static dyn crate::CharsetCodec, kind, result, score| {
+            let is_default = self.default.is_some_and(|default| {
+                crate::CharsetRegistry::canonical_name(default)
+                    == crate::CharsetRegistry::canonical_name(charset)
+            });
+            let multi_byte_run = kind == Kind::MultiByte
+                && non_ascii
+                && matches!(
+                    result,
+                    DetectionResult::Certain | DetectionResult::Tentative
+                )
+                && has_multi_byte_run(charset, bytes);
+            let preference = if is_default {
+                Preference::Default
+            } else if self.default.is_none()
+                && crate::CharsetRegistry::canonical_name(charset) == "windows-1252"
+            {
+                Preference::Fallback
+            } else {
+                Preference::None
+            };
+            visit(
+                charset,
+                result,
+                rank_key(kind, result, non_ascii, multi_byte_run, score, preference),
+            );
+            is_default
+        };
+        for entry in DETECTORS {
+            let result = (entry.2)(bytes);
+            if entry.1 == Kind::Unicode && result != DetectionResult::Certain {
+                continue;
+            }
+            default_seen |= candidate(entry.0, entry.1, result, 0);
+        }
+        #[cfg(feature = "statistical-detect")]
+        crate::StatisticalDetector::new().for_each_result(bytes, |charset, result, score| {
+            default_seen |= candidate(charset, Kind::Decoded, result, score);
+        });
+        #[cfg(not(feature = "statistical-detect"))]
+        for charset in crate::CharsetRegistry::charsets().iter().copied() {
+            if is_single_byte_candidate(charset) {
+                default_seen |=
+                    candidate(charset, Kind::Decoded, detect_decoded(charset, bytes), 0);
+            }
+        }
+        if let Some(default) = self.default.filter(|_| !default_seen) {
+            candidate(default, Kind::Decoded, detect_decoded(default, bytes), 0);
+        }
+    }
+}
+
+impl core::fmt::Debug for WebDetector {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("WebDetector")
+            .field(
+                "default",
+                &self.default.map(crate::CharsetRef::charset_name),
+            )
+            .finish()
+    }
+}
+
+impl crate::detect::CharsetDetector for WebDetector {
+    #[inline]
+    fn detect(&self, bytes: &[u8]) -> DetectionResult {
+        self.select_charset(bytes).1
+    }
+}
+
+impl crate::detect::CharsetSelector for WebDetector {
+    type Charset = &'static dyn crate::CharsetCodec;
+
+    #[inline]
+    fn select(&self, bytes: &[u8]) -> (Self::Charset, DetectionResult) {
+        self.select_charset(bytes)
+    }
+}
+
+/// The key a candidate is ranked by, where lower is more likely: the step of the detection order, the detection result, whether a single-byte charset is preferred, the statistical score and whether it is the default charset.
+///
+/// A multi-byte charset only takes part in step 3 if `multi_byte_run` is set, otherwise it is ranked after the single-byte charsets of step 4.
+/// The [`Preference::Fallback`] charset is ranked first of the single-byte charsets of step 4.
+const fn rank_key(
+    kind: Kind,
+    result: DetectionResult,
+    non_ascii: bool,
+    multi_byte_run: bool,
+    score: u8,
+    preference: Preference,
+) -> (u8, u8, u8, u8, u8) {
+    let result_rank = match result {
+        DetectionResult::Certain => 0,
+        DetectionResult::Tentative => 1,
+        DetectionResult::Incomplete => 2,
+        DetectionResult::Irrelevant => 3,
+    };
+    let step = match kind {
+        _ if result_rank > 1 => 4,
+        Kind::Unicode => 0,
+        Kind::Utf8 if non_ascii => 1,
+        Kind::MultiByte if non_ascii && multi_byte_run => 2,
+        Kind::Decoded if non_ascii && matches!(preference, Preference::Default) => 2,
+        Kind::Escaped => 2,
+        Kind::Utf8 | Kind::MultiByte | Kind::Decoded => 3,
+    };
+    let decoded_rank = match kind {
+        Kind::Decoded if non_ascii && matches!(preference, Preference::Fallback) => 0,
+        Kind::Decoded if non_ascii => 1,
+        Kind::Utf8 | Kind::MultiByte | Kind::Escaped | Kind::Decoded | Kind::Unicode => 2,
+    };
+    (
+        step,
+        result_rank,
+        decoded_rank,
+        u8::MAX - score,
+        if matches!(preference, Preference::Default) {
+            0
+        } else {
+            1
+        },
+    )
+}
+
+/// Returns `true` if the bytes decode to at least two consecutive multi-byte characters.
+///
+/// Text in a multi-byte charset has runs of such characters, while the bytes of accented letters in a single-byte charset mostly decode to isolated ones.
+fn has_multi_byte_run(charset: &dyn crate::CharsetCodec, bytes: &[u8]) -> bool {
+    let mut state = crate::CharsetState::new();
+    let mut rest = bytes;
+    let mut previous = false;
+    while !rest.is_empty() {
+        let (consumed, multi_byte) = match charset.decode_stateful(&mut state, rest) {
+            crate::DecodeResult::Utf8(len) => {
+                #[expect(clippy::cast_possible_truncation)]
+                let len = len as usize;
+                (len, false)
+            }
+            crate::DecodeResult::Char(_, len)
+            | crate::DecodeResult::CharPair(_, _, len)
+            | crate::DecodeResult::InvalidChar(_, len) => (len as usize, len > 1),
+            crate::DecodeResult::Empty | crate::DecodeResult::Incomplete => break,
+        };
+        if consumed == 0 {
+            break;
+        }
+        if multi_byte && previous {
+            return true;
+        }
+        previous = multi_byte;
+        rest = rest.get(consumed..).unwrap_or_default();
+    }
+    false
+}
+
+/// Returns `true` for single-byte charsets that are candidates for detection by decoding.
+#[expect(clippy::redundant_pub_crate)]
+pub(crate) fn is_single_byte_candidate(charset: &dyn crate::CharsetCodec) -> bool {
+    charset.size_hint() == (1, 1) && !NOT_DETECTED.contains(&charset.charset_name())
+}
+
+/// Returns `true` for control characters that are not expected in text.
+#[expect(clippy::redundant_pub_crate)]
+pub(crate) const fn is_unexpected_control(ch: char) -> bool {
+    ch.is_control() && !matches!(ch, '\t' | '\n' | '\x0C' | '\r')
+}
+
+/// Detect a charset by decoding the bytes.
+///
+/// The charset is irrelevant if any byte sequence is invalid or decodes to a control character that is not expected in text, which rules out most ISO-8859 charsets for data using the C1 range of a Windows code page.
+fn detect_decoded(charset: &dyn crate::CharsetCodec, bytes: &[u8]) -> DetectionResult {
+    if bytes.is_empty() {
+        return DetectionResult::Incomplete;
+    }
+    let mut state = crate::CharsetState::new();
+    let mut rest = bytes;
+    while !rest.is_empty() {
+        let consumed = match charset.decode_stateful(&mut state, rest) {
+            crate::DecodeResult::Utf8(len) => {
+                #[expect(clippy::cast_possible_truncation)]
+                let len = len as usize;
+                let text = rest.get(..len).unwrap_or(rest);
+                if text
+                    .iter()
+                    .any(|byte| is_unexpected_control(char::from(*byte)))
+                {
+                    return DetectionResult::Irrelevant;
+                }
+                len
+            }
+            crate::DecodeResult::Char(ch, len) => {
+                if is_unexpected_control(ch) {
+                    return DetectionResult::Irrelevant;
+                }
+                len as usize
+            }
+            crate::DecodeResult::CharPair(first, second, len) => {
+                if is_unexpected_control(first) || is_unexpected_control(second) {
+                    return DetectionResult::Irrelevant;
+                }
+                len as usize
+            }
+            crate::DecodeResult::InvalidChar(_, _) => return DetectionResult::Irrelevant,
+            crate::DecodeResult::Empty | crate::DecodeResult::Incomplete => break,
+        };
+        if consumed == 0 {
+            break;
+        }
+        rest = rest.get(consumed..).unwrap_or_default();
+    }
+    DetectionResult::Tentative
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn selected(detector: WebDetector, bytes: &[u8]) -> (&'static str, DetectionResult) {
+        let (charset, result) = detector.select_charset(bytes);
+        (crate::CharsetRegistry::canonical_name(charset), result)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn rank_names(detector: WebDetector, bytes: &[u8]) -> alloc::vec::Vec<&'static str> {
+        detector
+            .rank(bytes)
+            .iter()
+            .map(|entry| crate::CharsetRegistry::canonical_name(entry.0))
+            .collect()
+    }
+
+    #[test]
+    fn test_locale_default_name() {
+        assert_eq!(WebDetector::locale_default_name("ja-JP"), "shift_jis");
+        assert_eq!(WebDetector::locale_default_name(" PL "), "iso-8859-2");
+        assert_eq!(WebDetector::locale_default_name("zh-TW"), "big5");
+        assert_eq!(WebDetector::locale_default_name("zh_Hant_HK"), "big5");
+        assert_eq!(WebDetector::locale_default_name("zh-Hans-CN"), "gb18030");
+        assert_eq!(WebDetector::locale_default_name("en-US"), "windows-1252");
+        assert_eq!(WebDetector::locale_default_name(""), "windows-1252");
+    }
+
+    #[test]
+    fn test_select_unicode() {
+        let detector = WebDetector::with_locale("ja");
+        assert_eq!(
+            selected(detector, b"\xEF\xBB\xBFhello"),
+            ("utf-8", DetectionResult::Certain)
+        );
+        assert_eq!(
+            selected(detector, b"\xFF\xFEh\0i\0"),
+            ("utf-16le", DetectionResult::Certain)
+        );
+        assert_eq!(
+            selected(detector, "日本語のテキスト".as_bytes()),
+            ("utf-8", DetectionResult::Tentative)
+        );
+    }
+
+    #[cfg(all(feature = "shift_jis", feature = "windows-1252"))]
+    #[test]
+    fn test_select_locale() {
+        // "日本語" in Shift_JIS
+        let sjis = b"\x93\xFA\x96\x7B\x8C\xEA";
+        assert_eq!(
+            selected(WebDetector::with_locale("ja"), sjis),
+            ("shift_jis", DetectionResult::Tentative)
+        );
+        // the default charset of the locale is preferred if it can decode the data
+        let ranked = rank_names(WebDetector::with_locale("en"), sjis);
+        assert_eq!(ranked.get(..2), Some(&["windows-1252", "shift_jis"][..]));
+        // "日本語のテキストです。" in Shift_JIS, where the `0x81` of the full stop is not in windows-1252
+        let sentence = b"\x93\xFA\x96\x7B\x8C\xEA\x82\xCC\x83\x65\x83\x4C\x83\x58\x83\x67\x82\xC5\x82\xB7\x81\x42";
+        assert_eq!(
+            selected(WebDetector::with_locale("en"), sentence),
+            ("shift_jis", DetectionResult::Tentative)
+        );
+        assert_eq!(
+            selected(WebDetector::with_locale("ja"), b"plain ascii").0,
+            "shift_jis"
+        );
+        assert_eq!(selected(WebDetector::new(), b"plain ascii").0, "utf-8");
+        assert_eq!(
+            selected(WebDetector::with_locale("fr"), b"caf\xE9 au lait"),
+            ("windows-1252", DetectionResult::Tentative)
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_select_without_bom() {
+        let detector = WebDetector::new();
+        // no single-byte charset decodes the NUL bytes, but UTF-16 is never detected without a byte order mark
+        assert_ne!(selected(detector, b"h\0i\0").0, "utf-16le");
+        let ranked = rank_names(detector, b"h\0i\0");
+        assert!(!ranked.contains(&"utf-16le"));
+        assert!(!ranked.contains(&"utf-16be"));
+        assert_eq!(
+            rank_names(detector, b"\xFF\xFEh\0i\0").first(),
+            Some(&"utf-16le")
+        );
+    }
+
+    #[cfg(all(feature = "windows-1252", feature = "big5", feature = "gb18030"))]
+    #[test]
+    fn test_select_latin_default() {
+        // "Grüße aus München" in windows-1252, which also is valid Big5 and GB18030
+        let bytes = b"Gr\xFC\xDFe aus M\xFCnchen";
+        assert_eq!(
+            selected(WebDetector::with_locale("de"), bytes),
+            ("windows-1252", DetectionResult::Tentative)
+        );
+        // the multi-byte charsets only decode isolated characters, so they do not outrank the single-byte charsets
+        assert_eq!(
+            selected(WebDetector::new(), bytes),
+            ("windows-1252", DetectionResult::Tentative)
+        );
+    }
+
+    #[cfg(feature = "windows-1252")]
+    #[test]
+    fn test_select_french() {
+        // "Ceci est un texte français très simple à lire, n'est-ce pas ? Où est la forêt ?" in windows-1252
+        let bytes = b"Ceci est un texte fran\xE7ais tr\xE8s simple \xE0 lire, n'est-ce pas ? O\xF9 est la for\xEAt ?";
+        assert_eq!(
+            selected(WebDetector::with_locale("fr"), bytes),
+            ("windows-1252", DetectionResult::Tentative)
+        );
+    }
+
+    #[cfg(all(feature = "iso-8859-7", feature = "euc-kr"))]
+    #[test]
+    fn test_select_greek_default() {
+        // "Καλημέρα φίλε" in ISO-8859-7, which also is valid EUC-KR
+        let bytes = b"\xCA\xE1\xEB\xE7\xEC\xDD\xF1\xE1 \xF6\xDF\xEB\xE5";
+        assert_eq!(
+            selected(WebDetector::with_locale("el"), bytes),
+            ("iso-8859-7", DetectionResult::Tentative)
+        );
+    }
+
+    #[cfg(all(feature = "windows-1251", feature = "euc-kr"))]
+    #[test]
+    fn test_select_cyrillic_default() {
+        // "Привет мир" in windows-1251, which also is valid EUC-KR
+        let bytes = b"\xCF\xF0\xE8\xE2\xE5\xF2 \xEC\xE8\xF0";
+        assert_eq!(
+            selected(WebDetector::with_locale("ru"), bytes),
+            ("windows-1251", DetectionResult::Tentative)
+        );
+    }
+
+    #[cfg(feature = "euc-jp")]
+    #[test]
+    fn test_select_multi_byte_step() {
+        // "日本語のテキストです" in EUC-JP, which the language models may take for Greek in ISO-8859-7
+        let bytes =
+            b"\xC6\xFC\xCB\xDC\xB8\xEC\xA4\xCE\xA5\xC6\xA5\xAD\xA5\xB9\xA5\xC8\xA4\xC7\xA4\xB9";
+        assert_eq!(
+            selected(WebDetector::new(), bytes),
+            ("euc-jp", DetectionResult::Tentative)
+        );
+        assert_eq!(
+            selected(WebDetector::with_locale("ja"), bytes),
+            ("euc-jp", DetectionResult::Tentative)
+        );
+    }
+
+    #[cfg(all(feature = "alloc", feature = "windows-1251", feature = "iso-8859-5"))]
+    #[test]
+    fn test_rank() {
+        #[cfg(feature = "statistical-detect")]
+        fn rank_order(charset: &dyn crate::CharsetCodec, bytes: &[u8]) -> u8 {
+            let score = crate::StatisticalDetector::new().score(charset, bytes);
+            u8::MAX - score.map_or(0, |score| score.score())
+        }
+        #[cfg(not(feature = "statistical-detect"))]
+        fn rank_order(charset: &dyn crate::CharsetCodec, _bytes: &[u8]) -> Option<usize> {
+            let name = crate::CharsetRegistry::canonical_name(charset);
+            crate::CharsetRegistry::charsets()
+                .iter()
+                .position(|other| crate::CharsetRegistry::canonical_name(*other) == name)
+        }
+
+        let detector = WebDetector::with_locale("ru");
+        // "Привет, как дела? Всё хорошо…" in windows-1251
+        let bytes = b"\xCF\xF0\xE8\xE2\xE5\xF2, \xEA\xE0\xEA \xE4\xE5\xEB\xE0? \xC2\xF1\xB8 \xF5\xEE\xF0\xEE\xF8\xEE\x85";
+        let ranked = detector.rank(bytes);
+        let names: alloc::vec::Vec<_> = ranked
+            .iter()
+            .map(|entry| (crate::CharsetRegistry::canonical_name(entry.0), entry.1))
+            .collect();
+        // the language models tell windows-1251 apart from the other Cyrillic charsets
+        let expected = if cfg!(feature = "statistical-detect") {
+            DetectionResult::Certain
+        } else {
+            DetectionResult::Tentative
+        };
+        assert_eq!(names.first(), Some(&("windows-1251", expected)));
+        // the charsets that can decode the text come first, followed by those that can not
+        let viable = names
+            .iter()
+            .take_while(|entry| {
+                matches!(
+                    entry.1,
+                    DetectionResult::Certain | DetectionResult::Tentative
+                )
+            })
+            .count();
+        assert!(viable > 1, "other charsets can decode the text: {names:?}");
+        assert!(names.get(viable..).unwrap_or_default().iter().all(|entry| {
+            matches!(
+                entry.1,
+                DetectionResult::Incomplete | DetectionResult::Irrelevant
+            )
+        }));
+        // the other single-byte charsets are ordered by their score, or by the registry without the language models
+        let keys: alloc::vec::Vec<_> = ranked
+            .get(1..viable)
+            .unwrap_or_default()
+            .iter()
+            .map(|entry| rank_order(entry.0, bytes))
+            .collect();
+        assert!(keys.is_sorted(), "not ranked in order: {names:?}");
+        // the ellipsis at `0x85` is a C1 control character in ISO-8859-5
+        assert!(names.contains(&("iso-8859-5", DetectionResult::Irrelevant)));
+        assert!(names.contains(&("utf-8", DetectionResult::Irrelevant)));
+    }
+}
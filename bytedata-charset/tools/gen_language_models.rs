@@ -0,0 +1,408 @@
+//! Generates `src/statistical/models.rs`, the language models used by the `statistical-detect` feature.
+//!
+//! The corpus is the text of the gettext message catalogs (`<locale>/LC_MESSAGES/*.mo`) and translated manual pages (`<lang>/man*/*`) of a Linux distribution.
+//! The committed models were generated from `/usr/share/locale` and `/usr/share/man` of a Debian 12 installation.
+//! The letters of each language are listed in [`LANGUAGES`], the corpus only provides the frequencies of their bigrams.
+//!
+//! Run from the `bytedata-charset` directory, with `gzip` in the path to read compressed manual pages:
+//!
+//! ```sh
+//! rustc --edition 2021 -O tools/gen_language_models.rs -o target/gen_language_models \
+//!     && target/gen_language_models /usr/share/locale --man /usr/share/man \
+//!     && cargo fmt
+//! ```
+
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+
+/// The path of the generated models, relative to the crate root.
+const MODELS: &str = "src/statistical/models.rs";
+
+/// The languages to generate models for, with the name of their script and their non-ASCII lowercase letters.
+/// The models of Latin script languages also include the ASCII letters.
+const LANGUAGES: &[(&str, &str, &str)] = &[
+    ("ru", "Cyrillic", "абвгдеёжзийклмнопрстуфхцчшщъыьэюя"),
+    ("uk", "Cyrillic", "абвгґдеєжзиіїйклмнопрстуфхцчшщьюя"),
+    ("be", "Cyrillic", "абвгдеёжзійклмнопрстуўфхцчшыьэюя"),
+    ("bg", "Cyrillic", "абвгдежзийклмнопрстуфхцчшщъьюя"),
+    ("sr", "Cyrillic", "абвгдђежзијклљмнњопрстћуфхцчџш"),
+    ("mk", "Cyrillic", "абвгдѓежзѕијклљмнњопрстќуфхцчџш"),
+    ("el", "Greek", "αβγδεζηθικλμνξοπρστυφχψωάέήίόύώϊϋΐΰς"),
+    ("he", "Hebrew", "אבגדהוזחטיךכלםמןנסעףפץצקרשת"),
+    ("ar", "Arabic", "ءآأؤإئابةتثجحخدذرزسشصضطظعغفقكلمنهوىي"),
+    // Persian is written with the Arabic `ي` in windows-1256, which has no `ی`
+    ("fa", "Arabic", "ءآأؤئابپتثجچحخدذرزژسشصضطظعغفقکگلمنهوي"),
+    (
+        "th",
+        "Thai",
+        "กขฃคฅฆงจฉชซฌญฎฏฐฑฒณดตถทธนบปผฝพฟภมยรฤลฦวศษสหฬอฮฯะัาำิีึืฺุูเแโใไๅๆ็่้๊๋์ํ๎",
+    ),
+    ("de", "Latin", "äöüß"),
+    // a model is only used for charsets that can decode all of its letters, and ISO-8859-1 has no `œ`
+    ("fr", "Latin", "àâçéèêëîïôùûü"),
+    ("es", "Latin", "áéíñóúü"),
+    ("it", "Latin", "àèéìíòóùú"),
+    ("pt", "Latin", "áâãàçéêíóôõú"),
+    ("nl", "Latin", "áéèëïóöü"),
+    ("sv", "Latin", "åäöé"),
+    ("da", "Latin", "æøåé"),
+    ("nb", "Latin", "æøåé"),
+    ("fi", "Latin", "äöå"),
+    ("ca", "Latin", "àçéèíïòóúü"),
+    ("is", "Latin", "áðéíóúýþæö"),
+    ("ga", "Latin", "áéíóú"),
+    ("et", "Latin", "äöõüšž"),
+    ("pl", "Latin", "ąćęłńóśźż"),
+    ("cs", "Latin", "áčďéěíňóřšťúůýž"),
+    ("sk", "Latin", "áäčďéíĺľňóôŕšťúýž"),
+    ("sl", "Latin", "čšž"),
+    ("hr", "Latin", "čćđšž"),
+    ("hu", "Latin", "áéíóöőúüű"),
+    ("ro", "Latin", "ăâîșț"),
+    ("tr", "Latin", "çğıöşüâîû"),
+    ("lt", "Latin", "ąčęėįšųūž"),
+    ("lv", "Latin", "āčēģīķļņšūž"),
+];
+
+fn main() {
+    let mut locale_dirs = Vec::new();
+    let mut man_dirs = Vec::new();
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        if arg == "--man" {
+            man_dirs.push(PathBuf::from(args.next().expect("--man needs a directory")));
+        } else {
+            locale_dirs.push(PathBuf::from(arg));
+        }
+    }
+    assert!(
+        !locale_dirs.is_empty() || !man_dirs.is_empty(),
+        "usage: gen_language_models <locale dir>... [--man <man dir>]..."
+    );
+
+    let mut corpus: BTreeMap<String, String> = BTreeMap::new();
+    for dir in &locale_dirs {
+        for (lang, path) in language_dirs(dir) {
+            let Some(lang) = lang else { continue };
+            for catalog in sorted_files(&path.join("LC_MESSAGES")) {
+                if catalog.extension().is_some_and(|ext| ext == "mo") {
+                    let text = corpus.entry(lang.clone()).or_default();
+                    read_catalog(&catalog, text);
+                }
+            }
+        }
+    }
+    for dir in &man_dirs {
+        for (lang, path) in language_dirs(dir) {
+            let Some(lang) = lang else { continue };
+            for section in sorted_files(&path) {
+                let is_section = section
+                    .file_name()
+                    .and_then(|name| name.to_str())
+                    .is_some_and(|name| name.starts_with("man"));
+                if !is_section {
+                    continue;
+                }
+                for page in sorted_files(&section) {
+                    let text = corpus.entry(lang.clone()).or_default();
+                    read_man_page(&page, text);
+                }
+            }
+        }
+    }
+
+    let mut out = String::new();
+    out.push_str(concat!(
+        "//! Language models of the statistical detector.\n",
+        "//!\n",
+        "//! Each model lists the lowercase letters of the language and scores every bigram of a word boundary, a letter or any other letter.\n",
+        "//! The scores are `255 + 12 * log2(p)` for the probability `p` of the bigram, so that each step of 12 halves the probability, with `0` for bigrams that were never seen.\n",
+        "//! Generated by `tools/gen_language_models.rs` from the gettext message catalogs and translated manual pages of a Linux distribution, do not edit.\n",
+        "\n",
+        "use super::{LanguageModel, Script};\n",
+        "\n",
+        "pub(super) static MODELS: &[LanguageModel] = &[\n",
+    ));
+    for &(lang, script, letters) in LANGUAGES {
+        let text = corpus
+            .get(lang)
+            .unwrap_or_else(|| panic!("no corpus text for {lang}"));
+        let mut alphabet: Vec<char> = letters.chars().collect();
+        if script == "Latin" {
+            alphabet.extend('a'..='z');
+        }
+        alphabet.sort_unstable();
+        alphabet.dedup();
+        let bigrams = build_model(lang, &alphabet, &clean(text));
+        eprintln!(
+            "{lang}: {} letters, {} bytes of text",
+            alphabet.len(),
+            text.len()
+        );
+        write_model(&mut out, lang, script, &alphabet, &bigrams);
+    }
+    out.push_str("];\n");
+    std::fs::write(MODELS, out).expect("failed to write the models");
+}
+
+/// The subdirectories of a locale or manual page directory, with the language they are for.
+///
+/// The language is the part of the name before the region, except for Brazilian Portuguese and Chinese, and `None` for variants such as `sr@latin`.
+fn language_dirs(dir: &Path) -> Vec<(Option<String>, PathBuf)> {
+    sorted_files(dir)
+        .into_iter()
+        .filter(|path| path.is_dir())
+        .map(|path| {
+            let name = path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .unwrap_or_default();
+            let lang = if name.contains('@') || name.starts_with("man") {
+                None
+            } else if name == "pt_BR" || name.starts_with("zh_") {
+                Some(name.to_owned())
+            } else {
+                name.split('_').next().map(str::to_owned)
+            };
+            (lang, path)
+        })
+        .collect()
+}
+
+/// The entries of a directory sorted by name, or nothing if it does not exist.
+fn sorted_files(dir: &Path) -> Vec<PathBuf> {
+    let mut files: Vec<PathBuf> = std::fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .filter_map(|entry| Some(entry.ok()?.path()))
+                .collect()
+        })
+        .unwrap_or_default();
+    files.sort();
+    files
+}
+
+/// Append the translations of a UTF-8 gettext catalog to the text, one per line.
+fn read_catalog(path: &Path, text: &mut String) {
+    let Ok(data) = std::fs::read(path) else {
+        return;
+    };
+    let word = |offset: usize, le: bool| -> Option<usize> {
+        let bytes: [u8; 4] = data.get(offset..offset + 4)?.try_into().ok()?;
+        let value = if le {
+            u32::from_le_bytes(bytes)
+        } else {
+            u32::from_be_bytes(bytes)
+        };
+        usize::try_from(value).ok()
+    };
+    let le = match word(0, true) {
+        Some(0x9504_12DE) => true,
+        Some(0xDE12_0495) => false,
+        _ => return,
+    };
+    let (Some(count), Some(originals), Some(translations)) =
+        (word(8, le), word(12, le), word(16, le))
+    else {
+        return;
+    };
+    let string = |table: usize, idx: usize| -> Option<&[u8]> {
+        let len = word(table + idx * 8, le)?;
+        let offset = word(table + idx * 8 + 4, le)?;
+        data.get(offset..offset + len)
+    };
+    let mut strings = Vec::new();
+    for idx in 0..count {
+        let (Some(msgid), Some(msgstr)) = (string(originals, idx), string(translations, idx))
+        else {
+            return;
+        };
+        if msgid.is_empty() {
+            // the header, which declares the charset of the catalog
+            let header = String::from_utf8_lossy(msgstr).to_ascii_lowercase();
+            if header.contains("charset=") && !header.contains("charset=utf-8") {
+                return;
+            }
+            continue;
+        }
+        let Ok(msgstr) = std::str::from_utf8(msgstr) else {
+            return;
+        };
+        // plural forms are separated by NUL
+        strings.extend(msgstr.split('\0').filter(|form| !form.is_empty()));
+    }
+    for form in strings {
+        text.push_str(form);
+        text.push('\n');
+    }
+}
+
+/// Append the text lines of a manual page to the text, without requests and font or character escapes.
+fn read_man_page(path: &Path, text: &mut String) {
+    let data = if path.extension().is_some_and(|ext| ext == "gz") {
+        let Ok(output) = std::process::Command::new("gzip")
+            .arg("-dc")
+            .arg(path)
+            .output()
+        else {
+            return;
+        };
+        output.stdout
+    } else {
+        let Ok(data) = std::fs::read(path) else {
+            return;
+        };
+        data
+    };
+    let Ok(page) = String::from_utf8(data) else {
+        return;
+    };
+    for line in page.lines() {
+        if line.starts_with(['.', '\'']) {
+            continue;
+        }
+        let mut chars = line.chars();
+        while let Some(ch) = chars.next() {
+            if ch != '\\' {
+                text.push(ch);
+                continue;
+            }
+            let rest = chars.as_str();
+            let skip = match rest.chars().next() {
+                Some('f') if rest[1..].starts_with('(') => 4,
+                Some('f') if rest[1..].starts_with(['B', 'I', 'R', 'P']) => 2,
+                Some('(') => 3,
+                Some('-' | '&' | 'e') => 1,
+                Some('*') if rest[1..].starts_with('[') => rest.find(']').map_or(0, |end| end + 1),
+                _ => 0,
+            };
+            if skip == 0 {
+                text.push(ch);
+            } else {
+                text.push(' ');
+                for _ in 0..skip {
+                    chars.next();
+                }
+            }
+        }
+        text.push('\n');
+    }
+}
+
+/// Replace printf placeholders, markup, entities and underscores, which are not prose, with spaces.
+fn clean(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(ch) = rest.chars().next() {
+        let skip = match ch {
+            '%' => {
+                let flags = rest[1..]
+                    .find(|ch: char| !matches!(ch, '-' | '+' | ' ' | '#' | '0'..='9' | '.'))
+                    .map_or(rest.len(), |end| end + 1);
+                rest[flags..]
+                    .chars()
+                    .next()
+                    .filter(char::is_ascii_alphabetic)
+                    .map(|_| flags + 1)
+            }
+            '<' => rest.find('>').map(|end| end + 1),
+            '&' => {
+                let name = rest[1..]
+                    .find(|ch: char| !ch.is_ascii_lowercase())
+                    .map_or(rest.len(), |end| end + 1);
+                (name > 1 && rest[name..].starts_with(';')).then_some(name + 1)
+            }
+            '_' => Some(1),
+            _ => None,
+        };
+        if let Some(skip) = skip {
+            out.push(' ');
+            rest = &rest[skip..];
+        } else {
+            out.push(ch);
+            rest = &rest[ch.len_utf8()..];
+        }
+    }
+    out
+}
+
+/// Count the bigrams of the classes of the text, returning the score of each bigram.
+///
+/// The classes are the same as in `LanguageModel::class`: `0` for a word boundary, `1..=alphabet.len()` for the letters and the last one for any other letter.
+fn build_model(lang: &str, alphabet: &[char], text: &str) -> Vec<u8> {
+    let classes = alphabet.len() + 2;
+    let class = |ch: char| {
+        let ch = if lang == "fa" && ch == '\u{06CC}' {
+            '\u{064A}'
+        } else {
+            ch
+        };
+        let lower = ch.to_lowercase().next().unwrap_or(ch);
+        match alphabet.binary_search(&lower) {
+            Ok(idx) => idx + 1,
+            Err(_) if ch.is_alphabetic() => classes - 1,
+            Err(_) => 0,
+        }
+    };
+    let mut counts = vec![0_u64; classes * classes];
+    let mut prev = 0;
+    for ch in text.chars() {
+        let class = class(ch);
+        if class != 0 || prev != 0 {
+            counts[prev * classes + class] += 1;
+        }
+        prev = class;
+    }
+    let total: u64 = counts.iter().sum();
+    counts
+        .iter()
+        .enumerate()
+        .map(|(idx, &count)| {
+            let other = idx / classes == classes - 1 || idx % classes == classes - 1;
+            if count == 0 || other {
+                return 0;
+            }
+            let score = 255.0 + 12.0 * (count as f64 / total as f64).log2();
+            score.round_ties_even().clamp(1.0, 255.0) as u8
+        })
+        .collect()
+}
+
+/// Write a model as a `LanguageModel` expression.
+fn write_model(out: &mut String, lang: &str, script: &str, alphabet: &[char], bigrams: &[u8]) {
+    let _ = writeln!(out, "    LanguageModel {{");
+    let _ = writeln!(out, "        language: \"{lang}\",");
+    let _ = writeln!(out, "        script: Script::{script},");
+    let _ = writeln!(out, "        alphabet: &[");
+    let letters: Vec<String> = alphabet
+        .iter()
+        .map(|&ch| {
+            if ch.is_ascii() {
+                format!("'{ch}'")
+            } else {
+                format!("'\\u{{{:04X}}}'", u32::from(ch))
+            }
+        })
+        .collect();
+    write_wrapped(out, &letters);
+    let _ = writeln!(out, "        ],");
+    let _ = writeln!(out, "        bigrams: &[");
+    let scores: Vec<String> = bigrams.iter().map(u8::to_string).collect();
+    write_wrapped(out, &scores);
+    let _ = writeln!(out, "        ],");
+    let _ = writeln!(out, "    }},");
+}
+
+/// Write the items of an array, wrapped at 100 columns.
+fn write_wrapped(out: &mut String, items: &[String]) {
+    let mut line = String::from("           ");
+    for item in items {
+        if line.len() + item.len() + 2 > 100 {
+            let _ = writeln!(out, "{line}");
+            line = String::from("           ");
+        }
+        let _ = write!(line, " {item},");
+    }
+    let _ = writeln!(out, "{line}");
+}
@@ -0,0 +1,205 @@
+//! Generates `src/transliterate/table.rs`, the transliteration table used by the `transliterate` feature.
+//!
+//! The table is derived from the canonical and compatibility decompositions in the Unicode `NamesList.txt` of the `bytedata-unicode` crate,
+//! where combining marks are dropped so that only the base characters remain.
+//! Characters without a decomposition that still have a common approximation are listed in [`EXTRA`].
+//!
+//! Run from the `bytedata-charset` directory after updating `NamesList.txt` or [`EXTRA`]:
+//!
+//! ```sh
+//! rustc --edition 2021 -O tools/gen_transliterate.rs -o target/gen_transliterate && target/gen_transliterate
+//! ```
+
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::path::Path;
+
+/// The path to `NamesList.txt`, relative to the crate root.
+const NAMES_LIST: &str = "../bytedata-unicode/unicode/NamesList.txt";
+
+/// The path of the generated table, relative to the crate root.
+const TABLE: &str = "src/transliterate/table.rs";
+
+/// The maximum length of a transliteration, which is the capacity of an `EncodeReplacement`.
+const MAX_LEN: usize = 14;
+
+/// Approximations of characters that have no decomposition to ASCII.
+/// These also apply to characters that decompose into one of them, such as `ǣ` to `æ`.
+const EXTRA: &[(char, &str)] = &[
+    // letters
+    ('\u{00C6}', "AE"),
+    ('\u{00D0}', "D"),
+    ('\u{00D8}', "O"),
+    ('\u{00DE}', "TH"),
+    ('\u{00DF}', "ss"),
+    ('\u{00E6}', "ae"),
+    ('\u{00F0}', "d"),
+    ('\u{00F8}', "o"),
+    ('\u{00FE}', "th"),
+    ('\u{0110}', "D"),
+    ('\u{0111}', "d"),
+    ('\u{0126}', "H"),
+    ('\u{0127}', "h"),
+    ('\u{0131}', "i"),
+    ('\u{0141}', "L"),
+    ('\u{0142}', "l"),
+    ('\u{0152}', "OE"),
+    ('\u{0153}', "oe"),
+    ('\u{0166}', "T"),
+    ('\u{0167}', "t"),
+    ('\u{0180}', "b"),
+    ('\u{0192}', "f"),
+    ('\u{0237}', "j"),
+    ('\u{1E9E}', "SS"),
+    // punctuation
+    ('\u{00A1}', "!"),
+    ('\u{00AB}', "<<"),
+    ('\u{00AD}', "-"),
+    ('\u{00B7}', "."),
+    ('\u{00BB}', ">>"),
+    ('\u{00BF}', "?"),
+    ('\u{00D7}', "x"),
+    ('\u{00F7}', "/"),
+    ('\u{2010}', "-"),
+    ('\u{2012}', "-"),
+    ('\u{2013}', "-"),
+    ('\u{2014}', "-"),
+    ('\u{2015}', "-"),
+    ('\u{2018}', "'"),
+    ('\u{2019}', "'"),
+    ('\u{201A}', "'"),
+    ('\u{201B}', "'"),
+    ('\u{201C}', "\""),
+    ('\u{201D}', "\""),
+    ('\u{201E}', "\""),
+    ('\u{201F}', "\""),
+    ('\u{2022}', "*"),
+    ('\u{2032}', "'"),
+    ('\u{2033}', "\""),
+    ('\u{2039}', "<"),
+    ('\u{203A}', ">"),
+    ('\u{2044}', "/"),
+    ('\u{2212}', "-"),
+    // symbols
+    ('\u{00A2}', "c"),
+    ('\u{00A9}', "(C)"),
+    ('\u{00AE}', "(R)"),
+    ('\u{20AC}', "EUR"),
+];
+
+/// Returns `true` for combining marks, which are dropped from decompositions.
+const fn is_combining_mark(ch: u32) -> bool {
+    matches!(
+        ch,
+        0x0300..=0x036F | 0x1AB0..=0x1AFF | 0x1DC0..=0x1DFF | 0x20D0..=0x20FF | 0xFE20..=0xFE2F
+    )
+}
+
+/// Parse the decompositions of `NamesList.txt`, mapping a code point to the code points it decomposes into.
+fn parse_decompositions(names_list: &str) -> BTreeMap<u32, Vec<u32>> {
+    let mut decompositions = BTreeMap::new();
+    let mut current = None;
+    for line in names_list.lines() {
+        if let Some(rest) = line.strip_prefix('\t') {
+            let Some(code) = current else {
+                continue;
+            };
+            // `: ` is a canonical and `# ` a compatibility decomposition, the latter optionally with a `<tag>`
+            let Some(decomposition) = rest.strip_prefix(": ").or_else(|| rest.strip_prefix("# "))
+            else {
+                continue;
+            };
+            let decomposition = match decomposition.strip_prefix('<') {
+                Some(tagged) => tagged.split_once("> ").map_or("", |(_, rest)| rest),
+                None => decomposition,
+            };
+            let parts: Option<Vec<u32>> = decomposition
+                .split(' ')
+                .map(|part| u32::from_str_radix(part, 16).ok())
+                .collect();
+            if let Some(parts) = parts.filter(|parts| !parts.is_empty()) {
+                decompositions.entry(code).or_insert(parts);
+            }
+        } else {
+            current = line
+                .split_once('\t')
+                .and_then(|(code, _)| u32::from_str_radix(code, 16).ok());
+        }
+    }
+    decompositions
+}
+
+/// Resolve the ASCII approximation of a code point, returning `None` if there is none.
+fn resolve(
+    code: u32,
+    decompositions: &BTreeMap<u32, Vec<u32>>,
+    extra: &BTreeMap<u32, &str>,
+    out: &mut String,
+    stripped: &mut bool,
+) -> Option<()> {
+    if code < 0x80 {
+        out.push(char::from_u32(code)?);
+        return Some(());
+    }
+    if is_combining_mark(code) {
+        *stripped = true;
+        return Some(());
+    }
+    if let Some(text) = extra.get(&code) {
+        out.push_str(text);
+        return Some(());
+    }
+    for &part in decompositions.get(&code)? {
+        resolve(part, decompositions, extra, out, stripped)?;
+    }
+    Some(())
+}
+
+fn main() {
+    let names_list = std::fs::read_to_string(NAMES_LIST)
+        .unwrap_or_else(|err| panic!("unable to read {NAMES_LIST}: {err}"));
+    let decompositions = parse_decompositions(&names_list);
+    assert!(
+        !decompositions.is_empty(),
+        "no decompositions found in {NAMES_LIST}"
+    );
+    let extra: BTreeMap<u32, &str> = EXTRA
+        .iter()
+        .map(|&(ch, text)| (u32::from(ch), text))
+        .collect();
+
+    let mut table = BTreeMap::new();
+    for &code in decompositions.keys().chain(extra.keys()) {
+        let mut text = String::new();
+        let mut stripped = false;
+        if resolve(code, &decompositions, &extra, &mut text, &mut stripped).is_none() {
+            continue;
+        }
+        // a spacing mark such as `¨` decomposes into a space and a combining mark, which is not a useful approximation
+        if text.trim().is_empty() && (stripped || text.is_empty()) {
+            continue;
+        }
+        if text.len() <= MAX_LEN {
+            table.insert(code, text);
+        }
+    }
+
+    write_table(Path::new(TABLE), &table);
+}
+
+/// Write the table as a sorted static array.
+fn write_table(path: &Path, table: &BTreeMap<u32, String>) {
+    let mut out = String::new();
+    out.push_str("// Generated by tools/gen_transliterate.rs from the Unicode NamesList.txt, do not edit.\n\n");
+    out.push_str("/// The transliterations sorted by character.\n");
+    let _ = writeln!(
+        out,
+        "pub(super) static TRANSLITERATE: [(char, &str); {}] = [",
+        table.len()
+    );
+    for (&code, text) in table {
+        let _ = writeln!(out, "    ('\\u{{{code:04X}}}', {text:?}),");
+    }
+    out.push_str("];\n");
+    std::fs::write(path, out).expect("failed to write transliteration table");
+}
@@ -0,0 +1,208 @@
+use proc_macro2::{Delimiter, Group, Ident, Literal, Punct, Spacing, Span, TokenStream, TokenTree};
+
+use std::io::BufRead;
+
+use bytedata_charset_core::parse_mapping_line;
+
+/// The kind of charset encoder to generate from a mapping file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum MappingKind {
+    /// A `SingleByteEncoding` with a table of all 256 bytes.
+    SingleByte,
+    /// An `AsciiCompatible` with a table of the 128 bytes above ASCII.
+    AsciiCompatible,
+}
+
+fn fail(file_path: &str, line_count: u32, msg: &str) -> TokenStream {
+    TokenStream::from_iter([
+        TokenTree::Ident(Ident::new("compile_error", Span::call_site())),
+        TokenTree::Punct(Punct::new('!', Spacing::Alone)),
+        TokenTree::Literal(Literal::string(&format!(
+            "{msg} (location = {file_path}:{line_count})"
+        ))),
+    ])
+}
+
+/// Create the tokens for an absolute path such as `::bytedata_charset::single_byte`.
+fn absolute_path(segments: &[&str]) -> TokenStream {
+    let mut out = TokenStream::new();
+    for segment in segments {
+        out.extend([
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+            TokenTree::Ident(Ident::new(segment, Span::call_site())),
+        ]);
+    }
+    out
+}
+
+pub(super) fn charset_mapping<R: BufRead>(
+    kind: MappingKind,
+    name: &str,
+    file_path: &str,
+    mut file: R,
+) -> TokenStream {
+    let mut table: [Option<char>; 256] = [None; 256];
+    let mut buffer = String::new();
+    let mut line_count = 0_u32;
+
+    loop {
+        buffer.clear();
+        match file.read_line(&mut buffer) {
+            Ok(0) => break,
+            Ok(_) => line_count += 1,
+            Err(_) => return fail(file_path, line_count, "Failed to read file"),
+        }
+        let (byte, ch) = match parse_mapping_line(&buffer) {
+            Ok(Some((byte, Some(ch)))) => (byte, ch),
+            // empty, comment, or explicitly undefined byte
+            Ok(_) => continue,
+            Err(msg) => return fail(file_path, line_count, msg),
+        };
+        if table[byte as usize].replace(ch).is_some() {
+            return fail(file_path, line_count, "duplicate mapping for byte");
+        }
+    }
+
+    // WHATWG index files only list the bytes above ASCII
+    if table[..0x80].iter().all(Option::is_none) {
+        for (byte, ch) in table[..0x80].iter_mut().enumerate() {
+            *ch = Some(byte as u8 as char);
+        }
+    }
+
+    let (type_name, table) = match kind {
+        MappingKind::SingleByte => ("SingleByteEncoding", &table[..]),
+        MappingKind::AsciiCompatible => {
+            let ascii = table[..0x80]
+                .iter()
+                .enumerate()
+                .all(|(byte, ch)| ch.is_none_or(|ch| ch as usize == byte));
+            if !ascii {
+                return fail(
+                    file_path,
+                    line_count,
+                    "the bytes below 0x80 must map to ASCII, use `single_byte_charset!` instead",
+                );
+            }
+            ("AsciiCompatible", &table[0x80..])
+        }
+    };
+
+    let mut chars = TokenStream::new();
+    for ch in table {
+        chars.extend([
+            TokenTree::Literal(Literal::character(ch.unwrap_or('\0'))),
+            TokenTree::Punct(Punct::new(',', Spacing::Alone)),
+        ]);
+    }
+
+    let module = match kind {
+        MappingKind::SingleByte => "single_byte",
+        MappingKind::AsciiCompatible => "ascii7_compat",
+    };
+    let mut body = TokenStream::from_iter([
+        TokenTree::Ident(Ident::new("const", Span::call_site())),
+        TokenTree::Ident(Ident::new("CHARS", Span::call_site())),
+        TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+        TokenTree::Group(Group::new(
+            Delimiter::Bracket,
+            TokenStream::from_iter([
+                TokenTree::Ident(Ident::new("char", Span::call_site())),
+                TokenTree::Punct(Punct::new(';', Spacing::Alone)),
+                TokenTree::Literal(Literal::usize_unsuffixed(table.len())),
+            ]),
+        )),
+        TokenTree::Punct(Punct::new('=', Spacing::Alone)),
+        TokenTree::Group(Group::new(Delimiter::Bracket, chars)),
+        TokenTree::Punct(Punct::new(';', Spacing::Alone)),
+    ]);
+    body.extend(absolute_path(&[
+        "bytedata_charset",
+        module,
+        type_name,
+        "new",
+    ]));
+    body.extend([TokenTree::Group(Group::new(
+        Delimiter::Parenthesis,
+        TokenStream::from_iter([
+            TokenTree::Literal(Literal::string(name)),
+            TokenTree::Punct(Punct::new(',', Spacing::Alone)),
+            TokenTree::Punct(Punct::new('&', Spacing::Alone)),
+            TokenTree::Ident(Ident::new("CHARS", Span::call_site())),
+        ]),
+    ))]);
+    TokenStream::from_iter([TokenTree::Group(Group::new(Delimiter::Brace, body))])
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const MAPPINGS: &str = "#\n#    Name:     test to Unicode table\n#\n0x00\t0x0000\t#NULL\n0x41\t0x0041\t#LATIN CAPITAL LETTER A\n0x80\t0x20AC\t#EURO SIGN\n0x81\t      \t#UNDEFINED\n0xA0\t<RL>+0x0020\t#SPACE, right-left\n0xFF\t0x05B8+0xF87F\t#HEBREW POINT QAMATS, alternate form\n";
+
+    #[test]
+    fn test_mappings_format() {
+        let out = charset_mapping(
+            MappingKind::SingleByte,
+            "x-test",
+            "test.txt",
+            MAPPINGS.as_bytes(),
+        )
+        .to_string();
+        assert!(out.contains(":: bytedata_charset :: single_byte :: SingleByteEncoding :: new"));
+        assert!(out.contains("\"x-test\""));
+        assert!(out.contains("[char ; 256]"));
+        assert!(out.contains("'A' , '\\0'"));
+        assert!(out.contains("'€' , '\\0'"));
+        assert!(out.contains("' ' ,"));
+        assert!(out.contains("'\\u{5b8}' ,"));
+    }
+
+    #[test]
+    fn test_whatwg_format() {
+        let index = "# test index\n\n     0\t0x0402\tЂ (CYRILLIC CAPITAL LETTER DJE)\n   127\t0x044F\tя (CYRILLIC SMALL LETTER YA)\n";
+        let out = charset_mapping(
+            MappingKind::AsciiCompatible,
+            "x-test",
+            "index-test.txt",
+            index.as_bytes(),
+        )
+        .to_string();
+        assert!(out.contains(":: bytedata_charset :: ascii7_compat :: AsciiCompatible :: new"));
+        assert!(out.contains("[char ; 128]"));
+        assert!(out.starts_with("{ const CHARS : [char ; 128] = ['Ђ' , '\\0'"));
+        assert!(out.contains("'я' ,] ;"));
+    }
+
+    #[test]
+    fn test_invalid_mapping() {
+        let out = charset_mapping(
+            MappingKind::AsciiCompatible,
+            "x-test",
+            "test.txt",
+            "0x41\t0x0042\n".as_bytes(),
+        )
+        .to_string();
+        assert!(out.starts_with("compile_error !"));
+        assert!(out.contains("test.txt:1"));
+
+        let out = charset_mapping(
+            MappingKind::SingleByte,
+            "x-test",
+            "test.txt",
+            "0x41\t0x0041\n0x8140\t0x3000\n".as_bytes(),
+        )
+        .to_string();
+        assert!(out.contains("only single-byte mappings are supported"));
+
+        let out = charset_mapping(
+            MappingKind::SingleByte,
+            "x-test",
+            "test.txt",
+            "0x41\t0x0041\n0x41\t0x0042\n".as_bytes(),
+        )
+        .to_string();
+        assert!(out.contains("duplicate mapping for byte"));
+    }
+}
@@ -1,5 +1,6 @@
 use proc_macro::{Ident, Punct, TokenStream, TokenTree};
 
+mod charset_mapping;
 mod names_list;
 mod util;
 
@@ -21,3 +22,43 @@ pub fn names_list(input: TokenStream) -> TokenStream {
 
     proc_macro::TokenStream::from(names_list::names_list(path, file))
 }
+
+/// Generate a `bytedata_charset::single_byte::SingleByteEncoding` from a single-byte mapping file.
+///
+/// The expected input is a string literal containing the charset name followed by a string literal containing the path to the file relative to the project root.
+/// The file may be in the unicode.org `MAPPINGS` format (`0xNN<TAB>0xUUUU`) or a WHATWG `index-*.txt` file (`pointer<TAB>0xUUUU`), where the bytes below `0x80` are ASCII.
+/// Bytes without a mapping will be decoded as invalid characters.
+///
+/// The macro expands to a constant expression, and the reverse table used for encoding is built when the encoder is evaluated at compile time.
+///
+/// ```ignore
+/// static CP1125: SingleByteEncoding = bytedata_charset::single_byte_charset!("cp1125", "mappings/CP1125.TXT");
+/// ```
+#[proc_macro]
+pub fn single_byte_charset(input: TokenStream) -> TokenStream {
+    mapping_macro(charset_mapping::MappingKind::SingleByte, input)
+}
+
+/// Generate a `bytedata_charset::ascii7_compat::AsciiCompatible` from a single-byte mapping file.
+///
+/// This works like [`single_byte_charset!`], but fails if any of the bytes below `0x80` maps to a character other than the ASCII character of the same value.
+#[proc_macro]
+pub fn ascii_compatible_charset(input: TokenStream) -> TokenStream {
+    mapping_macro(charset_mapping::MappingKind::AsciiCompatible, input)
+}
+
+fn mapping_macro(kind: charset_mapping::MappingKind, input: TokenStream) -> TokenStream {
+    let input = proc_macro2::TokenStream::from(input);
+    let (name, path, file) = match util::parse_name_file_params(input) {
+        Ok((name, path, file)) => (name, path, std::io::BufReader::new(file)),
+        Err((span, err)) => {
+            return TokenStream::from_iter([
+                TokenTree::Ident(Ident::new("compile_error", span.unwrap())),
+                TokenTree::Punct(Punct::new('!', proc_macro::Spacing::Alone)),
+                TokenTree::Literal(proc_macro::Literal::string(err)),
+            ])
+        }
+    };
+
+    proc_macro::TokenStream::from(charset_mapping::charset_mapping(kind, &name, &path, file))
+}
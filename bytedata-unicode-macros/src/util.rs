@@ -1,26 +1,63 @@
 use proc_macro2::{Span, TokenStream, TokenTree};
 
-pub(crate) fn parse_file_param(
-    input: TokenStream,
-) -> Result<(String, std::fs::File), (Span, &'static str)> {
-    let mut input = input.into_iter();
-    let Some(path) = input.next() else {
+fn parse_str_literal(tok: Option<TokenTree>) -> Result<(Span, String), (Span, &'static str)> {
+    let Some(tok) = tok else {
         return Err((Span::call_site(), "Expected a quoted file path"));
     };
-    let (path_span, path) = match path {
+    let (lit_span, lit) = match tok {
         TokenTree::Literal(lit) => (lit.span(), lit.to_string()),
         tok => return Err((tok.span(), "Expected a string literal")),
     };
-    let Some(path) = path
-        .strip_prefix('"')
-        .and_then(|path| path.strip_suffix('"'))
-    else {
-        return Err((path_span, "Expected a string literal"));
+    let Some(lit) = lit.strip_prefix('"').and_then(|lit| lit.strip_suffix('"')) else {
+        return Err((lit_span, "Expected a string literal"));
+    };
+    Ok((lit_span, lit.to_owned()))
+}
+
+fn open_file(
+    path_span: Span,
+    path: String,
+) -> Result<(String, std::fs::File), (Span, &'static str)> {
+    std::fs::File::open(&path)
+        .map_err(|_| (path_span, "Failed to open file"))
+        .map(|x| (path, x))
+}
+
+pub(crate) fn parse_file_param(
+    input: TokenStream,
+) -> Result<(String, std::fs::File), (Span, &'static str)> {
+    let mut input = input.into_iter();
+    let (path_span, path) = parse_str_literal(input.next())?;
+    if let Some(p) = input.next() {
+        return Err((p.span(), "Unexpected token after file path"));
     };
+    open_file(path_span, path)
+}
+
+/// Parse the parameters `"name", "path"` where the path is relative to the project root.
+pub(crate) fn parse_name_file_params(
+    input: TokenStream,
+) -> Result<(String, String, std::fs::File), (Span, &'static str)> {
+    let mut input = input.into_iter();
+    let (name_span, name) = parse_str_literal(input.next())
+        .map_err(|(span, _)| (span, "Expected a quoted charset name"))?;
+    if name.is_empty() || name.contains(['\\', '"']) {
+        return Err((name_span, "Expected a plain charset name"));
+    }
+    match input.next() {
+        Some(TokenTree::Punct(p)) if p.as_char() == ',' => {}
+        Some(tok) => return Err((tok.span(), "Expected a comma after the charset name")),
+        None => return Err((Span::call_site(), "Expected a quoted file path")),
+    }
+    let (path_span, path) = parse_str_literal(input.next())?;
+    match input.next() {
+        Some(TokenTree::Punct(p)) if p.as_char() == ',' => {}
+        Some(p) => return Err((p.span(), "Unexpected token after file path")),
+        None => {}
+    }
     if let Some(p) = input.next() {
         return Err((p.span(), "Unexpected token after file path"));
     };
-    std::fs::File::open(path)
-        .map_err(|_| (path_span, "Failed to open file"))
-        .map(|x| (path.to_owned(), x))
+    let (path, file) = open_file(path_span, path)?;
+    Ok((name, path, file))
 }